@@ -514,8 +514,6 @@ async fn run_benchmark(args: &Args) -> Result<CausalDiscoveryBenchResults, Box<d
                     "maxPairs": args.max_pairs,
                     "minConfidence": args.min_confidence,
                     "sessionScope": "all",
-                    "similarityThreshold": 0.5,
-                    "skipAnalyzed": true,
                     "dryRun": true
                 }
             })),
@@ -555,8 +553,6 @@ async fn run_benchmark(args: &Args) -> Result<CausalDiscoveryBenchResults, Box<d
                         "maxPairs": args.max_pairs,
                         "minConfidence": args.min_confidence,
                         "sessionScope": "all",
-                        "similarityThreshold": 0.5,
-                        "skipAnalyzed": false,
                         "dryRun": false
                     }
                 })),
@@ -2,6 +2,7 @@
 //!
 //! NO BACKWARDS COMPATIBILITY - FAIL FAST WITH ROBUST LOGGING.
 
+use context_graph_core::error::{ContextGraphError, ErrorKind};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -96,4 +97,23 @@ impl CausalAgentError {
             message: e.to_string(),
         }
     }
+
+    /// Machine-readable classification of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::LlmNotInitialized | Self::LlmLoadError { .. } | Self::ModelNotFound { .. } => {
+                ErrorKind::CapabilityUnavailable
+            }
+            Self::InsufficientVram { .. } => ErrorKind::GpuFailure,
+            Self::MemoryNotFound { .. } | Self::NoCandidatesFound => ErrorKind::NotFound,
+            _ => ErrorKind::Internal,
+        }
+    }
+}
+
+impl From<CausalAgentError> for ContextGraphError {
+    fn from(err: CausalAgentError) -> Self {
+        let kind = err.kind();
+        ContextGraphError::from_source(kind, err)
+    }
 }
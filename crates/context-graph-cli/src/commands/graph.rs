@@ -0,0 +1,168 @@
+//! Graph commands - Bulk import and export of typed graph edges.
+//!
+//! # Usage
+//!
+//! ```bash
+//! # Seed the graph from a dependency dataset
+//! context-graph-cli graph import-edges --path ./deps.jsonl
+//!
+//! # Export the code edges leaving one memory
+//! context-graph-cli graph export-edges --path ./code-edges.jsonl \
+//!     --edge-type code_related --source 5f0c6a3e-...
+//! ```
+//!
+//! The JSONL record format is documented on
+//! `context_graph_storage::graph_edges::EdgeRecord`. Invalid and duplicate
+//! lines are skipped and reported with their line numbers; the import exits
+//! non-zero if any line was rejected.
+//!
+//! # Prerequisites
+//!
+//! Stop the MCP server first; both open the same RocksDB.
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use context_graph_core::graph_linking::GraphLinkEdgeType;
+use context_graph_storage::graph_edges::{EdgeExportFilter, EdgeFormat, EdgeRepository};
+use context_graph_storage::teleological::RocksDbTeleologicalStore;
+
+/// Graph subcommands.
+#[derive(Subcommand)]
+pub enum GraphCommands {
+    /// Import typed edges from a file
+    ImportEdges(ImportEdgesArgs),
+    /// Export typed edges to a file
+    ExportEdges(ExportEdgesArgs),
+}
+
+/// Arguments for `graph import-edges`
+#[derive(Args, Debug)]
+pub struct ImportEdgesArgs {
+    /// Edge file to import
+    #[arg(long)]
+    pub path: PathBuf,
+
+    /// Edge file format
+    #[arg(long, default_value = "jsonl")]
+    pub format: EdgeFormat,
+
+    /// Database path
+    #[arg(long, env = "CONTEXT_GRAPH_DATA_DIR")]
+    pub db_path: Option<PathBuf>,
+}
+
+/// Arguments for `graph export-edges`
+#[derive(Args, Debug)]
+pub struct ExportEdgesArgs {
+    /// Edge file to write
+    #[arg(long)]
+    pub path: PathBuf,
+
+    /// Only export edges of this type (snake_case, e.g. code_related)
+    #[arg(long, value_parser = parse_edge_type)]
+    pub edge_type: Option<GraphLinkEdgeType>,
+
+    /// Only export edges leaving this memory
+    #[arg(long)]
+    pub source: Option<Uuid>,
+
+    /// Database path
+    #[arg(long, env = "CONTEXT_GRAPH_DATA_DIR")]
+    pub db_path: Option<PathBuf>,
+}
+
+fn parse_edge_type(s: &str) -> Result<GraphLinkEdgeType, String> {
+    serde_json::from_value(serde_json::Value::String(s.to_string()))
+        .map_err(|_| format!("unknown edge type '{}'", s))
+}
+
+/// Handle graph commands
+pub async fn handle_graph_command(action: GraphCommands) -> i32 {
+    match action {
+        GraphCommands::ImportEdges(args) => handle_import_edges(args),
+        GraphCommands::ExportEdges(args) => handle_export_edges(args),
+    }
+}
+
+fn open_repository(db_path: Option<PathBuf>) -> Option<(RocksDbTeleologicalStore, EdgeRepository)> {
+    let db_path = db_path.unwrap_or_else(|| PathBuf::from("./contextgraph_data"));
+    match RocksDbTeleologicalStore::open(&db_path) {
+        Ok(store) => {
+            let repo = EdgeRepository::new(store.db_arc());
+            Some((store, repo))
+        }
+        Err(e) => {
+            error!(error = %e, db_path = ?db_path, "Failed to open TeleologicalStore");
+            None
+        }
+    }
+}
+
+fn handle_import_edges(args: ImportEdgesArgs) -> i32 {
+    let Some((_store, repo)) = open_repository(args.db_path) else {
+        return 1;
+    };
+    let file = match File::open(&args.path) {
+        Ok(file) => file,
+        Err(e) => {
+            error!(error = %e, path = %args.path.display(), "Failed to open edge file");
+            return 1;
+        }
+    };
+
+    match repo.import_edges(BufReader::new(file), args.format) {
+        Ok(report) => {
+            for rejected in &report.rejected {
+                warn!(line = rejected.line, reason = %rejected.reason, "Rejected edge");
+            }
+            info!(
+                lines = report.lines_read,
+                accepted = report.accepted,
+                rejected = report.rejected_count(),
+                "Edge import complete"
+            );
+            if report.rejected_count() > 0 {
+                return 1;
+            }
+            0
+        }
+        Err(e) => {
+            error!(error = %e, path = %args.path.display(), "Edge import failed");
+            1
+        }
+    }
+}
+
+fn handle_export_edges(args: ExportEdgesArgs) -> i32 {
+    let Some((_store, repo)) = open_repository(args.db_path) else {
+        return 1;
+    };
+    let file = match File::create(&args.path) {
+        Ok(file) => file,
+        Err(e) => {
+            error!(error = %e, path = %args.path.display(), "Failed to create edge file");
+            return 1;
+        }
+    };
+
+    let filter = EdgeExportFilter {
+        edge_type: args.edge_type,
+        source: args.source,
+    };
+    match repo.export_edges(BufWriter::new(file), &filter) {
+        Ok(written) => {
+            info!(edges = written, path = %args.path.display(), "Edge export complete");
+            0
+        }
+        Err(e) => {
+            error!(error = %e, path = %args.path.display(), "Edge export failed");
+            1
+        }
+    }
+}
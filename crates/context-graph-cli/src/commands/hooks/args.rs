@@ -318,6 +318,24 @@ pub struct TaskCompletedArgs {
     pub format: OutputFormat,
 }
 
+// ============================================================================
+// Verify Arguments
+// ============================================================================
+
+/// Verify command arguments
+/// Runs every hook end-to-end with synthetic input and checks p95 wall time
+/// against its timeout budget and its output against the HookOutput schema
+#[derive(Args, Debug, Clone)]
+pub struct VerifyArgs {
+    /// Invocations per hook
+    #[arg(long, default_value = "20")]
+    pub runs: usize,
+
+    /// Print the report as JSON (for CI)
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub json: bool,
+}
+
 // ============================================================================
 // Hooks Commands Enum
 // ============================================================================
@@ -369,6 +387,12 @@ pub enum HooksCommands {
     #[command(name = "task-completed")]
     TaskCompleted(TaskCompletedArgs),
 
+    /// Dry-run every hook and check its latency budget
+    /// Exits 1 if any hook's p95 exceeds its timeout or its output is invalid
+    /// CLI: context-graph-cli hooks verify
+    #[command(name = "verify")]
+    Verify(VerifyArgs),
+
     /// Generate hook configuration files
     /// Creates shell scripts for .claude/hooks/
     #[command(name = "generate-config")]
@@ -6,13 +6,18 @@
 //! |------|---------|-------------|
 //! | 0 | Success | Hook executed successfully |
 //! | 1 | General Error | Unspecified error |
-//! | 4 | Invalid Input | Malformed input data |
+//! | 4 | Invalid Input | Malformed input data or payload schema mismatch |
+//!
+//! Schema errors (`ERR_INVALID_PAYLOAD`) and runtime errors (`ERR_GENERAL`,
+//! `ERR_IO`) are distinguished by exit code and error code.
 //!
 //! # NO BACKWARDS COMPATIBILITY
 //! This module FAILS FAST on any error. Do not add fallback logic.
 
 use thiserror::Error;
 
+use super::payload::PayloadSchemaError;
+
 /// Hook-specific error types
 #[derive(Debug, Error)]
 pub enum HookError {
@@ -21,6 +26,11 @@ pub enum HookError {
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 
+    /// Hook payload does not match its typed schema
+    /// Exit code: 4
+    #[error("Invalid payload: {0}")]
+    InvalidPayload(PayloadSchemaError),
+
     /// JSON serialization/deserialization error
     /// Exit code: 4
     #[error("Serialization error: {0}")]
@@ -42,7 +52,7 @@ impl HookError {
     #[inline]
     pub fn exit_code(&self) -> i32 {
         match self {
-            Self::InvalidInput(_) | Self::Serialization(_) => 4,
+            Self::InvalidInput(_) | Self::InvalidPayload(_) | Self::Serialization(_) => 4,
             Self::Io(_) | Self::General(_) => 1,
         }
     }
@@ -58,6 +68,7 @@ impl HookError {
     pub fn error_code(&self) -> &'static str {
         match self {
             Self::InvalidInput(_) => "ERR_INVALID_INPUT",
+            Self::InvalidPayload(_) => "ERR_INVALID_PAYLOAD",
             Self::Serialization(_) => "ERR_SERIALIZATION",
             Self::Io(_) => "ERR_IO",
             Self::General(_) => "ERR_GENERAL",
@@ -65,14 +76,22 @@ impl HookError {
     }
 
     /// Convert to structured JSON error for shell script consumption
+    ///
+    /// Payload schema errors also list the offending fields.
     pub fn to_json_error(&self) -> serde_json::Value {
-        serde_json::json!({
+        let mut json = serde_json::json!({
             "error": true,
             "code": self.error_code(),
             "exit_code": self.exit_code(),
             "message": self.to_string(),
             "recoverable": self.is_recoverable(),
-        })
+        });
+        if let Self::InvalidPayload(schema) = self {
+            json["payload_type"] = serde_json::json!(schema.payload_type);
+            json["missing_fields"] = serde_json::json!(schema.missing);
+            json["unknown_fields"] = serde_json::json!(schema.unknown);
+        }
+        json
     }
 
     /// Create invalid input error
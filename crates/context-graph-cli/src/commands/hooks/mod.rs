@@ -17,6 +17,7 @@
 mod args;
 mod error;
 pub mod memory_cache;
+mod payload;
 pub mod post_tool_use;
 pub mod pre_compact;
 pub mod pre_tool_use;
@@ -26,6 +27,7 @@ pub mod session_state;
 pub mod task_completed;
 mod types;
 pub mod user_prompt_submit;
+pub mod verify;
 
 pub use args::HooksCommands;
 
@@ -85,6 +87,7 @@ pub async fn handle_hooks_command(cmd: HooksCommands) -> i32 {
         HooksCommands::TaskCompleted(args) => {
             emit_hook_result(task_completed::execute(args).await)
         }
+        HooksCommands::Verify(args) => verify::execute(args),
         HooksCommands::GenerateConfig(_args) => {
             error!(
                 "GenerateConfig is not implemented. Hook scripts are generated \
@@ -0,0 +1,546 @@
+//! Typed hook payload schemas
+//!
+//! # Architecture
+//! Each hook event with a structured payload has its own struct here. The
+//! `HookPayload` enum in `types.rs` wraps them, so the wire format is still
+//! `{ "type": "post_tool_use", "data": { ... } }`.
+//!
+//! Every struct is `deny_unknown_fields`. `HookInput::from_json` checks the
+//! payload data against the struct's field lists before deserializing, so a
+//! malformed payload fails with a `PayloadSchemaError` naming ALL missing and
+//! unknown fields instead of serde's first-error message.
+//!
+//! # Constitution References
+//! - AP-26: Exit codes (schema errors exit 4, runtime errors exit 1)
+//!
+//! # NO BACKWARDS COMPATIBILITY
+//! Unknown fields FAIL - do not add fallback defaults for malformed payloads.
+
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use super::types::{ConversationMessage, HookPayload, SessionEndStatus};
+
+/// Approximate characters per token for prompt token estimates
+const CHARS_PER_TOKEN: usize = 4;
+
+// =============================================================================
+// Schema Error
+// =============================================================================
+
+/// Payload that does not match its typed schema
+///
+/// Carries every missing and unknown field so the caller can fix the
+/// payload in one pass. `detail` holds type errors (e.g. a string where a
+/// bool is expected) or a payload type mismatch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PayloadSchemaError {
+    /// Payload type the data was checked against (e.g. "post_tool_use")
+    pub payload_type: String,
+    /// Required fields absent from the payload data
+    pub missing: Vec<String>,
+    /// Fields present in the payload data but not in the schema
+    pub unknown: Vec<String>,
+    /// Type error or mismatch description
+    pub detail: Option<String>,
+}
+
+impl PayloadSchemaError {
+    fn new(payload_type: &str) -> Self {
+        Self {
+            payload_type: payload_type.to_string(),
+            missing: Vec::new(),
+            unknown: Vec::new(),
+            detail: None,
+        }
+    }
+
+    fn with_detail(payload_type: &str, detail: impl Into<String>) -> Self {
+        Self {
+            detail: Some(detail.into()),
+            ..Self::new(payload_type)
+        }
+    }
+}
+
+impl fmt::Display for PayloadSchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} payload does not match schema", self.payload_type)?;
+        if !self.missing.is_empty() {
+            write!(f, "; missing fields [{}]", self.missing.join(", "))?;
+        }
+        if !self.unknown.is_empty() {
+            write!(f, "; unknown fields [{}]", self.unknown.join(", "))?;
+        }
+        if let Some(detail) = &self.detail {
+            write!(f, "; {}", detail)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for PayloadSchemaError {}
+
+// =============================================================================
+// Typed Payload Trait
+// =============================================================================
+
+/// A payload struct with a fixed field schema
+///
+/// `FIELDS` and `REQUIRED` must list the serialized field names; the
+/// `tc_payload_schema_fields_match_structs` test keeps them in sync.
+pub trait TypedPayload: DeserializeOwned + Sized {
+    /// `type` tag of the payload in `HookPayload`
+    const PAYLOAD_TYPE: &'static str;
+    /// All accepted fields
+    const FIELDS: &'static [&'static str];
+    /// Fields without a serde default
+    const REQUIRED: &'static [&'static str];
+
+    /// Borrow this payload from the enum if the variant matches
+    fn from_payload(payload: &HookPayload) -> Option<&Self>;
+}
+
+/// Check payload `data` against the schema of `T`.
+///
+/// Reports all missing and unknown fields at once, then any type error.
+pub fn check_schema<T: TypedPayload>(data: &serde_json::Value) -> Result<(), PayloadSchemaError> {
+    let Some(object) = data.as_object() else {
+        return Err(PayloadSchemaError::with_detail(
+            T::PAYLOAD_TYPE,
+            "payload data must be a JSON object",
+        ));
+    };
+
+    let mut error = PayloadSchemaError::new(T::PAYLOAD_TYPE);
+    error.missing = T::REQUIRED
+        .iter()
+        .filter(|field| !object.contains_key(**field))
+        .map(|field| field.to_string())
+        .collect();
+    error.unknown = object
+        .keys()
+        .filter(|key| !T::FIELDS.contains(&key.as_str()))
+        .cloned()
+        .collect();
+    error.unknown.sort_unstable();
+    if !error.missing.is_empty() || !error.unknown.is_empty() {
+        return Err(error);
+    }
+
+    serde_json::from_value::<T>(data.clone())
+        .map(|_| ())
+        .map_err(|e| PayloadSchemaError::with_detail(T::PAYLOAD_TYPE, e.to_string()))
+}
+
+/// Check payload `data` against the schema registered for `payload_type`.
+///
+/// Payload types without a typed schema pass; `HookPayload` deserialization
+/// still rejects them if they are malformed.
+pub fn check_payload(
+    payload_type: &str,
+    data: &serde_json::Value,
+) -> Result<(), PayloadSchemaError> {
+    match payload_type {
+        SessionStartPayload::PAYLOAD_TYPE => check_schema::<SessionStartPayload>(data),
+        PostToolUsePayload::PAYLOAD_TYPE => check_schema::<PostToolUsePayload>(data),
+        UserPromptSubmitPayload::PAYLOAD_TYPE => check_schema::<UserPromptSubmitPayload>(data),
+        SessionEndPayload::PAYLOAD_TYPE => check_schema::<SessionEndPayload>(data),
+        _ => Ok(()),
+    }
+}
+
+/// Error for a payload of the wrong variant
+pub fn mismatch<T: TypedPayload>(payload: &HookPayload) -> PayloadSchemaError {
+    PayloadSchemaError::with_detail(
+        T::PAYLOAD_TYPE,
+        format!(
+            "expected {} payload, got {}",
+            T::PAYLOAD_TYPE,
+            payload.type_name()
+        ),
+    )
+}
+
+// =============================================================================
+// SessionStart
+// =============================================================================
+
+/// SessionStart hook payload
+/// Timeout: 5000ms per TECH-HOOKS.md
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SessionStartPayload {
+    /// Current working directory
+    pub cwd: String,
+    /// How session was initiated (e.g., "cli", "ide", "resume")
+    pub source: String,
+    /// Previous session ID for continuity (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_session_id: Option<String>,
+}
+
+impl TypedPayload for SessionStartPayload {
+    const PAYLOAD_TYPE: &'static str = "session_start";
+    const FIELDS: &'static [&'static str] = &["cwd", "source", "previous_session_id"];
+    const REQUIRED: &'static [&'static str] = &["cwd", "source"];
+
+    fn from_payload(payload: &HookPayload) -> Option<&Self> {
+        match payload {
+            HookPayload::SessionStart(p) => Some(p),
+            _ => None,
+        }
+    }
+}
+
+// =============================================================================
+// PostToolUse
+// =============================================================================
+
+/// PostToolUse hook payload
+/// Timeout: 3000ms per TECH-HOOKS.md
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PostToolUsePayload {
+    /// Name of tool that was invoked
+    pub tool_name: String,
+    /// Tool input parameters as JSON
+    pub tool_input: serde_json::Value,
+    /// Tool response/result
+    pub tool_response: String,
+    /// Unique identifier for this tool use
+    pub tool_use_id: String,
+    /// Whether the tool executed successfully (from Claude Code)
+    /// None = infer from the response with the success heuristic
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_success: Option<bool>,
+    /// Tool execution time in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+    /// Files the tool read or modified
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub affected_files: Vec<String>,
+}
+
+impl TypedPayload for PostToolUsePayload {
+    const PAYLOAD_TYPE: &'static str = "post_tool_use";
+    const FIELDS: &'static [&'static str] = &[
+        "tool_name",
+        "tool_input",
+        "tool_response",
+        "tool_use_id",
+        "tool_success",
+        "duration_ms",
+        "affected_files",
+    ];
+    const REQUIRED: &'static [&'static str] =
+        &["tool_name", "tool_input", "tool_response", "tool_use_id"];
+
+    fn from_payload(payload: &HookPayload) -> Option<&Self> {
+        match payload {
+            HookPayload::PostToolUse(p) => Some(p),
+            _ => None,
+        }
+    }
+}
+
+// =============================================================================
+// UserPromptSubmit
+// =============================================================================
+
+/// UserPromptSubmit hook payload
+/// Timeout: 2000ms per constitution.yaml
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UserPromptSubmitPayload {
+    /// User's input prompt text
+    pub prompt: String,
+    /// Conversation history for context
+    #[serde(default)]
+    pub context: Vec<ConversationMessage>,
+    /// Prompt size in tokens as counted by the caller
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_estimate: Option<u32>,
+}
+
+impl UserPromptSubmitPayload {
+    /// Prompt size in tokens: the caller's count, else ~4 chars per token
+    pub fn tokens(&self) -> u32 {
+        self.token_estimate
+            .unwrap_or_else(|| self.prompt.chars().count().div_ceil(CHARS_PER_TOKEN) as u32)
+    }
+}
+
+impl TypedPayload for UserPromptSubmitPayload {
+    const PAYLOAD_TYPE: &'static str = "user_prompt_submit";
+    const FIELDS: &'static [&'static str] = &["prompt", "context", "token_estimate"];
+    const REQUIRED: &'static [&'static str] = &["prompt"];
+
+    fn from_payload(payload: &HookPayload) -> Option<&Self> {
+        match payload {
+            HookPayload::UserPromptSubmit(p) => Some(p),
+            _ => None,
+        }
+    }
+}
+
+// =============================================================================
+// SessionEnd
+// =============================================================================
+
+/// Session activity counters reported at SessionEnd
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SessionStats {
+    /// Prompts submitted during the session
+    #[serde(default)]
+    pub prompts: u64,
+    /// Tool invocations during the session
+    #[serde(default)]
+    pub tool_uses: u64,
+    /// Tokens used during the session, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tokens: Option<u64>,
+}
+
+/// SessionEnd hook payload
+/// Timeout: 30000ms per constitution.yaml (final persist + consolidation)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SessionEndPayload {
+    /// Session duration in milliseconds
+    pub duration_ms: u64,
+    /// How session ended
+    pub status: SessionEndStatus,
+    /// Optional reason for termination
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Session activity counters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<SessionStats>,
+}
+
+impl TypedPayload for SessionEndPayload {
+    const PAYLOAD_TYPE: &'static str = "session_end";
+    const FIELDS: &'static [&'static str] = &["duration_ms", "status", "reason", "stats"];
+    const REQUIRED: &'static [&'static str] = &["duration_ms", "status"];
+
+    fn from_payload(payload: &HookPayload) -> Option<&Self> {
+        match payload {
+            HookPayload::SessionEnd(p) => Some(p),
+            _ => None,
+        }
+    }
+}
+
+// =============================================================================
+// TESTS - golden fixtures in tests/fixtures/hooks/
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::hooks::error::HookError;
+    use crate::commands::hooks::types::HookInput;
+
+    const SESSION_START_VALID: &str =
+        include_str!("../../../tests/fixtures/hooks/session_start_valid.json");
+    const SESSION_START_INVALID: &str =
+        include_str!("../../../tests/fixtures/hooks/session_start_invalid.json");
+    const POST_TOOL_USE_VALID: &str =
+        include_str!("../../../tests/fixtures/hooks/post_tool_use_valid.json");
+    const POST_TOOL_USE_INVALID: &str =
+        include_str!("../../../tests/fixtures/hooks/post_tool_use_invalid.json");
+    const USER_PROMPT_SUBMIT_VALID: &str =
+        include_str!("../../../tests/fixtures/hooks/user_prompt_submit_valid.json");
+    const USER_PROMPT_SUBMIT_INVALID: &str =
+        include_str!("../../../tests/fixtures/hooks/user_prompt_submit_invalid.json");
+    const SESSION_END_VALID: &str =
+        include_str!("../../../tests/fixtures/hooks/session_end_valid.json");
+    const SESSION_END_INVALID: &str =
+        include_str!("../../../tests/fixtures/hooks/session_end_invalid.json");
+
+    fn schema_error(fixture: &str) -> (HookError, PayloadSchemaError) {
+        match HookInput::from_json(fixture) {
+            Err(HookError::InvalidPayload(schema)) => {
+                (HookError::InvalidPayload(schema.clone()), schema)
+            }
+            other => panic!("Expected InvalidPayload, got {:?}", other),
+        }
+    }
+
+    fn assert_fields_match<T: TypedPayload + Serialize>(full: T) {
+        let json = serde_json::to_value(&full).unwrap();
+        let mut keys: Vec<&str> = json
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(|k| k.as_str())
+            .collect();
+        let mut fields = T::FIELDS.to_vec();
+        keys.sort_unstable();
+        fields.sort_unstable();
+        assert_eq!(keys, fields, "{} FIELDS out of sync", T::PAYLOAD_TYPE);
+        assert!(T::REQUIRED.iter().all(|f| T::FIELDS.contains(f)));
+    }
+
+    // =========================================================================
+    // TC-PAYLOAD-SCHEMA-001: FIELDS lists match the structs
+    // =========================================================================
+    #[test]
+    fn tc_payload_schema_fields_match_structs() {
+        assert_fields_match(SessionStartPayload {
+            cwd: "/".into(),
+            source: "cli".into(),
+            previous_session_id: Some("prev".into()),
+        });
+        assert_fields_match(PostToolUsePayload {
+            tool_name: "Edit".into(),
+            tool_input: serde_json::json!({}),
+            tool_response: String::new(),
+            tool_use_id: "t".into(),
+            tool_success: Some(true),
+            duration_ms: Some(1),
+            affected_files: vec!["a.rs".into()],
+        });
+        assert_fields_match(UserPromptSubmitPayload {
+            prompt: "p".into(),
+            context: vec![],
+            token_estimate: Some(1),
+        });
+        assert_fields_match(SessionEndPayload {
+            duration_ms: 1,
+            status: SessionEndStatus::Normal,
+            reason: Some("r".into()),
+            stats: Some(SessionStats::default()),
+        });
+    }
+
+    // =========================================================================
+    // TC-PAYLOAD-SCHEMA-002: Valid golden fixtures parse into typed payloads
+    // =========================================================================
+    #[test]
+    fn tc_payload_schema_valid_fixtures() {
+        let input = HookInput::from_json(SESSION_START_VALID).expect("session_start fixture");
+        let start: &SessionStartPayload = input.typed_payload().unwrap();
+        assert_eq!(start.source, "resume");
+        assert_eq!(
+            start.previous_session_id.as_deref(),
+            Some("fixture-previous-session")
+        );
+
+        let input = HookInput::from_json(POST_TOOL_USE_VALID).expect("post_tool_use fixture");
+        let post: &PostToolUsePayload = input.typed_payload().unwrap();
+        assert_eq!(post.tool_name, "Edit");
+        assert_eq!(post.tool_success, Some(true));
+        assert_eq!(post.duration_ms, Some(42));
+        assert_eq!(post.affected_files, vec!["src/lib.rs".to_string()]);
+
+        let input = HookInput::from_json(USER_PROMPT_SUBMIT_VALID).expect("prompt fixture");
+        let prompt: &UserPromptSubmitPayload = input.typed_payload().unwrap();
+        assert_eq!(prompt.context.len(), 1);
+        assert_eq!(prompt.tokens(), 9, "Caller's token estimate wins");
+
+        let input = HookInput::from_json(SESSION_END_VALID).expect("session_end fixture");
+        let end: &SessionEndPayload = input.typed_payload().unwrap();
+        assert_eq!(end.status, SessionEndStatus::Clear);
+        assert_eq!(
+            end.stats,
+            Some(SessionStats {
+                prompts: 12,
+                tool_uses: 48,
+                tokens: Some(91000),
+            })
+        );
+    }
+
+    // =========================================================================
+    // TC-PAYLOAD-SCHEMA-003: Invalid golden fixtures name every bad field
+    // =========================================================================
+    #[test]
+    fn tc_payload_schema_invalid_fixtures() {
+        let cases = [
+            (
+                SESSION_START_INVALID,
+                "session_start",
+                vec!["cwd"],
+                vec!["branch"],
+            ),
+            (
+                POST_TOOL_USE_INVALID,
+                "post_tool_use",
+                vec!["tool_use_id"],
+                vec!["exit_status", "success"],
+            ),
+            (
+                USER_PROMPT_SUBMIT_INVALID,
+                "user_prompt_submit",
+                vec!["prompt"],
+                vec!["text"],
+            ),
+        ];
+        for (fixture, payload_type, missing, unknown) in cases {
+            let (err, schema) = schema_error(fixture);
+            assert_eq!(schema.payload_type, payload_type);
+            assert_eq!(schema.missing, missing, "{}", payload_type);
+            assert_eq!(schema.unknown, unknown, "{}", payload_type);
+
+            let message = err.to_string();
+            for field in missing.iter().chain(&unknown) {
+                assert!(
+                    message.contains(field),
+                    "'{}' not named in: {}",
+                    field,
+                    message
+                );
+            }
+            let json = err.to_json_error();
+            assert_eq!(json["code"], "ERR_INVALID_PAYLOAD");
+            assert_eq!(json["exit_code"], 4);
+            assert_eq!(json["missing_fields"], serde_json::json!(missing));
+            assert_eq!(json["unknown_fields"], serde_json::json!(unknown));
+        }
+
+        // Known fields with a wrong type: reported as detail, not defaulted
+        let (err, schema) = schema_error(SESSION_END_INVALID);
+        assert!(schema.missing.is_empty() && schema.unknown.is_empty());
+        let detail = schema.detail.expect("type error detail");
+        assert!(detail.contains("invalid type"), "detail: {}", detail);
+        assert_eq!(err.exit_code(), 4);
+    }
+
+    // =========================================================================
+    // TC-PAYLOAD-SCHEMA-004: Wrong payload type for the command
+    // =========================================================================
+    #[test]
+    fn tc_payload_schema_type_mismatch() {
+        let input = HookInput::from_json(SESSION_START_VALID).unwrap();
+        let err = input.typed_payload::<PostToolUsePayload>().unwrap_err();
+        assert_eq!(err.error_code(), "ERR_INVALID_PAYLOAD");
+        assert!(
+            err.to_string()
+                .contains("expected post_tool_use payload, got session_start"),
+            "{}",
+            err
+        );
+    }
+
+    // =========================================================================
+    // TC-PAYLOAD-SCHEMA-005: Schema errors are distinct from runtime errors
+    // =========================================================================
+    #[test]
+    fn tc_payload_schema_errors_distinct_from_runtime() {
+        let (schema_err, _) = schema_error(POST_TOOL_USE_INVALID);
+        let runtime_err = HookError::general("MCP server unavailable");
+
+        assert_eq!(schema_err.exit_code(), 4);
+        assert_eq!(runtime_err.exit_code(), 1);
+        assert_ne!(schema_err.error_code(), runtime_err.error_code());
+        assert!(schema_err
+            .to_string()
+            .starts_with("Invalid payload: post_tool_use payload"));
+        assert!(runtime_err.to_json_error().get("missing_fields").is_none());
+    }
+}
@@ -20,9 +20,8 @@ use crate::mcp_client::McpClient;
 
 use super::args::PostToolArgs;
 use super::error::{HookError, HookResult};
-use super::types::{
-    CoherenceState, HookInput, HookOutput, HookPayload, StabilityClassification,
-};
+use super::payload::PostToolUsePayload;
+use super::types::{CoherenceState, HookInput, HookOutput, StabilityClassification};
 
 // ============================================================================
 // Constants (from constitution.yaml)
@@ -151,7 +150,8 @@ pub async fn execute(args: PostToolArgs) -> HookResult<HookOutput> {
 // ============================================================================
 
 /// Parse stdin JSON into HookInput.
-/// FAIL FAST on empty or malformed input.
+/// FAIL FAST on empty or malformed input, or a payload that does not match
+/// its schema (no defaults for missing or mistyped fields).
 fn parse_stdin() -> HookResult<HookInput> {
     let stdin = io::stdin();
     let mut input_str = String::new();
@@ -174,9 +174,9 @@ fn parse_stdin() -> HookResult<HookInput> {
         "POST_TOOL: parsing stdin JSON"
     );
 
-    serde_json::from_str(&input_str).map_err(|e| {
-        error!(error = %e, "POST_TOOL: JSON parse failed");
-        HookError::invalid_input(format!("JSON parse failed: {}", e))
+    HookInput::from_json(&input_str).map_err(|e| {
+        error!(error = %e, "POST_TOOL: invalid hook input");
+        e
     })
 }
 
@@ -187,24 +187,23 @@ fn extract_tool_info(input: &HookInput) -> HookResult<(String, String, bool)> {
         return Err(HookError::invalid_input(error));
     }
 
-    match &input.payload {
-        HookPayload::PostToolUse {
-            tool_name,
-            tool_response,
-            tool_success,
-            ..
-        } => {
-            // Use explicit tool_success if provided, otherwise use smart heuristic
-            let success = tool_success.unwrap_or_else(|| infer_tool_success(tool_response));
-            Ok((tool_name.clone(), tool_response.clone(), success))
-        }
-        other => {
-            error!(payload_type = ?std::mem::discriminant(other), "POST_TOOL: unexpected payload type");
-            Err(HookError::invalid_input(
-                "Expected PostToolUse payload, got different type",
-            ))
-        }
-    }
+    let payload: &PostToolUsePayload = input.typed_payload().map_err(|e| {
+        error!(error = %e, "POST_TOOL: unexpected payload type");
+        e
+    })?;
+
+    debug!(
+        tool_name = %payload.tool_name,
+        duration_ms = ?payload.duration_ms,
+        affected_files = payload.affected_files.len(),
+        "POST_TOOL: typed payload"
+    );
+
+    // Use explicit tool_success if provided, otherwise use smart heuristic
+    let success = payload
+        .tool_success
+        .unwrap_or_else(|| infer_tool_success(&payload.tool_response));
+    Ok((payload.tool_name.clone(), payload.tool_response.clone(), success))
 }
 
 /// Infer tool success from response content using smart heuristics.
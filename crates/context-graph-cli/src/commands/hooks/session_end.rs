@@ -25,8 +25,9 @@ use super::session_state::{store_in_cache, SessionCache, SessionSnapshot};
 
 use super::args::SessionEndArgs;
 use super::error::{HookError, HookResult};
+use super::payload::SessionEndPayload;
 use super::types::{
-    CoherenceState, HookInput, HookOutput, HookPayload, StabilityClassification, SessionEndStatus,
+    CoherenceState, HookInput, HookOutput, StabilityClassification, SessionEndStatus,
 };
 
 /// Execute session-end hook.
@@ -124,10 +125,10 @@ fn parse_stdin(default_session_id: &str) -> HookResult<(String, Option<u64>, Ses
         "SESSION_END: parsing stdin JSON"
     );
 
-    // Try to parse as HookInput
-    let input: HookInput = serde_json::from_str(&input_str).map_err(|e| {
-        error!(error = %e, input_preview = %&input_str[..input_str.len().min(100)], "SESSION_END: JSON parse failed");
-        HookError::invalid_input(format!("JSON parse failed: {}", e))
+    // Parse as HookInput - a malformed payload fails instead of using defaults
+    let input = HookInput::from_json(&input_str).map_err(|e| {
+        error!(error = %e, input_preview = %&input_str[..input_str.len().min(100)], "SESSION_END: invalid hook input");
+        e
     })?;
 
     // Validate input
@@ -136,21 +137,22 @@ fn parse_stdin(default_session_id: &str) -> HookResult<(String, Option<u64>, Ses
     }
 
     // Extract session end payload
-    let (duration_ms, status) = match input.payload {
-        HookPayload::SessionEnd {
-            duration_ms,
-            status,
-            reason: _,
-        } => (Some(duration_ms), status),
-        other => {
-            error!(payload_type = ?std::mem::discriminant(&other), "SESSION_END: unexpected payload type");
-            return Err(HookError::invalid_input(
-                "Expected SessionEnd payload, got different type",
-            ));
-        }
-    };
+    let payload: &SessionEndPayload = input.typed_payload().map_err(|e| {
+        error!(error = %e, "SESSION_END: unexpected payload type");
+        e
+    })?;
+
+    if let Some(stats) = &payload.stats {
+        info!(
+            reason = ?payload.reason,
+            prompts = stats.prompts,
+            tool_uses = stats.tool_uses,
+            tokens = ?stats.tokens,
+            "SESSION_END: session stats"
+        );
+    }
 
-    Ok((input.session_id, duration_ms, status))
+    Ok((input.session_id.clone(), Some(payload.duration_ms), payload.status))
 }
 
 /// Persist session state to SessionCache.
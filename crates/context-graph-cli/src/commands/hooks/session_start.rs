@@ -23,9 +23,8 @@ use super::session_state::{store_in_cache, SessionCache, SessionSnapshot, NUM_EM
 
 use super::args::SessionStartArgs;
 use super::error::{HookError, HookResult};
-use super::types::{
-    CoherenceState, DriftMetrics, HookInput, HookOutput, HookPayload, StabilityClassification,
-};
+use super::payload::SessionStartPayload;
+use super::types::{CoherenceState, DriftMetrics, HookInput, HookOutput, StabilityClassification};
 
 /// Compute coherence from snapshot's integration, reflection, and differentiation metrics.
 #[inline]
@@ -133,7 +132,8 @@ pub async fn execute(args: SessionStartArgs) -> HookResult<HookOutput> {
 }
 
 /// Parse stdin JSON into HookInput.
-/// FAIL FAST on empty or malformed input.
+/// FAIL FAST on empty or malformed input, or a payload that does not match
+/// its schema.
 fn parse_stdin() -> HookResult<HookInput> {
     let stdin = io::stdin();
     let mut input_str = String::new();
@@ -156,9 +156,9 @@ fn parse_stdin() -> HookResult<HookInput> {
         "SESSION_START: parsing stdin JSON"
     );
 
-    serde_json::from_str(&input_str).map_err(|e| {
-        error!(error = %e, input_preview = %&input_str[..input_str.len().min(100)], "SESSION_START: JSON parse failed");
-        HookError::invalid_input(format!("JSON parse failed: {}", e))
+    HookInput::from_json(&input_str).map_err(|e| {
+        error!(error = %e, input_preview = %&input_str[..input_str.len().min(100)], "SESSION_START: invalid hook input");
+        e
     })
 }
 
@@ -171,18 +171,11 @@ fn extract_session_ids(input: &HookInput) -> HookResult<(Option<String>, Option<
 
     let session_id = Some(input.session_id.clone());
 
-    let previous_session_id = match &input.payload {
-        HookPayload::SessionStart {
-            previous_session_id,
-            ..
-        } => previous_session_id.clone(),
-        other => {
-            error!(payload_type = ?std::mem::discriminant(other), "SESSION_START: unexpected payload type");
-            return Err(HookError::invalid_input(
-                "Expected SessionStart payload, got different type",
-            ));
-        }
-    };
+    let payload: &SessionStartPayload = input.typed_payload().map_err(|e| {
+        error!(error = %e, "SESSION_START: unexpected payload type");
+        e
+    })?;
+    let previous_session_id = payload.previous_session_id.clone();
 
     Ok((session_id, previous_session_id))
 }
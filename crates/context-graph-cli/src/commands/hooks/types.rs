@@ -14,6 +14,12 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::error::{HookError, HookResult};
+use super::payload::{
+    check_payload, mismatch, PostToolUsePayload, SessionEndPayload, SessionStartPayload,
+    TypedPayload, UserPromptSubmitPayload,
+};
+
 /// Hook event types matching Claude Code native hooks
 /// Implements REQ-HOOKS-01 through REQ-HOOKS-05
 ///
@@ -79,11 +85,8 @@ impl HookEventType {
             Self::SessionEnd => "Session persistence and consolidation",
         }
     }
-}
 
-#[cfg(test)]
-impl HookEventType {
-    /// Get timeout in milliseconds for this hook type (test helper)
+    /// Get timeout in milliseconds for this hook type
     pub const fn timeout_ms(&self) -> u64 {
         match self {
             Self::PreToolUse => 500,
@@ -94,12 +97,7 @@ impl HookEventType {
         }
     }
 
-    /// Check if this hook type is time-critical (test helper)
-    pub const fn is_fast_path(&self) -> bool {
-        self.timeout_ms() <= 500
-    }
-
-    /// Get the corresponding CLI command for this hook type (test helper)
+    /// Get the corresponding CLI command for this hook type
     pub const fn cli_command(&self) -> &'static str {
         match self {
             Self::SessionStart => "hooks session-start",
@@ -110,7 +108,7 @@ impl HookEventType {
         }
     }
 
-    /// Get all hook event types (test helper)
+    /// Get all hook event types
     pub const fn all() -> [Self; 5] {
         [
             Self::SessionStart,
@@ -122,6 +120,14 @@ impl HookEventType {
     }
 }
 
+#[cfg(test)]
+impl HookEventType {
+    /// Check if this hook type is time-critical (test helper)
+    pub const fn is_fast_path(&self) -> bool {
+        self.timeout_ms() <= 500
+    }
+}
+
 impl std::fmt::Display for HookEventType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.description())
@@ -749,6 +755,9 @@ pub struct ConversationMessage {
 /// - `UserPromptSubmit`: User input with context (2000ms timeout)
 /// - `SessionEnd`: Session termination data (30000ms timeout)
 ///
+/// SessionStart, PostToolUse, UserPromptSubmit and SessionEnd wrap the
+/// `deny_unknown_fields` structs in `payload.rs`.
+///
 /// # JSON Format
 /// Uses internally tagged enum for Claude Code compatibility:
 /// ```json
@@ -762,15 +771,7 @@ pub struct ConversationMessage {
 pub enum HookPayload {
     /// SessionStart hook payload
     /// Timeout: 5000ms per TECH-HOOKS.md
-    SessionStart {
-        /// Current working directory
-        cwd: String,
-        /// How session was initiated (e.g., "cli", "ide")
-        source: String,
-        /// Previous session ID for continuity (optional)
-        #[serde(skip_serializing_if = "Option::is_none")]
-        previous_session_id: Option<String>,
-    },
+    SessionStart(SessionStartPayload),
 
     /// PreToolUse hook payload (fast path)
     /// Timeout: 500ms total per constitution.yaml - CLI logic ~100ms
@@ -785,42 +786,15 @@ pub enum HookPayload {
 
     /// PostToolUse hook payload
     /// Timeout: 3000ms per TECH-HOOKS.md
-    PostToolUse {
-        /// Name of tool that was invoked
-        tool_name: String,
-        /// Tool input parameters as JSON
-        tool_input: serde_json::Value,
-        /// Tool response/result
-        tool_response: String,
-        /// Unique identifier for this tool use
-        tool_use_id: String,
-        /// Whether the tool executed successfully (from Claude Code)
-        /// Defaults to None if not provided - will use smart heuristic
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        tool_success: Option<bool>,
-    },
+    PostToolUse(PostToolUsePayload),
 
     /// UserPromptSubmit hook payload
     /// Timeout: 2000ms per constitution.yaml
-    UserPromptSubmit {
-        /// User's input prompt text
-        prompt: String,
-        /// Conversation history for context
-        #[serde(default)]
-        context: Vec<ConversationMessage>,
-    },
+    UserPromptSubmit(UserPromptSubmitPayload),
 
     /// SessionEnd hook payload
     /// Timeout: 30000ms per constitution.yaml (final persist + consolidation)
-    SessionEnd {
-        /// Session duration in milliseconds
-        duration_ms: u64,
-        /// How session ended
-        status: SessionEndStatus,
-        /// Optional reason for termination
-        #[serde(skip_serializing_if = "Option::is_none")]
-        reason: Option<String>,
-    },
+    SessionEnd(SessionEndPayload),
 
     /// R5: PreCompact hook payload
     /// Timeout: 20000ms per settings.json
@@ -847,6 +821,21 @@ pub enum HookPayload {
     },
 }
 
+impl HookPayload {
+    /// `type` tag of this payload (e.g. "post_tool_use")
+    pub const fn type_name(&self) -> &'static str {
+        match self {
+            Self::SessionStart(_) => "session_start",
+            Self::PreToolUse { .. } => "pre_tool_use",
+            Self::PostToolUse(_) => "post_tool_use",
+            Self::UserPromptSubmit(_) => "user_prompt_submit",
+            Self::SessionEnd(_) => "session_end",
+            Self::PreCompact { .. } => "pre_compact",
+            Self::TaskCompleted { .. } => "task_completed",
+        }
+    }
+}
+
 // =============================================================================
 // Hook Input (stdin contract)
 // Technical Reference: TECH-HOOKS.md Section 2.2
@@ -884,6 +873,36 @@ pub struct HookInput {
 }
 
 impl HookInput {
+    /// Parse hook input JSON, checking typed payloads against their schema
+    ///
+    /// # Errors
+    /// - `HookError::InvalidPayload`: payload data has missing or unknown
+    ///   fields, or a field of the wrong type (exit code 4)
+    /// - `HookError::InvalidInput`: malformed JSON or envelope (exit code 4)
+    pub fn from_json(input: &str) -> HookResult<Self> {
+        let value: serde_json::Value = serde_json::from_str(input)
+            .map_err(|e| HookError::invalid_input(format!("JSON parse failed: {}", e)))?;
+
+        if let Some(payload_type) = value.pointer("/payload/type").and_then(|t| t.as_str()) {
+            let data = value
+                .pointer("/payload/data")
+                .unwrap_or(&serde_json::Value::Null);
+            check_payload(payload_type, data).map_err(HookError::InvalidPayload)?;
+        }
+
+        serde_json::from_value(value)
+            .map_err(|e| HookError::invalid_input(format!("JSON parse failed: {}", e)))
+    }
+
+    /// Borrow the payload as `T`, failing if the payload is another type
+    ///
+    /// # Errors
+    /// `HookError::InvalidPayload` naming the expected and actual types.
+    pub fn typed_payload<T: TypedPayload>(&self) -> HookResult<&T> {
+        T::from_payload(&self.payload)
+            .ok_or_else(|| HookError::InvalidPayload(mismatch::<T>(&self.payload)))
+    }
+
     /// Validate that input is well-formed
     /// Returns error message if invalid, None if valid
     pub fn validate(&self) -> Option<String> {
@@ -1624,11 +1643,11 @@ mod hook_io_tests {
     fn tc_hooks_payload_004_session_start() {
         println!("\n=== TC-HOOKS-PAYLOAD-004: HookPayload SessionStart ===");
 
-        let payload = HookPayload::SessionStart {
+        let payload = HookPayload::SessionStart(SessionStartPayload {
             cwd: "/home/user/project".into(),
             source: "cli".into(),
             previous_session_id: None,
-        };
+        });
 
         let json = serde_json::to_value(&payload).expect("serialize");
         println!("  JSON: {}", serde_json::to_string_pretty(&json).unwrap());
@@ -1642,22 +1661,22 @@ mod hook_io_tests {
         );
 
         // With previous session
-        let payload_with_prev = HookPayload::SessionStart {
+        let payload_with_prev = HookPayload::SessionStart(SessionStartPayload {
             cwd: "/home/user/project".into(),
             source: "ide".into(),
             previous_session_id: Some("prev-session-123".into()),
-        };
+        });
 
         let json2 = serde_json::to_value(&payload_with_prev).expect("serialize");
         assert_eq!(json2["data"]["previous_session_id"], "prev-session-123");
 
         // Round-trip
         let roundtrip: HookPayload = serde_json::from_value(json).expect("deserialize");
-        if let HookPayload::SessionStart {
+        if let HookPayload::SessionStart(SessionStartPayload {
             cwd,
             source,
             previous_session_id,
-        } = roundtrip
+        }) = roundtrip
         {
             assert_eq!(cwd, "/home/user/project");
             assert_eq!(source, "cli");
@@ -1725,7 +1744,7 @@ mod hook_io_tests {
         println!("\n=== TC-HOOKS-PAYLOAD-006: HookPayload PostToolUse ===");
 
         // Test with explicit tool_success
-        let payload = HookPayload::PostToolUse {
+        let payload = HookPayload::PostToolUse(PostToolUsePayload {
             tool_name: "Bash".into(),
             tool_input: serde_json::json!({
                 "command": "cargo build"
@@ -1733,7 +1752,9 @@ mod hook_io_tests {
             tool_response: "Compiling context-graph v0.1.0\nFinished release".into(),
             tool_use_id: "toolu_02DEF456".into(),
             tool_success: Some(true),
-        };
+            duration_ms: None,
+            affected_files: vec![],
+        });
 
         let json = serde_json::to_value(&payload).expect("serialize");
         println!("  JSON: {}", serde_json::to_string_pretty(&json).unwrap());
@@ -1749,12 +1770,12 @@ mod hook_io_tests {
 
         // Round-trip
         let roundtrip: HookPayload = serde_json::from_value(json).expect("deserialize");
-        if let HookPayload::PostToolUse {
+        if let HookPayload::PostToolUse(PostToolUsePayload {
             tool_name,
             tool_response,
             tool_success,
             ..
-        } = roundtrip
+        }) = roundtrip
         {
             assert_eq!(tool_name, "Bash");
             assert!(tool_response.contains("Compiling"));
@@ -1776,7 +1797,7 @@ mod hook_io_tests {
 
         let deserialized: HookPayload =
             serde_json::from_value(json_without_success).expect("deserialize without tool_success");
-        if let HookPayload::PostToolUse { tool_success, .. } = deserialized {
+        if let HookPayload::PostToolUse(PostToolUsePayload { tool_success, .. }) = deserialized {
             assert_eq!(tool_success, None, "tool_success should default to None");
         } else {
             panic!("Wrong variant");
@@ -1794,7 +1815,7 @@ mod hook_io_tests {
     fn tc_hooks_payload_007_user_prompt_submit() {
         println!("\n=== TC-HOOKS-PAYLOAD-007: HookPayload UserPromptSubmit ===");
 
-        let payload = HookPayload::UserPromptSubmit {
+        let payload = HookPayload::UserPromptSubmit(UserPromptSubmitPayload {
             prompt: "Help me fix this bug".into(),
             context: vec![
                 ConversationMessage {
@@ -1806,7 +1827,8 @@ mod hook_io_tests {
                     content: "I see the issue. Let me check...".into(),
                 },
             ],
-        };
+            token_estimate: None,
+        });
 
         let json = serde_json::to_value(&payload).expect("serialize");
         println!("  JSON: {}", serde_json::to_string_pretty(&json).unwrap());
@@ -1818,16 +1840,20 @@ mod hook_io_tests {
         assert_eq!(json["data"]["context"][1]["role"], "assistant");
 
         // Empty context (default)
-        let payload_empty = HookPayload::UserPromptSubmit {
+        let payload_empty = HookPayload::UserPromptSubmit(UserPromptSubmitPayload {
             prompt: "Hello".into(),
             context: vec![],
-        };
+            token_estimate: None,
+        });
         let json_empty = serde_json::to_value(&payload_empty).expect("serialize");
         assert!(json_empty["data"]["context"].as_array().unwrap().is_empty());
 
         // Round-trip
         let roundtrip: HookPayload = serde_json::from_value(json).expect("deserialize");
-        if let HookPayload::UserPromptSubmit { prompt, context } = roundtrip {
+        if let HookPayload::UserPromptSubmit(UserPromptSubmitPayload {
+            prompt, context, ..
+        }) = roundtrip
+        {
             assert_eq!(prompt, "Help me fix this bug");
             assert_eq!(context.len(), 2);
         } else {
@@ -1846,11 +1872,12 @@ mod hook_io_tests {
     fn tc_hooks_payload_008_session_end() {
         println!("\n=== TC-HOOKS-PAYLOAD-008: HookPayload SessionEnd ===");
 
-        let payload = HookPayload::SessionEnd {
+        let payload = HookPayload::SessionEnd(SessionEndPayload {
             duration_ms: 3600000,
             status: SessionEndStatus::Normal,
             reason: None,
-        };
+            stats: None,
+        });
 
         let json = serde_json::to_value(&payload).expect("serialize");
         println!("  JSON: {}", serde_json::to_string_pretty(&json).unwrap());
@@ -1864,22 +1891,24 @@ mod hook_io_tests {
         );
 
         // With reason
-        let payload_with_reason = HookPayload::SessionEnd {
+        let payload_with_reason = HookPayload::SessionEnd(SessionEndPayload {
             duration_ms: 120000,
             status: SessionEndStatus::Error,
             reason: Some("Connection lost".into()),
-        };
+            stats: None,
+        });
         let json2 = serde_json::to_value(&payload_with_reason).expect("serialize");
         assert_eq!(json2["data"]["status"], "error");
         assert_eq!(json2["data"]["reason"], "Connection lost");
 
         // Round-trip
         let roundtrip: HookPayload = serde_json::from_value(json).expect("deserialize");
-        if let HookPayload::SessionEnd {
+        if let HookPayload::SessionEnd(SessionEndPayload {
             duration_ms,
             status,
             reason,
-        } = roundtrip
+            ..
+        }) = roundtrip
         {
             assert_eq!(duration_ms, 3600000);
             assert_eq!(status, SessionEndStatus::Normal);
@@ -1934,11 +1963,11 @@ mod hook_io_tests {
             hook_type: HookEventType::SessionStart,
             session_id: "session-abc123".into(),
             timestamp_ms: 1705312345678,
-            payload: HookPayload::SessionStart {
+            payload: HookPayload::SessionStart(SessionStartPayload {
                 cwd: "/home/user/project".into(),
                 source: "cli".into(),
                 previous_session_id: None,
-            },
+            }),
         };
 
         let json = serde_json::to_value(&input).expect("serialize");
@@ -1970,11 +1999,11 @@ mod hook_io_tests {
         println!("\n=== TC-HOOKS-PAYLOAD-011: Edge Case - Empty Strings ===");
 
         // Empty cwd is technically valid (serialization perspective)
-        let payload = HookPayload::SessionStart {
+        let payload = HookPayload::SessionStart(SessionStartPayload {
             cwd: "".into(),
             source: "".into(),
             previous_session_id: Some("".into()),
-        };
+        });
 
         let json = serde_json::to_value(&payload).expect("serialize empty strings");
         assert_eq!(json["data"]["cwd"], "");
@@ -1983,11 +2012,11 @@ mod hook_io_tests {
 
         // Round-trip preserves empty strings
         let roundtrip: HookPayload = serde_json::from_value(json).expect("deserialize");
-        if let HookPayload::SessionStart {
+        if let HookPayload::SessionStart(SessionStartPayload {
             cwd,
             source,
             previous_session_id,
-        } = roundtrip
+        }) = roundtrip
         {
             assert_eq!(cwd, "");
             assert_eq!(source, "");
@@ -2008,28 +2037,30 @@ mod hook_io_tests {
         println!("\n=== TC-HOOKS-PAYLOAD-012: Edge Case - Large Values ===");
 
         // Large duration_ms (max u64)
-        let payload = HookPayload::SessionEnd {
+        let payload = HookPayload::SessionEnd(SessionEndPayload {
             duration_ms: u64::MAX,
             status: SessionEndStatus::Normal,
             reason: None,
-        };
+            stats: None,
+        });
 
         let json = serde_json::to_value(&payload).expect("serialize large duration");
         assert_eq!(json["data"]["duration_ms"], u64::MAX);
 
         // Large prompt
         let large_prompt = "x".repeat(100_000);
-        let payload2 = HookPayload::UserPromptSubmit {
+        let payload2 = HookPayload::UserPromptSubmit(UserPromptSubmitPayload {
             prompt: large_prompt.clone(),
             context: vec![],
-        };
+            token_estimate: None,
+        });
 
         let json2 = serde_json::to_value(&payload2).expect("serialize large prompt");
         assert_eq!(json2["data"]["prompt"].as_str().unwrap().len(), 100_000);
 
         // Round-trip
         let roundtrip: HookPayload = serde_json::from_value(json2).expect("deserialize");
-        if let HookPayload::UserPromptSubmit { prompt, .. } = roundtrip {
+        if let HookPayload::UserPromptSubmit(UserPromptSubmitPayload { prompt, .. }) = roundtrip {
             assert_eq!(prompt.len(), 100_000);
         } else {
             panic!("Wrong variant");
@@ -2046,13 +2077,14 @@ mod hook_io_tests {
     fn tc_hooks_payload_013_edge_case_unicode() {
         println!("\n=== TC-HOOKS-PAYLOAD-013: Edge Case - Unicode Content ===");
 
-        let payload = HookPayload::UserPromptSubmit {
+        let payload = HookPayload::UserPromptSubmit(UserPromptSubmitPayload {
             prompt: "Hello \u{1F600} World \u{4E2D}\u{6587} \u{0391}\u{03B2}\u{03B3}".into(),
             context: vec![ConversationMessage {
                 role: "user".into(),
                 content: "\u{1F389} Party! \u{1F3C6}".into(),
             }],
-        };
+            token_estimate: None,
+        });
 
         let json = serde_json::to_value(&payload).expect("serialize unicode");
         let json_str = serde_json::to_string(&json).expect("to string");
@@ -2060,7 +2092,10 @@ mod hook_io_tests {
 
         // Round-trip
         let roundtrip: HookPayload = serde_json::from_value(json).expect("deserialize");
-        if let HookPayload::UserPromptSubmit { prompt, context } = roundtrip {
+        if let HookPayload::UserPromptSubmit(UserPromptSubmitPayload {
+            prompt, context, ..
+        }) = roundtrip
+        {
             assert!(prompt.contains("\u{1F600}"));
             assert!(prompt.contains("\u{4E2D}\u{6587}"));
             assert_eq!(context[0].content, "\u{1F389} Party! \u{1F3C6}");
@@ -21,8 +21,9 @@ use super::session_state::{store_in_cache, SessionCache, SessionSnapshot};
 use super::args::PromptSubmitArgs;
 use super::error::{HookError, HookResult};
 use super::memory_cache::{cache_memories, CachedMemory};
+use super::payload::UserPromptSubmitPayload;
 use super::types::{
-    CoherenceState, ConversationMessage, HookInput, HookOutput, HookPayload, StabilityClassification,
+    CoherenceState, ConversationMessage, HookInput, HookOutput, StabilityClassification,
 };
 use crate::mcp_client::McpClient;
 
@@ -286,10 +287,7 @@ pub async fn execute(args: PromptSubmitArgs) -> HookResult<HookOutput> {
     // 9. R10: Causal intent detection — E5 search for "why"/"because" prompts
     let causal_memories = if mcp_available && has_causal_intent(&prompt) {
         info!("PROMPT_SUBMIT: R10 causal intent detected, adding E5 causal search");
-        match client
-            .search_causal_fast(&prompt, "effect", Some(3), true)
-            .await
-        {
+        match client.search_causal_fast(&prompt, Some(3), true).await {
             Ok(result) => {
                 let causal = parse_search_results(&result);
                 info!(
@@ -416,7 +414,8 @@ pub async fn execute(args: PromptSubmitArgs) -> HookResult<HookOutput> {
 // ============================================================================
 
 /// Parse stdin JSON into HookInput.
-/// FAIL FAST on empty or malformed input.
+/// FAIL FAST on empty or malformed input, or a payload that does not match
+/// its schema.
 fn parse_stdin() -> HookResult<HookInput> {
     let stdin = io::stdin();
     let mut input_str = String::new();
@@ -439,9 +438,9 @@ fn parse_stdin() -> HookResult<HookInput> {
         "PROMPT_SUBMIT: parsing stdin JSON"
     );
 
-    serde_json::from_str(&input_str).map_err(|e| {
-        error!(error = %e, "PROMPT_SUBMIT: JSON parse failed");
-        HookError::invalid_input(format!("JSON parse failed: {}", e))
+    HookInput::from_json(&input_str).map_err(|e| {
+        error!(error = %e, "PROMPT_SUBMIT: invalid hook input");
+        e
     })
 }
 
@@ -452,15 +451,18 @@ fn extract_prompt_info(input: &HookInput) -> HookResult<(String, Vec<Conversatio
         return Err(HookError::invalid_input(error));
     }
 
-    match &input.payload {
-        HookPayload::UserPromptSubmit { prompt, context } => Ok((prompt.clone(), context.clone())),
-        other => {
-            error!(payload_type = ?std::mem::discriminant(other), "PROMPT_SUBMIT: unexpected payload type");
-            Err(HookError::invalid_input(
-                "Expected UserPromptSubmit payload, got different type",
-            ))
-        }
-    }
+    let payload: &UserPromptSubmitPayload = input.typed_payload().map_err(|e| {
+        error!(error = %e, "PROMPT_SUBMIT: unexpected payload type");
+        e
+    })?;
+
+    debug!(
+        prompt_tokens = payload.tokens(),
+        context_len = payload.context.len(),
+        "PROMPT_SUBMIT: typed payload"
+    );
+
+    Ok((payload.prompt.clone(), payload.context.clone()))
 }
 
 // ============================================================================
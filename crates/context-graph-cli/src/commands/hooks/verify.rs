@@ -0,0 +1,419 @@
+//! Hook dry-run and latency budget verification
+//!
+//! `hooks verify` runs every `HookEventType::cli_command()` end-to-end with a
+//! synthetic `HookInput` on stdin, exactly as Claude Code invokes it, and
+//! checks two things per hook:
+//! - p95 wall time over N runs stays within `timeout_ms()`
+//! - stdout is a valid `HookOutput` (required `success` and
+//!   `execution_time_ms`, snake_case enums)
+//!
+//! Budget violations otherwise only surface when Claude Code kills the hook.
+//!
+//! # Exit Codes
+//! - 0: Every hook within budget with valid output
+//! - 1: At least one hook over budget, failing, or emitting invalid output
+//!
+//! # NO BACKWARDS COMPATIBILITY - FAIL FAST
+
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tracing::{error, info};
+
+use super::args::VerifyArgs;
+use super::payload::{
+    PostToolUsePayload, SessionEndPayload, SessionStartPayload, UserPromptSubmitPayload,
+};
+use super::types::{
+    ConversationMessage, HookEventType, HookInput, HookOutput, HookPayload, SessionEndStatus,
+};
+
+/// Result of one hook invocation.
+#[derive(Debug, Clone)]
+pub struct HookExecution {
+    /// Process exit code
+    pub exit_code: i32,
+    /// Everything the hook wrote to stdout
+    pub stdout: String,
+}
+
+/// Runs a hook command for the verifier.
+///
+/// The verifier times `execute` itself, so implementations should do all of
+/// the work Claude Code would wait for, including process startup.
+pub trait HookExecutor {
+    fn execute(&self, hook: HookEventType, input: &HookInput) -> io::Result<HookExecution>;
+}
+
+/// Executes hooks as child processes of a CLI binary.
+pub struct ProcessExecutor {
+    exe: PathBuf,
+}
+
+impl ProcessExecutor {
+    /// Executor for the currently running CLI binary.
+    pub fn current() -> io::Result<Self> {
+        Ok(Self {
+            exe: std::env::current_exe()?,
+        })
+    }
+}
+
+impl HookExecutor for ProcessExecutor {
+    fn execute(&self, hook: HookEventType, input: &HookInput) -> io::Result<HookExecution> {
+        let mut cmd = Command::new(&self.exe);
+        cmd.args(hook.cli_command().split_whitespace()).args([
+            "--session-id",
+            &input.session_id,
+            "--format",
+            "json-compact",
+        ]);
+        // session-start takes a bare flag, the other hooks take a value
+        match hook {
+            HookEventType::SessionStart => cmd.arg("--stdin"),
+            _ => cmd.args(["--stdin", "true"]),
+        };
+        let mut child = cmd
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        let payload = serde_json::to_string(input).map_err(io::Error::other)?;
+        if let Some(mut stdin) = child.stdin.take() {
+            writeln!(stdin, "{}", payload)?;
+        }
+        let output = child.wait_with_output()?;
+        Ok(HookExecution {
+            exit_code: output.status.code().unwrap_or(-1),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        })
+    }
+}
+
+/// Synthetic input for `hook`, shaped like what Claude Code sends.
+pub fn synthetic_input(hook: HookEventType, session_id: &str) -> HookInput {
+    let payload = match hook {
+        HookEventType::SessionStart => HookPayload::SessionStart(SessionStartPayload {
+            cwd: std::env::current_dir()
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|_| "/".to_string()),
+            source: "cli".to_string(),
+            previous_session_id: None,
+        }),
+        HookEventType::PreToolUse => HookPayload::PreToolUse {
+            tool_name: "Read".to_string(),
+            tool_input: serde_json::json!({ "file_path": "README.md" }),
+            tool_use_id: "verify-tool-use".to_string(),
+        },
+        HookEventType::PostToolUse => HookPayload::PostToolUse(PostToolUsePayload {
+            tool_name: "Read".to_string(),
+            tool_input: serde_json::json!({ "file_path": "README.md" }),
+            tool_response: "hooks verify synthetic tool response".to_string(),
+            tool_use_id: "verify-tool-use".to_string(),
+            tool_success: Some(true),
+            duration_ms: None,
+            affected_files: vec![],
+        }),
+        HookEventType::UserPromptSubmit => HookPayload::UserPromptSubmit(UserPromptSubmitPayload {
+            prompt: "How does the retry backoff work?".to_string(),
+            context: vec![ConversationMessage {
+                role: "user".to_string(),
+                content: "hooks verify synthetic turn".to_string(),
+            }],
+            token_estimate: None,
+        }),
+        HookEventType::SessionEnd => HookPayload::SessionEnd(SessionEndPayload {
+            duration_ms: 1_000,
+            status: SessionEndStatus::Normal,
+            reason: None,
+            stats: None,
+        }),
+    };
+    HookInput {
+        hook_type: hook,
+        session_id: session_id.to_string(),
+        timestamp_ms: now_ms(),
+        payload,
+    }
+}
+
+fn now_ms() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(1)
+}
+
+/// Check hook stdout against the `HookOutput` contract.
+///
+/// `success` and `execution_time_ms` must be present with the right types;
+/// the rest must deserialize strictly, which rejects non-snake_case enums.
+pub fn validate_output(stdout: &str) -> Result<HookOutput, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(stdout.trim()).map_err(|e| format!("stdout is not JSON: {}", e))?;
+    if !value
+        .get("success")
+        .is_some_and(serde_json::Value::is_boolean)
+    {
+        return Err("missing boolean field 'success'".to_string());
+    }
+    if !value
+        .get("execution_time_ms")
+        .is_some_and(serde_json::Value::is_u64)
+    {
+        return Err("missing integer field 'execution_time_ms'".to_string());
+    }
+    serde_json::from_value(value).map_err(|e| format!("invalid HookOutput: {}", e))
+}
+
+/// Verification result for one hook.
+#[derive(Debug, Clone, Serialize)]
+pub struct HookVerification {
+    pub hook: HookEventType,
+    pub command: &'static str,
+    pub budget_ms: u64,
+    pub runs: usize,
+    pub p50_ms: u64,
+    pub p95_ms: u64,
+    pub max_ms: u64,
+    /// Distinct failures (non-zero exit, spawn error, invalid output)
+    pub errors: Vec<String>,
+    pub within_budget: bool,
+    pub passed: bool,
+}
+
+/// Verification result for every hook checked.
+#[derive(Debug, Clone, Serialize)]
+pub struct VerifyReport {
+    pub hooks: Vec<HookVerification>,
+    pub passed: bool,
+}
+
+/// `q`-quantile of `sorted` (nearest rank), zero if empty.
+fn percentile(sorted: &[Duration], q: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = ((q * sorted.len() as f64).ceil() as usize).clamp(1, sorted.len());
+    sorted[rank - 1]
+}
+
+/// Run `hook` `runs` times through `executor` and check budget and output.
+pub fn verify_hook(
+    executor: &dyn HookExecutor,
+    hook: HookEventType,
+    runs: usize,
+) -> HookVerification {
+    let session_id = format!("hooks-verify-{}-{}", std::process::id(), now_ms());
+    let mut timings = Vec::with_capacity(runs);
+    let mut errors: Vec<String> = Vec::new();
+    let mut record_error = |message: String| {
+        if !errors.contains(&message) {
+            errors.push(message);
+        }
+    };
+
+    for _ in 0..runs {
+        let input = synthetic_input(hook, &session_id);
+        let start = Instant::now();
+        let result = executor.execute(hook, &input);
+        timings.push(start.elapsed());
+
+        match result {
+            Err(e) => record_error(format!("failed to run '{}': {}", hook.cli_command(), e)),
+            Ok(execution) if execution.exit_code != 0 => {
+                record_error(format!("exit code {}", execution.exit_code))
+            }
+            Ok(execution) => {
+                if let Err(e) = validate_output(&execution.stdout) {
+                    record_error(e);
+                }
+            }
+        }
+    }
+
+    timings.sort();
+    let p95 = percentile(&timings, 0.95);
+    let within_budget = p95.as_millis() as u64 <= hook.timeout_ms();
+    HookVerification {
+        hook,
+        command: hook.cli_command(),
+        budget_ms: hook.timeout_ms(),
+        runs,
+        p50_ms: percentile(&timings, 0.50).as_millis() as u64,
+        p95_ms: p95.as_millis() as u64,
+        max_ms: timings.last().map_or(0, |d| d.as_millis() as u64),
+        passed: within_budget && errors.is_empty(),
+        errors,
+        within_budget,
+    }
+}
+
+/// Verify each of `hooks`.
+pub fn verify_hooks(
+    executor: &dyn HookExecutor,
+    hooks: &[HookEventType],
+    runs: usize,
+) -> VerifyReport {
+    let hooks: Vec<HookVerification> = hooks
+        .iter()
+        .map(|&hook| verify_hook(executor, hook, runs))
+        .collect();
+    let passed = hooks.iter().all(|h| h.passed);
+    VerifyReport { hooks, passed }
+}
+
+fn print_text_report(report: &VerifyReport) {
+    for h in &report.hooks {
+        println!(
+            "{:<6} {:<22} p50={}ms p95={}ms max={}ms budget={}ms",
+            if h.passed { "PASS" } else { "FAIL" },
+            h.command,
+            h.p50_ms,
+            h.p95_ms,
+            h.max_ms,
+            h.budget_ms
+        );
+        if !h.within_budget {
+            println!("       p95 exceeds budget by {}ms", h.p95_ms - h.budget_ms);
+        }
+        for e in &h.errors {
+            println!("       {}", e);
+        }
+    }
+}
+
+/// Handle `hooks verify`.
+pub fn execute(args: VerifyArgs) -> i32 {
+    let executor = match ProcessExecutor::current() {
+        Ok(executor) => executor,
+        Err(e) => {
+            error!(error = %e, "Cannot locate CLI binary");
+            return 1;
+        }
+    };
+    let runs = args.runs.max(1);
+    info!(runs, "Verifying hook latency budgets");
+    let report = verify_hooks(&executor, &HookEventType::all(), runs);
+
+    if args.json {
+        match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                error!(error = %e, "Failed to serialize report");
+                return 1;
+            }
+        }
+    } else {
+        print_text_report(&report);
+    }
+
+    if report.passed {
+        0
+    } else {
+        1
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::hooks::args::{OutputFormat, PreToolArgs};
+    use crate::commands::hooks::pre_tool_use::handle_pre_tool_use;
+
+    /// Runs the real pre-tool handler in-process after a fixed delay,
+    /// standing in for a store that has become slow.
+    struct SlowStoreExecutor {
+        delay: Duration,
+    }
+
+    impl HookExecutor for SlowStoreExecutor {
+        fn execute(&self, hook: HookEventType, input: &HookInput) -> io::Result<HookExecution> {
+            assert_eq!(hook, HookEventType::PreToolUse);
+            std::thread::sleep(self.delay);
+            let args = PreToolArgs {
+                session_id: input.session_id.clone(),
+                tool_name: Some("Read".to_string()),
+                stdin: false,
+                fast_path: true,
+                format: OutputFormat::JsonCompact,
+            };
+            let output = handle_pre_tool_use(&args).map_err(|e| io::Error::other(e.to_string()))?;
+            Ok(HookExecution {
+                exit_code: 0,
+                stdout: serde_json::to_string(&output).map_err(io::Error::other)?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_fast_path_within_budget_passes() {
+        let executor = SlowStoreExecutor {
+            delay: Duration::ZERO,
+        };
+        let report = verify_hooks(&executor, &[HookEventType::PreToolUse], 5);
+        assert!(report.passed, "{:?}", report);
+        assert_eq!(report.hooks[0].budget_ms, 500);
+    }
+
+    #[test]
+    fn test_slowed_store_flags_budget_violation() {
+        let budget = HookEventType::PreToolUse.timeout_ms();
+        let executor = SlowStoreExecutor {
+            delay: Duration::from_millis(budget + 50),
+        };
+        let report = verify_hooks(&executor, &[HookEventType::PreToolUse], 2);
+
+        assert!(!report.passed);
+        let hook = &report.hooks[0];
+        assert!(!hook.within_budget);
+        assert!(
+            hook.p95_ms > budget,
+            "p95 {}ms must exceed {}ms",
+            hook.p95_ms,
+            budget
+        );
+        assert!(
+            hook.errors.is_empty(),
+            "Output itself is valid: {:?}",
+            hook.errors
+        );
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["hooks"][0]["hook"], "pre_tool_use");
+        assert_eq!(json["passed"], false);
+    }
+
+    #[test]
+    fn test_validate_output_requires_fields_and_snake_case() {
+        assert!(validate_output(r#"{"success":true,"execution_time_ms":3}"#).is_ok());
+        assert!(validate_output(r#"{"execution_time_ms":3}"#)
+            .unwrap_err()
+            .contains("success"));
+        assert!(validate_output(r#"{"success":true}"#)
+            .unwrap_err()
+            .contains("execution_time_ms"));
+        let camel = r#"{"success":true,"execution_time_ms":3,
+            "stability_classification":{"value":0.9,"level":"Healthy","crisis_triggered":false}}"#;
+        assert!(validate_output(camel).is_err());
+        assert!(validate_output("not json").is_err());
+    }
+
+    #[test]
+    fn test_synthetic_input_matches_hook_type() {
+        for hook in HookEventType::all() {
+            let input = synthetic_input(hook, "s");
+            assert!(input.validate().is_none());
+            let json = serde_json::to_value(&input).unwrap();
+            assert_eq!(json["hook_type"], json["payload"]["type"]);
+        }
+    }
+}
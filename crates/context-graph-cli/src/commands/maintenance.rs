@@ -0,0 +1,148 @@
+//! Maintenance commands - Periodic upkeep of stored embeddings.
+//!
+//! # Usage
+//!
+//! ```bash
+//! # Rewrite E2 recency vectors that no longer match their timestamps
+//! context-graph-cli maintenance refresh-temporal
+//!
+//! # Decay-encoded stores age continuously; run this from cron
+//! context-graph-cli maintenance refresh-temporal --encoding decay --drift-threshold 0.02
+//! ```
+//!
+//! Without `--resume-from`, an unfinished run is resumed from the cursor it
+//! checkpointed in the database. Fresh vectors are skipped, so rerunning the
+//! command is always safe.
+//!
+//! # Prerequisites
+//!
+//! No embedding models are needed: E2 is recomputed from stored timestamps.
+//! Stop the MCP server first; both open the same RocksDB.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use clap::{Args, Subcommand, ValueEnum};
+use tracing::{error, info};
+use uuid::Uuid;
+
+use context_graph_core::traits::TeleologicalMemoryStore;
+use context_graph_storage::teleological::RocksDbTeleologicalStore;
+use context_graph_storage::temporal_refresh::{
+    RecencyEncoding, TemporalRefreshConfig, TemporalRefreshCursor, TemporalRefreshJob,
+    DEFAULT_TEMPORAL_DRIFT_THRESHOLD,
+};
+
+/// Maintenance subcommands.
+#[derive(Subcommand)]
+pub enum MaintenanceCommands {
+    /// Recompute drifted E2 recency vectors from stored timestamps
+    RefreshTemporal(RefreshTemporalArgs),
+}
+
+/// E2 encoding selectable on the command line
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum EncodingArg {
+    /// Sinusoidal encoding of the absolute timestamp (production)
+    Absolute,
+    /// Exponential decay relative to the current time
+    Decay,
+}
+
+impl From<EncodingArg> for RecencyEncoding {
+    fn from(arg: EncodingArg) -> Self {
+        match arg {
+            EncodingArg::Absolute => RecencyEncoding::Absolute,
+            EncodingArg::Decay => RecencyEncoding::Decay,
+        }
+    }
+}
+
+/// Arguments for `maintenance refresh-temporal`
+#[derive(Args, Debug)]
+pub struct RefreshTemporalArgs {
+    /// Encoding the expected E2 vector is computed with
+    #[arg(long, value_enum, default_value = "absolute")]
+    pub encoding: EncodingArg,
+
+    /// Cosine distance above which a stored vector is refreshed
+    #[arg(long, default_value_t = DEFAULT_TEMPORAL_DRIFT_THRESHOLD)]
+    pub drift_threshold: f32,
+
+    /// Fingerprints checked per checkpointed batch
+    #[arg(long, default_value = "256")]
+    pub batch_size: usize,
+
+    /// Resume after this fingerprint ID instead of the stored cursor
+    #[arg(long)]
+    pub resume_from: Option<Uuid>,
+
+    /// Database path
+    #[arg(long, env = "CONTEXT_GRAPH_DATA_DIR")]
+    pub db_path: Option<PathBuf>,
+}
+
+/// Handle maintenance commands
+pub async fn handle_maintenance_command(action: MaintenanceCommands) -> i32 {
+    match action {
+        MaintenanceCommands::RefreshTemporal(args) => handle_refresh_temporal(args).await,
+    }
+}
+
+async fn handle_refresh_temporal(args: RefreshTemporalArgs) -> i32 {
+    let config = TemporalRefreshConfig::default()
+        .with_encoding(args.encoding.into())
+        .with_drift_threshold(args.drift_threshold)
+        .with_batch_size(args.batch_size);
+    if let Err(e) = config.validate() {
+        error!(error = %e, "Invalid temporal refresh configuration");
+        return 1;
+    }
+
+    let db_path = args
+        .db_path
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("./contextgraph_data"));
+    let store: Arc<dyn TeleologicalMemoryStore> = match RocksDbTeleologicalStore::open(&db_path) {
+        Ok(store) => Arc::new(store),
+        Err(e) => {
+            error!(error = %e, db_path = ?db_path, "Failed to open TeleologicalStore");
+            return 1;
+        }
+    };
+
+    let job = match TemporalRefreshJob::new(Arc::clone(&store), config) {
+        Ok(job) => job,
+        Err(e) => {
+            error!(error = %e, "Failed to create temporal refresh job");
+            return 1;
+        }
+    };
+
+    let result = job
+        .run(args.resume_from.map(TemporalRefreshCursor::after))
+        .await;
+    if let Err(e) = store.flush().await {
+        error!(error = %e, "Failed to flush store after temporal refresh");
+        return 1;
+    }
+    if let Err(e) = store.persist_hnsw_indexes_if_available() {
+        error!(error = %e, "Failed to persist HNSW indexes after temporal refresh");
+        return 1;
+    }
+
+    match result {
+        Ok(cursor) => {
+            info!(
+                refreshed = cursor.refreshed,
+                skipped = cursor.skipped,
+                "Temporal refresh complete"
+            );
+            0
+        }
+        Err(e) => {
+            error!(error = %e, "Temporal refresh failed - rerun to resume from the last checkpoint");
+            1
+        }
+    }
+}
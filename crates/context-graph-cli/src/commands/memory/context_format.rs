@@ -0,0 +1,645 @@
+//! Rendering and token budgeting for `memory inject-context`.
+//!
+//! Injected context has a fixed section layout so hooks and tooling can rely
+//! on it:
+//!
+//! 1. Identity line (session the context was built for)
+//! 2. Top topics (omitted for `brief`)
+//! 3. Top memories, most relevant first
+//! 4. Footer: `<!-- cg:injected n=<memories> tokens~=<estimate> -->`
+//!
+//! `--max-tokens` is enforced by dropping memories from the least relevant
+//! end until the rendered output (footer included) fits. Token counts come
+//! from a [`TokenEstimator`]; the default is the chars/4 heuristic used by
+//! the rest of the inject commands.
+//!
+//! # Constitution Compliance
+//!
+//! - AP-12: No magic numbers (layout limits are named constants)
+//! - AP-14: No .unwrap() in library code
+
+use std::cmp::Ordering;
+use std::fmt::Write;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+// =============================================================================
+// Constants (AP-12: No magic numbers)
+// =============================================================================
+
+/// Characters per token for [`CharsPerToken::default`].
+pub const DEFAULT_CHARS_PER_TOKEN: usize = 4;
+
+/// Topics listed by the `standard` format.
+const STANDARD_MAX_TOPICS: usize = 3;
+
+/// Topics listed by the `full` and `json` formats.
+const FULL_MAX_TOPICS: usize = 5;
+
+/// One-line summary length for the `brief` format (characters).
+const BRIEF_SUMMARY_CHARS: usize = 80;
+
+/// One-line summary length for the `standard` format (characters).
+const STANDARD_SUMMARY_CHARS: usize = 160;
+
+/// Memory ids are shortened to this many characters in text formats.
+const SHORT_ID_CHARS: usize = 8;
+
+// =============================================================================
+// Format and Token Estimation
+// =============================================================================
+
+/// Output format for `memory inject-context`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum InjectFormat {
+    /// Identity line and one-line memory summaries, no topics
+    Brief,
+    /// Identity line, top 3 topics, one-line memory summaries
+    #[default]
+    Standard,
+    /// Identity line, top 5 topics, full memory content
+    Full,
+    /// Same data as `full` as a single JSON object
+    Json,
+}
+
+impl InjectFormat {
+    /// Maximum topics rendered in this format.
+    fn max_topics(self) -> usize {
+        match self {
+            Self::Brief => 0,
+            Self::Standard => STANDARD_MAX_TOPICS,
+            Self::Full | Self::Json => FULL_MAX_TOPICS,
+        }
+    }
+
+    /// Summary length for memories, `None` for full content.
+    fn summary_chars(self) -> Option<usize> {
+        match self {
+            Self::Brief => Some(BRIEF_SUMMARY_CHARS),
+            Self::Standard => Some(STANDARD_SUMMARY_CHARS),
+            Self::Full | Self::Json => None,
+        }
+    }
+
+    /// Whether this format shows topics (and so needs the topic portfolio).
+    pub fn includes_topics(self) -> bool {
+        self.max_topics() > 0
+    }
+}
+
+/// Estimates how many tokens a piece of text costs in the prompt.
+pub trait TokenEstimator {
+    /// Estimated token count of `text`.
+    fn estimate(&self, text: &str) -> usize;
+}
+
+/// Token estimate of one token per `n` characters, rounded up.
+#[derive(Debug, Clone, Copy)]
+pub struct CharsPerToken(pub usize);
+
+impl Default for CharsPerToken {
+    fn default() -> Self {
+        Self(DEFAULT_CHARS_PER_TOKEN)
+    }
+}
+
+impl TokenEstimator for CharsPerToken {
+    fn estimate(&self, text: &str) -> usize {
+        text.chars().count().div_ceil(self.0.max(1))
+    }
+}
+
+// =============================================================================
+// Context Data
+// =============================================================================
+
+/// A memory candidate for injection.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InjectedMemory {
+    /// Memory id
+    pub id: String,
+    /// Relevance to the query
+    pub similarity: f64,
+    /// Memory content
+    pub content: String,
+}
+
+/// A topic shown in the injected context.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InjectedTopic {
+    /// Topic name (or a short id when unnamed)
+    pub name: String,
+    /// Topic confidence [0.0, 1.0]
+    pub confidence: f64,
+    /// Number of member memories
+    pub member_count: usize,
+}
+
+/// Everything needed to render injected context.
+#[derive(Debug, Clone, Default)]
+pub struct InjectionContext {
+    /// Session the context is injected into
+    pub session_id: String,
+    /// Candidate topics, any order
+    pub topics: Vec<InjectedTopic>,
+    /// Candidate memories, any order
+    pub memories: Vec<InjectedMemory>,
+}
+
+impl InjectionContext {
+    /// Build a context from MCP `search_graph` and `get_topic_portfolio`
+    /// results. Memories without content are skipped.
+    pub fn from_mcp(
+        session_id: &str,
+        search_results: &serde_json::Value,
+        topic_portfolio: Option<&serde_json::Value>,
+    ) -> Self {
+        let memories = search_results
+            .get("results")
+            .or_else(|| search_results.get("memories"))
+            .and_then(|v| v.as_array())
+            .map(|results| {
+                results
+                    .iter()
+                    .filter_map(|memory| {
+                        let content = memory.get("content").and_then(|v| v.as_str())?;
+                        if content.trim().is_empty() {
+                            return None;
+                        }
+                        Some(InjectedMemory {
+                            id: memory
+                                .get("id")
+                                .and_then(|v| v.as_str())
+                                .unwrap_or("?")
+                                .to_string(),
+                            similarity: memory
+                                .get("similarity")
+                                .or_else(|| memory.get("score"))
+                                .and_then(|v| v.as_f64())
+                                .unwrap_or(0.0),
+                            content: content.to_string(),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let topics = topic_portfolio
+            .and_then(|p| p.get("topics"))
+            .and_then(|t| t.as_array())
+            .map(|topics| {
+                topics
+                    .iter()
+                    .map(|topic| {
+                        let id = topic.get("id").and_then(|v| v.as_str()).unwrap_or("?");
+                        InjectedTopic {
+                            name: topic
+                                .get("name")
+                                .and_then(|v| v.as_str())
+                                .map(str::to_string)
+                                .unwrap_or_else(|| format!("topic {}", short_id(id))),
+                            confidence: topic
+                                .get("confidence")
+                                .and_then(|v| v.as_f64())
+                                .unwrap_or(0.0),
+                            member_count: topic
+                                .get("member_count")
+                                .and_then(|v| v.as_u64())
+                                .unwrap_or(0) as usize,
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            session_id: session_id.to_string(),
+            topics,
+            memories,
+        }
+    }
+}
+
+// =============================================================================
+// Rendering
+// =============================================================================
+
+/// Rendered injected context.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedContext {
+    /// Output to print, ending with the footer line
+    pub text: String,
+    /// Memories that fit the budget
+    pub memory_count: usize,
+    /// Estimated tokens of `text` before the footer
+    pub tokens: usize,
+}
+
+/// JSON shape of the `json` format.
+#[derive(Serialize)]
+struct JsonContext<'a> {
+    session_id: &'a str,
+    topics: &'a [InjectedTopic],
+    memories: &'a [InjectedMemory],
+    footer: String,
+}
+
+/// Render `context` in `format`, keeping the estimated size within
+/// `max_tokens`.
+///
+/// Memories are ranked by descending similarity (ties broken by id, so
+/// output is stable for the same data) and dropped from the least relevant
+/// end until the output fits. Topics are ranked by confidence, then member
+/// count, then name.
+pub fn render_context(
+    context: &InjectionContext,
+    format: InjectFormat,
+    max_tokens: usize,
+    estimator: &dyn TokenEstimator,
+) -> RenderedContext {
+    let mut topics = context.topics.clone();
+    topics.sort_by(|a, b| {
+        b.confidence
+            .partial_cmp(&a.confidence)
+            .unwrap_or(Ordering::Equal)
+            .then(b.member_count.cmp(&a.member_count))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    topics.truncate(format.max_topics());
+
+    let mut memories = context.memories.clone();
+    memories.sort_by(|a, b| {
+        b.similarity
+            .partial_cmp(&a.similarity)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    let mut rendered = render_with(context, &topics, &[], format, estimator);
+    for n in 1..=memories.len() {
+        let candidate = render_with(context, &topics, &memories[..n], format, estimator);
+        if estimator.estimate(&candidate.text) > max_tokens {
+            break;
+        }
+        rendered = candidate;
+    }
+    rendered
+}
+
+/// Render exactly `memories`, with the footer reflecting the result.
+fn render_with(
+    context: &InjectionContext,
+    topics: &[InjectedTopic],
+    memories: &[InjectedMemory],
+    format: InjectFormat,
+    estimator: &dyn TokenEstimator,
+) -> RenderedContext {
+    if format == InjectFormat::Json {
+        // Measure the body with an empty footer, then fill it in.
+        let mut json = JsonContext {
+            session_id: &context.session_id,
+            topics,
+            memories,
+            footer: String::new(),
+        };
+        let tokens = estimator.estimate(&serde_json::to_string(&json).unwrap_or_default());
+        json.footer = footer(memories.len(), tokens);
+        return RenderedContext {
+            text: serde_json::to_string(&json).unwrap_or_default(),
+            memory_count: memories.len(),
+            tokens,
+        };
+    }
+
+    let mut body = String::new();
+    let _ = writeln!(
+        body,
+        "# Context Graph (session {}, {} memories)",
+        context.session_id,
+        memories.len()
+    );
+
+    if !topics.is_empty() {
+        body.push_str("\n## Topics\n");
+        for topic in topics {
+            let _ = writeln!(
+                body,
+                "- {} ({} memories, confidence {:.2})",
+                topic.name, topic.member_count, topic.confidence
+            );
+        }
+    }
+
+    if !memories.is_empty() {
+        body.push_str("\n## Memories\n");
+        for (i, memory) in memories.iter().enumerate() {
+            match format.summary_chars() {
+                Some(max_chars) => {
+                    let _ = writeln!(
+                        body,
+                        "{}. [{:.2}] {} ({})",
+                        i + 1,
+                        memory.similarity,
+                        one_line_summary(&memory.content, max_chars),
+                        short_id(&memory.id)
+                    );
+                }
+                None => {
+                    let _ = writeln!(
+                        body,
+                        "\n### {}. {} (similarity {:.2})\n{}",
+                        i + 1,
+                        short_id(&memory.id),
+                        memory.similarity,
+                        memory.content.trim_end()
+                    );
+                }
+            }
+        }
+    }
+
+    let tokens = estimator.estimate(&body);
+    body.push('\n');
+    body.push_str(&footer(memories.len(), tokens));
+    body.push('\n');
+    RenderedContext {
+        text: body,
+        memory_count: memories.len(),
+        tokens,
+    }
+}
+
+/// Machine-readable footer marking a completed injection.
+fn footer(memory_count: usize, tokens: usize) -> String {
+    format!("<!-- cg:injected n={} tokens~={} -->", memory_count, tokens)
+}
+
+/// First non-empty line of `content`, cut to `max_chars` with an ellipsis.
+fn one_line_summary(content: &str, max_chars: usize) -> String {
+    let line = content
+        .lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty())
+        .unwrap_or("");
+    if line.chars().count() <= max_chars {
+        return line.to_string();
+    }
+    let cut: String = line.chars().take(max_chars.saturating_sub(1)).collect();
+    format!("{}…", cut.trim_end())
+}
+
+fn short_id(id: &str) -> &str {
+    id.get(..SHORT_ID_CHARS).unwrap_or(id)
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use context_graph_core::traits::TeleologicalSearchOptions;
+    use context_graph_test_utils::{
+        create_populated_store, PopulatedStore, PopulatedStoreSpec, StoreBackend,
+        POPULATED_STORE_SESSION,
+    };
+
+    /// Populate an in-memory store with 24 memories in 4 topics, give every
+    /// memory content, and build the context a query for memory 0 would get.
+    async fn populated_context() -> (PopulatedStore, InjectionContext) {
+        let populated = create_populated_store(PopulatedStoreSpec {
+            memories: 24,
+            topics: 4,
+            backend: StoreBackend::InMemory,
+            ..Default::default()
+        })
+        .await;
+        let store = &populated.store;
+        for (i, id) in populated.manifest.memory_ids.iter().enumerate() {
+            let content = format!(
+                "Memory {} about the ingestion pipeline and its retry behaviour.\n{}",
+                i,
+                "Details that only the full format shows. ".repeat(i % 5 + 1)
+            );
+            store.store_content(*id, &content).await.unwrap();
+        }
+
+        let query = store
+            .retrieve(populated.manifest.memory_ids[0])
+            .await
+            .unwrap()
+            .unwrap()
+            .semantic;
+        let results = store
+            .search_semantic(&query, TeleologicalSearchOptions::quick(20))
+            .await
+            .unwrap();
+        let mut memories = Vec::new();
+        for result in results {
+            let id = result.fingerprint.id;
+            memories.push(InjectedMemory {
+                id: id.to_string(),
+                similarity: result.similarity as f64,
+                content: store.get_content(id).await.unwrap().unwrap(),
+            });
+        }
+
+        let topics = store
+            .load_topic_portfolio(POPULATED_STORE_SESSION)
+            .await
+            .unwrap()
+            .unwrap()
+            .topics
+            .iter()
+            .map(|t| InjectedTopic {
+                name: format!("topic {}", short_id(&t.id.to_string())),
+                confidence: t.confidence as f64,
+                member_count: t.member_count(),
+            })
+            .collect();
+
+        let context = InjectionContext {
+            session_id: "session-under-test".to_string(),
+            topics,
+            memories,
+        };
+        (populated, context)
+    }
+
+    fn parse_footer(text: &str) -> (usize, usize) {
+        let footer = text.trim_end().lines().last().unwrap();
+        let inner = footer
+            .strip_prefix("<!-- cg:injected n=")
+            .and_then(|s| s.strip_suffix(" -->"))
+            .unwrap_or_else(|| panic!("Bad footer: {}", footer));
+        let (n, tokens) = inner.split_once(" tokens~=").unwrap();
+        (n.parse().unwrap(), tokens.parse().unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_budget_never_exceeded_by_more_than_one_memory() {
+        let (_populated, context) = populated_context().await;
+        let estimator = CharsPerToken::default();
+        let largest_memory = context
+            .memories
+            .iter()
+            .map(|m| estimator.estimate(&m.content))
+            .max()
+            .unwrap();
+
+        for format in [
+            InjectFormat::Brief,
+            InjectFormat::Standard,
+            InjectFormat::Full,
+            InjectFormat::Json,
+        ] {
+            let unbounded = render_context(&context, format, usize::MAX, &estimator);
+            assert_eq!(unbounded.memory_count, context.memories.len());
+
+            for max_tokens in [150, 400, 1200] {
+                let rendered = render_context(&context, format, max_tokens, &estimator);
+                let total = estimator.estimate(&rendered.text);
+                assert!(
+                    total <= max_tokens + largest_memory,
+                    "{:?} at {} tokens rendered {}",
+                    format,
+                    max_tokens,
+                    total
+                );
+                assert!(rendered.memory_count <= unbounded.memory_count);
+            }
+        }
+
+        let tight = render_context(&context, InjectFormat::Full, 400, &estimator);
+        assert!(tight.memory_count > 0 && tight.memory_count < context.memories.len());
+        assert!(estimator.estimate(&tight.text) <= 400);
+    }
+
+    #[tokio::test]
+    async fn test_trims_least_relevant_and_footer_matches() {
+        let (_populated, context) = populated_context().await;
+        let estimator = CharsPerToken::default();
+
+        let rendered = render_context(&context, InjectFormat::Standard, 300, &estimator);
+        let (n, tokens) = parse_footer(&rendered.text);
+        assert_eq!(n, rendered.memory_count);
+        assert_eq!(tokens, rendered.tokens);
+
+        let mut ranked = context.memories.clone();
+        ranked.sort_by(|a, b| {
+            b.similarity
+                .partial_cmp(&a.similarity)
+                .unwrap()
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        let best = short_id(&ranked[0].id);
+        let worst = short_id(&ranked[ranked.len() - 1].id);
+        assert!(
+            rendered.text.contains(best),
+            "Most relevant memory must be kept"
+        );
+        assert!(
+            !rendered.text.contains(worst),
+            "Least relevant memory must be trimmed"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_layout_and_ordering_are_stable() {
+        let (_populated, context) = populated_context().await;
+        let estimator = CharsPerToken::default();
+
+        let first = render_context(&context, InjectFormat::Standard, 1200, &estimator);
+        let mut shuffled = context.clone();
+        shuffled.memories.reverse();
+        shuffled.topics.reverse();
+        let second = render_context(&shuffled, InjectFormat::Standard, 1200, &estimator);
+        assert_eq!(first, second, "Input order must not change output");
+
+        let text = &first.text;
+        assert!(text.starts_with("# Context Graph (session session-under-test"));
+        let topics_at = text.find("## Topics").unwrap();
+        let memories_at = text.find("## Memories").unwrap();
+        assert!(topics_at < memories_at);
+        assert_eq!(text.matches("\n- topic ").count(), STANDARD_MAX_TOPICS);
+        assert!(text.trim_end().ends_with("-->"));
+        assert!(!text.contains("Details that only the full format shows"));
+
+        let brief = render_context(&context, InjectFormat::Brief, 1200, &estimator);
+        assert!(!brief.text.contains("## Topics"));
+        let full = render_context(&context, InjectFormat::Full, usize::MAX, &estimator);
+        assert!(full
+            .text
+            .contains("Details that only the full format shows"));
+    }
+
+    #[tokio::test]
+    async fn test_json_format() {
+        let (_populated, context) = populated_context().await;
+        let rendered = render_context(&context, InjectFormat::Json, 800, &CharsPerToken::default());
+        let json: serde_json::Value = serde_json::from_str(&rendered.text).unwrap();
+        assert_eq!(json["session_id"], "session-under-test");
+        let memories = json["memories"].as_array().unwrap();
+        assert_eq!(memories.len(), rendered.memory_count);
+        assert!(json["topics"].as_array().unwrap().len() <= FULL_MAX_TOPICS);
+        assert_eq!(
+            json["footer"],
+            format!(
+                "<!-- cg:injected n={} tokens~={} -->",
+                rendered.memory_count, rendered.tokens
+            )
+        );
+    }
+
+    #[test]
+    fn test_custom_estimator() {
+        struct Words;
+        impl TokenEstimator for Words {
+            fn estimate(&self, text: &str) -> usize {
+                text.split_whitespace().count()
+            }
+        }
+        let context = InjectionContext {
+            session_id: "s".to_string(),
+            topics: Vec::new(),
+            memories: (0..5)
+                .map(|i| InjectedMemory {
+                    id: format!("mem-{}", i),
+                    similarity: 0.9 - i as f64 * 0.1,
+                    content: "one two three four five".to_string(),
+                })
+                .collect(),
+        };
+        let rendered = render_context(&context, InjectFormat::Brief, 30, &Words);
+        assert!(Words.estimate(&rendered.text) <= 30);
+        assert!(rendered.memory_count < 5);
+    }
+
+    #[test]
+    fn test_from_mcp_parses_results_and_topics() {
+        let results = serde_json::json!({
+            "results": [
+                {"id": "abc12345-0000", "content": "First line\nsecond", "similarity": 0.8},
+                {"id": "skip", "content": "   ", "similarity": 0.9},
+                {"id": "def67890-0000", "content": "Other", "score": 0.5}
+            ]
+        });
+        let portfolio = serde_json::json!({
+            "topics": [
+                {"id": "0123456789", "confidence": 0.7, "member_count": 4},
+                {"id": "x", "name": "Retries", "confidence": 0.9, "member_count": 6}
+            ]
+        });
+        let context = InjectionContext::from_mcp("s", &results, Some(&portfolio));
+        assert_eq!(context.memories.len(), 2);
+        assert_eq!(context.memories[1].similarity, 0.5);
+        assert_eq!(context.topics[0].name, "topic 01234567");
+        assert_eq!(context.topics[1].name, "Retries");
+
+        assert_eq!(one_line_summary("\n  First line\nsecond", 80), "First line");
+        assert_eq!(one_line_summary("abcdefghij", 5), "abcd…");
+    }
+}
@@ -24,6 +24,9 @@ use clap::Args;
 use tracing::{debug, info};
 
 use crate::commands::hooks::memory_cache::get_cached_memories;
+use crate::commands::memory::context_format::{
+    render_context, CharsPerToken, InjectFormat, InjectionContext,
+};
 use crate::error::CliExitCode;
 use crate::mcp_client::McpClient;
 use crate::mcp_helpers::{mcp_error_to_exit_code, require_mcp_server, resolve_session_id};
@@ -65,9 +68,14 @@ pub struct InjectContextArgs {
     #[arg(long)]
     pub session_id: Option<String>,
 
-    /// Token budget for context (default: 1200 per constitution)
-    #[arg(long, default_value_t = DEFAULT_CONTEXT_BUDGET)]
-    pub budget: u32,
+    /// Token budget for context (default: 1200 per constitution).
+    /// Least relevant memories are dropped until the output fits.
+    #[arg(long = "max-tokens", alias = "budget", default_value_t = DEFAULT_CONTEXT_BUDGET)]
+    pub max_tokens: u32,
+
+    /// Output format (brief, standard, full, json)
+    #[arg(long, value_enum, default_value_t = InjectFormat::Standard)]
+    pub format: InjectFormat,
 
     /// Maximum number of results to retrieve (default: 10)
     #[arg(long, default_value = "10")]
@@ -204,7 +212,10 @@ fn format_search_results(
 
 /// Handle inject-context command.
 ///
-/// Searches for relevant memories via MCP server and outputs formatted context.
+/// Searches for relevant memories via MCP server and outputs context rendered
+/// by [`render_context`] in the requested format. Topics are fetched only for
+/// formats that show them; a failed topic lookup drops the topics section
+/// rather than the injection.
 /// Empty result = empty stdout (not an error).
 ///
 /// # Exit Codes
@@ -224,7 +235,8 @@ pub async fn handle_inject_context(args: InjectContextArgs) -> i32 {
     info!(
         query_len = query.len(),
         session_id = %session_id,
-        budget = args.budget,
+        max_tokens = args.max_tokens,
+        format = ?args.format,
         top_k = args.top_k,
         "Injecting memory context via MCP"
     );
@@ -234,29 +246,46 @@ pub async fn handle_inject_context(args: InjectContextArgs) -> i32 {
         return exit_code;
     }
 
-    match client.search_graph(&query, Some(args.top_k)).await {
-        Ok(results) => {
-            let (output, memory_count, tokens_used) =
-                format_search_results(&results, args.budget, &session_id);
-
-            if memory_count > 0 {
-                info!(
-                    memories = memory_count,
-                    tokens = tokens_used,
-                    "Context generated via MCP"
-                );
-                print!("{}", output);
-            } else {
-                debug!("No relevant context found");
-            }
-
-            CliExitCode::Success as i32
-        }
+    let results = match client.search_graph(&query, Some(args.top_k)).await {
+        Ok(results) => results,
         Err(e) => {
             eprintln!("ERROR: Failed to search for context via MCP: {}", e);
-            mcp_error_to_exit_code(&e)
+            return mcp_error_to_exit_code(&e);
         }
+    };
+
+    let portfolio = if args.format.includes_topics() {
+        match client.get_topic_portfolio(Some("brief")).await {
+            Ok(portfolio) => Some(portfolio),
+            Err(e) => {
+                debug!(error = %e, "Topic portfolio unavailable, omitting topics");
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let context = InjectionContext::from_mcp(&session_id, &results, portfolio.as_ref());
+    if context.memories.is_empty() {
+        debug!("No relevant context found");
+        return CliExitCode::Success as i32;
     }
+
+    let rendered = render_context(
+        &context,
+        args.format,
+        args.max_tokens as usize,
+        &CharsPerToken::default(),
+    );
+    info!(
+        memories = rendered.memory_count,
+        tokens = rendered.tokens,
+        "Context generated via MCP"
+    );
+    println!("{}", rendered.text.trim_end());
+
+    CliExitCode::Success as i32
 }
 
 /// Handle inject-brief command.
@@ -530,7 +559,8 @@ mod tests {
         let args = InjectContextArgs {
             query: None,
             session_id: Some("test".to_string()),
-            budget: DEFAULT_CONTEXT_BUDGET,
+            max_tokens: DEFAULT_CONTEXT_BUDGET,
+            format: InjectFormat::Standard,
             top_k: 10,
         };
 
@@ -546,7 +576,8 @@ mod tests {
         let args = InjectContextArgs {
             query: Some("   \n\t  ".to_string()),
             session_id: Some("test".to_string()),
-            budget: DEFAULT_CONTEXT_BUDGET,
+            max_tokens: DEFAULT_CONTEXT_BUDGET,
+            format: InjectFormat::Standard,
             top_k: 10,
         };
 
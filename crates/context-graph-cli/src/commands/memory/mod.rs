@@ -20,6 +20,7 @@
 //! - AP-26: Exit code 1 on error, 2 on corruption
 
 pub mod capture;
+pub mod context_format;
 pub mod inject;
 
 use clap::Subcommand;
@@ -46,8 +47,8 @@ pub enum MemoryCommands {
     /// # With environment variable
     /// USER_PROMPT="test query" context-graph-cli memory inject-context
     ///
-    /// # With custom budget
-    /// context-graph-cli memory inject-context --budget 800 "test"
+    /// # With custom budget and format
+    /// context-graph-cli memory inject-context --max-tokens 800 --format brief "test"
     /// ```
     InjectContext(inject::InjectContextArgs),
 
@@ -8,12 +8,24 @@
 //! - `warmup`: Pre-load embedding models into VRAM (TASK-EMB-WARMUP)
 //! - `topic`: Topic portfolio and stability commands
 //! - `divergence`: Divergence detection commands
+//! - `reembed`: Recompute embedding spaces after a model upgrade
+//! - `snapshot`: Export and import portable memory snapshots
+//! - `graph`: Bulk import and export of typed graph edges
+//! - `storage`: Integrity check and repair of the teleological store
+//! - `maintenance`: Periodic upkeep such as refreshing stale E2 vectors
+//! - `backup`: Consistent backups of the data directory and restore
 
+pub mod backup;
 pub mod divergence;
+pub mod graph;
 pub mod hooks;
+pub mod maintenance;
 pub mod memory;
+pub mod reembed;
 pub mod session;
 pub mod setup;
+pub mod snapshot;
+pub mod storage;
 pub mod topic;
 pub mod warmup;
 pub mod watch;
@@ -0,0 +1,166 @@
+//! Re-embed command - Recompute selected embedding spaces after a model upgrade.
+//!
+//! # Usage
+//!
+//! ```bash
+//! # Recompute E1 and E5 for every stored memory
+//! context-graph-cli reembed --embedders e1,e5 --batch-size 64
+//!
+//! # Resume an interrupted run after a specific fingerprint
+//! context-graph-cli reembed --embedders e1 --resume-from 5f0c6a3e-...
+//! ```
+//!
+//! Without `--resume-from`, an unfinished run is resumed from the cursor it
+//! checkpointed in the database. Fingerprints already at the target model
+//! version are skipped, so rerunning the command is always safe.
+//!
+//! # Prerequisites
+//!
+//! Run `context-graph-cli warmup` first to load embedding models into VRAM.
+//! Stop the MCP server while re-embedding; both open the same RocksDB.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use clap::Args;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use context_graph_core::reembed::{ReembedConfig, ReembedCursor, ReembedJob};
+use context_graph_core::teleological::{Embedder, EmbedderMask};
+use context_graph_core::traits::TeleologicalMemoryStore;
+use context_graph_embeddings::{
+    get_warm_provider, initialize_global_warm_provider, is_warm_initialized,
+};
+use context_graph_storage::teleological::RocksDbTeleologicalStore;
+
+/// Arguments for the reembed command
+#[derive(Args, Debug)]
+pub struct ReembedArgs {
+    /// Embedders to recompute (comma-separated, e.g. e1,e5)
+    #[arg(long, value_delimiter = ',', required = true)]
+    pub embedders: Vec<String>,
+
+    /// Fingerprints processed per checkpointed batch
+    #[arg(long, default_value = "64")]
+    pub batch_size: usize,
+
+    /// Resume after this fingerprint ID instead of the stored cursor
+    #[arg(long)]
+    pub resume_from: Option<Uuid>,
+
+    /// Version tag recorded for recomputed embedders (default: model ID)
+    #[arg(long)]
+    pub model_version: Option<String>,
+
+    /// Database path
+    #[arg(long, env = "CONTEXT_GRAPH_DATA_DIR")]
+    pub db_path: Option<PathBuf>,
+}
+
+/// Parse embedder names (e.g. "e1", "semantic") into a mask.
+fn parse_embedders(names: &[String]) -> Result<EmbedderMask, String> {
+    let mut mask = EmbedderMask::new();
+    for name in names {
+        let embedder = Embedder::from_name(name.trim()).map_err(|e| e.to_string())?;
+        mask.set(embedder);
+    }
+    Ok(mask)
+}
+
+/// Handle the reembed command
+pub async fn handle_reembed(args: ReembedArgs) -> i32 {
+    let embedders = match parse_embedders(&args.embedders) {
+        Ok(mask) => mask,
+        Err(e) => {
+            error!(error = %e, "Invalid --embedders value");
+            return 1;
+        }
+    };
+
+    let mut config = ReembedConfig::new(embedders).with_batch_size(args.batch_size);
+    if let Some(version) = args.model_version.clone() {
+        config = config.with_model_version(version);
+    }
+    if let Err(e) = config.validate() {
+        error!(error = %e, "Invalid re-embedding configuration");
+        return 1;
+    }
+
+    // The CLI runs as a separate process from the MCP server, so load models here
+    if !is_warm_initialized() {
+        info!("Embedding models not warm - initializing GPU embedding pipeline...");
+        if let Err(e) = initialize_global_warm_provider().await {
+            error!(error = %e, "Failed to initialize GPU embedding models");
+            return 1;
+        }
+    }
+    let provider = match get_warm_provider() {
+        Ok(provider) => provider,
+        Err(e) => {
+            error!(error = %e, "Failed to get warm embedding provider");
+            return 1;
+        }
+    };
+
+    let db_path = args
+        .db_path
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("./contextgraph_data"));
+    let store: Arc<dyn TeleologicalMemoryStore> = match RocksDbTeleologicalStore::open(&db_path) {
+        Ok(store) => Arc::new(store),
+        Err(e) => {
+            error!(error = %e, db_path = ?db_path, "Failed to open TeleologicalStore");
+            return 1;
+        }
+    };
+
+    let job = match ReembedJob::new(Arc::clone(&store), provider, config) {
+        Ok(job) => job,
+        Err(e) => {
+            error!(error = %e, "Failed to create re-embedding job");
+            return 1;
+        }
+    };
+
+    let result = job.run(args.resume_from.map(ReembedCursor::after)).await;
+    if let Err(e) = store.flush().await {
+        error!(error = %e, "Failed to flush store after re-embedding");
+        return 1;
+    }
+    if let Err(e) = store.persist_hnsw_indexes_if_available() {
+        error!(error = %e, "Failed to persist HNSW indexes after re-embedding");
+        return 1;
+    }
+
+    match result {
+        Ok(cursor) => {
+            info!(
+                migrated = cursor.migrated,
+                already_current = cursor.already_current,
+                missing_content = cursor.missing_content,
+                "Re-embedding complete"
+            );
+            0
+        }
+        Err(e) => {
+            error!(error = %e, "Re-embedding failed - rerun to resume from the last checkpoint");
+            1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_embedders() {
+        let mask = parse_embedders(&["e1".to_string(), "E5".to_string()]).unwrap();
+        assert_eq!(
+            mask,
+            EmbedderMask::from_slice(&[Embedder::Semantic, Embedder::Causal])
+        );
+        assert!(parse_embedders(&["e99".to_string()]).is_err());
+    }
+}
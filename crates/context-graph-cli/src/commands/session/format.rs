@@ -0,0 +1,419 @@
+//! Versioned on-disk format for session identity snapshots.
+//!
+//! `persist-identity` writes the cached [`SessionSnapshot`] to
+//! `<db_path>/session_identity.json` so the next process can restore it.
+//! Every file carries a `version`; loading dispatches on it and migrates
+//! older formats forward one version at a time.
+//!
+//! # Format History
+//!
+//! | Version | Change | Migration default |
+//! |---------|--------|-------------------|
+//! | 1 | Unversioned: `coherence` scalar, no trajectory | - |
+//! | 2 | `coherence` split into integration/reflection/differentiation; `trajectory` added | All three metrics = `coherence`; empty trajectory |
+//! | 3 | `previous_session_id` added | `None` (no session link) |
+//!
+//! A v1 file has no `version` key. The current format and the
+//! [`SUPPORTED_SNAPSHOT_VERSIONS`] before it load; anything older or newer
+//! fails fast with a [`SnapshotFormatError`] telling the user what to do.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::commands::hooks::session_state::{SessionSnapshot, NUM_EMBEDDERS};
+
+/// Version written by this release.
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 3;
+
+/// Number of versions that load: the current one and the two before it.
+pub const SUPPORTED_SNAPSHOT_VERSIONS: u32 = 3;
+
+/// Oldest version that still loads.
+pub const OLDEST_SUPPORTED_SNAPSHOT_VERSION: u32 =
+    SNAPSHOT_FORMAT_VERSION + 1 - SUPPORTED_SNAPSHOT_VERSIONS;
+
+/// Snapshot file name inside the database directory.
+pub const SNAPSHOT_FILE_NAME: &str = "session_identity.json";
+
+/// Errors loading or saving a snapshot file.
+#[derive(Debug, Error)]
+pub enum SnapshotFormatError {
+    /// Written by a release older than the support window.
+    #[error(
+        "Session snapshot version {version} is no longer supported (oldest supported: {oldest}). \
+         Delete {path} and restore with source=clear to start a fresh identity."
+    )]
+    Unsupported {
+        version: u32,
+        oldest: u32,
+        path: PathBuf,
+    },
+
+    /// Written by a newer release.
+    #[error(
+        "Session snapshot version {version} is newer than this CLI supports (current: {current}). \
+         Upgrade context-graph-cli to restore {path}."
+    )]
+    TooNew {
+        version: u32,
+        current: u32,
+        path: PathBuf,
+    },
+
+    /// The file does not match the format its version declares.
+    #[error("Session snapshot {path} is corrupt (version {version}): {source}")]
+    Malformed {
+        version: u32,
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// Reading or writing the file failed.
+    #[error("Session snapshot I/O error on {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+impl SnapshotFormatError {
+    /// Exit code per AP-26: corrupt files are 2, everything else 1.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Malformed { .. } => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// A snapshot read from disk, upgraded to the current format.
+#[derive(Debug, Clone)]
+pub struct LoadedSnapshot {
+    /// The snapshot in the current format.
+    pub snapshot: SessionSnapshot,
+    /// Version the file was written in.
+    pub version: u32,
+    /// Set to `version` when the file was migrated, `None` if it was current.
+    pub migrated_from: Option<u32>,
+}
+
+/// Current (v3) on-disk envelope.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SessionSnapshotEnvelope {
+    pub version: u32,
+    pub session_id: String,
+    pub timestamp_ms: u64,
+    pub topic_profile: [f32; NUM_EMBEDDERS],
+    pub integration: f32,
+    pub reflection: f32,
+    pub differentiation: f32,
+    pub trajectory: Vec<[f32; NUM_EMBEDDERS]>,
+    pub previous_session_id: Option<String>,
+}
+
+impl From<&SessionSnapshot> for SessionSnapshotEnvelope {
+    fn from(snapshot: &SessionSnapshot) -> Self {
+        Self {
+            version: SNAPSHOT_FORMAT_VERSION,
+            session_id: snapshot.session_id.clone(),
+            timestamp_ms: snapshot.timestamp_ms,
+            topic_profile: snapshot.topic_profile,
+            integration: snapshot.integration,
+            reflection: snapshot.reflection,
+            differentiation: snapshot.differentiation,
+            trajectory: snapshot.trajectory.clone(),
+            previous_session_id: snapshot.previous_session_id.clone(),
+        }
+    }
+}
+
+impl From<SessionSnapshotEnvelope> for SessionSnapshot {
+    fn from(envelope: SessionSnapshotEnvelope) -> Self {
+        Self {
+            session_id: envelope.session_id,
+            topic_profile: envelope.topic_profile,
+            integration: envelope.integration,
+            reflection: envelope.reflection,
+            differentiation: envelope.differentiation,
+            trajectory: envelope.trajectory,
+            previous_session_id: envelope.previous_session_id,
+            timestamp_ms: envelope.timestamp_ms,
+        }
+    }
+}
+
+/// v1 format: no `version` key.
+#[derive(Debug, Deserialize)]
+struct SnapshotV1 {
+    session_id: String,
+    timestamp_ms: u64,
+    topic_profile: [f32; NUM_EMBEDDERS],
+    coherence: f32,
+}
+
+/// v2 format.
+#[derive(Debug, Deserialize)]
+struct SnapshotV2 {
+    session_id: String,
+    timestamp_ms: u64,
+    topic_profile: [f32; NUM_EMBEDDERS],
+    integration: f32,
+    reflection: f32,
+    differentiation: f32,
+    trajectory: Vec<[f32; NUM_EMBEDDERS]>,
+}
+
+impl From<SnapshotV1> for SnapshotV2 {
+    /// The single coherence scalar becomes all three metrics, so their
+    /// mean (the coherence level) is unchanged.
+    fn from(v1: SnapshotV1) -> Self {
+        Self {
+            session_id: v1.session_id,
+            timestamp_ms: v1.timestamp_ms,
+            topic_profile: v1.topic_profile,
+            integration: v1.coherence,
+            reflection: v1.coherence,
+            differentiation: v1.coherence,
+            trajectory: Vec::new(),
+        }
+    }
+}
+
+impl From<SnapshotV2> for SessionSnapshotEnvelope {
+    fn from(v2: SnapshotV2) -> Self {
+        Self {
+            version: SNAPSHOT_FORMAT_VERSION,
+            session_id: v2.session_id,
+            timestamp_ms: v2.timestamp_ms,
+            topic_profile: v2.topic_profile,
+            integration: v2.integration,
+            reflection: v2.reflection,
+            differentiation: v2.differentiation,
+            trajectory: v2.trajectory,
+            previous_session_id: None,
+        }
+    }
+}
+
+/// Snapshot file path for `db_path`, defaulting to `~/.context-graph/db`.
+pub fn snapshot_path(db_path: Option<&Path>) -> PathBuf {
+    let dir = match db_path {
+        Some(path) => path.to_path_buf(),
+        None => home_dir()
+            .map(|h| h.join(".context-graph").join("db"))
+            .unwrap_or_else(|| PathBuf::from(".context-graph/db")),
+    };
+    dir.join(SNAPSHOT_FILE_NAME)
+}
+
+/// Parse a snapshot document of any supported version.
+///
+/// `path` is only used in error messages.
+pub fn parse_snapshot(json: &str, path: &Path) -> Result<LoadedSnapshot, SnapshotFormatError> {
+    let malformed = |version, source| SnapshotFormatError::Malformed {
+        version,
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let value: Value = serde_json::from_str(json).map_err(|e| malformed(0, e))?;
+    let version = match value.get("version") {
+        None => 1,
+        Some(v) => {
+            let version: u32 = serde_json::from_value(v.clone()).map_err(|e| malformed(0, e))?;
+            version
+        }
+    };
+
+    if version < OLDEST_SUPPORTED_SNAPSHOT_VERSION {
+        return Err(SnapshotFormatError::Unsupported {
+            version,
+            oldest: OLDEST_SUPPORTED_SNAPSHOT_VERSION,
+            path: path.to_path_buf(),
+        });
+    }
+    if version > SNAPSHOT_FORMAT_VERSION {
+        return Err(SnapshotFormatError::TooNew {
+            version,
+            current: SNAPSHOT_FORMAT_VERSION,
+            path: path.to_path_buf(),
+        });
+    }
+
+    let envelope: SessionSnapshotEnvelope = match version {
+        1 => {
+            let v1: SnapshotV1 = serde_json::from_value(value).map_err(|e| malformed(1, e))?;
+            SnapshotV2::from(v1).into()
+        }
+        2 => {
+            let v2: SnapshotV2 = serde_json::from_value(value).map_err(|e| malformed(2, e))?;
+            v2.into()
+        }
+        _ => serde_json::from_value(value).map_err(|e| malformed(version, e))?,
+    };
+
+    Ok(LoadedSnapshot {
+        snapshot: envelope.into(),
+        version,
+        migrated_from: (version < SNAPSHOT_FORMAT_VERSION).then_some(version),
+    })
+}
+
+/// Load the snapshot at `path`, or `None` if there is no file.
+pub fn load_snapshot(path: &Path) -> Result<Option<LoadedSnapshot>, SnapshotFormatError> {
+    let json = match std::fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(source) => {
+            return Err(SnapshotFormatError::Io {
+                path: path.to_path_buf(),
+                source,
+            })
+        }
+    };
+    parse_snapshot(&json, path).map(Some)
+}
+
+/// Write `snapshot` to `path` in the current format.
+///
+/// The file is written next to `path` and renamed over it, so a crash never
+/// leaves a half-written snapshot.
+pub fn save_snapshot(path: &Path, snapshot: &SessionSnapshot) -> Result<(), SnapshotFormatError> {
+    let io_error = |source| SnapshotFormatError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let json = serde_json::to_vec_pretty(&SessionSnapshotEnvelope::from(snapshot))
+        .expect("snapshot envelope is always serializable");
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, json).map_err(io_error)?;
+    std::fs::rename(&tmp, path).map_err(io_error)
+}
+
+/// Get home directory (cross-platform)
+fn home_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::hooks::session_state::CoherenceState;
+    use context_graph_test_utils::{SESSION_SNAPSHOT_V1, SESSION_SNAPSHOT_V2};
+
+    fn coherence_level(snapshot: &SessionSnapshot) -> f32 {
+        (snapshot.integration + snapshot.reflection + snapshot.differentiation) / 3.0
+    }
+
+    #[test]
+    fn test_v1_fixture_migrates() {
+        let loaded = parse_snapshot(SESSION_SNAPSHOT_V1, Path::new("v1.json")).unwrap();
+        assert_eq!(loaded.version, 1);
+        assert_eq!(loaded.migrated_from, Some(1));
+
+        let snapshot = &loaded.snapshot;
+        assert_eq!(snapshot.session_id, "session-1736985432000");
+        assert_eq!(snapshot.timestamp_ms, 1736985432000);
+        assert!(snapshot.trajectory.is_empty());
+        assert!(snapshot.previous_session_id.is_none());
+
+        // The v1 coherence scalar survives migration unchanged
+        let level = coherence_level(snapshot);
+        assert!((level - 0.72).abs() < 1e-6);
+        assert_eq!(CoherenceState::from_level(level).short_name(), "Aware");
+    }
+
+    #[test]
+    fn test_v2_fixture_migrates() {
+        let loaded = parse_snapshot(SESSION_SNAPSHOT_V2, Path::new("v2.json")).unwrap();
+        assert_eq!(loaded.version, 2);
+        assert_eq!(loaded.migrated_from, Some(2));
+
+        let snapshot = &loaded.snapshot;
+        assert_eq!(snapshot.session_id, "session-1739412871000");
+        assert_eq!(snapshot.trajectory.len(), 2);
+        assert_eq!(snapshot.trajectory[1], snapshot.topic_profile);
+        assert!(snapshot.previous_session_id.is_none());
+
+        let level = coherence_level(snapshot);
+        assert!((level - (0.81 + 0.64 + 0.77) / 3.0).abs() < 1e-6);
+        assert_eq!(CoherenceState::from_level(level).short_name(), "Aware");
+    }
+
+    #[test]
+    fn test_current_version_roundtrip_is_not_migrated() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = snapshot_path(Some(tmp.path()));
+
+        let mut snapshot = SessionSnapshot::new("session-roundtrip");
+        snapshot.integration = 0.9;
+        snapshot.previous_session_id = Some("session-before".to_string());
+        snapshot.append_to_trajectory([0.25; NUM_EMBEDDERS]);
+        save_snapshot(&path, &snapshot).unwrap();
+
+        let loaded = load_snapshot(&path).unwrap().expect("snapshot was saved");
+        assert_eq!(loaded.version, SNAPSHOT_FORMAT_VERSION);
+        assert_eq!(loaded.migrated_from, None);
+        assert_eq!(loaded.snapshot.session_id, "session-roundtrip");
+        assert_eq!(loaded.snapshot.integration, 0.9);
+        assert_eq!(
+            loaded.snapshot.previous_session_id.as_deref(),
+            Some("session-before")
+        );
+        assert_eq!(loaded.snapshot.trajectory, snapshot.trajectory);
+    }
+
+    #[test]
+    fn test_missing_file_is_none() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert!(load_snapshot(&snapshot_path(Some(tmp.path())))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_versions_outside_window_fail_fast() {
+        let path = Path::new("session_identity.json");
+
+        let err = parse_snapshot(r#"{"version": 0, "session_id": "old"}"#, path).unwrap_err();
+        assert!(matches!(
+            err,
+            SnapshotFormatError::Unsupported { version: 0, .. }
+        ));
+        assert!(err.to_string().contains("source=clear"));
+        assert_eq!(err.exit_code(), 1);
+
+        let future = format!(r#"{{"version": {}}}"#, SNAPSHOT_FORMAT_VERSION + 1);
+        let err = parse_snapshot(&future, path).unwrap_err();
+        assert!(matches!(err, SnapshotFormatError::TooNew { .. }));
+        assert!(err.to_string().contains("Upgrade"));
+    }
+
+    #[test]
+    fn test_malformed_snapshot_is_corruption() {
+        let path = Path::new("session_identity.json");
+        let err = parse_snapshot(r#"{"version": 2, "session_id": 7}"#, path).unwrap_err();
+        assert!(matches!(
+            err,
+            SnapshotFormatError::Malformed { version: 2, .. }
+        ));
+        assert_eq!(err.exit_code(), 2);
+
+        let err = parse_snapshot("not json", path).unwrap_err();
+        assert_eq!(err.exit_code(), 2);
+    }
+}
@@ -5,12 +5,15 @@
 //! - `restore-identity`: Restore session state from storage (TASK-SESSION-12)
 //! - `persist-identity`: Persist session state to storage (TASK-SESSION-13)
 //!
+//! Snapshots are written in the versioned format described in [`format`].
+//!
 //! # Constitution Reference
 //! - AP-26: Exit codes (0=success, 1=error, 2=corruption)
 //! - ARCH-07: Native Claude Code hooks
 //!
 //! NO BACKWARDS COMPATIBILITY - FAIL FAST WITH ROBUST LOGGING.
 
+pub mod format;
 mod persist;
 mod restore;
 
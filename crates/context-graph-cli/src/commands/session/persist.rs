@@ -1,6 +1,6 @@
 //! session persist-identity CLI command
 //!
-//! TASK-SESSION-13: Persists current session identity to RocksDB.
+//! TASK-SESSION-13: Persists current session identity to the snapshot file.
 //!
 //! # Input (stdin JSON from Claude Code SessionEnd hook)
 //!
@@ -11,6 +11,16 @@
 //! }
 //! ```
 //!
+//! # Staged Memories
+//! Memories stored with `staged: true` during the session are resolved via
+//! the MCP `end_staged_session` tool using `--staging-policy`
+//! (`promote_all` by default). Failures are logged and never block exit.
+//!
+//! # Snapshot File
+//! The snapshot is cached in-process and written to
+//! `<db_path>/session_identity.json` in the current versioned format
+//! (see `session::format`).
+//!
 //! # Output
 //! - Success: SILENT (no stdout) - required by Claude Code SessionEnd semantics
 //! - Error: stderr logging only
@@ -35,16 +45,31 @@ use std::path::PathBuf;
 
 use clap::Args;
 use serde::Deserialize;
-use tracing::{debug, info, warn};
+use tracing::{debug, error, info, warn};
 
+use super::format::{save_snapshot, snapshot_path};
 use crate::commands::hooks::session_state::{store_in_cache, SessionCache, SessionSnapshot};
+use crate::mcp_client::McpClient;
 
 /// Arguments for `session persist-identity` command
 #[derive(Args, Debug)]
 pub struct PersistIdentityArgs {
-    /// Path to RocksDB database directory
+    /// Database directory the snapshot file is written to
     #[arg(long, env = "CONTEXT_GRAPH_DB_PATH")]
     pub db_path: Option<PathBuf>,
+
+    /// What to do with the session's staged memories:
+    /// promote_all | discard_all | promote_above_importance
+    #[arg(
+        long,
+        env = "CONTEXT_GRAPH_STAGING_POLICY",
+        default_value = "promote_all"
+    )]
+    pub staging_policy: String,
+
+    /// Importance threshold for promote_above_importance
+    #[arg(long, env = "CONTEXT_GRAPH_STAGING_THRESHOLD")]
+    pub staging_threshold: Option<f32>,
 }
 
 /// Stdin input from Claude Code SessionEnd hook
@@ -77,8 +102,18 @@ pub async fn persist_identity_command(args: PersistIdentityArgs) -> i32 {
         input.reason, input.session_id
     );
 
+    // Resolve staged memories first: they exist even when the cache is cold
+    let cached = SessionCache::get();
+    let staging_session_id = input
+        .session_id
+        .clone()
+        .or_else(|| cached.as_ref().map(|s| s.session_id.clone()));
+    if let Some(ref sid) = staging_session_id {
+        resolve_staged_memories(&args, sid).await;
+    }
+
     // Get current identity from cache
-    let snapshot = match SessionCache::get() {
+    let snapshot = match cached {
         Some(s) => s,
         None => {
             warn!("persist-identity: Cache is cold, nothing to persist");
@@ -98,26 +133,25 @@ pub async fn persist_identity_command(args: PersistIdentityArgs) -> i32 {
         final_session_id
     );
 
-    // Log DB path for debugging (not used for in-memory cache)
-    let db_path = args.db_path.clone().unwrap_or_else(|| {
-        home_dir()
-            .map(|h| h.join(".context-graph").join("db"))
-            .unwrap_or_else(|| PathBuf::from(".context-graph/db"))
-    });
-    debug!("persist-identity: db_path={:?} (for reference only - using in-memory cache)", db_path);
-
     // Create snapshot with current state from cache
-    // Per PRD v6 Section 14, we use in-memory SessionCache instead of RocksDB
     let mut persist_snapshot = SessionSnapshot::new(&final_session_id);
     persist_snapshot.topic_profile = snapshot.topic_profile;
     persist_snapshot.trajectory = snapshot.trajectory.clone();
     persist_snapshot.integration = snapshot.integration;
     persist_snapshot.reflection = snapshot.reflection;
     persist_snapshot.differentiation = snapshot.differentiation;
+    persist_snapshot.previous_session_id = snapshot.previous_session_id.clone();
 
     // Save snapshot to in-memory cache
     store_in_cache(&persist_snapshot);
 
+    // Save to disk so the next process's restore-identity can pick it up
+    let path = snapshot_path(args.db_path.as_deref());
+    if let Err(e) = save_snapshot(&path, &persist_snapshot) {
+        error!("persist-identity: {}", e);
+        return e.exit_code();
+    }
+
     info!(
         "persist-identity: Successfully saved snapshot for session {}",
         final_session_id
@@ -126,6 +160,44 @@ pub async fn persist_identity_command(args: PersistIdentityArgs) -> i32 {
     0
 }
 
+/// Apply the staging policy to the session's staged memories via MCP.
+///
+/// Non-blocking: a missing server or a failed call is logged and ignored so
+/// SessionEnd always completes.
+async fn resolve_staged_memories(args: &PersistIdentityArgs, session_id: &str) {
+    let client = McpClient::new();
+    match client.is_server_running().await {
+        Ok(true) => {}
+        Ok(false) | Err(_) => {
+            warn!(
+                "persist-identity: MCP server not reachable, staged memories of {} left staged",
+                session_id
+            );
+            return;
+        }
+    }
+
+    match client
+        .end_staged_session(session_id, &args.staging_policy, args.staging_threshold)
+        .await
+    {
+        Ok(result) => {
+            let count = |key: &str| result.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+            info!(
+                "persist-identity: Applied staging policy {} to session {}: promoted={}, discarded={}",
+                args.staging_policy,
+                session_id,
+                count("promotedCount"),
+                count("discardedCount")
+            );
+        }
+        Err(e) => error!(
+            "persist-identity: Failed to apply staging policy to session {}: {}",
+            session_id, e
+        ),
+    }
+}
+
 /// Parse stdin JSON input with graceful fallback
 fn parse_stdin_input() -> PersistInput {
     let mut buffer = String::new();
@@ -140,13 +212,6 @@ fn parse_stdin_input() -> PersistInput {
     PersistInput::default()
 }
 
-/// Get home directory (cross-platform)
-fn home_dir() -> Option<PathBuf> {
-    std::env::var_os("HOME")
-        .or_else(|| std::env::var_os("USERPROFILE"))
-        .map(PathBuf::from)
-}
-
 // =============================================================================
 // Tests - Use in-memory SessionCache per PRD v6
 // =============================================================================
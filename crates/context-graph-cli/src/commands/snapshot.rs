@@ -0,0 +1,213 @@
+//! Snapshot commands - Export memories to and import them from a portable snapshot.
+//!
+//! # Usage
+//!
+//! ```bash
+//! # Export one namespace created during January
+//! context-graph-cli snapshot export --path ./backup --namespace project-a \
+//!     --created-after 2026-01-01T00:00:00Z --created-before 2026-02-01T00:00:00Z
+//!
+//! # Export the members of a topic
+//! context-graph-cli snapshot export --path ./topic-backup --topic-id 5f0c6a3e-...
+//!
+//! # Import into another database, giving conflicting memories new IDs
+//! context-graph-cli snapshot import --path ./backup --conflict-policy re-id
+//! ```
+//!
+//! A snapshot is a directory with `manifest.json`, `memories.cgeb` and
+//! `memories.cgei`. Import goes through the store's normal write path, so
+//! HNSW, SPLADE inverted and content-hash indexes are rebuilt. Topics and the
+//! K-NN graph are not: run `topic detect --force` afterwards and let the MCP
+//! server's graph builder catch up.
+//!
+//! # Prerequisites
+//!
+//! Export opens the database read-only and can run next to the MCP server.
+//! Import writes, so stop the MCP server first.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use clap::{Args, Subcommand};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use context_graph_core::snapshot::{
+    ConflictPolicy, SnapshotExporter, SnapshotFilter, SnapshotImporter,
+};
+use context_graph_core::traits::TeleologicalMemoryStore;
+use context_graph_storage::teleological::{
+    RocksDbTeleologicalStore, StoreAccessMode, TeleologicalStoreConfig,
+};
+
+/// Snapshot subcommands.
+#[derive(Subcommand)]
+pub enum SnapshotCommands {
+    /// Export memories to a snapshot directory
+    Export(ExportArgs),
+    /// Import a snapshot directory into the database
+    Import(ImportArgs),
+}
+
+/// Arguments for `snapshot export`
+#[derive(Args, Debug)]
+pub struct ExportArgs {
+    /// Snapshot directory to create
+    #[arg(long)]
+    pub path: PathBuf,
+
+    /// Only export this namespace (default: all namespaces)
+    #[arg(long)]
+    pub namespace: Option<String>,
+
+    /// Only export memories created at or after this time (RFC 3339)
+    #[arg(long)]
+    pub created_after: Option<DateTime<Utc>>,
+
+    /// Only export memories created before this time (RFC 3339)
+    #[arg(long)]
+    pub created_before: Option<DateTime<Utc>>,
+
+    /// Only export members of this topic (from the last persisted portfolio)
+    #[arg(long)]
+    pub topic_id: Option<Uuid>,
+
+    /// Fingerprints read from the database per page
+    #[arg(long, default_value = "256")]
+    pub batch_size: usize,
+
+    /// Database path
+    #[arg(long, env = "CONTEXT_GRAPH_DATA_DIR")]
+    pub db_path: Option<PathBuf>,
+}
+
+/// Arguments for `snapshot import`
+#[derive(Args, Debug)]
+pub struct ImportArgs {
+    /// Snapshot directory to import
+    #[arg(long)]
+    pub path: PathBuf,
+
+    /// What to do when a memory ID already exists: skip, overwrite or re-id
+    #[arg(long, default_value = "skip")]
+    pub conflict_policy: ConflictPolicy,
+
+    /// Database path
+    #[arg(long, env = "CONTEXT_GRAPH_DATA_DIR")]
+    pub db_path: Option<PathBuf>,
+}
+
+/// Handle snapshot commands
+pub async fn handle_snapshot_command(action: SnapshotCommands) -> i32 {
+    match action {
+        SnapshotCommands::Export(args) => handle_export(args).await,
+        SnapshotCommands::Import(args) => handle_import(args).await,
+    }
+}
+
+fn open_store(
+    db_path: Option<PathBuf>,
+    access_mode: StoreAccessMode,
+) -> Option<Arc<dyn TeleologicalMemoryStore>> {
+    let db_path = db_path.unwrap_or_else(|| PathBuf::from("./contextgraph_data"));
+    let config = TeleologicalStoreConfig::with_access_mode(access_mode);
+    match RocksDbTeleologicalStore::open_with_config(&db_path, config) {
+        Ok(store) => Some(Arc::new(store)),
+        Err(e) => {
+            error!(error = %e, db_path = ?db_path, "Failed to open TeleologicalStore");
+            None
+        }
+    }
+}
+
+async fn handle_export(args: ExportArgs) -> i32 {
+    let Some(store) = open_store(args.db_path, StoreAccessMode::ReadOnly) else {
+        return 1;
+    };
+
+    let mut filter =
+        SnapshotFilter::default().with_time_range(args.created_after, args.created_before);
+    if let Some(namespace) = args.namespace {
+        filter = filter.with_namespace(namespace);
+    }
+    if let Some(topic_id) = args.topic_id {
+        let portfolio = match store.load_latest_topic_portfolio().await {
+            Ok(portfolio) => portfolio,
+            Err(e) => {
+                error!(error = %e, "Failed to load topic portfolio");
+                return 1;
+            }
+        };
+        let members = portfolio.and_then(|p| {
+            p.topics
+                .into_iter()
+                .find(|topic| topic.id == topic_id)
+                .map(|topic| topic.member_memories)
+        });
+        let Some(members) = members else {
+            error!(topic_id = %topic_id, "Topic not found in the persisted portfolio");
+            return 1;
+        };
+        filter = filter.with_topic(topic_id, members);
+    }
+
+    let exporter = SnapshotExporter::new(store, filter).with_batch_size(args.batch_size);
+    match exporter.export(&args.path).await {
+        Ok(manifest) => {
+            info!(
+                memory_count = manifest.memory_count,
+                namespaces = ?manifest.namespace_counts,
+                path = %args.path.display(),
+                "Snapshot export complete"
+            );
+            0
+        }
+        Err(e) => {
+            error!(error = %e, path = %args.path.display(), "Snapshot export failed");
+            1
+        }
+    }
+}
+
+async fn handle_import(args: ImportArgs) -> i32 {
+    let Some(store) = open_store(args.db_path, StoreAccessMode::Exclusive) else {
+        return 1;
+    };
+
+    let importer = SnapshotImporter::new(Arc::clone(&store), args.conflict_policy);
+    let result = importer.import(&args.path).await;
+    if let Err(e) = store.flush().await {
+        error!(error = %e, "Failed to flush store after import");
+        return 1;
+    }
+    if let Err(e) = store.persist_hnsw_indexes_if_available() {
+        error!(error = %e, "Failed to persist HNSW indexes after import");
+        return 1;
+    }
+
+    match result {
+        Ok(report) => {
+            info!(
+                imported = report.imported,
+                overwritten = report.overwritten,
+                skipped = report.skipped,
+                reassigned = report.reassigned,
+                failed = report.failed,
+                "Snapshot import complete"
+            );
+            if report.failed > 0 {
+                warn!(
+                    failed = report.failed,
+                    "Some memories could not be imported"
+                );
+                return 1;
+            }
+            0
+        }
+        Err(e) => {
+            error!(error = %e, path = %args.path.display(), "Snapshot import failed");
+            1
+        }
+    }
+}
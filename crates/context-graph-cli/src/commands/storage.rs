@@ -0,0 +1,145 @@
+//! Storage commands - Integrity check and repair of the teleological store.
+//!
+//! # Usage
+//!
+//! ```bash
+//! # Report inconsistencies between fingerprints and their derived CFs
+//! context-graph-cli storage fsck
+//!
+//! # Regenerate missing entries and delete orphans
+//! context-graph-cli storage fsck --repair
+//! ```
+//!
+//! Exits non-zero if problems remain after the run. Corrupted fingerprints
+//! are reported but never repaired; restore them from a snapshot.
+//!
+//! # Prerequisites
+//!
+//! A plain check opens the database read-only and can run next to the MCP
+//! server. `--repair` writes, so stop the MCP server first; otherwise the
+//! command exits with the server's pid.
+
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
+use tracing::{error, info, warn};
+
+use context_graph_storage::teleological::{
+    check_integrity, repair, IntegrityReport, RepairPolicy, RocksDbTeleologicalStore,
+    StoreAccessMode, TeleologicalStoreConfig,
+};
+
+/// Storage subcommands.
+#[derive(Subcommand)]
+pub enum StorageCommands {
+    /// Check (and optionally repair) teleological column family integrity
+    Fsck(FsckArgs),
+}
+
+/// Arguments for `storage fsck`
+#[derive(Args, Debug)]
+pub struct FsckArgs {
+    /// Regenerate missing derived entries and delete orphans
+    #[arg(long)]
+    pub repair: bool,
+
+    /// Database path
+    #[arg(long, env = "CONTEXT_GRAPH_DATA_DIR")]
+    pub db_path: Option<PathBuf>,
+}
+
+/// Handle storage commands
+pub async fn handle_storage_command(action: StorageCommands) -> i32 {
+    match action {
+        StorageCommands::Fsck(args) => handle_fsck(args),
+    }
+}
+
+fn log_report(report: &IntegrityReport) {
+    for id in &report.corrupted_fingerprints {
+        warn!(%id, "Fingerprint does not decode");
+    }
+    for id in &report.missing_matryoshka {
+        warn!(%id, "Missing E1 Matryoshka entry");
+    }
+    for id in &report.orphan_matryoshka {
+        warn!(%id, "Orphan E1 Matryoshka entry");
+    }
+    for p in &report.missing_postings {
+        warn!(index = p.index.cf_name(), term = p.term_id, id = %p.fingerprint_id, "Missing posting");
+    }
+    for p in &report.dangling_postings {
+        warn!(index = p.index.cf_name(), term = p.term_id, id = %p.fingerprint_id, "Dangling posting");
+    }
+    for (index, term) in &report.corrupted_posting_lists {
+        warn!(
+            index = index.cf_name(),
+            term = *term,
+            "Posting list does not decode"
+        );
+    }
+}
+
+fn handle_fsck(args: FsckArgs) -> i32 {
+    let db_path = args
+        .db_path
+        .unwrap_or_else(|| PathBuf::from("./contextgraph_data"));
+    let access_mode = if args.repair {
+        StoreAccessMode::Exclusive
+    } else {
+        StoreAccessMode::ReadOnly
+    };
+    let config = TeleologicalStoreConfig::with_access_mode(access_mode);
+    let store = match RocksDbTeleologicalStore::open_with_config(&db_path, config) {
+        Ok(store) => store,
+        Err(e) => {
+            error!(error = %e, db_path = ?db_path, "Failed to open TeleologicalStore");
+            return 1;
+        }
+    };
+    let db = store.db_arc();
+
+    let report = match check_integrity(&db) {
+        Ok(report) => report,
+        Err(e) => {
+            error!(error = %e, "Integrity check failed");
+            return 1;
+        }
+    };
+    log_report(&report);
+    info!(
+        fingerprints = report.fingerprints_scanned,
+        issues = report.issue_count(),
+        "Integrity check complete"
+    );
+    if report.is_clean() {
+        return 0;
+    }
+    if !args.repair {
+        warn!(
+            issues = report.issue_count(),
+            "Store is inconsistent; rerun with --repair"
+        );
+        return 1;
+    }
+
+    match repair(&db, RepairPolicy::All) {
+        Ok(outcome) => info!(?outcome, "Repair complete"),
+        Err(e) => {
+            error!(error = %e, "Repair failed");
+            return 1;
+        }
+    }
+    match check_integrity(&db) {
+        Ok(after) if after.is_clean() => 0,
+        Ok(after) => {
+            log_report(&after);
+            warn!(issues = after.issue_count(), "Problems remain after repair");
+            1
+        }
+        Err(e) => {
+            error!(error = %e, "Integrity re-check failed");
+            1
+        }
+    }
+}
@@ -9,6 +9,9 @@
 //! - `hooks`: Claude Code native hooks commands
 //! - `memory`: Memory capture and context injection commands
 //! - `warmup`: Pre-load embedding models into VRAM
+//! - `reembed`: Recompute embedding spaces after a model upgrade
+//! - `snapshot export|import`: Portable memory snapshots
+//! - `maintenance refresh-temporal`: Rewrite stale E2 recency vectors
 //!
 //! This CLI provides hooks integration for Claude Code via .claude/settings.json.
 //! NO BACKWARDS COMPATIBILITY - FAIL FAST WITH ROBUST LOGGING.
@@ -102,6 +105,87 @@ enum Commands {
     /// Example:
     ///   context-graph-cli watch --path ./docs --session-id my-session
     Watch(commands::watch::WatchArgs),
+    /// Recompute selected embedding spaces after a model upgrade
+    ///
+    /// Streams every stored fingerprint, re-runs only the selected embedders
+    /// on its content, and writes it back with updated indexes and model
+    /// version metadata. Progress is checkpointed after every batch, so an
+    /// interrupted run resumes where it stopped.
+    ///
+    /// Example:
+    ///   context-graph-cli reembed --embedders e1,e5 --batch-size 64
+    Reembed(commands::reembed::ReembedArgs),
+    /// Export memories to or import them from a portable snapshot
+    ///
+    /// Snapshots are directories holding a JSON manifest plus `.cgeb`
+    /// records and a `.cgei` index. Export filters by namespace, creation
+    /// time and topic; import resolves ID conflicts with skip, overwrite or
+    /// re-id.
+    ///
+    /// Example:
+    ///   context-graph-cli snapshot export --path ./backup --namespace project-a
+    ///   context-graph-cli snapshot import --path ./backup --conflict-policy re-id
+    Snapshot {
+        #[command(subcommand)]
+        action: commands::snapshot::SnapshotCommands,
+    },
+    /// Bulk import or export typed graph edges
+    ///
+    /// Edges are JSONL records of source, target, edge_type and weight, with
+    /// optional direction, per-embedder scores and agreement bitset. Import
+    /// validates every line, skips duplicates of an earlier (source, target)
+    /// pair and reports rejected lines by number.
+    ///
+    /// Example:
+    ///   context-graph-cli graph import-edges --path ./deps.jsonl
+    ///   context-graph-cli graph export-edges --path ./edges.jsonl --edge-type code_related
+    Graph {
+        #[command(subcommand)]
+        action: commands::graph::GraphCommands,
+    },
+    /// Check and repair storage integrity
+    ///
+    /// Cross-checks stored fingerprints against the E1 Matryoshka entries
+    /// and the E13/E6 sparse inverted indexes. With --repair, missing
+    /// entries are regenerated from the fingerprints and orphans deleted.
+    ///
+    /// Example:
+    ///   context-graph-cli storage fsck
+    ///   context-graph-cli storage fsck --repair
+    Storage {
+        #[command(subcommand)]
+        action: commands::storage::StorageCommands,
+    },
+    /// Periodic maintenance of stored embeddings
+    ///
+    /// refresh-temporal recomputes each memory's E2 recency vector from its
+    /// stored timestamp and rewrites only the vectors that drifted past the
+    /// threshold, along with their HNSW entries. Progress is checkpointed
+    /// after every batch, so an interrupted run resumes where it stopped.
+    ///
+    /// Example:
+    ///   context-graph-cli maintenance refresh-temporal
+    ///   context-graph-cli maintenance refresh-temporal --encoding decay
+    Maintenance {
+        #[command(subcommand)]
+        action: commands::maintenance::MaintenanceCommands,
+    },
+    /// Back up the data directory or restore a backup
+    ///
+    /// A backup is a RocksDB checkpoint plus the HNSW snapshot and a
+    /// manifest of per-file checksums. --incremental-from hard-links SST
+    /// files unchanged since an earlier backup. Restore verifies the
+    /// checksums, rebuilds a fresh data directory and runs the integrity
+    /// checker.
+    ///
+    /// Example:
+    ///   context-graph-cli backup create ./backups/b1
+    ///   context-graph-cli backup create ./backups/b2 --incremental-from ./backups/b1
+    ///   context-graph-cli backup restore ./backups/b2 --to ./contextgraph_data_restored
+    Backup {
+        #[command(subcommand)]
+        action: commands::backup::BackupCommands,
+    },
 }
 
 #[tokio::main]
@@ -133,6 +217,14 @@ async fn main() {
         Commands::Setup(args) => commands::setup::handle_setup(args).await,
         Commands::Warmup(args) => commands::warmup::handle_warmup(args).await,
         Commands::Watch(args) => commands::watch::handle_watch(args).await,
+        Commands::Reembed(args) => commands::reembed::handle_reembed(args).await,
+        Commands::Snapshot { action } => commands::snapshot::handle_snapshot_command(action).await,
+        Commands::Graph { action } => commands::graph::handle_graph_command(action).await,
+        Commands::Storage { action } => commands::storage::handle_storage_command(action).await,
+        Commands::Maintenance { action } => {
+            commands::maintenance::handle_maintenance_command(action).await
+        }
+        Commands::Backup { action } => commands::backup::handle_backup_command(action).await,
     };
 
     std::process::exit(exit_code);
@@ -513,10 +513,13 @@ impl McpClient {
     ///
     /// Searches for cause→effect relationships when the user's prompt
     /// has causal intent (e.g., "why did X happen?").
+    ///
+    /// There is no direction parameter: `search_causes` only searches for
+    /// causes and rejects unknown fields such as `direction`, which it
+    /// always ignored. Use `search_effects` to search the other way.
     pub async fn search_causal_fast(
         &self,
         query: &str,
-        direction: &str,
         top_k: Option<u32>,
         include_content: bool,
     ) -> Result<serde_json::Value, McpClientError> {
@@ -524,7 +527,6 @@ impl McpClient {
             "name": "search_causes",
             "arguments": {
                 "query": query,
-                "direction": direction,
                 "topK": top_k.unwrap_or(3),
                 "includeContent": include_content
             }
@@ -532,7 +534,6 @@ impl McpClient {
 
         debug!(
             query_len = query.len(),
-            direction,
             top_k,
             "Calling MCP search_causes (fast path) - R10 causal intent"
         );
@@ -743,6 +744,48 @@ impl McpClient {
         self.call_tool(params).await
     }
 
+    /// Call the `end_staged_session` MCP tool.
+    ///
+    /// Resolves every memory still staged for a session at SessionEnd.
+    ///
+    /// # Arguments
+    ///
+    /// - `session_id`: Session whose staged memories are resolved
+    /// - `policy`: promote_all, discard_all or promote_above_importance
+    /// - `threshold`: Importance threshold for promote_above_importance
+    ///
+    /// # Returns
+    ///
+    /// The MCP tool result as JSON value with promoted/discarded counts.
+    pub async fn end_staged_session(
+        &self,
+        session_id: &str,
+        policy: &str,
+        threshold: Option<f32>,
+    ) -> Result<serde_json::Value, McpClientError> {
+        let mut arguments = json!({
+            "sessionId": session_id,
+            "policy": policy
+        });
+        if let Some(t) = threshold {
+            arguments["threshold"] = json!(t);
+        }
+
+        let params = json!({
+            "name": "end_staged_session",
+            "arguments": arguments
+        });
+
+        info!(
+            session_id,
+            policy,
+            ?threshold,
+            "Calling MCP end_staged_session"
+        );
+
+        self.call_tool(params).await
+    }
+
     /// Internal method to call an MCP tool.
     ///
     /// Establishes TCP connection, sends JSON-RPC request, and reads response.
@@ -4,6 +4,9 @@
 //! - `test_exit_code_4_empty_session_id`: Empty session_id returns exit code 4
 //! - `test_exit_code_4_malformed_json`: Malformed JSON returns exit code 4
 //! - `test_exit_code_4_missing_required_fields`: Missing fields returns exit code 4
+//! - `test_exit_code_4_payload_schema_fixtures`: Golden invalid payloads return exit
+//!   code 4 with ERR_INVALID_PAYLOAD naming the bad fields
+//! - `test_exit_code_0_payload_schema_fixtures`: Golden valid payloads return exit code 0
 //! - `test_exit_code_0_valid_input_all_hooks`: Valid input returns exit code 0
 //! - `test_exit_code_5_previous_session_not_found`: Non-existent previous session returns exit code 5
 //!
@@ -25,9 +28,18 @@ use tempfile::TempDir;
 
 use super::helpers::{
     create_session_end_input, create_session_start_input, generate_test_session_id,
-    invoke_hook_with_stdin, log_test_evidence, EXIT_INVALID_INPUT, EXIT_SUCCESS,
+    invoke_hook_with_stdin, load_hook_fixture, log_test_evidence, EXIT_INVALID_INPUT,
+    EXIT_SUCCESS,
 };
 
+/// (CLI command, fixture prefix) for every hook with a typed payload schema
+const PAYLOAD_SCHEMA_HOOKS: [(&str, &str); 4] = [
+    ("session-start", "session_start"),
+    ("post-tool", "post_tool_use"),
+    ("prompt-submit", "user_prompt_submit"),
+    ("session-end", "session_end"),
+];
+
 // =============================================================================
 // Exit Code 4: Invalid Input Tests
 // =============================================================================
@@ -197,6 +209,85 @@ fn test_exit_code_4_missing_required_fields() {
     );
 }
 
+/// Test that golden invalid payloads fail fast with a schema error
+///
+/// Schema errors use code ERR_INVALID_PAYLOAD (exit 4), distinct from
+/// malformed JSON (ERR_INVALID_INPUT) and runtime errors (exit 1).
+#[test]
+fn test_exit_code_4_payload_schema_fixtures() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let db_path = temp_dir.path();
+
+    for (hook_cmd, fixture) in PAYLOAD_SCHEMA_HOOKS {
+        let input = load_hook_fixture(&format!("{}_invalid", fixture));
+        let result = invoke_hook_with_stdin(hook_cmd, "fixture-session", &[], &input, db_path);
+
+        assert_eq!(
+            result.exit_code, EXIT_INVALID_INPUT,
+            "{} invalid payload should return exit code 4.\nstdout: {}\nstderr: {}",
+            hook_cmd, result.stdout, result.stderr
+        );
+
+        let error_line = result
+            .stderr
+            .lines()
+            .rev()
+            .find(|line| line.starts_with('{'))
+            .unwrap_or_else(|| {
+                panic!("{} must print JSON error to stderr: {}", hook_cmd, result.stderr)
+            });
+        let error: serde_json::Value =
+            serde_json::from_str(error_line).expect("stderr error must be JSON");
+        assert_eq!(error["code"], "ERR_INVALID_PAYLOAD", "{}: {}", hook_cmd, error);
+        assert_eq!(error["payload_type"], fixture);
+
+        // Every missing/unknown field is named in the message
+        let message = error["message"].as_str().unwrap();
+        for field in error["missing_fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .chain(error["unknown_fields"].as_array().unwrap())
+        {
+            assert!(
+                message.contains(field.as_str().unwrap()),
+                "{} message must name {}: {}",
+                hook_cmd,
+                field,
+                message
+            );
+        }
+
+        log_test_evidence(
+            "test_exit_code_4_payload_schema_fixtures",
+            fixture,
+            "fixture-session",
+            result.exit_code,
+            result.execution_time_ms,
+            false,
+            Some(error),
+        );
+    }
+}
+
+/// Test that golden valid payloads are accepted by every typed hook
+#[test]
+fn test_exit_code_0_payload_schema_fixtures() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let db_path = temp_dir.path();
+
+    for (hook_cmd, fixture) in PAYLOAD_SCHEMA_HOOKS {
+        let input = load_hook_fixture(&format!("{}_valid", fixture));
+        let result = invoke_hook_with_stdin(hook_cmd, "fixture-session", &[], &input, db_path);
+
+        assert_eq!(
+            result.exit_code, EXIT_SUCCESS,
+            "{} valid payload should return exit code 0.\nstdout: {}\nstderr: {}",
+            hook_cmd, result.stdout, result.stderr
+        );
+    }
+}
+
 // =============================================================================
 // Exit Code 0: Valid Input Tests
 // =============================================================================
@@ -7,6 +7,7 @@
 //! # Architecture
 //! 1. `invoke_hook` - Spawns real CLI process
 //! 2. `create_*_input` - Generates valid JSON input
+//! 3. `load_hook_fixture` - Loads golden JSON input from tests/fixtures/hooks
 //!
 //! # Constitution References
 //! - AP-26: Exit codes (0-6)
@@ -379,6 +380,15 @@ pub fn create_session_end_input(
     .to_string()
 }
 
+/// Load a golden HookInput fixture from `tests/fixtures/hooks/<name>.json`
+pub fn load_hook_fixture(name: &str) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/hooks")
+        .join(format!("{}.json", name));
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read fixture {}: {}", path.display(), e))
+}
+
 // =============================================================================
 // Test Evidence Logging
 // =============================================================================
@@ -0,0 +1,477 @@
+//! Weighted multi-space agreement scoring for topic detection.
+//!
+//! Implements the ARCH-09 topic rule directly on per-space cluster
+//! memberships: a group of memories is a topic when the weighted agreement
+//! of the spaces that co-cluster them reaches 2.5.
+//!
+//! # Scoring
+//!
+//! For two memories, pairwise agreement is the sum of the weights of every
+//! space where both sit in the same non-noise cluster. Memories linked by
+//! positive pairwise agreement form a candidate. A candidate's score is
+//!
+//! ```text
+//! weighted_agreement = Σ weight_s * agreement_s
+//! agreement_s        = fraction of member pairs co-clustered in space s
+//! ```
+//!
+//! so a candidate whose members agree everywhere in 3 semantic spaces scores
+//! 3.0, while a loose chain of memories that only agree pairwise is diluted.
+//!
+//! # Weights
+//!
+//! Defaults come from `category_for(embedder).topic_weight()` (semantic 1.0,
+//! relational/structural 0.5). Temporal embedders (E2-E4) are forced to 0.0
+//! per ARCH-04 and cannot be overridden.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::embeddings::category::{category_for, topic_threshold};
+use crate::teleological::Embedder;
+
+use super::membership::{ClusterMembership, NOISE_CLUSTER_ID};
+
+/// Agreement contributed by a single embedding space to a candidate topic.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpaceAgreement {
+    /// The embedding space.
+    pub space: Embedder,
+    /// Topic weight of the space.
+    pub weight: f32,
+    /// Fraction of member pairs co-clustered in this space (0.0..=1.0).
+    pub agreement: f32,
+}
+
+impl SpaceAgreement {
+    /// Weighted contribution to the candidate's score (`weight * agreement`).
+    #[inline]
+    pub fn contribution(&self) -> f32 {
+        self.weight * self.agreement
+    }
+}
+
+/// A group of memories scored against the ARCH-09 topic threshold.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopicCandidate {
+    /// Memory IDs in this candidate, sorted.
+    pub member_memories: Vec<Uuid>,
+    /// Weighted agreement across spaces. Range [0.0, 8.5] with default weights.
+    pub weighted_agreement: f32,
+    /// Spaces with positive contribution, largest contribution first.
+    pub contributing_spaces: Vec<SpaceAgreement>,
+    /// Whether `weighted_agreement` reached the scorer's threshold.
+    pub is_topic: bool,
+}
+
+impl TopicCandidate {
+    /// Number of memories in this candidate.
+    #[inline]
+    pub fn member_count(&self) -> usize {
+        self.member_memories.len()
+    }
+}
+
+/// Scores cross-space co-clustering against the ARCH-09 topic rule.
+///
+/// # Example
+///
+/// ```
+/// use context_graph_core::clustering::{ClusterMembership, TopicAgreementScorer};
+/// use context_graph_core::teleological::Embedder;
+/// use std::collections::HashMap;
+/// use uuid::Uuid;
+///
+/// let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+/// let mut memberships: HashMap<Embedder, Vec<ClusterMembership>> = HashMap::new();
+/// for space in [Embedder::Semantic, Embedder::Causal, Embedder::Code] {
+///     memberships.entry(space).or_default().extend([
+///         ClusterMembership::new(a, space, 0, 1.0, true),
+///         ClusterMembership::new(b, space, 0, 1.0, true),
+///     ]);
+/// }
+///
+/// let topics = TopicAgreementScorer::new().detect_topics(&memberships);
+/// assert_eq!(topics.len(), 1);
+/// assert_eq!(topics[0].weighted_agreement, 3.0);
+/// assert_eq!(topics[0].contributing_spaces.len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TopicAgreementScorer {
+    /// Per-embedder topic weights, indexed by `Embedder::index()`.
+    weights: [f32; 13],
+    /// Minimum weighted agreement for a topic (default 2.5).
+    threshold: f32,
+}
+
+impl Default for TopicAgreementScorer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TopicAgreementScorer {
+    /// Create with category weights and the ARCH-09 threshold (2.5).
+    pub fn new() -> Self {
+        let mut weights = [0.0f32; 13];
+        for embedder in Embedder::all() {
+            weights[embedder.index()] = category_for(embedder).topic_weight();
+        }
+        Self {
+            weights,
+            threshold: topic_threshold(),
+        }
+    }
+
+    /// Override the weight of one embedder.
+    ///
+    /// Negative or non-finite weights become 0.0. Temporal embedders (E2-E4)
+    /// always stay at 0.0 per ARCH-04.
+    #[must_use]
+    pub fn with_weight(mut self, embedder: Embedder, weight: f32) -> Self {
+        self.weights[embedder.index()] =
+            if category_for(embedder).is_temporal() || !weight.is_finite() {
+                0.0
+            } else {
+                weight.max(0.0)
+            };
+        self
+    }
+
+    /// Override the topic threshold (default 2.5).
+    ///
+    /// Non-finite values fall back to the ARCH-09 default.
+    #[must_use]
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = if threshold.is_finite() {
+            threshold
+        } else {
+            topic_threshold()
+        };
+        self
+    }
+
+    /// Get the weight for an embedder.
+    #[inline]
+    pub fn weight(&self, embedder: Embedder) -> f32 {
+        self.weights[embedder.index()]
+    }
+
+    /// Get the topic threshold.
+    #[inline]
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    /// Score every candidate, including those below the threshold.
+    ///
+    /// Candidates are connected components of memories with positive
+    /// pairwise agreement; singletons are never candidates. Results are
+    /// sorted by weighted agreement, highest first.
+    pub fn score(
+        &self,
+        memberships: &HashMap<Embedder, Vec<ClusterMembership>>,
+    ) -> Vec<TopicCandidate> {
+        let mem_clusters = Self::build_mem_clusters_map(memberships);
+        let mut memory_ids: Vec<Uuid> = mem_clusters.keys().copied().collect();
+        memory_ids.sort();
+        let rows: Vec<[i32; 13]> = memory_ids.iter().map(|id| mem_clusters[id]).collect();
+        let n = rows.len();
+
+        // Union-Find over pairs with positive weighted agreement
+        let mut parent: Vec<usize> = (0..n).collect();
+
+        fn find(parent: &mut [usize], i: usize) -> usize {
+            if parent[i] != i {
+                parent[i] = find(parent, parent[i]);
+            }
+            parent[i]
+        }
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                if self.pair_agreement(&rows[i], &rows[j]) > 0.0 {
+                    let (pi, pj) = (find(&mut parent, i), find(&mut parent, j));
+                    if pi != pj {
+                        parent[pi] = pj;
+                    }
+                }
+            }
+        }
+
+        let mut components: HashMap<usize, Vec<usize>> = HashMap::new();
+        for i in 0..n {
+            let root = find(&mut parent, i);
+            components.entry(root).or_default().push(i);
+        }
+
+        let mut candidates: Vec<TopicCandidate> = components
+            .into_values()
+            .filter(|members| members.len() >= 2)
+            .map(|members| self.score_component(&members, &rows, &memory_ids))
+            .collect();
+
+        candidates.sort_by(|a, b| {
+            b.weighted_agreement
+                .total_cmp(&a.weighted_agreement)
+                .then_with(|| b.member_count().cmp(&a.member_count()))
+                .then_with(|| a.member_memories.cmp(&b.member_memories))
+        });
+        candidates
+    }
+
+    /// Candidates whose weighted agreement reaches the threshold.
+    pub fn detect_topics(
+        &self,
+        memberships: &HashMap<Embedder, Vec<ClusterMembership>>,
+    ) -> Vec<TopicCandidate> {
+        self.score(memberships)
+            .into_iter()
+            .filter(|c| c.is_topic)
+            .collect()
+    }
+
+    /// Build map: memory_id -> cluster id per space (noise where absent).
+    fn build_mem_clusters_map(
+        memberships: &HashMap<Embedder, Vec<ClusterMembership>>,
+    ) -> HashMap<Uuid, [i32; 13]> {
+        let mut result: HashMap<Uuid, [i32; 13]> = HashMap::new();
+
+        for (embedder, space_memberships) in memberships {
+            for m in space_memberships {
+                result.entry(m.memory_id).or_insert([NOISE_CLUSTER_ID; 13])[embedder.index()] =
+                    m.cluster_id;
+            }
+        }
+
+        result
+    }
+
+    /// Sum of weights of spaces where both rows share a non-noise cluster.
+    fn pair_agreement(&self, a: &[i32; 13], b: &[i32; 13]) -> f32 {
+        a.iter()
+            .zip(b.iter())
+            .zip(self.weights.iter())
+            .filter(|((ca, cb), _)| **ca != NOISE_CLUSTER_ID && ca == cb)
+            .map(|(_, w)| w)
+            .sum()
+    }
+
+    /// Score one connected component of memory indices.
+    fn score_component(
+        &self,
+        members: &[usize],
+        rows: &[[i32; 13]],
+        memory_ids: &[Uuid],
+    ) -> TopicCandidate {
+        let pairs = (members.len() * (members.len() - 1) / 2) as f32;
+        let mut agreeing_pairs = [0usize; 13];
+
+        for (x, &i) in members.iter().enumerate() {
+            for &j in &members[x + 1..] {
+                for (space, count) in agreeing_pairs.iter_mut().enumerate() {
+                    let (ci, cj) = (rows[i][space], rows[j][space]);
+                    if ci != NOISE_CLUSTER_ID && ci == cj {
+                        *count += 1;
+                    }
+                }
+            }
+        }
+
+        let mut contributing_spaces: Vec<SpaceAgreement> = Embedder::all()
+            .map(|space| SpaceAgreement {
+                space,
+                weight: self.weight(space),
+                agreement: agreeing_pairs[space.index()] as f32 / pairs,
+            })
+            .filter(|s| s.contribution() > 0.0)
+            .collect();
+        contributing_spaces.sort_by(|a, b| b.contribution().total_cmp(&a.contribution()));
+
+        let weighted_agreement: f32 = contributing_spaces.iter().map(|s| s.contribution()).sum();
+        let mut member_memories: Vec<Uuid> = members.iter().map(|&i| memory_ids[i]).collect();
+        member_memories.sort();
+
+        TopicCandidate {
+            member_memories,
+            weighted_agreement,
+            contributing_spaces,
+            is_topic: weighted_agreement >= self.threshold,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Put every memory in `ids` into `cluster_id` for each of `spaces`.
+    fn co_cluster(
+        memberships: &mut HashMap<Embedder, Vec<ClusterMembership>>,
+        ids: &[Uuid],
+        spaces: &[Embedder],
+        cluster_id: i32,
+    ) {
+        for &space in spaces {
+            for &id in ids {
+                memberships
+                    .entry(space)
+                    .or_default()
+                    .push(ClusterMembership::new(id, space, cluster_id, 0.9, true));
+            }
+        }
+    }
+
+    #[test]
+    fn test_exactly_one_candidate_crosses_threshold() {
+        let scorer = TopicAgreementScorer::new();
+        let mut memberships = HashMap::new();
+
+        // 2 semantic + 1 relational = 2.5 -> TOPIC
+        let topic: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        co_cluster(
+            &mut memberships,
+            &topic,
+            &[Embedder::Semantic, Embedder::Code, Embedder::Graph],
+            1,
+        );
+
+        // 2 semantic only = 2.0 -> rejected
+        let semantic_pair: Vec<Uuid> = (0..2).map(|_| Uuid::new_v4()).collect();
+        co_cluster(
+            &mut memberships,
+            &semantic_pair,
+            &[Embedder::Semantic, Embedder::Causal],
+            2,
+        );
+
+        // 1 semantic + relational + structural = 2.0 -> rejected
+        let mixed_pair: Vec<Uuid> = (0..2).map(|_| Uuid::new_v4()).collect();
+        co_cluster(
+            &mut memberships,
+            &mixed_pair,
+            &[Embedder::Semantic, Embedder::Entity, Embedder::Hdc],
+            3,
+        );
+
+        let candidates = scorer.score(&memberships);
+        for c in &candidates {
+            println!(
+                "candidate members={} weighted_agreement={} is_topic={}",
+                c.member_count(),
+                c.weighted_agreement,
+                c.is_topic
+            );
+        }
+
+        assert_eq!(candidates.len(), 3, "all three groups are candidates");
+        let topics: Vec<&TopicCandidate> = candidates.iter().filter(|c| c.is_topic).collect();
+        assert_eq!(topics.len(), 1, "exactly one candidate crosses 2.5");
+
+        let mut expected = topic.clone();
+        expected.sort();
+        assert_eq!(topics[0].member_memories, expected);
+        assert!((topics[0].weighted_agreement - 2.5).abs() < 1e-6);
+
+        let spaces: Vec<Embedder> = topics[0]
+            .contributing_spaces
+            .iter()
+            .map(|s| s.space)
+            .collect();
+        assert_eq!(spaces.len(), 3);
+        assert!(spaces.contains(&Embedder::Semantic));
+        assert!(spaces.contains(&Embedder::Code));
+        assert!(spaces.contains(&Embedder::Graph));
+        assert_eq!(
+            *spaces.last().unwrap(),
+            Embedder::Graph,
+            "relational contributes least"
+        );
+
+        for rejected in candidates.iter().filter(|c| !c.is_topic) {
+            assert!((rejected.weighted_agreement - 2.0).abs() < 1e-6);
+        }
+
+        assert_eq!(scorer.detect_topics(&memberships), vec![topics[0].clone()]);
+
+        println!("[PASS] test_exactly_one_candidate_crosses_threshold");
+    }
+
+    #[test]
+    fn test_temporal_only_agreement_rejected() {
+        let scorer = TopicAgreementScorer::new();
+        let mut memberships = HashMap::new();
+
+        let ids: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        co_cluster(
+            &mut memberships,
+            &ids,
+            &[
+                Embedder::TemporalRecent,
+                Embedder::TemporalPeriodic,
+                Embedder::TemporalPositional,
+            ],
+            7,
+        );
+
+        let candidates = scorer.score(&memberships);
+        assert!(
+            candidates.iter().all(|c| !c.is_topic),
+            "temporal-only agreement must never form a topic"
+        );
+        assert!(scorer.detect_topics(&memberships).is_empty());
+
+        println!("[PASS] test_temporal_only_agreement_rejected");
+    }
+
+    #[test]
+    fn test_temporal_weight_cannot_be_overridden() {
+        let scorer = TopicAgreementScorer::new()
+            .with_weight(Embedder::TemporalRecent, 5.0)
+            .with_weight(Embedder::Graph, 2.0)
+            .with_weight(Embedder::Code, f32::NAN);
+
+        assert_eq!(scorer.weight(Embedder::TemporalRecent), 0.0);
+        assert_eq!(scorer.weight(Embedder::Graph), 2.0);
+        assert_eq!(scorer.weight(Embedder::Code), 0.0);
+        assert_eq!(scorer.weight(Embedder::Semantic), 1.0);
+
+        println!("[PASS] test_temporal_weight_cannot_be_overridden");
+    }
+
+    #[test]
+    fn test_loose_chain_is_diluted() {
+        let scorer = TopicAgreementScorer::new();
+        let mut memberships = HashMap::new();
+
+        // a-b agree in 3 semantic spaces, b-c in 3 others; a and c never agree
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        co_cluster(
+            &mut memberships,
+            &[a, b],
+            &[Embedder::Semantic, Embedder::Causal, Embedder::Code],
+            1,
+        );
+        co_cluster(
+            &mut memberships,
+            &[b, c],
+            &[
+                Embedder::Sparse,
+                Embedder::Contextual,
+                Embedder::LateInteraction,
+            ],
+            1,
+        );
+
+        let candidates = scorer.score(&memberships);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].member_count(), 3);
+        // Each space agrees on 1 of 3 pairs: 6 * 1.0 / 3 = 2.0
+        assert!((candidates[0].weighted_agreement - 2.0).abs() < 1e-6);
+        assert!(!candidates[0].is_topic);
+
+        println!("[PASS] test_loose_chain_is_diluted");
+    }
+}
@@ -10,6 +10,7 @@
 //! - branching_factor: 50
 //! - threshold: 0.3 (adaptive)
 //! - max_node_entries: 50
+//! - max_leaf_entries: 4096 (memory bound, see below)
 //!
 //! # Clustering Feature (CF)
 //!
@@ -27,14 +28,31 @@
 //! - Automatic node splitting when exceeding max_node_entries
 //! - Memory ID tracking for cluster membership queries
 //! - Threshold adaptation for target cluster count
+//! - Global agglomerative pass over leaf CFs ([`BIRCHTree::global_clusters`])
+//! - Re-seeding from batch HDBSCAN results ([`BIRCHTree::merge_from_hdbscan`])
+//!
+//! # Memory Bound
+//!
+//! A leaf entry costs `size_of::<BIRCHEntry>()` (72 bytes on 64-bit) plus
+//! `4 * d` bytes for its linear sum plus 16 bytes per tracked memory ID; at
+//! E1 (1024D) that is ~4.2 KB per entry before IDs. Internal entries carry
+//! the same CF cost but no IDs. The tree never holds more than
+//! `max_leaf_entries` leaf entries: when an insert would exceed it, the
+//! threshold grows by [`THRESHOLD_GROWTH_FACTOR`] and the tree is rebuilt
+//! from its own leaf CFs (the original points are not needed), so CF memory
+//! stays below roughly `2 * max_leaf_entries * (72 + 4 * d)` bytes.
+
+use std::collections::{BTreeMap, HashMap};
 
 use serde::{Deserialize, Serialize};
-use tracing::warn;
+use tracing::{debug, warn};
 use uuid::Uuid;
 
+use crate::embeddings::config::get_dimension;
 use crate::teleological::Embedder;
 
 use super::error::ClusterError;
+use super::membership::ClusterMembership;
 
 // =============================================================================
 // BIRCHParams
@@ -71,14 +89,30 @@ pub struct BIRCHParams {
     /// Maximum entries per leaf node.
     /// When exceeded, node splits.
     pub max_node_entries: usize,
+
+    /// Maximum leaf entries in the whole tree.
+    /// When exceeded, threshold grows and the tree is rebuilt from its CFs.
+    #[serde(default = "default_max_leaf_entries")]
+    pub max_leaf_entries: usize,
+}
+
+fn default_max_leaf_entries() -> usize {
+    DEFAULT_MAX_LEAF_ENTRIES
 }
 
+/// Default bound on leaf entries per tree.
+pub const DEFAULT_MAX_LEAF_ENTRIES: usize = 4096;
+
+/// Factor applied to the threshold when the leaf-entry bound is exceeded.
+pub const THRESHOLD_GROWTH_FACTOR: f32 = 1.5;
+
 impl Default for BIRCHParams {
     fn default() -> Self {
         Self {
             branching_factor: 50, // Per constitution
             threshold: 0.3,       // Per constitution
             max_node_entries: 50, // Per constitution
+            max_leaf_entries: DEFAULT_MAX_LEAF_ENTRIES,
         }
     }
 }
@@ -92,6 +126,7 @@ impl BIRCHParams {
             branching_factor,
             threshold,
             max_node_entries: max_entries,
+            max_leaf_entries: DEFAULT_MAX_LEAF_ENTRIES,
         }
     }
 
@@ -128,6 +163,7 @@ impl BIRCHParams {
             branching_factor: 50,
             threshold,
             max_node_entries: 50,
+            max_leaf_entries: DEFAULT_MAX_LEAF_ENTRIES,
         }
     }
 
@@ -158,6 +194,15 @@ impl BIRCHParams {
         self
     }
 
+    /// Set the tree-wide leaf entry bound.
+    ///
+    /// Value is NOT automatically clamped - use validate() to check.
+    #[must_use]
+    pub fn with_max_leaf_entries(mut self, entries: usize) -> Self {
+        self.max_leaf_entries = entries;
+        self
+    }
+
     /// Validate parameters.
     ///
     /// Fails fast with descriptive error messages.
@@ -168,6 +213,7 @@ impl BIRCHParams {
     /// - branching_factor < 2
     /// - threshold <= 0.0 or threshold is NaN/Infinity
     /// - max_node_entries < branching_factor
+    /// - max_leaf_entries < max_node_entries
     ///
     /// # Example
     ///
@@ -199,6 +245,13 @@ impl BIRCHParams {
             )));
         }
 
+        if self.max_leaf_entries < self.max_node_entries {
+            return Err(ClusterError::invalid_parameter(format!(
+                "max_leaf_entries ({}) must be >= max_node_entries ({}). The tree must hold at least one full leaf.",
+                self.max_leaf_entries, self.max_node_entries
+            )));
+        }
+
         Ok(())
     }
 }
@@ -451,6 +504,54 @@ impl ClusteringFeature {
 
         variance.sqrt() <= threshold
     }
+
+    /// Radius the union of this CF and `other` would have.
+    ///
+    /// Returns `f32::INFINITY` if both are non-empty and dimensions differ.
+    pub fn merged_radius(&self, other: &ClusteringFeature) -> f32 {
+        if other.n == 0 {
+            return self.radius();
+        }
+        if self.n == 0 {
+            return other.radius();
+        }
+        if self.ls.len() != other.ls.len() {
+            return f32::INFINITY;
+        }
+
+        let n = (self.n + other.n) as f32;
+        let centroid_norm_sq: f32 = self
+            .ls
+            .iter()
+            .zip(other.ls.iter())
+            .map(|(a, b)| {
+                let c = (a + b) / n;
+                c * c
+            })
+            .sum();
+        let variance = (self.ss + other.ss) / n - centroid_norm_sq;
+
+        if variance < 0.0 || variance.is_nan() {
+            0.0
+        } else {
+            variance.sqrt()
+        }
+    }
+
+    /// Squared Euclidean distance from this CF's centroid to `point`.
+    ///
+    /// Avoids allocating the centroid. Caller guarantees matching dimensions.
+    fn centroid_distance_sq(&self, point: &[f32]) -> f32 {
+        let n = self.n.max(1) as f32;
+        self.ls
+            .iter()
+            .zip(point.iter())
+            .map(|(s, p)| {
+                let d = s / n - p;
+                d * d
+            })
+            .sum()
+    }
 }
 
 #[cfg(test)]
@@ -657,12 +758,29 @@ impl BIRCHEntry {
         self.memory_ids.push(memory_id);
     }
 
+    /// Absorb another leaf entry (CF and memory IDs) into this one.
+    pub fn merge_entry(&mut self, other: BIRCHEntry) {
+        // Safe to ignore error since we validate dimensions at tree level
+        let _ = self.cf.merge(&other.cf);
+        self.memory_ids.extend(other.memory_ids);
+    }
+
     /// Get the number of points in this entry.
     #[inline]
     #[must_use]
     pub fn n(&self) -> u32 {
         self.cf.n
     }
+
+    /// Approximate heap + inline bytes held by this entry, excluding its child.
+    ///
+    /// `size_of::<BIRCHEntry>() + 4 * d + 16 * memory_ids`, using capacities.
+    #[must_use]
+    pub fn memory_bytes(&self) -> usize {
+        std::mem::size_of::<BIRCHEntry>()
+            + self.cf.ls.capacity() * std::mem::size_of::<f32>()
+            + self.memory_ids.capacity() * std::mem::size_of::<Uuid>()
+    }
 }
 
 // =============================================================================
@@ -782,7 +900,14 @@ impl BIRCHNode {
 /// BIRCH CF-tree for incremental clustering.
 ///
 /// Implements O(log n) insertion via tree traversal. When nodes overflow
-/// (exceed max_node_entries), they are split using the farthest-pair algorithm.
+/// (leaves beyond max_node_entries, internal nodes beyond branching_factor),
+/// they are split using the farthest-pair algorithm and the split propagates
+/// upward, growing a new root when the old one overflows.
+///
+/// Each tree is designated to one embedding space (E1 by default). Leaf CFs
+/// are the online micro-clusters; [`BIRCHTree::global_clusters`] turns them
+/// into topic-level clusters and [`BIRCHTree::merge_from_hdbscan`] re-seeds
+/// the tree after a periodic batch run.
 ///
 /// # Architecture
 ///
@@ -809,12 +934,54 @@ pub struct BIRCHTree {
     params: BIRCHParams,
     root: BIRCHNode,
     dimension: usize,
+    space: Embedder,
     total_points: usize,
+    leaf_entries: usize,
 }
 
+/// A cluster produced by the global phase over leaf CFs.
+#[derive(Debug, Clone)]
+pub struct BIRCHGlobalCluster {
+    /// Merged clustering feature of all leaf entries in this cluster.
+    pub cf: ClusteringFeature,
+    /// Memory IDs of all points in this cluster.
+    pub memory_ids: Vec<Uuid>,
+}
+
+impl BIRCHGlobalCluster {
+    /// Cluster centroid (LS / n).
+    #[must_use]
+    pub fn centroid(&self) -> Vec<f32> {
+        self.cf.centroid()
+    }
+
+    /// Number of points in this cluster.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cf.n as usize
+    }
+
+    /// Check if cluster has no points.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cf.n == 0
+    }
+}
+
+/// Minimum ratio between consecutive merge heights to count as a cluster gap.
+///
+/// Without `k_hint`, [`BIRCHTree::global_clusters`] returns a single cluster
+/// when no cut clears this ratio.
+pub const GLOBAL_MIN_GAP_RATIO: f32 = 1.5;
+
 impl BIRCHTree {
     /// Create a new empty BIRCH tree.
     ///
+    /// The tree is designated to E1 (Semantic); use [`BIRCHTree::for_space`]
+    /// to cluster another embedding space.
+    ///
     /// # Arguments
     ///
     /// * `params` - BIRCH parameters (branching_factor, threshold, max_node_entries)
@@ -847,36 +1014,35 @@ impl BIRCHTree {
             params,
             root: BIRCHNode::new_leaf(),
             dimension,
+            space: Embedder::Semantic,
             total_points: 0,
+            leaf_entries: 0,
         })
     }
 
-    /// Insert a point into the tree.
-    ///
-    /// Returns the cluster index (0-based) for this point.
-    ///
-    /// # Arguments
-    ///
-    /// * `embedding` - The embedding vector
-    /// * `memory_id` - UUID to track this memory
+    /// Create a tree designated to an embedding space, sized to its dimension.
     ///
     /// # Errors
     ///
-    /// Returns `ClusterError::DimensionMismatch` if embedding dimension doesn't match.
-    /// Returns `ClusterError::InvalidParameter` if embedding contains NaN/Infinity.
+    /// Returns `ClusterError::InvalidParameter` if params.validate() fails.
     ///
     /// # Example
     ///
     /// ```
     /// use context_graph_core::clustering::birch::{BIRCHTree, birch_defaults};
-    /// use uuid::Uuid;
+    /// use context_graph_core::teleological::Embedder;
     ///
-    /// let mut tree = BIRCHTree::new(birch_defaults(), 3).unwrap();
-    /// let id = Uuid::new_v4();
-    /// let cluster_idx = tree.insert(&[1.0, 2.0, 3.0], id).unwrap();
+    /// let tree = BIRCHTree::for_space(birch_defaults(), Embedder::Semantic).unwrap();
+    /// assert_eq!(tree.dimension(), 1024);
     /// ```
-    pub fn insert(&mut self, embedding: &[f32], memory_id: Uuid) -> Result<usize, ClusterError> {
-        // Validate dimension
+    pub fn for_space(params: BIRCHParams, embedder: Embedder) -> Result<Self, ClusterError> {
+        let mut tree = Self::new(params, get_dimension(embedder))?;
+        tree.space = embedder;
+        Ok(tree)
+    }
+
+    /// Validate an embedding against the tree dimension (finite values only).
+    fn validate_embedding(&self, embedding: &[f32]) -> Result<(), ClusterError> {
         if embedding.len() != self.dimension {
             return Err(ClusterError::dimension_mismatch(
                 self.dimension,
@@ -894,327 +1060,457 @@ impl BIRCHTree {
             }
         }
 
-        // Insert into tree
-        let cluster_idx = self.insert_recursive(embedding, memory_id);
+        Ok(())
+    }
 
-        // Check if root needs splitting
-        if self.root.entries.len() > self.params.max_node_entries {
-            self.split_root();
-        }
+    /// Insert a point into the tree.
+    ///
+    /// Returns the index of the point's entry within its leaf node.
+    ///
+    /// # Arguments
+    ///
+    /// * `embedding` - The embedding vector
+    /// * `memory_id` - UUID to track this memory
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClusterError::DimensionMismatch` if embedding dimension doesn't match.
+    /// Returns `ClusterError::InvalidParameter` if embedding contains NaN/Infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use context_graph_core::clustering::birch::{BIRCHTree, birch_defaults};
+    /// use uuid::Uuid;
+    ///
+    /// let mut tree = BIRCHTree::new(birch_defaults(), 3).unwrap();
+    /// let id = Uuid::new_v4();
+    /// let cluster_idx = tree.insert(&[1.0, 2.0, 3.0], id).unwrap();
+    /// ```
+    pub fn insert(&mut self, embedding: &[f32], memory_id: Uuid) -> Result<usize, ClusterError> {
+        self.validate_embedding(embedding)?;
 
+        let cluster_idx = self.insert_entry(BIRCHEntry::from_point(embedding, memory_id));
         self.total_points += 1;
+
+        if self.leaf_entries > self.params.max_leaf_entries {
+            self.rebuild_within_bound();
+            return Ok(self.leaf_index_of(memory_id).unwrap_or(0));
+        }
+
         Ok(cluster_idx)
     }
 
-    /// Recursive insertion into a node.
+    /// Insert a (possibly multi-point) leaf entry, growing a new root on overflow.
     ///
-    /// Returns the cluster index for the inserted point.
-    fn insert_recursive(&mut self, embedding: &[f32], memory_id: Uuid) -> usize {
-        if self.root.is_leaf {
-            // Find closest entry or create new one
-            if let Some(idx) = self.find_closest_fitting_entry(&self.root, embedding) {
-                // Merge into existing entry - need to work around borrow checker
-                let entry = &mut self.root.entries[idx];
-                entry.merge_point(embedding, memory_id);
-                idx
-            } else {
-                // Create new entry
-                let new_entry = BIRCHEntry::from_point(embedding, memory_id);
-                let new_idx = self.root.entries.len();
-                self.root.entries.push(new_entry);
+    /// Returns the entry's index within its leaf node at insertion time.
+    fn insert_entry(&mut self, entry: BIRCHEntry) -> usize {
+        let (leaf_idx, created, split) = Self::insert_into(&mut self.root, entry, &self.params);
 
-                // Check if leaf needs splitting (handled at caller level for root)
-                new_idx
-            }
-        } else {
-            // Non-leaf: find closest child and descend
-            let closest_idx = self.root.find_closest(embedding).unwrap_or(0);
+        if created {
+            self.leaf_entries += 1;
+        }
 
-            // Take child out temporarily to avoid borrow conflicts
-            let mut child_opt = self.root.entries[closest_idx].child.take();
+        if let Some(sibling) = split {
+            let old_root = std::mem::replace(&mut self.root, BIRCHNode::new_internal());
+            let old_cf = old_root.total_cf();
+            let sibling_cf = sibling.total_cf();
+            self.root
+                .entries
+                .push(BIRCHEntry::with_child(old_cf, old_root));
+            self.root
+                .entries
+                .push(BIRCHEntry::with_child(sibling_cf, sibling));
+        }
 
-            if let Some(ref mut child) = child_opt {
-                // Insert into child (now we have exclusive access)
-                let threshold = self.params.threshold;
-                let cluster_idx = Self::insert_into_node_owned(child, embedding, memory_id, threshold);
+        leaf_idx
+    }
 
-                // Check if child needs splitting before putting back
-                let needs_split = child.entries.len() > self.params.max_node_entries;
+    /// Insert `entry` below `node`.
+    ///
+    /// Returns the entry's leaf index, whether it opened a new leaf entry
+    /// (rather than being absorbed), and the sibling split off `node` if it
+    /// overflowed.
+    fn insert_into(
+        node: &mut BIRCHNode,
+        entry: BIRCHEntry,
+        params: &BIRCHParams,
+    ) -> (usize, bool, Option<BIRCHNode>) {
+        let (leaf_idx, created) = if node.is_leaf {
+            match Self::closest_fitting(&node.entries, &entry.cf, params.threshold) {
+                Some(idx) => {
+                    node.entries[idx].merge_entry(entry);
+                    (idx, false)
+                }
+                None => {
+                    node.entries.push(entry);
+                    (node.entries.len() - 1, true)
+                }
+            }
+        } else {
+            let idx = Self::closest_entry(&node.entries, &entry.cf.centroid());
+            let parent = &mut node.entries[idx];
+            // Safe to ignore error since we validate dimensions at tree level
+            let _ = parent.cf.merge(&entry.cf);
+
+            let Some(child) = parent.child.as_mut() else {
+                warn!(
+                    entry_idx = idx,
+                    "BIRCH insert: internal entry has no child, skipping"
+                );
+                return (0, false, None);
+            };
+
+            let (leaf_idx, created, split) = Self::insert_into(child, entry, params);
+            if let Some(sibling) = split {
+                parent.cf = child.total_cf();
+                let sibling_cf = sibling.total_cf();
+                node.entries
+                    .push(BIRCHEntry::with_child(sibling_cf, sibling));
+            }
+            (leaf_idx, created)
+        };
 
-                // Put child back
-                self.root.entries[closest_idx].child = child_opt;
+        let capacity = if node.is_leaf {
+            params.max_node_entries
+        } else {
+            params.branching_factor
+        };
+        let split = (node.entries.len() > capacity).then(|| Self::split_node(node));
 
-                // Update CF in parent
-                let _ = self.root.entries[closest_idx].cf.add_point(embedding);
+        (leaf_idx, created, split)
+    }
 
-                // Handle split if needed
-                if needs_split {
-                    self.split_child(closest_idx);
-                }
+    /// Find the closest entry whose merge with `cf` stays within threshold.
+    fn closest_fitting(
+        entries: &[BIRCHEntry],
+        cf: &ClusteringFeature,
+        threshold: f32,
+    ) -> Option<usize> {
+        let centroid = cf.centroid();
 
-                cluster_idx
-            } else {
-                // Put None back (shouldn't happen in well-formed tree)
-                self.root.entries[closest_idx].child = child_opt;
-                0
-            }
-        }
+        entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.cf.merged_radius(cf) <= threshold)
+            .map(|(i, entry)| (i, entry.cf.centroid_distance_sq(&centroid)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
     }
 
-    /// Insert into a node without requiring &self (avoids borrow conflicts).
-    /// Uses explicit threshold parameter instead of self.params.
-    fn insert_into_node_owned(
-        node: &mut BIRCHNode,
-        embedding: &[f32],
-        memory_id: Uuid,
-        threshold: f32,
-    ) -> usize {
-        if node.is_leaf {
-            // Find closest fitting entry or create new
-            if let Some(idx) = Self::find_closest_fitting_in_node(node, embedding, threshold) {
-                node.entries[idx].merge_point(embedding, memory_id);
-                idx
+    /// Find the entry whose centroid is closest to `point` (0 if empty).
+    fn closest_entry(entries: &[BIRCHEntry], point: &[f32]) -> usize {
+        entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| (i, entry.cf.centroid_distance_sq(point)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map_or(0, |(i, _)| i)
+    }
+
+    /// Split an overflowing node around its farthest pair of entries.
+    ///
+    /// `node` keeps the entries closer to the first seed; the rest are
+    /// returned as a new sibling of the same kind.
+    fn split_node(node: &mut BIRCHNode) -> BIRCHNode {
+        let (seed1, seed2) = Self::find_farthest_pair(&node.entries);
+        let c1 = node.entries[seed1].cf.centroid();
+        let c2 = node.entries[seed2].cf.centroid();
+
+        let mut sibling = BIRCHNode {
+            is_leaf: node.is_leaf,
+            entries: Vec::new(),
+        };
+
+        for (i, entry) in std::mem::take(&mut node.entries).into_iter().enumerate() {
+            let keep = if i == seed1 {
+                true
+            } else if i == seed2 {
+                false
             } else {
-                let new_entry = BIRCHEntry::from_point(embedding, memory_id);
-                let new_idx = node.entries.len();
-                node.entries.push(new_entry);
-                new_idx
-            }
-        } else {
-            // Non-leaf: descend to closest child
-            let closest_idx = node.find_closest(embedding).unwrap_or(0);
+                entry.cf.centroid_distance_sq(&c1) <= entry.cf.centroid_distance_sq(&c2)
+            };
 
-            if let Some(ref mut child) = node.entries[closest_idx].child {
-                let cluster_idx = Self::insert_into_node_owned(child, embedding, memory_id, threshold);
-                let _ = node.entries[closest_idx].cf.add_point(embedding);
-                cluster_idx
+            if keep {
+                node.entries.push(entry);
             } else {
-                0
+                sibling.entries.push(entry);
             }
         }
+
+        sibling
     }
 
-    /// Find closest fitting entry in a node (static version, no &self).
-    fn find_closest_fitting_in_node(
-        node: &BIRCHNode,
-        embedding: &[f32],
-        threshold: f32,
-    ) -> Option<usize> {
-        if node.entries.is_empty() {
-            return None;
+    /// Find farthest pair of entries (for split seeds).
+    fn find_farthest_pair(entries: &[BIRCHEntry]) -> (usize, usize) {
+        if entries.len() < 2 {
+            return (0, entries.len().saturating_sub(1));
         }
 
-        let mut best_idx = None;
-        let mut best_dist = f32::INFINITY;
+        let centroids: Vec<Vec<f32>> = entries.iter().map(|e| e.cf.centroid()).collect();
+        let mut max_dist = -1.0f32;
+        let mut pair = (0, 1);
 
-        for (i, entry) in node.entries.iter().enumerate() {
-            if entry.cf.would_fit(embedding, threshold) {
-                let point_cf = ClusteringFeature::from_point(embedding);
-                match entry.cf.distance(&point_cf) {
-                    Ok(dist) => {
-                        if dist < best_dist {
-                            best_dist = dist;
-                            best_idx = Some(i);
-                        }
-                    }
-                    Err(e) => {
-                        warn!(entry_idx = i, error = %e, "BIRCH find_closest_fitting_entry: distance error, skipping entry");
-                    }
+        for i in 0..centroids.len() {
+            for j in (i + 1)..centroids.len() {
+                let dist = squared_euclidean(&centroids[i], &centroids[j]);
+                if dist > max_dist {
+                    max_dist = dist;
+                    pair = (i, j);
                 }
             }
         }
 
-        best_idx
+        pair
     }
 
-    /// Find closest entry that can fit the point within threshold.
-    #[allow(dead_code)]
-    fn find_closest_fitting_entry(&self, node: &BIRCHNode, embedding: &[f32]) -> Option<usize> {
-        self.find_closest_fitting_entry_in_node(node, embedding)
-    }
+    /// Grow the threshold and rebuild from leaf CFs until the leaf bound holds.
+    fn rebuild_within_bound(&mut self) {
+        while self.leaf_entries > self.params.max_leaf_entries {
+            let before = self.leaf_entries;
+            self.params.threshold *= THRESHOLD_GROWTH_FACTOR;
 
-    /// Find closest entry in a node that can fit the point.
-    fn find_closest_fitting_entry_in_node(
-        &self,
-        node: &BIRCHNode,
-        embedding: &[f32],
-    ) -> Option<usize> {
-        if node.entries.is_empty() {
-            return None;
+            let mut entries = Vec::with_capacity(before);
+            Self::drain_leaf_entries(
+                std::mem::replace(&mut self.root, BIRCHNode::new_leaf()),
+                &mut entries,
+            );
+            self.leaf_entries = 0;
+            for entry in entries {
+                self.insert_entry(entry);
+            }
+
+            debug!(
+                before,
+                after = self.leaf_entries,
+                threshold = self.params.threshold,
+                "BIRCH rebuild: leaf entry bound exceeded, threshold grown"
+            );
         }
+    }
 
-        let mut best_idx = None;
-        let mut best_dist = f32::INFINITY;
+    /// Move every leaf entry out of `node`.
+    fn drain_leaf_entries(node: BIRCHNode, out: &mut Vec<BIRCHEntry>) {
+        if node.is_leaf {
+            out.extend(node.entries);
+        } else {
+            for entry in node.entries {
+                if let Some(child) = entry.child {
+                    Self::drain_leaf_entries(*child, out);
+                }
+            }
+        }
+    }
 
-        for (i, entry) in node.entries.iter().enumerate() {
-            if entry.cf.would_fit(embedding, self.params.threshold) {
-                let point_cf = ClusteringFeature::from_point(embedding);
-                match entry.cf.distance(&point_cf) {
-                    Ok(dist) => {
-                        if dist < best_dist {
-                            best_dist = dist;
-                            best_idx = Some(i);
-                        }
-                    }
-                    Err(e) => {
-                        warn!(entry_idx = i, error = %e, "BIRCH find_closest_in_tree: distance error, skipping entry");
-                    }
+    /// Collect references to every leaf entry, in tree order.
+    fn collect_leaf_entries<'a>(node: &'a BIRCHNode, out: &mut Vec<&'a BIRCHEntry>) {
+        if node.is_leaf {
+            out.extend(node.entries.iter());
+        } else {
+            for entry in &node.entries {
+                if let Some(ref child) = entry.child {
+                    Self::collect_leaf_entries(child, out);
                 }
             }
         }
+    }
 
-        best_idx
+    /// Index of the entry holding `memory_id` within its leaf node.
+    fn leaf_index_of(&self, memory_id: Uuid) -> Option<usize> {
+        fn search(node: &BIRCHNode, memory_id: Uuid) -> Option<usize> {
+            if node.is_leaf {
+                return node
+                    .entries
+                    .iter()
+                    .position(|e| e.memory_ids.contains(&memory_id));
+            }
+            node.entries
+                .iter()
+                .filter_map(|e| e.child.as_deref())
+                .find_map(|child| search(child, memory_id))
+        }
+        search(&self.root, memory_id)
     }
 
-    /// Split root node, increasing tree height.
-    fn split_root(&mut self) {
-        if self.root.entries.len() <= self.params.max_node_entries {
-            return;
+    /// Global clustering phase over leaf CFs.
+    ///
+    /// Runs Ward-linkage agglomeration (nearest-neighbor chain, O(L²·d) for L
+    /// leaf entries) on the leaf CFs, treating each as a weighted point, then
+    /// cuts the dendrogram at the largest ratio between consecutive merge
+    /// heights. With `k_hint > 0` the cut is restricted to
+    /// `[k_hint / 2, 2 * k_hint]` clusters; with `k_hint == 0` any count is
+    /// allowed and a single cluster is returned when no gap reaches
+    /// [`GLOBAL_MIN_GAP_RATIO`].
+    ///
+    /// Clusters are returned largest first.
+    #[must_use]
+    pub fn global_clusters(&self, k_hint: usize) -> Vec<BIRCHGlobalCluster> {
+        let mut leaves = Vec::with_capacity(self.leaf_entries);
+        Self::collect_leaf_entries(&self.root, &mut leaves);
+        let m = leaves.len();
+        if m == 0 {
+            return Vec::new();
         }
 
-        let (seed1, seed2) = self.find_farthest_pair(&self.root.entries);
+        let mut cfs: Vec<ClusteringFeature> = leaves.iter().map(|e| e.cf.clone()).collect();
+        let mut centroids: Vec<Vec<f32>> = cfs.iter().map(|cf| cf.centroid()).collect();
+        let mut active = vec![true; m];
+        let mut merges: Vec<(f32, usize, usize)> = Vec::with_capacity(m - 1);
+        let mut chain: Vec<usize> = Vec::new();
 
-        // Create two new nodes
-        let mut node1 = BIRCHNode {
-            is_leaf: self.root.is_leaf,
-            entries: Vec::new(),
-        };
-        let mut node2 = BIRCHNode {
-            is_leaf: self.root.is_leaf,
-            entries: Vec::new(),
+        let ward = |cfs: &[ClusteringFeature], centroids: &[Vec<f32>], a: usize, b: usize| {
+            let (na, nb) = (cfs[a].n as f32, cfs[b].n as f32);
+            (2.0 * na * nb / (na + nb) * squared_euclidean(&centroids[a], &centroids[b])).sqrt()
         };
 
-        // Distribute entries
-        for (i, entry) in self.root.entries.drain(..).enumerate() {
-            if i == seed1 {
-                node1.entries.push(entry);
-            } else if i == seed2 {
-                node2.entries.push(entry);
-            } else {
-                // Assign to closer seed
-                let cf1 = if node1.entries.is_empty() {
-                    ClusteringFeature::new(self.dimension)
-                } else {
-                    node1.total_cf()
-                };
-                let cf2 = if node2.entries.is_empty() {
-                    ClusteringFeature::new(self.dimension)
-                } else {
-                    node2.total_cf()
-                };
-
-                let dist1 = entry.cf.distance(&cf1).unwrap_or(f32::MAX);
-                let dist2 = entry.cf.distance(&cf2).unwrap_or(f32::MAX);
-
-                if dist1 <= dist2 {
-                    node1.entries.push(entry);
-                } else {
-                    node2.entries.push(entry);
+        while merges.len() + 1 < m {
+            if chain.is_empty() {
+                chain.extend(active.iter().position(|&a| a));
+            }
+            let top = chain[chain.len() - 1];
+            let prev = chain.len().checked_sub(2).map(|i| chain[i]);
+
+            // Prefer the previous chain element on ties so the chain terminates
+            let mut nearest = prev;
+            let mut nearest_dist = prev.map_or(f32::INFINITY, |p| ward(&cfs, &centroids, top, p));
+            for j in (0..m).filter(|&j| active[j] && j != top && Some(j) != prev) {
+                let dist = ward(&cfs, &centroids, top, j);
+                if dist < nearest_dist {
+                    nearest_dist = dist;
+                    nearest = Some(j);
                 }
             }
+
+            let Some(nearest) = nearest else {
+                break;
+            };
+
+            if Some(nearest) == prev {
+                chain.truncate(chain.len() - 2);
+                let (keep, gone) = (top.min(nearest), top.max(nearest));
+                let gone_cf = std::mem::replace(&mut cfs[gone], ClusteringFeature::new(0));
+                let _ = cfs[keep].merge(&gone_cf);
+                centroids[keep] = cfs[keep].centroid();
+                active[gone] = false;
+                merges.push((nearest_dist, keep, gone));
+            } else {
+                chain.push(nearest);
+            }
         }
 
-        // Create new root with two children
-        let cf1 = node1.total_cf();
-        let cf2 = node2.total_cf();
+        merges.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let heights: Vec<f32> = merges.iter().map(|&(h, _, _)| h).collect();
+        let n_clusters = select_cluster_count(&heights, k_hint);
 
-        let entry1 = BIRCHEntry::with_child(cf1, node1);
-        let entry2 = BIRCHEntry::with_child(cf2, node2);
+        // Replay the lowest merges with union-find to label leaf entries
+        let mut parent: Vec<usize> = (0..m).collect();
+        fn find(parent: &mut [usize], mut x: usize) -> usize {
+            while parent[x] != x {
+                parent[x] = parent[parent[x]];
+                x = parent[x];
+            }
+            x
+        }
+        for &(_, a, b) in &merges[..m - n_clusters] {
+            let (ra, rb) = (find(&mut parent, a), find(&mut parent, b));
+            parent[rb] = ra;
+        }
 
-        self.root = BIRCHNode::new_internal();
-        self.root.entries.push(entry1);
-        self.root.entries.push(entry2);
-    }
+        let mut root_to_cluster: HashMap<usize, usize> = HashMap::new();
+        let mut clusters: Vec<BIRCHGlobalCluster> = Vec::new();
+        for (i, leaf) in leaves.iter().enumerate() {
+            let root = find(&mut parent, i);
+            let idx = *root_to_cluster.entry(root).or_insert_with(|| {
+                clusters.push(BIRCHGlobalCluster {
+                    cf: ClusteringFeature::new(self.dimension),
+                    memory_ids: Vec::new(),
+                });
+                clusters.len() - 1
+            });
+            let _ = clusters[idx].cf.merge(&leaf.cf);
+            clusters[idx].memory_ids.extend_from_slice(&leaf.memory_ids);
+        }
 
-    /// Split a child node at the given index.
-    fn split_child(&mut self, child_idx: usize) {
-        let child = match self.root.entries[child_idx].child.take() {
-            Some(c) => c,
-            None => return,
-        };
+        clusters.sort_by(|a, b| b.cf.n.cmp(&a.cf.n));
+        clusters
+    }
 
-        if child.entries.len() <= self.params.max_node_entries {
-            self.root.entries[child_idx].child = Some(child);
-            return;
+    /// Re-seed the tree from a batch HDBSCAN result.
+    ///
+    /// Discards the current tree and rebuilds it from `embeddings`: points of
+    /// each HDBSCAN cluster are first summarized into threshold-sized CFs
+    /// within that cluster (so seeded leaf CFs never straddle batch cluster
+    /// boundaries), then inserted as entries; noise points are inserted
+    /// individually. `memberships[i]` must describe `embeddings[i]`, as
+    /// returned by [`super::HDBSCANClusterer::fit`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClusterError::InvalidParameter` if lengths differ, a
+    /// membership belongs to another space, or an embedding has NaN/Infinity.
+    /// Returns `ClusterError::DimensionMismatch` if an embedding has the wrong
+    /// dimension. The tree is left untouched on error.
+    pub fn merge_from_hdbscan(
+        &mut self,
+        embeddings: &[Vec<f32>],
+        memberships: &[ClusterMembership],
+    ) -> Result<(), ClusterError> {
+        if embeddings.len() != memberships.len() {
+            return Err(ClusterError::invalid_parameter(format!(
+                "embeddings ({}) and memberships ({}) must have the same length",
+                embeddings.len(),
+                memberships.len()
+            )));
         }
 
-        let (seed1, seed2) = self.find_farthest_pair(&child.entries);
-
-        let mut node1 = BIRCHNode {
-            is_leaf: child.is_leaf,
-            entries: Vec::new(),
-        };
-        let mut node2 = BIRCHNode {
-            is_leaf: child.is_leaf,
-            entries: Vec::new(),
-        };
+        let mut clusters: BTreeMap<i32, Vec<usize>> = BTreeMap::new();
+        let mut noise = Vec::new();
+        for (i, (embedding, membership)) in embeddings.iter().zip(memberships).enumerate() {
+            if membership.space != self.space {
+                return Err(ClusterError::invalid_parameter(format!(
+                    "memberships[{}] is for {:?} but this tree clusters {:?}",
+                    i, membership.space, self.space
+                )));
+            }
+            self.validate_embedding(embedding)?;
 
-        // Distribute entries
-        for (i, entry) in child.entries.into_iter().enumerate() {
-            if i == seed1 {
-                node1.entries.push(entry);
-            } else if i == seed2 {
-                node2.entries.push(entry);
+            if membership.is_noise() {
+                noise.push(i);
             } else {
-                let cf1 = if node1.entries.is_empty() {
-                    ClusteringFeature::new(self.dimension)
-                } else {
-                    node1.total_cf()
-                };
-                let cf2 = if node2.entries.is_empty() {
-                    ClusteringFeature::new(self.dimension)
-                } else {
-                    node2.total_cf()
-                };
-
-                let dist1 = entry.cf.distance(&cf1).unwrap_or(f32::MAX);
-                let dist2 = entry.cf.distance(&cf2).unwrap_or(f32::MAX);
-
-                if dist1 <= dist2 {
-                    node1.entries.push(entry);
-                } else {
-                    node2.entries.push(entry);
-                }
+                clusters.entry(membership.cluster_id).or_default().push(i);
             }
         }
 
-        // Update existing entry and add new one
-        let cf1 = node1.total_cf();
-        let cf2 = node2.total_cf();
-
-        self.root.entries[child_idx].cf = cf1;
-        self.root.entries[child_idx].child = Some(Box::new(node1));
-
-        let new_entry = BIRCHEntry::with_child(cf2, node2);
-        self.root.entries.push(new_entry);
-    }
+        self.root = BIRCHNode::new_leaf();
+        self.leaf_entries = 0;
 
-    /// Find farthest pair of entries (for split seeds).
-    fn find_farthest_pair(&self, entries: &[BIRCHEntry]) -> (usize, usize) {
-        if entries.len() < 2 {
-            return (0, entries.len().saturating_sub(1));
+        for members in clusters.values() {
+            let mut local: Vec<BIRCHEntry> = Vec::new();
+            for &i in members {
+                let point = BIRCHEntry::from_point(&embeddings[i], memberships[i].memory_id);
+                match Self::closest_fitting(&local, &point.cf, self.params.threshold) {
+                    Some(idx) => local[idx].merge_entry(point),
+                    None => local.push(point),
+                }
+            }
+            for entry in local {
+                self.insert_entry(entry);
+            }
         }
 
-        let mut max_dist = 0.0f32;
-        let mut pair = (0, 1);
+        for &i in &noise {
+            self.insert_entry(BIRCHEntry::from_point(
+                &embeddings[i],
+                memberships[i].memory_id,
+            ));
+        }
 
-        for i in 0..entries.len() {
-            for j in (i + 1)..entries.len() {
-                match entries[i].cf.distance(&entries[j].cf) {
-                    Ok(dist) => {
-                        if dist > max_dist {
-                            max_dist = dist;
-                            pair = (i, j);
-                        }
-                    }
-                    Err(e) => {
-                        warn!(i = i, j = j, error = %e, "BIRCH find_farthest_pair: distance error, skipping pair");
-                    }
-                }
-            }
+        self.total_points = embeddings.len();
+        if self.leaf_entries > self.params.max_leaf_entries {
+            self.rebuild_within_bound();
         }
 
-        pair
+        Ok(())
     }
 
     /// Get all leaf CFs as cluster summaries.
@@ -1335,6 +1631,34 @@ impl BIRCHTree {
         self.dimension
     }
 
+    /// Get the embedding space this tree clusters.
+    #[inline]
+    #[must_use]
+    pub fn space(&self) -> Embedder {
+        self.space
+    }
+
+    /// Get the number of leaf entries (bounded by `max_leaf_entries`).
+    #[inline]
+    #[must_use]
+    pub fn leaf_entry_count(&self) -> usize {
+        self.leaf_entries
+    }
+
+    /// Approximate bytes held by all entries in the tree.
+    ///
+    /// Sums [`BIRCHEntry::memory_bytes`] over leaf and internal entries.
+    #[must_use]
+    pub fn memory_bytes(&self) -> usize {
+        fn node_bytes(node: &BIRCHNode) -> usize {
+            node.entries
+                .iter()
+                .map(|e| e.memory_bytes() + e.child.as_deref().map_or(0, node_bytes))
+                .sum()
+        }
+        node_bytes(&self.root)
+    }
+
     /// Get number of points tracked via ClusteringFeature.
     ///
     /// This sums all CF.n values in leaf entries.
@@ -1344,6 +1668,39 @@ impl BIRCHTree {
     }
 }
 
+/// Squared Euclidean distance between two equal-length vectors.
+fn squared_euclidean(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b.iter()).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+
+/// Choose the cluster count for a dendrogram with ascending merge `heights`.
+///
+/// Picks the count whose next merge shows the largest jump relative to the
+/// last applied merge, within the range allowed by `k_hint`.
+fn select_cluster_count(heights: &[f32], k_hint: usize) -> usize {
+    const EPS: f32 = 1e-6;
+    let m = heights.len() + 1;
+
+    let (lo, hi) = if k_hint == 0 {
+        (1, m)
+    } else {
+        (k_hint.div_ceil(2), (2 * k_hint).min(m))
+    };
+
+    let mut best = None;
+    let mut best_ratio = GLOBAL_MIN_GAP_RATIO;
+    for count in lo.max(2)..=hi.min(m - 1) {
+        let applied = m - count;
+        let ratio = (heights[applied] + EPS) / (heights[applied - 1] + EPS);
+        if ratio > best_ratio {
+            best_ratio = ratio;
+            best = Some(count);
+        }
+    }
+
+    best.unwrap_or(if k_hint == 0 { 1 } else { k_hint.min(m) })
+}
+
 // =============================================================================
 // BIRCH Tree Tests (TASK-P4-006)
 // =============================================================================
@@ -1598,4 +1955,207 @@ mod birch_tree_tests {
 
         println!("[PASS] test_physical_output_verification");
     }
+
+    // =========================================================================
+    // GLOBAL PHASE / STREAMING TESTS
+    // =========================================================================
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use rand_distr::{Distribution, Normal};
+    use std::time::{Duration, Instant};
+
+    /// Stream `n` points round-robin from seeded isotropic Gaussians.
+    fn gaussian_stream(
+        centers: usize,
+        n: usize,
+        dim: usize,
+        sigma: f32,
+        seed: u64,
+    ) -> (Vec<Vec<f32>>, Vec<(Vec<f32>, usize)>) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let means: Vec<Vec<f32>> = (0..centers)
+            .map(|_| (0..dim).map(|_| rng.gen_range(-5.0..5.0)).collect())
+            .collect();
+        let noise = Normal::new(0.0f32, sigma).expect("valid sigma");
+        let points = (0..n)
+            .map(|i| {
+                let c = i % centers;
+                let p = means[c]
+                    .iter()
+                    .map(|m| m + noise.sample(&mut rng))
+                    .collect();
+                (p, c)
+            })
+            .collect();
+        (means, points)
+    }
+
+    #[test]
+    fn test_birch_streaming_recovers_gaussians() {
+        let dim = 32;
+        let (means, points) = gaussian_stream(5, 10_000, dim, 0.3, 42);
+
+        // Start with a threshold far below the Gaussian radius (~1.7) so the
+        // leaf bound has to grow it while streaming.
+        let params = BIRCHParams::default()
+            .with_threshold(0.5)
+            .with_max_leaf_entries(512);
+        let mut tree = BIRCHTree::new(params, dim).expect("valid tree");
+
+        let mut latencies = Vec::with_capacity(points.len());
+        for (point, _) in &points {
+            let start = Instant::now();
+            tree.insert(point, Uuid::new_v4()).expect("insert");
+            latencies.push(start.elapsed());
+        }
+        latencies.sort();
+        let median = latencies[latencies.len() / 2];
+
+        assert_eq!(tree.total_points(), 10_000);
+        assert!(tree.leaf_entry_count() <= 512, "leaf bound must hold");
+        assert!(tree.params().threshold > 0.5, "threshold must have grown");
+        assert!(
+            median < Duration::from_millis(1),
+            "median insert latency {:?} must be sub-millisecond",
+            median
+        );
+
+        for k_hint in [0, 8] {
+            let clusters = tree.global_clusters(k_hint);
+            assert_eq!(
+                clusters.len(),
+                5,
+                "k_hint={} must recover 5 clusters",
+                k_hint
+            );
+
+            for cluster in &clusters {
+                assert_eq!(cluster.len(), 2_000, "each Gaussian holds 2000 points");
+                assert_eq!(cluster.memory_ids.len(), 2_000);
+
+                let centroid = cluster.centroid();
+                let error = means
+                    .iter()
+                    .map(|m| squared_euclidean(m, &centroid).sqrt())
+                    .fold(f32::INFINITY, f32::min);
+                assert!(error < 0.1, "centroid error {} too large", error);
+            }
+        }
+
+        println!(
+            "[PASS] test_birch_streaming_recovers_gaussians - median insert {:?}, {} leaves, threshold {:.3}, {} bytes",
+            median,
+            tree.leaf_entry_count(),
+            tree.params().threshold,
+            tree.memory_bytes()
+        );
+    }
+
+    #[test]
+    fn test_birch_leaf_bound_limits_memory() {
+        let params = BIRCHParams::default()
+            .with_threshold(0.01)
+            .with_max_leaf_entries(60);
+        let mut tree = BIRCHTree::new(params, 4).expect("valid tree");
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let ids: Vec<Uuid> = (0..1_000).map(|_| Uuid::new_v4()).collect();
+        for id in &ids {
+            let point: Vec<f32> = (0..4).map(|_| rng.gen_range(-1.0..1.0)).collect();
+            tree.insert(&point, *id).expect("insert");
+        }
+
+        assert!(tree.leaf_entry_count() <= 60);
+        assert_eq!(tree.cluster_count(), tree.leaf_entry_count());
+        assert_eq!(tree.get_cf_total_points(), 1_000);
+
+        let tracked: usize = tree
+            .get_cluster_members()
+            .iter()
+            .map(|(_, m)| m.len())
+            .sum();
+        assert_eq!(tracked, 1_000, "rebuilds must not lose memory IDs");
+        assert!(tree.memory_bytes() >= 1_000 * std::mem::size_of::<Uuid>());
+
+        println!(
+            "[PASS] test_birch_leaf_bound_limits_memory - {} leaves, threshold {:.3}",
+            tree.leaf_entry_count(),
+            tree.params().threshold
+        );
+    }
+
+    #[test]
+    fn test_birch_merge_from_hdbscan_reseeds() {
+        let params = BIRCHParams::default().with_threshold(0.5);
+        let mut tree = BIRCHTree::new(params, 2).expect("valid tree");
+        tree.insert(&[50.0, 50.0], Uuid::new_v4())
+            .expect("stale insert");
+
+        let mut embeddings = Vec::new();
+        let mut memberships = Vec::new();
+        let mut labels = HashMap::new();
+        for i in 0..20 {
+            let (center, label) = if i % 2 == 0 { (0.0, 0) } else { (100.0, 1) };
+            let id = Uuid::new_v4();
+            embeddings.push(vec![center + (i as f32) * 0.01, center]);
+            memberships.push(ClusterMembership::new(
+                id,
+                Embedder::Semantic,
+                label,
+                1.0,
+                true,
+            ));
+            labels.insert(id, label);
+        }
+        let noise_id = Uuid::new_v4();
+        embeddings.push(vec![-30.0, 30.0]);
+        memberships.push(ClusterMembership::noise(noise_id, Embedder::Semantic));
+        labels.insert(noise_id, -1);
+
+        tree.merge_from_hdbscan(&embeddings, &memberships)
+            .expect("reseed must succeed");
+
+        assert_eq!(tree.total_points(), 21, "stale points must be discarded");
+        let members = tree.get_cluster_members();
+        let tracked: usize = members.iter().map(|(_, m)| m.len()).sum();
+        assert_eq!(tracked, 21);
+        for (_, ids) in &members {
+            let first = labels[&ids[0]];
+            assert!(
+                ids.iter().all(|id| labels[id] == first),
+                "seeded leaf CFs must not straddle batch clusters"
+            );
+        }
+
+        // The noise point sits nearer cluster 0 than cluster 1
+        let clusters = tree.global_clusters(2);
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].len(), 11);
+        assert_eq!(clusters[1].len(), 10);
+
+        println!("[PASS] test_birch_merge_from_hdbscan_reseeds");
+    }
+
+    #[test]
+    fn test_birch_merge_from_hdbscan_rejects_wrong_space() {
+        let mut tree = BIRCHTree::new(birch_defaults(), 2).expect("valid tree");
+        let kept = Uuid::new_v4();
+        tree.insert(&[1.0, 1.0], kept).expect("insert");
+
+        let memberships = vec![ClusterMembership::new(
+            Uuid::new_v4(),
+            Embedder::Code,
+            0,
+            1.0,
+            true,
+        )];
+        let result = tree.merge_from_hdbscan(&[vec![0.0, 0.0]], &memberships);
+
+        assert!(matches!(result, Err(ClusterError::InvalidParameter { .. })));
+        assert_eq!(tree.total_points(), 1, "tree must be untouched on error");
+        assert_eq!(tree.get_cluster_members()[0].1, vec![kept]);
+
+        println!("[PASS] test_birch_merge_from_hdbscan_rejects_wrong_space");
+    }
 }
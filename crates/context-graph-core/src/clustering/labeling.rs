@@ -0,0 +1,793 @@
+//! Readable labels for topics, derived from their members.
+//!
+//! A [`TopicLabeler`] turns a topic's member list into a short label (its
+//! three most discriminative terms) and a one-sentence description built
+//! from the member nearest the E1 centroid. No model call is needed:
+//!
+//! - Terms come from the members' E6 and E13 SPLADE vectors, resolved to
+//!   strings through a [`TermVocabulary`]. Without a vocabulary (or for a
+//!   member whose sparse vectors resolve to nothing) the member's content
+//!   words are used instead.
+//! - Each term is scored tf-idf style: its mean per-member weight inside the
+//!   topic times `ln(1 + N / df)` over the whole [`LabelCorpus`], so terms
+//!   shared by every topic rank below the ones that set this topic apart.
+//!
+//! A [`LabelRefiner`] can be plugged in to rewrite the draft (for example
+//! with an LLM); a failing refiner leaves the draft in place.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::error::CoreResult;
+use crate::retrieval::distance::cosine_similarity;
+use crate::teleological::Embedder;
+use crate::types::fingerprint::{SparseVector, TeleologicalFingerprint};
+
+use super::topic_record::TopicRecord;
+
+/// Default number of terms in a label.
+pub const DEFAULT_LABEL_TERMS: usize = 3;
+
+/// Default membership churn (1 - Jaccard against the membership at labeling
+/// time) above which a topic is relabeled.
+pub const DEFAULT_RELABEL_CHURN_THRESHOLD: f32 = 0.3;
+
+/// Words that never make a useful label term.
+const STOP_WORDS: &[&str] = &[
+    "about", "after", "all", "also", "and", "any", "are", "because", "been", "before", "being",
+    "between", "both", "but", "can", "could", "did", "does", "doing", "each", "for", "from", "had",
+    "has", "have", "having", "her", "here", "him", "his", "how", "into", "its", "just", "more",
+    "most", "not", "now", "off", "once", "only", "other", "our", "out", "over", "own", "same",
+    "she", "should", "some", "such", "than", "that", "the", "their", "them", "then", "there",
+    "these", "they", "this", "those", "through", "too", "under", "until", "use", "used", "uses",
+    "using", "very", "was", "were", "what", "when", "where", "which", "while", "who", "whom",
+    "why", "will", "with", "would", "you", "your",
+];
+
+/// Resolves SPLADE vocabulary indices to term strings.
+pub trait TermVocabulary: Send + Sync {
+    /// The term at vocabulary index `id`, if any.
+    fn term(&self, id: u16) -> Option<&str>;
+}
+
+/// BERT WordPiece vocabulary, one token per line with the line number as
+/// the index (the `vocab.txt` shipped with the SPLADE models).
+#[derive(Debug, Clone, Default)]
+pub struct BertVocabulary {
+    terms: Vec<String>,
+}
+
+impl BertVocabulary {
+    /// Build a vocabulary from terms in index order.
+    pub fn from_terms(terms: Vec<String>) -> Self {
+        Self { terms }
+    }
+
+    /// Load a `vocab.txt` file.
+    ///
+    /// # Errors
+    /// Returns the I/O error if the file cannot be read.
+    pub fn from_vocab_txt(path: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        Ok(Self::from_terms(
+            text.lines()
+                .map(|line| line.trim_end().to_string())
+                .collect(),
+        ))
+    }
+
+    /// Number of terms.
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// True if the vocabulary has no terms.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl TermVocabulary for BertVocabulary {
+    fn term(&self, id: u16) -> Option<&str> {
+        self.terms.get(id as usize).map(String::as_str)
+    }
+}
+
+/// Hook for rewriting a drafted label, e.g. with an LLM.
+#[async_trait]
+pub trait LabelRefiner: Send + Sync {
+    /// Return a replacement for `draft`, or `None` to keep it.
+    ///
+    /// `snippets` are the representative member snippets the draft was
+    /// built from, nearest the centroid first.
+    async fn refine(
+        &self,
+        draft: &TopicLabel,
+        snippets: &[String],
+    ) -> CoreResult<Option<TopicLabel>>;
+}
+
+/// A generated topic label.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopicLabel {
+    /// Short label: the top terms joined with ", ".
+    pub label: String,
+    /// One-sentence description.
+    pub description: String,
+    /// Top discriminative terms, best first.
+    pub terms: Vec<String>,
+    /// Members nearest the E1 centroid, nearest first.
+    pub representative_ids: Vec<Uuid>,
+}
+
+/// Tuning for [`TopicLabeler`].
+#[derive(Debug, Clone)]
+pub struct LabelingConfig {
+    /// Terms in the label.
+    pub label_terms: usize,
+    /// Representative members handed to the refiner.
+    pub representative_count: usize,
+    /// Maximum words in a description snippet.
+    pub snippet_words: usize,
+    /// Shortest term considered.
+    pub min_term_len: usize,
+    /// Membership churn above which a labeled topic is relabeled.
+    pub relabel_churn_threshold: f32,
+}
+
+impl Default for LabelingConfig {
+    fn default() -> Self {
+        Self {
+            label_terms: DEFAULT_LABEL_TERMS,
+            representative_count: 3,
+            snippet_words: 20,
+            min_term_len: 3,
+            relabel_churn_threshold: DEFAULT_RELABEL_CHURN_THRESHOLD,
+        }
+    }
+}
+
+/// The per-member inputs to labeling.
+#[derive(Debug, Clone)]
+pub struct LabelDocument {
+    /// Memory ID.
+    pub id: Uuid,
+    /// Original content, if still available.
+    pub content: Option<String>,
+    /// E1 semantic embedding.
+    pub semantic: Vec<f32>,
+    /// E6 sparse vector.
+    pub sparse: SparseVector,
+    /// E13 SPLADE vector.
+    pub splade: SparseVector,
+}
+
+impl LabelDocument {
+    /// Take the E1, E6 and E13 embeddings from a stored fingerprint.
+    pub fn from_fingerprint(
+        fingerprint: &TeleologicalFingerprint,
+        content: Option<String>,
+    ) -> Self {
+        Self {
+            id: fingerprint.id,
+            content,
+            semantic: fingerprint.semantic.e1_semantic.clone(),
+            sparse: fingerprint.semantic.e6_sparse.clone(),
+            splade: fingerprint.semantic.e13_splade.clone(),
+        }
+    }
+}
+
+/// Documents plus their term weights and the global document frequencies
+/// that idf is computed against. Built by [`TopicLabeler::corpus`].
+#[derive(Debug, Default)]
+pub struct LabelCorpus {
+    documents: HashMap<Uuid, LabelDocument>,
+    terms: HashMap<Uuid, HashMap<String, f32>>,
+    doc_freq: HashMap<String, usize>,
+}
+
+impl LabelCorpus {
+    /// Number of documents.
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    /// True if the corpus has no documents.
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    fn idf(&self, term: &str) -> f32 {
+        let df = self.doc_freq.get(term).copied().unwrap_or(0).max(1);
+        (1.0 + self.documents.len() as f32 / df as f32).ln()
+    }
+}
+
+/// Generates labels and descriptions for topics. See the module docs.
+#[derive(Clone, Default)]
+pub struct TopicLabeler {
+    config: LabelingConfig,
+    vocabulary: Option<Arc<dyn TermVocabulary>>,
+    refiner: Option<Arc<dyn LabelRefiner>>,
+}
+
+impl TopicLabeler {
+    /// Create a labeler that reads terms from member content only.
+    pub fn new(config: LabelingConfig) -> Self {
+        Self {
+            config,
+            vocabulary: None,
+            refiner: None,
+        }
+    }
+
+    /// Resolve SPLADE terms through `vocabulary`.
+    pub fn with_vocabulary(mut self, vocabulary: Arc<dyn TermVocabulary>) -> Self {
+        self.vocabulary = Some(vocabulary);
+        self
+    }
+
+    /// Pass every draft through `refiner`.
+    pub fn with_refiner(mut self, refiner: Arc<dyn LabelRefiner>) -> Self {
+        self.refiner = Some(refiner);
+        self
+    }
+
+    /// The labeler's configuration.
+    pub fn config(&self) -> &LabelingConfig {
+        &self.config
+    }
+
+    /// Extract term weights from `documents` and count document frequencies.
+    ///
+    /// The corpus should cover every topic being labeled, so that idf
+    /// reflects the global term distribution rather than a single topic.
+    pub fn corpus(&self, documents: impl IntoIterator<Item = LabelDocument>) -> LabelCorpus {
+        let mut corpus = LabelCorpus::default();
+        for doc in documents {
+            let mut weights = self.sparse_terms(&doc);
+            if weights.is_empty() {
+                weights = self.content_terms(doc.content.as_deref().unwrap_or(""));
+            }
+            for term in weights.keys() {
+                *corpus.doc_freq.entry(term.clone()).or_insert(0) += 1;
+            }
+            corpus.terms.insert(doc.id, weights);
+            corpus.documents.insert(doc.id, doc);
+        }
+        corpus
+    }
+
+    /// Draft a label for a topic with `members`.
+    ///
+    /// `centroid` is the topic's E1 centroid; the mean of the members' E1
+    /// vectors is used when it is absent. Returns `None` if no member is in
+    /// the corpus or no term survives filtering.
+    pub fn draft(
+        &self,
+        members: &[Uuid],
+        centroid: Option<&[f32]>,
+        corpus: &LabelCorpus,
+    ) -> Option<TopicLabel> {
+        let present: Vec<&LabelDocument> = members
+            .iter()
+            .filter_map(|id| corpus.documents.get(id))
+            .collect();
+        if present.is_empty() {
+            return None;
+        }
+
+        let terms = self.top_terms(&present, corpus);
+        if terms.is_empty() {
+            return None;
+        }
+
+        let representatives = self.representatives(&present, centroid);
+        let snippet = representatives
+            .iter()
+            .find_map(|doc| doc.content.as_deref())
+            .map(|content| snippet(content, self.config.snippet_words))
+            .filter(|s| !s.is_empty());
+
+        let label = terms.join(", ");
+        let description = match snippet {
+            Some(snippet) => format!(
+                "{} memories about {}, e.g. \"{}\".",
+                present.len(),
+                join_terms(&terms),
+                snippet
+            ),
+            None => format!("{} memories about {}.", present.len(), join_terms(&terms)),
+        };
+
+        Some(TopicLabel {
+            label,
+            description,
+            terms,
+            representative_ids: representatives.iter().map(|doc| doc.id).collect(),
+        })
+    }
+
+    /// Draft a label and pass it through the refiner, if one is set.
+    pub async fn label(
+        &self,
+        members: &[Uuid],
+        centroid: Option<&[f32]>,
+        corpus: &LabelCorpus,
+    ) -> Option<TopicLabel> {
+        let draft = self.draft(members, centroid, corpus)?;
+        let Some(refiner) = &self.refiner else {
+            return Some(draft);
+        };
+
+        let snippets: Vec<String> = draft
+            .representative_ids
+            .iter()
+            .filter_map(|id| corpus.documents.get(id)?.content.as_deref())
+            .map(|content| snippet(content, self.config.snippet_words))
+            .collect();
+        match refiner.refine(&draft, &snippets).await {
+            Ok(Some(refined)) => Some(refined),
+            Ok(None) => Some(draft),
+            Err(e) => {
+                tracing::warn!(error = %e, label = %draft.label, "Label refiner failed, keeping draft");
+                Some(draft)
+            }
+        }
+    }
+
+    /// Label every record whose membership has churned past the configured
+    /// threshold since it was last labeled (or that was never labeled).
+    ///
+    /// Returns the number of records relabeled.
+    pub async fn relabel_records(
+        &self,
+        records: &mut [TopicRecord],
+        corpus: &LabelCorpus,
+        now: DateTime<Utc>,
+    ) -> usize {
+        let mut relabeled = 0;
+        for record in records.iter_mut() {
+            if !record.needs_relabel(self.config.relabel_churn_threshold) {
+                continue;
+            }
+            let centroid = record.centroids.get(&Embedder::Semantic).cloned();
+            if let Some(label) = self
+                .label(&record.member_ids, centroid.as_deref(), corpus)
+                .await
+            {
+                record.apply_label(label, now);
+                relabeled += 1;
+            }
+        }
+        relabeled
+    }
+
+    fn sparse_terms(&self, doc: &LabelDocument) -> HashMap<String, f32> {
+        let mut weights = HashMap::new();
+        let Some(vocabulary) = &self.vocabulary else {
+            return weights;
+        };
+        for vector in [&doc.sparse, &doc.splade] {
+            for (&idx, &value) in vector.indices.iter().zip(&vector.values) {
+                if value <= 0.0 {
+                    continue;
+                }
+                let Some(term) = vocabulary.term(idx) else {
+                    continue;
+                };
+                if !self.is_label_term(term) {
+                    continue;
+                }
+                let weight = weights.entry(term.to_string()).or_insert(0.0_f32);
+                *weight = weight.max(value);
+            }
+        }
+        normalize_max(&mut weights);
+        weights
+    }
+
+    fn content_terms(&self, content: &str) -> HashMap<String, f32> {
+        let mut weights = HashMap::new();
+        for word in content
+            .split(|c: char| !c.is_alphanumeric())
+            .map(str::to_lowercase)
+            .filter(|w| self.is_label_term(w))
+        {
+            *weights.entry(word).or_insert(0.0) += 1.0;
+        }
+        normalize_max(&mut weights);
+        weights
+    }
+
+    /// Whole alphabetic words of at least `min_term_len` characters that are
+    /// not stop words. Rejects WordPiece continuations and special tokens.
+    fn is_label_term(&self, term: &str) -> bool {
+        term.chars().count() >= self.config.min_term_len
+            && term.chars().all(char::is_alphabetic)
+            && !STOP_WORDS.contains(&term)
+    }
+
+    fn top_terms(&self, members: &[&LabelDocument], corpus: &LabelCorpus) -> Vec<String> {
+        let mut tf: HashMap<&str, f32> = HashMap::new();
+        for doc in members {
+            if let Some(weights) = corpus.terms.get(&doc.id) {
+                for (term, weight) in weights {
+                    *tf.entry(term.as_str()).or_insert(0.0) += weight;
+                }
+            }
+        }
+
+        let n = members.len() as f32;
+        let mut scored: Vec<(&str, f32)> = tf
+            .into_iter()
+            .map(|(term, sum)| (term, sum / n * corpus.idf(term)))
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        scored
+            .into_iter()
+            .take(self.config.label_terms)
+            .map(|(term, _)| term.to_string())
+            .collect()
+    }
+
+    fn representatives<'a>(
+        &self,
+        members: &[&'a LabelDocument],
+        centroid: Option<&[f32]>,
+    ) -> Vec<&'a LabelDocument> {
+        let mean;
+        let centroid = match centroid {
+            Some(c) => c,
+            None => {
+                mean = mean_vector(members.iter().map(|doc| doc.semantic.as_slice()));
+                mean.as_slice()
+            }
+        };
+
+        let mut ranked: Vec<(&LabelDocument, f32)> = members
+            .iter()
+            .map(|&doc| (doc, cosine_similarity(&doc.semantic, centroid)))
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.id.cmp(&b.0.id)));
+        ranked
+            .into_iter()
+            .take(self.config.representative_count)
+            .map(|(doc, _)| doc)
+            .collect()
+    }
+}
+
+impl TopicRecord {
+    /// True if the record has no generated label yet, or its membership has
+    /// churned by more than `churn_threshold` since it was labeled.
+    pub fn needs_relabel(&self, churn_threshold: f32) -> bool {
+        if self.labeled_at.is_none() {
+            return true;
+        }
+        let churn =
+            1.0 - super::stability::membership_jaccard(&self.labeled_member_ids, &self.member_ids);
+        churn > churn_threshold
+    }
+
+    /// Store a generated label, remembering the membership it describes.
+    pub fn apply_label(&mut self, label: TopicLabel, now: DateTime<Utc>) {
+        self.label = Some(label.label);
+        self.description = Some(label.description);
+        self.labeled_member_ids = self.member_ids.clone();
+        self.labeled_at = Some(now);
+        self.updated_at = now;
+    }
+}
+
+/// Distinct IDs across `records`, in first-seen order. Used to load the
+/// documents for a labeling corpus.
+pub fn label_corpus_ids(records: &[TopicRecord]) -> Vec<Uuid> {
+    let mut seen = HashSet::new();
+    records
+        .iter()
+        .flat_map(|r| r.member_ids.iter().copied())
+        .filter(|id| seen.insert(*id))
+        .collect()
+}
+
+fn normalize_max(weights: &mut HashMap<String, f32>) {
+    let max = weights.values().copied().fold(0.0_f32, f32::max);
+    if max > 0.0 {
+        for weight in weights.values_mut() {
+            *weight /= max;
+        }
+    }
+}
+
+fn mean_vector<'a>(vectors: impl Iterator<Item = &'a [f32]>) -> Vec<f32> {
+    let mut sum: Vec<f32> = Vec::new();
+    let mut count = 0usize;
+    for v in vectors {
+        if sum.is_empty() {
+            sum = vec![0.0; v.len()];
+        }
+        if v.len() != sum.len() {
+            continue;
+        }
+        for (s, x) in sum.iter_mut().zip(v) {
+            *s += x;
+        }
+        count += 1;
+    }
+    if count > 0 {
+        for s in &mut sum {
+            *s /= count as f32;
+        }
+    }
+    sum
+}
+
+/// First sentence of `content`, cut to `max_words` words, without trailing
+/// punctuation.
+fn snippet(content: &str, max_words: usize) -> String {
+    let first = content
+        .split_inclusive(['.', '!', '?', '\n'])
+        .map(str::trim)
+        .find(|s| !s.is_empty())
+        .unwrap_or("");
+    let words: Vec<&str> = first.split_whitespace().collect();
+    let mut text = words[..words.len().min(max_words)].join(" ");
+    if words.len() > max_words {
+        text.push_str("...");
+    } else {
+        text = text
+            .trim_end_matches(|c: char| c.is_ascii_punctuation())
+            .to_string();
+    }
+    text
+}
+
+/// "a", "a and b", "a, b and c".
+fn join_terms(terms: &[String]) -> String {
+    match terms {
+        [] => String::new(),
+        [only] => only.clone(),
+        [init @ .., last] => format!("{} and {}", init.join(", "), last),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::clustering::topic::{Topic, TopicProfile};
+
+    const RUST: [&str; 4] = [
+        "The rust async runtime polls each future until it is ready.",
+        "Tokio is an async runtime for rust with a work stealing scheduler.",
+        "Blocking inside an async task starves the rust runtime executor.",
+        "Spawning async tasks on the runtime requires futures to be Send in rust.",
+    ];
+
+    const MEDICAL: [&str; 4] = [
+        "Pediatric dosage guidelines scale the medical dose by body weight.",
+        "The guidelines cap the daily dosage of acetaminophen for adults.",
+        "Medical dosage guidelines recommend lower doses for renal impairment.",
+        "Nurses check the dosage against the medical guidelines before administering.",
+    ];
+
+    /// Vocabulary of every lowercased word in the test corpus plus some
+    /// WordPiece noise, with SPLADE-like vectors built from it.
+    struct Fixture {
+        vocabulary: Arc<BertVocabulary>,
+        index: HashMap<String, u16>,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let mut terms: Vec<String> = vec!["[PAD]".into(), "[CLS]".into(), "##ing".into()];
+            for content in RUST.iter().chain(MEDICAL.iter()) {
+                for word in content.split(|c: char| !c.is_alphanumeric()) {
+                    let word = word.to_lowercase();
+                    if !word.is_empty() && !terms.contains(&word) {
+                        terms.push(word);
+                    }
+                }
+            }
+            let index = terms
+                .iter()
+                .enumerate()
+                .map(|(i, t)| (t.clone(), i as u16))
+                .collect();
+            Self {
+                vocabulary: Arc::new(BertVocabulary::from_terms(terms)),
+                index,
+            }
+        }
+
+        fn sparse(&self, content: &str) -> SparseVector {
+            let mut counts: HashMap<u16, f32> = HashMap::new();
+            for word in content.split(|c: char| !c.is_alphanumeric()) {
+                if let Some(&i) = self.index.get(&word.to_lowercase()) {
+                    *counts.entry(i).or_insert(0.0) += 1.0;
+                }
+            }
+            // Special tokens and subwords fire too, as in real SPLADE output
+            counts.insert(1, 2.0);
+            counts.insert(2, 1.5);
+            let mut pairs: Vec<(u16, f32)> = counts.into_iter().collect();
+            pairs.sort_by_key(|(i, _)| *i);
+            let (indices, values) = pairs.into_iter().unzip();
+            SparseVector::new(indices, values).unwrap()
+        }
+
+        fn document(&self, id: u128, content: &str, semantic: Vec<f32>) -> LabelDocument {
+            LabelDocument {
+                id: Uuid::from_u128(id),
+                content: Some(content.to_string()),
+                semantic,
+                sparse: self.sparse(content),
+                splade: self.sparse(content),
+            }
+        }
+
+        fn corpus(&self, labeler: &TopicLabeler) -> LabelCorpus {
+            let rust = RUST
+                .iter()
+                .enumerate()
+                .map(|(i, c)| self.document(i as u128, c, vec![1.0, 0.1 * i as f32, 0.0]));
+            let medical = MEDICAL
+                .iter()
+                .enumerate()
+                .map(|(i, c)| self.document(10 + i as u128, c, vec![0.0, 0.1 * i as f32, 1.0]));
+            labeler.corpus(rust.chain(medical))
+        }
+    }
+
+    fn ids(range: std::ops::Range<u128>) -> Vec<Uuid> {
+        range.map(Uuid::from_u128).collect()
+    }
+
+    fn theme_hits(label: &TopicLabel, theme: &[&str]) -> usize {
+        theme
+            .iter()
+            .filter(|t| label.terms.iter().any(|l| l == *t))
+            .count()
+    }
+
+    #[test]
+    fn test_labels_name_both_themes_from_splade_terms() {
+        let fixture = Fixture::new();
+        let labeler = TopicLabeler::new(LabelingConfig::default())
+            .with_vocabulary(fixture.vocabulary.clone());
+        let corpus = fixture.corpus(&labeler);
+
+        let rust = labeler.draft(&ids(0..4), None, &corpus).unwrap();
+        let medical = labeler.draft(&ids(10..14), None, &corpus).unwrap();
+
+        assert!(
+            theme_hits(&rust, &["rust", "async", "runtime"]) >= 2,
+            "rust label: {}",
+            rust.label
+        );
+        assert!(
+            theme_hits(&medical, &["medical", "dosage", "guidelines"]) >= 2,
+            "medical label: {}",
+            medical.label
+        );
+        assert_eq!(rust.terms.len(), DEFAULT_LABEL_TERMS);
+        assert_eq!(rust.label, rust.terms.join(", "));
+        assert!(!rust.label.contains("[CLS]") && !rust.label.contains("##"));
+    }
+
+    #[test]
+    fn test_description_quotes_member_nearest_centroid() {
+        let fixture = Fixture::new();
+        let labeler = TopicLabeler::new(LabelingConfig::default())
+            .with_vocabulary(fixture.vocabulary.clone());
+        let corpus = fixture.corpus(&labeler);
+
+        // Member 2 sits exactly on the centroid
+        let centroid = [1.0, 0.2, 0.0];
+        let label = labeler.draft(&ids(0..4), Some(&centroid), &corpus).unwrap();
+
+        assert_eq!(label.representative_ids[0], Uuid::from_u128(2));
+        assert!(label.description.starts_with("4 memories about "));
+        assert!(
+            label
+                .description
+                .contains("\"Blocking inside an async task starves the rust runtime executor\""),
+            "{}",
+            label.description
+        );
+    }
+
+    #[test]
+    fn test_content_terms_without_vocabulary() {
+        let fixture = Fixture::new();
+        let labeler = TopicLabeler::new(LabelingConfig::default());
+        let corpus = fixture.corpus(&labeler);
+
+        let rust = labeler.draft(&ids(0..4), None, &corpus).unwrap();
+        let medical = labeler.draft(&ids(10..14), None, &corpus).unwrap();
+
+        assert!(
+            theme_hits(&rust, &["rust", "async", "runtime"]) >= 2,
+            "{}",
+            rust.label
+        );
+        assert!(
+            theme_hits(&medical, &["medical", "dosage", "guidelines"]) >= 2,
+            "{}",
+            medical.label
+        );
+        assert!(labeler.draft(&ids(50..52), None, &corpus).is_none());
+    }
+
+    struct UppercaseRefiner;
+
+    #[async_trait]
+    impl LabelRefiner for UppercaseRefiner {
+        async fn refine(
+            &self,
+            draft: &TopicLabel,
+            snippets: &[String],
+        ) -> CoreResult<Option<TopicLabel>> {
+            assert!(!snippets.is_empty());
+            Ok(Some(TopicLabel {
+                label: draft.label.to_uppercase(),
+                ..draft.clone()
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_relabel_records_respects_churn_threshold() {
+        let fixture = Fixture::new();
+        let labeler = TopicLabeler::new(LabelingConfig::default())
+            .with_vocabulary(fixture.vocabulary.clone())
+            .with_refiner(Arc::new(UppercaseRefiner));
+        let corpus = fixture.corpus(&labeler);
+
+        let topic = Topic::new(TopicProfile::new([0.0; 13]), HashMap::new(), ids(0..4));
+        let now = Utc::now();
+        let mut records = vec![TopicRecord::from_topic(&topic, HashMap::new(), now)];
+        assert!(records[0].needs_relabel(0.3));
+
+        assert_eq!(labeler.relabel_records(&mut records, &corpus, now).await, 1);
+        let first = records[0].label.clone().unwrap();
+        assert_eq!(first, first.to_uppercase());
+        assert!(records[0].description.is_some());
+        assert_eq!(records[0].labeled_member_ids, ids(0..4));
+
+        // One member swapped: churn 1 - 3/5 = 0.4 > 0.3
+        records[0].member_ids = vec![
+            Uuid::from_u128(0),
+            Uuid::from_u128(1),
+            Uuid::from_u128(2),
+            Uuid::from_u128(10),
+        ];
+        assert!(records[0].needs_relabel(0.3));
+        assert!(!records[0].needs_relabel(0.5));
+
+        // Small churn: one member added, 1 - 4/5 = 0.2
+        records[0].member_ids = ids(0..5);
+        assert!(!records[0].needs_relabel(0.3));
+        assert_eq!(labeler.relabel_records(&mut records, &corpus, now).await, 0);
+    }
+
+    #[test]
+    fn test_snippet_and_join_terms() {
+        assert_eq!(snippet("First one. Second one.", 20), "First one");
+        assert_eq!(snippet("a b c d e", 3), "a b c...");
+        assert_eq!(
+            join_terms(&["a".into(), "b".into(), "c".into()]),
+            "a, b and c"
+        );
+        assert_eq!(join_terms(&["a".into()]), "a");
+    }
+}
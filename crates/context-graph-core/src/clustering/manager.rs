@@ -45,6 +45,7 @@
 
 use std::collections::{HashMap, HashSet};
 
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::embeddings::category::category_for;
@@ -58,8 +59,9 @@ use super::error::ClusterError;
 use super::fingerprint_matrix::{build_fingerprint_matrix, FingerprintMatrixConfig, SimilarityStats};
 use super::hdbscan::{hdbscan_defaults, HDBSCANClusterer, HDBSCANParams};
 use super::membership::ClusterMembership;
-use super::stability::TopicStabilityTracker;
+use super::stability::{TopicObservation, TopicStabilityTracker};
 use super::topic::{Topic, TopicProfile};
+use super::topic_record::TopicRecord;
 
 // =============================================================================
 // Constants
@@ -220,10 +222,10 @@ struct PerSpaceState {
 }
 
 impl PerSpaceState {
-    /// Create new per-space state with given dimension.
-    fn new(params: &BIRCHParams, dimension: usize) -> Result<Self, ClusterError> {
+    /// Create new per-space state for an embedding space.
+    fn new(params: &BIRCHParams, embedder: Embedder) -> Result<Self, ClusterError> {
         Ok(Self {
-            tree: BIRCHTree::new(params.clone(), dimension)?,
+            tree: BIRCHTree::for_space(params.clone(), embedder)?,
             embeddings: Vec::new(),
             memory_ids: Vec::new(),
             memberships: HashMap::new(),
@@ -316,8 +318,7 @@ impl MultiSpaceClusterManager {
         let mut states: Vec<PerSpaceState> = Vec::with_capacity(13);
 
         for embedder in Embedder::all() {
-            let dimension = get_dimension(embedder);
-            let state = PerSpaceState::new(&params.birch_params, dimension)?;
+            let state = PerSpaceState::new(&params.birch_params, embedder)?;
             states.push(state);
         }
 
@@ -467,6 +468,11 @@ impl MultiSpaceClusterManager {
             let memberships =
                 clusterer.fit(&state.embeddings, &state.memory_ids, embedder)?;
 
+            // Re-seed the online tree from the batch result
+            state
+                .tree
+                .merge_from_hdbscan(&state.embeddings, &memberships)?;
+
             // Update memberships and build clusters
             state.memberships.clear();
             state.clusters.clear();
@@ -695,6 +701,7 @@ impl MultiSpaceClusterManager {
 
         // Take stability snapshot
         self.take_stability_snapshot();
+        self.record_detection_run();
 
         Ok(FdmcResult {
             total_clusters,
@@ -796,6 +803,7 @@ impl MultiSpaceClusterManager {
 
         if mem_clusters.is_empty() {
             self.take_stability_snapshot();
+            self.record_detection_run();
             return Ok(());
         }
 
@@ -805,6 +813,7 @@ impl MultiSpaceClusterManager {
 
         if n < 2 {
             self.take_stability_snapshot();
+            self.record_detection_run();
             return Ok(());
         }
 
@@ -1054,6 +1063,7 @@ impl MultiSpaceClusterManager {
 
         // Take a stability snapshot after synthesizing topics (AP-70)
         self.take_stability_snapshot();
+        self.record_detection_run();
 
         Ok(())
     }
@@ -1336,6 +1346,7 @@ impl MultiSpaceClusterManager {
             entropy,
             session_id.into(),
         )
+        .with_stability_runs(self.stability_tracker.runs().cloned().collect())
     }
 
     /// Export the current topic portfolio using internal churn rate.
@@ -1375,6 +1386,7 @@ impl MultiSpaceClusterManager {
             entropy,
             session_id.into(),
         )
+        .with_stability_runs(self.stability_tracker.runs().cloned().collect())
     }
 
     /// Import topics from a persisted portfolio.
@@ -1428,6 +1440,10 @@ impl MultiSpaceClusterManager {
             self.topics.insert(imported_topic.id, imported_topic);
         }
 
+        // Restore per-topic run history so stability survives restarts
+        self.stability_tracker
+            .restore_runs(portfolio.stability_runs.clone());
+
         self.topics.len()
     }
 
@@ -1480,6 +1496,49 @@ impl MultiSpaceClusterManager {
         self.stability_tracker.take_snapshot(&topics_vec);
     }
 
+    /// Record the current topics as a detection run for per-topic tracking.
+    ///
+    /// Each topic is recorded with its members and the centroid of its
+    /// cluster in every dense, non-temporal contributing space. The tracker
+    /// matches topics to the previous run and computes churn, drift, age
+    /// and stability per topic.
+    pub fn record_detection_run(&mut self) {
+        let observations: Vec<TopicObservation> = self
+            .topics
+            .values()
+            .map(|topic| TopicObservation {
+                topic_id: topic.id,
+                members: topic.member_memories.clone(),
+                centroids: self.topic_centroids(topic),
+            })
+            .collect();
+
+        self.stability_tracker.record_run(observations);
+    }
+
+    /// Centroid of `topic`'s cluster in every dense, non-temporal
+    /// contributing space.
+    pub fn topic_centroids(&self, topic: &Topic) -> HashMap<Embedder, Vec<f32>> {
+        topic
+            .cluster_ids
+            .iter()
+            .filter(|(space, _)| space.is_dense() && category_for(**space).topic_weight() > 0.0)
+            .filter_map(|(space, cluster_id)| {
+                let cluster = self.spaces[space.index()].clusters.get(cluster_id)?;
+                Some((*space, cluster.centroid.clone()))
+            })
+            .collect()
+    }
+
+    /// Current topics as storable records, before reconciliation with the
+    /// stored portfolio (see `reconcile_topic_records`).
+    pub fn topic_records(&self, now: DateTime<Utc>) -> Vec<TopicRecord> {
+        self.topics
+            .values()
+            .map(|topic| TopicRecord::from_topic(topic, self.topic_centroids(topic), now))
+            .collect()
+    }
+
     /// Compute churn by comparing current state to ~1 hour ago.
     ///
     /// # Returns
@@ -30,24 +30,34 @@
 //! - [`InsertResult`]: Result of inserting a memory into the manager
 //! - [`ReclusterResult`]: Result of HDBSCAN batch reclustering
 //! - [`TopicSynthesizer`]: Standalone synthesizer using weighted agreement formula
+//! - [`TopicAgreementScorer`]: Scores candidate topics by weighted multi-space agreement
 //! - [`TopicSnapshot`]: Snapshot of topic portfolio at a point in time
 //! - [`TopicStabilityTracker`]: Portfolio-level stability tracking for dream triggers
 //! - [`PersistedTopicPortfolio`]: Serializable topic portfolio for session persistence
 //! - [`PersistenceError`]: Error types for persistence operations
+//! - [`TopicRecord`]: Stored topic with an identity that survives detection runs
+//! - [`TopicLabeler`]: Generates topic labels and descriptions from member content
 
+pub mod agreement;
 pub mod birch;
 pub mod cluster;
 pub mod error;
 pub mod fingerprint_matrix;
 pub mod hdbscan;
+pub mod labeling;
 pub mod manager;
 pub mod membership;
 pub mod persistence;
 pub mod stability;
 pub mod synthesizer;
 pub mod topic;
+pub mod topic_record;
 
-pub use birch::{birch_defaults, BIRCHEntry, BIRCHNode, BIRCHParams, BIRCHTree, ClusteringFeature};
+pub use agreement::{SpaceAgreement, TopicAgreementScorer, TopicCandidate};
+pub use birch::{
+    birch_defaults, BIRCHEntry, BIRCHGlobalCluster, BIRCHNode, BIRCHParams, BIRCHTree,
+    ClusteringFeature, DEFAULT_MAX_LEAF_ENTRIES, GLOBAL_MIN_GAP_RATIO, THRESHOLD_GROWTH_FACTOR,
+};
 pub use cluster::Cluster;
 pub use error::ClusterError;
 pub use fingerprint_matrix::{
@@ -55,6 +65,11 @@ pub use fingerprint_matrix::{
     FingerprintMatrixConfig, SimilarityStats,
 };
 pub use hdbscan::{hdbscan_defaults, ClusterSelectionMethod, HDBSCANClusterer, HDBSCANParams};
+pub use labeling::{
+    label_corpus_ids, BertVocabulary, LabelCorpus, LabelDocument, LabelRefiner, LabelingConfig,
+    TermVocabulary, TopicLabel, TopicLabeler, DEFAULT_LABEL_TERMS,
+    DEFAULT_RELABEL_CHURN_THRESHOLD,
+};
 pub use manager::{
     manager_defaults, FdmcResult, InsertResult, ManagerParams, MultiSpaceClusterManager,
     ReclusterResult, UpdateStatus, DEFAULT_RECLUSTER_THRESHOLD, MAX_WEIGHTED_AGREEMENT,
@@ -63,11 +78,14 @@ pub use manager::{
 pub use membership::ClusterMembership;
 pub use persistence::{PersistedTopicPortfolio, PersistenceError, TopicPortfolio};
 pub use stability::{
-    TopicSnapshot, TopicStabilityTracker, DEFAULT_CHURN_THRESHOLD, DEFAULT_ENTROPY_DURATION_SECS,
-    DEFAULT_ENTROPY_THRESHOLD, SNAPSHOT_RETENTION_HOURS,
+    TopicObservation, TopicRun, TopicRunMetrics, TopicSeries, TopicSeriesPoint, TopicSnapshot,
+    TopicStabilityTracker, TrackedTopic, DEFAULT_CHURN_THRESHOLD, DEFAULT_ENTROPY_DURATION_SECS,
+    DEFAULT_ENTROPY_THRESHOLD, DEFAULT_MAX_TRACKED_RUNS, MIN_TRACK_JACCARD,
+    SNAPSHOT_RETENTION_HOURS,
 };
 pub use synthesizer::{TopicSynthesizer, DEFAULT_MERGE_THRESHOLD, DEFAULT_MIN_SILHOUETTE};
 pub use topic::{
     build_topic_hierarchy, Topic, TopicPhase, TopicProfile, TopicStability, MAX_TOPIC_DEPTH,
     TOPIC_SILHOUETTE_THRESHOLD,
 };
+pub use topic_record::{reconcile_topic_records, TopicReconciliation, TopicRecord};
@@ -35,7 +35,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use super::stability::TopicStabilityTracker;
+use super::stability::{TopicRun, TopicStabilityTracker};
 use super::topic::Topic;
 
 // =============================================================================
@@ -127,6 +127,12 @@ pub struct PersistedTopicPortfolio {
 
     /// Unix timestamp in milliseconds when this portfolio was persisted.
     pub persisted_at_ms: u64,
+
+    /// Per-topic detection run history (bounded to the tracker's last N runs).
+    ///
+    /// Defaults to empty for portfolios persisted before run tracking existed.
+    #[serde(default)]
+    pub stability_runs: Vec<TopicRun>,
 }
 
 impl PersistedTopicPortfolio {
@@ -163,6 +169,7 @@ impl PersistedTopicPortfolio {
             entropy: clamp_metric(entropy),
             session_id,
             persisted_at_ms,
+            stability_runs: Vec::new(),
         }
     }
 
@@ -188,9 +195,17 @@ impl PersistedTopicPortfolio {
             entropy: clamp_metric(entropy),
             session_id,
             persisted_at_ms,
+            stability_runs: Vec::new(),
         }
     }
 
+    /// Attach per-topic detection run history.
+    #[must_use]
+    pub fn with_stability_runs(mut self, runs: Vec<TopicRun>) -> Self {
+        self.stability_runs = runs;
+        self
+    }
+
     /// Serialize the portfolio to JSON bytes.
     ///
     /// # Returns
@@ -293,6 +308,7 @@ impl Default for PersistedTopicPortfolio {
             entropy: 0.0,
             session_id: String::new(),
             persisted_at_ms: 0,
+            stability_runs: Vec::new(),
         }
     }
 }
@@ -518,12 +534,14 @@ impl TopicPortfolio {
             entropy,
             session_id.into(),
         )
+        .with_stability_runs(self.stability_tracker.runs().cloned().collect())
     }
 
     /// Import topics from a persisted portfolio.
     ///
-    /// Replaces current topics with imported ones. Does NOT restore
-    /// stability tracker state (snapshots are not persisted).
+    /// Replaces current topics with imported ones and restores per-topic
+    /// detection runs. Portfolio snapshots are not persisted, so churn
+    /// history starts fresh.
     ///
     /// # Returns
     ///
@@ -533,6 +551,8 @@ impl TopicPortfolio {
         for topic in &portfolio.topics {
             self.topics.insert(topic.id, topic.clone());
         }
+        self.stability_tracker
+            .restore_runs(portfolio.stability_runs.clone());
         self.topics.len()
     }
 
@@ -700,4 +720,39 @@ mod tests {
         println!("[PASS] test_constitution_churn_thresholds");
     }
 
+    #[test]
+    fn test_stability_runs_survive_roundtrip() {
+        use crate::clustering::stability::TopicObservation;
+        use crate::teleological::Embedder;
+
+        let members: Vec<Uuid> = (0..5).map(Uuid::from_u128).collect();
+        let observe = || TopicObservation {
+            topic_id: Uuid::new_v4(),
+            members: members.clone(),
+            centroids: HashMap::from([(Embedder::Semantic, vec![1.0, 0.0])]),
+        };
+
+        let mut portfolio = TopicPortfolio::new();
+        portfolio.stability_tracker.record_run(vec![observe()]);
+        portfolio.stability_tracker.record_run(vec![observe()]);
+
+        let exported = portfolio.export("session-runs", 0.3);
+        let bytes = exported.to_bytes().expect("serialize");
+        let restored = PersistedTopicPortfolio::from_bytes(&bytes).expect("deserialize");
+        assert_eq!(restored.stability_runs.len(), 2);
+
+        let mut reloaded = TopicPortfolio::new();
+        reloaded.import(&restored);
+        let run = reloaded.stability_tracker.record_run(vec![observe()]);
+        assert_eq!(run.topics[0].metrics.runs_observed, 3);
+        assert_eq!(run.topics[0].metrics.membership_churn, 0.0);
+
+        // Portfolios persisted before run tracking still load
+        let legacy = br#"{"topics":[],"churn_rate":0.1,"entropy":0.2,"session_id":"old","persisted_at_ms":1}"#;
+        let legacy = PersistedTopicPortfolio::from_bytes(legacy).expect("legacy deserialize");
+        assert!(legacy.stability_runs.is_empty());
+
+        println!("[PASS] test_stability_runs_survive_roundtrip");
+    }
+
 }
@@ -13,12 +13,23 @@
 //!
 //! churn = |symmetric_difference| / |union|
 //! where symmetric_difference = topics_added + topics_removed
+//!
+//! # Per-Topic Tracking
+//!
+//! Each detection run can also be recorded with `record_run`. Topics are
+//! matched to the previous run by membership Jaccard similarity using an
+//! optimal (Hungarian) assignment, giving each topic a track with membership
+//! churn, centroid drift, age and a composite stability score. Only the last
+//! `max_runs` runs are retained.
 
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::teleological::Embedder;
+
 use super::topic::Topic;
 
 /// Default churn threshold for dream trigger (0.5 per constitution).
@@ -73,6 +84,93 @@ impl TopicSnapshot {
     }
 }
 
+/// Default number of detection runs retained for per-topic tracking.
+pub const DEFAULT_MAX_TRACKED_RUNS: usize = 32;
+
+/// Minimum membership Jaccard similarity for a topic in one run to be
+/// considered the continuation of a topic in the previous run.
+pub const MIN_TRACK_JACCARD: f32 = 0.2;
+
+/// One topic as observed by a single detection run.
+///
+/// Topic IDs are regenerated on every synthesis, so identity across runs is
+/// recovered from membership overlap rather than from `topic_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicObservation {
+    /// Topic ID assigned by this run.
+    pub topic_id: Uuid,
+    /// Member memory IDs.
+    pub members: Vec<Uuid>,
+    /// Cluster centroid per contributing dense space.
+    ///
+    /// Only the most recent run keeps centroids; they are dropped from older
+    /// runs since drift is only ever measured against the previous run.
+    #[serde(default)]
+    pub centroids: HashMap<Embedder, Vec<f32>>,
+}
+
+/// Stability metrics of a tracked topic at one detection run.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TopicRunMetrics {
+    /// 1 - Jaccard(members, previous members); 1.0 for a newly seen topic.
+    pub membership_churn: f32,
+    /// Mean cosine distance between centroids of shared spaces, in [0, 1].
+    pub centroid_drift: f32,
+    /// Hours since the topic was first seen.
+    pub age_hours: f32,
+    /// Number of consecutive runs the topic has been observed in.
+    pub runs_observed: u32,
+    /// Composite score in [0, 1]: (1 - churn) * (1 - drift).
+    pub stability_score: f32,
+}
+
+/// A topic observation matched to a track spanning detection runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackedTopic {
+    /// Stable identity across runs (the topic ID of the first observation).
+    pub track_id: Uuid,
+    /// When this track was first observed.
+    pub first_seen: DateTime<Utc>,
+    /// The observation from this run.
+    pub observation: TopicObservation,
+    /// Metrics relative to the previous run.
+    pub metrics: TopicRunMetrics,
+}
+
+/// All tracked topics of a single detection run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicRun {
+    /// When the run was recorded.
+    pub timestamp: DateTime<Utc>,
+    /// Topics detected by the run.
+    pub topics: Vec<TrackedTopic>,
+}
+
+/// One point of a per-topic stability series.
+#[derive(Debug, Clone, Serialize)]
+pub struct TopicSeriesPoint {
+    /// Run timestamp.
+    pub timestamp: DateTime<Utc>,
+    /// Topic ID assigned by that run.
+    pub topic_id: Uuid,
+    /// Member count at that run.
+    pub member_count: usize,
+    /// Metrics at that run.
+    #[serde(flatten)]
+    pub metrics: TopicRunMetrics,
+}
+
+/// Stability series of one tracked topic across detection runs.
+#[derive(Debug, Clone, Serialize)]
+pub struct TopicSeries {
+    /// Stable track identity.
+    pub track_id: Uuid,
+    /// When the track was first observed.
+    pub first_seen: DateTime<Utc>,
+    /// Points in chronological order.
+    pub points: Vec<TopicSeriesPoint>,
+}
+
 /// Tracks portfolio-level topic stability and dream triggers.
 ///
 /// This is DISTINCT from TopicStability (per-topic metrics).
@@ -80,6 +178,7 @@ impl TopicSnapshot {
 /// - Takes periodic snapshots of all topics
 /// - Computes churn rate (topics appearing/disappearing)
 /// - Tracks high-entropy duration for dream triggers
+/// - Matches topics across detection runs for per-topic churn and drift
 ///
 /// # Dream Trigger Conditions
 ///
@@ -102,6 +201,10 @@ pub struct TopicStabilityTracker {
     entropy_duration_secs: u64,
     /// History of churn calculations with timestamps.
     churn_history: VecDeque<(DateTime<Utc>, f32)>,
+    /// Per-topic tracking of the most recent detection runs.
+    runs: VecDeque<TopicRun>,
+    /// Maximum number of detection runs retained.
+    max_runs: usize,
 }
 
 impl Default for TopicStabilityTracker {
@@ -125,6 +228,8 @@ impl TopicStabilityTracker {
             entropy_threshold: DEFAULT_ENTROPY_THRESHOLD,
             entropy_duration_secs: DEFAULT_ENTROPY_DURATION_SECS,
             churn_history: VecDeque::new(),
+            runs: VecDeque::new(),
+            max_runs: DEFAULT_MAX_TRACKED_RUNS,
         }
     }
 
@@ -143,9 +248,20 @@ impl TopicStabilityTracker {
             entropy_threshold: entropy.clamp(0.0, 1.0),
             entropy_duration_secs: duration_secs,
             churn_history: VecDeque::new(),
+            runs: VecDeque::new(),
+            max_runs: DEFAULT_MAX_TRACKED_RUNS,
         }
     }
 
+    /// Set the number of detection runs retained for per-topic tracking.
+    ///
+    /// Values below 1 are raised to 1.
+    #[must_use]
+    pub fn with_max_runs(mut self, max_runs: usize) -> Self {
+        self.max_runs = max_runs.max(1);
+        self
+    }
+
     /// Take a snapshot of current topic portfolio.
     ///
     /// Stores topic IDs and member counts. Old snapshots (>24h) are cleaned.
@@ -375,6 +491,122 @@ impl TopicStabilityTracker {
         (added, removed)
     }
 
+    /// Record a detection run for per-topic tracking.
+    ///
+    /// Each observation is matched to a topic of the previous run by maximum
+    /// total membership Jaccard (Hungarian assignment). Pairs below
+    /// `MIN_TRACK_JACCARD` are treated as unmatched, so the observation starts
+    /// a new track. Only the last `max_runs` runs are retained.
+    pub fn record_run(&mut self, observations: Vec<TopicObservation>) -> &TopicRun {
+        self.record_run_at(observations, Utc::now())
+    }
+
+    /// Record a detection run with an explicit timestamp.
+    fn record_run_at(
+        &mut self,
+        observations: Vec<TopicObservation>,
+        timestamp: DateTime<Utc>,
+    ) -> &TopicRun {
+        let topics: Vec<TrackedTopic> = match self.runs.back() {
+            Some(prev) => {
+                let matches = match_observations(&prev.topics, &observations);
+                observations
+                    .into_iter()
+                    .zip(matches)
+                    .map(|(obs, matched)| match matched {
+                        Some(j) => continue_track(&prev.topics[j], obs, timestamp),
+                        None => start_track(obs, timestamp),
+                    })
+                    .collect()
+            }
+            None => observations
+                .into_iter()
+                .map(|obs| start_track(obs, timestamp))
+                .collect(),
+        };
+
+        // Drift is only measured against the previous run, so older runs
+        // do not need their centroids.
+        if let Some(prev) = self.runs.back_mut() {
+            for topic in &mut prev.topics {
+                topic.observation.centroids.clear();
+            }
+        }
+
+        tracing::debug!(
+            topic_count = topics.len(),
+            continued = topics
+                .iter()
+                .filter(|t| t.metrics.runs_observed > 1)
+                .count(),
+            "Stability: detection run recorded"
+        );
+
+        self.runs.push_back(TopicRun { timestamp, topics });
+        while self.runs.len() > self.max_runs {
+            self.runs.pop_front();
+        }
+        self.runs.back().expect("run was just pushed")
+    }
+
+    /// Retained detection runs, oldest first.
+    pub fn runs(&self) -> impl Iterator<Item = &TopicRun> {
+        self.runs.iter()
+    }
+
+    /// Most recent detection run.
+    pub fn latest_run(&self) -> Option<&TopicRun> {
+        self.runs.back()
+    }
+
+    /// Number of retained detection runs.
+    #[inline]
+    pub fn run_count(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// Restore detection runs (e.g. from a persisted portfolio).
+    ///
+    /// Replaces any existing runs; keeps only the newest `max_runs`.
+    pub fn restore_runs(&mut self, runs: Vec<TopicRun>) {
+        let skip = runs.len().saturating_sub(self.max_runs);
+        self.runs = runs.into_iter().skip(skip).collect();
+    }
+
+    /// Per-topic stability series across the retained runs.
+    ///
+    /// # Arguments
+    /// * `since` - Only include points at or after this time (all if `None`)
+    ///
+    /// # Returns
+    /// One series per track, ordered by first appearance.
+    pub fn topic_series(&self, since: Option<DateTime<Utc>>) -> Vec<TopicSeries> {
+        let mut series: Vec<TopicSeries> = Vec::new();
+        let mut index: HashMap<Uuid, usize> = HashMap::new();
+
+        let in_window = |run: &&TopicRun| since.map_or(true, |s| run.timestamp >= s);
+        for run in self.runs.iter().filter(in_window) {
+            for topic in &run.topics {
+                let idx = *index.entry(topic.track_id).or_insert_with(|| {
+                    series.push(TopicSeries {
+                        track_id: topic.track_id,
+                        first_seen: topic.first_seen,
+                        points: Vec::new(),
+                    });
+                    series.len() - 1
+                });
+                series[idx].points.push(TopicSeriesPoint {
+                    timestamp: run.timestamp,
+                    topic_id: topic.observation.topic_id,
+                    member_count: topic.observation.members.len(),
+                    metrics: topic.metrics,
+                });
+            }
+        }
+
+        series
+    }
+
     /// Reset high-entropy tracking (call after dream completes).
     pub fn reset_entropy_tracking(&mut self) {
         self.high_entropy_start = None;
@@ -407,6 +639,235 @@ impl TopicStabilityTracker {
     }
 }
 
+/// Start a new track from an unmatched observation.
+fn start_track(observation: TopicObservation, timestamp: DateTime<Utc>) -> TrackedTopic {
+    TrackedTopic {
+        track_id: observation.topic_id,
+        first_seen: timestamp,
+        observation,
+        metrics: TopicRunMetrics {
+            membership_churn: 1.0,
+            centroid_drift: 0.0,
+            age_hours: 0.0,
+            runs_observed: 1,
+            stability_score: 0.0,
+        },
+    }
+}
+
+/// Continue `prev`'s track with a matched observation.
+fn continue_track(
+    prev: &TrackedTopic,
+    observation: TopicObservation,
+    timestamp: DateTime<Utc>,
+) -> TrackedTopic {
+    let churn = 1.0 - membership_jaccard(&prev.observation.members, &observation.members);
+    let drift = centroid_drift(&prev.observation.centroids, &observation.centroids);
+    let age_ms = timestamp
+        .signed_duration_since(prev.first_seen)
+        .num_milliseconds();
+    let age_hours = (age_ms as f32 / 3_600_000.0).max(0.0);
+
+    TrackedTopic {
+        track_id: prev.track_id,
+        first_seen: prev.first_seen,
+        observation,
+        metrics: TopicRunMetrics {
+            membership_churn: churn,
+            centroid_drift: drift,
+            age_hours,
+            runs_observed: prev.metrics.runs_observed.saturating_add(1),
+            stability_score: ((1.0 - churn) * (1.0 - drift)).clamp(0.0, 1.0),
+        },
+    }
+}
+
+/// Jaccard similarity of two member sets. Two empty sets are identical.
+pub(crate) fn membership_jaccard(a: &[Uuid], b: &[Uuid]) -> f32 {
+    let a: HashSet<_> = a.iter().collect();
+    let b: HashSet<_> = b.iter().collect();
+
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(&b).count() as f32 / union as f32
+}
+
+/// Mean cosine distance over spaces with a centroid in both runs.
+///
+/// Each per-space distance is clamped to [0, 1]; returns 0.0 when no space
+/// is shared or a centroid is degenerate.
+pub(crate) fn centroid_drift(
+    prev: &HashMap<Embedder, Vec<f32>>,
+    curr: &HashMap<Embedder, Vec<f32>>,
+) -> f32 {
+    let mut sum = 0.0f32;
+    let mut count = 0usize;
+
+    for (space, a) in prev {
+        let Some(b) = curr.get(space) else {
+            continue;
+        };
+        if a.len() != b.len() {
+            continue;
+        }
+        let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm_a < f32::EPSILON || norm_b < f32::EPSILON {
+            continue;
+        }
+        let distance = 1.0 - dot / (norm_a * norm_b);
+        if distance.is_finite() {
+            sum += distance.clamp(0.0, 1.0);
+            count += 1;
+        }
+    }
+
+    if count == 0 {
+        0.0
+    } else {
+        sum / count as f32
+    }
+}
+
+/// Match current observations to previous-run topics.
+///
+/// Returns, for each observation, the index of its matched previous topic.
+fn match_observations(prev: &[TrackedTopic], curr: &[TopicObservation]) -> Vec<Option<usize>> {
+    let prev: Vec<&[Uuid]> = prev
+        .iter()
+        .map(|p| p.observation.members.as_slice())
+        .collect();
+    let curr: Vec<&[Uuid]> = curr.iter().map(|c| c.members.as_slice()).collect();
+    match_by_membership(&prev, &curr)
+}
+
+/// Match current member sets to previous member sets.
+///
+/// Maximum total Jaccard assignment; pairs below `MIN_TRACK_JACCARD` are
+/// left unmatched. Returns, for each current set, the index of its match.
+pub(crate) fn match_by_membership(prev: &[&[Uuid]], curr: &[&[Uuid]]) -> Vec<Option<usize>> {
+    if prev.is_empty() || curr.is_empty() {
+        return vec![None; curr.len()];
+    }
+
+    let similarity: Vec<Vec<f32>> = curr
+        .iter()
+        .map(|c| prev.iter().map(|p| membership_jaccard(p, c)).collect())
+        .collect();
+
+    max_weight_assignment(&similarity)
+        .into_iter()
+        .enumerate()
+        .map(|(i, j)| j.filter(|&j| similarity[i][j] >= MIN_TRACK_JACCARD))
+        .collect()
+}
+
+/// Maximum-weight bipartite assignment of rows to columns.
+///
+/// Runs the Hungarian algorithm on `1 - weight` with the smaller side as
+/// rows. Returns the assigned column for each row; rows left over when
+/// there are more rows than columns get `None`.
+fn max_weight_assignment(weights: &[Vec<f32>]) -> Vec<Option<usize>> {
+    let rows = weights.len();
+    let cols = weights.first().map_or(0, Vec::len);
+    if rows == 0 || cols == 0 {
+        return vec![None; rows];
+    }
+
+    if rows <= cols {
+        let cost: Vec<Vec<f64>> = weights
+            .iter()
+            .map(|r| r.iter().map(|&w| 1.0 - w as f64).collect())
+            .collect();
+        hungarian(&cost, cols).into_iter().map(Some).collect()
+    } else {
+        let cost: Vec<Vec<f64>> = (0..cols)
+            .map(|j| (0..rows).map(|i| 1.0 - weights[i][j] as f64).collect())
+            .collect();
+        let mut assignment = vec![None; rows];
+        for (j, i) in hungarian(&cost, rows).into_iter().enumerate() {
+            assignment[i] = Some(j);
+        }
+        assignment
+    }
+}
+
+/// Minimum-cost assignment for an `n x m` cost matrix with `n <= m`.
+///
+/// O(n^2 m) potentials-based Hungarian algorithm. Returns the column
+/// assigned to each row.
+fn hungarian(cost: &[Vec<f64>], m: usize) -> Vec<usize> {
+    let n = cost.len();
+    let mut u = vec![0.0f64; n + 1];
+    let mut v = vec![0.0f64; m + 1];
+    // p[j]: row (1-based) assigned to column j; column 0 is a sentinel.
+    let mut p = vec![0usize; m + 1];
+    let mut way = vec![0usize; m + 1];
+
+    for i in 1..=n {
+        p[0] = i;
+        let mut j0 = 0usize;
+        let mut minv = vec![f64::INFINITY; m + 1];
+        let mut used = vec![false; m + 1];
+
+        loop {
+            used[j0] = true;
+            let i0 = p[j0];
+            let mut delta = f64::INFINITY;
+            let mut j1 = 0usize;
+
+            for j in 1..=m {
+                if !used[j] {
+                    let reduced = cost[i0 - 1][j - 1] - u[i0] - v[j];
+                    if reduced < minv[j] {
+                        minv[j] = reduced;
+                        way[j] = j0;
+                    }
+                    if minv[j] < delta {
+                        delta = minv[j];
+                        j1 = j;
+                    }
+                }
+            }
+
+            for j in 0..=m {
+                if used[j] {
+                    u[p[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    minv[j] -= delta;
+                }
+            }
+
+            j0 = j1;
+            if p[j0] == 0 {
+                break;
+            }
+        }
+
+        // Augment along the alternating path.
+        loop {
+            let j1 = way[j0];
+            p[j0] = p[j1];
+            j0 = j1;
+            if j0 == 0 {
+                break;
+            }
+        }
+    }
+
+    let mut assignment = vec![0usize; n];
+    for j in 1..=m {
+        if p[j] != 0 {
+            assignment[p[j] - 1] = j - 1;
+        }
+    }
+    assignment
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -587,4 +1048,195 @@ mod tests {
         println!("[PASS] Stability detection works correctly\n");
     }
 
+    // ===== Per-Topic Run Tracking Tests =====
+
+    fn members(ids: impl IntoIterator<Item = u128>) -> Vec<Uuid> {
+        ids.into_iter().map(Uuid::from_u128).collect()
+    }
+
+    fn observation(ids: Vec<Uuid>, centroid: Option<Vec<f32>>) -> TopicObservation {
+        TopicObservation {
+            topic_id: Uuid::new_v4(),
+            members: ids,
+            centroids: centroid
+                .map(|c| HashMap::from([(Embedder::Semantic, c)]))
+                .unwrap_or_default(),
+        }
+    }
+
+    fn track_of<'a>(run: &'a TopicRun, member: u128) -> &'a TrackedTopic {
+        run.topics
+            .iter()
+            .find(|t| t.observation.members.contains(&Uuid::from_u128(member)))
+            .expect("topic containing member")
+    }
+
+    #[test]
+    fn test_record_runs_hand_calculated_metrics() {
+        println!("=== TEST: test_record_runs_hand_calculated_metrics ===");
+        let mut tracker = TopicStabilityTracker::new();
+        let t0 = Utc::now() - Duration::hours(3);
+
+        // Run 1: A = {1..10} at [1, 0], B = {11..20} at [0, 1]
+        let run1 = tracker.record_run_at(
+            vec![
+                observation(members(1..=10), Some(vec![1.0, 0.0])),
+                observation(members(11..=20), Some(vec![0.0, 1.0])),
+            ],
+            t0,
+        );
+        assert!(run1.topics.iter().all(|t| t.metrics.runs_observed == 1));
+        let all_new = run1.topics.iter().all(|t| t.metrics.membership_churn == 1.0);
+        assert!(all_new);
+        let track_a = track_of(run1, 1).track_id;
+        let track_b = track_of(run1, 11).track_id;
+
+        // Run 2 (+1h), submitted in a different order:
+        // C = {30..35} is new
+        // B unchanged
+        // A loses {9, 10}, gains {21, 22}, centroid moves to [0.8, 0.6]
+        let mut a2 = members(1..=8);
+        a2.extend(members([21, 22]));
+        let run2 = tracker.record_run_at(
+            vec![
+                observation(members(30..=35), None),
+                observation(members(11..=20), Some(vec![0.0, 1.0])),
+                observation(a2.clone(), Some(vec![0.8, 0.6])),
+            ],
+            t0 + Duration::hours(1),
+        );
+
+        // A: Jaccard = 8/12, churn = 1/3; drift = 1 - cos = 1 - 0.8 = 0.2
+        // stability = (2/3) * 0.8 = 0.5333
+        let a = track_of(run2, 1);
+        println!("run 2 A metrics: {:?}", a.metrics);
+        assert_eq!(a.track_id, track_a);
+        assert!((a.metrics.membership_churn - 1.0 / 3.0).abs() < 1e-5);
+        assert!((a.metrics.centroid_drift - 0.2).abs() < 1e-5);
+        assert!((a.metrics.stability_score - 0.533_333).abs() < 1e-5);
+        assert!((a.metrics.age_hours - 1.0).abs() < 1e-4);
+        assert_eq!(a.metrics.runs_observed, 2);
+
+        let b = track_of(run2, 11);
+        assert_eq!(b.track_id, track_b);
+        assert_eq!(b.metrics.membership_churn, 0.0);
+        assert!(b.metrics.centroid_drift.abs() < 1e-6);
+        assert!((b.metrics.stability_score - 1.0).abs() < 1e-6);
+
+        let c = track_of(run2, 30);
+        assert_eq!(c.metrics.runs_observed, 1);
+        let track_c = c.track_id;
+
+        // Run 3 (+3h): B disappears
+        // A keeps its members, centroid moves to [0.6, 0.8]
+        // C loses {35}
+        // D = {11, 12, 40..47} overlaps B by 2/18 < MIN_TRACK_JACCARD, so it is new
+        let mut d3 = members([11, 12]);
+        d3.extend(members(40..=47));
+        let run3 = tracker.record_run_at(
+            vec![
+                observation(a2, Some(vec![0.6, 0.8])),
+                observation(members(30..=34), None),
+                observation(d3, None),
+            ],
+            t0 + Duration::hours(3),
+        );
+
+        // A: churn 0; drift = 1 - (0.48 + 0.48) = 0.04; stability 0.96
+        let a = track_of(run3, 1);
+        println!("run 3 A metrics: {:?}", a.metrics);
+        assert_eq!(a.track_id, track_a);
+        assert_eq!(a.metrics.membership_churn, 0.0);
+        assert!((a.metrics.centroid_drift - 0.04).abs() < 1e-5);
+        assert!((a.metrics.stability_score - 0.96).abs() < 1e-5);
+        assert!((a.metrics.age_hours - 3.0).abs() < 1e-4);
+        assert_eq!(a.metrics.runs_observed, 3);
+
+        // C: Jaccard = 5/6, churn = 1/6, no centroids => drift 0
+        let c = track_of(run3, 30);
+        assert_eq!(c.track_id, track_c);
+        assert!((c.metrics.membership_churn - 1.0 / 6.0).abs() < 1e-5);
+        assert_eq!(c.metrics.centroid_drift, 0.0);
+        assert!((c.metrics.stability_score - 5.0 / 6.0).abs() < 1e-5);
+        assert!((c.metrics.age_hours - 2.0).abs() < 1e-4);
+
+        let d = track_of(run3, 40);
+        assert_ne!(d.track_id, track_b);
+        assert_eq!(d.metrics.runs_observed, 1);
+
+        // Series: A, B, C, D in order of first appearance
+        let series = tracker.topic_series(None);
+        assert_eq!(series.len(), 4);
+        assert_eq!(series[0].track_id, track_a);
+        assert_eq!(series[0].points.len(), 3);
+        assert_eq!(series[1].track_id, track_b);
+        assert_eq!(series[1].points.len(), 2);
+
+        // Lookback excluding run 1
+        let recent = tracker.topic_series(Some(t0 + Duration::minutes(30)));
+        assert_eq!(recent[0].track_id, track_c);
+        assert_eq!(recent.len(), 4);
+
+        println!("[PASS] Per-topic churn and drift match hand calculations\n");
+    }
+
+    #[test]
+    fn test_max_weight_assignment_is_optimal() {
+        println!("=== TEST: test_max_weight_assignment_is_optimal ===");
+
+        // Greedy would take (0,0)=0.6 and leave row 1 with 0.0;
+        // the optimum is (0,1)+(1,0) = 1.0.
+        let square = vec![vec![0.6, 0.5], vec![0.5, 0.0]];
+        assert_eq!(max_weight_assignment(&square), vec![Some(1), Some(0)]);
+
+        // More rows than columns: the weakest row stays unassigned.
+        let tall = vec![vec![0.9, 0.1], vec![0.8, 0.7], vec![0.1, 0.2]];
+        assert_eq!(max_weight_assignment(&tall), vec![Some(0), Some(1), None]);
+
+        // More columns than rows.
+        let wide = vec![vec![0.1, 0.3, 0.9]];
+        assert_eq!(max_weight_assignment(&wide), vec![Some(2)]);
+
+        assert!(max_weight_assignment(&[]).is_empty());
+
+        println!("[PASS] Hungarian assignment maximizes total weight\n");
+    }
+
+    #[test]
+    fn test_runs_bounded_and_restorable() {
+        println!("=== TEST: test_runs_bounded_and_restorable ===");
+        let mut tracker = TopicStabilityTracker::new().with_max_runs(2);
+        let t0 = Utc::now() - Duration::hours(3);
+
+        for h in 0..3 {
+            tracker.record_run_at(
+                vec![observation(members(1..=5), Some(vec![1.0, 0.0]))],
+                t0 + Duration::hours(h),
+            );
+        }
+
+        assert_eq!(tracker.run_count(), 2);
+        let runs: Vec<_> = tracker.runs().cloned().collect();
+        // Only the latest run keeps centroids
+        assert!(runs[0].topics[0].observation.centroids.is_empty());
+        assert_eq!(runs[1].topics[0].observation.centroids.len(), 1);
+        assert_eq!(runs[1].topics[0].metrics.runs_observed, 3);
+
+        let json = serde_json::to_string(&runs).expect("serialize runs");
+        let restored: Vec<TopicRun> = serde_json::from_str(&json).expect("deserialize runs");
+
+        let mut fresh = TopicStabilityTracker::new().with_max_runs(1);
+        fresh.restore_runs(restored);
+        assert_eq!(fresh.run_count(), 1);
+
+        // Tracking continues from the restored run
+        let run = fresh.record_run_at(
+            vec![observation(members(1..=5), Some(vec![1.0, 0.0]))],
+            t0 + Duration::hours(3),
+        );
+        assert_eq!(run.topics[0].metrics.runs_observed, 4);
+        assert_eq!(run.topics[0].metrics.membership_churn, 0.0);
+
+        println!("[PASS] Runs are bounded, persistable and restorable\n");
+    }
 }
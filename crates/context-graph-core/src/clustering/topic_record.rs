@@ -0,0 +1,373 @@
+//! Durable topic records and their reconciliation across detection runs.
+//!
+//! Topic IDs produced by synthesis are derived from membership, so they
+//! change whenever a single member joins or leaves. A [`TopicRecord`] is the
+//! stored form of a topic: its ID is assigned once, when the topic first
+//! appears, and carried forward by [`reconcile_topic_records`] as long as
+//! later detection runs keep finding a cluster with overlapping membership.
+//!
+//! Matching uses the same maximum-Jaccard assignment as the
+//! [`TopicStabilityTracker`](super::TopicStabilityTracker), with the same
+//! `MIN_TRACK_JACCARD` cutoff, so a stored topic and its stability track
+//! agree on what counts as "the same topic".
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::teleological::Embedder;
+
+use super::stability::{centroid_drift, match_by_membership, membership_jaccard};
+use super::topic::{Topic, TopicPhase, TopicProfile};
+
+/// A topic as persisted between detection runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicRecord {
+    /// Stable identity, assigned when the topic was first detected.
+    pub id: Uuid,
+    /// Human-readable label: generated from member content once the topic
+    /// has been labeled, otherwise the synthesis name from the latest run.
+    pub label: Option<String>,
+    /// One-sentence description generated with the label.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Membership the label was generated from; compared with `member_ids`
+    /// to decide when to relabel.
+    #[serde(default)]
+    pub labeled_member_ids: Vec<Uuid>,
+    /// When the label was generated; `None` for a synthesis name.
+    #[serde(default)]
+    pub labeled_at: Option<DateTime<Utc>>,
+    /// Per-space strength profile from the most recent run.
+    pub profile: TopicProfile,
+    /// Spaces where the topic has strong representation.
+    pub contributing_spaces: Vec<Embedder>,
+    /// Cluster centroid per contributing dense space.
+    #[serde(default)]
+    pub centroids: HashMap<Embedder, Vec<f32>>,
+    /// Member memory IDs.
+    pub member_ids: Vec<Uuid>,
+    /// Weighted agreement per ARCH-09 (>= 2.5 for a topic).
+    pub weighted_agreement: f32,
+    /// weighted_agreement / 8.5.
+    pub confidence: f32,
+    /// Lifecycle phase reported by synthesis.
+    pub phase: TopicPhase,
+    /// (1 - membership churn) * (1 - centroid drift) against the previous
+    /// run; 0.0 for a topic seen once.
+    pub stability_score: f32,
+    /// Number of consecutive detection runs that found this topic.
+    pub runs_observed: u32,
+    /// Parent topic ID for hierarchical topics.
+    #[serde(default)]
+    pub parent_id: Option<Uuid>,
+    /// Depth in the topic hierarchy (0 = root).
+    #[serde(default)]
+    pub depth: u8,
+    /// When the topic was first detected.
+    pub created_at: DateTime<Utc>,
+    /// When the record last changed (detection run or member removal).
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TopicRecord {
+    /// Build a record for a freshly detected topic.
+    ///
+    /// The record takes the topic's synthesis ID; reconciliation replaces it
+    /// with the stored ID when the topic continues an existing one.
+    pub fn from_topic(
+        topic: &Topic,
+        centroids: HashMap<Embedder, Vec<f32>>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: topic.id,
+            label: topic.name.clone(),
+            description: None,
+            labeled_member_ids: Vec::new(),
+            labeled_at: None,
+            profile: topic.profile.clone(),
+            contributing_spaces: topic.contributing_spaces.clone(),
+            centroids,
+            member_ids: topic.member_memories.clone(),
+            weighted_agreement: topic.profile.weighted_agreement(),
+            confidence: topic.confidence,
+            phase: topic.stability.phase,
+            stability_score: 0.0,
+            runs_observed: 1,
+            parent_id: topic.parent_id,
+            depth: topic.depth,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Number of member memories.
+    #[inline]
+    pub fn member_count(&self) -> usize {
+        self.member_ids.len()
+    }
+
+    /// Remove `removed` from the member list, adding `replacement` in their
+    /// place if any were present (merges).
+    ///
+    /// Returns true if the member list changed.
+    pub fn replace_members(
+        &mut self,
+        removed: &HashSet<Uuid>,
+        replacement: Option<Uuid>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        let before = self.member_ids.len();
+        self.member_ids.retain(|id| !removed.contains(id));
+        if self.member_ids.len() == before {
+            return false;
+        }
+        if let Some(merged) = replacement {
+            if !self.member_ids.contains(&merged) {
+                self.member_ids.push(merged);
+            }
+        }
+        self.updated_at = now;
+        true
+    }
+}
+
+/// Result of reconciling a detection run against the stored topics.
+#[derive(Debug, Clone, Default)]
+pub struct TopicReconciliation {
+    /// Records to write: continued topics under their stored IDs, plus new
+    /// topics under fresh IDs.
+    pub upserts: Vec<TopicRecord>,
+    /// Stored topic IDs no longer found by detection.
+    pub retired: Vec<Uuid>,
+    /// Number of upserts that continue a stored topic.
+    pub continued: usize,
+    /// Number of upserts that are new topics.
+    pub created: usize,
+}
+
+/// Reconcile freshly detected topics with the stored records.
+///
+/// Each detected topic is matched to at most one stored topic by membership
+/// overlap. A matched topic keeps the stored ID and `created_at`, and its
+/// stability score is computed from the membership churn and centroid drift
+/// since the stored record. A generated label is carried forward with the
+/// membership it was generated from, so the labeler can decide whether the
+/// topic has drifted far enough to relabel. Unmatched detected topics are new; unmatched
+/// stored topics are retired. Parent links are rewritten to the stored IDs.
+pub fn reconcile_topic_records(
+    existing: &[TopicRecord],
+    detected: Vec<TopicRecord>,
+    now: DateTime<Utc>,
+) -> TopicReconciliation {
+    let prev: Vec<&[Uuid]> = existing.iter().map(|r| r.member_ids.as_slice()).collect();
+    let curr: Vec<&[Uuid]> = detected.iter().map(|r| r.member_ids.as_slice()).collect();
+    let matches = match_by_membership(&prev, &curr);
+
+    let mut id_map: HashMap<Uuid, Uuid> = HashMap::new();
+    let mut matched_existing: HashSet<usize> = HashSet::new();
+    let mut reconciliation = TopicReconciliation::default();
+
+    for (mut record, matched) in detected.into_iter().zip(matches) {
+        match matched {
+            Some(j) => {
+                let stored = &existing[j];
+                let churn = 1.0 - membership_jaccard(&stored.member_ids, &record.member_ids);
+                let drift = centroid_drift(&stored.centroids, &record.centroids);
+
+                id_map.insert(record.id, stored.id);
+                record.id = stored.id;
+                if stored.labeled_at.is_some() {
+                    record.label = stored.label.clone();
+                    record.description = stored.description.clone();
+                    record.labeled_member_ids = stored.labeled_member_ids.clone();
+                    record.labeled_at = stored.labeled_at;
+                } else {
+                    record.label = record.label.or_else(|| stored.label.clone());
+                }
+                record.created_at = stored.created_at;
+                record.runs_observed = stored.runs_observed.saturating_add(1);
+                record.stability_score = ((1.0 - churn) * (1.0 - drift)).clamp(0.0, 1.0);
+                matched_existing.insert(j);
+                reconciliation.continued += 1;
+            }
+            None => {
+                record.created_at = now;
+                record.runs_observed = 1;
+                record.stability_score = 0.0;
+                reconciliation.created += 1;
+            }
+        }
+        record.updated_at = now;
+        reconciliation.upserts.push(record);
+    }
+
+    for record in &mut reconciliation.upserts {
+        if let Some(parent) = record.parent_id {
+            record.parent_id = Some(id_map.get(&parent).copied().unwrap_or(parent));
+        }
+    }
+
+    reconciliation.retired = existing
+        .iter()
+        .enumerate()
+        .filter(|(j, _)| !matched_existing.contains(j))
+        .map(|(_, r)| r.id)
+        .collect();
+
+    tracing::debug!(
+        continued = reconciliation.continued,
+        created = reconciliation.created,
+        retired = reconciliation.retired.len(),
+        "Topic records reconciled"
+    );
+
+    reconciliation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn members(ids: impl IntoIterator<Item = u128>) -> Vec<Uuid> {
+        ids.into_iter().map(Uuid::from_u128).collect()
+    }
+
+    fn detected(id: u128, ids: Vec<Uuid>, centroid: Vec<f32>, now: DateTime<Utc>) -> TopicRecord {
+        let mut topic = Topic::new(
+            TopicProfile::new([0.0; 13]),
+            HashMap::from([(Embedder::Semantic, 0)]),
+            ids,
+        );
+        topic.id = Uuid::from_u128(id);
+        TopicRecord::from_topic(&topic, HashMap::from([(Embedder::Semantic, centroid)]), now)
+    }
+
+    #[test]
+    fn test_reconcile_keeps_ids_across_drifting_runs() {
+        let t0 = Utc::now();
+        let first = reconcile_topic_records(
+            &[],
+            vec![
+                detected(1, members(1..=5), vec![1.0, 0.0], t0),
+                detected(2, members(10..=14), vec![0.0, 1.0], t0),
+            ],
+            t0,
+        );
+        assert_eq!(first.created, 2);
+        assert!(first.retired.is_empty());
+        let stored = first.upserts;
+
+        // Second run: topic A gains a member, topic B loses one, a third
+        // cluster appears. Synthesis IDs all change.
+        let t1 = t0 + chrono::Duration::minutes(10);
+        let second = reconcile_topic_records(
+            &stored,
+            vec![
+                detected(101, members(10..=13), vec![0.0, 1.0], t1),
+                detected(102, members(1..=6), vec![1.0, 0.0], t1),
+                detected(103, members(20..=24), vec![1.0, 1.0], t1),
+            ],
+            t1,
+        );
+
+        assert_eq!(second.continued, 2);
+        assert_eq!(second.created, 1);
+        assert!(second.retired.is_empty());
+        assert_eq!(second.upserts[0].id, Uuid::from_u128(2));
+        assert_eq!(second.upserts[1].id, Uuid::from_u128(1));
+        assert_eq!(second.upserts[2].id, Uuid::from_u128(103));
+
+        // A: Jaccard 5/6, same centroid
+        let a = &second.upserts[1];
+        assert_eq!(a.created_at, t0);
+        assert_eq!(a.updated_at, t1);
+        assert_eq!(a.runs_observed, 2);
+        assert!((a.stability_score - 5.0 / 6.0).abs() < 1e-6);
+
+        let new = &second.upserts[2];
+        assert_eq!(new.created_at, t1);
+        assert_eq!(new.runs_observed, 1);
+        assert_eq!(new.stability_score, 0.0);
+    }
+
+    #[test]
+    fn test_reconcile_retires_vanished_topics_and_remaps_parents() {
+        let t0 = Utc::now();
+        let stored = reconcile_topic_records(
+            &[],
+            vec![
+                detected(1, members(1..=5), vec![1.0, 0.0], t0),
+                detected(2, members(10..=14), vec![0.0, 1.0], t0),
+            ],
+            t0,
+        )
+        .upserts;
+
+        let mut child = detected(201, members(1..=3), vec![1.0, 0.0], t0);
+        child.parent_id = Some(Uuid::from_u128(200));
+        let result = reconcile_topic_records(
+            &stored,
+            vec![detected(200, members(1..=5), vec![1.0, 0.0], t0), child],
+            t0,
+        );
+
+        assert_eq!(result.retired, vec![Uuid::from_u128(2)]);
+        assert_eq!(result.upserts[1].parent_id, Some(Uuid::from_u128(1)));
+    }
+
+    #[test]
+    fn test_replace_members() {
+        let now = Utc::now();
+        let mut record = detected(1, members(1..=4), vec![1.0], now);
+
+        let unrelated: HashSet<Uuid> = members([9]).into_iter().collect();
+        assert!(!record.replace_members(&unrelated, None, now));
+
+        let merged = Uuid::from_u128(50);
+        let sources: HashSet<Uuid> = members([1, 2]).into_iter().collect();
+        assert!(record.replace_members(&sources, Some(merged), now));
+        assert_eq!(
+            record.member_ids,
+            vec![Uuid::from_u128(3), Uuid::from_u128(4), merged]
+        );
+
+        let deleted: HashSet<Uuid> = members([3]).into_iter().collect();
+        assert!(record.replace_members(&deleted, None, now));
+        assert_eq!(record.member_count(), 2);
+    }
+
+    #[test]
+    fn test_reconcile_carries_generated_label() {
+        let t0 = Utc::now();
+        let mut stored = detected(1, members(1..=5), vec![1.0, 0.0], t0);
+        stored.label = Some("rust, async, runtime".into());
+        stored.description = Some("5 memories about rust.".into());
+        stored.labeled_member_ids = members(1..=5);
+        stored.labeled_at = Some(t0);
+        let unlabeled = detected(2, members(10..=14), vec![0.0, 1.0], t0);
+
+        let result = reconcile_topic_records(
+            &[stored, unlabeled],
+            vec![
+                detected(101, members(1..=6), vec![1.0, 0.0], t0),
+                detected(102, members(10..=14), vec![0.0, 1.0], t0),
+            ],
+            t0,
+        );
+
+        let labeled = &result.upserts[0];
+        assert_eq!(labeled.label.as_deref(), Some("rust, async, runtime"));
+        assert_eq!(labeled.labeled_member_ids, members(1..=5));
+        assert_eq!(labeled.labeled_at, Some(t0));
+        // Synthesis names are refreshed every run
+        assert_eq!(
+            result.upserts[1].label.as_deref(),
+            Some("Topic (5 memories)")
+        );
+        assert!(result.upserts[1].labeled_at.is_none());
+    }
+}
@@ -0,0 +1,614 @@
+//! Content-type classification for language-aware E1/E7 routing.
+//!
+//! [`ContentClassifier`] estimates how much of a text is prose, code, a mix
+//! of both, or structured data. It uses string heuristics only, with no model
+//! call:
+//!
+//! - **Fences**: lines inside a markdown ```` ``` ```` block are code
+//! - **Symbol density**: the share of non-whitespace characters that are
+//!   code punctuation (`{}()[];=<>&|` etc.)
+//! - **Keyword hits**: the per-language indicators of
+//!   [`detect_code_query_type`](super::detect_code_query_type), matched at
+//!   word starts, counted per language
+//! - **Structure**: JSON documents, `key: value` / `key = value` lines and
+//!   delimited table rows
+//!
+//! The resulting [`ContentDistribution`] drives two decisions:
+//!
+//! - At store time, pure prose skips E7. The code model has nothing to say
+//!   about prose, and its vector only adds noise to fusion.
+//! - At query time, [`ContentClassification::fusion_weights`] blends the
+//!   prose, semantic and code weight profiles by the distribution.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::teleological::{Embedder, EmbedderMask};
+use crate::types::fingerprint::NUM_EMBEDDERS;
+use crate::weights::get_weight_profile;
+
+use super::{
+    C_CPP_INDICATORS, GENERAL_CODE_INDICATORS, GO_INDICATORS, JS_TS_INDICATORS,
+    PYTHON_INDICATORS, RUST_INDICATORS,
+};
+
+/// Minimum prose probability for content to count as pure prose (E7 skipped).
+pub const PURE_PROSE_MIN: f32 = 0.9;
+
+/// Weight profile blended in for the code share of a distribution.
+const CODE_PROFILE: &str = "code_search";
+
+/// Weight profile blended in for the mixed and structured shares.
+const SEMANTIC_PROFILE: &str = "semantic_search";
+
+/// Characters counted as code punctuation for symbol density.
+const CODE_SYMBOLS: &[char] = &[
+    '{', '}', '[', ']', '(', ')', '<', '>', ';', '=', '&', '|', '*', '$', '@', '\\', '/', '+',
+    '^', '~', '`', '"', '_',
+];
+
+/// Languages whose indicator lists vote for a code line.
+const LANGUAGES: [(&str, &[&str]); 5] = [
+    ("rust", RUST_INDICATORS),
+    ("python", PYTHON_INDICATORS),
+    ("javascript", JS_TS_INDICATORS),
+    ("go", GO_INDICATORS),
+    ("c_cpp", C_CPP_INDICATORS),
+];
+
+/// Kind of content a text holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentType {
+    /// Natural-language text.
+    Prose,
+    /// Source code.
+    Code,
+    /// Prose interleaved with code, e.g. a markdown doc with snippets.
+    Mixed,
+    /// JSON, YAML, TOML, CSV and similar.
+    StructuredData,
+}
+
+impl ContentType {
+    /// All content types.
+    pub const ALL: [ContentType; 4] = [
+        ContentType::Prose,
+        ContentType::Code,
+        ContentType::Mixed,
+        ContentType::StructuredData,
+    ];
+
+    /// Lowercase name, as accepted by the `content_type` tool argument.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ContentType::Prose => "prose",
+            ContentType::Code => "code",
+            ContentType::Mixed => "mixed",
+            ContentType::StructuredData => "structured_data",
+        }
+    }
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ContentType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ContentType::ALL
+            .into_iter()
+            .find(|t| t.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                format!(
+                    "Unknown content_type '{}'. Valid: prose, code, mixed, structured_data",
+                    s
+                )
+            })
+    }
+}
+
+/// Probability of each [`ContentType`]; the four shares sum to 1.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ContentDistribution {
+    pub prose: f32,
+    pub code: f32,
+    pub mixed: f32,
+    pub structured_data: f32,
+}
+
+impl ContentDistribution {
+    /// All probability on `content_type`.
+    pub fn one_hot(content_type: ContentType) -> Self {
+        let mut distribution = Self {
+            prose: 0.0,
+            code: 0.0,
+            mixed: 0.0,
+            structured_data: 0.0,
+        };
+        match content_type {
+            ContentType::Prose => distribution.prose = 1.0,
+            ContentType::Code => distribution.code = 1.0,
+            ContentType::Mixed => distribution.mixed = 1.0,
+            ContentType::StructuredData => distribution.structured_data = 1.0,
+        }
+        distribution
+    }
+
+    /// Probability of `content_type`.
+    pub fn get(&self, content_type: ContentType) -> f32 {
+        match content_type {
+            ContentType::Prose => self.prose,
+            ContentType::Code => self.code,
+            ContentType::Mixed => self.mixed,
+            ContentType::StructuredData => self.structured_data,
+        }
+    }
+
+    /// Most probable type; ties go to the type listed first in [`ContentType::ALL`].
+    pub fn dominant(&self) -> ContentType {
+        ContentType::ALL
+            .into_iter()
+            .fold(ContentType::Prose, |best, t| {
+                if self.get(t) > self.get(best) {
+                    t
+                } else {
+                    best
+                }
+            })
+    }
+}
+
+/// How a classification was obtained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentTypeSource {
+    /// Computed by [`ContentClassifier`].
+    Detected,
+    /// Given by the caller (`content_type` argument).
+    Explicit,
+}
+
+/// Result of classifying one text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContentClassification {
+    /// Dominant type of the distribution.
+    pub content_type: ContentType,
+    /// Probability of each type.
+    pub distribution: ContentDistribution,
+    /// Language with the most keyword hits among code lines, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Whether the classification was detected or given.
+    pub source: ContentTypeSource,
+}
+
+impl ContentClassification {
+    /// Classification fixed by the caller.
+    pub fn explicit(content_type: ContentType) -> Self {
+        Self {
+            content_type,
+            distribution: ContentDistribution::one_hot(content_type),
+            language: None,
+            source: ContentTypeSource::Explicit,
+        }
+    }
+
+    /// Whether the content is prose with no meaningful code share.
+    pub fn is_pure_prose(&self) -> bool {
+        self.content_type == ContentType::Prose && self.distribution.prose >= PURE_PROSE_MIN
+    }
+
+    /// Embedders to run when storing the content: all 13, minus E7 for pure prose.
+    pub fn embedder_mask(&self) -> EmbedderMask {
+        let mut mask = EmbedderMask::all();
+        if self.is_pure_prose() {
+            mask.unset(Embedder::Code);
+        }
+        mask
+    }
+
+    /// Embedders left out by [`embedder_mask`](Self::embedder_mask).
+    pub fn skipped_embedders(&self) -> Vec<Embedder> {
+        let mask = self.embedder_mask();
+        Embedder::all().filter(|e| !mask.contains(*e)).collect()
+    }
+
+    /// Fusion weights for a query with this classification.
+    ///
+    /// Blends three profiles by the distribution: prose weights (semantic
+    /// search with E7's share moved to E1), `code_search` for code, and
+    /// `semantic_search` for mixed and structured content. Each profile sums
+    /// to 1, so the blend does too.
+    pub fn fusion_weights(&self) -> [f32; NUM_EMBEDDERS] {
+        let semantic = builtin_profile(SEMANTIC_PROFILE);
+        let code = builtin_profile(CODE_PROFILE);
+        let mut prose = semantic;
+        prose[Embedder::Semantic.index()] += prose[Embedder::Code.index()];
+        prose[Embedder::Code.index()] = 0.0;
+
+        let d = &self.distribution;
+        let balanced = d.mixed + d.structured_data;
+        let mut weights = [0.0f32; NUM_EMBEDDERS];
+        for (i, w) in weights.iter_mut().enumerate() {
+            *w = d.prose * prose[i] + d.code * code[i] + balanced * semantic[i];
+        }
+        weights
+    }
+}
+
+/// A built-in weight profile; their presence is covered by the weights tests.
+fn builtin_profile(name: &str) -> [f32; NUM_EMBEDDERS] {
+    get_weight_profile(name).unwrap_or_else(|e| panic!("Built-in weight profile missing: {}", e))
+}
+
+/// How one line of text was judged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineKind {
+    Prose,
+    Code,
+    Structured,
+    /// Headings and fence markers; not counted.
+    Neutral,
+}
+
+/// Heuristic prose/code/structured-data classifier.
+#[derive(Debug, Clone, Copy)]
+pub struct ContentClassifier {
+    /// Symbol density at or above which a line is code on its own.
+    pub code_symbol_density: f32,
+    /// Lower symbol density that marks a line as code when it also hits a
+    /// language keyword.
+    pub keyword_symbol_density: f32,
+    /// Share of counted lines that must look structured before the
+    /// structured-data share is non-zero.
+    pub min_structured_share: f32,
+}
+
+impl Default for ContentClassifier {
+    fn default() -> Self {
+        Self {
+            code_symbol_density: 0.12,
+            keyword_symbol_density: 0.05,
+            min_structured_share: 0.6,
+        }
+    }
+}
+
+impl ContentClassifier {
+    /// Classify `text`.
+    ///
+    /// Every non-blank line is judged prose, code, structured or neutral
+    /// (headings, fence markers). With `r` the code share and `s` the
+    /// structured share of the counted lines, the mixed probability is
+    /// `m = (4r(1-r))^2`, which peaks when code and prose are balanced; the
+    /// rest splits between prose and code in proportion `1-r : r`, and the
+    /// whole is scaled by `1-s`. Empty text is prose.
+    pub fn classify(&self, text: &str) -> ContentClassification {
+        let trimmed = text.trim();
+        if looks_like_json(trimmed) {
+            return Self::detected(ContentDistribution::one_hot(ContentType::StructuredData), None);
+        }
+
+        let mut in_fence = false;
+        let (mut code, mut structured, mut counted) = (0usize, 0usize, 0usize);
+        let mut language_hits = [0usize; LANGUAGES.len()];
+
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let kind = if line.starts_with("```") || line.starts_with("~~~") {
+                in_fence = !in_fence;
+                LineKind::Neutral
+            } else if in_fence {
+                LineKind::Code
+            } else {
+                self.classify_line(line)
+            };
+
+            match kind {
+                LineKind::Neutral => continue,
+                LineKind::Code => {
+                    code += 1;
+                    for (hits, (_, indicators)) in language_hits.iter_mut().zip(LANGUAGES) {
+                        if indicators.iter().any(|ind| contains_at_word_start(line, ind)) {
+                            *hits += 1;
+                        }
+                    }
+                }
+                LineKind::Structured => structured += 1,
+                LineKind::Prose => {}
+            }
+            counted += 1;
+        }
+
+        if counted == 0 {
+            return Self::detected(ContentDistribution::one_hot(ContentType::Prose), None);
+        }
+
+        let counted_f = counted as f32;
+        let r = code as f32 / counted_f;
+        let structured_share = structured as f32 / counted_f;
+        let s = if structured >= 2 && structured_share >= self.min_structured_share {
+            structured_share
+        } else {
+            0.0
+        };
+        let m = (4.0 * r * (1.0 - r)).powi(2);
+        let distribution = ContentDistribution {
+            prose: (1.0 - r) * (1.0 - m) * (1.0 - s),
+            code: r * (1.0 - m) * (1.0 - s),
+            mixed: m * (1.0 - s),
+            structured_data: s,
+        };
+
+        let language = language_hits
+            .iter()
+            .zip(LANGUAGES)
+            .filter(|(hits, _)| **hits > 0)
+            .fold(None, |best: Option<(usize, &str)>, (hits, (name, _))| match best {
+                Some((best_hits, _)) if best_hits >= *hits => best,
+                _ => Some((*hits, name)),
+            })
+            .map(|(_, name)| name.to_string());
+
+        Self::detected(distribution, language)
+    }
+
+    fn detected(distribution: ContentDistribution, language: Option<String>) -> ContentClassification {
+        ContentClassification {
+            content_type: distribution.dominant(),
+            distribution,
+            language,
+            source: ContentTypeSource::Detected,
+        }
+    }
+
+    /// Judge one trimmed, non-blank line outside a fence.
+    fn classify_line(&self, line: &str) -> LineKind {
+        // Headings and lone words (`counts`, `Thanks`) say nothing either way
+        if is_markdown_heading(line) || line.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return LineKind::Neutral;
+        }
+
+        let density = symbol_density(line);
+        let words = line.split_whitespace().count();
+        let sentence_end = line.ends_with(['.', '?', '!']);
+        if words >= 6 && sentence_end && density < self.code_symbol_density {
+            return LineKind::Prose;
+        }
+
+        if line.starts_with("//") || line.starts_with("/*") || line.starts_with("#[") {
+            return LineKind::Code;
+        }
+        let code_ending = line.ends_with([';', '{', '}']) || line.starts_with('}');
+        if let Some(value) = key_value(line) {
+            // `x: f32,` is a struct field or argument, not a config entry
+            if value.ends_with(',') {
+                return LineKind::Code;
+            }
+            if !code_ending && !value.ends_with(['(', '.']) {
+                return LineKind::Structured;
+            }
+        } else if is_table_line(line) {
+            return LineKind::Structured;
+        }
+        if density >= self.code_symbol_density || code_ending {
+            return LineKind::Code;
+        }
+
+        let keyword_hit = LANGUAGES
+            .iter()
+            .flat_map(|(_, indicators)| indicators.iter())
+            .chain(GENERAL_CODE_INDICATORS)
+            .any(|ind| contains_at_word_start(line, ind));
+        if keyword_hit && density >= self.keyword_symbol_density {
+            LineKind::Code
+        } else {
+            LineKind::Prose
+        }
+    }
+}
+
+/// Whether the whole text is a JSON object or array.
+fn looks_like_json(text: &str) -> bool {
+    let bracketed = (text.starts_with('{') && text.ends_with('}'))
+        || (text.starts_with('[') && text.ends_with(']'));
+    bracketed && serde_json::from_str::<serde_json::Value>(text).is_ok()
+}
+
+/// `# Heading` through `###### Heading`.
+fn is_markdown_heading(line: &str) -> bool {
+    let hashes = line.chars().take_while(|&c| c == '#').count();
+    (1..=6).contains(&hashes) && line[hashes..].starts_with(' ')
+}
+
+/// Share of non-whitespace characters that are code punctuation. A `.`
+/// directly followed by an identifier character (`x.len`) counts too.
+fn symbol_density(line: &str) -> f32 {
+    let chars: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+    if chars.is_empty() {
+        return 0.0;
+    }
+    let symbols = chars
+        .iter()
+        .enumerate()
+        .filter(|(i, c)| {
+            CODE_SYMBOLS.contains(c)
+                || (**c == '.'
+                    && chars
+                        .get(i + 1)
+                        .is_some_and(|next| next.is_alphanumeric() || *next == '_'))
+        })
+        .count();
+    symbols as f32 / chars.len() as f32
+}
+
+/// `[section]` header or a row delimited by `|` or tabs.
+fn is_table_line(line: &str) -> bool {
+    (line.len() > 2 && line.starts_with('[') && line.ends_with(']') && !line.contains(' '))
+        || line.matches('|').count() >= 2
+        || line.matches('\t').count() >= 2
+}
+
+/// The value of a `key: value`, `key = value` or `key:` line (YAML list
+/// items included), or `None` if the line has no such shape.
+fn key_value(line: &str) -> Option<&str> {
+    let entry = line.strip_prefix("- ").unwrap_or(line);
+    let (key, value) = entry
+        .split_once(": ")
+        .or_else(|| entry.split_once(" = "))
+        .or_else(|| entry.strip_suffix(':').map(|key| (key, "")))?;
+    let key = key.trim().trim_matches('"');
+    let key_ok = !key.is_empty()
+        && key.len() <= 40
+        && key
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'));
+    key_ok.then(|| value.trim_end())
+}
+
+/// Whether `indicator` occurs in `line` at the start of a word, so that
+/// `"use "` does not match inside `"because "`.
+fn contains_at_word_start(line: &str, indicator: &str) -> bool {
+    line.match_indices(indicator).any(|(i, _)| {
+        line[..i]
+            .chars()
+            .next_back()
+            .map_or(true, |prev| !(prev.is_alphanumeric() || prev == '_'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RUST_SNIPPET: &str = r#"use std::collections::HashMap;
+
+pub fn count_words(text: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_lowercase()).or_insert(0) += 1;
+    }
+    counts
+}"#;
+
+    const ENGLISH_PARAGRAPH: &str = "The quarterly planning meeting moved to Thursday because \
+        half of the team was travelling. We agreed to focus on onboarding improvements first, \
+        and the design review will happen once the new hires have settled in.";
+
+    const MIXED_MARKDOWN: &str = r#"# Retry policy
+
+Failed uploads are retried with exponential backoff, capped at five attempts.
+The delay doubles after every failure so the storage service can recover.
+
+```rust
+fn backoff(attempt: u32) -> Duration {
+    Duration::from_millis(100 * 2u64.pow(attempt))
+}
+```
+
+Callers should treat the final error as permanent and surface it to the user.
+"#;
+
+    #[test]
+    fn test_rust_snippet_is_code() {
+        let classification = ContentClassifier::default().classify(RUST_SNIPPET);
+        assert_eq!(classification.content_type, ContentType::Code);
+        assert_eq!(classification.source, ContentTypeSource::Detected);
+        assert_eq!(classification.language.as_deref(), Some("rust"));
+        assert!(classification.distribution.code > 0.9);
+        assert_eq!(classification.embedder_mask(), EmbedderMask::all());
+    }
+
+    #[test]
+    fn test_english_paragraph_is_pure_prose_and_skips_e7() {
+        let classification = ContentClassifier::default().classify(ENGLISH_PARAGRAPH);
+        assert_eq!(classification.content_type, ContentType::Prose);
+        assert!(classification.is_pure_prose());
+        assert_eq!(classification.language, None);
+
+        let mask = classification.embedder_mask();
+        assert!(!mask.contains(Embedder::Code));
+        assert_eq!(mask.count(), NUM_EMBEDDERS - 1);
+        assert_eq!(classification.skipped_embedders(), vec![Embedder::Code]);
+    }
+
+    #[test]
+    fn test_mixed_markdown_is_mixed_and_keeps_e7() {
+        let classification = ContentClassifier::default().classify(MIXED_MARKDOWN);
+        assert_eq!(classification.content_type, ContentType::Mixed);
+        assert!(!classification.is_pure_prose());
+        assert!(classification.embedder_mask().contains(Embedder::Code));
+        assert_eq!(classification.language.as_deref(), Some("rust"));
+    }
+
+    #[test]
+    fn test_structured_data() {
+        let classifier = ContentClassifier::default();
+        let json = classifier.classify(r#"{"name": "worker", "replicas": 3, "ports": [80, 443]}"#);
+        assert_eq!(json.content_type, ContentType::StructuredData);
+
+        let yaml = classifier.classify("name: worker\nreplicas: 3\nimage: registry/worker:1.4\n");
+        assert_eq!(yaml.content_type, ContentType::StructuredData);
+
+        // Rust struct fields end in ',' and are not key/value lines
+        let rust = classifier.classify("struct Point {\n    x: f32,\n    y: f32,\n}");
+        assert_eq!(rust.content_type, ContentType::Code);
+    }
+
+    #[test]
+    fn test_prose_keywords_do_not_count_as_code() {
+        // "use", "type", "class" and "because" are English first
+        let classification = ContentClassifier::default()
+            .classify("We use this type of class schedule because it suits the students best.");
+        assert!(classification.is_pure_prose());
+    }
+
+    #[test]
+    fn test_distribution_sums_to_one() {
+        let classifier = ContentClassifier::default();
+        for text in [RUST_SNIPPET, ENGLISH_PARAGRAPH, MIXED_MARKDOWN, "", "a: 1\nb: 2"] {
+            let d = classifier.classify(text).distribution;
+            let sum = d.prose + d.code + d.mixed + d.structured_data;
+            assert!((sum - 1.0).abs() < 1e-5, "{:?} sums to {}", text, sum);
+        }
+    }
+
+    #[test]
+    fn test_explicit_override() {
+        let prose = ContentClassification::explicit(ContentType::Prose);
+        assert_eq!(prose.source, ContentTypeSource::Explicit);
+        assert!(!prose.embedder_mask().contains(Embedder::Code));
+
+        let code = ContentClassification::explicit(ContentType::Code);
+        assert_eq!(code.embedder_mask(), EmbedderMask::all());
+
+        assert_eq!("structured_data".parse(), Ok(ContentType::StructuredData));
+        assert!("markdown".parse::<ContentType>().is_err());
+    }
+
+    #[test]
+    fn test_fusion_weights_follow_distribution() {
+        let prose = ContentClassification::explicit(ContentType::Prose).fusion_weights();
+        let code = ContentClassification::explicit(ContentType::Code).fusion_weights();
+        let mixed = ContentClassifier::default().classify(MIXED_MARKDOWN).fusion_weights();
+
+        let e1 = Embedder::Semantic.index();
+        let e7 = Embedder::Code.index();
+        assert_eq!(prose[e7], 0.0);
+        assert!(code[e7] > code[e1]);
+        assert!(mixed[e7] > prose[e7] && mixed[e7] < code[e7]);
+        for weights in [prose, code, mixed] {
+            assert!(crate::weights::validate_weights(&weights).is_ok());
+        }
+    }
+}
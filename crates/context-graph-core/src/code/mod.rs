@@ -19,6 +19,15 @@
 //! - Query type detection is fast (O(n) string scan)
 //! - Similarity adjustment is applied post-embedding comparison
 //! - Integration point: `compute_embedder_scores` in storage layer
+//! - [`ContentClassifier`] reuses the same indicators to classify whole
+//!   texts as prose, code, mixed or structured data for E1/E7 routing
+
+mod classifier;
+
+pub use classifier::{
+    ContentClassification, ContentClassifier, ContentDistribution, ContentType,
+    ContentTypeSource, PURE_PROSE_MIN,
+};
 
 use serde::{Deserialize, Serialize};
 
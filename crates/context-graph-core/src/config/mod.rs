@@ -14,8 +14,9 @@ use crate::error::{CoreError, CoreResult};
 
 // Re-export all sub-config types for backwards compatibility
 pub use sub_configs::{
-    CudaConfig, EmbeddingConfig, IndexConfig, LoggingConfig, McpConfig, ServerConfig, StorageConfig,
-    UtlConfig, WatcherConfig,
+    BackupConfig, CudaConfig, DeterminismMode, DispatchLimitsConfig, EdgeInferenceConfig,
+    EmbeddingConfig, IndexConfig, LoggingConfig, McpConfig, ServerConfig, StorageConfig,
+    ToolAuditConfig, UtlConfig, WatcherConfig,
 };
 
 // Re-export embedder configuration types (TASK-L04)
@@ -127,6 +128,7 @@ impl Config {
         // TASK-INTEG-017: Validate MCP config including TCP transport fields
         // FAIL FAST: Any invalid MCP config stops startup immediately
         self.mcp.validate()?;
+        self.embedding.validate()?;
 
         if self.storage.backend != "memory" {
             let path = PathBuf::from(&self.storage.path);
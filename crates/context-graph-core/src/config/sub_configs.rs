@@ -60,6 +60,22 @@ pub struct McpConfig {
     /// Used when transport = "tcp" or "sse"
     #[serde(default = "default_max_connections")]
     pub max_connections: usize,
+
+    /// Rate limits and concurrency caps applied to tools/call
+    #[serde(default)]
+    pub limits: DispatchLimitsConfig,
+
+    /// Edges inferred between newly stored memories and their neighbors
+    #[serde(default)]
+    pub edge_inference: EdgeInferenceConfig,
+
+    /// Audit log of mutating tool calls
+    #[serde(default)]
+    pub audit: ToolAuditConfig,
+
+    /// The create_backup maintenance tool
+    #[serde(default)]
+    pub backup: BackupConfig,
 }
 
 // ============================================================================
@@ -105,6 +121,10 @@ impl Default for McpConfig {
             tcp_port: default_tcp_port(),
             sse_port: default_sse_port(), // TASK-42
             max_connections: default_max_connections(),
+            limits: DispatchLimitsConfig::default(),
+            edge_inference: EdgeInferenceConfig::default(),
+            audit: ToolAuditConfig::default(),
+            backup: BackupConfig::default(),
         }
     }
 }
@@ -209,6 +229,341 @@ impl McpConfig {
             }
         }
 
+        self.limits.validate()?;
+        self.edge_inference.validate()?;
+        self.audit.validate()?;
+        self.backup.validate()
+    }
+}
+
+/// Rate limiting and concurrency caps for MCP tool dispatch.
+///
+/// Each client session (one TCP connection, or the stdio client) gets a token
+/// bucket of `session_burst` calls refilled at `session_rps` per second.
+/// Tool categories are capped independently; once `max_queue_depth` calls are
+/// already waiting for a slot in a category, further calls are rejected with
+/// `SERVER_BUSY` and a retry-after hint instead of queuing.
+///
+/// ```toml
+/// [mcp.limits]
+/// session_rps = 20.0
+/// search_concurrency = 8
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DispatchLimitsConfig {
+    /// Apply the limits below (default: true)
+    #[serde(default = "default_limits_enabled")]
+    pub enabled: bool,
+
+    /// Sustained tools/call rate per session, in calls per second (default: 20)
+    #[serde(default = "default_session_rps")]
+    pub session_rps: f64,
+
+    /// Calls a session may make back-to-back before the rate applies (default: 40)
+    #[serde(default = "default_session_burst")]
+    pub session_burst: u32,
+
+    /// Concurrent search tool executions (default: 8)
+    #[serde(default = "default_search_concurrency")]
+    pub search_concurrency: usize,
+
+    /// Concurrent store/curation tool executions (default: 4)
+    #[serde(default = "default_store_concurrency")]
+    pub store_concurrency: usize,
+
+    /// Concurrent maintenance tool executions (default: 1)
+    #[serde(default = "default_maintenance_concurrency")]
+    pub maintenance_concurrency: usize,
+
+    /// Calls allowed to wait for a slot per category before rejecting (default: 32)
+    #[serde(default = "default_max_queue_depth")]
+    pub max_queue_depth: usize,
+
+    /// Retry-after hint returned when a category queue is full (default: 500)
+    #[serde(default = "default_busy_retry_after_ms")]
+    pub busy_retry_after_ms: u64,
+}
+
+fn default_limits_enabled() -> bool {
+    true
+}
+
+fn default_session_rps() -> f64 {
+    20.0
+}
+
+fn default_session_burst() -> u32 {
+    40
+}
+
+fn default_search_concurrency() -> usize {
+    8
+}
+
+fn default_store_concurrency() -> usize {
+    4
+}
+
+fn default_maintenance_concurrency() -> usize {
+    1
+}
+
+fn default_max_queue_depth() -> usize {
+    32
+}
+
+fn default_busy_retry_after_ms() -> u64 {
+    500
+}
+
+impl Default for DispatchLimitsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_limits_enabled(),
+            session_rps: default_session_rps(),
+            session_burst: default_session_burst(),
+            search_concurrency: default_search_concurrency(),
+            store_concurrency: default_store_concurrency(),
+            maintenance_concurrency: default_maintenance_concurrency(),
+            max_queue_depth: default_max_queue_depth(),
+            busy_retry_after_ms: default_busy_retry_after_ms(),
+        }
+    }
+}
+
+impl DispatchLimitsConfig {
+    /// Validate the dispatch limits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CoreError::ConfigError` if a rate or concurrency cap is zero.
+    pub fn validate(&self) -> crate::error::CoreResult<()> {
+        use crate::error::CoreError;
+
+        if !(self.session_rps.is_finite() && self.session_rps > 0.0) {
+            return Err(CoreError::ConfigError(format!(
+                "McpConfig validation failed: limits.session_rps must be > 0, got {}",
+                self.session_rps
+            )));
+        }
+        if self.session_burst == 0 {
+            return Err(CoreError::ConfigError(
+                "McpConfig validation failed: limits.session_burst must be > 0".to_string(),
+            ));
+        }
+        for (name, value) in [
+            ("search_concurrency", self.search_concurrency),
+            ("store_concurrency", self.store_concurrency),
+            ("maintenance_concurrency", self.maintenance_concurrency),
+        ] {
+            if value == 0 {
+                return Err(CoreError::ConfigError(format!(
+                    "McpConfig validation failed: limits.{} must be > 0",
+                    name
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Edge inference run after memories are stored.
+///
+/// Each new memory is linked to up to `top_k` E1 nearest neighbors whose
+/// similarity reaches the `theta_edge` of the memory's domain. A pair whose
+/// E5 cause/effect similarity is at least `causal_ratio` times stronger in
+/// one direction gets a causal edge in that direction. No memory gets more
+/// than `max_fanout` outgoing edges from inference.
+///
+/// ```toml
+/// [mcp.edge_inference]
+/// top_k = 3
+/// causal_edges = false
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EdgeInferenceConfig {
+    /// Infer edges on store (default: true)
+    #[serde(default = "default_edge_inference_enabled")]
+    pub enabled: bool,
+
+    /// Nearest neighbors considered per new memory (default: 5)
+    #[serde(default = "default_edge_inference_top_k")]
+    pub top_k: usize,
+
+    /// Maximum outgoing edges per memory (default: 16)
+    #[serde(default = "default_edge_inference_max_fanout")]
+    pub max_fanout: usize,
+
+    /// Create causal edges for strongly asymmetric E5 pairs (default: true)
+    #[serde(default = "default_edge_inference_causal_edges")]
+    pub causal_edges: bool,
+
+    /// Minimum ratio between the two E5 directions for a causal edge (default: 1.5)
+    #[serde(default = "default_edge_inference_causal_ratio")]
+    pub causal_ratio: f32,
+}
+
+fn default_edge_inference_enabled() -> bool {
+    true
+}
+
+fn default_edge_inference_top_k() -> usize {
+    5
+}
+
+fn default_edge_inference_max_fanout() -> usize {
+    16
+}
+
+fn default_edge_inference_causal_edges() -> bool {
+    true
+}
+
+fn default_edge_inference_causal_ratio() -> f32 {
+    1.5
+}
+
+impl Default for EdgeInferenceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_edge_inference_enabled(),
+            top_k: default_edge_inference_top_k(),
+            max_fanout: default_edge_inference_max_fanout(),
+            causal_edges: default_edge_inference_causal_edges(),
+            causal_ratio: default_edge_inference_causal_ratio(),
+        }
+    }
+}
+
+impl EdgeInferenceConfig {
+    /// Validate the edge inference settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CoreError::ConfigError` if `top_k` or `max_fanout` is zero or
+    /// `causal_ratio` is below 1.
+    pub fn validate(&self) -> crate::error::CoreResult<()> {
+        use crate::error::CoreError;
+
+        for (name, value) in [("top_k", self.top_k), ("max_fanout", self.max_fanout)] {
+            if value == 0 {
+                return Err(CoreError::ConfigError(format!(
+                    "McpConfig validation failed: edge_inference.{} must be > 0",
+                    name
+                )));
+            }
+        }
+        if !(self.causal_ratio.is_finite() && self.causal_ratio >= 1.0) {
+            return Err(CoreError::ConfigError(format!(
+                "McpConfig validation failed: edge_inference.causal_ratio must be >= 1.0, got {}",
+                self.causal_ratio
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Audit log of mutating tool calls.
+///
+/// Every store, forget, merge, boost, consolidation and import/export call
+/// is recorded with its redacted arguments and affected memory IDs.
+/// Records older than `retention_days` are pruned when a client initializes.
+///
+/// ```toml
+/// [mcp.audit]
+/// retention_days = 365
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ToolAuditConfig {
+    /// Record mutating tool calls (default: true)
+    #[serde(default = "default_tool_audit_enabled")]
+    pub enabled: bool,
+
+    /// Days a record is kept before pruning (default: 90)
+    #[serde(default = "default_tool_audit_retention_days")]
+    pub retention_days: u32,
+}
+
+fn default_tool_audit_enabled() -> bool {
+    true
+}
+
+fn default_tool_audit_retention_days() -> u32 {
+    90
+}
+
+impl Default for ToolAuditConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_tool_audit_enabled(),
+            retention_days: default_tool_audit_retention_days(),
+        }
+    }
+}
+
+impl ToolAuditConfig {
+    /// Validate the audit settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CoreError::ConfigError` if `retention_days` is zero.
+    pub fn validate(&self) -> crate::error::CoreResult<()> {
+        if self.retention_days == 0 {
+            return Err(crate::error::CoreError::ConfigError(
+                "McpConfig validation failed: audit.retention_days must be > 0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The create_backup maintenance tool.
+///
+/// Disabled by default: a backup copies the whole data directory. When
+/// enabled, backups are written to named subdirectories of `root` only.
+///
+/// ```toml
+/// [mcp.backup]
+/// enabled = true
+/// root = "/var/backups/contextgraph"
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BackupConfig {
+    /// Allow create_backup calls (default: false)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Directory holding the backups (default: "./contextgraph_backups")
+    #[serde(default = "default_backup_root")]
+    pub root: String,
+}
+
+fn default_backup_root() -> String {
+    "./contextgraph_backups".to_string()
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            root: default_backup_root(),
+        }
+    }
+}
+
+impl BackupConfig {
+    /// Validate the backup settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CoreError::ConfigError` if backups are enabled with an empty `root`.
+    pub fn validate(&self) -> crate::error::CoreResult<()> {
+        if self.enabled && self.root.trim().is_empty() {
+            return Err(crate::error::CoreError::ConfigError(
+                "McpConfig validation failed: backup.root must be non-empty when enabled"
+                    .to_string(),
+            ));
+        }
         Ok(())
     }
 }
@@ -253,14 +608,120 @@ impl Default for StorageConfig {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct EmbeddingConfig {
     pub model: String,
+    /// Pending embedding requests allowed per embedder before new ones are
+    /// rejected with `CoreError::Backpressure`.
+    #[serde(default = "default_embedding_max_queue_depth")]
+    pub max_queue_depth: usize,
+    /// Reproducible embedding outputs (`[embedding.determinism]`)
+    #[serde(default)]
+    pub determinism: DeterminismMode,
+}
+
+fn default_embedding_max_queue_depth() -> usize {
+    1024
 }
 
 impl Default for EmbeddingConfig {
     fn default() -> Self {
         Self {
             model: "stub".to_string(),
+            max_queue_depth: default_embedding_max_queue_depth(),
+            determinism: DeterminismMode::default(),
+        }
+    }
+}
+
+impl EmbeddingConfig {
+    /// Validate the embedding settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CoreError::ConfigError` if the determinism settings are invalid.
+    pub fn validate(&self) -> crate::error::CoreResult<()> {
+        self.determinism.validate()
+    }
+}
+
+/// Embedding determinism mode.
+///
+/// GPU inference is not bit-reproducible by default: cuBLAS picks reduction
+/// orders per launch and TF32/reduced-precision GEMMs round differently
+/// between runs, so the same content embedded twice differs by ~1e-5. When
+/// enabled, the embedding provider forces full-precision deterministic
+/// kernels, reseeds the device RNG before every request, runs one request
+/// at a time, and rounds every output value to `decimals` places before
+/// normalization, so stored vectors and cache keys are stable across runs.
+///
+/// # Throughput
+///
+/// Serializing requests removes all cross-request GPU overlap, and
+/// full-precision GEMMs run slower than TF32/FP16 ones. Expect roughly a
+/// 2-4x drop in concurrent embedding throughput; single-request latency is
+/// largely unchanged. Intended for snapshot tests and audit replays, not
+/// for serving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DeterminismMode {
+    /// Whether deterministic embedding is enabled.
+    /// Default: false
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Decimal places kept in each output value (1-7; f32 holds ~7).
+    /// Default: 5
+    #[serde(default = "default_determinism_decimals")]
+    pub decimals: u32,
+
+    /// Seed for the device RNG and any sampling.
+    /// Default: 42
+    #[serde(default = "default_determinism_seed")]
+    pub seed: u64,
+}
+
+fn default_determinism_decimals() -> u32 {
+    5
+}
+
+fn default_determinism_seed() -> u64 {
+    42
+}
+
+impl Default for DeterminismMode {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            decimals: default_determinism_decimals(),
+            seed: default_determinism_seed(),
+        }
+    }
+}
+
+impl DeterminismMode {
+    /// Largest supported `decimals`; f32 carries about 7 significant digits.
+    pub const MAX_DECIMALS: u32 = 7;
+
+    /// Enabled mode with default precision and seed.
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..Self::default()
         }
     }
+
+    /// Validate the determinism settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CoreError::ConfigError` if `decimals` is outside 1..=7.
+    pub fn validate(&self) -> crate::error::CoreResult<()> {
+        if !(1..=Self::MAX_DECIMALS).contains(&self.decimals) {
+            return Err(crate::error::CoreError::ConfigError(format!(
+                "embedding.determinism.decimals must be in 1..={}, got {}",
+                Self::MAX_DECIMALS,
+                self.decimals
+            )));
+        }
+        Ok(())
+    }
 }
 
 /// Index backend configuration (HNSW parameters).
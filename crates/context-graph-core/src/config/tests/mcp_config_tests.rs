@@ -4,7 +4,9 @@
 //! Tests cover all validation rules including transport type, TCP-specific fields,
 //! and edge cases for FAIL FAST behavior.
 
-use crate::config::McpConfig;
+use crate::config::{
+    BackupConfig, DispatchLimitsConfig, EdgeInferenceConfig, McpConfig, ToolAuditConfig,
+};
 
 // ============================================================================
 // TASK-INTEG-017: McpConfig Default Tests
@@ -653,3 +655,164 @@ fn test_mcp_config_clone_and_debug() {
     assert!(debug_str.contains("McpConfig"));
     assert!(debug_str.contains("stdio"));
 }
+
+// ============================================================================
+// Dispatch Limits Tests
+// ============================================================================
+
+#[test]
+fn test_dispatch_limits_defaults_validate() {
+    let limits = DispatchLimitsConfig::default();
+    assert!(limits.enabled);
+    assert_eq!(limits.search_concurrency, 8);
+    assert_eq!(limits.maintenance_concurrency, 1);
+    assert!(limits.validate().is_ok());
+}
+
+#[test]
+fn test_dispatch_limits_reject_zero_caps() {
+    let config = McpConfig {
+        limits: DispatchLimitsConfig {
+            search_concurrency: 0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let err_msg = config.validate().unwrap_err().to_string();
+    assert!(err_msg.contains("search_concurrency"), "got: {}", err_msg);
+
+    let limits = DispatchLimitsConfig {
+        session_rps: 0.0,
+        ..Default::default()
+    };
+    assert!(limits.validate().is_err(), "zero rate must fail");
+}
+
+#[test]
+fn test_dispatch_limits_parse_from_toml() {
+    let config: McpConfig = toml::from_str(
+        r#"
+        transport = "tcp"
+        [limits]
+        session_rps = 5.0
+        search_concurrency = 2
+        "#,
+    )
+    .expect("limits must parse");
+    assert_eq!(config.limits.session_rps, 5.0);
+    assert_eq!(config.limits.search_concurrency, 2);
+    assert_eq!(config.limits.store_concurrency, 4, "unset fields keep defaults");
+}
+
+// ============================================================================
+// Edge Inference Tests
+// ============================================================================
+
+#[test]
+fn test_edge_inference_defaults_validate() {
+    let inference = EdgeInferenceConfig::default();
+    assert!(inference.enabled);
+    assert_eq!(inference.top_k, 5);
+    assert_eq!(inference.max_fanout, 16);
+    assert!(inference.validate().is_ok());
+}
+
+#[test]
+fn test_edge_inference_rejects_invalid_values() {
+    let config = McpConfig {
+        edge_inference: EdgeInferenceConfig {
+            max_fanout: 0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let err_msg = config.validate().unwrap_err().to_string();
+    assert!(err_msg.contains("max_fanout"), "got: {}", err_msg);
+
+    let inference = EdgeInferenceConfig {
+        causal_ratio: 0.5,
+        ..Default::default()
+    };
+    assert!(inference.validate().is_err(), "ratio below 1 must fail");
+}
+
+#[test]
+fn test_edge_inference_parse_from_toml() {
+    let config: McpConfig = toml::from_str(
+        r#"
+        [edge_inference]
+        enabled = false
+        top_k = 3
+        "#,
+    )
+    .expect("edge_inference must parse");
+    assert!(!config.edge_inference.enabled);
+    assert_eq!(config.edge_inference.top_k, 3);
+    assert_eq!(
+        config.edge_inference.max_fanout, 16,
+        "unset fields keep defaults"
+    );
+}
+
+// ============================================================================
+// Tool Audit Tests
+// ============================================================================
+
+#[test]
+fn test_tool_audit_defaults_and_validation() {
+    let audit = ToolAuditConfig::default();
+    assert!(audit.enabled);
+    assert_eq!(audit.retention_days, 90);
+    assert!(audit.validate().is_ok());
+
+    let config = McpConfig {
+        audit: ToolAuditConfig {
+            retention_days: 0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let err_msg = config.validate().unwrap_err().to_string();
+    assert!(err_msg.contains("retention_days"), "got: {}", err_msg);
+
+    let config: McpConfig = toml::from_str(
+        r#"
+        [audit]
+        retention_days = 365
+        "#,
+    )
+    .expect("audit must parse");
+    assert_eq!(config.audit.retention_days, 365);
+    assert!(config.audit.enabled, "unset fields keep defaults");
+}
+
+// ============================================================================
+// Backup Tests
+// ============================================================================
+
+#[test]
+fn test_backup_disabled_by_default() {
+    let backup = BackupConfig::default();
+    assert!(!backup.enabled);
+    assert!(backup.validate().is_ok());
+
+    let config = McpConfig {
+        backup: BackupConfig {
+            enabled: true,
+            root: " ".to_string(),
+        },
+        ..Default::default()
+    };
+    let err_msg = config.validate().unwrap_err().to_string();
+    assert!(err_msg.contains("backup.root"), "got: {}", err_msg);
+
+    let config: McpConfig = toml::from_str(
+        r#"
+        [backup]
+        enabled = true
+        "#,
+    )
+    .expect("backup must parse");
+    assert!(config.backup.enabled);
+    assert_eq!(config.backup.root, "./contextgraph_backups");
+}
@@ -3,7 +3,9 @@
 //! Includes Phase-aware safety validation tests that prevent
 //! Production phase from using stub/in-memory backends.
 
-use crate::config::{Config, EmbeddingConfig, IndexConfig, Phase, StorageConfig, UtlConfig};
+use crate::config::{
+    Config, DeterminismMode, EmbeddingConfig, IndexConfig, Phase, StorageConfig, UtlConfig,
+};
 
 #[test]
 fn test_validation_passes() {
@@ -30,6 +32,7 @@ fn test_ghost_phase_allows_stubs() {
         phase: Phase::Ghost,
         embedding: EmbeddingConfig {
             model: "stub".to_string(),
+            ..Default::default()
         },
         storage: StorageConfig {
             backend: "memory".to_string(),
@@ -65,6 +68,7 @@ fn test_development_phase_allows_stubs() {
         phase: Phase::Development,
         embedding: EmbeddingConfig {
             model: "stub".to_string(),
+            ..Default::default()
         },
         storage: StorageConfig {
             backend: "memory".to_string(),
@@ -88,6 +92,7 @@ fn test_production_phase_rejects_stub_embedding() {
         phase: Phase::Production,
         embedding: EmbeddingConfig {
             model: "stub".to_string(),
+            ..Default::default()
         },
         storage: StorageConfig {
             backend: "rocksdb".to_string(),
@@ -132,6 +137,7 @@ fn test_production_phase_rejects_memory_storage() {
         phase: Phase::Production,
         embedding: EmbeddingConfig {
             model: "multi_array_13".to_string(),
+            ..Default::default()
         },
         storage: StorageConfig {
             backend: "memory".to_string(), // <-- DANGEROUS
@@ -175,6 +181,7 @@ fn test_production_phase_rejects_memory_index() {
         phase: Phase::Production,
         embedding: EmbeddingConfig {
             model: "multi_array_13".to_string(),
+            ..Default::default()
         },
         storage: StorageConfig {
             backend: "rocksdb".to_string(),
@@ -211,6 +218,7 @@ fn test_production_phase_rejects_stub_utl() {
         phase: Phase::Production,
         embedding: EmbeddingConfig {
             model: "multi_array_13".to_string(),
+            ..Default::default()
         },
         storage: StorageConfig {
             backend: "rocksdb".to_string(),
@@ -250,6 +258,7 @@ fn test_production_phase_accepts_valid_config() {
         phase: Phase::Production,
         embedding: EmbeddingConfig {
             model: "multi_array_13".to_string(),
+            ..Default::default()
         },
         storage: StorageConfig {
             backend: "rocksdb".to_string(),
@@ -291,6 +300,7 @@ fn test_production_phase_reports_all_dangerous_configs() {
         phase: Phase::Production,
         embedding: EmbeddingConfig {
             model: "stub".to_string(),
+            ..Default::default()
         },
         storage: StorageConfig {
             backend: "memory".to_string(),
@@ -339,6 +349,7 @@ fn test_uses_stubs_helper() {
     let config_real = Config {
         embedding: EmbeddingConfig {
             model: "multi_array_13".to_string(),
+            ..Default::default()
         },
         storage: StorageConfig {
             backend: "rocksdb".to_string(),
@@ -394,6 +405,7 @@ fn test_is_production_safe_helper() {
         phase: Phase::Production,
         embedding: EmbeddingConfig {
             model: "multi_array_13".to_string(),
+            ..Default::default()
         },
         storage: StorageConfig {
             backend: "rocksdb".to_string(),
@@ -430,6 +442,7 @@ fn test_is_production_safe_helper() {
         phase: Phase::Ghost,
         embedding: EmbeddingConfig {
             model: "multi_array_13".to_string(),
+            ..Default::default()
         },
         storage: StorageConfig {
             backend: "rocksdb".to_string(),
@@ -463,3 +476,34 @@ fn test_default_config_is_ghost_phase() {
     );
     assert!(config.uses_stubs(), "Default config should use stubs");
 }
+
+/// Determinism precision must fit in an f32
+#[test]
+fn test_embedding_determinism_validation() {
+    let mut config = Config::default();
+    assert!(!config.embedding.determinism.enabled, "Determinism is opt-in");
+    assert!(config.validate().is_ok());
+
+    config.embedding.determinism = DeterminismMode {
+        decimals: 0,
+        ..DeterminismMode::enabled()
+    };
+    let err = config.validate().unwrap_err().to_string();
+    assert!(err.contains("embedding.determinism.decimals"), "{}", err);
+
+    config.embedding.determinism.decimals = DeterminismMode::MAX_DECIMALS + 1;
+    assert!(config.validate().is_err());
+
+    let parsed: EmbeddingConfig = toml::from_str(
+        r#"
+        model = "multi_array_13"
+        [determinism]
+        enabled = true
+        decimals = 4
+        "#,
+    )
+    .expect("embedding config must parse");
+    assert!(parsed.determinism.enabled);
+    assert_eq!(parsed.determinism.decimals, 4);
+    assert_eq!(parsed.determinism.seed, 42);
+}
@@ -21,7 +21,9 @@ use crate::traits::{
     EmbeddingMetadata, MultiArrayEmbeddingOutput, MultiArrayEmbeddingProvider, SingleEmbedder,
     SparseEmbedder, TokenEmbedder,
 };
-use crate::types::fingerprint::{SemanticFingerprint, NUM_EMBEDDERS};
+use crate::types::fingerprint::{
+    NormStrictness, SemanticFingerprint, SemanticFingerprintBuilder, NUM_EMBEDDERS,
+};
 
 use super::stubs::{StubSingleEmbedder, StubSparseEmbedder, StubTokenEmbedder};
 
@@ -242,38 +244,30 @@ impl StubMultiArrayProvider {
         latencies[11] = e12_lat; // E12
         latencies[12] = e13_lat; // E13
 
-        // Build fingerprint
-        // For E5 and E8, we use the same vector for both roles in stub mode
-        // (real models would produce different encodings via dual projections)
-        let fingerprint = SemanticFingerprint {
-            e1_semantic: e1_vec,
-            e2_temporal_recent: e2_vec,
-            e3_temporal_periodic: e3_vec,
-            e4_temporal_positional: e4_vec,
-            e5_causal_as_cause: e5_vec.clone(),
-            e5_causal_as_effect: e5_vec,
-            e5_causal: Vec::new(), // Empty - using new dual format
-            e6_sparse,
-            e7_code: e7_vec,
-            e8_graph_as_source: e8_vec.clone(),
-            e8_graph_as_target: e8_vec,
-            e8_graph: Vec::new(), // Empty - using new dual format
-            e9_hdc: e9_vec,
-            // E10: Using new dual format for asymmetric paraphrase/context similarity
-            e10_multimodal_paraphrase: e10_vec.clone(),
-            e10_multimodal_as_context: e10_vec,
-            e11_entity: e11_vec,
-            e12_late_interaction: e12_tokens,
-            e13_splade: e13_sparse,
-        };
-
-        // Validate fingerprint before returning (ARCH-01: atomic)
-        fingerprint.validate().map_err(|e| {
-            CoreError::ValidationError {
+        // Build and validate the fingerprint (ARCH-01: atomic)
+        // For E5, E8 and E10, we use the same vector for both roles in stub mode
+        // (real models would produce different encodings via dual projections).
+        // Stub embedders are not normalized, so norms are not checked.
+        let fingerprint = SemanticFingerprintBuilder::new()
+            .with_norm_strictness(NormStrictness::Ignore)
+            .set_e1(e1_vec)
+            .set_e2(e2_vec)
+            .set_e3(e3_vec)
+            .set_e4(e4_vec)
+            .set_e5(e5_vec.clone(), e5_vec)
+            .set_e6_sparse(e6_sparse)
+            .set_e7(e7_vec)
+            .set_e8(e8_vec.clone(), e8_vec)
+            .set_e9(e9_vec)
+            .set_e10(e10_vec.clone(), e10_vec)
+            .set_e11(e11_vec)
+            .set_e12(e12_tokens)
+            .set_e13_sparse(e13_sparse)
+            .build()
+            .map_err(|e| CoreError::ValidationError {
                 field: "fingerprint".to_string(),
                 message: format!("Validation failed after embedding: {}", e),
-            }
-        })?;
+            })?;
 
         // Verify total time
         let _total_elapsed = start_total.elapsed();
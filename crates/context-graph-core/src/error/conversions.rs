@@ -156,6 +156,13 @@ impl From<CoreError> for ContextGraphError {
                 );
                 ContextGraphError::Embedding(EmbeddingError::LegacyUnknownEmbedder(msg))
             }
+            CoreError::Backpressure {
+                queue_depth,
+                retry_after_ms,
+            } => ContextGraphError::Embedding(EmbeddingError::Backpressure {
+                queue_depth,
+                retry_after_ms,
+            }),
             CoreError::MissingField { field, context } => {
                 ContextGraphError::Validation(format!("Missing {}: {}", field, context))
             }
@@ -165,6 +172,7 @@ impl From<CoreError> for ContextGraphError {
             CoreError::LegacyFormatRejected(msg) => {
                 ContextGraphError::Storage(StorageError::Migration(format!("Legacy: {}", msg)))
             }
+            CoreError::Classified(e) => *e,
         }
     }
 }
@@ -0,0 +1,98 @@
+//! Machine-readable error classification shared by all crates.
+//!
+//! Every error that reaches the MCP layer is classified by an [`ErrorKind`],
+//! which decides the JSON-RPC code in one place instead of per handler.
+//! Crates convert their own errors with
+//! [`ContextGraphError::from_source`](super::ContextGraphError::from_source),
+//! which keeps the original error as `source()`.
+
+use std::error::Error;
+use std::fmt;
+
+/// What went wrong, independent of the crate that failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// A queue or rate limit is full; retry later.
+    Backpressure,
+    /// A model, GPU capability or feature the operation needs is not available.
+    CapabilityUnavailable,
+    /// The requested memory, index or resource does not exist.
+    NotFound,
+    /// Stored data failed an integrity check.
+    Corruption,
+    /// A GPU/CUDA operation failed (OOM, kernel, device).
+    GpuFailure,
+    /// The caller's input is invalid.
+    InvalidInput,
+    /// The operation ran out of time.
+    Timeout,
+    /// Anything else: I/O, serialization, bugs.
+    Internal,
+}
+
+impl ErrorKind {
+    /// All kinds, in declaration order.
+    pub const ALL: [ErrorKind; 8] = [
+        Self::Backpressure,
+        Self::CapabilityUnavailable,
+        Self::NotFound,
+        Self::Corruption,
+        Self::GpuFailure,
+        Self::InvalidInput,
+        Self::Timeout,
+        Self::Internal,
+    ];
+
+    /// Stable snake_case name, used in logs and responses.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Backpressure => "backpressure",
+            Self::CapabilityUnavailable => "capability_unavailable",
+            Self::NotFound => "not_found",
+            Self::Corruption => "corruption",
+            Self::GpuFailure => "gpu_failure",
+            Self::InvalidInput => "invalid_input",
+            Self::Timeout => "timeout",
+            Self::Internal => "internal",
+        }
+    }
+
+    /// Whether errors of this kind are worth retrying by default.
+    pub fn is_retryable(self) -> bool {
+        matches!(self, Self::Backpressure | Self::Timeout)
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Render `err` and every error in its `source()` chain, outermost first.
+///
+/// Sources whose message is already part of the rendered text (wrappers that
+/// print their source) are skipped so each message appears once.
+///
+/// # Examples
+///
+/// ```rust
+/// use context_graph_core::error::{error_chain, ContextGraphError, ErrorKind};
+///
+/// let io = std::io::Error::new(std::io::ErrorKind::Other, "disk unplugged");
+/// let err = ContextGraphError::from_source(ErrorKind::Internal, io);
+/// assert_eq!(error_chain(&err), "disk unplugged");
+/// ```
+pub fn error_chain(err: &(dyn Error + 'static)) -> String {
+    let mut rendered = err.to_string();
+    let mut source = err.source();
+    while let Some(cause) = source {
+        let message = cause.to_string();
+        if !rendered.contains(&message) {
+            rendered.push_str(": ");
+            rendered.push_str(&message);
+        }
+        source = cause.source();
+    }
+    rendered
+}
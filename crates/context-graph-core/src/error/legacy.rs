@@ -5,6 +5,9 @@
 use thiserror::Error;
 use uuid::Uuid;
 
+use super::kind::ErrorKind;
+use super::unified::ContextGraphError;
+
 // ============================================================================
 // LEGACY CORE ERROR (RETAINED FOR COMPATIBILITY)
 // ============================================================================
@@ -79,6 +82,15 @@ pub enum CoreError {
     #[error("Embedding error: {0}")]
     Embedding(String),
 
+    /// Embedding pipeline is saturated; retry after `retry_after_ms`.
+    #[error(
+        "Embedding backpressure: {queue_depth} requests queued, retry after {retry_after_ms}ms"
+    )]
+    Backpressure {
+        queue_depth: usize,
+        retry_after_ms: u64,
+    },
+
     /// Missing required field.
     #[error("Missing required field '{field}': {context}")]
     MissingField { field: String, context: String },
@@ -90,6 +102,45 @@ pub enum CoreError {
     /// Legacy format rejected.
     #[error("Legacy format rejected: {0}. See documentation for migration guide.")]
     LegacyFormatRejected(String),
+
+    /// Classified error from another crate, carried without flattening.
+    ///
+    /// Displays as the inner error and forwards `source()` to it, so the
+    /// original error survives the trait boundaries that return `CoreResult`.
+    #[error(transparent)]
+    Classified(Box<ContextGraphError>),
+}
+
+impl CoreError {
+    /// Machine-readable classification of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::NodeNotFound { .. } => ErrorKind::NotFound,
+            Self::DimensionMismatch { .. }
+            | Self::ValidationError { .. }
+            | Self::MissingField { .. } => ErrorKind::InvalidInput,
+            Self::FeatureDisabled { .. } => ErrorKind::CapabilityUnavailable,
+            Self::Backpressure { .. } => ErrorKind::Backpressure,
+            Self::LegacyFormatRejected(_) => ErrorKind::Corruption,
+            Self::Classified(e) => e.kind(),
+            _ => ErrorKind::Internal,
+        }
+    }
+
+    /// Whether retrying the failed operation may succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Backpressure { .. } => true,
+            Self::Classified(e) => e.is_retryable(),
+            _ => false,
+        }
+    }
+}
+
+impl From<ContextGraphError> for CoreError {
+    fn from(err: ContextGraphError) -> Self {
+        CoreError::Classified(Box::new(err))
+    }
 }
 
 impl From<serde_json::Error> for CoreError {
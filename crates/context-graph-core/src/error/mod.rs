@@ -3,6 +3,8 @@
 //! This module defines the central error types used throughout the context-graph system:
 //!
 //! - [`ContextGraphError`]: Top-level unified error for all crate errors
+//! - [`ErrorKind`]: Machine-readable classification of any error, see [`error_chain`]
+//!   for logging the full source chain
 //! - [`CoreError`]: Legacy error type (retained for compatibility)
 //! - Sub-error types: [`EmbeddingError`], [`StorageError`], [`IndexError`],
 //!   [`ConfigError`], [`GpuError`], [`McpError`]
@@ -39,6 +41,7 @@
 //! ```
 
 mod conversions;
+mod kind;
 mod legacy;
 mod sub_errors;
 mod unified;
@@ -47,6 +50,7 @@ mod unified;
 mod tests;
 
 // Re-export all public types for backwards compatibility
+pub use kind::{error_chain, ErrorKind};
 pub use legacy::{CoreError, CoreResult};
 pub use sub_errors::{ConfigError, EmbeddingError, GpuError, IndexError, McpError, StorageError};
 pub use unified::ContextGraphError;
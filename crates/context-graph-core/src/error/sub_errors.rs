@@ -86,6 +86,21 @@ pub enum EmbeddingError {
         message: String,
     },
 
+    /// Embedding queue is at capacity; the request was not accepted.
+    ///
+    /// # Recovery
+    ///
+    /// Retry after `retry_after_ms`.
+    #[error(
+        "Embedding backpressure: {queue_depth} requests queued, retry after {retry_after_ms}ms"
+    )]
+    Backpressure {
+        /// Pending requests in the saturated queue
+        queue_depth: usize,
+        /// Suggested delay before retrying
+        retry_after_ms: u64,
+    },
+
     /// Legacy embedding error where the specific embedder is unknown.
     ///
     /// Used when converting from `CoreError::Embedding(String)` which does not
@@ -42,6 +42,13 @@ fn test_is_recoverable() {
     let e = ContextGraphError::Embedding(EmbeddingError::ModelNotLoaded(Embedder::Semantic));
     assert!(e.is_recoverable());
 
+    let e: ContextGraphError = CoreError::Backpressure {
+        queue_depth: 10,
+        retry_after_ms: 50,
+    }
+    .into();
+    assert!(e.is_recoverable());
+
     let e = ContextGraphError::Storage(StorageError::Transaction("test".to_string()));
     assert!(e.is_recoverable());
 
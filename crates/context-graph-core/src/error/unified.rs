@@ -2,6 +2,7 @@
 
 use thiserror::Error;
 
+use super::kind::ErrorKind;
 use super::sub_errors::{
     ConfigError, EmbeddingError, GpuError, IndexError, McpError, StorageError,
 };
@@ -13,7 +14,9 @@ use super::sub_errors::{
 /// Top-level unified error type for context-graph library.
 ///
 /// All crate errors should be convertible to this type via `From` implementations.
-/// Provides JSON-RPC error code mapping for MCP protocol responses.
+/// Errors from other crates become [`ContextGraphError::External`], which keeps
+/// the original error as `source()`. Every variant has an [`ErrorKind`]
+/// (see [`ContextGraphError::kind`]) that the MCP layer maps to JSON-RPC codes.
 ///
 /// # JSON-RPC Error Codes
 ///
@@ -99,6 +102,21 @@ pub enum ContextGraphError {
     /// These errors indicate bugs and should be investigated.
     #[error("Internal error: {0}")]
     Internal(String),
+
+    /// Error from another crate, classified by kind.
+    ///
+    /// Displays as the original error, which stays reachable via `source()`.
+    /// Build with [`ContextGraphError::from_source`].
+    #[error("{source}")]
+    External {
+        /// Classification of the failure.
+        kind: ErrorKind,
+        /// Whether retrying the operation may succeed.
+        retryable: bool,
+        /// The original error.
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync + 'static>,
+    },
 }
 
 impl ContextGraphError {
@@ -133,6 +151,100 @@ impl ContextGraphError {
             Self::Mcp(e) => e.error_code(),
             Self::Validation(_) => -32602, // INVALID_PARAMS
             Self::Internal(_) => -32603,   // INTERNAL_ERROR
+            Self::External { kind, .. } => match kind {
+                ErrorKind::InvalidInput => -32602,
+                ErrorKind::NotFound => -32002,
+                ErrorKind::Corruption => -32004,
+                ErrorKind::GpuFailure => -32009,
+                ErrorKind::Timeout => -32007,
+                ErrorKind::Backpressure => -32050,
+                ErrorKind::CapabilityUnavailable => -32052,
+                ErrorKind::Internal => -32603,
+            },
+        }
+    }
+
+    /// Wrap an error from another crate, keeping it as `source()`.
+    ///
+    /// `retryable` defaults to [`ErrorKind::is_retryable`]; override it with
+    /// [`ContextGraphError::retryable`].
+    pub fn from_source<E>(kind: ErrorKind, source: E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        Self::External {
+            kind,
+            retryable: kind.is_retryable(),
+            source: Box::new(source),
+        }
+    }
+
+    /// Set the retryable flag of an [`External`](Self::External) error.
+    /// Other variants are returned unchanged.
+    #[must_use]
+    pub fn retryable(mut self, value: bool) -> Self {
+        if let Self::External { retryable, .. } = &mut self {
+            *retryable = value;
+        }
+        self
+    }
+
+    /// Machine-readable classification of this error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use context_graph_core::error::{ContextGraphError, ErrorKind, IndexError};
+    ///
+    /// let err = ContextGraphError::Index(IndexError::Timeout(500));
+    /// assert_eq!(err.kind(), ErrorKind::Timeout);
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Embedding(e) => match e {
+                EmbeddingError::ModelNotLoaded(_) | EmbeddingError::ModelNotFound { .. } => {
+                    ErrorKind::CapabilityUnavailable
+                }
+                EmbeddingError::Backpressure { .. } => ErrorKind::Backpressure,
+                EmbeddingError::DimensionMismatch { .. }
+                | EmbeddingError::BatchTooLarge { .. }
+                | EmbeddingError::EmptyInput => ErrorKind::InvalidInput,
+                _ => ErrorKind::Internal,
+            },
+            Self::Storage(e) => match e {
+                StorageError::NotFound(_) => ErrorKind::NotFound,
+                StorageError::AlreadyExists(_) => ErrorKind::InvalidInput,
+                StorageError::Corruption(_) | StorageError::IncompleteArray(_) => {
+                    ErrorKind::Corruption
+                }
+                _ => ErrorKind::Internal,
+            },
+            Self::Index(e) => match e {
+                IndexError::NotFound(_) => ErrorKind::NotFound,
+                IndexError::RebuildRequired(_) => ErrorKind::CapabilityUnavailable,
+                IndexError::Corruption(_, _) => ErrorKind::Corruption,
+                IndexError::Timeout(_) => ErrorKind::Timeout,
+                _ => ErrorKind::Internal,
+            },
+            Self::Config(_) => ErrorKind::Internal,
+            Self::Gpu(GpuError::NotAvailable) => ErrorKind::CapabilityUnavailable,
+            Self::Gpu(_) => ErrorKind::GpuFailure,
+            Self::Mcp(McpError::RateLimited(_)) => ErrorKind::Backpressure,
+            Self::Mcp(_) | Self::Validation(_) => ErrorKind::InvalidInput,
+            Self::Internal(_) => ErrorKind::Internal,
+            Self::External { kind, .. } => *kind,
+        }
+    }
+
+    /// Whether retrying the failed operation may succeed.
+    ///
+    /// The explicit flag for [`External`](Self::External) errors,
+    /// [`is_recoverable`](Self::is_recoverable) otherwise.
+    #[inline]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::External { retryable, .. } => *retryable,
+            _ => self.is_recoverable(),
         }
     }
 
@@ -167,10 +279,15 @@ impl ContextGraphError {
         matches!(
             self,
             Self::Embedding(EmbeddingError::ModelNotLoaded(_))
+                | Self::Embedding(EmbeddingError::Backpressure { .. })
                 | Self::Storage(StorageError::Transaction(_))
                 | Self::Index(IndexError::Timeout(_))
                 | Self::Mcp(McpError::RateLimited(_))
                 | Self::Gpu(GpuError::OutOfMemory { .. })
+                | Self::External {
+                    retryable: true,
+                    ..
+                }
         )
     }
 
@@ -212,6 +329,10 @@ impl ContextGraphError {
                 | Self::Index(IndexError::Corruption(_, _))
                 | Self::Gpu(GpuError::NotAvailable)
                 | Self::Internal(_)
+                | Self::External {
+                    kind: ErrorKind::Corruption,
+                    ..
+                }
         )
     }
 
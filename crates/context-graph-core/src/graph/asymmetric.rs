@@ -385,6 +385,7 @@ pub fn adjust_batch_graph_similarities(
 // E8 Asymmetric Fingerprint-Based Similarity
 // =============================================================================
 
+use crate::graph_linking::DirectedRelation;
 use crate::types::fingerprint::SemanticFingerprint;
 
 /// Compute asymmetric E8 graph similarity between query and document fingerprints.
@@ -489,6 +490,67 @@ pub fn compute_e8_asymmetric_full(
     )
 }
 
+/// Minimum gap between the two asymmetric cosines of a pair before the pair
+/// is treated as directed. Below it the pair is scored symmetrically.
+pub const E8_DIRECTION_MARGIN: f32 = 0.02;
+
+/// E8 score of a candidate edge `source → target`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct E8EdgeSimilarity {
+    /// Stronger asymmetric cosine times the direction modifier, clamped to [0, 1].
+    pub similarity: f32,
+    /// Modifier applied, recorded on the edge via `similarity_modifier()`:
+    /// - Forward: `source` is the structural source (1.2)
+    /// - Backward: `source` is the structural target (0.8)
+    /// - Symmetric: no dominant direction or legacy vectors (1.0)
+    pub direction: DirectedRelation,
+}
+
+/// Score a candidate E8 edge `source → target` for graph construction.
+///
+/// Both pairings are compared: `cos(source.as_source, target.as_target)` and
+/// `cos(target.as_source, source.as_target)`. The larger one is the base
+/// similarity, shared by both edge directions, and the pairing that wins
+/// decides the structural roles. The edge then gets the Constitution
+/// direction modifier for those roles, so a hub→leaf edge scores 1.5× its
+/// leaf→hub counterpart (before clamping).
+///
+/// Fingerprints without asymmetric E8 vectors fall back to symmetric cosine
+/// on the active E8 vector.
+pub fn compute_e8_edge_similarity(
+    source: &SemanticFingerprint,
+    target: &SemanticFingerprint,
+) -> E8EdgeSimilarity {
+    if !source.has_asymmetric_e8() || !target.has_asymmetric_e8() {
+        let sim = cosine_similarity_f32(source.e8_active_vector(), target.e8_active_vector());
+        return E8EdgeSimilarity {
+            similarity: sim.clamp(0.0, 1.0),
+            direction: DirectedRelation::Symmetric,
+        };
+    }
+
+    let forward = compute_e8_asymmetric_fingerprint_similarity(source, target, true);
+    let backward = compute_e8_asymmetric_fingerprint_similarity(target, source, true);
+    let (source_role, target_role) = if forward - backward > E8_DIRECTION_MARGIN {
+        (GraphDirection::Source, GraphDirection::Target)
+    } else if backward - forward > E8_DIRECTION_MARGIN {
+        (GraphDirection::Target, GraphDirection::Source)
+    } else {
+        (GraphDirection::Unknown, GraphDirection::Unknown)
+    };
+    let modifier = GraphDirection::direction_modifier(source_role, target_role);
+    let direction = match source_role {
+        GraphDirection::Source => DirectedRelation::Forward,
+        GraphDirection::Target => DirectedRelation::Backward,
+        GraphDirection::Unknown => DirectedRelation::Symmetric,
+    };
+
+    E8EdgeSimilarity {
+        similarity: (forward.max(backward) * modifier).clamp(0.0, 1.0),
+        direction,
+    }
+}
+
 /// Detect graph query intent from query text.
 ///
 /// Analyzes the query text to determine if the user is asking for:
@@ -1202,4 +1264,61 @@ mod tests {
         // Results should be re-ordered based on connectivity + similarity blend
         println!("[VERIFIED] rank_by_connectivity produces: {:?}", ranked);
     }
+
+    // =========================================================================
+    // E8 Edge Similarity Tests
+    // =========================================================================
+
+    /// Hub's source encoding aligns with the leaf's target encoding; the
+    /// reverse pairing is orthogonal.
+    fn hub_and_leaf() -> (SemanticFingerprint, SemanticFingerprint) {
+        use crate::types::fingerprint::E8_DIM;
+        let axis = |dims: &[usize]| {
+            let mut v = vec![0.0f32; E8_DIM];
+            for &d in dims {
+                v[d] = 1.0;
+            }
+            v
+        };
+        let mut hub = SemanticFingerprint::zeroed();
+        hub.e8_graph_as_source = axis(&[0, 1]);
+        hub.e8_graph_as_target = axis(&[2]);
+        let mut leaf = SemanticFingerprint::zeroed();
+        leaf.e8_graph_as_source = axis(&[3]);
+        leaf.e8_graph_as_target = axis(&[0]);
+        (hub, leaf)
+    }
+
+    #[test]
+    fn test_e8_edge_similarity_hub_to_leaf_ratio() {
+        let (hub, leaf) = hub_and_leaf();
+
+        let forward = compute_e8_edge_similarity(&hub, &leaf);
+        let reverse = compute_e8_edge_similarity(&leaf, &hub);
+
+        assert_eq!(forward.direction, DirectedRelation::Forward);
+        assert_eq!(reverse.direction, DirectedRelation::Backward);
+        let base = std::f32::consts::FRAC_1_SQRT_2;
+        assert!((forward.similarity - base * 1.2).abs() < 1e-5);
+        assert!((reverse.similarity - base * 0.8).abs() < 1e-5);
+        let ratio = forward.similarity / reverse.similarity;
+        let expected = direction_mod::SOURCE_TO_TARGET / direction_mod::TARGET_TO_SOURCE;
+        assert!((ratio - expected).abs() < 1e-4, "ratio {}", ratio);
+        assert_eq!(
+            forward.direction.similarity_modifier(),
+            direction_mod::SOURCE_TO_TARGET
+        );
+    }
+
+    #[test]
+    fn test_e8_edge_similarity_without_asymmetric_vectors_is_symmetric() {
+        let (mut hub, mut leaf) = hub_and_leaf();
+        hub.e8_graph_as_target.clear();
+        leaf.e8_graph_as_target.clear();
+
+        let forward = compute_e8_edge_similarity(&hub, &leaf);
+        let reverse = compute_e8_edge_similarity(&leaf, &hub);
+        assert_eq!(forward.direction, DirectedRelation::Symmetric);
+        assert_eq!(forward.similarity, reverse.similarity);
+    }
 }
@@ -236,8 +236,8 @@ impl EdgeBuilder {
                 // Track direction for asymmetric embedders (E5, E8)
                 if edge.is_asymmetric() {
                     has_directed = true;
-                    if edge.direction().is_forward() {
-                        direction = DirectedRelation::Forward;
+                    if !edge.direction().is_symmetric() {
+                        direction = edge.direction();
                     }
                 }
             }
@@ -251,9 +251,9 @@ impl EdgeBuilder {
                 }
 
                 // Track reverse direction
-                if edge.is_asymmetric() && edge.direction().is_forward() {
+                if edge.is_asymmetric() && !edge.direction().is_symmetric() {
                     has_directed = true;
-                    direction = DirectedRelation::Backward;
+                    direction = edge.direction().reverse();
                 }
             }
         }
@@ -273,6 +273,9 @@ impl EdgeBuilder {
         // For non-asymmetric edge types, use Symmetric direction
         if !has_directed {
             direction = DirectedRelation::Symmetric;
+        } else if edge_type.is_asymmetric() && direction.is_symmetric() {
+            // Directed embedder without a dominant direction: keep pair order
+            direction = DirectedRelation::Forward;
         }
 
         // Compute weight: use learned projection if available, else fallback to normalized agreement
@@ -598,4 +601,34 @@ mod tests {
         assert_eq!(stats.total_edges, 1);
         assert!(stats.embedder_ids.contains(&0));
     }
+
+    #[test]
+    fn test_build_typed_edges_keeps_e8_backward_direction() {
+        let config = EdgeBuilderConfig::default().with_min_weighted_agreement(0.5);
+        let mut builder = EdgeBuilder::new(config);
+
+        // Pairs are keyed smaller-UUID-first; make the smaller one the leaf
+        let (leaf, hub) = {
+            let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+            if a < b {
+                (a, b)
+            } else {
+                (b, a)
+            }
+        };
+        let mut graph = KnnGraph::new(7, 20);
+        graph.add_edge(
+            EmbedderEdge::with_direction(leaf, hub, 7, 0.64, DirectedRelation::Backward).unwrap(),
+        );
+        graph.add_edge(
+            EmbedderEdge::with_direction(hub, leaf, 7, 0.96, DirectedRelation::Forward).unwrap(),
+        );
+        builder.add_knn_graph(graph);
+
+        let edges = builder.build_typed_edges().unwrap();
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].source(), leaf);
+        assert_eq!(edges[0].edge_type(), GraphLinkEdgeType::GraphConnected);
+        assert_eq!(edges[0].direction(), DirectedRelation::Backward);
+    }
 }
@@ -16,8 +16,27 @@
 //! | ParaphraseAligned | E10 | Paraphrase (same meaning) |
 //! | KeywordOverlap | E6, E13 | Keyword/lexical similarity |
 //! | MultiAgreement | 3+ embedders | Multiple embedders agree |
+//!
+//! # Relation Types
+//!
+//! Asserted rather than detected from embedder agreement. All are directed
+//! (source → target) and carry a fixed default weight.
+//!
+//! | Type | Meaning | Default Weight |
+//! |------|---------|----------------|
+//! | Refutes | Source refutes target | 0.3 |
+//! | Supersedes | Source replaces target | 0.9 |
+//! | DerivedFrom | Source was derived from target | 0.8 |
+//! | TemporalFollows | Source follows target in time | 0.7 |
+//!
+//! # Serialized Form
+//!
+//! Human-readable formats (JSON) use the snake_case name; binary formats
+//! (bincode) use the explicit `as_u8` ordinal, so adding or reordering
+//! variants never changes what stored edges decode to.
 
-use serde::{Deserialize, Serialize};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 
 /// Edge types derived from embedder agreement patterns.
@@ -42,8 +61,7 @@ use std::fmt;
 /// assert!(causal.is_asymmetric());
 /// assert_eq!(causal.primary_embedder_index(), Some(4)); // E5
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
 pub enum GraphLinkEdgeType {
     /// High E1 (semantic) similarity.
@@ -78,11 +96,39 @@ pub enum GraphLinkEdgeType {
     /// Multiple embedders (3+) agree on similarity.
     /// The strongest signal - multiple perspectives confirm the relationship.
     MultiAgreement = 7,
+
+    /// Source refutes target. Directed.
+    Refutes = 8,
+
+    /// Source supersedes (replaces) target. Directed.
+    Supersedes = 9,
+
+    /// Source was derived from target. Directed.
+    DerivedFrom = 10,
+
+    /// Source follows target in time. Directed.
+    TemporalFollows = 11,
 }
 
 impl GraphLinkEdgeType {
     /// Total number of edge types.
-    pub const COUNT: usize = 8;
+    pub const COUNT: usize = 12;
+
+    /// snake_case names, indexed by `as_u8`.
+    pub const NAMES: [&'static str; Self::COUNT] = [
+        "semantic_similar",
+        "code_related",
+        "entity_shared",
+        "causal_chain",
+        "graph_connected",
+        "paraphrase_aligned",
+        "keyword_overlap",
+        "multi_agreement",
+        "refutes",
+        "supersedes",
+        "derived_from",
+        "temporal_follows",
+    ];
 
     /// Check if this edge type requires asymmetric similarity handling.
     ///
@@ -94,9 +140,26 @@ impl GraphLinkEdgeType {
         matches!(self, Self::CausalChain | Self::GraphConnected)
     }
 
+    /// Check if this is an asserted relation rather than an embedder-derived type.
+    #[inline]
+    pub fn is_relation(&self) -> bool {
+        matches!(
+            self,
+            Self::Refutes | Self::Supersedes | Self::DerivedFrom | Self::TemporalFollows
+        )
+    }
+
+    /// Check if edges of this type need a direction: asymmetric embedder
+    /// types and every relation type.
+    #[inline]
+    pub fn is_directed(&self) -> bool {
+        self.is_asymmetric() || self.is_relation()
+    }
+
     /// Get the primary embedder index for this edge type.
     ///
-    /// Returns `None` for `MultiAgreement` since it requires 3+ embedders.
+    /// Returns `None` for `MultiAgreement` since it requires 3+ embedders,
+    /// and for relation types, which no embedder detects.
     ///
     /// # Returns
     ///
@@ -118,12 +181,17 @@ impl GraphLinkEdgeType {
             Self::ParaphraseAligned => Some(9),    // E10
             Self::KeywordOverlap => Some(5),   // E6 (or E13=12)
             Self::MultiAgreement => None,      // No single primary
+            Self::Refutes
+            | Self::Supersedes
+            | Self::DerivedFrom
+            | Self::TemporalFollows => None, // Asserted, not detected
         }
     }
 
     /// Get the default similarity threshold for this edge type.
     ///
     /// Higher thresholds for specialized embedders, lower for broad semantic.
+    /// Relation types are asserted, so any weight passes (0.0).
     pub fn default_threshold(&self) -> f32 {
         match self {
             Self::SemanticSimilar => 0.75, // E1 is broad, need higher threshold
@@ -134,6 +202,25 @@ impl GraphLinkEdgeType {
             Self::ParaphraseAligned => 0.70,   // E10 paraphrase matching
             Self::KeywordOverlap => 0.50,  // Sparse similarity scores differently
             Self::MultiAgreement => 0.60,  // Multiple agree = strong signal
+            Self::Refutes
+            | Self::Supersedes
+            | Self::DerivedFrom
+            | Self::TemporalFollows => 0.0,
+        }
+    }
+
+    /// Default weight of an edge of this type created without embedder scores.
+    ///
+    /// Relation types get fixed weights by how strongly they tie the two
+    /// memories; embedder-derived types fall back to their threshold, the
+    /// lowest weight a detected edge of that type can have.
+    pub fn default_weight(&self) -> f32 {
+        match self {
+            Self::Refutes => 0.3,         // Like a contradiction: low base weight
+            Self::Supersedes => 0.9,      // The successor stands in for the target
+            Self::DerivedFrom => 0.8,     // Strong provenance link
+            Self::TemporalFollows => 0.7, // Time order is reliable but loose
+            _ => self.default_threshold(),
         }
     }
 
@@ -148,9 +235,27 @@ impl GraphLinkEdgeType {
             Self::ParaphraseAligned => "Paraphrase aligned (E10)",
             Self::KeywordOverlap => "Keyword overlap (E6/E13)",
             Self::MultiAgreement => "Multi-embedder agreement (3+)",
+            Self::Refutes => "Refutes (asserted, directed)",
+            Self::Supersedes => "Supersedes (asserted, directed)",
+            Self::DerivedFrom => "Derived from (asserted, directed)",
+            Self::TemporalFollows => "Temporally follows (asserted, directed)",
         }
     }
 
+    /// snake_case name, as used in JSON and tool arguments.
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        Self::NAMES[self.as_u8() as usize]
+    }
+
+    /// Parse a snake_case name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::NAMES
+            .iter()
+            .position(|n| *n == name)
+            .and_then(|i| Self::from_u8(i as u8))
+    }
+
     /// Convert to u8 for storage.
     #[inline]
     pub fn as_u8(&self) -> u8 {
@@ -168,12 +273,16 @@ impl GraphLinkEdgeType {
             5 => Some(Self::ParaphraseAligned),
             6 => Some(Self::KeywordOverlap),
             7 => Some(Self::MultiAgreement),
+            8 => Some(Self::Refutes),
+            9 => Some(Self::Supersedes),
+            10 => Some(Self::DerivedFrom),
+            11 => Some(Self::TemporalFollows),
             _ => None,
         }
     }
 
     /// Get all variants.
-    pub fn all() -> [Self; 8] {
+    pub fn all() -> [Self; Self::COUNT] {
         [
             Self::SemanticSimilar,
             Self::CodeRelated,
@@ -183,6 +292,20 @@ impl GraphLinkEdgeType {
             Self::ParaphraseAligned,
             Self::KeywordOverlap,
             Self::MultiAgreement,
+            Self::Refutes,
+            Self::Supersedes,
+            Self::DerivedFrom,
+            Self::TemporalFollows,
+        ]
+    }
+
+    /// Get relation variants only.
+    pub fn relation_variants() -> [Self; 4] {
+        [
+            Self::Refutes,
+            Self::Supersedes,
+            Self::DerivedFrom,
+            Self::TemporalFollows,
         ]
     }
 
@@ -191,7 +314,7 @@ impl GraphLinkEdgeType {
         [Self::CausalChain, Self::GraphConnected]
     }
 
-    /// Get symmetric variants only.
+    /// Get symmetric variants only (relation types are all directed).
     pub fn symmetric_variants() -> [Self; 6] {
         [
             Self::SemanticSimilar,
@@ -213,17 +336,31 @@ impl Default for GraphLinkEdgeType {
 
 impl fmt::Display for GraphLinkEdgeType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let s = match self {
-            Self::SemanticSimilar => "semantic_similar",
-            Self::CodeRelated => "code_related",
-            Self::EntityShared => "entity_shared",
-            Self::CausalChain => "causal_chain",
-            Self::GraphConnected => "graph_connected",
-            Self::ParaphraseAligned => "paraphrase_aligned",
-            Self::KeywordOverlap => "keyword_overlap",
-            Self::MultiAgreement => "multi_agreement",
-        };
-        write!(f, "{}", s)
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for GraphLinkEdgeType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.as_str())
+        } else {
+            serializer.serialize_u8(self.as_u8())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GraphLinkEdgeType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let name = String::deserialize(deserializer)?;
+            Self::from_name(&name)
+                .ok_or_else(|| D::Error::unknown_variant(&name, &Self::NAMES))
+        } else {
+            let value = u8::deserialize(deserializer)?;
+            Self::from_u8(value)
+                .ok_or_else(|| D::Error::custom(format!("invalid edge type ordinal {}", value)))
+        }
     }
 }
 
@@ -233,8 +370,8 @@ mod tests {
 
     #[test]
     fn test_count() {
-        assert_eq!(GraphLinkEdgeType::COUNT, 8);
-        assert_eq!(GraphLinkEdgeType::all().len(), 8);
+        assert_eq!(GraphLinkEdgeType::COUNT, 12);
+        assert_eq!(GraphLinkEdgeType::all().len(), 12);
     }
 
     #[test]
@@ -306,7 +443,7 @@ mod tests {
 
     #[test]
     fn test_from_u8_invalid() {
-        assert!(GraphLinkEdgeType::from_u8(8).is_none());
+        assert!(GraphLinkEdgeType::from_u8(12).is_none());
         assert!(GraphLinkEdgeType::from_u8(255).is_none());
     }
 
@@ -350,4 +487,42 @@ mod tests {
                        "{:?} should have u8 value {}", edge_type, i);
         }
     }
+    #[test]
+    fn test_relation_types_are_directed_and_unscored() {
+        for edge_type in GraphLinkEdgeType::relation_variants() {
+            assert!(edge_type.is_relation());
+            assert!(edge_type.is_directed());
+            assert!(!edge_type.is_asymmetric());
+            assert_eq!(edge_type.primary_embedder_index(), None);
+        }
+        assert!(GraphLinkEdgeType::CausalChain.is_directed());
+        assert!(!GraphLinkEdgeType::SemanticSimilar.is_directed());
+        assert_eq!(GraphLinkEdgeType::Supersedes.default_weight(), 0.9);
+        assert_eq!(GraphLinkEdgeType::Refutes.default_weight(), 0.3);
+    }
+
+    #[test]
+    fn test_names_match_ordinals() {
+        for edge_type in GraphLinkEdgeType::all() {
+            assert_eq!(GraphLinkEdgeType::from_name(edge_type.as_str()), Some(edge_type));
+        }
+        assert_eq!(
+            GraphLinkEdgeType::from_name("supersedes"),
+            Some(GraphLinkEdgeType::Supersedes)
+        );
+        assert_eq!(GraphLinkEdgeType::from_name("Supersedes"), None);
+    }
+
+    #[test]
+    fn test_binary_form_is_the_explicit_ordinal() {
+        let bytes = bincode::serialize(&GraphLinkEdgeType::DerivedFrom).unwrap();
+        assert_eq!(bytes, vec![10]);
+        let restored: GraphLinkEdgeType = bincode::deserialize(&[3]).unwrap();
+        assert_eq!(restored, GraphLinkEdgeType::CausalChain);
+        assert!(bincode::deserialize::<GraphLinkEdgeType>(&[12]).is_err());
+
+        let json = serde_json::to_string(&GraphLinkEdgeType::TemporalFollows).unwrap();
+        assert_eq!(json, "\"temporal_follows\"");
+        assert!(serde_json::from_str::<GraphLinkEdgeType>("\"follows\"").is_err());
+    }
 }
@@ -26,7 +26,7 @@
 //! | E_GRAPHLINK_011 | Invalid similarity score |
 //! | E_GRAPHLINK_012 | Edge threshold violation |
 //! | E_GRAPHLINK_013 | Insufficient neighbors for K-NN |
-//! | E_GRAPHLINK_014 | Direction required for asymmetric edge |
+//! | E_GRAPHLINK_014 | Direction required for directed edge |
 //! | E_GRAPHLINK_015 | Agreement count mismatch |
 
 use thiserror::Error;
@@ -68,7 +68,7 @@ pub enum EdgeError {
     },
 
     /// Invalid edge type value.
-    #[error("E_GRAPHLINK_004: Invalid edge type value {value}. Must be 0-7.")]
+    #[error("E_GRAPHLINK_004: Invalid edge type value {value}. Must be 0-11.")]
     InvalidEdgeType { value: u8 },
 
     /// Missing embedder edge in K-NN graph.
@@ -121,8 +121,8 @@ pub enum EdgeError {
         actual: usize,
     },
 
-    /// Direction required for asymmetric edge type but not provided.
-    #[error("E_GRAPHLINK_014: Direction required for asymmetric edge type {edge_type} but not provided.")]
+    /// Direction required for a directed edge type but not provided.
+    #[error("E_GRAPHLINK_014: Direction required for directed edge type {edge_type} but not provided.")]
     DirectionRequired { edge_type: GraphLinkEdgeType },
 
     /// Agreement count doesn't match agreeing embedders bitset.
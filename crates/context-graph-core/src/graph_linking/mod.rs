@@ -13,13 +13,14 @@
 //!
 //! # Module Structure
 //!
-//! - `edge_type`: 8 graph linking edge types derived from embedder patterns
+//! - `edge_type`: 8 embedder-derived edge types plus 4 asserted relation types
 //! - `direction`: Directed relation for asymmetric edges (E5, E8)
 //! - `embedder_edge`: K-NN graph edges per embedder
 //! - `typed_edge`: Multi-relation edges with embedder agreement
 //! - `error`: Fail-fast error types for graph linking operations
 //! - `thresholds`: Configurable edge detection thresholds
 //! - `storage_keys`: Binary key formats for RocksDB storage
+//! - `subgraph`: Weighted, filterable neighborhood extraction with Mermaid/JSON rendering
 
 mod direction;
 mod edge_builder;
@@ -30,6 +31,7 @@ mod knn_graph;
 mod nn_descent;
 pub mod service;
 mod storage_keys;
+mod subgraph;
 mod thresholds;
 mod typed_edge;
 mod weight_projector;
@@ -41,8 +43,13 @@ pub use edge_type::GraphLinkEdgeType;
 pub use embedder_edge::EmbedderEdge;
 pub use error::{EdgeError, EdgeResult};
 pub use knn_graph::{KnnGraph, KnnGraphStats};
-pub use nn_descent::{build_asymmetric_knn, NnDescent, NnDescentConfig, NnDescentStats};
+pub use nn_descent::{
+    build_asymmetric_knn, build_directed_knn, NnDescent, NnDescentConfig, NnDescentStats,
+};
 pub use storage_keys::{EdgeStorageKey, TypedEdgeStorageKey};
+pub use subgraph::{
+    extract_subgraph, Subgraph, SubgraphEdge, SubgraphNode, SubgraphOptions, TraversalFilter,
+};
 pub use thresholds::{EdgeThresholds, DEFAULT_THRESHOLDS};
 pub use typed_edge::TypedEdge;
 pub use weight_projector::{OptionalProjector, WeightProjector, NUM_EMBEDDERS};
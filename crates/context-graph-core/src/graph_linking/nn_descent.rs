@@ -523,6 +523,50 @@ where
     Ok(graph)
 }
 
+/// Build a K-NN graph from a pairwise directed scorer.
+///
+/// Unlike `build_asymmetric_knn`, the scorer sees both endpoints, so it can
+/// compare both pairings and pick a per-edge direction (E8 uses
+/// `compute_e8_edge_similarity`). `score(a, b)` returns the similarity of
+/// the edge `a → b` and the direction it was scored with, or `None` if the
+/// pair cannot be scored. Exhaustive O(n²) like `build_asymmetric_knn`.
+///
+/// # Arguments
+///
+/// * `embedder_id` - Which asymmetric embedder (4 for E5 or 7 for E8)
+/// * `nodes` - Node IDs
+/// * `score` - Directed pair scorer
+/// * `config` - Algorithm configuration (k, min_similarity)
+pub fn build_directed_knn<S>(
+    embedder_id: u8,
+    nodes: &[Uuid],
+    score: S,
+    config: NnDescentConfig,
+) -> EdgeResult<KnnGraph>
+where
+    S: Fn(Uuid, Uuid) -> Option<(f32, DirectedRelation)>,
+{
+    let mut graph = KnnGraph::with_capacity(embedder_id, config.k, nodes.len());
+
+    for &source in nodes {
+        let mut candidates: Vec<(Uuid, f32, DirectedRelation)> = nodes
+            .iter()
+            .filter(|&&target| target != source)
+            .filter_map(|&target| score(source, target).map(|(sim, dir)| (target, sim, dir)))
+            .filter(|&(_, sim, _)| sim >= config.min_similarity)
+            .collect();
+
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        for (target, sim, direction) in candidates.into_iter().take(config.k) {
+            let edge = EmbedderEdge::with_direction(source, target, embedder_id, sim, direction)?;
+            graph.add_edge(edge);
+        }
+    }
+
+    Ok(graph)
+}
+
 /// Statistics from NN-Descent execution.
 #[derive(Debug, Clone)]
 pub struct NnDescentStats {
@@ -695,4 +739,34 @@ mod tests {
             assert_eq!(edge.direction(), DirectedRelation::Forward);
         }
     }
+
+    #[test]
+    fn test_directed_knn_keeps_scorer_direction() {
+        let nodes: Vec<Uuid> = (0..6).map(|_| Uuid::new_v4()).collect();
+        let hub = nodes[0];
+
+        // Hub → leaf edges are amplified, leaf → hub dampened, leaf ↔ leaf unscored
+        let graph = build_directed_knn(
+            7,
+            &nodes,
+            |a, b| match (a == hub, b == hub) {
+                (true, false) => Some((0.84, DirectedRelation::Forward)),
+                (false, true) => Some((0.56, DirectedRelation::Backward)),
+                _ => None,
+            },
+            NnDescentConfig::default().with_k(3),
+        )
+        .unwrap();
+
+        assert_eq!(graph.get_neighbors(hub).len(), 3);
+        assert!(graph
+            .get_neighbors(hub)
+            .iter()
+            .all(|e| e.direction() == DirectedRelation::Forward));
+        let back = graph.get_neighbors(nodes[1]);
+        assert_eq!(back.len(), 1);
+        assert_eq!(back[0].target(), hub);
+        assert_eq!(back[0].direction(), DirectedRelation::Backward);
+        assert!((back[0].similarity() - 0.56).abs() < 1e-6);
+    }
 }
@@ -31,7 +31,7 @@ use uuid::Uuid;
 use super::{
     DirectedRelation, EdgeBuilder, EdgeBuilderConfig, EdgeResult,
     GraphLinkEdgeType, KnnGraph, KnnGraphStats, NnDescent, NnDescentConfig,
-    TypedEdge,
+    TraversalFilter, TypedEdge,
 };
 
 /// Configuration for the GraphLinkService.
@@ -220,6 +220,25 @@ impl GraphLinkService {
         get_embedding: F,
         similarity: S,
     ) -> EdgeResult<BuildResult>
+    where
+        F: Fn(Uuid, u8) -> Option<Vec<f32>>,
+        S: Fn(&[f32], &[f32]) -> f32,
+    {
+        self.build_with_graphs(nodes, get_embedding, similarity, Vec::new())
+    }
+
+    /// Build like `build`, but use `prebuilt` K-NN graphs as-is.
+    ///
+    /// Active embedders with a prebuilt graph skip NN-Descent. Used for
+    /// graphs that need more than a vector similarity, e.g. E8 built with
+    /// `build_directed_knn` over whole fingerprints.
+    pub fn build_with_graphs<F, S>(
+        &mut self,
+        nodes: &[Uuid],
+        get_embedding: F,
+        similarity: S,
+        prebuilt: Vec<KnnGraph>,
+    ) -> EdgeResult<BuildResult>
     where
         F: Fn(Uuid, u8) -> Option<Vec<f32>>,
         S: Fn(&[f32], &[f32]) -> f32,
@@ -227,11 +246,20 @@ impl GraphLinkService {
         let mut graphs = HashMap::new();
         let mut stats = HashMap::new();
 
+        for graph in prebuilt {
+            stats.insert(graph.embedder_id(), graph.stats());
+            graphs.insert(graph.embedder_id(), graph);
+        }
+
         // Build K-NN graph for each active embedder
         for &embedder_id in &self.config.active_embedders {
+            if graphs.contains_key(&embedder_id) {
+                continue;
+            }
             let graph = if embedder_id == 4 || embedder_id == 7 {
                 // Asymmetric embedders (E5, E8) - need source/target embeddings
-                // For now, use symmetric approach (can be enhanced later)
+                // Vector-only fallback; callers with fingerprints pass a
+                // directed graph via `build_with_graphs` instead
                 let nn = NnDescent::new(embedder_id, nodes, self.config.nn_descent.clone());
                 nn.build(
                     |id| get_embedding(id, embedder_id),
@@ -336,13 +364,13 @@ impl GraphLinkService {
     ///
     /// * `start` - Starting node
     /// * `max_hops` - Maximum traversal depth
-    /// * `edge_type` - Optional filter by edge type
+    /// * `filter` - Edge types and directions to follow
     /// * `min_weight` - Minimum edge weight to follow
     pub fn traverse(
         &self,
         start: Uuid,
         max_hops: usize,
-        edge_type: Option<GraphLinkEdgeType>,
+        filter: &TraversalFilter,
         min_weight: f32,
     ) -> TraversalResult {
         let mut paths = Vec::new();
@@ -358,12 +386,15 @@ impl GraphLinkService {
 
             for (path, edges, weight) in current_paths {
                 let last_node = *path.last().unwrap();
-                let outgoing = self.get_typed_edges(last_node, edge_type);
+                let outgoing = self.get_typed_edges(last_node, None);
 
                 for edge in outgoing.edges {
                     if edge.weight < min_weight {
                         continue;
                     }
+                    if !filter.allows(edge.edge_type, edge.direction) {
+                        continue;
+                    }
                     if visited.contains(&edge.target) {
                         continue;
                     }
@@ -442,6 +473,7 @@ impl GraphLinkService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph_linking::EmbedderEdge;
 
     fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
         let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
@@ -572,4 +604,24 @@ mod tests {
         service.clear();
         assert!(!service.is_built());
     }
+
+    #[test]
+    fn test_build_with_prebuilt_graph() {
+        let nodes: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        let mut e8 = KnnGraph::new(7, 20);
+        e8.add_edge(
+            EmbedderEdge::with_direction(nodes[0], nodes[1], 7, 0.9, DirectedRelation::Forward)
+                .unwrap(),
+        );
+
+        let config = GraphLinkServiceConfig::default().with_active_embedders(vec![0, 7]);
+        let mut service = GraphLinkService::new(config);
+        let result = service
+            .build_with_graphs(&nodes, |_, _| Some(vec![1.0, 0.0]), cosine_similarity, vec![e8])
+            .unwrap();
+
+        // E8 kept as given, E1 built by NN-Descent
+        assert_eq!(result.graphs[&7].edge_count(), 1);
+        assert!(result.graphs[&0].edge_count() > 0);
+    }
 }
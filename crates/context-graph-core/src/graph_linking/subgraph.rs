@@ -0,0 +1,611 @@
+//! Neighborhood subgraph extraction for context assembly.
+//!
+//! Expands typed edges outward from a set of center memories, level by level,
+//! up to a hop radius. Within each level the frontier is ranked by path
+//! priority (product of effective edge weights along the best path), so when
+//! the node cap is hit the lowest-priority frontier nodes are the ones
+//! dropped. Visited nodes are never re-expanded, so cycles terminate.
+//!
+//! # Effective Weights
+//!
+//! An edge's effective weight is its stored weight scaled by how much the
+//! query's weight profile values the edge type's primary embedder, relative
+//! to the profile's strongest embedder. Under `code_search` a CodeRelated
+//! (E7) edge keeps its full weight while a SemanticSimilar (E1) edge is
+//! halved; MultiAgreement edges use the best of their agreeing embedders.
+//! Without a profile effective weight equals stored weight. Relation edges
+//! (supersedes, derived_from, ...) have no embedder and keep their weight.
+//!
+//! # Filtering
+//!
+//! A `TraversalFilter` restricts which edges are followed by type and by
+//! direction, e.g. `TraversalFilter::edge_types(&[Supersedes])` walks only
+//! the supersedes chain.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let subgraph = extract_subgraph(&[memory_id], 2, &SubgraphOptions::default(), |id| {
+//!     edge_repository.get_typed_edges_from(id)
+//! })?;
+//! let mermaid = subgraph.to_mermaid();
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::{DirectedRelation, GraphLinkEdgeType, TypedEdge, NUM_EMBEDDERS};
+
+/// Which typed edges a traversal may follow.
+///
+/// The default follows every edge.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TraversalFilter {
+    /// Edge types to follow; `None` follows all types.
+    pub edge_types: Option<Vec<GraphLinkEdgeType>>,
+    /// Edge directions to follow; `None` follows all directions.
+    pub directions: Option<Vec<DirectedRelation>>,
+}
+
+impl TraversalFilter {
+    /// Follow only edges of `edge_types`.
+    pub fn edge_types(edge_types: &[GraphLinkEdgeType]) -> Self {
+        Self {
+            edge_types: Some(edge_types.to_vec()),
+            directions: None,
+        }
+    }
+
+    /// Follow only edges stored with one of `directions`.
+    pub fn with_directions(mut self, directions: &[DirectedRelation]) -> Self {
+        self.directions = Some(directions.to_vec());
+        self
+    }
+
+    /// True if `edge` may be followed.
+    pub fn admits(&self, edge: &TypedEdge) -> bool {
+        self.allows(edge.edge_type(), edge.direction())
+    }
+
+    /// True if an edge of this type and direction may be followed.
+    pub fn allows(&self, edge_type: GraphLinkEdgeType, direction: DirectedRelation) -> bool {
+        self.edge_types
+            .as_ref()
+            .map_or(true, |types| types.contains(&edge_type))
+            && self
+                .directions
+                .as_ref()
+                .map_or(true, |dirs| dirs.contains(&direction))
+    }
+}
+
+/// Limits and weighting for `extract_subgraph`.
+#[derive(Debug, Clone)]
+pub struct SubgraphOptions {
+    /// Maximum nodes, centers included.
+    pub max_nodes: usize,
+    /// Maximum edges, highest effective weight kept.
+    pub max_edges: usize,
+    /// Edges with a lower effective weight are not followed.
+    pub min_weight: f32,
+    /// Per-embedder query weights (e.g. from `get_weight_profile`).
+    pub profile: Option<[f32; NUM_EMBEDDERS]>,
+    /// Edges the expansion may follow.
+    pub filter: TraversalFilter,
+}
+
+impl Default for SubgraphOptions {
+    fn default() -> Self {
+        Self {
+            max_nodes: 50,
+            max_edges: 200,
+            min_weight: 0.0,
+            profile: None,
+            filter: TraversalFilter::default(),
+        }
+    }
+}
+
+impl SubgraphOptions {
+    /// Weight edges by `profile`.
+    pub fn with_profile(mut self, profile: [f32; NUM_EMBEDDERS]) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
+    /// Cap the node count.
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = max_nodes;
+        self
+    }
+
+    /// Cap the edge count.
+    pub fn with_max_edges(mut self, max_edges: usize) -> Self {
+        self.max_edges = max_edges;
+        self
+    }
+
+    /// Follow only edges `filter` admits.
+    pub fn with_filter(mut self, filter: TraversalFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Stored weight scaled by the profile's weight for the edge's embedder.
+    pub fn effective_weight(&self, edge: &TypedEdge) -> f32 {
+        let Some(profile) = &self.profile else {
+            return edge.weight();
+        };
+        let max = profile.iter().copied().fold(0.0f32, f32::max);
+        if max <= 0.0 {
+            return edge.weight();
+        }
+        let factor = match edge.edge_type().primary_embedder_index() {
+            Some(index) => profile[index] / max,
+            None => (0..NUM_EMBEDDERS as u8)
+                .filter(|&i| edge.embedder_agrees(i))
+                .map(|i| profile[i as usize] / max)
+                .fold(None, |best: Option<f32>, f| {
+                    Some(best.map_or(f, |b| b.max(f)))
+                })
+                .unwrap_or(1.0),
+        };
+        edge.weight() * factor
+    }
+}
+
+/// A memory in the extracted subgraph.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubgraphNode {
+    pub id: Uuid,
+    /// Hops from the nearest center (0 for centers).
+    pub hop: usize,
+    /// Product of effective weights along the best path from a center.
+    pub priority: f32,
+    /// Short content summary, filled in by the caller.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+/// A typed edge between two nodes of the subgraph.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubgraphEdge {
+    pub source: Uuid,
+    pub target: Uuid,
+    pub edge_type: GraphLinkEdgeType,
+    pub weight: f32,
+    pub effective_weight: f32,
+    pub direction: DirectedRelation,
+}
+
+/// Local graph structure around a set of center memories.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Subgraph {
+    /// Nodes ordered by hop, then by descending priority.
+    pub nodes: Vec<SubgraphNode>,
+    /// Edges ordered by descending effective weight.
+    pub edges: Vec<SubgraphEdge>,
+    /// True if the node or edge cap dropped anything.
+    pub truncated: bool,
+}
+
+impl Subgraph {
+    /// True if `id` is a node of the subgraph.
+    pub fn contains(&self, id: Uuid) -> bool {
+        self.nodes.iter().any(|n| n.id == id)
+    }
+
+    /// Fill in node summaries from `summary_of`.
+    pub fn set_summaries(&mut self, mut summary_of: impl FnMut(Uuid) -> Option<String>) {
+        for node in &mut self.nodes {
+            node.summary = summary_of(node.id);
+        }
+    }
+
+    /// JSON rendering for injection into tool responses.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::Value::Null)
+    }
+
+    /// Mermaid flowchart rendering. Nodes are labelled with their summary,
+    /// or the first 8 characters of their ID.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("graph LR\n");
+        let index: HashMap<Uuid, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.id, i))
+            .collect();
+        for (i, node) in self.nodes.iter().enumerate() {
+            let label = match &node.summary {
+                Some(summary) => mermaid_escape(summary),
+                None => node.id.to_string()[..8].to_string(),
+            };
+            let _ = writeln!(out, "    n{}[\"{}\"]", i, label);
+        }
+        for edge in &self.edges {
+            let (Some(s), Some(t)) = (index.get(&edge.source), index.get(&edge.target)) else {
+                continue;
+            };
+            let arrow = if edge.direction.is_symmetric() {
+                "---"
+            } else {
+                "-->"
+            };
+            let _ = writeln!(
+                out,
+                "    n{} {}|{} {:.2}| n{}",
+                s, arrow, edge.edge_type, edge.effective_weight, t
+            );
+        }
+        out
+    }
+}
+
+fn mermaid_escape(text: &str) -> String {
+    let flat: String = text
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .take(60)
+        .collect();
+    flat.replace('"', "#quot;")
+}
+
+/// Extract the subgraph within `radius` hops of `center_ids`.
+///
+/// `edges_of` returns the outgoing typed edges of a node (typically
+/// `EdgeRepository::get_typed_edges_from`); its first error aborts the
+/// extraction. Centers always count toward `max_nodes` first.
+pub fn extract_subgraph<E>(
+    center_ids: &[Uuid],
+    radius: usize,
+    opts: &SubgraphOptions,
+    mut edges_of: impl FnMut(Uuid) -> Result<Vec<TypedEdge>, E>,
+) -> Result<Subgraph, E> {
+    let mut subgraph = Subgraph::default();
+    let mut included: HashSet<Uuid> = HashSet::new();
+    let mut seen_edges: HashMap<(Uuid, Uuid), SubgraphEdge> = HashMap::new();
+
+    let mut frontier: Vec<(Uuid, f32)> = Vec::new();
+    for &id in center_ids {
+        if included.contains(&id) {
+            continue;
+        }
+        if included.len() >= opts.max_nodes {
+            subgraph.truncated = true;
+            break;
+        }
+        included.insert(id);
+        subgraph.nodes.push(SubgraphNode {
+            id,
+            hop: 0,
+            priority: 1.0,
+            summary: None,
+        });
+        frontier.push((id, 1.0));
+    }
+
+    for hop in 1..=radius {
+        // Best priority per candidate reached from this level
+        let mut candidates: HashMap<Uuid, f32> = HashMap::new();
+        for &(node, priority) in &frontier {
+            for edge in edges_of(node)? {
+                if !opts.filter.admits(&edge) {
+                    continue;
+                }
+                let effective = opts.effective_weight(&edge);
+                if effective < opts.min_weight || edge.target() == node {
+                    continue;
+                }
+                seen_edges
+                    .entry((edge.source(), edge.target()))
+                    .or_insert_with(|| SubgraphEdge {
+                        source: edge.source(),
+                        target: edge.target(),
+                        edge_type: edge.edge_type(),
+                        weight: edge.weight(),
+                        effective_weight: effective,
+                        direction: edge.direction(),
+                    });
+                if included.contains(&edge.target()) {
+                    continue;
+                }
+                let reach = priority * effective;
+                let best = candidates.entry(edge.target()).or_insert(reach);
+                *best = best.max(reach);
+            }
+        }
+        if candidates.is_empty() {
+            break;
+        }
+
+        let mut ranked: Vec<(Uuid, f32)> = candidates.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        let room = opts.max_nodes.saturating_sub(included.len());
+        if ranked.len() > room {
+            subgraph.truncated = true;
+            ranked.truncate(room);
+        }
+
+        for &(id, priority) in &ranked {
+            included.insert(id);
+            subgraph.nodes.push(SubgraphNode {
+                id,
+                hop,
+                priority,
+                summary: None,
+            });
+        }
+        frontier = ranked;
+        if frontier.is_empty() {
+            break;
+        }
+    }
+
+    let mut edges: Vec<SubgraphEdge> = seen_edges
+        .into_values()
+        .filter(|e| included.contains(&e.source) && included.contains(&e.target))
+        .collect();
+    edges.sort_by(|a, b| {
+        b.effective_weight
+            .total_cmp(&a.effective_weight)
+            .then_with(|| (a.source, a.target).cmp(&(b.source, b.target)))
+    });
+    if edges.len() > opts.max_edges {
+        subgraph.truncated = true;
+        edges.truncate(opts.max_edges);
+    }
+    subgraph.edges = edges;
+    Ok(subgraph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weights::get_weight_profile;
+    use std::convert::Infallible;
+
+    /// Directed graph held in memory for tests.
+    struct TestGraph {
+        nodes: Vec<Uuid>,
+        out: HashMap<Uuid, Vec<TypedEdge>>,
+    }
+
+    impl TestGraph {
+        fn new(n: usize) -> Self {
+            // Sorted IDs make node i's position deterministic in tie-breaks
+            let mut nodes: Vec<Uuid> = (0..n).map(|_| Uuid::new_v4()).collect();
+            nodes.sort();
+            Self {
+                nodes,
+                out: HashMap::new(),
+            }
+        }
+
+        fn link(&mut self, from: usize, to: usize, edge_type: GraphLinkEdgeType, weight: f32) {
+            let direction = if edge_type.is_asymmetric() {
+                DirectedRelation::Forward
+            } else {
+                DirectedRelation::Symmetric
+            };
+            let edge = TypedEdge::new(
+                self.nodes[from],
+                self.nodes[to],
+                edge_type,
+                weight,
+                direction,
+                [0.0; NUM_EMBEDDERS],
+                0,
+                0,
+            )
+            .unwrap();
+            self.out.entry(self.nodes[from]).or_default().push(edge);
+        }
+
+        fn edges_of(&self, id: Uuid) -> Result<Vec<TypedEdge>, Infallible> {
+            Ok(self.out.get(&id).cloned().unwrap_or_default())
+        }
+
+        /// 200-node ring (semantic, both directions) with a code chord from
+        /// every 10th node to the node 50 positions ahead.
+        fn ring() -> Self {
+            let mut graph = Self::new(200);
+            for i in 0..200 {
+                let next = (i + 1) % 200;
+                graph.link(i, next, GraphLinkEdgeType::SemanticSimilar, 0.9);
+                graph.link(next, i, GraphLinkEdgeType::SemanticSimilar, 0.9);
+                if i % 10 == 0 {
+                    graph.link(i, (i + 50) % 200, GraphLinkEdgeType::CodeRelated, 0.8);
+                }
+            }
+            graph
+        }
+    }
+
+    #[test]
+    fn test_radius_bounds_expansion_and_cycles_terminate() {
+        let graph = TestGraph::ring();
+        let center = graph.nodes[5];
+        let opts = SubgraphOptions::default().with_max_nodes(1000);
+
+        let subgraph = extract_subgraph(&[center], 3, &opts, |id| graph.edges_of(id)).unwrap();
+
+        // Node 5 has no chord: 3 hops each way around the ring
+        assert_eq!(subgraph.nodes.len(), 7);
+        assert!(!subgraph.truncated);
+        for (offset, hop) in [(2usize, 3usize), (8, 3), (4, 1), (6, 1)] {
+            let node = subgraph
+                .nodes
+                .iter()
+                .find(|n| n.id == graph.nodes[offset])
+                .expect("node within radius");
+            assert_eq!(node.hop, hop);
+        }
+        assert!(!subgraph.contains(graph.nodes[9]));
+        assert!(subgraph.nodes.iter().all(|n| n.hop <= 3));
+
+        // A radius far beyond the ring size still terminates with each node once
+        let whole = extract_subgraph(&[center], 500, &opts, |id| graph.edges_of(id)).unwrap();
+        assert_eq!(whole.nodes.len(), 200);
+        let unique: HashSet<Uuid> = whole.nodes.iter().map(|n| n.id).collect();
+        assert_eq!(unique.len(), 200);
+    }
+
+    #[test]
+    fn test_cap_trims_lowest_priority_frontier_first() {
+        let mut graph = TestGraph::new(6);
+        graph.link(0, 1, GraphLinkEdgeType::SemanticSimilar, 0.9);
+        graph.link(0, 2, GraphLinkEdgeType::SemanticSimilar, 0.3);
+        graph.link(0, 3, GraphLinkEdgeType::SemanticSimilar, 0.6);
+        graph.link(0, 4, GraphLinkEdgeType::SemanticSimilar, 0.1);
+        graph.link(1, 5, GraphLinkEdgeType::SemanticSimilar, 0.9);
+        let opts = SubgraphOptions::default().with_max_nodes(3);
+
+        let subgraph =
+            extract_subgraph(&[graph.nodes[0]], 2, &opts, |id| graph.edges_of(id)).unwrap();
+
+        let ids: Vec<Uuid> = subgraph.nodes.iter().map(|n| n.id).collect();
+        assert_eq!(ids, vec![graph.nodes[0], graph.nodes[1], graph.nodes[3]]);
+        assert!(subgraph.truncated);
+        // Only edges between kept nodes survive
+        assert_eq!(subgraph.edges.len(), 2);
+        assert!((subgraph.edges[0].effective_weight - 0.9).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_edge_cap_keeps_strongest() {
+        let graph = TestGraph::ring();
+        let opts = SubgraphOptions::default()
+            .with_max_nodes(1000)
+            .with_max_edges(3);
+
+        let subgraph =
+            extract_subgraph(&[graph.nodes[0]], 2, &opts, |id| graph.edges_of(id)).unwrap();
+
+        assert_eq!(subgraph.edges.len(), 3);
+        assert!(subgraph.truncated);
+        assert!(subgraph
+            .edges
+            .windows(2)
+            .all(|w| w[0].effective_weight >= w[1].effective_weight));
+    }
+
+    #[test]
+    fn test_profile_changes_selected_nodes() {
+        // Center has one semantic and one code neighbor; room for only one
+        let mut graph = TestGraph::new(3);
+        graph.link(0, 1, GraphLinkEdgeType::SemanticSimilar, 0.8);
+        graph.link(0, 2, GraphLinkEdgeType::CodeRelated, 0.7);
+        let extract = |profile: &str| {
+            let opts = SubgraphOptions::default()
+                .with_max_nodes(2)
+                .with_profile(get_weight_profile(profile).unwrap());
+            extract_subgraph(&[graph.nodes[0]], 1, &opts, |id| graph.edges_of(id)).unwrap()
+        };
+
+        let code = extract("code_search");
+        let semantic = extract("semantic_search");
+        assert!(code.contains(graph.nodes[2]) && !code.contains(graph.nodes[1]));
+        assert!(semantic.contains(graph.nodes[1]) && !semantic.contains(graph.nodes[2]));
+
+        // code_search: E7 is the strongest embedder, E1 half of it
+        let code_edge = &code.edges[0];
+        assert_eq!(code_edge.edge_type, GraphLinkEdgeType::CodeRelated);
+        assert!((code_edge.effective_weight - 0.7).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_supersedes_filter_walks_chain_in_order() {
+        // 0 supersedes 1 supersedes 2 supersedes 3; 0 also has semantic neighbors
+        let mut graph = TestGraph::new(6);
+        for i in 0..3 {
+            let edge = TypedEdge::relation(
+                graph.nodes[i],
+                graph.nodes[i + 1],
+                GraphLinkEdgeType::Supersedes,
+            )
+            .unwrap();
+            graph.out.entry(graph.nodes[i]).or_default().push(edge);
+        }
+        graph.link(0, 4, GraphLinkEdgeType::SemanticSimilar, 0.95);
+        graph.link(4, 5, GraphLinkEdgeType::SemanticSimilar, 0.95);
+        let center = graph.nodes[0];
+        let extract = |filter: TraversalFilter| {
+            let opts = SubgraphOptions::default().with_filter(filter);
+            extract_subgraph(&[center], 10, &opts, |id| graph.edges_of(id)).unwrap()
+        };
+
+        let chain = extract(TraversalFilter::edge_types(&[GraphLinkEdgeType::Supersedes]));
+        let ids: Vec<Uuid> = chain.nodes.iter().map(|n| n.id).collect();
+        assert_eq!(ids, graph.nodes[..4].to_vec());
+        assert_eq!(chain.nodes.iter().map(|n| n.hop).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert!(chain
+            .edges
+            .iter()
+            .all(|e| e.edge_type == GraphLinkEdgeType::Supersedes));
+
+        let semantic_only = extract(TraversalFilter::edge_types(&[
+            GraphLinkEdgeType::SemanticSimilar,
+        ]));
+        assert!(semantic_only
+            .nodes
+            .iter()
+            .all(|n| n.id == center || !graph.nodes[1..4].contains(&n.id)));
+
+        // Forward-only skips the symmetric semantic edges entirely
+        let forward =
+            extract(TraversalFilter::default().with_directions(&[DirectedRelation::Forward]));
+        assert_eq!(forward.nodes.len(), 4);
+    }
+
+    #[test]
+    fn test_filter_on_chain_without_matching_edges_returns_nothing() {
+        let mut graph = TestGraph::new(3);
+        for i in 0..2 {
+            let edge = TypedEdge::relation(
+                graph.nodes[i],
+                graph.nodes[i + 1],
+                GraphLinkEdgeType::Supersedes,
+            )
+            .unwrap();
+            graph.out.entry(graph.nodes[i]).or_default().push(edge);
+        }
+        let opts = SubgraphOptions::default().with_filter(TraversalFilter::edge_types(&[
+            GraphLinkEdgeType::SemanticSimilar,
+        ]));
+
+        let subgraph =
+            extract_subgraph(&[graph.nodes[0]], 5, &opts, |id| graph.edges_of(id)).unwrap();
+        assert_eq!(subgraph.nodes.len(), 1, "only the center");
+        assert!(subgraph.edges.is_empty());
+    }
+
+    #[test]
+    fn test_renderings() {
+        let mut graph = TestGraph::new(2);
+        graph.link(0, 1, GraphLinkEdgeType::CausalChain, 0.5);
+        let mut subgraph =
+            extract_subgraph(&[graph.nodes[0]], 1, &SubgraphOptions::default(), |id| {
+                graph.edges_of(id)
+            })
+            .unwrap();
+        subgraph.set_summaries(|id| (id == graph.nodes[0]).then(|| "Retry \"backoff\"".into()));
+
+        let mermaid = subgraph.to_mermaid();
+        assert!(mermaid.starts_with("graph LR\n"));
+        assert!(mermaid.contains("n0[\"Retry #quot;backoff#quot;\"]"));
+        assert!(mermaid.contains("n0 -->|causal_chain 0.50| n1"));
+
+        let json = subgraph.to_json();
+        assert_eq!(json["nodes"].as_array().unwrap().len(), 2);
+        assert_eq!(json["edges"][0]["edgeType"], "causal_chain");
+        assert_eq!(json["truncated"], false);
+    }
+}
@@ -64,6 +64,11 @@ impl EdgeThresholds {
             GraphLinkEdgeType::ParaphraseAligned => self.paraphrase_aligned,
             GraphLinkEdgeType::KeywordOverlap => self.keyword_overlap,
             GraphLinkEdgeType::MultiAgreement => self.multi_agreement,
+            // Asserted relations are not detected from similarity
+            GraphLinkEdgeType::Refutes
+            | GraphLinkEdgeType::Supersedes
+            | GraphLinkEdgeType::DerivedFrom
+            | GraphLinkEdgeType::TemporalFollows => 0.0,
         }
     }
 
@@ -73,7 +78,7 @@ impl EdgeThresholds {
         similarity >= self.get(edge_type)
     }
 
-    /// Get all thresholds as an array indexed by edge type.
+    /// Get the embedder-derived thresholds as an array indexed by edge type.
     pub fn as_array(&self) -> [f32; 8] {
         [
             self.semantic_similar,
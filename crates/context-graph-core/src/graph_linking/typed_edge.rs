@@ -2,7 +2,8 @@
 //!
 //! TypedEdge represents a relationship between two nodes that has been
 //! classified into one of 8 edge types based on which embedders agree
-//! that the nodes are similar.
+//! that the nodes are similar, or asserted as one of the 4 relation types
+//! (refutes, supersedes, derived_from, temporal_follows).
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -82,7 +83,7 @@ impl TypedEdge {
     /// # Errors
     ///
     /// - `InvalidSimilarityScore` if weight not in [0.0, 1.0]
-    /// - `DirectionRequired` if edge_type is asymmetric but direction is Symmetric
+    /// - `DirectionRequired` if edge_type is directed but direction is Symmetric
     /// - `AgreementCountMismatch` if count doesn't match bitset popcount
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -104,8 +105,8 @@ impl TypedEdge {
             });
         }
 
-        // Validate direction for asymmetric types
-        if edge_type.is_asymmetric() && direction.is_symmetric() {
+        // Validate direction for asymmetric and relation types
+        if edge_type.is_directed() && direction.is_symmetric() {
             return Err(EdgeError::DirectionRequired { edge_type });
         }
 
@@ -130,6 +131,31 @@ impl TypedEdge {
         })
     }
 
+    /// Create an asserted relation edge (source → target) at the type's
+    /// default weight.
+    ///
+    /// # Errors
+    ///
+    /// `InvalidEdgeType` if `edge_type` is embedder-derived; those edges
+    /// come from `from_scores`.
+    pub fn relation(source: Uuid, target: Uuid, edge_type: GraphLinkEdgeType) -> EdgeResult<Self> {
+        if !edge_type.is_relation() {
+            return Err(EdgeError::InvalidEdgeType {
+                value: edge_type.as_u8(),
+            });
+        }
+        Self::new(
+            source,
+            target,
+            edge_type,
+            edge_type.default_weight(),
+            DirectedRelation::Forward,
+            [0.0; NUM_EMBEDDERS],
+            0,
+            0,
+        )
+    }
+
     /// Create a typed edge from embedder scores, auto-detecting edge type.
     ///
     /// This analyzes the embedder scores to determine the appropriate edge type
@@ -397,6 +423,31 @@ mod tests {
         assert!(!edge.embedder_agrees(1)); // E2
     }
 
+    #[test]
+    fn test_relation_edge_is_forward_at_default_weight() {
+        let (source, target) = (Uuid::new_v4(), Uuid::new_v4());
+        let edge = TypedEdge::relation(source, target, GraphLinkEdgeType::Supersedes).unwrap();
+
+        assert_eq!(edge.edge_type(), GraphLinkEdgeType::Supersedes);
+        assert_eq!(edge.weight(), 0.9);
+        assert!(edge.direction().is_forward());
+        assert_eq!(edge.agreement_count(), 0);
+
+        assert!(TypedEdge::relation(source, target, GraphLinkEdgeType::SemanticSimilar).is_err());
+        // Relations need a direction like asymmetric types do
+        let symmetric = TypedEdge::new(
+            source,
+            target,
+            GraphLinkEdgeType::DerivedFrom,
+            0.8,
+            DirectedRelation::Symmetric,
+            default_scores(),
+            0,
+            0,
+        );
+        assert!(matches!(symmetric, Err(EdgeError::DirectionRequired { .. })));
+    }
+
     #[test]
     fn test_new_asymmetric_edge() {
         let source = Uuid::new_v4();
@@ -0,0 +1,357 @@
+//! Memory importance scoring.
+//!
+//! [`ImportanceModel`] turns the stored `importance` field of a
+//! [`TeleologicalFingerprint`] into the value used for ranking:
+//!
+//! - **Base**: derived from the Multi-UTL learning score at store time
+//! - **Boosts**: event-driven increments (workspace entry, explicit tool call,
+//!   reference by a newer memory)
+//! - **Decay**: exponential towards `floor` with a configurable half-life,
+//!   computed lazily from `last_updated` so no background scan is needed
+//! - **Clamp**: every value stays within `[floor, ceiling]`
+//! - **Access**: an optional bonus from the store's [`AccessStats`], growing
+//!   with hits in the last 7 days and fading with time since the last access
+//!
+//! # Formula
+//! ```text
+//! effective(t) = floor + (importance - floor) * 0.5^((t - last_updated) / half_life)
+//! ```
+//!
+//! The stored value is only rewritten when an event is applied. The decay
+//! accrued so far is folded in first, so boosts compound on the current
+//! effective value rather than on a stale one.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::similarity::MultiUtlParams;
+use crate::traits::AccessStats;
+use crate::types::fingerprint::TeleologicalFingerprint;
+
+/// Default decay half-life: 7 days.
+pub const DEFAULT_IMPORTANCE_HALF_LIFE_SECS: u64 = 7 * 24 * 3600;
+
+/// Recent hits at which the access bonus reaches half of `access_weight`.
+pub const ACCESS_SATURATION_HITS: f32 = 5.0;
+
+/// Configuration for [`ImportanceModel`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ImportanceConfig {
+    /// Time for the distance to `floor` to halve. Default: 7 days.
+    pub half_life_secs: u64,
+    /// Lower bound and decay asymptote. Default: 0.0.
+    pub floor: f32,
+    /// Upper bound. Default: 1.0.
+    pub ceiling: f32,
+    /// Boost when a memory enters the injected workspace. Default: 0.05.
+    pub workspace_entry_boost: f32,
+    /// Boost when a newer memory references this one via a graph edge. Default: 0.02.
+    pub reference_boost: f32,
+    /// How strongly effective importance shifts search scores. Default: 0.1.
+    ///
+    /// Applied as `similarity + ranking_weight * (effective - neutral)`, so a
+    /// fresh memory at the default importance keeps its similarity unchanged.
+    pub ranking_weight: f32,
+    /// Largest bonus that frequent, recent access adds. Default: 0.1.
+    pub access_weight: f32,
+}
+
+impl Default for ImportanceConfig {
+    fn default() -> Self {
+        Self {
+            half_life_secs: DEFAULT_IMPORTANCE_HALF_LIFE_SECS,
+            floor: 0.0,
+            ceiling: 1.0,
+            workspace_entry_boost: 0.05,
+            reference_boost: 0.02,
+            ranking_weight: 0.1,
+            access_weight: 0.1,
+        }
+    }
+}
+
+/// Event that raises a memory's importance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImportanceEvent {
+    /// Memory was selected into the injected workspace.
+    WorkspaceEntry,
+    /// Explicit `boost_importance` call; `delta` may be negative.
+    ExplicitBoost { delta: f32 },
+    /// A newer memory linked to this one via a graph edge.
+    Referenced,
+}
+
+/// Outcome of [`ImportanceModel::apply_event_at`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImportanceUpdate {
+    /// Effective (decayed) importance just before the event.
+    pub old: f32,
+    /// Stored importance after the event.
+    pub new: f32,
+    /// Whether the result hit `floor` or `ceiling`.
+    pub clamped: bool,
+}
+
+/// Computes base, boosted and decayed importance.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ImportanceModel {
+    config: ImportanceConfig,
+}
+
+impl ImportanceModel {
+    /// Create a model with the given configuration.
+    pub fn new(config: ImportanceConfig) -> Self {
+        Self { config }
+    }
+
+    /// The active configuration.
+    pub fn config(&self) -> &ImportanceConfig {
+        &self.config
+    }
+
+    /// Clamp `value` to `[floor, ceiling]`. NaN maps to `floor`.
+    pub fn clamp(&self, value: f32) -> f32 {
+        if value.is_nan() {
+            return self.config.floor;
+        }
+        value.clamp(self.config.floor, self.config.ceiling)
+    }
+
+    /// Base importance at store time from the Multi-UTL learning score.
+    ///
+    /// `L_multi` lies in (0, 1) and is mapped linearly onto `[floor, ceiling]`.
+    pub fn base_importance(&self, utl: &MultiUtlParams) -> f32 {
+        let learning = utl.compute();
+        self.clamp(self.config.floor + (self.config.ceiling - self.config.floor) * learning)
+    }
+
+    /// Decay `importance` from `last_updated` to `now`.
+    ///
+    /// Timestamps in the future (clock skew) are treated as zero elapsed time.
+    pub fn decayed(&self, importance: f32, last_updated: DateTime<Utc>, now: DateTime<Utc>) -> f32 {
+        let start = self.clamp(importance);
+        let factor = self.decay_factor(last_updated, now);
+        self.clamp(self.config.floor + (start - self.config.floor) * factor)
+    }
+
+    /// `0.5^((now - since) / half_life)`, 1.0 for future timestamps.
+    fn decay_factor(&self, since: DateTime<Utc>, now: DateTime<Utc>) -> f32 {
+        if self.config.half_life_secs == 0 {
+            return 0.0;
+        }
+        let elapsed_secs = (now - since).num_milliseconds().max(0) as f64 / 1000.0;
+        0.5f64.powf(elapsed_secs / self.config.half_life_secs as f64) as f32
+    }
+
+    /// Effective importance of `fingerprint` at `now`.
+    pub fn effective_at(&self, fingerprint: &TeleologicalFingerprint, now: DateTime<Utc>) -> f32 {
+        self.decayed(fingerprint.importance, fingerprint.last_updated, now)
+    }
+
+    /// Effective importance of `fingerprint` at the current time.
+    pub fn effective(&self, fingerprint: &TeleologicalFingerprint) -> f32 {
+        self.effective_at(fingerprint, Utc::now())
+    }
+
+    /// Bonus from recent, repeated access at `now`.
+    ///
+    /// `access_weight * hits_7d / (hits_7d + ACCESS_SATURATION_HITS)`, decayed
+    /// from the last access with the importance half-life. Zero for memories
+    /// never accessed or not accessed within the last 7 days.
+    pub fn access_bonus_at(&self, stats: &AccessStats, now: DateTime<Utc>) -> f32 {
+        let Some(last_access) = stats.last_access else {
+            return 0.0;
+        };
+        let recent = stats.hits_7d as f32;
+        let frequency = recent / (recent + ACCESS_SATURATION_HITS);
+        self.config.access_weight * frequency * self.decay_factor(last_access, now)
+    }
+
+    /// Effective importance at `now` plus the access bonus, clamped.
+    pub fn effective_with_access_at(
+        &self,
+        fingerprint: &TeleologicalFingerprint,
+        stats: &AccessStats,
+        now: DateTime<Utc>,
+    ) -> f32 {
+        self.clamp(self.effective_at(fingerprint, now) + self.access_bonus_at(stats, now))
+    }
+
+    /// Fold the decay accrued up to `now` into the stored value.
+    ///
+    /// Call before anything else moves `last_updated` forward (e.g.
+    /// `record_access`), otherwise the accrued decay is lost.
+    pub fn materialize_at(&self, fingerprint: &mut TeleologicalFingerprint, now: DateTime<Utc>) {
+        fingerprint.importance = self.effective_at(fingerprint, now);
+        fingerprint.last_updated = now;
+    }
+
+    /// Apply `event` at `now`, updating `importance` and `last_updated`.
+    pub fn apply_event_at(
+        &self,
+        fingerprint: &mut TeleologicalFingerprint,
+        event: ImportanceEvent,
+        now: DateTime<Utc>,
+    ) -> ImportanceUpdate {
+        let old = self.effective_at(fingerprint, now);
+        let delta = match event {
+            ImportanceEvent::WorkspaceEntry => self.config.workspace_entry_boost,
+            ImportanceEvent::ExplicitBoost { delta } => delta,
+            ImportanceEvent::Referenced => self.config.reference_boost,
+        };
+        let raw = old + delta;
+        let new = self.clamp(raw);
+        fingerprint.importance = new;
+        fingerprint.last_updated = now;
+        ImportanceUpdate {
+            old,
+            new,
+            clamped: (raw - new).abs() > f32::EPSILON,
+        }
+    }
+
+    /// Search score after shifting `similarity` by effective importance.
+    ///
+    /// Memories above the default importance gain, memories below it lose.
+    pub fn rank_score(&self, similarity: f32, effective_importance: f32) -> f32 {
+        similarity
+            + self.config.ranking_weight
+                * (effective_importance - TeleologicalFingerprint::DEFAULT_IMPORTANCE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::fingerprint::SemanticFingerprint;
+    use chrono::Duration;
+
+    fn fingerprint_at(importance: f32, at: DateTime<Utc>) -> TeleologicalFingerprint {
+        let mut fp = TeleologicalFingerprint::with_importance(
+            SemanticFingerprint::zeroed(),
+            [0u8; 32],
+            importance,
+        );
+        fp.created_at = at;
+        fp.last_updated = at;
+        fp
+    }
+
+    #[test]
+    fn test_decay_halves_distance_to_floor() {
+        let model = ImportanceModel::default();
+        let t0 = Utc::now();
+        let hl = Duration::seconds(DEFAULT_IMPORTANCE_HALF_LIFE_SECS as i64);
+        let fp = fingerprint_at(0.85, t0);
+
+        assert!((model.effective_at(&fp, t0) - 0.85).abs() < 1e-6);
+        assert!((model.effective_at(&fp, t0 + hl) - 0.425).abs() < 1e-5);
+        assert!((model.effective_at(&fp, t0 + hl * 2) - 0.2125).abs() < 1e-5);
+        // Clock skew: no negative decay
+        assert!((model.effective_at(&fp, t0 - hl) - 0.85).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_events_clamp_and_compound_on_decayed_value() {
+        let model = ImportanceModel::default();
+        let t0 = Utc::now();
+        let hl = Duration::seconds(DEFAULT_IMPORTANCE_HALF_LIFE_SECS as i64);
+        let mut fp = fingerprint_at(0.85, t0);
+
+        let update = model.apply_event_at(
+            &mut fp,
+            ImportanceEvent::ExplicitBoost { delta: 0.1 },
+            t0 + hl,
+        );
+        assert!((update.old - 0.425).abs() < 1e-5);
+        assert!((update.new - 0.525).abs() < 1e-5);
+        assert!(!update.clamped);
+        assert_eq!(fp.last_updated, t0 + hl);
+
+        let update = model.apply_event_at(
+            &mut fp,
+            ImportanceEvent::ExplicitBoost { delta: 2.0 },
+            t0 + hl,
+        );
+        assert_eq!(update.new, 1.0);
+        assert!(update.clamped);
+
+        let update = model.apply_event_at(
+            &mut fp,
+            ImportanceEvent::ExplicitBoost { delta: -2.0 },
+            t0 + hl,
+        );
+        assert_eq!(update.new, model.config().floor);
+        assert!(update.clamped);
+
+        let update = model.apply_event_at(&mut fp, ImportanceEvent::Referenced, t0 + hl);
+        assert!((update.new - 0.02).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_base_importance_from_utl() {
+        let model = ImportanceModel::default();
+        // Neutral UTL params: sigmoid(0) = 0.5
+        let base = model.base_importance(&MultiUtlParams::default());
+        assert!((base - 0.5).abs() < 1e-5);
+
+        let novel = MultiUtlParams::default()
+            .with_semantic_deltas([1.0; 13])
+            .with_coherence_deltas([1.0; 13]);
+        assert!(model.base_importance(&novel) > base);
+    }
+
+    #[test]
+    fn test_access_bonus_grows_with_hits_and_fades_with_age() {
+        let model = ImportanceModel::default();
+        let t0 = Utc::now();
+        let hl = Duration::seconds(DEFAULT_IMPORTANCE_HALF_LIFE_SECS as i64);
+        let fp = fingerprint_at(0.5, t0);
+        let stats = |hits_7d: u64, last_access: Option<DateTime<Utc>>| AccessStats {
+            hits: hits_7d,
+            last_access,
+            hits_7d,
+        };
+
+        let untouched = AccessStats::default();
+        assert_eq!(model.access_bonus_at(&untouched, t0), 0.0);
+        assert_eq!(model.effective_with_access_at(&fp, &untouched, t0), 0.5);
+
+        // 5 recent hits reach half of access_weight
+        let bonus = model.access_bonus_at(&stats(5, Some(t0)), t0);
+        assert!((bonus - 0.05).abs() < 1e-6);
+        assert!(model.access_bonus_at(&stats(20, Some(t0)), t0) > bonus);
+        assert!((model.access_bonus_at(&stats(5, Some(t0)), t0 + hl) - 0.025).abs() < 1e-6);
+        assert_eq!(model.access_bonus_at(&stats(0, Some(t0 - hl * 2)), t0), 0.0);
+
+        let top = fingerprint_at(0.98, t0);
+        assert_eq!(
+            model.effective_with_access_at(&top, &stats(50, Some(t0)), t0),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_boost_outranks_until_half_life_elapses() {
+        let model = ImportanceModel::default();
+        let t0 = Utc::now();
+        let hl = Duration::seconds(DEFAULT_IMPORTANCE_HALF_LIFE_SECS as i64);
+
+        let unboosted = fingerprint_at(TeleologicalFingerprint::DEFAULT_IMPORTANCE, t0);
+        let mut boosted = fingerprint_at(TeleologicalFingerprint::DEFAULT_IMPORTANCE, t0);
+        model.apply_event_at(
+            &mut boosted,
+            ImportanceEvent::ExplicitBoost { delta: 0.4 },
+            t0,
+        );
+
+        // The unboosted memory is slightly more relevant to the query
+        let (sim_unboosted, sim_boosted) = (0.80, 0.77);
+        let score = |fp: &TeleologicalFingerprint, sim: f32, now| {
+            model.rank_score(sim, model.effective_at(fp, now))
+        };
+
+        assert!(score(&boosted, sim_boosted, t0) > score(&unboosted, sim_unboosted, t0));
+        assert!(score(&boosted, sim_boosted, t0 + hl) < score(&unboosted, sim_unboosted, t0 + hl));
+    }
+}
@@ -38,13 +38,16 @@ pub mod error;
 pub mod fusion;
 pub mod graph;
 pub mod graph_linking;
+pub mod importance;
 pub mod injection;
 pub mod index;
 pub mod memory;
 pub mod monitoring;
 pub mod quantization;
+pub mod reembed;
 pub mod retrieval;
 pub mod similarity;
+pub mod snapshot;
 pub mod stubs;
 pub mod teleological;
 pub mod traits;
@@ -117,7 +120,8 @@ pub use fusion::{
 pub use graph::{
     GraphDirection, ConnectivityContext, compute_graph_asymmetric_similarity,
     compute_graph_asymmetric_similarity_simple, compute_e8_asymmetric_fingerprint_similarity,
-    compute_e8_asymmetric_full, detect_graph_query_intent, adjust_batch_graph_similarities,
+    compute_e8_asymmetric_full, compute_e8_edge_similarity, detect_graph_query_intent,
+    adjust_batch_graph_similarities,
 };
 
 // Graph linking types - K-NN graph construction and multi-relation edges
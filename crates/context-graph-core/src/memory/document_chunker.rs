@@ -0,0 +1,480 @@
+//! Token-budgeted document chunking for store_memory.
+//!
+//! This module provides:
+//! - [`Chunker`] - Pluggable chunking strategy
+//! - [`TokenCounter`] - Pluggable token counting (defaults to the injection estimate)
+//! - [`SemanticChunker`] - Splits on headings, blank lines and sentences with overlap
+//! - [`DocumentChunk`] - One chunk with its position in the source document
+//!
+//! Unlike [`super::TextChunker`] (fixed word windows for watched markdown
+//! files), [`SemanticChunker`] packs whole structural units into chunks of at
+//! most `max_tokens`:
+//! 1. A heading always starts a new chunk.
+//! 2. Paragraphs (blank-line separated, fenced code kept intact) are packed greedily.
+//! 3. Units larger than the budget fall back to sentences, then to word windows.
+//! 4. Consecutive chunks within a section share up to `overlap_tokens` of trailing units.
+//!
+//! Chunking is a pure function of the input and configuration, so identical
+//! content always yields identical chunks.
+//!
+//! # Example
+//! ```rust
+//! use context_graph_core::memory::{Chunker, SemanticChunker};
+//!
+//! let chunker = SemanticChunker::new(64, 8).unwrap();
+//! let chunks = chunker.chunk("# Title\n\nFirst paragraph.\n\n# Next\n\nSecond.").unwrap();
+//! assert_eq!(chunks.len(), 2);
+//! assert_eq!(chunks[1].heading.as_deref(), Some("Next"));
+//! ```
+
+use std::sync::Arc;
+
+use crate::injection::estimate_tokens;
+
+use super::ChunkerError;
+
+/// Counts tokens for chunk budgeting.
+///
+/// Implement this with the embedding model's tokenizer for exact budgets.
+pub trait TokenCounter: Send + Sync {
+    /// Number of tokens in `text`.
+    fn count(&self, text: &str) -> usize;
+}
+
+/// Word-based estimate (`words * 1.3`), shared with the injection budget.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WordTokenCounter;
+
+impl TokenCounter for WordTokenCounter {
+    fn count(&self, text: &str) -> usize {
+        estimate_tokens(text) as usize
+    }
+}
+
+/// A chunk of a larger document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentChunk {
+    /// 0-based position in the document.
+    pub index: usize,
+    /// Chunk text.
+    pub content: String,
+    /// Token count according to the chunker's [`TokenCounter`].
+    pub token_count: usize,
+    /// Nearest heading above the chunk, without leading `#`s.
+    pub heading: Option<String>,
+    /// First source line (1-based).
+    pub start_line: u32,
+    /// Last source line (1-based, inclusive).
+    pub end_line: u32,
+}
+
+/// Splits a document into chunks.
+pub trait Chunker: Send + Sync {
+    /// Chunk `content`. Must be deterministic for identical input.
+    ///
+    /// # Errors
+    /// - [`ChunkerError::EmptyContent`] if content is empty or whitespace-only
+    fn chunk(&self, content: &str) -> Result<Vec<DocumentChunk>, ChunkerError>;
+}
+
+/// A structural unit: heading, paragraph, sentence or word window.
+#[derive(Debug, Clone)]
+struct Unit {
+    text: String,
+    tokens: usize,
+    heading: Option<String>,
+    starts_section: bool,
+    start_line: u32,
+    end_line: u32,
+}
+
+/// Heading-, paragraph- and sentence-aware chunker with a token budget.
+#[derive(Clone)]
+pub struct SemanticChunker {
+    max_tokens: usize,
+    overlap_tokens: usize,
+    counter: Arc<dyn TokenCounter>,
+}
+
+impl SemanticChunker {
+    /// Default token budget per chunk.
+    pub const DEFAULT_MAX_TOKENS: usize = 512;
+    /// Default overlap between consecutive chunks of a section.
+    pub const DEFAULT_OVERLAP_TOKENS: usize = 64;
+    /// Smallest accepted budget.
+    pub const MIN_MAX_TOKENS: usize = 32;
+
+    /// Create a chunker using [`WordTokenCounter`].
+    ///
+    /// # Errors
+    /// - [`ChunkerError::ChunkSizeTooSmall`] if `max_tokens < MIN_MAX_TOKENS`
+    /// - [`ChunkerError::InvalidOverlap`] if `overlap_tokens >= max_tokens`
+    pub fn new(max_tokens: usize, overlap_tokens: usize) -> Result<Self, ChunkerError> {
+        Self::with_counter(max_tokens, overlap_tokens, Arc::new(WordTokenCounter))
+    }
+
+    /// Create a chunker with a custom [`TokenCounter`].
+    ///
+    /// # Errors
+    /// Same as [`SemanticChunker::new`].
+    pub fn with_counter(
+        max_tokens: usize,
+        overlap_tokens: usize,
+        counter: Arc<dyn TokenCounter>,
+    ) -> Result<Self, ChunkerError> {
+        if max_tokens < Self::MIN_MAX_TOKENS {
+            return Err(ChunkerError::ChunkSizeTooSmall {
+                chunk_size: max_tokens,
+                min: Self::MIN_MAX_TOKENS,
+            });
+        }
+        if overlap_tokens >= max_tokens {
+            return Err(ChunkerError::InvalidOverlap {
+                chunk_size: max_tokens,
+                overlap: overlap_tokens,
+            });
+        }
+        Ok(Self {
+            max_tokens,
+            overlap_tokens,
+            counter,
+        })
+    }
+
+    /// Configured token budget per chunk.
+    pub fn max_tokens(&self) -> usize {
+        self.max_tokens
+    }
+
+    /// Configured overlap in tokens.
+    pub fn overlap_tokens(&self) -> usize {
+        self.overlap_tokens
+    }
+
+    /// Token count of `text` according to this chunker's counter.
+    pub fn count_tokens(&self, text: &str) -> usize {
+        self.counter.count(text)
+    }
+
+    /// Split content into headings and paragraphs. Fenced code blocks are
+    /// never split on blank lines.
+    fn blocks(&self, content: &str) -> Vec<Unit> {
+        let mut units = Vec::new();
+        let mut heading: Option<String> = None;
+        let mut lines: Vec<&str> = Vec::new();
+        let mut start_line = 0u32;
+        let mut in_fence = false;
+
+        let mut flush = |lines: &mut Vec<&str>,
+                         start: u32,
+                         end: u32,
+                         heading: &Option<String>,
+                         starts_section: bool| {
+            if lines.is_empty() {
+                return;
+            }
+            let text = lines.join("\n");
+            lines.clear();
+            units.push(Unit {
+                tokens: self.counter.count(&text),
+                text,
+                heading: heading.clone(),
+                starts_section,
+                start_line: start,
+                end_line: end,
+            });
+        };
+
+        for (i, line) in content.lines().enumerate() {
+            let line_no = i as u32 + 1;
+            let trimmed = line.trim();
+
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                if lines.is_empty() {
+                    start_line = line_no;
+                }
+                lines.push(line);
+                in_fence = !in_fence;
+                continue;
+            }
+            if in_fence {
+                lines.push(line);
+                continue;
+            }
+
+            if let Some(title) = heading_title(trimmed) {
+                flush(&mut lines, start_line, line_no - 1, &heading, false);
+                heading = Some(title.to_string());
+                lines.push(line);
+                flush(&mut lines, line_no, line_no, &heading, true);
+            } else if trimmed.is_empty() {
+                flush(&mut lines, start_line, line_no - 1, &heading, false);
+            } else {
+                if lines.is_empty() {
+                    start_line = line_no;
+                }
+                lines.push(line);
+            }
+        }
+        let last_line = content.lines().count() as u32;
+        flush(&mut lines, start_line, last_line, &heading, false);
+        units
+    }
+
+    /// Break a unit over budget into sentences, then word windows.
+    fn split_oversized(&self, unit: Unit) -> Vec<Unit> {
+        if unit.tokens <= self.max_tokens {
+            return vec![unit];
+        }
+
+        let mut pieces: Vec<String> = Vec::new();
+        for sentence in split_sentences(&unit.text) {
+            if self.counter.count(sentence) <= self.max_tokens {
+                pieces.push(sentence.to_string());
+                continue;
+            }
+            let mut window = String::new();
+            for word in sentence.split_whitespace() {
+                let candidate = if window.is_empty() {
+                    word.to_string()
+                } else {
+                    format!("{} {}", window, word)
+                };
+                if !window.is_empty() && self.counter.count(&candidate) > self.max_tokens {
+                    pieces.push(std::mem::replace(&mut window, word.to_string()));
+                } else {
+                    window = candidate;
+                }
+            }
+            if !window.is_empty() {
+                pieces.push(window);
+            }
+        }
+
+        pieces
+            .into_iter()
+            .enumerate()
+            .map(|(i, text)| Unit {
+                tokens: self.counter.count(&text),
+                text,
+                heading: unit.heading.clone(),
+                starts_section: unit.starts_section && i == 0,
+                start_line: unit.start_line,
+                end_line: unit.end_line,
+            })
+            .collect()
+    }
+
+    fn emit(&self, chunks: &mut Vec<DocumentChunk>, units: &[Unit]) {
+        let (Some(first), Some(last)) = (units.first(), units.last()) else {
+            return;
+        };
+        let content = units
+            .iter()
+            .map(|u| u.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        chunks.push(DocumentChunk {
+            index: chunks.len(),
+            token_count: self.counter.count(&content),
+            content,
+            heading: first.heading.clone(),
+            start_line: first.start_line,
+            end_line: last.end_line,
+        });
+    }
+
+    /// Trailing units of `units` totalling at most `overlap_tokens`,
+    /// never the whole chunk.
+    fn overlap_tail(&self, units: &[Unit]) -> Vec<Unit> {
+        let mut tokens = 0;
+        let mut start = units.len();
+        while start > 1 {
+            let next = tokens + units[start - 1].tokens;
+            if next > self.overlap_tokens {
+                break;
+            }
+            tokens = next;
+            start -= 1;
+        }
+        units[start..].to_vec()
+    }
+}
+
+impl Default for SemanticChunker {
+    fn default() -> Self {
+        Self {
+            max_tokens: Self::DEFAULT_MAX_TOKENS,
+            overlap_tokens: Self::DEFAULT_OVERLAP_TOKENS,
+            counter: Arc::new(WordTokenCounter),
+        }
+    }
+}
+
+impl Chunker for SemanticChunker {
+    fn chunk(&self, content: &str) -> Result<Vec<DocumentChunk>, ChunkerError> {
+        if content.trim().is_empty() {
+            return Err(ChunkerError::EmptyContent);
+        }
+
+        let units: Vec<Unit> = self
+            .blocks(content)
+            .into_iter()
+            .flat_map(|u| self.split_oversized(u))
+            .collect();
+
+        let mut chunks = Vec::new();
+        let mut current: Vec<Unit> = Vec::new();
+        let mut current_tokens = 0;
+
+        for unit in units {
+            if unit.starts_section && !current.is_empty() {
+                self.emit(&mut chunks, &current);
+                current.clear();
+                current_tokens = 0;
+            } else if current_tokens + unit.tokens > self.max_tokens && !current.is_empty() {
+                self.emit(&mut chunks, &current);
+                current = self.overlap_tail(&current);
+                current_tokens = current.iter().map(|u| u.tokens).sum();
+                if current_tokens + unit.tokens > self.max_tokens {
+                    current.clear();
+                    current_tokens = 0;
+                }
+            }
+            current_tokens += unit.tokens;
+            current.push(unit);
+        }
+        self.emit(&mut chunks, &current);
+
+        Ok(chunks)
+    }
+}
+
+/// Markdown ATX heading text (`# Title` -> `Title`).
+fn heading_title(line: &str) -> Option<&str> {
+    let hashes = line.chars().take_while(|&c| c == '#').count();
+    if hashes == 0 || hashes > 6 {
+        return None;
+    }
+    let rest = &line[hashes..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    Some(rest.trim())
+}
+
+/// Split on `.`, `!` or `?` followed by whitespace, keeping the terminator.
+fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        if matches!(c, '.' | '!' | '?') {
+            if let Some(&(next, n)) = chars.peek() {
+                if n.is_whitespace() {
+                    let sentence = text[start..next].trim();
+                    if !sentence.is_empty() {
+                        sentences.push(sentence);
+                    }
+                    start = next;
+                }
+            }
+        }
+    }
+    let tail = text[start..].trim();
+    if !tail.is_empty() {
+        sentences.push(tail);
+    }
+    sentences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(title: &str, sentences: usize, word: &str) -> String {
+        let body: Vec<String> = (0..sentences)
+            .map(|i| format!("The {} sentence number {} describes this section.", word, i))
+            .collect();
+        format!("## {}\n\n{}\n", title, body.join(" "))
+    }
+
+    #[test]
+    fn test_headings_start_new_chunks() {
+        let doc = format!(
+            "# Guide\n\nIntro paragraph.\n\n{}\n{}",
+            section("Install", 2, "install"),
+            section("Usage", 2, "usage")
+        );
+        let chunks = SemanticChunker::new(64, 8).unwrap().chunk(&doc).unwrap();
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].heading.as_deref(), Some("Guide"));
+        assert_eq!(chunks[1].heading.as_deref(), Some("Install"));
+        assert_eq!(chunks[2].heading.as_deref(), Some("Usage"));
+        assert!(chunks[2].content.starts_with("## Usage"));
+        assert!(!chunks[2].content.contains("install sentence"));
+        assert_eq!(chunks[0].start_line, 1);
+        for (i, c) in chunks.iter().enumerate() {
+            assert_eq!(c.index, i);
+        }
+    }
+
+    #[test]
+    fn test_long_section_splits_within_budget_with_overlap() {
+        let doc = section("Reference", 40, "reference");
+        let chunker = SemanticChunker::new(64, 16).unwrap();
+        let chunks = chunker.chunk(&doc).unwrap();
+
+        assert!(chunks.len() > 3, "got {} chunks", chunks.len());
+        for c in &chunks {
+            assert!(
+                c.token_count <= 64 + 16,
+                "chunk {} has {} tokens",
+                c.index,
+                c.token_count
+            );
+        }
+        // Consecutive chunks share their boundary sentence
+        for pair in chunks.windows(2) {
+            let last_sentence = pair[0].content.rsplit("\n\n").next().unwrap();
+            assert!(pair[1].content.starts_with(last_sentence));
+        }
+    }
+
+    #[test]
+    fn test_chunking_is_deterministic() {
+        let doc = format!(
+            "{}\n{}",
+            section("A", 30, "alpha"),
+            section("B", 30, "beta")
+        );
+        let chunker = SemanticChunker::new(48, 8).unwrap();
+        assert_eq!(chunker.chunk(&doc).unwrap(), chunker.chunk(&doc).unwrap());
+    }
+
+    #[test]
+    fn test_fenced_code_is_not_split_on_blank_lines() {
+        let doc = "# Code\n\n```rust\nfn a() {}\n\nfn b() {}\n```\n";
+        let chunks = SemanticChunker::new(64, 0).unwrap().chunk(doc).unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert!(chunks[0].content.contains("fn a() {}\n\nfn b() {}"));
+        assert_eq!(chunks[0].end_line, 7);
+    }
+
+    #[test]
+    fn test_config_validation_and_empty_content() {
+        assert!(matches!(
+            SemanticChunker::new(8, 0),
+            Err(ChunkerError::ChunkSizeTooSmall { .. })
+        ));
+        assert!(matches!(
+            SemanticChunker::new(64, 64),
+            Err(ChunkerError::InvalidOverlap { .. })
+        ));
+        assert!(matches!(
+            SemanticChunker::default().chunk("  \n "),
+            Err(ChunkerError::EmptyContent)
+        ));
+    }
+}
@@ -36,6 +36,7 @@ pub mod capture;
 pub mod chunker;
 pub mod code_capture;
 pub mod code_watcher;
+pub mod document_chunker;
 pub mod manager;
 pub mod session;
 pub mod source;
@@ -52,6 +53,9 @@ pub use code_capture::{
     CodeEmbeddingProvider, CodeSearchResult, CodeStorage,
 };
 pub use code_watcher::{CodeFileWatcher, CodeWatcherError, WatcherStats};
+pub use document_chunker::{
+    Chunker, DocumentChunk, SemanticChunker, TokenCounter, WordTokenCounter,
+};
 pub use chunker::{ChunkerError, TextChunk, TextChunker};
 // ChunkMetadata is defined in this file and exported directly
 pub use manager::{SessionError, SessionManager, CF_SESSIONS};
@@ -0,0 +1,135 @@
+//! Configuration and resume cursor for a re-embedding run.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::{CoreError, CoreResult};
+use crate::teleological::{Embedder, EmbedderMask};
+
+/// Default number of fingerprints processed per batch.
+pub const DEFAULT_REEMBED_BATCH_SIZE: usize = 64;
+
+/// Processing cursor key used when none is configured.
+pub const DEFAULT_REEMBED_CURSOR_KEY: &str = "reembed_cursor";
+
+/// Configuration for a [`super::ReembedJob`].
+#[derive(Debug, Clone)]
+pub struct ReembedConfig {
+    /// Embedders to recompute. All other spaces are left untouched.
+    pub embedders: EmbedderMask,
+
+    /// Fingerprints fetched, embedded, and checkpointed per batch.
+    pub batch_size: usize,
+
+    /// Version tag recorded for every recomputed embedder.
+    ///
+    /// None = use the provider's model ID for that embedder.
+    pub model_version: Option<String>,
+
+    /// Processing cursor key the run checkpoints under.
+    pub cursor_key: String,
+}
+
+impl ReembedConfig {
+    /// Create a config recomputing `embedders` with default batching.
+    pub fn new(embedders: EmbedderMask) -> Self {
+        Self {
+            embedders,
+            batch_size: DEFAULT_REEMBED_BATCH_SIZE,
+            model_version: None,
+            cursor_key: DEFAULT_REEMBED_CURSOR_KEY.to_string(),
+        }
+    }
+
+    /// Set the batch size.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the version tag recorded for recomputed embedders.
+    pub fn with_model_version(mut self, version: impl Into<String>) -> Self {
+        self.model_version = Some(version.into());
+        self
+    }
+
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// - `CoreError::ValidationError` if no embedders are selected, the batch
+    ///   size is zero, or a temporal embedder (E2-E4) is selected. Temporal
+    ///   spaces encode capture-time metadata that is not stored with the
+    ///   content, so recomputing them would silently change their meaning.
+    pub fn validate(&self) -> CoreResult<()> {
+        if self.embedders.is_empty() {
+            return Err(CoreError::ValidationError {
+                field: "embedders".to_string(),
+                message: "At least one embedder must be selected".to_string(),
+            });
+        }
+        if self.batch_size == 0 {
+            return Err(CoreError::ValidationError {
+                field: "batch_size".to_string(),
+                message: "Batch size must be greater than 0".to_string(),
+            });
+        }
+        if let Some(temporal) = [
+            Embedder::TemporalRecent,
+            Embedder::TemporalPeriodic,
+            Embedder::TemporalPositional,
+        ]
+        .into_iter()
+        .find(|e| self.embedders.contains(*e))
+        {
+            return Err(CoreError::ValidationError {
+                field: "embedders".to_string(),
+                message: format!(
+                    "{} cannot be re-embedded: temporal embeddings depend on capture-time metadata",
+                    temporal.short_name()
+                ),
+            });
+        }
+        if self.cursor_key.is_empty() {
+            return Err(CoreError::ValidationError {
+                field: "cursor_key".to_string(),
+                message: "Cursor key cannot be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Persisted progress of a re-embedding run.
+///
+/// Serialized as JSON via `store_processing_cursor` after every batch. The
+/// run resumes after `last_fingerprint_id`; a completed cursor starts a new
+/// run from the beginning.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReembedCursor {
+    /// ID of the last fingerprint processed (fingerprints are visited in ID order).
+    pub last_fingerprint_id: Option<Uuid>,
+    /// Fingerprints recomputed and written back.
+    pub migrated: u64,
+    /// Fingerprints skipped because every selected embedder was already current.
+    pub already_current: u64,
+    /// Fingerprints skipped because their source content is not stored.
+    pub missing_content: u64,
+    /// True once the run has visited every fingerprint.
+    pub completed: bool,
+}
+
+impl ReembedCursor {
+    /// Cursor resuming after `fingerprint_id`.
+    pub fn after(fingerprint_id: Uuid) -> Self {
+        Self {
+            last_fingerprint_id: Some(fingerprint_id),
+            ..Self::default()
+        }
+    }
+
+    /// Total fingerprints visited so far.
+    pub fn visited(&self) -> u64 {
+        self.migrated + self.already_current + self.missing_content
+    }
+}
@@ -0,0 +1,233 @@
+//! Re-embedding job: streams stored fingerprints and recomputes selected spaces.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::error::{CoreError, CoreResult};
+use crate::teleological::Embedder;
+use crate::traits::{MultiArrayEmbeddingProvider, TeleologicalMemoryStore};
+use crate::types::audit::EmbeddingVersionRecord;
+use crate::types::fingerprint::TeleologicalFingerprint;
+
+use super::config::{ReembedConfig, ReembedCursor};
+
+/// What happened to one fingerprint in a batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Migrated,
+    AlreadyCurrent,
+    MissingContent,
+}
+
+/// Recomputes selected embedding spaces for every stored fingerprint.
+///
+/// Fingerprints are visited in ID order, one batch at a time. For each one,
+/// the selected embedders are re-run on the stored content, the new spaces
+/// replace the old ones (ID, timestamps, and other spaces are preserved),
+/// and `update()` rewrites the fingerprint and its per-embedder indexes. The
+/// embedding version registry records the new version of each recomputed
+/// embedder, so a fingerprint whose spaces come from different model
+/// versions can be detected.
+///
+/// After each batch the cursor is checkpointed under
+/// [`ReembedConfig::cursor_key`]. Fingerprints already at the target version
+/// are skipped, so replaying a batch after a crash does no extra work.
+pub struct ReembedJob {
+    store: Arc<dyn TeleologicalMemoryStore>,
+    provider: Arc<dyn MultiArrayEmbeddingProvider>,
+    config: ReembedConfig,
+}
+
+impl ReembedJob {
+    /// Create a job.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CoreError::ValidationError` if `config` is invalid.
+    pub fn new(
+        store: Arc<dyn TeleologicalMemoryStore>,
+        provider: Arc<dyn MultiArrayEmbeddingProvider>,
+        config: ReembedConfig,
+    ) -> CoreResult<Self> {
+        config.validate()?;
+        Ok(Self {
+            store,
+            provider,
+            config,
+        })
+    }
+
+    /// The job configuration.
+    pub fn config(&self) -> &ReembedConfig {
+        &self.config
+    }
+
+    /// Run to completion, starting from `resume_from` or the stored cursor.
+    ///
+    /// With `resume_from = None`, an unfinished stored cursor is resumed and a
+    /// finished (or missing) one starts a fresh run.
+    pub async fn run(&self, resume_from: Option<ReembedCursor>) -> CoreResult<ReembedCursor> {
+        let mut cursor = match resume_from {
+            Some(cursor) => cursor,
+            None => self.load_cursor().await?,
+        };
+        info!(
+            embedders = ?self.config.embedders.iter().map(Embedder::short_name).collect::<Vec<_>>(),
+            batch_size = self.config.batch_size,
+            after = ?cursor.last_fingerprint_id,
+            "Starting re-embedding run"
+        );
+
+        while self.run_batch(&mut cursor).await? {}
+
+        cursor.completed = true;
+        self.save_cursor(&cursor).await?;
+        info!(
+            migrated = cursor.migrated,
+            already_current = cursor.already_current,
+            missing_content = cursor.missing_content,
+            "Re-embedding run complete"
+        );
+        Ok(cursor)
+    }
+
+    /// Process the next batch after `cursor` and checkpoint it.
+    ///
+    /// Returns `false` when there are no fingerprints left.
+    pub async fn run_batch(&self, cursor: &mut ReembedCursor) -> CoreResult<bool> {
+        let page = self
+            .store
+            .list_fingerprints_after(cursor.last_fingerprint_id, self.config.batch_size)
+            .await?;
+        if page.is_empty() {
+            return Ok(false);
+        }
+
+        let ids: Vec<Uuid> = page.iter().map(|fp| fp.id).collect();
+        let contents = self.store.get_content_batch(&ids).await?;
+
+        for (fingerprint, content) in page.into_iter().zip(contents) {
+            let id = fingerprint.id;
+            match self.migrate_one(fingerprint, content).await? {
+                Outcome::Migrated => cursor.migrated += 1,
+                Outcome::AlreadyCurrent => cursor.already_current += 1,
+                Outcome::MissingContent => cursor.missing_content += 1,
+            }
+            cursor.last_fingerprint_id = Some(id);
+        }
+
+        self.save_cursor(cursor).await?;
+        debug!(
+            visited = cursor.visited(),
+            after = ?cursor.last_fingerprint_id,
+            "Re-embedding batch checkpointed"
+        );
+        Ok(true)
+    }
+
+    /// Load the stored cursor, or a fresh one if none is stored, the stored
+    /// run already completed, or it cannot be parsed.
+    pub async fn load_cursor(&self) -> CoreResult<ReembedCursor> {
+        let Some(bytes) = self
+            .store
+            .get_processing_cursor(&self.config.cursor_key)
+            .await?
+        else {
+            return Ok(ReembedCursor::default());
+        };
+        match serde_json::from_slice::<ReembedCursor>(&bytes) {
+            Ok(cursor) if cursor.completed => Ok(ReembedCursor::default()),
+            Ok(cursor) => Ok(cursor),
+            Err(e) => {
+                // Restarting is safe: already-current fingerprints are skipped
+                warn!(error = %e, "Failed to parse re-embedding cursor, starting fresh");
+                Ok(ReembedCursor::default())
+            }
+        }
+    }
+
+    async fn save_cursor(&self, cursor: &ReembedCursor) -> CoreResult<()> {
+        let json = serde_json::to_vec(cursor).map_err(|e| {
+            CoreError::SerializationError(format!("Failed to serialize re-embedding cursor: {}", e))
+        })?;
+        self.store
+            .store_processing_cursor(&self.config.cursor_key, &json)
+            .await
+    }
+
+    /// Version string recorded for `embedder`.
+    fn target_version(&self, embedder: Embedder) -> String {
+        match &self.config.model_version {
+            Some(version) => version.clone(),
+            None => self.provider.model_ids()[embedder.index()].to_string(),
+        }
+    }
+
+    /// Check if every selected embedder in `record` is at the target version.
+    pub fn is_current(&self, record: Option<&EmbeddingVersionRecord>) -> bool {
+        let Some(record) = record else {
+            return false;
+        };
+        self.config.embedders.iter().all(|embedder| {
+            record.embedder_versions.get(embedder.short_name())
+                == Some(&self.target_version(embedder))
+        })
+    }
+
+    async fn migrate_one(
+        &self,
+        mut fingerprint: TeleologicalFingerprint,
+        content: Option<String>,
+    ) -> CoreResult<Outcome> {
+        let id = fingerprint.id;
+        let existing = self.store.get_embedding_version(id).await?;
+        if self.is_current(existing.as_ref()) {
+            return Ok(Outcome::AlreadyCurrent);
+        }
+        let Some(content) = content else {
+            warn!(fingerprint_id = %id, "No stored content - cannot re-embed fingerprint");
+            return Ok(Outcome::MissingContent);
+        };
+
+        let mut output = self
+            .provider
+            .embed_selective(&content, self.config.embedders)
+            .await?;
+
+        fingerprint.apply_embeddings(self.config.embedders, &mut output.fingerprint);
+
+        if !self.store.update(fingerprint).await? {
+            // Deleted between listing and update
+            warn!(fingerprint_id = %id, "Fingerprint disappeared during re-embedding");
+            return Ok(Outcome::MissingContent);
+        }
+
+        let mut record = existing.unwrap_or_else(|| EmbeddingVersionRecord {
+            fingerprint_id: id,
+            computed_at: Utc::now(),
+            embedder_versions: HashMap::new(),
+            e7_model_version: None,
+            computation_time_ms: None,
+            deterministic: false,
+        });
+        for embedder in self.config.embedders.iter() {
+            let version = self.target_version(embedder);
+            if embedder == Embedder::Code {
+                record.e7_model_version = Some(version.clone());
+            }
+            record
+                .embedder_versions
+                .insert(embedder.short_name().to_string(), version);
+        }
+        record.computed_at = Utc::now();
+        record.computation_time_ms = Some(output.total_latency.as_millis() as u64);
+        record.deterministic = self.provider.determinism().is_some();
+        self.store.store_embedding_version(&record).await?;
+
+        Ok(Outcome::Migrated)
+    }
+}
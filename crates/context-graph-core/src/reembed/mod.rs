@@ -0,0 +1,26 @@
+//! Re-embedding migration for model upgrades.
+//!
+//! When an embedder's model is swapped (e.g. a new E1 checkpoint), stored
+//! fingerprints keep vectors from the old model and are no longer comparable
+//! with new queries. [`ReembedJob`] walks every stored fingerprint, re-runs
+//! only the selected embedders on the stored content, writes the updated
+//! fingerprint back (re-indexing it), and records the new model version per
+//! embedder in the embedding version registry.
+//!
+//! Progress is checkpointed as a [`ReembedCursor`] after every batch, so an
+//! interrupted run resumes where it stopped.
+//!
+//! # Module Structure
+//!
+//! - `config`: Run configuration and the persisted resume cursor
+//! - `job`: The batch loop, per-fingerprint migration, and version tracking
+
+mod config;
+mod job;
+#[cfg(test)]
+mod tests;
+
+pub use config::{
+    ReembedConfig, ReembedCursor, DEFAULT_REEMBED_BATCH_SIZE, DEFAULT_REEMBED_CURSOR_KEY,
+};
+pub use job::ReembedJob;
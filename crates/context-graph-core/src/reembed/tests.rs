@@ -0,0 +1,162 @@
+//! Tests for re-embedding migration.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::stubs::{InMemoryTeleologicalStore, StubMultiArrayProvider};
+use crate::teleological::{Embedder, EmbedderMask};
+use crate::traits::{MultiArrayEmbeddingProvider, TeleologicalMemoryStore};
+use crate::types::fingerprint::TeleologicalFingerprint;
+
+use super::*;
+
+const NEW_VERSION: &str = "e1-semantic-v2";
+
+/// Store `n` fingerprints with content, embedded by a separate provider.
+async fn seeded_store(n: usize) -> (Arc<InMemoryTeleologicalStore>, Vec<Uuid>) {
+    let store = Arc::new(InMemoryTeleologicalStore::new());
+    let seeder = StubMultiArrayProvider::new();
+    let mut ids = Vec::with_capacity(n);
+    for i in 0..n {
+        let content = format!("memory number {}", i);
+        let output = seeder.embed_all(&content).await.unwrap();
+        let fp = TeleologicalFingerprint::new(output.fingerprint, [i as u8; 32]);
+        let id = store.store(fp).await.unwrap();
+        store.store_content(id, &content).await.unwrap();
+        ids.push(id);
+    }
+    (store, ids)
+}
+
+fn e1_job(
+    store: &Arc<InMemoryTeleologicalStore>,
+    provider: &Arc<StubMultiArrayProvider>,
+    batch_size: usize,
+) -> ReembedJob {
+    let config = ReembedConfig::new(EmbedderMask::from_slice(&[Embedder::Semantic]))
+        .with_batch_size(batch_size)
+        .with_model_version(NEW_VERSION);
+    ReembedJob::new(store.clone(), provider.clone(), config).unwrap()
+}
+
+#[tokio::test]
+async fn test_interrupted_run_resumes_and_migrates_each_fingerprint_once() {
+    let (store, ids) = seeded_store(50).await;
+    let provider = Arc::new(StubMultiArrayProvider::new());
+
+    // First run is "killed" after 3 batches (24 fingerprints)
+    {
+        let job = e1_job(&store, &provider, 8);
+        let mut cursor = job.load_cursor().await.unwrap();
+        for _ in 0..3 {
+            assert!(job.run_batch(&mut cursor).await.unwrap());
+        }
+        assert_eq!(cursor.migrated, 24);
+    }
+
+    // A new job picks up the stored cursor
+    let job = e1_job(&store, &provider, 8);
+    let resumed = job.load_cursor().await.unwrap();
+    assert_eq!(resumed.migrated, 24);
+    assert!(!resumed.completed);
+
+    let report = job.run(None).await.unwrap();
+    assert!(report.completed);
+    assert_eq!(report.migrated, 50);
+    assert_eq!(report.already_current, 0);
+    assert_eq!(
+        provider.embedder_call_count(Embedder::Semantic),
+        50,
+        "Each fingerprint re-embedded exactly once"
+    );
+    for embedder in Embedder::all().filter(|e| *e != Embedder::Semantic) {
+        assert_eq!(
+            provider.embedder_call_count(embedder),
+            0,
+            "{:?} must not run",
+            embedder
+        );
+    }
+
+    for id in &ids {
+        let record = store.get_embedding_version(*id).await.unwrap().unwrap();
+        assert_eq!(
+            record.embedder_versions.get("E1").map(String::as_str),
+            Some(NEW_VERSION)
+        );
+        assert!(job.is_current(Some(&record)));
+
+        let fp = store.retrieve(*id).await.unwrap().unwrap();
+        assert_eq!(fp.semantic.e1_semantic.len(), 1024);
+        assert_eq!(
+            fp.semantic.e7_code.len(),
+            1536,
+            "Unselected spaces preserved"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_replayed_batch_skips_current_fingerprints() {
+    let (store, _) = seeded_store(20).await;
+    let provider = Arc::new(StubMultiArrayProvider::new());
+    let job = e1_job(&store, &provider, 5);
+
+    // Crash after writing batch 2 but before its checkpoint: resume from batch 1
+    let mut cursor = ReembedCursor::default();
+    job.run_batch(&mut cursor).await.unwrap();
+    let after_first = cursor.clone();
+    job.run_batch(&mut cursor).await.unwrap();
+
+    let report = job.run(Some(after_first)).await.unwrap();
+    assert_eq!(
+        report.migrated, 15,
+        "Batches 1, 3, and 4 counted by this cursor"
+    );
+    assert_eq!(report.already_current, 5);
+    assert_eq!(provider.embedder_call_count(Embedder::Semantic), 20);
+}
+
+#[tokio::test]
+async fn test_preserves_identity_and_timestamps() {
+    let (store, ids) = seeded_store(3).await;
+    let before = store.retrieve(ids[0]).await.unwrap().unwrap();
+    let provider = Arc::new(StubMultiArrayProvider::new());
+
+    e1_job(&store, &provider, 2).run(None).await.unwrap();
+
+    let after = store.retrieve(ids[0]).await.unwrap().unwrap();
+    assert_eq!(after.id, before.id);
+    assert_eq!(after.created_at, before.created_at);
+    assert_eq!(after.content_hash, before.content_hash);
+}
+
+#[tokio::test]
+async fn test_missing_content_is_skipped() {
+    let (store, ids) = seeded_store(4).await;
+    store.delete_content(ids[1]).await.unwrap();
+    let provider = Arc::new(StubMultiArrayProvider::new());
+
+    let report = e1_job(&store, &provider, 10).run(None).await.unwrap();
+    assert_eq!(report.migrated, 3);
+    assert_eq!(report.missing_content, 1);
+    assert!(store.get_embedding_version(ids[1]).await.unwrap().is_none());
+}
+
+#[test]
+fn test_config_rejects_temporal_and_empty_selection() {
+    let temporal = ReembedConfig::new(EmbedderMask::from_slice(&[
+        Embedder::Semantic,
+        Embedder::TemporalRecent,
+    ]));
+    assert!(temporal.validate().is_err());
+
+    assert!(ReembedConfig::new(EmbedderMask::new()).validate().is_err());
+    assert!(
+        ReembedConfig::new(EmbedderMask::from_slice(&[Embedder::Causal]))
+            .with_batch_size(0)
+            .validate()
+            .is_err()
+    );
+}
@@ -0,0 +1,608 @@
+//! Cross-space divergence monitoring.
+//!
+//! `DivergenceDetector` compares the current query against recent memories.
+//! `DivergenceMonitor` instead compares embedding spaces against each other:
+//! for each stored memory it takes the k nearest neighbors in two spaces
+//! (e.g. E1 semantic vs E7 code) and raises an alert when the neighborhood
+//! Jaccard falls below a threshold, meaning the spaces disagree about what
+//! the memory is.
+//!
+//! Alerts carry the memory, the two spaces, the divergence score and example
+//! neighbors found by only one of the spaces. They are retained in the
+//! monitor until evicted and persisted as JSON under
+//! [`DIVERGENCE_ALERTS_KEY`] via `store_processing_cursor`, so callers can
+//! page through unacknowledged alerts and acknowledge them across restarts.
+//!
+//! # Architecture Rules
+//!
+//! - ARCH-10 / AP-62: Only SEMANTIC embedders may be compared
+//! - AP-63: Temporal embedders are never compared
+//! - AP-77: E5 (Causal) is excluded, it has no symmetric similarity
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::error::{CoreError, CoreResult};
+use crate::teleological::Embedder;
+use crate::traits::TeleologicalMemoryStore;
+use crate::types::fingerprint::SemanticFingerprint;
+
+use super::detector::is_divergence_space;
+use super::distance::compute_similarity_for_space;
+
+/// Processing-cursor key under which the alert log is persisted.
+pub const DIVERGENCE_ALERTS_KEY: &str = "cross_space_divergence_alerts";
+
+/// Default number of nearest neighbors compared per space.
+pub const DEFAULT_NEIGHBORHOOD_K: usize = 5;
+
+/// Default neighborhood Jaccard below which an alert is raised.
+pub const DEFAULT_MIN_NEIGHBORHOOD_JACCARD: f32 = 0.2;
+
+/// Default number of conflicting neighbors reported per space.
+pub const DEFAULT_MAX_EXAMPLES: usize = 3;
+
+/// Default maximum number of alerts retained.
+pub const DEFAULT_MAX_RETAINED_ALERTS: usize = 1000;
+
+/// Default space pairs compared by the monitor.
+pub const DEFAULT_SPACE_PAIRS: [(Embedder, Embedder); 3] = [
+    (Embedder::Semantic, Embedder::Code),          // E1 vs E7
+    (Embedder::Semantic, Embedder::Contextual),    // E1 vs E10
+    (Embedder::Semantic, Embedder::KeywordSplade), // E1 vs E13
+];
+
+/// Configuration for [`DivergenceMonitor`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DivergenceMonitorConfig {
+    /// Space pairs whose neighborhoods are compared.
+    pub space_pairs: Vec<(Embedder, Embedder)>,
+    /// Number of nearest neighbors per space.
+    pub neighborhood_k: usize,
+    /// Alert when neighborhood Jaccard is below this value (0.0, 1.0].
+    pub min_jaccard: f32,
+    /// Check only this many memories per scan (evenly strided). `None` checks all.
+    pub sample_size: Option<usize>,
+    /// Conflicting neighbors reported per space.
+    pub max_examples: usize,
+    /// Maximum alerts retained; acknowledged alerts are evicted first.
+    pub max_alerts: usize,
+}
+
+impl Default for DivergenceMonitorConfig {
+    fn default() -> Self {
+        Self {
+            space_pairs: DEFAULT_SPACE_PAIRS.to_vec(),
+            neighborhood_k: DEFAULT_NEIGHBORHOOD_K,
+            min_jaccard: DEFAULT_MIN_NEIGHBORHOOD_JACCARD,
+            sample_size: None,
+            max_examples: DEFAULT_MAX_EXAMPLES,
+            max_alerts: DEFAULT_MAX_RETAINED_ALERTS,
+        }
+    }
+}
+
+impl DivergenceMonitorConfig {
+    /// Set the compared space pairs.
+    #[must_use]
+    pub fn with_space_pairs(mut self, pairs: Vec<(Embedder, Embedder)>) -> Self {
+        self.space_pairs = pairs;
+        self
+    }
+
+    /// Set the neighborhood size.
+    #[must_use]
+    pub fn with_neighborhood_k(mut self, k: usize) -> Self {
+        self.neighborhood_k = k;
+        self
+    }
+
+    /// Set the alert threshold on neighborhood Jaccard.
+    #[must_use]
+    pub fn with_min_jaccard(mut self, min_jaccard: f32) -> Self {
+        self.min_jaccard = min_jaccard;
+        self
+    }
+
+    /// Check only `sample_size` memories per scan.
+    #[must_use]
+    pub fn with_sample_size(mut self, sample_size: usize) -> Self {
+        self.sample_size = Some(sample_size);
+        self
+    }
+
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    /// `CoreError::ConfigError` if a value is out of range or a pair uses a
+    /// space that is not allowed for divergence detection.
+    pub fn validate(&self) -> CoreResult<()> {
+        if self.space_pairs.is_empty() {
+            return Err(CoreError::ConfigError(
+                "divergence monitor needs at least one space pair".to_string(),
+            ));
+        }
+        for &(a, b) in &self.space_pairs {
+            if a == b {
+                return Err(CoreError::ConfigError(format!(
+                    "divergence monitor pair compares {} with itself",
+                    a.name()
+                )));
+            }
+            for space in [a, b] {
+                // E5 is semantic but returns a "no signal" sentinel without direction
+                if !is_divergence_space(space) || space == Embedder::Causal {
+                    return Err(CoreError::ConfigError(format!(
+                        "{} cannot be used for divergence detection (ARCH-10, AP-77)",
+                        space.name()
+                    )));
+                }
+            }
+        }
+        if self.neighborhood_k == 0 {
+            return Err(CoreError::ConfigError(
+                "neighborhood_k must be at least 1".to_string(),
+            ));
+        }
+        if !(self.min_jaccard > 0.0 && self.min_jaccard <= 1.0) {
+            return Err(CoreError::ConfigError(format!(
+                "min_jaccard must be in (0.0, 1.0], got {}",
+                self.min_jaccard
+            )));
+        }
+        if self.sample_size == Some(0) {
+            return Err(CoreError::ConfigError(
+                "sample_size must be at least 1".to_string(),
+            ));
+        }
+        if self.max_alerts == 0 {
+            return Err(CoreError::ConfigError(
+                "max_alerts must be at least 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Alert raised when two spaces disagree about a memory's neighborhood.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrossSpaceAlert {
+    /// Unique alert ID (used to acknowledge).
+    pub alert_id: Uuid,
+    /// Memory whose neighborhoods diverge.
+    pub memory_id: Uuid,
+    /// First space of the pair.
+    pub space_a: Embedder,
+    /// Second space of the pair.
+    pub space_b: Embedder,
+    /// Jaccard overlap of the two k-NN neighborhoods [0.0, 1.0].
+    pub neighborhood_jaccard: f32,
+    /// Divergence score: 1 - neighborhood_jaccard.
+    pub divergence_score: f32,
+    /// Example neighbors found in `space_a` but not in `space_b`, nearest first.
+    pub neighbors_only_in_a: Vec<Uuid>,
+    /// Example neighbors found in `space_b` but not in `space_a`, nearest first.
+    pub neighbors_only_in_b: Vec<Uuid>,
+    /// When the alert was raised.
+    pub detected_at: DateTime<Utc>,
+    /// When the alert was acknowledged, if it has been.
+    pub acknowledged_at: Option<DateTime<Utc>>,
+}
+
+impl CrossSpaceAlert {
+    /// Whether the alert has been acknowledged.
+    #[inline]
+    pub fn is_acknowledged(&self) -> bool {
+        self.acknowledged_at.is_some()
+    }
+
+    fn is_same_divergence(&self, memory_id: Uuid, a: Embedder, b: Embedder) -> bool {
+        self.memory_id == memory_id && self.space_a == a && self.space_b == b
+    }
+}
+
+/// Page of unacknowledged alerts, newest first.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CrossSpaceAlertPage {
+    /// Alerts in this page.
+    pub alerts: Vec<CrossSpaceAlert>,
+    /// Total unacknowledged alerts.
+    pub total_unacknowledged: usize,
+    /// Offset of this page.
+    pub offset: usize,
+}
+
+/// Monitors memories for disagreement between embedding spaces.
+///
+/// A memory is only alerted once per space pair while its alert is
+/// retained; acknowledging an alert does not cause it to be raised again.
+#[derive(Debug, Clone)]
+pub struct DivergenceMonitor {
+    config: DivergenceMonitorConfig,
+    /// Retained alerts, oldest first.
+    alerts: Vec<CrossSpaceAlert>,
+}
+
+impl DivergenceMonitor {
+    /// Create a monitor with no alerts.
+    ///
+    /// # Errors
+    /// `CoreError::ConfigError` if the configuration is invalid.
+    pub fn new(config: DivergenceMonitorConfig) -> CoreResult<Self> {
+        config.validate()?;
+        Ok(Self {
+            config,
+            alerts: Vec::new(),
+        })
+    }
+
+    /// Create a monitor and restore alerts persisted under [`DIVERGENCE_ALERTS_KEY`].
+    ///
+    /// An unparsable alert log is logged and discarded.
+    ///
+    /// # Errors
+    /// `CoreError::ConfigError` for an invalid configuration, or the store's
+    /// error if the read fails.
+    pub async fn load(
+        store: &dyn TeleologicalMemoryStore,
+        config: DivergenceMonitorConfig,
+    ) -> CoreResult<Self> {
+        let mut monitor = Self::new(config)?;
+        if let Some(bytes) = store.get_processing_cursor(DIVERGENCE_ALERTS_KEY).await? {
+            match serde_json::from_slice::<Vec<CrossSpaceAlert>>(&bytes) {
+                Ok(alerts) => {
+                    monitor.alerts = alerts;
+                    monitor.enforce_retention();
+                }
+                Err(e) => warn!(error = %e, "Failed to parse divergence alert log, starting empty"),
+            }
+        }
+        Ok(monitor)
+    }
+
+    /// Persist the alert log under [`DIVERGENCE_ALERTS_KEY`].
+    ///
+    /// # Errors
+    /// `CoreError::SerializationError` or the store's write error.
+    pub async fn persist(&self, store: &dyn TeleologicalMemoryStore) -> CoreResult<()> {
+        let json = serde_json::to_vec(&self.alerts).map_err(|e| {
+            CoreError::SerializationError(format!("Failed to serialize divergence alerts: {}", e))
+        })?;
+        store
+            .store_processing_cursor(DIVERGENCE_ALERTS_KEY, &json)
+            .await
+    }
+
+    /// Get the configuration.
+    #[inline]
+    pub fn config(&self) -> &DivergenceMonitorConfig {
+        &self.config
+    }
+
+    /// Scan memories for cross-space divergence.
+    ///
+    /// Neighborhoods are computed over all given memories; with a
+    /// `sample_size`, only an evenly strided subset is checked. Needs more
+    /// than `neighborhood_k` memories, otherwise nothing is checked.
+    ///
+    /// # Returns
+    /// Alerts newly raised by this scan.
+    pub fn scan<'a>(
+        &mut self,
+        memories: impl IntoIterator<Item = (Uuid, &'a SemanticFingerprint)>,
+    ) -> Vec<CrossSpaceAlert> {
+        let memories: Vec<(Uuid, &SemanticFingerprint)> = memories.into_iter().collect();
+        let n = memories.len();
+        let k = self.config.neighborhood_k;
+        if n <= k {
+            debug!(
+                memory_count = n,
+                k, "Divergence monitor: too few memories to scan"
+            );
+            return Vec::new();
+        }
+
+        let subjects: Vec<usize> = match self.config.sample_size {
+            Some(sample) if sample < n => (0..sample).map(|i| i * n / sample).collect(),
+            _ => (0..n).collect(),
+        };
+
+        // k-NN per (space, subject), computed once per space
+        let mut neighborhoods: HashMap<Embedder, Vec<Vec<usize>>> = HashMap::new();
+        for &(a, b) in &self.config.space_pairs {
+            for space in [a, b] {
+                neighborhoods.entry(space).or_insert_with(|| {
+                    subjects
+                        .iter()
+                        .map(|&i| nearest_neighbors(space, &memories, i, k))
+                        .collect()
+                });
+            }
+        }
+
+        let now = Utc::now();
+        let mut raised = Vec::new();
+        for (s, &i) in subjects.iter().enumerate() {
+            let memory_id = memories[i].0;
+            for &(a, b) in &self.config.space_pairs {
+                let near_a = &neighborhoods[&a][s];
+                let near_b = &neighborhoods[&b][s];
+                let jaccard = neighborhood_jaccard(near_a, near_b);
+                if jaccard >= self.config.min_jaccard {
+                    continue;
+                }
+                let already_alerted = self
+                    .alerts
+                    .iter()
+                    .chain(raised.iter())
+                    .any(|alert| alert.is_same_divergence(memory_id, a, b));
+                if already_alerted {
+                    continue;
+                }
+
+                let only_in = |from: &[usize], other: &[usize]| -> Vec<Uuid> {
+                    from.iter()
+                        .filter(|j| !other.contains(*j))
+                        .take(self.config.max_examples)
+                        .map(|&j| memories[j].0)
+                        .collect()
+                };
+                raised.push(CrossSpaceAlert {
+                    alert_id: Uuid::new_v4(),
+                    memory_id,
+                    space_a: a,
+                    space_b: b,
+                    neighborhood_jaccard: jaccard,
+                    divergence_score: 1.0 - jaccard,
+                    neighbors_only_in_a: only_in(near_a, near_b),
+                    neighbors_only_in_b: only_in(near_b, near_a),
+                    detected_at: now,
+                    acknowledged_at: None,
+                });
+            }
+        }
+
+        debug!(
+            memory_count = n,
+            checked = subjects.len(),
+            raised = raised.len(),
+            "Divergence monitor: scan complete"
+        );
+
+        self.alerts.extend(raised.iter().cloned());
+        self.enforce_retention();
+        raised
+    }
+
+    /// Page through unacknowledged alerts, newest first.
+    pub fn unacknowledged(&self, offset: usize, limit: usize) -> CrossSpaceAlertPage {
+        let pending: Vec<&CrossSpaceAlert> = self
+            .alerts
+            .iter()
+            .rev()
+            .filter(|alert| !alert.is_acknowledged())
+            .collect();
+
+        CrossSpaceAlertPage {
+            alerts: pending
+                .iter()
+                .skip(offset)
+                .take(limit)
+                .map(|&a| a.clone())
+                .collect(),
+            total_unacknowledged: pending.len(),
+            offset,
+        }
+    }
+
+    /// Acknowledge an alert.
+    ///
+    /// Acknowledging twice keeps the first acknowledgement time.
+    ///
+    /// # Returns
+    /// The acknowledged alert, or `None` if no retained alert has this ID.
+    pub fn acknowledge(&mut self, alert_id: Uuid) -> Option<&CrossSpaceAlert> {
+        let alert = self.alerts.iter_mut().find(|a| a.alert_id == alert_id)?;
+        alert.acknowledged_at.get_or_insert_with(Utc::now);
+        Some(&*alert)
+    }
+
+    /// All retained alerts, oldest first.
+    pub fn alerts(&self) -> &[CrossSpaceAlert] {
+        &self.alerts
+    }
+
+    /// Evict alerts beyond `max_alerts`, oldest acknowledged alerts first.
+    fn enforce_retention(&mut self) {
+        let mut excess = self.alerts.len().saturating_sub(self.config.max_alerts);
+        if excess == 0 {
+            return;
+        }
+        self.alerts.retain(|alert| {
+            if excess > 0 && alert.is_acknowledged() {
+                excess -= 1;
+                false
+            } else {
+                true
+            }
+        });
+        if excess > 0 {
+            self.alerts.drain(..excess);
+        }
+    }
+}
+
+/// Indices of the `k` memories most similar to `memories[subject]` in `space`,
+/// nearest first. Ties are broken by index for deterministic output.
+fn nearest_neighbors(
+    space: Embedder,
+    memories: &[(Uuid, &SemanticFingerprint)],
+    subject: usize,
+    k: usize,
+) -> Vec<usize> {
+    let query = memories[subject].1;
+    let mut scored: Vec<(usize, f32)> = memories
+        .iter()
+        .enumerate()
+        .filter(|&(j, _)| j != subject)
+        .map(|(j, (_, fp))| (j, compute_similarity_for_space(space, query, fp)))
+        .filter(|(_, sim)| sim.is_finite())
+        .collect();
+    scored.sort_by(|x, y| y.1.total_cmp(&x.1).then(x.0.cmp(&y.0)));
+    scored.into_iter().take(k).map(|(j, _)| j).collect()
+}
+
+/// Jaccard overlap of two neighborhoods. Two empty neighborhoods agree.
+fn neighborhood_jaccard(a: &[usize], b: &[usize]) -> f32 {
+    let a: HashSet<usize> = a.iter().copied().collect();
+    let b: HashSet<usize> = b.iter().copied().collect();
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(&b).count() as f32 / union as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stubs::InMemoryTeleologicalStore;
+
+    /// Unit vector along `axis` plus a small orthogonal `jitter` component.
+    fn direction(dim: usize, axis: usize, jitter: usize) -> Vec<f32> {
+        let mut v = vec![0.0; dim];
+        v[axis] = 1.0;
+        v[100 + jitter] = 0.05;
+        v
+    }
+
+    /// Fingerprint whose E1/E10 point at `semantic_axis` and E7 at `code_axis`.
+    fn fingerprint(semantic_axis: usize, code_axis: usize, jitter: usize) -> SemanticFingerprint {
+        let mut fp = SemanticFingerprint::zeroed();
+        let e1_dim = fp.e1_semantic.len();
+        let e7_dim = fp.e7_code.len();
+        let e10_dim = fp.e10_multimodal_paraphrase.len();
+        fp.e1_semantic = direction(e1_dim, semantic_axis, jitter);
+        fp.e7_code = direction(e7_dim, code_axis, jitter);
+        fp.e10_multimodal_paraphrase = direction(e10_dim, semantic_axis, jitter);
+        fp.e10_multimodal_as_context = direction(e10_dim, semantic_axis, jitter);
+        fp
+    }
+
+    /// Two consistent clusters of 6 plus one memory whose E1 points at
+    /// cluster X while its E7 points at cluster Y.
+    fn crafted_memories() -> (Vec<(Uuid, SemanticFingerprint)>, Uuid) {
+        let mut memories = Vec::new();
+        for i in 0..6 {
+            memories.push((Uuid::new_v4(), fingerprint(0, 0, i)));
+            memories.push((Uuid::new_v4(), fingerprint(1, 1, 6 + i)));
+        }
+        let rogue = Uuid::new_v4();
+        memories.push((rogue, fingerprint(0, 1, 12)));
+        (memories, rogue)
+    }
+
+    fn test_config() -> DivergenceMonitorConfig {
+        DivergenceMonitorConfig::default().with_space_pairs(vec![
+            (Embedder::Semantic, Embedder::Code),
+            (Embedder::Semantic, Embedder::Contextual),
+        ])
+    }
+
+    #[test]
+    fn test_scan_flags_memory_whose_spaces_disagree() {
+        println!("=== TEST: test_scan_flags_memory_whose_spaces_disagree ===");
+        let (memories, rogue) = crafted_memories();
+        let mut monitor = DivergenceMonitor::new(test_config()).unwrap();
+
+        let raised = monitor.scan(memories.iter().map(|(id, fp)| (*id, fp)));
+        println!("raised: {:?}", raised);
+
+        assert_eq!(raised.len(), 1, "exactly one alert expected");
+        let alert = &raised[0];
+        assert_eq!(alert.memory_id, rogue);
+        assert_eq!(
+            (alert.space_a, alert.space_b),
+            (Embedder::Semantic, Embedder::Code)
+        );
+        assert_eq!(alert.neighborhood_jaccard, 0.0);
+        assert_eq!(alert.divergence_score, 1.0);
+        assert_eq!(alert.neighbors_only_in_a.len(), DEFAULT_MAX_EXAMPLES);
+        assert_eq!(alert.neighbors_only_in_b.len(), DEFAULT_MAX_EXAMPLES);
+        assert!(!alert.is_acknowledged());
+
+        // Rescanning does not duplicate the alert
+        assert!(monitor
+            .scan(memories.iter().map(|(id, fp)| (*id, fp)))
+            .is_empty());
+        assert_eq!(monitor.alerts().len(), 1);
+
+        println!("[PASS] Divergent E1/E7 memory raises exactly one alert\n");
+    }
+
+    #[tokio::test]
+    async fn test_alerts_page_acknowledge_and_persist() {
+        println!("=== TEST: test_alerts_page_acknowledge_and_persist ===");
+        let (memories, rogue) = crafted_memories();
+        let store = InMemoryTeleologicalStore::new();
+        let mut monitor = DivergenceMonitor::new(test_config()).unwrap();
+        monitor.scan(memories.iter().map(|(id, fp)| (*id, fp)));
+        monitor.persist(&store).await.unwrap();
+
+        let mut reloaded = DivergenceMonitor::load(&store, test_config())
+            .await
+            .unwrap();
+        let page = reloaded.unacknowledged(0, 10);
+        assert_eq!(page.total_unacknowledged, 1);
+        assert_eq!(page.alerts[0].memory_id, rogue);
+        assert!(reloaded.unacknowledged(1, 10).alerts.is_empty());
+
+        let alert_id = page.alerts[0].alert_id;
+        let acked_at = reloaded.acknowledge(alert_id).unwrap().acknowledged_at;
+        assert!(acked_at.is_some());
+        assert_eq!(
+            reloaded.acknowledge(alert_id).unwrap().acknowledged_at,
+            acked_at
+        );
+        assert!(reloaded.acknowledge(Uuid::new_v4()).is_none());
+        assert_eq!(reloaded.unacknowledged(0, 10).total_unacknowledged, 0);
+
+        // Acknowledged alerts are not raised again
+        assert!(reloaded
+            .scan(memories.iter().map(|(id, fp)| (*id, fp)))
+            .is_empty());
+
+        println!("[PASS] Alerts page, acknowledge and survive persistence\n");
+    }
+
+    #[test]
+    fn test_config_rejects_disallowed_spaces() {
+        let temporal = DivergenceMonitorConfig::default()
+            .with_space_pairs(vec![(Embedder::Semantic, Embedder::TemporalRecent)]);
+        assert!(temporal.validate().is_err());
+
+        let causal = DivergenceMonitorConfig::default()
+            .with_space_pairs(vec![(Embedder::Semantic, Embedder::Causal)]);
+        assert!(causal.validate().is_err());
+
+        let same = DivergenceMonitorConfig::default()
+            .with_space_pairs(vec![(Embedder::Code, Embedder::Code)]);
+        assert!(same.validate().is_err());
+
+        assert!(DivergenceMonitorConfig::default()
+            .with_min_jaccard(0.0)
+            .validate()
+            .is_err());
+        assert!(DivergenceMonitorConfig::default()
+            .with_neighborhood_k(0)
+            .validate()
+            .is_err());
+        assert!(DivergenceMonitorConfig::default().validate().is_ok());
+
+        println!("[PASS] Invalid divergence monitor configs rejected");
+    }
+}
@@ -83,12 +83,15 @@ pub mod config;
 pub mod detector;
 pub mod distance;
 pub mod divergence;
+pub mod divergence_monitor;
 mod executor;
 pub mod insight_annotation;
 pub mod multi_space;
+pub mod pipeline_metrics;
 mod query;
 mod result;
 pub mod retriever;
+pub mod search_profile;
 pub mod similarity;
 pub mod sparse_index;
 mod teleological_query;
@@ -108,12 +111,20 @@ pub use executor::{IndexType, MultiEmbeddingQueryExecutor, SpaceInfo};
 // AP-007: InMemoryMultiEmbeddingExecutor is TEST ONLY - uses stubs
 #[cfg(test)]
 pub use in_memory_executor::InMemoryMultiEmbeddingExecutor;
+pub use pipeline_metrics::{
+    LatencySummary, PipelineMetrics, PipelineMetricsSnapshot, PipelineStage, PipelineTimings,
+    StageSpan, StageTiming,
+};
 pub use query::{EmbeddingSpaceMask, MultiEmbeddingQuery, PipelineStageConfig};
 pub use result::{
     AggregatedMatch, MultiEmbeddingResult, PipelineStageTiming, ScoredMatch, SpaceContribution,
     SpaceSearchResult,
     SearchResultProvenance, QueryClassification, EmbedderContribution,
 };
+pub use search_profile::{
+    classify_search_domain, resolve_search_profile, DomainClassification, DomainSource,
+    ResolvedSearchProfile, SearchDomain, SearchProfile, MIN_DOMAIN_CUES,
+};
 pub use teleological_query::TeleologicalQuery;
 pub use teleological_result::{
     PipelineBreakdown, ScoredMemory, TeleologicalRetrievalResult,
@@ -151,6 +162,12 @@ pub use detector::{
     DivergenceDetector, RecentMemory, is_divergence_space,
 };
 
+// Cross-space divergence monitoring
+pub use divergence_monitor::{
+    CrossSpaceAlert, CrossSpaceAlertPage, DivergenceMonitor, DivergenceMonitorConfig,
+    DIVERGENCE_ALERTS_KEY,
+};
+
 // High-level retrieval orchestrator
 pub use retriever::{
     memory_to_recent, RetrieverError, SimilarityRetriever,
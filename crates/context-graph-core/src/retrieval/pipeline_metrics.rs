@@ -0,0 +1,377 @@
+//! Per-stage timing and latency histograms for the retrieval pipeline.
+//!
+//! Each search stage runs inside a [`StageSpan`]: a `tracing` span named
+//! after the stage that records candidate counts in/out and elapsed time.
+//! The finished stages form a [`PipelineTimings`] returned alongside the
+//! results, and stores fold every `PipelineTimings` into their
+//! [`PipelineMetrics`], whose per-stage histograms give p50/p95/p99.
+//!
+//! Spans are created at `DEBUG` under [`PIPELINE_TRACE_TARGET`], so without
+//! a subscriber enabling that target a stage costs two `Instant::now()`
+//! calls and a handful of relaxed atomic adds.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tracing::Span;
+
+/// `tracing` target of the per-stage spans.
+pub const PIPELINE_TRACE_TARGET: &str = "context_graph::pipeline";
+
+/// Retrieval pipeline stages, in the order they are reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PipelineStage {
+    /// E13 SPLADE sparse recall.
+    SpladeRecall,
+    /// E1 Matryoshka 128D prefilter.
+    MatryoshkaFilter,
+    /// Dense HNSW recall/scoring across the embedder indexes.
+    HnswSearch,
+    /// E12 ColBERT MaxSim reranking.
+    MaxsimRerank,
+    /// Multi-space score fusion, sort and truncation.
+    Fusion,
+}
+
+impl PipelineStage {
+    /// All stages in report order.
+    pub const ALL: [PipelineStage; 5] = [
+        PipelineStage::SpladeRecall,
+        PipelineStage::MatryoshkaFilter,
+        PipelineStage::HnswSearch,
+        PipelineStage::MaxsimRerank,
+        PipelineStage::Fusion,
+    ];
+
+    /// Span and metric name of the stage.
+    pub fn name(self) -> &'static str {
+        match self {
+            PipelineStage::SpladeRecall => "splade_recall",
+            PipelineStage::MatryoshkaFilter => "matryoshka_filter",
+            PipelineStage::HnswSearch => "hnsw_search",
+            PipelineStage::MaxsimRerank => "maxsim_rerank",
+            PipelineStage::Fusion => "fusion",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Outcome of one stage of one search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageTiming {
+    /// Candidates entering the stage.
+    pub candidates_in: usize,
+    /// Candidates leaving the stage.
+    pub candidates_out: usize,
+    /// Wall time spent in the stage.
+    pub elapsed: Duration,
+}
+
+/// Stage timings of a single search. Stages the backend did not run are `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PipelineTimings {
+    stages: [Option<StageTiming>; 5],
+}
+
+impl PipelineTimings {
+    /// Empty timings (no stage run yet).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record (or overwrite) the timing of `stage`.
+    pub fn record(&mut self, stage: PipelineStage, timing: StageTiming) {
+        self.stages[stage.index()] = Some(timing);
+    }
+
+    /// Timing of `stage`, if it ran.
+    pub fn get(&self, stage: PipelineStage) -> Option<&StageTiming> {
+        self.stages[stage.index()].as_ref()
+    }
+
+    /// Stages that ran, in report order.
+    pub fn iter(&self) -> impl Iterator<Item = (PipelineStage, &StageTiming)> {
+        PipelineStage::ALL
+            .into_iter()
+            .filter_map(|stage| self.get(stage).map(|t| (stage, t)))
+    }
+
+    /// True if every stage ran.
+    pub fn is_complete(&self) -> bool {
+        self.stages.iter().all(Option::is_some)
+    }
+
+    /// Sum of the recorded stage times.
+    pub fn total(&self) -> Duration {
+        self.iter().map(|(_, t)| t.elapsed).sum()
+    }
+}
+
+/// A stage in progress: its `tracing` span plus a start instant.
+///
+/// Not entered, so it can be held across `.await`; finish it with
+/// [`StageSpan::finish`].
+pub struct StageSpan {
+    stage: PipelineStage,
+    candidates_in: usize,
+    start: Instant,
+    span: Span,
+}
+
+impl StageSpan {
+    /// Start timing `stage` with `candidates_in` candidates.
+    pub fn start(stage: PipelineStage, candidates_in: usize) -> Self {
+        let span = tracing::debug_span!(
+            target: PIPELINE_TRACE_TARGET,
+            "pipeline_stage",
+            stage = stage.name(),
+            candidates_in,
+            candidates_out = tracing::field::Empty,
+            elapsed_us = tracing::field::Empty,
+        );
+        Self {
+            stage,
+            candidates_in,
+            start: Instant::now(),
+            span,
+        }
+    }
+
+    /// Stop the clock, record the outcome on the span and into `timings`.
+    pub fn finish(self, candidates_out: usize, timings: &mut PipelineTimings) -> StageTiming {
+        let timing = StageTiming {
+            candidates_in: self.candidates_in,
+            candidates_out,
+            elapsed: self.start.elapsed(),
+        };
+        if !self.span.is_disabled() {
+            self.span.record("candidates_out", candidates_out);
+            self.span
+                .record("elapsed_us", timing.elapsed.as_micros() as u64);
+        }
+        timings.record(self.stage, timing);
+        timing
+    }
+}
+
+/// Number of histogram buckets; bucket `i` holds durations below
+/// `2^((i + 1) / 2)` microseconds, the last one everything above.
+const HISTOGRAM_BUCKETS: usize = 64;
+
+/// Lock-free latency histogram with √2-spaced microsecond buckets.
+#[derive(Debug)]
+pub struct LatencyHistogram {
+    buckets: [AtomicU64; HISTOGRAM_BUCKETS],
+    count: AtomicU64,
+    sum_us: AtomicU64,
+    max_us: AtomicU64,
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            count: AtomicU64::new(0),
+            sum_us: AtomicU64::new(0),
+            max_us: AtomicU64::new(0),
+        }
+    }
+}
+
+impl LatencyHistogram {
+    fn bucket_of(us: u64) -> usize {
+        if us == 0 {
+            return 0;
+        }
+        // floor(2 * log2(us)), refined by comparing us² against 2^k
+        let log2 = 63 - us.leading_zeros() as usize;
+        let half_step = (us as u128) * (us as u128) >= 1u128 << (2 * log2 + 1);
+        (2 * log2 + usize::from(half_step)).min(HISTOGRAM_BUCKETS - 1)
+    }
+
+    fn bucket_upper_bound(index: usize) -> Duration {
+        let bound = 2f64.powf((index + 1) as f64 / 2.0);
+        Duration::from_micros(bound.ceil() as u64)
+    }
+
+    /// Add one sample.
+    pub fn record(&self, elapsed: Duration) {
+        let us = elapsed.as_micros().min(u64::MAX as u128) as u64;
+        self.buckets[Self::bucket_of(us)].fetch_add(1, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_us.fetch_add(us, Ordering::Relaxed);
+        self.max_us.fetch_max(us, Ordering::Relaxed);
+    }
+
+    /// Number of samples recorded.
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Upper bound of the bucket holding quantile `q` (0.0..=1.0), capped at
+    /// the observed maximum. `Duration::ZERO` when empty.
+    pub fn quantile(&self, q: f64) -> Duration {
+        let counts: Vec<u64> = self
+            .buckets
+            .iter()
+            .map(|b| b.load(Ordering::Relaxed))
+            .collect();
+        let total: u64 = counts.iter().sum();
+        if total == 0 {
+            return Duration::ZERO;
+        }
+        let rank = ((q.clamp(0.0, 1.0) * total as f64).ceil() as u64).max(1);
+        let max = Duration::from_micros(self.max_us.load(Ordering::Relaxed));
+        let mut seen = 0;
+        for (index, count) in counts.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return Self::bucket_upper_bound(index).min(max);
+            }
+        }
+        max
+    }
+
+    /// Summary statistics of the recorded samples.
+    pub fn summary(&self) -> LatencySummary {
+        let count = self.count();
+        let sum_us = self.sum_us.load(Ordering::Relaxed);
+        LatencySummary {
+            count,
+            mean_us: if count == 0 { 0 } else { sum_us / count },
+            p50_us: self.quantile(0.50).as_micros() as u64,
+            p95_us: self.quantile(0.95).as_micros() as u64,
+            p99_us: self.quantile(0.99).as_micros() as u64,
+            max_us: self.max_us.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Latency statistics of one stage, in microseconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatencySummary {
+    pub count: u64,
+    pub mean_us: u64,
+    pub p50_us: u64,
+    pub p95_us: u64,
+    pub p99_us: u64,
+    pub max_us: u64,
+}
+
+/// Point-in-time view of [`PipelineMetrics`], keyed by stage name.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PipelineMetricsSnapshot {
+    /// Searches folded into the metrics.
+    pub searches: u64,
+    /// Per-stage latency, for stages with at least one sample.
+    pub stages: std::collections::BTreeMap<String, LatencySummary>,
+}
+
+impl PipelineMetricsSnapshot {
+    /// Latency summary of `stage`, if it has samples.
+    pub fn stage(&self, stage: PipelineStage) -> Option<&LatencySummary> {
+        self.stages.get(stage.name())
+    }
+}
+
+/// In-process aggregation of [`PipelineTimings`] into per-stage histograms.
+#[derive(Debug, Default)]
+pub struct PipelineMetrics {
+    searches: AtomicU64,
+    stages: [LatencyHistogram; 5],
+}
+
+impl PipelineMetrics {
+    /// Empty metrics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold one search's timings into the histograms.
+    pub fn record(&self, timings: &PipelineTimings) {
+        self.searches.fetch_add(1, Ordering::Relaxed);
+        for (stage, timing) in timings.iter() {
+            self.stages[stage.index()].record(timing.elapsed);
+        }
+    }
+
+    /// Histogram of `stage`.
+    pub fn histogram(&self, stage: PipelineStage) -> &LatencyHistogram {
+        &self.stages[stage.index()]
+    }
+
+    /// Current per-stage summaries.
+    pub fn snapshot(&self) -> PipelineMetricsSnapshot {
+        PipelineMetricsSnapshot {
+            searches: self.searches.load(Ordering::Relaxed),
+            stages: PipelineStage::ALL
+                .into_iter()
+                .filter(|&stage| self.histogram(stage).count() > 0)
+                .map(|stage| (stage.name().to_string(), self.histogram(stage).summary()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_bounds_contain_samples() {
+        for us in [0u64, 1, 2, 3, 5, 8, 100, 1_000, 65_537, 10_000_000] {
+            let bucket = LatencyHistogram::bucket_of(us);
+            assert!(
+                LatencyHistogram::bucket_upper_bound(bucket) >= Duration::from_micros(us),
+                "{}us landed in bucket {}",
+                us,
+                bucket
+            );
+            if bucket > 0 {
+                assert!(
+                    LatencyHistogram::bucket_upper_bound(bucket - 1) <= Duration::from_micros(us)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_quantiles_are_ordered_and_bounded() {
+        let histogram = LatencyHistogram::default();
+        for us in 1..=1000 {
+            histogram.record(Duration::from_micros(us));
+        }
+        let summary = histogram.summary();
+        assert_eq!(summary.count, 1000);
+        assert_eq!(summary.max_us, 1000);
+        assert!(summary.p50_us >= 500 && summary.p50_us <= 725);
+        assert!(summary.p50_us <= summary.p95_us);
+        assert!(summary.p95_us <= summary.p99_us);
+        assert!(summary.p99_us <= summary.max_us);
+    }
+
+    #[test]
+    fn test_stage_span_records_timing() {
+        let mut timings = PipelineTimings::new();
+        let stage = StageSpan::start(PipelineStage::Fusion, 10);
+        let timing = stage.finish(3, &mut timings);
+        assert_eq!(timings.get(PipelineStage::Fusion), Some(&timing));
+        assert_eq!(timing.candidates_in, 10);
+        assert_eq!(timing.candidates_out, 3);
+        assert!(!timings.is_complete());
+
+        let metrics = PipelineMetrics::new();
+        metrics.record(&timings);
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.searches, 1);
+        assert_eq!(snapshot.stages.len(), 1);
+        assert_eq!(snapshot.stage(PipelineStage::Fusion).unwrap().count, 1);
+    }
+}
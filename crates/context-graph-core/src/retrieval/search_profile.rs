@@ -0,0 +1,431 @@
+//! Per-domain search profiles.
+//!
+//! A [`SearchProfile`] bundles the retrieval settings that should change with
+//! the kind of content being searched: the fusion weight profile, the score
+//! cut-off applied after reranking, whether ColBERT reranking runs, the
+//! duplicate threshold `theta_dup` that `merge_concepts` and the
+//! `store_memory` near-duplicate check enforce, and the E1 threshold
+//! `theta_edge` above which stored memories are linked.
+//! Code and legal queries want a few precise hits; creative queries want
+//! loose associations, so their cut-off is lower. Creative variations on
+//! one idea are still distinct memories, so their `theta_dup` is the highest.
+//!
+//! The domain is either given by the caller or detected from the query text
+//! by [`classify_search_domain`], a keyword classifier with no model call.
+//! [`resolve_search_profile`] records which of the two happened so the
+//! response can report it.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Minimum cue matches before a query is classified into a domain.
+pub const MIN_DOMAIN_CUES: usize = 2;
+
+/// Content domain a search is tuned for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchDomain {
+    /// No domain-specific tuning.
+    General,
+    /// Source code, APIs and build tooling.
+    Code,
+    /// Contracts, statutes and compliance.
+    Legal,
+    /// Papers, studies and citations.
+    Academic,
+    /// Fiction, ideas and brainstorming.
+    Creative,
+}
+
+impl SearchDomain {
+    /// All domains.
+    pub const ALL: [SearchDomain; 5] = [
+        SearchDomain::General,
+        SearchDomain::Code,
+        SearchDomain::Legal,
+        SearchDomain::Academic,
+        SearchDomain::Creative,
+    ];
+
+    /// Lowercase name, as accepted by the `domain` tool argument.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SearchDomain::General => "general",
+            SearchDomain::Code => "code",
+            SearchDomain::Legal => "legal",
+            SearchDomain::Academic => "academic",
+            SearchDomain::Creative => "creative",
+        }
+    }
+
+    /// The search profile for this domain.
+    pub fn profile(self) -> SearchProfile {
+        match self {
+            SearchDomain::General => SearchProfile {
+                domain: self,
+                weight_profile: None,
+                min_score: 0.0,
+                rerank: false,
+                theta_dup: 0.85,
+                theta_edge: 0.90,
+            },
+            SearchDomain::Code => SearchProfile {
+                domain: self,
+                weight_profile: Some("code_search"),
+                min_score: 0.75,
+                rerank: true,
+                theta_dup: 0.90,
+                theta_edge: 0.93,
+            },
+            SearchDomain::Legal => SearchProfile {
+                domain: self,
+                weight_profile: Some("fact_checking"),
+                min_score: 0.72,
+                rerank: true,
+                theta_dup: 0.92,
+                theta_edge: 0.93,
+            },
+            SearchDomain::Academic => SearchProfile {
+                domain: self,
+                weight_profile: Some("graph_reasoning"),
+                min_score: 0.68,
+                rerank: false,
+                theta_dup: 0.88,
+                theta_edge: 0.91,
+            },
+            SearchDomain::Creative => SearchProfile {
+                domain: self,
+                weight_profile: Some("semantic_search"),
+                min_score: 0.55,
+                rerank: false,
+                theta_dup: 0.95,
+                theta_edge: 0.88,
+            },
+        }
+    }
+
+    /// Query cues that vote for this domain. Single words match whole
+    /// tokens; entries with punctuation match anywhere in the query.
+    fn cues(self) -> &'static [&'static str] {
+        match self {
+            SearchDomain::General => &[],
+            SearchDomain::Code => &[
+                "::",
+                "()",
+                "->",
+                "fn",
+                "function",
+                "method",
+                "struct",
+                "class",
+                "trait",
+                "impl",
+                "compile",
+                "compiler",
+                "error",
+                "bug",
+                "api",
+                "async",
+                "await",
+                "thread",
+                "crate",
+                "module",
+                "import",
+                "return",
+                "variable",
+                "rust",
+                "python",
+                "typescript",
+                "java",
+                "sql",
+                "refactor",
+                "stack",
+                "runtime",
+                "borrow",
+                "lifetime",
+                "closure",
+            ],
+            SearchDomain::Legal => &[
+                "contract",
+                "clause",
+                "liability",
+                "statute",
+                "regulation",
+                "compliance",
+                "court",
+                "plaintiff",
+                "defendant",
+                "indemnity",
+                "indemnify",
+                "breach",
+                "jurisdiction",
+                "lawsuit",
+                "legal",
+                "law",
+                "gdpr",
+                "license",
+                "licence",
+                "warranty",
+                "tort",
+            ],
+            SearchDomain::Academic => &[
+                "paper",
+                "study",
+                "studies",
+                "hypothesis",
+                "citation",
+                "cite",
+                "journal",
+                "theorem",
+                "proof",
+                "experiment",
+                "dataset",
+                "survey",
+                "literature",
+                "methodology",
+                "abstract",
+                "peer",
+                "research",
+                "findings",
+                "evidence",
+            ],
+            SearchDomain::Creative => &[
+                "story",
+                "poem",
+                "poetry",
+                "novel",
+                "character",
+                "plot",
+                "narrative",
+                "scene",
+                "metaphor",
+                "fiction",
+                "imagine",
+                "brainstorm",
+                "idea",
+                "ideas",
+                "lyrics",
+                "song",
+                "dream",
+                "myth",
+                "fantasy",
+                "creative",
+            ],
+        }
+    }
+}
+
+impl fmt::Display for SearchDomain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SearchDomain {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SearchDomain::ALL
+            .into_iter()
+            .find(|d| d.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                format!(
+                    "Unknown domain '{}'. Valid: general, code, legal, academic, creative",
+                    s
+                )
+            })
+    }
+}
+
+/// Retrieval settings for one domain.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SearchProfile {
+    /// Domain the profile belongs to.
+    pub domain: SearchDomain,
+    /// Named weight profile for multi-space fusion; `None` keeps the default.
+    pub weight_profile: Option<&'static str>,
+    /// Results scoring below this after reranking are dropped.
+    pub min_score: f32,
+    /// Whether ColBERT late-interaction reranking runs.
+    pub rerank: bool,
+    /// Similarity at or above which two memories count as duplicates;
+    /// `merge_concepts` refuses to merge below it (weighted over all spaces)
+    /// and `store_memory` flags new content reaching it (E1).
+    pub theta_dup: f32,
+    /// E1 similarity at or above which a newly stored memory is linked to a
+    /// neighbor by edge inference.
+    pub theta_edge: f32,
+}
+
+/// How the domain of a search was chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DomainSource {
+    /// Given by the caller.
+    Explicit,
+    /// Detected from the query text.
+    Detected,
+    /// No domain given and none detected.
+    Default,
+}
+
+/// Result of classifying a query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DomainClassification {
+    /// Best-matching domain, or `General` below [`MIN_DOMAIN_CUES`].
+    pub domain: SearchDomain,
+    /// Cues of the chosen domain found in the query.
+    pub matched_cues: Vec<&'static str>,
+}
+
+/// Classify a query by counting domain cues.
+///
+/// The domain with the most cues wins if it has at least
+/// [`MIN_DOMAIN_CUES`]; ties go to the domain listed first in
+/// [`SearchDomain::ALL`]. Anything else is `General`.
+pub fn classify_search_domain(query: &str) -> DomainClassification {
+    let lower = query.to_lowercase();
+    let tokens: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|t| !t.is_empty())
+        .collect();
+
+    let mut best = DomainClassification {
+        domain: SearchDomain::General,
+        matched_cues: Vec::new(),
+    };
+    for domain in SearchDomain::ALL {
+        let matched: Vec<&'static str> = domain
+            .cues()
+            .iter()
+            .copied()
+            .filter(|cue| {
+                if cue.chars().all(char::is_alphanumeric) {
+                    tokens.contains(cue)
+                } else {
+                    lower.contains(*cue)
+                }
+            })
+            .collect();
+        if matched.len() >= MIN_DOMAIN_CUES && matched.len() > best.matched_cues.len() {
+            best = DomainClassification {
+                domain,
+                matched_cues: matched,
+            };
+        }
+    }
+    best
+}
+
+/// A profile together with how its domain was chosen.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedSearchProfile {
+    /// The profile to search with.
+    pub profile: SearchProfile,
+    /// Whether the domain was given, detected or defaulted.
+    pub source: DomainSource,
+    /// Cues that decided a detected domain; empty otherwise.
+    pub matched_cues: Vec<&'static str>,
+}
+
+/// Resolve the profile for a search: the explicit domain if given,
+/// otherwise the one detected from `query`.
+pub fn resolve_search_profile(
+    explicit: Option<SearchDomain>,
+    query: &str,
+) -> ResolvedSearchProfile {
+    if let Some(domain) = explicit {
+        return ResolvedSearchProfile {
+            profile: domain.profile(),
+            source: DomainSource::Explicit,
+            matched_cues: Vec::new(),
+        };
+    }
+
+    let classification = classify_search_domain(query);
+    let source = if classification.domain == SearchDomain::General {
+        DomainSource::Default
+    } else {
+        DomainSource::Detected
+    };
+    ResolvedSearchProfile {
+        profile: classification.domain.profile(),
+        source,
+        matched_cues: classification.matched_cues,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weights::get_weight_profile;
+
+    #[test]
+    fn test_classify_search_domain() {
+        let code =
+            classify_search_domain("Why does this async fn fail to compile with a borrow error?");
+        assert_eq!(code.domain, SearchDomain::Code);
+        assert!(code.matched_cues.contains(&"borrow"));
+
+        assert_eq!(
+            classify_search_domain("Which clause limits liability for breach of contract?").domain,
+            SearchDomain::Legal
+        );
+        assert_eq!(
+            classify_search_domain("Write a story where the main character finds a lost poem")
+                .domain,
+            SearchDomain::Creative
+        );
+        assert_eq!(
+            classify_search_domain("Which study cites the original hypothesis paper?").domain,
+            SearchDomain::Academic
+        );
+
+        // One cue is not enough
+        assert_eq!(
+            classify_search_domain("what did we decide about the api").domain,
+            SearchDomain::General
+        );
+    }
+
+    #[test]
+    fn test_resolve_search_profile_sources() {
+        let explicit = resolve_search_profile(Some(SearchDomain::Creative), "fn main() -> error");
+        assert_eq!(explicit.profile.domain, SearchDomain::Creative);
+        assert_eq!(explicit.source, DomainSource::Explicit);
+
+        let detected = resolve_search_profile(None, "fn main() -> error");
+        assert_eq!(detected.profile.domain, SearchDomain::Code);
+        assert_eq!(detected.source, DomainSource::Detected);
+
+        let default = resolve_search_profile(None, "meeting notes from tuesday");
+        assert_eq!(default.profile, SearchDomain::General.profile());
+        assert_eq!(default.source, DomainSource::Default);
+    }
+
+    #[test]
+    fn test_profiles_reference_known_weight_profiles() {
+        for domain in SearchDomain::ALL {
+            let profile = domain.profile();
+            if let Some(name) = profile.weight_profile {
+                assert!(get_weight_profile(name).is_ok(), "{} -> {}", domain, name);
+            }
+            assert!((0.0..=1.0).contains(&profile.min_score));
+            assert!(profile.theta_dup > profile.min_score && profile.theta_dup < 1.0);
+            assert!(profile.theta_edge > 0.5 && profile.theta_edge < 1.0);
+            assert_eq!(domain.as_str().parse::<SearchDomain>(), Ok(domain));
+        }
+        assert!(
+            SearchDomain::Code.profile().min_score > SearchDomain::Creative.profile().min_score
+        );
+        assert!(
+            SearchDomain::Creative.profile().theta_dup > SearchDomain::Code.profile().theta_dup
+        );
+        assert!(
+            SearchDomain::Code.profile().theta_edge > SearchDomain::Creative.profile().theta_edge
+        );
+        assert!("medical".parse::<SearchDomain>().is_err());
+    }
+}
@@ -0,0 +1,217 @@
+//! Snapshot export: streams matching memories from a store into a snapshot.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use crate::error::{CoreError, CoreResult};
+use crate::traits::TeleologicalMemoryStore;
+use crate::types::fingerprint::TeleologicalFingerprint;
+
+use super::format::{RecordSidecar, SnapshotManifest, SnapshotWriter, SNAPSHOT_FORMAT_VERSION};
+
+/// Default number of fingerprints read from the store per page.
+pub const DEFAULT_SNAPSHOT_BATCH_SIZE: usize = 256;
+
+/// Selects which memories an export includes. All set conditions must match.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotFilter {
+    /// Only memories in this namespace.
+    pub namespace: Option<String>,
+    /// Only memories created at or after this time.
+    pub created_after: Option<DateTime<Utc>>,
+    /// Only memories created before this time.
+    pub created_before: Option<DateTime<Utc>>,
+    /// Topic whose members were exported (recorded in the manifest).
+    pub topic_id: Option<Uuid>,
+    /// Member IDs of `topic_id`. Not serialized; the manifest keeps the topic ID.
+    #[serde(skip)]
+    pub member_ids: Option<HashSet<Uuid>>,
+}
+
+impl SnapshotFilter {
+    /// Restrict to one namespace.
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Restrict to memories created in `[after, before)`. Either bound may be open.
+    pub fn with_time_range(
+        mut self,
+        after: Option<DateTime<Utc>>,
+        before: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.created_after = after;
+        self.created_before = before;
+        self
+    }
+
+    /// Restrict to the members of a topic.
+    pub fn with_topic(mut self, topic_id: Uuid, members: impl IntoIterator<Item = Uuid>) -> Self {
+        self.topic_id = Some(topic_id);
+        self.member_ids = Some(members.into_iter().collect());
+        self
+    }
+
+    /// Check if `fingerprint` passes every set condition.
+    pub fn matches(&self, fingerprint: &TeleologicalFingerprint) -> bool {
+        self.namespace
+            .as_ref()
+            .map_or(true, |ns| &fingerprint.namespace == ns)
+            && self
+                .created_after
+                .map_or(true, |after| fingerprint.created_at >= after)
+            && self
+                .created_before
+                .map_or(true, |before| fingerprint.created_at < before)
+            && self
+                .member_ids
+                .as_ref()
+                .map_or(true, |members| members.contains(&fingerprint.id))
+    }
+
+    fn validate(&self) -> CoreResult<()> {
+        if let (Some(after), Some(before)) = (self.created_after, self.created_before) {
+            if after >= before {
+                return Err(CoreError::ValidationError {
+                    field: "created_after".to_string(),
+                    message: format!(
+                        "created_after ({}) must be before created_before ({})",
+                        after, before
+                    ),
+                });
+            }
+        }
+        if self.topic_id.is_some() != self.member_ids.is_some() {
+            return Err(CoreError::ValidationError {
+                field: "topic_id".to_string(),
+                message: "topic_id and member_ids must be set together".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Writes a store's memories (fingerprint, content, source metadata and
+/// embedding version) into a snapshot directory.
+///
+/// Fingerprints are read in ID order, one page at a time, so memory use is
+/// bounded by the batch size regardless of store size. Audit history is not
+/// exported; it stays with the originating store.
+pub struct SnapshotExporter {
+    store: Arc<dyn TeleologicalMemoryStore>,
+    filter: SnapshotFilter,
+    batch_size: usize,
+    model_ids: Vec<String>,
+}
+
+impl SnapshotExporter {
+    /// Create an exporter with default batching.
+    pub fn new(store: Arc<dyn TeleologicalMemoryStore>, filter: SnapshotFilter) -> Self {
+        Self {
+            store,
+            filter,
+            batch_size: DEFAULT_SNAPSHOT_BATCH_SIZE,
+            model_ids: Vec::new(),
+        }
+    }
+
+    /// Set the page size used when reading from the store.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Record the exporting provider's model IDs (E1-E13) in the manifest.
+    ///
+    /// Importers compare them with their own models to detect snapshots whose
+    /// vectors are not comparable with locally embedded queries.
+    pub fn with_model_ids(mut self, model_ids: impl IntoIterator<Item = String>) -> Self {
+        self.model_ids = model_ids.into_iter().collect();
+        self
+    }
+
+    /// Export every matching memory into `dir`.
+    ///
+    /// # Errors
+    ///
+    /// - `CoreError::ValidationError` for an invalid filter, a zero batch
+    ///   size, or a `dir` that already holds a snapshot
+    /// - Storage and serialization errors from the store or the files
+    pub async fn export(&self, dir: &Path) -> CoreResult<SnapshotManifest> {
+        self.filter.validate()?;
+        if self.batch_size == 0 {
+            return Err(CoreError::ValidationError {
+                field: "batch_size".to_string(),
+                message: "Batch size must be greater than 0".to_string(),
+            });
+        }
+
+        let mut writer = SnapshotWriter::create(dir).await?;
+        let mut namespace_counts: BTreeMap<String, usize> = BTreeMap::new();
+        let mut memory_count = 0;
+        let mut after: Option<Uuid> = None;
+
+        loop {
+            let page = self
+                .store
+                .list_fingerprints_after(after, self.batch_size)
+                .await?;
+            let Some(last) = page.last() else {
+                break;
+            };
+            after = Some(last.id);
+
+            let selected: Vec<TeleologicalFingerprint> = page
+                .into_iter()
+                .filter(|fp| self.filter.matches(fp))
+                .collect();
+            if selected.is_empty() {
+                continue;
+            }
+
+            let ids: Vec<Uuid> = selected.iter().map(|fp| fp.id).collect();
+            let contents = self.store.get_content_batch(&ids).await?;
+            let metadata = self.store.get_source_metadata_batch(&ids).await?;
+
+            for ((fingerprint, content), source_metadata) in
+                selected.into_iter().zip(contents).zip(metadata)
+            {
+                let sidecar = RecordSidecar {
+                    content,
+                    source_metadata,
+                    embedding_version: self.store.get_embedding_version(fingerprint.id).await?,
+                };
+                writer.append(&fingerprint, &sidecar).await?;
+                *namespace_counts
+                    .entry(fingerprint.namespace.clone())
+                    .or_insert(0) += 1;
+                memory_count += 1;
+            }
+            debug!(exported = memory_count, after = ?after, "Snapshot export page written");
+        }
+
+        let manifest = SnapshotManifest {
+            format_version: SNAPSHOT_FORMAT_VERSION,
+            writer_version: env!("CARGO_PKG_VERSION").to_string(),
+            created_at: Utc::now(),
+            memory_count,
+            namespace_counts,
+            model_ids: self.model_ids.clone(),
+            filter: self.filter.clone(),
+        };
+        writer.finish(&manifest).await?;
+        info!(
+            memory_count,
+            path = %dir.display(),
+            "Snapshot export complete"
+        );
+        Ok(manifest)
+    }
+}
@@ -0,0 +1,398 @@
+//! On-disk snapshot layout: manifest, `.cgeb` record stream and `.cgei` index.
+//!
+//! `.cgeb` (batch) starts with the magic `CGEB` and a little-endian `u16`
+//! format version, followed by one record per memory:
+//!
+//! ```text
+//! u32 fingerprint_len | bincode(TeleologicalFingerprint) | u32 sidecar_len | JSON(RecordSidecar)
+//! ```
+//!
+//! `.cgei` (index) starts with `CGEI` and the same version, followed by one
+//! fixed 28-byte entry per record: the 16-byte fingerprint ID, the `u64`
+//! record offset in `.cgeb`, and the `u32` record length. The reader checks
+//! every record against its index entry, so truncation or reordering is
+//! detected instead of silently importing partial data.
+//!
+//! Fingerprints use bincode (the same encoding the RocksDB store uses);
+//! content and source metadata use JSON because `SourceMetadata` skips
+//! `None` fields, which bincode cannot round-trip.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
+use uuid::Uuid;
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::audit::EmbeddingVersionRecord;
+use crate::types::fingerprint::TeleologicalFingerprint;
+use crate::types::SourceMetadata;
+
+use super::export::SnapshotFilter;
+
+/// Current snapshot format version (manifest, `.cgeb` and `.cgei`).
+pub const SNAPSHOT_FORMAT_VERSION: u16 = 1;
+
+/// Manifest file name inside a snapshot directory.
+pub const SNAPSHOT_MANIFEST_FILE: &str = "manifest.json";
+
+/// Record stream file name inside a snapshot directory.
+pub const SNAPSHOT_DATA_FILE: &str = "memories.cgeb";
+
+/// Record index file name inside a snapshot directory.
+pub const SNAPSHOT_INDEX_FILE: &str = "memories.cgei";
+
+const DATA_MAGIC: &[u8; 4] = b"CGEB";
+const INDEX_MAGIC: &[u8; 4] = b"CGEI";
+const HEADER_LEN: u64 = 6;
+const INDEX_ENTRY_LEN: usize = 28;
+
+/// Describes a snapshot directory. Written last, so a snapshot without a
+/// manifest is incomplete.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    /// Snapshot format version.
+    pub format_version: u16,
+    /// Version of context-graph-core that wrote the snapshot.
+    pub writer_version: String,
+    /// When the export finished.
+    pub created_at: DateTime<Utc>,
+    /// Number of memories in the snapshot.
+    pub memory_count: usize,
+    /// Memories per namespace.
+    pub namespace_counts: BTreeMap<String, usize>,
+    /// Model ID per embedder slot (E1-E13) of the exporting provider, if known.
+    #[serde(default)]
+    pub model_ids: Vec<String>,
+    /// Filter applied during export.
+    pub filter: SnapshotFilter,
+}
+
+impl SnapshotManifest {
+    /// Read and validate the manifest in `dir`.
+    ///
+    /// # Errors
+    ///
+    /// - `CoreError::StorageError` if the manifest cannot be read
+    /// - `CoreError::SerializationError` if it is not valid JSON
+    /// - `CoreError::ValidationError` if the format version is unsupported
+    pub async fn read(dir: &Path) -> CoreResult<Self> {
+        let path = dir.join(SNAPSHOT_MANIFEST_FILE);
+        let bytes = tokio::fs::read(&path)
+            .await
+            .map_err(|e| io_error("read snapshot manifest", &path, e))?;
+        let manifest: Self = serde_json::from_slice(&bytes).map_err(|e| {
+            CoreError::SerializationError(format!("Invalid snapshot manifest: {}", e))
+        })?;
+        if manifest.format_version != SNAPSHOT_FORMAT_VERSION {
+            return Err(CoreError::ValidationError {
+                field: "format_version".to_string(),
+                message: format!(
+                    "Unsupported snapshot format version {} (supported: {})",
+                    manifest.format_version, SNAPSHOT_FORMAT_VERSION
+                ),
+            });
+        }
+        Ok(manifest)
+    }
+
+    async fn write(&self, dir: &Path) -> CoreResult<()> {
+        let path = dir.join(SNAPSHOT_MANIFEST_FILE);
+        let json = serde_json::to_vec_pretty(self).map_err(|e| {
+            CoreError::SerializationError(format!("Failed to serialize snapshot manifest: {}", e))
+        })?;
+        tokio::fs::write(&path, json)
+            .await
+            .map_err(|e| io_error("write snapshot manifest", &path, e))
+    }
+}
+
+/// Per-memory data stored next to the fingerprint.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct RecordSidecar {
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub source_metadata: Option<SourceMetadata>,
+    #[serde(default)]
+    pub embedding_version: Option<EmbeddingVersionRecord>,
+}
+
+/// Streams records into a new snapshot directory.
+pub(crate) struct SnapshotWriter {
+    dir: PathBuf,
+    data: BufWriter<File>,
+    index: BufWriter<File>,
+    offset: u64,
+    count: usize,
+}
+
+impl SnapshotWriter {
+    /// Create `dir` (if needed) and the record and index files.
+    ///
+    /// Refuses to overwrite an existing snapshot.
+    pub async fn create(dir: &Path) -> CoreResult<Self> {
+        if tokio::fs::try_exists(dir.join(SNAPSHOT_MANIFEST_FILE))
+            .await
+            .unwrap_or(false)
+        {
+            return Err(CoreError::ValidationError {
+                field: "path".to_string(),
+                message: format!("{} already contains a snapshot", dir.display()),
+            });
+        }
+        tokio::fs::create_dir_all(dir)
+            .await
+            .map_err(|e| io_error("create snapshot directory", dir, e))?;
+
+        let mut data = BufWriter::new(create_file(&dir.join(SNAPSHOT_DATA_FILE)).await?);
+        let mut index = BufWriter::new(create_file(&dir.join(SNAPSHOT_INDEX_FILE)).await?);
+        write_header(&mut data, DATA_MAGIC, dir).await?;
+        write_header(&mut index, INDEX_MAGIC, dir).await?;
+
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            data,
+            index,
+            offset: HEADER_LEN,
+            count: 0,
+        })
+    }
+
+    /// Append one memory.
+    pub async fn append(
+        &mut self,
+        fingerprint: &TeleologicalFingerprint,
+        sidecar: &RecordSidecar,
+    ) -> CoreResult<()> {
+        let fp_bytes = bincode::serialize(fingerprint).map_err(|e| {
+            CoreError::SerializationError(format!(
+                "Failed to serialize fingerprint {}: {}",
+                fingerprint.id, e
+            ))
+        })?;
+        let sidecar_bytes = serde_json::to_vec(sidecar).map_err(|e| {
+            CoreError::SerializationError(format!(
+                "Failed to serialize metadata for {}: {}",
+                fingerprint.id, e
+            ))
+        })?;
+
+        let mut record = Vec::with_capacity(8 + fp_bytes.len() + sidecar_bytes.len());
+        record.extend_from_slice(&frame_len(fp_bytes.len())?.to_le_bytes());
+        record.extend_from_slice(&fp_bytes);
+        record.extend_from_slice(&frame_len(sidecar_bytes.len())?.to_le_bytes());
+        record.extend_from_slice(&sidecar_bytes);
+        let record_len = frame_len(record.len())?;
+
+        let mut entry = [0u8; INDEX_ENTRY_LEN];
+        entry[..16].copy_from_slice(fingerprint.id.as_bytes());
+        entry[16..24].copy_from_slice(&self.offset.to_le_bytes());
+        entry[24..].copy_from_slice(&record_len.to_le_bytes());
+
+        let data_path = self.dir.join(SNAPSHOT_DATA_FILE);
+        self.data
+            .write_all(&record)
+            .await
+            .map_err(|e| io_error("write snapshot record", &data_path, e))?;
+        let index_path = self.dir.join(SNAPSHOT_INDEX_FILE);
+        self.index
+            .write_all(&entry)
+            .await
+            .map_err(|e| io_error("write snapshot index", &index_path, e))?;
+
+        self.offset += record.len() as u64;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Flush both files and write the manifest.
+    pub async fn finish(mut self, manifest: &SnapshotManifest) -> CoreResult<()> {
+        debug_assert_eq!(manifest.memory_count, self.count);
+        let data_path = self.dir.join(SNAPSHOT_DATA_FILE);
+        self.data
+            .flush()
+            .await
+            .map_err(|e| io_error("flush snapshot records", &data_path, e))?;
+        let index_path = self.dir.join(SNAPSHOT_INDEX_FILE);
+        self.index
+            .flush()
+            .await
+            .map_err(|e| io_error("flush snapshot index", &index_path, e))?;
+        manifest.write(&self.dir).await
+    }
+}
+
+/// Reads records back in the order they were written.
+pub(crate) struct SnapshotReader {
+    data: BufReader<File>,
+    data_path: PathBuf,
+    index: Vec<(Uuid, u64, u32)>,
+    position: usize,
+    offset: u64,
+}
+
+impl SnapshotReader {
+    /// Open the snapshot in `dir` and load its index.
+    ///
+    /// # Errors
+    ///
+    /// `CoreError::SerializationError` if either file has a bad header or the
+    /// index does not hold exactly `manifest.memory_count` entries.
+    pub async fn open(dir: &Path, manifest: &SnapshotManifest) -> CoreResult<Self> {
+        let index_path = dir.join(SNAPSHOT_INDEX_FILE);
+        let index_bytes = tokio::fs::read(&index_path)
+            .await
+            .map_err(|e| io_error("read snapshot index", &index_path, e))?;
+        check_header(&index_bytes, INDEX_MAGIC, &index_path)?;
+        let entries = &index_bytes[HEADER_LEN as usize..];
+        if entries.len() % INDEX_ENTRY_LEN != 0
+            || entries.len() / INDEX_ENTRY_LEN != manifest.memory_count
+        {
+            return Err(CoreError::SerializationError(format!(
+                "Snapshot index {} has {} bytes of entries, expected {} entries",
+                index_path.display(),
+                entries.len(),
+                manifest.memory_count
+            )));
+        }
+        let index = entries
+            .chunks_exact(INDEX_ENTRY_LEN)
+            .map(|entry| {
+                let id = Uuid::from_slice(&entry[..16]).expect("16-byte slice");
+                let offset = u64::from_le_bytes(entry[16..24].try_into().expect("8-byte slice"));
+                let len = u32::from_le_bytes(entry[24..].try_into().expect("4-byte slice"));
+                (id, offset, len)
+            })
+            .collect();
+
+        let data_path = dir.join(SNAPSHOT_DATA_FILE);
+        let mut data = BufReader::new(
+            File::open(&data_path)
+                .await
+                .map_err(|e| io_error("open snapshot records", &data_path, e))?,
+        );
+        let mut header = [0u8; HEADER_LEN as usize];
+        data.read_exact(&mut header)
+            .await
+            .map_err(|e| io_error("read snapshot records", &data_path, e))?;
+        check_header(&header, DATA_MAGIC, &data_path)?;
+
+        Ok(Self {
+            data,
+            data_path,
+            index,
+            position: 0,
+            offset: HEADER_LEN,
+        })
+    }
+
+    /// Read the next record, or `None` after the last one.
+    pub async fn next_record(
+        &mut self,
+    ) -> CoreResult<Option<(TeleologicalFingerprint, RecordSidecar)>> {
+        let Some(&(expected_id, expected_offset, expected_len)) = self.index.get(self.position)
+        else {
+            return Ok(None);
+        };
+        if expected_offset != self.offset {
+            return Err(self.corrupt(format!(
+                "record {} expected at offset {}, stream is at {}",
+                self.position, expected_offset, self.offset
+            )));
+        }
+
+        let fp_bytes = self.read_frame().await?;
+        let sidecar_bytes = self.read_frame().await?;
+        let record_len = 8 + fp_bytes.len() + sidecar_bytes.len();
+        if record_len != expected_len as usize {
+            return Err(self.corrupt(format!(
+                "record {} is {} bytes, index says {}",
+                self.position, record_len, expected_len
+            )));
+        }
+
+        let fingerprint: TeleologicalFingerprint = bincode::deserialize(&fp_bytes)
+            .map_err(|e| self.corrupt(format!("record {} fingerprint: {}", self.position, e)))?;
+        if fingerprint.id != expected_id {
+            return Err(self.corrupt(format!(
+                "record {} holds fingerprint {}, index says {}",
+                self.position, fingerprint.id, expected_id
+            )));
+        }
+        let sidecar: RecordSidecar = serde_json::from_slice(&sidecar_bytes)
+            .map_err(|e| self.corrupt(format!("record {} metadata: {}", self.position, e)))?;
+
+        self.offset += record_len as u64;
+        self.position += 1;
+        Ok(Some((fingerprint, sidecar)))
+    }
+
+    async fn read_frame(&mut self) -> CoreResult<Vec<u8>> {
+        let mut len = [0u8; 4];
+        self.data
+            .read_exact(&mut len)
+            .await
+            .map_err(|e| io_error("read snapshot records", &self.data_path, e))?;
+        let mut frame = vec![0u8; u32::from_le_bytes(len) as usize];
+        self.data
+            .read_exact(&mut frame)
+            .await
+            .map_err(|e| io_error("read snapshot records", &self.data_path, e))?;
+        Ok(frame)
+    }
+
+    fn corrupt(&self, detail: String) -> CoreError {
+        CoreError::SerializationError(format!(
+            "Corrupt snapshot {}: {}",
+            self.data_path.display(),
+            detail
+        ))
+    }
+}
+
+fn io_error(action: &str, path: &Path, e: std::io::Error) -> CoreError {
+    CoreError::StorageError(format!("Failed to {} {}: {}", action, path.display(), e))
+}
+
+fn frame_len(len: usize) -> CoreResult<u32> {
+    u32::try_from(len).map_err(|_| {
+        CoreError::SerializationError(format!("Snapshot record of {} bytes is too large", len))
+    })
+}
+
+async fn create_file(path: &Path) -> CoreResult<File> {
+    File::create(path)
+        .await
+        .map_err(|e| io_error("create", path, e))
+}
+
+async fn write_header(file: &mut BufWriter<File>, magic: &[u8; 4], dir: &Path) -> CoreResult<()> {
+    file.write_all(magic)
+        .await
+        .map_err(|e| io_error("write snapshot header in", dir, e))?;
+    file.write_all(&SNAPSHOT_FORMAT_VERSION.to_le_bytes())
+        .await
+        .map_err(|e| io_error("write snapshot header in", dir, e))
+}
+
+fn check_header(bytes: &[u8], magic: &[u8; 4], path: &Path) -> CoreResult<()> {
+    if bytes.len() < HEADER_LEN as usize || &bytes[..4] != magic {
+        return Err(CoreError::SerializationError(format!(
+            "{} is not a snapshot file (bad magic)",
+            path.display()
+        )));
+    }
+    let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+    if version != SNAPSHOT_FORMAT_VERSION {
+        return Err(CoreError::SerializationError(format!(
+            "{} has unsupported format version {}",
+            path.display(),
+            version
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,218 @@
+//! Snapshot import: loads a snapshot into a store under a conflict policy.
+
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::error::{CoreError, CoreResult};
+use crate::traits::TeleologicalMemoryStore;
+use crate::types::fingerprint::TeleologicalFingerprint;
+
+use super::format::{RecordSidecar, SnapshotManifest, SnapshotReader};
+
+/// What to do when an imported memory's ID already exists in the store.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConflictPolicy {
+    /// Keep the existing memory and drop the imported one.
+    #[default]
+    Skip,
+    /// Replace the existing memory with the imported one.
+    Overwrite,
+    /// Store the imported memory under a freshly generated ID.
+    ReId,
+}
+
+impl fmt::Display for ConflictPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Skip => "skip",
+            Self::Overwrite => "overwrite",
+            Self::ReId => "re-id",
+        })
+    }
+}
+
+impl FromStr for ConflictPolicy {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "skip" => Ok(Self::Skip),
+            "overwrite" => Ok(Self::Overwrite),
+            "re-id" | "reid" => Ok(Self::ReId),
+            other => Err(CoreError::ValidationError {
+                field: "conflict_policy".to_string(),
+                message: format!(
+                    "Unknown conflict policy '{}', expected skip, overwrite or re-id",
+                    other
+                ),
+            }),
+        }
+    }
+}
+
+/// Outcome counts of one import run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportReport {
+    /// Records stored under their original ID (no conflict)
+    pub imported: usize,
+    /// Records that replaced an existing memory (`Overwrite`)
+    pub overwritten: usize,
+    /// Records dropped because the ID already existed (`Skip`)
+    pub skipped: usize,
+    /// Records stored under a new ID (`ReId`)
+    pub reassigned: usize,
+    /// Records the store rejected
+    pub failed: usize,
+    /// The snapshot's model IDs differ from the importer's
+    pub model_mismatch: bool,
+    /// IDs of every memory written to the store, in snapshot order
+    pub stored_ids: Vec<Uuid>,
+}
+
+/// Loads a snapshot written by [`SnapshotExporter`](super::SnapshotExporter)
+/// into a store.
+///
+/// Every record goes through the store's normal `store`/`update` path, so the
+/// store rebuilds its secondary indexes (HNSW, SPLADE inverted, content hash)
+/// for imported memories. A record the store rejects is counted as failed and
+/// the import continues; a corrupt snapshot aborts the import.
+pub struct SnapshotImporter {
+    store: Arc<dyn TeleologicalMemoryStore>,
+    policy: ConflictPolicy,
+    model_ids: Vec<String>,
+}
+
+impl SnapshotImporter {
+    /// Create an importer with the given conflict policy.
+    pub fn new(store: Arc<dyn TeleologicalMemoryStore>, policy: ConflictPolicy) -> Self {
+        Self {
+            store,
+            policy,
+            model_ids: Vec::new(),
+        }
+    }
+
+    /// Set the importing provider's model IDs (E1-E13) to compare against the
+    /// snapshot manifest.
+    pub fn with_model_ids(mut self, model_ids: impl IntoIterator<Item = String>) -> Self {
+        self.model_ids = model_ids.into_iter().collect();
+        self
+    }
+
+    /// Import the snapshot in `dir`.
+    ///
+    /// # Errors
+    ///
+    /// `CoreError::SerializationError` or `CoreError::StorageError` if the
+    /// snapshot is missing, from an unsupported format version, or corrupt.
+    /// Records already imported before the error stay in the store.
+    pub async fn import(&self, dir: &Path) -> CoreResult<ImportReport> {
+        let manifest = SnapshotManifest::read(dir).await?;
+        let mut reader = SnapshotReader::open(dir, &manifest).await?;
+
+        let mut report = ImportReport {
+            model_mismatch: !self.model_ids.is_empty()
+                && !manifest.model_ids.is_empty()
+                && self.model_ids != manifest.model_ids,
+            ..Default::default()
+        };
+        if report.model_mismatch {
+            warn!(
+                snapshot_models = ?manifest.model_ids,
+                local_models = ?self.model_ids,
+                "Snapshot was embedded with different models; run reembed after import"
+            );
+        }
+
+        while let Some((fingerprint, sidecar)) = reader.next_record().await? {
+            let original_id = fingerprint.id;
+            match self.import_record(fingerprint, sidecar).await {
+                Ok(Outcome::Imported(id)) => {
+                    report.imported += 1;
+                    report.stored_ids.push(id);
+                }
+                Ok(Outcome::Overwritten(id)) => {
+                    report.overwritten += 1;
+                    report.stored_ids.push(id);
+                }
+                Ok(Outcome::Reassigned(id)) => {
+                    debug!(from = %original_id, to = %id, "Imported memory under new ID");
+                    report.reassigned += 1;
+                    report.stored_ids.push(id);
+                }
+                Ok(Outcome::Skipped) => report.skipped += 1,
+                Err(e) => {
+                    warn!(fingerprint_id = %original_id, error = %e, "Failed to import memory");
+                    report.failed += 1;
+                }
+            }
+        }
+
+        info!(
+            imported = report.imported,
+            overwritten = report.overwritten,
+            skipped = report.skipped,
+            reassigned = report.reassigned,
+            failed = report.failed,
+            path = %dir.display(),
+            "Snapshot import complete"
+        );
+        Ok(report)
+    }
+
+    async fn import_record(
+        &self,
+        mut fingerprint: TeleologicalFingerprint,
+        sidecar: RecordSidecar,
+    ) -> CoreResult<Outcome> {
+        let exists = self.store.retrieve(fingerprint.id).await?.is_some();
+        let outcome = match (exists, self.policy) {
+            (false, _) => {
+                self.store.store(fingerprint.clone()).await?;
+                Outcome::Imported(fingerprint.id)
+            }
+            (true, ConflictPolicy::Skip) => return Ok(Outcome::Skipped),
+            (true, ConflictPolicy::Overwrite) => {
+                if !self.store.update(fingerprint.clone()).await? {
+                    return Err(CoreError::StorageError(format!(
+                        "Fingerprint {} disappeared before it could be overwritten",
+                        fingerprint.id
+                    )));
+                }
+                Outcome::Overwritten(fingerprint.id)
+            }
+            (true, ConflictPolicy::ReId) => {
+                fingerprint.id = Uuid::new_v4();
+                self.store.store(fingerprint.clone()).await?;
+                Outcome::Reassigned(fingerprint.id)
+            }
+        };
+
+        let id = fingerprint.id;
+        if let Some(content) = &sidecar.content {
+            self.store.store_content(id, content).await?;
+        }
+        if let Some(metadata) = &sidecar.source_metadata {
+            self.store.store_source_metadata(id, metadata).await?;
+        }
+        if let Some(mut version) = sidecar.embedding_version {
+            version.fingerprint_id = id;
+            self.store.store_embedding_version(&version).await?;
+        }
+        Ok(outcome)
+    }
+}
+
+enum Outcome {
+    Imported(Uuid),
+    Overwritten(Uuid),
+    Reassigned(Uuid),
+    Skipped,
+}
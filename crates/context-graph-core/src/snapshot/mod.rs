@@ -0,0 +1,34 @@
+//! Memory snapshots: portable export and import of stored memories.
+//!
+//! A snapshot is a directory holding three files:
+//!
+//! - `manifest.json`: format version, writer version, memory counts per
+//!   namespace, the exporting models and the filter used
+//! - `memories.cgeb`: the record stream (fingerprint plus content, source
+//!   metadata and embedding version per memory)
+//! - `memories.cgei`: a fixed-width index of record IDs and offsets
+//!
+//! [`SnapshotExporter`] streams memories out of a store page by page, with
+//! optional namespace, time-range and topic filters. [`SnapshotImporter`]
+//! writes them into another store through its normal store path, so
+//! secondary indexes are rebuilt, and resolves ID conflicts with a
+//! [`ConflictPolicy`]. Audit history is not part of a snapshot.
+//!
+//! # Module Structure
+//!
+//! - `format`: Manifest and the `.cgeb`/`.cgei` reader and writer
+//! - `export`: Export filter and exporter
+//! - `import`: Conflict policy, import report and importer
+
+mod export;
+mod format;
+mod import;
+#[cfg(test)]
+mod tests;
+
+pub use export::{SnapshotExporter, SnapshotFilter, DEFAULT_SNAPSHOT_BATCH_SIZE};
+pub use format::{
+    SnapshotManifest, SNAPSHOT_DATA_FILE, SNAPSHOT_FORMAT_VERSION, SNAPSHOT_INDEX_FILE,
+    SNAPSHOT_MANIFEST_FILE,
+};
+pub use import::{ConflictPolicy, ImportReport, SnapshotImporter};
@@ -0,0 +1,264 @@
+//! Tests for snapshot export and import.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use tempfile::TempDir;
+use uuid::Uuid;
+
+use crate::stubs::{InMemoryTeleologicalStore, StubMultiArrayProvider};
+use crate::traits::{
+    MultiArrayEmbeddingProvider, TeleologicalMemoryStore, TeleologicalSearchOptions,
+    TeleologicalSearchResult,
+};
+use crate::types::audit::EmbeddingVersionRecord;
+use crate::types::fingerprint::TeleologicalFingerprint;
+use crate::types::SourceMetadata;
+
+use super::*;
+
+/// Store `n` memories with content, source metadata and embedding versions.
+///
+/// Every third memory goes to namespace "project-b"; creation times are one
+/// hour apart, starting `n` hours ago.
+async fn seeded_store(n: usize) -> (Arc<InMemoryTeleologicalStore>, Vec<Uuid>) {
+    let store = Arc::new(InMemoryTeleologicalStore::new());
+    let provider = StubMultiArrayProvider::new();
+    let start = Utc::now() - Duration::hours(n as i64);
+    let mut ids = Vec::with_capacity(n);
+    for i in 0..n {
+        let content = format!("snapshot memory {} about topic {}", i, i % 7);
+        let output = provider.embed_all(&content).await.unwrap();
+        let mut fp = TeleologicalFingerprint::new(output.fingerprint, [i as u8; 32]);
+        fp.created_at = start + Duration::hours(i as i64);
+        if i % 3 == 0 {
+            fp = fp.with_namespace("project-b");
+        }
+        let id = store.store(fp).await.unwrap();
+        store.store_content(id, &content).await.unwrap();
+        store
+            .store_source_metadata(
+                id,
+                &SourceMetadata::md_file_chunk(format!("doc{}.md", i), 0, 1),
+            )
+            .await
+            .unwrap();
+        store
+            .store_embedding_version(&EmbeddingVersionRecord {
+                fingerprint_id: id,
+                computed_at: Utc::now(),
+                embedder_versions: HashMap::from([("E1".to_string(), "e1-v1".to_string())]),
+                e7_model_version: None,
+                computation_time_ms: Some(5),
+                deterministic: false,
+            })
+            .await
+            .unwrap();
+        ids.push(id);
+    }
+    (store, ids)
+}
+
+async fn export_all(store: &Arc<InMemoryTeleologicalStore>, dir: &TempDir) -> SnapshotManifest {
+    SnapshotExporter::new(store.clone(), SnapshotFilter::default())
+        .with_batch_size(16)
+        .export(dir.path())
+        .await
+        .unwrap()
+}
+
+/// Result IDs and scores, with equal scores ordered by ID.
+fn ranked(results: &[TeleologicalSearchResult]) -> Vec<(Uuid, f32)> {
+    let mut ranked: Vec<(Uuid, f32)> = results
+        .iter()
+        .map(|r| (r.fingerprint.id, r.similarity))
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    ranked
+}
+
+#[tokio::test]
+async fn test_round_trip_preserves_retrieval_and_search() {
+    let (source, ids) = seeded_store(100).await;
+    let dir = TempDir::new().unwrap();
+    let manifest = export_all(&source, &dir).await;
+    assert_eq!(manifest.memory_count, 100);
+    assert_eq!(manifest.namespace_counts["project-b"], 34);
+    assert_eq!(manifest.namespace_counts["default"], 66);
+    assert_eq!(SnapshotManifest::read(dir.path()).await.unwrap(), manifest);
+
+    let target = Arc::new(InMemoryTeleologicalStore::new());
+    let report = SnapshotImporter::new(target.clone(), ConflictPolicy::Skip)
+        .import(dir.path())
+        .await
+        .unwrap();
+    assert_eq!(report.imported, 100);
+    assert_eq!(report.failed, 0);
+    assert_eq!(report.stored_ids.len(), 100);
+
+    for &id in ids.iter().step_by(9) {
+        let original = source.retrieve(id).await.unwrap().unwrap();
+        let imported = target.retrieve(id).await.unwrap().unwrap();
+        assert_eq!(imported.namespace, original.namespace);
+        assert_eq!(imported.created_at, original.created_at);
+        assert_eq!(imported.content_hash, original.content_hash);
+        assert_eq!(
+            target.get_content(id).await.unwrap(),
+            source.get_content(id).await.unwrap()
+        );
+        assert_eq!(
+            target.get_source_metadata(id).await.unwrap(),
+            source.get_source_metadata(id).await.unwrap()
+        );
+        let version = target.get_embedding_version(id).await.unwrap().unwrap();
+        assert_eq!(version.embedder_versions["E1"], "e1-v1");
+    }
+
+    let provider = StubMultiArrayProvider::new();
+    for query in ["snapshot memory 12", "topic 3", "memory about topic 5"] {
+        let embedded = provider.embed_all(query).await.unwrap().fingerprint;
+        for namespace in ["default", "project-b"] {
+            let options = TeleologicalSearchOptions::quick(10).with_namespace(namespace);
+            let expected = source
+                .search_semantic(&embedded, options.clone())
+                .await
+                .unwrap();
+            let actual = target.search_semantic(&embedded, options).await.unwrap();
+            assert!(!expected.is_empty());
+            assert_eq!(
+                ranked(&actual),
+                ranked(&expected),
+                "search parity for '{}' in {}",
+                query,
+                namespace
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_export_filters_by_namespace_time_and_topic() {
+    let (store, ids) = seeded_store(30).await;
+    let origin = store.retrieve(ids[0]).await.unwrap().unwrap().created_at;
+
+    let dir = TempDir::new().unwrap();
+    let filter = SnapshotFilter::default()
+        .with_namespace("project-b")
+        .with_time_range(Some(origin + Duration::hours(10)), None);
+    let manifest = SnapshotExporter::new(store.clone(), filter)
+        .export(dir.path())
+        .await
+        .unwrap();
+    // project-b holds indexes 0, 3, ..., 27; indexes 12..=27 are in range
+    assert_eq!(manifest.memory_count, 6);
+    assert_eq!(manifest.filter.namespace.as_deref(), Some("project-b"));
+
+    let topic_id = Uuid::new_v4();
+    let members = [ids[1], ids[4], ids[5]];
+    let dir = TempDir::new().unwrap();
+    let manifest = SnapshotExporter::new(
+        store.clone(),
+        SnapshotFilter::default().with_topic(topic_id, members),
+    )
+    .export(dir.path())
+    .await
+    .unwrap();
+    assert_eq!(manifest.memory_count, 3);
+    assert_eq!(manifest.filter.topic_id, Some(topic_id));
+
+    let target = Arc::new(InMemoryTeleologicalStore::new());
+    let mut report = SnapshotImporter::new(target, ConflictPolicy::Skip)
+        .import(dir.path())
+        .await
+        .unwrap();
+    report.stored_ids.sort();
+    let mut expected = members.to_vec();
+    expected.sort();
+    assert_eq!(report.stored_ids, expected);
+}
+
+#[tokio::test]
+async fn test_import_conflict_policies() {
+    let (store, ids) = seeded_store(10).await;
+    let dir = TempDir::new().unwrap();
+    export_all(&store, &dir).await;
+
+    // Re-importing into the source store: every ID conflicts
+    let skipped = SnapshotImporter::new(store.clone(), ConflictPolicy::Skip)
+        .import(dir.path())
+        .await
+        .unwrap();
+    assert_eq!(skipped.skipped, 10);
+    assert!(skipped.stored_ids.is_empty());
+    assert_eq!(store.count().await.unwrap(), 10);
+
+    store.store_content(ids[0], "edited locally").await.unwrap();
+    let overwritten = SnapshotImporter::new(store.clone(), ConflictPolicy::Overwrite)
+        .import(dir.path())
+        .await
+        .unwrap();
+    assert_eq!(overwritten.overwritten, 10);
+    assert_eq!(store.count().await.unwrap(), 10);
+    assert_eq!(
+        store.get_content(ids[0]).await.unwrap().as_deref(),
+        Some("snapshot memory 0 about topic 0")
+    );
+
+    let reassigned = SnapshotImporter::new(store.clone(), ConflictPolicy::ReId)
+        .import(dir.path())
+        .await
+        .unwrap();
+    assert_eq!(reassigned.reassigned, 10);
+    assert_eq!(store.count().await.unwrap(), 20);
+    for new_id in &reassigned.stored_ids {
+        assert!(!ids.contains(new_id));
+        let version = store.get_embedding_version(*new_id).await.unwrap().unwrap();
+        assert_eq!(version.fingerprint_id, *new_id);
+    }
+}
+
+#[tokio::test]
+async fn test_export_refuses_existing_snapshot_and_import_detects_corruption() {
+    let (store, _) = seeded_store(5).await;
+    let dir = TempDir::new().unwrap();
+    export_all(&store, &dir).await;
+
+    let again = SnapshotExporter::new(store.clone(), SnapshotFilter::default())
+        .export(dir.path())
+        .await;
+    assert!(again.is_err(), "Export must not overwrite a snapshot");
+
+    // Truncate the record stream
+    let data_path = dir.path().join(SNAPSHOT_DATA_FILE);
+    let bytes = std::fs::read(&data_path).unwrap();
+    std::fs::write(&data_path, &bytes[..bytes.len() - 10]).unwrap();
+
+    let target = Arc::new(InMemoryTeleologicalStore::new());
+    let result = SnapshotImporter::new(target, ConflictPolicy::Skip)
+        .import(dir.path())
+        .await;
+    assert!(result.is_err(), "Truncated snapshot must be rejected");
+}
+
+#[test]
+fn test_conflict_policy_parsing() {
+    assert_eq!(
+        "skip".parse::<ConflictPolicy>().unwrap(),
+        ConflictPolicy::Skip
+    );
+    assert_eq!(
+        "Overwrite".parse::<ConflictPolicy>().unwrap(),
+        ConflictPolicy::Overwrite
+    );
+    assert_eq!(
+        "re-id".parse::<ConflictPolicy>().unwrap(),
+        ConflictPolicy::ReId
+    );
+    assert_eq!(
+        "reid".parse::<ConflictPolicy>().unwrap(),
+        ConflictPolicy::ReId
+    );
+    assert!("merge".parse::<ConflictPolicy>().is_err());
+    assert_eq!(ConflictPolicy::ReId.to_string(), "re-id");
+}
@@ -22,13 +22,21 @@
 //! based on internal state, not hardcoded values.
 
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::RwLock;
+use std::sync::{Mutex, RwLock};
 use std::time::Duration;
 
 use crate::error::CoreResult;
-use crate::traits::{EmbeddingMetadata, MultiArrayEmbeddingOutput, MultiArrayEmbeddingProvider};
-use crate::types::fingerprint::{SemanticFingerprint, SparseVector, NUM_EMBEDDERS};
+use crate::teleological::{Embedder, EmbedderGroup, EmbedderMask};
+use crate::traits::{
+    EmbeddingMetadata, MultiArrayEmbeddingOutput, MultiArrayEmbeddingProvider,
+    PartialMultiArrayOutput,
+};
+use crate::types::fingerprint::{
+    EmbedderOutput, FingerprintBuildError, NormStrictness, SemanticFingerprintBuilder,
+    SparseVector, NUM_EMBEDDERS,
+};
 
 /// Stub implementation of MultiArrayEmbeddingProvider for testing.
 ///
@@ -42,6 +50,8 @@ use crate::types::fingerprint::{SemanticFingerprint, SparseVector, NUM_EMBEDDERS
 /// - Per-embedder health status (all start healthy, can be individually disabled)
 /// - Error state (any fatal errors that would make the provider unusable)
 /// - Embedding count (for diagnostics)
+/// - Per-embedder invocation counts (to verify selective embedding skips models)
+/// - Embedding passes per content (to verify callers reuse query embeddings)
 ///
 /// # Example
 ///
@@ -66,6 +76,12 @@ pub struct StubMultiArrayProvider {
     /// Per-embedder health status (true = healthy, false = unhealthy).
     /// This allows simulating individual embedder failures for testing.
     embedder_health: RwLock<[bool; NUM_EMBEDDERS]>,
+    /// Number of times each embedder was invoked, indexed by `Embedder::index()`.
+    embedder_calls: [AtomicU64; NUM_EMBEDDERS],
+    /// Number of embedding passes per content (see `embed_pass_count`).
+    content_passes: Mutex<HashMap<String, u64>>,
+    /// Number of `embed_batch_all` calls.
+    batch_calls: AtomicU64,
 }
 
 impl Default for StubMultiArrayProvider {
@@ -89,9 +105,36 @@ impl StubMultiArrayProvider {
             fingerprint_count: AtomicU64::new(0),
             last_error: RwLock::new(None),
             embedder_health: RwLock::new([true; NUM_EMBEDDERS]),
+            embedder_calls: std::array::from_fn(|_| AtomicU64::new(0)),
+            content_passes: Mutex::new(HashMap::new()),
+            batch_calls: AtomicU64::new(0),
         }
     }
 
+    /// Get the number of times `embedder` has been invoked.
+    pub fn embedder_call_count(&self, embedder: Embedder) -> u64 {
+        self.embedder_calls[embedder.index()].load(Ordering::Relaxed)
+    }
+
+    /// Get the number of embedding passes run over `content`.
+    ///
+    /// A pass is one provider call that runs at least one content model.
+    /// Calls selecting only the temporal embedders (E2-E4) are not counted:
+    /// those encode the metadata timestamp or sequence, not the text.
+    pub fn embed_pass_count(&self, content: &str) -> u64 {
+        self.content_passes
+            .lock()
+            .ok()
+            .and_then(|passes| passes.get(content).copied())
+            .unwrap_or(0)
+    }
+
+    /// Get the number of `embed_batch_all` calls, however many contents each
+    /// carried.
+    pub fn embed_batch_call_count(&self) -> u64 {
+        self.batch_calls.load(Ordering::Relaxed)
+    }
+
     /// Get the total number of fingerprints generated by this provider.
     pub fn fingerprint_count(&self) -> u64 {
         self.fingerprint_count.load(Ordering::Relaxed)
@@ -264,6 +307,81 @@ impl StubMultiArrayProvider {
     }
 }
 
+impl StubMultiArrayProvider {
+    /// Count a pass over `content` that ran the embedders in `mask`.
+    fn record_pass(&self, content: &str, mask: EmbedderMask) {
+        if EmbedderGroup::Temporal.embedders().contains_all(mask) {
+            return;
+        }
+        if let Ok(mut passes) = self.content_passes.lock() {
+            *passes.entry(content.to_string()).or_insert(0) += 1;
+        }
+    }
+
+    /// Run one (simulated) embedder and return its output.
+    fn embed_space(&self, content: &str, embedder: Embedder) -> EmbedderOutput {
+        self.embedder_calls[embedder.index()].fetch_add(1, Ordering::Relaxed);
+
+        // Each embedder uses a different index to produce distinct vectors
+        match embedder {
+            Embedder::Semantic => {
+                EmbedderOutput::Dense(Self::fill_dense_embedding(content, 1024, 0))
+            }
+            Embedder::TemporalRecent => {
+                EmbedderOutput::Dense(Self::fill_dense_embedding(content, 512, 1))
+            }
+            Embedder::TemporalPeriodic => {
+                EmbedderOutput::Dense(Self::fill_dense_embedding(content, 512, 2))
+            }
+            Embedder::TemporalPositional => {
+                EmbedderOutput::Dense(Self::fill_dense_embedding(content, 512, 3))
+            }
+            // E5: CORE-01 FIX: Generate DISTINCT vectors for asymmetric cause/effect fields.
+            // Using different embedder indices (4 vs 17) produces different dimensional patterns
+            // so that tests can actually verify asymmetric search logic.
+            // Legacy e5_causal is left empty, matching production (dual-vector format).
+            Embedder::Causal => EmbedderOutput::DualDense(
+                Self::fill_dense_embedding(content, 768, 4),
+                Self::fill_dense_embedding(content, 768, 17),
+            ),
+            Embedder::Sparse => EmbedderOutput::Sparse(Self::generate_sparse_vector(content)),
+            Embedder::Code => EmbedderOutput::Dense(Self::fill_dense_embedding(content, 1536, 6)),
+            // E8: CORE-01 FIX: Generate DISTINCT vectors for asymmetric source/target fields.
+            Embedder::Graph => EmbedderOutput::DualDense(
+                Self::fill_dense_embedding(content, 1024, 7),
+                Self::fill_dense_embedding(content, 1024, 19),
+            ),
+            // HDC projected
+            Embedder::Hdc => EmbedderOutput::Dense(Self::fill_dense_embedding(content, 1024, 8)),
+            // E10: CORE-01 FIX: Generate DISTINCT vectors for asymmetric paraphrase/context fields.
+            Embedder::Contextual => EmbedderOutput::DualDense(
+                Self::fill_dense_embedding(content, 768, 9),
+                Self::fill_dense_embedding(content, 768, 21),
+            ),
+            // KEPLER
+            Embedder::Entity => EmbedderOutput::Dense(Self::fill_dense_embedding(content, 768, 10)),
+            Embedder::LateInteraction => {
+                EmbedderOutput::Tokens(Self::generate_token_embeddings(content))
+            }
+            Embedder::KeywordSplade => {
+                EmbedderOutput::Sparse(Self::generate_sparse_vector(content))
+            }
+        }
+    }
+
+    /// Builder for the stub's vectors, which are not unit-norm.
+    fn builder() -> SemanticFingerprintBuilder {
+        SemanticFingerprintBuilder::new().with_norm_strictness(NormStrictness::Ignore)
+    }
+}
+
+fn build_error(e: FingerprintBuildError) -> crate::error::CoreError {
+    crate::error::CoreError::ValidationError {
+        field: "fingerprint".to_string(),
+        message: e.to_string(),
+    }
+}
+
 #[async_trait]
 impl MultiArrayEmbeddingProvider for StubMultiArrayProvider {
     async fn embed_all(&self, content: &str) -> CoreResult<MultiArrayEmbeddingOutput> {
@@ -276,39 +394,13 @@ impl MultiArrayEmbeddingProvider for StubMultiArrayProvider {
         }
 
         // Generate deterministic fingerprint
-        let mut fingerprint = SemanticFingerprint::zeroed();
-
-        // Fill dense embeddings with embedder-specific patterns
-        // Each embedder uses a different index to produce distinct vectors
-        fingerprint.e1_semantic = Self::fill_dense_embedding(content, 1024, 0);
-        fingerprint.e2_temporal_recent = Self::fill_dense_embedding(content, 512, 1);
-        fingerprint.e3_temporal_periodic = Self::fill_dense_embedding(content, 512, 2);
-        fingerprint.e4_temporal_positional = Self::fill_dense_embedding(content, 512, 3);
-        // E5: CORE-01 FIX: Generate DISTINCT vectors for asymmetric cause/effect fields.
-        // Using different embedder indices (4 vs 17) produces different dimensional patterns
-        // so that tests can actually verify asymmetric search logic.
-        fingerprint.e5_causal_as_cause = Self::fill_dense_embedding(content, 768, 4);
-        fingerprint.e5_causal_as_effect = Self::fill_dense_embedding(content, 768, 17);
-        // Legacy field left empty to match production behavior (dual-vector format)
-        // Production provider does NOT populate e5_causal for new-format fingerprints.
-        fingerprint.e7_code = Self::fill_dense_embedding(content, 1536, 6);
-        // E8: CORE-01 FIX: Generate DISTINCT vectors for asymmetric source/target fields.
-        fingerprint.e8_graph_as_source = Self::fill_dense_embedding(content, 1024, 7);
-        fingerprint.e8_graph_as_target = Self::fill_dense_embedding(content, 1024, 19);
-        // Legacy field uses source vector for backward compatibility
-        fingerprint.e8_graph = fingerprint.e8_graph_as_source.clone();
-        fingerprint.e9_hdc = Self::fill_dense_embedding(content, 1024, 8); // HDC projected
-        // E10: CORE-01 FIX: Generate DISTINCT vectors for asymmetric paraphrase/context fields.
-        fingerprint.e10_multimodal_paraphrase = Self::fill_dense_embedding(content, 768, 9);
-        fingerprint.e10_multimodal_as_context = Self::fill_dense_embedding(content, 768, 21);
-        fingerprint.e11_entity = Self::fill_dense_embedding(content, 768, 10); // KEPLER
-
-        // Fill sparse embeddings
-        fingerprint.e6_sparse = Self::generate_sparse_vector(content);
-        fingerprint.e13_splade = Self::generate_sparse_vector(content);
-
-        // Fill token-level embeddings
-        fingerprint.e12_late_interaction = Self::generate_token_embeddings(content);
+        self.record_pass(content, EmbedderMask::all());
+        let fingerprint = Embedder::all()
+            .fold(Self::builder(), |builder, embedder| {
+                builder.set(embedder, self.embed_space(content, embedder))
+            })
+            .build()
+            .map_err(build_error)?;
 
         // Track successful fingerprint generation
         self.fingerprint_count.fetch_add(1, Ordering::Relaxed);
@@ -332,6 +424,49 @@ impl MultiArrayEmbeddingProvider for StubMultiArrayProvider {
         })
     }
 
+    /// Run only the selected embedders; unselected ones are never invoked.
+    async fn embed_selective(
+        &self,
+        content: &str,
+        mask: EmbedderMask,
+    ) -> CoreResult<PartialMultiArrayOutput> {
+        if !self.is_ready() {
+            tracing::error!(
+                "StubMultiArrayProvider: embed_selective called but provider is not ready"
+            );
+            return Err(crate::error::CoreError::Internal(
+                "StubMultiArrayProvider is not ready".into(),
+            ));
+        }
+
+        self.record_pass(content, mask);
+        let mut builder = Self::builder();
+        let mut per_embedder_latency = [Duration::ZERO; NUM_EMBEDDERS];
+        for embedder in mask.iter() {
+            builder = builder.set(embedder, self.embed_space(content, embedder));
+            per_embedder_latency[embedder.index()] = Duration::from_millis(5);
+        }
+        let (fingerprint, _) = builder.build_partial().map_err(build_error)?;
+
+        Ok(PartialMultiArrayOutput {
+            mask,
+            fingerprint,
+            total_latency: Duration::from_millis(5 * mask.count() as u64),
+            per_embedder_latency,
+            model_ids: core::array::from_fn(|i| format!("stub-e{}", i + 1)),
+        })
+    }
+
+    /// Metadata is ignored, as in `embed_all_with_metadata`.
+    async fn embed_selective_with_metadata(
+        &self,
+        content: &str,
+        mask: EmbedderMask,
+        _metadata: EmbeddingMetadata,
+    ) -> CoreResult<PartialMultiArrayOutput> {
+        self.embed_selective(content, mask).await
+    }
+
     async fn embed_batch_all(
         &self,
         contents: &[String],
@@ -346,6 +481,7 @@ impl MultiArrayEmbeddingProvider for StubMultiArrayProvider {
                 "StubMultiArrayProvider is not ready".into(),
             ));
         }
+        self.batch_calls.fetch_add(1, Ordering::Relaxed);
 
         let mut results = Vec::with_capacity(contents.len());
         for content in contents {
@@ -427,6 +563,7 @@ impl MultiArrayEmbeddingProvider for StubMultiArrayProvider {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::fingerprint::EmbeddingState;
 
     /// Test that a properly initialized provider is ready.
     #[test]
@@ -563,7 +700,7 @@ mod tests {
         assert_eq!(fp.e5_causal_as_cause.len(), 768);
         assert_eq!(fp.e5_causal_as_effect.len(), 768);
         assert_eq!(fp.e7_code.len(), 1536);
-        assert_eq!(fp.e8_graph.len(), 1024); // Upgraded from 384D
+        assert!(fp.e8_graph.is_empty()); // Legacy field empty, as in production
         assert_eq!(fp.e9_hdc.len(), 1024); // HDC projected
         assert_eq!(fp.e10_multimodal_paraphrase.len(), 768);
         assert_eq!(fp.e11_entity.len(), 768); // KEPLER
@@ -616,6 +753,8 @@ mod tests {
 
         let outputs = provider.embed_batch_all(&contents, &[]).await.unwrap();
         assert_eq!(outputs.len(), 3);
+        assert_eq!(provider.embed_batch_call_count(), 1);
+        assert_eq!(provider.embed_pass_count("second content"), 1);
 
         // Each output should have valid fingerprint
         for output in &outputs {
@@ -735,4 +874,94 @@ mod tests {
         let healthy_count = health.iter().filter(|&&h| h).count();
         assert_eq!(healthy_count, NUM_EMBEDDERS - 1);
     }
+
+    /// Test that embed_selective runs only the selected embedders.
+    #[tokio::test]
+    async fn test_embed_selective_skips_unselected_embedders() {
+        let provider = StubMultiArrayProvider::new();
+        let mask = EmbedderMask::from_slice(&[Embedder::Semantic, Embedder::Causal]);
+
+        let output = provider.embed_selective("drift check", mask).await.unwrap();
+
+        for embedder in Embedder::all() {
+            let selected = mask.contains(embedder);
+            assert_eq!(
+                provider.embedder_call_count(embedder),
+                u64::from(selected),
+                "{:?} call count",
+                embedder
+            );
+            assert_eq!(
+                output.per_embedder_latency[embedder.index()].is_zero(),
+                !selected
+            );
+        }
+        assert_eq!(output.fingerprint.e1_semantic.len(), 1024);
+        assert_eq!(output.fingerprint.e5_causal_as_cause.len(), 768);
+        assert!(
+            output.fingerprint.e7_code.is_empty(),
+            "Unselected space must be empty, not zeros"
+        );
+        assert!(output.fingerprint.e12_late_interaction.is_empty());
+        assert!(output.fingerprint.e13_splade.is_empty());
+
+        let fingerprint = output.into_fingerprint([0u8; 32]);
+        let not_computed = Embedder::all()
+            .filter(|e| fingerprint.embedding_state(*e) == EmbeddingState::NotComputed)
+            .count();
+        assert_eq!(not_computed, 11);
+        assert_eq!(fingerprint.computed_mask(), mask);
+        assert!(!fingerprint.is_fully_computed());
+    }
+
+    /// Test that embed_selective matches embed_all on the selected spaces.
+    #[tokio::test]
+    async fn test_embed_selective_matches_embed_all() {
+        let provider = StubMultiArrayProvider::new();
+        let full = provider.embed_all("same content").await.unwrap();
+        let partial = provider
+            .embed_selective("same content", EmbedderMask::from_slice(&[Embedder::Graph]))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            partial.fingerprint.e8_graph_as_source,
+            full.fingerprint.e8_graph_as_source
+        );
+        assert_eq!(
+            partial.fingerprint.e8_graph_as_target,
+            full.fingerprint.e8_graph_as_target
+        );
+        assert!(partial.fingerprint.e1_semantic.is_empty());
+    }
+
+    /// Test that passes are counted per content, temporal-only passes excluded.
+    #[tokio::test]
+    async fn test_embed_pass_count() {
+        let provider = StubMultiArrayProvider::new();
+        provider.embed_all("alpha").await.unwrap();
+        provider
+            .embed_selective("alpha", EmbedderMask::from_slice(&[Embedder::Semantic]))
+            .await
+            .unwrap();
+        provider
+            .embed_selective("alpha", EmbedderGroup::Temporal.embedders())
+            .await
+            .unwrap();
+        provider.embed_all("beta").await.unwrap();
+
+        assert_eq!(provider.embed_pass_count("alpha"), 2);
+        assert_eq!(provider.embed_pass_count("beta"), 1);
+        assert_eq!(provider.embed_pass_count("gamma"), 0);
+    }
+
+    /// Test that embed_selective fails when the provider is not ready.
+    #[tokio::test]
+    async fn test_embed_selective_fails_when_not_ready() {
+        let provider = StubMultiArrayProvider::new();
+        provider.set_error("down".to_string());
+        let result = provider.embed_selective("x", EmbedderMask::all()).await;
+        assert!(result.is_err());
+        assert_eq!(provider.embedder_call_count(Embedder::Semantic), 0);
+    }
 }
@@ -45,13 +45,17 @@ mod tests;
 mod trait_impl;
 
 use std::sync::atomic::AtomicUsize;
+#[cfg(any(test, feature = "test-utils"))]
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use dashmap::DashMap;
 use tracing::info;
 use uuid::Uuid;
 
-use crate::clustering::PersistedTopicPortfolio;
+use crate::clustering::{PersistedTopicPortfolio, TopicRecord};
+use crate::retrieval::PipelineMetrics;
 use crate::traits::TeleologicalStorageBackend;
+use crate::types::audit::EmbeddingVersionRecord;
 use crate::types::fingerprint::TeleologicalFingerprint;
 use crate::types::{CausalRelationship, SourceMetadata};
 
@@ -72,14 +76,25 @@ pub struct InMemoryTeleologicalStore {
     pub(crate) source_metadata: DashMap<Uuid, SourceMetadata>,
     /// Topic portfolio storage: session_id -> PersistedTopicPortfolio
     pub(crate) topic_portfolios: DashMap<String, PersistedTopicPortfolio>,
+    /// Topic records: topic_id -> TopicRecord
+    pub(crate) topic_records: DashMap<Uuid, TopicRecord>,
     /// Causal relationships storage: causal_id -> CausalRelationship
     pub(crate) causal_relationships: DashMap<Uuid, CausalRelationship>,
     /// Causal by source index: source_fingerprint_id -> Vec<causal_id>
     pub(crate) causal_by_source: DashMap<Uuid, Vec<Uuid>>,
     /// File index: file_path -> Vec<fingerprint_id> (proper implementation, not no-op)
     pub(crate) file_index: DashMap<String, Vec<Uuid>>,
+    /// Embedding version registry: fingerprint_id -> EmbeddingVersionRecord
+    pub(crate) embedding_versions: DashMap<Uuid, EmbeddingVersionRecord>,
+    /// Processing cursors: key -> serialized cursor bytes
+    pub(crate) processing_cursors: DashMap<String, Vec<u8>>,
     /// Running size estimate in bytes
     pub(crate) size_bytes: AtomicUsize,
+    /// Per-stage latency of semantic searches
+    pub(crate) pipeline_metrics: PipelineMetrics,
+    /// When set, `retrieve_batch` fails (see `set_fail_batch_retrieval`)
+    #[cfg(any(test, feature = "test-utils"))]
+    pub(crate) fail_batch_retrieval: AtomicBool,
 }
 
 impl InMemoryTeleologicalStore {
@@ -92,10 +107,16 @@ impl InMemoryTeleologicalStore {
             content: DashMap::new(),
             source_metadata: DashMap::new(),
             topic_portfolios: DashMap::new(),
+            topic_records: DashMap::new(),
             causal_relationships: DashMap::new(),
             causal_by_source: DashMap::new(),
             file_index: DashMap::new(),
+            embedding_versions: DashMap::new(),
+            processing_cursors: DashMap::new(),
             size_bytes: AtomicUsize::new(0),
+            pipeline_metrics: PipelineMetrics::new(),
+            #[cfg(any(test, feature = "test-utils"))]
+            fail_batch_retrieval: AtomicBool::new(false),
         }
     }
 
@@ -111,10 +132,16 @@ impl InMemoryTeleologicalStore {
             content: DashMap::with_capacity(capacity),
             source_metadata: DashMap::with_capacity(capacity),
             topic_portfolios: DashMap::new(),
+            topic_records: DashMap::new(),
             causal_relationships: DashMap::new(),
             causal_by_source: DashMap::new(),
             file_index: DashMap::new(),
+            embedding_versions: DashMap::new(),
+            processing_cursors: DashMap::new(),
             size_bytes: AtomicUsize::new(0),
+            pipeline_metrics: PipelineMetrics::new(),
+            #[cfg(any(test, feature = "test-utils"))]
+            fail_batch_retrieval: AtomicBool::new(false),
         }
     }
 
@@ -130,6 +157,13 @@ impl InMemoryTeleologicalStore {
     pub fn backend_type(&self) -> TeleologicalStorageBackend {
         TeleologicalStorageBackend::InMemory
     }
+
+    /// Make `retrieve_batch` fail with a storage error, as a multi-get does
+    /// when one key cannot be read. Single `retrieve` calls keep working.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn set_fail_batch_retrieval(&self, fail: bool) {
+        self.fail_batch_retrieval.store(fail, Ordering::Relaxed);
+    }
 }
 
 impl Default for InMemoryTeleologicalStore {
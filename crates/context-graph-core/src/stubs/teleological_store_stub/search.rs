@@ -5,14 +5,16 @@
 
 use std::collections::HashSet;
 
+use chrono::Utc;
 use tracing::{debug, error};
 use uuid::Uuid;
 
 use super::similarity::compute_semantic_scores;
 use super::InMemoryTeleologicalStore;
 use crate::error::{CoreError, CoreResult};
+use crate::retrieval::{PipelineStage, PipelineTimings, StageSpan};
 use crate::traits::{TeleologicalSearchOptions, TeleologicalSearchResult};
-use crate::types::fingerprint::{SemanticFingerprint, SparseVector};
+use crate::types::fingerprint::{SemanticFingerprint, SparseVector, TeleologicalFingerprint};
 
 impl InMemoryTeleologicalStore {
     /// Search by semantic fingerprint similarity.
@@ -21,24 +23,60 @@ impl InMemoryTeleologicalStore {
         query: &SemanticFingerprint,
         options: TeleologicalSearchOptions,
     ) -> CoreResult<Vec<TeleologicalSearchResult>> {
+        let (results, _) = self.search_semantic_timed_impl(query, options).await?;
+        Ok(results)
+    }
+
+    /// Search by semantic fingerprint similarity, timing each pipeline stage.
+    ///
+    /// Every stage is a full scan here. The stub has no Matryoshka prefilter
+    /// or MaxSim rerank, so those stages pass candidates through unchanged;
+    /// they are still timed so the timings are complete.
+    pub async fn search_semantic_timed_impl(
+        &self,
+        query: &SemanticFingerprint,
+        options: TeleologicalSearchOptions,
+    ) -> CoreResult<(Vec<TeleologicalSearchResult>, PipelineTimings)> {
         debug!(
             "Semantic search with top_k={}, min_similarity={}",
             options.top_k, options.min_similarity
         );
+        let mut timings = PipelineTimings::new();
 
-        let mut results: Vec<TeleologicalSearchResult> = Vec::new();
+        // Recall: every live, unexpired fingerprint in an admitted namespace
+        let stage = StageSpan::start(PipelineStage::SpladeRecall, self.data.len());
         let deleted_ids: HashSet<Uuid> = self.deleted.iter().map(|r| *r.key()).collect();
-
-        for entry in self.data.iter() {
-            let id = *entry.key();
-            let fp = entry.value();
-
-            if !options.include_deleted && deleted_ids.contains(&id) {
-                continue;
-            }
-
-            let embedder_scores = compute_semantic_scores(query, &fp.semantic);
-
+        let now = Utc::now();
+        let candidates: Vec<Uuid> = self
+            .data
+            .iter()
+            .filter(|entry| options.include_deleted || !deleted_ids.contains(entry.key()))
+            .filter(|entry| !entry.value().is_expired_at(now))
+            .filter(|entry| options.admits_namespace(&entry.value().namespace))
+            .map(|entry| *entry.key())
+            .collect();
+        stage.finish(candidates.len(), &mut timings);
+
+        let stage = StageSpan::start(PipelineStage::MatryoshkaFilter, candidates.len());
+        stage.finish(candidates.len(), &mut timings);
+
+        let stage = StageSpan::start(PipelineStage::HnswSearch, candidates.len());
+        let scored: Vec<(TeleologicalFingerprint, [f32; 13])> = candidates
+            .iter()
+            .filter_map(|id| self.data.get(id))
+            .map(|fp| {
+                let embedder_scores = compute_semantic_scores(query, &fp.semantic);
+                (fp.value().clone(), embedder_scores)
+            })
+            .collect();
+        stage.finish(scored.len(), &mut timings);
+
+        let stage = StageSpan::start(PipelineStage::MaxsimRerank, scored.len());
+        stage.finish(scored.len(), &mut timings);
+
+        let stage = StageSpan::start(PipelineStage::Fusion, scored.len());
+        let mut results: Vec<TeleologicalSearchResult> = Vec::new();
+        for (fp, embedder_scores) in scored {
             let active_scores: Vec<f32> = if options.embedder_indices.is_empty() {
                 embedder_scores.to_vec()
             } else {
@@ -59,7 +97,11 @@ impl InMemoryTeleologicalStore {
                 continue;
             }
 
-            results.push(TeleologicalSearchResult::new(fp.clone(), similarity, embedder_scores));
+            results.push(TeleologicalSearchResult::new(
+                fp,
+                similarity,
+                embedder_scores,
+            ));
         }
 
         results.sort_by(|a, b| {
@@ -69,8 +111,15 @@ impl InMemoryTeleologicalStore {
         });
 
         results.truncate(options.top_k);
-        debug!("Semantic search returned {} results", results.len());
-        Ok(results)
+        stage.finish(results.len(), &mut timings);
+
+        self.pipeline_metrics.record(&timings);
+        debug!(
+            "Semantic search returned {} results in {:?}",
+            results.len(),
+            timings.total()
+        );
+        Ok((results, timings))
     }
 
     /// Search by text query - NOT IMPLEMENTED.
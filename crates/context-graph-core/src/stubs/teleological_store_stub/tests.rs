@@ -33,6 +33,31 @@ async fn test_retrieve_nonexistent() {
     assert!(result.is_none());
 }
 
+#[tokio::test]
+async fn test_retrieve_partial_matches_full_retrieve() {
+    use crate::teleological::{Embedder, EmbedderMask};
+    use crate::types::fingerprint::EmbeddingSlice;
+
+    let store = InMemoryTeleologicalStore::new();
+    let fp = TeleologicalFingerprint::new(SemanticFingerprint::stub(), [3u8; 32]);
+    let id = store.store(fp).await.unwrap();
+
+    let mask = EmbedderMask::from_slice(&[Embedder::Semantic, Embedder::Code]);
+    let partial = store.retrieve_partial(id, mask).await.unwrap().unwrap();
+    let full = store.retrieve(id).await.unwrap().unwrap();
+
+    for idx in [Embedder::Semantic.index(), Embedder::Code.index()] {
+        match (partial.get_embedding(idx), full.semantic.get_embedding(idx)) {
+            (Some(EmbeddingSlice::Dense(p)), Some(EmbeddingSlice::Dense(f))) => assert_eq!(p, f),
+            other => panic!("expected dense slices for {}, got {:?}", idx, other),
+        }
+    }
+    assert!(partial.get_embedding(Embedder::Entity.index()).is_none());
+
+    store.delete(id, true).await.unwrap();
+    assert!(store.retrieve_partial(id, mask).await.unwrap().is_none());
+}
+
 #[tokio::test]
 async fn test_update() {
     let store = InMemoryTeleologicalStore::new();
@@ -93,6 +118,40 @@ async fn test_search_semantic() {
     assert!(results.len() <= 5);
 }
 
+#[tokio::test]
+async fn test_search_semantic_timed_populates_pipeline_metrics() {
+    use crate::retrieval::PipelineStage;
+
+    let store = InMemoryTeleologicalStore::new();
+    for _ in 0..5 {
+        store.store(create_test_fingerprint()).await.unwrap();
+    }
+    let query = SemanticFingerprint::zeroed();
+
+    for _ in 0..100 {
+        let options = TeleologicalSearchOptions::quick(3);
+        let (results, timings) = store.search_semantic_timed(&query, options).await.unwrap();
+        assert!(
+            timings.is_complete(),
+            "every stage must be timed: {:?}",
+            timings
+        );
+        let recall = timings.get(PipelineStage::SpladeRecall).unwrap();
+        assert_eq!(recall.candidates_in, 5);
+        assert_eq!(recall.candidates_out, 5);
+        let fusion = timings.get(PipelineStage::Fusion).unwrap();
+        assert_eq!(fusion.candidates_out, results.len());
+    }
+
+    let metrics = store.pipeline_metrics();
+    assert_eq!(metrics.searches, 100);
+    for stage in PipelineStage::ALL {
+        let summary = metrics.stage(stage).unwrap();
+        assert_eq!(summary.count, 100, "{}", stage.name());
+        assert!(summary.p50_us <= summary.p95_us && summary.p95_us <= summary.p99_us);
+    }
+}
+
 #[tokio::test]
 async fn test_batch_store_and_retrieve() {
     let store = InMemoryTeleologicalStore::new();
@@ -105,6 +164,108 @@ async fn test_batch_store_and_retrieve() {
     assert!(retrieved.iter().all(|r| r.is_some()));
 }
 
+#[tokio::test]
+async fn test_retrieve_batch_duplicates_missing_and_empty() {
+    let store = InMemoryTeleologicalStore::new();
+    let a = create_test_fingerprint();
+    let b = create_test_fingerprint();
+    let (a_id, b_id) = (a.id, b.id);
+    store.store(a).await.unwrap();
+    store.store(b).await.unwrap();
+    store.delete(b_id, true).await.unwrap();
+
+    let missing = Uuid::new_v4();
+    let ids = [a_id, missing, a_id, b_id];
+    let retrieved = store.retrieve_batch(&ids).await.unwrap();
+    assert_eq!(retrieved.len(), 4);
+    assert_eq!(retrieved[0].as_ref().map(|fp| fp.id), Some(a_id));
+    assert!(retrieved[1].is_none());
+    assert_eq!(retrieved[2].as_ref().map(|fp| fp.id), Some(a_id));
+    assert!(retrieved[3].is_none(), "soft-deleted ids must be None");
+
+    assert!(store.retrieve_batch(&[]).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_find_by_content_hash() {
+    let store = InMemoryTeleologicalStore::new();
+    let fp = TeleologicalFingerprint::new(SemanticFingerprint::zeroed(), [9u8; 32]);
+    let id = store.store(fp).await.unwrap();
+    store.store(create_test_fingerprint()).await.unwrap();
+
+    assert_eq!(store.find_by_content_hash(&[9u8; 32]).await.unwrap(), vec![id]);
+    assert!(store.find_by_content_hash(&[1u8; 32]).await.unwrap().is_empty());
+
+    store.delete(id, true).await.unwrap();
+    assert!(store.find_by_content_hash(&[9u8; 32]).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_namespace_search_isolation() {
+    let store = InMemoryTeleologicalStore::new();
+    let a = store
+        .store(create_test_fingerprint().with_namespace("project-a"))
+        .await
+        .unwrap();
+    let b = store
+        .store(create_test_fingerprint().with_namespace("project-b"))
+        .await
+        .unwrap();
+
+    let query = SemanticFingerprint::zeroed();
+    let results = store
+        .search_semantic(&query, TeleologicalSearchOptions::quick(10).with_namespace("project-a"))
+        .await
+        .unwrap();
+    assert_eq!(results.iter().map(|r| r.fingerprint.id).collect::<Vec<_>>(), vec![a]);
+
+    let all = store
+        .search_semantic(&query, TeleologicalSearchOptions::quick(10))
+        .await
+        .unwrap();
+    assert!(all.iter().any(|r| r.fingerprint.id == b));
+
+    let counts = store.count_by_namespace().await.unwrap();
+    assert_eq!(counts.get("project-a"), Some(&1));
+    assert_eq!(counts.get("project-b"), Some(&1));
+}
+
+#[tokio::test]
+async fn test_staged_namespace_requires_opt_in() {
+    let store = InMemoryTeleologicalStore::new();
+    let staging = TeleologicalFingerprint::staging_namespace("session/42");
+    assert_eq!(staging, "staged.session_42");
+    let staged = store
+        .store(create_test_fingerprint().with_namespace(staging.clone()))
+        .await
+        .unwrap();
+    let permanent = store.store(create_test_fingerprint()).await.unwrap();
+
+    let query = SemanticFingerprint::zeroed();
+    let all = store
+        .search_semantic(&query, TeleologicalSearchOptions::quick(10))
+        .await
+        .unwrap();
+    assert_eq!(
+        all.iter().map(|r| r.fingerprint.id).collect::<Vec<_>>(),
+        vec![permanent]
+    );
+
+    let opted_in = store
+        .search_semantic(
+            &query,
+            TeleologicalSearchOptions::quick(10).with_namespace(staging.clone()),
+        )
+        .await
+        .unwrap();
+    let opted_in_ids: Vec<_> = opted_in.iter().map(|r| r.fingerprint.id).collect();
+    assert_eq!(opted_in_ids, vec![staged]);
+    assert_eq!(
+        store.list_namespace_ids(&staging).await.unwrap(),
+        vec![staged]
+    );
+}
+
 #[tokio::test]
 async fn test_empty_store_count() {
     let store = InMemoryTeleologicalStore::new();
@@ -4,20 +4,26 @@
 //! the various impl methods in other submodules.
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::Ordering;
 
 use async_trait::async_trait;
+use chrono::Utc;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 use super::InMemoryTeleologicalStore;
 use crate::error::{CoreError, CoreResult};
+use crate::retrieval::{PipelineMetricsSnapshot, PipelineTimings};
 use crate::traits::{
     TeleologicalMemoryStore, TeleologicalSearchOptions, TeleologicalSearchResult,
     TeleologicalStorageBackend,
 };
-use crate::types::fingerprint::{SemanticFingerprint, SparseVector, TeleologicalFingerprint};
+use crate::teleological::EmbedderMask;
+use crate::types::fingerprint::{
+    PartialFingerprint, SemanticFingerprint, SparseVector, TeleologicalFingerprint,
+};
 use crate::types::SourceMetadata;
 
 #[async_trait]
@@ -36,7 +42,28 @@ impl TeleologicalMemoryStore for InMemoryTeleologicalStore {
             debug!("Fingerprint {} is soft-deleted", id);
             return Ok(None);
         }
-        Ok(self.data.get(&id).map(|r| r.clone()))
+        let now = Utc::now();
+        Ok(self
+            .data
+            .get(&id)
+            .filter(|r| !r.is_expired_at(now))
+            .map(|r| r.clone()))
+    }
+
+    async fn retrieve_partial(
+        &self,
+        id: Uuid,
+        mask: EmbedderMask,
+    ) -> CoreResult<Option<PartialFingerprint>> {
+        if self.deleted.contains_key(&id) {
+            debug!("Fingerprint {} is soft-deleted", id);
+            return Ok(None);
+        }
+        // Project under the map guard so the full fingerprint is never cloned
+        Ok(self
+            .data
+            .get(&id)
+            .map(|r| PartialFingerprint::from_full(&r, mask)))
     }
 
     async fn update(&self, fingerprint: TeleologicalFingerprint) -> CoreResult<bool> {
@@ -80,6 +107,7 @@ impl TeleologicalMemoryStore for InMemoryTeleologicalStore {
             self.content.remove(&id);
             debug!("Hard-deleted fingerprint {} (content also removed)", id);
         }
+        self.replace_topic_members(&[id], None).await?;
         Ok(true)
     }
 
@@ -91,6 +119,18 @@ impl TeleologicalMemoryStore for InMemoryTeleologicalStore {
         self.search_semantic_impl(query, options).await
     }
 
+    async fn search_semantic_timed(
+        &self,
+        query: &SemanticFingerprint,
+        options: TeleologicalSearchOptions,
+    ) -> CoreResult<(Vec<TeleologicalSearchResult>, PipelineTimings)> {
+        self.search_semantic_timed_impl(query, options).await
+    }
+
+    fn pipeline_metrics(&self) -> PipelineMetricsSnapshot {
+        self.pipeline_metrics.snapshot()
+    }
+
     async fn search_text(
         &self,
         text: &str,
@@ -133,11 +173,27 @@ impl TeleologicalMemoryStore for InMemoryTeleologicalStore {
         ids: &[Uuid],
     ) -> CoreResult<Vec<Option<TeleologicalFingerprint>>> {
         debug!("Batch retrieving {} fingerprints", ids.len());
-        let mut results = Vec::with_capacity(ids.len());
-        for id in ids {
-            results.push(self.retrieve(*id).await?);
+        #[cfg(any(test, feature = "test-utils"))]
+        if self.fail_batch_retrieval.load(Ordering::Relaxed) {
+            return Err(CoreError::StorageError(
+                "batch retrieval failed (injected)".to_string(),
+            ));
         }
-        Ok(results)
+        // Single synchronous pass over the map, no per-id await
+        let now = Utc::now();
+        Ok(ids
+            .iter()
+            .map(|id| {
+                if self.deleted.contains_key(id) {
+                    None
+                } else {
+                    self.data
+                        .get(id)
+                        .filter(|r| !r.is_expired_at(now))
+                        .map(|r| r.clone())
+                }
+            })
+            .collect())
     }
 
     async fn count(&self) -> CoreResult<usize> {
@@ -243,6 +299,38 @@ impl TeleologicalMemoryStore for InMemoryTeleologicalStore {
         Ok(matching_ids)
     }
 
+    async fn find_by_content_hash(&self, content_hash: &[u8; 32]) -> CoreResult<Vec<Uuid>> {
+        // O(n) scan - acceptable for the in-memory test stub
+        let matching_ids: Vec<Uuid> = self
+            .data
+            .iter()
+            .filter(|entry| &entry.value().content_hash == content_hash)
+            .filter(|entry| !self.deleted.contains_key(entry.key()))
+            .map(|entry| *entry.key())
+            .collect();
+        Ok(matching_ids)
+    }
+
+    async fn count_by_namespace(&self) -> CoreResult<HashMap<String, usize>> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for entry in self.data.iter() {
+            if !self.deleted.contains_key(entry.key()) {
+                *counts.entry(entry.value().namespace.clone()).or_insert(0) += 1;
+            }
+        }
+        Ok(counts)
+    }
+
+    async fn list_namespace_ids(&self, namespace: &str) -> CoreResult<Vec<Uuid>> {
+        Ok(self
+            .data
+            .iter()
+            .filter(|entry| entry.value().namespace == namespace)
+            .filter(|entry| !self.deleted.contains_key(entry.key()))
+            .map(|entry| *entry.key())
+            .collect())
+    }
+
     // ==================== File Index Storage ====================
     // In-memory stub implementation uses source_metadata scanning as fallback
 
@@ -421,6 +509,40 @@ impl TeleologicalMemoryStore for InMemoryTeleologicalStore {
         Ok(self.topic_portfolios.get("__latest__").map(|r| r.clone()))
     }
 
+    async fn list_topic_records(&self) -> CoreResult<Vec<crate::clustering::TopicRecord>> {
+        Ok(self.topic_records.iter().map(|r| r.value().clone()).collect())
+    }
+
+    async fn apply_topic_reconciliation(
+        &self,
+        reconciliation: &crate::clustering::TopicReconciliation,
+    ) -> CoreResult<()> {
+        for id in &reconciliation.retired {
+            self.topic_records.remove(id);
+        }
+        for record in &reconciliation.upserts {
+            self.topic_records.insert(record.id, record.clone());
+        }
+        Ok(())
+    }
+
+    async fn replace_topic_members(
+        &self,
+        removed: &[Uuid],
+        replacement: Option<Uuid>,
+    ) -> CoreResult<usize> {
+        let removed: std::collections::HashSet<Uuid> = removed.iter().copied().collect();
+        let now = Utc::now();
+        let mut changed = 0;
+        self.topic_records.retain(|_, record| {
+            if record.replace_members(&removed, replacement, now) {
+                changed += 1;
+            }
+            record.member_count() > 0
+        });
+        Ok(changed)
+    }
+
     async fn scan_fingerprints_for_clustering(
         &self,
         limit: Option<usize>,
@@ -932,6 +1054,44 @@ impl TeleologicalMemoryStore for InMemoryTeleologicalStore {
         Ok(Vec::new())
     }
 
+    // ==================== Memory Lineage (Stubs) ====================
+
+    async fn append_lineage_record(&self, _record: &crate::types::audit::LineageRecord) -> CoreResult<()> {
+        // Test stub: no-op
+        Ok(())
+    }
+
+    async fn get_lineage_records(
+        &self,
+        _child_id: Uuid,
+    ) -> CoreResult<Vec<crate::types::audit::LineageRecord>> {
+        // Test stub: always returns empty
+        Ok(Vec::new())
+    }
+
+    // ==================== Tool Execution Audit (Stubs) ====================
+
+    async fn append_tool_audit_record(
+        &self,
+        _record: &crate::types::audit::ToolAuditRecord,
+    ) -> CoreResult<()> {
+        // Test stub: no-op
+        Ok(())
+    }
+
+    async fn query_tool_audit_log(
+        &self,
+        _query: &crate::types::audit::ToolAuditQuery,
+    ) -> CoreResult<crate::types::audit::ToolAuditPage> {
+        // Test stub: always returns an empty page
+        Ok(crate::types::audit::ToolAuditPage::default())
+    }
+
+    async fn prune_tool_audit_log(&self, _before: chrono::DateTime<chrono::Utc>) -> CoreResult<usize> {
+        // Test stub: nothing to prune
+        Ok(0)
+    }
+
     // ==================== Importance History (Phase 4 Stubs) ====================
 
     async fn append_importance_change(&self, _record: &crate::types::audit::ImportanceChangeRecord) -> CoreResult<()> {
@@ -950,17 +1110,20 @@ impl TeleologicalMemoryStore for InMemoryTeleologicalStore {
 
     // ==================== Embedding Version Registry (Phase 6 Stubs) ====================
 
-    async fn store_embedding_version(&self, _record: &crate::types::audit::EmbeddingVersionRecord) -> CoreResult<()> {
-        // Test stub: no-op
+    async fn store_embedding_version(&self, record: &crate::types::audit::EmbeddingVersionRecord) -> CoreResult<()> {
+        self.embedding_versions
+            .insert(record.fingerprint_id, record.clone());
         Ok(())
     }
 
     async fn get_embedding_version(
         &self,
-        _fingerprint_id: Uuid,
+        fingerprint_id: Uuid,
     ) -> CoreResult<Option<crate::types::audit::EmbeddingVersionRecord>> {
-        // Test stub: always returns None
-        Ok(None)
+        Ok(self
+            .embedding_versions
+            .get(&fingerprint_id)
+            .map(|r| r.clone()))
     }
 
     // ==================== Custom Weight Profile Persistence (Stubs) ====================
@@ -987,16 +1150,13 @@ impl TeleologicalMemoryStore for InMemoryTeleologicalStore {
 
     // ==================== Processing Cursor Persistence ====================
 
-    async fn store_processing_cursor(&self, _key: &str, _data: &[u8]) -> CoreResult<()> {
-        Err(CoreError::NotImplemented(
-            "InMemoryTeleologicalStore does not support processing cursors. Use RocksDB.".to_string(),
-        ))
+    async fn store_processing_cursor(&self, key: &str, data: &[u8]) -> CoreResult<()> {
+        self.processing_cursors.insert(key.to_string(), data.to_vec());
+        Ok(())
     }
 
-    async fn get_processing_cursor(&self, _key: &str) -> CoreResult<Option<Vec<u8>>> {
-        Err(CoreError::NotImplemented(
-            "InMemoryTeleologicalStore does not support processing cursors. Use RocksDB.".to_string(),
-        ))
+    async fn get_processing_cursor(&self, key: &str) -> CoreResult<Option<Vec<u8>>> {
+        Ok(self.processing_cursors.get(key).map(|r| r.clone()))
     }
 
     // ==================== Type Downcasting ====================
@@ -373,6 +373,12 @@ impl EmbedderMask {
         (self.0 & (1 << embedder.index())) != 0
     }
 
+    /// Check if every embedder in `other` is also set in this mask.
+    #[inline]
+    pub fn contains_all(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
     /// Iterate over all embedders that are set in this mask.
     pub fn iter(self) -> impl Iterator<Item = Embedder> {
         Embedder::all().filter(move |&e| self.contains(e))
@@ -554,6 +560,16 @@ mod tests {
         println!("[PASS] EmbedderMask::all() contains all 13 embedders");
     }
 
+    #[test]
+    fn test_embedder_mask_contains_all() {
+        let subset = EmbedderMask::from_slice(&[Embedder::Semantic, Embedder::Code]);
+        assert!(EmbedderMask::all().contains_all(subset));
+        assert!(subset.contains_all(subset));
+        assert!(subset.contains_all(EmbedderMask::new()));
+        assert!(!subset.contains_all(EmbedderMask::all()));
+        assert!(!subset.contains_all(EmbedderMask::from_slice(&[Embedder::Causal])));
+    }
+
     #[test]
     fn test_embedder_mask_iter() {
         let mask =
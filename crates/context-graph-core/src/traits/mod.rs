@@ -19,19 +19,27 @@ mod teleological_memory_store_tests;
 // CAUSAL-HINT: Added CausalHint and CausalDirectionHint for E5 enhancement
 // MULTI-REL: Added ExtractedCausalRelationship, MechanismType, MultiRelationshipResult
 pub use multi_array_embedding::{
-    CausalDirectionHint, CausalHint, CausalHintGuidance, EmbeddingHintProvenance,
-    EmbeddingMetadata, ExtractedCausalRelationship, MechanismType, MultiArrayEmbeddingOutput,
-    MultiArrayEmbeddingProvider, MultiRelationshipResult, SingleEmbedder, SparseEmbedder,
-    TokenEmbedder,
+    CausalDirectionHint, CausalHint, CausalHintGuidance, EmbedderQueueDepth,
+    EmbeddingHintProvenance, EmbeddingMetadata, ExtractedCausalRelationship, MechanismType,
+    MultiArrayEmbeddingOutput, MultiArrayEmbeddingProvider, MultiRelationshipResult,
+    PartialMultiArrayOutput, SingleEmbedder, SparseEmbedder, TokenEmbedder,
 };
 
 // Teleological memory store trait - TASK-F008
 pub use teleological_memory_store::{
-    NormalizationStrategyOption, SearchStrategy, TeleologicalMemoryStore,
+    MetadataFilter, NormalizationStrategyOption, SearchStrategy, TeleologicalMemoryStore,
     TeleologicalMemoryStoreExt, TeleologicalSearchOptions, TeleologicalSearchResult,
-    TeleologicalStorageBackend, TemporalBreakdown,
+    TeleologicalStorageBackend, TemporalBreakdown, TimeFilterExclusions,
 };
 
+// On-disk storage metrics for get_memetic_status
+pub use teleological_memory_store::{
+    format_bytes, AuxiliaryFileMetrics, ColumnFamilyMetrics, MemorySize, StorageMetrics,
+};
+
+// Per-memory access statistics for importance and lifecycle policies
+pub use teleological_memory_store::{AccessStats, ACCESS_WINDOW_DAYS};
+
 // Temporal search options (ARCH-14)
 pub use teleological_memory_store::{
     DecayFunction, MultiAnchorMode, PeriodicOptions, SequenceDirection, SequenceOptions,
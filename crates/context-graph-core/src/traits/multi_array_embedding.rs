@@ -61,8 +61,12 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use std::time::Duration;
 
+use crate::config::DeterminismMode;
 use crate::error::CoreResult;
-use crate::types::fingerprint::{SemanticFingerprint, SparseVector, NUM_EMBEDDERS};
+use crate::teleological::{Embedder, EmbedderMask};
+use crate::types::fingerprint::{
+    EmbeddingState, SemanticFingerprint, SparseVector, TeleologicalFingerprint, NUM_EMBEDDERS,
+};
 
 /// Output from multi-array embedding generation.
 ///
@@ -214,6 +218,71 @@ impl MultiArrayEmbeddingOutput {
     }
 }
 
+// ============================================================================
+// PARTIAL (SELECTIVE) EMBEDDING OUTPUT
+// ============================================================================
+
+/// Output from [`MultiArrayEmbeddingProvider::embed_selective`].
+///
+/// Only the embedders in `mask` were run. Their spaces in `fingerprint` hold
+/// real embeddings; every other space is empty (never zero-filled) and has
+/// zero latency.
+#[derive(Debug, Clone)]
+pub struct PartialMultiArrayOutput {
+    /// Embedders that were computed.
+    pub mask: EmbedderMask,
+
+    /// Fingerprint with only the `mask` spaces populated.
+    pub fingerprint: SemanticFingerprint,
+
+    /// Wall-clock time for the selected embedders.
+    pub total_latency: Duration,
+
+    /// Per-embedder latency; `Duration::ZERO` for unselected embedders.
+    pub per_embedder_latency: [Duration; NUM_EMBEDDERS],
+
+    /// Model IDs for each embedder slot (including unselected ones).
+    pub model_ids: [String; NUM_EMBEDDERS],
+}
+
+impl PartialMultiArrayOutput {
+    /// Restrict a full output to the embedders in `mask`.
+    ///
+    /// Used by the default `embed_selective()`; unselected spaces are
+    /// cleared and their latency zeroed.
+    pub fn from_full(output: MultiArrayEmbeddingOutput, mask: EmbedderMask) -> Self {
+        let mut fingerprint = output.fingerprint;
+        let mut per_embedder_latency = output.per_embedder_latency;
+        for embedder in Embedder::all().filter(|e| !mask.contains(*e)) {
+            fingerprint.clear_embedding(embedder);
+            per_embedder_latency[embedder.index()] = Duration::ZERO;
+        }
+        Self {
+            mask,
+            fingerprint,
+            total_latency: output.total_latency,
+            per_embedder_latency,
+            model_ids: output.model_ids,
+        }
+    }
+
+    /// Computation state of one embedding space.
+    #[inline]
+    pub fn state(&self, embedder: Embedder) -> EmbeddingState {
+        if self.mask.contains(embedder) {
+            EmbeddingState::Computed
+        } else {
+            EmbeddingState::NotComputed
+        }
+    }
+
+    /// Build a [`TeleologicalFingerprint`] marking unselected spaces
+    /// [`EmbeddingState::NotComputed`].
+    pub fn into_fingerprint(self, content_hash: [u8; 32]) -> TeleologicalFingerprint {
+        TeleologicalFingerprint::from_partial(self.fingerprint, self.mask, content_hash)
+    }
+}
+
 // ============================================================================
 // CAUSAL HINTS FOR E5 EMBEDDING ENHANCEMENT
 // ============================================================================
@@ -604,6 +673,7 @@ impl MultiRelationshipResult {
 ///     session_sequence: Some(42),  // 43rd memory in this session
 ///     timestamp: Some(Utc::now()),
 ///     causal_hint: None,  // Optional LLM hint for E5
+///     e12_pruning: None,  // Optional E12 token pruning
 /// };
 ///
 /// let output = provider.embed_all_with_metadata(content, metadata).await?;
@@ -632,6 +702,12 @@ pub struct EmbeddingMetadata {
     /// When provided, the E5 embedder can use direction hints to generate
     /// better cause/effect vectors. If None, falls back to marker detection.
     pub causal_hint: Option<CausalHint>,
+
+    /// Target compression for E12 (ColBERT) token pruning.
+    ///
+    /// When set (e.g. `Some(0.5)` keeps ~half the tokens), E12 drops the
+    /// least-attended tokens before storage. If None, all tokens are kept.
+    pub e12_pruning: Option<f32>,
 }
 
 impl EmbeddingMetadata {
@@ -647,6 +723,7 @@ impl EmbeddingMetadata {
             session_sequence: Some(sequence),
             timestamp: Some(Utc::now()),
             causal_hint: None,
+            e12_pruning: None,
         }
     }
 
@@ -658,6 +735,7 @@ impl EmbeddingMetadata {
             session_sequence: None,
             timestamp: Some(timestamp),
             causal_hint: None,
+            e12_pruning: None,
         }
     }
 
@@ -671,6 +749,16 @@ impl EmbeddingMetadata {
         self
     }
 
+    /// Prune E12 tokens to `target_compression` for this call.
+    ///
+    /// # Arguments
+    /// * `target_compression` - Fraction of tokens to drop, in (0.0, 1.0)
+    #[must_use]
+    pub fn with_e12_pruning(mut self, target_compression: f32) -> Self {
+        self.e12_pruning = Some(target_compression);
+        self
+    }
+
     /// Format E4 instruction string for hybrid session+sequence mode.
     ///
     /// Returns "session:X sequence:N" if both session_id and session_sequence are set,
@@ -732,6 +820,18 @@ impl EmbeddingMetadata {
 
 }
 
+/// Pending-request depth of one embedder's queue, for status reporting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbedderQueueDepth {
+    /// Embedder the queue feeds.
+    pub embedder: Embedder,
+    /// Requests currently pending.
+    pub depth: usize,
+    /// Depth at which new requests are rejected with `CoreError::Backpressure`.
+    pub capacity: usize,
+}
+
 /// Multi-Array Embedding Provider trait.
 ///
 /// Orchestrates 13 individual embedders to produce a complete [`SemanticFingerprint`].
@@ -879,6 +979,54 @@ pub trait MultiArrayEmbeddingProvider: Send + Sync {
         metadata: &[EmbeddingMetadata],
     ) -> CoreResult<Vec<MultiArrayEmbeddingOutput>>;
 
+    /// Embed content using only the embedders selected by `mask`.
+    ///
+    /// For callers that need a few spaces (e.g. E1 + E5 for drift checks)
+    /// and should not pay for all 13 models.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Text content to embed (must be non-empty)
+    /// * `mask` - Embedders to run
+    ///
+    /// # Returns
+    ///
+    /// A [`PartialMultiArrayOutput`] whose unselected spaces are empty.
+    ///
+    /// # Default Implementation
+    ///
+    /// Falls back to `embed_all` and masks the result (wasteful but correct).
+    /// Implementations should override to skip unselected models entirely.
+    async fn embed_selective(
+        &self,
+        content: &str,
+        mask: EmbedderMask,
+    ) -> CoreResult<PartialMultiArrayOutput> {
+        let output = self.embed_all(content).await?;
+        Ok(PartialMultiArrayOutput::from_full(output, mask))
+    }
+
+    /// [`embed_selective`](Self::embed_selective) with explicit metadata.
+    ///
+    /// Selected temporal embedders (E2-E4) use the metadata instructions,
+    /// E5 the causal hint and E12 the pruning target, exactly as in
+    /// [`embed_all_with_metadata`](Self::embed_all_with_metadata). Used to
+    /// complete a cached query embedding with the metadata-dependent spaces
+    /// of a memory being stored.
+    ///
+    /// # Default Implementation
+    ///
+    /// Falls back to `embed_all_with_metadata` and masks the result.
+    async fn embed_selective_with_metadata(
+        &self,
+        content: &str,
+        mask: EmbedderMask,
+        metadata: EmbeddingMetadata,
+    ) -> CoreResult<PartialMultiArrayOutput> {
+        let output = self.embed_all_with_metadata(content, metadata).await?;
+        Ok(PartialMultiArrayOutput::from_full(output, mask))
+    }
+
     /// Embed content using only E1 (semantic) embedder.
     ///
     /// Efficient for cases where only E1 embedding is needed, avoiding
@@ -1030,6 +1178,24 @@ pub trait MultiArrayEmbeddingProvider: Send + Sync {
     ///
     /// Array of booleans indicating health status for each embedder.
     fn health_status(&self) -> [bool; NUM_EMBEDDERS];
+
+    /// Current queue depth and capacity per embedder.
+    ///
+    /// # Default Implementation
+    ///
+    /// Returns an empty list: the provider does not bound or track queued work.
+    fn queue_depths(&self) -> Vec<EmbedderQueueDepth> {
+        Vec::new()
+    }
+
+    /// Determinism mode applied to this provider's outputs.
+    ///
+    /// # Default Implementation
+    ///
+    /// Returns `None`: outputs may differ in low-order bits between runs.
+    fn determinism(&self) -> Option<DeterminismMode> {
+        None
+    }
 }
 
 /// Individual dense embedder trait for composition.
@@ -1217,6 +1383,23 @@ pub trait TokenEmbedder: Send + Sync {
     /// - Tokenization or encoding fails
     async fn embed_tokens(&self, content: &str) -> CoreResult<Vec<Vec<f32>>>;
 
+    /// Generate per-token embeddings, dropping the least important tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Text content to tokenize and embed
+    /// * `target_compression` - Fraction of tokens to drop, in (0.0, 1.0)
+    ///
+    /// The default implementation does not prune and returns
+    /// `embed_tokens(content)`.
+    async fn embed_tokens_pruned(
+        &self,
+        content: &str,
+        _target_compression: f32,
+    ) -> CoreResult<Vec<Vec<f32>>> {
+        self.embed_tokens(content).await
+    }
+
     /// Check if model is loaded and ready.
     fn is_ready(&self) -> bool;
 }
@@ -1544,6 +1727,7 @@ mod tests {
             session_sequence: Some(42),
             timestamp: None,
             causal_hint: None,
+            e12_pruning: None,
         };
         let instruction = metadata.e4_instruction();
 
@@ -1582,6 +1766,7 @@ mod tests {
             session_sequence: None,
             timestamp: Some(ts),
             causal_hint: None,
+            e12_pruning: None,
         };
         let instruction = metadata.e4_instruction();
 
@@ -1597,6 +1782,17 @@ mod tests {
         );
     }
 
+    /// Test that E12 pruning is off by default and set per call.
+    #[test]
+    fn test_e12_pruning_defaults_off() {
+        assert_eq!(EmbeddingMetadata::default().e12_pruning, None);
+        assert_eq!(EmbeddingMetadata::with_sequence("s", 1).e12_pruning, None);
+
+        let metadata = EmbeddingMetadata::with_sequence("s", 1).with_e12_pruning(0.5);
+        assert_eq!(metadata.e12_pruning, Some(0.5));
+        assert_eq!(metadata.session_sequence, Some(1));
+    }
+
     /// Test e4_instruction() with UUID session_id (common real-world case).
     #[test]
     fn test_e4_instruction_with_uuid_session() {
@@ -0,0 +1,32 @@
+//! Per-memory access statistics reported by [`TeleologicalMemoryStore`].
+//!
+//! Backends count every `retrieve` that finds a memory and every search
+//! result returned for it. [`AccessStats`] lets importance scoring and
+//! lifecycle policies tell a memory nobody reads from one retrieved daily.
+//!
+//! [`TeleologicalMemoryStore`]: super::TeleologicalMemoryStore
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Length of the recent-access window behind [`AccessStats::hits_7d`].
+pub const ACCESS_WINDOW_DAYS: i64 = 7;
+
+/// How often and how recently one memory was accessed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessStats {
+    /// Retrievals and search hits since the memory was stored.
+    pub hits: u64,
+    /// Most recent access; `None` if the memory was never accessed.
+    pub last_access: Option<DateTime<Utc>>,
+    /// Accesses during the last [`ACCESS_WINDOW_DAYS`] UTC days, today included.
+    pub hits_7d: u64,
+}
+
+impl AccessStats {
+    /// Whether the memory was never accessed.
+    pub fn is_untouched(&self) -> bool {
+        self.hits == 0
+    }
+}
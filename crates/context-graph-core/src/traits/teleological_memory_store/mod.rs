@@ -30,19 +30,30 @@
 //! - [`options`]: Search options (`TeleologicalSearchOptions`)
 //! - [`result`]: Search result type (`TeleologicalSearchResult`)
 //! - [`store`]: Core trait (`TeleologicalMemoryStore`)
+//! - [`storage_metrics`]: On-disk footprint (`StorageMetrics`, `MemorySize`)
+//! - [`access_stats`]: Per-memory retrieval counts (`AccessStats`)
 //! - [`ext`]: Extension trait (`TeleologicalMemoryStoreExt`)
 
+mod access_stats;
 mod backend;
 mod ext;
 mod options;
 mod result;
+mod storage_metrics;
 mod store;
 
 // Re-export all public types
+pub use access_stats::{AccessStats, ACCESS_WINDOW_DAYS};
 pub use backend::TeleologicalStorageBackend;
 pub use ext::TeleologicalMemoryStoreExt;
-pub use options::{NormalizationStrategyOption, SearchStrategy, TeleologicalSearchOptions};
+pub use options::{
+    MetadataFilter, NormalizationStrategyOption, SearchStrategy, TeleologicalSearchOptions,
+    TimeFilterExclusions,
+};
 pub use result::{TeleologicalSearchResult, TemporalBreakdown};
+pub use storage_metrics::{
+    format_bytes, AuxiliaryFileMetrics, ColumnFamilyMetrics, MemorySize, StorageMetrics,
+};
 pub use store::TeleologicalMemoryStore;
 
 // Re-export temporal search types (ARCH-14)
@@ -42,13 +42,19 @@
 //! - [Elastic Weighted RRF](https://www.elastic.co/blog/weighted-reciprocal-rank-fusion-rrf)
 //! - [ColBERT Late Interaction](https://weaviate.io/blog/late-interaction-overview)
 
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::causal::asymmetric::CausalDirection;
 use crate::code::CodeQueryType;
 use crate::fusion::FusionStrategy;
-use crate::types::fingerprint::SemanticFingerprint;
+use crate::types::fingerprint::{SemanticFingerprint, TeleologicalFingerprint};
+use crate::types::SourceType;
 
 /// Search strategy for semantic queries.
 ///
@@ -192,6 +198,60 @@ impl TimeWindow {
     }
 }
 
+/// Metadata predicates applied DURING k-NN retrieval (filtered search).
+///
+/// Unlike post-filtering, candidates are fetched with progressive over-fetching
+/// until `top_k` of them pass the filter, so selective filters still return a
+/// full result set. All set predicates must match (AND).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MetadataFilter {
+    /// Only return memories whose source type is one of these.
+    /// Empty means any source type.
+    #[serde(default)]
+    pub source_types: Vec<SourceType>,
+
+    /// Only return memories created within this window.
+    #[serde(default)]
+    pub created_within: Option<TimeWindow>,
+}
+
+impl MetadataFilter {
+    /// Filter to memories of the given source types.
+    pub fn with_source_types(mut self, source_types: Vec<SourceType>) -> Self {
+        self.source_types = source_types;
+        self
+    }
+
+    /// Filter to memories created within `window`.
+    pub fn with_created_within(mut self, window: TimeWindow) -> Self {
+        self.created_within = Some(window);
+        self
+    }
+
+    /// Check if any predicate is set.
+    pub fn is_active(&self) -> bool {
+        !self.source_types.is_empty()
+            || self.created_within.as_ref().is_some_and(|w| w.is_defined())
+    }
+
+    /// Check if a memory's creation time passes the time predicate.
+    pub fn matches_created_at(&self, created_at_ms: i64) -> bool {
+        self.created_within
+            .as_ref()
+            .is_none_or(|w| w.contains(created_at_ms))
+    }
+
+    /// Check if a memory's source type passes the source predicate.
+    ///
+    /// Memories without source metadata are treated as `SourceType::Unknown`.
+    pub fn matches_source_type(&self, source_type: Option<&SourceType>) -> bool {
+        self.source_types.is_empty()
+            || self
+                .source_types
+                .contains(source_type.unwrap_or(&SourceType::Unknown))
+    }
+}
+
 /// Options for E3 periodic pattern matching.
 ///
 /// Boosts memories that match the target time pattern (hour-of-day, day-of-week).
@@ -927,6 +987,25 @@ impl Default for TemporalSearchOptions {
     }
 }
 
+/// Distinct candidates excluded by a search's `time_range`.
+///
+/// Clones share one set, so a caller that keeps a clone of the handle reads
+/// the count after the store has searched with the options.
+#[derive(Debug, Clone, Default)]
+pub struct TimeFilterExclusions(Arc<Mutex<HashSet<Uuid>>>);
+
+impl TimeFilterExclusions {
+    /// Record that `id` fell outside the time range.
+    pub fn record(&self, id: Uuid) {
+        self.0.lock().insert(id);
+    }
+
+    /// Number of distinct candidates recorded.
+    pub fn count(&self) -> usize {
+        self.0.lock().len()
+    }
+}
+
 /// Search options for teleological memory queries.
 ///
 /// Controls filtering, pagination, and result formatting for
@@ -1156,12 +1235,82 @@ pub struct TeleologicalSearchOptions {
     /// to pre-filter candidates before full multi-embedder search.
     #[serde(default)]
     pub enable_teleological_prefilter: bool,
+
+    // =========================================================================
+    // Namespace Isolation
+    // =========================================================================
+
+    /// Restrict results to fingerprints stored in this namespace.
+    ///
+    /// `None` searches every namespace except session staging namespaces
+    /// (`staged.*`), which must be named explicitly. Fingerprints stored
+    /// before namespaces existed belong to `"default"`.
+    #[serde(default)]
+    pub namespace: Option<String>,
+
+    // =========================================================================
+    // Filtered k-NN
+    // =========================================================================
+
+    /// Metadata predicates applied during retrieval rather than afterwards.
+    ///
+    /// Honored by single-embedder and E1-only searches and by the E1 ranking
+    /// of multi-space search, which over-fetch progressively until `top_k`
+    /// candidates pass.
+    #[serde(default)]
+    pub metadata_filter: Option<MetadataFilter>,
+
+    // =========================================================================
+    // Snapshot Visibility
+    // =========================================================================
+
+    /// Commit sequence to read at.
+    ///
+    /// Fingerprints committed after this sequence are left out of every
+    /// stage. `None` reads at the sequence current when the search starts.
+    /// Sequences come from the store and restart when it is reopened.
+    #[serde(default)]
+    pub as_of: Option<u64>,
+
+    // =========================================================================
+    // Time Range and Recency
+    // =========================================================================
+
+    /// Only return memories created in `[start, end)`.
+    ///
+    /// A hard filter on stored creation timestamps. It joins
+    /// `metadata_filter` during retrieval, so `top_k` survives a selective
+    /// range, and is re-checked on the final results. Excluded candidates
+    /// are counted in `time_filter_exclusions`.
+    #[serde(default)]
+    pub time_range: Option<(DateTime<Utc>, DateTime<Utc>)>,
+
+    /// Weight of recency in the final score [0.0, 1.0].
+    ///
+    /// Scores are multiplied by `1 - w + w * 0.5^(age / half_life)`: 0.0
+    /// leaves them unchanged, 1.0 halves a score per half-life of age.
+    /// Unlike `temporal_options` this reads only the stored timestamp.
+    #[serde(default)]
+    pub recency_weight: f32,
+
+    /// Half-life of the recency decay in seconds.
+    /// Default: 604800 (7 days)
+    #[serde(default = "TeleologicalSearchOptions::default_recency_half_life")]
+    pub recency_half_life_secs: u64,
+
+    /// Candidates `time_range` excluded during this search.
+    #[serde(skip)]
+    pub time_filter_exclusions: TimeFilterExclusions,
 }
 
 impl TeleologicalSearchOptions {
     fn default_rerank_weight() -> f32 {
         0.4 // 40% E12 MaxSim, 60% fusion score
     }
+
+    fn default_recency_half_life() -> u64 {
+        7 * 86400 // 7 days
+    }
 }
 
 impl Default for TeleologicalSearchOptions {
@@ -1196,6 +1345,17 @@ impl Default for TeleologicalSearchOptions {
             use_quantized_prefilter: false,
             // 13D teleological pre-filter - disabled by default
             enable_teleological_prefilter: false,
+            // Namespace filter - all namespaces by default
+            namespace: None,
+            // Metadata filter - none by default
+            metadata_filter: None,
+            // Snapshot - sequence at search start by default
+            as_of: None,
+            // Time range and recency - off by default
+            time_range: None,
+            recency_weight: 0.0,
+            recency_half_life_secs: Self::default_recency_half_life(),
+            time_filter_exclusions: TimeFilterExclusions::default(),
         }
     }
 }
@@ -1637,6 +1797,85 @@ impl TeleologicalSearchOptions {
         self.enable_teleological_prefilter = enabled;
         self
     }
+
+    /// Restrict results to a single namespace.
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Check whether a fingerprint in `namespace` may appear in results.
+    #[inline]
+    pub fn admits_namespace(&self, namespace: &str) -> bool {
+        match &self.namespace {
+            Some(ns) => ns == namespace,
+            None => !TeleologicalFingerprint::is_staging_namespace(namespace),
+        }
+    }
+
+    /// Apply metadata predicates during k-NN retrieval.
+    pub fn with_metadata_filter(mut self, filter: MetadataFilter) -> Self {
+        self.metadata_filter = Some(filter);
+        self
+    }
+
+    /// Read at commit sequence `sequence`, hiding anything committed later.
+    #[inline]
+    pub fn as_of(mut self, sequence: u64) -> Self {
+        self.as_of = Some(sequence);
+        self
+    }
+
+    /// Only return memories created in `[start, end)`.
+    pub fn with_time_range(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.time_range = Some((start, end));
+        self
+    }
+
+    /// Multiply scores by an exponential decay of age.
+    ///
+    /// `weight` is clamped to [0.0, 1.0].
+    pub fn with_recency_weight(mut self, weight: f32, half_life_secs: u64) -> Self {
+        self.recency_weight = weight.clamp(0.0, 1.0);
+        self.recency_half_life_secs = half_life_secs;
+        self
+    }
+
+    /// Check a creation time against `time_range`.
+    #[inline]
+    pub fn time_range_admits(&self, created_at: DateTime<Utc>) -> bool {
+        self.time_range
+            .map_or(true, |(start, end)| created_at >= start && created_at < end)
+    }
+
+    /// `metadata_filter` with `time_range` intersected into its creation
+    /// window: the predicates filtered k-NN applies during retrieval.
+    pub fn effective_metadata_filter(&self) -> Option<MetadataFilter> {
+        let Some((start, end)) = self.time_range else {
+            return self.metadata_filter.clone();
+        };
+        let (start_ms, end_ms) = (start.timestamp_millis(), end.timestamp_millis());
+        let mut filter = self.metadata_filter.clone().unwrap_or_default();
+        let window = filter.created_within.take().unwrap_or_default();
+        filter.created_within = Some(TimeWindow {
+            start_ms: Some(window.start_ms.map_or(start_ms, |s| s.max(start_ms))),
+            end_ms: Some(window.end_ms.map_or(end_ms, |e| e.min(end_ms))),
+        });
+        Some(filter)
+    }
+
+    /// Score multiplier for a memory of the given age under `recency_weight`.
+    ///
+    /// Ages below zero (clock skew) count as zero.
+    pub fn recency_factor(&self, age: chrono::Duration) -> f32 {
+        if self.recency_weight <= 0.0 {
+            return 1.0;
+        }
+        let age_secs = age.num_milliseconds().max(0) as f64 / 1000.0;
+        let half_life = self.recency_half_life_secs.max(1) as f64;
+        let decay = 0.5f64.powf(age_secs / half_life) as f32;
+        1.0 - self.recency_weight + self.recency_weight * decay
+    }
 }
 
 #[cfg(test)]
@@ -1836,4 +2075,78 @@ mod tests {
         let around = SequenceOptions::around(id);
         assert_eq!(around.direction, SequenceDirection::Both);
     }
+
+    #[test]
+    fn test_metadata_filter_predicates() {
+        let filter = MetadataFilter::default();
+        assert!(!filter.is_active());
+        assert!(filter.matches_source_type(None));
+
+        let filter = MetadataFilter::default()
+            .with_source_types(vec![SourceType::MDFileChunk])
+            .with_created_within(TimeWindow {
+                start_ms: Some(1_000),
+                end_ms: Some(2_000),
+            });
+        assert!(filter.is_active());
+        assert!(filter.matches_source_type(Some(&SourceType::MDFileChunk)));
+        assert!(!filter.matches_source_type(Some(&SourceType::Manual)));
+        assert!(!filter.matches_source_type(None));
+        assert!(filter.matches_created_at(1_500));
+        assert!(!filter.matches_created_at(2_000));
+
+        let opts = TeleologicalSearchOptions::quick(5).with_metadata_filter(filter);
+        assert!(opts.metadata_filter.is_some());
+    }
+
+    #[test]
+    fn test_time_range_joins_metadata_filter() {
+        let start = DateTime::from_timestamp_millis(1_000).unwrap();
+        let end = DateTime::from_timestamp_millis(5_000).unwrap();
+        let opts = TeleologicalSearchOptions::quick(5).with_time_range(start, end);
+        assert!(opts.time_range_admits(start));
+        assert!(!opts.time_range_admits(end));
+
+        let window = opts.effective_metadata_filter().unwrap().created_within.unwrap();
+        assert_eq!((window.start_ms, window.end_ms), (Some(1_000), Some(5_000)));
+
+        // Intersected with an existing creation window
+        let opts = opts.with_metadata_filter(MetadataFilter::default().with_created_within(
+            TimeWindow {
+                start_ms: Some(2_000),
+                end_ms: Some(9_000),
+            },
+        ));
+        let window = opts.effective_metadata_filter().unwrap().created_within.unwrap();
+        assert_eq!((window.start_ms, window.end_ms), (Some(2_000), Some(5_000)));
+
+        assert!(TeleologicalSearchOptions::default().effective_metadata_filter().is_none());
+    }
+
+    #[test]
+    fn test_recency_factor_decays_by_half_life() {
+        let day = chrono::Duration::days(1);
+        let off = TeleologicalSearchOptions::default();
+        assert_eq!(off.recency_factor(day * 30), 1.0);
+
+        let full = TeleologicalSearchOptions::default().with_recency_weight(1.0, 86400);
+        assert!((full.recency_factor(chrono::Duration::zero()) - 1.0).abs() < 1e-6);
+        assert!((full.recency_factor(day) - 0.5).abs() < 1e-6);
+        assert!((full.recency_factor(day * 2) - 0.25).abs() < 1e-6);
+        assert_eq!(full.recency_factor(-day), 1.0);
+
+        let half = TeleologicalSearchOptions::default().with_recency_weight(0.5, 86400);
+        assert!((half.recency_factor(day) - 0.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_time_filter_exclusions_shared_across_clones() {
+        let opts = TeleologicalSearchOptions::default();
+        let handle = opts.time_filter_exclusions.clone();
+        let id = Uuid::new_v4();
+        let searched = opts.clone();
+        searched.time_filter_exclusions.record(id);
+        searched.time_filter_exclusions.record(id);
+        assert_eq!(handle.count(), 1);
+    }
 }
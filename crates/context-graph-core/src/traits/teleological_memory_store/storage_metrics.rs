@@ -0,0 +1,155 @@
+//! On-disk storage metrics reported by [`TeleologicalMemoryStore`].
+//!
+//! [`StorageMetrics`] breaks the store's footprint down per column family
+//! (live data, keys, pending compaction, SST files) and lists auxiliary
+//! files kept next to the database, such as checkpoints and HNSW snapshots.
+//! [`MemorySize`] is one row of
+//! [`largest_memories`](TeleologicalMemoryStore::largest_memories).
+//!
+//! [`TeleologicalMemoryStore`]: super::TeleologicalMemoryStore
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// RocksDB-reported figures for one column family.
+///
+/// Live data size and key count are RocksDB estimates; SST figures are exact.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnFamilyMetrics {
+    pub name: String,
+    pub live_data_bytes: u64,
+    pub num_keys: u64,
+    pub pending_compaction_bytes: u64,
+    pub sst_files: u64,
+    pub sst_bytes: u64,
+}
+
+/// A file or directory stored next to the database.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuxiliaryFileMetrics {
+    /// Entry name relative to the data directory (e.g. `checkpoints`).
+    pub name: String,
+    /// Regular files under the entry.
+    pub files: u64,
+    pub bytes: u64,
+}
+
+/// Point-in-time storage footprint of a store.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageMetrics {
+    /// Column families, largest live data first.
+    pub column_families: Vec<ColumnFamilyMetrics>,
+    /// Auxiliary entries, largest first.
+    pub auxiliary: Vec<AuxiliaryFileMetrics>,
+}
+
+impl StorageMetrics {
+    /// Metrics of the column family `name`.
+    pub fn column_family(&self, name: &str) -> Option<&ColumnFamilyMetrics> {
+        self.column_families.iter().find(|cf| cf.name == name)
+    }
+
+    /// Estimated live data across all column families.
+    pub fn total_live_data_bytes(&self) -> u64 {
+        self.column_families.iter().map(|cf| cf.live_data_bytes).sum()
+    }
+
+    /// SST bytes across all column families.
+    pub fn total_sst_bytes(&self) -> u64 {
+        self.column_families.iter().map(|cf| cf.sst_bytes).sum()
+    }
+
+    /// Bytes pending compaction across all column families.
+    pub fn total_pending_compaction_bytes(&self) -> u64 {
+        self.column_families
+            .iter()
+            .map(|cf| cf.pending_compaction_bytes)
+            .sum()
+    }
+
+    /// Bytes of all auxiliary entries.
+    pub fn auxiliary_bytes(&self) -> u64 {
+        self.auxiliary.iter().map(|a| a.bytes).sum()
+    }
+}
+
+/// Stored footprint of one memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemorySize {
+    pub id: Uuid,
+    /// Serialized fingerprint (all 13 embeddings).
+    pub fingerprint_bytes: u64,
+    /// Stored content text, 0 if none.
+    pub content_bytes: u64,
+}
+
+impl MemorySize {
+    /// Fingerprint plus content bytes.
+    pub fn total_bytes(&self) -> u64 {
+        self.fingerprint_bytes + self.content_bytes
+    }
+}
+
+/// Format a byte count with binary units, e.g. `1.50 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes_units() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.00 MiB");
+    }
+
+    #[test]
+    fn test_totals_sum_column_families() {
+        let metrics = StorageMetrics {
+            column_families: vec![
+                ColumnFamilyMetrics {
+                    name: "fingerprints".to_string(),
+                    live_data_bytes: 300,
+                    sst_bytes: 400,
+                    ..Default::default()
+                },
+                ColumnFamilyMetrics {
+                    name: "typed_edges".to_string(),
+                    live_data_bytes: 20,
+                    pending_compaction_bytes: 7,
+                    ..Default::default()
+                },
+            ],
+            auxiliary: vec![AuxiliaryFileMetrics {
+                name: "checkpoints".to_string(),
+                files: 3,
+                bytes: 50,
+            }],
+        };
+
+        assert_eq!(metrics.total_live_data_bytes(), 320);
+        assert_eq!(metrics.total_sst_bytes(), 400);
+        assert_eq!(metrics.total_pending_compaction_bytes(), 7);
+        assert_eq!(metrics.auxiliary_bytes(), 50);
+        assert_eq!(metrics.column_family("typed_edges").unwrap().live_data_bytes, 20);
+        assert!(metrics.column_family("missing").is_none());
+    }
+}
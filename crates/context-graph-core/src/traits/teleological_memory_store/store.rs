@@ -4,20 +4,25 @@
 //! teleological memory architecture.
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::error::{CoreError, CoreResult};
+use crate::retrieval::{PipelineMetricsSnapshot, PipelineTimings};
+use crate::teleological::EmbedderMask;
 use crate::types::fingerprint::{
-    SemanticFingerprint, SparseVector, TeleologicalFingerprint,
+    PartialFingerprint, SemanticFingerprint, SparseVector, TeleologicalFingerprint,
 };
 use crate::types::SourceMetadata;
 
+use super::access_stats::AccessStats;
 use super::backend::TeleologicalStorageBackend;
 use super::options::TeleologicalSearchOptions;
 use super::result::TeleologicalSearchResult;
+use super::storage_metrics::{MemorySize, StorageMetrics};
 
 /// Core trait for teleological memory storage operations.
 ///
@@ -52,6 +57,13 @@ pub trait TeleologicalMemoryStore: Send + Sync {
 
     /// Store a new teleological fingerprint.
     ///
+    /// # Atomicity
+    /// The fingerprint and every persisted entry derived from it (secondary
+    /// indexes, inverted postings, markers) become visible together or not
+    /// at all; a crash mid-store must not leave partial state. Durability
+    /// of a returned `Ok` is backend-defined (see the RocksDB store's
+    /// `FsyncPolicy`). In-memory ANN indexes may be rebuilt on restart.
+    ///
     /// # Arguments
     /// * `fingerprint` - The fingerprint to store
     ///
@@ -77,6 +89,38 @@ pub trait TeleologicalMemoryStore: Send + Sync {
     /// - `CoreError::SerializationError` - Deserialization failure
     async fn retrieve(&self, id: Uuid) -> CoreResult<Option<TeleologicalFingerprint>>;
 
+    /// Retrieve only the embedding spaces selected by `mask`.
+    ///
+    /// Stage 1 filtering needs the E1 Matryoshka 128D prefix and the topic
+    /// profile, not the full ~63KB fingerprint. Backends should read only the
+    /// column families needed for the mask. The E1 128D prefix and topic
+    /// profile are always included when available, so an empty mask loads
+    /// just those two.
+    ///
+    /// Default: retrieves the full fingerprint and projects it. Override for
+    /// backends that can avoid the full read.
+    ///
+    /// # Arguments
+    /// * `id` - The UUID of the fingerprint to retrieve
+    /// * `mask` - Embedders whose full embeddings should be loaded
+    ///
+    /// # Returns
+    /// `Some(partial)` if found, `None` if not found or soft-deleted.
+    ///
+    /// # Errors
+    /// - `CoreError::StorageError` - Storage backend failure
+    /// - `CoreError::SerializationError` - Deserialization failure
+    async fn retrieve_partial(
+        &self,
+        id: Uuid,
+        mask: EmbedderMask,
+    ) -> CoreResult<Option<PartialFingerprint>> {
+        Ok(self
+            .retrieve(id)
+            .await?
+            .map(|fp| PartialFingerprint::from_full(&fp, mask)))
+    }
+
     /// Update an existing fingerprint.
     ///
     /// Replaces the entire fingerprint with the new data.
@@ -93,6 +137,41 @@ pub trait TeleologicalMemoryStore: Send + Sync {
     /// - `CoreError::ValidationError` - Invalid fingerprint data
     async fn update(&self, fingerprint: TeleologicalFingerprint) -> CoreResult<bool>;
 
+    /// Replace only the embedding spaces selected by `mask`.
+    ///
+    /// The selected spaces are moved out of `embeddings` into the stored
+    /// fingerprint; every other space, the ID, and all metadata are left as
+    /// stored. Backends should rewrite only the indexes of the selected
+    /// spaces.
+    ///
+    /// Default: retrieves the full fingerprint, swaps the spaces in, and
+    /// calls [`update`](Self::update). Override for backends that can avoid
+    /// re-indexing unchanged spaces.
+    ///
+    /// # Arguments
+    /// * `id` - The fingerprint to update
+    /// * `mask` - Embedders whose spaces are replaced
+    /// * `embeddings` - Source of the new spaces (unselected spaces are ignored)
+    ///
+    /// # Returns
+    /// `true` if updated, `false` if ID not found.
+    ///
+    /// # Errors
+    /// - `CoreError::StorageError` - Storage backend failure
+    /// - `CoreError::IndexError` - Re-indexing a selected space failed
+    async fn update_embeddings(
+        &self,
+        id: Uuid,
+        mask: EmbedderMask,
+        mut embeddings: SemanticFingerprint,
+    ) -> CoreResult<bool> {
+        let Some(mut fingerprint) = self.retrieve(id).await? else {
+            return Ok(false);
+        };
+        fingerprint.apply_embeddings(mask, &mut embeddings);
+        self.update(fingerprint).await
+    }
+
     /// Delete a fingerprint.
     ///
     /// # Arguments
@@ -129,6 +208,27 @@ pub trait TeleologicalMemoryStore: Send + Sync {
         options: TeleologicalSearchOptions,
     ) -> CoreResult<Vec<TeleologicalSearchResult>>;
 
+    /// Same as [`search_semantic`](Self::search_semantic), also returning the
+    /// per-stage [`PipelineTimings`] of this search.
+    ///
+    /// Implementations fold the timings into the histograms reported by
+    /// [`pipeline_metrics`](Self::pipeline_metrics). The default runs
+    /// `search_semantic` and reports no stages.
+    async fn search_semantic_timed(
+        &self,
+        query: &SemanticFingerprint,
+        options: TeleologicalSearchOptions,
+    ) -> CoreResult<(Vec<TeleologicalSearchResult>, PipelineTimings)> {
+        let results = self.search_semantic(query, options).await?;
+        Ok((results, PipelineTimings::default()))
+    }
+
+    /// Per-stage search latency (p50/p95/p99) aggregated since the store was
+    /// opened. Empty for backends that don't instrument their pipeline.
+    fn pipeline_metrics(&self) -> PipelineMetricsSnapshot {
+        PipelineMetricsSnapshot::default()
+    }
+
     /// Full-text search using text query (generates embeddings internally).
     ///
     /// This method handles embedding generation for the text query and
@@ -219,19 +319,29 @@ pub trait TeleologicalMemoryStore: Send + Sync {
 
     /// Retrieve multiple fingerprints by their UUIDs.
     ///
+    /// Default: loops over `retrieve`. Override with a batched read
+    /// (e.g. RocksDB `multi_get_cf`) to avoid N round trips.
+    ///
     /// # Arguments
-    /// * `ids` - Slice of UUIDs to retrieve
+    /// * `ids` - Slice of UUIDs to retrieve (duplicates allowed)
     ///
     /// # Returns
-    /// Vector of `Option<TeleologicalFingerprint>` (same order as input).
-    /// `None` entries indicate IDs not found or soft-deleted.
+    /// Vector of `Option<TeleologicalFingerprint>` (same order and length as input).
+    /// `None` entries indicate IDs not found or soft-deleted. An empty slice
+    /// returns an empty Vec.
     ///
     /// # Errors
     /// - `CoreError::StorageError` - Storage backend failure
     async fn retrieve_batch(
         &self,
         ids: &[Uuid],
-    ) -> CoreResult<Vec<Option<TeleologicalFingerprint>>>;
+    ) -> CoreResult<Vec<Option<TeleologicalFingerprint>>> {
+        let mut results = Vec::with_capacity(ids.len());
+        for id in ids {
+            results.push(self.retrieve(*id).await?);
+        }
+        Ok(results)
+    }
 
     // ==================== Statistics ====================
 
@@ -255,6 +365,51 @@ pub trait TeleologicalMemoryStore: Send + Sync {
     /// Returns the enum variant identifying this implementation.
     fn backend_type(&self) -> TeleologicalStorageBackend;
 
+    /// Per-column-family sizes and auxiliary files on disk.
+    ///
+    /// Reads only engine properties and directory sizes, no data. Empty for
+    /// backends without column families.
+    ///
+    /// # Errors
+    /// - `CoreError::StorageError` - Property or filesystem read failure
+    fn storage_metrics(&self) -> CoreResult<StorageMetrics> {
+        Ok(StorageMetrics::default())
+    }
+
+    /// The `n` live memories with the largest stored footprint, largest
+    /// first. Scans every fingerprint; meant for debugging bloat, not for
+    /// hot paths. Empty for backends that don't track stored sizes.
+    ///
+    /// # Errors
+    /// - `CoreError::StorageError` - Storage backend failure
+    async fn largest_memories(&self, _n: usize) -> CoreResult<Vec<MemorySize>> {
+        Ok(Vec::new())
+    }
+
+    // ==================== Access Statistics ====================
+
+    /// Access statistics for each of `ids`, in order.
+    ///
+    /// Counts every `retrieve` that found the memory and every search result
+    /// returned for it, including accesses not yet flushed to disk. IDs never
+    /// accessed get zero stats. Default: zero stats for backends that don't
+    /// track access.
+    ///
+    /// # Errors
+    /// - `CoreError::StorageError` - Storage backend failure
+    async fn access_stats(&self, ids: &[Uuid]) -> CoreResult<Vec<AccessStats>> {
+        Ok(vec![AccessStats::default(); ids.len()])
+    }
+
+    /// Persist pending access counts now instead of at the next periodic
+    /// flush. Default: no-op.
+    ///
+    /// # Errors
+    /// - `CoreError::StorageError` - Write failure
+    async fn flush_access_stats(&self) -> CoreResult<()> {
+        Ok(())
+    }
+
     // ==================== Persistence ====================
 
     /// Flush all pending writes to durable storage.
@@ -391,6 +546,54 @@ pub trait TeleologicalMemoryStore: Send + Sync {
     /// - `CoreError::StorageError` - Storage backend failure
     async fn find_fingerprints_by_file_path(&self, file_path: &str) -> CoreResult<Vec<Uuid>>;
 
+    // ==================== Content Hash Index ====================
+
+    /// Find all live fingerprints stored with the given content hash.
+    ///
+    /// Backed by a `content_hash -> Vec<Uuid>` secondary index maintained on
+    /// store/update/delete. Used for exact duplicate detection at store time.
+    /// Soft-deleted fingerprints are excluded.
+    ///
+    /// # Arguments
+    /// * `content_hash` - SHA-256 hash of the memory content
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Uuid>)` - UUIDs of matching fingerprints (may be empty)
+    ///
+    /// # Errors
+    /// - `CoreError::StorageError` - Storage backend failure
+    async fn find_by_content_hash(&self, content_hash: &[u8; 32]) -> CoreResult<Vec<Uuid>> {
+        let _ = content_hash;
+        Err(CoreError::Internal("Content hash index not supported by this backend".into()))
+    }
+
+    // ==================== Namespaces ====================
+
+    /// Count live fingerprints per namespace.
+    ///
+    /// Fingerprints stored before namespaces existed are counted under
+    /// `TeleologicalFingerprint::DEFAULT_NAMESPACE`. Soft-deleted fingerprints
+    /// are excluded.
+    ///
+    /// # Errors
+    /// - `CoreError::StorageError` - Storage backend failure
+    async fn count_by_namespace(&self) -> CoreResult<HashMap<String, usize>> {
+        Err(CoreError::Internal("Namespace counts not supported by this backend".into()))
+    }
+
+    /// List the IDs of live fingerprints stored in `namespace`.
+    ///
+    /// Only non-default namespaces are supported; listing the default
+    /// namespace would require a full scan. Soft-deleted fingerprints are
+    /// excluded.
+    ///
+    /// # Errors
+    /// - `CoreError::StorageError` - Storage backend failure
+    async fn list_namespace_ids(&self, namespace: &str) -> CoreResult<Vec<Uuid>> {
+        let _ = namespace;
+        Err(CoreError::Internal("Namespace listing not supported by this backend".into()))
+    }
+
     // ==================== File Index Storage ====================
     // Enables O(1) lookup of fingerprints by file path for file watcher management.
     // See `defaults.rs` for default implementations.
@@ -521,6 +724,40 @@ pub trait TeleologicalMemoryStore: Send + Sync {
         &self,
     ) -> CoreResult<Option<crate::clustering::PersistedTopicPortfolio>>;
 
+    // ==================== Topic Records ====================
+    // One record per topic, keyed by an ID that survives detection runs.
+
+    /// List every stored topic record.
+    ///
+    /// # Errors
+    /// - `CoreError::StorageError` - Storage backend failure
+    /// - `CoreError::SerializationError` - Deserialization failure
+    async fn list_topic_records(&self) -> CoreResult<Vec<crate::clustering::TopicRecord>>;
+
+    /// Apply a detection run: write `upserts` and delete `retired` in one batch.
+    ///
+    /// # Errors
+    /// - `CoreError::StorageError` - Storage backend failure
+    /// - `CoreError::SerializationError` - Serialization failure
+    async fn apply_topic_reconciliation(
+        &self,
+        reconciliation: &crate::clustering::TopicReconciliation,
+    ) -> CoreResult<()>;
+
+    /// Remove `removed` from every topic's member list, adding `replacement`
+    /// (a merge result) to the topics that contained any of them.
+    ///
+    /// Topics left without members are deleted. Returns the number of topic
+    /// records changed.
+    ///
+    /// # Errors
+    /// - `CoreError::StorageError` - Storage backend failure
+    async fn replace_topic_members(
+        &self,
+        removed: &[Uuid],
+        replacement: Option<Uuid>,
+    ) -> CoreResult<usize>;
+
     // =========================================================================
     // Clustering Support
     // =========================================================================
@@ -563,6 +800,33 @@ pub trait TeleologicalMemoryStore: Send + Sync {
         limit: usize,
     ) -> CoreResult<Vec<TeleologicalFingerprint>>;
 
+    /// List one page of fingerprints ordered by ID, starting after `after`.
+    ///
+    /// Used by long-running scans (e.g. re-embedding) that page through the
+    /// whole store and resume from the last ID they processed.
+    ///
+    /// Default implementation loads every fingerprint and sorts them; backends
+    /// with ordered keys should override it.
+    ///
+    /// # Arguments
+    /// * `after` - Exclusive lower bound (None = start from the first ID)
+    /// * `limit` - Maximum number of fingerprints to return
+    ///
+    /// # Returns
+    /// Up to `limit` fingerprints with `id > after`, ascending by ID
+    /// (skipping soft-deleted entries).
+    async fn list_fingerprints_after(
+        &self,
+        after: Option<Uuid>,
+        limit: usize,
+    ) -> CoreResult<Vec<TeleologicalFingerprint>> {
+        let mut page = self.list_fingerprints_unbiased(usize::MAX).await?;
+        page.retain(|fp| !matches!(after, Some(after) if fp.id <= after));
+        page.sort_by_key(|fp| fp.id);
+        page.truncate(limit);
+        Ok(page)
+    }
+
     // =========================================================================
     // Causal Relationship Storage (CF_CAUSAL_RELATIONSHIPS)
     //
@@ -852,6 +1116,41 @@ pub trait TeleologicalMemoryStore: Send + Sync {
         limit: usize,
     ) -> CoreResult<Vec<crate::types::audit::MergeRecord>>;
 
+    // ==================== Memory Lineage ====================
+
+    /// Append a lineage record (parents -> child) for a merge or consolidation.
+    ///
+    /// Stored in CF_MEMORY_LINEAGE. PERMANENT -- never expires.
+    async fn append_lineage_record(&self, record: &crate::types::audit::LineageRecord) -> CoreResult<()>;
+
+    /// Retrieve every lineage record whose child is `child_id`, oldest first.
+    async fn get_lineage_records(
+        &self,
+        child_id: Uuid,
+    ) -> CoreResult<Vec<crate::types::audit::LineageRecord>>;
+
+    // ==================== Tool Execution Audit ====================
+
+    /// Append a record of one mutating tool call.
+    ///
+    /// Stored in CF_TOOL_AUDIT_LOG. Append-only; records leave the log only
+    /// through `prune_tool_audit_log`.
+    async fn append_tool_audit_record(
+        &self,
+        record: &crate::types::audit::ToolAuditRecord,
+    ) -> CoreResult<()>;
+
+    /// Query the tool audit log, newest first.
+    async fn query_tool_audit_log(
+        &self,
+        query: &crate::types::audit::ToolAuditQuery,
+    ) -> CoreResult<crate::types::audit::ToolAuditPage>;
+
+    /// Delete tool audit records older than `before` (retention policy).
+    ///
+    /// Returns the number of records removed.
+    async fn prune_tool_audit_log(&self, before: chrono::DateTime<chrono::Utc>) -> CoreResult<usize>;
+
     // ==================== Importance History (Phase 4, item 5.11) ====================
 
     /// Append an importance change record to the permanent history.
@@ -40,7 +40,7 @@ use uuid::Uuid;
 
 /// Build a 24-byte storage key: `{uuid_bytes}_{timestamp_nanos_be}`.
 ///
-/// Used by MergeRecord, ImportanceChangeRecord, and AuditRecord for
+/// Used by MergeRecord, LineageRecord, ImportanceChangeRecord, and AuditRecord for
 /// consistent key generation across all provenance column families.
 fn build_uuid_ts_key(id: &Uuid, timestamp: &DateTime<Utc>) -> [u8; 24] {
     let mut key = [0u8; 24];
@@ -105,6 +105,83 @@ impl MergeRecord {
     }
 }
 
+// ============================================================================
+// Memory Lineage
+// ============================================================================
+
+/// Operation that produced a lineage edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineageOperation {
+    /// `merge_concepts`: parents were combined into a new memory
+    Merge,
+    /// `trigger_consolidation`: a near-duplicate parent was folded into an
+    /// existing survivor
+    Consolidation,
+}
+
+impl std::fmt::Display for LineageOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Merge => write!(f, "merge"),
+            Self::Consolidation => write!(f, "consolidation"),
+        }
+    }
+}
+
+/// One step of a memory's ancestry: `parent_ids` went into `child_id`.
+///
+/// A memory may have several records (e.g. created by a merge, then absorbing
+/// duplicates during consolidation); following `parent_ids` recursively
+/// yields its ancestry DAG.
+///
+/// # Key Format (CF_MEMORY_LINEAGE)
+///
+/// `{child_uuid_bytes}_{timestamp_nanos_be}` (16 + 8 = 24 bytes)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LineageRecord {
+    /// Memory that received the parents' content
+    pub child_id: Uuid,
+    /// Memories merged or consolidated into the child
+    pub parent_ids: Vec<Uuid>,
+    /// How the parents were combined
+    pub operation: LineageOperation,
+    /// When the operation ran
+    pub timestamp: DateTime<Utc>,
+    /// E1 similarity at merge time (minimum pairwise for multi-parent merges)
+    pub similarity: f32,
+}
+
+impl LineageRecord {
+    /// Create a record timestamped now.
+    pub fn new(
+        child_id: Uuid,
+        parent_ids: Vec<Uuid>,
+        operation: LineageOperation,
+        similarity: f32,
+    ) -> Self {
+        Self {
+            child_id,
+            parent_ids,
+            operation,
+            timestamp: Utc::now(),
+            similarity,
+        }
+    }
+
+    /// Generate the storage key for CF_MEMORY_LINEAGE.
+    ///
+    /// Format: `{child_uuid_bytes}_{timestamp_nanos_be}` (24 bytes).
+    pub fn storage_key(&self) -> [u8; 24] {
+        build_uuid_ts_key(&self.child_id, &self.timestamp)
+    }
+
+    /// Generate a prefix key for scanning all lineage records of a child.
+    pub fn prefix_key(child_id: &Uuid) -> [u8; 16] {
+        build_uuid_prefix(child_id)
+    }
+}
+
 // ============================================================================
 // Importance Change History (Phase 4, item 5.11)
 // ============================================================================
@@ -221,6 +298,10 @@ pub struct EmbeddingVersionRecord {
     pub e7_model_version: Option<String>,
     /// Total embedding computation time in milliseconds
     pub computation_time_ms: Option<u64>,
+    /// Whether the embeddings were computed in determinism mode
+    /// (bit-reproducible; see `config::DeterminismMode`)
+    #[serde(default)]
+    pub deterministic: bool,
 }
 
 // ============================================================================
@@ -371,6 +452,17 @@ pub enum AuditOperation {
         /// Number of memories affected by this event.
         memories_affected: usize,
     },
+
+    /// A near-duplicate memory was folded into a surviving memory.
+    ///
+    /// Recorded against the consolidated (tombstoned) memory; `into` points
+    /// at the survivor that now carries its metadata and edges.
+    MemoryConsolidated {
+        /// UUID of the surviving memory.
+        into: Uuid,
+        /// E1 similarity between the two memories (SRC-3 normalized).
+        similarity: f32,
+    },
 }
 
 // ============================================================================
@@ -566,6 +658,13 @@ impl std::fmt::Display for AuditOperation {
                     event_type, file_path, memories_affected
                 )
             }
+            AuditOperation::MemoryConsolidated { into, similarity } => {
+                write!(
+                    f,
+                    "MemoryConsolidated(into={}, similarity={:.3})",
+                    into, similarity
+                )
+            }
         }
     }
 }
@@ -582,6 +681,139 @@ impl std::fmt::Display for AuditResult {
     }
 }
 
+// ============================================================================
+// Tool Execution Audit
+// ============================================================================
+
+/// One mutating MCP tool call, as recorded in the tool audit log.
+///
+/// Unlike `AuditRecord`, which describes what happened to one memory, a
+/// `ToolAuditRecord` describes one tool invocation: who called which tool,
+/// with which (redacted) arguments, which memories it touched, and whether
+/// it succeeded. `arguments` is redacted by the caller before the record is
+/// built; the store persists it as given.
+///
+/// # Key Format (CF_TOOL_AUDIT_LOG)
+///
+/// `{timestamp_nanos_be}_{uuid_bytes}` (8 + 16 = 24 bytes)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolAuditRecord {
+    /// Unique identifier for this audit record
+    pub id: Uuid,
+    /// When the tool call finished
+    pub timestamp: DateTime<Utc>,
+    /// Session the call belongs to, if known
+    pub session_id: Option<String>,
+    /// Canonical tool name (aliases resolved)
+    pub tool_name: String,
+    /// Call arguments with content fields replaced by hashes
+    pub arguments: serde_json::Value,
+    /// Memories read from the arguments or created/changed by the call
+    pub memory_ids: Vec<Uuid>,
+    /// Whether the call succeeded
+    pub outcome: ToolAuditOutcome,
+    /// Wall-clock handler time in milliseconds
+    pub duration_ms: u64,
+}
+
+/// Outcome of an audited tool call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ToolAuditOutcome {
+    /// The tool returned a result
+    Success,
+    /// The tool returned an error. Only the error code is kept: error
+    /// messages may quote argument values.
+    Error {
+        /// JSON-RPC or tool error code, when the response carried one
+        code: Option<i64>,
+    },
+}
+
+impl ToolAuditRecord {
+    /// Create a record with a fresh UUID, timestamped now.
+    pub fn new(
+        tool_name: impl Into<String>,
+        session_id: Option<String>,
+        arguments: serde_json::Value,
+        memory_ids: Vec<Uuid>,
+        outcome: ToolAuditOutcome,
+        duration_ms: u64,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            session_id,
+            tool_name: tool_name.into(),
+            arguments,
+            memory_ids,
+            outcome,
+            duration_ms,
+        }
+    }
+
+    /// Generate the storage key for CF_TOOL_AUDIT_LOG.
+    ///
+    /// Format: `{timestamp_nanos_be}_{uuid_bytes}` (24 bytes), so iteration
+    /// is chronological.
+    pub fn storage_key(&self) -> [u8; 24] {
+        let mut key = [0u8; 24];
+        let nanos = self.timestamp.timestamp_nanos_opt().unwrap_or(0);
+        key[..8].copy_from_slice(&nanos.to_be_bytes());
+        key[8..24].copy_from_slice(self.id.as_bytes());
+        key
+    }
+}
+
+/// Filter and page for tool audit log queries.
+///
+/// All filters combine with AND; `None` matches everything. Results are
+/// returned newest first, skipping `offset` matches and returning at most
+/// `limit` (0 = no limit).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ToolAuditQuery {
+    /// Only records at or after this time
+    pub from: Option<DateTime<Utc>>,
+    /// Only records at or before this time
+    pub to: Option<DateTime<Utc>>,
+    /// Only calls of this tool
+    pub tool_name: Option<String>,
+    /// Only calls from this session
+    pub session_id: Option<String>,
+    /// Only calls that touched this memory
+    pub memory_id: Option<Uuid>,
+    /// Matching records to skip
+    pub offset: usize,
+    /// Maximum records to return (0 = no limit)
+    pub limit: usize,
+}
+
+impl ToolAuditQuery {
+    /// Whether `record` passes every filter except the time range, which
+    /// stores apply through the key order.
+    pub fn matches(&self, record: &ToolAuditRecord) -> bool {
+        self.tool_name
+            .as_deref()
+            .map_or(true, |t| record.tool_name == t)
+            && self
+                .session_id
+                .as_deref()
+                .map_or(true, |s| record.session_id.as_deref() == Some(s))
+            && self
+                .memory_id
+                .map_or(true, |m| record.memory_ids.contains(&m))
+    }
+}
+
+/// One page of tool audit records, newest first.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ToolAuditPage {
+    /// Records on this page
+    pub records: Vec<ToolAuditRecord>,
+    /// Whether more records match beyond this page
+    pub has_more: bool,
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -720,6 +952,10 @@ mod tests {
                 file_path: "/test/file.rs".to_string(),
                 memories_affected: 12,
             },
+            AuditOperation::MemoryConsolidated {
+                into: Uuid::new_v4(),
+                similarity: 0.97,
+            },
         ];
 
         for op in operations {
@@ -803,6 +1039,60 @@ mod tests {
         assert!(k1 < k2, "Later record should have larger key");
     }
 
+    #[test]
+    fn test_lineage_record_key_and_serialization() {
+        let child = Uuid::new_v4();
+        let parents = vec![Uuid::new_v4(), Uuid::new_v4()];
+        let record = LineageRecord::new(child, parents.clone(), LineageOperation::Merge, 0.91);
+
+        let key = record.storage_key();
+        assert_eq!(&key[..16], child.as_bytes());
+        assert_eq!(LineageRecord::prefix_key(&child), key[..16]);
+
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(json["operation"], "merge");
+        let restored: LineageRecord = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, record);
+        assert_eq!(restored.parent_ids, parents);
+        assert_eq!(LineageOperation::Consolidation.to_string(), "consolidation");
+    }
+
+    #[test]
+    fn test_tool_audit_record_key_and_query_matching() {
+        let memory = Uuid::new_v4();
+        let record = ToolAuditRecord::new(
+            "forget_concept",
+            Some("session-1".to_string()),
+            serde_json::json!({"node_id": memory.to_string()}),
+            vec![memory],
+            ToolAuditOutcome::Success,
+            3,
+        );
+
+        let key = record.storage_key();
+        let nanos = record.timestamp.timestamp_nanos_opt().unwrap();
+        assert_eq!(&key[..8], &nanos.to_be_bytes());
+        assert_eq!(&key[8..], record.id.as_bytes());
+
+        assert!(ToolAuditQuery::default().matches(&record));
+        let by_memory = ToolAuditQuery {
+            memory_id: Some(memory),
+            tool_name: Some("forget_concept".to_string()),
+            ..Default::default()
+        };
+        assert!(by_memory.matches(&record));
+        let other_session = ToolAuditQuery {
+            session_id: Some("session-2".to_string()),
+            ..Default::default()
+        };
+        assert!(!other_session.matches(&record));
+
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(json["outcome"]["status"], "success");
+        let restored: ToolAuditRecord = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, record);
+    }
+
     #[test]
     fn test_display_impls() {
         assert_eq!(
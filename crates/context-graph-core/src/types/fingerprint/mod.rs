@@ -29,19 +29,25 @@
 //! assert!(size > 60000); // ~60KB minimum for dense embeddings
 //! ```
 
+mod partial;
 mod semantic;
 mod sparse;
 mod teleological;
 
 // Re-export SemanticFingerprint types (TASK-F001, TASK-CORE-003)
 pub use semantic::{
-    EmbeddingRef, EmbeddingSlice, SemanticFingerprint, TeleologicalArray, ValidationError, E10_DIM,
+    EmbedderOutput, EmbeddingRef, EmbeddingSlice, FingerprintBuildError, NormStrictness,
+    SemanticFingerprint, SemanticFingerprintBuilder, TeleologicalArray, ValidationError, E10_DIM,
     E11_DIM, E12_TOKEN_DIM, E13_SPLADE_VOCAB, E1_DIM, E2_DIM, E3_DIM, E4_DIM, E5_DIM,
     E6_SPARSE_VOCAB, E7_DIM, E8_DIM, E9_DIM, NUM_EMBEDDERS, TOTAL_DENSE_DIMS,
+    UNIT_NORM_TOLERANCE,
 };
 
 // Re-export SparseVector types (TASK-F001)
 pub use sparse::{SparseVector, SparseVectorError, MAX_SPARSE_ACTIVE, SPARSE_VOCAB_SIZE};
 
 // Re-export TeleologicalFingerprint (TASK-F002)
-pub use teleological::TeleologicalFingerprint;
+pub use teleological::{EmbeddingState, TeleologicalFingerprint};
+
+// Re-export partial-load types for bandwidth-sensitive retrieval
+pub use partial::{PartialEmbedding, PartialFingerprint, E1_MATRYOSHKA_128_DIM};
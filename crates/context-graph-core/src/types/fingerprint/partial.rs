@@ -0,0 +1,179 @@
+//! PartialFingerprint: a subset of a TeleologicalFingerprint loaded on demand.
+//!
+//! Stage 1 filtering of the retrieval pipeline only needs the E1 Matryoshka
+//! 128D prefix and the 13D topic profile. Deserializing the full ~63KB
+//! fingerprint for every candidate wastes bandwidth, so storage backends can
+//! serve `retrieve_partial()` with only the embedders selected by an
+//! [`EmbedderMask`].
+//!
+//! # Always-loaded fields
+//!
+//! `e1_matryoshka_128` and `topic_profile` are cheap (512 + 52 bytes) and are
+//! populated whenever the backend has them, regardless of the mask. An empty
+//! mask therefore requests "E1_128 + topic profile only".
+
+use uuid::Uuid;
+
+use super::semantic::EmbeddingSlice;
+use super::{SparseVector, TeleologicalFingerprint};
+use crate::teleological::{Embedder, EmbedderMask};
+
+/// Dimension of the E1 Matryoshka truncated prefix.
+pub const E1_MATRYOSHKA_128_DIM: usize = 128;
+
+/// Owned embedding data for one loaded embedding space.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PartialEmbedding {
+    /// Dense embedding (E1-E5, E7-E11).
+    Dense(Vec<f32>),
+    /// Sparse embedding (E6, E13).
+    Sparse(SparseVector),
+    /// Token-level embedding (E12).
+    TokenLevel(Vec<Vec<f32>>),
+}
+
+impl PartialEmbedding {
+    /// Borrow as an [`EmbeddingSlice`] (same shape as `SemanticFingerprint::get_embedding`).
+    pub fn as_slice(&self) -> EmbeddingSlice<'_> {
+        match self {
+            Self::Dense(v) => EmbeddingSlice::Dense(v),
+            Self::Sparse(sv) => EmbeddingSlice::Sparse(sv),
+            Self::TokenLevel(tokens) => EmbeddingSlice::TokenLevel(tokens),
+        }
+    }
+
+    /// Copy an embedding slice into owned storage.
+    pub fn from_slice(slice: EmbeddingSlice<'_>) -> Self {
+        match slice {
+            EmbeddingSlice::Dense(v) => Self::Dense(v.to_vec()),
+            EmbeddingSlice::Sparse(sv) => Self::Sparse(sv.clone()),
+            EmbeddingSlice::TokenLevel(tokens) => Self::TokenLevel(tokens.to_vec()),
+        }
+    }
+}
+
+/// A fingerprint with only the requested embedding spaces loaded.
+///
+/// `get_embedding(idx)` mirrors `SemanticFingerprint::get_embedding` but
+/// returns `None` for spaces that were not requested (or not available).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialFingerprint {
+    /// Fingerprint UUID.
+    pub id: Uuid,
+
+    /// Embedders that were requested for this load.
+    pub mask: EmbedderMask,
+
+    /// E1 Matryoshka 128D prefix (always loaded when available).
+    pub e1_matryoshka_128: Option<Vec<f32>>,
+
+    /// 13D topic profile (always loaded when available).
+    ///
+    /// Topic profiles are written by the clustering pipeline, not at store
+    /// time, so this is `None` for memories that have not been profiled yet.
+    pub topic_profile: Option<[f32; 13]>,
+
+    /// Number of bytes read from the backend to build this value.
+    ///
+    /// Useful for verifying that partial loads actually save bandwidth.
+    pub bytes_read: usize,
+
+    embeddings: [Option<PartialEmbedding>; 13],
+}
+
+impl PartialFingerprint {
+    /// Create an empty partial fingerprint for the given mask.
+    pub fn new(id: Uuid, mask: EmbedderMask) -> Self {
+        Self {
+            id,
+            mask,
+            e1_matryoshka_128: None,
+            topic_profile: None,
+            bytes_read: 0,
+            embeddings: Default::default(),
+        }
+    }
+
+    /// Project a full fingerprint down to the embedders in `mask`.
+    ///
+    /// The E1 Matryoshka prefix is derived from the full E1 vector. The topic
+    /// profile is not part of the fingerprint and is left as `None`.
+    pub fn from_full(fp: &TeleologicalFingerprint, mask: EmbedderMask) -> Self {
+        let mut partial = Self::new(fp.id, mask);
+
+        let e1 = &fp.semantic.e1_semantic;
+        if e1.len() >= E1_MATRYOSHKA_128_DIM {
+            partial.e1_matryoshka_128 = Some(e1[..E1_MATRYOSHKA_128_DIM].to_vec());
+        }
+
+        for embedder in mask.iter() {
+            if let Some(slice) = fp.semantic.get_embedding(embedder.index()) {
+                partial.set_embedding(embedder, PartialEmbedding::from_slice(slice));
+            }
+        }
+        partial
+    }
+
+    /// Store a loaded embedding for `embedder`.
+    pub fn set_embedding(&mut self, embedder: Embedder, embedding: PartialEmbedding) {
+        self.embeddings[embedder.index()] = Some(embedding);
+    }
+
+    /// Get embedding by index (0-12).
+    ///
+    /// Returns `None` if the index is out of range or the space was not loaded.
+    pub fn get_embedding(&self, idx: usize) -> Option<EmbeddingSlice<'_>> {
+        self.embeddings
+            .get(idx)
+            .and_then(|e| e.as_ref())
+            .map(PartialEmbedding::as_slice)
+    }
+
+    /// Check whether the embedding for `embedder` was loaded.
+    pub fn is_loaded(&self, embedder: Embedder) -> bool {
+        self.embeddings[embedder.index()].is_some()
+    }
+
+    /// Mask of the embedding spaces that are actually present.
+    pub fn loaded_mask(&self) -> EmbedderMask {
+        let loaded: Vec<Embedder> = Embedder::all().filter(|&e| self.is_loaded(e)).collect();
+        EmbedderMask::from_slice(&loaded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::fingerprint::SemanticFingerprint;
+
+    #[test]
+    fn test_from_full_loads_only_masked_spaces() {
+        let fp = TeleologicalFingerprint::new(SemanticFingerprint::stub(), [7u8; 32]);
+        let mask = EmbedderMask::from_slice(&[Embedder::Semantic, Embedder::KeywordSplade]);
+        let partial = PartialFingerprint::from_full(&fp, mask);
+
+        assert_eq!(partial.id, fp.id);
+        assert_eq!(partial.loaded_mask(), mask);
+        assert!(partial.get_embedding(Embedder::Code.index()).is_none());
+        assert!(partial.get_embedding(13).is_none());
+
+        match partial.get_embedding(Embedder::Semantic.index()) {
+            Some(EmbeddingSlice::Dense(v)) => assert_eq!(v, fp.semantic.e1_semantic.as_slice()),
+            other => panic!("expected dense E1, got {:?}", other),
+        }
+        assert_eq!(
+            partial.e1_matryoshka_128.as_deref(),
+            Some(&fp.semantic.e1_semantic[..E1_MATRYOSHKA_128_DIM])
+        );
+    }
+
+    #[test]
+    fn test_empty_mask_keeps_only_matryoshka() {
+        let fp = TeleologicalFingerprint::new(SemanticFingerprint::stub(), [1u8; 32]);
+        let partial = PartialFingerprint::from_full(&fp, EmbedderMask::new());
+
+        assert!(partial.loaded_mask().is_empty());
+        assert!(partial.e1_matryoshka_128.is_some());
+        assert!(partial.topic_profile.is_none());
+    }
+}
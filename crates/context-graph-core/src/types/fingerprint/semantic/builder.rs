@@ -0,0 +1,500 @@
+//! Validated construction of SemanticFingerprint.
+//!
+//! Filling the 13 spaces of a [`SemanticFingerprint`] by hand is easy to get
+//! wrong: every dense space is a `Vec<f32>`, so assigning the E3 vector to
+//! `e2_temporal_recent` compiles and silently corrupts temporal search.
+//! [`SemanticFingerprintBuilder`] takes one typed setter per space, checks
+//! dimensions as each space is set, and [`SemanticFingerprintBuilder::build`]
+//! reports every missing or invalid space at once.
+//!
+//! Model index `i` (the order of `MultiArrayEmbeddingOutput::model_ids` and
+//! `per_embedder_latency`) is space `Embedder::from_index(i)`. Which fields
+//! each space fills is decided only in [`SemanticFingerprintBuilder::set`]
+//! and the `place` step of `build`.
+
+use std::fmt;
+
+use tracing::warn;
+
+use super::constants::{
+    E10_DIM, E11_DIM, E12_TOKEN_DIM, E13_SPLADE_VOCAB, E1_DIM, E2_DIM, E3_DIM, E4_DIM, E5_DIM,
+    E6_SPARSE_VOCAB, E7_DIM, E8_DIM, E9_DIM, NUM_EMBEDDERS,
+};
+use super::fingerprint::{SemanticFingerprint, ValidationError};
+use crate::teleological::{Embedder, EmbedderMask};
+use crate::traits::MultiArrayEmbeddingOutput;
+use crate::types::fingerprint::SparseVector;
+
+/// Allowed distance of a dense vector's L2 norm from 1.0.
+pub const UNIT_NORM_TOLERANCE: f32 = 1e-3;
+
+/// How [`SemanticFingerprintBuilder::build`] treats dense vectors that are
+/// not unit-norm. All-zero vectors (skipped spaces) are never flagged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NormStrictness {
+    /// No norm check.
+    Ignore,
+    /// Log a warning per offending vector.
+    #[default]
+    Warn,
+    /// Fail the build with [`ValidationError::NotUnitNorm`].
+    Error,
+}
+
+/// Output of one embedding model, before it is placed in its space.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EmbedderOutput {
+    /// E1-E4, E7, E9, E11.
+    Dense(Vec<f32>),
+    /// E5 (cause, effect), E8 (source, target), E10 (paraphrase, context).
+    DualDense(Vec<f32>, Vec<f32>),
+    /// E6, E13.
+    Sparse(SparseVector),
+    /// E12, one 128D vector per token.
+    Tokens(Vec<Vec<f32>>),
+}
+
+impl EmbedderOutput {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Dense(_) => "dense",
+            Self::DualDense(..) => "dual dense",
+            Self::Sparse(_) => "sparse",
+            Self::Tokens(_) => "tokens",
+        }
+    }
+
+    /// Move the space of `embedder` out of `fingerprint`.
+    fn take(fingerprint: &mut SemanticFingerprint, embedder: Embedder) -> Self {
+        use std::mem::take;
+
+        let fp = fingerprint;
+        match embedder {
+            Embedder::Semantic => Self::Dense(take(&mut fp.e1_semantic)),
+            Embedder::TemporalRecent => Self::Dense(take(&mut fp.e2_temporal_recent)),
+            Embedder::TemporalPeriodic => Self::Dense(take(&mut fp.e3_temporal_periodic)),
+            Embedder::TemporalPositional => Self::Dense(take(&mut fp.e4_temporal_positional)),
+            Embedder::Causal => {
+                fp.migrate_legacy_e5();
+                Self::DualDense(
+                    take(&mut fp.e5_causal_as_cause),
+                    take(&mut fp.e5_causal_as_effect),
+                )
+            }
+            Embedder::Sparse => {
+                Self::Sparse(std::mem::replace(&mut fp.e6_sparse, SparseVector::empty()))
+            }
+            Embedder::Code => Self::Dense(take(&mut fp.e7_code)),
+            Embedder::Graph => {
+                if fp.e8_graph_as_source.is_empty() && fp.e8_graph_as_target.is_empty() {
+                    fp.e8_graph_as_source = fp.e8_graph.clone();
+                    fp.e8_graph_as_target = take(&mut fp.e8_graph);
+                }
+                Self::DualDense(
+                    take(&mut fp.e8_graph_as_source),
+                    take(&mut fp.e8_graph_as_target),
+                )
+            }
+            Embedder::Hdc => Self::Dense(take(&mut fp.e9_hdc)),
+            Embedder::Contextual => Self::DualDense(
+                take(&mut fp.e10_multimodal_paraphrase),
+                take(&mut fp.e10_multimodal_as_context),
+            ),
+            Embedder::Entity => Self::Dense(take(&mut fp.e11_entity)),
+            Embedder::LateInteraction => Self::Tokens(take(&mut fp.e12_late_interaction)),
+            Embedder::KeywordSplade => {
+                Self::Sparse(std::mem::replace(&mut fp.e13_splade, SparseVector::empty()))
+            }
+        }
+    }
+}
+
+/// Every missing or invalid space found by a build.
+#[derive(Debug, Clone)]
+pub struct FingerprintBuildError {
+    pub errors: Vec<ValidationError>,
+}
+
+impl fmt::Display for FingerprintBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} invalid fingerprint space(s): ", self.errors.len())?;
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for FingerprintBuildError {}
+
+/// Builder for [`SemanticFingerprint`] with per-space validation.
+///
+/// ```
+/// use context_graph_core::types::fingerprint::{SemanticFingerprintBuilder, E1_DIM};
+///
+/// let result = SemanticFingerprintBuilder::new()
+///     .set_e1(vec![0.0; E1_DIM])
+///     .set_e2(vec![0.0; 100])
+///     .build();
+/// // E2 has the wrong dimension and E3-E13 are missing: all are reported
+/// assert_eq!(result.unwrap_err().errors.len(), 12);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SemanticFingerprintBuilder {
+    spaces: [Option<EmbedderOutput>; NUM_EMBEDDERS],
+    /// Spaces a setter was called for, valid or not.
+    attempted: [bool; NUM_EMBEDDERS],
+    errors: Vec<ValidationError>,
+    strictness: NormStrictness,
+}
+
+impl SemanticFingerprintBuilder {
+    /// Empty builder with [`NormStrictness::Warn`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder holding the output of model `i` in space `i`.
+    pub fn from_model_outputs(outputs: [EmbedderOutput; NUM_EMBEDDERS]) -> Self {
+        Embedder::all()
+            .zip(outputs)
+            .fold(Self::new(), |builder, (embedder, output)| {
+                builder.set(embedder, output)
+            })
+    }
+
+    /// Builder re-validating every space of a provider's fingerprint.
+    pub fn from_provider_output(output: MultiArrayEmbeddingOutput) -> Self {
+        let mut fingerprint = output.fingerprint;
+        let outputs: [EmbedderOutput; NUM_EMBEDDERS] = std::array::from_fn(|i| {
+            let embedder = Embedder::from_index(i).expect("model index below NUM_EMBEDDERS");
+            EmbedderOutput::take(&mut fingerprint, embedder)
+        });
+        Self::from_model_outputs(outputs)
+    }
+
+    /// Set how `build` treats dense vectors that are not unit-norm.
+    pub fn with_norm_strictness(mut self, strictness: NormStrictness) -> Self {
+        self.strictness = strictness;
+        self
+    }
+
+    /// Place `output` in the space of `embedder`, checking its shape.
+    ///
+    /// An output of the wrong kind or dimension is recorded as an error and
+    /// reported by `build`; the space stays unset.
+    pub fn set(mut self, embedder: Embedder, output: EmbedderOutput) -> Self {
+        let idx = embedder.index();
+        self.attempted[idx] = true;
+        self.spaces[idx] = None;
+
+        let checked = match (embedder, &output) {
+            (
+                Embedder::Semantic
+                | Embedder::TemporalRecent
+                | Embedder::TemporalPeriodic
+                | Embedder::TemporalPositional
+                | Embedder::Code
+                | Embedder::Hdc
+                | Embedder::Entity,
+                EmbedderOutput::Dense(v),
+            ) => check_dense(embedder, v),
+            (
+                Embedder::Causal | Embedder::Graph | Embedder::Contextual,
+                EmbedderOutput::DualDense(a, b),
+            ) => check_dense(embedder, a).and_then(|()| check_dense(embedder, b)),
+            (Embedder::Sparse | Embedder::KeywordSplade, EmbedderOutput::Sparse(sv)) => {
+                check_sparse(embedder, sv)
+            }
+            (Embedder::LateInteraction, EmbedderOutput::Tokens(tokens)) => check_tokens(tokens),
+            _ => Err(ValidationError::WrongRepresentation {
+                embedder,
+                expected: expected_kind(embedder),
+                actual: output.kind(),
+            }),
+        };
+
+        match checked {
+            Ok(()) => self.spaces[idx] = Some(output),
+            Err(e) => self.errors.push(e),
+        }
+        self
+    }
+
+    /// E1 semantic, `E1_DIM` values.
+    pub fn set_e1(self, v: Vec<f32>) -> Self {
+        self.set(Embedder::Semantic, EmbedderOutput::Dense(v))
+    }
+
+    /// E2 temporal-recent, `E2_DIM` values.
+    pub fn set_e2(self, v: Vec<f32>) -> Self {
+        self.set(Embedder::TemporalRecent, EmbedderOutput::Dense(v))
+    }
+
+    /// E3 temporal-periodic, `E3_DIM` values.
+    pub fn set_e3(self, v: Vec<f32>) -> Self {
+        self.set(Embedder::TemporalPeriodic, EmbedderOutput::Dense(v))
+    }
+
+    /// E4 temporal-positional, `E4_DIM` values.
+    pub fn set_e4(self, v: Vec<f32>) -> Self {
+        self.set(Embedder::TemporalPositional, EmbedderOutput::Dense(v))
+    }
+
+    /// E5 causal, `E5_DIM` values each for the cause and effect encodings.
+    pub fn set_e5(self, as_cause: Vec<f32>, as_effect: Vec<f32>) -> Self {
+        self.set(
+            Embedder::Causal,
+            EmbedderOutput::DualDense(as_cause, as_effect),
+        )
+    }
+
+    /// E6 sparse lexical, indices below `E6_SPARSE_VOCAB`.
+    pub fn set_e6_sparse(self, sv: SparseVector) -> Self {
+        self.set(Embedder::Sparse, EmbedderOutput::Sparse(sv))
+    }
+
+    /// E7 code, `E7_DIM` values.
+    pub fn set_e7(self, v: Vec<f32>) -> Self {
+        self.set(Embedder::Code, EmbedderOutput::Dense(v))
+    }
+
+    /// E8 graph, `E8_DIM` values each for the source and target encodings.
+    pub fn set_e8(self, as_source: Vec<f32>, as_target: Vec<f32>) -> Self {
+        self.set(
+            Embedder::Graph,
+            EmbedderOutput::DualDense(as_source, as_target),
+        )
+    }
+
+    /// E9 HDC projection, `E9_DIM` values.
+    pub fn set_e9(self, v: Vec<f32>) -> Self {
+        self.set(Embedder::Hdc, EmbedderOutput::Dense(v))
+    }
+
+    /// E10 multimodal, `E10_DIM` values each for paraphrase and context.
+    pub fn set_e10(self, paraphrase: Vec<f32>, as_context: Vec<f32>) -> Self {
+        self.set(
+            Embedder::Contextual,
+            EmbedderOutput::DualDense(paraphrase, as_context),
+        )
+    }
+
+    /// E11 entity, `E11_DIM` values.
+    pub fn set_e11(self, v: Vec<f32>) -> Self {
+        self.set(Embedder::Entity, EmbedderOutput::Dense(v))
+    }
+
+    /// E12 late-interaction tokens; fixed-size tokens cannot be mis-sized.
+    pub fn set_e12_tokens(self, tokens: Vec<[f32; E12_TOKEN_DIM]>) -> Self {
+        let tokens = tokens.into_iter().map(|t| t.to_vec()).collect();
+        self.set(Embedder::LateInteraction, EmbedderOutput::Tokens(tokens))
+    }
+
+    /// E12 late-interaction tokens as model output, `E12_TOKEN_DIM` each.
+    pub fn set_e12(self, tokens: Vec<Vec<f32>>) -> Self {
+        self.set(Embedder::LateInteraction, EmbedderOutput::Tokens(tokens))
+    }
+
+    /// E13 SPLADE, indices below `E13_SPLADE_VOCAB`.
+    pub fn set_e13_sparse(self, sv: SparseVector) -> Self {
+        self.set(Embedder::KeywordSplade, EmbedderOutput::Sparse(sv))
+    }
+
+    /// Complete fingerprint, or every missing and invalid space.
+    pub fn build(mut self) -> Result<SemanticFingerprint, FingerprintBuildError> {
+        for embedder in Embedder::all() {
+            if !self.attempted[embedder.index()] {
+                self.errors
+                    .push(ValidationError::MissingEmbedding { embedder });
+            }
+        }
+        self.build_partial().map(|(fingerprint, _)| fingerprint)
+    }
+
+    /// Fingerprint of the spaces that were set, the rest left empty, and
+    /// the mask of set spaces. Invalid spaces still fail the build.
+    pub fn build_partial(
+        mut self,
+    ) -> Result<(SemanticFingerprint, EmbedderMask), FingerprintBuildError> {
+        self.check_norms();
+        if !self.errors.is_empty() {
+            return Err(FingerprintBuildError {
+                errors: self.errors,
+            });
+        }
+
+        let mut fingerprint = empty_fingerprint();
+        let mut mask = EmbedderMask::new();
+        for (embedder, output) in Embedder::all().zip(self.spaces) {
+            if let Some(output) = output {
+                place(&mut fingerprint, embedder, output);
+                mask.set(embedder);
+            }
+        }
+        Ok((fingerprint, mask))
+    }
+
+    fn check_norms(&mut self) {
+        if self.strictness == NormStrictness::Ignore {
+            return;
+        }
+        for (embedder, output) in Embedder::all().zip(&self.spaces) {
+            let vectors: Vec<&Vec<f32>> = match output {
+                Some(EmbedderOutput::Dense(v)) => vec![v],
+                Some(EmbedderOutput::DualDense(a, b)) => vec![a, b],
+                _ => continue,
+            };
+            for v in vectors {
+                let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+                if norm == 0.0 || (norm - 1.0).abs() <= UNIT_NORM_TOLERANCE {
+                    continue;
+                }
+                match self.strictness {
+                    NormStrictness::Warn => {
+                        warn!(embedder = %embedder, norm, "Dense embedding is not unit-norm")
+                    }
+                    NormStrictness::Error => self
+                        .errors
+                        .push(ValidationError::NotUnitNorm { embedder, norm }),
+                    NormStrictness::Ignore => {}
+                }
+            }
+        }
+    }
+}
+
+fn expected_kind(embedder: Embedder) -> &'static str {
+    match embedder {
+        Embedder::Causal | Embedder::Graph | Embedder::Contextual => "dual dense",
+        Embedder::Sparse | Embedder::KeywordSplade => "sparse",
+        Embedder::LateInteraction => "tokens",
+        _ => "dense",
+    }
+}
+
+fn dense_dim(embedder: Embedder) -> usize {
+    match embedder {
+        Embedder::Semantic => E1_DIM,
+        Embedder::TemporalRecent => E2_DIM,
+        Embedder::TemporalPeriodic => E3_DIM,
+        Embedder::TemporalPositional => E4_DIM,
+        Embedder::Causal => E5_DIM,
+        Embedder::Code => E7_DIM,
+        Embedder::Graph => E8_DIM,
+        Embedder::Hdc => E9_DIM,
+        Embedder::Contextual => E10_DIM,
+        Embedder::Entity => E11_DIM,
+        Embedder::LateInteraction => E12_TOKEN_DIM,
+        Embedder::Sparse => E6_SPARSE_VOCAB,
+        Embedder::KeywordSplade => E13_SPLADE_VOCAB,
+    }
+}
+
+fn check_dense(embedder: Embedder, v: &[f32]) -> Result<(), ValidationError> {
+    let expected = dense_dim(embedder);
+    if v.is_empty() {
+        return Err(ValidationError::EmptyDenseEmbedding { embedder, expected });
+    }
+    if v.len() != expected {
+        return Err(ValidationError::DimensionMismatch {
+            embedder,
+            expected,
+            actual: v.len(),
+        });
+    }
+    Ok(())
+}
+
+fn check_sparse(embedder: Embedder, sv: &SparseVector) -> Result<(), ValidationError> {
+    if sv.indices.len() != sv.values.len() {
+        return Err(ValidationError::SparseIndicesValuesMismatch {
+            embedder,
+            indices_len: sv.indices.len(),
+            values_len: sv.values.len(),
+        });
+    }
+    let vocab_size = dense_dim(embedder);
+    match sv.indices.iter().find(|&&idx| idx as usize >= vocab_size) {
+        Some(&index) => Err(ValidationError::SparseIndexOutOfBounds {
+            embedder,
+            index: index as u32,
+            vocab_size,
+        }),
+        None => Ok(()),
+    }
+}
+
+fn check_tokens(tokens: &[Vec<f32>]) -> Result<(), ValidationError> {
+    match tokens.iter().position(|t| t.len() != E12_TOKEN_DIM) {
+        Some(token_index) => Err(ValidationError::TokenDimensionMismatch {
+            embedder: Embedder::LateInteraction,
+            token_index,
+            expected: E12_TOKEN_DIM,
+            actual: tokens[token_index].len(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Fingerprint with every space empty (not computed).
+fn empty_fingerprint() -> SemanticFingerprint {
+    SemanticFingerprint {
+        e1_semantic: Vec::new(),
+        e2_temporal_recent: Vec::new(),
+        e3_temporal_periodic: Vec::new(),
+        e4_temporal_positional: Vec::new(),
+        e5_causal_as_cause: Vec::new(),
+        e5_causal_as_effect: Vec::new(),
+        e5_causal: Vec::new(),
+        e6_sparse: SparseVector::empty(),
+        e7_code: Vec::new(),
+        e8_graph_as_source: Vec::new(),
+        e8_graph_as_target: Vec::new(),
+        e8_graph: Vec::new(),
+        e9_hdc: Vec::new(),
+        e10_multimodal_paraphrase: Vec::new(),
+        e10_multimodal_as_context: Vec::new(),
+        e11_entity: Vec::new(),
+        e12_late_interaction: Vec::new(),
+        e13_splade: SparseVector::empty(),
+    }
+}
+
+/// Move a validated output into its fields. Legacy E5/E8 fields stay empty.
+fn place(fp: &mut SemanticFingerprint, embedder: Embedder, output: EmbedderOutput) {
+    match (embedder, output) {
+        (Embedder::Semantic, EmbedderOutput::Dense(v)) => fp.e1_semantic = v,
+        (Embedder::TemporalRecent, EmbedderOutput::Dense(v)) => fp.e2_temporal_recent = v,
+        (Embedder::TemporalPeriodic, EmbedderOutput::Dense(v)) => fp.e3_temporal_periodic = v,
+        (Embedder::TemporalPositional, EmbedderOutput::Dense(v)) => fp.e4_temporal_positional = v,
+        (Embedder::Causal, EmbedderOutput::DualDense(cause, effect)) => {
+            fp.e5_causal_as_cause = cause;
+            fp.e5_causal_as_effect = effect;
+        }
+        (Embedder::Sparse, EmbedderOutput::Sparse(sv)) => fp.e6_sparse = sv,
+        (Embedder::Code, EmbedderOutput::Dense(v)) => fp.e7_code = v,
+        (Embedder::Graph, EmbedderOutput::DualDense(source, target)) => {
+            fp.e8_graph_as_source = source;
+            fp.e8_graph_as_target = target;
+        }
+        (Embedder::Hdc, EmbedderOutput::Dense(v)) => fp.e9_hdc = v,
+        (Embedder::Contextual, EmbedderOutput::DualDense(paraphrase, context)) => {
+            fp.e10_multimodal_paraphrase = paraphrase;
+            fp.e10_multimodal_as_context = context;
+        }
+        (Embedder::Entity, EmbedderOutput::Dense(v)) => fp.e11_entity = v,
+        (Embedder::LateInteraction, EmbedderOutput::Tokens(tokens)) => {
+            fp.e12_late_interaction = tokens
+        }
+        (Embedder::KeywordSplade, EmbedderOutput::Sparse(sv)) => fp.e13_splade = sv,
+        (embedder, output) => unreachable!(
+            "{} output for {} passed validation in SemanticFingerprintBuilder::set",
+            output.kind(),
+            embedder
+        ),
+    }
+}
@@ -16,6 +16,8 @@ use super::constants::{
     E6_SPARSE_VOCAB, E7_DIM, E8_DIM, E9_DIM,
 };
 use super::slice::EmbeddingSlice;
+#[cfg(any(test, feature = "test-utils"))]
+use super::builder::{NormStrictness, SemanticFingerprintBuilder};
 use crate::teleological::Embedder;
 use crate::types::fingerprint::SparseVector;
 
@@ -110,6 +112,33 @@ pub enum ValidationError {
         /// Length of values vector
         values_len: usize,
     },
+
+    /// A space was never set on a `SemanticFingerprintBuilder`.
+    #[error("Missing embedding for {embedder}")]
+    MissingEmbedding {
+        /// The embedder with no output
+        embedder: Embedder,
+    },
+
+    /// A space was given the wrong kind of output (e.g. sparse for E1).
+    #[error("Wrong representation for {embedder}: expected {expected}, got {actual}")]
+    WrongRepresentation {
+        /// The embedder the output was set for
+        embedder: Embedder,
+        /// Representation the space stores
+        expected: &'static str,
+        /// Representation that was given
+        actual: &'static str,
+    },
+
+    /// A dense vector is not unit-norm (strict builds only).
+    #[error("Non-unit-norm embedding for {embedder}: L2 norm {norm}")]
+    NotUnitNorm {
+        /// The embedder with the offending vector
+        embedder: Embedder,
+        /// The vector's L2 norm
+        norm: f32,
+    },
 }
 
 /// SemanticFingerprint: Stores all 13 embeddings without fusion.
@@ -243,26 +272,7 @@ impl SemanticFingerprint {
     #[cfg(any(test, feature = "test-utils"))]
     #[must_use = "zeroed fingerprints should be used explicitly and with caution"]
     pub fn zeroed() -> Self {
-        Self {
-            e1_semantic: vec![0.0; E1_DIM],
-            e2_temporal_recent: vec![0.0; E2_DIM],
-            e3_temporal_periodic: vec![0.0; E3_DIM],
-            e4_temporal_positional: vec![0.0; E4_DIM],
-            e5_causal_as_cause: vec![0.0; E5_DIM],
-            e5_causal_as_effect: vec![0.0; E5_DIM],
-            e5_causal: Vec::new(), // Legacy field, empty by default
-            e6_sparse: SparseVector::empty(),
-            e7_code: vec![0.0; E7_DIM],
-            e8_graph_as_source: vec![0.0; E8_DIM],
-            e8_graph_as_target: vec![0.0; E8_DIM],
-            e8_graph: Vec::new(), // Legacy field, empty by default
-            e9_hdc: vec![0.0; E9_DIM],
-            e10_multimodal_paraphrase: vec![0.0; E10_DIM],
-            e10_multimodal_as_context: vec![0.0; E10_DIM],
-            e11_entity: vec![0.0; E11_DIM],
-            e12_late_interaction: Vec::new(),
-            e13_splade: SparseVector::empty(),
-        }
+        Self::filled(0.0)
     }
 
     /// Create a non-zero stub fingerprint for testing.
@@ -278,26 +288,29 @@ impl SemanticFingerprint {
     #[cfg(any(test, feature = "test-utils"))]
     #[must_use]
     pub fn stub() -> Self {
-        Self {
-            e1_semantic: vec![0.1; E1_DIM],
-            e2_temporal_recent: vec![0.1; E2_DIM],
-            e3_temporal_periodic: vec![0.1; E3_DIM],
-            e4_temporal_positional: vec![0.1; E4_DIM],
-            e5_causal_as_cause: vec![0.1; E5_DIM],
-            e5_causal_as_effect: vec![0.1; E5_DIM],
-            e5_causal: Vec::new(),
-            e6_sparse: SparseVector::empty(),
-            e7_code: vec![0.1; E7_DIM],
-            e8_graph_as_source: vec![0.1; E8_DIM],
-            e8_graph_as_target: vec![0.1; E8_DIM],
-            e8_graph: Vec::new(),
-            e9_hdc: vec![0.1; E9_DIM],
-            e10_multimodal_paraphrase: vec![0.1; E10_DIM],
-            e10_multimodal_as_context: vec![0.1; E10_DIM],
-            e11_entity: vec![0.1; E11_DIM],
-            e12_late_interaction: Vec::new(),
-            e13_splade: SparseVector::empty(),
-        }
+        Self::filled(0.1)
+    }
+
+    /// Every dense component set to `value`; no E12 tokens, empty sparse spaces.
+    #[cfg(any(test, feature = "test-utils"))]
+    fn filled(value: f32) -> Self {
+        SemanticFingerprintBuilder::new()
+            .with_norm_strictness(NormStrictness::Ignore)
+            .set_e1(vec![value; E1_DIM])
+            .set_e2(vec![value; E2_DIM])
+            .set_e3(vec![value; E3_DIM])
+            .set_e4(vec![value; E4_DIM])
+            .set_e5(vec![value; E5_DIM], vec![value; E5_DIM])
+            .set_e6_sparse(SparseVector::empty())
+            .set_e7(vec![value; E7_DIM])
+            .set_e8(vec![value; E8_DIM], vec![value; E8_DIM])
+            .set_e9(vec![value; E9_DIM])
+            .set_e10(vec![value; E10_DIM], vec![value; E10_DIM])
+            .set_e11(vec![value; E11_DIM])
+            .set_e12(Vec::new())
+            .set_e13_sparse(SparseVector::empty())
+            .build()
+            .expect("space constants match the builder's dimension checks")
     }
 
     /// Get embedding by index (0-12).
@@ -788,6 +801,88 @@ impl SemanticFingerprint {
             && self.e5_causal_as_cause.is_empty()
             && self.e5_causal_as_effect.is_empty()
     }
+
+    /// Remove the data for one embedding space, leaving it empty.
+    ///
+    /// Dual-vector spaces (E5, E8, E10) clear both vectors and the legacy
+    /// field. Used for partial fingerprints, where an empty space means
+    /// "not computed" rather than a zero vector.
+    pub fn clear_embedding(&mut self, embedder: Embedder) {
+        match embedder {
+            Embedder::Semantic => self.e1_semantic = Vec::new(),
+            Embedder::TemporalRecent => self.e2_temporal_recent = Vec::new(),
+            Embedder::TemporalPeriodic => self.e3_temporal_periodic = Vec::new(),
+            Embedder::TemporalPositional => self.e4_temporal_positional = Vec::new(),
+            Embedder::Causal => {
+                self.e5_causal_as_cause = Vec::new();
+                self.e5_causal_as_effect = Vec::new();
+                self.e5_causal = Vec::new();
+            }
+            Embedder::Sparse => self.e6_sparse = SparseVector::empty(),
+            Embedder::Code => self.e7_code = Vec::new(),
+            Embedder::Graph => {
+                self.e8_graph_as_source = Vec::new();
+                self.e8_graph_as_target = Vec::new();
+                self.e8_graph = Vec::new();
+            }
+            Embedder::Hdc => self.e9_hdc = Vec::new(),
+            Embedder::Contextual => {
+                self.e10_multimodal_paraphrase = Vec::new();
+                self.e10_multimodal_as_context = Vec::new();
+            }
+            Embedder::Entity => self.e11_entity = Vec::new(),
+            Embedder::LateInteraction => self.e12_late_interaction = Vec::new(),
+            Embedder::KeywordSplade => self.e13_splade = SparseVector::empty(),
+        }
+    }
+
+    /// Move one embedding space out of `source` into `self`.
+    ///
+    /// Replaces the space in `self` (both vectors and the legacy field for
+    /// dual spaces) and leaves it empty in `source`. Used when re-embedding
+    /// selected spaces of a stored fingerprint.
+    pub fn take_embedding(&mut self, embedder: Embedder, source: &mut SemanticFingerprint) {
+        use std::mem::take;
+
+        match embedder {
+            Embedder::Semantic => self.e1_semantic = take(&mut source.e1_semantic),
+            Embedder::TemporalRecent => {
+                self.e2_temporal_recent = take(&mut source.e2_temporal_recent)
+            }
+            Embedder::TemporalPeriodic => {
+                self.e3_temporal_periodic = take(&mut source.e3_temporal_periodic)
+            }
+            Embedder::TemporalPositional => {
+                self.e4_temporal_positional = take(&mut source.e4_temporal_positional)
+            }
+            Embedder::Causal => {
+                self.e5_causal_as_cause = take(&mut source.e5_causal_as_cause);
+                self.e5_causal_as_effect = take(&mut source.e5_causal_as_effect);
+                self.e5_causal = take(&mut source.e5_causal);
+            }
+            Embedder::Sparse => {
+                self.e6_sparse = std::mem::replace(&mut source.e6_sparse, SparseVector::empty())
+            }
+            Embedder::Code => self.e7_code = take(&mut source.e7_code),
+            Embedder::Graph => {
+                self.e8_graph_as_source = take(&mut source.e8_graph_as_source);
+                self.e8_graph_as_target = take(&mut source.e8_graph_as_target);
+                self.e8_graph = take(&mut source.e8_graph);
+            }
+            Embedder::Hdc => self.e9_hdc = take(&mut source.e9_hdc),
+            Embedder::Contextual => {
+                self.e10_multimodal_paraphrase = take(&mut source.e10_multimodal_paraphrase);
+                self.e10_multimodal_as_context = take(&mut source.e10_multimodal_as_context);
+            }
+            Embedder::Entity => self.e11_entity = take(&mut source.e11_entity),
+            Embedder::LateInteraction => {
+                self.e12_late_interaction = take(&mut source.e12_late_interaction)
+            }
+            Embedder::KeywordSplade => {
+                self.e13_splade = std::mem::replace(&mut source.e13_splade, SparseVector::empty())
+            }
+        }
+    }
 }
 
 // NOTE: Default is intentionally NOT implemented for SemanticFingerprint.
@@ -22,6 +22,7 @@
 //!
 //! Typical storage is ~46KB per fingerprint (vs ~6KB fused = 67% info loss avoided).
 
+mod builder;
 mod constants;
 mod fingerprint;
 mod slice;
@@ -31,6 +32,10 @@ mod validation;
 mod tests;
 
 // Re-export all public types
+pub use builder::{
+    EmbedderOutput, FingerprintBuildError, NormStrictness, SemanticFingerprintBuilder,
+    UNIT_NORM_TOLERANCE,
+};
 pub use constants::{
     E10_DIM, E11_DIM, E12_TOKEN_DIM, E13_SPLADE_VOCAB, E1_DIM, E2_DIM, E3_DIM, E4_DIM, E5_DIM,
     E6_SPARSE_VOCAB, E7_DIM, E8_DIM, E9_DIM, NUM_EMBEDDERS, TOTAL_DENSE_DIMS,
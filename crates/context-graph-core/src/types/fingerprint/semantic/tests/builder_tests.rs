@@ -0,0 +1,205 @@
+//! Tests for SemanticFingerprintBuilder.
+//!
+//! The builder is the single place model outputs are mapped to fingerprint
+//! fields, so these tests pin the index mapping and the aggregated errors.
+
+use crate::teleological::Embedder;
+use crate::types::fingerprint::semantic::*;
+use crate::types::fingerprint::SparseVector;
+
+/// Output for model `i` whose values identify the model.
+fn model_output(embedder: Embedder) -> EmbedderOutput {
+    let marker = (embedder.index() + 1) as f32 * 0.01;
+    let dense = |dim: usize| vec![marker; dim];
+    match embedder {
+        Embedder::Semantic => EmbedderOutput::Dense(dense(E1_DIM)),
+        Embedder::TemporalRecent => EmbedderOutput::Dense(dense(E2_DIM)),
+        Embedder::TemporalPeriodic => EmbedderOutput::Dense(dense(E3_DIM)),
+        Embedder::TemporalPositional => EmbedderOutput::Dense(dense(E4_DIM)),
+        Embedder::Causal => EmbedderOutput::DualDense(dense(E5_DIM), vec![-marker; E5_DIM]),
+        Embedder::Code => EmbedderOutput::Dense(dense(E7_DIM)),
+        Embedder::Graph => EmbedderOutput::DualDense(dense(E8_DIM), vec![-marker; E8_DIM]),
+        Embedder::Hdc => EmbedderOutput::Dense(dense(E9_DIM)),
+        Embedder::Contextual => EmbedderOutput::DualDense(dense(E10_DIM), vec![-marker; E10_DIM]),
+        Embedder::Entity => EmbedderOutput::Dense(dense(E11_DIM)),
+        Embedder::LateInteraction => EmbedderOutput::Tokens(vec![dense(E12_TOKEN_DIM); 3]),
+        Embedder::Sparse | Embedder::KeywordSplade => EmbedderOutput::Sparse(
+            SparseVector::new(vec![embedder.index() as u16], vec![marker])
+                .expect("valid sparse vector"),
+        ),
+    }
+}
+
+fn first_value(slice: EmbeddingSlice<'_>) -> f32 {
+    match slice {
+        EmbeddingSlice::Dense(v) => v[0],
+        EmbeddingSlice::Sparse(sv) => sv.values[0],
+        EmbeddingSlice::TokenLevel(tokens) => tokens[0][0],
+    }
+}
+
+#[test]
+fn test_builder_rejects_wrong_dimension() {
+    let err = SemanticFingerprintBuilder::new()
+        .set_e1(vec![0.0; 100])
+        .build_partial()
+        .unwrap_err();
+
+    assert_eq!(err.errors.len(), 1);
+    assert!(matches!(
+        err.errors[0],
+        ValidationError::DimensionMismatch {
+            embedder: Embedder::Semantic,
+            expected: E1_DIM,
+            actual: 100,
+        }
+    ));
+}
+
+#[test]
+fn test_builder_rejects_wrong_representation() {
+    let err = SemanticFingerprintBuilder::new()
+        .set(
+            Embedder::Semantic,
+            EmbedderOutput::Sparse(SparseVector::empty()),
+        )
+        .build_partial()
+        .unwrap_err();
+
+    assert!(matches!(
+        err.errors[0],
+        ValidationError::WrongRepresentation {
+            embedder: Embedder::Semantic,
+            expected: "dense",
+            actual: "sparse",
+        }
+    ));
+}
+
+#[test]
+fn test_builder_reports_every_missing_space() {
+    let err = SemanticFingerprintBuilder::new()
+        .set_e1(vec![0.0; E1_DIM])
+        .build()
+        .unwrap_err();
+
+    let missing: Vec<Embedder> = err
+        .errors
+        .iter()
+        .map(|e| match e {
+            ValidationError::MissingEmbedding { embedder } => *embedder,
+            other => panic!("unexpected error: {}", other),
+        })
+        .collect();
+    let expected: Vec<Embedder> = Embedder::all().skip(1).collect();
+    assert_eq!(missing, expected);
+}
+
+#[test]
+fn test_builder_reports_invalid_and_missing_together() {
+    let err = SemanticFingerprintBuilder::new()
+        .set_e1(vec![0.0; E1_DIM])
+        .set_e2(vec![0.0; 100])
+        .set_e5(vec![0.0; E5_DIM], vec![0.0; 7])
+        .build()
+        .unwrap_err();
+
+    // E2 and E5 invalid, E3, E4, E6-E13 missing
+    assert_eq!(err.errors.len(), 12);
+    assert!(err.errors.iter().any(|e| matches!(
+        e,
+        ValidationError::DimensionMismatch {
+            embedder: Embedder::TemporalRecent,
+            ..
+        }
+    )));
+    assert!(err.errors.iter().any(|e| matches!(
+        e,
+        ValidationError::DimensionMismatch {
+            embedder: Embedder::Causal,
+            actual: 7,
+            ..
+        }
+    )));
+    assert!(!err.errors.iter().any(|e| matches!(
+        e,
+        ValidationError::MissingEmbedding {
+            embedder: Embedder::TemporalRecent | Embedder::Causal
+        }
+    )));
+    assert!(err
+        .to_string()
+        .starts_with("12 invalid fingerprint space(s)"));
+}
+
+#[test]
+fn test_model_outputs_land_in_matching_spaces() {
+    let outputs = std::array::from_fn(|i| model_output(Embedder::from_index(i).unwrap()));
+    let fp = SemanticFingerprintBuilder::from_model_outputs(outputs)
+        .with_norm_strictness(NormStrictness::Ignore)
+        .build()
+        .expect("all spaces valid");
+
+    assert!(fp.validate().is_ok());
+    for i in 0..NUM_EMBEDDERS {
+        let expected = (i + 1) as f32 * 0.01;
+        let actual = first_value(fp.get_embedding(i).unwrap());
+        assert_eq!(actual, expected, "model {} landed in the wrong space", i);
+    }
+    let second = |embedder: Embedder| -((embedder.index() + 1) as f32 * 0.01);
+    assert_eq!(fp.e5_causal_as_effect[0], second(Embedder::Causal));
+    assert_eq!(fp.e8_graph_as_target[0], second(Embedder::Graph));
+    assert_eq!(
+        fp.e10_multimodal_as_context[0],
+        second(Embedder::Contextual)
+    );
+}
+
+#[test]
+fn test_norm_strictness_error_rejects_non_unit_vectors() {
+    let mut unit = vec![0.0; E1_DIM];
+    unit[0] = 1.0;
+    let zeros_ok = SemanticFingerprintBuilder::new()
+        .with_norm_strictness(NormStrictness::Error)
+        .set_e1(unit)
+        .set_e7(vec![0.0; E7_DIM])
+        .build_partial();
+    assert!(zeros_ok.is_ok(), "unit and all-zero vectors pass");
+
+    let err = SemanticFingerprintBuilder::new()
+        .with_norm_strictness(NormStrictness::Error)
+        .set_e1(vec![1.0; E1_DIM])
+        .build_partial()
+        .unwrap_err();
+    assert!(matches!(
+        err.errors[0],
+        ValidationError::NotUnitNorm {
+            embedder: Embedder::Semantic,
+            ..
+        }
+    ));
+
+    // The default only warns
+    assert!(SemanticFingerprintBuilder::new()
+        .set_e1(vec![1.0; E1_DIM])
+        .build_partial()
+        .is_ok());
+}
+
+#[test]
+fn test_build_partial_masks_set_spaces() {
+    let (fp, mask) = SemanticFingerprintBuilder::new()
+        .set_e1(vec![0.5; E1_DIM])
+        .set_e12_tokens(vec![[0.25; E12_TOKEN_DIM]; 2])
+        .with_norm_strictness(NormStrictness::Ignore)
+        .build_partial()
+        .expect("set spaces are valid");
+
+    assert_eq!(mask.count(), 2);
+    assert!(mask.contains(Embedder::Semantic));
+    assert!(mask.contains(Embedder::LateInteraction));
+    assert!(!mask.contains(Embedder::Code));
+    assert_eq!(fp.e12_late_interaction.len(), 2);
+    assert_eq!(fp.e12_late_interaction[1][E12_TOKEN_DIM - 1], 0.25);
+    assert!(fp.e7_code.is_empty());
+}
@@ -1,5 +1,6 @@
 //! Tests for SemanticFingerprint module.
 
+mod builder_tests;
 mod core_tests;
 mod storage_tests;
 mod task_core_003_tests;
@@ -345,6 +345,128 @@ impl SparseVector {
         }
         dense
     }
+
+    /// Copy keeping the `k` largest activations.
+    ///
+    /// Ties are broken by lower vocabulary index. The result keeps indices
+    /// sorted and never has more active entries than `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use context_graph_core::types::fingerprint::SparseVector;
+    ///
+    /// let sv = SparseVector::new(vec![1, 5, 9], vec![0.2, 0.9, 0.5]).unwrap();
+    /// let top = sv.top_k(2);
+    /// assert_eq!(top.indices, vec![5, 9]);
+    /// ```
+    pub fn top_k(&self, k: usize) -> Self {
+        if k >= self.nnz() {
+            return self.clone();
+        }
+
+        let mut order: Vec<usize> = (0..self.nnz()).collect();
+        order.sort_by(|&a, &b| {
+            self.values[b]
+                .total_cmp(&self.values[a])
+                .then_with(|| self.indices[a].cmp(&self.indices[b]))
+        });
+        order.truncate(k);
+        order.sort_unstable();
+
+        Self {
+            indices: order.iter().map(|&i| self.indices[i]).collect(),
+            values: order.iter().map(|&i| self.values[i]).collect(),
+        }
+    }
+
+    /// Union of two vectors keeping the larger value at shared indices.
+    ///
+    /// Used for query expansion: the expanded query activates every term
+    /// either side activates, at its strongest weight.
+    ///
+    /// # Errors
+    ///
+    /// `TooManyActive` if the union exceeds `MAX_SPARSE_ACTIVE`; callers
+    /// can `top_k` the inputs first.
+    pub fn merge_max(&self, other: &Self) -> Result<Self, SparseVectorError> {
+        let mut indices = Vec::with_capacity(self.nnz() + other.nnz());
+        let mut values = Vec::with_capacity(self.nnz() + other.nnz());
+        let mut i = 0;
+        let mut j = 0;
+
+        // Two-pointer merge on sorted indices
+        while i < self.indices.len() || j < other.indices.len() {
+            let order = match (self.indices.get(i), other.indices.get(j)) {
+                (Some(a), Some(b)) => a.cmp(b),
+                (Some(_), None) => Ordering::Less,
+                _ => Ordering::Greater,
+            };
+            match order {
+                Ordering::Less => {
+                    indices.push(self.indices[i]);
+                    values.push(self.values[i]);
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    indices.push(other.indices[j]);
+                    values.push(other.values[j]);
+                    j += 1;
+                }
+                Ordering::Equal => {
+                    indices.push(self.indices[i]);
+                    values.push(self.values[i].max(other.values[j]));
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+
+        if indices.len() > MAX_SPARSE_ACTIVE {
+            return Err(SparseVectorError::TooManyActive {
+                count: indices.len(),
+                max: MAX_SPARSE_ACTIVE,
+            });
+        }
+        Ok(Self { indices, values })
+    }
+
+    /// Scale active values to unit L2 norm.
+    ///
+    /// # Errors
+    ///
+    /// `ZeroNorm` if the vector is empty or all zeros, `NonFiniteValue` if
+    /// the norm is NaN or infinite. The vector is unchanged on error.
+    pub fn l2_normalize(&mut self) -> Result<(), SparseVectorError> {
+        let norm = self.l2_norm();
+        if !norm.is_finite() {
+            return Err(SparseVectorError::NonFiniteValue);
+        }
+        if norm == 0.0 {
+            return Err(SparseVectorError::ZeroNorm);
+        }
+        for value in &mut self.values {
+            *value /= norm;
+        }
+        Ok(())
+    }
+
+    /// Drop entries whose value is below `threshold` (NaN values are
+    /// dropped too). Returns the number of entries removed.
+    pub fn prune_below(&mut self, threshold: f32) -> usize {
+        let before = self.nnz();
+        let mut keep = 0;
+        for i in 0..before {
+            if self.values[i] >= threshold {
+                self.indices[keep] = self.indices[i];
+                self.values[keep] = self.values[i];
+                keep += 1;
+            }
+        }
+        self.indices.truncate(keep);
+        self.values.truncate(keep);
+        before - keep
+    }
 }
 
 impl Default for SparseVector {
@@ -353,9 +475,10 @@ impl Default for SparseVector {
     }
 }
 
-/// Errors for SparseVector construction.
+/// Errors for SparseVector construction and arithmetic.
 ///
-/// These errors are returned by `SparseVector::new()` when validation fails.
+/// These errors are returned by `SparseVector::new()` when validation fails,
+/// and by `merge_max` / `l2_normalize` when a result would be invalid.
 /// All errors contain detailed information about what went wrong.
 #[derive(Debug, Clone, PartialEq)]
 pub enum SparseVectorError {
@@ -383,6 +506,20 @@ pub enum SparseVectorError {
         /// The index where the violation was detected
         index: u16,
     },
+
+    /// An operation would produce more than `MAX_SPARSE_ACTIVE` entries.
+    TooManyActive {
+        /// Active entries the result would have
+        count: usize,
+        /// The limit (MAX_SPARSE_ACTIVE)
+        max: usize,
+    },
+
+    /// Normalization of a vector with zero L2 norm.
+    ZeroNorm,
+
+    /// A value (or the norm computed from the values) is NaN or infinite.
+    NonFiniteValue,
 }
 
 impl fmt::Display for SparseVectorError {
@@ -408,6 +545,11 @@ impl fmt::Display for SparseVectorError {
                     index
                 )
             }
+            Self::TooManyActive { count, max } => {
+                write!(f, "{} active entries exceeds maximum {}", count, max)
+            }
+            Self::ZeroNorm => write!(f, "cannot normalize a vector with zero norm"),
+            Self::NonFiniteValue => write!(f, "vector contains NaN or infinite values"),
         }
     }
 }
@@ -593,6 +735,12 @@ mod tests {
             e3.to_string(),
             "indices must be sorted ascending without duplicates, failed at 50"
         );
+
+        let e4 = SparseVectorError::TooManyActive {
+            count: 2000,
+            max: 1526,
+        };
+        assert_eq!(e4.to_string(), "2000 active entries exceeds maximum 1526");
     }
 
     #[test]
@@ -710,4 +858,120 @@ mod tests {
             sparsity
         );
     }
+
+    // =========================================================================
+    // Arithmetic and Pruning Tests
+    // =========================================================================
+
+    #[test]
+    fn test_sparse_vector_top_k() {
+        let sv = SparseVector::new(vec![1, 5, 9, 12], vec![0.2, 0.9, 0.5, 0.5]).unwrap();
+        assert_eq!(sv.top_k(3).indices, vec![5, 9, 12]);
+        // Tie between 9 and 12 goes to the lower index
+        let top = sv.top_k(2);
+        assert_eq!(top.indices, vec![5, 9]);
+        assert_eq!(top.values, vec![0.9, 0.5]);
+        assert_eq!(sv.top_k(10), sv);
+        assert!(sv.top_k(0).is_empty());
+    }
+
+    #[test]
+    fn test_sparse_vector_merge_max() {
+        let a = SparseVector::new(vec![1, 3, 5], vec![0.1, 0.8, 0.3]).unwrap();
+        let b = SparseVector::new(vec![3, 4, 5], vec![0.2, 0.4, 0.6]).unwrap();
+        let merged = a.merge_max(&b).unwrap();
+        assert_eq!(merged.indices, vec![1, 3, 4, 5]);
+        assert_eq!(merged.values, vec![0.1, 0.8, 0.4, 0.6]);
+        assert_eq!(a.merge_max(&SparseVector::empty()).unwrap(), a);
+    }
+
+    #[test]
+    fn test_sparse_vector_merge_max_too_many_active() {
+        let evens: Vec<u16> = (0..MAX_SPARSE_ACTIVE as u16).map(|i| i * 2).collect();
+        let odds: Vec<u16> = evens.iter().map(|i| i + 1).collect();
+        let a = SparseVector::new(evens, vec![0.5; MAX_SPARSE_ACTIVE]).unwrap();
+        let b = SparseVector::new(odds, vec![0.5; MAX_SPARSE_ACTIVE]).unwrap();
+        assert_eq!(
+            a.merge_max(&b),
+            Err(SparseVectorError::TooManyActive {
+                count: 2 * MAX_SPARSE_ACTIVE,
+                max: MAX_SPARSE_ACTIVE,
+            })
+        );
+    }
+
+    #[test]
+    fn test_sparse_vector_l2_normalize_errors() {
+        let mut empty = SparseVector::empty();
+        assert_eq!(empty.l2_normalize(), Err(SparseVectorError::ZeroNorm));
+
+        let mut zeros = SparseVector::new(vec![1, 2], vec![0.0, 0.0]).unwrap();
+        assert_eq!(zeros.l2_normalize(), Err(SparseVectorError::ZeroNorm));
+
+        let mut nan = SparseVector::new(vec![1, 2], vec![f32::NAN, 1.0]).unwrap();
+        assert_eq!(nan.l2_normalize(), Err(SparseVectorError::NonFiniteValue));
+        assert_eq!(nan.values[1], 1.0);
+    }
+
+    #[test]
+    fn test_sparse_vector_prune_below() {
+        let mut sv = SparseVector::new(vec![1, 2, 3, 4], vec![0.05, 0.5, f32::NAN, 0.1]).unwrap();
+        assert_eq!(sv.prune_below(0.1), 2);
+        assert_eq!(sv.indices, vec![2, 4]);
+        assert_eq!(sv.values, vec![0.5, 0.1]);
+        assert_eq!(sv.prune_below(0.0), 0);
+    }
+
+    /// Random valid vector with up to `max_nnz` entries over a small
+    /// vocabulary prefix, so pairs overlap often.
+    fn random_sparse(rng: &mut rand::rngs::StdRng, max_nnz: usize) -> SparseVector {
+        use rand::Rng;
+        let nnz = rng.gen_range(0..=max_nnz);
+        let mut indices: Vec<u16> = (0..nnz).map(|_| rng.gen_range(0..2_000)).collect();
+        indices.sort_unstable();
+        indices.dedup();
+        let values = indices.iter().map(|_| rng.gen_range(0.0..3.0)).collect();
+        SparseVector::new(indices, values).unwrap()
+    }
+
+    #[test]
+    fn test_sparse_vector_randomized_properties() {
+        use rand::{Rng, SeedableRng};
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0x5a1ade);
+
+        for _ in 0..500 {
+            let a = random_sparse(&mut rng, 400);
+            let b = random_sparse(&mut rng, 400);
+
+            // Dot symmetry
+            assert!((a.dot(&b) - b.dot(&a)).abs() <= 1e-4 * (1.0 + a.dot(&b).abs()));
+
+            // top_k never increases the active count and keeps sorted indices
+            let k = rng.gen_range(0..500);
+            let top = a.top_k(k);
+            assert!(top.nnz() <= a.nnz() && top.nnz() <= k);
+            assert!(SparseVector::new(top.indices.clone(), top.values.clone()).is_ok());
+
+            // merge_max dominates both inputs
+            let merged = a.merge_max(&b).unwrap();
+            assert!(merged.nnz() <= a.nnz() + b.nnz());
+            for (&idx, &val) in a.indices.iter().zip(&a.values) {
+                assert!(merged.get(idx).unwrap() >= val);
+            }
+
+            // Normalization yields unit norm
+            let mut normalized = a.clone();
+            match normalized.l2_normalize() {
+                Ok(()) => assert!((normalized.l2_norm() - 1.0).abs() < 1e-5),
+                Err(e) => assert_eq!(e, SparseVectorError::ZeroNorm),
+            }
+
+            // prune_below removes exactly the entries under the threshold
+            let threshold = rng.gen_range(0.0..3.0);
+            let mut pruned = a.clone();
+            let removed = pruned.prune_below(threshold);
+            assert_eq!(pruned.nnz() + removed, a.nnz());
+            assert!(pruned.values.iter().all(|&v| v >= threshold));
+        }
+    }
 }
@@ -2,12 +2,13 @@
 //!
 //! This module contains constructors, constants, and core methods.
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::types::fingerprint::{SemanticFingerprint, SparseVector};
+use crate::teleological::{Embedder, EmbedderMask};
+use crate::types::fingerprint::{SemanticFingerprint, SparseVector, NUM_EMBEDDERS};
 
-use super::types::TeleologicalFingerprint;
+use super::types::{EmbeddingState, TeleologicalFingerprint};
 
 impl TeleologicalFingerprint {
     /// Expected size in bytes for a complete teleological fingerprint.
@@ -20,6 +21,18 @@ impl TeleologicalFingerprint {
     /// Default importance score for new fingerprints.
     pub const DEFAULT_IMPORTANCE: f32 = 0.5;
 
+    /// Namespace for memories stored without an explicit namespace.
+    pub const DEFAULT_NAMESPACE: &'static str = "default";
+
+    /// Prefix of session-scoped staging namespaces (`staged.<session_id>`).
+    ///
+    /// Staged memories are hidden from searches that do not name their
+    /// namespace explicitly, until they are promoted or discarded.
+    pub const STAGING_NAMESPACE_PREFIX: &'static str = "staged.";
+
+    /// Maximum length of a namespace name.
+    pub const MAX_NAMESPACE_LEN: usize = 64;
+
     /// Create a new TeleologicalFingerprint with default importance (0.5).
     ///
     /// Automatically:
@@ -58,6 +71,70 @@ impl TeleologicalFingerprint {
             importance: importance.clamp(0.0, 1.0),
             last_accessed_at: now,
             e6_sparse: None,
+            expires_at: None,
+            namespace: Self::DEFAULT_NAMESPACE.to_string(),
+            embedding_states: [EmbeddingState::Computed; NUM_EMBEDDERS],
+        }
+    }
+
+    /// Create a fingerprint in which only the embedders in `computed` ran.
+    ///
+    /// Spaces outside `computed` are cleared (left empty, never zero-filled)
+    /// and marked [`EmbeddingState::NotComputed`].
+    ///
+    /// # Arguments
+    /// * `semantic` - Semantic fingerprint holding the computed embeddings
+    /// * `computed` - Embedders whose output is present in `semantic`
+    /// * `content_hash` - SHA-256 hash of source content
+    pub fn from_partial(
+        mut semantic: SemanticFingerprint,
+        computed: EmbedderMask,
+        content_hash: [u8; 32],
+    ) -> Self {
+        let mut states = [EmbeddingState::Computed; NUM_EMBEDDERS];
+        for embedder in Embedder::all().filter(|e| !computed.contains(*e)) {
+            semantic.clear_embedding(embedder);
+            states[embedder.index()] = EmbeddingState::NotComputed;
+        }
+
+        let mut fp = Self::new(semantic, content_hash);
+        fp.embedding_states = states;
+        fp
+    }
+
+    /// Computation state of one embedding space.
+    #[inline]
+    pub fn embedding_state(&self, embedder: Embedder) -> EmbeddingState {
+        self.embedding_states[embedder.index()]
+    }
+
+    /// Mask of the embedders whose output is present.
+    pub fn computed_mask(&self) -> EmbedderMask {
+        let computed: Vec<Embedder> = Embedder::all()
+            .filter(|e| self.embedding_state(*e) == EmbeddingState::Computed)
+            .collect();
+        EmbedderMask::from_slice(&computed)
+    }
+
+    /// Check if all 13 embedding spaces were computed.
+    #[inline]
+    pub fn is_fully_computed(&self) -> bool {
+        self.embedding_states
+            .iter()
+            .all(|s| *s == EmbeddingState::Computed)
+    }
+
+    /// Move the spaces selected by `mask` out of `source` and mark them computed.
+    ///
+    /// The original E6 sparse vector, if kept, follows a replaced E6 space.
+    /// Metadata and all other spaces are untouched.
+    pub fn apply_embeddings(&mut self, mask: EmbedderMask, source: &mut SemanticFingerprint) {
+        for embedder in mask.iter() {
+            self.semantic.take_embedding(embedder, source);
+            self.embedding_states[embedder.index()] = EmbeddingState::Computed;
+        }
+        if mask.contains(Embedder::Sparse) && self.e6_sparse.is_some() {
+            self.e6_sparse = Some(self.semantic.e6_sparse.clone());
         }
     }
 
@@ -103,6 +180,63 @@ impl TeleologicalFingerprint {
         self.e6_sparse.as_ref()
     }
 
+    /// Builder pattern: set the expiry time.
+    ///
+    /// Expired fingerprints are hidden from retrieve/search and removed by
+    /// the storage backend's `purge_expired`.
+    pub fn with_expires_at(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Check if this fingerprint has expired as of `now`.
+    ///
+    /// Fingerprints without `expires_at` never expire.
+    #[inline]
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|t| t <= now)
+    }
+
+    /// Builder pattern: set the namespace this memory is stored in.
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+
+    /// Check if this fingerprint belongs to the default namespace.
+    #[inline]
+    pub fn is_default_namespace(&self) -> bool {
+        self.namespace == Self::DEFAULT_NAMESPACE
+    }
+
+    /// Staging namespace for a session.
+    ///
+    /// Characters outside `[A-Za-z0-9._-]` are replaced with `_` and the
+    /// result is capped at `MAX_NAMESPACE_LEN`, so any session ID maps to a
+    /// valid namespace name.
+    pub fn staging_namespace(session_id: &str) -> String {
+        let mut namespace = String::from(Self::STAGING_NAMESPACE_PREFIX);
+        namespace.extend(
+            session_id
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .take(Self::MAX_NAMESPACE_LEN - Self::STAGING_NAMESPACE_PREFIX.len()),
+        );
+        namespace
+    }
+
+    /// Check if a namespace is a session staging namespace.
+    #[inline]
+    pub fn is_staging_namespace(namespace: &str) -> bool {
+        namespace.starts_with(Self::STAGING_NAMESPACE_PREFIX)
+    }
+
     /// Compute E6 term overlap score with a query sparse vector.
     ///
     /// Returns the fraction of query terms that appear in this document.
@@ -12,4 +12,4 @@ mod test_helpers;
 mod tests;
 
 // Re-export the main type for backwards compatibility
-pub use types::TeleologicalFingerprint;
+pub use types::{EmbeddingState, TeleologicalFingerprint};
@@ -67,6 +67,37 @@ fn test_teleological_record_access() {
     println!("[PASS] record_access increments count and updates timestamp");
 }
 
+// ===== Expiry Tests =====
+
+#[test]
+fn test_teleological_expiry() {
+    let fp = TeleologicalFingerprint::new(make_test_semantic(), make_test_hash());
+    assert!(fp.expires_at.is_none());
+    assert!(!fp.is_expired_at(Utc::now() + chrono::Duration::days(365)));
+
+    let deadline = Utc::now() + chrono::Duration::seconds(60);
+    let fp = fp.with_expires_at(deadline);
+    assert!(!fp.is_expired_at(deadline - chrono::Duration::seconds(1)));
+    assert!(fp.is_expired_at(deadline));
+
+    println!("[PASS] expires_at hides fingerprint at and after the deadline");
+}
+
+// ===== Namespace Tests =====
+
+#[test]
+fn test_teleological_namespace() {
+    let fp = TeleologicalFingerprint::new(make_test_semantic(), make_test_hash());
+    assert_eq!(fp.namespace, TeleologicalFingerprint::DEFAULT_NAMESPACE);
+    assert!(fp.is_default_namespace());
+
+    let fp = fp.with_namespace("project-a");
+    assert_eq!(fp.namespace, "project-a");
+    assert!(!fp.is_default_namespace());
+
+    println!("[PASS] namespace defaults to \"default\" and is settable");
+}
+
 // ===== Constants Tests =====
 
 #[test]
@@ -6,11 +6,26 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::types::fingerprint::{SemanticFingerprint, SparseVector};
+use crate::types::fingerprint::{SemanticFingerprint, SparseVector, NUM_EMBEDDERS};
 
 /// Number of embedder dimensions in the teleological signature vector.
 pub const TELEOLOGICAL_VECTOR_DIM: usize = 13;
 
+/// Whether an embedding space of a fingerprint was actually generated.
+///
+/// Fingerprints built from a selective embedding run (see
+/// `MultiArrayEmbeddingProvider::embed_selective`) leave unselected spaces
+/// empty and mark them `NotComputed`, so an absent embedding is never
+/// mistaken for a genuinely zero one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EmbeddingState {
+    /// The embedder ran and its output is stored in the fingerprint.
+    #[default]
+    Computed,
+    /// The embedder was skipped; the space holds no data.
+    NotComputed,
+}
+
 /// Complete teleological fingerprint for a memory node.
 ///
 /// This struct combines semantic content with metadata for tracking
@@ -51,11 +66,13 @@ pub struct TeleologicalFingerprint {
     /// Importance score [0.0, 1.0] for memory prioritization.
     /// Used by consolidation, boost_importance, and dream phases.
     /// Default: 0.5
+    ///
+    /// This is the value as of `last_updated`; read the decayed value through
+    /// `importance::ImportanceModel::effective_at`.
     pub importance: f32,
 
-    /// When this memory was last accessed (read) for importance decay.
+    /// When this memory was last accessed (read).
     /// Default: same as `created_at`. Updated on search result retrieval.
-    /// Used for computing effective_importance = importance * 0.5^(days_since_access / 30).
     ///
     /// NOTE: This field uses `#[serde(skip)]` because TeleologicalFingerprint is
     /// serialized with bincode (positional format). Adding a new field to the
@@ -79,6 +96,34 @@ pub struct TeleologicalFingerprint {
     /// be serialized for bincode compatibility.
     #[serde(default)]
     pub e6_sparse: Option<SparseVector>,
+
+    /// When this memory expires and becomes invisible to retrieve/search.
+    ///
+    /// `None` (default) means the memory never expires. Expired fingerprints
+    /// are physically removed by the storage backend's `purge_expired`.
+    ///
+    /// Added in storage format version 2. Version 1 records are decoded with
+    /// `expires_at = None` by the storage layer.
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+
+    /// Namespace (collection) this memory belongs to.
+    ///
+    /// Searches filtered by namespace never return memories from another
+    /// namespace. Added in storage format version 3; older records are decoded
+    /// into [`TeleologicalFingerprint::DEFAULT_NAMESPACE`].
+    #[serde(default = "default_namespace")]
+    pub namespace: String,
+
+    /// Per-embedder computation state, indexed by `Embedder::index()`.
+    ///
+    /// All `Computed` except for fingerprints built with
+    /// [`TeleologicalFingerprint::from_partial`]. Like `last_accessed_at`,
+    /// this is `#[serde(skip)]` to keep the bincode layout stable: partial
+    /// fingerprints are for transient use (e.g. drift checks) and decode as
+    /// fully computed.
+    #[serde(skip)]
+    pub embedding_states: [EmbeddingState; NUM_EMBEDDERS],
 }
 
 /// Default for `last_accessed_at` when deserializing legacy fingerprints
@@ -88,6 +133,11 @@ fn default_last_accessed_at() -> DateTime<Utc> {
     Utc::now()
 }
 
+/// Default for `namespace` when deserializing fingerprints that predate namespaces.
+fn default_namespace() -> String {
+    TeleologicalFingerprint::DEFAULT_NAMESPACE.to_string()
+}
+
 impl TeleologicalFingerprint {
     /// Compute 13D teleological signature vector.
     ///
@@ -43,8 +43,8 @@ pub use fingerprint::{
 };
 pub use audit::{
     AuditOperation, AuditRecord, AuditResult, ConsolidationCandidate, ConsolidationRecommendation,
-    EmbeddingVersionRecord, ImportanceChangeRecord, MergeRecord,
-    RecommendationStatus,
+    EmbeddingVersionRecord, ImportanceChangeRecord, LineageOperation, LineageRecord, MergeRecord,
+    RecommendationStatus, ToolAuditOutcome, ToolAuditPage, ToolAuditQuery, ToolAuditRecord,
 };
 pub use discovery::{DiscoveryCycleResult, ServiceStatus};
 pub use graph_edge::*;
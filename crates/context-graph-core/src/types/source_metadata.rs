@@ -131,6 +131,16 @@ pub struct SourceMetadata {
     /// without re-extracting from content at search time.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub entity_names: Option<Vec<String>>,
+
+    /// Content type the memory was classified as at store time, detected or
+    /// given via `content_type`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_classification: Option<crate::code::ContentClassification>,
+
+    /// Embedders skipped at store time because of the content type (E7 for
+    /// pure prose). Their spaces hold zero vectors and never match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skipped_embedders: Option<Vec<crate::teleological::Embedder>>,
 }
 
 /// Type of memory source.
@@ -184,6 +194,8 @@ impl Default for SourceMetadata {
             hook_execution_timestamp_ms: None,
             embedding_hint_provenance: None,
             entity_names: None,
+            content_classification: None,
+            skipped_embedders: None,
         }
     }
 }
@@ -1,7 +1,8 @@
-//! GPU k-NN kernel using brute-force L2 distance computation.
+//! GPU k-NN kernel using brute-force distance computation.
 //!
-//! This kernel computes all-pairs L2 distances and finds k nearest neighbors
-//! for each query point. Used for HDBSCAN core distance computation.
+//! This kernel computes all-pairs L2 or inner-product scores and finds k
+//! nearest neighbors for each query point. Used for HDBSCAN core distance
+//! computation and top-k search.
 //!
 //! # Constitution Compliance
 //!
@@ -19,6 +20,13 @@
 // Block size for kernels - 256 is optimal for RTX 5090
 #define BLOCK_SIZE 256
 
+// Distance metrics (must match KNN_METRIC_* in src/ffi/knn.rs)
+#define METRIC_L2 0
+#define METRIC_INNER_PRODUCT 1
+
+// Max neighbors tracked in registers per thread
+#define MAX_K 16
+
 //! Compute L2 squared distance between two vectors.
 __device__ __forceinline__ float l2_distance_squared(
     const float* __restrict__ a,
@@ -33,26 +41,76 @@ __device__ __forceinline__ float l2_distance_squared(
     return sum;
 }
 
+//! Compute inner product between two vectors.
+//! Uses round-to-nearest add/mul without FMA contraction so scores match a
+//! sequential f32 dot product on the host bit-for-bit.
+__device__ __forceinline__ float inner_product(
+    const float* __restrict__ a,
+    const float* __restrict__ b,
+    int dimension
+) {
+    float sum = 0.0f;
+    for (int d = 0; d < dimension; d++) {
+        sum = __fadd_rn(sum, __fmul_rn(a[d], b[d]));
+    }
+    return sum;
+}
+
+//! Check a slot in the validity bitmap (one bit per vector, 32 per word).
+//! A null bitmap means every slot is valid.
+__device__ __forceinline__ bool slot_is_valid(
+    const uint32_t* __restrict__ valid,
+    int slot
+) {
+    return valid == nullptr || ((valid[slot >> 5] >> (slot & 31)) & 1u);
+}
+
+//! Ranking key for a pair: smaller is nearer.
+//! L2 uses squared distance; inner product uses the negated dot product so
+//! unit vectors skip norm computation entirely.
+__device__ __forceinline__ float metric_key(
+    const float* __restrict__ a,
+    const float* __restrict__ b,
+    int dimension,
+    int metric
+) {
+    if (metric == METRIC_INNER_PRODUCT) {
+        return -inner_product(a, b, dimension);
+    }
+    return l2_distance_squared(a, b, dimension);
+}
+
 //! Kernel: Compute core distances for all points.
 //! Each thread handles one point.
 //!
 //! # Arguments
 //! - vectors: Device pointer to input vectors (n_points * dimension floats)
+//! - valid: Device pointer to validity bitmap (ceil(n_points / 32) words),
+//!   or null if all points are valid. Removed points are skipped as
+//!   neighbors and get core distance 0.
 //! - n_points: Number of points
 //! - dimension: Vector dimension
 //! - k: Number of neighbors for core distance
+//! - metric: METRIC_L2 (Euclidean core distance) or METRIC_INNER_PRODUCT
+//!   (cosine distance 1 - dot, vectors must be unit-norm)
 //! - core_dists: Device pointer to output core distances (n_points floats)
 extern "C" __global__ void compute_core_distances_kernel(
     const float* __restrict__ vectors,
+    const uint32_t* __restrict__ valid,
     int n_points,
     int dimension,
     int k,
+    int metric,
     float* __restrict__ core_dists
 ) {
     int tid = blockIdx.x * blockDim.x + threadIdx.x;
     if (tid >= n_points) return;
+    if (!slot_is_valid(valid, tid)) {
+        core_dists[tid] = 0.0f;
+        return;
+    }
 
-    const float* query = vectors + tid * dimension;
+    const float* query = vectors + (int64_t)tid * dimension;
 
     // For small k, use a simple O(n*k) selection
     // Keep track of top-(k+1) smallest distances (including self)
@@ -60,20 +118,21 @@ extern "C" __global__ void compute_core_distances_kernel(
     if (k_search > n_points) k_search = n_points;
 
     // Use local registers for top-k tracking (max k=16 for register pressure)
-    float top_k[16];
+    float top_k[MAX_K];
     int actual_k = k_search;
-    if (actual_k > 16) actual_k = 16;
+    if (actual_k > MAX_K) actual_k = MAX_K;
 
     // Initialize with max values
     #pragma unroll
-    for (int i = 0; i < 16; i++) {
+    for (int i = 0; i < MAX_K; i++) {
         top_k[i] = FLT_MAX;
     }
 
     // Stream through all points
     for (int j = 0; j < n_points; j++) {
-        const float* target = vectors + j * dimension;
-        float dist = l2_distance_squared(query, target, dimension);
+        if (!slot_is_valid(valid, j)) continue;
+        const float* target = vectors + (int64_t)j * dimension;
+        float dist = metric_key(query, target, dimension, metric);
 
         // Insert into sorted top-k if smaller
         if (dist < top_k[actual_k - 1]) {
@@ -86,11 +145,89 @@ extern "C" __global__ void compute_core_distances_kernel(
         }
     }
 
-    // k-th neighbor distance (index k gives k-th non-self, since index 0 is self with dist=0)
+    // k-th neighbor distance (index k gives k-th non-self, since index 0 is self)
     int kth_idx = k;
     if (kth_idx >= actual_k) kth_idx = actual_k - 1;
     float kth_dist = top_k[kth_idx];
-    core_dists[tid] = sqrtf(kth_dist);
+    if (metric == METRIC_INNER_PRODUCT) {
+        // key = -dot, so 1 + key is the cosine distance for unit vectors
+        core_dists[tid] = fmaxf(0.0f, 1.0f + kth_dist);
+    } else {
+        core_dists[tid] = sqrtf(kth_dist);
+    }
+}
+
+//! Kernel: Top-k nearest neighbor search.
+//! Each thread handles one query and scans all indexed vectors.
+//! Ties keep the lower index, so results are deterministic.
+//!
+//! # Arguments
+//! - vectors: Device pointer to indexed vectors (n_points * dimension floats)
+//! - valid: Device pointer to validity bitmap, or null if all slots are valid
+//! - n_points: Number of indexed vector slots
+//! - queries: Device pointer to query vectors (n_queries * dimension floats)
+//! - n_queries: Number of queries
+//! - dimension: Vector dimension
+//! - k: Neighbors per query (1..=MAX_K, at most the number of valid slots)
+//! - metric: METRIC_L2 or METRIC_INNER_PRODUCT
+//! - out_indices: Device pointer to output indices (n_queries * k ints)
+//! - out_scores: Device pointer to output scores (n_queries * k floats);
+//!   L2 distances ascending, or inner products descending
+extern "C" __global__ void knn_search_kernel(
+    const float* __restrict__ vectors,
+    const uint32_t* __restrict__ valid,
+    int n_points,
+    const float* __restrict__ queries,
+    int n_queries,
+    int dimension,
+    int k,
+    int metric,
+    int* __restrict__ out_indices,
+    float* __restrict__ out_scores
+) {
+    int tid = blockIdx.x * blockDim.x + threadIdx.x;
+    if (tid >= n_queries) return;
+
+    const float* query = queries + (int64_t)tid * dimension;
+
+    int actual_k = k;
+    if (actual_k > n_points) actual_k = n_points;
+    if (actual_k > MAX_K) actual_k = MAX_K;
+
+    float top_key[MAX_K];
+    int top_idx[MAX_K];
+
+    #pragma unroll
+    for (int i = 0; i < MAX_K; i++) {
+        top_key[i] = FLT_MAX;
+        top_idx[i] = -1;
+    }
+
+    for (int j = 0; j < n_points; j++) {
+        if (!slot_is_valid(valid, j)) continue;
+        const float* target = vectors + (int64_t)j * dimension;
+        float key = metric_key(query, target, dimension, metric);
+
+        // Strict comparison: an equal key never displaces an earlier index
+        if (key < top_key[actual_k - 1]) {
+            int pos = actual_k - 1;
+            while (pos > 0 && key < top_key[pos - 1]) {
+                top_key[pos] = top_key[pos - 1];
+                top_idx[pos] = top_idx[pos - 1];
+                pos--;
+            }
+            top_key[pos] = key;
+            top_idx[pos] = j;
+        }
+    }
+
+    int64_t base = (int64_t)tid * k;
+    for (int i = 0; i < actual_k; i++) {
+        out_indices[base + i] = top_idx[i];
+        out_scores[base + i] = (metric == METRIC_INNER_PRODUCT)
+            ? -top_key[i]
+            : sqrtf(top_key[i]);
+    }
 }
 
 //! Kernel: Compute pairwise L2 distances.
@@ -1,5 +1,6 @@
 //! Error types for CUDA operations.
 
+use context_graph_core::error::{ContextGraphError, ErrorKind};
 use thiserror::Error;
 
 /// CUDA-specific errors.
@@ -74,5 +75,26 @@ pub enum CudaError {
     },
 }
 
+impl CudaError {
+    /// Machine-readable classification of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::NoDevice | Self::NotImplemented(_) => ErrorKind::CapabilityUnavailable,
+            Self::DimensionMismatch { .. }
+            | Self::InvalidConfig(_)
+            | Self::InvalidArgument { .. }
+            | Self::BatchTooLarge { .. } => ErrorKind::InvalidInput,
+            _ => ErrorKind::GpuFailure,
+        }
+    }
+}
+
+impl From<CudaError> for ContextGraphError {
+    fn from(err: CudaError) -> Self {
+        let kind = err.kind();
+        ContextGraphError::from_source(kind, err)
+    }
+}
+
 /// Result type for CUDA operations.
 pub type CudaResult<T> = Result<T, CudaError>;
@@ -11,11 +11,11 @@
 
 use std::ffi::{c_void, CString};
 use std::os::raw::c_int;
-use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::atomic::{AtomicI32, AtomicUsize, Ordering};
 use std::sync::Once;
 
 use crate::error::{CudaError, CudaResult};
-use crate::ffi::cuda_driver::{cuInit, CUcontext, CUresult, CUDA_SUCCESS};
+use crate::ffi::cuda_driver::{cuCtxSetCurrent, cuInit, CUcontext, CUresult, CUDA_SUCCESS};
 
 // Include generated PTX
 include!(concat!(env!("OUT_DIR"), "/knn_ptx.rs"));
@@ -52,6 +52,11 @@ extern "C" {
         srcDevice: CUdeviceptr,
         byteCount: usize,
     ) -> CUresult;
+    fn cuMemcpyDtoD_v2(
+        dstDevice: CUdeviceptr,
+        srcDevice: CUdeviceptr,
+        byteCount: usize,
+    ) -> CUresult;
 
     fn cuLaunchKernel(
         f: CUfunction,
@@ -326,6 +331,28 @@ impl Drop for CudaModule {
 
 const BLOCK_SIZE: u32 = 256;
 
+/// Kernel metric code for Euclidean (L2) distance.
+pub const KNN_METRIC_L2: i32 = 0;
+
+/// Kernel metric code for inner product over unit-norm vectors.
+pub const KNN_METRIC_INNER_PRODUCT: i32 = 1;
+
+/// Maximum neighbors per query tracked in kernel registers.
+pub const KNN_MAX_K: usize = 16;
+
+fn validate_metric(metric: i32) -> CudaResult<()> {
+    if metric != KNN_METRIC_L2 && metric != KNN_METRIC_INNER_PRODUCT {
+        return Err(CudaError::InvalidArgument {
+            argument: "metric".to_string(),
+            reason: format!(
+                "Unknown metric code {}, expected {} (L2) or {} (inner product)",
+                metric, KNN_METRIC_L2, KNN_METRIC_INNER_PRODUCT
+            ),
+        });
+    }
+    Ok(())
+}
+
 /// Compute core distances for HDBSCAN using GPU k-NN.
 ///
 /// # Arguments
@@ -334,16 +361,21 @@ const BLOCK_SIZE: u32 = 256;
 /// * `n_points` - Number of points
 /// * `dimension` - Vector dimension
 /// * `k` - Number of neighbors (typically min_samples)
+/// * `metric` - `KNN_METRIC_L2` or `KNN_METRIC_INNER_PRODUCT`
 ///
 /// # Returns
 ///
-/// Vector of core distances (n_points elements).
+/// Vector of core distances (n_points elements). For the inner-product
+/// metric the core distance is the cosine distance `1 - dot`.
 pub fn compute_core_distances_gpu(
     vectors: &[f32],
     n_points: usize,
     dimension: usize,
     k: usize,
+    metric: i32,
 ) -> CudaResult<Vec<f32>> {
+    validate_metric(metric)?;
+
     if n_points == 0 || dimension == 0 {
         return Ok(vec![]);
     }
@@ -367,105 +399,12 @@ pub fn compute_core_distances_gpu(
 
     // Load PTX module
     let module = CudaModule::load_ptx(PTX)?;
-    let kernel = module.get_function("compute_core_distances_kernel")?;
-
-    // Allocate GPU memory
-    let vectors_size = std::mem::size_of_val(vectors);
-    let output_size = n_points * std::mem::size_of::<f32>();
-
-    let d_vectors = GpuBuffer::new(vectors_size)?;
-    let d_output = GpuBuffer::new(output_size)?;
 
     // Copy input to GPU
-    let vectors_bytes =
-        unsafe { std::slice::from_raw_parts(vectors.as_ptr() as *const u8, vectors_size) };
-    d_vectors.copy_from_host(vectors_bytes)?;
-
-    // Set up kernel parameters (bounds-checked to prevent i32 truncation)
-    // BLD-08 FIX: Validate ALL three parameters before i32 cast, not just n_points.
-    // The original code only checked n_points but cast dimension and k unchecked.
-    if n_points > i32::MAX as usize {
-        return Err(CudaError::CudaRuntimeError {
-            operation: format!(
-                "compute_core_distances_kernel: n_points {} exceeds i32::MAX ({})",
-                n_points, i32::MAX
-            ),
-            code: -1,
-        });
-    }
-    if dimension > i32::MAX as usize {
-        return Err(CudaError::CudaRuntimeError {
-            operation: format!(
-                "compute_core_distances_kernel: dimension {} exceeds i32::MAX ({})",
-                dimension, i32::MAX
-            ),
-            code: -1,
-        });
-    }
-    if k > i32::MAX as usize {
-        return Err(CudaError::CudaRuntimeError {
-            operation: format!(
-                "compute_core_distances_kernel: k {} exceeds i32::MAX ({})",
-                k, i32::MAX
-            ),
-            code: -1,
-        });
-    }
-    let n_points_i32 = n_points as i32;
-    let dimension_i32 = dimension as i32;
-    let k_i32 = k as i32;
-    let d_vectors_ptr = d_vectors.ptr();
-    let d_output_ptr = d_output.ptr();
-
-    let mut params: [*mut c_void; 5] = [
-        &d_vectors_ptr as *const _ as *mut c_void,
-        &n_points_i32 as *const _ as *mut c_void,
-        &dimension_i32 as *const _ as *mut c_void,
-        &k_i32 as *const _ as *mut c_void,
-        &d_output_ptr as *const _ as *mut c_void,
-    ];
-
-    // Launch kernel
-    let num_blocks = (n_points as u32).div_ceil(BLOCK_SIZE);
-    let ret = unsafe {
-        cuLaunchKernel(
-            kernel,
-            num_blocks,
-            1,
-            1,          // grid
-            BLOCK_SIZE,
-            1,
-            1,          // block
-            0,          // shared mem
-            std::ptr::null_mut(), // stream
-            params.as_mut_ptr(),
-            std::ptr::null_mut(), // extra
-        )
-    };
-
-    if ret != CUDA_SUCCESS {
-        return Err(CudaError::CudaRuntimeError {
-            operation: "cuLaunchKernel(compute_core_distances_kernel)".to_string(),
-            code: ret,
-        });
-    }
-
-    // Synchronize
-    let ret = unsafe { cuCtxSynchronize() };
-    if ret != CUDA_SUCCESS {
-        return Err(CudaError::CudaRuntimeError {
-            operation: "cuCtxSynchronize".to_string(),
-            code: ret,
-        });
-    }
-
-    // Copy results back
-    let mut output = vec![0.0f32; n_points];
-    let output_bytes =
-        unsafe { std::slice::from_raw_parts_mut(output.as_mut_ptr() as *mut u8, output_size) };
-    d_output.copy_to_host(output_bytes)?;
+    let d_vectors = GpuBuffer::new(std::mem::size_of_val(vectors))?;
+    d_vectors.copy_from_host(f32_bytes(vectors))?;
 
-    Ok(output)
+    launch_core_distances(&module, d_vectors.ptr(), 0, n_points, dimension, k, metric)
 }
 
 /// Compute pairwise distances for all point pairs using GPU.
@@ -626,6 +565,676 @@ pub fn compute_pairwise_distances_gpu(
     Ok(output)
 }
 
+/// Find the `k` nearest indexed vectors for each query using GPU brute force.
+///
+/// # Arguments
+///
+/// * `vectors` - Flattened indexed vectors (n_points * dimension elements)
+/// * `n_points` - Number of indexed vectors
+/// * `queries` - Flattened query vectors (n_queries * dimension elements)
+/// * `n_queries` - Number of queries
+/// * `dimension` - Vector dimension
+/// * `k` - Neighbors per query (1..=`KNN_MAX_K`, at most n_points)
+/// * `metric` - `KNN_METRIC_L2` or `KNN_METRIC_INNER_PRODUCT`
+///
+/// # Returns
+///
+/// `(indices, scores)`, each n_queries * k elements in row-major order.
+/// L2 scores are distances in ascending order; inner-product scores are
+/// similarities in descending order. Ties keep the lower index.
+pub fn knn_search_gpu(
+    vectors: &[f32],
+    n_points: usize,
+    queries: &[f32],
+    n_queries: usize,
+    dimension: usize,
+    k: usize,
+    metric: i32,
+) -> CudaResult<(Vec<i32>, Vec<f32>)> {
+    validate_search_args(queries, n_queries, dimension, k, n_points, metric)?;
+
+    if n_queries == 0 {
+        return Ok((vec![], vec![]));
+    }
+
+    if vectors.len() != n_points * dimension {
+        return Err(CudaError::InvalidArgument {
+            argument: "vectors".to_string(),
+            reason: format!(
+                "Expected {} elements, got {}",
+                n_points * dimension,
+                vectors.len()
+            ),
+        });
+    }
+
+    // Initialize CUDA
+    ensure_cuda_initialized()?;
+
+    // Create context on device 0
+    let _ctx = CudaContext::new(0)?;
+
+    // Load PTX module
+    let module = CudaModule::load_ptx(PTX)?;
+
+    // Copy input to GPU
+    let d_vectors = GpuBuffer::new(std::mem::size_of_val(vectors))?;
+    d_vectors.copy_from_host(f32_bytes(vectors))?;
+
+    launch_knn_search(
+        &module,
+        d_vectors.ptr(),
+        0,
+        n_points,
+        queries,
+        n_queries,
+        dimension,
+        k,
+        metric,
+    )
+}
+
+/// Validate search arguments shared by [`knn_search_gpu`] and [`KnnDeviceStore::search`].
+fn validate_search_args(
+    queries: &[f32],
+    n_queries: usize,
+    dimension: usize,
+    k: usize,
+    available: usize,
+    metric: i32,
+) -> CudaResult<()> {
+    validate_metric(metric)?;
+
+    if dimension == 0 {
+        return Err(CudaError::InvalidArgument {
+            argument: "dimension".to_string(),
+            reason: "Dimension must be > 0".to_string(),
+        });
+    }
+
+    if queries.len() != n_queries * dimension {
+        return Err(CudaError::InvalidArgument {
+            argument: "queries".to_string(),
+            reason: format!(
+                "Expected {} elements, got {}",
+                n_queries * dimension,
+                queries.len()
+            ),
+        });
+    }
+
+    if n_queries > 0 && (k == 0 || k > KNN_MAX_K || k > available) {
+        return Err(CudaError::InvalidArgument {
+            argument: "k".to_string(),
+            reason: format!(
+                "k={} must be in 1..={} and at most the {} searchable vectors",
+                k, KNN_MAX_K, available
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+// =============================================================================
+// KERNEL LAUNCH HELPERS
+// =============================================================================
+
+/// View an f32 slice as bytes for host-device copies.
+fn f32_bytes(values: &[f32]) -> &[u8] {
+    let size = std::mem::size_of_val(values);
+    unsafe { std::slice::from_raw_parts(values.as_ptr() as *const u8, size) }
+}
+
+/// View a mutable f32 slice as bytes for device-host copies.
+fn f32_bytes_mut(values: &mut [f32]) -> &mut [u8] {
+    let size = std::mem::size_of_val(values);
+    unsafe { std::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut u8, size) }
+}
+
+/// Convert a size to a kernel `int` parameter, failing on i32 truncation.
+fn kernel_int(kernel: &str, name: &str, value: usize) -> CudaResult<i32> {
+    i32::try_from(value).map_err(|_| CudaError::CudaRuntimeError {
+        operation: format!(
+            "{}: {} {} exceeds i32::MAX ({})",
+            kernel,
+            name,
+            value,
+            i32::MAX
+        ),
+        code: -1,
+    })
+}
+
+/// Launch a one-thread-per-item kernel and wait for it to finish.
+fn launch_and_sync(
+    kernel: CUfunction,
+    name: &str,
+    n_threads: usize,
+    params: &mut [*mut c_void],
+) -> CudaResult<()> {
+    let n_threads = kernel_int(name, "n_threads", n_threads)?;
+    let num_blocks = (n_threads as u32).div_ceil(BLOCK_SIZE);
+    let ret = unsafe {
+        cuLaunchKernel(
+            kernel,
+            num_blocks,
+            1,
+            1,          // grid
+            BLOCK_SIZE,
+            1,
+            1,          // block
+            0,          // shared mem
+            std::ptr::null_mut(), // stream
+            params.as_mut_ptr(),
+            std::ptr::null_mut(), // extra
+        )
+    };
+
+    if ret != CUDA_SUCCESS {
+        return Err(CudaError::CudaRuntimeError {
+            operation: format!("cuLaunchKernel({})", name),
+            code: ret,
+        });
+    }
+
+    // Synchronize
+    let ret = unsafe { cuCtxSynchronize() };
+    if ret != CUDA_SUCCESS {
+        return Err(CudaError::CudaRuntimeError {
+            operation: "cuCtxSynchronize".to_string(),
+            code: ret,
+        });
+    }
+
+    Ok(())
+}
+
+/// Run `compute_core_distances_kernel` over vectors already on the device.
+///
+/// `d_valid` is the validity bitmap, or 0 when every point is valid.
+fn launch_core_distances(
+    module: &CudaModule,
+    d_vectors: CUdeviceptr,
+    d_valid: CUdeviceptr,
+    n_points: usize,
+    dimension: usize,
+    k: usize,
+    metric: i32,
+) -> CudaResult<Vec<f32>> {
+    const KERNEL: &str = "compute_core_distances_kernel";
+
+    // BLD-08 FIX: Validate ALL parameters before i32 cast, not just n_points.
+    let n_points_i32 = kernel_int(KERNEL, "n_points", n_points)?;
+    let dimension_i32 = kernel_int(KERNEL, "dimension", dimension)?;
+    let k_i32 = kernel_int(KERNEL, "k", k)?;
+
+    let kernel = module.get_function(KERNEL)?;
+    let d_output = GpuBuffer::new(n_points * std::mem::size_of::<f32>())?;
+    let d_output_ptr = d_output.ptr();
+
+    let mut params: [*mut c_void; 7] = [
+        &d_vectors as *const _ as *mut c_void,
+        &d_valid as *const _ as *mut c_void,
+        &n_points_i32 as *const _ as *mut c_void,
+        &dimension_i32 as *const _ as *mut c_void,
+        &k_i32 as *const _ as *mut c_void,
+        &metric as *const _ as *mut c_void,
+        &d_output_ptr as *const _ as *mut c_void,
+    ];
+    launch_and_sync(kernel, KERNEL, n_points, &mut params)?;
+
+    // Copy results back
+    let mut output = vec![0.0f32; n_points];
+    d_output.copy_to_host(f32_bytes_mut(&mut output))?;
+
+    Ok(output)
+}
+
+/// Run `knn_search_kernel` for host queries against vectors already on the device.
+///
+/// `d_valid` is the validity bitmap, or 0 when every slot is valid.
+#[allow(clippy::too_many_arguments)]
+fn launch_knn_search(
+    module: &CudaModule,
+    d_vectors: CUdeviceptr,
+    d_valid: CUdeviceptr,
+    n_points: usize,
+    queries: &[f32],
+    n_queries: usize,
+    dimension: usize,
+    k: usize,
+    metric: i32,
+) -> CudaResult<(Vec<i32>, Vec<f32>)> {
+    const KERNEL: &str = "knn_search_kernel";
+
+    let n_points_i32 = kernel_int(KERNEL, "n_points", n_points)?;
+    let n_queries_i32 = kernel_int(KERNEL, "n_queries", n_queries)?;
+    let dimension_i32 = kernel_int(KERNEL, "dimension", dimension)?;
+    let k_i32 = kernel_int(KERNEL, "k", k)?;
+
+    let output_len = n_queries
+        .checked_mul(k)
+        .ok_or_else(|| CudaError::CudaRuntimeError {
+            operation: format!(
+                "{}: output size overflow for n_queries={}, k={}",
+                KERNEL, n_queries, k
+            ),
+            code: -1,
+        })?;
+
+    let kernel = module.get_function(KERNEL)?;
+
+    // Allocate GPU memory and copy queries
+    let d_queries = GpuBuffer::new(std::mem::size_of_val(queries))?;
+    d_queries.copy_from_host(f32_bytes(queries))?;
+    let d_indices = GpuBuffer::new(output_len * std::mem::size_of::<i32>())?;
+    let d_scores = GpuBuffer::new(output_len * std::mem::size_of::<f32>())?;
+
+    let d_queries_ptr = d_queries.ptr();
+    let d_indices_ptr = d_indices.ptr();
+    let d_scores_ptr = d_scores.ptr();
+
+    let mut params: [*mut c_void; 10] = [
+        &d_vectors as *const _ as *mut c_void,
+        &d_valid as *const _ as *mut c_void,
+        &n_points_i32 as *const _ as *mut c_void,
+        &d_queries_ptr as *const _ as *mut c_void,
+        &n_queries_i32 as *const _ as *mut c_void,
+        &dimension_i32 as *const _ as *mut c_void,
+        &k_i32 as *const _ as *mut c_void,
+        &metric as *const _ as *mut c_void,
+        &d_indices_ptr as *const _ as *mut c_void,
+        &d_scores_ptr as *const _ as *mut c_void,
+    ];
+    launch_and_sync(kernel, KERNEL, n_queries, &mut params)?;
+
+    // Copy results back
+    let mut indices = vec![0i32; output_len];
+    let indices_size = std::mem::size_of_val(indices.as_slice());
+    let indices_bytes =
+        unsafe { std::slice::from_raw_parts_mut(indices.as_mut_ptr() as *mut u8, indices_size) };
+    d_indices.copy_to_host(indices_bytes)?;
+
+    let mut scores = vec![0.0f32; output_len];
+    d_scores.copy_to_host(f32_bytes_mut(&mut scores))?;
+
+    Ok((indices, scores))
+}
+
+// =============================================================================
+// INCREMENTAL DEVICE STORE
+// =============================================================================
+
+/// Minimum slot capacity allocated by [`KnnDeviceStore`].
+const STORE_MIN_CAPACITY: usize = 64;
+
+/// Device bytes currently held by all k-NN device stores.
+static KNN_DEVICE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// High-water mark of [`KNN_DEVICE_BYTES`].
+static KNN_PEAK_DEVICE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+fn track_device_alloc(bytes: usize) {
+    let current = KNN_DEVICE_BYTES.fetch_add(bytes, Ordering::Relaxed) + bytes;
+    KNN_PEAK_DEVICE_BYTES.fetch_max(current, Ordering::Relaxed);
+}
+
+fn track_device_free(bytes: usize) {
+    KNN_DEVICE_BYTES.fetch_sub(bytes, Ordering::Relaxed);
+}
+
+/// Device memory currently held by k-NN device stores, in bytes.
+pub fn knn_device_bytes() -> usize {
+    KNN_DEVICE_BYTES.load(Ordering::Relaxed)
+}
+
+/// Peak device memory held by k-NN device stores since process start, in bytes.
+pub fn knn_peak_device_bytes() -> usize {
+    KNN_PEAK_DEVICE_BYTES.load(Ordering::Relaxed)
+}
+
+/// Copy host bytes into device memory at `dst`.
+fn copy_host_to_device(dst: CUdeviceptr, src: &[u8]) -> CudaResult<()> {
+    let ret = unsafe { cuMemcpyHtoD_v2(dst, src.as_ptr() as *const c_void, src.len()) };
+    if ret == CUDA_SUCCESS {
+        Ok(())
+    } else {
+        Err(CudaError::CudaRuntimeError {
+            operation: "cuMemcpyHtoD_v2".to_string(),
+            code: ret,
+        })
+    }
+}
+
+/// Copy `bytes` of device memory from `src` to `dst`.
+fn copy_device_to_device(dst: CUdeviceptr, src: CUdeviceptr, bytes: usize) -> CudaResult<()> {
+    if bytes == 0 {
+        return Ok(());
+    }
+    let ret = unsafe { cuMemcpyDtoD_v2(dst, src, bytes) };
+    if ret == CUDA_SUCCESS {
+        Ok(())
+    } else {
+        Err(CudaError::CudaRuntimeError {
+            operation: "cuMemcpyDtoD_v2".to_string(),
+            code: ret,
+        })
+    }
+}
+
+/// Device-resident vector store for incremental k-NN.
+///
+/// Vectors stay on the GPU between calls, so appends upload only the new
+/// rows. Capacity grows by doubling. Removed slots are cleared in a validity
+/// bitmap that the kernels skip, and [`compact`](Self::compact) reclaims them.
+pub struct KnnDeviceStore {
+    /// Vector rows, `capacity * dimension` floats
+    vectors: GpuBuffer,
+    /// Validity bitmap, one bit per slot
+    valid: GpuBuffer,
+    /// Host mirror of the validity bitmap
+    valid_bits: Vec<u32>,
+    dimension: usize,
+    capacity: usize,
+    /// Slots in use, including removed ones
+    len: usize,
+    /// Slots still valid
+    live: usize,
+    peak_bytes: usize,
+    module: CudaModule,
+    // Must be dropped last: buffers and module are freed in this context.
+    ctx: CudaContext,
+}
+
+// SAFETY: The store owns its context, module and buffers exclusively, and
+// makes its context current on the calling thread before every driver call.
+unsafe impl Send for KnnDeviceStore {}
+
+impl KnnDeviceStore {
+    /// Create an empty store on device 0.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidArgument` if `dimension` is 0
+    /// - `CudaRuntimeError` if context creation or allocation fails
+    pub fn new(dimension: usize) -> CudaResult<Self> {
+        if dimension == 0 {
+            return Err(CudaError::InvalidArgument {
+                argument: "dimension".to_string(),
+                reason: "Dimension must be > 0".to_string(),
+            });
+        }
+
+        ensure_cuda_initialized()?;
+        let ctx = CudaContext::new(0)?;
+        let module = CudaModule::load_ptx(PTX)?;
+
+        let capacity = STORE_MIN_CAPACITY;
+        let vectors = GpuBuffer::new(capacity * dimension * std::mem::size_of::<f32>())?;
+        let valid_bits = vec![0u32; capacity.div_ceil(32)];
+        let valid = GpuBuffer::new(valid_bits.len() * std::mem::size_of::<u32>())?;
+
+        let mut store = Self {
+            vectors,
+            valid,
+            valid_bits,
+            dimension,
+            capacity,
+            len: 0,
+            live: 0,
+            peak_bytes: 0,
+            module,
+            ctx,
+        };
+        store.upload_valid_bits()?;
+        track_device_alloc(store.device_bytes());
+        store.peak_bytes = store.device_bytes();
+        Ok(store)
+    }
+
+    /// Append flattened vectors, growing capacity by doubling if needed.
+    ///
+    /// Returns the slot range assigned to the new vectors.
+    pub fn append(&mut self, vectors: &[f32]) -> CudaResult<std::ops::Range<usize>> {
+        if vectors.len() % self.dimension != 0 {
+            return Err(CudaError::InvalidArgument {
+                argument: "vectors".to_string(),
+                reason: format!(
+                    "Length {} is not a multiple of dimension {}",
+                    vectors.len(),
+                    self.dimension
+                ),
+            });
+        }
+        let n = vectors.len() / self.dimension;
+        let start = self.len;
+        if n == 0 {
+            return Ok(start..start);
+        }
+
+        self.bind()?;
+        let needed = self.len + n;
+        if needed > self.capacity {
+            self.reallocate((self.capacity * 2).max(needed), None)?;
+        }
+
+        let offset = (self.len * self.dimension * std::mem::size_of::<f32>()) as u64;
+        copy_host_to_device(self.vectors.ptr() + offset, f32_bytes(vectors))?;
+
+        for slot in start..needed {
+            self.valid_bits[slot / 32] |= 1 << (slot % 32);
+        }
+        self.upload_valid_bits()?;
+
+        self.len = needed;
+        self.live += n;
+        Ok(start..needed)
+    }
+
+    /// Mark slots as removed. Out-of-range or already-removed slots are ignored.
+    ///
+    /// Returns the number of slots newly removed.
+    pub fn remove(&mut self, slots: &[usize]) -> CudaResult<usize> {
+        let mut removed = 0;
+        for &slot in slots {
+            if self.is_valid(slot) {
+                self.valid_bits[slot / 32] &= !(1 << (slot % 32));
+                removed += 1;
+            }
+        }
+
+        if removed > 0 {
+            self.bind()?;
+            self.upload_valid_bits()?;
+            self.live -= removed;
+        }
+        Ok(removed)
+    }
+
+    /// Move live vectors to the front of a right-sized buffer.
+    ///
+    /// Returns the old slot of each surviving vector, in new slot order.
+    pub fn compact(&mut self) -> CudaResult<Vec<usize>> {
+        let kept: Vec<usize> = (0..self.len).filter(|&slot| self.is_valid(slot)).collect();
+        self.bind()?;
+        self.reallocate(self.live.max(STORE_MIN_CAPACITY), Some(&kept))?;
+
+        self.valid_bits.iter_mut().for_each(|word| *word = 0);
+        for slot in 0..kept.len() {
+            self.valid_bits[slot / 32] |= 1 << (slot % 32);
+        }
+        self.upload_valid_bits()?;
+
+        self.len = kept.len();
+        self.live = kept.len();
+        Ok(kept)
+    }
+
+    /// Compute core distances for every slot. Removed slots get 0.
+    pub fn core_distances(&self, k: usize, metric: i32) -> CudaResult<Vec<f32>> {
+        validate_metric(metric)?;
+        if self.len == 0 {
+            return Ok(vec![]);
+        }
+
+        self.bind()?;
+        launch_core_distances(
+            &self.module,
+            self.vectors.ptr(),
+            self.valid.ptr(),
+            self.len,
+            self.dimension,
+            k,
+            metric,
+        )
+    }
+
+    /// Search live slots for the `k` nearest neighbors of each query.
+    ///
+    /// Returns slot indices and scores as in [`knn_search_gpu`].
+    pub fn search(
+        &self,
+        queries: &[f32],
+        n_queries: usize,
+        k: usize,
+        metric: i32,
+    ) -> CudaResult<(Vec<i32>, Vec<f32>)> {
+        validate_search_args(queries, n_queries, self.dimension, k, self.live, metric)?;
+        if n_queries == 0 {
+            return Ok((vec![], vec![]));
+        }
+
+        self.bind()?;
+        launch_knn_search(
+            &self.module,
+            self.vectors.ptr(),
+            self.valid.ptr(),
+            self.len,
+            queries,
+            n_queries,
+            self.dimension,
+            k,
+            metric,
+        )
+    }
+
+    /// Whether `slot` holds a live vector.
+    pub fn is_valid(&self, slot: usize) -> bool {
+        slot < self.len && (self.valid_bits[slot / 32] >> (slot % 32)) & 1 == 1
+    }
+
+    /// Slots in use, including removed ones.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the store has no slots in use.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of live vectors.
+    pub fn live(&self) -> usize {
+        self.live
+    }
+
+    /// Allocated slot capacity.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Device bytes currently allocated by this store.
+    pub fn device_bytes(&self) -> usize {
+        self.vectors.size() + self.valid.size()
+    }
+
+    /// Peak device bytes held by this store, including transient
+    /// double allocation while growing.
+    pub fn peak_device_bytes(&self) -> usize {
+        self.peak_bytes
+    }
+
+    /// Make this store's context current on the calling thread.
+    fn bind(&self) -> CudaResult<()> {
+        let ret = unsafe { cuCtxSetCurrent(self.ctx.ctx) };
+        if ret == CUDA_SUCCESS {
+            Ok(())
+        } else {
+            Err(CudaError::CudaRuntimeError {
+                operation: "cuCtxSetCurrent".to_string(),
+                code: ret,
+            })
+        }
+    }
+
+    /// Replace the device buffers with `new_capacity` slots.
+    ///
+    /// Copies all used slots, or only `kept` slots (packed to the front)
+    /// when given. The host bitmap is resized but not rewritten.
+    fn reallocate(&mut self, new_capacity: usize, kept: Option<&[usize]>) -> CudaResult<()> {
+        let row_bytes = self.dimension * std::mem::size_of::<f32>();
+        let vectors = GpuBuffer::new(new_capacity * row_bytes)?;
+        let words = new_capacity.div_ceil(32);
+        let valid = GpuBuffer::new(words * std::mem::size_of::<u32>())?;
+        let new_bytes = vectors.size() + valid.size();
+        track_device_alloc(new_bytes);
+        self.peak_bytes = self.peak_bytes.max(self.device_bytes() + new_bytes);
+
+        let copied = match kept {
+            None => copy_device_to_device(vectors.ptr(), self.vectors.ptr(), self.len * row_bytes),
+            Some(kept) => {
+                // Copy runs of consecutive live slots in one call each
+                let mut result = Ok(());
+                let mut dst = 0;
+                let mut i = 0;
+                while i < kept.len() && result.is_ok() {
+                    let run_start = kept[i];
+                    let mut run_len = 1;
+                    while i + run_len < kept.len() && kept[i + run_len] == run_start + run_len {
+                        run_len += 1;
+                    }
+                    result = copy_device_to_device(
+                        vectors.ptr() + (dst * row_bytes) as u64,
+                        self.vectors.ptr() + (run_start * row_bytes) as u64,
+                        run_len * row_bytes,
+                    );
+                    dst += run_len;
+                    i += run_len;
+                }
+                result
+            }
+        };
+        if let Err(e) = copied {
+            track_device_free(new_bytes);
+            return Err(e);
+        }
+
+        track_device_free(self.device_bytes());
+        self.vectors = vectors;
+        self.valid = valid;
+        self.valid_bits.resize(words, 0);
+        self.capacity = new_capacity;
+        Ok(())
+    }
+
+    /// Upload the host bitmap mirror to the device.
+    fn upload_valid_bits(&self) -> CudaResult<()> {
+        let size = std::mem::size_of_val(self.valid_bits.as_slice());
+        let bytes =
+            unsafe { std::slice::from_raw_parts(self.valid_bits.as_ptr() as *const u8, size) };
+        self.valid.copy_from_host(bytes)
+    }
+}
+
+impl Drop for KnnDeviceStore {
+    fn drop(&mut self) {
+        // Buffers are freed after this runs; they belong to our context.
+        let _ = self.bind();
+        track_device_free(self.device_bytes());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -677,7 +1286,7 @@ mod tests {
             1.0, 1.0, 1.0, // Point 4
         ];
 
-        let result = compute_core_distances_gpu(&vectors, 5, 3, 2);
+        let result = compute_core_distances_gpu(&vectors, 5, 3, 2, KNN_METRIC_L2);
         match result {
             Ok(distances) => {
                 assert_eq!(distances.len(), 5);
@@ -689,4 +1298,74 @@ mod tests {
             Err(e) => panic!("GPU computation failed: {}", e),
         }
     }
+
+    #[test]
+    fn test_knn_search_rejects_unknown_metric() {
+        let vectors = vec![1.0, 0.0, 0.0, 1.0];
+        let result = knn_search_gpu(&vectors, 2, &vectors, 2, 2, 1, 7);
+        assert!(matches!(
+            result,
+            Err(CudaError::InvalidArgument { ref argument, .. }) if argument == "metric"
+        ));
+    }
+
+    #[test]
+    fn test_knn_search_inner_product_small() {
+        if !cuda_available() {
+            println!("No GPU, skipping");
+            return;
+        }
+
+        // 3 unit vectors in 2D
+        let vectors = vec![
+            1.0, 0.0, // Point 0
+            0.0, 1.0, // Point 1
+            0.6, 0.8, // Point 2
+        ];
+        let queries = vec![0.8, 0.6];
+
+        let (indices, scores) =
+            knn_search_gpu(&vectors, 3, &queries, 1, 2, 3, KNN_METRIC_INNER_PRODUCT)
+                .expect("GPU search failed");
+        assert_eq!(indices, vec![2, 0, 1]);
+        assert!((scores[0] - 0.96).abs() < 1e-6);
+        assert!((scores[1] - 0.8).abs() < 1e-6);
+        assert!((scores[2] - 0.6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_device_store_grow_remove_compact() {
+        if !cuda_available() {
+            println!("No GPU, skipping");
+            return;
+        }
+
+        let mut store = KnnDeviceStore::new(2).expect("Failed to create store");
+        assert_eq!(store.capacity(), STORE_MIN_CAPACITY);
+
+        // 100 points on a line: point i = (i, 0)
+        let vectors: Vec<f32> = (0..100).flat_map(|i| [i as f32, 0.0]).collect();
+        assert_eq!(store.append(&vectors).expect("Append failed"), 0..100);
+        assert_eq!(store.capacity(), 2 * STORE_MIN_CAPACITY);
+        assert!(store.peak_device_bytes() > store.device_bytes());
+
+        // Removing point 1 makes point 2 the nearest neighbor of point 0
+        assert_eq!(store.remove(&[1, 1, 500]).expect("Remove failed"), 1);
+        let (indices, _) = store
+            .search(&[0.0, 0.0], 1, 2, KNN_METRIC_L2)
+            .expect("Search failed");
+        assert_eq!(indices, vec![0, 2]);
+
+        let kept = store.compact().expect("Compact failed");
+        assert_eq!(kept.len(), 99);
+        assert_eq!(kept[1], 2);
+        assert_eq!(store.len(), 99);
+        assert_eq!(store.live(), 99);
+
+        let (indices, scores) = store
+            .search(&[0.0, 0.0], 1, 2, KNN_METRIC_L2)
+            .expect("Search failed");
+        assert_eq!(indices, vec![0, 1]);
+        assert_eq!(scores, vec![0.0, 2.0]);
+    }
 }
@@ -28,7 +28,7 @@ use tracing::{debug, error, info, instrument};
 use uuid::Uuid;
 
 use super::error::{GpuHdbscanError, GpuHdbscanResult};
-use super::gpu_knn::GpuKnnIndex;
+use super::gpu_knn::{GpuKnnIndex, KnnMetric};
 
 /// Cluster membership result.
 #[derive(Debug, Clone, PartialEq)]
@@ -84,6 +84,9 @@ pub struct HdbscanParams {
     pub min_samples: usize,
     /// Cluster selection method.
     pub cluster_selection_method: ClusterSelectionMethod,
+    /// Distance metric for core distances and mutual reachability.
+    /// InnerProduct requires unit-norm embeddings.
+    pub metric: KnnMetric,
 }
 
 impl Default for HdbscanParams {
@@ -92,6 +95,7 @@ impl Default for HdbscanParams {
             min_cluster_size: 3, // Constitution default
             min_samples: 2,
             cluster_selection_method: ClusterSelectionMethod::EOM,
+            metric: KnnMetric::L2,
         }
     }
 }
@@ -216,8 +220,8 @@ impl GpuHdbscanClusterer {
         // === STEP 1: GPU k-NN for core distances ===
         let knn_start = Instant::now();
 
-        let mut knn_index = GpuKnnIndex::new(dimension)?;
-        knn_index.add(embeddings)?;
+        let mut knn_index = GpuKnnIndex::with_metric(dimension, self.params.metric)?;
+        knn_index.add(embeddings, memory_ids)?;
 
         let core_distances = knn_index.compute_core_distances_with_vectors(
             embeddings,
@@ -282,6 +286,8 @@ impl GpuHdbscanClusterer {
     /// Compute mutual reachability matrix.
     ///
     /// MR(a,b) = max(core_dist(a), core_dist(b), dist(a,b))
+    ///
+    /// `dist` uses the same metric as the GPU core distances.
     fn compute_mutual_reachability(
         &self,
         embeddings: &[Vec<f32>],
@@ -292,7 +298,7 @@ impl GpuHdbscanClusterer {
 
         for i in 0..n {
             for j in (i + 1)..n {
-                let dist = self.euclidean_distance(&embeddings[i], &embeddings[j]);
+                let dist = self.params.metric.distance(&embeddings[i], &embeddings[j]);
                 let mr = dist.max(core_distances[i]).max(core_distances[j]);
                 mutual_reach[i][j] = mr;
                 mutual_reach[j][i] = mr;
@@ -1,36 +1,112 @@
 //! GPU-accelerated k-NN using custom CUDA kernel.
 //!
 //! Provides efficient batch k-NN computation on GPU for HDBSCAN core distance.
+//! Vectors stay resident on the device, so incremental adds and removes
+//! avoid rebuilding the index.
 //! Uses Driver API to avoid WSL2 cudart static initialization bugs.
 
+use std::collections::{HashMap, HashSet};
 use std::time::Instant;
 
 use tracing::{debug, info, instrument};
+use uuid::Uuid;
 
-use crate::ffi::knn::{compute_core_distances_gpu, cuda_available};
+use crate::ffi::knn::{
+    cuda_available, KnnDeviceStore, KNN_MAX_K, KNN_METRIC_INNER_PRODUCT, KNN_METRIC_L2,
+};
 
 use super::error::{GpuHdbscanError, GpuHdbscanResult};
 
+/// Default tolerance on `| ||v|| - 1 |` for inner-product indexes.
+pub const DEFAULT_UNIT_NORM_TOLERANCE: f32 = 1e-3;
+
+/// Distance metric used by the GPU k-NN kernels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KnnMetric {
+    /// Euclidean distance - default, works for any vectors.
+    #[default]
+    L2,
+    /// Inner product over unit-norm vectors.
+    ///
+    /// Skips norm computation in the kernels. Vectors are checked for unit
+    /// norm at insert time; core distances are cosine distances `1 - dot`.
+    InnerProduct,
+}
+
+impl KnnMetric {
+    /// Metric code passed to the CUDA kernels.
+    fn kernel_code(self) -> i32 {
+        match self {
+            Self::L2 => KNN_METRIC_L2,
+            Self::InnerProduct => KNN_METRIC_INNER_PRODUCT,
+        }
+    }
+
+    /// CPU distance matching the kernel's core distance for this metric.
+    #[inline]
+    pub fn distance(self, a: &[f32], b: &[f32]) -> f32 {
+        match self {
+            Self::L2 => a
+                .iter()
+                .zip(b.iter())
+                .map(|(x, y)| (x - y) * (x - y))
+                .sum::<f32>()
+                .sqrt(),
+            Self::InnerProduct => {
+                let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+                (1.0 - dot).max(0.0)
+            }
+        }
+    }
+}
+
+/// Default fraction of removed slots that triggers automatic compaction.
+pub const DEFAULT_COMPACT_THRESHOLD: f32 = 0.25;
+
+/// Device memory usage of a [`GpuKnnIndex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct KnnMemoryUsage {
+    /// Device bytes currently allocated.
+    pub device_bytes: usize,
+    /// Peak device bytes, including transient double allocation while growing.
+    pub peak_device_bytes: usize,
+    /// Allocated vector slots.
+    pub capacity: usize,
+    /// Live vectors.
+    pub live_vectors: usize,
+    /// Removed slots not yet reclaimed by compaction.
+    pub tombstones: usize,
+}
+
 /// GPU k-NN index for HDBSCAN core distance computation.
 ///
 /// Uses custom CUDA kernel for brute-force exact k-NN.
-/// No training required.
+/// No training required. Vectors stay on the device and are keyed by memory
+/// ID, so adds and removes do not rebuild the index.
 ///
 /// # Constitution Compliance
 ///
 /// - ARCH-GPU-05: k-NN runs on GPU
 /// - ARCH-GPU-06: Batch operations preferred
 pub struct GpuKnnIndex {
-    /// Stored vectors (flattened)
-    vectors: Vec<f32>,
+    /// Device-resident vectors and validity bitmap
+    store: KnnDeviceStore,
+    /// Memory ID per device slot (None once removed)
+    slot_ids: Vec<Option<Uuid>>,
+    /// Device slot per live memory ID
+    id_slots: HashMap<Uuid, usize>,
     /// Vector dimension
     dimension: usize,
-    /// Number of vectors in index
-    vector_count: usize,
+    /// Distance metric for search and core distances
+    metric: KnnMetric,
+    /// Allowed deviation from unit norm when metric is InnerProduct
+    unit_norm_tolerance: f32,
+    /// Tombstone fraction above which `remove` compacts automatically
+    compact_threshold: f32,
 }
 
 impl GpuKnnIndex {
-    /// Create a new GPU k-NN index.
+    /// Create a new GPU k-NN index using L2 distance.
     ///
     /// # Arguments
     ///
@@ -39,8 +115,23 @@ impl GpuKnnIndex {
     /// # Errors
     ///
     /// - `GpuNotAvailable` if no GPU detected
-    #[instrument(skip_all, fields(dimension))]
     pub fn new(dimension: usize) -> GpuHdbscanResult<Self> {
+        Self::with_metric(dimension, KnnMetric::L2)
+    }
+
+    /// Create a new GPU k-NN index with an explicit metric.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimension` - Vector dimension (must be > 0)
+    /// * `metric` - Distance metric for search and core distances
+    ///
+    /// # Errors
+    ///
+    /// - `GpuNotAvailable` if no GPU detected
+    /// - `CudaError` if the device store cannot be allocated
+    #[instrument(skip_all, fields(dimension, ?metric))]
+    pub fn with_metric(dimension: usize, metric: KnnMetric) -> GpuHdbscanResult<Self> {
         // Validate dimension
         if dimension == 0 {
             return Err(GpuHdbscanError::invalid_dimension(dimension));
@@ -53,27 +144,80 @@ impl GpuKnnIndex {
             ));
         }
 
-        debug!(dimension, "Creating GPU k-NN index");
+        debug!(dimension, ?metric, "Creating GPU k-NN index");
+
+        let store = KnnDeviceStore::new(dimension)
+            .map_err(|e| GpuHdbscanError::internal("KnnDeviceStore::new", e.to_string()))?;
 
         Ok(Self {
-            vectors: Vec::new(),
+            store,
+            slot_ids: Vec::new(),
+            id_slots: HashMap::new(),
             dimension,
-            vector_count: 0,
+            metric,
+            unit_norm_tolerance: DEFAULT_UNIT_NORM_TOLERANCE,
+            compact_threshold: DEFAULT_COMPACT_THRESHOLD,
         })
     }
 
+    /// Set the unit-norm tolerance checked by `add` for inner-product indexes.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidParameter` if `tolerance` is not finite or not in (0, 1)
+    pub fn with_unit_norm_tolerance(mut self, tolerance: f32) -> GpuHdbscanResult<Self> {
+        if !tolerance.is_finite() || tolerance <= 0.0 || tolerance >= 1.0 {
+            return Err(GpuHdbscanError::invalid_parameter(
+                "unit_norm_tolerance",
+                tolerance,
+                "must be finite and in (0, 1)",
+            ));
+        }
+        self.unit_norm_tolerance = tolerance;
+        Ok(self)
+    }
+
+    /// Set the tombstone fraction above which `remove` compacts automatically.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidParameter` if `threshold` is not finite or not in (0, 1]
+    pub fn with_compact_threshold(mut self, threshold: f32) -> GpuHdbscanResult<Self> {
+        if !threshold.is_finite() || threshold <= 0.0 || threshold > 1.0 {
+            return Err(GpuHdbscanError::invalid_parameter(
+                "compact_threshold",
+                threshold,
+                "must be finite and in (0, 1]",
+            ));
+        }
+        self.compact_threshold = threshold;
+        Ok(self)
+    }
+
     /// Add vectors to the index.
     ///
+    /// Only the new vectors are uploaded; device capacity grows by doubling.
+    ///
     /// # Arguments
     ///
     /// * `vectors` - Vectors to add, each must be `dimension` elements
+    /// * `ids` - Memory ID of each vector, not already in the index
     ///
     /// # Errors
     ///
-    /// - `DimensionMismatch` if any vector has wrong dimension
+    /// - `DimensionMismatch` if `vectors` and `ids` differ in length
+    /// - `InvalidParameter` if any vector has wrong dimension or an ID is duplicated
     /// - `NonFiniteValue` if any value is NaN or Infinity
+    /// - `InvalidParameter` if the metric is InnerProduct and a vector's
+    ///   norm is outside `1 ± unit_norm_tolerance`
     #[instrument(skip_all, fields(n_vectors = vectors.len()))]
-    pub fn add(&mut self, vectors: &[Vec<f32>]) -> GpuHdbscanResult<()> {
+    pub fn add(&mut self, vectors: &[Vec<f32>], ids: &[Uuid]) -> GpuHdbscanResult<()> {
+        if vectors.len() != ids.len() {
+            return Err(GpuHdbscanError::dimension_mismatch(
+                vectors.len(),
+                ids.len(),
+            ));
+        }
         if vectors.is_empty() {
             return Ok(());
         }
@@ -81,7 +225,18 @@ impl GpuKnnIndex {
         let n = vectors.len();
         debug!(n_vectors = n, dimension = self.dimension, "Adding vectors to index");
 
-        // Validate dimensions and values
+        // Validate IDs, dimensions and values before touching the device
+        let mut batch_ids = HashSet::with_capacity(n);
+        for (i, id) in ids.iter().enumerate() {
+            if self.id_slots.contains_key(id) || !batch_ids.insert(*id) {
+                return Err(GpuHdbscanError::InvalidParameter {
+                    parameter: format!("ids[{}]", i),
+                    value: id.to_string(),
+                    requirement: "must not already be in the index".to_string(),
+                });
+            }
+        }
+
         for (i, vec) in vectors.iter().enumerate() {
             if vec.len() != self.dimension {
                 return Err(GpuHdbscanError::InvalidParameter {
@@ -96,15 +251,111 @@ impl GpuKnnIndex {
                     return Err(GpuHdbscanError::non_finite_value(i * self.dimension + j, val));
                 }
             }
+
+            if self.metric == KnnMetric::InnerProduct {
+                let norm = vec.iter().map(|x| x * x).sum::<f32>().sqrt();
+                if (norm - 1.0).abs() > self.unit_norm_tolerance {
+                    return Err(GpuHdbscanError::InvalidParameter {
+                        parameter: format!("vectors[{}] norm", i),
+                        value: norm.to_string(),
+                        requirement: format!(
+                            "must be 1.0 ± {} for inner-product metric",
+                            self.unit_norm_tolerance
+                        ),
+                    });
+                }
+            }
         }
 
-        // Flatten vectors (row-major)
-        for vec in vectors {
-            self.vectors.extend(vec);
+        // Flatten vectors (row-major) and append on device
+        let flat: Vec<f32> = vectors.iter().flatten().copied().collect();
+        let slots = self
+            .store
+            .append(&flat)
+            .map_err(|e| GpuHdbscanError::internal("KnnDeviceStore::append", e.to_string()))?;
+
+        for (slot, id) in slots.zip(ids) {
+            self.slot_ids.push(Some(*id));
+            self.id_slots.insert(*id, slot);
         }
-        self.vector_count += n;
 
-        debug!(total_vectors = self.vector_count, "Vectors added to index");
+        debug!(
+            total_vectors = self.len(),
+            capacity = self.store.capacity(),
+            "Vectors added to index"
+        );
+        Ok(())
+    }
+
+    /// Remove vectors by memory ID.
+    ///
+    /// Removed slots are skipped by the kernels immediately. When removed
+    /// slots exceed the compact threshold, the index compacts itself.
+    ///
+    /// # Returns
+    ///
+    /// Number of vectors removed.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidParameter` if any ID is not in the index (nothing is removed)
+    #[instrument(skip_all, fields(n_ids = ids.len()))]
+    pub fn remove(&mut self, ids: &[Uuid]) -> GpuHdbscanResult<usize> {
+        let mut slots = Vec::with_capacity(ids.len());
+        for (i, id) in ids.iter().enumerate() {
+            match self.id_slots.get(id) {
+                Some(&slot) => slots.push(slot),
+                None => {
+                    return Err(GpuHdbscanError::InvalidParameter {
+                        parameter: format!("ids[{}]", i),
+                        value: id.to_string(),
+                        requirement: "must be in the index".to_string(),
+                    });
+                }
+            }
+        }
+
+        let removed = self
+            .store
+            .remove(&slots)
+            .map_err(|e| GpuHdbscanError::internal("KnnDeviceStore::remove", e.to_string()))?;
+        for (id, slot) in ids.iter().zip(slots) {
+            self.id_slots.remove(id);
+            self.slot_ids[slot] = None;
+        }
+
+        let tombstones = self.store.len() - self.store.live();
+        debug!(removed, tombstones, "Vectors removed from index");
+
+        if tombstones as f32 > self.compact_threshold * self.store.len() as f32 {
+            self.compact()?;
+        }
+        Ok(removed)
+    }
+
+    /// Reclaim removed slots by packing live vectors on the device.
+    ///
+    /// Live vectors keep their relative order.
+    #[instrument(skip_all, fields(n_slots = self.store.len(), n_live = self.store.live()))]
+    pub fn compact(&mut self) -> GpuHdbscanResult<()> {
+        let kept = self
+            .store
+            .compact()
+            .map_err(|e| GpuHdbscanError::internal("KnnDeviceStore::compact", e.to_string()))?;
+
+        let slot_ids: Vec<Option<Uuid>> = kept.iter().map(|&old| self.slot_ids[old]).collect();
+        self.id_slots = slot_ids
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, id)| id.map(|id| (id, slot)))
+            .collect();
+        self.slot_ids = slot_ids;
+
+        debug!(
+            live = self.len(),
+            capacity = self.store.capacity(),
+            "Index compacted"
+        );
         Ok(())
     }
 
@@ -119,47 +370,52 @@ impl GpuKnnIndex {
     ///
     /// # Returns
     ///
-    /// Vector of core distances, one per vector in index.
-    /// Core distance is the L2 distance to the k-th nearest neighbor.
+    /// Vector of core distances, one per live vector in [`ids`](Self::ids) order.
+    /// Core distance is the L2 distance (or cosine distance for
+    /// InnerProduct) to the k-th nearest neighbor.
     ///
     /// # Errors
     ///
     /// - `InsufficientData` if index has fewer than k+1 vectors
     /// - `CudaError` if GPU computation fails
-    #[instrument(skip_all, fields(k, n_vectors = self.vector_count))]
+    #[instrument(skip_all, fields(k, n_vectors = self.len()))]
     pub fn compute_core_distances(&self, k: usize) -> GpuHdbscanResult<Vec<f32>> {
-        if self.vector_count == 0 {
+        let live = self.len();
+        if live == 0 {
             return Ok(vec![]);
         }
 
         // Need k+1 neighbors (including self) to get k-th neighbor distance
-        let k_search = k.min(self.vector_count - 1) + 1;
+        let k_search = k.min(live - 1) + 1;
 
-        if self.vector_count < k_search {
-            return Err(GpuHdbscanError::insufficient_data(k_search, self.vector_count));
+        if live < k_search {
+            return Err(GpuHdbscanError::insufficient_data(k_search, live));
         }
 
         let start = Instant::now();
         info!(
             k,
             k_search,
-            n_vectors = self.vector_count,
+            n_vectors = live,
             "Computing core distances on GPU"
         );
 
-        // Run GPU k-NN kernel
-        let core_distances = compute_core_distances_gpu(
-            &self.vectors,
-            self.vector_count,
-            self.dimension,
-            k,
-        )
-        .map_err(|e| GpuHdbscanError::internal("compute_core_distances_gpu", e.to_string()))?;
+        // Run GPU k-NN kernel over all slots; removed slots are skipped
+        let slot_distances = self
+            .store
+            .core_distances(k_search - 1, self.metric.kernel_code())
+            .map_err(|e| GpuHdbscanError::internal("compute_core_distances_gpu", e.to_string()))?;
+
+        let core_distances: Vec<f32> = slot_distances
+            .into_iter()
+            .zip(&self.slot_ids)
+            .filter_map(|(dist, id)| id.map(|_| dist))
+            .collect();
 
         let elapsed = start.elapsed();
         info!(
             elapsed_us = elapsed.as_micros(),
-            avg_core_dist = core_distances.iter().sum::<f32>() / self.vector_count as f32,
+            avg_core_dist = core_distances.iter().sum::<f32>() / live as f32,
             "Core distances computed on GPU"
         );
 
@@ -175,7 +431,7 @@ impl GpuKnnIndex {
     ///
     /// # Returns
     ///
-    /// Vector of core distances (L2 distances to k-th neighbor).
+    /// Vector of core distances to the k-th neighbor under the index metric.
     #[instrument(skip_all, fields(k, n_vectors = vectors.len()))]
     pub fn compute_core_distances_with_vectors(
         &self,
@@ -187,30 +443,145 @@ impl GpuKnnIndex {
             return Ok(vec![]);
         }
 
-        if n != self.vector_count {
-            return Err(GpuHdbscanError::dimension_mismatch(self.vector_count, n));
+        if n != self.len() {
+            return Err(GpuHdbscanError::dimension_mismatch(self.len(), n));
         }
 
         // Just delegate to compute_core_distances since we have the vectors stored
         self.compute_core_distances(k)
     }
 
+    /// Find the `k` nearest indexed vectors for each query.
+    ///
+    /// # Arguments
+    ///
+    /// * `queries` - Query vectors, each must be `dimension` elements
+    /// * `k` - Neighbors per query (1..=16, at most the index size)
+    ///
+    /// # Returns
+    ///
+    /// One `(memory_id, score)` list per query. L2 scores are distances in
+    /// ascending order; InnerProduct scores are similarities in descending
+    /// order. Ties keep the earlier-inserted vector.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidParameter` if `k` is out of range or a query has wrong dimension
+    /// - `NonFiniteValue` if any query value is NaN or Infinity
+    /// - `CudaError` if GPU computation fails
+    #[instrument(skip_all, fields(k, n_queries = queries.len(), n_vectors = self.len()))]
+    pub fn search(
+        &self,
+        queries: &[Vec<f32>],
+        k: usize,
+    ) -> GpuHdbscanResult<Vec<Vec<(Uuid, f32)>>> {
+        if queries.is_empty() {
+            return Ok(vec![]);
+        }
+
+        if k == 0 || k > KNN_MAX_K || k > self.len() {
+            return Err(GpuHdbscanError::invalid_parameter(
+                "k",
+                k,
+                format!(
+                    "must be in 1..={} and <= index size ({})",
+                    KNN_MAX_K,
+                    self.len()
+                ),
+            ));
+        }
+
+        let mut flat = Vec::with_capacity(queries.len() * self.dimension);
+        for (i, query) in queries.iter().enumerate() {
+            if query.len() != self.dimension {
+                return Err(GpuHdbscanError::InvalidParameter {
+                    parameter: format!("queries[{}].len()", i),
+                    value: query.len().to_string(),
+                    requirement: format!("must equal dimension {}", self.dimension),
+                });
+            }
+            for (j, &val) in query.iter().enumerate() {
+                if !val.is_finite() {
+                    return Err(GpuHdbscanError::non_finite_value(i * self.dimension + j, val));
+                }
+            }
+            flat.extend(query);
+        }
+
+        let start = Instant::now();
+        let (slots, scores) = self
+            .store
+            .search(&flat, queries.len(), k, self.metric.kernel_code())
+            .map_err(|e| GpuHdbscanError::internal("knn_search_gpu", e.to_string()))?;
+
+        debug!(elapsed_us = start.elapsed().as_micros(), "GPU k-NN search complete");
+
+        slots
+            .chunks(k)
+            .zip(scores.chunks(k))
+            .map(|(row_slots, row_scores)| {
+                row_slots
+                    .iter()
+                    .zip(row_scores)
+                    .map(|(&slot, &score)| {
+                        usize::try_from(slot)
+                            .ok()
+                            .and_then(|slot| self.slot_ids.get(slot).copied().flatten())
+                            .map(|id| (id, score))
+                            .ok_or_else(|| {
+                                GpuHdbscanError::internal(
+                                    "knn_search_gpu",
+                                    format!("kernel returned non-live slot {}", slot),
+                                )
+                            })
+                    })
+                    .collect::<GpuHdbscanResult<Vec<_>>>()
+            })
+            .collect()
+    }
+
+    /// Memory IDs of live vectors, in core-distance order.
+    pub fn ids(&self) -> Vec<Uuid> {
+        self.slot_ids.iter().flatten().copied().collect()
+    }
+
+    /// Check if a memory ID is in the index.
+    pub fn contains(&self, id: &Uuid) -> bool {
+        self.id_slots.contains_key(id)
+    }
+
+    /// Device memory usage of this index.
+    pub fn memory_usage(&self) -> KnnMemoryUsage {
+        KnnMemoryUsage {
+            device_bytes: self.store.device_bytes(),
+            peak_device_bytes: self.store.peak_device_bytes(),
+            capacity: self.store.capacity(),
+            live_vectors: self.store.live(),
+            tombstones: self.store.len() - self.store.live(),
+        }
+    }
+
     /// Get the number of vectors in the index.
     pub fn len(&self) -> usize {
-        self.vector_count
+        self.id_slots.len()
     }
 
     /// Check if the index is empty.
     pub fn is_empty(&self) -> bool {
-        self.vector_count == 0
+        self.id_slots.is_empty()
     }
 
     /// Get the vector dimension.
     pub fn dimension(&self) -> usize {
         self.dimension
     }
+
+    /// Get the distance metric.
+    pub fn metric(&self) -> KnnMetric {
+        self.metric
+    }
 }
 
 // CUDA-M1 FIX: Removed `unsafe impl Send for GpuKnnIndex`.
-// All fields (Vec<f32>, usize, usize) are Send, so the compiler
-// auto-derives Send. The explicit unsafe impl was unnecessary.
+// All fields are Send (KnnDeviceStore carries its own SAFETY-justified impl),
+// so the compiler auto-derives Send.
@@ -29,7 +29,10 @@ mod clusterer;
 
 pub use error::{GpuHdbscanError, GpuHdbscanResult};
 pub use clusterer::{ClusterMembership, ClusterSelectionMethod, GpuHdbscanClusterer, HdbscanParams};
-pub use gpu_knn::GpuKnnIndex;
+pub use gpu_knn::{
+    GpuKnnIndex, KnnMemoryUsage, KnnMetric, DEFAULT_COMPACT_THRESHOLD,
+    DEFAULT_UNIT_NORM_TOLERANCE,
+};
 
 #[cfg(test)]
 mod tests;
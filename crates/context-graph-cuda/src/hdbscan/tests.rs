@@ -37,7 +37,8 @@ fn test_gpu_knn_add_vectors() {
         })
         .collect();
 
-    index.add(&vectors).expect("Failed to add vectors");
+    let ids: Vec<Uuid> = (0..vectors.len()).map(|_| Uuid::new_v4()).collect();
+    index.add(&vectors, &ids).expect("Failed to add vectors");
 
     assert_eq!(index.len(), 10);
     assert!(!index.is_empty());
@@ -71,7 +72,8 @@ fn test_gpu_core_distances() {
         vectors.push(v);
     }
 
-    index.add(&vectors).expect("Failed to add vectors");
+    let ids: Vec<Uuid> = (0..vectors.len()).map(|_| Uuid::new_v4()).collect();
+    index.add(&vectors, &ids).expect("Failed to add vectors");
     assert_eq!(index.len(), 20);
 
     // Compute core distances with k=3
@@ -260,3 +262,206 @@ fn test_gpu_hdbscan_rejects_infinity() {
         Ok(_) => panic!("Should have rejected Infinity values"),
     }
 }
+
+/// Deterministic xorshift32 unit vectors for GPU/CPU parity tests.
+fn random_unit_vectors(n: usize, dim: usize, seed: u32) -> Vec<Vec<f32>> {
+    let mut state = seed;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        (state as f32 / u32::MAX as f32) * 2.0 - 1.0
+    };
+    (0..n)
+        .map(|_| {
+            let v: Vec<f32> = (0..dim).map(|_| next()).collect();
+            let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+            v.into_iter().map(|x| x / norm).collect()
+        })
+        .collect()
+}
+
+/// Test default params keep the L2 metric.
+#[test]
+fn test_hdbscan_params_default_metric_l2() {
+    assert_eq!(HdbscanParams::default().metric, KnnMetric::L2);
+    assert_eq!(KnnMetric::default(), KnnMetric::L2);
+}
+
+/// Test CPU distances used for mutual reachability.
+#[test]
+fn test_knn_metric_cpu_distance() {
+    let a = [1.0, 0.0];
+    let b = [0.0, 1.0];
+    assert!((KnnMetric::L2.distance(&a, &b) - 2.0f32.sqrt()).abs() < 1e-6);
+    assert!((KnnMetric::InnerProduct.distance(&a, &b) - 1.0).abs() < 1e-6);
+    assert_eq!(KnnMetric::InnerProduct.distance(&a, &a), 0.0);
+}
+
+/// Test GPU inner-product top-k matches CPU brute force exactly on indices.
+#[test]
+#[ignore = "requires GPU"]
+fn test_gpu_knn_inner_product_matches_cpu_bruteforce() {
+    const N: usize = 10_000;
+    const DIM: usize = 64;
+    const K: usize = 10;
+
+    let vectors = random_unit_vectors(N, DIM, 0x9E37_79B9);
+    let queries = random_unit_vectors(32, DIM, 0x85EB_CA6B);
+
+    let mut index = GpuKnnIndex::with_metric(DIM, KnnMetric::InnerProduct)
+        .expect("Failed to create GPU k-NN index");
+    let ids: Vec<Uuid> = (0..vectors.len()).map(|_| Uuid::new_v4()).collect();
+    index.add(&vectors, &ids).expect("Failed to add vectors");
+
+    let gpu = index.search(&queries, K).expect("GPU search failed");
+    assert_eq!(gpu.len(), queries.len());
+
+    for (q, query) in queries.iter().enumerate() {
+        // Sequential f32 dot, same rounding as the kernel
+        let mut cpu: Vec<(usize, f32)> = vectors
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let dot = query.iter().zip(v).fold(0.0f32, |acc, (a, b)| acc + a * b);
+                (i, dot)
+            })
+            .collect();
+        cpu.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let gpu_indices: Vec<Uuid> = gpu[q].iter().map(|&(id, _)| id).collect();
+        let cpu_indices: Vec<Uuid> = cpu[..K].iter().map(|&(i, _)| ids[i]).collect();
+        assert_eq!(gpu_indices, cpu_indices, "top-{} mismatch for query {}", K, q);
+
+        for (&(_, gpu_score), &(_, cpu_score)) in gpu[q].iter().zip(&cpu[..K]) {
+            assert!((gpu_score - cpu_score).abs() < 1e-5);
+        }
+    }
+}
+
+/// Test inner-product index rejects non-unit vectors at insert time.
+#[test]
+#[ignore = "requires GPU"]
+fn test_gpu_knn_inner_product_rejects_non_unit_vectors() {
+    let mut index = GpuKnnIndex::with_metric(3, KnnMetric::InnerProduct)
+        .expect("Failed to create GPU k-NN index");
+
+    let ids = [Uuid::new_v4(), Uuid::new_v4()];
+    let result = index.add(&[vec![1.0, 0.0, 0.0], vec![2.0, 0.0, 0.0]], &ids);
+    match result {
+        Err(GpuHdbscanError::InvalidParameter { parameter, .. }) => {
+            assert_eq!(parameter, "vectors[1] norm");
+        }
+        Err(e) => panic!("Expected InvalidParameter error, got: {}", e),
+        Ok(_) => panic!("Should have rejected non-unit vector"),
+    }
+    assert!(index.is_empty());
+
+    // A looser tolerance accepts slightly denormalized vectors
+    let mut index = GpuKnnIndex::with_metric(3, KnnMetric::InnerProduct)
+        .expect("Failed to create GPU k-NN index")
+        .with_unit_norm_tolerance(0.05)
+        .expect("Valid tolerance");
+    index.add(&[vec![1.02, 0.0, 0.0]], &[Uuid::new_v4()]).expect("Within tolerance");
+    assert_eq!(index.len(), 1);
+}
+
+/// Test clustering with the inner-product metric on unit vectors.
+#[test]
+#[ignore = "requires GPU"]
+fn test_gpu_hdbscan_inner_product_metric() {
+    let clusterer = GpuHdbscanClusterer::with_params(HdbscanParams {
+        metric: KnnMetric::InnerProduct,
+        ..HdbscanParams::default()
+    });
+
+    // Two tight groups of unit vectors around orthogonal axes
+    let mut embeddings: Vec<Vec<f32>> = Vec::new();
+    for axis in 0..2 {
+        for i in 0..5 {
+            let mut v = vec![0.0f32; 8];
+            v[axis] = 1.0;
+            v[axis + 2] = 0.02 * i as f32;
+            let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+            embeddings.push(v.into_iter().map(|x| x / norm).collect());
+        }
+    }
+    let memory_ids: Vec<Uuid> = (0..10).map(|_| Uuid::new_v4()).collect();
+
+    let memberships = clusterer.fit(&embeddings, &memory_ids)
+        .expect("HDBSCAN clustering failed");
+
+    assert_eq!(memberships.len(), 10);
+    let non_noise = memberships.iter().filter(|m| m.cluster_id >= 0).count();
+    assert!(non_noise > 0, "All points marked as noise");
+}
+
+/// Test incremental add/remove matches a freshly built index over survivors.
+#[test]
+#[ignore = "requires GPU"]
+fn test_gpu_knn_incremental_matches_fresh_build() {
+    const DIM: usize = 32;
+    const K: usize = 8;
+
+    let vectors = random_unit_vectors(5_500, DIM, 0x2545_F491);
+    let ids: Vec<Uuid> = (0..vectors.len()).map(|_| Uuid::new_v4()).collect();
+
+    // Never auto-compact so search runs over tombstones first
+    let mut index = GpuKnnIndex::with_metric(DIM, KnnMetric::InnerProduct)
+        .expect("Failed to create GPU k-NN index")
+        .with_compact_threshold(1.0)
+        .expect("Valid threshold");
+    index.add(&vectors[..5_000], &ids[..5_000]).expect("Initial add failed");
+    let capacity_before = index.memory_usage().capacity;
+    index.add(&vectors[5_000..], &ids[5_000..]).expect("Incremental add failed");
+
+    // Remove 200 vectors spread across both batches
+    let removed: Vec<Uuid> = ids.iter().step_by(27).take(200).copied().collect();
+    assert_eq!(index.remove(&removed).expect("Remove failed"), 200);
+    assert_eq!(index.len(), 5_300);
+
+    let usage = index.memory_usage();
+    assert_eq!(usage.tombstones, 200);
+    assert_eq!(usage.live_vectors, 5_300);
+    assert!(usage.capacity >= capacity_before);
+    assert!(usage.peak_device_bytes >= usage.device_bytes);
+
+    let (survivors, survivor_ids): (Vec<Vec<f32>>, Vec<Uuid>) = vectors
+        .iter()
+        .zip(&ids)
+        .filter(|(_, id)| !removed.contains(id))
+        .map(|(v, id)| (v.clone(), *id))
+        .unzip();
+    let mut fresh = GpuKnnIndex::with_metric(DIM, KnnMetric::InnerProduct)
+        .expect("Failed to create GPU k-NN index");
+    fresh.add(&survivors, &survivor_ids).expect("Fresh add failed");
+
+    let queries = random_unit_vectors(64, DIM, 0x1B87_3593);
+    let expected = fresh.search(&queries, K).expect("Fresh search failed");
+    assert_eq!(index.search(&queries, K).expect("Search failed"), expected);
+    assert_eq!(index.ids(), fresh.ids());
+    assert_eq!(
+        index.compute_core_distances(K).expect("Core distances failed"),
+        fresh.compute_core_distances(K).expect("Fresh core distances failed")
+    );
+
+    // Compaction reclaims tombstones without changing results
+    index.compact().expect("Compact failed");
+    assert_eq!(index.memory_usage().tombstones, 0);
+    assert_eq!(index.search(&queries, K).expect("Search failed"), expected);
+    assert_eq!(index.ids(), fresh.ids());
+}
+
+/// Test remove fails fast on unknown IDs without removing anything.
+#[test]
+#[ignore = "requires GPU"]
+fn test_gpu_knn_remove_unknown_id() {
+    let mut index = GpuKnnIndex::new(4).expect("Failed to create GPU k-NN index");
+    let id = Uuid::new_v4();
+    index.add(&[vec![1.0, 0.0, 0.0, 0.0]], &[id]).expect("Add failed");
+
+    let result = index.remove(&[id, Uuid::new_v4()]);
+    assert!(matches!(result, Err(GpuHdbscanError::InvalidParameter { .. })));
+    assert!(index.contains(&id));
+    assert_eq!(index.len(), 1);
+}
@@ -83,6 +83,13 @@ pub use ffi::{
     cuda_device_count,
     compute_core_distances_gpu,
     compute_pairwise_distances_gpu,
+    knn_search_gpu,
+    knn_device_bytes,
+    knn_peak_device_bytes,
+    KnnDeviceStore,
+    KNN_MAX_K,
+    KNN_METRIC_INNER_PRODUCT,
+    KNN_METRIC_L2,
     // FAISS GPU status
     is_faiss_gpu_available,
     faiss_status,
@@ -113,7 +120,7 @@ pub use similarity::{
 // GPU HDBSCAN clustering (ARCH-GPU-05)
 pub use hdbscan::{
     ClusterMembership, ClusterSelectionMethod, GpuHdbscanClusterer, GpuHdbscanError,
-    GpuHdbscanResult, GpuKnnIndex, HdbscanParams,
+    GpuHdbscanResult, GpuKnnIndex, HdbscanParams, KnnMemoryUsage, KnnMetric,
 };
 
 // AP-007: StubVectorOps export is gated to test-only builds
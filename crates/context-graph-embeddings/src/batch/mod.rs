@@ -60,5 +60,8 @@
 mod processor;
 mod types;
 
-pub use processor::{BatchProcessor, BatchProcessorConfig, BatchProcessorStats};
-pub use types::{Batch, BatchQueue, BatchQueueStats, BatchQueueSummary, BatchRequest};
+pub use processor::{BatchProcessor, BatchProcessorConfig, BatchProcessorStats, MemoryBudget};
+pub use types::{
+    Batch, BatchQueue, BatchQueueStats, BatchQueueSummary, BatchRequest, Priority,
+    PriorityLaneSummary,
+};
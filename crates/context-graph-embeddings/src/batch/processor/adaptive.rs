@@ -0,0 +1,247 @@
+//! Adaptive batch sizing under GPU memory pressure.
+//!
+//! Tracks, per model, a current batch size limit and the recent peak memory
+//! observed for each batch size. The worker asks for the largest batch whose
+//! predicted peak fits the [`MemoryBudget`]; an out-of-memory failure halves
+//! the limit and marks that size as not fitting, and a run of successes at the
+//! limit grows it by one.
+//!
+//! With `BatchConfig::dynamic_batching` disabled, every model uses the fixed
+//! `max_batch_size`.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::error::EmbeddingError;
+use crate::types::ModelId;
+
+use super::config::MemoryBudget;
+
+/// Consecutive successful batches at the limit before it grows by one.
+const GROWTH_AFTER_SUCCESSES: u32 = 16;
+
+/// Sizing state for one model.
+#[derive(Debug)]
+struct ModelSizing {
+    /// Current maximum batch size.
+    limit: usize,
+    /// Most recent peak bytes per batch size. Sizes that ran out of memory
+    /// are recorded as just over budget.
+    peaks: BTreeMap<usize, usize>,
+    /// Successful batches at the limit since the last change.
+    successes_at_limit: u32,
+}
+
+/// Per-model batch size limits driven by memory budget and OOM feedback.
+#[derive(Debug)]
+pub(crate) struct AdaptiveBatchSizer {
+    budget: MemoryBudget,
+    max_batch_size: usize,
+    enabled: bool,
+    models: HashMap<ModelId, ModelSizing>,
+}
+
+impl AdaptiveBatchSizer {
+    /// Create a sizer starting every model at `max_batch_size`.
+    pub(crate) fn new(max_batch_size: usize, budget: MemoryBudget, enabled: bool) -> Self {
+        Self {
+            budget,
+            max_batch_size: max_batch_size.max(1),
+            enabled,
+            models: HashMap::new(),
+        }
+    }
+
+    /// Current batch size limit for `model_id`.
+    pub(crate) fn limit(&self, model_id: ModelId) -> usize {
+        self.models
+            .get(&model_id)
+            .map_or(self.max_batch_size, |m| m.limit)
+    }
+
+    /// Largest batch size up to `pending` whose predicted peak memory fits
+    /// the budget. Always at least 1 when `pending > 0`.
+    pub(crate) fn choose(&self, model_id: ModelId, pending: usize) -> usize {
+        let cap = pending.min(self.limit(model_id));
+        if !self.enabled || cap <= 1 {
+            return cap;
+        }
+        let Some(sizing) = self.models.get(&model_id) else {
+            return cap;
+        };
+        (1..=cap)
+            .rev()
+            .find(|&n| self.fits(sizing, n))
+            .unwrap_or(1)
+    }
+
+    /// Record a successful batch of `batch_size` with optional measured peak.
+    pub(crate) fn record_success(
+        &mut self,
+        model_id: ModelId,
+        batch_size: usize,
+        peak_bytes: Option<usize>,
+    ) {
+        if !self.enabled {
+            return;
+        }
+        let max_batch_size = self.max_batch_size;
+        let sizing = self.sizing_mut(model_id);
+        if let Some(peak) = peak_bytes {
+            sizing.peaks.insert(batch_size, peak);
+        }
+        if batch_size < sizing.limit || sizing.limit >= max_batch_size {
+            return;
+        }
+
+        sizing.successes_at_limit += 1;
+        if sizing.successes_at_limit < GROWTH_AFTER_SUCCESSES {
+            return;
+        }
+        sizing.successes_at_limit = 0;
+        let next = sizing.limit + 1;
+        let grow = {
+            let sizing = &self.models[&model_id];
+            self.fits(sizing, next)
+        };
+        if grow {
+            self.sizing_mut(model_id).limit = next;
+            tracing::debug!(model_id = ?model_id, limit = next, "Batch size limit raised");
+        }
+    }
+
+    /// Record that a batch of `batch_size` ran out of GPU memory.
+    ///
+    /// Halves the limit (multiplicative backoff) and marks `batch_size` as
+    /// not fitting so growth stops below it.
+    pub(crate) fn record_oom(&mut self, model_id: ModelId, batch_size: usize) {
+        if !self.enabled {
+            return;
+        }
+        let over_budget = self.budget.max_bytes.saturating_add(1);
+        let sizing = self.sizing_mut(model_id);
+        sizing.peaks.insert(batch_size, over_budget);
+        sizing.limit = (batch_size / 2).clamp(1, sizing.limit.max(1));
+        sizing.successes_at_limit = 0;
+        tracing::warn!(
+            model_id = ?model_id,
+            batch_size,
+            limit = sizing.limit,
+            "Batch ran out of GPU memory - reducing batch size limit"
+        );
+    }
+
+    /// Current limit for every model with recorded sizing state.
+    pub(crate) fn limits(&self) -> HashMap<ModelId, usize> {
+        self.models.iter().map(|(id, m)| (*id, m.limit)).collect()
+    }
+
+    fn sizing_mut(&mut self, model_id: ModelId) -> &mut ModelSizing {
+        let limit = self.max_batch_size;
+        self.models.entry(model_id).or_insert_with(|| ModelSizing {
+            limit,
+            peaks: BTreeMap::new(),
+            successes_at_limit: 0,
+        })
+    }
+
+    /// Check if the predicted peak for `n` fits the budget.
+    ///
+    /// Uses the exact observation when present, otherwise scales the nearest
+    /// smaller observation linearly (conservative: fixed overhead is scaled
+    /// too), otherwise the nearest larger one. No observations: fits.
+    fn fits(&self, sizing: &ModelSizing, n: usize) -> bool {
+        let predicted = sizing
+            .peaks
+            .range(..=n)
+            .next_back()
+            .or_else(|| sizing.peaks.range(n..).next())
+            .map(|(&m, &peak)| {
+                if m == n {
+                    peak
+                } else {
+                    (peak as u128 * n as u128).div_ceil(m as u128).min(usize::MAX as u128) as usize
+                }
+            });
+        match predicted {
+            Some(bytes) => bytes <= self.budget.max_bytes,
+            None => true,
+        }
+    }
+}
+
+/// Check if `error` reports GPU memory exhaustion.
+pub(crate) fn is_out_of_memory(error: &EmbeddingError) -> bool {
+    match error {
+        EmbeddingError::OomDuringBatch { .. } => true,
+        EmbeddingError::GpuError { message } => {
+            let message = message.to_ascii_lowercase();
+            message.contains("out of memory") || message.contains("out_of_memory")
+        }
+        _ => false,
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GB: usize = 1024 * 1024 * 1024;
+
+    fn budget(max_bytes: usize) -> MemoryBudget {
+        MemoryBudget { max_bytes }
+    }
+
+    #[test]
+    fn test_oom_halves_limit_and_blocks_size() {
+        let mut sizer = AdaptiveBatchSizer::new(32, budget(GB), true);
+        assert_eq!(sizer.choose(ModelId::Hdc, 100), 32);
+
+        sizer.record_oom(ModelId::Hdc, 32);
+        assert_eq!(sizer.limit(ModelId::Hdc), 16);
+        assert_eq!(sizer.choose(ModelId::Hdc, 100), 16);
+        assert_eq!(sizer.limit(ModelId::Semantic), 32, "Other models unaffected");
+
+        sizer.record_oom(ModelId::Hdc, 9);
+        assert_eq!(sizer.limit(ModelId::Hdc), 4);
+        for _ in 0..GROWTH_AFTER_SUCCESSES * 10 {
+            let n = sizer.choose(ModelId::Hdc, 100);
+            sizer.record_success(ModelId::Hdc, n, None);
+        }
+        assert_eq!(sizer.limit(ModelId::Hdc), 8, "Growth stops below the OOM size");
+    }
+
+    #[test]
+    fn test_choose_uses_observed_peaks() {
+        let mut sizer = AdaptiveBatchSizer::new(32, budget(GB), true);
+        sizer.record_success(ModelId::Sparse, 4, Some(GB / 4));
+
+        // 4 inputs use 256MB, so 16 is predicted at 1GB and 17 over budget
+        assert_eq!(sizer.choose(ModelId::Sparse, 100), 16);
+        assert_eq!(sizer.choose(ModelId::Sparse, 10), 10);
+    }
+
+    #[test]
+    fn test_disabled_uses_fixed_size() {
+        let mut sizer = AdaptiveBatchSizer::new(32, budget(1), false);
+        sizer.record_success(ModelId::Hdc, 32, Some(GB));
+        assert_eq!(sizer.choose(ModelId::Hdc, 100), 32);
+    }
+
+    #[test]
+    fn test_is_out_of_memory_classification() {
+        assert!(is_out_of_memory(&EmbeddingError::GpuError {
+            message: "CUDA_ERROR_OUT_OF_MEMORY during matmul".to_string(),
+        }));
+        assert!(is_out_of_memory(&EmbeddingError::GpuError {
+            message: "DriverError(out of memory)".to_string(),
+        }));
+        assert!(!is_out_of_memory(&EmbeddingError::GpuError {
+            message: "kernel launch failed".to_string(),
+        }));
+        assert!(!is_out_of_memory(&EmbeddingError::EmptyInput));
+    }
+}
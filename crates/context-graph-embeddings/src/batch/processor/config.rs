@@ -2,13 +2,34 @@
 //!
 //! Contains configuration types and validation for the BatchProcessor.
 
+use std::collections::HashMap;
+
 use crate::config::BatchConfig;
 use crate::error::{EmbeddingError, EmbeddingResult};
+use crate::types::ModelId;
 
 // ============================================================================
 // CONFIGURATION
 // ============================================================================
 
+/// GPU memory available to a single batch forward pass.
+///
+/// Adaptive sizing (`BatchConfig::dynamic_batching`) picks the largest batch
+/// whose predicted peak memory fits within `max_bytes`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryBudget {
+    /// Maximum peak bytes per batch (default: 8GB).
+    pub max_bytes: usize,
+}
+
+impl Default for MemoryBudget {
+    fn default() -> Self {
+        Self {
+            max_bytes: 8 * 1024 * 1024 * 1024,
+        }
+    }
+}
+
 /// Configuration for the BatchProcessor.
 #[derive(Debug, Clone)]
 pub struct BatchProcessorConfig {
@@ -24,6 +45,13 @@ pub struct BatchProcessorConfig {
 
     /// Channel buffer size for incoming requests (default: 1000).
     pub request_buffer_size: usize,
+
+    /// Per-batch GPU memory budget for adaptive batch sizing.
+    pub memory_budget: MemoryBudget,
+
+    /// Per-model queue capacity, overriding `batch_config.max_queue_depth`
+    /// for the listed models (default: empty).
+    pub queue_depth_overrides: HashMap<ModelId, usize>,
 }
 
 impl Default for BatchProcessorConfig {
@@ -33,6 +61,8 @@ impl Default for BatchProcessorConfig {
             poll_interval_ms: 10,
             max_concurrent_batches: 4,
             request_buffer_size: 1000,
+            memory_budget: MemoryBudget::default(),
+            queue_depth_overrides: HashMap::new(),
         }
     }
 }
@@ -58,10 +88,30 @@ impl BatchProcessorConfig {
                 message: "poll_interval_ms must be > 0".to_string(),
             });
         }
+        if self.memory_budget.max_bytes == 0 {
+            return Err(EmbeddingError::ConfigError {
+                message: "memory_budget.max_bytes must be > 0".to_string(),
+            });
+        }
+        if let Some((model_id, _)) = self.queue_depth_overrides.iter().find(|(_, &d)| d == 0) {
+            return Err(EmbeddingError::ConfigError {
+                message: format!("queue_depth_overrides[{:?}] must be > 0", model_id),
+            });
+        }
         // Validate nested batch config
         self.batch_config.validate()?;
         Ok(())
     }
+
+    /// Queue capacity for `model_id`: its override if present, otherwise
+    /// `batch_config.max_queue_depth`.
+    #[must_use]
+    pub fn queue_capacity(&self, model_id: ModelId) -> usize {
+        self.queue_depth_overrides
+            .get(&model_id)
+            .copied()
+            .unwrap_or(self.batch_config.max_queue_depth)
+    }
 }
 
 // ============================================================================
@@ -124,6 +174,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_config_validate_zero_queue_depth_override() {
+        let mut config = BatchProcessorConfig::default();
+        config.queue_depth_overrides.insert(ModelId::Semantic, 0);
+
+        let result = config.validate();
+        assert!(result.is_err());
+        if let Err(EmbeddingError::ConfigError { message }) = result {
+            assert!(message.contains("queue_depth_overrides"));
+        }
+    }
+
+    #[test]
+    fn test_config_queue_capacity_override() {
+        let mut config = BatchProcessorConfig::default();
+        config.queue_depth_overrides.insert(ModelId::Semantic, 10);
+
+        assert_eq!(config.queue_capacity(ModelId::Semantic), 10);
+        assert_eq!(config.queue_capacity(ModelId::Code), 1024);
+    }
+
     #[test]
     fn test_config_clone() {
         let config = BatchProcessorConfig::default();
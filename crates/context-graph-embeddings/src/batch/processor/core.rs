@@ -4,7 +4,7 @@
 
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use tokio::sync::{mpsc, Notify, RwLock, Semaphore};
@@ -16,6 +16,7 @@ use crate::types::ModelId;
 
 use crate::batch::{BatchQueue, BatchRequest};
 
+use super::adaptive::AdaptiveBatchSizer;
 use super::config::BatchProcessorConfig;
 use super::stats::{BatchProcessorStats, BatchProcessorStatsInternal};
 use super::worker;
@@ -78,6 +79,9 @@ pub struct BatchProcessor {
 
     /// Semaphore for limiting concurrent batches.
     pub(crate) batch_semaphore: Arc<Semaphore>,
+
+    /// Per-model adaptive batch size limits.
+    pub(crate) sizer: Arc<Mutex<AdaptiveBatchSizer>>,
 }
 
 impl BatchProcessor {
@@ -99,10 +103,9 @@ impl BatchProcessor {
         // Create per-model queues for all 14 models
         let mut queues = HashMap::with_capacity(14);
         for model_id in ModelId::all() {
-            queues.insert(
-                *model_id,
-                BatchQueue::new(*model_id, config.batch_config.clone()),
-            );
+            let queue = BatchQueue::new(*model_id, config.batch_config.clone())
+                .with_capacity(config.queue_capacity(*model_id));
+            queues.insert(*model_id, queue);
         }
         let queues = Arc::new(RwLock::new(queues));
 
@@ -114,6 +117,11 @@ impl BatchProcessor {
         let is_running = Arc::new(AtomicBool::new(true));
         let stats = Arc::new(BatchProcessorStatsInternal::default());
         let batch_semaphore = Arc::new(Semaphore::new(config.max_concurrent_batches));
+        let sizer = Arc::new(Mutex::new(AdaptiveBatchSizer::new(
+            config.batch_config.max_batch_size,
+            config.memory_budget,
+            config.batch_config.dynamic_batching,
+        )));
 
         // Clone for worker
         let worker_queues = queues.clone();
@@ -122,6 +130,7 @@ impl BatchProcessor {
         let worker_running = is_running.clone();
         let worker_stats = stats.clone();
         let worker_semaphore = batch_semaphore.clone();
+        let worker_sizer = sizer.clone();
         let poll_interval = Duration::from_millis(config.poll_interval_ms);
 
         // Spawn worker task
@@ -134,6 +143,7 @@ impl BatchProcessor {
                 worker_running,
                 worker_stats,
                 worker_semaphore,
+                worker_sizer,
                 poll_interval,
             )
             .await;
@@ -149,6 +159,7 @@ impl BatchProcessor {
             is_running,
             stats,
             batch_semaphore,
+            sizer,
         })
     }
 
@@ -162,6 +173,15 @@ impl BatchProcessor {
         queues_guard.get(&model_id).map(|q| q.len()).unwrap_or(0)
     }
 
+    /// Get `(depth, capacity)` of every model queue.
+    pub async fn queue_depths(&self) -> HashMap<ModelId, (usize, usize)> {
+        let queues_guard = self.queues.read().await;
+        queues_guard
+            .iter()
+            .map(|(model_id, q)| (*model_id, (q.len(), q.capacity())))
+            .collect()
+    }
+
     /// Get total queue depth across all models.
     pub async fn total_queue_depth(&self) -> usize {
         let queues_guard = self.queues.read().await;
@@ -176,6 +196,9 @@ impl BatchProcessor {
         let mut snapshot = self.stats.snapshot();
         snapshot.current_queue_depth = queue_depth;
         snapshot.active_batches = active;
+        if let Ok(sizer) = self.sizer.lock() {
+            snapshot.batch_limits = sizer.limits();
+        }
         snapshot
     }
 
@@ -219,7 +242,8 @@ impl BatchProcessor {
 // All fields are inherently Send + Sync:
 //   Arc<ModelRegistry>, Arc<RwLock<HashMap<...>>>, BatchProcessorConfig,
 //   mpsc::Sender<BatchRequest>, Option<JoinHandle<()>>, Arc<Notify>,
-//   Arc<AtomicBool>, Arc<BatchProcessorStatsInternal>, Arc<Semaphore>
+//   Arc<AtomicBool>, Arc<BatchProcessorStatsInternal>, Arc<Semaphore>,
+//   Arc<Mutex<AdaptiveBatchSizer>>
 // No unsafe Send/Sync override needed.
 
 impl Drop for BatchProcessor {
@@ -33,12 +33,14 @@
 //!
 //! # Module Structure
 //!
+//! - `adaptive` - Adaptive batch sizing under GPU memory pressure
 //! - `config` - Configuration types and validation
 //! - `stats` - Statistics types for metrics tracking
 //! - `worker` - Worker loop and batch processing logic
 //! - `core` - Main BatchProcessor struct and lifecycle methods
 //! - `submit` - Request submission API
 
+mod adaptive;
 mod config;
 mod core;
 mod stats;
@@ -46,6 +48,6 @@ mod submit;
 mod worker;
 
 // Re-export public types for backwards compatibility
-pub use config::BatchProcessorConfig;
+pub use config::{BatchProcessorConfig, MemoryBudget};
 pub use core::BatchProcessor;
 pub use stats::BatchProcessorStats;
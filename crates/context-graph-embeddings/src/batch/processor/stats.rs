@@ -2,8 +2,11 @@
 //!
 //! Contains statistics types for tracking batch processing metrics.
 
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 
+use crate::types::ModelId;
+
 // ============================================================================
 // INTERNAL STATISTICS
 // ============================================================================
@@ -15,6 +18,7 @@ pub(crate) struct BatchProcessorStatsInternal {
     pub(crate) batches_processed: AtomicU64,
     pub(crate) requests_completed: AtomicU64,
     pub(crate) requests_failed: AtomicU64,
+    pub(crate) oom_retries: AtomicU64,
 }
 
 impl BatchProcessorStatsInternal {
@@ -25,8 +29,10 @@ impl BatchProcessorStatsInternal {
             batches_processed: self.batches_processed.load(Ordering::Relaxed),
             requests_completed: self.requests_completed.load(Ordering::Relaxed),
             requests_failed: self.requests_failed.load(Ordering::Relaxed),
-            current_queue_depth: 0, // Must be filled by caller
-            active_batches: 0,      // Must be filled by caller
+            oom_retries: self.oom_retries.load(Ordering::Relaxed),
+            current_queue_depth: 0,       // Must be filled by caller
+            active_batches: 0,            // Must be filled by caller
+            batch_limits: HashMap::new(), // Must be filled by caller
         }
     }
 
@@ -53,6 +59,12 @@ impl BatchProcessorStatsInternal {
     pub fn add_requests_failed(&self, count: u64) {
         self.requests_failed.fetch_add(count, Ordering::Relaxed);
     }
+
+    /// Increment out-of-memory split retries counter.
+    #[inline]
+    pub fn inc_oom_retries(&self) {
+        self.oom_retries.fetch_add(1, Ordering::Relaxed);
+    }
 }
 
 // ============================================================================
@@ -70,10 +82,15 @@ pub struct BatchProcessorStats {
     pub requests_completed: u64,
     /// Total requests failed.
     pub requests_failed: u64,
+    /// Batches split in half and retried after running out of GPU memory.
+    pub oom_retries: u64,
     /// Current queue depth across all models.
     pub current_queue_depth: usize,
     /// Currently processing batch count.
     pub active_batches: usize,
+    /// Current adaptive batch size limit per model (models still at
+    /// `max_batch_size` with no recorded batches are omitted).
+    pub batch_limits: HashMap<ModelId, usize>,
 }
 
 // ============================================================================
@@ -123,8 +140,10 @@ mod tests {
             batches_processed: 10,
             requests_completed: 95,
             requests_failed: 5,
+            oom_retries: 1,
             current_queue_depth: 3,
             active_batches: 2,
+            batch_limits: HashMap::from([(ModelId::Hdc, 8)]),
         };
 
         let cloned = stats.clone();
@@ -135,6 +154,7 @@ mod tests {
         assert_eq!(stats.requests_failed, cloned.requests_failed);
         assert_eq!(stats.current_queue_depth, cloned.current_queue_depth);
         assert_eq!(stats.active_batches, cloned.active_batches);
+        assert_eq!(stats.batch_limits, cloned.batch_limits);
     }
 
     #[test]
@@ -7,7 +7,7 @@ use std::sync::atomic::Ordering;
 use crate::error::{EmbeddingError, EmbeddingResult};
 use crate::types::{ModelEmbedding, ModelId, ModelInput};
 
-use crate::batch::BatchRequest;
+use crate::batch::{BatchRequest, Priority};
 
 use super::core::BatchProcessor;
 
@@ -36,6 +36,23 @@ impl BatchProcessor {
         &self,
         model_id: ModelId,
         input: ModelInput,
+    ) -> EmbeddingResult<ModelEmbedding> {
+        self.submit_with_priority(model_id, input, Priority::default())
+            .await
+    }
+
+    /// Submit a single embedding request in a specific priority lane.
+    ///
+    /// Same as [`Self::submit`], but the request is batched ahead of (or
+    /// behind) requests in other lanes. See [`Priority`].
+    ///
+    /// # Errors
+    /// Same as [`Self::submit`].
+    pub async fn submit_with_priority(
+        &self,
+        model_id: ModelId,
+        input: ModelInput,
+        priority: Priority,
     ) -> EmbeddingResult<ModelEmbedding> {
         if !self.is_running_internal() {
             return Err(EmbeddingError::BatchError {
@@ -43,7 +60,7 @@ impl BatchProcessor {
             });
         }
 
-        let (request, rx) = BatchRequest::new(input, model_id);
+        let (request, rx) = BatchRequest::new_with_priority(input, model_id, priority);
         self.inc_requests_submitted();
 
         // Send to worker
@@ -15,19 +15,22 @@
 //! batches, not through spawning detached tasks.
 
 use std::collections::HashMap;
+use std::ops::Range;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use tokio::sync::{mpsc, Notify, RwLock, Semaphore};
 use tokio::time::interval;
 
-use crate::error::EmbeddingResult;
+use crate::error::{EmbeddingError, EmbeddingResult};
 use crate::models::ModelRegistry;
-use crate::types::{ModelEmbedding, ModelId};
+use crate::traits::EmbeddingModel;
+use crate::types::{ModelEmbedding, ModelId, ModelInput};
 
 use crate::batch::{Batch, BatchQueue, BatchRequest};
 
+use super::adaptive::{is_out_of_memory, AdaptiveBatchSizer};
 use super::stats::BatchProcessorStatsInternal;
 
 // ============================================================================
@@ -49,6 +52,7 @@ pub(crate) async fn worker_loop(
     is_running: Arc<AtomicBool>,
     stats: Arc<BatchProcessorStatsInternal>,
     batch_semaphore: Arc<Semaphore>,
+    sizer: Arc<Mutex<AdaptiveBatchSizer>>,
     poll_interval: Duration,
 ) {
     let mut poll_timer = interval(poll_interval);
@@ -58,7 +62,7 @@ pub(crate) async fn worker_loop(
             // Check for shutdown
             _ = shutdown_notify.notified() => {
                 tracing::info!("Worker received shutdown signal, flushing queues...");
-                flush_all_queues(&queues, &registry, &stats, &batch_semaphore).await;
+                flush_all_queues(&queues, &registry, &stats, &batch_semaphore, &sizer).await;
                 tracing::info!("Worker shutdown complete");
                 break;
             }
@@ -67,11 +71,14 @@ pub(crate) async fn worker_loop(
             Some(request) = request_rx.recv() => {
                 let model_id = request.model_id;
 
-                // Add to appropriate queue
+                // Add to appropriate queue; a full queue answers the
+                // request with Backpressure instead of growing unboundedly
                 {
                     let mut queues_guard = queues.write().await;
                     if let Some(queue) = queues_guard.get_mut(&model_id) {
-                        queue.push(request);
+                        if queue.try_push(request).is_err() {
+                            stats.add_requests_failed(1);
+                        }
                     }
                 }
 
@@ -82,6 +89,7 @@ pub(crate) async fn worker_loop(
                     model_id,
                     &stats,
                     &batch_semaphore,
+                    &sizer,
                 ).await;
             }
 
@@ -100,6 +108,7 @@ pub(crate) async fn worker_loop(
                         *model_id,
                         &stats,
                         &batch_semaphore,
+                        &sizer,
                     ).await;
                 }
             }
@@ -113,6 +122,9 @@ pub(crate) async fn worker_loop(
 
 /// Process a queue if it's ready to flush.
 ///
+/// A queue is ready when `should_flush()` holds or it has reached the
+/// model's adaptive batch size limit.
+///
 /// All processing is done INLINE - no detached tasks are created.
 async fn process_queue_if_ready(
     queues: &Arc<RwLock<HashMap<ModelId, BatchQueue>>>,
@@ -120,13 +132,16 @@ async fn process_queue_if_ready(
     model_id: ModelId,
     stats: &Arc<BatchProcessorStatsInternal>,
     batch_semaphore: &Arc<Semaphore>,
+    sizer: &Arc<Mutex<AdaptiveBatchSizer>>,
 ) {
+    let limit = current_limit(sizer, model_id);
+
     // Check if should flush (read lock)
     let should_flush = {
         let queues_guard = queues.read().await;
         queues_guard
             .get(&model_id)
-            .map(|q| q.should_flush())
+            .map(|q| q.should_flush() || q.len() >= limit)
             .unwrap_or(false)
     };
 
@@ -145,12 +160,12 @@ async fn process_queue_if_ready(
         let mut queues_guard = queues.write().await;
         queues_guard
             .get_mut(&model_id)
-            .and_then(|q| q.drain_batch())
+            .and_then(|q| q.drain_batch_up_to(choose_size(sizer, model_id, q.len())))
     };
 
     if let Some(batch) = batch {
         // Process INLINE - no spawning
-        process_batch(batch, registry, stats).await;
+        process_batch(batch, registry, stats, sizer).await;
     }
 
     drop(permit);
@@ -162,11 +177,17 @@ async fn process_queue_if_ready(
 
 /// Process a single batch through the model.
 ///
+/// Inputs that fail validation are answered individually; the rest go
+/// through `embed_batch()`. A chunk that runs out of GPU memory is split in
+/// half and both halves retried, so only an input that cannot fit on its own
+/// fails with the OOM error.
+///
 /// Called inline from the worker loop - never spawned.
 async fn process_batch(
     batch: Batch,
     registry: &Arc<ModelRegistry>,
     stats: &Arc<BatchProcessorStatsInternal>,
+    sizer: &Arc<Mutex<AdaptiveBatchSizer>>,
 ) {
     let batch_size = batch.len();
     let model_id = batch.model_id;
@@ -187,29 +208,79 @@ async fn process_batch(
         }
     };
 
-    // Process each input in the batch
-    let mut results: Vec<EmbeddingResult<ModelEmbedding>> = Vec::with_capacity(batch_size);
-    let mut success_count: u64 = 0;
-    let mut fail_count: u64 = 0;
+    let mut results: Vec<Option<EmbeddingResult<ModelEmbedding>>> =
+        (0..batch_size).map(|_| None).collect();
 
-    for input in &batch.inputs {
-        match model.embed(input).await {
-            Ok(embedding) => {
-                results.push(Ok(embedding));
-                success_count += 1;
+    // Invalid inputs fail on their own instead of failing their chunk
+    let mut valid: Vec<usize> = Vec::with_capacity(batch_size);
+    for (i, input) in batch.inputs.iter().enumerate() {
+        match model.validate_input(input) {
+            Ok(()) => valid.push(i),
+            Err(e) => results[i] = Some(Err(e)),
+        }
+    }
+    let inputs: Vec<ModelInput> = valid.iter().map(|&i| batch.inputs[i].clone()).collect();
+
+    // Chunks still to run; halves are pushed in reverse to keep input order
+    let mut pending: Vec<Range<usize>> = vec![0..inputs.len()];
+    while let Some(chunk) = pending.pop() {
+        if chunk.is_empty() {
+            continue;
+        }
+        let len = chunk.len();
+
+        match embed_chunk(model.as_ref(), &inputs[chunk.clone()]).await {
+            Ok(embeddings) => {
+                let peak = model.last_batch_peak_memory();
+                with_sizer(sizer, |s| s.record_success(model_id, len, peak));
+                for (offset, embedding) in embeddings.into_iter().enumerate() {
+                    results[valid[chunk.start + offset]] = Some(Ok(embedding));
+                }
+            }
+            Err(e) if is_out_of_memory(&e) && len > 1 => {
+                with_sizer(sizer, |s| s.record_oom(model_id, len));
+                stats.inc_oom_retries();
+                let mid = chunk.start + len / 2;
+                pending.push(mid..chunk.end);
+                pending.push(chunk.start..mid);
             }
             Err(e) => {
+                if is_out_of_memory(&e) {
+                    with_sizer(sizer, |s| s.record_oom(model_id, len));
+                }
                 tracing::warn!(
                     model_id = ?model_id,
+                    chunk_size = len,
                     error = %e,
-                    "Embedding failed for input"
+                    "Embedding failed for batch chunk"
                 );
-                results.push(Err(e));
-                fail_count += 1;
+                if len == 1 {
+                    results[valid[chunk.start]] = Some(Err(e));
+                } else {
+                    let message = format!("Batch of {} inputs failed: {}", len, e);
+                    for i in chunk {
+                        results[valid[i]] = Some(Err(EmbeddingError::BatchError {
+                            message: message.clone(),
+                        }));
+                    }
+                }
             }
         }
     }
 
+    let results: Vec<EmbeddingResult<ModelEmbedding>> = results
+        .into_iter()
+        .map(|result| {
+            result.unwrap_or_else(|| {
+                Err(EmbeddingError::InternalError {
+                    message: "Batch input finished without a result".to_string(),
+                })
+            })
+        })
+        .collect();
+    let success_count = results.iter().filter(|r| r.is_ok()).count() as u64;
+    let fail_count = results.len() as u64 - success_count;
+
     // Complete batch with individual results
     batch.complete(results);
 
@@ -219,6 +290,52 @@ async fn process_batch(
     stats.inc_batches_processed();
 }
 
+/// Run one chunk through `embed_batch()`, requiring one output per input.
+async fn embed_chunk(
+    model: &dyn EmbeddingModel,
+    inputs: &[ModelInput],
+) -> EmbeddingResult<Vec<ModelEmbedding>> {
+    let embeddings = model.embed_batch(inputs).await?;
+    if embeddings.len() != inputs.len() {
+        return Err(EmbeddingError::BatchError {
+            message: format!(
+                "Model {:?} returned {} embeddings for {} inputs",
+                model.model_id(),
+                embeddings.len(),
+                inputs.len()
+            ),
+        });
+    }
+    Ok(embeddings)
+}
+
+// ============================================================================
+// ADAPTIVE SIZING
+// ============================================================================
+//
+// The sizer sits behind a std Mutex: every access is short and synchronous,
+// and the guard is never held across an await.
+
+fn with_sizer(sizer: &Mutex<AdaptiveBatchSizer>, f: impl FnOnce(&mut AdaptiveBatchSizer)) {
+    if let Ok(mut guard) = sizer.lock() {
+        f(&mut guard);
+    }
+}
+
+fn current_limit(sizer: &Mutex<AdaptiveBatchSizer>, model_id: ModelId) -> usize {
+    sizer
+        .lock()
+        .map(|s| s.limit(model_id))
+        .unwrap_or(usize::MAX)
+}
+
+fn choose_size(sizer: &Mutex<AdaptiveBatchSizer>, model_id: ModelId, pending: usize) -> usize {
+    sizer
+        .lock()
+        .map(|s| s.choose(model_id, pending))
+        .unwrap_or(pending)
+}
+
 // ============================================================================
 // FLUSH OPERATIONS
 // ============================================================================
@@ -229,6 +346,7 @@ async fn flush_all_queues(
     registry: &Arc<ModelRegistry>,
     stats: &Arc<BatchProcessorStatsInternal>,
     batch_semaphore: &Arc<Semaphore>,
+    sizer: &Arc<Mutex<AdaptiveBatchSizer>>,
 ) {
     for model_id in ModelId::all() {
         loop {
@@ -251,11 +369,13 @@ async fn flush_all_queues(
 
             let batch = {
                 let mut queues_guard = queues.write().await;
-                queues_guard.get_mut(model_id).and_then(|q| q.drain_batch())
+                queues_guard
+                    .get_mut(model_id)
+                    .and_then(|q| q.drain_batch_up_to(choose_size(sizer, *model_id, q.len())))
             };
 
             if let Some(batch) = batch {
-                process_batch(batch, registry, stats).await;
+                process_batch(batch, registry, stats, sizer).await;
             }
 
             drop(permit);
@@ -271,9 +391,130 @@ async fn flush_all_queues(
 mod tests {
     use super::*;
 
+    use std::sync::atomic::AtomicUsize;
+
+    use crate::batch::{BatchProcessor, BatchProcessorConfig};
+    use crate::config::BatchConfig;
+    use crate::models::ModelRegistryConfig;
+    use crate::traits::{ModelFactory, SingleModelConfig};
+    use crate::types::InputType;
+
+    /// Largest batch the fake model can run before "running out of memory".
+    const FAKE_GPU_CAPACITY: usize = 8;
+
+    /// Fake model whose `embed_batch` fails with a CUDA OOM above 8 inputs.
+    struct OomModel {
+        model_id: ModelId,
+        max_successful_batch: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl EmbeddingModel for OomModel {
+        fn model_id(&self) -> ModelId {
+            self.model_id
+        }
+
+        fn supported_input_types(&self) -> &[InputType] {
+            &[InputType::Text]
+        }
+
+        async fn embed(&self, input: &ModelInput) -> EmbeddingResult<ModelEmbedding> {
+            self.validate_input(input)?;
+            let vector = vec![0.5; self.dimension()];
+            Ok(ModelEmbedding::new(self.model_id, vector, 100))
+        }
+
+        async fn embed_batch(&self, inputs: &[ModelInput]) -> EmbeddingResult<Vec<ModelEmbedding>> {
+            if inputs.len() > FAKE_GPU_CAPACITY {
+                return Err(EmbeddingError::GpuError {
+                    message: "CUDA out of memory".to_string(),
+                });
+            }
+            self.max_successful_batch.fetch_max(inputs.len(), Ordering::SeqCst);
+            let mut embeddings = Vec::with_capacity(inputs.len());
+            for input in inputs {
+                embeddings.push(self.embed(input).await?);
+            }
+            Ok(embeddings)
+        }
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+    }
+
+    struct OomFactory {
+        max_successful_batch: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl ModelFactory for OomFactory {
+        fn create_model(
+            &self,
+            model_id: ModelId,
+            _config: &SingleModelConfig,
+        ) -> EmbeddingResult<Box<dyn EmbeddingModel>> {
+            Ok(Box::new(OomModel {
+                model_id,
+                max_successful_batch: Arc::clone(&self.max_successful_batch),
+            }))
+        }
+
+        fn supported_models(&self) -> &[ModelId] {
+            ModelId::all()
+        }
+
+        fn estimate_memory(&self, model_id: ModelId) -> usize {
+            crate::traits::get_memory_estimate(model_id)
+        }
+    }
+
     #[test]
     fn test_queues_created_for_all_14_models() {
         let all_models = ModelId::all();
         assert_eq!(all_models.len(), 14, "Expected 14 models");
     }
+
+    #[tokio::test]
+    async fn test_oom_backoff_converges_and_completes_all_requests() {
+        let max_successful_batch = Arc::new(AtomicUsize::new(0));
+        let factory = Arc::new(OomFactory {
+            max_successful_batch: Arc::clone(&max_successful_batch),
+        });
+        let registry = ModelRegistry::new(ModelRegistryConfig::default(), factory)
+            .await
+            .expect("registry");
+
+        let config = BatchProcessorConfig {
+            batch_config: BatchConfig {
+                max_batch_size: 32,
+                min_batch_size: 1,
+                max_wait_ms: 50,
+                dynamic_batching: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut processor = BatchProcessor::new(Arc::new(registry), config)
+            .await
+            .expect("processor");
+
+        let inputs: Vec<ModelInput> = (0..100)
+            .map(|i| ModelInput::text(format!("request {}", i)).unwrap())
+            .collect();
+        let embeddings = processor
+            .submit_batch(ModelId::Semantic, inputs)
+            .await
+            .expect("every request should complete");
+        assert_eq!(embeddings.len(), 100);
+
+        let stats = processor.stats().await;
+        let limit = stats.batch_limits[&ModelId::Semantic];
+        assert!(limit <= FAKE_GPU_CAPACITY, "Limit should converge to <= 8, got {}", limit);
+        assert!(stats.oom_retries > 0, "Oversized batches should have been split");
+        assert_eq!(stats.requests_completed, 100);
+        assert!(max_successful_batch.load(Ordering::SeqCst) <= FAKE_GPU_CAPACITY);
+
+        processor.shutdown().await;
+    }
 }
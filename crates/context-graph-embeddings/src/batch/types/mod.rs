@@ -36,5 +36,5 @@ mod tests;
 // Re-export all public types for backwards compatibility
 pub use batch::Batch;
 pub use queue::BatchQueue;
-pub use request::BatchRequest;
-pub use stats::{BatchQueueStats, BatchQueueSummary};
+pub use request::{BatchRequest, Priority};
+pub use stats::{BatchQueueStats, BatchQueueSummary, PriorityLaneSummary};
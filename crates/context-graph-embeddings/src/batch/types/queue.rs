@@ -3,16 +3,25 @@
 //! This module provides the `BatchQueue` type which manages pending
 //! requests for a single model, implementing batching logic based on
 //! size and timeout thresholds.
+//!
+//! Requests wait in one FIFO lane per [`Priority`]. Batches are assembled
+//! from the highest effective priority first, where a request's effective
+//! priority rises one lane per `priority_aging_ms` it has waited.
+//!
+//! Each queue is bounded by `max_queue_depth`; [`BatchQueue::try_push`]
+//! rejects requests beyond it with `EmbeddingError::Backpressure`.
 
+use std::cmp::Reverse;
 use std::collections::VecDeque;
+use std::time::{Duration, Instant};
 
 use crate::config::BatchConfig;
-use crate::error::EmbeddingError;
+use crate::error::{EmbeddingError, EmbeddingResult};
 use crate::types::ModelId;
 
 use super::batch::Batch;
-use super::request::BatchRequest;
-use super::stats::{BatchQueueStats, BatchQueueSummary};
+use super::request::{BatchRequest, Priority};
+use super::stats::{BatchQueueStats, BatchQueueSummary, PriorityLaneSummary};
 
 /// Queue of pending requests for a single model.
 ///
@@ -51,8 +60,9 @@ use super::stats::{BatchQueueStats, BatchQueueSummary};
 /// ```
 #[derive(Debug)]
 pub struct BatchQueue {
-    /// Pending requests ordered by submission time.
-    requests: VecDeque<BatchRequest>,
+    /// Pending requests, one lane per priority (indexed by `Priority::lane`),
+    /// each ordered by submission time.
+    lanes: [VecDeque<BatchRequest>; 3],
 
     /// Configuration for batching behavior.
     config: BatchConfig,
@@ -60,6 +70,9 @@ pub struct BatchQueue {
     /// Model this queue serves.
     model_id: ModelId,
 
+    /// Maximum number of pending requests accepted by `try_push`.
+    capacity: usize,
+
     /// Statistics.
     stats: BatchQueueStats,
 }
@@ -72,21 +85,90 @@ impl BatchQueue {
     /// * `config` - Batching configuration
     #[must_use]
     pub fn new(model_id: ModelId, config: BatchConfig) -> Self {
+        let capacity = config.max_queue_depth.max(1);
         Self {
-            requests: VecDeque::new(),
+            lanes: Default::default(),
             config,
             model_id,
+            capacity,
             stats: BatchQueueStats::default(),
         }
     }
 
+    /// Override the queue capacity from `config.max_queue_depth`.
+    ///
+    /// `capacity` is clamped to at least 1.
+    #[must_use]
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
     /// Add request to queue.
     ///
     /// The request will be batched with others and processed
-    /// when `should_flush()` returns true.
+    /// when `should_flush()` returns true. Does not check capacity;
+    /// use [`Self::try_push`] on paths that must apply backpressure.
     pub fn push(&mut self, request: BatchRequest) {
         self.stats.record_request();
-        self.requests.push_back(request);
+        self.lanes[request.priority.lane()].push_back(request);
+    }
+
+    /// Add request to queue unless it is at capacity.
+    ///
+    /// A rejected request is completed with `EmbeddingError::Backpressure`
+    /// on its response channel, and the same error is returned.
+    ///
+    /// # Errors
+    /// `EmbeddingError::Backpressure` if `len() >= capacity()`.
+    pub fn try_push(&mut self, request: BatchRequest) -> EmbeddingResult<()> {
+        if !self.is_full() {
+            self.push(request);
+            return Ok(());
+        }
+
+        let queue_depth = self.len();
+        let retry_after_ms = self.retry_after_hint_ms();
+        tracing::debug!(
+            request_id = %request.id,
+            model_id = ?self.model_id,
+            queue_depth,
+            retry_after_ms,
+            "Rejecting request: model queue at capacity"
+        );
+        self.stats.record_rejected();
+        let error = || EmbeddingError::Backpressure {
+            model_id: self.model_id,
+            queue_depth,
+            retry_after_ms,
+        };
+        // Ignore send errors (receiver may have dropped)
+        let _ = request.response_tx.send(Err(error()));
+        Err(error())
+    }
+
+    /// Maximum number of pending requests accepted by `try_push`.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Check if the queue has reached its capacity.
+    #[inline]
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.len() >= self.capacity
+    }
+
+    /// Estimated time until the queue has room again (milliseconds).
+    ///
+    /// One `max_wait_ms` flush interval per full batch ahead of a new request.
+    #[must_use]
+    pub fn retry_after_hint_ms(&self) -> u64 {
+        let batch_size = self.config.max_batch_size.max(1);
+        let batches_ahead = (self.len() + 1).div_ceil(batch_size) as u64;
+        self.config.max_wait_ms.max(1) * batches_ahead
     }
 
     /// Check if queue should be flushed (batch ready).
@@ -98,18 +180,18 @@ impl BatchQueue {
     /// Returns false if queue is empty.
     #[must_use]
     pub fn should_flush(&self) -> bool {
-        if self.requests.is_empty() {
+        if self.is_empty() {
             return false;
         }
 
         // Flush if reached max batch size
-        if self.requests.len() >= self.config.max_batch_size {
+        if self.len() >= self.config.max_batch_size {
             return true;
         }
 
         // Flush if oldest request waited too long
-        if let Some(oldest) = self.requests.front() {
-            if oldest.elapsed().as_millis() as u64 >= self.config.max_wait_ms {
+        if let Some(oldest) = self.oldest_wait_time() {
+            if oldest.as_millis() as u64 >= self.config.max_wait_ms {
                 return true;
             }
         }
@@ -119,22 +201,45 @@ impl BatchQueue {
 
     /// Extract a batch of requests for processing.
     ///
-    /// Drains up to `max_batch_size` requests from the queue.
+    /// Drains up to `max_batch_size` requests from the queue, highest
+    /// effective priority first (oldest first within a priority). Requests
+    /// whose deadline has passed are completed with
+    /// `EmbeddingError::DeadlineExceeded` and left out of the batch.
     /// If `sort_by_length` is enabled, sorts by estimated token count
     /// for padding efficiency.
     ///
     /// # Returns
-    /// `Some(Batch)` if there are requests to process, `None` if queue is empty.
+    /// `Some(Batch)` if there are requests to process, `None` if queue is empty
+    /// or every pending request had expired.
     pub fn drain_batch(&mut self) -> Option<Batch> {
-        if self.requests.is_empty() {
+        self.drain_batch_up_to(self.config.max_batch_size)
+    }
+
+    /// Extract a batch of at most `max_size` requests.
+    ///
+    /// Like [`Self::drain_batch`] but with a caller-chosen size limit (used by
+    /// adaptive batch sizing). `max_size` is clamped to at least 1.
+    pub fn drain_batch_up_to(&mut self, max_size: usize) -> Option<Batch> {
+        let now = Instant::now();
+        self.expire_overdue(now);
+        if self.is_empty() {
             return None;
         }
 
-        let batch_size = self.requests.len().min(self.config.max_batch_size);
+        let batch_size = self.len().min(max_size.max(1));
         let mut batch = Batch::new(self.model_id);
 
-        // Drain requests
-        let mut requests: Vec<BatchRequest> = self.requests.drain(..batch_size).collect();
+        // Take from whichever lane head ranks highest, one at a time
+        let mut requests: Vec<BatchRequest> = Vec::with_capacity(batch_size);
+        while requests.len() < batch_size {
+            let Some(lane) = self.next_lane(now) else {
+                break;
+            };
+            match self.lanes[lane].pop_front() {
+                Some(request) => requests.push(request),
+                None => break,
+            }
+        }
 
         // Calculate average wait time before moving requests
         let avg_wait_us = if !requests.is_empty() {
@@ -163,27 +268,86 @@ impl BatchQueue {
         Some(batch)
     }
 
+    /// Priority a request is scheduled at after aging.
+    ///
+    /// Escalates one lane per full `priority_aging_ms` waited.
+    #[must_use]
+    pub fn effective_priority(&self, request: &BatchRequest, now: Instant) -> Priority {
+        let aging_ms = self.config.priority_aging_ms.max(1) as u128;
+        let waited_ms = now.saturating_duration_since(request.submitted_at).as_millis();
+        let steps = (waited_ms / aging_ms).min(Priority::all().len() as u128);
+        (0..steps).fold(request.priority, |priority, _| priority.escalated())
+    }
+
+    /// Lane whose head request should be batched next.
+    fn next_lane(&self, now: Instant) -> Option<usize> {
+        self.lanes
+            .iter()
+            .enumerate()
+            .filter_map(|(lane, requests)| {
+                requests.front().map(|head| {
+                    let rank = (self.effective_priority(head, now), Reverse(head.submitted_at));
+                    (lane, rank)
+                })
+            })
+            .max_by_key(|(_, rank)| *rank)
+            .map(|(lane, _)| lane)
+    }
+
+    /// Complete every request whose deadline has passed with
+    /// `EmbeddingError::DeadlineExceeded` and remove it from the queue.
+    fn expire_overdue(&mut self, now: Instant) {
+        for lane in &mut self.lanes {
+            if lane.iter().all(|r| r.overdue(now).is_none()) {
+                continue;
+            }
+            let pending = std::mem::take(lane);
+            for request in pending {
+                match request.overdue(now) {
+                    Some(overdue) => {
+                        let overdue_ms = overdue.as_millis() as u64;
+                        tracing::debug!(
+                            request_id = %request.id,
+                            model_id = ?request.model_id,
+                            overdue_ms,
+                            "Dropping queued request past its deadline"
+                        );
+                        // Ignore send errors (receiver may have dropped)
+                        let _ = request
+                            .response_tx
+                            .send(Err(EmbeddingError::DeadlineExceeded { overdue_ms }));
+                        self.stats.record_expired();
+                    }
+                    None => lane.push_back(request),
+                }
+            }
+        }
+    }
+
     /// Number of pending requests.
     #[inline]
     #[must_use]
     pub fn len(&self) -> usize {
-        self.requests.len()
+        self.lanes.iter().map(VecDeque::len).sum()
     }
 
     /// Check if queue is empty.
     #[inline]
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.requests.is_empty()
+        self.lanes.iter().all(VecDeque::is_empty)
     }
 
-    /// Oldest request wait time.
+    /// Oldest request wait time across all priority lanes.
     ///
     /// # Returns
     /// `Some(Duration)` if queue is not empty, `None` if empty.
     #[must_use]
-    pub fn oldest_wait_time(&self) -> Option<std::time::Duration> {
-        self.requests.front().map(|r| r.elapsed())
+    pub fn oldest_wait_time(&self) -> Option<Duration> {
+        self.lanes
+            .iter()
+            .filter_map(|lane| lane.front().map(|r| r.elapsed()))
+            .max()
     }
 
     /// Clear all pending requests with error.
@@ -195,7 +359,7 @@ impl BatchQueue {
     /// * `message` - The error message to send to all pending requests
     pub fn cancel_all(&mut self, message: impl Into<String>) {
         let msg = message.into();
-        for request in self.requests.drain(..) {
+        for request in self.lanes.iter_mut().flat_map(|lane| lane.drain(..)) {
             // Ignore send errors (receiver may have dropped)
             let _ = request.response_tx.send(Err(EmbeddingError::BatchError {
                 message: msg.clone(),
@@ -225,9 +389,23 @@ impl BatchQueue {
         &self.stats
     }
 
-    /// Get a summary of queue statistics.
+    /// Get a summary of queue statistics, including per-priority depths
+    /// and capacity.
     #[must_use]
     pub fn stats_summary(&self) -> BatchQueueSummary {
-        self.stats.summary()
+        let mut summary = self.stats.summary();
+        summary.capacity = self.capacity;
+        summary.lanes = Priority::all()
+            .iter()
+            .map(|&priority| {
+                let lane = &self.lanes[priority.lane()];
+                PriorityLaneSummary {
+                    priority,
+                    depth: lane.len(),
+                    oldest_age: lane.front().map(|r| r.elapsed()),
+                }
+            })
+            .collect();
+        summary
     }
 }
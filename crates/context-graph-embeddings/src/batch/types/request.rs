@@ -3,7 +3,7 @@
 //! This module provides the `BatchRequest` type for submitting
 //! individual embedding requests to the batch system.
 
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use tokio::sync::oneshot;
 use uuid::Uuid;
@@ -11,6 +11,57 @@ use uuid::Uuid;
 use crate::error::EmbeddingResult;
 use crate::types::{ModelEmbedding, ModelId, ModelInput};
 
+/// Scheduling lane for a batch request.
+///
+/// `BatchQueue` assembles batches from higher lanes first. Requests escalate
+/// one lane per `BatchConfig::priority_aging_ms` of waiting, so Bulk work
+/// still makes progress under sustained Interactive load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Priority {
+    /// Background work such as bulk re-embedding.
+    Bulk,
+    /// Default lane.
+    #[default]
+    Normal,
+    /// Latency-sensitive requests (e.g. MCP tool calls).
+    Interactive,
+}
+
+impl Priority {
+    /// Returns all priorities, highest first.
+    pub fn all() -> &'static [Priority] {
+        &[Priority::Interactive, Priority::Normal, Priority::Bulk]
+    }
+
+    /// Returns the priority name as snake_case string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Priority::Interactive => "interactive",
+            Priority::Normal => "normal",
+            Priority::Bulk => "bulk",
+        }
+    }
+
+    /// The next lane up (Interactive stays Interactive).
+    #[must_use]
+    pub fn escalated(self) -> Self {
+        match self {
+            Priority::Bulk => Priority::Normal,
+            Priority::Normal | Priority::Interactive => Priority::Interactive,
+        }
+    }
+
+    /// Lane index in `all()` order.
+    #[inline]
+    pub(crate) fn lane(self) -> usize {
+        match self {
+            Priority::Interactive => 0,
+            Priority::Normal => 1,
+            Priority::Bulk => 2,
+        }
+    }
+}
+
 /// Individual embedding request submitted to the batch system.
 ///
 /// Each request carries its input, target model, and a response channel
@@ -19,7 +70,7 @@ use crate::types::{ModelEmbedding, ModelId, ModelInput};
 /// # Example
 ///
 /// ```
-/// # use context_graph_embeddings::batch::BatchRequest;
+/// # use context_graph_embeddings::batch::{BatchRequest, Priority};
 /// # use context_graph_embeddings::types::{ModelId, ModelInput};
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// let input = ModelInput::text("Hello, world!")?;
@@ -29,7 +80,7 @@ use crate::types::{ModelEmbedding, ModelId, ModelInput};
 /// assert!(!request.id.is_nil());
 /// // Request tracks model and priority
 /// assert_eq!(request.model_id, ModelId::Semantic);
-/// assert_eq!(request.priority, 0);
+/// assert_eq!(request.priority, Priority::Normal);
 /// # Ok(())
 /// # }
 /// ```
@@ -52,9 +103,13 @@ pub struct BatchRequest {
     /// Used for timeout calculations and metrics.
     pub submitted_at: Instant,
 
-    /// Priority level (higher = more urgent).
-    /// Default is 0. Higher values are processed first.
-    pub priority: u8,
+    /// Scheduling lane. Default is `Priority::Normal`.
+    pub priority: Priority,
+
+    /// Latest time the request is still worth processing.
+    /// Expired requests fail with `EmbeddingError::DeadlineExceeded`
+    /// instead of being sent to the model.
+    pub deadline: Option<Instant>,
 }
 
 impl BatchRequest {
@@ -88,29 +143,20 @@ impl BatchRequest {
         input: ModelInput,
         model_id: ModelId,
     ) -> (Self, oneshot::Receiver<EmbeddingResult<ModelEmbedding>>) {
-        let (tx, rx) = oneshot::channel();
-        let request = Self {
-            id: Uuid::new_v4(),
-            input,
-            model_id,
-            response_tx: tx,
-            submitted_at: Instant::now(),
-            priority: 0,
-        };
-        (request, rx)
+        Self::new_with_priority(input, model_id, Priority::default())
     }
 
-    /// Create a new batch request with specified priority.
+    /// Create a new batch request in the given priority lane.
     ///
     /// # Arguments
     /// * `input` - The input to embed
     /// * `model_id` - The model to use for embedding
-    /// * `priority` - Priority level (higher = more urgent, 0-255)
+    /// * `priority` - Scheduling lane
     #[must_use]
-    pub fn with_priority(
+    pub fn new_with_priority(
         input: ModelInput,
         model_id: ModelId,
-        priority: u8,
+        priority: Priority,
     ) -> (Self, oneshot::Receiver<EmbeddingResult<ModelEmbedding>>) {
         let (tx, rx) = oneshot::channel();
         let request = Self {
@@ -120,10 +166,30 @@ impl BatchRequest {
             response_tx: tx,
             submitted_at: Instant::now(),
             priority,
+            deadline: None,
         };
         (request, rx)
     }
 
+    /// Set a deadline after which the request is dropped from its queue.
+    #[must_use]
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// How far past its deadline the request is at `now`.
+    ///
+    /// # Returns
+    /// `Some(overdue)` if the deadline has passed, `None` otherwise or when
+    /// no deadline is set.
+    #[must_use]
+    pub fn overdue(&self, now: Instant) -> Option<Duration> {
+        self.deadline
+            .filter(|deadline| *deadline <= now)
+            .map(|deadline| now.duration_since(deadline))
+    }
+
     /// Time elapsed since submission.
     ///
     /// Used for timeout checking and metrics.
@@ -4,6 +4,9 @@
 //! using atomics for thread-safe concurrent updates.
 
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use super::request::Priority;
 
 /// Queue statistics for monitoring and debugging.
 ///
@@ -35,6 +38,14 @@ pub struct BatchQueueStats {
     /// Total requests that failed.
     pub requests_failed: AtomicU64,
 
+    /// Requests dropped because their deadline passed while queued.
+    /// Also counted in `requests_failed`.
+    pub requests_expired: AtomicU64,
+
+    /// Requests rejected because the queue was at capacity.
+    /// Never queued, so not counted in `requests_received`.
+    pub requests_rejected: AtomicU64,
+
     /// Cumulative wait time in microseconds.
     pub total_wait_time_us: AtomicU64,
 
@@ -50,6 +61,8 @@ impl Clone for BatchQueueStats {
             batches_processed: AtomicU64::new(self.batches_processed.load(Ordering::Relaxed)),
             requests_completed: AtomicU64::new(self.requests_completed.load(Ordering::Relaxed)),
             requests_failed: AtomicU64::new(self.requests_failed.load(Ordering::Relaxed)),
+            requests_expired: AtomicU64::new(self.requests_expired.load(Ordering::Relaxed)),
+            requests_rejected: AtomicU64::new(self.requests_rejected.load(Ordering::Relaxed)),
             total_wait_time_us: AtomicU64::new(self.total_wait_time_us.load(Ordering::Relaxed)),
             batch_size_sum: AtomicU64::new(self.batch_size_sum.load(Ordering::Relaxed)),
         }
@@ -90,7 +103,23 @@ impl BatchQueueStats {
         }
     }
 
+    /// Record a request that expired before processing.
+    #[inline]
+    pub fn record_expired(&self) {
+        self.requests_expired.fetch_add(1, Ordering::Relaxed);
+        self.requests_failed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a request rejected because the queue was full.
+    #[inline]
+    pub fn record_rejected(&self) {
+        self.requests_rejected.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Get a summary snapshot of current statistics.
+    ///
+    /// `lanes` is left empty and `capacity` zero; `BatchQueue::stats_summary()`
+    /// fills them from the live queue.
     #[must_use]
     pub fn summary(&self) -> BatchQueueSummary {
         let batches = self.batches_processed.load(Ordering::Relaxed);
@@ -102,12 +131,16 @@ impl BatchQueueStats {
             batches_processed: batches,
             requests_completed: self.requests_completed.load(Ordering::Relaxed),
             requests_failed: self.requests_failed.load(Ordering::Relaxed),
+            requests_expired: self.requests_expired.load(Ordering::Relaxed),
+            requests_rejected: self.requests_rejected.load(Ordering::Relaxed),
             avg_batch_size: if batches > 0 {
                 (size_sum as f64) / (batches as f64)
             } else {
                 0.0
             },
             avg_wait_time_us: if batches > 0 { wait_sum / batches } else { 0 },
+            capacity: 0,
+            lanes: Vec::new(),
         }
     }
 
@@ -117,6 +150,8 @@ impl BatchQueueStats {
         self.batches_processed.store(0, Ordering::Relaxed);
         self.requests_completed.store(0, Ordering::Relaxed);
         self.requests_failed.store(0, Ordering::Relaxed);
+        self.requests_expired.store(0, Ordering::Relaxed);
+        self.requests_rejected.store(0, Ordering::Relaxed);
         self.total_wait_time_us.store(0, Ordering::Relaxed);
         self.batch_size_sum.store(0, Ordering::Relaxed);
     }
@@ -139,9 +174,34 @@ pub struct BatchQueueSummary {
     /// Total requests that failed.
     pub requests_failed: u64,
 
+    /// Requests dropped because their deadline passed while queued.
+    pub requests_expired: u64,
+
+    /// Requests rejected because the queue was at capacity.
+    pub requests_rejected: u64,
+
     /// Average batch size (floating point for precision).
     pub avg_batch_size: f64,
 
     /// Average wait time in microseconds.
     pub avg_wait_time_us: u64,
+
+    /// Maximum number of pending requests the queue accepts.
+    pub capacity: usize,
+
+    /// Current depth of each priority lane, highest priority first.
+    pub lanes: Vec<PriorityLaneSummary>,
+}
+
+/// Snapshot of one priority lane in a `BatchQueue`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriorityLaneSummary {
+    /// Lane priority (as submitted, before aging).
+    pub priority: Priority,
+
+    /// Number of pending requests in the lane.
+    pub depth: usize,
+
+    /// Wait time of the lane's oldest request, `None` if empty.
+    pub oldest_age: Option<Duration>,
 }
@@ -7,7 +7,7 @@
 
 use super::*;
 use crate::config::BatchConfig;
-use crate::error::EmbeddingResult;
+use crate::error::{EmbeddingError, EmbeddingResult};
 use crate::types::{ImageFormat, ModelEmbedding, ModelId, ModelInput};
 
 // ============================================================
@@ -20,16 +20,27 @@ fn test_batch_request_new_creates_valid_request() {
     let (request, _rx) = BatchRequest::new(input.clone(), ModelId::Semantic);
 
     assert_eq!(request.model_id, ModelId::Semantic);
-    assert_eq!(request.priority, 0);
+    assert_eq!(request.priority, Priority::Normal);
+    assert!(request.deadline.is_none());
     assert!(!request.id.is_nil());
 }
 
 #[test]
-fn test_batch_request_with_priority() {
+fn test_batch_request_new_with_priority() {
     let input = ModelInput::text("Urgent request").unwrap();
-    let (request, _rx) = BatchRequest::with_priority(input, ModelId::Semantic, 100);
+    let (request, _rx) =
+        BatchRequest::new_with_priority(input, ModelId::Semantic, Priority::Interactive);
 
-    assert_eq!(request.priority, 100);
+    assert_eq!(request.priority, Priority::Interactive);
+}
+
+#[test]
+fn test_priority_ordering_and_escalation() {
+    assert!(Priority::Interactive > Priority::Normal);
+    assert!(Priority::Normal > Priority::Bulk);
+    assert_eq!(Priority::Bulk.escalated(), Priority::Normal);
+    assert_eq!(Priority::Normal.escalated(), Priority::Interactive);
+    assert_eq!(Priority::Interactive.escalated(), Priority::Interactive);
 }
 
 #[test]
@@ -288,6 +299,21 @@ fn test_batch_queue_drain_batch_respects_max_size() {
     assert!(queue.drain_batch().is_none());
 }
 
+#[test]
+fn test_batch_queue_drain_batch_up_to_limits_size() {
+    let mut queue = BatchQueue::new(ModelId::Hdc, BatchConfig::default());
+
+    for i in 0..10 {
+        let input = ModelInput::text(format!("Test {}", i)).unwrap();
+        let (request, _rx) = BatchRequest::new(input, ModelId::Hdc);
+        queue.push(request);
+    }
+
+    assert_eq!(queue.drain_batch_up_to(4).unwrap().len(), 4);
+    assert_eq!(queue.drain_batch_up_to(0).unwrap().len(), 1, "Zero clamps to one");
+    assert_eq!(queue.len(), 5);
+}
+
 #[test]
 fn test_batch_queue_drain_batch_sorts_by_length() {
     let mut config = BatchConfig::default();
@@ -339,6 +365,138 @@ fn test_batch_queue_oldest_wait_time() {
     assert!(wait.unwrap() >= std::time::Duration::from_millis(10));
 }
 
+#[test]
+fn test_batch_queue_interactive_jumps_bulk_backlog() {
+    let mut queue = BatchQueue::new(ModelId::Semantic, BatchConfig::default());
+
+    for i in 0..100 {
+        let input = ModelInput::text(format!("Bulk {}", i)).unwrap();
+        let (request, _rx) =
+            BatchRequest::new_with_priority(input, ModelId::Semantic, Priority::Bulk);
+        queue.push(request);
+    }
+    let input = ModelInput::text("Interactive").unwrap();
+    let (request, _rx) =
+        BatchRequest::new_with_priority(input, ModelId::Semantic, Priority::Interactive);
+    let interactive_id = request.id;
+    queue.push(request);
+
+    let batch = queue.drain_batch().unwrap();
+    assert!(
+        batch.request_ids.contains(&interactive_id),
+        "Interactive request must be in the first batch"
+    );
+    assert_eq!(queue.len(), 101 - batch.len());
+    batch.fail("test cleanup");
+}
+
+#[test]
+fn test_batch_queue_aged_bulk_escalates() {
+    let mut config = BatchConfig::default();
+    config.priority_aging_ms = 20;
+    config.max_batch_size = 1;
+    let mut queue = BatchQueue::new(ModelId::Semantic, config);
+
+    let input = ModelInput::text("Old bulk").unwrap();
+    let (bulk, _bulk_rx) = BatchRequest::new_with_priority(input, ModelId::Semantic, Priority::Bulk);
+    let bulk_id = bulk.id;
+    queue.push(bulk);
+
+    std::thread::sleep(std::time::Duration::from_millis(50));
+
+    // Two aging intervals lift Bulk to Interactive; being older, it wins the tie
+    let input = ModelInput::text("Fresh interactive").unwrap();
+    let (fresh, _fresh_rx) =
+        BatchRequest::new_with_priority(input, ModelId::Semantic, Priority::Interactive);
+    queue.push(fresh);
+
+    let batch = queue.drain_batch().unwrap();
+    assert_eq!(batch.request_ids, vec![bulk_id]);
+    batch.fail("test cleanup");
+}
+
+#[tokio::test]
+async fn test_batch_queue_expired_request_gets_deadline_error() {
+    let mut queue = BatchQueue::new(ModelId::Semantic, BatchConfig::default());
+
+    let input = ModelInput::text("Too late").unwrap();
+    let (expired, expired_rx) = BatchRequest::new(input, ModelId::Semantic);
+    queue.push(expired.with_deadline(std::time::Instant::now()));
+
+    let input = ModelInput::text("In time").unwrap();
+    let (live, _live_rx) = BatchRequest::new(input, ModelId::Semantic);
+    let live_id = live.id;
+    queue.push(live.with_deadline(std::time::Instant::now() + std::time::Duration::from_secs(60)));
+
+    std::thread::sleep(std::time::Duration::from_millis(5));
+
+    let batch = queue.drain_batch().unwrap();
+    assert_eq!(batch.request_ids, vec![live_id], "Expired request must not be batched");
+
+    let result = expired_rx.await.unwrap();
+    assert!(matches!(result, Err(EmbeddingError::DeadlineExceeded { .. })));
+    assert_eq!(queue.stats_summary().requests_expired, 1);
+    batch.fail("test cleanup");
+}
+
+#[tokio::test]
+async fn test_batch_queue_try_push_rejects_at_capacity() {
+    let mut queue = BatchQueue::new(ModelId::Semantic, BatchConfig::default()).with_capacity(10);
+    assert_eq!(queue.capacity(), 10);
+
+    let mut accepted = Vec::new();
+    for i in 0..10 {
+        let input = ModelInput::text(format!("Queued {i}")).unwrap();
+        let (request, rx) = BatchRequest::new(input, ModelId::Semantic);
+        queue.try_push(request).expect("below capacity");
+        accepted.push(rx);
+    }
+    assert!(queue.is_full());
+
+    let input = ModelInput::text("Overflow").unwrap();
+    let (overflow, overflow_rx) = BatchRequest::new(input, ModelId::Semantic);
+    let err = queue.try_push(overflow).unwrap_err();
+    assert!(matches!(
+        err,
+        EmbeddingError::Backpressure { queue_depth: 10, retry_after_ms, .. } if retry_after_ms > 0
+    ));
+    assert!(matches!(
+        overflow_rx.await.unwrap(),
+        Err(EmbeddingError::Backpressure { .. })
+    ));
+
+    let summary = queue.stats_summary();
+    assert_eq!(summary.requests_rejected, 1);
+    assert_eq!(summary.requests_received, 10);
+    assert_eq!(summary.capacity, 10);
+
+    let batch = queue.drain_batch().unwrap();
+    assert_eq!(batch.len(), 10, "Queued requests must still be processed");
+    assert!(!queue.is_full());
+    batch.fail("test cleanup");
+    drop(accepted);
+}
+
+#[test]
+fn test_batch_queue_summary_reports_lanes() {
+    let mut queue = BatchQueue::new(ModelId::Semantic, BatchConfig::default());
+
+    for priority in [Priority::Bulk, Priority::Bulk, Priority::Interactive] {
+        let input = ModelInput::text("Test").unwrap();
+        let (request, _rx) = BatchRequest::new_with_priority(input, ModelId::Semantic, priority);
+        queue.push(request);
+    }
+
+    let summary = queue.stats_summary();
+    let depths: Vec<(Priority, usize)> = summary.lanes.iter().map(|l| (l.priority, l.depth)).collect();
+    assert_eq!(
+        depths,
+        vec![(Priority::Interactive, 1), (Priority::Normal, 0), (Priority::Bulk, 2)]
+    );
+    assert!(summary.lanes[1].oldest_age.is_none());
+    assert!(summary.lanes[2].oldest_age.is_some());
+}
+
 #[tokio::test]
 async fn test_batch_queue_cancel_all() {
     let config = BatchConfig::default();
@@ -0,0 +1,395 @@
+//! Disk tier for the embedding cache.
+//!
+//! Entries survive process restarts so embeddings already paid for in GPU time
+//! are not recomputed.
+//!
+//! # Layout
+//!
+//! `{dir}/{first key byte as 2 hex}/{key as 16 hex}.emb` - 256 shard
+//! directories keep per-directory file counts small.
+//!
+//! # Entry Format (little-endian)
+//!
+//! | Field | Type |
+//! |-------|------|
+//! | magic | `b"CGEC"` |
+//! | version | u8 |
+//! | content_hash, total_latency_us | u64, u64 |
+//! | slot count | u8 |
+//! | per slot: model_id, flags, latency_us | u8, u8, u64 |
+//! | per slot: vector length + f32 values | u32 + f32s |
+//! | per slot: attention length + f32 values (if flagged) | u32 + f32s |
+//! | xxHash64 of everything above | u64 |
+//!
+//! # Concurrency
+//!
+//! Several processes may share one directory. Writes go to a unique temp file
+//! and are renamed into place, so readers never see a partial entry. Files
+//! that disappear between listing and reading (another process evicted them)
+//! are treated as misses.
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{Receiver, Sender};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::SystemTime;
+
+use crate::error::{EmbeddingError, EmbeddingResult};
+use crate::types::{ModelEmbedding, ModelId, MultiArrayEmbedding};
+
+use super::{CacheKey, CacheStats};
+
+const ENTRY_MAGIC: &[u8; 4] = b"CGEC";
+const ENTRY_VERSION: u8 = 1;
+const ENTRY_EXTENSION: &str = "emb";
+
+const FLAG_PROJECTED: u8 = 0b01;
+const FLAG_ATTENTION: u8 = 0b10;
+
+/// Unique suffix source for temp files within this process.
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Directory of encoded cache entries with a byte budget.
+#[derive(Debug)]
+pub(crate) struct DiskTier {
+    dir: PathBuf,
+    max_bytes: u64,
+}
+
+impl DiskTier {
+    /// Open (creating if needed) a disk tier rooted at `dir`.
+    pub(crate) fn open(dir: PathBuf, max_bytes: u64) -> EmbeddingResult<Self> {
+        fs::create_dir_all(&dir).map_err(|e| EmbeddingError::CacheError {
+            message: format!("Failed to create cache directory {}: {}", dir.display(), e),
+        })?;
+        Ok(Self { dir, max_bytes })
+    }
+
+    /// Path of the entry file for `key`.
+    pub(crate) fn entry_path(&self, key: &CacheKey) -> PathBuf {
+        let shard = format!("{:02x}", key.as_u64() >> 56);
+        self.dir
+            .join(shard)
+            .join(format!("{}.{}", key, ENTRY_EXTENSION))
+    }
+
+    /// Read the entry for `key`.
+    ///
+    /// Returns `Ok(None)` if no file exists. A hit refreshes the file mtime so
+    /// budget eviction treats it as recently used.
+    ///
+    /// # Errors
+    /// `EmbeddingError::CacheError` if the file exists but is corrupt.
+    pub(crate) fn read(&self, key: &CacheKey) -> EmbeddingResult<Option<MultiArrayEmbedding>> {
+        let path = self.entry_path(key);
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(EmbeddingError::IoError(e)),
+        };
+
+        let embedding = decode_entry(&bytes).map_err(|message| EmbeddingError::CacheError {
+            message: format!("Corrupt cache entry {}: {}", path.display(), message),
+        })?;
+
+        // Best effort: another process may have evicted the file already
+        if let Ok(file) = fs::File::options().write(true).open(&path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Ok(Some(embedding))
+    }
+
+    /// Write the entry for `key` via temp file + rename.
+    ///
+    /// # Returns
+    /// Number of bytes written.
+    pub(crate) fn write(
+        &self,
+        key: &CacheKey,
+        embedding: &MultiArrayEmbedding,
+    ) -> EmbeddingResult<u64> {
+        let path = self.entry_path(key);
+        if let Some(shard) = path.parent() {
+            fs::create_dir_all(shard)?;
+        }
+
+        let bytes = encode_entry(embedding);
+        let tmp = path.with_extension(format!(
+            "tmp.{}.{}",
+            std::process::id(),
+            TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&tmp, &bytes)?;
+        if let Err(e) = fs::rename(&tmp, &path) {
+            let _ = fs::remove_file(&tmp);
+            return Err(EmbeddingError::IoError(e));
+        }
+        Ok(bytes.len() as u64)
+    }
+
+    /// Remove the entry for `key` (missing files are ignored).
+    pub(crate) fn remove(&self, key: &CacheKey) -> EmbeddingResult<()> {
+        match fs::remove_file(self.entry_path(key)) {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(EmbeddingError::IoError(e)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Remove every entry file.
+    pub(crate) fn clear(&self) -> EmbeddingResult<()> {
+        for (path, _, _) in self.list_entries()? {
+            match fs::remove_file(&path) {
+                Err(e) if e.kind() != ErrorKind::NotFound => return Err(EmbeddingError::IoError(e)),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Delete least recently used entries (by mtime) until the total size is
+    /// within the byte budget.
+    ///
+    /// # Returns
+    /// Number of files evicted.
+    pub(crate) fn enforce_budget(&self) -> EmbeddingResult<usize> {
+        let mut entries = self.list_entries()?;
+        let mut total: u64 = entries.iter().map(|(_, len, _)| len).sum();
+        if total <= self.max_bytes {
+            return Ok(0);
+        }
+
+        entries.sort_by_key(|(_, _, mtime)| *mtime);
+        let mut evicted = 0;
+        for (path, len, _) in entries {
+            if total <= self.max_bytes {
+                break;
+            }
+            match fs::remove_file(&path) {
+                Ok(()) => evicted += 1,
+                Err(e) if e.kind() == ErrorKind::NotFound => {}
+                Err(e) => return Err(EmbeddingError::IoError(e)),
+            }
+            total = total.saturating_sub(len);
+        }
+
+        tracing::debug!(evicted, total_bytes = total, "Disk cache budget enforced");
+        Ok(evicted)
+    }
+
+    /// All entry files as (path, length, mtime). Vanished files are skipped.
+    fn list_entries(&self) -> EmbeddingResult<Vec<(PathBuf, u64, SystemTime)>> {
+        let mut entries = Vec::new();
+        for shard in fs::read_dir(&self.dir)? {
+            let shard = match shard {
+                Ok(shard) => shard.path(),
+                Err(_) => continue,
+            };
+            if !shard.is_dir() {
+                continue;
+            }
+            let files = match fs::read_dir(&shard) {
+                Ok(files) => files,
+                Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                Err(e) => return Err(EmbeddingError::IoError(e)),
+            };
+            for file in files.flatten() {
+                let path = file.path();
+                if path.extension().and_then(|e| e.to_str()) != Some(ENTRY_EXTENSION) {
+                    continue;
+                }
+                if let Ok(meta) = file.metadata() {
+                    let mtime = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                    entries.push((path, meta.len(), mtime));
+                }
+            }
+        }
+        Ok(entries)
+    }
+}
+
+// ============================================================================
+// BACKGROUND WRITER
+// ============================================================================
+
+/// Message for the background writer thread.
+pub(crate) enum DiskWrite {
+    /// Persist an entry.
+    Entry(CacheKey, Arc<MultiArrayEmbedding>),
+    /// Drain pending writes, enforce the budget, and exit.
+    Shutdown,
+}
+
+/// Spawn the thread that writes entries evicted from memory down to disk.
+///
+/// The budget is enforced once at startup and again whenever bytes written
+/// since the last check exceed 1/16 of the budget.
+pub(crate) fn spawn_writer(
+    tier: Arc<DiskTier>,
+    stats: Arc<CacheStats>,
+) -> EmbeddingResult<(Sender<DiskWrite>, JoinHandle<()>)> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let handle = std::thread::Builder::new()
+        .name("embedding-cache-disk".to_string())
+        .spawn(move || run_writer(&tier, &rx, &stats))
+        .map_err(|e| EmbeddingError::CacheError {
+            message: format!("Failed to spawn disk cache writer: {}", e),
+        })?;
+    Ok((tx, handle))
+}
+
+fn run_writer(tier: &DiskTier, rx: &Receiver<DiskWrite>, stats: &CacheStats) {
+    let check_every = (tier.max_bytes / 16).max(1);
+    let mut since_check = 0u64;
+
+    if let Err(e) = tier.enforce_budget() {
+        tracing::error!(error = %e, "Disk cache budget enforcement failed");
+    }
+
+    // A closed channel (all senders dropped) also ends the loop
+    while let Ok(msg) = rx.recv() {
+        match msg {
+            DiskWrite::Entry(key, embedding) => match tier.write(&key, &embedding) {
+                Ok(bytes) => {
+                    stats.record_disk_write();
+                    since_check += bytes;
+                }
+                Err(e) => tracing::error!(key = %key, error = %e, "Disk cache write failed"),
+            },
+            DiskWrite::Shutdown => break,
+        }
+
+        if since_check >= check_every {
+            since_check = 0;
+            if let Err(e) = tier.enforce_budget() {
+                tracing::error!(error = %e, "Disk cache budget enforcement failed");
+            }
+        }
+    }
+
+    if let Err(e) = tier.enforce_budget() {
+        tracing::error!(error = %e, "Disk cache budget enforcement failed");
+    }
+}
+
+// ============================================================================
+// ENTRY ENCODING
+// ============================================================================
+
+fn encode_entry(embedding: &MultiArrayEmbedding) -> Vec<u8> {
+    let slots: Vec<&ModelEmbedding> = embedding.embeddings.iter().flatten().collect();
+
+    let mut buf = Vec::with_capacity(64 + slots.iter().map(|e| e.vector.len() * 4 + 16).sum::<usize>());
+    buf.extend_from_slice(ENTRY_MAGIC);
+    buf.push(ENTRY_VERSION);
+    buf.extend_from_slice(&embedding.content_hash.to_le_bytes());
+    buf.extend_from_slice(&embedding.total_latency_us.to_le_bytes());
+    buf.push(slots.len() as u8);
+
+    for emb in slots {
+        let mut flags = 0u8;
+        if emb.is_projected {
+            flags |= FLAG_PROJECTED;
+        }
+        if emb.attention_weights.is_some() {
+            flags |= FLAG_ATTENTION;
+        }
+        buf.push(emb.model_id as u8);
+        buf.push(flags);
+        buf.extend_from_slice(&emb.latency_us.to_le_bytes());
+        put_f32s(&mut buf, &emb.vector);
+        if let Some(ref attn) = emb.attention_weights {
+            put_f32s(&mut buf, attn);
+        }
+    }
+
+    let checksum = xxhash_rust::xxh64::xxh64(&buf, 0);
+    buf.extend_from_slice(&checksum.to_le_bytes());
+    buf
+}
+
+fn decode_entry(bytes: &[u8]) -> Result<MultiArrayEmbedding, String> {
+    if bytes.len() < ENTRY_MAGIC.len() + 8 {
+        return Err("file too short".to_string());
+    }
+    let (body, checksum) = bytes.split_at(bytes.len() - 8);
+    let expected = u64::from_le_bytes(checksum.try_into().map_err(|_| "bad checksum field")?);
+    if xxhash_rust::xxh64::xxh64(body, 0) != expected {
+        return Err("checksum mismatch".to_string());
+    }
+
+    let mut reader = Reader { bytes: body, pos: 0 };
+    if reader.take(ENTRY_MAGIC.len())? != ENTRY_MAGIC {
+        return Err("bad magic".to_string());
+    }
+    let version = reader.u8()?;
+    if version != ENTRY_VERSION {
+        return Err(format!("unsupported version {} (expected {})", version, ENTRY_VERSION));
+    }
+
+    let mut embedding = MultiArrayEmbedding::new();
+    embedding.content_hash = reader.u64()?;
+    embedding.total_latency_us = reader.u64()?;
+
+    let count = reader.u8()?;
+    for _ in 0..count {
+        let model_id = ModelId::try_from(reader.u8()?)?;
+        let flags = reader.u8()?;
+        let latency_us = reader.u64()?;
+        let mut emb = ModelEmbedding::new(model_id, reader.f32s()?, latency_us);
+        emb.is_projected = flags & FLAG_PROJECTED != 0;
+        if flags & FLAG_ATTENTION != 0 {
+            emb.attention_weights = Some(reader.f32s()?);
+        }
+        embedding.embeddings[model_id as u8 as usize] = Some(emb);
+    }
+
+    if reader.pos != body.len() {
+        return Err("trailing bytes".to_string());
+    }
+    Ok(embedding)
+}
+
+fn put_f32s(buf: &mut Vec<u8>, values: &[f32]) {
+    buf.extend_from_slice(&(values.len() as u32).to_le_bytes());
+    for v in values {
+        buf.extend_from_slice(&v.to_le_bytes());
+    }
+}
+
+/// Bounds-checked little-endian reader over an entry body.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], String> {
+        let slice = self
+            .pos
+            .checked_add(n)
+            .and_then(|end| self.bytes.get(self.pos..end))
+            .ok_or_else(|| "truncated".to_string())?;
+        self.pos += n;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u64(&mut self) -> Result<u64, String> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().map_err(|_| "truncated")?))
+    }
+
+    fn f32s(&mut self) -> Result<Vec<f32>, String> {
+        let len = u32::from_le_bytes(self.take(4)?.try_into().map_err(|_| "truncated")?) as usize;
+        let raw = self.take(len.checked_mul(4).ok_or("length overflow")?)?;
+        Ok(raw
+            .chunks_exact(4)
+            .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .collect())
+    }
+}
@@ -0,0 +1,91 @@
+//! In-flight request tracking for [`super::EmbeddingCache::get_or_compute`].
+//!
+//! The first caller to miss on a key becomes the leader and registers a
+//! broadcast sender; later callers subscribe and await the leader's result
+//! instead of starting a duplicate GPU computation.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::broadcast;
+
+use crate::error::EmbeddingError;
+use crate::types::MultiArrayEmbedding;
+
+use super::CacheKey;
+
+/// Result broadcast from the leader to waiting callers.
+pub(crate) type InFlightResult = Result<Arc<MultiArrayEmbedding>, Arc<EmbeddingError>>;
+
+/// Keys currently being computed, with the channel their result is sent on.
+pub(crate) type InFlightMap = Mutex<HashMap<CacheKey, broadcast::Sender<InFlightResult>>>;
+
+/// A caller's part in a coalesced computation.
+pub(crate) enum Role {
+    /// First caller: computes and broadcasts the result.
+    Leader(broadcast::Sender<InFlightResult>),
+    /// Later caller: awaits the leader's result.
+    Waiter(broadcast::Receiver<InFlightResult>),
+}
+
+/// Removes the leader's in-flight entry when dropped.
+///
+/// Covers both completion and cancellation: if the leader's future is dropped
+/// mid-computation, the sender is dropped with the entry, waiters see a
+/// closed channel, and one of them retries as the new leader.
+pub(crate) struct InFlightGuard<'a> {
+    pub(crate) map: &'a InFlightMap,
+    pub(crate) key: CacheKey,
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut map) = self.map.lock() {
+            map.remove(&self.key);
+        }
+    }
+}
+
+/// Check if `error` was caused by the input itself.
+///
+/// The same content fails the same way on every attempt, so these errors are
+/// remembered in the negative cache.
+pub(crate) fn is_input_rejection(error: &EmbeddingError) -> bool {
+    matches!(
+        error,
+        EmbeddingError::EmptyInput
+            | EmbeddingError::InputTooLong { .. }
+            | EmbeddingError::InputTooLarge { .. }
+            | EmbeddingError::InvalidImage { .. }
+    )
+}
+
+/// Owned copy of a shared error for a waiting caller.
+///
+/// Input rejections and cache errors keep their variant; anything else is
+/// reported as a `CacheError` carrying the original message.
+pub(crate) fn replay_error(error: &EmbeddingError) -> EmbeddingError {
+    match error {
+        EmbeddingError::EmptyInput => EmbeddingError::EmptyInput,
+        EmbeddingError::InputTooLong { actual, max } => EmbeddingError::InputTooLong {
+            actual: *actual,
+            max: *max,
+        },
+        EmbeddingError::InputTooLarge {
+            max_tokens,
+            actual_tokens,
+        } => EmbeddingError::InputTooLarge {
+            max_tokens: *max_tokens,
+            actual_tokens: *actual_tokens,
+        },
+        EmbeddingError::InvalidImage { reason } => EmbeddingError::InvalidImage {
+            reason: reason.clone(),
+        },
+        EmbeddingError::CacheError { message } => EmbeddingError::CacheError {
+            message: message.clone(),
+        },
+        other => EmbeddingError::CacheError {
+            message: format!("Coalesced embedding computation failed: {}", other),
+        },
+    }
+}
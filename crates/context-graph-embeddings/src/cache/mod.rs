@@ -11,20 +11,41 @@
 //! of the input content. Uses moka's concurrent cache for thread-safe access
 //! with configurable eviction policies and TTL.
 //!
+//! With `persist_to_disk`, entries evicted from memory (and everything still
+//! in memory at shutdown) are written down to a sharded directory by a
+//! background thread. Memory misses check the disk tier and promote hits, so
+//! a restarted process reuses embeddings instead of recomputing them.
+//!
+//! `get_or_compute` coalesces concurrent misses on one key into a single
+//! computation and remembers input rejections (e.g. empty text) for a short
+//! TTL so repeated bad input does not reach the pipeline.
+//!
 //! # Critical Requirements (NO FALLBACKS)
 //!
 //! - If cache operations fail, errors propagate (no silent degradation)
 //! - Real moka cache backend (no stubs or mocks in production)
 //! - Fail-fast on invalid configuration
 
+mod disk;
+mod inflight;
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
 use std::time::Duration;
 
+use moka::notification::RemovalCause;
 use moka::sync::Cache;
 
+use disk::{DiskTier, DiskWrite};
+use inflight::{InFlightGuard, InFlightMap, InFlightResult, Role};
+
 use crate::config::CacheConfig;
-use crate::error::EmbeddingResult;
+use crate::error::{EmbeddingError, EmbeddingResult};
 use crate::types::dimensions::{MODEL_COUNT, TOTAL_DIMENSION};
 use crate::types::MultiArrayEmbedding;
 
@@ -96,8 +117,16 @@ impl std::fmt::Display for CacheKey {
 /// Thread-safe counters using atomic operations.
 #[derive(Debug, Default)]
 pub struct CacheStats {
-    /// Total cache hits
+    /// Hits served from memory
     hits: AtomicU64,
+    /// Hits served from the disk tier (memory miss, disk hit)
+    disk_hits: AtomicU64,
+    /// Entries written down to the disk tier
+    disk_writes: AtomicU64,
+    /// Lookups that awaited another caller's in-flight computation
+    coalesced: AtomicU64,
+    /// Lookups rejected by the negative cache
+    negative_hits: AtomicU64,
     /// Total cache misses
     misses: AtomicU64,
     /// Total insertions
@@ -112,30 +141,84 @@ impl CacheStats {
         Self::default()
     }
 
-    /// Record a cache hit.
+    /// Record a cache hit served from memory.
     #[inline]
     pub fn record_hit(&self) {
         self.hits.fetch_add(1, Ordering::Relaxed);
     }
 
+    /// Record a cache hit served from the disk tier.
+    #[inline]
+    pub fn record_disk_hit(&self) {
+        self.disk_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Record a cache miss.
     #[inline]
     pub fn record_miss(&self) {
         self.misses.fetch_add(1, Ordering::Relaxed);
     }
 
+    /// Record an entry written down to the disk tier.
+    #[inline]
+    pub fn record_disk_write(&self) {
+        self.disk_writes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a lookup that awaited an in-flight computation.
+    #[inline]
+    pub fn record_coalesced(&self) {
+        self.coalesced.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a lookup rejected by the negative cache.
+    #[inline]
+    pub fn record_negative_hit(&self) {
+        self.negative_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Record an insertion.
     #[inline]
     pub fn record_insertion(&self) {
         self.insertions.fetch_add(1, Ordering::Relaxed);
     }
 
-    /// Get total hits.
+    /// Get total hits (memory + disk).
     #[inline]
     pub fn hits(&self) -> u64 {
+        self.memory_hits() + self.disk_hits()
+    }
+
+    /// Get hits served from memory.
+    #[inline]
+    pub fn memory_hits(&self) -> u64 {
         self.hits.load(Ordering::Relaxed)
     }
 
+    /// Get hits served from the disk tier.
+    #[inline]
+    pub fn disk_hits(&self) -> u64 {
+        self.disk_hits.load(Ordering::Relaxed)
+    }
+
+    /// Get entries written down to the disk tier.
+    #[inline]
+    pub fn disk_writes(&self) -> u64 {
+        self.disk_writes.load(Ordering::Relaxed)
+    }
+
+    /// Get lookups that awaited another caller's in-flight computation.
+    #[inline]
+    pub fn coalesced(&self) -> u64 {
+        self.coalesced.load(Ordering::Relaxed)
+    }
+
+    /// Get lookups rejected by the negative cache.
+    #[inline]
+    pub fn negative_hits(&self) -> u64 {
+        self.negative_hits.load(Ordering::Relaxed)
+    }
+
     /// Get total misses.
     #[inline]
     pub fn misses(&self) -> u64 {
@@ -152,7 +235,7 @@ impl CacheStats {
     ///
     /// Returns 0.0 if no lookups have been performed.
     pub fn hit_ratio(&self) -> f64 {
-        let hits = self.hits.load(Ordering::Relaxed);
+        let hits = self.hits();
         let misses = self.misses.load(Ordering::Relaxed);
         let total = hits + misses;
         if total == 0 {
@@ -165,6 +248,10 @@ impl CacheStats {
     /// Reset all statistics to zero.
     pub fn reset(&self) {
         self.hits.store(0, Ordering::Relaxed);
+        self.disk_hits.store(0, Ordering::Relaxed);
+        self.disk_writes.store(0, Ordering::Relaxed);
+        self.coalesced.store(0, Ordering::Relaxed);
+        self.negative_hits.store(0, Ordering::Relaxed);
         self.misses.store(0, Ordering::Relaxed);
         self.insertions.store(0, Ordering::Relaxed);
         self.estimated_evictions.store(0, Ordering::Relaxed);
@@ -175,6 +262,10 @@ impl Clone for CacheStats {
     fn clone(&self) -> Self {
         Self {
             hits: AtomicU64::new(self.hits.load(Ordering::Relaxed)),
+            disk_hits: AtomicU64::new(self.disk_hits.load(Ordering::Relaxed)),
+            disk_writes: AtomicU64::new(self.disk_writes.load(Ordering::Relaxed)),
+            coalesced: AtomicU64::new(self.coalesced.load(Ordering::Relaxed)),
+            negative_hits: AtomicU64::new(self.negative_hits.load(Ordering::Relaxed)),
             misses: AtomicU64::new(self.misses.load(Ordering::Relaxed)),
             insertions: AtomicU64::new(self.insertions.load(Ordering::Relaxed)),
             estimated_evictions: AtomicU64::new(self.estimated_evictions.load(Ordering::Relaxed)),
@@ -238,6 +329,9 @@ pub const fn estimate_complete_embedding_memory() -> usize {
 // EMBEDDING CACHE
 // ============================================================================
 
+/// Maximum number of remembered input rejections.
+const NEGATIVE_CACHE_CAPACITY: u64 = 10_000;
+
 /// High-performance embedding cache using moka.
 ///
 /// Thread-safe, concurrent cache with configurable eviction and TTL.
@@ -261,6 +355,16 @@ pub struct EmbeddingCache {
     stats: Arc<CacheStats>,
     /// Whether caching is enabled
     enabled: bool,
+    /// Disk tier (None unless `persist_to_disk`)
+    disk: Option<Arc<DiskTier>>,
+    /// Queue feeding the background disk writer
+    disk_tx: Option<Sender<DiskWrite>>,
+    /// Background disk writer, joined on drop
+    disk_writer: Mutex<Option<JoinHandle<()>>>,
+    /// Keys with a computation in progress (see `get_or_compute`)
+    in_flight: InFlightMap,
+    /// Recently rejected inputs (None if `negative_ttl_seconds == 0`)
+    negative: Option<Cache<CacheKey, Arc<EmbeddingError>>>,
 }
 
 impl EmbeddingCache {
@@ -278,6 +382,10 @@ impl EmbeddingCache {
     /// - `enabled && max_entries == 0`
     /// - `enabled && max_bytes == 0`
     /// - `persist_to_disk && disk_path.is_none()`
+    /// - `persist_to_disk && disk_max_bytes == 0`
+    ///
+    /// Returns `EmbeddingError::CacheError` if the disk tier directory cannot
+    /// be created or its writer thread cannot be spawned.
     pub fn new(config: CacheConfig) -> EmbeddingResult<Self> {
         // Validate configuration (fail fast)
         config.validate()?;
@@ -301,6 +409,31 @@ impl EmbeddingCache {
                 estimate_embedding_memory(value)
             });
 
+        let stats = Arc::new(CacheStats::new());
+
+        // Disk tier: entries evicted for size are queued for the writer thread
+        let (disk, disk_tx, disk_writer) = match (&config.disk_path, enabled && config.persist_to_disk) {
+            (Some(dir), true) => {
+                let tier = Arc::new(DiskTier::open(dir.clone(), config.disk_max_bytes)?);
+                let (tx, handle) = disk::spawn_writer(Arc::clone(&tier), Arc::clone(&stats))?;
+                let listener_tx = tx.clone();
+                builder = builder.eviction_listener(
+                    move |key: Arc<CacheKey>, value: Arc<MultiArrayEmbedding>, cause| {
+                        if cause == RemovalCause::Size {
+                            let _ = listener_tx.send(DiskWrite::Entry(*key, value));
+                        }
+                    },
+                );
+                tracing::info!(
+                    disk_path = %dir.display(),
+                    disk_max_bytes = config.disk_max_bytes,
+                    "Embedding cache disk tier enabled"
+                );
+                (Some(tier), Some(tx), Some(handle))
+            }
+            _ => (None, None, None),
+        };
+
         // Add TTL if configured
         if let Some(ttl_secs) = config.ttl_seconds {
             if ttl_secs > 0 {
@@ -311,6 +444,13 @@ impl EmbeddingCache {
 
         let inner = builder.build();
 
+        let negative = (config.negative_ttl_seconds > 0).then(|| {
+            Cache::builder()
+                .max_capacity(NEGATIVE_CACHE_CAPACITY)
+                .time_to_live(Duration::from_secs(config.negative_ttl_seconds))
+                .build()
+        });
+
         tracing::info!(
             enabled,
             max_entries = config.max_entries,
@@ -324,8 +464,31 @@ impl EmbeddingCache {
         Ok(Self {
             inner,
             config,
-            stats: Arc::new(CacheStats::new()),
+            stats,
             enabled,
+            disk,
+            disk_tx,
+            disk_writer: Mutex::new(disk_writer),
+            in_flight: Mutex::new(HashMap::new()),
+            negative,
+        })
+    }
+
+    /// Create a cache backed by a disk tier in `dir`.
+    ///
+    /// Equivalent to [`EmbeddingCache::new`] with `persist_to_disk` enabled,
+    /// `disk_path = dir`, and `disk_max_bytes = max_bytes`. Several processes
+    /// may share the same directory.
+    pub fn with_disk_tier(
+        config: CacheConfig,
+        dir: impl Into<PathBuf>,
+        max_bytes: u64,
+    ) -> EmbeddingResult<Self> {
+        Self::new(CacheConfig {
+            persist_to_disk: true,
+            disk_path: Some(dir.into()),
+            disk_max_bytes: max_bytes,
+            ..config
         })
     }
 
@@ -352,15 +515,37 @@ impl EmbeddingCache {
             return None;
         }
 
-        match self.inner.get(key) {
-            Some(value) => {
-                self.stats.record_hit();
-                tracing::trace!(key = %key, "Cache HIT");
-                Some(value)
-            }
-            None => {
-                self.stats.record_miss();
-                tracing::trace!(key = %key, "Cache MISS");
+        if let Some(value) = self.inner.get(key) {
+            self.stats.record_hit();
+            tracing::trace!(key = %key, "Cache HIT");
+            return Some(value);
+        }
+
+        if let Some(value) = self.get_from_disk(key) {
+            self.stats.record_disk_hit();
+            tracing::trace!(key = %key, "Cache DISK HIT");
+            self.inner.insert(*key, Arc::clone(&value));
+            return Some(value);
+        }
+
+        self.stats.record_miss();
+        tracing::trace!(key = %key, "Cache MISS");
+        None
+    }
+
+    /// Look up `key` in the disk tier.
+    ///
+    /// A corrupt entry is logged, deleted, and reported as a miss so the
+    /// embedding is recomputed rather than served wrong.
+    fn get_from_disk(&self, key: &CacheKey) -> Option<Arc<MultiArrayEmbedding>> {
+        let disk = self.disk.as_ref()?;
+        match disk.read(key) {
+            Ok(entry) => entry.map(Arc::new),
+            Err(e) => {
+                tracing::error!(key = %key, error = %e, "Disk cache read failed - dropping entry");
+                if let Err(e) = disk.remove(key) {
+                    tracing::error!(key = %key, error = %e, "Failed to remove bad disk cache entry");
+                }
                 None
             }
         }
@@ -438,6 +623,107 @@ impl EmbeddingCache {
         Ok(arc_embedding)
     }
 
+    /// Get an embedding, computing it at most once across concurrent callers.
+    ///
+    /// On a miss the first caller awaits `compute` and caches the result;
+    /// callers arriving while it runs await the same result instead of
+    /// starting their own computation. If the computation fails, every
+    /// waiter receives the error and the key is released so a later call can
+    /// retry. Input rejections (see `negative_ttl_seconds`) are remembered and
+    /// returned immediately for repeated lookups.
+    ///
+    /// # Errors
+    /// - The leader receives the error from `compute` unchanged
+    /// - Waiters receive input rejections unchanged and other failures as
+    ///   `EmbeddingError::CacheError` with the original message
+    pub async fn get_or_compute<Fut>(
+        &self,
+        key: CacheKey,
+        compute: Fut,
+    ) -> EmbeddingResult<Arc<MultiArrayEmbedding>>
+    where
+        Fut: Future<Output = EmbeddingResult<MultiArrayEmbedding>>,
+    {
+        if !self.enabled {
+            return compute.await.map(Arc::new);
+        }
+
+        let mut compute = Some(compute);
+        loop {
+            if let Some(cached) = self.get(&key) {
+                return Ok(cached);
+            }
+            if let Some(rejected) = self.negative.as_ref().and_then(|n| n.get(&key)) {
+                self.stats.record_negative_hit();
+                tracing::trace!(key = %key, "Cache NEGATIVE HIT");
+                return Err(inflight::replay_error(&rejected));
+            }
+
+            let role = {
+                let mut in_flight = self.in_flight.lock().map_err(|e| EmbeddingError::CacheError {
+                    message: format!("In-flight map lock poisoned: {}", e),
+                })?;
+                // Re-check under the lock: a leader may have finished since the miss
+                if let Some(cached) = self.inner.get(&key) {
+                    return Ok(cached);
+                }
+                match in_flight.get(&key) {
+                    Some(tx) => Role::Waiter(tx.subscribe()),
+                    None => {
+                        let (tx, _) = tokio::sync::broadcast::channel(1);
+                        in_flight.insert(key, tx.clone());
+                        Role::Leader(tx)
+                    }
+                }
+            };
+
+            let tx = match role {
+                Role::Leader(tx) => tx,
+                Role::Waiter(mut rx) => {
+                    self.stats.record_coalesced();
+                    match rx.recv().await {
+                        Ok(Ok(value)) => return Ok(value),
+                        Ok(Err(e)) => return Err(inflight::replay_error(&e)),
+                        // Leader was cancelled: retry, possibly as the new leader
+                        Err(_) => continue,
+                    }
+                }
+            };
+
+            let guard = InFlightGuard {
+                map: &self.in_flight,
+                key,
+            };
+            let Some(compute) = compute.take() else {
+                return Err(EmbeddingError::InternalError {
+                    message: "get_or_compute led a computation twice".to_string(),
+                });
+            };
+
+            let result = compute.await.map(Arc::new);
+            let message: InFlightResult = match &result {
+                Ok(value) => {
+                    self.insert_arc(key, Arc::clone(value));
+                    Ok(Arc::clone(value))
+                }
+                Err(e) => {
+                    let shared = Arc::new(inflight::replay_error(e));
+                    if inflight::is_input_rejection(e) {
+                        if let Some(ref negative) = self.negative {
+                            negative.insert(key, Arc::clone(&shared));
+                        }
+                    }
+                    Err(shared)
+                }
+            };
+
+            // Release the key before notifying so retries after an error lead anew
+            drop(guard);
+            let _ = tx.send(message);
+            return result;
+        }
+    }
+
     /// Remove an entry from the cache.
     ///
     /// # Arguments
@@ -447,15 +733,55 @@ impl EmbeddingCache {
             return;
         }
         self.inner.invalidate(key);
+        if let Some(ref disk) = self.disk {
+            if let Err(e) = disk.remove(key) {
+                tracing::error!(key = %key, error = %e, "Failed to remove disk cache entry");
+            }
+        }
         tracing::trace!(key = %key, "Cache REMOVE");
     }
 
-    /// Clear all entries from the cache.
+    /// Clear all entries from the cache (memory and disk tier).
     pub fn clear(&self) {
         self.inner.invalidate_all();
+        if let Some(ref disk) = self.disk {
+            if let Err(e) = disk.clear() {
+                tracing::error!(error = %e, "Failed to clear disk cache");
+            }
+        }
         tracing::info!("Cache CLEARED");
     }
 
+    /// Write every in-memory entry down to the disk tier and wait for the
+    /// writer to finish.
+    ///
+    /// Called automatically on drop. After flushing, entries evicted from
+    /// memory are no longer persisted.
+    pub fn flush_to_disk(&self) {
+        let Some(ref tx) = self.disk_tx else {
+            return;
+        };
+        // Deliver pending size evictions to the listener first
+        self.inner.run_pending_tasks();
+        for (key, value) in self.inner.iter() {
+            let _ = tx.send(DiskWrite::Entry(*key, value));
+        }
+        let _ = tx.send(DiskWrite::Shutdown);
+
+        let handle = self.disk_writer.lock().ok().and_then(|mut h| h.take());
+        if let Some(handle) = handle {
+            if handle.join().is_err() {
+                tracing::error!("Disk cache writer thread panicked");
+            }
+        }
+    }
+
+    /// Check if the disk tier is enabled.
+    #[inline]
+    pub fn has_disk_tier(&self) -> bool {
+        self.disk.is_some()
+    }
+
     /// Get the current number of entries in the cache.
     #[inline]
     pub fn len(&self) -> u64 {
@@ -522,10 +848,17 @@ impl std::fmt::Debug for EmbeddingCache {
             .field("max_entries", &self.config.max_entries)
             .field("hit_ratio", &self.hit_ratio())
             .field("stats", &self.stats)
+            .field("disk_tier", &self.disk)
             .finish()
     }
 }
 
+impl Drop for EmbeddingCache {
+    fn drop(&mut self) {
+        self.flush_to_disk();
+    }
+}
+
 // ============================================================================
 // TESTS
 // ============================================================================
@@ -847,4 +1180,198 @@ mod tests {
             "Cache should have entries after concurrent access"
         );
     }
+
+    #[test]
+    fn test_disk_tier_survives_restart() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let keys: Vec<CacheKey> = (0..5)
+            .map(|i| CacheKey::from_content(&format!("persisted_{}", i)))
+            .collect();
+
+        {
+            let cache = EmbeddingCache::with_disk_tier(CacheConfig::default(), dir.path(), 1 << 30)
+                .expect("cache creation should succeed");
+            assert!(cache.has_disk_tier());
+            for key in &keys {
+                cache.insert(*key, create_complete_embedding());
+            }
+            // Dropped here: in-memory entries are flushed to disk
+        }
+
+        let cache = EmbeddingCache::with_disk_tier(CacheConfig::default(), dir.path(), 1 << 30)
+            .expect("cache creation should succeed");
+        assert!(cache.is_empty(), "New process starts with empty memory tier");
+
+        for key in &keys {
+            let mut factory_called = false;
+            let result = cache
+                .get_or_insert_with(*key, || {
+                    factory_called = true;
+                    Ok(create_complete_embedding())
+                })
+                .expect("lookup should succeed");
+            assert!(!factory_called, "Disk hit must not recompute");
+            assert_eq!(result.filled_count(), MODEL_COUNT);
+            assert_eq!(
+                result.get(ModelId::Semantic).unwrap().vector,
+                create_complete_embedding().get(ModelId::Semantic).unwrap().vector
+            );
+        }
+
+        assert_eq!(cache.stats().disk_hits(), keys.len() as u64);
+        assert_eq!(cache.stats().memory_hits(), 0);
+
+        // Promoted into memory: second lookup is a memory hit
+        assert!(cache.get(&keys[0]).is_some());
+        assert_eq!(cache.stats().memory_hits(), 1);
+    }
+
+    #[test]
+    fn test_disk_tier_writes_evicted_entries() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let entry_bytes = estimate_embedding_memory(&create_complete_embedding()) as usize;
+        let config = CacheConfig {
+            max_bytes: entry_bytes * 2,
+            ..CacheConfig::default()
+        };
+        let cache = EmbeddingCache::with_disk_tier(config, dir.path(), 1 << 30)
+            .expect("cache creation should succeed");
+
+        for i in 0..20 {
+            let key = CacheKey::from_content(&format!("evict_{}", i));
+            cache.insert(key, create_complete_embedding());
+        }
+        cache.flush_to_disk();
+
+        assert!(cache.stats().disk_writes() >= 20, "Evicted and resident entries all persisted");
+        let tier = cache.disk.as_ref().unwrap();
+        for i in 0..20 {
+            assert!(tier.entry_path(&CacheKey::from_content(&format!("evict_{}", i))).exists());
+        }
+    }
+
+    #[test]
+    fn test_disk_tier_enforces_byte_budget() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let tier = DiskTier::open(dir.path().to_path_buf(), 150_000).expect("open");
+        let embedding = create_complete_embedding();
+
+        let mut keys = Vec::new();
+        for i in 0..6 {
+            let key = CacheKey::from_content(&format!("budget_{}", i));
+            tier.write(&key, &embedding).expect("write");
+            keys.push(key);
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        // Touch the oldest entry so it becomes most recently used
+        assert!(tier.read(&keys[0]).expect("read").is_some());
+
+        let evicted = tier.enforce_budget().expect("enforce");
+        assert!(evicted > 0, "Six ~50KB entries exceed a 150KB budget");
+        assert!(tier.entry_path(&keys[0]).exists(), "Recently read entry kept");
+        assert!(!tier.entry_path(&keys[1]).exists(), "Least recently used entry evicted");
+    }
+
+    #[test]
+    fn test_disk_tier_corrupt_entry_is_miss() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let key = CacheKey::from_content("corrupt");
+        let cache = EmbeddingCache::with_disk_tier(CacheConfig::default(), dir.path(), 1 << 30)
+            .expect("cache creation should succeed");
+
+        let path = cache.disk.as_ref().unwrap().entry_path(&key);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, b"CGEC not a real entry").unwrap();
+
+        assert!(cache.get(&key).is_none());
+        assert!(!path.exists(), "Corrupt entry removed");
+        assert_eq!(cache.stats().misses(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_get_or_compute_coalesces_concurrent_misses() {
+        let cache = Arc::new(EmbeddingCache::new(CacheConfig::default()).expect("cache"));
+        let computations = Arc::new(AtomicU64::new(0));
+        let key = CacheKey::from_content("hot new content");
+
+        let handles: Vec<_> = (0..100)
+            .map(|_| {
+                let cache = Arc::clone(&cache);
+                let computations = Arc::clone(&computations);
+                tokio::spawn(async move {
+                    cache
+                        .get_or_compute(key, async move {
+                            computations.fetch_add(1, Ordering::SeqCst);
+                            tokio::time::sleep(Duration::from_millis(50)).await;
+                            Ok(create_complete_embedding())
+                        })
+                        .await
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let embedding = handle.await.expect("task").expect("lookup should succeed");
+            assert_eq!(embedding.filled_count(), MODEL_COUNT);
+        }
+
+        assert_eq!(computations.load(Ordering::SeqCst), 1, "Compute must run exactly once");
+        assert!(cache.stats().coalesced() > 0);
+        assert!(cache.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_get_or_compute_error_reaches_waiters_and_allows_retry() {
+        let cache = Arc::new(EmbeddingCache::new(CacheConfig::default()).expect("cache"));
+        let key = CacheKey::from_content("gpu failure");
+
+        let handles: Vec<_> = (0..10)
+            .map(|_| {
+                let cache = Arc::clone(&cache);
+                tokio::spawn(async move {
+                    cache
+                        .get_or_compute(key, async {
+                            tokio::time::sleep(Duration::from_millis(50)).await;
+                            Err(EmbeddingError::GpuError {
+                                message: "kernel launch failed".to_string(),
+                            })
+                        })
+                        .await
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let err = handle.await.expect("task").expect_err("every caller sees the failure");
+            assert!(err.to_string().contains("kernel launch failed"), "got {}", err);
+        }
+        assert!(cache.in_flight.lock().unwrap().is_empty(), "Failed key released");
+
+        // Non-input failures are not negatively cached: a retry computes
+        let retried = cache
+            .get_or_compute(key, async { Ok(create_complete_embedding()) })
+            .await
+            .expect("retry should succeed");
+        assert_eq!(retried.filled_count(), MODEL_COUNT);
+    }
+
+    #[tokio::test]
+    async fn test_get_or_compute_negative_caches_input_rejection() {
+        let cache = EmbeddingCache::new(CacheConfig::default()).expect("cache");
+        let key = CacheKey::from_content("");
+        let computations = AtomicU64::new(0);
+
+        for _ in 0..5 {
+            let result = cache
+                .get_or_compute(key, async {
+                    computations.fetch_add(1, Ordering::SeqCst);
+                    Err(EmbeddingError::EmptyInput)
+                })
+                .await;
+            assert!(matches!(result, Err(EmbeddingError::EmptyInput)));
+        }
+
+        assert_eq!(computations.load(Ordering::SeqCst), 1);
+        assert_eq!(cache.stats().negative_hits(), 4);
+    }
 }
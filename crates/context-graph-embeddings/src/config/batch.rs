@@ -81,6 +81,14 @@ fn default_sort_by_length() -> bool {
     true
 }
 
+fn default_priority_aging_ms() -> u64 {
+    1000
+}
+
+fn default_max_queue_depth() -> usize {
+    1024
+}
+
 // ============================================================================
 // BATCH CONFIG
 // ============================================================================
@@ -131,6 +139,20 @@ pub struct BatchConfig {
     /// Default: true
     #[serde(default = "default_sort_by_length")]
     pub sort_by_length: bool,
+
+    /// Time a queued request waits before it is escalated one priority lane
+    /// (milliseconds). Keeps Bulk requests from starving behind a steady
+    /// stream of Interactive ones.
+    /// Default: 1000
+    #[serde(default = "default_priority_aging_ms")]
+    pub priority_aging_ms: u64,
+
+    /// Maximum number of pending requests per model queue. Requests beyond
+    /// this are rejected with `EmbeddingError::Backpressure` instead of
+    /// queueing unboundedly.
+    /// Default: 1024
+    #[serde(default = "default_max_queue_depth")]
+    pub max_queue_depth: usize,
 }
 
 impl Default for BatchConfig {
@@ -142,6 +164,8 @@ impl Default for BatchConfig {
             dynamic_batching: default_dynamic_batching(),
             padding_strategy: PaddingStrategy::default(),
             sort_by_length: default_sort_by_length(),
+            priority_aging_ms: default_priority_aging_ms(),
+            max_queue_depth: default_max_queue_depth(),
         }
     }
 }
@@ -153,6 +177,8 @@ impl BatchConfig {
     /// - `EmbeddingError::ConfigError` if max_batch_size is 0
     /// - `EmbeddingError::ConfigError` if min_batch_size > max_batch_size
     /// - `EmbeddingError::ConfigError` if max_wait_ms is 0 when min_batch_size > 1
+    /// - `EmbeddingError::ConfigError` if priority_aging_ms is 0
+    /// - `EmbeddingError::ConfigError` if max_queue_depth is 0
     pub fn validate(&self) -> EmbeddingResult<()> {
         if self.max_batch_size == 0 {
             return Err(EmbeddingError::ConfigError {
@@ -175,6 +201,18 @@ impl BatchConfig {
             });
         }
 
+        if self.priority_aging_ms == 0 {
+            return Err(EmbeddingError::ConfigError {
+                message: "priority_aging_ms must be > 0".to_string(),
+            });
+        }
+
+        if self.max_queue_depth == 0 {
+            return Err(EmbeddingError::ConfigError {
+                message: "max_queue_depth must be > 0".to_string(),
+            });
+        }
+
         Ok(())
     }
 }
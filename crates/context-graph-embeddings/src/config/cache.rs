@@ -75,6 +75,14 @@ fn default_max_bytes() -> usize {
     1_073_741_824 // 1 GB
 }
 
+fn default_disk_max_bytes() -> u64 {
+    10_737_418_240 // 10 GB
+}
+
+fn default_negative_ttl_seconds() -> u64 {
+    30
+}
+
 // ============================================================================
 // CACHE CONFIG
 // ============================================================================
@@ -130,6 +138,19 @@ pub struct CacheConfig {
     /// Default: None
     #[serde(default)]
     pub disk_path: Option<PathBuf>,
+
+    /// Byte budget for the disk tier. Least recently used entry files
+    /// (by mtime) are deleted when exceeded.
+    /// Default: 10GB (10_737_418_240 bytes)
+    #[serde(default = "default_disk_max_bytes")]
+    pub disk_max_bytes: u64,
+
+    /// Time-to-live in seconds for remembered input validation failures
+    /// (e.g. empty text). Repeated bad input fails fast without reaching
+    /// the pipeline. 0 disables negative caching.
+    /// Default: 30
+    #[serde(default = "default_negative_ttl_seconds")]
+    pub negative_ttl_seconds: u64,
 }
 
 impl Default for CacheConfig {
@@ -142,6 +163,8 @@ impl Default for CacheConfig {
             eviction_policy: EvictionPolicy::Lru,
             persist_to_disk: false,
             disk_path: None,
+            disk_max_bytes: default_disk_max_bytes(),
+            negative_ttl_seconds: default_negative_ttl_seconds(),
         }
     }
 }
@@ -154,6 +177,7 @@ impl CacheConfig {
     /// - enabled && max_entries == 0
     /// - enabled && max_bytes == 0
     /// - persist_to_disk && disk_path.is_none()
+    /// - persist_to_disk && disk_max_bytes == 0
     pub fn validate(&self) -> EmbeddingResult<()> {
         if self.enabled && self.max_entries == 0 {
             return Err(EmbeddingError::ConfigError {
@@ -170,6 +194,11 @@ impl CacheConfig {
                 message: "disk_path required when persist_to_disk enabled".to_string(),
             });
         }
+        if self.persist_to_disk && self.disk_max_bytes == 0 {
+            return Err(EmbeddingError::ConfigError {
+                message: "disk_max_bytes must be > 0 when persist_to_disk enabled".to_string(),
+            });
+        }
         Ok(())
     }
 
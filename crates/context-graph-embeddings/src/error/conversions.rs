@@ -0,0 +1,71 @@
+//! Conversions from [`EmbeddingError`] into the core error types.
+//!
+//! Errors keep their full value as `source()` so callers above the
+//! `MultiArrayEmbeddingProvider` boundary (e.g. the MCP layer) still see
+//! the candle/CUDA failure instead of a flattened string.
+
+use context_graph_core::error::{ContextGraphError, CoreError, ErrorKind};
+
+use super::types::EmbeddingError;
+
+impl EmbeddingError {
+    /// Machine-readable classification of this error.
+    #[must_use]
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Backpressure { .. } => ErrorKind::Backpressure,
+            Self::ModelNotFound { .. }
+            | Self::NotInitialized { .. }
+            | Self::ModelNotLoaded { .. }
+            | Self::UnsupportedModality { .. }
+            | Self::CudaUnavailable { .. }
+            | Self::WeightFileMissing { .. }
+            | Self::ProjectionMatrixMissing { .. }
+            | Self::CodebookMissing { .. }
+            | Self::QuantizerNotImplemented { .. }
+            | Self::UnsupportedOperation { .. } => ErrorKind::CapabilityUnavailable,
+            Self::GpuError { .. }
+            | Self::MemoryBudgetExceeded { .. }
+            | Self::InsufficientVram { .. }
+            | Self::OomDuringBatch { .. } => ErrorKind::GpuFailure,
+            Self::EmptyInput
+            | Self::InputTooLong { .. }
+            | Self::InputTooLarge { .. }
+            | Self::InvalidImage { .. }
+            | Self::InvalidModelInput { .. } => ErrorKind::InvalidInput,
+            Self::WeightChecksumMismatch { .. } | Self::StorageCorruption { .. } => {
+                ErrorKind::Corruption
+            }
+            Self::Timeout { .. } | Self::DeadlineExceeded { .. } => ErrorKind::Timeout,
+            _ => ErrorKind::Internal,
+        }
+    }
+}
+
+impl From<EmbeddingError> for ContextGraphError {
+    fn from(err: EmbeddingError) -> Self {
+        let kind = err.kind();
+        // A batch OOM can succeed once other requests release VRAM
+        let retryable = kind.is_retryable() || matches!(err, EmbeddingError::OomDuringBatch { .. });
+        ContextGraphError::from_source(kind, err).retryable(retryable)
+    }
+}
+
+/// Backpressure stays `CoreError::Backpressure`, which handlers match to
+/// answer SERVER_BUSY with a retry hint; everything else is carried as
+/// [`CoreError::Classified`].
+impl From<EmbeddingError> for CoreError {
+    fn from(err: EmbeddingError) -> Self {
+        match err {
+            EmbeddingError::Backpressure {
+                queue_depth,
+                retry_after_ms,
+                ..
+            } => CoreError::Backpressure {
+                queue_depth,
+                retry_after_ms,
+            },
+            err => CoreError::from(ContextGraphError::from(err)),
+        }
+    }
+}
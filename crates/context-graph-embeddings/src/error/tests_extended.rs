@@ -119,10 +119,16 @@ fn test_all_17_variants_can_be_created() {
         max: 50,
     };
 
-    // Processing Errors (2) - FusionError removed (TASK-F006)
+    // Processing Errors (3) - FusionError removed (TASK-F006)
     let _e8 = EmbeddingError::BatchError {
         message: "test".to_string(),
     };
+    let _e8b = EmbeddingError::DeadlineExceeded { overdue_ms: 5 };
+    let _e8c = EmbeddingError::Backpressure {
+        model_id: ModelId::Semantic,
+        queue_depth: 10,
+        retry_after_ms: 50,
+    };
     let _e9 = EmbeddingError::TokenizationError {
         model_id: ModelId::Semantic,
         message: "test".to_string(),
@@ -844,3 +850,48 @@ fn test_all_13_model_ids_work_with_spec_errors() {
         println!("Model {:?}: OK", model_id);
     }
 }
+
+// ============================================================
+// CORE ERROR CONVERSIONS
+// ============================================================
+
+#[test]
+fn test_conversion_to_core_keeps_source_and_kind() {
+    use context_graph_core::error::{ContextGraphError, CoreError, ErrorKind};
+
+    let err = EmbeddingError::OomDuringBatch {
+        batch_size: 64,
+        model_id: TEST_MODEL,
+    };
+    let message = err.to_string();
+
+    let core: CoreError = err.into();
+    assert_eq!(core.kind(), ErrorKind::GpuFailure);
+    assert!(core.is_retryable());
+    assert_eq!(core.to_string(), message);
+
+    let unified: ContextGraphError = core.into();
+    let source = unified.source().expect("original error is the source");
+    assert_eq!(source.to_string(), message);
+    assert!(source.downcast_ref::<EmbeddingError>().is_some());
+}
+
+#[test]
+fn test_backpressure_converts_to_core_backpressure() {
+    use context_graph_core::error::{CoreError, ErrorKind};
+
+    let core: CoreError = EmbeddingError::Backpressure {
+        model_id: TEST_MODEL,
+        queue_depth: 12,
+        retry_after_ms: 40,
+    }
+    .into();
+    assert!(matches!(
+        core,
+        CoreError::Backpressure {
+            queue_depth: 12,
+            retry_after_ms: 40
+        }
+    ));
+    assert_eq!(core.kind(), ErrorKind::Backpressure);
+}
@@ -46,7 +46,7 @@ pub enum ErrorSeverity {
 /// |----------|----------|-------------------|
 /// | Model | ModelNotFound, ModelLoadError, NotInitialized | Retry with different config |
 /// | Validation | InvalidDimension, InvalidValue, EmptyInput, InputTooLong | Fix input data |
-/// | Processing | BatchError, TokenizationError | Retry or fallback model |
+/// | Processing | BatchError, DeadlineExceeded, Backpressure, TokenizationError | Retry or fallback model |
 /// | Infrastructure | GpuError, CacheError, IoError, Timeout | Retry or degrade |
 /// | Configuration | ConfigError, UnsupportedModality | Fix configuration |
 /// | Serialization | SerializationError | Fix data format |
@@ -122,6 +122,19 @@ pub enum EmbeddingError {
     #[error("Batch processing error: {message}")]
     BatchError { message: String },
 
+    /// Request deadline passed while it was still queued; it was not run.
+    #[error("Deadline exceeded: request expired {overdue_ms}ms before processing")]
+    DeadlineExceeded { overdue_ms: u64 },
+
+    /// Model queue is at capacity; the request was rejected without queueing.
+    /// Callers should retry after roughly `retry_after_ms`.
+    #[error("Backpressure on {model_id:?}: queue depth {queue_depth} at capacity, retry after {retry_after_ms}ms")]
+    Backpressure {
+        model_id: ModelId,
+        queue_depth: usize,
+        retry_after_ms: u64,
+    },
+
     /// Tokenization failed (unknown tokens, encoding error).
     #[error("Tokenization error for {model_id:?}: {message}")]
     TokenizationError { model_id: ModelId, message: String },
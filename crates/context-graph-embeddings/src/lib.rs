@@ -75,7 +75,9 @@ pub use config::{
     PaddingStrategy,
 };
 pub use error::{EmbeddingError, EmbeddingResult};
-pub use provider::{EmbeddingProvider, ProductionMultiArrayProvider};
+pub use provider::{
+    BackpressureProvider, DeterministicProvider, EmbeddingProvider, ProductionMultiArrayProvider,
+};
 pub use traits::{
     get_memory_estimate, DevicePlacement, EmbeddingModel, ModelFactory, QuantizationMode,
     SingleModelConfig, MEMORY_ESTIMATES, TOTAL_MEMORY_ESTIMATE,
@@ -107,7 +109,8 @@ pub use storage::{
 
 // Pruning re-exports
 pub use pruning::{
-    ImportanceScoringMethod, PrunedEmbeddings, TokenPruningConfig, TokenPruningQuantizer,
+    ImportanceScoringMethod, MaxSimDeltaEstimate, PrunedEmbeddings, TokenPruningConfig,
+    TokenPruningQuantizer,
 };
 
 // Global warm provider re-exports (TASK-EMB-016)
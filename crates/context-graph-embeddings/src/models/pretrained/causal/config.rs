@@ -11,6 +11,11 @@ pub const CAUSAL_DIMENSION: usize = 768;
 /// 512 is sufficient and faster.
 pub const CAUSAL_MAX_TOKENS: usize = 512;
 
+/// Padded-token budget per batched forward pass (batch_size × max_len).
+///
+/// `gpu_forward_batch()` splits larger inputs into several passes.
+pub const CAUSAL_BATCH_TOKEN_BUDGET: usize = 16_384;
+
 /// Latency budget in milliseconds (P95 target).
 pub const CAUSAL_LATENCY_BUDGET_MS: u32 = 8;
 
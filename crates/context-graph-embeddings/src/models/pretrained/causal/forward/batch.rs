@@ -0,0 +1,185 @@
+//! Batched forward pass for NomicBERT.
+//!
+//! Per ARCH-GPU-06: Batch operations preferred - minimize kernel launches.
+//! Inputs are tokenized, grouped under a padded-token budget, right-padded to
+//! the longest sequence in each group, and run through the encoder as one
+//! [batch, seq, hidden] pass. Masked mean pooling is applied per row, so each
+//! output matches the single-input `gpu_forward()` result.
+
+use std::ops::Range;
+
+use candle_core::Tensor;
+use tokenizers::Tokenizer;
+
+use crate::error::{EmbeddingError, EmbeddingResult};
+use crate::models::attention::AttentionStrategy;
+use crate::types::ModelId;
+
+use super::super::config::{
+    CAUSAL_BATCH_TOKEN_BUDGET, CAUSAL_MAX_TOKENS, CAUSE_INSTRUCTION, EFFECT_INSTRUCTION,
+};
+use super::super::weights::NomicWeights;
+use super::encoder::run_encoder;
+use super::ops::{l2_normalize, mean_pooling};
+use super::compute_embeddings;
+
+/// Split sequences (sorted by length ascending) into groups whose padded size
+/// `len × max_len` stays within `budget`.
+///
+/// A single sequence longer than the budget still gets its own group.
+pub fn batch_groups(sorted_lengths: &[usize], budget: usize) -> Vec<Range<usize>> {
+    let mut groups = Vec::new();
+    let mut start = 0;
+    for (i, &len) in sorted_lengths.iter().enumerate() {
+        // Lengths are ascending, so `len` is the group's padded length
+        if i > start && (i - start + 1) * len > budget {
+            groups.push(start..i);
+            start = i;
+        }
+    }
+    if start < sorted_lengths.len() {
+        groups.push(start..sorted_lengths.len());
+    }
+    groups
+}
+
+/// GPU batch forward pass for multiple texts.
+///
+/// Returns one L2-normalized 768D vector per input, in input order. Inputs
+/// are truncated to `CAUSAL_MAX_TOKENS` like `gpu_forward()`.
+///
+/// # Errors
+///
+/// - `EmbeddingError::TokenizationError` if any input fails to tokenize
+/// - `EmbeddingError::GpuError` on tensor failures (NO CPU fallback per AP-GPU-01)
+pub fn gpu_forward_batch(
+    texts: &[&str],
+    weights: &NomicWeights,
+    tokenizer: &Tokenizer,
+    strategy: &dyn AttentionStrategy,
+) -> EmbeddingResult<Vec<Vec<f32>>> {
+    if texts.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let max_len = weights.config.max_position_embeddings.min(CAUSAL_MAX_TOKENS);
+    let sequences: Vec<Vec<u32>> = texts
+        .iter()
+        .map(|text| {
+            tokenizer
+                .encode(*text, true)
+                .map(|enc| enc.get_ids()[..enc.get_ids().len().min(max_len)].to_vec())
+                .map_err(|e| EmbeddingError::TokenizationError {
+                    model_id: ModelId::Causal,
+                    message: format!("CausalModel batch tokenization failed: {}", e),
+                })
+        })
+        .collect::<Result<_, _>>()?;
+
+    // Sort by length so each group pads to a similar length
+    let mut order: Vec<usize> = (0..sequences.len()).collect();
+    order.sort_by_key(|&i| sequences[i].len());
+    let sorted_lengths: Vec<usize> = order.iter().map(|&i| sequences[i].len()).collect();
+
+    let mut results: Vec<Vec<f32>> = vec![Vec::new(); texts.len()];
+    for group in batch_groups(&sorted_lengths, CAUSAL_BATCH_TOKEN_BUDGET) {
+        let members = &order[group];
+        let batch: Vec<&[u32]> = members.iter().map(|&i| sequences[i].as_slice()).collect();
+        for (&i, vector) in members.iter().zip(forward_padded(&batch, weights, strategy)?) {
+            results[i] = vector;
+        }
+    }
+
+    Ok(results)
+}
+
+/// Batched variant of `gpu_forward_dual()`.
+///
+/// Runs the cause-prefixed and effect-prefixed texts through
+/// [`gpu_forward_batch`] and returns (cause_vec, effect_vec) per input.
+pub fn gpu_forward_dual_batch(
+    texts: &[&str],
+    weights: &NomicWeights,
+    tokenizer: &Tokenizer,
+    strategy: &dyn AttentionStrategy,
+) -> EmbeddingResult<Vec<(Vec<f32>, Vec<f32>)>> {
+    let prefixed: Vec<String> = texts
+        .iter()
+        .map(|text| format!("{}{}", CAUSE_INSTRUCTION, text))
+        .chain(texts.iter().map(|text| format!("{}{}", EFFECT_INSTRUCTION, text)))
+        .collect();
+    let refs: Vec<&str> = prefixed.iter().map(String::as_str).collect();
+
+    let mut vectors = gpu_forward_batch(&refs, weights, tokenizer, strategy)?;
+    let effects = vectors.split_off(texts.len());
+    Ok(vectors.into_iter().zip(effects).collect())
+}
+
+/// One encoder pass over right-padded token sequences.
+fn forward_padded(
+    batch: &[&[u32]],
+    weights: &NomicWeights,
+    strategy: &dyn AttentionStrategy,
+) -> EmbeddingResult<Vec<Vec<f32>>> {
+    let device = weights.device;
+    let batch_size = batch.len();
+    let seq_len = batch.iter().map(|ids| ids.len()).max().unwrap_or(1).max(1);
+
+    // input_ids / attention_mask: [batch_size, seq_len], padding id 0 masked out
+    let mut token_ids = vec![0u32; batch_size * seq_len];
+    let mut attention_mask = vec![0.0f32; batch_size * seq_len];
+    for (row, ids) in batch.iter().enumerate() {
+        let offset = row * seq_len;
+        token_ids[offset..offset + ids.len()].copy_from_slice(ids);
+        attention_mask[offset..offset + ids.len()].fill(1.0);
+    }
+
+    let input_ids = Tensor::from_slice(&token_ids, (batch_size, seq_len), device).map_err(|e| {
+        EmbeddingError::GpuError {
+            message: format!("CausalModel batch input_ids tensor failed: {}", e),
+        }
+    })?;
+
+    let attention_mask_tensor =
+        Tensor::from_slice(&attention_mask, (batch_size, seq_len), device).map_err(|e| {
+            EmbeddingError::GpuError {
+                message: format!("CausalModel batch attention_mask tensor failed: {}", e),
+            }
+        })?;
+
+    let token_type_tensor =
+        Tensor::zeros((batch_size, seq_len), candle_core::DType::U32, device).map_err(|e| {
+            EmbeddingError::GpuError {
+                message: format!("CausalModel batch token_type tensor failed: {}", e),
+            }
+        })?;
+
+    let embeddings = compute_embeddings(&input_ids, &token_type_tensor, weights, seq_len)?;
+    let hidden_states = run_encoder(embeddings, &attention_mask_tensor, weights, strategy)?;
+    let pooled = mean_pooling(&hidden_states, &attention_mask_tensor)?;
+    let normalized = l2_normalize(&pooled)?;
+
+    normalized
+        .to_vec2()
+        .map_err(|e| EmbeddingError::GpuError {
+            message: format!("CausalModel batch to_vec2 failed: {}", e),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_groups_respect_token_budget() {
+        let lengths = [10, 20, 30, 100, 100, 100, 500];
+        let groups = batch_groups(&lengths, 300);
+
+        assert_eq!(groups, vec![0..3, 3..6, 6..7]);
+        for group in &groups {
+            let padded = group.len() * lengths[group.end - 1];
+            assert!(padded <= 300 || group.len() == 1, "group {:?} pads to {}", group, padded);
+        }
+        assert!(batch_groups(&[], 300).is_empty());
+    }
+}
@@ -0,0 +1,222 @@
+//! Chunked forward pass for inputs longer than the position limit.
+//!
+//! The token sequence is split into overlapping windows, each window is
+//! encoded and mean-pooled on its own, and the pooled vectors are combined
+//! by a length-weighted mean before L2 normalization.
+//!
+//! Special tokens ([CLS]/[SEP]) and any instruction prefix are repeated in
+//! every window so each pass sees the same framing as a single-window input.
+
+use std::ops::Range;
+
+use tokenizers::Tokenizer;
+
+use crate::error::{EmbeddingError, EmbeddingResult};
+use crate::models::attention::AttentionStrategy;
+use crate::types::ModelId;
+
+use super::super::config::{CAUSAL_MAX_TOKENS, CAUSE_INSTRUCTION, EFFECT_INSTRUCTION};
+use super::super::weights::NomicWeights;
+use super::encode_pooled;
+
+/// Window size and stride for [`gpu_forward_chunked`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkingConfig {
+    /// Tokens per window, including special tokens and instruction prefix.
+    /// Capped at the model position limit.
+    pub window_tokens: usize,
+    /// Content tokens between consecutive window starts.
+    /// `stride < window` gives overlapping windows.
+    pub stride_tokens: usize,
+}
+
+impl Default for ChunkingConfig {
+    fn default() -> Self {
+        Self {
+            window_tokens: CAUSAL_MAX_TOKENS,
+            stride_tokens: CAUSAL_MAX_TOKENS * 3 / 4,
+        }
+    }
+}
+
+/// Content spans covering `len` tokens with windows of `window` and `stride`.
+///
+/// The last window is aligned to the end of the sequence so the tail is
+/// always covered by a full window.
+pub fn window_spans(len: usize, window: usize, stride: usize) -> Vec<Range<usize>> {
+    if len <= window || window == 0 {
+        return vec![0..len];
+    }
+    let stride = stride.clamp(1, window);
+    let mut spans = Vec::new();
+    let mut start = 0;
+    while start + window < len {
+        spans.push(start..start + window);
+        start += stride;
+    }
+    spans.push(len - window..len);
+    spans
+}
+
+/// Check if `text` tokenizes to more than one window of the position limit.
+///
+/// Used by the embedding provider to route long inputs to
+/// [`gpu_forward_chunked`] instead of the truncating [`super::gpu_forward`].
+pub fn needs_chunking(
+    text: &str,
+    weights: &NomicWeights,
+    tokenizer: &Tokenizer,
+) -> EmbeddingResult<bool> {
+    let max_len = weights.config.max_position_embeddings.min(CAUSAL_MAX_TOKENS);
+    Ok(encode(tokenizer, text, true)?.len() > max_len)
+}
+
+/// GPU forward pass covering the whole input via overlapping windows.
+///
+/// Inputs that fit in one window produce the same vector as
+/// [`super::gpu_forward`]. Longer inputs are encoded per window and
+/// aggregated by length-weighted mean, then L2-normalized.
+pub fn gpu_forward_chunked(
+    text: &str,
+    weights: &NomicWeights,
+    tokenizer: &Tokenizer,
+    strategy: &dyn AttentionStrategy,
+    chunking: &ChunkingConfig,
+) -> EmbeddingResult<Vec<f32>> {
+    let pooled = chunked_pooled("", text, weights, tokenizer, strategy, chunking)?;
+    Ok(normalize(pooled))
+}
+
+/// Chunked variant of [`super::gpu_forward_dual`].
+///
+/// Cause and effect pooled vectors are aggregated separately across windows
+/// (each window carries its own instruction prefix) and normalized last.
+pub fn gpu_forward_dual_chunked(
+    text: &str,
+    weights: &NomicWeights,
+    tokenizer: &Tokenizer,
+    strategy: &dyn AttentionStrategy,
+    chunking: &ChunkingConfig,
+) -> EmbeddingResult<(Vec<f32>, Vec<f32>)> {
+    let cause = chunked_pooled(CAUSE_INSTRUCTION, text, weights, tokenizer, strategy, chunking)?;
+    let effect = chunked_pooled(EFFECT_INSTRUCTION, text, weights, tokenizer, strategy, chunking)?;
+    Ok((normalize(cause), normalize(effect)))
+}
+
+/// Length-weighted mean of per-window pooled vectors for `prefix + text`.
+fn chunked_pooled(
+    prefix: &str,
+    text: &str,
+    weights: &NomicWeights,
+    tokenizer: &Tokenizer,
+    strategy: &dyn AttentionStrategy,
+    chunking: &ChunkingConfig,
+) -> EmbeddingResult<Vec<f32>> {
+    let encoding = encode(tokenizer, &format!("{}{}", prefix, text), true)?;
+    let ids = encoding.get_ids();
+    let special = encoding.get_special_tokens_mask();
+
+    // Split into leading specials, instruction prefix, content, trailing specials
+    let lead = special.iter().take_while(|&&m| m == 1).count();
+    let trail = special[lead..].iter().rev().take_while(|&&m| m == 1).count();
+    let prefix_len = if prefix.is_empty() {
+        0
+    } else {
+        encode(tokenizer, prefix, false)?.get_ids().len()
+    };
+    let body_start = (lead + prefix_len).min(ids.len() - trail);
+    let head = &ids[..body_start];
+    let body = &ids[body_start..ids.len() - trail];
+    let tail = &ids[ids.len() - trail..];
+
+    let max_len = weights.config.max_position_embeddings.min(CAUSAL_MAX_TOKENS);
+    let window = chunking.window_tokens.min(max_len);
+    let framing = head.len() + tail.len();
+    if window <= framing {
+        return Err(EmbeddingError::ConfigError {
+            message: format!(
+                "Chunk window of {} tokens leaves no room for content after {} framing tokens",
+                window, framing
+            ),
+        });
+    }
+
+    let spans = window_spans(body.len(), window - framing, chunking.stride_tokens);
+    if spans.len() > 1 {
+        tracing::info!(
+            "E5 input of {} tokens exceeds {}-token window — encoding {} overlapping windows",
+            ids.len(),
+            window,
+            spans.len()
+        );
+    }
+
+    let mut sum = vec![0.0f32; weights.config.hidden_size];
+    let mut total = 0usize;
+    for span in spans {
+        let mut window_ids = Vec::with_capacity(framing + span.len());
+        window_ids.extend_from_slice(head);
+        window_ids.extend_from_slice(&body[span]);
+        window_ids.extend_from_slice(tail);
+        let mask = vec![1.0f32; window_ids.len()];
+
+        let pooled: Vec<f32> = encode_pooled(&window_ids, &mask, weights, strategy)?
+            .flatten_all()
+            .and_then(|t| t.to_vec1())
+            .map_err(|e| EmbeddingError::GpuError {
+                message: format!("CausalModel chunk pooled to_vec1 failed: {}", e),
+            })?;
+
+        let n = window_ids.len();
+        for (acc, v) in sum.iter_mut().zip(&pooled) {
+            *acc += v * n as f32;
+        }
+        total += n;
+    }
+
+    for v in &mut sum {
+        *v /= total as f32;
+    }
+    Ok(sum)
+}
+
+fn encode(
+    tokenizer: &Tokenizer,
+    text: &str,
+    add_special_tokens: bool,
+) -> EmbeddingResult<tokenizers::Encoding> {
+    tokenizer
+        .encode(text, add_special_tokens)
+        .map_err(|e| EmbeddingError::TokenizationError {
+            model_id: ModelId::Causal,
+            message: format!("CausalModel tokenization failed: {}", e),
+        })
+}
+
+fn normalize(mut v: Vec<f32>) -> Vec<f32> {
+    let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > f32::EPSILON {
+        for x in &mut v {
+            *x /= norm;
+        }
+    }
+    v
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_spans_cover_sequence_with_overlap() {
+        assert_eq!(window_spans(100, 200, 150), vec![0..100]);
+
+        let spans = window_spans(3000, 510, 382);
+        assert_eq!(spans.first().unwrap().start, 0);
+        assert_eq!(spans.last().unwrap().end, 3000);
+        assert!(spans.iter().all(|s| s.len() == 510));
+        for pair in spans.windows(2) {
+            assert!(pair[1].start < pair[0].end, "consecutive windows must overlap");
+        }
+    }
+}
@@ -17,6 +17,8 @@
 //! different vector representations for different instruction contexts.
 
 mod attention;
+mod batch;
+mod chunked;
 mod encoder;
 mod ops;
 
@@ -30,6 +32,10 @@ use crate::types::ModelId;
 use super::config::CAUSAL_MAX_TOKENS;
 use super::weights::NomicWeights;
 
+pub use batch::{batch_groups, gpu_forward_batch, gpu_forward_dual_batch};
+pub use chunked::{
+    gpu_forward_chunked, gpu_forward_dual_chunked, needs_chunking, window_spans, ChunkingConfig,
+};
 use encoder::run_encoder;
 pub use encoder::run_encoder_with_lora;
 pub use ops::layer_norm;
@@ -40,13 +46,15 @@ pub use ops::{l2_normalize, mean_pooling};
 /// Tokenizes input, computes embeddings (word + token_type + LayerNorm),
 /// runs encoder layers with RoPE attention and SwiGLU FFN,
 /// then mean-pools and L2-normalizes.
+///
+/// Input beyond `CAUSAL_MAX_TOKENS` is truncated; use [`gpu_forward_chunked`]
+/// to cover long documents.
 pub fn gpu_forward(
     text: &str,
     weights: &NomicWeights,
     tokenizer: &Tokenizer,
     strategy: &dyn AttentionStrategy,
 ) -> EmbeddingResult<Vec<f32>> {
-    let device = weights.device;
     let config = &weights.config;
 
     // Tokenize input text
@@ -67,8 +75,43 @@ pub fn gpu_forward(
     // Truncate to max tokens
     let max_len = config.max_position_embeddings.min(CAUSAL_MAX_TOKENS);
     let seq_len = token_ids.len().min(max_len);
-    let token_ids = &token_ids[..seq_len];
-    let attention_mask = &attention_mask[..seq_len];
+
+    let pooled = encode_pooled(
+        &token_ids[..seq_len],
+        &attention_mask[..seq_len],
+        weights,
+        strategy,
+    )?;
+
+    // L2 normalize
+    let normalized = l2_normalize(&pooled)?;
+
+    // Convert to Vec<f32>
+    let vector: Vec<f32> = normalized
+        .flatten_all()
+        .map_err(|e| EmbeddingError::GpuError {
+            message: format!("CausalModel flatten output failed: {}", e),
+        })?
+        .to_vec1()
+        .map_err(|e| EmbeddingError::GpuError {
+            message: format!("CausalModel to_vec1 failed: {}", e),
+        })?;
+
+    Ok(vector)
+}
+
+/// Encode one token window and mean-pool it (not normalized).
+///
+/// Returns a [1, hidden_size] tensor. Callers must keep `token_ids` within
+/// the position limit.
+fn encode_pooled(
+    token_ids: &[u32],
+    attention_mask: &[f32],
+    weights: &NomicWeights,
+    strategy: &dyn AttentionStrategy,
+) -> EmbeddingResult<Tensor> {
+    let device = weights.device;
+    let seq_len = token_ids.len();
 
     // Create GPU tensors
     let input_ids = Tensor::from_slice(token_ids, (1, seq_len), device).map_err(|e| {
@@ -101,23 +144,7 @@ pub fn gpu_forward(
     let hidden_states = run_encoder(embeddings, &attention_mask_tensor, weights, strategy)?;
 
     // === POOLING ===
-    let pooled = mean_pooling(&hidden_states, &attention_mask_tensor)?;
-
-    // L2 normalize
-    let normalized = l2_normalize(&pooled)?;
-
-    // Convert to Vec<f32>
-    let vector: Vec<f32> = normalized
-        .flatten_all()
-        .map_err(|e| EmbeddingError::GpuError {
-            message: format!("CausalModel flatten output failed: {}", e),
-        })?
-        .to_vec1()
-        .map_err(|e| EmbeddingError::GpuError {
-            message: format!("CausalModel to_vec1 failed: {}", e),
-        })?;
-
-    Ok(vector)
+    mean_pooling(&hidden_states, &attention_mask_tensor)
 }
 
 /// Compute embeddings: word + token_type + LayerNorm.
@@ -131,6 +158,9 @@ fn compute_embeddings(
     seq_len: usize,
 ) -> EmbeddingResult<Tensor> {
     let config = &weights.config;
+    let batch_size = input_ids.dim(0).map_err(|e| EmbeddingError::GpuError {
+        message: format!("CausalModel get batch size failed: {}", e),
+    })?;
 
     let word_embeds = weights
         .embeddings
@@ -146,7 +176,7 @@ fn compute_embeddings(
         .map_err(|e| EmbeddingError::GpuError {
             message: format!("CausalModel word embedding lookup failed: {}", e),
         })?
-        .reshape((1, seq_len, config.hidden_size))
+        .reshape((batch_size, seq_len, config.hidden_size))
         .map_err(|e| EmbeddingError::GpuError {
             message: format!("CausalModel word embedding reshape failed: {}", e),
         })?;
@@ -165,7 +195,7 @@ fn compute_embeddings(
         .map_err(|e| EmbeddingError::GpuError {
             message: format!("CausalModel token_type embedding lookup failed: {}", e),
         })?
-        .reshape((1, seq_len, config.hidden_size))
+        .reshape((batch_size, seq_len, config.hidden_size))
         .map_err(|e| EmbeddingError::GpuError {
             message: format!("CausalModel token_type embedding reshape failed: {}", e),
         })?;
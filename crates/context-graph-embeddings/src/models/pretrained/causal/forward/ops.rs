@@ -69,6 +69,9 @@ pub fn layer_norm(x: &Tensor, weight: &Tensor, bias: &Tensor, eps: f64) -> Embed
 }
 
 /// Mean pooling over sequence dimension.
+///
+/// hidden_states: [batch, seq, hidden], attention_mask: [batch, seq].
+/// Each row is divided by its own token count, so padded rows pool correctly.
 pub fn mean_pooling(hidden_states: &Tensor, attention_mask: &Tensor) -> EmbeddingResult<Tensor> {
     let mask_expanded = attention_mask
         .unsqueeze(2)
@@ -88,10 +91,15 @@ pub fn mean_pooling(hidden_states: &Tensor, attention_mask: &Tensor) -> Embeddin
         message: format!("CausalModel sum pooling failed: {}", e),
     })?;
 
+    // Per-row token count: [batch, 1]
     let mask_sum = attention_mask
-        .sum_all()
+        .sum_keepdim(1)
         .map_err(|e| EmbeddingError::GpuError {
             message: format!("CausalModel mask sum failed: {}", e),
+        })?
+        .clamp(1e-9, f64::INFINITY)
+        .map_err(|e| EmbeddingError::GpuError {
+            message: format!("CausalModel mask sum clamp failed: {}", e),
         })?;
 
     sum_hidden
@@ -21,7 +21,12 @@ use crate::models::attention::{self, AttentionStrategy, resolve_attention_mode};
 use crate::traits::{EmbeddingModel, SingleModelConfig};
 use crate::types::{InputType, ModelEmbedding, ModelId, ModelInput};
 
-use super::forward::{gpu_forward, gpu_forward_dual, gpu_forward_dual_trained, gpu_forward_single_trained};
+use super::config::{CAUSE_INSTRUCTION, EFFECT_INSTRUCTION};
+use super::forward::{
+    gpu_forward, gpu_forward_batch, gpu_forward_chunked, gpu_forward_dual,
+    gpu_forward_dual_batch, gpu_forward_dual_chunked, gpu_forward_dual_trained,
+    gpu_forward_single_trained, needs_chunking, ChunkingConfig,
+};
 use super::loader::load_nomic_weights;
 use super::weights::{NomicWeights, TrainableProjection};
 use crate::training::lora::{LoraConfig, LoraLayers};
@@ -84,7 +89,7 @@ pub(crate) enum ModelState {
 /// ```
 pub struct CausalModel {
     /// Model weights and inference engine.
-    model_state: std::sync::RwLock<ModelState>,
+    pub(crate) model_state: std::sync::RwLock<ModelState>,
 
     /// Path to model weights directory.
     model_path: PathBuf,
@@ -93,7 +98,7 @@ pub struct CausalModel {
     loaded: AtomicBool,
 
     /// Attention strategy (dense, tiled, sliding window).
-    attention_strategy: Box<dyn AttentionStrategy>,
+    pub(crate) attention_strategy: Box<dyn AttentionStrategy>,
 }
 
 impl CausalModel {
@@ -222,10 +227,22 @@ impl CausalModel {
         Ok(())
     }
 
-    /// Sequential processing of multiple inputs. Note: processes items one at a
-    /// time (no GPU batching). For true batch inference, see Kepler model.
+    /// Embed multiple inputs with batched GPU forward passes.
+    ///
+    /// Per ARCH-GPU-06: without trained weights, inputs that fit in one window
+    /// run through `gpu_forward_batch()` (grouped by a padded-token budget);
+    /// longer inputs use the chunked path. With trained LoRA weights, inputs
+    /// are processed one at a time.
     pub async fn embed_batch(&self, inputs: &[ModelInput]) -> EmbeddingResult<Vec<ModelEmbedding>> {
         self.ensure_initialized()?;
+        if inputs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        if let Some(results) = self.embed_batch_untrained(inputs)? {
+            return Ok(results);
+        }
+
         let mut results = Vec::with_capacity(inputs.len());
         for input in inputs {
             results.push(self.embed(input).await?);
@@ -233,6 +250,130 @@ impl CausalModel {
         Ok(results)
     }
 
+    /// Batched path for [`Self::embed_batch`]; `None` when trained weights are loaded.
+    fn embed_batch_untrained(
+        &self,
+        inputs: &[ModelInput],
+    ) -> EmbeddingResult<Option<Vec<ModelEmbedding>>> {
+        let state = self
+            .model_state
+            .read()
+            .map_err(|e| EmbeddingError::InternalError {
+                message: format!("CausalModel failed to acquire read lock: {}", e),
+            })?;
+
+        let ModelState::Loaded {
+            weights,
+            tokenizer,
+            trained: None,
+        } = &*state
+        else {
+            return Ok(None);
+        };
+
+        let start = std::time::Instant::now();
+        let strategy = self.attention_strategy.as_ref();
+
+        let mut texts = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            self.validate_input(input)?;
+            texts.push(format!("search_document: {}", input_text(input)?));
+        }
+
+        let mut vectors: Vec<Vec<f32>> = vec![Vec::new(); texts.len()];
+        let mut short = Vec::with_capacity(texts.len());
+        for (i, text) in texts.iter().enumerate() {
+            if needs_chunking(text, weights, tokenizer)? {
+                vectors[i] = forward_auto(text, weights, tokenizer, strategy)?;
+            } else {
+                short.push(i);
+            }
+        }
+        let short_texts: Vec<&str> = short.iter().map(|&i| texts[i].as_str()).collect();
+        for (&i, vector) in short
+            .iter()
+            .zip(gpu_forward_batch(&short_texts, weights, tokenizer, strategy)?)
+        {
+            vectors[i] = vector;
+        }
+
+        let latency_us = start.elapsed().as_micros() as u64 / inputs.len() as u64;
+        tracing::debug!(
+            batch_size = inputs.len(),
+            chunked = inputs.len() - short.len(),
+            "CausalModel batch embedding complete"
+        );
+        Ok(Some(
+            vectors
+                .into_iter()
+                .map(|vector| ModelEmbedding::new(ModelId::Causal, vector, latency_us))
+                .collect(),
+        ))
+    }
+
+    /// Embed many texts as BOTH cause and effect roles.
+    ///
+    /// Batched counterpart of [`Self::embed_dual`]: without trained weights,
+    /// uses `gpu_forward_dual_batch()` for inputs that fit in one window.
+    pub async fn embed_dual_batch(
+        &self,
+        contents: &[&str],
+    ) -> EmbeddingResult<Vec<(Vec<f32>, Vec<f32>)>> {
+        self.ensure_initialized()?;
+
+        let state = self
+            .model_state
+            .read()
+            .map_err(|e| EmbeddingError::InternalError {
+                message: format!("CausalModel failed to acquire read lock: {}", e),
+            })?;
+
+        match &*state {
+            ModelState::Loaded {
+                weights,
+                tokenizer,
+                trained,
+            } => {
+                let strategy = self.attention_strategy.as_ref();
+                if let Some(ref t) = trained {
+                    return contents
+                        .iter()
+                        .map(|content| {
+                            gpu_forward_dual_trained(
+                                content, weights, tokenizer, &t.lora, &t.projection, strategy,
+                            )
+                        })
+                        .collect();
+                }
+
+                let mut pairs: Vec<(Vec<f32>, Vec<f32>)> = vec![Default::default(); contents.len()];
+                let mut short = Vec::with_capacity(contents.len());
+                for (i, content) in contents.iter().enumerate() {
+                    let long = needs_chunking(&format!("{}{}", CAUSE_INSTRUCTION, content), weights, tokenizer)?
+                        || needs_chunking(&format!("{}{}", EFFECT_INSTRUCTION, content), weights, tokenizer)?;
+                    if long {
+                        pairs[i] = gpu_forward_dual_chunked(
+                            content, weights, tokenizer, strategy, &ChunkingConfig::default(),
+                        )?;
+                    } else {
+                        short.push(i);
+                    }
+                }
+                let short_texts: Vec<&str> = short.iter().map(|&i| contents[i]).collect();
+                for (&i, pair) in short
+                    .iter()
+                    .zip(gpu_forward_dual_batch(&short_texts, weights, tokenizer, strategy)?)
+                {
+                    pairs[i] = pair;
+                }
+                Ok(pairs)
+            }
+            ModelState::Unloaded => Err(EmbeddingError::NotInitialized {
+                model_id: ModelId::Causal,
+            }),
+        }
+    }
+
     // =========================================================================
     // ASYMMETRIC DUAL EMBEDDING METHODS
     // =========================================================================
@@ -299,7 +440,7 @@ impl CausalModel {
                 } else {
                     // No trained weights: use causal instruction prefix for base model
                     let text = format!("{}{}", instruction, content);
-                    gpu_forward(&text, weights, tokenizer, strategy)?
+                    forward_auto(&text, weights, tokenizer, strategy)?
                 };
 
                 if vec.len() != 768 {
@@ -372,7 +513,15 @@ impl CausalModel {
                         content, weights, tokenizer, &t.lora, &t.projection, strategy,
                     )?
                 } else {
-                    gpu_forward_dual(content, weights, tokenizer, strategy)?
+                    let long = needs_chunking(&format!("{}{}", CAUSE_INSTRUCTION, content), weights, tokenizer)?
+                        || needs_chunking(&format!("{}{}", EFFECT_INSTRUCTION, content), weights, tokenizer)?;
+                    if long {
+                        gpu_forward_dual_chunked(
+                            content, weights, tokenizer, strategy, &ChunkingConfig::default(),
+                        )?
+                    } else {
+                        gpu_forward_dual(content, weights, tokenizer, strategy)?
+                    }
                 };
 
                 // Validate dimensions (fail fast on implementation error)
@@ -553,24 +702,7 @@ impl EmbeddingModel for CausalModel {
         let start = std::time::Instant::now();
 
         // Extract text content
-        let text_content = match input {
-            ModelInput::Text {
-                content,
-                instruction,
-            } => {
-                let mut full = content.clone();
-                if let Some(inst) = instruction {
-                    full = format!("{} {}", inst, full);
-                }
-                full
-            }
-            _ => {
-                return Err(EmbeddingError::UnsupportedModality {
-                    model_id: ModelId::Causal,
-                    input_type: InputType::from(input),
-                });
-            }
-        };
+        let text_content = input_text(input)?;
 
         let state = self
             .model_state
@@ -589,7 +721,7 @@ impl EmbeddingModel for CausalModel {
                     // Use trained LoRA + projection (cause direction by default for single embed)
                     gpu_forward_single_trained(&prefixed, weights, tokenizer, &ts.lora, &ts.projection, true, strategy)?
                 } else {
-                    gpu_forward(&prefixed, weights, tokenizer, strategy)?
+                    forward_auto(&prefixed, weights, tokenizer, strategy)?
                 };
                 let latency_us = start.elapsed().as_micros() as u64;
                 Ok(ModelEmbedding::new(ModelId::Causal, vector, latency_us))
@@ -599,6 +731,10 @@ impl EmbeddingModel for CausalModel {
             }),
         }
     }
+
+    async fn embed_batch(&self, inputs: &[ModelInput]) -> EmbeddingResult<Vec<ModelEmbedding>> {
+        CausalModel::embed_batch(self, inputs).await
+    }
 }
 
 // SAFETY: CausalModel is Send + Sync because:
@@ -613,3 +749,35 @@ impl EmbeddingModel for CausalModel {
 // - All GPU operations are serialized through the RwLock
 unsafe impl Send for CausalModel {}
 unsafe impl Sync for CausalModel {}
+
+/// Single forward pass, switching to overlapping windows when `text` exceeds
+/// the position limit so long inputs are not silently truncated.
+fn forward_auto(
+    text: &str,
+    weights: &NomicWeights,
+    tokenizer: &Tokenizer,
+    strategy: &dyn AttentionStrategy,
+) -> EmbeddingResult<Vec<f32>> {
+    if needs_chunking(text, weights, tokenizer)? {
+        gpu_forward_chunked(text, weights, tokenizer, strategy, &ChunkingConfig::default())
+    } else {
+        gpu_forward(text, weights, tokenizer, strategy)
+    }
+}
+
+/// Text content of a model input, with any instruction prepended.
+fn input_text(input: &ModelInput) -> EmbeddingResult<String> {
+    match input {
+        ModelInput::Text {
+            content,
+            instruction,
+        } => Ok(match instruction {
+            Some(inst) => format!("{} {}", inst, content),
+            None => content.clone(),
+        }),
+        _ => Err(EmbeddingError::UnsupportedModality {
+            model_id: ModelId::Causal,
+            input_type: InputType::from(input),
+        }),
+    }
+}
@@ -57,3 +57,122 @@ async fn test_embed_as_effect_returns_768d_vector() {
         "Effect embedding must be 768D"
     );
 }
+
+#[tokio::test]
+async fn test_chunked_forward_covers_long_document() {
+    use super::forward::{gpu_forward, gpu_forward_chunked, ChunkingConfig};
+    use super::model::ModelState;
+
+    let model = create_and_load_model().await;
+    let state = model.model_state.read().unwrap();
+    let ModelState::Loaded { weights, tokenizer, .. } = &*state else {
+        panic!("model must be loaded");
+    };
+    let strategy = model.attention_strategy.as_ref();
+
+    // Head and tail discuss unrelated topics so truncation loses the tail
+    let head = "The garden was quiet in the warm afternoon sun. ".repeat(60);
+    let tail = "The database crashed because the disk filled up and writes failed. ".repeat(200);
+    let doc = format!("{}{}", head, tail);
+    let token_count = tokenizer.encode(doc.as_str(), true).unwrap().len();
+    assert!(token_count >= 3000, "synthetic document has {} tokens", token_count);
+
+    let truncated = gpu_forward(&doc, weights, tokenizer, strategy).unwrap();
+    let chunked =
+        gpu_forward_chunked(&doc, weights, tokenizer, strategy, &ChunkingConfig::default())
+            .unwrap();
+
+    let norm: f32 = chunked.iter().map(|x| x * x).sum::<f32>().sqrt();
+    assert!((norm - 1.0).abs() < 1e-4, "chunked embedding norm {}", norm);
+    assert_eq!(chunked.len(), CAUSAL_DIMENSION);
+
+    let cosine: f32 = truncated.iter().zip(&chunked).map(|(a, b)| a * b).sum();
+    assert!(
+        cosine < 0.99,
+        "chunked embedding should differ from truncated one, cosine = {}",
+        cosine
+    );
+}
+
+fn varied_length_texts(n: usize) -> Vec<String> {
+    (0..n)
+        .map(|i| {
+            format!(
+                "search_document: {}",
+                "Heavy rain caused the river to flood the lower town. ".repeat(1 + i * 3)
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_batched_forward_matches_single_input() {
+    use super::forward::{gpu_forward, gpu_forward_batch};
+    use super::model::ModelState;
+
+    let model = create_and_load_model().await;
+    let state = model.model_state.read().unwrap();
+    let ModelState::Loaded { weights, tokenizer, .. } = &*state else {
+        panic!("model must be loaded");
+    };
+    let strategy = model.attention_strategy.as_ref();
+
+    let texts = varied_length_texts(16);
+    let refs: Vec<&str> = texts.iter().map(String::as_str).collect();
+    let batched = gpu_forward_batch(&refs, weights, tokenizer, strategy).unwrap();
+    assert_eq!(batched.len(), texts.len());
+
+    for (i, text) in texts.iter().enumerate() {
+        let single = gpu_forward(text, weights, tokenizer, strategy).unwrap();
+        let max_diff = single
+            .iter()
+            .zip(&batched[i])
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0f32, f32::max);
+        assert!(max_diff < 1e-4, "input {} differs by {}", i, max_diff);
+    }
+}
+
+#[tokio::test]
+async fn test_batched_forward_throughput_at_batch_16() {
+    use super::forward::{gpu_forward, gpu_forward_batch};
+    use super::model::ModelState;
+    use std::time::Instant;
+
+    let model = create_and_load_model().await;
+    let state = model.model_state.read().unwrap();
+    let ModelState::Loaded { weights, tokenizer, .. } = &*state else {
+        panic!("model must be loaded");
+    };
+    let strategy = model.attention_strategy.as_ref();
+
+    let texts = vec!["search_document: The outage was caused by an expired TLS certificate on the load balancer."; 16];
+    const ROUNDS: u32 = 10;
+
+    // Warm up kernels before timing
+    gpu_forward_batch(&texts, weights, tokenizer, strategy).unwrap();
+    gpu_forward(texts[0], weights, tokenizer, strategy).unwrap();
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for text in &texts {
+            gpu_forward(text, weights, tokenizer, strategy).unwrap();
+        }
+    }
+    let sequential = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        gpu_forward_batch(&texts, weights, tokenizer, strategy).unwrap();
+    }
+    let batched = start.elapsed();
+
+    let speedup = sequential.as_secs_f64() / batched.as_secs_f64();
+    eprintln!(
+        "[BENCHMARK] E5 batch 16: sequential {:?}, batched {:?}, speedup {:.1}x",
+        sequential / ROUNDS,
+        batched / ROUNDS,
+        speedup
+    );
+    assert!(speedup > 3.0, "batched speedup {:.1}x must exceed 3x", speedup);
+}
@@ -68,4 +68,7 @@ impl EmbeddingModel for KeplerModel {
 
         Ok(ModelEmbedding::new(ModelId::Kepler, vector, latency_us))
     }
+    async fn embed_batch(&self, inputs: &[ModelInput]) -> EmbeddingResult<Vec<ModelEmbedding>> {
+        KeplerModel::embed_batch(self, inputs).await
+    }
 }
@@ -1,10 +1,11 @@
 //! Token embedding and pooling methods for LateInteractionModel.
 
 use crate::error::{EmbeddingError, EmbeddingResult};
+use crate::pruning::{PrunedEmbeddings, TokenPruningQuantizer};
 use crate::traits::EmbeddingModel;
 use crate::types::{InputType, ModelEmbedding, ModelId, ModelInput};
 
-use super::gpu_forward::gpu_forward_tokens;
+use super::gpu_forward::{gpu_forward_tokens, gpu_forward_tokens_with_attention};
 use super::model::LateInteractionModel;
 use super::types::{ModelState, TokenEmbeddings, LATE_INTERACTION_DIMENSION};
 
@@ -58,6 +59,65 @@ impl LateInteractionModel {
         gpu_forward_tokens(trimmed, weights, projection, tokenizer)
     }
 
+    /// Get per-token embeddings with low-importance tokens pruned.
+    ///
+    /// Token importance comes from the last encoder layer's attention
+    /// probabilities, which the forward pass already computed; they are
+    /// reduced on the GPU rather than recomputed.
+    ///
+    /// # Arguments
+    /// * `text` - Input text to tokenize and embed
+    /// * `quantizer` - Pruning configuration and scoring method
+    ///
+    /// # Errors
+    /// Same as `embed_tokens`, plus any pruning error.
+    pub async fn embed_tokens_pruned(
+        &self,
+        text: &str,
+        quantizer: &TokenPruningQuantizer,
+    ) -> EmbeddingResult<PrunedEmbeddings> {
+        if !self.is_initialized() {
+            return Err(EmbeddingError::NotInitialized {
+                model_id: self.model_id(),
+            });
+        }
+
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return Err(EmbeddingError::EmptyInput);
+        }
+
+        let state = self
+            .model_state
+            .read()
+            .map_err(|e| EmbeddingError::InternalError {
+                message: format!("LateInteractionModel failed to acquire read lock: {}", e),
+            })?;
+
+        let (weights, projection, tokenizer) = match &*state {
+            ModelState::Loaded {
+                weights,
+                projection,
+                tokenizer,
+            } => (weights, projection, tokenizer),
+            _ => {
+                return Err(EmbeddingError::NotInitialized {
+                    model_id: ModelId::LateInteraction,
+                });
+            }
+        };
+
+        let (token_embs, attention) =
+            gpu_forward_tokens_with_attention(trimmed, weights, projection, tokenizer)?;
+
+        match attention {
+            Some(probs) => {
+                quantizer.prune_with_attention(&token_embs.vectors, &probs, &token_embs.mask)
+            }
+            None => quantizer.prune(&token_embs.vectors, None),
+        }
+    }
+
     /// Pool token embeddings to single 128D vector for fusion.
     ///
     /// Uses mean pooling over valid (non-padding) tokens,
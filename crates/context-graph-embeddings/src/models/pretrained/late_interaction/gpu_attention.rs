@@ -10,6 +10,8 @@ use super::gpu_attention_ops::{
 };
 
 /// Run self-attention forward pass.
+///
+/// Returns `(output, attention_probs)`; see [`compute_attention`].
 pub(crate) fn self_attention_forward(
     hidden_states: &Tensor,
     attention: &AttentionWeights,
@@ -17,7 +19,7 @@ pub(crate) fn self_attention_forward(
     hidden_size: usize,
     num_attention_heads: usize,
     layer_idx: usize,
-) -> EmbeddingResult<Tensor> {
+) -> EmbeddingResult<(Tensor, Tensor)> {
     let (batch_size, seq_len, _hidden_size) =
         hidden_states
             .dims3()
@@ -101,7 +103,8 @@ pub(crate) fn self_attention_forward(
     )?;
 
     // Attention scores
-    let context = compute_attention(&query, &key, &value, attention_mask, head_dim, layer_idx)?;
+    let (context, attention_probs) =
+        compute_attention(&query, &key, &value, attention_mask, head_dim, layer_idx)?;
 
     // Reshape back to [batch, seq_len, hidden_size]
     let context = context
@@ -163,12 +166,15 @@ pub(crate) fn self_attention_forward(
             ),
         })?;
 
-    output
-        .broadcast_add(&attention.output_bias)
-        .map_err(|e| EmbeddingError::GpuError {
-            message: format!(
-                "LateInteractionModel layer {} output bias failed: {}",
-                layer_idx, e
-            ),
-        })
+    let output =
+        output
+            .broadcast_add(&attention.output_bias)
+            .map_err(|e| EmbeddingError::GpuError {
+                message: format!(
+                    "LateInteractionModel layer {} output bias failed: {}",
+                    layer_idx, e
+                ),
+            })?;
+
+    Ok((output, attention_probs))
 }
@@ -85,6 +85,9 @@ pub(crate) fn reshape_for_attention(
 }
 
 /// Compute attention scores and context.
+///
+/// Returns `(context, attention_probs)`, where `attention_probs` has shape
+/// [batch, heads, seq_len, seq_len].
 pub(crate) fn compute_attention(
     query: &Tensor,
     key: &Tensor,
@@ -92,7 +95,7 @@ pub(crate) fn compute_attention(
     attention_mask: &Tensor,
     head_dim: usize,
     layer_idx: usize,
-) -> EmbeddingResult<Tensor> {
+) -> EmbeddingResult<(Tensor, Tensor)> {
     let key_t = key
         .transpose(2, 3)
         .map_err(|e| EmbeddingError::GpuError {
@@ -143,12 +146,14 @@ pub(crate) fn compute_attention(
             }
         })?;
 
-    attention_probs
+    let context = attention_probs
         .matmul(value)
         .map_err(|e| EmbeddingError::GpuError {
             message: format!(
                 "LateInteractionModel layer {} context matmul failed: {}",
                 layer_idx, e
             ),
-        })
+        })?;
+
+    Ok((context, attention_probs))
 }
@@ -9,12 +9,16 @@ use super::gpu_attention::self_attention_forward;
 use super::gpu_utils::{ffn_forward, layer_norm};
 
 /// Run all encoder layers with attention mask.
+///
+/// Returns `(hidden_states, last_attention_probs)`. The last layer's
+/// attention probabilities [batch, heads, seq_len, seq_len] are kept so token
+/// pruning can score importance without recomputing attention.
 pub(crate) fn run_encoder_layers(
     mut hidden_states: Tensor,
     attention_mask_tensor: &Tensor,
     weights: &BertWeights,
     config: &BertConfig,
-) -> EmbeddingResult<Tensor> {
+) -> EmbeddingResult<(Tensor, Option<Tensor>)> {
     // Create attention mask for broadcasting: [batch, 1, 1, seq_len]
     let extended_attention_mask = attention_mask_tensor
         .unsqueeze(1)
@@ -47,29 +51,34 @@ pub(crate) fn run_encoder_layers(
             message: format!("LateInteractionModel attention mask scale failed: {}", e),
         })?;
 
+    let mut last_attention = None;
     for (layer_idx, layer) in weights.encoder_layers.iter().enumerate() {
-        hidden_states = encoder_layer_forward(
+        let (output, attention_probs) = encoder_layer_forward(
             &hidden_states,
             layer,
             &extended_attention_mask,
             config,
             layer_idx,
         )?;
+        hidden_states = output;
+        last_attention = Some(attention_probs);
     }
 
-    Ok(hidden_states)
+    Ok((hidden_states, last_attention))
 }
 
 /// Run single encoder layer forward pass.
+///
+/// Returns `(output, attention_probs)`.
 fn encoder_layer_forward(
     hidden_states: &Tensor,
     layer: &EncoderLayerWeights,
     attention_mask: &Tensor,
     config: &BertConfig,
     layer_idx: usize,
-) -> EmbeddingResult<Tensor> {
+) -> EmbeddingResult<(Tensor, Tensor)> {
     // Self-attention
-    let attention_output = self_attention_forward(
+    let (attention_output, attention_probs) = self_attention_forward(
         hidden_states,
         &layer.attention,
         attention_mask,
@@ -105,10 +114,12 @@ fn encoder_layer_forward(
         ),
     })?;
 
-    layer_norm(
+    let output = layer_norm(
         &output,
         &layer.ffn.layer_norm_weight,
         &layer.ffn.layer_norm_bias,
         config.layer_norm_eps,
-    )
+    )?;
+
+    Ok((output, attention_probs))
 }
@@ -30,6 +30,20 @@ pub(crate) fn gpu_forward_tokens(
     projection: &ColBertProjection,
     tokenizer: &Tokenizer,
 ) -> EmbeddingResult<TokenEmbeddings> {
+    gpu_forward_tokens_with_attention(text, weights, projection, tokenizer)
+        .map(|(token_embs, _)| token_embs)
+}
+
+/// Run the ColBERT forward pass, also returning the last encoder layer's
+/// attention probabilities [1, heads, seq_len, seq_len] (still on GPU).
+///
+/// The attention tensor is None only if the model has no encoder layers.
+pub(crate) fn gpu_forward_tokens_with_attention(
+    text: &str,
+    weights: &BertWeights,
+    projection: &ColBertProjection,
+    tokenizer: &Tokenizer,
+) -> EmbeddingResult<(TokenEmbeddings, Option<Tensor>)> {
     let device = weights.device();
     let config = &weights.config;
 
@@ -77,13 +91,17 @@ pub(crate) fn gpu_forward_tokens(
     )?;
 
     // === ENCODER LAYERS ===
-    let hidden_states = run_encoder_layers(embeddings, &attention_mask_tensor, weights, config)?;
+    let (hidden_states, last_attention) =
+        run_encoder_layers(embeddings, &attention_mask_tensor, weights, config)?;
 
     // === PROJECTION AND NORMALIZATION ===
     let normalized = project_and_normalize(hidden_states, projection)?;
 
     // === CONVERT TO TokenEmbeddings ===
-    convert_to_token_embeddings(normalized, token_strings, attention_mask, seq_len)
+    let token_embs =
+        convert_to_token_embeddings(normalized, token_strings, attention_mask, seq_len)?;
+
+    Ok((token_embs, last_attention))
 }
 
 /// Create GPU tensors for input.
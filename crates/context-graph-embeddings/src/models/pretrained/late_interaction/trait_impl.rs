@@ -3,6 +3,7 @@
 use async_trait::async_trait;
 
 use crate::error::{EmbeddingError, EmbeddingResult};
+use crate::pruning::{PrunedEmbeddings, TokenPruningConfig, TokenPruningQuantizer};
 use crate::traits::EmbeddingModel;
 use crate::types::{InputType, ModelEmbedding, ModelId, ModelInput};
 
@@ -76,4 +77,15 @@ impl EmbeddingModel for LateInteractionModel {
             latency_us,
         ))
     }
+
+    async fn embed_tokens_pruned(
+        &self,
+        input: &ModelInput,
+        config: &TokenPruningConfig,
+    ) -> EmbeddingResult<PrunedEmbeddings> {
+        self.validate_input(input)?;
+        let quantizer = TokenPruningQuantizer::new(config.clone())?;
+        let content = Self::extract_content(input)?;
+        LateInteractionModel::embed_tokens_pruned(self, &content, &quantizer).await
+    }
 }
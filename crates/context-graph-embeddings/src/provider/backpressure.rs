@@ -0,0 +1,356 @@
+//! Queue-depth-bounded wrapper for a MultiArrayEmbeddingProvider.
+//!
+//! [`ProductionMultiArrayProvider`](super::ProductionMultiArrayProvider) runs
+//! embedders directly rather than through the `BatchProcessor`, so nothing
+//! stops a burst of `store_memory` calls from piling up unbounded work on the
+//! GPU. [`BackpressureProvider`] counts in-flight requests per embedder against
+//! the same capacities as the batch queues (`BatchConfig::max_queue_depth`
+//! plus `BatchProcessorConfig::queue_depth_overrides`) and rejects requests
+//! that would exceed them with `CoreError::Backpressure`.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use context_graph_core::config::DeterminismMode;
+use context_graph_core::error::{CoreError, CoreResult};
+use context_graph_core::teleological::{Embedder, EmbedderMask};
+use context_graph_core::traits::{
+    EmbedderQueueDepth, EmbeddingMetadata, MultiArrayEmbeddingOutput, MultiArrayEmbeddingProvider,
+    PartialMultiArrayOutput,
+};
+use context_graph_core::types::fingerprint::NUM_EMBEDDERS;
+
+use crate::batch::BatchProcessorConfig;
+use crate::types::ModelId;
+
+/// MultiArrayEmbeddingProvider that bounds in-flight requests per embedder.
+///
+/// A request reserves one slot (one per content item for batches) on every
+/// embedder it will run, and releases them when it completes. If any
+/// embedder is at capacity the request is rejected before reaching the
+/// inner provider; requests already admitted are unaffected.
+pub struct BackpressureProvider {
+    /// The provider doing the actual embedding
+    inner: Arc<dyn MultiArrayEmbeddingProvider>,
+    /// In-flight requests per embedder, indexed by `Embedder::index()`
+    pending: [AtomicUsize; NUM_EMBEDDERS],
+    /// Capacity per embedder, indexed by `Embedder::index()`
+    capacities: [usize; NUM_EMBEDDERS],
+    /// Flush interval used for retry hints (milliseconds)
+    max_wait_ms: u64,
+    /// Batch size used for retry hints
+    max_batch_size: usize,
+}
+
+impl BackpressureProvider {
+    /// Wrap `inner`, taking per-embedder capacities from `config`.
+    pub fn new(inner: Arc<dyn MultiArrayEmbeddingProvider>, config: &BatchProcessorConfig) -> Self {
+        let capacities = std::array::from_fn(|idx| {
+            let embedder = Embedder::from_index(idx).expect("index < NUM_EMBEDDERS");
+            config.queue_capacity(ModelId::from(embedder)).max(1)
+        });
+        Self {
+            inner,
+            pending: std::array::from_fn(|_| AtomicUsize::new(0)),
+            capacities,
+            max_wait_ms: config.batch_config.max_wait_ms.max(1),
+            max_batch_size: config.batch_config.max_batch_size.max(1),
+        }
+    }
+
+    /// In-flight requests for `embedder`.
+    pub fn depth(&self, embedder: Embedder) -> usize {
+        self.pending[embedder.index()].load(Ordering::SeqCst)
+    }
+
+    /// Capacity for `embedder`.
+    pub fn capacity(&self, embedder: Embedder) -> usize {
+        self.capacities[embedder.index()]
+    }
+
+    /// Reserve `count` slots on every embedder in `mask`.
+    ///
+    /// All-or-nothing: if any embedder lacks room, slots already taken are
+    /// released and `CoreError::Backpressure` is returned.
+    fn reserve(&self, mask: EmbedderMask, count: usize) -> CoreResult<Reservation<'_>> {
+        let mut reserved = EmbedderMask::new();
+        for embedder in mask.iter() {
+            let idx = embedder.index();
+            let capacity = self.capacities[idx];
+            let admitted =
+                self.pending[idx].fetch_update(Ordering::SeqCst, Ordering::SeqCst, |d| {
+                    (d + count <= capacity).then_some(d + count)
+                });
+            match admitted {
+                Ok(_) => reserved.set(embedder),
+                Err(queue_depth) => {
+                    drop(Reservation {
+                        provider: self,
+                        mask: reserved,
+                        count,
+                    });
+                    let retry_after_ms = self.retry_after_hint_ms(queue_depth);
+                    tracing::debug!(
+                        embedder = ?embedder,
+                        queue_depth,
+                        capacity,
+                        retry_after_ms,
+                        "Rejecting embedding request: embedder at capacity"
+                    );
+                    return Err(CoreError::Backpressure {
+                        queue_depth,
+                        retry_after_ms,
+                    });
+                }
+            }
+        }
+        Ok(Reservation {
+            provider: self,
+            mask: reserved,
+            count,
+        })
+    }
+
+    /// One flush interval per full batch ahead of a new request, matching
+    /// `BatchQueue::retry_after_hint_ms`.
+    fn retry_after_hint_ms(&self, queue_depth: usize) -> u64 {
+        let batches_ahead = (queue_depth + 1).div_ceil(self.max_batch_size) as u64;
+        self.max_wait_ms * batches_ahead
+    }
+}
+
+/// Slots held by an admitted request; released on drop.
+struct Reservation<'a> {
+    provider: &'a BackpressureProvider,
+    mask: EmbedderMask,
+    count: usize,
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        for embedder in self.mask.iter() {
+            self.provider.pending[embedder.index()].fetch_sub(self.count, Ordering::SeqCst);
+        }
+    }
+}
+
+#[async_trait]
+impl MultiArrayEmbeddingProvider for BackpressureProvider {
+    async fn embed_all(&self, content: &str) -> CoreResult<MultiArrayEmbeddingOutput> {
+        let _slots = self.reserve(EmbedderMask::all(), 1)?;
+        self.inner.embed_all(content).await
+    }
+
+    async fn embed_all_with_metadata(
+        &self,
+        content: &str,
+        metadata: EmbeddingMetadata,
+    ) -> CoreResult<MultiArrayEmbeddingOutput> {
+        let _slots = self.reserve(EmbedderMask::all(), 1)?;
+        self.inner.embed_all_with_metadata(content, metadata).await
+    }
+
+    async fn embed_batch_all(
+        &self,
+        contents: &[String],
+        metadata: &[EmbeddingMetadata],
+    ) -> CoreResult<Vec<MultiArrayEmbeddingOutput>> {
+        let _slots = self.reserve(EmbedderMask::all(), contents.len())?;
+        self.inner.embed_batch_all(contents, metadata).await
+    }
+
+    async fn embed_selective(
+        &self,
+        content: &str,
+        mask: EmbedderMask,
+    ) -> CoreResult<PartialMultiArrayOutput> {
+        let _slots = self.reserve(mask, 1)?;
+        self.inner.embed_selective(content, mask).await
+    }
+
+    async fn embed_selective_with_metadata(
+        &self,
+        content: &str,
+        mask: EmbedderMask,
+        metadata: EmbeddingMetadata,
+    ) -> CoreResult<PartialMultiArrayOutput> {
+        let _slots = self.reserve(mask, 1)?;
+        self.inner
+            .embed_selective_with_metadata(content, mask, metadata)
+            .await
+    }
+
+    async fn embed_e1_only(&self, content: &str) -> CoreResult<Vec<f32>> {
+        let _slots = self.reserve(EmbedderMask::from_slice(&[Embedder::Semantic]), 1)?;
+        self.inner.embed_e1_only(content).await
+    }
+
+    async fn embed_e5_dual(&self, content: &str) -> CoreResult<(Vec<f32>, Vec<f32>)> {
+        let _slots = self.reserve(EmbedderMask::from_slice(&[Embedder::Causal]), 1)?;
+        self.inner.embed_e5_dual(content).await
+    }
+
+    async fn embed_e8_dual(&self, content: &str) -> CoreResult<(Vec<f32>, Vec<f32>)> {
+        let _slots = self.reserve(EmbedderMask::from_slice(&[Embedder::Graph]), 1)?;
+        self.inner.embed_e8_dual(content).await
+    }
+
+    async fn embed_e11_only(&self, content: &str) -> CoreResult<Vec<f32>> {
+        let _slots = self.reserve(EmbedderMask::from_slice(&[Embedder::Entity]), 1)?;
+        self.inner.embed_e11_only(content).await
+    }
+
+    fn dimensions(&self) -> [usize; NUM_EMBEDDERS] {
+        self.inner.dimensions()
+    }
+
+    fn model_ids(&self) -> [&str; NUM_EMBEDDERS] {
+        self.inner.model_ids()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_status(&self) -> [bool; NUM_EMBEDDERS] {
+        self.inner.health_status()
+    }
+
+    fn queue_depths(&self) -> Vec<EmbedderQueueDepth> {
+        Embedder::all()
+            .map(|embedder| EmbedderQueueDepth {
+                embedder,
+                depth: self.depth(embedder),
+                capacity: self.capacity(embedder),
+            })
+            .collect()
+    }
+
+    fn determinism(&self) -> Option<DeterminismMode> {
+        self.inner.determinism()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use context_graph_core::stubs::StubMultiArrayProvider;
+    use tokio::sync::Semaphore;
+
+    /// Stub provider whose `embed_all` blocks until the gate is opened.
+    struct GatedProvider {
+        inner: StubMultiArrayProvider,
+        gate: Arc<Semaphore>,
+    }
+
+    #[async_trait]
+    impl MultiArrayEmbeddingProvider for GatedProvider {
+        async fn embed_all(&self, content: &str) -> CoreResult<MultiArrayEmbeddingOutput> {
+            let _permit = self.gate.acquire().await.expect("gate closed");
+            self.inner.embed_all(content).await
+        }
+
+        async fn embed_batch_all(
+            &self,
+            contents: &[String],
+            metadata: &[EmbeddingMetadata],
+        ) -> CoreResult<Vec<MultiArrayEmbeddingOutput>> {
+            self.inner.embed_batch_all(contents, metadata).await
+        }
+
+        fn model_ids(&self) -> [&str; NUM_EMBEDDERS] {
+            self.inner.model_ids()
+        }
+
+        fn is_ready(&self) -> bool {
+            self.inner.is_ready()
+        }
+
+        fn health_status(&self) -> [bool; NUM_EMBEDDERS] {
+            self.inner.health_status()
+        }
+    }
+
+    fn gated_provider(capacity: usize) -> (Arc<BackpressureProvider>, Arc<Semaphore>) {
+        let gate = Arc::new(Semaphore::new(0));
+        let inner = Arc::new(GatedProvider {
+            inner: StubMultiArrayProvider::new(),
+            gate: Arc::clone(&gate),
+        });
+        let mut config = BatchProcessorConfig::default();
+        config.batch_config.max_queue_depth = capacity;
+        (Arc::new(BackpressureProvider::new(inner, &config)), gate)
+    }
+
+    #[tokio::test]
+    async fn test_rejects_request_beyond_capacity_and_completes_queued() {
+        let (provider, gate) = gated_provider(10);
+
+        let mut queued = Vec::new();
+        for i in 0..10 {
+            let provider = Arc::clone(&provider);
+            queued.push(tokio::spawn(async move {
+                provider.embed_all(&format!("memory {i}")).await
+            }));
+        }
+        while provider.depth(Embedder::Semantic) < 10 {
+            tokio::task::yield_now().await;
+        }
+
+        let err = provider.embed_all("memory 10").await.unwrap_err();
+        assert!(matches!(
+            err,
+            CoreError::Backpressure { queue_depth: 10, retry_after_ms } if retry_after_ms > 0
+        ));
+
+        gate.add_permits(10);
+        for handle in queued {
+            assert!(
+                handle.await.unwrap().is_ok(),
+                "Queued requests must complete"
+            );
+        }
+        assert_eq!(provider.depth(Embedder::Semantic), 0);
+        assert!(provider.embed_all("after drain").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_rejected_request_releases_partial_reservation() {
+        let gate = Arc::new(Semaphore::new(0));
+        let inner = Arc::new(GatedProvider {
+            inner: StubMultiArrayProvider::new(),
+            gate: Arc::clone(&gate),
+        });
+        let mut config = BatchProcessorConfig::default();
+        config.queue_depth_overrides.insert(ModelId::Causal, 1);
+        let provider = Arc::new(BackpressureProvider::new(inner, &config));
+
+        let held = {
+            let provider = Arc::clone(&provider);
+            tokio::spawn(async move { provider.embed_all("holds causal").await })
+        };
+        while provider.depth(Embedder::Causal) < 1 {
+            tokio::task::yield_now().await;
+        }
+
+        assert!(provider.embed_all("rejected").await.is_err());
+        assert_eq!(
+            provider.depth(Embedder::Semantic),
+            1,
+            "Rejection must not leak slots"
+        );
+
+        let depths = provider.queue_depths();
+        assert_eq!(depths.len(), NUM_EMBEDDERS);
+        let causal = depths
+            .iter()
+            .find(|d| d.embedder == Embedder::Causal)
+            .unwrap();
+        assert_eq!((causal.depth, causal.capacity), (1, 1));
+
+        gate.add_permits(1);
+        assert!(held.await.unwrap().is_ok());
+    }
+}
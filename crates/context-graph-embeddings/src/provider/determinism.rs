@@ -0,0 +1,336 @@
+//! Reproducible-output wrapper for a MultiArrayEmbeddingProvider.
+//!
+//! GPU inference is not bit-reproducible by default (see
+//! [`DeterminismMode`]): the same content embedded twice differs by ~1e-5,
+//! which breaks byte-exact snapshot tests and audit replays.
+//! [`DeterministicProvider`] removes the sources this process controls:
+//!
+//! - Reduced-precision (TF32/FP16/BF16 accumulate) GEMMs are disabled in
+//!   candle, so matmul reductions run in full precision.
+//! - The device RNG is reseeded with `mode.seed` before every request, so
+//!   any sampling sees the same stream regardless of earlier requests.
+//! - Requests run one at a time, so kernels from concurrent requests never
+//!   interleave on the device and each request sees the same workspace.
+//! - Every output value is rounded to `mode.decimals` places, then vectors
+//!   that were unit-normalized are renormalized, so residual noise below
+//!   the precision never reaches storage or cache keys.
+//!
+//! cuBLAS only picks deterministic algorithms when `CUBLAS_WORKSPACE_CONFIG`
+//! (e.g. `:4096:8`) is set before the CUDA context is created, which has to
+//! happen in the process environment; a warning is logged when it is missing.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use context_graph_core::config::DeterminismMode;
+use context_graph_core::error::CoreResult;
+use context_graph_core::teleological::EmbedderMask;
+use context_graph_core::traits::{
+    EmbedderQueueDepth, EmbeddingMetadata, MultiArrayEmbeddingOutput, MultiArrayEmbeddingProvider,
+    PartialMultiArrayOutput,
+};
+use context_graph_core::types::fingerprint::{SemanticFingerprint, SparseVector, NUM_EMBEDDERS};
+
+/// Environment variable cuBLAS reads to select deterministic workspaces.
+pub const CUBLAS_WORKSPACE_CONFIG: &str = "CUBLAS_WORKSPACE_CONFIG";
+
+/// A vector whose norm is this close to 1 is treated as unit-normalized.
+const UNIT_NORM_TOLERANCE: f64 = 1e-3;
+
+/// Force full-precision GEMMs and check the cuBLAS workspace setting.
+///
+/// Process-wide and idempotent. Called by [`DeterministicProvider::new`];
+/// call it earlier (before models load) to cover model warmup as well.
+pub fn enable_deterministic_kernels() {
+    candle_core::cuda::set_gemm_reduced_precision_f32(false);
+    candle_core::cuda::set_gemm_reduced_precision_f16(false);
+    candle_core::cuda::set_gemm_reduced_precision_bf16(false);
+
+    if std::env::var_os(CUBLAS_WORKSPACE_CONFIG).is_none() {
+        tracing::warn!(
+            "Embedding determinism enabled but {} is not set; cuBLAS may still pick \
+             nondeterministic algorithms. Set {}=:4096:8 before starting the server.",
+            CUBLAS_WORKSPACE_CONFIG,
+            CUBLAS_WORKSPACE_CONFIG
+        );
+    }
+}
+
+/// Round `values` to `decimals` places, then renormalize if they were unit length.
+///
+/// Zero vectors and vectors of other norms are only rounded.
+pub fn stabilize_dense(values: &mut [f32], decimals: u32) {
+    let scale = 10f64.powi(decimals as i32);
+    let mut norm_sq = 0.0f64;
+    for v in values.iter_mut() {
+        let rounded = (f64::from(*v) * scale).round() / scale;
+        *v = rounded as f32;
+        norm_sq += rounded * rounded;
+    }
+
+    let norm = norm_sq.sqrt();
+    if norm > 0.0 && (norm - 1.0).abs() < UNIT_NORM_TOLERANCE {
+        for v in values.iter_mut() {
+            *v = (f64::from(*v) / norm) as f32;
+        }
+    }
+}
+
+/// Round sparse activations to `decimals` places.
+fn stabilize_sparse(sparse: &mut SparseVector, decimals: u32) {
+    let scale = 10f64.powi(decimals as i32);
+    for v in sparse.values.iter_mut() {
+        *v = ((f64::from(*v) * scale).round() / scale) as f32;
+    }
+}
+
+/// Apply [`stabilize_dense`] / sparse rounding to every space in `fp`.
+pub fn stabilize_fingerprint(fp: &mut SemanticFingerprint, decimals: u32) {
+    for dense in [
+        &mut fp.e1_semantic,
+        &mut fp.e2_temporal_recent,
+        &mut fp.e3_temporal_periodic,
+        &mut fp.e4_temporal_positional,
+        &mut fp.e5_causal_as_cause,
+        &mut fp.e5_causal_as_effect,
+        &mut fp.e5_causal,
+        &mut fp.e7_code,
+        &mut fp.e8_graph_as_source,
+        &mut fp.e8_graph_as_target,
+        &mut fp.e8_graph,
+        &mut fp.e9_hdc,
+        &mut fp.e10_multimodal_paraphrase,
+        &mut fp.e10_multimodal_as_context,
+        &mut fp.e11_entity,
+    ] {
+        stabilize_dense(dense, decimals);
+    }
+    for token in fp.e12_late_interaction.iter_mut() {
+        stabilize_dense(token, decimals);
+    }
+    stabilize_sparse(&mut fp.e6_sparse, decimals);
+    stabilize_sparse(&mut fp.e13_splade, decimals);
+}
+
+/// MultiArrayEmbeddingProvider producing bit-reproducible outputs.
+///
+/// Wrap the real provider with this when `[embedding.determinism]` is
+/// enabled. The throughput cost is documented on [`DeterminismMode`].
+pub struct DeterministicProvider {
+    /// The provider doing the actual embedding
+    inner: Arc<dyn MultiArrayEmbeddingProvider>,
+    /// Active settings (always `enabled`)
+    mode: DeterminismMode,
+    /// Held for the duration of each request so requests never overlap
+    serial: Mutex<()>,
+}
+
+impl DeterministicProvider {
+    /// Wrap `inner` and switch the process to deterministic kernels.
+    pub fn new(inner: Arc<dyn MultiArrayEmbeddingProvider>, mode: DeterminismMode) -> Self {
+        enable_deterministic_kernels();
+        tracing::info!(
+            decimals = mode.decimals,
+            seed = mode.seed,
+            "Deterministic embedding enabled (requests serialized)"
+        );
+        Self {
+            inner,
+            mode: DeterminismMode {
+                enabled: true,
+                ..mode
+            },
+            serial: Mutex::new(()),
+        }
+    }
+
+    /// Reset the device RNG so every request samples the same stream.
+    fn reseed(&self) {
+        if !crate::gpu::is_gpu_available() {
+            return;
+        }
+        if let Err(e) = crate::gpu::device().set_seed(self.mode.seed) {
+            tracing::warn!(error = %e, "Failed to reseed GPU RNG for deterministic embedding");
+        }
+    }
+
+    fn stabilize(&self, values: &mut [f32]) {
+        stabilize_dense(values, self.mode.decimals);
+    }
+}
+
+#[async_trait]
+impl MultiArrayEmbeddingProvider for DeterministicProvider {
+    async fn embed_all(&self, content: &str) -> CoreResult<MultiArrayEmbeddingOutput> {
+        let _serial = self.serial.lock().await;
+        self.reseed();
+        let mut output = self.inner.embed_all(content).await?;
+        stabilize_fingerprint(&mut output.fingerprint, self.mode.decimals);
+        Ok(output)
+    }
+
+    async fn embed_all_with_metadata(
+        &self,
+        content: &str,
+        metadata: EmbeddingMetadata,
+    ) -> CoreResult<MultiArrayEmbeddingOutput> {
+        let _serial = self.serial.lock().await;
+        self.reseed();
+        let mut output = self.inner.embed_all_with_metadata(content, metadata).await?;
+        stabilize_fingerprint(&mut output.fingerprint, self.mode.decimals);
+        Ok(output)
+    }
+
+    async fn embed_batch_all(
+        &self,
+        contents: &[String],
+        metadata: &[EmbeddingMetadata],
+    ) -> CoreResult<Vec<MultiArrayEmbeddingOutput>> {
+        let _serial = self.serial.lock().await;
+        self.reseed();
+        let mut outputs = self.inner.embed_batch_all(contents, metadata).await?;
+        for output in outputs.iter_mut() {
+            stabilize_fingerprint(&mut output.fingerprint, self.mode.decimals);
+        }
+        Ok(outputs)
+    }
+
+    async fn embed_selective(
+        &self,
+        content: &str,
+        mask: EmbedderMask,
+    ) -> CoreResult<PartialMultiArrayOutput> {
+        let _serial = self.serial.lock().await;
+        self.reseed();
+        let mut output = self.inner.embed_selective(content, mask).await?;
+        stabilize_fingerprint(&mut output.fingerprint, self.mode.decimals);
+        Ok(output)
+    }
+
+    async fn embed_selective_with_metadata(
+        &self,
+        content: &str,
+        mask: EmbedderMask,
+        metadata: EmbeddingMetadata,
+    ) -> CoreResult<PartialMultiArrayOutput> {
+        let _serial = self.serial.lock().await;
+        self.reseed();
+        let mut output = self
+            .inner
+            .embed_selective_with_metadata(content, mask, metadata)
+            .await?;
+        stabilize_fingerprint(&mut output.fingerprint, self.mode.decimals);
+        Ok(output)
+    }
+
+    async fn embed_e1_only(&self, content: &str) -> CoreResult<Vec<f32>> {
+        let _serial = self.serial.lock().await;
+        self.reseed();
+        let mut vector = self.inner.embed_e1_only(content).await?;
+        self.stabilize(&mut vector);
+        Ok(vector)
+    }
+
+    async fn embed_e5_dual(&self, content: &str) -> CoreResult<(Vec<f32>, Vec<f32>)> {
+        let _serial = self.serial.lock().await;
+        self.reseed();
+        let (mut cause, mut effect) = self.inner.embed_e5_dual(content).await?;
+        self.stabilize(&mut cause);
+        self.stabilize(&mut effect);
+        Ok((cause, effect))
+    }
+
+    async fn embed_e8_dual(&self, content: &str) -> CoreResult<(Vec<f32>, Vec<f32>)> {
+        let _serial = self.serial.lock().await;
+        self.reseed();
+        let (mut source, mut target) = self.inner.embed_e8_dual(content).await?;
+        self.stabilize(&mut source);
+        self.stabilize(&mut target);
+        Ok((source, target))
+    }
+
+    async fn embed_e11_only(&self, content: &str) -> CoreResult<Vec<f32>> {
+        let _serial = self.serial.lock().await;
+        self.reseed();
+        let mut vector = self.inner.embed_e11_only(content).await?;
+        self.stabilize(&mut vector);
+        Ok(vector)
+    }
+
+    fn dimensions(&self) -> [usize; NUM_EMBEDDERS] {
+        self.inner.dimensions()
+    }
+
+    fn model_ids(&self) -> [&str; NUM_EMBEDDERS] {
+        self.inner.model_ids()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_status(&self) -> [bool; NUM_EMBEDDERS] {
+        self.inner.health_status()
+    }
+
+    fn queue_depths(&self) -> Vec<EmbedderQueueDepth> {
+        self.inner.queue_depths()
+    }
+
+    fn determinism(&self) -> Option<DeterminismMode> {
+        Some(self.mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use context_graph_core::stubs::StubMultiArrayProvider;
+
+    fn bits(values: &[f32]) -> Vec<u32> {
+        values.iter().map(|v| v.to_bits()).collect()
+    }
+
+    #[test]
+    fn test_stabilize_dense_absorbs_low_order_noise() {
+        let mut a = vec![0.6f32, 0.8, 0.0];
+        let mut b = vec![0.6f32 + 2e-7, 0.8 - 3e-7, 1e-8];
+        stabilize_dense(&mut a, 5);
+        stabilize_dense(&mut b, 5);
+        assert_eq!(bits(&a), bits(&b));
+        let norm: f32 = a.iter().map(|v| v * v).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-6, "unit vectors stay unit, got {}", norm);
+
+        // Zero and non-unit vectors are rounded but not rescaled
+        let mut zero = vec![0.0f32; 4];
+        stabilize_dense(&mut zero, 5);
+        assert!(zero.iter().all(|v| *v == 0.0));
+        let mut scaled = vec![3.141_59f32, 2.0];
+        stabilize_dense(&mut scaled, 2);
+        assert_eq!(scaled, vec![3.14, 2.0]);
+    }
+
+    #[tokio::test]
+    async fn test_wrapper_reports_mode_and_repeats_outputs() {
+        let mode = DeterminismMode {
+            decimals: 4,
+            ..DeterminismMode::enabled()
+        };
+        let provider = DeterministicProvider::new(Arc::new(StubMultiArrayProvider::new()), mode);
+        assert_eq!(provider.determinism(), Some(mode));
+
+        let first = provider.embed_all("same input").await.unwrap().fingerprint;
+        for _ in 0..4 {
+            let again = provider.embed_all("same input").await.unwrap().fingerprint;
+            assert_eq!(bits(&again.e1_semantic), bits(&first.e1_semantic));
+            assert_eq!(bits(&again.e7_code), bits(&first.e7_code));
+            assert_eq!(bits(&again.e13_splade.values), bits(&first.e13_splade.values));
+        }
+        assert!(first.e13_splade.values.iter().all(|v| {
+            let scaled = f64::from(*v) * 1e4;
+            (scaled - scaled.round()).abs() < 1e-2
+        }));
+    }
+}
@@ -3,6 +3,8 @@
 //! This module provides the core abstractions for embedding providers:
 //! - [`EmbeddingProvider`]: Trait for single-model embedding providers
 //! - [`ProductionMultiArrayProvider`]: Production 13-embedder orchestrator
+//! - [`BackpressureProvider`]: Bounds in-flight requests per embedder
+//! - [`DeterministicProvider`]: Bit-reproducible outputs for tests and audits
 //! - [`CausalHintProvider`]: LLM-based causal hints for E5 enhancement
 //!
 //! # Architecture
@@ -28,10 +30,17 @@
 //! └── timeout() -> Duration                 // Get timeout duration
 //! ```
 
+mod backpressure;
 mod causal_hint;
+mod determinism;
 mod multi_array;
 
+pub use backpressure::BackpressureProvider;
 pub use causal_hint::{CausalHintProvider, ExtractionStatus, NoOpCausalHintProvider};
+pub use determinism::{
+    enable_deterministic_kernels, stabilize_dense, stabilize_fingerprint, DeterministicProvider,
+    CUBLAS_WORKSPACE_CONFIG,
+};
 pub use multi_array::ProductionMultiArrayProvider;
 
 use async_trait::async_trait;
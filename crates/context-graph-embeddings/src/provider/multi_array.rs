@@ -54,8 +54,9 @@ use tokio::sync::RwLock;
 use context_graph_core::error::{CoreError, CoreResult};
 use context_graph_core::traits::{
     CausalHint, EmbeddingMetadata, MultiArrayEmbeddingOutput, MultiArrayEmbeddingProvider,
-    SingleEmbedder, SparseEmbedder, TokenEmbedder,
+    PartialMultiArrayOutput, SingleEmbedder, SparseEmbedder, TokenEmbedder,
 };
+use context_graph_core::teleological::{Embedder, EmbedderMask};
 use context_graph_core::types::fingerprint::{
     SemanticFingerprint, SparseVector, E11_DIM, E12_TOKEN_DIM, E1_DIM, E2_DIM, E3_DIM, E4_DIM,
     E7_DIM, E9_DIM, NUM_EMBEDDERS,
@@ -66,6 +67,7 @@ use crate::config::GpuConfig;
 use crate::error::EmbeddingResult;
 use crate::models::pretrained::{CausalModel, ContextualModel, GraphModel};
 use crate::models::DefaultModelFactory;
+use crate::pruning::TokenPruningConfig;
 use crate::traits::{EmbeddingModel, ModelFactory, SingleModelConfig};
 use crate::types::{ModelId, ModelInput};
 
@@ -129,9 +131,7 @@ impl DenseEmbedderAdapter {
             })?,
         };
 
-        let embedding = model.embed(&input).await.map_err(|e| {
-            CoreError::Embedding(format!("Embedding failed for {:?}: {}", self.model_id, e))
-        })?;
+        let embedding = model.embed(&input).await.map_err(CoreError::from)?;
 
         Ok(embedding.into_vec())
     }
@@ -226,12 +226,7 @@ impl SparseEmbedder for SparseEmbedderAdapter {
 
         // Call embed_sparse() to get actual sparse vocabulary indices and weights
         // NOT embed() which returns a 1536D projected dense vector
-        let (indices, values) = model.embed_sparse(&input).await.map_err(|e| {
-            CoreError::Embedding(format!(
-                "Sparse embedding failed for {:?}: {}",
-                self.model_id, e
-            ))
-        })?;
+        let (indices, values) = model.embed_sparse(&input).await.map_err(CoreError::from)?;
 
         SparseVector::new(indices, values)
             .map_err(|e| CoreError::Internal(format!("Failed to create sparse vector: {}", e)))
@@ -302,12 +297,7 @@ impl TokenEmbedder for TokenEmbedderAdapter {
             message: e.to_string(),
         })?;
 
-        let embedding = model.embed(&input).await.map_err(|e| {
-            CoreError::Embedding(format!(
-                "Token embedding failed for {:?}: {}",
-                self.model_id, e
-            ))
-        })?;
+        let embedding = model.embed(&input).await.map_err(CoreError::from)?;
 
         // For ColBERT, the model produces [num_tokens, 128] tensor
         // We reshape the flat vector into token embeddings
@@ -334,6 +324,51 @@ impl TokenEmbedder for TokenEmbedderAdapter {
         Ok(tokens)
     }
 
+    async fn embed_tokens_pruned(
+        &self,
+        content: &str,
+        target_compression: f32,
+    ) -> CoreResult<Vec<Vec<f32>>> {
+        if content.is_empty() {
+            return Err(CoreError::ValidationError {
+                field: "content".to_string(),
+                message: "Content cannot be empty".to_string(),
+            });
+        }
+
+        let config = TokenPruningConfig::with_compression(target_compression).map_err(|e| {
+            CoreError::ValidationError {
+                field: "e12_pruning".to_string(),
+                message: e.to_string(),
+            }
+        })?;
+
+        let model = self.model.read().await;
+        if !model.is_initialized() {
+            return Err(CoreError::Internal(format!(
+                "Model {:?} not initialized",
+                self.model_id
+            )));
+        }
+
+        let input = ModelInput::text(content).map_err(|e| CoreError::ValidationError {
+            field: "content".to_string(),
+            message: e.to_string(),
+        })?;
+
+        let pruned = model
+            .embed_tokens_pruned(&input, &config)
+            .await
+            .map_err(CoreError::from)?;
+
+        tracing::debug!(
+            retained = pruned.token_count(),
+            retained_ratio = pruned.retained_ratio,
+            "E12 tokens pruned"
+        );
+        Ok(pruned.embeddings)
+    }
+
     fn is_ready(&self) -> bool {
         // EMB-6 FIX: Delegate to model's is_initialized()
         match self.model.try_read() {
@@ -405,9 +440,10 @@ impl CausalDualEmbedderAdapter {
             ));
         }
 
-        self.model.embed_dual(content).await.map_err(|e| {
-            CoreError::Embedding(format!("E5 dual embedding failed: {}", e))
-        })
+        self.model
+            .embed_dual(content)
+            .await
+            .map_err(CoreError::from)
     }
 
     /// Check if the model is ready for embedding.
@@ -447,12 +483,11 @@ impl CausalDualEmbedderAdapter {
         let guidance = hint.and_then(|h| h.to_guidance());
 
         // Embed with LLM-guided marker detection
-        let (mut cause_vec, mut effect_vec) = self.model
+        let (mut cause_vec, mut effect_vec) = self
+            .model
             .embed_dual_guided(content, guidance.as_ref())
             .await
-            .map_err(|e| {
-                CoreError::Embedding(format!("E5 guided dual embedding failed: {}", e))
-            })?;
+            .map_err(CoreError::from)?;
 
         // KEEP the direction bias (complementary to marker guidance)
         if let Some(hint) = hint {
@@ -547,9 +582,10 @@ impl GraphDualEmbedderAdapter {
             ));
         }
 
-        self.model.embed_dual(content).await.map_err(|e| {
-            CoreError::Embedding(format!("E8 dual embedding failed: {}", e))
-        })
+        self.model
+            .embed_dual(content)
+            .await
+            .map_err(CoreError::from)
     }
 
     /// Check if the model is ready for embedding.
@@ -616,9 +652,10 @@ impl ContextualDualEmbedderAdapter {
             ));
         }
 
-        self.model.embed_dual(content).await.map_err(|e| {
-            CoreError::Embedding(format!("E10 dual embedding failed: {}", e))
-        })
+        self.model
+            .embed_dual(content)
+            .await
+            .map_err(CoreError::from)
     }
 
     /// Check if the model is ready for embedding.
@@ -889,6 +926,212 @@ impl ProductionMultiArrayProvider {
         (result, duration)
     }
 
+    /// Like `timed_embed`, but when `selected` is false the future is dropped
+    /// without being polled, so the model is never invoked.
+    async fn timed_embed_if<F, T>(
+        selected: bool,
+        embedder_name: &str,
+        fut: F,
+    ) -> (Result<Option<T>, CoreError>, Duration)
+    where
+        F: std::future::Future<Output = Result<T, CoreError>>,
+    {
+        if !selected {
+            return (Ok(None), Duration::ZERO);
+        }
+        let (result, duration) = Self::timed_embed(embedder_name, fut).await;
+        (result.map(Some), duration)
+    }
+
+    /// Shared body of `embed_selective` and `embed_selective_with_metadata`.
+    ///
+    /// Selected embedders run in parallel exactly as in `embed_all`; the
+    /// others are skipped without touching their models, and their spaces
+    /// are left empty in the returned fingerprint.
+    async fn embed_masked(
+        &self,
+        content: &str,
+        mask: EmbedderMask,
+        metadata: Option<&EmbeddingMetadata>,
+    ) -> CoreResult<PartialMultiArrayOutput> {
+        if content.is_empty() {
+            return Err(CoreError::ValidationError {
+                field: "content".to_string(),
+                message: "Content cannot be empty".to_string(),
+            });
+        }
+
+        let start = Instant::now();
+        let on = |embedder: Embedder| mask.contains(embedder);
+
+        let e1 = Arc::clone(&self.e1_semantic);
+        let e2 = Arc::clone(&self.e2_temporal_recent);
+        let e3 = Arc::clone(&self.e3_temporal_periodic);
+        let e4 = Arc::clone(&self.e4_temporal_positional);
+        let e5 = Arc::clone(&self.e5_causal);
+        let e6 = Arc::clone(&self.e6_sparse);
+        let e7 = Arc::clone(&self.e7_code);
+        let e8 = Arc::clone(&self.e8_graph);
+        let e9 = Arc::clone(&self.e9_hdc);
+        let e10 = Arc::clone(&self.e10_contextual);
+        let e11 = Arc::clone(&self.e11_entity);
+        let e12 = Arc::clone(&self.e12_late_interaction);
+        let e13 = Arc::clone(&self.e13_splade);
+
+        let content_owned = content.to_string();
+
+        // Metadata inputs, `None` when called without metadata
+        let e2_instruction = metadata.map(|m| m.e2_instruction());
+        let e3_instruction = metadata.map(|m| m.e3_instruction());
+        let e4_instruction = metadata.map(|m| m.e4_instruction());
+        let causal_hint = metadata.map(|m| m.causal_hint.clone());
+        let e12_pruning = metadata.and_then(|m| m.e12_pruning);
+
+        // Run the selected embedders in parallel
+        let (
+            (r1, d1),
+            (r2, d2),
+            (r3, d3),
+            (r4, d4),
+            (r5, d5),
+            (r6, d6),
+            (r7, d7),
+            (r8, d8),
+            (r9, d9),
+            (r10, d10),
+            (r11, d11),
+            (r12, d12),
+            (r13, d13),
+        ) = tokio::join!(
+            Self::timed_embed_if(on(Embedder::Semantic), "E1_Semantic", {
+                let c = content_owned.clone();
+                async move { e1.embed(&c).await }
+            }),
+            Self::timed_embed_if(on(Embedder::TemporalRecent), "E2_TemporalRecent", {
+                let c = content_owned.clone();
+                let inst = e2_instruction.clone();
+                async move {
+                    if TEMPORAL_EMBEDDERS_ENABLED {
+                        e2.embed_with_instruction(&c, inst.as_deref()).await
+                    } else {
+                        Ok(vec![0.0f32; E2_DIM])
+                    }
+                }
+            }),
+            Self::timed_embed_if(on(Embedder::TemporalPeriodic), "E3_TemporalPeriodic", {
+                let c = content_owned.clone();
+                let inst = e3_instruction.clone();
+                async move {
+                    if TEMPORAL_EMBEDDERS_ENABLED {
+                        e3.embed_with_instruction(&c, inst.as_deref()).await
+                    } else {
+                        Ok(vec![0.0f32; E3_DIM])
+                    }
+                }
+            }),
+            Self::timed_embed_if(on(Embedder::TemporalPositional), "E4_TemporalPositional", {
+                let c = content_owned.clone();
+                let inst = e4_instruction.clone();
+                async move {
+                    if TEMPORAL_EMBEDDERS_ENABLED {
+                        e4.embed_with_instruction(&c, inst.as_deref()).await
+                    } else {
+                        Ok(vec![0.0f32; E4_DIM])
+                    }
+                }
+            }),
+            Self::timed_embed_if(on(Embedder::Causal), "E5_Causal_Dual", {
+                let c = content_owned.clone();
+                let hint = causal_hint.clone();
+                async move {
+                    match hint {
+                        Some(hint) => e5.embed_dual_with_hint(&c, hint.as_ref()).await,
+                        None => e5.embed_dual(&c).await,
+                    }
+                }
+            }),
+            Self::timed_embed_if(on(Embedder::Sparse), "E6_Sparse", {
+                let c = content_owned.clone();
+                async move { e6.embed_sparse(&c).await }
+            }),
+            Self::timed_embed_if(on(Embedder::Code), "E7_Code", {
+                let c = content_owned.clone();
+                async move { e7.embed(&c).await }
+            }),
+            Self::timed_embed_if(on(Embedder::Graph), "E8_Graph_Dual", {
+                let c = content_owned.clone();
+                async move { e8.embed_dual(&c).await }
+            }),
+            Self::timed_embed_if(on(Embedder::Hdc), "E9_HDC", {
+                let c = content_owned.clone();
+                async move { e9.embed(&c).await }
+            }),
+            Self::timed_embed_if(on(Embedder::Contextual), "E10_Contextual_Dual", {
+                let c = content_owned.clone();
+                async move { e10.embed_dual(&c).await }
+            }),
+            Self::timed_embed_if(on(Embedder::Entity), "E11_Entity", {
+                let c = content_owned.clone();
+                async move {
+                    if E11_ENTITY_ENABLED {
+                        e11.embed(&c).await
+                    } else {
+                        Ok(vec![0.0f32; E11_DIM])
+                    }
+                }
+            }),
+            Self::timed_embed_if(on(Embedder::LateInteraction), "E12_LateInteraction", {
+                let c = content_owned.clone();
+                async move {
+                    match e12_pruning {
+                        Some(target_compression) => {
+                            e12.embed_tokens_pruned(&c, target_compression).await
+                        }
+                        None => e12.embed_tokens(&c).await,
+                    }
+                }
+            }),
+            Self::timed_embed_if(on(Embedder::KeywordSplade), "E13_SPLADE", {
+                let c = content_owned.clone();
+                async move { e13.embed_sparse(&c).await }
+            }),
+        );
+
+        // Unselected spaces stay empty - never zero-filled
+        let (e5_cause_vec, e5_effect_vec) = r5?.unwrap_or_default();
+        let (e8_source_vec, e8_target_vec) = r8?.unwrap_or_default();
+        let (e10_paraphrase_vec, e10_context_vec) = r10?.unwrap_or_default();
+
+        let fingerprint = SemanticFingerprint {
+            e1_semantic: r1?.unwrap_or_default(),
+            e2_temporal_recent: r2?.unwrap_or_default(),
+            e3_temporal_periodic: r3?.unwrap_or_default(),
+            e4_temporal_positional: r4?.unwrap_or_default(),
+            e5_causal_as_cause: e5_cause_vec,
+            e5_causal_as_effect: e5_effect_vec,
+            e5_causal: Vec::new(),
+            e6_sparse: r6?.unwrap_or_else(SparseVector::empty),
+            e7_code: r7?.unwrap_or_default(),
+            e8_graph_as_source: e8_source_vec,
+            e8_graph_as_target: e8_target_vec,
+            e8_graph: Vec::new(),
+            e9_hdc: r9?.unwrap_or_default(),
+            e10_multimodal_paraphrase: e10_paraphrase_vec,
+            e10_multimodal_as_context: e10_context_vec,
+            e11_entity: r11?.unwrap_or_default(),
+            e12_late_interaction: r12?.unwrap_or_default(),
+            e13_splade: r13?.unwrap_or_else(SparseVector::empty),
+        };
+
+        Ok(PartialMultiArrayOutput {
+            mask,
+            fingerprint,
+            total_latency: start.elapsed(),
+            per_embedder_latency: [d1, d2, d3, d4, d5, d6, d7, d8, d9, d10, d11, d12, d13],
+            model_ids: self.model_ids.clone(),
+        })
+    }
+
     // =========================================================================
     // MODEL ACCESSOR METHODS
     // =========================================================================
@@ -1135,6 +1378,39 @@ impl MultiArrayEmbeddingProvider for ProductionMultiArrayProvider {
         })
     }
 
+    /// Generate embeddings for only the embedders selected by `mask`.
+    ///
+    /// Selected embedders run in parallel exactly as in `embed_all`; the
+    /// others are skipped without touching their models, and their spaces
+    /// are left empty in the returned fingerprint.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CoreError` if content is empty or any selected embedder fails.
+    async fn embed_selective(
+        &self,
+        content: &str,
+        mask: EmbedderMask,
+    ) -> CoreResult<PartialMultiArrayOutput> {
+        self.embed_masked(content, mask, None).await
+    }
+
+    /// Generate embeddings for the embedders selected by `mask`, passing
+    /// metadata to the ones that use it exactly as `embed_all_with_metadata`
+    /// does (E2-E4 instructions, E5 causal hint, E12 pruning).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CoreError` if content is empty or any selected embedder fails.
+    async fn embed_selective_with_metadata(
+        &self,
+        content: &str,
+        mask: EmbedderMask,
+        metadata: EmbeddingMetadata,
+    ) -> CoreResult<PartialMultiArrayOutput> {
+        self.embed_masked(content, mask, Some(&metadata)).await
+    }
+
     /// Generate complete 13-embedding fingerprint with explicit metadata.
     ///
     /// E4-FIX: This override passes session sequence numbers to E4 via
@@ -1189,6 +1465,9 @@ impl MultiArrayEmbeddingProvider for ProductionMultiArrayProvider {
         // CAUSAL-HINT Phase 6: Clone causal hint for E5 embedding
         let causal_hint = metadata.causal_hint.clone();
 
+        // Optional per-call E12 token pruning
+        let e12_pruning = metadata.e12_pruning;
+
         // Run all 13 embedders in parallel
         let (
             (r1, d1),
@@ -1284,7 +1563,14 @@ impl MultiArrayEmbeddingProvider for ProductionMultiArrayProvider {
             }),
             Self::timed_embed("E12_LateInteraction", {
                 let c = content_owned.clone();
-                async move { e12.embed_tokens(&c).await }
+                async move {
+                    match e12_pruning {
+                        Some(target_compression) => {
+                            e12.embed_tokens_pruned(&c, target_compression).await
+                        }
+                        None => e12.embed_tokens(&c).await,
+                    }
+                }
             }),
             Self::timed_embed("E13_SPLADE", {
                 let c = content_owned.clone();
@@ -1893,6 +2179,7 @@ mod tests {
             session_sequence: None,
             timestamp: Some(ts),
             causal_hint: None,
+            e12_pruning: None,
         };
 
         let instruction = metadata.e2_instruction();
@@ -1920,12 +2207,14 @@ mod tests {
             session_sequence: None,
             timestamp: Some(ts1),
             causal_hint: None,
+            e12_pruning: None,
         };
         let meta2 = EmbeddingMetadata {
             session_id: None,
             session_sequence: None,
             timestamp: Some(ts2),
             causal_hint: None,
+            e12_pruning: None,
         };
 
         assert_ne!(
@@ -1945,6 +2234,7 @@ mod tests {
             session_sequence: None,
             timestamp: Some(ts),
             causal_hint: None,
+            e12_pruning: None,
         };
 
         let e2 = metadata.e2_instruction();
@@ -1960,6 +2250,7 @@ mod tests {
             session_sequence: None,
             timestamp: None,
             causal_hint: None,
+            e12_pruning: None,
         };
 
         let instruction = metadata.e2_instruction();
@@ -1978,6 +2269,7 @@ mod tests {
             session_sequence: Some(42),
             timestamp: None,
             causal_hint: None,
+            e12_pruning: None,
         };
 
         let instruction = metadata.e4_instruction();
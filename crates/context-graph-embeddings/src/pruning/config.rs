@@ -12,6 +12,9 @@
 
 use crate::error::{EmbeddingError, EmbeddingResult};
 
+/// Number of buckets in [`PrunedEmbeddings::importance_histogram`].
+pub const IMPORTANCE_HISTOGRAM_BUCKETS: usize = 10;
+
 /// Configuration for token pruning of E12 (ColBERT) embeddings.
 ///
 /// Token pruning reduces the number of per-token embeddings while
@@ -170,6 +173,8 @@ pub enum ImportanceScoringMethod {
 ///     embeddings: vec![vec![0.0; 128]; 10],
 ///     retained_indices: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
 ///     compression_ratio: 0.5,
+///     retained_ratio: 0.5,
+///     importance_histogram: vec![],
 /// };
 /// assert_eq!(pruned.token_count(), 10);
 /// assert_eq!(pruned.memory_bytes(), 5120); // 10 * 128 * 4
@@ -187,6 +192,34 @@ pub struct PrunedEmbeddings {
     /// Achieved compression ratio [0, 1]
     /// 0.0 = no compression, 1.0 = all tokens removed (impossible)
     pub compression_ratio: f32,
+
+    /// Fraction of original tokens retained: `1.0 - compression_ratio`
+    pub retained_ratio: f32,
+
+    /// Token counts per importance bucket, over all original tokens
+    ///
+    /// `IMPORTANCE_HISTOGRAM_BUCKETS` equal-width buckets spanning the
+    /// min..max importance score. Empty if no scores were computed.
+    pub importance_histogram: Vec<usize>,
+}
+
+/// Estimated MaxSim score change caused by pruning.
+///
+/// Produced by [`PrunedEmbeddings::estimate_maxsim_delta`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MaxSimDeltaEstimate {
+    /// Number of sample queries scored
+    pub sample_size: usize,
+
+    /// Mean of (unpruned score - pruned score) over the sample
+    ///
+    /// Scores are MaxSim averaged over query tokens, so the delta is in
+    /// cosine units. Never negative while at least one token is retained,
+    /// since pruning can only remove matches.
+    pub mean_delta: f32,
+
+    /// Largest per-query delta in the sample
+    pub max_delta: f32,
 }
 
 impl PrunedEmbeddings {
@@ -203,6 +236,102 @@ impl PrunedEmbeddings {
     pub fn memory_bytes(&self) -> usize {
         self.embeddings.len() * 128 * std::mem::size_of::<f32>()
     }
+
+    /// Estimate how much pruning lowers MaxSim scores.
+    ///
+    /// Scores up to `sample_size` of `sample_queries` against both the
+    /// unpruned `original` tokens and the pruned tokens, and reports the mean
+    /// score delta. Scores are MaxSim divided by the query token count.
+    ///
+    /// # Errors
+    ///
+    /// - `EmbeddingError::EmptyInput` if `original`, `sample_queries`, or a
+    ///   sampled query is empty, or `sample_size` is 0
+    /// - `EmbeddingError::InvalidDimension` if `original` does not match the
+    ///   retained indices
+    pub fn estimate_maxsim_delta(
+        &self,
+        original: &[Vec<f32>],
+        sample_queries: &[Vec<Vec<f32>>],
+        sample_size: usize,
+    ) -> EmbeddingResult<MaxSimDeltaEstimate> {
+        if original.is_empty() || sample_queries.is_empty() || sample_size == 0 {
+            return Err(EmbeddingError::EmptyInput);
+        }
+        if let Some(&last) = self.retained_indices.last() {
+            if last >= original.len() {
+                return Err(EmbeddingError::InvalidDimension {
+                    expected: last + 1,
+                    actual: original.len(),
+                });
+            }
+        }
+
+        let sample = &sample_queries[..sample_size.min(sample_queries.len())];
+        let mut total_delta = 0.0f32;
+        let mut max_delta = 0.0f32;
+        for query in sample {
+            if query.is_empty() {
+                return Err(EmbeddingError::EmptyInput);
+            }
+            let delta = mean_maxsim(query, original) - mean_maxsim(query, &self.embeddings);
+            total_delta += delta;
+            max_delta = max_delta.max(delta);
+        }
+
+        Ok(MaxSimDeltaEstimate {
+            sample_size: sample.len(),
+            mean_delta: total_delta / sample.len() as f32,
+            max_delta,
+        })
+    }
+}
+
+/// MaxSim of `query` against `doc`, divided by the query token count.
+///
+/// Tokens are assumed L2 normalized, so cosine = dot product. A query token
+/// with no document tokens to match contributes 0.
+fn mean_maxsim(query: &[Vec<f32>], doc: &[Vec<f32>]) -> f32 {
+    let total: f32 = query
+        .iter()
+        .map(|q| {
+            let max_sim = doc
+                .iter()
+                .map(|d| q.iter().zip(d).map(|(x, y)| x * y).sum::<f32>())
+                .fold(f32::NEG_INFINITY, f32::max);
+            if max_sim > f32::NEG_INFINITY {
+                max_sim
+            } else {
+                0.0
+            }
+        })
+        .sum();
+    total / query.len() as f32
+}
+
+/// Bucket importance `scores` into `IMPORTANCE_HISTOGRAM_BUCKETS`
+/// equal-width buckets spanning their min..max.
+///
+/// Returns an empty histogram for no scores; identical (or non-finite
+/// range) scores all land in the first bucket.
+pub(crate) fn importance_histogram(scores: &[f32]) -> Vec<usize> {
+    if scores.is_empty() {
+        return Vec::new();
+    }
+    let min = scores.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = scores.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let width = (max - min) / IMPORTANCE_HISTOGRAM_BUCKETS as f32;
+
+    let mut histogram = vec![0usize; IMPORTANCE_HISTOGRAM_BUCKETS];
+    for &score in scores {
+        let bucket = if width > 0.0 && width.is_finite() {
+            (((score - min) / width) as usize).min(IMPORTANCE_HISTOGRAM_BUCKETS - 1)
+        } else {
+            0
+        };
+        histogram[bucket] += 1;
+    }
+    histogram
 }
 
 #[cfg(test)]
@@ -340,6 +469,8 @@ mod tests {
             embeddings: vec![vec![0.0; 128]; 10],
             retained_indices: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
             compression_ratio: 0.5,
+            retained_ratio: 0.5,
+            importance_histogram: vec![],
         };
         assert_eq!(pruned.token_count(), 10);
     }
@@ -350,17 +481,75 @@ mod tests {
             embeddings: vec![vec![0.0; 128]; 10],
             retained_indices: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
             compression_ratio: 0.5,
+            retained_ratio: 0.5,
+            importance_histogram: vec![],
         };
         // 10 tokens * 128 dims * 4 bytes = 5120 bytes
         assert_eq!(pruned.memory_bytes(), 5120);
     }
 
+    #[test]
+    fn test_importance_histogram_buckets() {
+        let scores: Vec<f32> = (0..20).map(|i| i as f32).collect();
+        let histogram = importance_histogram(&scores);
+        assert_eq!(histogram.len(), IMPORTANCE_HISTOGRAM_BUCKETS);
+        assert_eq!(histogram.iter().sum::<usize>(), 20);
+        assert!(histogram.iter().all(|&count| count == 2));
+
+        assert!(importance_histogram(&[]).is_empty());
+        assert_eq!(importance_histogram(&[0.3; 4])[0], 4);
+    }
+
+    #[test]
+    fn test_estimate_maxsim_delta_uses_sample_size() {
+        // Two orthogonal unit tokens; pruning kept only the first
+        let mut a = vec![0.0; 128];
+        a[0] = 1.0;
+        let mut b = vec![0.0; 128];
+        b[1] = 1.0;
+        let original = vec![a.clone(), b.clone()];
+        let pruned = PrunedEmbeddings {
+            embeddings: vec![a.clone()],
+            retained_indices: vec![0],
+            compression_ratio: 0.5,
+            retained_ratio: 0.5,
+            importance_histogram: vec![],
+        };
+
+        // Query matching the kept token loses nothing; one matching the
+        // dropped token loses its full match
+        let queries = vec![vec![a.clone()], vec![b.clone()], vec![a.clone()]];
+        let estimate = pruned
+            .estimate_maxsim_delta(&original, &queries, 2)
+            .unwrap();
+        assert_eq!(estimate.sample_size, 2);
+        assert!((estimate.mean_delta - 0.5).abs() < 1e-6);
+        assert!((estimate.max_delta - 1.0).abs() < 1e-6);
+
+        // Sample size is capped at the number of queries
+        let estimate = pruned
+            .estimate_maxsim_delta(&original, &queries, 10)
+            .unwrap();
+        assert_eq!(estimate.sample_size, 3);
+
+        assert!(matches!(
+            pruned.estimate_maxsim_delta(&original, &queries, 0),
+            Err(EmbeddingError::EmptyInput)
+        ));
+        assert!(matches!(
+            pruned.estimate_maxsim_delta(&[], &queries, 2),
+            Err(EmbeddingError::EmptyInput)
+        ));
+    }
+
     #[test]
     fn test_pruned_embeddings_empty() {
         let pruned = PrunedEmbeddings {
             embeddings: vec![],
             retained_indices: vec![],
             compression_ratio: 1.0,
+            retained_ratio: 0.0,
+            importance_histogram: vec![],
         };
         assert_eq!(pruned.token_count(), 0);
         assert_eq!(pruned.memory_bytes(), 0);
@@ -0,0 +1,169 @@
+//! GPU attention-based importance scoring for E12 token pruning.
+//!
+//! Scores tokens from the attention probabilities the E12 forward pass
+//! already computed, so pruning never re-runs attention. The reduction runs
+//! on the tensor's device; only one score per token is copied back.
+//!
+//! # Constitution References
+//!
+//! - embeddings.models.E12_LateInteraction: "128D/tok, dense_per_token"
+//! - rules: "Never unwrap() in prod"
+
+use candle_core::{DType, Tensor};
+
+use crate::error::{EmbeddingError, EmbeddingResult};
+
+/// Score token importance as the mean attention each token receives.
+///
+/// For attention probabilities of shape [1, heads, seq_len, seq_len], the
+/// score of token `j` is the attention paid to `j`, averaged over heads and
+/// over all non-padding query tokens. Padding tokens score 0.0.
+///
+/// # Arguments
+///
+/// * `attention_probs` - Softmax attention of one sequence, [1, heads, seq_len, seq_len]
+/// * `token_mask` - True for real tokens, false for padding; length seq_len
+///
+/// # Errors
+///
+/// - `EmbeddingError::InvalidDimension` if the tensor is not a single
+///   [1, heads, seq_len, seq_len] sequence matching `token_mask`
+/// - `EmbeddingError::EmptyInput` if `token_mask` has no real tokens
+/// - `EmbeddingError::GpuError` if a tensor operation fails
+pub fn attention_importance(
+    attention_probs: &Tensor,
+    token_mask: &[bool],
+) -> EmbeddingResult<Vec<f32>> {
+    let (batch, _heads, queries, keys) =
+        attention_probs
+            .dims4()
+            .map_err(|e| EmbeddingError::GpuError {
+                message: format!("Attention importance get dims failed: {}", e),
+            })?;
+    if batch != 1 {
+        return Err(EmbeddingError::InvalidDimension {
+            expected: 1,
+            actual: batch,
+        });
+    }
+    if queries != keys || keys != token_mask.len() {
+        return Err(EmbeddingError::InvalidDimension {
+            expected: token_mask.len(),
+            actual: keys,
+        });
+    }
+
+    let valid = token_mask.iter().filter(|&&m| m).count();
+    if valid == 0 {
+        return Err(EmbeddingError::EmptyInput);
+    }
+
+    let mask: Vec<f32> = token_mask
+        .iter()
+        .map(|&m| if m { 1.0 } else { 0.0 })
+        .collect();
+    let query_mask =
+        Tensor::from_slice(&mask, (1, queries, 1), attention_probs.device()).map_err(|e| {
+            EmbeddingError::GpuError {
+                message: format!("Attention importance mask tensor failed: {}", e),
+            }
+        })?;
+
+    // [1, heads, q, k] -> [1, q, k], averaged over heads
+    let per_query = attention_probs
+        .to_dtype(DType::F32)
+        .map_err(|e| EmbeddingError::GpuError {
+            message: format!("Attention importance dtype cast failed: {}", e),
+        })?
+        .mean(1)
+        .map_err(|e| EmbeddingError::GpuError {
+            message: format!("Attention importance head mean failed: {}", e),
+        })?;
+
+    // Drop padding query rows, then sum attention received per key -> [1, k]
+    let received = per_query
+        .broadcast_mul(&query_mask)
+        .map_err(|e| EmbeddingError::GpuError {
+            message: format!("Attention importance query mask failed: {}", e),
+        })?
+        .sum(1)
+        .map_err(|e| EmbeddingError::GpuError {
+            message: format!("Attention importance key sum failed: {}", e),
+        })?;
+
+    let received = (received / valid as f64).map_err(|e| EmbeddingError::GpuError {
+        message: format!("Attention importance normalization failed: {}", e),
+    })?;
+
+    let scores: Vec<f32> = received
+        .squeeze(0)
+        .map_err(|e| EmbeddingError::GpuError {
+            message: format!("Attention importance squeeze failed: {}", e),
+        })?
+        .to_vec1()
+        .map_err(|e| EmbeddingError::GpuError {
+            message: format!("Attention importance to_vec1 failed: {}", e),
+        })?;
+
+    Ok(scores
+        .into_iter()
+        .zip(token_mask)
+        .map(|(score, &m)| if m { score } else { 0.0 })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candle_core::Device;
+
+    /// Attention of `seq_len` tokens over `heads` heads where every query
+    /// attends to `focus` with weight 0.5 and spreads the rest evenly.
+    fn focused_attention(heads: usize, seq_len: usize, focus: usize) -> Tensor {
+        let rest = 0.5 / (seq_len - 1) as f32;
+        let data: Vec<f32> = (0..heads * seq_len * seq_len)
+            .map(|i| if i % seq_len == focus { 0.5 } else { rest })
+            .collect();
+        Tensor::from_vec(data, (1, heads, seq_len, seq_len), &Device::Cpu).unwrap()
+    }
+
+    #[test]
+    fn test_most_attended_token_scores_highest() {
+        let probs = focused_attention(4, 6, 2);
+        let scores = attention_importance(&probs, &[true; 6]).unwrap();
+
+        assert_eq!(scores.len(), 6);
+        assert!((scores[2] - 0.5).abs() < 1e-6);
+        for (i, score) in scores.iter().enumerate().filter(|(i, _)| *i != 2) {
+            assert!((score - 0.1).abs() < 1e-6, "token {} scored {}", i, score);
+        }
+    }
+
+    #[test]
+    fn test_padding_tokens_score_zero() {
+        let probs = focused_attention(2, 4, 0);
+        let scores = attention_importance(&probs, &[true, true, true, false]).unwrap();
+        assert_eq!(scores[3], 0.0);
+        assert!(scores[0] > scores[1]);
+    }
+
+    #[test]
+    fn test_mask_length_mismatch_fails() {
+        let probs = focused_attention(2, 4, 0);
+        let result = attention_importance(&probs, &[true; 5]);
+        assert!(matches!(
+            result,
+            Err(EmbeddingError::InvalidDimension {
+                expected: 5,
+                actual: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn test_all_padding_fails() {
+        let probs = focused_attention(2, 4, 0);
+        let result = attention_importance(&probs, &[false; 4]);
+        assert!(matches!(result, Err(EmbeddingError::EmptyInput)));
+    }
+}
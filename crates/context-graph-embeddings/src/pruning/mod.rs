@@ -2,6 +2,9 @@
 //!
 //! This module provides configuration types for token pruning,
 //! which reduces embedding size by ~50% while maintaining recall quality.
+//! Attention-based scoring can reuse the attention probabilities from the E12
+//! forward pass (see [`attention_importance`]), and [`PrunedEmbeddings`]
+//! reports quality metrics such as an estimated MaxSim score delta.
 //!
 //! # Constitution References
 //!
@@ -23,7 +26,12 @@
 //! ```
 
 mod config;
+mod gpu_scoring;
 mod token_pruner;
 
-pub use config::{ImportanceScoringMethod, PrunedEmbeddings, TokenPruningConfig};
+pub use config::{
+    ImportanceScoringMethod, MaxSimDeltaEstimate, PrunedEmbeddings, TokenPruningConfig,
+    IMPORTANCE_HISTOGRAM_BUCKETS,
+};
+pub use gpu_scoring::attention_importance;
 pub use token_pruner::TokenPruningQuantizer;
@@ -10,8 +10,13 @@
 //! - rules: "Result<T,E>, thiserror derivation"
 //! - rules: "Never unwrap() in prod"
 
+use candle_core::Tensor;
+
 use crate::error::{EmbeddingError, EmbeddingResult};
-use crate::pruning::config::{ImportanceScoringMethod, PrunedEmbeddings, TokenPruningConfig};
+use crate::pruning::config::{
+    importance_histogram, ImportanceScoringMethod, PrunedEmbeddings, TokenPruningConfig,
+};
+use crate::pruning::gpu_scoring::attention_importance;
 
 /// E12 Late Interaction embedding dimension per token.
 const LATE_INTERACTION_DIMENSION: usize = 128;
@@ -106,7 +111,8 @@ impl TokenPruningQuantizer {
     /// # Errors
     ///
     /// - `EmbeddingError::EmptyInput` if embeddings is empty
-    /// - `EmbeddingError::InvalidDimension` if any embedding is not 128D
+    /// - `EmbeddingError::InvalidDimension` if any embedding is not 128D, or
+    ///   `attention_weights` does not have one weight per token
     ///
     /// # Algorithm
     ///
@@ -138,6 +144,15 @@ impl TokenPruningQuantizer {
 
         let num_tokens = embeddings.len();
 
+        if let Some(weights) = attention_weights {
+            if weights.len() != num_tokens {
+                return Err(EmbeddingError::InvalidDimension {
+                    expected: num_tokens,
+                    actual: weights.len(),
+                });
+            }
+        }
+
         // Step 2: Calculate target token count
         // target_compression = 0.5 means remove 50%, so keep 50%
         let retention_ratio = 1.0 - self.config.target_compression;
@@ -147,19 +162,22 @@ impl TokenPruningQuantizer {
         target_count = target_count.max(self.config.min_tokens);
         target_count = target_count.min(num_tokens);
 
-        // Step 4: Check if pruning is needed
+        // Step 4: Score all tokens
+        let scores = self.score_tokens(embeddings, attention_weights);
+        let histogram = importance_histogram(&scores);
+
+        // Step 5: Check if pruning is needed
         if target_count >= num_tokens {
             // No pruning needed - return all embeddings unchanged
             return Ok(PrunedEmbeddings {
                 embeddings: embeddings.to_vec(),
                 retained_indices: (0..num_tokens).collect(),
                 compression_ratio: 0.0,
+                retained_ratio: 1.0,
+                importance_histogram: histogram,
             });
         }
 
-        // Step 5: Score all tokens
-        let scores = self.score_tokens(embeddings, attention_weights);
-
         // Step 6: Rank tokens by importance (descending)
         let mut indexed_scores: Vec<(usize, f32)> = scores.into_iter().enumerate().collect();
         indexed_scores.sort_by(|a, b| {
@@ -191,9 +209,41 @@ impl TokenPruningQuantizer {
             embeddings: pruned_embeddings,
             retained_indices,
             compression_ratio,
+            retained_ratio: 1.0 - compression_ratio,
+            importance_histogram: histogram,
         })
     }
 
+    /// Prune using attention probabilities from the E12 forward pass.
+    ///
+    /// With `AttentionBased` scoring, token importance is the mean attention
+    /// each token receives (see [`attention_importance`]), reduced on the
+    /// tensor's device without recomputing attention. Other scoring methods
+    /// ignore the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `embeddings` - Token embeddings, shape [num_tokens, 128]
+    /// * `attention_probs` - Attention probabilities, [1, heads, num_tokens, num_tokens]
+    /// * `token_mask` - True for real tokens, false for padding
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::prune`], plus `EmbeddingError::GpuError` if the
+    /// attention reduction fails.
+    pub fn prune_with_attention(
+        &self,
+        embeddings: &[Vec<f32>],
+        attention_probs: &Tensor,
+        token_mask: &[bool],
+    ) -> EmbeddingResult<PrunedEmbeddings> {
+        if self.config.scoring_method != ImportanceScoringMethod::AttentionBased {
+            return self.prune(embeddings, None);
+        }
+        let scores = attention_importance(attention_probs, token_mask)?;
+        self.prune(embeddings, Some(&scores))
+    }
+
     /// Score tokens by their importance using the configured method.
     fn score_tokens(&self, embeddings: &[Vec<f32>], attention_weights: Option<&[f32]>) -> Vec<f32> {
         match self.config.scoring_method {
@@ -553,4 +603,99 @@ mod tests {
             })
         ));
     }
+
+    // === Attention Tensor Tests ===
+
+    /// Unit vector along `axis`.
+    fn unit(axis: usize) -> Vec<f32> {
+        let mut v = vec![0.0; 128];
+        v[axis] = 1.0;
+        v
+    }
+
+    /// Attention over `seq_len` tokens where every query's attention to
+    /// token `j` is proportional to `received[j]`.
+    fn attention_tensor(heads: usize, received: &[f32]) -> Tensor {
+        let total: f32 = received.iter().sum();
+        let row: Vec<f32> = received.iter().map(|r| r / total).collect();
+        let seq_len = received.len();
+        let data: Vec<f32> = (0..heads * seq_len)
+            .flat_map(|_| row.iter().copied())
+            .collect();
+        Tensor::from_vec(
+            data,
+            (1, heads, seq_len, seq_len),
+            &candle_core::Device::Cpu,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_prune_with_attention_keeps_most_attended_tokens() {
+        let config = TokenPruningConfig {
+            target_compression: 0.5,
+            min_tokens: 1,
+            scoring_method: ImportanceScoringMethod::AttentionBased,
+        };
+        let quantizer = TokenPruningQuantizer::new(config).unwrap();
+
+        // 8 orthogonal tokens; odd positions receive 4x the attention
+        let embeddings: Vec<Vec<f32>> = (0..8).map(unit).collect();
+        let received: Vec<f32> = (0..8).map(|i| if i % 2 == 1 { 4.0 } else { 1.0 }).collect();
+        let probs = attention_tensor(4, &received);
+
+        let result = quantizer
+            .prune_with_attention(&embeddings, &probs, &[true; 8])
+            .unwrap();
+
+        assert_eq!(result.retained_indices, vec![1, 3, 5, 7]);
+        assert_eq!(result.embeddings[0], embeddings[1]);
+        assert!((result.retained_ratio - 0.5).abs() < 1e-6);
+        assert!((result.compression_ratio + result.retained_ratio - 1.0).abs() < 1e-6);
+        // Two importance levels land in the first and last buckets
+        assert_eq!(result.importance_histogram.iter().sum::<usize>(), 8);
+        assert_eq!(result.importance_histogram.first(), Some(&4));
+        assert_eq!(result.importance_histogram.last(), Some(&4));
+
+        // Each query token matches exactly one document token: kept tokens
+        // lose nothing, dropped tokens lose their full match
+        let queries: Vec<Vec<Vec<f32>>> = (0..8).map(|i| vec![unit(i)]).collect();
+        let estimate = result
+            .estimate_maxsim_delta(&embeddings, &queries, 6)
+            .unwrap();
+        assert_eq!(estimate.sample_size, 6);
+        assert!((estimate.mean_delta - 0.5).abs() < 1e-6);
+        assert!((estimate.max_delta - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_prune_with_attention_ignores_tensor_for_magnitude_scoring() {
+        let config = TokenPruningConfig {
+            target_compression: 0.5,
+            min_tokens: 1,
+            scoring_method: ImportanceScoringMethod::EmbeddingMagnitude,
+        };
+        let quantizer = TokenPruningQuantizer::new(config).unwrap();
+        let embeddings: Vec<Vec<f32>> = (0..4).map(|i| vec![(i as f32 + 1.0) * 0.1; 128]).collect();
+        let probs = attention_tensor(2, &[8.0, 4.0, 2.0, 1.0]);
+
+        let result = quantizer
+            .prune_with_attention(&embeddings, &probs, &[true; 4])
+            .unwrap();
+        assert_eq!(result.retained_indices, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_attention_weights_length_mismatch_fails() {
+        let quantizer = TokenPruningQuantizer::new(TokenPruningConfig::default()).unwrap();
+        let embeddings = make_embeddings(4);
+        let result = quantizer.prune(&embeddings, Some(&[1.0, 0.5]));
+        assert!(matches!(
+            result,
+            Err(EmbeddingError::InvalidDimension {
+                expected: 4,
+                actual: 2
+            })
+        ));
+    }
 }
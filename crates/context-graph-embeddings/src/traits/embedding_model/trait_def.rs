@@ -1,6 +1,7 @@
 //! Core trait definition for embedding models.
 
 use crate::error::{EmbeddingError, EmbeddingResult};
+use crate::pruning::{PrunedEmbeddings, TokenPruningConfig};
 use crate::types::{InputType, ModelEmbedding, ModelId, ModelInput};
 use async_trait::async_trait;
 
@@ -114,6 +115,33 @@ pub trait EmbeddingModel: Send + Sync {
     /// ```
     async fn embed(&self, input: &ModelInput) -> EmbeddingResult<ModelEmbedding>;
 
+    /// Generate embeddings for several inputs in one call.
+    ///
+    /// Models with a true batched forward pass override this. The default
+    /// embeds inputs one at a time.
+    ///
+    /// # Returns
+    /// One embedding per input, in input order.
+    ///
+    /// # Errors
+    /// Any error from `embed()`. An out-of-memory `EmbeddingError::GpuError`
+    /// lets `BatchProcessor` retry with a smaller batch.
+    async fn embed_batch(&self, inputs: &[ModelInput]) -> EmbeddingResult<Vec<ModelEmbedding>> {
+        let mut results = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            results.push(self.embed(input).await?);
+        }
+        Ok(results)
+    }
+
+    /// Peak GPU memory in bytes used by the most recent `embed_batch()` call.
+    ///
+    /// Used by `BatchProcessor` adaptive sizing to predict how large a batch
+    /// fits in its memory budget. Default: `None` (not measured).
+    fn last_batch_peak_memory(&self) -> Option<usize> {
+        None
+    }
+
     /// Returns whether the model is initialized and ready for inference.
     ///
     /// Models may require initialization (loading weights, warming up GPU)
@@ -335,4 +363,33 @@ pub trait EmbeddingModel: Send + Sync {
             input_type: InputType::from(input),
         })
     }
+
+    /// Generate per-token embeddings with low-importance tokens pruned.
+    ///
+    /// This method is only implemented by the late-interaction model (E12).
+    /// Other models return `EmbeddingError::UnsupportedModality`.
+    ///
+    /// # Arguments
+    /// * `input` - The input to embed (text only for ColBERT)
+    /// * `config` - Token pruning configuration
+    ///
+    /// # Returns
+    /// - `Ok(PrunedEmbeddings)` with the retained 128D token vectors
+    /// - `Err(EmbeddingError)` on failure or for non-token models
+    ///
+    /// # Errors
+    /// - `EmbeddingError::UnsupportedModality` for models without per-token output
+    /// - `EmbeddingError::ConfigError` if `config` is invalid
+    /// - `EmbeddingError::NotInitialized` if model not initialized
+    async fn embed_tokens_pruned(
+        &self,
+        input: &ModelInput,
+        _config: &TokenPruningConfig,
+    ) -> EmbeddingResult<PrunedEmbeddings> {
+        // Default: per-token embedding not supported
+        Err(EmbeddingError::UnsupportedModality {
+            model_id: self.model_id(),
+            input_type: InputType::from(input),
+        })
+    }
 }
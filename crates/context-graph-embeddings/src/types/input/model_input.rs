@@ -4,6 +4,7 @@
 //! to the embedding models, allowing each model to handle inputs it supports.
 
 use crate::error::{EmbeddingError, EmbeddingResult};
+use crate::models::pretrained::KeplerModel;
 use serde::{Deserialize, Serialize};
 
 use super::ImageFormat;
@@ -184,6 +185,43 @@ impl ModelInput {
         Ok(Self::Code { content, language })
     }
 
+    /// Create a text input from a knowledge triple for the E11 entity model.
+    ///
+    /// The triple is verbalized with [`KeplerModel::encode_triple`], so the
+    /// relation's underscores become spaces: `("Paris", "capital_of", "France")`
+    /// embeds as `"Paris capital of France"`.
+    ///
+    /// # Arguments
+    /// * `head` - Head entity name (must not be blank)
+    /// * `relation` - Relation predicate (must not be blank)
+    /// * `tail` - Tail entity name (must not be blank)
+    ///
+    /// # Errors
+    /// Returns `EmbeddingError::ConfigError` naming the first part that is
+    /// empty or whitespace-only.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use context_graph_embeddings::types::ModelInput;
+    ///
+    /// let input = ModelInput::entity_relation("Paris", "capital_of", "France").unwrap();
+    /// assert_eq!(input.as_text().unwrap().0, "Paris capital of France");
+    /// ```
+    pub fn entity_relation(head: &str, relation: &str, tail: &str) -> EmbeddingResult<Self> {
+        for (part, value) in [("head", head), ("relation", relation), ("tail", tail)] {
+            if value.trim().is_empty() {
+                return Err(EmbeddingError::ConfigError {
+                    message: format!("Entity relation {} cannot be empty", part),
+                });
+            }
+        }
+        Ok(Self::Text {
+            content: KeplerModel::encode_triple(head.trim(), relation.trim(), tail.trim()),
+            instruction: None,
+        })
+    }
+
     /// Create an image input.
     ///
     /// # Arguments
@@ -0,0 +1,39 @@
+//! Entity relation construction tests for ModelInput.
+
+use crate::error::EmbeddingError;
+use crate::types::input::ModelInput;
+
+// ============================================================
+// ENTITY RELATION CONSTRUCTION TESTS (3 tests)
+// ============================================================
+
+#[test]
+fn test_entity_relation_verbalizes_triple_as_text() {
+    let input = ModelInput::entity_relation("Tokio", "depends_on", "Mio").unwrap();
+    assert!(input.is_text());
+    let (content, instruction) = input.as_text().unwrap();
+    assert_eq!(content, "Tokio depends on Mio");
+    assert!(instruction.is_none());
+}
+
+#[test]
+fn test_entity_relation_trims_parts() {
+    let input = ModelInput::entity_relation("  Paris ", "capital_of", " France").unwrap();
+    assert_eq!(input.as_text().unwrap().0, "Paris capital of France");
+}
+
+#[test]
+fn test_entity_relation_with_blank_part_returns_config_error() {
+    for (head, relation, tail, part) in [
+        ("", "capital_of", "France", "head"),
+        ("Paris", "   ", "France", "relation"),
+        ("Paris", "capital_of", "\t", "tail"),
+    ] {
+        match ModelInput::entity_relation(head, relation, tail) {
+            Err(EmbeddingError::ConfigError { message }) => {
+                assert!(message.contains(part), "{} not in '{}'", part, message);
+            }
+            other => panic!("Expected ConfigError for blank {}, got {:?}", part, other),
+        }
+    }
+}
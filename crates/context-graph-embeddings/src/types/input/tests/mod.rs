@@ -5,6 +5,7 @@
 
 mod audio_tests;
 mod code_tests;
+mod entity_relation_tests;
 mod hash_and_size_tests;
 mod image_tests;
 mod input_type_tests;
@@ -0,0 +1,96 @@
+//! Embedding determinism mode against the real 13-embedder provider.
+//!
+//! With determinism on, the same content embedded repeatedly must produce
+//! bit-identical fingerprints. With it off, outputs only need to agree to
+//! within GPU noise, and the deterministic output must differ from the raw
+//! one (the flag has an effect).
+//!
+//! Run with: CUBLAS_WORKSPACE_CONFIG=:4096:8 cargo test --package context-graph-embeddings
+//!   determinism -- --ignored --nocapture
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use context_graph_core::config::DeterminismMode;
+use context_graph_core::traits::MultiArrayEmbeddingProvider;
+use context_graph_core::types::fingerprint::SemanticFingerprint;
+use context_graph_embeddings::config::GpuConfig;
+use context_graph_embeddings::provider::{DeterministicProvider, ProductionMultiArrayProvider};
+
+const CONTENT: &str = "The retry loop backs off exponentially after each failed connection.";
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+fn bits(values: &[f32]) -> Vec<u32> {
+    values.iter().map(|v| v.to_bits()).collect()
+}
+
+/// Dense spaces compared between runs.
+fn dense_spaces(fp: &SemanticFingerprint) -> [(&'static str, &[f32]); 6] {
+    [
+        ("E1", fp.e1_semantic.as_slice()),
+        ("E5_cause", fp.e5_causal_as_cause.as_slice()),
+        ("E7", fp.e7_code.as_slice()),
+        ("E8_source", fp.e8_graph_as_source.as_slice()),
+        ("E9", fp.e9_hdc.as_slice()),
+        ("E10_context", fp.e10_multimodal_as_context.as_slice()),
+    ]
+}
+
+async fn production_provider() -> Arc<dyn MultiArrayEmbeddingProvider> {
+    let models_dir = PathBuf::from(
+        std::env::var("MODELS_DIR").unwrap_or_else(|_| "./models".to_string()),
+    );
+    Arc::new(
+        ProductionMultiArrayProvider::new(models_dir, GpuConfig::default())
+            .await
+            .expect("Failed to create provider"),
+    )
+}
+
+#[tokio::test]
+#[ignore = "requires models directory with pretrained weights"]
+async fn test_determinism_on_gives_bit_identical_outputs() {
+    let raw = production_provider().await;
+    let provider = DeterministicProvider::new(Arc::clone(&raw), DeterminismMode::enabled());
+    assert!(provider.determinism().is_some());
+
+    let first = provider.embed_all(CONTENT).await.expect("embed_all failed").fingerprint;
+    for run in 1..5 {
+        let again = provider.embed_all(CONTENT).await.expect("embed_all failed").fingerprint;
+        for ((name, a), (_, b)) in dense_spaces(&first).into_iter().zip(dense_spaces(&again)) {
+            assert_eq!(bits(a), bits(b), "{} differs on run {}", name, run);
+        }
+        assert_eq!(
+            first.e12_late_interaction.len(),
+            again.e12_late_interaction.len()
+        );
+        for (a, b) in first.e12_late_interaction.iter().zip(&again.e12_late_interaction) {
+            assert_eq!(bits(a), bits(b), "E12 token differs on run {}", run);
+        }
+        assert_eq!(first.e13_splade.indices, again.e13_splade.indices);
+        assert_eq!(bits(&first.e13_splade.values), bits(&again.e13_splade.values));
+    }
+
+    // Determinism off: the raw output agrees to within GPU noise but is
+    // not the rounded deterministic output
+    let plain = raw.embed_all(CONTENT).await.expect("embed_all failed").fingerprint;
+    assert!(raw.determinism().is_none());
+    for ((name, det), (_, off)) in dense_spaces(&first).into_iter().zip(dense_spaces(&plain)) {
+        let cosine = cosine_similarity(det, off);
+        assert!(cosine > 0.9999, "{} cosine {} must exceed 0.9999", name, cosine);
+    }
+    assert_ne!(
+        bits(&first.e1_semantic),
+        bits(&plain.e1_semantic),
+        "Determinism mode must change the emitted vectors"
+    );
+}
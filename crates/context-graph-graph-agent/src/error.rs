@@ -1,5 +1,6 @@
 //! Error types for the graph relationship discovery agent.
 
+use context_graph_core::error::{ContextGraphError, ErrorKind};
 use thiserror::Error;
 
 /// Result type for graph agent operations.
@@ -60,3 +61,21 @@ impl From<context_graph_causal_agent::CausalAgentError> for GraphAgentError {
         }
     }
 }
+
+impl GraphAgentError {
+    /// Machine-readable classification of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::LlmNotInitialized | Self::LlmLoadError { .. } => ErrorKind::CapabilityUnavailable,
+            Self::MemoryNotFound { .. } => ErrorKind::NotFound,
+            _ => ErrorKind::Internal,
+        }
+    }
+}
+
+impl From<GraphAgentError> for ContextGraphError {
+    fn from(err: GraphAgentError) -> Self {
+        let kind = err.kind();
+        ContextGraphError::from_source(kind, err)
+    }
+}
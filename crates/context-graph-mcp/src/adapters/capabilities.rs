@@ -0,0 +1,158 @@
+//! Hardware capabilities detected at server startup.
+//!
+//! `CapabilityMatrix` records which GPU components actually work on this
+//! machine: the CUDA driver, a CUDA device for Candle, and FAISS GPU. The
+//! tool dispatcher consults it to decide whether a tool can run, must run on
+//! its CPU path, or has to be refused (see `handlers::tools::capability_policy`).
+//!
+//! Per-model load status comes from the shared `ProviderHealth`, so
+//! `report()` always shows the current state rather than the state at
+//! startup.
+
+use std::sync::Arc;
+
+use serde_json::json;
+use tracing::{info, warn};
+
+use super::ProviderHealth;
+
+/// A hardware capability a tool may depend on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+    /// CUDA driver with at least one device (custom kernels, GPU HDBSCAN).
+    CudaDriver,
+    /// Candle running on a CUDA device (embedding inference).
+    CandleCuda,
+}
+
+impl Capability {
+    /// Name used in status reports and CAPABILITY_UNAVAILABLE errors.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::CudaDriver => "cuda_driver",
+            Self::CandleCuda => "candle_cuda",
+        }
+    }
+}
+
+/// Which GPU capabilities are usable, assembled once at startup.
+///
+/// FAISS GPU support is reported for diagnostics; no tool requires it.
+#[derive(Debug, Clone)]
+pub struct CapabilityMatrix {
+    cuda_driver: bool,
+    cuda_device_count: i32,
+    candle_cuda: bool,
+    gpu_name: Option<String>,
+    faiss_gpu: bool,
+    health: Option<Arc<ProviderHealth>>,
+}
+
+impl CapabilityMatrix {
+    /// Probe the CUDA driver, Candle device and FAISS GPU support.
+    ///
+    /// Never fails: a probe that errors counts as the capability missing.
+    pub fn detect() -> Self {
+        let cuda_driver = context_graph_cuda::cuda_available();
+        let cuda_device_count = if cuda_driver {
+            context_graph_cuda::cuda_device_count().unwrap_or(0)
+        } else {
+            0
+        };
+        let candle_cuda = match context_graph_embeddings::gpu::init_gpu() {
+            Ok(_) => true,
+            Err(e) => {
+                warn!(error = %e, "Candle CUDA device unavailable; embedding tools run degraded");
+                false
+            }
+        };
+        let gpu_name = candle_cuda.then(|| context_graph_embeddings::gpu::get_gpu_info().name);
+        let faiss_gpu = context_graph_cuda::is_faiss_gpu_available();
+
+        let matrix = Self {
+            cuda_driver,
+            cuda_device_count,
+            candle_cuda,
+            gpu_name,
+            faiss_gpu,
+            health: None,
+        };
+        info!(
+            cuda_driver,
+            cuda_device_count, candle_cuda, faiss_gpu, "Capability matrix assembled"
+        );
+        matrix
+    }
+
+    /// Matrix with every GPU capability missing, as on a CPU-only machine.
+    #[cfg(test)]
+    pub fn no_gpu() -> Self {
+        Self {
+            cuda_driver: false,
+            cuda_device_count: 0,
+            candle_cuda: false,
+            gpu_name: None,
+            faiss_gpu: false,
+            health: None,
+        }
+    }
+
+    /// Report per-model load status from `health` alongside the hardware.
+    pub fn with_health(mut self, health: Arc<ProviderHealth>) -> Self {
+        self.health = Some(health);
+        self
+    }
+
+    /// True if `capability` is usable.
+    pub fn has(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::CudaDriver => self.cuda_driver,
+            Capability::CandleCuda => self.candle_cuda,
+        }
+    }
+
+    /// The entries of `required` that are not usable, in order.
+    pub fn missing(&self, required: &[Capability]) -> Vec<Capability> {
+        required.iter().copied().filter(|&c| !self.has(c)).collect()
+    }
+
+    /// JSON view for get_memetic_status.
+    pub fn report(&self) -> serde_json::Value {
+        json!({
+            "cudaDriver": self.cuda_driver,
+            "cudaDeviceCount": self.cuda_device_count,
+            "candleDevice": if self.candle_cuda { "cuda" } else { "cpu" },
+            "gpuName": self.gpu_name,
+            "faissGpu": self.faiss_gpu,
+            "models": self.health.as_ref().map(|h| h.snapshot())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_gpu_reports_every_capability_missing() {
+        let matrix = CapabilityMatrix::no_gpu();
+        let all = [Capability::CudaDriver, Capability::CandleCuda];
+        assert_eq!(matrix.missing(&all), all.to_vec());
+
+        let report = matrix.report();
+        assert_eq!(report["cudaDriver"], false);
+        assert_eq!(report["candleDevice"], "cpu");
+        assert_eq!(report["faissGpu"], false);
+        assert!(report["models"].is_null());
+    }
+
+    #[test]
+    fn test_report_includes_model_health() {
+        let health = Arc::new(ProviderHealth::new());
+        health.set_all_ready();
+        let report = CapabilityMatrix::no_gpu().with_health(health).report();
+        let models = report["models"].as_array().expect("models array");
+        assert_eq!(models.len(), 13);
+        assert!(models.iter().all(|m| m["state"] == "ready"));
+    }
+}
@@ -10,9 +10,16 @@ use async_trait::async_trait;
 use tokio::sync::RwLock;
 
 use context_graph_core::error::{CoreError, CoreResult};
-use context_graph_core::traits::{EmbeddingMetadata, MultiArrayEmbeddingOutput, MultiArrayEmbeddingProvider};
+use context_graph_core::teleological::{Embedder, EmbedderMask};
+use context_graph_core::traits::{
+    EmbeddingMetadata, MultiArrayEmbeddingOutput, MultiArrayEmbeddingProvider,
+    PartialMultiArrayOutput,
+};
 use context_graph_core::types::fingerprint::{NUM_EMBEDDERS, E11_DIM};
 use context_graph_core::weights::E11_ENTITY_ENABLED;
+use context_graph_embeddings::ModelId;
+
+use super::provider_health::ProviderHealth;
 
 /// Lazy wrapper for MultiArrayEmbeddingProvider that allows immediate MCP startup.
 ///
@@ -37,6 +44,8 @@ pub struct LazyMultiArrayProvider {
     loading: Arc<AtomicBool>,
     /// Error message if loading failed
     failed: Arc<RwLock<Option<String>>>,
+    /// Per-model load state, shared with the model loader and handlers
+    health: Arc<ProviderHealth>,
 }
 
 #[allow(dead_code)]
@@ -57,6 +66,56 @@ impl LazyMultiArrayProvider {
             inner,
             loading,
             failed,
+            health: Arc::new(ProviderHealth::new()),
+        }
+    }
+
+    /// Share `health` with the model loader instead of a private tracker.
+    pub fn with_health(mut self, health: Arc<ProviderHealth>) -> Self {
+        self.health = health;
+        self
+    }
+
+    /// Per-model load state.
+    pub fn health(&self) -> &Arc<ProviderHealth> {
+        &self.health
+    }
+
+    /// Preload `models` by running one selective embedding through them.
+    ///
+    /// Each model is marked Loading, then Ready or Failed with the observed
+    /// duration. Fails without touching model state while the provider
+    /// itself is still loading.
+    pub async fn warmup(&self, models: Vec<ModelId>) -> CoreResult<()> {
+        if self.loading.load(Ordering::SeqCst) {
+            return Err(CoreError::Internal(
+                "Embedding models are still loading. Please wait and try again.".to_string(),
+            ));
+        }
+        if models.is_empty() {
+            return Ok(());
+        }
+
+        for &model in &models {
+            self.health.set_loading(model, 0);
+        }
+        let embedders: Vec<Embedder> = models.iter().map(|&m| Embedder::from(m)).collect();
+        match self
+            .embed_selective("warmup", EmbedderMask::from_slice(&embedders))
+            .await
+        {
+            Ok(_) => {
+                for &model in &models {
+                    self.health.set_ready(model);
+                }
+                Ok(())
+            }
+            Err(e) => {
+                for &model in &models {
+                    self.health.set_failed(model, e.to_string());
+                }
+                Err(e)
+            }
         }
     }
 
@@ -135,6 +194,67 @@ impl MultiArrayEmbeddingProvider for LazyMultiArrayProvider {
         }
     }
 
+    /// Delegate to the real provider so unselected models are skipped; the
+    /// default trait impl would run all 13 embedders.
+    async fn embed_selective(
+        &self,
+        content: &str,
+        mask: EmbedderMask,
+    ) -> CoreResult<PartialMultiArrayOutput> {
+        if self.loading.load(Ordering::SeqCst) {
+            return Err(CoreError::Internal(
+                "Embedding models are still loading. Please wait and try again.".to_string(),
+            ));
+        }
+
+        if let Some(ref err) = *self.failed.read().await {
+            return Err(CoreError::Internal(format!(
+                "Embedding model loading failed: {}",
+                err
+            )));
+        }
+
+        let guard = self.inner.read().await;
+        match guard.as_ref() {
+            Some(provider) => provider.embed_selective(content, mask).await,
+            None => Err(CoreError::Internal(
+                "Embedding provider not available. This is a bug.".to_string(),
+            )),
+        }
+    }
+
+    async fn embed_selective_with_metadata(
+        &self,
+        content: &str,
+        mask: EmbedderMask,
+        metadata: EmbeddingMetadata,
+    ) -> CoreResult<PartialMultiArrayOutput> {
+        if self.loading.load(Ordering::SeqCst) {
+            return Err(CoreError::Internal(
+                "Embedding models are still loading. Please wait and try again.".to_string(),
+            ));
+        }
+
+        if let Some(ref err) = *self.failed.read().await {
+            return Err(CoreError::Internal(format!(
+                "Embedding model loading failed: {}",
+                err
+            )));
+        }
+
+        let guard = self.inner.read().await;
+        match guard.as_ref() {
+            Some(provider) => {
+                provider
+                    .embed_selective_with_metadata(content, mask, metadata)
+                    .await
+            }
+            None => Err(CoreError::Internal(
+                "Embedding provider not available. This is a bug.".to_string(),
+            )),
+        }
+    }
+
     async fn embed_batch_all(
         &self,
         contents: &[String],
@@ -316,6 +436,7 @@ impl MultiArrayEmbeddingProvider for LazyMultiArrayProvider {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::adapters::provider_health::ModelLoadState;
 
     #[tokio::test]
     async fn test_returns_loading_error_while_loading() {
@@ -360,4 +481,40 @@ mod tests {
         loading.store(false, Ordering::SeqCst);
         assert!(provider.is_loaded());
     }
+
+    #[tokio::test]
+    async fn test_warmup_while_loading_leaves_models_untouched() {
+        let inner = Arc::new(RwLock::new(None));
+        let loading = Arc::new(AtomicBool::new(true));
+        let failed = Arc::new(RwLock::new(None));
+        let health = Arc::new(ProviderHealth::new());
+
+        let provider =
+            LazyMultiArrayProvider::new(inner, loading, failed).with_health(Arc::clone(&health));
+
+        let result = provider.warmup(vec![ModelId::Semantic]).await;
+        assert!(result.unwrap_err().to_string().contains("still loading"));
+        assert_eq!(health.state(ModelId::Semantic), ModelLoadState::NotLoaded);
+    }
+
+    #[tokio::test]
+    async fn test_warmup_failure_marks_selected_models_failed() {
+        // Loading finished but no provider was installed: the selective
+        // embed fails, so only the requested models become Failed
+        let inner = Arc::new(RwLock::new(None));
+        let loading = Arc::new(AtomicBool::new(false));
+        let failed = Arc::new(RwLock::new(None));
+
+        let provider = LazyMultiArrayProvider::new(inner, loading, failed);
+
+        let result = provider
+            .warmup(vec![ModelId::Semantic, ModelId::Code])
+            .await;
+        assert!(result.is_err());
+        let health = provider.health();
+        assert!(health.state(ModelId::Semantic).is_failed());
+        assert!(health.state(ModelId::Code).is_failed());
+        assert!(!health.state(ModelId::Causal).is_failed());
+        assert_eq!(health.blocking_models(ModelId::production()).len(), 13);
+    }
 }
@@ -6,17 +6,27 @@
 //! # Available Adapters
 //!
 //! - [`LazyMultiArrayProvider`]: Wraps provider for lazy loading on MCP startup
+//! - [`ProviderHealth`]: Per-model load state and ETA for the embedding provider
+//! - [`CapabilityMatrix`]: GPU capabilities detected at startup
 //! - [`LlmCausalHintProvider`]: LLM-based causal hint provider for E5 enhancement
 
+pub mod capabilities;
 /// LLM-based causal hint provider (requires `llm` feature).
 /// Wraps CausalDiscoveryLLM for causal hint generation during store_memory.
 #[cfg(feature = "llm")]
 pub mod causal_hint;
 pub mod lazy_provider;
+pub mod provider_health;
 
 // LazyMultiArrayProvider allows immediate MCP startup while models load in background
 pub use lazy_provider::LazyMultiArrayProvider;
 
+// ProviderHealth tracks which embedding models are loaded
+pub use provider_health::ProviderHealth;
+
+// CapabilityMatrix drives graceful degradation without a GPU
+pub use capabilities::{Capability, CapabilityMatrix};
+
 
 // LlmCausalHintProvider wraps CausalDiscoveryLLM for causal hint generation
 #[cfg(feature = "llm")]
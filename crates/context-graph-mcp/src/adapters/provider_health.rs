@@ -0,0 +1,365 @@
+//! Per-model load state of the embedding provider.
+//!
+//! `LazyMultiArrayProvider` only knows "loading", "failed" or "ready" for the
+//! provider as a whole. `ProviderHealth` tracks each production `ModelId`
+//! separately so callers can tell which models block a request and how long
+//! loading is likely to take.
+//!
+//! # ETA
+//!
+//! Every model that goes Loading -> Ready records its load duration. The
+//! estimated time remaining is the mean observed duration times the work
+//! left: a full model for each NotLoaded model, the unfinished percentage for
+//! each Loading one. Until one model has finished there is no estimate.
+//!
+//! # Index builds
+//!
+//! The store reports HNSW rebuild progress per space through
+//! [`ProviderHealth::index_build_observer`]; the latest report of each space
+//! is kept for `get_embedding_status`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::RwLock;
+use serde::Serialize;
+
+use context_graph_embeddings::ModelId;
+use context_graph_storage::teleological::indexes::{IndexBuildProgress, IndexBuildProgressFn};
+
+/// Load state of a single embedding model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "state", rename_all = "camelCase")]
+pub enum ModelLoadState {
+    /// Loading has not started.
+    NotLoaded,
+    /// Loading is in progress; `pct` is 0-100.
+    Loading { pct: u8 },
+    /// Model is loaded and can embed.
+    Ready,
+    /// Loading failed; embedding calls needing this model cannot succeed.
+    Failed { error: String },
+}
+
+impl ModelLoadState {
+    /// True for `Ready`.
+    pub fn is_ready(&self) -> bool {
+        matches!(self, Self::Ready)
+    }
+
+    /// True for `Failed`.
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Self::Failed { .. })
+    }
+}
+
+/// Point-in-time state of one model, as reported by `get_embedding_status`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelHealth {
+    pub model: &'static str,
+    #[serde(flatten)]
+    pub state: ModelLoadState,
+    /// Load duration in milliseconds, once the model has been loaded.
+    pub load_ms: Option<u64>,
+}
+
+/// Latest progress of one HNSW index build, as reported by
+/// `get_embedding_status`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexBuildHealth {
+    pub space: String,
+    pub inserted: usize,
+    pub total: usize,
+    pub pct: u8,
+    pub elapsed_ms: u64,
+    /// Estimated milliseconds until this space is built.
+    pub eta_ms: Option<u64>,
+}
+
+impl From<&IndexBuildProgress> for IndexBuildHealth {
+    fn from(progress: &IndexBuildProgress) -> Self {
+        Self {
+            space: format!("{:?}", progress.embedder),
+            inserted: progress.inserted,
+            total: progress.total,
+            pct: progress.percent(),
+            elapsed_ms: progress.elapsed.as_millis() as u64,
+            eta_ms: progress.eta.map(|eta| eta.as_millis() as u64),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ModelEntry {
+    state: ModelLoadState,
+    started: Option<Instant>,
+    load_duration: Option<Duration>,
+}
+
+/// Shared per-model load state for the production embedding models.
+///
+/// Written by the model loader (server startup, `warmup`), read by handlers
+/// before embedding calls. Safe to share across tokio tasks.
+#[derive(Debug)]
+pub struct ProviderHealth {
+    models: RwLock<HashMap<ModelId, ModelEntry>>,
+    /// Latest build progress per HNSW space, in first-reported order.
+    index_builds: RwLock<Vec<IndexBuildHealth>>,
+}
+
+impl Default for ProviderHealth {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProviderHealth {
+    /// All production models start as `NotLoaded`.
+    pub fn new() -> Self {
+        let models = ModelId::production()
+            .iter()
+            .map(|&model| {
+                (
+                    model,
+                    ModelEntry {
+                        state: ModelLoadState::NotLoaded,
+                        started: None,
+                        load_duration: None,
+                    },
+                )
+            })
+            .collect();
+        Self {
+            models: RwLock::new(models),
+            index_builds: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Mark `model` as loading with `pct` (clamped to 100) progress.
+    ///
+    /// The first call of a load starts its timer. Models outside
+    /// `ModelId::production()` are not tracked and are ignored.
+    pub fn set_loading(&self, model: ModelId, pct: u8) {
+        let mut models = self.models.write();
+        if let Some(entry) = models.get_mut(&model) {
+            if !matches!(entry.state, ModelLoadState::Loading { .. }) {
+                entry.started = Some(Instant::now());
+            }
+            entry.state = ModelLoadState::Loading { pct: pct.min(100) };
+        }
+    }
+
+    /// Mark `model` as ready, recording its load duration if it was loading.
+    pub fn set_ready(&self, model: ModelId) {
+        let mut models = self.models.write();
+        if let Some(entry) = models.get_mut(&model) {
+            if let Some(started) = entry.started.take() {
+                entry.load_duration = Some(started.elapsed());
+            }
+            entry.state = ModelLoadState::Ready;
+        }
+    }
+
+    /// Mark `model` as failed.
+    pub fn set_failed(&self, model: ModelId, error: impl Into<String>) {
+        let mut models = self.models.write();
+        if let Some(entry) = models.get_mut(&model) {
+            entry.started = None;
+            entry.state = ModelLoadState::Failed {
+                error: error.into(),
+            };
+        }
+    }
+
+    /// Mark every production model as loading from 0%.
+    pub fn set_all_loading(&self) {
+        for &model in ModelId::production() {
+            self.set_loading(model, 0);
+        }
+    }
+
+    /// Mark every production model as ready.
+    pub fn set_all_ready(&self) {
+        for &model in ModelId::production() {
+            self.set_ready(model);
+        }
+    }
+
+    /// Mark every production model as failed with the same error.
+    pub fn set_all_failed(&self, error: &str) {
+        for &model in ModelId::production() {
+            self.set_failed(model, error);
+        }
+    }
+
+    /// Current state of `model` (`NotLoaded` for models not tracked).
+    pub fn state(&self, model: ModelId) -> ModelLoadState {
+        self.models
+            .read()
+            .get(&model)
+            .map(|entry| entry.state.clone())
+            .unwrap_or(ModelLoadState::NotLoaded)
+    }
+
+    /// Models in `required` that are not `Ready`, in the given order.
+    pub fn blocking_models(&self, required: &[ModelId]) -> Vec<ModelId> {
+        let models = self.models.read();
+        required
+            .iter()
+            .copied()
+            .filter(|model| !models.get(model).is_some_and(|e| e.state.is_ready()))
+            .collect()
+    }
+
+    /// Per-model state in production order.
+    pub fn snapshot(&self) -> Vec<ModelHealth> {
+        let models = self.models.read();
+        ModelId::production()
+            .iter()
+            .map(|model| {
+                let entry = &models[model];
+                ModelHealth {
+                    model: model.as_str(),
+                    state: entry.state.clone(),
+                    load_ms: entry.load_duration.map(|d| d.as_millis() as u64),
+                }
+            })
+            .collect()
+    }
+
+    /// Estimated time until every production model that is not failed is
+    /// ready, or `None` before any load duration has been observed.
+    pub fn estimated_remaining(&self) -> Option<Duration> {
+        let models = self.models.read();
+        let observed: Vec<Duration> = models.values().filter_map(|e| e.load_duration).collect();
+        if observed.is_empty() {
+            return None;
+        }
+        let mean = observed.iter().sum::<Duration>() / observed.len() as u32;
+        let remaining_pct: u32 = models
+            .values()
+            .map(|entry| match entry.state {
+                ModelLoadState::NotLoaded => 100,
+                ModelLoadState::Loading { pct } => 100 - u32::from(pct),
+                ModelLoadState::Ready | ModelLoadState::Failed { .. } => 0,
+            })
+            .sum();
+        Some(mean * remaining_pct / 100)
+    }
+
+    /// Record the latest build progress of one HNSW space.
+    pub fn record_index_build(&self, progress: &IndexBuildProgress) {
+        let health = IndexBuildHealth::from(progress);
+        let mut builds = self.index_builds.write();
+        match builds.iter_mut().find(|b| b.space == health.space) {
+            Some(entry) => *entry = health,
+            None => builds.push(health),
+        }
+    }
+
+    /// Progress callback for `IndexBuildConfig` that records into `self`.
+    pub fn index_build_observer(self: &Arc<Self>) -> IndexBuildProgressFn {
+        let health = Arc::clone(self);
+        Arc::new(move |progress: &IndexBuildProgress| health.record_index_build(progress))
+    }
+
+    /// Latest progress of every HNSW space reported so far.
+    pub fn index_build_snapshot(&self) -> Vec<IndexBuildHealth> {
+        self.index_builds.read().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starts_not_loaded_and_tracks_transitions() {
+        let health = ProviderHealth::new();
+        assert_eq!(health.snapshot().len(), 13);
+        assert_eq!(health.state(ModelId::Semantic), ModelLoadState::NotLoaded);
+        assert_eq!(health.blocking_models(ModelId::production()).len(), 13);
+
+        health.set_loading(ModelId::Semantic, 40);
+        assert_eq!(
+            health.state(ModelId::Semantic),
+            ModelLoadState::Loading { pct: 40 }
+        );
+        health.set_ready(ModelId::Semantic);
+        assert!(health.state(ModelId::Semantic).is_ready());
+
+        health.set_failed(ModelId::Code, "out of VRAM");
+        assert!(health.state(ModelId::Code).is_failed());
+
+        let blocking = health.blocking_models(&[ModelId::Semantic, ModelId::Code]);
+        assert_eq!(blocking, vec![ModelId::Code]);
+    }
+
+    #[test]
+    fn test_all_ready_clears_blocking_models() {
+        let health = ProviderHealth::new();
+        health.set_all_loading();
+        assert_eq!(health.blocking_models(ModelId::production()).len(), 13);
+        health.set_all_ready();
+        assert!(health.blocking_models(ModelId::production()).is_empty());
+        assert!(health.snapshot().iter().all(|m| m.load_ms.is_some()));
+    }
+
+    #[test]
+    fn test_eta_needs_an_observed_load() {
+        let health = ProviderHealth::new();
+        assert!(health.estimated_remaining().is_none());
+
+        health.set_loading(ModelId::Semantic, 0);
+        std::thread::sleep(Duration::from_millis(20));
+        health.set_ready(ModelId::Semantic);
+        let eta = health.estimated_remaining().expect("one load observed");
+        assert!(eta > Duration::ZERO);
+
+        health.set_all_ready();
+        assert_eq!(health.estimated_remaining(), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_state_serializes_tagged() {
+        let json = serde_json::to_value(ModelLoadState::Loading { pct: 50 }).unwrap();
+        assert_eq!(json, serde_json::json!({ "state": "loading", "pct": 50 }));
+        let json = serde_json::to_value(ModelLoadState::Failed {
+            error: "boom".to_string(),
+        })
+        .unwrap();
+        assert_eq!(json["state"], "failed");
+        assert_eq!(json["error"], "boom");
+    }
+
+    #[test]
+    fn test_index_build_observer_keeps_latest_per_space() {
+        use context_graph_storage::teleological::indexes::EmbedderIndex;
+
+        let health = Arc::new(ProviderHealth::new());
+        let observer = health.index_build_observer();
+        let progress = |embedder, inserted| IndexBuildProgress {
+            embedder,
+            inserted,
+            total: 100,
+            elapsed: Duration::from_millis(200),
+            eta: Some(Duration::from_millis(300)),
+        };
+
+        observer(&progress(EmbedderIndex::E1Semantic, 40));
+        observer(&progress(EmbedderIndex::E7Code, 10));
+        observer(&progress(EmbedderIndex::E1Semantic, 100));
+
+        let builds = health.index_build_snapshot();
+        assert_eq!(builds.len(), 2);
+        assert_eq!(builds[0].space, "E1Semantic");
+        assert_eq!(builds[0].pct, 100);
+        assert_eq!(builds[1].inserted, 10);
+        let json = serde_json::to_value(&builds[1]).unwrap();
+        assert_eq!(json["etaMs"], 300);
+        assert_eq!(json["elapsedMs"], 200);
+    }
+}
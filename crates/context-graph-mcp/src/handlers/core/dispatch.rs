@@ -8,6 +8,7 @@ use tracing::debug;
 use crate::protocol::{error_codes, methods, JsonRpcRequest, JsonRpcResponse};
 
 use super::handlers::Handlers;
+use super::progress::RequestContext;
 
 impl Handlers {
     /// Dispatch a request to the appropriate handler.
@@ -16,19 +17,50 @@ impl Handlers {
     /// - tools/list: List available tools
     /// - tools/call: Call a specific tool
     ///
+    /// Topic portfolio views are also exposed as MCP resources
+    /// (resources/list, resources/read, resources/subscribe).
+    ///
     /// Direct method calls (memory/store, etc.) are NOT supported.
     pub async fn dispatch(&self, request: JsonRpcRequest) -> JsonRpcResponse {
+        self.dispatch_with_context(request, RequestContext::default())
+            .await
+    }
+
+    /// Dispatch a request with a transport context.
+    ///
+    /// Progress notifications for long-running tools are sent through the
+    /// context's notifier, so the transport can write them to the client
+    /// before the final response.
+    pub async fn dispatch_with_context(
+        &self,
+        request: JsonRpcRequest,
+        ctx: RequestContext,
+    ) -> JsonRpcResponse {
         debug!("Dispatching method: {}", request.method);
 
         match request.method.as_str() {
             // MCP lifecycle methods
             methods::INITIALIZE => self.handle_initialize(request.id).await,
             "notifications/initialized" => self.handle_initialized_notification(),
+            methods::NOTIFICATION_CANCELLED => self.handle_cancelled_notification(request.params),
             methods::SHUTDOWN => self.handle_shutdown(request.id).await,
 
             // MCP tools protocol (PRD v6 Section 10)
             methods::TOOLS_LIST => self.handle_tools_list(request.id).await,
-            methods::TOOLS_CALL => self.handle_tools_call(request.id, request.params).await,
+            methods::TOOLS_CALL => {
+                self.handle_tools_call(request.id, request.params, ctx)
+                    .await
+            }
+
+            // MCP resources protocol (topic portfolio)
+            methods::RESOURCES_LIST => self.handle_resources_list(request.id, request.params),
+            methods::RESOURCES_READ => self.handle_resources_read(request.id, request.params),
+            methods::RESOURCES_SUBSCRIBE => {
+                self.handle_resources_subscription(request.id, request.params, &ctx, true)
+            }
+            methods::RESOURCES_UNSUBSCRIBE => {
+                self.handle_resources_subscription(request.id, request.params, &ctx, false)
+            }
 
             // Unknown method
             _ => JsonRpcResponse::error(
@@ -12,10 +12,11 @@ use std::sync::Arc;
 
 use parking_lot::RwLock;
 use serde_json::json;
-use tokio::sync::RwLock as TokioRwLock;
+use tokio::sync::{Mutex as TokioMutex, RwLock as TokioRwLock};
 use tracing::{info, warn};
 
-use context_graph_core::clustering::{ClusterError, MultiSpaceClusterManager};
+use context_graph_core::clustering::{ClusterError, MultiSpaceClusterManager, TermVocabulary};
+use context_graph_core::config::{BackupConfig, EdgeInferenceConfig, ToolAuditConfig};
 use context_graph_core::memory::{CodeEmbeddingProvider, CodeStorage};
 use context_graph_core::monitoring::LayerStatusProvider;
 use context_graph_core::traits::{MultiArrayEmbeddingProvider, TeleologicalMemoryStore};
@@ -23,7 +24,7 @@ use context_graph_core::traits::{MultiArrayEmbeddingProvider, TeleologicalMemory
 use context_graph_embeddings::models::CausalModel;
 #[cfg(feature = "llm")]
 use context_graph_graph_agent::GraphDiscoveryService;
-use context_graph_storage::{BackgroundGraphBuilder, EdgeRepository};
+use context_graph_storage::{BackgroundGraphBuilder, EdgeInferenceService, EdgeRepository};
 
 use crate::protocol::{JsonRpcId, JsonRpcResponse};
 
@@ -142,6 +143,60 @@ pub struct Handlers {
     /// Auto-consolidation status for daemon_status reporting.
     pub(in crate::handlers) auto_consolidation_status:
         Arc<TokioRwLock<crate::handlers::tools::consolidation::AutoConsolidationStatus>>,
+
+    /// Serializes load/modify/persist cycles of the cross-space divergence
+    /// alert log so concurrent scans and acknowledgements don't overwrite
+    /// each other.
+    pub(in crate::handlers) divergence_alerts_lock: Arc<TokioMutex<()>>,
+
+    /// Cancellation flags of running tools/call requests, set by
+    /// `notifications/cancelled`.
+    pub(in crate::handlers) in_flight: Arc<super::InFlightRequests>,
+
+    /// Notification channels subscribed to MCP resources (resources/subscribe).
+    pub(in crate::handlers) resource_subscriptions:
+        crate::handlers::resources::ResourceSubscriptions,
+
+    /// Per-session rate limits and per-category concurrency caps for tools/call.
+    /// Defaults until McpServer::new() applies `config.mcp.limits`.
+    pub(in crate::handlers) dispatch_limiter: super::DispatchLimiter,
+
+    /// Per-model embedding load state for get_embedding_status and the
+    /// RETRY_LATER readiness check. Injected by McpServer::new() via
+    /// set_provider_health(); None in tests, where models are always loaded.
+    pub(in crate::handlers) provider_health: Option<Arc<crate::adapters::ProviderHealth>>,
+
+    /// GPU capabilities detected at startup, reported by get_memetic_status
+    /// and used to refuse or degrade tools. Injected by McpServer::new() via
+    /// set_capability_matrix(); None in tests, where every tool runs normally.
+    pub(in crate::handlers) capability_matrix: Option<Arc<crate::adapters::CapabilityMatrix>>,
+
+    /// SPLADE vocabulary used by detect_topics to turn E6/E13 term indices
+    /// into topic labels. Injected by McpServer::new() via
+    /// set_term_vocabulary() when the sparse model's vocab.txt is present;
+    /// None otherwise, and labels are built from member content words.
+    pub(in crate::handlers) term_vocabulary: Option<Arc<dyn TermVocabulary>>,
+
+    /// Recently computed query embeddings, shared across tools so a prompt
+    /// searched and then stored is embedded once.
+    pub(in crate::handlers) query_embeddings: Arc<super::QueryEmbeddingCache>,
+
+    /// Edge inference run after store_memory and store_memories_batch.
+    /// Injected by McpServer::new() via set_edge_inference() from
+    /// `[mcp.edge_inference]`; None in tests unless a test enables it.
+    pub(in crate::handlers) edge_inference: Option<EdgeInferenceConfig>,
+
+    /// Audit log settings for mutating tool calls (`[mcp.audit]`).
+    /// Enabled with default retention unless set_tool_audit() overrides it.
+    pub(in crate::handlers) tool_audit: ToolAuditConfig,
+
+    /// create_backup settings (`[mcp.backup]`). Disabled unless
+    /// set_backup() enables it.
+    pub(in crate::handlers) backup: BackupConfig,
+
+    /// Per-component caches behind get_memetic_status, kept current by
+    /// store and delete hooks.
+    pub(in crate::handlers) status_aggregator: Arc<super::StatusAggregator>,
 }
 
 impl Handlers {
@@ -180,6 +235,18 @@ impl Handlers {
             causal_model: None,
             daemon_state: None,
             auto_consolidation_status: Arc::new(TokioRwLock::new(crate::handlers::tools::consolidation::AutoConsolidationStatus::default())),
+            divergence_alerts_lock: Arc::new(TokioMutex::new(())),
+            in_flight: Arc::new(super::InFlightRequests::default()),
+            resource_subscriptions: Default::default(),
+            dispatch_limiter: Default::default(),
+            provider_health: None,
+            capability_matrix: None,
+            term_vocabulary: None,
+            query_embeddings: Arc::new(super::QueryEmbeddingCache::default()),
+            edge_inference: None,
+            tool_audit: ToolAuditConfig::default(),
+            backup: BackupConfig::default(),
+            status_aggregator: Arc::new(super::StatusAggregator::default()),
         })
     }
 
@@ -233,6 +300,18 @@ impl Handlers {
             causal_model: Some(causal_model),
             daemon_state: None,
             auto_consolidation_status: Arc::new(TokioRwLock::new(crate::handlers::tools::consolidation::AutoConsolidationStatus::default())),
+            divergence_alerts_lock: Arc::new(TokioMutex::new(())),
+            in_flight: Arc::new(super::InFlightRequests::default()),
+            resource_subscriptions: Default::default(),
+            dispatch_limiter: Default::default(),
+            provider_health: None,
+            capability_matrix: None,
+            term_vocabulary: None,
+            query_embeddings: Arc::new(super::QueryEmbeddingCache::default()),
+            edge_inference: None,
+            tool_audit: ToolAuditConfig::default(),
+            backup: BackupConfig::default(),
+            status_aggregator: Arc::new(super::StatusAggregator::default()),
         })
     }
 
@@ -275,6 +354,18 @@ impl Handlers {
             causal_model: None,
             daemon_state: None,
             auto_consolidation_status: Arc::new(TokioRwLock::new(crate::handlers::tools::consolidation::AutoConsolidationStatus::default())),
+            divergence_alerts_lock: Arc::new(TokioMutex::new(())),
+            in_flight: Arc::new(super::InFlightRequests::default()),
+            resource_subscriptions: Default::default(),
+            dispatch_limiter: Default::default(),
+            provider_health: None,
+            capability_matrix: None,
+            term_vocabulary: None,
+            query_embeddings: Arc::new(super::QueryEmbeddingCache::default()),
+            edge_inference: None,
+            tool_audit: ToolAuditConfig::default(),
+            backup: BackupConfig::default(),
+            status_aggregator: Arc::new(super::StatusAggregator::default()),
         })
     }
 
@@ -308,6 +399,12 @@ impl Handlers {
         self.edge_repository.as_ref()
     }
 
+    /// Attach an edge repository to handlers built without graph linking.
+    #[cfg(test)]
+    pub(crate) fn set_edge_repository(&mut self, edge_repository: EdgeRepository) {
+        self.edge_repository = Some(edge_repository);
+    }
+
     /// Get the background graph builder if available.
     ///
     /// The graph builder queues fingerprints on store_memory and builds K-NN edges in batches.
@@ -315,6 +412,49 @@ impl Handlers {
         self.graph_builder.as_ref()
     }
 
+    /// Infer edges for newly stored memories with `config`.
+    pub fn set_edge_inference(&mut self, config: EdgeInferenceConfig) {
+        info!(
+            enabled = config.enabled,
+            top_k = config.top_k,
+            max_fanout = config.max_fanout,
+            causal_edges = config.causal_edges,
+            "Edge inference configured"
+        );
+        self.edge_inference = Some(config);
+    }
+
+    /// Audit mutating tool calls with `config`.
+    pub fn set_tool_audit(&mut self, config: ToolAuditConfig) {
+        info!(
+            enabled = config.enabled,
+            retention_days = config.retention_days,
+            "Tool audit log configured"
+        );
+        self.tool_audit = config;
+    }
+
+    /// Configure the create_backup tool from `[mcp.backup]`.
+    pub fn set_backup(&mut self, config: BackupConfig) {
+        info!(
+            enabled = config.enabled,
+            root = %config.root,
+            "Backup tool configured"
+        );
+        self.backup = config;
+    }
+
+    /// The edge inference service, if enabled and an edge repository is attached.
+    pub(in crate::handlers) fn edge_inference(&self) -> Option<EdgeInferenceService> {
+        let config = self.edge_inference.as_ref().filter(|c| c.enabled)?;
+        let edge_repository = self.edge_repository.as_ref()?;
+        Some(EdgeInferenceService::new(
+            edge_repository.clone(),
+            Arc::clone(&self.teleological_store),
+            config.clone(),
+        ))
+    }
+
     // =========================================================================
     // Graph Discovery Agent Accessors (GRAPH-AGENT)
     // =========================================================================
@@ -421,11 +561,20 @@ impl Handlers {
             }
         }
 
+        // Apply the audit log retention policy
+        if self.tool_audit.enabled {
+            self.prune_tool_audit_log().await;
+        }
+
         let capabilities = json!({
             "protocolVersion": "2024-11-05",
             "capabilities": {
                 "tools": {
                     "listChanged": false
+                },
+                "resources": {
+                    "subscribe": true,
+                    "listChanged": false
                 }
             },
             "serverInfo": {
@@ -500,6 +649,8 @@ impl Handlers {
                 // Import into cluster manager
                 let mut cluster_manager = self.cluster_manager.write();
                 let imported = cluster_manager.import_portfolio(&portfolio);
+                drop(cluster_manager);
+                self.notify_topics_updated();
 
                 info!(
                     topic_count = imported,
@@ -0,0 +1,482 @@
+//! Rate limiting and concurrency caps for tools/call.
+//!
+//! Every tools/call passes through [`DispatchLimiter::admit`] before its
+//! handler runs:
+//! - A token bucket per client session (see [`RequestContext::with_session`])
+//!   bounds the sustained call rate. Requests without a session, such as
+//!   in-process dispatch, are not rate limited.
+//! - Search, store and maintenance tools each have a concurrency cap. Calls
+//!   over the cap wait for a slot, but only up to `max_queue_depth` per
+//!   category; beyond that the call is rejected immediately.
+//!
+//! Rejections are JSON-RPC errors with code `SERVER_BUSY` and
+//! `data.retryAfterMs`, so clients back off instead of piling up requests.
+//!
+//! [`RequestContext::with_session`]: super::RequestContext::with_session
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use context_graph_core::config::DispatchLimitsConfig;
+use parking_lot::Mutex;
+use serde_json::json;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::{debug, info};
+
+use crate::protocol::{error_codes, JsonRpcId, JsonRpcResponse};
+use crate::tools::tool_names;
+
+use super::handlers::Handlers;
+
+/// Sessions tracked before idle (fully refilled) buckets are pruned.
+const MAX_TRACKED_SESSIONS: usize = 1024;
+
+/// Tool groups that share a concurrency cap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolCategory {
+    /// Query-embedding searches (GPU-bound)
+    Search,
+    /// Writes: storing, importing and curating memories
+    Store,
+    /// Long-running batch work: consolidation, clustering, discovery, repair
+    Maintenance,
+}
+
+impl ToolCategory {
+    pub const ALL: [ToolCategory; 3] = [Self::Search, Self::Store, Self::Maintenance];
+
+    /// Category of `tool`, or `None` for cheap read/status tools that are
+    /// never capped.
+    pub fn of(tool: &str) -> Option<Self> {
+        match tool {
+            tool_names::SEARCH_GRAPH
+            | tool_names::SEARCH_GRAPH_BATCH
+            | tool_names::SEARCH_CAUSES
+            | tool_names::SEARCH_EFFECTS
+            | tool_names::SEARCH_CAUSAL_RELATIONSHIPS
+            | tool_names::SEARCH_CONNECTIONS
+            | tool_names::SEARCH_BY_KEYWORDS
+            | tool_names::QUERY_MEMORIES
+            | tool_names::SEARCH_CODE
+            | tool_names::SEARCH_ROBUST
+            | tool_names::SEARCH_BY_ENTITIES
+            | tool_names::FIND_RELATED_ENTITIES
+            | tool_names::SEARCH_BY_EMBEDDER
+            | tool_names::COMPARE_EMBEDDER_VIEWS
+            | tool_names::SEARCH_CROSS_EMBEDDER_ANOMALIES
+            | tool_names::SEARCH_BY_TOKENS
+            | tool_names::SEARCH_BY_EXPANSION
+            | tool_names::SEARCH_RECENT
+            | tool_names::SEARCH_PERIODIC
+            | tool_names::GET_UNIFIED_NEIGHBORS => Some(Self::Search),
+            tool_names::STORE_MEMORY
+            | tool_names::STORE_MEMORIES_BATCH
+            | tool_names::IMPORT_MEMORIES
+            | tool_names::PROMOTE_STAGED
+            | tool_names::END_STAGED_SESSION
+            | tool_names::MERGE_CONCEPTS
+            | tool_names::FORGET_CONCEPT
+            | tool_names::BOOST_IMPORTANCE
+            | tool_names::DELETE_FILE_CONTENT => Some(Self::Store),
+            tool_names::TRIGGER_CONSOLIDATION
+            | tool_names::DETECT_TOPICS
+            | tool_names::RECONCILE_FILES
+            | tool_names::TRIGGER_CAUSAL_DISCOVERY
+            | tool_names::DISCOVER_GRAPH_RELATIONSHIPS
+            | tool_names::REPAIR_CAUSAL_RELATIONSHIPS
+            | tool_names::CREATE_BACKUP
+            | tool_names::EXPORT_MEMORIES => Some(Self::Maintenance),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Search => "search",
+            Self::Store => "store",
+            Self::Maintenance => "maintenance",
+        }
+    }
+}
+
+/// Why a call was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusyReason {
+    /// The session used up its token bucket
+    RateLimited,
+    /// `max_queue_depth` calls were already waiting in this category
+    QueueFull(ToolCategory),
+}
+
+/// Rejection returned by [`DispatchLimiter::admit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServerBusy {
+    pub reason: BusyReason,
+    pub retry_after_ms: u64,
+}
+
+impl ServerBusy {
+    /// `SERVER_BUSY` error carrying the reason and retry-after hint in `data`.
+    pub fn into_response(self, id: Option<JsonRpcId>) -> JsonRpcResponse {
+        let (reason, category, message) = match self.reason {
+            BusyReason::RateLimited => (
+                "rate_limited",
+                None,
+                "session rate limit exceeded".to_string(),
+            ),
+            BusyReason::QueueFull(category) => (
+                "queue_full",
+                Some(category.as_str()),
+                format!("too many {} calls queued", category.as_str()),
+            ),
+        };
+        JsonRpcResponse::error_with_data(
+            id,
+            error_codes::SERVER_BUSY,
+            format!(
+                "Server busy: {}; retry after {} ms",
+                message, self.retry_after_ms
+            ),
+            json!({
+                "reason": reason,
+                "category": category,
+                "retryAfterMs": self.retry_after_ms
+            }),
+        )
+    }
+}
+
+/// Held for the duration of an admitted call; frees its category slot on drop.
+#[derive(Debug)]
+pub struct DispatchPermit {
+    _slot: Option<OwnedSemaphorePermit>,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn level(&self, now: Instant, rps: f64, burst: f64) -> f64 {
+        (self.tokens + now.duration_since(self.updated).as_secs_f64() * rps).min(burst)
+    }
+}
+
+#[derive(Debug)]
+struct CategoryGate {
+    slots: Arc<Semaphore>,
+    limit: usize,
+    waiting: AtomicUsize,
+}
+
+impl CategoryGate {
+    fn new(limit: usize) -> Self {
+        Self {
+            slots: Arc::new(Semaphore::new(limit)),
+            limit,
+            waiting: AtomicUsize::new(0),
+        }
+    }
+}
+
+/// Decrements a gate's waiting count when the waiter is admitted or dropped.
+struct WaitingGuard<'a>(&'a AtomicUsize);
+
+impl Drop for WaitingGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Per-session rate limits and per-category concurrency caps.
+#[derive(Debug)]
+pub struct DispatchLimiter {
+    config: DispatchLimitsConfig,
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+    gates: [CategoryGate; 3],
+    rate_limited: AtomicU64,
+    queue_rejected: AtomicU64,
+}
+
+impl Default for DispatchLimiter {
+    fn default() -> Self {
+        Self::new(DispatchLimitsConfig::default())
+    }
+}
+
+impl DispatchLimiter {
+    pub fn new(config: DispatchLimitsConfig) -> Self {
+        let gates = [
+            CategoryGate::new(config.search_concurrency),
+            CategoryGate::new(config.store_concurrency),
+            CategoryGate::new(config.maintenance_concurrency),
+        ];
+        Self {
+            config,
+            buckets: Mutex::new(HashMap::new()),
+            gates,
+            rate_limited: AtomicU64::new(0),
+            queue_rejected: AtomicU64::new(0),
+        }
+    }
+
+    fn gate(&self, category: ToolCategory) -> &CategoryGate {
+        &self.gates[category as usize]
+    }
+
+    /// Admit a call to `tool` from `session`, waiting for a category slot if
+    /// the queue has room.
+    pub async fn admit(
+        &self,
+        tool: &str,
+        session: Option<&str>,
+    ) -> Result<DispatchPermit, ServerBusy> {
+        if !self.config.enabled {
+            return Ok(DispatchPermit { _slot: None });
+        }
+        if let Some(session) = session {
+            self.take_token(session)?;
+        }
+        let Some(category) = ToolCategory::of(tool) else {
+            return Ok(DispatchPermit { _slot: None });
+        };
+
+        let gate = self.gate(category);
+        if let Ok(slot) = Arc::clone(&gate.slots).try_acquire_owned() {
+            return Ok(DispatchPermit { _slot: Some(slot) });
+        }
+        if gate.waiting.fetch_add(1, Ordering::AcqRel) >= self.config.max_queue_depth {
+            gate.waiting.fetch_sub(1, Ordering::AcqRel);
+            self.queue_rejected.fetch_add(1, Ordering::Relaxed);
+            debug!(tool, category = category.as_str(), "Dispatch queue full");
+            return Err(ServerBusy {
+                reason: BusyReason::QueueFull(category),
+                retry_after_ms: self.config.busy_retry_after_ms,
+            });
+        }
+        let _waiting = WaitingGuard(&gate.waiting);
+        let slot = Arc::clone(&gate.slots)
+            .acquire_owned()
+            .await
+            .expect("dispatch semaphores are never closed");
+        Ok(DispatchPermit { _slot: Some(slot) })
+    }
+
+    /// Take up to `extra` more `category` slots for an admitted call that
+    /// can split its work, such as search_graph_batch. Never waits or
+    /// queues: only free slots are granted, so the call runs on at most
+    /// 1 + the returned permits and the category cap still holds. With
+    /// limits disabled, slots up to the configured cap are granted.
+    pub fn try_admit_extra(&self, category: ToolCategory, extra: usize) -> Vec<DispatchPermit> {
+        let gate = self.gate(category);
+        let extra = extra.min(gate.limit.saturating_sub(1));
+        if !self.config.enabled {
+            return (0..extra).map(|_| DispatchPermit { _slot: None }).collect();
+        }
+        (0..extra)
+            .map_while(|_| Arc::clone(&gate.slots).try_acquire_owned().ok())
+            .map(|slot| DispatchPermit { _slot: Some(slot) })
+            .collect()
+    }
+
+    fn take_token(&self, session: &str) -> Result<(), ServerBusy> {
+        let now = Instant::now();
+        let rps = self.config.session_rps;
+        let burst = f64::from(self.config.session_burst);
+
+        let mut buckets = self.buckets.lock();
+        if buckets.len() >= MAX_TRACKED_SESSIONS && !buckets.contains_key(session) {
+            // A fully refilled bucket is indistinguishable from a new one
+            buckets.retain(|_, bucket| bucket.level(now, rps, burst) < burst);
+        }
+        let bucket = buckets.entry(session.to_string()).or_insert(TokenBucket {
+            tokens: burst,
+            updated: now,
+        });
+        bucket.tokens = bucket.level(now, rps, burst);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Ok(());
+        }
+        let retry_after_ms = ((1.0 - bucket.tokens) / rps * 1000.0).ceil() as u64;
+        self.rate_limited.fetch_add(1, Ordering::Relaxed);
+        debug!(session, retry_after_ms, "Session rate limited");
+        Err(ServerBusy {
+            reason: BusyReason::RateLimited,
+            retry_after_ms,
+        })
+    }
+
+    /// Configured limits plus live slot usage, for get_memetic_status.
+    pub fn status(&self) -> serde_json::Value {
+        let mut categories = serde_json::Map::new();
+        for category in ToolCategory::ALL {
+            let gate = self.gate(category);
+            categories.insert(
+                category.as_str().to_string(),
+                json!({
+                    "limit": gate.limit,
+                    "inFlight": gate.limit - gate.slots.available_permits(),
+                    "queued": gate.waiting.load(Ordering::Acquire)
+                }),
+            );
+        }
+        json!({
+            "enabled": self.config.enabled,
+            "sessionRps": self.config.session_rps,
+            "sessionBurst": self.config.session_burst,
+            "maxQueueDepth": self.config.max_queue_depth,
+            "trackedSessions": self.buckets.lock().len(),
+            "categories": categories,
+            "rejected": {
+                "rateLimited": self.rate_limited.load(Ordering::Relaxed),
+                "queueFull": self.queue_rejected.load(Ordering::Relaxed)
+            }
+        })
+    }
+}
+
+impl Handlers {
+    /// Replace the default dispatch limits with configured ones.
+    ///
+    /// Called by McpServer::new() with `config.mcp.limits` before Arc-wrapping.
+    pub fn set_dispatch_limits(&mut self, config: DispatchLimitsConfig) {
+        info!(
+            enabled = config.enabled,
+            session_rps = config.session_rps,
+            search = config.search_concurrency,
+            store = config.store_concurrency,
+            maintenance = config.maintenance_concurrency,
+            "Dispatch limits configured"
+        );
+        self.dispatch_limiter = DispatchLimiter::new(config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn limits(search_concurrency: usize, max_queue_depth: usize) -> DispatchLimitsConfig {
+        DispatchLimitsConfig {
+            search_concurrency,
+            max_queue_depth,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_cap_bounds_overlap_and_rejects_overflow() {
+        let limiter = Arc::new(DispatchLimiter::new(limits(5, 20)));
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let calls: Vec<_> = (0..100)
+            .map(|i| {
+                let limiter = Arc::clone(&limiter);
+                let running = Arc::clone(&running);
+                let peak = Arc::clone(&peak);
+                tokio::spawn(async move {
+                    let _permit = match limiter.admit(tool_names::SEARCH_GRAPH, None).await {
+                        Ok(permit) => permit,
+                        Err(busy) => return Err(busy.into_response(Some(JsonRpcId::Number(i)))),
+                    };
+                    // Instrumented handler: record how many calls overlap
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(5)).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                    Ok(())
+                })
+            })
+            .collect();
+
+        let mut completed = 0;
+        let mut rejected = 0;
+        for call in calls {
+            match call.await.unwrap() {
+                Ok(()) => completed += 1,
+                Err(response) => {
+                    let error = response.error.expect("busy must be a JSON-RPC error");
+                    assert_eq!(error.code, error_codes::SERVER_BUSY);
+                    let data = error.data.expect("busy error carries data");
+                    assert_eq!(data["reason"], json!("queue_full"));
+                    assert_eq!(data["retryAfterMs"], json!(500));
+                    rejected += 1;
+                }
+            }
+        }
+
+        assert!(peak.load(Ordering::SeqCst) <= 5, "cap of 5 exceeded");
+        assert_eq!(completed, 25, "5 running plus 20 queued");
+        assert_eq!(rejected, 75);
+        let status = limiter.status();
+        assert_eq!(status["rejected"]["queueFull"], json!(75));
+        assert_eq!(status["categories"]["search"]["inFlight"], json!(0));
+    }
+
+    #[tokio::test]
+    async fn test_session_bucket_limits_rate_per_session() {
+        let limiter = DispatchLimiter::new(DispatchLimitsConfig {
+            session_rps: 1.0,
+            session_burst: 2,
+            ..Default::default()
+        });
+        for _ in 0..2 {
+            assert!(limiter.admit("get_memetic_status", Some("a")).await.is_ok());
+        }
+        let busy = limiter
+            .admit("get_memetic_status", Some("a"))
+            .await
+            .unwrap_err();
+        assert_eq!(busy.reason, BusyReason::RateLimited);
+        assert!(busy.retry_after_ms > 0 && busy.retry_after_ms <= 1000);
+
+        // Other sessions and session-less calls have their own budget
+        assert!(limiter.admit("get_memetic_status", Some("b")).await.is_ok());
+        assert!(limiter.admit("get_memetic_status", None).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_extra_slots_never_exceed_cap() {
+        let limiter = DispatchLimiter::new(limits(4, 20));
+        let _first = limiter
+            .admit(tool_names::SEARCH_GRAPH_BATCH, None)
+            .await
+            .unwrap();
+        let extra = limiter.try_admit_extra(ToolCategory::Search, 10);
+        assert_eq!(extra.len(), 3, "only the free slots are granted");
+        assert!(limiter.try_admit_extra(ToolCategory::Search, 10).is_empty());
+        assert_eq!(
+            limiter.status()["categories"]["search"]["inFlight"],
+            json!(4)
+        );
+
+        drop(extra);
+        assert_eq!(limiter.try_admit_extra(ToolCategory::Search, 1).len(), 1);
+    }
+
+    #[test]
+    fn test_uncategorized_tools_are_not_capped() {
+        assert_eq!(
+            ToolCategory::of(tool_names::SEARCH_GRAPH),
+            Some(ToolCategory::Search)
+        );
+        assert_eq!(
+            ToolCategory::of(tool_names::STORE_MEMORY),
+            Some(ToolCategory::Store)
+        );
+        assert_eq!(
+            ToolCategory::of(tool_names::TRIGGER_CONSOLIDATION),
+            Some(ToolCategory::Maintenance)
+        );
+        assert_eq!(ToolCategory::of(tool_names::GET_MEMETIC_STATUS), None);
+    }
+}
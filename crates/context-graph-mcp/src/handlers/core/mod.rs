@@ -7,5 +7,18 @@
 
 mod dispatch;
 mod handlers;
+mod limits;
+mod progress;
+mod query_embedding;
+mod status_aggregator;
+mod tool_audit;
 
 pub use self::handlers::Handlers;
+pub use self::limits::{BusyReason, ServerBusy, ToolCategory};
+pub(crate) use self::limits::DispatchLimiter;
+pub use self::progress::{
+    CancellationFlag, Cancelled, NotificationSender, ProgressReporter, RequestContext,
+};
+pub(crate) use self::progress::InFlightRequests;
+pub use self::query_embedding::{QueryEmbeddingBundle, QueryEmbeddingCache};
+pub use self::status_aggregator::{StatusAggregator, StorageStatus};
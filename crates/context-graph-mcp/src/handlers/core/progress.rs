@@ -0,0 +1,299 @@
+//! Progress notifications and cancellation for long-running tool calls.
+//!
+//! MCP clients opt into progress by sending `_meta.progressToken` in the
+//! tools/call params. Handlers report stages through a [`ProgressReporter`];
+//! the transport writes each `notifications/progress` message to the client
+//! while the call is still running. Without a token, or when the caller did
+//! not supply a notification channel, reporting is a no-op.
+//!
+//! Clients cancel with `notifications/cancelled { requestId }`. Handlers check
+//! the flag at stage boundaries via [`ProgressReporter::checkpoint`], so work
+//! stops before the next stage instead of mid-write.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use serde_json::json;
+use tokio::sync::mpsc;
+use tracing::{debug, info};
+
+use crate::protocol::{methods, JsonRpcId, JsonRpcNotification, JsonRpcResponse};
+
+use super::handlers::Handlers;
+
+/// Channel the transport drains to deliver server-initiated notifications.
+pub type NotificationSender = mpsc::UnboundedSender<JsonRpcNotification>;
+
+/// Per-request transport context passed to [`Handlers::dispatch_with_context`].
+#[derive(Debug, Clone, Default)]
+pub struct RequestContext {
+    notifier: Option<NotificationSender>,
+    session: Option<String>,
+}
+
+impl RequestContext {
+    /// Context whose notifications are delivered through `notifier`.
+    pub fn with_notifier(notifier: NotificationSender) -> Self {
+        Self {
+            notifier: Some(notifier),
+            session: None,
+        }
+    }
+
+    /// Attribute the request to a client session for per-session rate limiting.
+    pub fn with_session(mut self, session: impl Into<String>) -> Self {
+        self.session = Some(session.into());
+        self
+    }
+
+    /// Channel for notifications to this request's client, if any.
+    pub(crate) fn notifier(&self) -> Option<&NotificationSender> {
+        self.notifier.as_ref()
+    }
+
+    /// Client session the request belongs to, if the transport set one.
+    pub(crate) fn session(&self) -> Option<&str> {
+        self.session.as_deref()
+    }
+}
+
+/// Shared flag set when the client cancels a request.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationFlag(Arc<AtomicBool>);
+
+impl CancellationFlag {
+    /// Mark the request as cancelled.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    /// Check if the request was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+/// Cancellation flags of tools/call requests currently executing.
+///
+/// Keyed by the serialized request ID so string and numeric IDs never collide.
+#[derive(Debug, Default)]
+pub(crate) struct InFlightRequests {
+    requests: Mutex<HashMap<String, CancellationFlag>>,
+}
+
+impl InFlightRequests {
+    fn key(id: &JsonRpcId) -> String {
+        serde_json::to_string(id).unwrap_or_default()
+    }
+
+    /// Register `id` and return its flag plus a guard that unregisters it.
+    pub(crate) fn register(self: &Arc<Self>, id: &JsonRpcId) -> (CancellationFlag, InFlightGuard) {
+        let flag = CancellationFlag::default();
+        let key = Self::key(id);
+        self.requests.lock().insert(key.clone(), flag.clone());
+        let guard = InFlightGuard {
+            requests: Arc::clone(self),
+            key,
+        };
+        (flag, guard)
+    }
+
+    /// Cancel the request with `id`. Returns false if it is not running.
+    pub(crate) fn cancel(&self, id: &JsonRpcId) -> bool {
+        match self.requests.lock().get(&Self::key(id)) {
+            Some(flag) => {
+                flag.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Removes a request from [`InFlightRequests`] when the call returns.
+pub(crate) struct InFlightGuard {
+    requests: Arc<InFlightRequests>,
+    key: String,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.requests.requests.lock().remove(&self.key);
+    }
+}
+
+/// Returned by [`ProgressReporter::checkpoint`] after a client cancel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+/// Handle long-running tool handlers use to report progress and observe
+/// cancellation.
+#[derive(Debug, Clone, Default)]
+pub struct ProgressReporter {
+    token: Option<serde_json::Value>,
+    notifier: Option<NotificationSender>,
+    cancel: CancellationFlag,
+}
+
+impl ProgressReporter {
+    /// Reporter that sends nothing and is never cancelled.
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn new(
+        token: Option<serde_json::Value>,
+        notifier: Option<NotificationSender>,
+        cancel: CancellationFlag,
+    ) -> Self {
+        Self {
+            token,
+            notifier,
+            cancel,
+        }
+    }
+
+    /// Check if progress notifications reach a client.
+    pub fn is_enabled(&self) -> bool {
+        self.token.is_some() && self.notifier.is_some()
+    }
+
+    /// Report `progress` out of `total` (if known) with a stage label.
+    pub fn report(&self, progress: u64, total: Option<u64>, message: &str) {
+        let (Some(token), Some(notifier)) = (&self.token, &self.notifier) else {
+            return;
+        };
+        let mut params = json!({
+            "progressToken": token,
+            "progress": progress,
+            "message": message,
+        });
+        if let Some(total) = total {
+            params["total"] = json!(total);
+        }
+        // The receiver is gone once the connection closes; nothing to report to
+        if notifier
+            .send(JsonRpcNotification::new(
+                methods::NOTIFICATION_PROGRESS,
+                params,
+            ))
+            .is_err()
+        {
+            debug!("Progress notification dropped: transport closed");
+        }
+    }
+
+    /// Stage boundary: yield so the transport can deliver a pending cancel,
+    /// then fail if the request was cancelled.
+    pub async fn checkpoint(&self) -> Result<(), Cancelled> {
+        tokio::task::yield_now().await;
+        if self.cancel.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Handlers {
+    /// Build the reporter for a tools/call from its `_meta.progressToken`.
+    pub(crate) fn progress_reporter(
+        params: &serde_json::Value,
+        ctx: &RequestContext,
+        cancel: CancellationFlag,
+    ) -> ProgressReporter {
+        let token = params
+            .get("_meta")
+            .and_then(|meta| meta.get("progressToken"))
+            .filter(|token| token.is_string() || token.is_number())
+            .cloned();
+        ProgressReporter::new(token, ctx.notifier.clone(), cancel)
+    }
+
+    /// Tool error returned when a client cancels a call between stages.
+    pub(crate) fn cancelled_result(
+        &self,
+        id: Option<JsonRpcId>,
+        tool: &str,
+        next_stage: &str,
+    ) -> JsonRpcResponse {
+        info!(tool, next_stage, "Tool call stopped after client cancel");
+        self.tool_error(
+            id,
+            &format!("{} cancelled by client before stage '{}'", tool, next_stage),
+        )
+    }
+
+    /// Handle `notifications/cancelled` from the client.
+    pub fn handle_cancelled_notification(
+        &self,
+        params: Option<serde_json::Value>,
+    ) -> JsonRpcResponse {
+        let request_id = params
+            .as_ref()
+            .and_then(|p| p.get("requestId"))
+            .and_then(|id| serde_json::from_value::<JsonRpcId>(id.clone()).ok());
+        match request_id {
+            Some(request_id) => {
+                let reason = params
+                    .as_ref()
+                    .and_then(|p| p.get("reason"))
+                    .and_then(|r| r.as_str())
+                    .unwrap_or("none given");
+                if self.in_flight.cancel(&request_id) {
+                    info!(request_id = ?request_id, reason, "Request cancelled by client");
+                } else {
+                    debug!(request_id = ?request_id, "Cancel for unknown or finished request ignored");
+                }
+            }
+            None => debug!("notifications/cancelled without a valid requestId ignored"),
+        }
+        JsonRpcResponse {
+            jsonrpc: "2.0".to_string(),
+            id: None,
+            result: None,
+            error: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reporter_without_token_is_silent_and_never_cancelled() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let reporter = ProgressReporter::new(None, Some(tx), CancellationFlag::default());
+        assert!(!reporter.is_enabled());
+        reporter.report(1, Some(2), "stage");
+        assert!(rx.try_recv().is_err());
+        assert!(reporter.checkpoint().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_reporter_emits_progress_and_observes_cancel() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let requests = Arc::new(InFlightRequests::default());
+        let id = JsonRpcId::String("req-1".to_string());
+        let (flag, guard) = requests.register(&id);
+        let reporter = ProgressReporter::new(Some(json!("tok")), Some(tx), flag);
+
+        reporter.report(1, Some(4), "Scanning");
+        let note = rx.try_recv().unwrap();
+        assert_eq!(note.method, methods::NOTIFICATION_PROGRESS);
+        let params = note.params.unwrap();
+        assert_eq!(params["progressToken"], json!("tok"));
+        assert_eq!(params["progress"], json!(1));
+        assert_eq!(params["total"], json!(4));
+
+        assert!(reporter.checkpoint().await.is_ok());
+        assert!(requests.cancel(&id));
+        assert_eq!(reporter.checkpoint().await, Err(Cancelled));
+
+        drop(guard);
+        assert!(!requests.cancel(&id), "finished requests are unregistered");
+    }
+}
@@ -0,0 +1,172 @@
+//! Query embedding reuse across pipeline stages and tools.
+//!
+//! A query is embedded once per request into a [`QueryEmbeddingBundle`] and
+//! shared by every stage that needs it. Bundles are also kept in a small
+//! [`QueryEmbeddingCache`] for a few seconds, keyed by query text and
+//! embedder mask, so the hook sequence `search_graph(prompt)` followed by
+//! `inject_context(content = prompt)` embeds the prompt once instead of twice.
+//!
+//! Only content-derived spaces are reused across calls. Spaces that depend on
+//! per-call metadata (E2-E4 timestamps, E5 causal hint, E12 pruning) are
+//! recomputed by the caller, see `store_memory`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use context_graph_core::teleological::EmbedderMask;
+use context_graph_core::traits::PartialMultiArrayOutput;
+use context_graph_core::types::fingerprint::{SemanticFingerprint, NUM_EMBEDDERS};
+use parking_lot::Mutex;
+
+/// Default number of cached query bundles.
+pub const DEFAULT_QUERY_CACHE_CAPACITY: usize = 64;
+
+/// Default lifetime of a cached query bundle.
+pub const DEFAULT_QUERY_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Embeddings of one query text for the spaces in `mask`.
+#[derive(Debug, Clone)]
+pub struct QueryEmbeddingBundle {
+    /// Spaces computed for this bundle; the others are empty.
+    pub mask: EmbedderMask,
+    /// Query fingerprint.
+    pub fingerprint: SemanticFingerprint,
+    /// Wall-clock time the provider spent computing the bundle.
+    pub provider_latency: Duration,
+    /// Provider time per embedder (zero for spaces outside `mask`).
+    pub per_embedder_latency: [Duration; NUM_EMBEDDERS],
+    /// Model IDs used per embedder.
+    pub model_ids: [String; NUM_EMBEDDERS],
+}
+
+impl From<PartialMultiArrayOutput> for QueryEmbeddingBundle {
+    fn from(output: PartialMultiArrayOutput) -> Self {
+        Self {
+            mask: output.mask,
+            fingerprint: output.fingerprint,
+            provider_latency: output.total_latency,
+            per_embedder_latency: output.per_embedder_latency,
+            model_ids: output.model_ids,
+        }
+    }
+}
+
+struct CacheEntry {
+    bundle: Arc<QueryEmbeddingBundle>,
+    inserted_at: Instant,
+}
+
+/// Short-lived LRU of query bundles keyed by (query text, embedder mask).
+pub struct QueryEmbeddingCache {
+    capacity: usize,
+    ttl: Duration,
+    entries: Mutex<HashMap<(String, EmbedderMask), CacheEntry>>,
+}
+
+impl QueryEmbeddingCache {
+    /// Create a cache holding at most `capacity` bundles for `ttl` each.
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Fresh bundle for `text` that covers every space in `mask`.
+    ///
+    /// A bundle computed for a wider mask also serves narrower requests.
+    /// A hit refreshes the entry so recently used queries are evicted last.
+    pub fn get(&self, text: &str, mask: EmbedderMask) -> Option<Arc<QueryEmbeddingBundle>> {
+        let mut entries = self.entries.lock();
+        let now = Instant::now();
+        entries.retain(|_, entry| now.duration_since(entry.inserted_at) < self.ttl);
+
+        let entry = entries
+            .iter_mut()
+            .filter(|((t, m), _)| t == text && m.contains_all(mask))
+            .min_by_key(|((_, m), _)| m.count())
+            .map(|(_, entry)| entry)?;
+        entry.inserted_at = now;
+        Some(Arc::clone(&entry.bundle))
+    }
+
+    /// Cache `bundle` for `text`, evicting the least recently used entry
+    /// when full.
+    pub fn insert(&self, text: &str, bundle: Arc<QueryEmbeddingBundle>) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock();
+        let key = (text.to_string(), bundle.mask);
+        if !entries.contains_key(&key) && entries.len() >= self.capacity {
+            if let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.inserted_at)
+                .map(|(key, _)| key.clone())
+            {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(
+            key,
+            CacheEntry {
+                bundle,
+                inserted_at: Instant::now(),
+            },
+        );
+    }
+}
+
+impl Default for QueryEmbeddingCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_QUERY_CACHE_CAPACITY, DEFAULT_QUERY_CACHE_TTL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use context_graph_core::teleological::Embedder;
+
+    fn bundle(mask: EmbedderMask) -> Arc<QueryEmbeddingBundle> {
+        Arc::new(QueryEmbeddingBundle {
+            mask,
+            fingerprint: SemanticFingerprint::zeroed(),
+            provider_latency: Duration::ZERO,
+            per_embedder_latency: [Duration::ZERO; NUM_EMBEDDERS],
+            model_ids: std::array::from_fn(|_| String::new()),
+        })
+    }
+
+    #[test]
+    fn test_wider_mask_serves_narrower_request() {
+        let cache = QueryEmbeddingCache::default();
+        cache.insert("q", bundle(EmbedderMask::all()));
+
+        let semantic = EmbedderMask::from_slice(&[Embedder::Semantic]);
+        assert!(cache.get("q", semantic).is_some());
+        assert!(cache.get("other", semantic).is_none());
+
+        cache.insert("r", bundle(semantic));
+        assert!(cache.get("r", EmbedderMask::all()).is_none());
+    }
+
+    #[test]
+    fn test_capacity_and_ttl() {
+        let cache = QueryEmbeddingCache::new(2, Duration::from_secs(60));
+        cache.insert("a", bundle(EmbedderMask::all()));
+        cache.insert("b", bundle(EmbedderMask::all()));
+        // Touch "a" so "b" is the least recently used
+        assert!(cache.get("a", EmbedderMask::all()).is_some());
+        cache.insert("c", bundle(EmbedderMask::all()));
+        assert!(cache.get("a", EmbedderMask::all()).is_some());
+        assert!(cache.get("b", EmbedderMask::all()).is_none());
+        assert!(cache.get("c", EmbedderMask::all()).is_some());
+
+        let expired = QueryEmbeddingCache::new(2, Duration::ZERO);
+        expired.insert("a", bundle(EmbedderMask::all()));
+        assert!(expired.get("a", EmbedderMask::all()).is_none());
+    }
+}
@@ -0,0 +1,373 @@
+//! Component-level caching for get_memetic_status.
+//!
+//! get_memetic_status used to recompute every figure on each call. The
+//! [`StatusAggregator`] keeps one cache entry per component and refreshes
+//! each under its own policy:
+//!
+//! - **counts** (total and per-namespace fingerprint counts): maintained
+//!   incrementally by [`StatusAggregator::record_stored`] on every store and
+//!   dropped by [`StatusAggregator::invalidate_counts`] after tools that
+//!   delete, merge or move memories. Recomputed after `counts_max_age` so
+//!   writes from background jobs are picked up.
+//! - **layers** (LayerStatusProvider): recomputed after `layers_ttl`.
+//! - **storage** (on-disk size and per-column-family `StorageMetrics`):
+//!   refreshed every `storage_refresh_interval`, or earlier once
+//!   `storage_dirty_threshold` writes have happened since the last refresh.
+//!   Served with `stale: true` in between.
+//!
+//! `force_refresh` bypasses every cache. Cheap live values (dispatch limits,
+//! GPU k-NN bytes, pipeline latency, capabilities) are read on every call and
+//! are not cached here.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+
+use context_graph_core::error::CoreResult;
+use context_graph_core::monitoring::{LayerStatusProvider, MonitorResult};
+use context_graph_core::traits::{StorageMetrics, TeleologicalMemoryStore};
+
+use crate::tools::tool_names;
+
+/// Default age after which cached counts are recomputed.
+pub const DEFAULT_COUNTS_MAX_AGE: Duration = Duration::from_secs(60);
+
+/// Default lifetime of cached layer statuses.
+pub const DEFAULT_LAYERS_TTL: Duration = Duration::from_secs(30);
+
+/// Default interval between storage size refreshes.
+pub const DEFAULT_STORAGE_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Default number of writes that forces an early storage size refresh.
+pub const DEFAULT_STORAGE_DIRTY_THRESHOLD: usize = 64;
+
+/// Tools that change fingerprint counts other than by storing new memories.
+const COUNT_INVALIDATING_TOOLS: &[&str] = &[
+    tool_names::FORGET_CONCEPT,
+    tool_names::MERGE_CONCEPTS,
+    tool_names::TRIGGER_CONSOLIDATION,
+    tool_names::DELETE_FILE_CONTENT,
+    tool_names::RECONCILE_FILES,
+    tool_names::IMPORT_MEMORIES,
+    tool_names::PROMOTE_STAGED,
+    tool_names::END_STAGED_SESSION,
+    tool_names::TRIGGER_CAUSAL_DISCOVERY,
+];
+
+/// Refresh policy of each cached component.
+#[derive(Debug, Clone)]
+pub struct StatusCacheConfig {
+    pub counts_max_age: Duration,
+    pub layers_ttl: Duration,
+    pub storage_refresh_interval: Duration,
+    pub storage_dirty_threshold: usize,
+}
+
+impl Default for StatusCacheConfig {
+    fn default() -> Self {
+        Self {
+            counts_max_age: DEFAULT_COUNTS_MAX_AGE,
+            layers_ttl: DEFAULT_LAYERS_TTL,
+            storage_refresh_interval: DEFAULT_STORAGE_REFRESH_INTERVAL,
+            storage_dirty_threshold: DEFAULT_STORAGE_DIRTY_THRESHOLD,
+        }
+    }
+}
+
+/// A component value with the time it was computed.
+#[derive(Debug, Clone)]
+pub struct StatusComponent<T> {
+    pub value: T,
+    pub computed_at: DateTime<Utc>,
+    /// The value was served from cache and writes have happened since.
+    pub stale: bool,
+}
+
+impl<T> StatusComponent<T> {
+    /// `{"computed_at": ..., "stale": ...}` for the response.
+    pub fn meta(&self) -> Value {
+        json!({
+            "computed_at": self.computed_at,
+            "stale": self.stale,
+        })
+    }
+}
+
+/// Total and per-namespace fingerprint counts.
+#[derive(Debug, Clone)]
+pub struct FingerprintCounts {
+    pub total: usize,
+    pub by_namespace: HashMap<String, usize>,
+}
+
+/// Total on-disk size plus its per-column-family breakdown.
+#[derive(Debug, Clone)]
+pub struct StorageStatus {
+    pub size_bytes: usize,
+    pub metrics: StorageMetrics,
+}
+
+/// Status of the four reported layers.
+#[derive(Debug, Clone)]
+pub struct LayerStatuses {
+    pub perception: String,
+    pub memory: String,
+    pub action: String,
+    pub meta: String,
+}
+
+/// A layer whose status could not be read.
+#[derive(Debug)]
+pub struct LayerStatusError {
+    pub layer: &'static str,
+    pub message: String,
+}
+
+struct Cached<T> {
+    value: T,
+    computed_at: DateTime<Utc>,
+    refreshed: Instant,
+}
+
+impl<T: Clone> Cached<T> {
+    fn new(value: T) -> Self {
+        Self {
+            value,
+            computed_at: Utc::now(),
+            refreshed: Instant::now(),
+        }
+    }
+
+    fn component(&self, stale: bool) -> StatusComponent<T> {
+        StatusComponent {
+            value: self.value.clone(),
+            computed_at: self.computed_at,
+            stale,
+        }
+    }
+}
+
+/// Per-component status caches with invalidation hooks.
+pub struct StatusAggregator {
+    config: StatusCacheConfig,
+    counts: Mutex<Option<Cached<FingerprintCounts>>>,
+    /// Bumped by every count change so a recompute that raced with a
+    /// store or delete is not cached.
+    counts_generation: AtomicU64,
+    layers: Mutex<Option<Cached<LayerStatuses>>>,
+    storage: Mutex<Option<Cached<StorageStatus>>>,
+    /// Writes since the storage size was last computed.
+    storage_dirty: AtomicUsize,
+}
+
+impl Default for StatusAggregator {
+    fn default() -> Self {
+        Self::new(StatusCacheConfig::default())
+    }
+}
+
+impl StatusAggregator {
+    /// Create an aggregator with empty caches.
+    pub fn new(config: StatusCacheConfig) -> Self {
+        Self {
+            config,
+            counts: Mutex::new(None),
+            counts_generation: AtomicU64::new(0),
+            layers: Mutex::new(None),
+            storage: Mutex::new(None),
+            storage_dirty: AtomicUsize::new(0),
+        }
+    }
+
+    /// Count a newly stored fingerprint in `namespace`.
+    pub fn record_stored(&self, namespace: &str) {
+        self.counts_generation.fetch_add(1, Ordering::SeqCst);
+        self.storage_dirty.fetch_add(1, Ordering::SeqCst);
+        if let Some(cached) = self.counts.lock().as_mut() {
+            cached.value.total += 1;
+            *cached
+                .value
+                .by_namespace
+                .entry(namespace.to_string())
+                .or_insert(0) += 1;
+            cached.computed_at = Utc::now();
+        }
+    }
+
+    /// Drop cached counts; the next status call recomputes them.
+    pub fn invalidate_counts(&self) {
+        self.counts_generation.fetch_add(1, Ordering::SeqCst);
+        self.storage_dirty.fetch_add(1, Ordering::SeqCst);
+        *self.counts.lock() = None;
+    }
+
+    /// Invalidation hook run by tools/call after every tool.
+    pub fn after_tool_call(&self, tool_name: &str) {
+        if COUNT_INVALIDATING_TOOLS.contains(&tool_name) {
+            self.invalidate_counts();
+        }
+    }
+
+    /// Fingerprint counts, cached until invalidated or `counts_max_age`.
+    pub async fn counts(
+        &self,
+        store: &dyn TeleologicalMemoryStore,
+        force_refresh: bool,
+    ) -> CoreResult<StatusComponent<FingerprintCounts>> {
+        if !force_refresh {
+            if let Some(cached) = self.counts.lock().as_ref() {
+                if cached.refreshed.elapsed() < self.config.counts_max_age {
+                    return Ok(cached.component(false));
+                }
+            }
+        }
+
+        let generation = self.counts_generation.load(Ordering::SeqCst);
+        let total = store.count().await?;
+        let by_namespace = store.count_by_namespace().await?;
+        let cached = Cached::new(FingerprintCounts {
+            total,
+            by_namespace,
+        });
+        let component = cached.component(false);
+        if self.counts_generation.load(Ordering::SeqCst) == generation {
+            *self.counts.lock() = Some(cached);
+        }
+        Ok(component)
+    }
+
+    /// Layer statuses, cached for `layers_ttl`.
+    pub async fn layers(
+        &self,
+        provider: &dyn LayerStatusProvider,
+        force_refresh: bool,
+    ) -> Result<StatusComponent<LayerStatuses>, LayerStatusError> {
+        if !force_refresh {
+            if let Some(cached) = self.layers.lock().as_ref() {
+                if cached.refreshed.elapsed() < self.config.layers_ttl {
+                    return Ok(cached.component(false));
+                }
+            }
+        }
+
+        fn read(
+            layer: &'static str,
+            status: MonitorResult<context_graph_core::monitoring::LayerStatus>,
+        ) -> Result<String, LayerStatusError> {
+            status
+                .map(|s| s.as_str().to_string())
+                .map_err(|e| LayerStatusError {
+                    layer,
+                    message: e.to_string(),
+                })
+        }
+
+        let cached = Cached::new(LayerStatuses {
+            perception: read("perception", provider.perception_status().await)?,
+            memory: read("memory", provider.memory_status().await)?,
+            action: read("action", provider.action_status().await)?,
+            meta: read("meta", provider.meta_status().await)?,
+        });
+        let component = cached.component(false);
+        *self.layers.lock() = Some(cached);
+        Ok(component)
+    }
+
+    /// Storage size and metrics, refreshed on `storage_refresh_interval` or
+    /// after `storage_dirty_threshold` writes.
+    pub fn storage(
+        &self,
+        store: &dyn TeleologicalMemoryStore,
+        force_refresh: bool,
+    ) -> CoreResult<StatusComponent<StorageStatus>> {
+        let dirty = self.storage_dirty.load(Ordering::SeqCst);
+        if !force_refresh && dirty < self.config.storage_dirty_threshold {
+            if let Some(cached) = self.storage.lock().as_ref() {
+                if cached.refreshed.elapsed() < self.config.storage_refresh_interval {
+                    return Ok(cached.component(dirty > 0));
+                }
+            }
+        }
+
+        let metrics = store.storage_metrics()?;
+        self.storage_dirty.fetch_sub(dirty, Ordering::SeqCst);
+        let cached = Cached::new(StorageStatus {
+            size_bytes: store.storage_size_bytes(),
+            metrics,
+        });
+        let component = cached.component(false);
+        *self.storage.lock() = Some(cached);
+        Ok(component)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use context_graph_core::monitoring::StubLayerStatusProvider;
+    use context_graph_core::stubs::InMemoryTeleologicalStore;
+
+    #[tokio::test]
+    async fn test_record_stored_updates_cached_counts() {
+        let store = InMemoryTeleologicalStore::new();
+        let aggregator = StatusAggregator::default();
+
+        let first = aggregator.counts(&store, false).await.unwrap();
+        assert_eq!(first.value.total, 0);
+
+        aggregator.record_stored("project-a");
+        aggregator.record_stored("project-a");
+        let counts = aggregator.counts(&store, false).await.unwrap();
+        assert_eq!(counts.value.total, 2);
+        assert_eq!(counts.value.by_namespace["project-a"], 2);
+        assert!(counts.computed_at >= first.computed_at);
+
+        // Invalidation falls back to the store, which holds nothing
+        aggregator.invalidate_counts();
+        let counts = aggregator.counts(&store, false).await.unwrap();
+        assert_eq!(counts.value.total, 0);
+    }
+
+    #[tokio::test]
+    async fn test_storage_size_stale_until_dirty_threshold() {
+        let store = InMemoryTeleologicalStore::new();
+        let aggregator = StatusAggregator::new(StatusCacheConfig {
+            storage_dirty_threshold: 3,
+            ..Default::default()
+        });
+
+        let first = aggregator.storage(&store, false).unwrap();
+        assert!(!first.stale);
+
+        aggregator.record_stored("default");
+        let cached = aggregator.storage(&store, false).unwrap();
+        assert!(cached.stale, "a write since the last refresh marks it stale");
+        assert_eq!(cached.computed_at, first.computed_at);
+
+        aggregator.record_stored("default");
+        aggregator.record_stored("default");
+        let refreshed = aggregator.storage(&store, false).unwrap();
+        assert!(!refreshed.stale);
+        assert!(refreshed.computed_at >= first.computed_at);
+    }
+
+    #[tokio::test]
+    async fn test_layers_cached_until_forced() {
+        let provider = StubLayerStatusProvider;
+        let aggregator = StatusAggregator::default();
+
+        let first = aggregator.layers(&provider, false).await.unwrap();
+        let cached = aggregator.layers(&provider, false).await.unwrap();
+        assert_eq!(cached.computed_at, first.computed_at);
+        assert_eq!(cached.value.memory, first.value.memory);
+
+        let forced = aggregator.layers(&provider, true).await.unwrap();
+        assert!(forced.computed_at >= first.computed_at);
+        assert!(!forced.stale);
+    }
+}
@@ -0,0 +1,315 @@
+//! Audit log of mutating tool calls.
+//!
+//! tools/call runs every audited tool between [`Handlers::begin_tool_audit`]
+//! and [`Handlers::finish_tool_audit`], which together write one
+//! `ToolAuditRecord`: tool, session, redacted arguments, affected memory IDs
+//! and outcome. `query_audit_log` reads the records back.
+//!
+//! # Redaction
+//!
+//! All redaction rules live in [`audit_policy`]. A tool without a policy is
+//! not audited; a tool with one records only the argument fields its policy
+//! lists verbatim. Every other string, at any depth, is replaced by its
+//! SHA-256 digest and length. Adding a tool or a field therefore cannot log
+//! raw content unless the field is explicitly allowlisted here. Numbers and
+//! booleans are kept: they carry no content.
+//!
+//! Calls rejected before dispatch (schema errors, rate limits, missing
+//! capabilities) never reach the handler and are not audited.
+
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use context_graph_core::types::audit::{ToolAuditOutcome, ToolAuditRecord};
+
+use crate::protocol::JsonRpcResponse;
+use crate::tools::tool_names;
+
+use super::handlers::Handlers;
+use super::RequestContext;
+
+/// Redaction rules for one audited tool.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AuditPolicy {
+    /// Argument fields recorded verbatim (matched by name at any depth)
+    pub plain_fields: &'static [&'static str],
+}
+
+/// Fields holding memory IDs, read from both the arguments and the result.
+const MEMORY_ID_FIELDS: &[&str] = &[
+    "node_id",
+    "source_ids",
+    "fingerprintId",
+    "chunkIds",
+    "merged_id",
+    "forgotten_id",
+    "kept_id",
+];
+
+/// Redaction policy for `tool`, or `None` if the tool is not audited.
+pub(crate) fn audit_policy(tool: &str) -> Option<AuditPolicy> {
+    let plain_fields: &'static [&'static str] = match tool {
+        tool_names::STORE_MEMORY | tool_names::STORE_MEMORIES_BATCH => {
+            &["sessionId", "operatorId", "namespace", "content_type", "domain"]
+        }
+        tool_names::FORGET_CONCEPT | tool_names::BOOST_IMPORTANCE => &["node_id", "operator_id"],
+        tool_names::MERGE_CONCEPTS => &["source_ids", "merge_strategy", "domain"],
+        tool_names::TRIGGER_CONSOLIDATION => &["strategy"],
+        tool_names::IMPORT_MEMORIES => &["path", "conflictPolicy"],
+        tool_names::CREATE_BACKUP => &["name", "incremental_from"],
+        tool_names::EXPORT_MEMORIES => &[
+            "path",
+            "namespace",
+            "createdAfter",
+            "createdBefore",
+            "topicId",
+        ],
+        _ => return None,
+    };
+    Some(AuditPolicy { plain_fields })
+}
+
+/// `{"sha256": <hex>, "len": <chars>}` stand-in for a redacted string.
+fn hash_string(s: &str) -> Value {
+    json!({
+        "sha256": hex::encode(Sha256::digest(s.as_bytes())),
+        "len": s.chars().count(),
+    })
+}
+
+/// Copy of `value` with every string not under a plain field hashed.
+pub(crate) fn redact_arguments(policy: &AuditPolicy, value: &Value) -> Value {
+    match value {
+        Value::String(s) => hash_string(s),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| redact_arguments(policy, item))
+                .collect(),
+        ),
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(key, field)| {
+                    let kept = if policy.plain_fields.contains(&key.as_str()) {
+                        field.clone()
+                    } else {
+                        redact_arguments(policy, field)
+                    };
+                    (key.clone(), kept)
+                })
+                .collect::<Map<_, _>>(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Append every UUID found under a [`MEMORY_ID_FIELDS`] key in `value`.
+fn collect_memory_ids(value: &Value, ids: &mut Vec<Uuid>) {
+    match value {
+        Value::Array(items) => items.iter().for_each(|item| collect_memory_ids(item, ids)),
+        Value::Object(fields) => {
+            for (key, field) in fields {
+                if MEMORY_ID_FIELDS.contains(&key.as_str()) {
+                    let candidates: Vec<&Value> = match field {
+                        Value::Array(items) => items.iter().collect(),
+                        single => vec![single],
+                    };
+                    for id in candidates
+                        .into_iter()
+                        .filter_map(|v| v.as_str())
+                        .filter_map(|s| Uuid::parse_str(s).ok())
+                    {
+                        if !ids.contains(&id) {
+                            ids.push(id);
+                        }
+                    }
+                } else {
+                    collect_memory_ids(field, ids);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Outcome and result payload of a tools/call response.
+fn response_outcome(response: &JsonRpcResponse) -> (ToolAuditOutcome, Option<Value>) {
+    if let Some(error) = &response.error {
+        return (
+            ToolAuditOutcome::Error {
+                code: Some(i64::from(error.code)),
+            },
+            None,
+        );
+    }
+    let Some(result) = &response.result else {
+        return (ToolAuditOutcome::Error { code: None }, None);
+    };
+    if result.get("isError").and_then(Value::as_bool) == Some(true) {
+        let code = result.get("errorCode").and_then(Value::as_i64);
+        return (ToolAuditOutcome::Error { code }, None);
+    }
+    let data = result
+        .get("content")
+        .and_then(|c| c.get(0))
+        .and_then(|c| c.get("text"))
+        .and_then(Value::as_str)
+        .and_then(|text| serde_json::from_str(text).ok());
+    (ToolAuditOutcome::Success, data)
+}
+
+/// An audited call in progress: everything known before the handler runs.
+///
+/// Holds only redacted arguments, so raw content is not kept past dispatch.
+pub(crate) struct PendingToolAudit {
+    tool_name: String,
+    session_id: Option<String>,
+    arguments: Value,
+    memory_ids: Vec<Uuid>,
+    started: Instant,
+}
+
+impl Handlers {
+    /// Start auditing a call to `tool_name`, or `None` if the tool is not
+    /// audited or auditing is disabled.
+    pub(crate) fn begin_tool_audit(
+        &self,
+        tool_name: &str,
+        arguments: &Value,
+        ctx: &RequestContext,
+    ) -> Option<PendingToolAudit> {
+        if !self.tool_audit.enabled {
+            return None;
+        }
+        let policy = audit_policy(tool_name)?;
+
+        let session_id = arguments
+            .get("sessionId")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .or_else(|| ctx.session().map(str::to_string))
+            .or_else(|| self.get_session_id());
+        let mut memory_ids = Vec::new();
+        collect_memory_ids(arguments, &mut memory_ids);
+
+        Some(PendingToolAudit {
+            tool_name: tool_name.to_string(),
+            session_id,
+            arguments: redact_arguments(&policy, arguments),
+            memory_ids,
+            started: Instant::now(),
+        })
+    }
+
+    /// Write the audit record for a finished call.
+    ///
+    /// A failed write is logged and does not change the tool's response.
+    pub(crate) async fn finish_tool_audit(
+        &self,
+        pending: PendingToolAudit,
+        response: &JsonRpcResponse,
+    ) {
+        let PendingToolAudit {
+            tool_name,
+            session_id,
+            arguments,
+            mut memory_ids,
+            started,
+        } = pending;
+
+        let (outcome, data) = response_outcome(response);
+        if let Some(data) = &data {
+            collect_memory_ids(data, &mut memory_ids);
+        }
+
+        let record = ToolAuditRecord::new(
+            tool_name,
+            session_id,
+            arguments,
+            memory_ids,
+            outcome,
+            started.elapsed().as_millis() as u64,
+        );
+        match self.teleological_store.append_tool_audit_record(&record).await {
+            Ok(()) => debug!(
+                tool = %record.tool_name,
+                record_id = %record.id,
+                memories = record.memory_ids.len(),
+                "Tool call audited"
+            ),
+            Err(e) => warn!(
+                tool = %record.tool_name,
+                error = %e,
+                "Failed to write tool audit record (tool call completed)"
+            ),
+        }
+    }
+
+    /// Delete audit records older than the configured retention window.
+    pub(crate) async fn prune_tool_audit_log(&self) {
+        let retention = Duration::from_secs(u64::from(self.tool_audit.retention_days) * 86_400);
+        let Ok(retention) = chrono::Duration::from_std(retention) else {
+            return;
+        };
+        let cutoff = Utc::now() - retention;
+        match self.teleological_store.prune_tool_audit_log(cutoff).await {
+            Ok(0) => {}
+            Ok(removed) => debug!(removed, %cutoff, "Pruned tool audit log"),
+            Err(e) => warn!(error = %e, "Failed to prune tool audit log (continuing)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlisted_fields_are_hashed_at_any_depth() {
+        let policy = audit_policy(tool_names::STORE_MEMORIES_BATCH).unwrap();
+        let args = json!({
+            "items": [{ "content": "secret plan", "importance": 0.7, "namespace": "ops" }],
+            "sessionId": "s-1",
+        });
+        let redacted = redact_arguments(&policy, &args);
+
+        assert_eq!(redacted["sessionId"], "s-1");
+        let item = &redacted["items"][0];
+        assert_eq!(item["namespace"], "ops");
+        assert_eq!(item["importance"], 0.7);
+        assert_eq!(item["content"]["len"], 11);
+        assert_eq!(
+            item["content"]["sha256"],
+            hex::encode(Sha256::digest(b"secret plan"))
+        );
+        assert!(!redacted.to_string().contains("secret plan"));
+    }
+
+    #[test]
+    fn test_read_only_tools_are_not_audited() {
+        assert!(audit_policy(tool_names::SEARCH_GRAPH).is_none());
+        assert!(audit_policy(tool_names::QUERY_AUDIT_LOG).is_none());
+        assert!(audit_policy(tool_names::FORGET_CONCEPT).is_some());
+    }
+
+    #[test]
+    fn test_memory_ids_collected_from_arguments_and_results() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut ids = Vec::new();
+        collect_memory_ids(
+            &json!({ "source_ids": [a.to_string(), b.to_string()], "rationale": c.to_string() }),
+            &mut ids,
+        );
+        collect_memory_ids(
+            &json!({ "merged_id": c.to_string(), "nested": [{ "kept_id": a.to_string() }] }),
+            &mut ids,
+        );
+        assert_eq!(ids, vec![a, b, c], "rationale is not an ID field; duplicates dropped");
+    }
+}
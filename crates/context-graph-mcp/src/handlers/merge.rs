@@ -8,6 +8,14 @@
 //! - intersection: Keep dimensions where all sources have significant values
 //! - weighted_average: Weight embeddings by access count
 //!
+//! ## Similarity Enforcement
+//! Sources must be near-duplicates: the lowest pairwise similarity
+//! (TeleologicalComparator per-space scores, weighted by the domain's weight
+//! profile) must reach the domain's `theta_dup`. Below it the merge is
+//! refused with the scores unless `force_merge` is set. `preview: true`
+//! returns the would-be merge, its similarity breakdown and the typed edges
+//! the merged memory would take over, without writing anything.
+//!
 //! ## Error Handling
 //! FAIL FAST: All errors return immediately with detailed error codes.
 //! NO fallbacks, NO default values, NO mock data.
@@ -16,6 +24,8 @@
 //! Uses TeleologicalMemoryStore (NOT MemoryNode) per codebase architecture.
 //! ARCH-01: TeleologicalFingerprint is the atomic storage unit.
 
+use std::collections::{BTreeMap, HashSet};
+
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -26,9 +36,14 @@ use uuid::Uuid;
 use context_graph_core::causal::asymmetric::{
     infer_direction_from_fingerprint, CausalDirection,
 };
+use context_graph_core::graph_linking::GraphLinkEdgeType;
+use context_graph_core::retrieval::{resolve_search_profile, DomainSource, SearchDomain};
+use context_graph_core::teleological::TeleologicalComparator;
+use context_graph_core::types::audit::{LineageOperation, LineageRecord};
 use context_graph_core::types::fingerprint::{
-    SemanticFingerprint, SparseVector, TeleologicalFingerprint,
+    SemanticFingerprint, SparseVector, TeleologicalFingerprint, NUM_EMBEDDERS,
 };
+use context_graph_core::weights::{get_weight_profile, space_name};
 
 use crate::protocol::{JsonRpcId, JsonRpcResponse};
 use crate::handlers::tools::helpers::ToolErrorKind;
@@ -58,6 +73,12 @@ pub struct MergeConceptsInput {
     /// Optional operator ID for provenance tracking (Phase 1.2)
     #[serde(default)]
     pub operator_id: Option<String>,
+    /// Return the would-be merge without writing anything
+    #[serde(default)]
+    pub preview: bool,
+    /// Domain whose theta_dup applies; detected from the source contents when omitted
+    #[serde(default)]
+    pub domain: Option<SearchDomain>,
 }
 
 /// Output for merge_concepts tool
@@ -71,10 +92,79 @@ pub struct MergeConceptsOutput {
     pub reversal_hash: String,
     /// Details of the merged node
     pub merged_node: MergedNodeInfo,
+    /// Similarity of the sources against the domain's theta_dup
+    pub similarity: MergeSimilarity,
     /// Error message if any (null on success)
     pub error: Option<String>,
 }
 
+/// Similarity of one pair of sources.
+#[derive(Debug, Clone, Serialize)]
+pub struct PairSimilarity {
+    pub source_a: Uuid,
+    pub source_b: Uuid,
+    /// Per-space scores weighted by the domain's weight profile
+    pub weighted: f32,
+    /// TeleologicalComparator score per space; spaces without a score are omitted
+    pub per_space: BTreeMap<&'static str, f32>,
+}
+
+/// Similarity of the merge sources against the domain's theta_dup.
+#[derive(Debug, Clone, Serialize)]
+pub struct MergeSimilarity {
+    pub domain: SearchDomain,
+    /// Whether the domain was given or detected from the source contents
+    pub domain_source: DomainSource,
+    /// Weight profile used to combine the per-space scores
+    pub weight_profile: &'static str,
+    pub theta_dup: f32,
+    /// Lowest weighted similarity over all source pairs
+    pub weighted: f32,
+    /// Whether `weighted` reaches `theta_dup`
+    pub passes: bool,
+    pub pairs: Vec<PairSimilarity>,
+}
+
+/// A typed edge the merged memory takes over from one of its sources.
+#[derive(Debug, Clone, Serialize)]
+pub struct MergedEdge {
+    /// Node at the other end of the edge
+    pub peer_id: Uuid,
+    /// "outgoing" or "incoming", relative to the merged memory
+    pub direction: &'static str,
+    pub edge_type: GraphLinkEdgeType,
+    pub weight: f32,
+    /// Source memory the edge is taken from
+    pub from_source: Uuid,
+}
+
+/// Output for merge_concepts with `preview: true`. Nothing is written.
+#[derive(Debug, Clone, Serialize)]
+pub struct MergePreview {
+    pub preview: bool,
+    /// Whether the merge would run with the same arguments and preview=false
+    pub would_merge: bool,
+    pub target_name: String,
+    pub source_ids: Vec<Uuid>,
+    pub strategy: MergeStrategy,
+    /// Total access count from all sources
+    pub total_access_count: u64,
+    /// Content the merged memory would be stored with
+    pub merged_content: String,
+    pub similarity: MergeSimilarity,
+    /// Typed edges the merged memory would take over; edges between sources
+    /// collapse and the first source wins where two sources link the same node
+    pub edges: Vec<MergedEdge>,
+}
+
+/// Sources, merged fingerprint and similarity of a merge, before any write.
+struct MergePlan {
+    sources: Vec<TeleologicalFingerprint>,
+    merged: TeleologicalFingerprint,
+    merged_content: String,
+    similarity: MergeSimilarity,
+}
+
 /// Information about the merged node
 #[derive(Debug, Clone, Serialize)]
 pub struct MergedNodeInfo {
@@ -116,6 +206,9 @@ const REVERSAL_DAYS: i64 = 30;
 /// Intersection threshold: dimension is "significant" if >= this value
 const INTERSECTION_THRESHOLD: f32 = 0.01;
 
+/// Weight profile for domains without one of their own
+const DEFAULT_MERGE_WEIGHT_PROFILE: &str = "semantic_search";
+
 impl Handlers {
     /// Handle merge_concepts tool call.
     ///
@@ -240,8 +333,52 @@ impl Handlers {
             }
         }
 
+        let plan = match self.plan_merge(&input).await {
+            Ok(plan) => plan,
+            Err(e) => {
+                error!("merge_concepts FAILED: {}", e);
+                return self.tool_error_typed(id, ToolErrorKind::Storage, &e);
+            }
+        };
+
+        if input.preview {
+            return match self.merge_preview(&input, plan) {
+                Ok(preview) => self.tool_result(id, json!(preview)),
+                Err(e) => {
+                    error!("merge_concepts preview FAILED: {}", e);
+                    self.tool_error_typed(id, ToolErrorKind::Storage, &e)
+                }
+            };
+        }
+
+        // FAIL FAST: Sources below the domain's duplicate threshold
+        if !plan.similarity.passes && !input.force_merge {
+            let similarity = &plan.similarity;
+            error!(
+                weighted = similarity.weighted,
+                theta_dup = similarity.theta_dup,
+                domain = similarity.domain.as_str(),
+                "merge_concepts: Sources below theta_dup"
+            );
+            let mut response = self.tool_error_typed(
+                id,
+                ToolErrorKind::Validation,
+                &format!(
+                    "Weighted similarity {:.3} is below theta_dup {:.2} for domain '{}'. \
+                     Use force_merge=true to merge anyway.",
+                    similarity.weighted,
+                    similarity.theta_dup,
+                    similarity.domain.as_str()
+                ),
+            );
+            if let Some(result) = response.result.as_mut() {
+                result["similarity"] = json!(similarity);
+            }
+            return response;
+        }
+
         // Execute the merge operation
-        match self.execute_merge(&input).await {
+        match self.execute_merge(&input, plan).await {
             Ok(output) => {
                 info!(
                     "merge_concepts SUCCESS: merged {} nodes into {} with hash {}",
@@ -258,19 +395,14 @@ impl Handlers {
         }
     }
 
-    /// Execute the merge operation.
+    /// Plan the merge without writing anything.
     ///
     /// 1. Fetch all source fingerprints (FAIL FAST if any missing)
     /// 2. Optional: Check priors compatibility (unless force_merge)
-    /// 3. Merge fingerprints using specified strategy
-    /// 4. Create merged fingerprint with combined attributes
-    /// 5. Generate reversal hash and store reversal record
-    /// 6. Store merged fingerprint
-    /// 7. Mark source fingerprints as merged (soft delete)
-    async fn execute_merge(
-        &self,
-        input: &MergeConceptsInput,
-    ) -> Result<MergeConceptsOutput, String> {
+    /// 3. Score the sources against the domain's theta_dup
+    /// 4. Merge fingerprints using specified strategy
+    /// 5. Create merged fingerprint with combined attributes
+    async fn plan_merge(&self, input: &MergeConceptsInput) -> Result<MergePlan, String> {
         // Step 1: Fetch all source fingerprints using batch retrieval
         let source_fingerprints = self.fetch_source_fingerprints(&input.source_ids).await?;
 
@@ -290,7 +422,12 @@ impl Handlers {
             self.check_fingerprint_compatibility(&source_fingerprints)?;
         }
 
-        // Step 3: Merge fingerprints using strategy
+        // Step 3: Similarity against the domain's theta_dup
+        let similarity = self
+            .merge_similarity(&source_fingerprints, input.domain)
+            .await?;
+
+        // Step 4: Merge fingerprints using strategy
         let merged_semantic = match input.merge_strategy {
             MergeStrategy::Union => self.merge_semantic_union(&source_fingerprints),
             MergeStrategy::Intersection => self.merge_semantic_intersection(&source_fingerprints),
@@ -299,10 +436,7 @@ impl Handlers {
             }
         };
 
-        // Step 4: Create merged fingerprint
-        let now = Utc::now();
-        let total_access_count: u64 = source_fingerprints.iter().map(|f| f.access_count).sum();
-
+        // Step 5: Create merged fingerprint
         // Generate content hash for merged content
         let merged_content = format!(
             "[MERGED] {}\nMerged from {} sources\nStrategy: {:?}\nRationale: {}",
@@ -327,11 +461,61 @@ impl Handlers {
         let e6_sparse = merged_semantic.e6_sparse.clone();
 
         // E6-FIX: Chain .with_e6_sparse() to propagate the merged E6 sparse vector
-        let merged_fingerprint = TeleologicalFingerprint::new(merged_semantic, content_hash)
-            .with_e6_sparse(e6_sparse);
+        let merged =
+            TeleologicalFingerprint::new(merged_semantic, content_hash).with_e6_sparse(e6_sparse);
+
+        Ok(MergePlan {
+            sources: source_fingerprints,
+            merged,
+            merged_content,
+            similarity,
+        })
+    }
+
+    /// Describe a planned merge: metadata, similarity and the typed edges the
+    /// merged memory would take over. Reads only.
+    fn merge_preview(
+        &self,
+        input: &MergeConceptsInput,
+        plan: MergePlan,
+    ) -> Result<MergePreview, String> {
+        let edges = self.merged_edges(&input.source_ids)?;
+        Ok(MergePreview {
+            preview: true,
+            would_merge: plan.similarity.passes || input.force_merge,
+            target_name: input.target_name.clone(),
+            source_ids: input.source_ids.clone(),
+            strategy: input.merge_strategy,
+            total_access_count: plan.sources.iter().map(|f| f.access_count).sum(),
+            merged_content: plan.merged_content,
+            similarity: plan.similarity,
+            edges,
+        })
+    }
+
+    /// Execute a planned merge.
+    ///
+    /// 1. Generate reversal hash and store reversal record
+    /// 2. Store merged fingerprint and content
+    /// 3. Move the sources' edges onto the merged memory
+    /// 4. Mark source fingerprints as merged (soft delete)
+    /// 5. Write audit, merge history and lineage records
+    async fn execute_merge(
+        &self,
+        input: &MergeConceptsInput,
+        plan: MergePlan,
+    ) -> Result<MergeConceptsOutput, String> {
+        let MergePlan {
+            sources: source_fingerprints,
+            merged: merged_fingerprint,
+            merged_content,
+            similarity,
+        } = plan;
         let merged_id = merged_fingerprint.id;
+        let now = Utc::now();
+        let total_access_count: u64 = source_fingerprints.iter().map(|f| f.access_count).sum();
 
-        // Step 5: Generate reversal hash and store reversal record
+        // Step 1: Generate reversal hash and store reversal record
         let reversal_hash = self.generate_reversal_hash(&input.source_ids, merged_id);
         let expires_at = now + chrono::Duration::days(REVERSAL_DAYS);
 
@@ -363,7 +547,7 @@ impl Handlers {
         // Store reversal record (for 30-day undo per SEC-06)
         self.store_reversal_record(&reversal_record).await?;
 
-        // Step 6: Store merged fingerprint
+        // Step 2: Store merged fingerprint
         self.teleological_store
             .store(merged_fingerprint)
             .await
@@ -378,7 +562,34 @@ impl Handlers {
             return Err(format!("Failed to store merged content for {}: {}", merged_id, e));
         }
 
-        // Step 7: Mark source fingerprints as merged (soft delete per SEC-06)
+        // The merged memory takes its sources' place in stored topics, so the
+        // soft deletes below find nothing left to remove (non-fatal)
+        if let Err(e) = self
+            .teleological_store
+            .replace_topic_members(&input.source_ids, Some(merged_id))
+            .await
+        {
+            error!(
+                merged_id = %merged_id,
+                error = %e,
+                "merge_concepts: Failed to update topic members (non-fatal)"
+            );
+        }
+
+        // Step 3: The merged memory takes over its sources' edges, matching
+        // the preview (non-fatal, the merge itself is stored)
+        for source_id in &input.source_ids {
+            if let Err(e) = self.retarget_edges(*source_id, merged_id) {
+                warn!(
+                    source_id = %source_id,
+                    merged_id = %merged_id,
+                    error = %e,
+                    "merge_concepts: Failed to move source edges (non-fatal)"
+                );
+            }
+        }
+
+        // Step 4: Mark source fingerprints as merged (soft delete per SEC-06)
         // Critical: failing to soft-delete sources creates duplicates
         let mut soft_delete_failures: Vec<String> = Vec::new();
         for source_id in &input.source_ids {
@@ -416,6 +627,9 @@ impl Handlers {
                 "source_count": source_fingerprints.len(),
                 "strategy": format!("{:?}", input.merge_strategy),
                 "force_merge": input.force_merge,
+                "domain": similarity.domain.as_str(),
+                "weighted_similarity": similarity.weighted,
+                "theta_dup": similarity.theta_dup,
             }));
 
             if let Err(e) = self.teleological_store.append_audit_record(&audit_record).await {
@@ -463,6 +677,23 @@ impl Handlers {
             }
         }
 
+        // Lineage edge merged_id -> sources for get_memory_lineage
+        {
+            let lineage = LineageRecord::new(
+                merged_id,
+                input.source_ids.clone(),
+                LineageOperation::Merge,
+                similarity.weighted,
+            );
+            if let Err(e) = self.teleological_store.append_lineage_record(&lineage).await {
+                warn!(
+                    merged_id = %merged_id,
+                    error = %e,
+                    "merge_concepts: Failed to write lineage record (merge completed successfully)"
+                );
+            }
+        }
+
         Ok(MergeConceptsOutput {
             success: true,
             merged_id,
@@ -475,10 +706,127 @@ impl Handlers {
                 created_at: now.to_rfc3339(),
                 total_access_count,
             },
+            similarity,
             error: None,
         })
     }
 
+    /// Score every source pair with TeleologicalComparator and weight the
+    /// per-space scores with the domain's weight profile.
+    ///
+    /// The domain is `explicit` or detected from the source contents.
+    async fn merge_similarity(
+        &self,
+        fingerprints: &[TeleologicalFingerprint],
+        explicit: Option<SearchDomain>,
+    ) -> Result<MergeSimilarity, String> {
+        let ids: Vec<Uuid> = fingerprints.iter().map(|f| f.id).collect();
+        let contents = self
+            .teleological_store
+            .get_content_batch(&ids)
+            .await
+            .map_err(|e| format!("Failed to read source contents: {}", e))?;
+        let text = contents
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join("\n");
+        let resolved = resolve_search_profile(explicit, &text);
+        let profile = resolved.profile;
+        let weight_profile = profile
+            .weight_profile
+            .unwrap_or(DEFAULT_MERGE_WEIGHT_PROFILE);
+        let weights = get_weight_profile(weight_profile)
+            .map_err(|e| format!("Invalid weight profile '{}': {}", weight_profile, e))?;
+
+        let comparator = TeleologicalComparator::new();
+        let mut pairs = Vec::new();
+        for (i, a) in fingerprints.iter().enumerate() {
+            for b in &fingerprints[i + 1..] {
+                let result = comparator
+                    .compare(&a.semantic, &b.semantic)
+                    .map_err(|e| format!("Failed to compare {} and {}: {}", a.id, b.id, e))?;
+                let (mut weighted, mut weight_sum) = (0.0f32, 0.0f32);
+                let mut per_space = BTreeMap::new();
+                for (idx, score) in result.per_embedder.iter().enumerate().take(NUM_EMBEDDERS) {
+                    if let Some(score) = *score {
+                        per_space.insert(space_name(idx), score);
+                        weighted += score * weights[idx];
+                        weight_sum += weights[idx];
+                    }
+                }
+                pairs.push(PairSimilarity {
+                    source_a: a.id,
+                    source_b: b.id,
+                    weighted: if weight_sum > 0.0 {
+                        weighted / weight_sum
+                    } else {
+                        0.0
+                    },
+                    per_space,
+                });
+            }
+        }
+
+        let weighted = pairs
+            .iter()
+            .map(|p| p.weighted)
+            .fold(f32::INFINITY, f32::min);
+        Ok(MergeSimilarity {
+            domain: profile.domain,
+            domain_source: resolved.source,
+            weight_profile,
+            theta_dup: profile.theta_dup,
+            weighted,
+            passes: weighted >= profile.theta_dup,
+            pairs,
+        })
+    }
+
+    /// Typed edges of `source_ids` as the merged memory would hold them.
+    ///
+    /// Mirrors `retarget_edges` run for each source in order: edges between
+    /// sources collapse and the first edge between the merged memory and a
+    /// node wins. Empty when graph linking is disabled.
+    fn merged_edges(&self, source_ids: &[Uuid]) -> Result<Vec<MergedEdge>, String> {
+        let Some(repo) = &self.edge_repository else {
+            return Ok(Vec::new());
+        };
+        let sources: HashSet<Uuid> = source_ids.iter().copied().collect();
+        let mut seen: HashSet<(Option<Uuid>, Option<Uuid>)> = HashSet::new();
+        let mut merged = Vec::new();
+        for &source_id in source_ids {
+            let mut typed = repo
+                .get_typed_edges_from(source_id)
+                .map_err(|e| format!("Failed to read typed edges from {}: {}", source_id, e))?;
+            typed.extend(
+                repo.get_typed_edges_to(source_id)
+                    .map_err(|e| format!("Failed to read typed edges to {}: {}", source_id, e))?,
+            );
+            for edge in typed {
+                // None stands for the merged memory
+                let from = Some(edge.source()).filter(|id| !sources.contains(id));
+                let to = Some(edge.target()).filter(|id| !sources.contains(id));
+                let (peer_id, direction) = match (from, to) {
+                    (None, Some(peer)) => (peer, "outgoing"),
+                    (Some(peer), None) => (peer, "incoming"),
+                    _ => continue,
+                };
+                if !seen.insert((from, to)) {
+                    continue;
+                }
+                merged.push(MergedEdge {
+                    peer_id,
+                    direction,
+                    edge_type: edge.edge_type(),
+                    weight: edge.weight(),
+                    from_source: source_id,
+                });
+            }
+        }
+        Ok(merged)
+    }
+
     /// Fetch source fingerprints from storage using batch retrieval.
     /// FAIL FAST if any fingerprint is not found.
     async fn fetch_source_fingerprints(
@@ -556,37 +904,6 @@ impl Handlers {
             ));
         }
 
-        // Check semantic similarity spread using E1 embeddings
-        // Calculate pairwise cosine similarities
-        let mut similarities: Vec<f32> = Vec::new();
-        for i in 0..fingerprints.len() {
-            for j in (i + 1)..fingerprints.len() {
-                let e1_i = &fingerprints[i].semantic.e1_semantic;
-                let e1_j = &fingerprints[j].semantic.e1_semantic;
-
-                // Cosine similarity
-                let dot: f32 = e1_i.iter().zip(e1_j.iter()).map(|(a, b)| a * b).sum();
-                let norm_i: f32 = e1_i.iter().map(|x| x * x).sum::<f32>().sqrt();
-                let norm_j: f32 = e1_j.iter().map(|x| x * x).sum::<f32>().sqrt();
-
-                if norm_i > 0.0 && norm_j > 0.0 {
-                    similarities.push(dot / (norm_i * norm_j));
-                }
-            }
-        }
-
-        if !similarities.is_empty() {
-            let min_sim = similarities.iter().cloned().fold(f32::INFINITY, f32::min);
-
-            // Warn if semantic similarity is low (below 0.3 threshold)
-            if min_sim < 0.3 {
-                warn!(
-                    "Low semantic similarity in merge: min_similarity={:.2} (consider force_merge)",
-                    min_sim
-                );
-            }
-        }
-
         Ok(())
     }
 
@@ -7,10 +7,17 @@
 
 mod core;
 mod merge;
+mod resources;
 mod tools;
 
 #[cfg(test)]
 mod tests;
 
-pub use self::core::Handlers;
+pub use self::core::{
+    BusyReason, CancellationFlag, Cancelled, Handlers, NotificationSender, ProgressReporter,
+    RequestContext, ServerBusy, ToolCategory,
+};
+pub use self::resources::{
+    RESOURCES_PAGE_SIZE, TOPICS_MARKDOWN_URI, TOPICS_URI, TOPIC_URI_PREFIX,
+};
 pub(crate) use self::tools::daemon_tools::DaemonState;
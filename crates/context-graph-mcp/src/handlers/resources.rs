@@ -0,0 +1,396 @@
+//! MCP resources: read-only views of the topic portfolio.
+//!
+//! Resources let clients pull topics into context without calling a tool:
+//! - `contextgraph://topics` - portfolio as JSON (same summaries as get_topic_portfolio)
+//! - `contextgraph://topics.md` - portfolio rendered as a markdown table
+//! - `contextgraph://topics/{id}` - one topic with its member memory IDs
+//!
+//! `resources/list` is paginated with an opaque `cursor`. `resources/subscribe`
+//! registers the connection's notification channel for a URI; after each
+//! successful recluster, subscribers of topic URIs receive
+//! `notifications/resources/updated` and re-read the resource.
+
+use std::collections::HashMap;
+
+use parking_lot::Mutex;
+use serde_json::json;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use context_graph_core::clustering::Topic;
+
+use crate::protocol::{error_codes, methods, JsonRpcId, JsonRpcNotification, JsonRpcResponse};
+
+use super::core::{NotificationSender, RequestContext};
+use super::tools::topic_tools::topic_to_summary;
+use super::Handlers;
+
+/// Topic portfolio as JSON.
+pub const TOPICS_URI: &str = "contextgraph://topics";
+
+/// Topic portfolio as markdown.
+pub const TOPICS_MARKDOWN_URI: &str = "contextgraph://topics.md";
+
+/// Prefix of per-topic URIs (`contextgraph://topics/{id}`).
+pub const TOPIC_URI_PREFIX: &str = "contextgraph://topics/";
+
+/// Maximum resources returned by one resources/list call.
+pub const RESOURCES_PAGE_SIZE: usize = 50;
+
+const JSON_MIME: &str = "application/json";
+const MARKDOWN_MIME: &str = "text/markdown";
+
+/// Notification channels subscribed to each resource URI.
+#[derive(Debug, Default)]
+pub(crate) struct ResourceSubscriptions {
+    subscribers: Mutex<HashMap<String, Vec<NotificationSender>>>,
+}
+
+impl ResourceSubscriptions {
+    fn subscribe(&self, uri: &str, sender: &NotificationSender) {
+        let mut subscribers = self.subscribers.lock();
+        let senders = subscribers.entry(uri.to_string()).or_default();
+        if !senders.iter().any(|s| s.same_channel(sender)) {
+            senders.push(sender.clone());
+        }
+    }
+
+    fn unsubscribe(&self, uri: &str, sender: &NotificationSender) -> bool {
+        let mut subscribers = self.subscribers.lock();
+        let Some(senders) = subscribers.get_mut(uri) else {
+            return false;
+        };
+        let before = senders.len();
+        senders.retain(|s| !s.same_channel(sender));
+        let removed = senders.len() < before;
+        if senders.is_empty() {
+            subscribers.remove(uri);
+        }
+        removed
+    }
+
+    /// Notify subscribers of every URI matching `is_updated`.
+    ///
+    /// Channels of closed connections are dropped. Returns the number of
+    /// notifications sent.
+    fn notify_updated(&self, is_updated: impl Fn(&str) -> bool) -> usize {
+        let mut sent = 0;
+        self.subscribers.lock().retain(|uri, senders| {
+            if is_updated(uri) {
+                let notification = JsonRpcNotification::new(
+                    methods::NOTIFICATION_RESOURCE_UPDATED,
+                    json!({ "uri": uri }),
+                );
+                senders.retain(|s| s.send(notification.clone()).is_ok());
+                sent += senders.len();
+            } else {
+                senders.retain(|s| !s.is_closed());
+            }
+            !senders.is_empty()
+        });
+        sent
+    }
+}
+
+/// A page of `entries` starting at `cursor`, plus the cursor of the next page.
+///
+/// Cursors are offsets returned as `nextCursor` by a previous call.
+fn paginate<T>(
+    entries: Vec<T>,
+    cursor: Option<&str>,
+    page_size: usize,
+) -> Result<(Vec<T>, Option<String>), String> {
+    let offset = match cursor {
+        None => 0,
+        Some(c) => c
+            .parse::<usize>()
+            .ok()
+            .filter(|&offset| offset <= entries.len())
+            .ok_or_else(|| format!("Invalid cursor '{}'", c))?,
+    };
+    let end = (offset + page_size).min(entries.len());
+    let next_cursor = (end < entries.len()).then(|| end.to_string());
+    let page = entries
+        .into_iter()
+        .skip(offset)
+        .take(end - offset)
+        .collect();
+    Ok((page, next_cursor))
+}
+
+fn topic_uri(topic_id: Uuid) -> String {
+    format!("{}{}", TOPIC_URI_PREFIX, topic_id)
+}
+
+fn topic_label(topic: &Topic) -> String {
+    topic
+        .name
+        .clone()
+        .unwrap_or_else(|| format!("Topic {}", topic.id))
+}
+
+/// Topics ordered largest first, ties broken by ID so pages are stable.
+fn sorted_topics(topics: &HashMap<Uuid, Topic>) -> Vec<&Topic> {
+    let mut sorted: Vec<&Topic> = topics.values().collect();
+    sorted.sort_by(|a, b| {
+        b.member_count()
+            .cmp(&a.member_count())
+            .then(a.id.cmp(&b.id))
+    });
+    sorted
+}
+
+fn render_portfolio_markdown(topics: &[&Topic], churn_rate: f32) -> String {
+    let mut out = String::from("# Topic portfolio\n\n");
+    if topics.is_empty() {
+        out.push_str(
+            "No topics detected yet. Run detect_topics once enough memories are stored.\n",
+        );
+        return out;
+    }
+    out.push_str(&format!(
+        "{} topics, churn rate {:.2}\n\n",
+        topics.len(),
+        churn_rate
+    ));
+    out.push_str("| Topic | Members | Confidence | Phase | Contributing spaces |\n");
+    out.push_str("|---|---|---|---|---|\n");
+    for topic in topics {
+        let summary = topic_to_summary(topic);
+        out.push_str(&format!(
+            "| {} | {} | {:.2} | {} | {} |\n",
+            topic_label(topic).replace('|', "\\|"),
+            summary.member_count,
+            summary.confidence,
+            summary.phase,
+            summary.contributing_spaces.join(", ")
+        ));
+    }
+    out
+}
+
+impl Handlers {
+    /// Handle resources/list.
+    ///
+    /// Portfolio resources come first, then one resource per topic.
+    pub(crate) fn handle_resources_list(
+        &self,
+        id: Option<JsonRpcId>,
+        params: Option<serde_json::Value>,
+    ) -> JsonRpcResponse {
+        let cursor = params
+            .as_ref()
+            .and_then(|p| p.get("cursor"))
+            .and_then(|c| c.as_str());
+
+        let mut entries = vec![
+            json!({
+                "uri": TOPICS_URI,
+                "name": "Topic portfolio",
+                "description": "All detected topics with confidence, phase and contributing spaces",
+                "mimeType": JSON_MIME,
+            }),
+            json!({
+                "uri": TOPICS_MARKDOWN_URI,
+                "name": "Topic portfolio (markdown)",
+                "description": "All detected topics as a markdown table",
+                "mimeType": MARKDOWN_MIME,
+            }),
+        ];
+        {
+            let cluster_manager = self.cluster_manager.read();
+            entries.extend(sorted_topics(cluster_manager.get_topics()).into_iter().map(|topic| {
+                json!({
+                    "uri": topic_uri(topic.id),
+                    "name": topic_label(topic),
+                    "description": format!("Topic with {} member memories", topic.member_count()),
+                    "mimeType": JSON_MIME,
+                })
+            }));
+        }
+
+        match paginate(entries, cursor, RESOURCES_PAGE_SIZE) {
+            Ok((resources, next_cursor)) => {
+                debug!(count = resources.len(), ?next_cursor, "resources/list");
+                let mut result = json!({ "resources": resources });
+                if let Some(next_cursor) = next_cursor {
+                    result["nextCursor"] = json!(next_cursor);
+                }
+                JsonRpcResponse::success(id, result)
+            }
+            Err(msg) => JsonRpcResponse::error(id, error_codes::INVALID_PARAMS, msg),
+        }
+    }
+
+    /// Handle resources/read.
+    pub(crate) fn handle_resources_read(
+        &self,
+        id: Option<JsonRpcId>,
+        params: Option<serde_json::Value>,
+    ) -> JsonRpcResponse {
+        let Some(uri) = params
+            .as_ref()
+            .and_then(|p| p.get("uri"))
+            .and_then(|u| u.as_str())
+        else {
+            return JsonRpcResponse::error(
+                id,
+                error_codes::INVALID_PARAMS,
+                "Missing 'uri' parameter in resources/read",
+            );
+        };
+
+        let cluster_manager = self.cluster_manager.read();
+        let topics = cluster_manager.get_topics();
+        let (mime_type, text) = if uri == TOPICS_URI {
+            let summaries: Vec<_> = sorted_topics(topics)
+                .into_iter()
+                .map(topic_to_summary)
+                .collect();
+            let body = json!({
+                "total_topics": summaries.len(),
+                "topics": summaries,
+                "churn_rate": cluster_manager.current_churn(),
+            });
+            (JSON_MIME, body.to_string())
+        } else if uri == TOPICS_MARKDOWN_URI {
+            let text =
+                render_portfolio_markdown(&sorted_topics(topics), cluster_manager.current_churn());
+            (MARKDOWN_MIME, text)
+        } else if let Some(topic) = uri
+            .strip_prefix(TOPIC_URI_PREFIX)
+            .and_then(|raw| Uuid::parse_str(raw).ok())
+            .and_then(|topic_id| topics.get(&topic_id))
+        {
+            let body = json!({
+                "topic": topic_to_summary(topic),
+                "member_ids": topic.member_memories,
+                "created_at": topic.created_at.to_rfc3339(),
+            });
+            (JSON_MIME, body.to_string())
+        } else {
+            return JsonRpcResponse::error(
+                id,
+                error_codes::RESOURCE_NOT_FOUND,
+                format!("Resource not found: {}", uri),
+            );
+        };
+
+        JsonRpcResponse::success(
+            id,
+            json!({
+                "contents": [{
+                    "uri": uri,
+                    "mimeType": mime_type,
+                    "text": text,
+                }]
+            }),
+        )
+    }
+
+    /// Handle resources/subscribe and resources/unsubscribe.
+    ///
+    /// Updates go through the connection's notification channel, so requests
+    /// without one (e.g. inside a batch) are rejected.
+    pub(crate) fn handle_resources_subscription(
+        &self,
+        id: Option<JsonRpcId>,
+        params: Option<serde_json::Value>,
+        ctx: &RequestContext,
+        subscribe: bool,
+    ) -> JsonRpcResponse {
+        let Some(uri) = params
+            .as_ref()
+            .and_then(|p| p.get("uri"))
+            .and_then(|u| u.as_str())
+        else {
+            return JsonRpcResponse::error(
+                id,
+                error_codes::INVALID_PARAMS,
+                "Missing 'uri' parameter",
+            );
+        };
+        if uri != TOPICS_URI && uri != TOPICS_MARKDOWN_URI && !uri.starts_with(TOPIC_URI_PREFIX) {
+            return JsonRpcResponse::error(
+                id,
+                error_codes::RESOURCE_NOT_FOUND,
+                format!("Resource not found: {}", uri),
+            );
+        }
+        let Some(notifier) = ctx.notifier() else {
+            return JsonRpcResponse::error(
+                id,
+                error_codes::INVALID_REQUEST,
+                "Subscriptions require a connection that accepts notifications (not available in batches)",
+            );
+        };
+
+        if subscribe {
+            self.resource_subscriptions.subscribe(uri, notifier);
+            info!(uri, "Client subscribed to resource");
+        } else if !self.resource_subscriptions.unsubscribe(uri, notifier) {
+            debug!(uri, "Unsubscribe for resource without subscription ignored");
+        }
+        JsonRpcResponse::success(id, json!({}))
+    }
+
+    /// Notify subscribers that topic resources changed after a recluster.
+    pub(crate) fn notify_topics_updated(&self) {
+        let sent = self
+            .resource_subscriptions
+            .notify_updated(|uri| uri.starts_with(TOPICS_URI));
+        if sent > 0 {
+            debug!(sent, "Sent topic resource update notifications");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc;
+
+    use super::*;
+
+    #[test]
+    fn test_paginate_walks_all_pages() {
+        let entries: Vec<usize> = (0..120).collect();
+        let (page, next) = paginate(entries.clone(), None, 50).unwrap();
+        assert_eq!(page, (0..50).collect::<Vec<_>>());
+        assert_eq!(next.as_deref(), Some("50"));
+
+        let (page, next) = paginate(entries.clone(), Some("100"), 50).unwrap();
+        assert_eq!(page, (100..120).collect::<Vec<_>>());
+        assert!(next.is_none(), "last page has no cursor");
+
+        assert!(paginate(entries.clone(), Some("121"), 50).is_err());
+        assert!(paginate(entries, Some("abc"), 50).is_err());
+    }
+
+    #[test]
+    fn test_subscriptions_notify_matching_uris_and_drop_closed_channels() {
+        let subscriptions = ResourceSubscriptions::default();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let (closed_tx, closed_rx) = mpsc::unbounded_channel();
+        drop(closed_rx);
+
+        subscriptions.subscribe(TOPICS_URI, &tx);
+        subscriptions.subscribe(TOPICS_URI, &tx);
+        subscriptions.subscribe(TOPICS_MARKDOWN_URI, &closed_tx);
+
+        assert_eq!(subscriptions.notify_updated(|uri| uri == TOPICS_URI), 1);
+        let notification = rx.try_recv().unwrap();
+        assert_eq!(notification.method, methods::NOTIFICATION_RESOURCE_UPDATED);
+        assert_eq!(notification.params.unwrap()["uri"], json!(TOPICS_URI));
+        assert!(rx.try_recv().is_err(), "duplicate subscribe is ignored");
+        assert!(
+            !subscriptions
+                .subscribers
+                .lock()
+                .contains_key(TOPICS_MARKDOWN_URI),
+            "closed channels are pruned"
+        );
+
+        assert!(subscriptions.unsubscribe(TOPICS_URI, &tx));
+        assert_eq!(subscriptions.notify_updated(|_| true), 0);
+    }
+}
@@ -0,0 +1,122 @@
+//! create_backup Tool Tests
+//!
+//! Verifies the backup tool end to end:
+//! - Refused while `[mcp.backup]` is disabled
+//! - A full and an incremental backup verify and restore with every memory
+//! - Names cannot escape the configured backup root
+
+use serde_json::json;
+use tempfile::TempDir;
+
+use context_graph_core::config::BackupConfig;
+use context_graph_core::traits::TeleologicalMemoryStore;
+use context_graph_storage::teleological::{
+    restore_backup, verify_backup, RocksDbTeleologicalStore,
+};
+
+use crate::handlers::Handlers;
+use crate::protocol::error_codes;
+
+use super::{call_tool, call_tool_raw, create_test_handlers, store_memory_with};
+
+fn enable_backups(handlers: &mut Handlers, root: &TempDir) {
+    handlers.set_backup(BackupConfig {
+        enabled: true,
+        root: root.path().display().to_string(),
+    });
+}
+
+#[tokio::test]
+async fn test_create_backup_disabled_by_default() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+
+    let result = call_tool_raw(&handlers, 1, "create_backup", json!({ "name": "b1" })).await;
+    assert_eq!(result["isError"], json!(true), "{}", result);
+    assert_eq!(
+        result["errorCode"],
+        json!(error_codes::CAPABILITY_UNAVAILABLE)
+    );
+}
+
+#[tokio::test]
+async fn test_full_and_incremental_backups_restore() {
+    let (mut handlers, _tempdir) = create_test_handlers().await;
+    let root = TempDir::new().unwrap();
+    enable_backups(&mut handlers, &root);
+
+    let first = store_memory_with(
+        &handlers,
+        1,
+        json!({
+            "content": "Backups are taken from a RocksDB checkpoint.",
+            "namespace": "backup-test"
+        }),
+    )
+    .await;
+    let full = call_tool(&handlers, 2, "create_backup", json!({ "name": "full" })).await;
+    assert!(full["files"].as_u64().unwrap() > 0);
+    assert!(full["hnswFiles"].as_u64().unwrap() > 0);
+    assert_eq!(full["linkedFiles"], json!(0));
+
+    let second = store_memory_with(
+        &handlers,
+        3,
+        json!({
+            "content": "Incremental backups link unchanged SST files.",
+            "namespace": "backup-test"
+        }),
+    )
+    .await;
+    let incremental = call_tool(
+        &handlers,
+        4,
+        "create_backup",
+        json!({ "name": "incr", "incremental_from": "full" }),
+    )
+    .await;
+    assert_eq!(incremental["incrementalFrom"], json!("full"));
+    assert!(incremental["linkedFiles"].as_u64().unwrap() > 0);
+
+    let backup = root.path().join("incr");
+    assert_eq!(incremental["path"], json!(backup.display().to_string()));
+    verify_backup(&backup).expect("incremental backup verifies");
+
+    let restored_dir = root.path().join("restored");
+    let report = restore_backup(&backup, &restored_dir).unwrap();
+    assert!(report.integrity.is_clean(), "{:?}", report.integrity);
+    let restored = RocksDbTeleologicalStore::open(&restored_dir).unwrap();
+    for id in [first, second] {
+        assert!(
+            restored.retrieve(id).await.unwrap().is_some(),
+            "{} missing after restore",
+            id
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_create_backup_rejects_paths_outside_root() {
+    let (mut handlers, _tempdir) = create_test_handlers().await;
+    let root = TempDir::new().unwrap();
+    enable_backups(&mut handlers, &root);
+
+    for (i, args) in [
+        json!({ "name": "../escape" }),
+        json!({ "name": "/tmp/escape" }),
+        json!({ "name": "ok", "incremental_from": ".." }),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let result = call_tool_raw(&handlers, i as i64, "create_backup", args.clone()).await;
+        assert_eq!(
+            result["isError"],
+            json!(true),
+            "{} accepted: {}",
+            args,
+            result
+        );
+    }
+    assert!(!root.path().parent().unwrap().join("escape").exists());
+    assert!(std::fs::read_dir(root.path()).unwrap().next().is_none());
+}
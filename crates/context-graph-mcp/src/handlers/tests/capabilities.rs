@@ -0,0 +1,99 @@
+//! GPU Degradation Tests
+//!
+//! Runs the handlers with a forced "no GPU" `CapabilityMatrix`:
+//! - get_memetic_status reports the missing capabilities
+//! - store_memory and search_graph succeed and are flagged `degraded`
+//! - detect_topics returns CAPABILITY_UNAVAILABLE naming cuda_driver
+//! - Every published tool returns a result or that structured error,
+//!   never an internal error
+
+use std::sync::Arc;
+
+use serde_json::json;
+
+use crate::adapters::CapabilityMatrix;
+use crate::handlers::Handlers;
+use crate::protocol::error_codes;
+use crate::tools::get_tool_definitions;
+
+use super::{call_tool, call_tool_raw, call_tool_response, create_test_handlers_with_edges};
+
+async fn no_gpu_handlers() -> (Handlers, tempfile::TempDir) {
+    let (mut handlers, _store, _edges, tempdir) = create_test_handlers_with_edges().await;
+    handlers.set_capability_matrix(Arc::new(CapabilityMatrix::no_gpu()));
+    (handlers, tempdir)
+}
+
+#[tokio::test]
+async fn test_no_gpu_status_store_search_and_detect_topics() {
+    let (handlers, _tempdir) = no_gpu_handlers().await;
+
+    let status = call_tool(&handlers, 1, "get_memetic_status", json!({})).await;
+    assert_eq!(status["capabilities"]["cudaDriver"], json!(false));
+    assert_eq!(status["capabilities"]["candleDevice"], json!("cpu"));
+
+    let result = call_tool_raw(
+        &handlers,
+        2,
+        "store_memory",
+        json!({ "content": "Ledger snapshots run every six hours" }),
+    )
+    .await;
+    assert_eq!(result["isError"], json!(false), "{}", result);
+    assert_eq!(result["degraded"], json!(true));
+    assert_eq!(result["degradedCapabilities"], json!(["candle_cuda"]));
+
+    let result = call_tool_raw(
+        &handlers,
+        3,
+        "search_graph",
+        json!({ "query": "ledger snapshots" }),
+    )
+    .await;
+    assert_eq!(result["isError"], json!(false), "{}", result);
+    assert_eq!(result["degraded"], json!(true));
+
+    // Non-embedding tools are not flagged
+    let status = call_tool_raw(&handlers, 4, "get_memetic_status", json!({})).await;
+    assert!(status.get("degraded").is_none());
+
+    let topics = call_tool_response(&handlers, 5, "detect_topics", json!({})).await;
+    let error = topics.error.expect("detect_topics must be refused");
+    assert_eq!(error.code, error_codes::CAPABILITY_UNAVAILABLE);
+    let data = error.data.expect("CAPABILITY_UNAVAILABLE carries data");
+    assert_eq!(data["tool"], json!("detect_topics"));
+    assert_eq!(data["missingCapabilities"], json!(["cuda_driver"]));
+}
+
+#[tokio::test]
+async fn test_no_gpu_every_tool_returns_result_or_capability_error() {
+    let (handlers, _tempdir) = no_gpu_handlers().await;
+    call_tool_raw(
+        &handlers,
+        0,
+        "store_memory",
+        json!({ "content": "Canary releases hold five percent of traffic" }),
+    )
+    .await;
+
+    for (i, tool) in get_tool_definitions().iter().enumerate() {
+        let response = call_tool_response(&handlers, i as i64 + 1, &tool.name, json!({})).await;
+        match (response.result, response.error) {
+            (_, Some(error)) => assert_eq!(
+                error.code,
+                error_codes::CAPABILITY_UNAVAILABLE,
+                "{} returned protocol error: {}",
+                tool.name,
+                error.message
+            ),
+            (Some(result), None) => assert_ne!(
+                result.get("errorCode"),
+                Some(&json!(error_codes::INTERNAL_ERROR)),
+                "{} returned internal error: {}",
+                tool.name,
+                result
+            ),
+            (None, None) => panic!("{} returned an empty response", tool.name),
+        }
+    }
+}
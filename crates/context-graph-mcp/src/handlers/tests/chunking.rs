@@ -0,0 +1,144 @@
+//! Chunked store_memory Tests
+//!
+//! Verifies that store_memory with `chunking` enabled:
+//! - Splits a structured markdown document into one chunk per section
+//! - Links the parent document to every chunk and each chunk to the next
+//! - Lets search find a phrase from the last section in its own chunk
+
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{call_tool, create_test_handlers_with_edges};
+
+const RUNBOOK: &str = "# Payments Service Runbook
+
+Operational guide for the payments service owned by the checkout team.
+
+## Deployment
+
+Deploys run through the blue green pipeline every weekday morning. \
+Each release is canaried on five percent of traffic for thirty minutes before promotion.
+
+## Monitoring
+
+Latency dashboards track the p99 of card authorization requests. \
+Alerts fire when the error budget burn rate exceeds two for one hour.
+
+## Backups
+
+The ledger database is snapshotted every six hours to cold storage. \
+Restores are rehearsed quarterly against a scratch cluster.
+
+## Incident Response
+
+During an outage the on-call engineer must rotate the pager escalation keys \
+and post status updates in the incident channel every fifteen minutes.
+";
+
+fn uuid(value: &serde_json::Value) -> Uuid {
+    value
+        .as_str()
+        .expect("id must be a string")
+        .parse()
+        .expect("id must be a UUID")
+}
+
+#[tokio::test]
+async fn test_chunked_store_links_sections_and_search_finds_last_chunk() {
+    let (handlers, _store_ref, edges, _tempdir) = create_test_handlers_with_edges().await;
+
+    let data = call_tool(
+        &handlers,
+        1,
+        "store_memory",
+        json!({
+            "content": RUNBOOK,
+            "chunking": { "enabled": true, "max_tokens": 64, "overlap": 8 }
+        }),
+    )
+    .await;
+
+    let document = uuid(&data["documentId"]);
+    assert_eq!(data["fingerprintId"], data["documentId"]);
+    let chunk_ids: Vec<Uuid> = data["chunkIds"]
+        .as_array()
+        .expect("chunkIds must be an array")
+        .iter()
+        .map(uuid)
+        .collect();
+    // Title + intro, then one chunk per section
+    assert_eq!(chunk_ids.len(), 5, "{}", data);
+    assert_eq!(data["edgesCreated"], json!(9));
+
+    // Parent links to every chunk
+    let mut children: Vec<Uuid> = edges
+        .get_typed_edges_from(document)
+        .expect("edges from document")
+        .iter()
+        .map(|e| e.target())
+        .collect();
+    children.sort();
+    let mut expected = chunk_ids.clone();
+    expected.sort();
+    assert_eq!(children, expected);
+
+    // Each chunk links to the next one only
+    for (i, chunk) in chunk_ids.iter().enumerate() {
+        let next: Vec<Uuid> = edges
+            .get_typed_edges_from(*chunk)
+            .expect("edges from chunk")
+            .iter()
+            .map(|e| e.target())
+            .collect();
+        assert_eq!(
+            next,
+            chunk_ids
+                .get(i + 1)
+                .copied()
+                .into_iter()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    let search = call_tool(
+        &handlers,
+        2,
+        "search_graph",
+        json!({ "query": "rotate the pager escalation keys during an outage", "topK": 3 }),
+    )
+    .await;
+    let top = &search["results"][0]["fingerprintId"];
+    assert_eq!(top, &json!(chunk_ids[4].to_string()), "{}", search);
+
+    // Identical documents deduplicate onto the parent
+    let again = call_tool(
+        &handlers,
+        3,
+        "store_memory",
+        json!({
+            "content": RUNBOOK,
+            "chunking": { "enabled": true, "max_tokens": 64, "overlap": 8 }
+        }),
+    )
+    .await;
+    assert_eq!(again["deduplicated"], json!(true));
+    assert_eq!(again["fingerprintId"], json!(document.to_string()));
+}
+
+#[tokio::test]
+async fn test_short_content_is_stored_unchunked() {
+    let (handlers, _store_ref, _edges, _tempdir) = create_test_handlers_with_edges().await;
+
+    let data = call_tool(
+        &handlers,
+        1,
+        "store_memory",
+        json!({
+            "content": "A single short note about the payments service.",
+            "chunking": { "enabled": true, "max_tokens": 64 }
+        }),
+    )
+    .await;
+    assert!(data["fingerprintId"].is_string());
+    assert!(data.get("chunkIds").is_none());
+}
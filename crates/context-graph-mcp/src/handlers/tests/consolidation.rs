@@ -0,0 +1,125 @@
+//! Consolidation Tests
+//!
+//! Verifies that trigger_consolidation merges near-duplicates end to end:
+//! - Two nearly identical memories collapse into one survivor, a distinct one stays
+//! - The merged memory is tombstoned with a `MemoryConsolidated` pointer to the survivor
+//! - Typed and K-NN edges are re-pointed from the merged memory to the survivor
+//! - `dry_run` reports candidates without touching storage
+
+use serde_json::json;
+use uuid::Uuid;
+
+use context_graph_core::graph_linking::{
+    DirectedRelation, EmbedderEdge, GraphLinkEdgeType, TypedEdge,
+};
+use context_graph_core::types::audit::AuditOperation;
+
+use super::{call_tool, create_test_handlers_with_edges, store_memory};
+
+const DUPLICATE_A: &str =
+    "The deployment pipeline failed because the database migration timed out.";
+const DUPLICATE_B: &str =
+    "The deployment pipeline failed because the database migration timed out!";
+const DISTINCT: &str = "Bananas are rich in potassium and make a convenient snack.";
+
+fn typed_edge(source: Uuid, target: Uuid) -> TypedEdge {
+    let mut scores = [0.0f32; 13];
+    scores[0] = 0.8;
+    TypedEdge::new(
+        source,
+        target,
+        GraphLinkEdgeType::SemanticSimilar,
+        0.8,
+        DirectedRelation::Symmetric,
+        scores,
+        1,
+        0b1,
+    )
+    .expect("valid typed edge")
+}
+
+#[tokio::test]
+async fn test_consolidation_merges_duplicates_and_retargets_edges() {
+    let (handlers, store_ref, edges, _tempdir) = create_test_handlers_with_edges().await;
+    let keep = store_memory(&handlers, 1, DUPLICATE_A).await;
+    let merge = store_memory(&handlers, 2, DUPLICATE_B).await;
+    let distinct = store_memory(&handlers, 3, DISTINCT).await;
+
+    // Edges touching the duplicate that must follow it to the survivor
+    edges
+        .store_typed_edge(&typed_edge(distinct, merge))
+        .unwrap();
+    edges
+        .store_typed_edge(&typed_edge(merge, distinct))
+        .unwrap();
+    let knn = EmbedderEdge::new(distinct, merge, 0, 0.7).unwrap();
+    edges.store_embedder_edges(0, distinct, &[knn]).unwrap();
+
+    let data = call_tool(
+        &handlers,
+        4,
+        "trigger_consolidation",
+        json!({ "strategy": "similarity", "min_similarity": 0.9 }),
+    )
+    .await;
+
+    let result = &data["consolidation_result"];
+    assert_eq!(result["status"], json!("merged"), "{}", data);
+    assert_eq!(result["merged_count"], json!(1));
+    // Equal access counts: the older memory survives
+    let pair = &result["merged_pairs"][0];
+    assert_eq!(pair["kept_id"], json!(keep.to_string()));
+    assert_eq!(pair["merged_id"], json!(merge.to_string()));
+    assert_eq!(pair["edges_retargeted"], json!(3));
+
+    // One survivor of the pair, the distinct memory untouched
+    assert_eq!(store_ref.count().await.unwrap(), 2);
+    assert!(store_ref.retrieve(keep).await.unwrap().is_some());
+    assert!(store_ref.retrieve(distinct).await.unwrap().is_some());
+    assert!(store_ref.retrieve(merge).await.unwrap().is_none());
+
+    // Tombstone points at the survivor
+    let audit = store_ref.get_audit_by_target(merge, 10).await.unwrap();
+    assert!(
+        audit.iter().any(|r| matches!(
+            r.operation,
+            AuditOperation::MemoryConsolidated { into, .. } if into == keep
+        )),
+        "merged memory must carry a MemoryConsolidated pointer to {}",
+        keep
+    );
+
+    // Edges re-targeted
+    assert!(edges.get_typed_edge(distinct, keep).unwrap().is_some());
+    assert!(edges.get_typed_edge(keep, distinct).unwrap().is_some());
+    assert!(edges.get_typed_edge(distinct, merge).unwrap().is_none());
+    assert!(edges.get_typed_edges_from(merge).unwrap().is_empty());
+    let knn_targets: Vec<Uuid> = edges
+        .get_embedder_edges(0, distinct)
+        .unwrap()
+        .iter()
+        .map(|e| e.target())
+        .collect();
+    assert_eq!(knn_targets, vec![keep]);
+}
+
+#[tokio::test]
+async fn test_consolidation_dry_run_leaves_store_untouched() {
+    let (handlers, store_ref, _edges, _tempdir) = create_test_handlers_with_edges().await;
+    store_memory(&handlers, 1, DUPLICATE_A).await;
+    store_memory(&handlers, 2, DUPLICATE_B).await;
+
+    let data = call_tool(
+        &handlers,
+        3,
+        "trigger_consolidation",
+        json!({ "min_similarity": 0.9, "dry_run": true }),
+    )
+    .await;
+
+    let result = &data["consolidation_result"];
+    assert_eq!(result["status"], json!("candidates_found"));
+    assert_eq!(result["merged_count"], json!(0));
+    assert_eq!(data["candidates_sample"].as_array().unwrap().len(), 1);
+    assert_eq!(store_ref.count().await.unwrap(), 2);
+}
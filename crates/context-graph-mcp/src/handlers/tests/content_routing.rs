@@ -0,0 +1,237 @@
+//! Content Routing Tests
+//!
+//! Verifies language-aware routing between E1 and E7:
+//! - store_memory classifies a Rust snippet, an English paragraph and a mixed
+//!   markdown doc, and skips E7 only for the pure prose
+//! - The decision is kept in the memory's source metadata
+//! - content_type overrides the classifier
+//! - A code query is routed to code-weighted fusion, which ranks the stored
+//!   snippet at least as high as the default semantic profile does
+
+use serde_json::json;
+use uuid::Uuid;
+
+use context_graph_core::code::{ContentType, ContentTypeSource};
+use context_graph_core::teleological::Embedder;
+
+use crate::handlers::Handlers;
+use crate::protocol::JsonRpcId;
+
+use super::{call_tool, create_test_handlers, make_request};
+
+const RUST_SNIPPET: &str = r#"use std::collections::HashMap;
+
+pub fn count_words(text: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_lowercase()).or_insert(0) += 1;
+    }
+    counts
+}"#;
+
+const ENGLISH_PARAGRAPH: &str = "To count words, split the text on whitespace, lowercase each \
+    word and keep a running tally for every distinct word in a table.";
+
+const MIXED_MARKDOWN: &str = r#"# Retry policy
+
+Failed uploads are retried with exponential backoff, capped at five attempts.
+The delay doubles after every failure so the storage service can recover.
+
+```rust
+fn backoff(attempt: u32) -> Duration {
+    Duration::from_millis(100 * 2u64.pow(attempt))
+}
+```
+
+Callers should treat the final error as permanent and surface it to the user.
+"#;
+
+const CODE_QUERY: &str = r#"fn word_frequencies(input: &str) -> HashMap<String, u32> {
+    let mut freq = HashMap::new();
+    for w in input.split_whitespace() {
+        *freq.entry(w.to_lowercase()).or_default() += 1;
+    }
+    freq
+}"#;
+
+async fn store(
+    handlers: &Handlers,
+    id: i64,
+    arguments: serde_json::Value,
+) -> (Uuid, serde_json::Value) {
+    let stored = call_tool(handlers, id, "store_memory", arguments).await;
+    let fingerprint_id = stored["fingerprintId"]
+        .as_str()
+        .and_then(|s| Uuid::parse_str(s).ok())
+        .expect("store_memory must return fingerprintId");
+    (fingerprint_id, stored["contentClassification"].clone())
+}
+
+fn rank_of(data: &serde_json::Value, id: Uuid) -> Option<usize> {
+    data["results"]
+        .as_array()
+        .expect("results array")
+        .iter()
+        .position(|r| r["fingerprintId"] == json!(id.to_string()))
+}
+
+#[tokio::test]
+async fn test_store_classifies_and_skips_e7_for_prose() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+
+    let (code_id, code) = store(&handlers, 1, json!({ "content": RUST_SNIPPET })).await;
+    assert_eq!(code["contentType"], "code");
+    assert_eq!(code["source"], "detected");
+    assert_eq!(code["language"], "rust");
+    assert_eq!(code["skippedEmbedders"], json!([]));
+
+    let (prose_id, prose) =
+        store(&handlers, 2, json!({ "content": ENGLISH_PARAGRAPH })).await;
+    assert_eq!(prose["contentType"], "prose");
+    assert_eq!(prose["skippedEmbedders"], json!(["E7"]));
+
+    let (_, mixed) = store(&handlers, 3, json!({ "content": MIXED_MARKDOWN })).await;
+    assert_eq!(mixed["contentType"], "mixed");
+    assert_eq!(mixed["skippedEmbedders"], json!([]));
+
+    // The skip is recorded in source metadata and leaves E7 empty
+    let metadata = handlers
+        .teleological_store
+        .get_source_metadata(prose_id)
+        .await
+        .expect("source metadata read")
+        .expect("source metadata stored");
+    let classification = metadata
+        .content_classification
+        .expect("classification recorded");
+    assert_eq!(classification.content_type, ContentType::Prose);
+    assert_eq!(metadata.skipped_embedders, Some(vec![Embedder::Code]));
+
+    let prose_fp = handlers
+        .teleological_store
+        .retrieve(prose_id)
+        .await
+        .unwrap()
+        .expect("prose memory stored");
+    assert!(prose_fp.semantic.e7_code.iter().all(|&v| v == 0.0));
+    let code_fp = handlers
+        .teleological_store
+        .retrieve(code_id)
+        .await
+        .unwrap()
+        .expect("code memory stored");
+    assert!(code_fp.semantic.e7_code.iter().any(|&v| v != 0.0));
+    let code_metadata = handlers
+        .teleological_store
+        .get_source_metadata(code_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(code_metadata.skipped_embedders, None);
+}
+
+#[tokio::test]
+async fn test_content_type_overrides_classifier() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+
+    let (id, classification) = store(
+        &handlers,
+        1,
+        json!({ "content": ENGLISH_PARAGRAPH, "content_type": "code" }),
+    )
+    .await;
+    assert_eq!(classification["contentType"], "code");
+    assert_eq!(classification["source"], "explicit");
+    assert_eq!(classification["skippedEmbedders"], json!([]));
+
+    let metadata = handlers
+        .teleological_store
+        .get_source_metadata(id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        metadata.content_classification.map(|c| c.source),
+        Some(ContentTypeSource::Explicit)
+    );
+
+    // Unknown content types are rejected by the schema
+    let params = json!({
+        "name": "store_memory",
+        "arguments": { "content": RUST_SNIPPET, "content_type": "markdown" },
+    });
+    let response = handlers
+        .dispatch(make_request(
+            "tools/call",
+            Some(JsonRpcId::Number(2)),
+            Some(params),
+        ))
+        .await;
+    let rejected = response.error.is_some()
+        || response
+            .result
+            .as_ref()
+            .is_some_and(|r| r["isError"] == json!(true));
+    assert!(rejected, "content_type 'markdown' must be rejected");
+}
+
+#[tokio::test]
+async fn test_code_query_ranks_snippet_higher_under_code_weights() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+
+    let (snippet_id, _) = store(&handlers, 1, json!({ "content": RUST_SNIPPET })).await;
+    store(&handlers, 2, json!({ "content": ENGLISH_PARAGRAPH })).await;
+    store(&handlers, 3, json!({ "content": MIXED_MARKDOWN })).await;
+    for (i, content) in [
+        "The word counter runs nightly over the support inbox and emails a summary.",
+        "Lowercasing every token before counting avoids treating 'The' and 'the' apart.",
+        "Our style guide prefers descriptive function names over abbreviations.",
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        store(&handlers, 10 + i as i64, json!({ "content": content })).await;
+    }
+
+    // minSimilarity 0 keeps the domain cut-off out of the comparison
+    let routed = call_tool(
+        &handlers,
+        100,
+        "search_graph",
+        json!({ "query": CODE_QUERY, "topK": 10, "minSimilarity": 0.0 }),
+    )
+    .await;
+    let default = call_tool(
+        &handlers,
+        101,
+        "search_graph",
+        json!({
+            "query": CODE_QUERY,
+            "topK": 10,
+            "minSimilarity": 0.0,
+            "weightProfile": "semantic_search",
+        }),
+    )
+    .await;
+
+    assert_eq!(routed["contentRouting"]["contentType"], "code");
+    assert_eq!(routed["contentRouting"]["applied"], json!(true));
+    assert_eq!(routed["effectiveProfile"], "content_routed");
+    assert_eq!(default["contentRouting"]["applied"], json!(false));
+    assert_eq!(default["effectiveProfile"], "semantic_search");
+
+    let routed_rank =
+        rank_of(&routed, snippet_id).expect("snippet found under code weights");
+    let default_rank = rank_of(&default, snippet_id).unwrap_or(usize::MAX);
+    assert_eq!(
+        routed_rank, 0,
+        "code weights must rank the snippet first: {}",
+        routed
+    );
+    assert!(
+        routed_rank <= default_rank,
+        "routed rank {} vs default rank {}",
+        routed_rank,
+        default_rank
+    );
+}
@@ -0,0 +1,56 @@
+//! Dispatch Limits Tests
+//!
+//! Verifies rate limiting over the dispatch layer:
+//! - A session that exhausts its token bucket gets SERVER_BUSY with a retry hint
+//! - Requests without a session are not rate limited
+//! - get_memetic_status reports the configured limits and rejections
+
+use context_graph_core::config::DispatchLimitsConfig;
+use serde_json::json;
+
+use crate::handlers::RequestContext;
+use crate::protocol::{error_codes, JsonRpcId, JsonRpcRequest};
+
+use super::{create_test_handlers, extract_mcp_tool_data, make_request};
+
+fn status_call(id: i64) -> JsonRpcRequest {
+    let params = json!({ "name": "get_memetic_status", "arguments": {} });
+    make_request("tools/call", Some(JsonRpcId::Number(id)), Some(params))
+}
+
+#[tokio::test]
+async fn test_session_over_rate_limit_gets_server_busy() {
+    let (mut handlers, _tempdir) = create_test_handlers().await;
+    handlers.set_dispatch_limits(DispatchLimitsConfig {
+        session_rps: 0.5,
+        session_burst: 2,
+        ..Default::default()
+    });
+    let session = RequestContext::default().with_session("agent-1");
+
+    for id in 0..2 {
+        let response = handlers
+            .dispatch_with_context(status_call(id), session.clone())
+            .await;
+        assert!(response.error.is_none(), "burst calls must be admitted");
+    }
+
+    let response = handlers
+        .dispatch_with_context(status_call(2), session.clone())
+        .await;
+    let error = response.error.expect("third call must be rejected");
+    assert_eq!(error.code, error_codes::SERVER_BUSY);
+    let data = error.data.expect("busy error carries a retry hint");
+    assert_eq!(data["reason"], json!("rate_limited"));
+    assert!(data["retryAfterMs"].as_u64().unwrap() > 0);
+    assert_eq!(response.id, Some(JsonRpcId::Number(2)));
+
+    // No session: in-process callers are never rate limited
+    let response = handlers.dispatch(status_call(3)).await;
+    let result = response.result.expect("session-less call must be admitted");
+    let status = extract_mcp_tool_data(&result);
+    let limits = &status["dispatchLimits"];
+    assert_eq!(limits["sessionBurst"], json!(2));
+    assert_eq!(limits["rejected"]["rateLimited"], json!(1));
+    assert_eq!(limits["categories"]["search"]["limit"], json!(8));
+}
@@ -0,0 +1,133 @@
+//! Edge Inference Tests
+//!
+//! Verifies that stored memories are linked to their E1 nearest neighbors:
+//! - A cluster of related memories becomes connected
+//! - An unrelated memory stays isolated
+//! - No memory exceeds `max_fanout` outgoing edges, including in a batch
+
+use std::collections::HashSet;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use context_graph_core::config::EdgeInferenceConfig;
+use context_graph_storage::EdgeRepository;
+
+use super::{call_tool, create_test_handlers_with_edges};
+
+const CLUSTER: [&str; 5] = [
+    "The deployment pipeline failed because the database migration timed out.",
+    "Deployment pipeline failure: the database migration timed out.",
+    "The database migration timed out, so the deployment pipeline failed.",
+    "Our deployment pipeline broke when the database migration hit its timeout.",
+    "The deployment pipeline failed again after the database migration timed out.",
+];
+const OUTLIER: &str = "Sourdough bread needs a long, cold overnight proof in the fridge.";
+
+fn parse_id(value: &serde_json::Value) -> Uuid {
+    value
+        .as_str()
+        .expect("fingerprintId must be a string")
+        .parse()
+        .expect("fingerprintId must be a UUID")
+}
+
+/// Memories reachable from `start` over typed edges in either direction.
+fn reachable(edges: &EdgeRepository, start: Uuid) -> HashSet<Uuid> {
+    let mut seen = HashSet::from([start]);
+    let mut frontier = vec![start];
+    while let Some(node) = frontier.pop() {
+        let outgoing = edges.get_typed_edges_from(node).unwrap();
+        let incoming = edges.get_typed_edges_to(node).unwrap();
+        for edge in outgoing.iter().chain(incoming.iter()) {
+            for next in [edge.source(), edge.target()] {
+                if seen.insert(next) {
+                    frontier.push(next);
+                }
+            }
+        }
+    }
+    seen
+}
+
+#[tokio::test]
+async fn test_cluster_connects_and_outlier_stays_isolated() {
+    let (mut handlers, _store, edges, _tempdir) = create_test_handlers_with_edges().await;
+    handlers.set_edge_inference(EdgeInferenceConfig::default());
+
+    let mut cluster = Vec::new();
+    let mut edges_created = 0;
+    for (i, content) in CLUSTER.iter().take(3).enumerate() {
+        let data = call_tool(
+            &handlers,
+            i as i64,
+            "store_memory",
+            json!({ "content": content }),
+        )
+        .await;
+        edges_created += data["edgesCreated"]
+            .as_u64()
+            .expect("store_memory must report edgesCreated");
+        cluster.push(parse_id(&data["fingerprintId"]));
+    }
+    let outlier = call_tool(&handlers, 10, "store_memory", json!({ "content": OUTLIER })).await;
+    assert_eq!(outlier["edgesCreated"], json!(0), "{}", outlier);
+    let outlier = parse_id(&outlier["fingerprintId"]);
+
+    assert!(edges_created > 0);
+    let component = reachable(&edges, cluster[0]);
+    for id in &cluster {
+        assert!(
+            component.contains(id),
+            "{} not connected to the cluster",
+            id
+        );
+    }
+    assert!(!component.contains(&outlier));
+    assert!(edges.get_typed_edges_from(outlier).unwrap().is_empty());
+    assert!(edges.get_typed_edges_to(outlier).unwrap().is_empty());
+
+    // Every inferred edge is unique per (source, target)
+    let mut pairs = HashSet::new();
+    for id in &cluster {
+        for edge in edges.get_typed_edges_from(*id).unwrap() {
+            assert!(pairs.insert((edge.source(), edge.target())));
+            assert!(edge.weight() > 0.0 && edge.weight() <= 1.0);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_fanout_cap_holds_for_batches() {
+    let config = EdgeInferenceConfig {
+        max_fanout: 2,
+        ..Default::default()
+    };
+    let (mut handlers, _store, edges, _tempdir) = create_test_handlers_with_edges().await;
+    handlers.set_edge_inference(config);
+
+    let items: Vec<serde_json::Value> = CLUSTER
+        .iter()
+        .map(|content| json!({ "content": content }))
+        .collect();
+    let data = call_tool(
+        &handlers,
+        1,
+        "store_memories_batch",
+        json!({ "items": items }),
+    )
+    .await;
+    assert_eq!(data["stored"], json!(CLUSTER.len()));
+    assert!(data["edgesCreated"].as_u64().unwrap() > 0, "{}", data);
+
+    let mut at_cap = 0;
+    for result in data["results"].as_array().unwrap() {
+        let id = parse_id(&result["fingerprintId"]);
+        let outgoing = edges.get_typed_edges_from(id).unwrap().len();
+        assert!(outgoing <= 2, "{} has {} outgoing edges", id, outgoing);
+        if outgoing == 2 {
+            at_cap += 1;
+        }
+    }
+    assert!(at_cap > 0, "the cap should bind for a five-memory cluster");
+}
@@ -0,0 +1,106 @@
+//! search_graph edgeTypes Expansion Tests
+//!
+//! Verifies the typed graph-expansion phase of search_graph:
+//! - A supersedes chain is expanded hop by hop from the top hit
+//! - Filtering to a type with no stored edges expands nothing
+//! - Unknown edge type names are validation errors
+
+use serde_json::json;
+use uuid::Uuid;
+
+use context_graph_core::graph_linking::{GraphLinkEdgeType, TypedEdge};
+use context_graph_storage::EdgeRepository;
+
+use crate::handlers::Handlers;
+
+use super::{call_tool, call_tool_raw, create_test_handlers_with_edges, store_memory};
+
+const VERSIONS: [&str; 3] = [
+    "Retention policy v1: keep audit logs for 30 days.",
+    "Retention policy v2: keep audit logs for 90 days.",
+    "Retention policy v3: keep audit logs for one year, then archive them.",
+];
+
+/// Store the versions and link each newer one to the one it supersedes.
+async fn store_chain(handlers: &Handlers, edges: &EdgeRepository) -> Vec<Uuid> {
+    let mut ids = Vec::new();
+    for (i, content) in VERSIONS.iter().enumerate() {
+        ids.push(store_memory(handlers, i as i64, content).await);
+    }
+    for pair in ids.windows(2) {
+        let edge = TypedEdge::relation(pair[1], pair[0], GraphLinkEdgeType::Supersedes).unwrap();
+        edges.store_typed_edge(&edge).unwrap();
+    }
+    ids
+}
+
+fn expanded_ids(data: &serde_json::Value) -> Vec<String> {
+    data["graphExpansion"]["nodes"]
+        .as_array()
+        .expect("graphExpansion.nodes must be an array")
+        .iter()
+        .map(|n| n["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_supersedes_expansion_follows_the_chain() {
+    let (handlers, _store, edges, _tempdir) = create_test_handlers_with_edges().await;
+    let ids = store_chain(&handlers, &edges).await;
+
+    let data = call_tool(
+        &handlers,
+        10,
+        "search_graph",
+        json!({
+            "query": VERSIONS[2],
+            "topK": 1,
+            "edgeTypes": ["supersedes"],
+            "expansionHops": 2,
+        }),
+    )
+    .await;
+    assert_eq!(
+        data["results"][0]["fingerprintId"],
+        json!(ids[2].to_string())
+    );
+    assert_eq!(data["graphExpansion"]["edgeTypes"], json!(["supersedes"]));
+    assert_eq!(
+        expanded_ids(&data),
+        vec![ids[1].to_string(), ids[0].to_string()]
+    );
+    for edge in data["graphExpansion"]["edges"].as_array().unwrap() {
+        assert_eq!(edge["edgeType"], json!("supersedes"));
+    }
+
+    let semantic_only = call_tool(
+        &handlers,
+        11,
+        "search_graph",
+        json!({
+            "query": VERSIONS[2],
+            "topK": 1,
+            "edgeTypes": ["semantic_similar"],
+            "expansionHops": 2,
+        }),
+    )
+    .await;
+    assert!(expanded_ids(&semantic_only).is_empty(), "{}", semantic_only);
+    assert_eq!(semantic_only["graphExpansion"]["edges"], json!([]));
+}
+
+#[tokio::test]
+async fn test_unknown_edge_type_is_rejected() {
+    let (handlers, _store, _edges, _tempdir) = create_test_handlers_with_edges().await;
+
+    for edge_types in [json!(["overrides"]), json!([]), json!("supersedes")] {
+        let result = call_tool_raw(
+            &handlers,
+            1,
+            "search_graph",
+            json!({ "query": "retention policy", "edgeTypes": edge_types }),
+        )
+        .await;
+        assert_eq!(result["isError"], json!(true), "{}", result);
+    }
+}
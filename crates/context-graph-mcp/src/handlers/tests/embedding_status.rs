@@ -0,0 +1,133 @@
+//! Embedding Status Tests
+//!
+//! Simulates staged model loading through a shared `ProviderHealth`:
+//! - get_embedding_status reports each NotLoaded -> Loading -> Ready transition
+//! - store_memory and search_graph get RETRY_LATER naming the blocking models
+//! - Non-embedding tools are never blocked
+//! - Once every model is Ready, store_memory succeeds
+
+use std::sync::Arc;
+
+use context_graph_embeddings::ModelId;
+use serde_json::json;
+
+use crate::adapters::ProviderHealth;
+use crate::handlers::Handlers;
+use crate::protocol::{error_codes, JsonRpcResponse};
+
+use super::{call_tool, call_tool_response, create_test_handlers};
+
+async fn embedding_status(handlers: &Handlers, id: i64) -> serde_json::Value {
+    call_tool(handlers, id, "get_embedding_status", json!({})).await
+}
+
+fn model_state<'a>(status: &'a serde_json::Value, model: ModelId) -> &'a serde_json::Value {
+    status["models"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|m| m["model"] == json!(model.as_str()))
+        .expect("model must be reported")
+}
+
+fn blocking_models(response: JsonRpcResponse) -> Vec<String> {
+    let error = response.error.expect("call must be rejected");
+    assert_eq!(error.code, error_codes::RETRY_LATER);
+    let data = error.data.expect("RETRY_LATER carries data");
+    assert!(data["retryAfterMs"].as_u64().unwrap() > 0);
+    data["blockingModels"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|m| m.as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_staged_loading_blocks_until_ready() {
+    let (mut handlers, _tempdir) = create_test_handlers().await;
+    let health = Arc::new(ProviderHealth::new());
+    handlers.set_provider_health(Arc::clone(&health));
+    let store_args = json!({ "content": "Retry backoff doubles up to 30 seconds" });
+
+    // Nothing loaded: every production model blocks the store
+    let status = embedding_status(&handlers, 1).await;
+    assert_eq!(status["ready"], json!(false));
+    assert_eq!(status["readyCount"], json!(0));
+    assert_eq!(status["estimatedRemainingMs"], json!(null));
+    assert_eq!(status["determinism"], json!(null), "determinism is opt-in");
+    assert_eq!(
+        model_state(&status, ModelId::Semantic)["state"],
+        json!("notLoaded")
+    );
+    let blocking =
+        blocking_models(call_tool_response(&handlers, 2, "store_memory", store_args.clone()).await);
+    assert_eq!(blocking.len(), 13);
+
+    // Status tools never wait for models
+    let response = call_tool_response(&handlers, 3, "get_memetic_status", json!({})).await;
+    assert!(response.error.is_none());
+
+    // First stage: E1 loading, then ready
+    health.set_loading(ModelId::Semantic, 60);
+    let status = embedding_status(&handlers, 4).await;
+    let semantic = model_state(&status, ModelId::Semantic);
+    assert_eq!(semantic["state"], json!("loading"));
+    assert_eq!(semantic["pct"], json!(60));
+
+    health.set_ready(ModelId::Semantic);
+    let status = embedding_status(&handlers, 5).await;
+    assert_eq!(
+        model_state(&status, ModelId::Semantic)["state"],
+        json!("ready")
+    );
+    assert_eq!(status["readyCount"], json!(1));
+    assert!(status["estimatedRemainingMs"].is_u64());
+
+    let blocking = blocking_models(
+        call_tool_response(&handlers, 6, "search_graph", json!({ "query": "backoff" })).await,
+    );
+    assert_eq!(blocking.len(), 12);
+    assert!(!blocking.contains(&"semantic".to_string()));
+
+    // Second stage: everything but E7 ready
+    for &model in ModelId::production() {
+        if model != ModelId::Code {
+            health.set_ready(model);
+        }
+    }
+    let blocking =
+        blocking_models(call_tool_response(&handlers, 7, "store_memory", store_args.clone()).await);
+    assert_eq!(blocking, vec!["code".to_string()]);
+
+    // All ready: the store goes through
+    health.set_ready(ModelId::Code);
+    let status = embedding_status(&handlers, 8).await;
+    assert_eq!(status["ready"], json!(true));
+    assert_eq!(status["readyCount"], json!(13));
+    let response = call_tool_response(&handlers, 9, "store_memory", store_args).await;
+    let result = response
+        .result
+        .expect("store_memory must be admitted once ready");
+    assert_eq!(result["isError"], json!(false), "{}", result);
+}
+
+#[tokio::test]
+async fn test_failed_model_is_reported() {
+    let (mut handlers, _tempdir) = create_test_handlers().await;
+    let health = Arc::new(ProviderHealth::new());
+    health.set_all_ready();
+    health.set_failed(ModelId::Splade, "weights missing");
+    handlers.set_provider_health(Arc::clone(&health));
+
+    let status = embedding_status(&handlers, 1).await;
+    assert_eq!(status["failedCount"], json!(1));
+    let splade = model_state(&status, ModelId::Splade);
+    assert_eq!(splade["state"], json!("failed"));
+    assert_eq!(splade["error"], json!("weights missing"));
+
+    let response =
+        call_tool_response(&handlers, 2, "store_memory", json!({ "content": "x" })).await;
+    let data = response.error.expect("rejected").data.unwrap();
+    assert_eq!(data["failedModels"], json!(["splade"]));
+}
@@ -0,0 +1,179 @@
+//! Error Taxonomy Tests
+//!
+//! Builds leaf errors from each crate, converts them through the chain a
+//! handler sees (leaf -> CoreError -> ContextGraphError -> ToolErrorKind),
+//! and checks the kind, the JSON-RPC code and that `source()` still reaches
+//! the original error.
+
+use std::error::Error;
+use std::sync::Arc;
+
+use serde_json::json;
+use tempfile::TempDir;
+use uuid::Uuid;
+
+use context_graph_core::error::{error_chain, ContextGraphError, CoreError, ErrorKind};
+use context_graph_core::monitoring::StubLayerStatusProvider;
+use context_graph_core::stubs::StubMultiArrayProvider;
+use context_graph_cuda::CudaError;
+use context_graph_embeddings::{EmbeddingError, ModelId};
+use context_graph_graph_agent::{create_stub_graph_discovery_service, GraphAgentError};
+use context_graph_storage::teleological::{
+    RocksDbTeleologicalStore, SearchError, TeleologicalStoreError,
+};
+
+use crate::handlers::tools::helpers::ToolErrorKind;
+use crate::handlers::Handlers;
+use crate::protocol::{error_codes, JsonRpcId};
+
+/// Assert the classification of `err` and that its source is `leaf_message`.
+fn assert_classified(err: ContextGraphError, kind: ErrorKind, code: i32, leaf_message: &str) {
+    assert_eq!(err.kind(), kind, "kind of {}", err);
+    assert_eq!(ToolErrorKind::from(err.kind()).code_and_label().0, code);
+    let source = err.source().expect("classified error must keep its source");
+    assert_eq!(source.to_string(), leaf_message);
+    assert!(error_chain(&err).contains(leaf_message));
+}
+
+#[test]
+fn test_embedding_oom_is_retryable_gpu_failure() {
+    let leaf = EmbeddingError::OomDuringBatch {
+        batch_size: 64,
+        model_id: ModelId::Semantic,
+    };
+    let message = leaf.to_string();
+
+    let core = CoreError::from(leaf);
+    assert_eq!(core.kind(), ErrorKind::GpuFailure);
+    assert!(core.is_retryable());
+    assert_eq!(core.source().map(|s| s.to_string()), Some(message.clone()));
+
+    let err = ContextGraphError::from(core);
+    assert!(err.is_retryable());
+    assert_classified(err, ErrorKind::GpuFailure, error_codes::GPU_ERROR, &message);
+}
+
+#[test]
+fn test_embedding_backpressure_stays_core_backpressure() {
+    let leaf = EmbeddingError::Backpressure {
+        queue_depth: 32,
+        retry_after_ms: 250,
+        model_id: ModelId::Semantic,
+    };
+    let core = CoreError::from(leaf);
+    assert!(matches!(
+        core,
+        CoreError::Backpressure {
+            queue_depth: 32,
+            retry_after_ms: 250
+        }
+    ));
+    let err = ContextGraphError::from(core);
+    assert_eq!(err.kind(), ErrorKind::Backpressure);
+    assert_eq!(
+        ToolErrorKind::from(err.kind()).code_and_label().0,
+        error_codes::SERVER_BUSY
+    );
+}
+
+#[test]
+fn test_store_corruption_maps_to_storage_code() {
+    let leaf = TeleologicalStoreError::CorruptionDetected {
+        path: "/data/cg".to_string(),
+        missing_count: 1,
+        missing_files: "000042.sst".to_string(),
+        manifest_file: "MANIFEST-000007".to_string(),
+    };
+    let message = leaf.to_string();
+
+    let core = CoreError::from(leaf);
+    let err = ContextGraphError::from(core);
+    assert!(err.is_critical());
+    assert!(!err.is_retryable());
+    assert_classified(
+        err,
+        ErrorKind::Corruption,
+        error_codes::STORAGE_ERROR,
+        &message,
+    );
+}
+
+#[test]
+fn test_search_not_found_maps_to_not_found_code() {
+    let leaf = SearchError::NotFound { id: Uuid::nil() };
+    let message = leaf.to_string();
+    assert_classified(
+        ContextGraphError::from(leaf),
+        ErrorKind::NotFound,
+        error_codes::NODE_NOT_FOUND,
+        &message,
+    );
+}
+
+#[test]
+fn test_missing_capabilities_map_to_capability_unavailable() {
+    let cuda = CudaError::NoDevice;
+    let message = cuda.to_string();
+    assert_classified(
+        ContextGraphError::from(cuda),
+        ErrorKind::CapabilityUnavailable,
+        error_codes::CAPABILITY_UNAVAILABLE,
+        &message,
+    );
+
+    let agent = GraphAgentError::LlmNotInitialized;
+    let message = agent.to_string();
+    let core = CoreError::from(ContextGraphError::from(agent));
+    assert_classified(
+        ContextGraphError::from(core),
+        ErrorKind::CapabilityUnavailable,
+        error_codes::CAPABILITY_UNAVAILABLE,
+        &message,
+    );
+}
+
+#[test]
+fn test_every_kind_has_a_distinct_label() {
+    let mut labels: Vec<&str> = ErrorKind::ALL
+        .iter()
+        .map(|kind| ToolErrorKind::from(*kind).code_and_label().1)
+        .collect();
+    labels.sort_unstable();
+    labels.dedup();
+    assert_eq!(labels.len(), ErrorKind::ALL.len());
+}
+
+#[tokio::test]
+async fn test_tool_error_from_response_shape() {
+    let tempdir = TempDir::new().expect("Failed to create temp directory");
+    let store = RocksDbTeleologicalStore::open(tempdir.path().join("test_rocksdb"))
+        .expect("Failed to open RocksDbTeleologicalStore");
+    let handlers = Handlers::with_defaults(
+        Arc::new(store),
+        Arc::new(StubMultiArrayProvider::new()),
+        Arc::new(StubLayerStatusProvider),
+        create_stub_graph_discovery_service(),
+    )
+    .expect("Default cluster manager should always succeed in tests");
+
+    let leaf = EmbeddingError::OomDuringBatch {
+        batch_size: 8,
+        model_id: ModelId::Code,
+    };
+    let response = handlers.tool_error_from(
+        Some(JsonRpcId::Number(1)),
+        "search_graph",
+        "Embedding failed",
+        CoreError::from(leaf),
+    );
+    let result = response.result.expect("tool errors are MCP results");
+    assert_eq!(result["isError"], json!(true));
+    assert_eq!(result["errorCode"], json!(error_codes::GPU_ERROR));
+    assert_eq!(result["retryable"], json!(true));
+    let text = result["content"][0]["text"].as_str().unwrap();
+    assert!(
+        text.starts_with("[GPU_FAILURE -32009] Embedding failed: "),
+        "{}",
+        text
+    );
+}
@@ -0,0 +1,110 @@
+//! Memory Lineage Tests
+//!
+//! Verifies that get_memory_lineage reconstructs ancestry across operations:
+//! - merge_concepts records the merged memory's sources as parents
+//! - trigger_consolidation records the absorbed memory as the survivor's parent
+//! - Ancestors are reported at their depth, with whether they still exist
+
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{call_tool, create_test_handlers_with_edges, store_memory};
+
+const NEAR_DUPLICATE_A: &str =
+    "The deployment pipeline failed because the database migration timed out.";
+const NEAR_DUPLICATE_B: &str =
+    "The deployment pipeline failed because the database migration timed out!";
+const NEAR_DUPLICATE_D: &str =
+    "The deployment pipeline failed since the database migration timed out.";
+
+#[tokio::test]
+async fn test_lineage_spans_merge_and_consolidation() {
+    let (handlers, _store_ref, _edges, _tempdir) = create_test_handlers_with_edges().await;
+    // D is stored first so it is older than the merge result and survives consolidation
+    let d = store_memory(&handlers, 1, NEAR_DUPLICATE_D).await;
+    let a = store_memory(&handlers, 2, NEAR_DUPLICATE_A).await;
+    let b = store_memory(&handlers, 3, NEAR_DUPLICATE_B).await;
+
+    let merged = call_tool(
+        &handlers,
+        4,
+        "merge_concepts",
+        json!({
+            "source_ids": [a, b],
+            "target_name": "Migration timeout",
+            "rationale": "Same incident",
+            "force_merge": true
+        }),
+    )
+    .await;
+    let c: Uuid = merged["merged_id"]
+        .as_str()
+        .expect("merge_concepts must return merged_id")
+        .parse()
+        .expect("merged_id must be a UUID");
+
+    let data = call_tool(
+        &handlers,
+        5,
+        "trigger_consolidation",
+        json!({ "strategy": "similarity", "min_similarity": 0.9 }),
+    )
+    .await;
+    let pair = &data["consolidation_result"]["merged_pairs"][0];
+    assert_eq!(pair["kept_id"], json!(d.to_string()), "{}", data);
+    assert_eq!(pair["merged_id"], json!(c.to_string()), "{}", data);
+
+    let lineage = call_tool(
+        &handlers,
+        6,
+        "get_memory_lineage",
+        json!({ "memory_id": d.to_string() }),
+    )
+    .await;
+
+    assert_eq!(lineage["ancestor_count"], json!(3), "{}", lineage);
+    assert_eq!(lineage["truncated"], json!(false));
+    let depths: HashMap<String, (u64, bool)> = lineage["ancestors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| {
+            (
+                n["id"].as_str().unwrap().to_string(),
+                (n["depth"].as_u64().unwrap(), n["exists"].as_bool().unwrap()),
+            )
+        })
+        .collect();
+    // Every ancestor was soft-deleted by the operation that consumed it
+    assert_eq!(depths[&c.to_string()], (1, false));
+    assert_eq!(depths[&a.to_string()], (2, false));
+    assert_eq!(depths[&b.to_string()], (2, false));
+
+    let operations: HashMap<String, String> = lineage["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| {
+            (
+                e["parent_id"].as_str().unwrap().to_string(),
+                e["operation"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    assert_eq!(operations[&c.to_string()], "consolidation");
+    assert_eq!(operations[&a.to_string()], "merge");
+    assert_eq!(operations[&b.to_string()], "merge");
+
+    // max_depth cuts the walk below the consolidation edge
+    let shallow = call_tool(
+        &handlers,
+        7,
+        "get_memory_lineage",
+        json!({ "memory_id": d.to_string(), "max_depth": 1 }),
+    )
+    .await;
+    assert_eq!(shallow["ancestor_count"], json!(1));
+    assert_eq!(shallow["truncated"], json!(true));
+}
@@ -171,8 +171,8 @@ async fn test_e2e_tools_list_all_tools() {
     // Audit-12 TST-H3 FIX: Exact assertion (this test is #[cfg(feature = "llm")])
     assert_eq!(
         tools.len(),
-        58,
-        "Expected exactly 58 tools with LLM feature, found {}",
+        70,
+        "Expected exactly 70 tools with LLM feature, found {}",
         tools.len()
     );
 
@@ -231,8 +231,7 @@ async fn test_e2e_core_tools_workflow() {
         "name": "store_memory",
         "arguments": {
             "content": "Tokio runtime provides green threads for async programming",
-            "importance": 0.85,
-            "tags": ["rust", "async", "tokio"]
+            "importance": 0.85
         }
     });
     let store_request = make_request("tools/call", Some(JsonRpcId::Number(2)), Some(store_params));
@@ -612,7 +611,8 @@ async fn test_e2e_curation_tools_workflow() {
             "source_ids": [fingerprint_ids[0], fingerprint_ids[1]],
             "target_name": "Merged Database Concept",
             "rationale": "Consolidating similar database memories",
-            "merge_strategy": "union"
+            "merge_strategy": "union",
+            "force_merge": true
         }
     });
     let merge_request = make_request(
@@ -897,7 +897,8 @@ async fn test_e2e_all_11_tools_callable() {
             "source_ids": [&fingerprint_id, &fingerprint_id_for_merge],
             "target_name": "Merged Concept",
             "rationale": "Consolidating similar memories",
-            "merge_strategy": "union"
+            "merge_strategy": "union",
+            "force_merge": true
         }),
     )
     .await
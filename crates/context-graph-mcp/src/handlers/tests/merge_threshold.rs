@@ -0,0 +1,117 @@
+//! Merge Threshold Tests
+//!
+//! Verifies merge_concepts similarity enforcement:
+//! - Sources below the domain's theta_dup are refused with the scores
+//! - `force_merge` merges them anyway
+//! - `preview` reports the merge and combined edge set without writing,
+//!   checked against the store's state digest
+
+use serde_json::json;
+use uuid::Uuid;
+
+use context_graph_core::graph_linking::{DirectedRelation, GraphLinkEdgeType, TypedEdge};
+
+use crate::protocol::error_codes;
+
+use super::{call_tool, call_tool_raw, create_test_handlers_with_rocksdb_edges, store_memory};
+
+const DUPLICATE_A: &str =
+    "The deployment pipeline failed because the database migration timed out.";
+const DUPLICATE_B: &str =
+    "The deployment pipeline failed because the database migration timed out!";
+const UNRELATED: &str = "Sourdough bread needs a long, cold overnight proof in the fridge.";
+
+fn merge_args(a: Uuid, b: Uuid) -> serde_json::Value {
+    json!({
+        "source_ids": [a, b],
+        "target_name": "Migration timeout",
+        "rationale": "Same incident",
+        "domain": "general"
+    })
+}
+
+fn typed_edge(source: Uuid, target: Uuid) -> TypedEdge {
+    let mut scores = [0.0f32; 13];
+    scores[0] = 0.8;
+    TypedEdge::new(
+        source,
+        target,
+        GraphLinkEdgeType::SemanticSimilar,
+        0.8,
+        DirectedRelation::Symmetric,
+        scores,
+        1,
+        0b1,
+    )
+    .expect("valid typed edge")
+}
+
+#[tokio::test]
+async fn test_merge_below_theta_dup_is_refused_unless_forced() {
+    let (handlers, store_ref, _edges, _tempdir) = create_test_handlers_with_rocksdb_edges().await;
+    let a = store_memory(&handlers, 1, DUPLICATE_A).await;
+    let unrelated = store_memory(&handlers, 2, UNRELATED).await;
+
+    let result = call_tool_raw(&handlers, 3, "merge_concepts", merge_args(a, unrelated)).await;
+    assert_eq!(result["isError"], json!(true), "{}", result);
+    assert_eq!(result["errorCode"], json!(error_codes::INVALID_PARAMS));
+    let similarity = &result["similarity"];
+    assert_eq!(similarity["passes"], json!(false));
+    assert_eq!(similarity["domain"], json!("general"));
+    let weighted = similarity["weighted"].as_f64().unwrap();
+    assert!(weighted < similarity["theta_dup"].as_f64().unwrap());
+    assert!(similarity["pairs"][0]["per_space"]["E1_Semantic"].is_number());
+    // Nothing merged
+    assert!(store_ref.retrieve(a).await.unwrap().is_some());
+    assert!(store_ref.retrieve(unrelated).await.unwrap().is_some());
+
+    let mut forced = merge_args(a, unrelated);
+    forced["force_merge"] = json!(true);
+    let data = call_tool(&handlers, 4, "merge_concepts", forced).await;
+    assert_eq!(data["success"], json!(true));
+    assert_eq!(data["similarity"]["passes"], json!(false));
+    assert!(store_ref.retrieve(a).await.unwrap().is_none());
+    assert!(store_ref.retrieve(unrelated).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_merge_preview_writes_nothing() {
+    let (handlers, store_ref, edges, _tempdir) = create_test_handlers_with_rocksdb_edges().await;
+    let a = store_memory(&handlers, 1, DUPLICATE_A).await;
+    let b = store_memory(&handlers, 2, DUPLICATE_B).await;
+    let peer = store_memory(&handlers, 3, UNRELATED).await;
+
+    // Both sources link the peer; the edge between them collapses
+    edges.store_typed_edge(&typed_edge(a, peer)).unwrap();
+    edges.store_typed_edge(&typed_edge(b, peer)).unwrap();
+    edges.store_typed_edge(&typed_edge(peer, b)).unwrap();
+    edges.store_typed_edge(&typed_edge(a, b)).unwrap();
+
+    let before = store_ref.state_digest().unwrap();
+    let mut args = merge_args(a, b);
+    args["preview"] = json!(true);
+    let preview = call_tool(&handlers, 4, "merge_concepts", args).await;
+    assert_eq!(store_ref.state_digest().unwrap(), before);
+
+    assert_eq!(preview["preview"], json!(true));
+    assert_eq!(preview["would_merge"], json!(true), "{}", preview);
+    assert_eq!(preview["similarity"]["passes"], json!(true));
+    assert!(preview["merged_content"]
+        .as_str()
+        .unwrap()
+        .contains("Migration timeout"));
+    let merged_edges = preview["edges"].as_array().unwrap();
+    assert_eq!(merged_edges.len(), 2, "{}", preview);
+    assert_eq!(merged_edges[0]["peer_id"], json!(peer.to_string()));
+    assert_eq!(merged_edges[0]["direction"], json!("outgoing"));
+    assert_eq!(merged_edges[0]["from_source"], json!(a.to_string()));
+    assert_eq!(merged_edges[1]["direction"], json!("incoming"));
+    assert_eq!(merged_edges[1]["from_source"], json!(b.to_string()));
+
+    // The real merge takes over the same edges
+    let data = call_tool(&handlers, 5, "merge_concepts", merge_args(a, b)).await;
+    let merged: Uuid = data["merged_id"].as_str().unwrap().parse().unwrap();
+    assert!(edges.get_typed_edge(merged, peer).unwrap().is_some());
+    assert!(edges.get_typed_edge(peer, merged).unwrap().is_some());
+    assert!(edges.get_typed_edges_from(a).unwrap().is_empty());
+}
@@ -12,6 +12,10 @@
 //! - `create_test_handlers_with_rocksdb_store_access()` - Same + exposed store ref for FSV
 //! - `create_test_handlers_with_real_embeddings()` - Alias for create_test_handlers()
 //! - `create_test_handlers_with_real_embeddings_store_access()` - Alias for store access variant
+//! - `create_test_handlers_with_edges()` - Store access + `EdgeRepository` on the same database
+//! - `create_test_handlers_with_rocksdb_edges()` - Same, with the concrete RocksDB store
+//! - `call_tool()` / `call_tool_raw()` / `call_tool_response()` - Dispatch one tools/call
+//! - `store_memory()` / `store_memory_with()` - store_memory returning the new fingerprint ID
 //!
 //! # TempDir Lifecycle
 //!
@@ -26,13 +30,37 @@
 //! }
 //! ```
 
+mod backup_tool;
+mod capabilities;
+mod chunking;
+mod consolidation;
+mod content_routing;
+mod dispatch_limits;
+mod edge_inference;
+mod edge_types;
+mod embedding_status;
 mod error_codes;
+mod error_taxonomy;
 mod initialize;
+mod lineage;
 mod mcp_protocol_e2e_test;
+mod merge_threshold;
+mod near_duplicate;
+mod progress;
+mod query_dsl;
+mod query_embedding;
+mod resources;
+mod search_batch;
 mod search_periodic_test;
+mod search_profiles;
+mod staging;
+mod status_cache;
 mod tcp_transport_integration;
+mod time_range;
+mod tool_audit;
 mod tools_call;
 mod tools_list;
+mod topic_records;
 mod topic_tools;
 
 use std::sync::Arc;
@@ -42,6 +70,8 @@ use tempfile::TempDir;
 use context_graph_core::monitoring::{LayerStatusProvider, StubLayerStatusProvider};
 use context_graph_core::traits::{MultiArrayEmbeddingProvider, TeleologicalMemoryStore};
 use context_graph_storage::teleological::RocksDbTeleologicalStore;
+#[cfg(feature = "llm")]
+use context_graph_storage::EdgeRepository;
 
 // GRAPH-AGENT: Import stub for testing (enabled via test-utils feature in dev-dependencies)
 #[cfg(feature = "llm")]
@@ -156,7 +186,7 @@ async fn get_warm_loaded_provider() -> Arc<dyn MultiArrayEmbeddingProvider> {
 }
 
 use crate::handlers::Handlers;
-use crate::protocol::{JsonRpcId, JsonRpcRequest};
+use crate::protocol::{JsonRpcId, JsonRpcRequest, JsonRpcResponse};
 
 // ============================================================================
 // MCP Response Parsing Helpers
@@ -344,6 +374,52 @@ pub(crate) async fn create_test_handlers_with_real_embeddings_store_access(
     (handlers, teleological_store, tempdir)
 }
 
+/// Create test handlers with store access and an `EdgeRepository` on the same RocksDB.
+///
+/// For tools that read or rewrite graph edges; the repository is attached to
+/// the handlers and also returned so tests can seed and inspect edges.
+#[cfg(feature = "llm")]
+pub(crate) async fn create_test_handlers_with_edges() -> (
+    Handlers,
+    Arc<dyn TeleologicalMemoryStore>,
+    EdgeRepository,
+    TempDir,
+) {
+    let (handlers, rocksdb_store, edge_repository, tempdir) =
+        create_test_handlers_with_rocksdb_edges().await;
+    (handlers, rocksdb_store, edge_repository, tempdir)
+}
+
+/// Same as `create_test_handlers_with_edges()`, but returns the concrete
+/// RocksDB store for assertions on storage-only APIs such as `state_digest`.
+#[cfg(feature = "llm")]
+pub(crate) async fn create_test_handlers_with_rocksdb_edges() -> (
+    Handlers,
+    Arc<RocksDbTeleologicalStore>,
+    EdgeRepository,
+    TempDir,
+) {
+    let tempdir = TempDir::new().expect("Failed to create temp directory for edge test");
+    let db_path = tempdir.path().join("test_rocksdb_edges");
+
+    let rocksdb_store = Arc::new(
+        RocksDbTeleologicalStore::open(&db_path)
+            .expect("Failed to open RocksDbTeleologicalStore in edge test"),
+    );
+    let edge_repository = EdgeRepository::new(rocksdb_store.db_arc());
+
+    let mut handlers = Handlers::with_defaults(
+        Arc::clone(&rocksdb_store) as Arc<dyn TeleologicalMemoryStore>,
+        get_warm_loaded_provider().await,
+        Arc::new(StubLayerStatusProvider),
+        create_stub_graph_discovery_service(),
+    )
+    .expect("Default cluster manager should always succeed in tests");
+    handlers.set_edge_repository(edge_repository.clone());
+
+    (handlers, rocksdb_store, edge_repository, tempdir)
+}
+
 /// Resolve models directory for tests.
 ///
 /// Priority:
@@ -384,8 +460,77 @@ pub(crate) fn make_request(
     }
 }
 
+/// Dispatch a tools/call and return the whole response, for calls that may
+/// be refused with a JSON-RPC error instead of a tool result.
+pub(super) async fn call_tool_response(
+    handlers: &Handlers,
+    id: i64,
+    name: &str,
+    arguments: serde_json::Value,
+) -> JsonRpcResponse {
+    let params = serde_json::json!({ "name": name, "arguments": arguments });
+    handlers
+        .dispatch(make_request(
+            "tools/call",
+            Some(JsonRpcId::Number(id)),
+            Some(params),
+        ))
+        .await
+}
+
+/// Dispatch a tools/call and return the raw `result`, error or not.
+pub(super) async fn call_tool_raw(
+    handlers: &Handlers,
+    id: i64,
+    name: &str,
+    arguments: serde_json::Value,
+) -> serde_json::Value {
+    call_tool_response(handlers, id, name, arguments)
+        .await
+        .result
+        .expect("tools/call must return a result")
+}
+
+/// Dispatch a tools/call that must succeed and return the parsed tool payload.
+pub(super) async fn call_tool(
+    handlers: &Handlers,
+    id: i64,
+    name: &str,
+    arguments: serde_json::Value,
+) -> serde_json::Value {
+    let result = call_tool_raw(handlers, id, name, arguments).await;
+    assert_eq!(
+        result["isError"],
+        serde_json::json!(false),
+        "{} failed: {}",
+        name,
+        result
+    );
+    extract_mcp_tool_data(&result)
+}
+
 // ============================================================================
 // TASK-GAP-001: Removed obsolete test helper code
 // ============================================================================
 // Legacy test helpers were removed as they referenced deleted modules.
 // Current tests use the simplified handler construction per constitution v6.
+
+/// Store `content` via the store_memory tool and return its fingerprint ID.
+pub(super) async fn store_memory(handlers: &Handlers, id: i64, content: &str) -> uuid::Uuid {
+    store_memory_with(handlers, id, serde_json::json!({ "content": content })).await
+}
+
+/// Call store_memory with full `arguments` (e.g. a namespace) and return
+/// the new fingerprint ID.
+pub(super) async fn store_memory_with(
+    handlers: &Handlers,
+    id: i64,
+    arguments: serde_json::Value,
+) -> uuid::Uuid {
+    let data = call_tool(handlers, id, "store_memory", arguments).await;
+    data["fingerprintId"]
+        .as_str()
+        .expect("store_memory must return fingerprintId")
+        .parse()
+        .expect("fingerprintId must be a UUID")
+}
@@ -0,0 +1,193 @@
+//! Near-Duplicate Detection Tests
+//!
+//! Verifies the domain-aware near-duplicate check in store_memory:
+//! - A pair at E1 similarity 0.92 is flagged under the Code domain
+//!   (theta_dup 0.90) but not under Creative (theta_dup 0.95)
+//! - Exact-hash dedup and semantic near-dup report different dedupKinds
+//! - strict_dedup refuses the write
+//!
+//! The neighbor is planted directly in the store with an E1 vector built to
+//! sit at the target similarity from the embedding of the stored content.
+
+use serde_json::json;
+use uuid::Uuid;
+
+use context_graph_core::types::fingerprint::TeleologicalFingerprint;
+
+use crate::handlers::Handlers;
+
+use super::{call_tool, call_tool_raw, create_test_handlers};
+
+const CONTENT: &str = "Retry the upload with exponential backoff before giving up.";
+
+/// Similarity of the planted neighbor on the search scale, (cos + 1) / 2.
+const PAIR_SIMILARITY: f32 = 0.92;
+
+/// Unit vector whose cosine with `a` is `2 * similarity - 1`.
+fn vector_at_similarity(a: &[f32], similarity: f32) -> Vec<f32> {
+    let norm = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let a: Vec<f32> = a.iter().map(|x| x / norm).collect();
+
+    // Orthogonal direction from the axis `a` leans on least
+    let axis = (0..a.len())
+        .min_by(|&i, &j| a[i].abs().total_cmp(&a[j].abs()))
+        .unwrap();
+    let mut u: Vec<f32> = a.iter().map(|x| -a[axis] * x).collect();
+    u[axis] += 1.0;
+    let u_norm = u.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    let cos = 2.0 * similarity - 1.0;
+    let sin = (1.0 - cos * cos).sqrt();
+    a.iter()
+        .zip(&u)
+        .map(|(x, y)| cos * x + sin * y / u_norm)
+        .collect()
+}
+
+/// Plant a memory in `namespace` at `PAIR_SIMILARITY` from CONTENT's E1.
+async fn plant_neighbor(handlers: &Handlers, namespace: &str) -> Uuid {
+    let probe_namespace = format!("{}-probe", namespace);
+    let probe = call_tool(
+        handlers,
+        900,
+        "store_memory",
+        json!({ "content": CONTENT, "namespace": probe_namespace }),
+    )
+    .await;
+    let probe_id: Uuid = probe["fingerprintId"].as_str().unwrap().parse().unwrap();
+    let mut semantic = handlers
+        .teleological_store
+        .retrieve(probe_id)
+        .await
+        .unwrap()
+        .expect("probe stored")
+        .semantic;
+    semantic.e1_semantic = vector_at_similarity(&semantic.e1_semantic, PAIR_SIMILARITY);
+
+    let neighbor = TeleologicalFingerprint::new(semantic, [7u8; 32]).with_namespace(namespace);
+    let neighbor_id = neighbor.id;
+    handlers
+        .teleological_store
+        .store(neighbor)
+        .await
+        .expect("neighbor stored");
+    neighbor_id
+}
+
+#[tokio::test]
+async fn test_code_domain_flags_pair_creative_does_not() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+
+    let code_neighbor = plant_neighbor(&handlers, "dedup-code").await;
+    let code = call_tool(
+        &handlers,
+        1,
+        "store_memory",
+        json!({ "content": CONTENT, "namespace": "dedup-code", "domain": "code" }),
+    )
+    .await;
+    assert_eq!(code["deduplicated"], json!(false));
+    assert_eq!(code["dedupKind"], json!("semantic"));
+    let duplicate_of = &code["duplicateOf"];
+    assert_eq!(
+        duplicate_of["fingerprintId"],
+        json!(code_neighbor.to_string())
+    );
+    assert_eq!(duplicate_of["domain"], json!("code"));
+    assert_eq!(duplicate_of["domainSource"], json!("explicit"));
+    assert_eq!(duplicate_of["thetaDup"].as_f64().unwrap() as f32, 0.90);
+    let similarity = duplicate_of["similarity"].as_f64().unwrap() as f32;
+    assert!(
+        (similarity - PAIR_SIMILARITY).abs() < 0.01,
+        "similarity {}",
+        similarity
+    );
+    // Advisory only: the new memory is stored
+    assert_ne!(code["fingerprintId"], json!(code_neighbor.to_string()));
+
+    plant_neighbor(&handlers, "dedup-creative").await;
+    let creative = call_tool(
+        &handlers,
+        2,
+        "store_memory",
+        json!({ "content": CONTENT, "namespace": "dedup-creative", "domain": "creative" }),
+    )
+    .await;
+    assert_eq!(creative["deduplicated"], json!(false));
+    assert!(creative.get("dedupKind").is_none(), "{}", creative);
+    assert!(creative.get("duplicateOf").is_none(), "{}", creative);
+}
+
+#[tokio::test]
+async fn test_exact_and_semantic_dedup_are_distinguished() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    plant_neighbor(&handlers, "dedup-kinds").await;
+
+    let args = json!({ "content": CONTENT, "namespace": "dedup-kinds", "domain": "code" });
+    let first = call_tool(&handlers, 1, "store_memory", args.clone()).await;
+    assert_eq!(first["dedupKind"], json!("semantic"));
+
+    let again = call_tool(&handlers, 2, "store_memory", args).await;
+    assert_eq!(again["deduplicated"], json!(true));
+    assert_eq!(again["dedupKind"], json!("exact_hash"));
+    assert_eq!(again["fingerprintId"], first["fingerprintId"]);
+    assert!(again.get("duplicateOf").is_none());
+}
+
+#[tokio::test]
+async fn test_strict_dedup_blocks_the_write() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    let neighbor = plant_neighbor(&handlers, "dedup-strict").await;
+    let count_in = |counts: std::collections::HashMap<String, usize>| {
+        counts.get("dedup-strict").copied().unwrap_or(0)
+    };
+    let before = count_in(
+        handlers
+            .teleological_store
+            .count_by_namespace()
+            .await
+            .unwrap(),
+    );
+
+    let result = call_tool_raw(
+        &handlers,
+        1,
+        "store_memory",
+        json!({
+            "content": CONTENT,
+            "namespace": "dedup-strict",
+            "domain": "code",
+            "strict_dedup": true,
+        }),
+    )
+    .await;
+    assert_eq!(result["isError"], json!(true), "{}", result);
+    assert_eq!(result["dedupKind"], json!("semantic"));
+    assert_eq!(
+        result["duplicateOf"]["fingerprintId"],
+        json!(neighbor.to_string())
+    );
+    let after = count_in(
+        handlers
+            .teleological_store
+            .count_by_namespace()
+            .await
+            .unwrap(),
+    );
+    assert_eq!(after, before, "strict_dedup must not store the memory");
+
+    // Below the domain's theta_dup strict mode stores normally
+    let creative = call_tool(
+        &handlers,
+        2,
+        "store_memory",
+        json!({
+            "content": CONTENT,
+            "namespace": "dedup-strict",
+            "domain": "creative",
+            "strict_dedup": true,
+        }),
+    )
+    .await;
+    assert!(creative["fingerprintId"].is_string());
+}
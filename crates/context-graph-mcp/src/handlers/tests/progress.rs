@@ -0,0 +1,160 @@
+//! Progress Notification and Cancellation Tests
+//!
+//! Verifies that long-running tools (trigger_consolidation, detect_topics):
+//! - Emit `notifications/progress` for each stage when `_meta.progressToken` is set
+//! - Emit nothing when the client sends no token
+//! - Stop between stages after `notifications/cancelled`
+
+use std::sync::Arc;
+
+use serde_json::json;
+use tokio::sync::mpsc;
+
+use crate::handlers::RequestContext;
+use crate::protocol::{methods, JsonRpcId, JsonRpcNotification, JsonRpcResponse};
+
+use super::{create_test_handlers, make_request};
+
+fn tool_call_with_token(id: i64, name: &str, token: &str) -> crate::protocol::JsonRpcRequest {
+    let params = json!({
+        "name": name,
+        "arguments": {},
+        "_meta": { "progressToken": token }
+    });
+    make_request("tools/call", Some(JsonRpcId::Number(id)), Some(params))
+}
+
+fn is_tool_error(response: &JsonRpcResponse) -> bool {
+    response
+        .result
+        .as_ref()
+        .expect("tools/call must return a result")["isError"]
+        == json!(true)
+}
+
+fn drain(rx: &mut mpsc::UnboundedReceiver<JsonRpcNotification>) -> Vec<serde_json::Value> {
+    let mut params = Vec::new();
+    while let Ok(note) = rx.try_recv() {
+        assert_eq!(note.method, methods::NOTIFICATION_PROGRESS);
+        params.push(note.params.expect("progress notification must have params"));
+    }
+    params
+}
+
+#[tokio::test]
+async fn test_trigger_consolidation_reports_every_stage() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    for (i, content) in ["alpha memory", "beta memory", "gamma memory"]
+        .iter()
+        .enumerate()
+    {
+        let params = json!({ "name": "store_memory", "arguments": { "content": content } });
+        let request = make_request(
+            "tools/call",
+            Some(JsonRpcId::Number(i as i64)),
+            Some(params),
+        );
+        handlers.dispatch(request).await;
+    }
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let request = tool_call_with_token(10, "trigger_consolidation", "consolidate-1");
+    let response = handlers
+        .dispatch_with_context(request, RequestContext::with_notifier(tx))
+        .await;
+    assert!(!is_tool_error(&response), "consolidation should succeed");
+
+    let notes = drain(&mut rx);
+    let progress: Vec<u64> = notes
+        .iter()
+        .map(|p| p["progress"].as_u64().unwrap())
+        .collect();
+    assert_eq!(progress, vec![0, 1, 2, 3, 4, 5], "start, each stage, end");
+    assert!(notes
+        .iter()
+        .all(|p| p["progressToken"] == json!("consolidate-1")));
+    assert!(notes.iter().all(|p| p["total"] == json!(5)));
+    assert_eq!(notes.last().unwrap()["message"], json!("Complete"));
+}
+
+#[tokio::test]
+async fn test_progress_requires_token() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let params = json!({ "name": "trigger_consolidation", "arguments": {} });
+    let request = make_request("tools/call", Some(JsonRpcId::Number(1)), Some(params));
+
+    let response = handlers
+        .dispatch_with_context(request, RequestContext::with_notifier(tx))
+        .await;
+
+    assert!(!is_tool_error(&response));
+    assert!(drain(&mut rx).is_empty(), "no token means no notifications");
+}
+
+#[tokio::test]
+async fn test_detect_topics_reports_start_when_below_minimum() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let request = tool_call_with_token(1, "detect_topics", "topics-1");
+
+    let response = handlers
+        .dispatch_with_context(request, RequestContext::with_notifier(tx))
+        .await;
+
+    // Empty store: detect_topics fails the minimum-memory check after starting
+    assert!(is_tool_error(&response));
+    let notes = drain(&mut rx);
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0]["progress"], json!(0));
+    assert_eq!(notes[0]["total"], json!(3));
+}
+
+#[tokio::test]
+async fn test_cancel_stops_consolidation_before_completion() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    let handlers = Arc::new(handlers);
+    let (tx, mut rx) = mpsc::unbounded_channel();
+
+    let request = tool_call_with_token(42, "trigger_consolidation", "consolidate-2");
+    let running = {
+        let handlers = Arc::clone(&handlers);
+        tokio::spawn(async move {
+            handlers
+                .dispatch_with_context(request, RequestContext::with_notifier(tx))
+                .await
+        })
+    };
+
+    // Cancel as soon as the call reports its first stage
+    let first = rx.recv().await.expect("first progress notification");
+    assert_eq!(first.params.unwrap()["progress"], json!(0));
+    let cancel = make_request(
+        methods::NOTIFICATION_CANCELLED,
+        None,
+        Some(json!({ "requestId": 42, "reason": "user aborted" })),
+    );
+    let ack = handlers.dispatch(cancel).await;
+    assert!(ack.id.is_none() && ack.result.is_none() && ack.error.is_none());
+
+    let response = running.await.unwrap();
+    assert!(
+        is_tool_error(&response),
+        "cancelled call must return a tool error"
+    );
+    let text = response.result.unwrap()["content"][0]["text"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert!(
+        text.contains("cancelled"),
+        "unexpected error text: {}",
+        text
+    );
+
+    let notes = drain(&mut rx);
+    assert!(
+        notes.iter().all(|p| p["message"] != json!("Complete")),
+        "cancelled call must not report completion"
+    );
+}
@@ -0,0 +1,240 @@
+//! query_memories Tests
+//!
+//! Verifies the structured query DSL against a brute-force evaluation over a
+//! small seeded corpus:
+//! - must(semantic) + must_not(metadata domain=code) returns every non-code
+//!   memory ranked by E1 similarity
+//! - should over two semantic clauses averages the matched clause scores
+//! - Queries without a must or should clause are rejected
+//!
+//! The brute force embeds the clause text the same way the tool does and
+//! scores every stored memory with `compute_similarity_for_space`.
+
+use serde_json::json;
+use uuid::Uuid;
+
+use context_graph_core::retrieval::{
+    classify_search_domain, compute_similarity_for_space, SearchDomain,
+};
+use context_graph_core::teleological::Embedder;
+use context_graph_core::types::fingerprint::SemanticFingerprint;
+
+use crate::handlers::Handlers;
+
+use super::{call_tool, call_tool_raw, create_test_handlers};
+
+const CORPUS: [&str; 8] = [
+    "Restore the database from last night's backup after the disk failure.",
+    "The backup manifest lists a checksum for every copied file.",
+    "Fix the borrow error: the async fn holds a reference across an await and fails to compile.",
+    "impl Drop for the struct so the compiler releases the lock; the trait method returns an error.",
+    "We hiked to the lake and watched the sunset over the mountains.",
+    "Quarterly planning moved the release review to Thursday morning.",
+    "Rebuilding the HNSW index after a crash replays every stored memory.",
+    "The api method panics with a null pointer error when the struct is empty.",
+];
+
+/// Store the corpus; returns (id, content) pairs.
+async fn seed(handlers: &Handlers) -> Vec<(Uuid, &'static str)> {
+    let mut stored = Vec::new();
+    for (i, content) in CORPUS.iter().enumerate() {
+        let data = call_tool(
+            handlers,
+            100 + i as i64,
+            "store_memory",
+            json!({ "content": content }),
+        )
+        .await;
+        stored.push((
+            data["fingerprintId"].as_str().unwrap().parse().unwrap(),
+            *content,
+        ));
+    }
+    stored
+}
+
+async fn embed(handlers: &Handlers, text: &str) -> SemanticFingerprint {
+    handlers
+        .embed_query(None, text, "query_dsl_test")
+        .await
+        .unwrap_or_else(|_| panic!("embedding '{}' failed", text))
+}
+
+async fn semantic(handlers: &Handlers, id: Uuid) -> SemanticFingerprint {
+    handlers
+        .teleological_store
+        .retrieve(id)
+        .await
+        .unwrap()
+        .expect("seeded memory")
+        .semantic
+}
+
+fn mean_similarity(
+    spaces: &[Embedder],
+    query: &SemanticFingerprint,
+    memory: &SemanticFingerprint,
+) -> f32 {
+    spaces
+        .iter()
+        .map(|space| compute_similarity_for_space(*space, query, memory))
+        .sum::<f32>()
+        / spaces.len() as f32
+}
+
+/// Rank (id, score) like the tool: score descending, then id.
+fn rank(mut scored: Vec<(Uuid, f32)>) -> Vec<(Uuid, f32)> {
+    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then(a.0.cmp(&b.0)));
+    scored
+}
+
+fn assert_matches(data: &serde_json::Value, expected: &[(Uuid, f32)]) {
+    let results = data["results"].as_array().unwrap();
+    let actual: Vec<(Uuid, f32)> = results
+        .iter()
+        .map(|r| {
+            (
+                r["fingerprintId"].as_str().unwrap().parse().unwrap(),
+                r["score"].as_f64().unwrap() as f32,
+            )
+        })
+        .collect();
+    assert_eq!(
+        actual.iter().map(|r| r.0).collect::<Vec<_>>(),
+        expected.iter().map(|r| r.0).collect::<Vec<_>>(),
+        "plan: {}",
+        data["plan"]
+    );
+    for ((_, got), (_, want)) in actual.iter().zip(expected) {
+        assert!(
+            (got - want).abs() < 1e-5,
+            "score {} != brute force {}",
+            got,
+            want
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_must_semantic_must_not_code_domain_matches_brute_force() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    let stored = seed(&handlers).await;
+    let text = "how do we recover data after a crash";
+
+    let data = call_tool(
+        &handlers,
+        1,
+        "query_memories",
+        json!({
+            "query": {
+                "must": [{ "semantic": { "text": text } }],
+                "must_not": [{ "metadata": { "field": "domain", "op": "eq", "value": "Code" } }]
+            },
+            "topK": 20
+        }),
+    )
+    .await;
+
+    let query = embed(&handlers, text).await;
+    let mut expected = Vec::new();
+    let mut code = 0;
+    for (id, content) in &stored {
+        if classify_search_domain(content).domain == SearchDomain::Code {
+            code += 1;
+            continue;
+        }
+        let memory = semantic(&handlers, *id).await;
+        expected.push((*id, mean_similarity(&[Embedder::Semantic], &query, &memory)));
+    }
+    assert!(code >= 2, "corpus must contain code memories to exclude");
+    assert_matches(&data, &rank(expected));
+
+    let plan = &data["plan"];
+    assert_eq!(plan["recall"]["combine"], json!("intersect"));
+    assert_eq!(plan["recall"]["sources"][0]["via"], json!("E1 HNSW"));
+    assert_eq!(plan["loaded"], json!(CORPUS.len()));
+    let metadata_filter = plan["filters"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["clause"] == json!("must_not[0]"))
+        .expect("must_not stage in plan");
+    assert_eq!(metadata_filter["removed"], json!(code));
+    assert_eq!(plan["scoring"]["clauses"], json!(["must[0]"]));
+}
+
+#[tokio::test]
+async fn test_should_of_two_semantics_matches_brute_force() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    let stored = seed(&handlers).await;
+    let (text_a, text_b) = ("backup checksums", "compiler errors in rust structs");
+    let min_score_b = 0.75;
+
+    let data = call_tool(
+        &handlers,
+        1,
+        "query_memories",
+        json!({
+            "query": {
+                "should": [
+                    { "semantic": { "text": text_a } },
+                    { "semantic": { "text": text_b, "spaces": ["E1", "E7"], "minScore": min_score_b } }
+                ]
+            },
+            "topK": 20
+        }),
+    )
+    .await;
+
+    let (query_a, query_b) = (
+        embed(&handlers, text_a).await,
+        embed(&handlers, text_b).await,
+    );
+    let mut expected = Vec::new();
+    for (id, _) in &stored {
+        let memory = semantic(&handlers, *id).await;
+        let a = mean_similarity(&[Embedder::Semantic], &query_a, &memory);
+        let b = mean_similarity(&[Embedder::Semantic, Embedder::Code], &query_b, &memory);
+        let b = if b >= min_score_b { b } else { 0.0 };
+        // Clause a has minScore 0, so every memory matches a should clause
+        expected.push((*id, (a + b) / 2.0));
+    }
+    assert_matches(&data, &rank(expected));
+
+    let plan = &data["plan"];
+    assert_eq!(plan["recall"]["combine"], json!("union"));
+    assert_eq!(plan["recall"]["sources"].as_array().unwrap().len(), 2);
+    assert_eq!(
+        plan["scoring"]["clauses"],
+        json!(["should[0]", "should[1]"])
+    );
+}
+
+#[tokio::test]
+async fn test_query_without_must_or_should_is_rejected() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+
+    for (i, query) in [
+        json!({ "must_not": [{ "topic": { "id": Uuid::new_v4().to_string() } }] }),
+        json!({ "must": [{ "metadata": { "field": "importance", "op": "gt", "value": 0.5 } }] }),
+        json!({ "must": [{ "vector": { "values": [0.1] } }] }),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let result = call_tool_raw(
+            &handlers,
+            i as i64,
+            "query_memories",
+            json!({ "query": query }),
+        )
+        .await;
+        assert_eq!(
+            result["isError"],
+            json!(true),
+            "{} accepted: {}",
+            query,
+            result
+        );
+    }
+}
@@ -0,0 +1,59 @@
+//! Query Embedding Reuse Tests
+//!
+//! Verifies that a prompt searched with search_graph and then stored with
+//! inject_context (the hook sequence) is embedded exactly once. The content
+//! spaces of the stored memory come from the cached query embedding; only
+//! the temporal spaces, which read the store metadata, are recomputed.
+//!
+//! Uses the stub provider so embedding passes can be counted.
+
+use std::sync::Arc;
+
+use serde_json::json;
+use tempfile::TempDir;
+
+use context_graph_core::monitoring::StubLayerStatusProvider;
+use context_graph_core::stubs::StubMultiArrayProvider;
+use context_graph_core::teleological::Embedder;
+use context_graph_graph_agent::create_stub_graph_discovery_service;
+use context_graph_storage::teleological::RocksDbTeleologicalStore;
+
+use crate::handlers::Handlers;
+
+use super::call_tool;
+
+const PROMPT: &str = "How is the ledger database snapshotted to cold storage?";
+
+#[tokio::test]
+async fn test_search_then_inject_embeds_prompt_once() {
+    let tempdir = TempDir::new().expect("Failed to create temp directory");
+    let store = RocksDbTeleologicalStore::open(tempdir.path().join("test_rocksdb"))
+        .expect("Failed to open RocksDbTeleologicalStore");
+    let provider = Arc::new(StubMultiArrayProvider::new());
+    let handlers = Handlers::with_defaults(
+        Arc::new(store),
+        Arc::clone(&provider) as _,
+        Arc::new(StubLayerStatusProvider),
+        create_stub_graph_discovery_service(),
+    )
+    .expect("Default cluster manager should always succeed in tests");
+
+    call_tool(&handlers, 1, "search_graph", json!({ "query": PROMPT })).await;
+    let stored = call_tool(&handlers, 2, "inject_context", json!({ "content": PROMPT })).await;
+    assert!(stored["fingerprintId"].is_string());
+
+    assert_eq!(provider.embed_pass_count(PROMPT), 1);
+    assert_eq!(provider.embedder_call_count(Embedder::Semantic), 1);
+    assert_eq!(provider.embedder_call_count(Embedder::Code), 1);
+    // Temporal spaces encode the store timestamp and sequence, so they run again
+    assert_eq!(
+        provider.embedder_call_count(Embedder::TemporalPositional),
+        2
+    );
+
+    // A different prompt is a separate pass
+    let other = "Who owns the payments service?";
+    call_tool(&handlers, 3, "inject_context", json!({ "content": other })).await;
+    assert_eq!(provider.embed_pass_count(other), 1);
+    assert_eq!(provider.embed_pass_count(PROMPT), 1);
+}
@@ -0,0 +1,164 @@
+//! MCP Resources Tests
+//!
+//! Verifies the topic portfolio resources over the dispatch layer:
+//! - initialize advertises resources with subscribe support
+//! - resources/list entries can all be read back with resources/read
+//! - Unknown URIs and bad cursors are rejected
+//! - A recluster (detect_topics) notifies resources/subscribe subscribers
+
+use serde_json::json;
+use tokio::sync::mpsc;
+
+use crate::handlers::{RequestContext, TOPICS_MARKDOWN_URI, TOPICS_URI};
+use crate::protocol::{error_codes, methods, JsonRpcId};
+
+use super::{create_test_handlers, make_request};
+
+#[tokio::test]
+async fn test_initialize_advertises_resource_subscriptions() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    let request = make_request("initialize", Some(JsonRpcId::Number(1)), None);
+
+    let result = handlers.dispatch(request).await.result.unwrap();
+
+    assert_eq!(
+        result["capabilities"]["resources"]["subscribe"],
+        json!(true)
+    );
+}
+
+#[tokio::test]
+async fn test_resources_list_read_round_trip() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    let request = make_request(methods::RESOURCES_LIST, Some(JsonRpcId::Number(1)), None);
+
+    let result = handlers.dispatch(request).await.result.unwrap();
+
+    let resources = result["resources"].as_array().unwrap();
+    let uris: Vec<&str> = resources
+        .iter()
+        .map(|r| r["uri"].as_str().unwrap())
+        .collect();
+    assert!(uris.contains(&TOPICS_URI));
+    assert!(uris.contains(&TOPICS_MARKDOWN_URI));
+    assert!(result.get("nextCursor").is_none(), "one page when empty");
+
+    for (i, resource) in resources.iter().enumerate() {
+        let request = make_request(
+            methods::RESOURCES_READ,
+            Some(JsonRpcId::Number(10 + i as i64)),
+            Some(json!({ "uri": resource["uri"] })),
+        );
+        let response = handlers.dispatch(request).await;
+        assert!(
+            response.error.is_none(),
+            "{} must be readable",
+            resource["uri"]
+        );
+        let contents = &response.result.unwrap()["contents"][0];
+        assert_eq!(contents["uri"], resource["uri"]);
+        assert_eq!(contents["mimeType"], resource["mimeType"]);
+        let text = contents["text"].as_str().unwrap();
+        if contents["mimeType"] == json!("application/json") {
+            let body: serde_json::Value = serde_json::from_str(text).unwrap();
+            assert_eq!(body["total_topics"], json!(0));
+        } else {
+            assert!(text.starts_with("# Topic portfolio"));
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_resources_read_rejects_unknown_uri_and_bad_cursor() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+
+    let unknown_topic = format!("contextgraph://topics/{}", uuid::Uuid::new_v4());
+    for uri in [unknown_topic.as_str(), "contextgraph://north-star"] {
+        let request = make_request(
+            methods::RESOURCES_READ,
+            Some(JsonRpcId::Number(1)),
+            Some(json!({ "uri": uri })),
+        );
+        let error = handlers.dispatch(request).await.error.expect("must error");
+        assert_eq!(error.code, error_codes::RESOURCE_NOT_FOUND, "uri {}", uri);
+    }
+
+    let request = make_request(
+        methods::RESOURCES_LIST,
+        Some(JsonRpcId::Number(2)),
+        Some(json!({ "cursor": "not-a-cursor" })),
+    );
+    let error = handlers.dispatch(request).await.error.expect("must error");
+    assert_eq!(error.code, error_codes::INVALID_PARAMS);
+}
+
+#[tokio::test]
+async fn test_topic_update_notifies_subscribers() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    let contents = [
+        "Rust ownership and borrowing rules",
+        "Rust lifetimes and the borrow checker",
+        "Rust traits and generics",
+        "Baking sourdough bread at home",
+        "Sourdough starter feeding schedule",
+        "Sourdough hydration and crumb",
+    ];
+    for (i, content) in contents.iter().enumerate() {
+        let params = json!({ "name": "store_memory", "arguments": { "content": content } });
+        let request = make_request(
+            "tools/call",
+            Some(JsonRpcId::Number(i as i64)),
+            Some(params),
+        );
+        handlers.dispatch(request).await;
+    }
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let ctx = RequestContext::with_notifier(tx);
+    let subscribe = make_request(
+        methods::RESOURCES_SUBSCRIBE,
+        Some(JsonRpcId::Number(100)),
+        Some(json!({ "uri": TOPICS_URI })),
+    );
+    let response = handlers.dispatch_with_context(subscribe, ctx.clone()).await;
+    assert!(response.error.is_none());
+
+    // Without a notification channel there is nothing to push updates to
+    let batch_subscribe = make_request(
+        methods::RESOURCES_SUBSCRIBE,
+        Some(JsonRpcId::Number(101)),
+        Some(json!({ "uri": TOPICS_URI })),
+    );
+    assert!(handlers.dispatch(batch_subscribe).await.error.is_some());
+
+    let detect = make_request(
+        "tools/call",
+        Some(JsonRpcId::Number(102)),
+        Some(json!({ "name": "detect_topics", "arguments": { "force": true } })),
+    );
+    let response = handlers.dispatch(detect).await;
+    assert_eq!(response.result.unwrap()["isError"], json!(false));
+
+    let notification = rx.try_recv().expect("recluster must notify subscribers");
+    assert_eq!(notification.method, methods::NOTIFICATION_RESOURCE_UPDATED);
+    assert_eq!(notification.params.unwrap()["uri"], json!(TOPICS_URI));
+
+    // After unsubscribing, later reclusters stay silent
+    let unsubscribe = make_request(
+        methods::RESOURCES_UNSUBSCRIBE,
+        Some(JsonRpcId::Number(103)),
+        Some(json!({ "uri": TOPICS_URI })),
+    );
+    assert!(handlers
+        .dispatch_with_context(unsubscribe, ctx)
+        .await
+        .error
+        .is_none());
+    let detect = make_request(
+        "tools/call",
+        Some(JsonRpcId::Number(104)),
+        Some(json!({ "name": "detect_topics", "arguments": { "force": true } })),
+    );
+    handlers.dispatch(detect).await;
+    assert!(rx.try_recv().is_err());
+}
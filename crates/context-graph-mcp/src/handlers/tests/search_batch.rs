@@ -0,0 +1,214 @@
+//! search_graph_batch Tests
+//!
+//! Verifies that a batch of queries returns what the same queries return
+//! through individual search_graph calls, while the provider sees a single
+//! embedding batch. Two handlers share one store so each starts with an
+//! empty query embedding cache.
+//!
+//! Uses the stub provider so embedding batches and passes can be counted.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde_json::json;
+use tempfile::TempDir;
+
+use context_graph_core::monitoring::StubLayerStatusProvider;
+use context_graph_core::stubs::StubMultiArrayProvider;
+use context_graph_core::traits::TeleologicalMemoryStore;
+use context_graph_graph_agent::create_stub_graph_discovery_service;
+use context_graph_storage::teleological::RocksDbTeleologicalStore;
+
+use crate::handlers::Handlers;
+
+use super::{call_tool, call_tool_raw};
+
+const CORPUS: [&str; 10] = [
+    "The ledger database is snapshotted to cold storage every night",
+    "Payments service retries failed card charges three times",
+    "Rust borrow checker rejects aliasing mutable references",
+    "Kubernetes evicts pods when node memory pressure is high",
+    "The search index is rebuilt from RocksDB on startup",
+    "Invoices are generated on the first business day of the month",
+    "TLS certificates are rotated by the ingress controller",
+    "The mobile app caches user profiles for offline use",
+    "Grafana alerts page the on-call engineer after five minutes",
+    "Feature flags are evaluated on the edge before routing",
+];
+
+const QUERIES: [&str; 8] = [
+    "How are database snapshots stored?",
+    "What happens when a card charge fails?",
+    "Why does the borrow checker complain?",
+    "When are pods evicted?",
+    "How is the search index rebuilt?",
+    "When are invoices sent?",
+    "Who rotates certificates?",
+    "How are alerts routed to on-call?",
+];
+
+fn handlers_for(
+    store: &Arc<dyn TeleologicalMemoryStore>,
+    provider: &Arc<StubMultiArrayProvider>,
+) -> Handlers {
+    Handlers::with_defaults(
+        Arc::clone(store),
+        Arc::clone(provider) as _,
+        Arc::new(StubLayerStatusProvider),
+        create_stub_graph_discovery_service(),
+    )
+    .expect("Default cluster manager should always succeed in tests")
+}
+
+/// Result IDs in rank order, with their scores.
+fn ranked(response: &serde_json::Value) -> Vec<(String, f64)> {
+    response["results"]
+        .as_array()
+        .expect("results array")
+        .iter()
+        .map(|r| {
+            (
+                r["fingerprintId"].as_str().unwrap().to_string(),
+                r["similarity"].as_f64().unwrap(),
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_batch_matches_individual_calls_with_one_embedding_batch() {
+    let tempdir = TempDir::new().expect("Failed to create temp directory");
+    let store: Arc<dyn TeleologicalMemoryStore> = Arc::new(
+        RocksDbTeleologicalStore::open(tempdir.path().join("test_rocksdb"))
+            .expect("Failed to open RocksDbTeleologicalStore"),
+    );
+
+    let single_provider = Arc::new(StubMultiArrayProvider::new());
+    let single = handlers_for(&store, &single_provider);
+    for (i, content) in CORPUS.iter().enumerate() {
+        call_tool(
+            &single,
+            i as i64,
+            "store_memory",
+            json!({ "content": content }),
+        )
+        .await;
+    }
+
+    let options = json!({ "topK": 5, "strategy": "e1_only", "minSimilarity": 0.0 });
+    let mut expected = Vec::new();
+    for (i, query) in QUERIES.iter().enumerate() {
+        let mut args = options.clone();
+        args["query"] = json!(query);
+        expected.push(call_tool(&single, 100 + i as i64, "search_graph", args).await);
+    }
+    // Let the background access-count writes of those searches land
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let batch_provider = Arc::new(StubMultiArrayProvider::new());
+    let batch = handlers_for(&store, &batch_provider);
+    let queries: Vec<_> = QUERIES.iter().map(|q| json!({ "query": q })).collect();
+    let response = call_tool(
+        &batch,
+        200,
+        "search_graph_batch",
+        json!({ "queries": queries, "options": options }),
+    )
+    .await;
+
+    assert_eq!(response["count"], json!(QUERIES.len()));
+    assert_eq!(response["failed"], json!(0));
+    assert_eq!(response["embeddingBatch"]["embedded"], json!(QUERIES.len()));
+    assert!(response["timing"]["totalMs"].is_u64());
+
+    let results = response["results"].as_array().unwrap();
+    for (i, (entry, single)) in results.iter().zip(&expected).enumerate() {
+        assert_eq!(entry["index"], json!(i));
+        let (batch_ranked, single_ranked) = (ranked(entry), ranked(single));
+        assert!(!single_ranked.is_empty(), "query {} found nothing", i);
+        let ids = |r: &[(String, f64)]| r.iter().map(|(id, _)| id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&batch_ranked), ids(&single_ranked), "query {}", i);
+        for ((_, batch_score), (_, single_score)) in batch_ranked.iter().zip(&single_ranked) {
+            // Importance decays between the two runs; far below rank resolution
+            assert!((batch_score - single_score).abs() < 1e-5, "query {}", i);
+        }
+        assert_eq!(entry["searchStrategy"], single["searchStrategy"]);
+    }
+
+    assert_eq!(batch_provider.embed_batch_call_count(), 1);
+    for query in QUERIES {
+        assert_eq!(batch_provider.embed_pass_count(query), 1, "{}", query);
+    }
+    assert_eq!(single_provider.embed_batch_call_count(), 0);
+}
+
+#[tokio::test]
+async fn test_failed_query_does_not_fail_batch() {
+    let tempdir = TempDir::new().expect("Failed to create temp directory");
+    let store: Arc<dyn TeleologicalMemoryStore> = Arc::new(
+        RocksDbTeleologicalStore::open(tempdir.path().join("test_rocksdb"))
+            .expect("Failed to open RocksDbTeleologicalStore"),
+    );
+    let provider = Arc::new(StubMultiArrayProvider::new());
+    let handlers = handlers_for(&store, &provider);
+    call_tool(
+        &handlers,
+        1,
+        "store_memory",
+        json!({ "content": CORPUS[0] }),
+    )
+    .await;
+
+    let response = call_tool(
+        &handlers,
+        2,
+        "search_graph_batch",
+        json!({
+            "queries": [
+                { "query": QUERIES[0] },
+                { "query": QUERIES[1], "topK": 500 },
+                { "query": "" },
+                { "query": QUERIES[2], "strategy": "sideways" }
+            ]
+        }),
+    )
+    .await;
+
+    assert_eq!(response["count"], json!(4));
+    assert_eq!(response["failed"], json!(3));
+    let results = response["results"].as_array().unwrap();
+    assert!(results[0]["results"].is_array());
+    assert!(results[0].get("error").is_none());
+    for (index, entry) in results.iter().enumerate().skip(1) {
+        assert_eq!(entry["index"], json!(index));
+        assert!(entry["error"].is_string(), "query {} should fail", index);
+    }
+    assert!(results[1]["error"].as_str().unwrap().contains("topK"));
+    // Invalid queries are still embedded with the batch; the empty one is not
+    assert_eq!(provider.embed_batch_call_count(), 1);
+    assert_eq!(provider.embed_pass_count(""), 0);
+}
+
+#[tokio::test]
+async fn test_candidate_budget_rejects_batch() {
+    let tempdir = TempDir::new().expect("Failed to create temp directory");
+    let store: Arc<dyn TeleologicalMemoryStore> = Arc::new(
+        RocksDbTeleologicalStore::open(tempdir.path().join("test_rocksdb"))
+            .expect("Failed to open RocksDbTeleologicalStore"),
+    );
+    let provider = Arc::new(StubMultiArrayProvider::new());
+    let handlers = handlers_for(&store, &provider);
+
+    let queries: Vec<_> = QUERIES.iter().map(|q| json!({ "query": q })).collect();
+    let result = call_tool_raw(
+        &handlers,
+        1,
+        "search_graph_batch",
+        json!({ "queries": queries, "options": { "topK": 100 } }),
+    )
+    .await;
+    assert_eq!(result["isError"], json!(true));
+    let message = result["content"][0]["text"].as_str().unwrap();
+    assert!(message.contains("candidate budget"), "{}", message);
+    assert_eq!(provider.embed_batch_call_count(), 0);
+}
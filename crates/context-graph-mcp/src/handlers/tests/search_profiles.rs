@@ -0,0 +1,144 @@
+//! Domain Search Profile Tests
+//!
+//! Verifies that search_graph resolves a per-domain search profile:
+//! - The same query under the Code and Creative profiles returns different
+//!   result sets, with Code cutting more candidates at a higher threshold
+//! - A query without `domain` gets its domain detected and echoed
+//! - An explicit minSimilarity disables the profile cut-off
+
+use serde_json::json;
+
+use crate::handlers::Handlers;
+
+use super::{call_tool, create_test_handlers};
+
+const CORPUS: [&str; 10] = [
+    "The tokio runtime schedules async tasks across a pool of worker threads.",
+    "Holding a mutex guard across an await point makes the future not Send.",
+    "Use spawn_blocking for CPU-heavy work inside an async Rust service.",
+    "The borrow checker rejects the closure because it outlives the stack frame.",
+    "The old lighthouse keeper wrote letters to the sea every winter night.",
+    "A dragon made of clockwork guards the library at the edge of the map.",
+    "She painted the storm as a flock of silver birds leaving the city.",
+    "The quarterly budget review moved to the second Monday of the month.",
+    "Our team retrospective agreed to rotate the on-call schedule weekly.",
+    "The garden needs compost before the tomatoes are planted in spring.",
+];
+
+const QUERY: &str = "how do async tasks get scheduled on worker threads";
+
+async fn store_corpus(handlers: &Handlers) {
+    for (i, content) in CORPUS.iter().enumerate() {
+        call_tool(
+            handlers,
+            i as i64,
+            "store_memory",
+            json!({ "content": content }),
+        )
+        .await;
+    }
+}
+
+fn result_ids(data: &serde_json::Value) -> Vec<String> {
+    data["results"]
+        .as_array()
+        .expect("results array")
+        .iter()
+        .map(|r| r["fingerprintId"].as_str().expect("fingerprintId").to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_code_profile_cuts_more_than_creative() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    store_corpus(&handlers).await;
+
+    let code = call_tool(
+        &handlers,
+        100,
+        "search_graph",
+        json!({ "query": QUERY, "topK": 10, "domain": "code" }),
+    )
+    .await;
+    let creative = call_tool(
+        &handlers,
+        101,
+        "search_graph",
+        json!({ "query": QUERY, "topK": 10, "domain": "creative" }),
+    )
+    .await;
+
+    let code_profile = &code["searchProfile"];
+    let creative_profile = &creative["searchProfile"];
+    assert_eq!(code_profile["domain"], json!("code"));
+    assert_eq!(code_profile["source"], json!("explicit"));
+    assert_eq!(code_profile["weightProfile"], json!("code_search"));
+    assert_eq!(code_profile["rerank"], json!(true));
+    assert_eq!(creative_profile["domain"], json!("creative"));
+    assert_eq!(creative_profile["rerank"], json!(false));
+
+    let code_cutoff = code_profile["minScore"].as_f64().unwrap();
+    let creative_cutoff = creative_profile["minScore"].as_f64().unwrap();
+    assert!(code_cutoff > creative_cutoff);
+
+    // Code keeps fewer hits, every one of them above its higher cut-off
+    let code_ids = result_ids(&code);
+    let creative_ids = result_ids(&creative);
+    assert!(!code_ids.is_empty(), "code profile should keep the async memories");
+    assert!(
+        code_ids.len() < creative_ids.len(),
+        "code {} vs creative {}",
+        code_ids.len(),
+        creative_ids.len()
+    );
+    assert_ne!(code_ids, creative_ids);
+    assert!(
+        code_profile["belowCutoff"].as_u64().unwrap()
+            > creative_profile["belowCutoff"].as_u64().unwrap()
+    );
+    for r in code["results"].as_array().unwrap() {
+        assert!(r["similarity"].as_f64().unwrap() >= code_cutoff - 1e-6);
+    }
+}
+
+#[tokio::test]
+async fn test_domain_detected_when_omitted() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    store_corpus(&handlers).await;
+
+    let detected = call_tool(
+        &handlers,
+        200,
+        "search_graph",
+        json!({ "query": "why does the borrow checker reject this async closure", "topK": 5 }),
+    )
+    .await;
+    assert_eq!(detected["searchProfile"]["domain"], json!("code"));
+    assert_eq!(detected["searchProfile"]["detected"], json!(true));
+    assert!(!detected["searchProfile"]["matchedCues"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+
+    let general = call_tool(
+        &handlers,
+        201,
+        "search_graph",
+        json!({ "query": "when is the budget review", "topK": 5 }),
+    )
+    .await;
+    assert_eq!(general["searchProfile"]["domain"], json!("general"));
+    assert_eq!(general["searchProfile"]["source"], json!("default"));
+    assert_eq!(general["searchProfile"]["belowCutoff"], json!(0));
+
+    // Explicit minSimilarity replaces the profile cut-off
+    let explicit = call_tool(
+        &handlers,
+        202,
+        "search_graph",
+        json!({ "query": QUERY, "topK": 10, "domain": "code", "minSimilarity": 0.0 }),
+    )
+    .await;
+    assert_eq!(explicit["searchProfile"]["minScore"], json!(0.0));
+    assert_eq!(explicit["searchProfile"]["belowCutoff"], json!(0));
+}
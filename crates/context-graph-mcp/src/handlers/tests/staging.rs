@@ -0,0 +1,140 @@
+//! Session Staging Tests
+//!
+//! Verifies the staging lifecycle end to end:
+//! - `store_memory` with `staged: true` lands in `staged.<sessionId>`
+//! - Staged memories are only searchable by naming the staging namespace
+//! - promote_staged moves selected memories into the default namespace
+//! - end_staged_session with discard_all removes everything still staged
+
+use serde_json::json;
+use uuid::Uuid;
+
+use context_graph_core::types::fingerprint::TeleologicalFingerprint;
+
+use crate::handlers::Handlers;
+
+use super::{call_tool, create_test_handlers_with_edges};
+
+const SESSION: &str = "staging-test-session";
+
+async fn stage(handlers: &Handlers, id: i64, content: &str) -> Uuid {
+    let data = call_tool(
+        handlers,
+        id,
+        "store_memory",
+        json!({ "content": content, "staged": true, "sessionId": SESSION }),
+    )
+    .await;
+    assert_eq!(data["staged"], json!(true));
+    data["fingerprintId"]
+        .as_str()
+        .expect("store_memory must return fingerprintId")
+        .parse()
+        .expect("fingerprintId must be a UUID")
+}
+
+fn result_ids(data: &serde_json::Value) -> Vec<Uuid> {
+    data["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["fingerprintId"].as_str().unwrap().parse().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_staging_promote_two_then_discard_rest() {
+    let (handlers, store_ref, _edges, _tempdir) = create_test_handlers_with_edges().await;
+    let staging = TeleologicalFingerprint::staging_namespace(SESSION);
+
+    let mut staged = Vec::new();
+    for i in 0..5 {
+        let content = format!("Scratch note {} about the staging experiment", i);
+        staged.push(stage(&handlers, i + 1, &content).await);
+    }
+    assert_eq!(
+        store_ref.list_namespace_ids(&staging).await.unwrap().len(),
+        5
+    );
+
+    let data = call_tool(
+        &handlers,
+        10,
+        "promote_staged",
+        json!({ "sessionId": SESSION, "ids": [staged[0], staged[1]] }),
+    )
+    .await;
+    assert_eq!(data["promotedCount"], json!(2), "{}", data);
+
+    let data = call_tool(
+        &handlers,
+        11,
+        "end_staged_session",
+        json!({ "sessionId": SESSION, "policy": "discard_all" }),
+    )
+    .await;
+    assert_eq!(data["stagedCount"], json!(3));
+    assert_eq!(data["discardedCount"], json!(3));
+    assert_eq!(data["promotedCount"], json!(0));
+
+    // Exactly the two promoted memories remain, now in the default namespace
+    assert_eq!(store_ref.count().await.unwrap(), 2);
+    for id in &staged[..2] {
+        let fp = store_ref
+            .retrieve(*id)
+            .await
+            .unwrap()
+            .expect("promoted memory");
+        assert!(fp.is_default_namespace());
+    }
+    for id in &staged[2..] {
+        assert!(store_ref.retrieve(*id).await.unwrap().is_none());
+    }
+    assert!(store_ref
+        .list_namespace_ids(&staging)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_staged_memories_require_search_opt_in() {
+    let (handlers, _store_ref, _edges, _tempdir) = create_test_handlers_with_edges().await;
+    let content = "Staged scratch work on the retry backoff";
+    let staged = stage(&handlers, 1, content).await;
+
+    let data = call_tool(
+        &handlers,
+        2,
+        "search_graph",
+        json!({ "query": content, "topK": 10 }),
+    )
+    .await;
+    assert!(!result_ids(&data).contains(&staged));
+
+    let staging = TeleologicalFingerprint::staging_namespace(SESSION);
+    let data = call_tool(
+        &handlers,
+        3,
+        "search_graph",
+        json!({ "query": content, "topK": 10, "namespace": staging }),
+    )
+    .await;
+    assert!(result_ids(&data).contains(&staged));
+
+    call_tool(
+        &handlers,
+        4,
+        "promote_staged",
+        json!({ "sessionId": SESSION, "all": true }),
+    )
+    .await;
+    let data = call_tool(
+        &handlers,
+        5,
+        "search_graph",
+        json!({ "query": content, "topK": 10 }),
+    )
+    .await;
+    assert!(result_ids(&data).contains(&staged));
+}
@@ -0,0 +1,107 @@
+//! get_memetic_status Component Cache Tests
+//!
+//! Verifies the StatusAggregator behind get_memetic_status:
+//! - An immediate second call is served from cache
+//! - store_memory refreshes the counts component but not the layers one
+//! - force_refresh recomputes every component
+
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+
+use super::{call_tool, create_test_handlers};
+
+fn computed_at(status: &serde_json::Value, component: &str) -> String {
+    status["components"][component]["computed_at"]
+        .as_str()
+        .unwrap_or_else(|| panic!("components.{} must carry computed_at", component))
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_second_call_served_from_cache() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+
+    let first = call_tool(&handlers, 1, "get_memetic_status", json!({})).await;
+    let second = call_tool(&handlers, 2, "get_memetic_status", json!({})).await;
+
+    for component in ["counts", "layers", "storage"] {
+        assert_eq!(
+            computed_at(&first, component),
+            computed_at(&second, component),
+            "{} must be served from cache",
+            component
+        );
+        assert_eq!(second["components"][component]["stale"], json!(false));
+    }
+
+    // The cached path does no store or provider work
+    let store = handlers.teleological_store.as_ref();
+    let provider = handlers.layer_status_provider.as_ref();
+    let start = Instant::now();
+    handlers.status_aggregator.counts(store, false).await.unwrap();
+    handlers.status_aggregator.layers(provider, false).await.unwrap();
+    handlers.status_aggregator.storage(store, false).unwrap();
+    let elapsed = start.elapsed();
+    assert!(
+        elapsed < Duration::from_millis(1),
+        "cached components took {:?}",
+        elapsed
+    );
+}
+
+#[tokio::test]
+async fn test_store_invalidates_counts_not_layers() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+
+    let before = call_tool(&handlers, 1, "get_memetic_status", json!({})).await;
+    call_tool(
+        &handlers,
+        2,
+        "store_memory",
+        json!({
+            "content": "Status counts follow stores without a rescan",
+            "namespace": "status-test",
+        }),
+    )
+    .await;
+    let after = call_tool(&handlers, 3, "get_memetic_status", json!({})).await;
+
+    assert_eq!(
+        after["fingerprintCount"].as_u64().unwrap(),
+        before["fingerprintCount"].as_u64().unwrap() + 1
+    );
+    assert_eq!(after["namespaceCounts"]["status-test"], json!(1));
+    assert_ne!(computed_at(&before, "counts"), computed_at(&after, "counts"));
+    assert_eq!(computed_at(&before, "layers"), computed_at(&after, "layers"));
+
+    // The storage size is not recomputed for one write, and says so
+    assert_eq!(computed_at(&before, "storage"), computed_at(&after, "storage"));
+    assert_eq!(after["components"]["storage"]["stale"], json!(true));
+}
+
+#[tokio::test]
+async fn test_force_refresh_recomputes_everything() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+
+    let cached = call_tool(&handlers, 1, "get_memetic_status", json!({})).await;
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    let forced = call_tool(
+        &handlers,
+        2,
+        "get_memetic_status",
+        json!({ "force_refresh": true }),
+    )
+    .await;
+
+    for component in ["counts", "layers", "storage"] {
+        assert_ne!(
+            computed_at(&cached, component),
+            computed_at(&forced, component),
+            "{} must be recomputed by force_refresh",
+            component
+        );
+        assert_eq!(forced["components"][component]["stale"], json!(false));
+    }
+    assert_eq!(forced["fingerprintCount"], cached["fingerprintCount"]);
+}
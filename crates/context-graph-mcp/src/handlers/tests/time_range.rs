@@ -0,0 +1,127 @@
+//! search_graph Time Range Tests
+//!
+//! Verifies the query-time temporal arguments of search_graph:
+//! - timeRange drops memories created outside it and reports how many
+//!   candidates it excluded
+//! - Malformed or inverted ranges and out-of-range recencyWeight are rejected
+//!
+//! Old memories are planted directly in the store with a backdated
+//! created_at and the embeddings of content stored through store_memory.
+
+use serde_json::json;
+use uuid::Uuid;
+
+use context_graph_core::types::fingerprint::TeleologicalFingerprint;
+
+use crate::handlers::Handlers;
+
+use super::{call_tool, call_tool_raw, create_test_handlers};
+
+const CONTENT: &str = "Rotate the signing keys before the certificate expires.";
+
+/// Store CONTENT now and plant copies of its embeddings `ages_days` old.
+async fn seed(handlers: &Handlers, ages_days: &[i64]) -> (Uuid, Vec<Uuid>) {
+    let stored = call_tool(handlers, 900, "store_memory", json!({ "content": CONTENT })).await;
+    let recent: Uuid = stored["fingerprintId"].as_str().unwrap().parse().unwrap();
+    let semantic = handlers
+        .teleological_store
+        .retrieve(recent)
+        .await
+        .unwrap()
+        .expect("memory stored")
+        .semantic;
+
+    let mut planted = Vec::new();
+    for (i, age) in ages_days.iter().enumerate() {
+        let mut fp = TeleologicalFingerprint::new(semantic.clone(), [i as u8 + 1; 32]);
+        fp.created_at = chrono::Utc::now() - chrono::Duration::days(*age);
+        planted.push(fp.id);
+        handlers
+            .teleological_store
+            .store(fp)
+            .await
+            .expect("planted memory stored");
+    }
+    (recent, planted)
+}
+
+fn result_ids(response: &serde_json::Value) -> Vec<String> {
+    response["results"]
+        .as_array()
+        .expect("results array")
+        .iter()
+        .map(|r| r["fingerprintId"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_time_range_excludes_old_memories_and_counts_them() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    let (recent, planted) = seed(&handlers, &[90, 200, 340]).await;
+
+    let now = chrono::Utc::now();
+    let response = call_tool(
+        &handlers,
+        1,
+        "search_graph",
+        json!({
+            "query": CONTENT,
+            "topK": 10,
+            "minSimilarity": 0.0,
+            "timeRange": {
+                "start": (now - chrono::Duration::days(30)).to_rfc3339(),
+                "end": (now + chrono::Duration::hours(1)).to_rfc3339(),
+            },
+        }),
+    )
+    .await;
+
+    let ids = result_ids(&response);
+    assert_eq!(ids, vec![recent.to_string()], "{}", response);
+    assert!(planted.iter().all(|id| !ids.contains(&id.to_string())));
+    assert!(
+        response["timeFilter"]["excluded"].as_u64().unwrap() >= planted.len() as u64,
+        "{}",
+        response["timeFilter"]
+    );
+
+    // Without a range every copy is returned and nothing is reported
+    let response = call_tool(
+        &handlers,
+        2,
+        "search_graph",
+        json!({ "query": CONTENT, "topK": 10, "minSimilarity": 0.0 }),
+    )
+    .await;
+    assert_eq!(result_ids(&response).len(), 1 + planted.len());
+    assert!(response.get("timeFilter").is_none());
+}
+
+#[tokio::test]
+async fn test_invalid_time_arguments_rejected() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+
+    for (i, args) in [
+        json!({ "query": CONTENT, "timeRange": { "start": "yesterday", "end": "today" } }),
+        json!({
+            "query": CONTENT,
+            "timeRange": {
+                "start": "2026-02-01T00:00:00Z",
+                "end": "2026-01-01T00:00:00Z",
+            },
+        }),
+        json!({ "query": CONTENT, "recencyWeight": 1.5 }),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let result = call_tool_raw(&handlers, i as i64, "search_graph", args.clone()).await;
+        assert_eq!(
+            result["isError"],
+            json!(true),
+            "{} accepted: {}",
+            args,
+            result
+        );
+    }
+}
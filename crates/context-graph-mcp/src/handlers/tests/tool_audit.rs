@@ -0,0 +1,101 @@
+//! Tool Audit Log Tests
+//!
+//! Verifies that mutating tool calls are recorded and queryable:
+//! - store_memory and forget_concept each write one record linked to the memory
+//! - Memory content never appears in a record, only its hash
+//! - query_audit_log filters by memory, tool, and pages with next_offset
+
+use serde_json::json;
+
+use super::{call_tool, create_test_handlers};
+
+const SECRET_CONTENT: &str = "The staging database password rotates every Tuesday at noon.";
+
+#[tokio::test]
+async fn test_store_and_forget_are_audited_without_content() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+
+    let stored = call_tool(
+        &handlers,
+        1,
+        "store_memory",
+        json!({ "content": SECRET_CONTENT, "sessionId": "audit-session" }),
+    )
+    .await;
+    let memory_id = stored["fingerprintId"]
+        .as_str()
+        .expect("store_memory must return fingerprintId")
+        .to_string();
+
+    call_tool(
+        &handlers,
+        2,
+        "forget_concept",
+        json!({ "node_id": memory_id, "reason": SECRET_CONTENT }),
+    )
+    .await;
+
+    // Reads are not audited
+    call_tool(&handlers, 3, "search_graph", json!({ "query": "database password" })).await;
+
+    let log = call_tool(
+        &handlers,
+        4,
+        "query_audit_log",
+        json!({ "memory_id": memory_id }),
+    )
+    .await;
+    let records = log["records"].as_array().expect("records must be an array");
+    assert_eq!(records.len(), 2, "store + forget expected: {}", log);
+    assert_eq!(log["has_more"], json!(false));
+
+    // Newest first
+    assert_eq!(records[0]["tool_name"], "forget_concept");
+    assert_eq!(records[1]["tool_name"], "store_memory");
+    for record in records {
+        assert!(
+            record["memory_ids"]
+                .as_array()
+                .unwrap()
+                .contains(&json!(memory_id)),
+            "record must link to the memory: {}",
+            record
+        );
+        assert_eq!(record["outcome"]["status"], "success");
+    }
+    assert_eq!(records[1]["session_id"], "audit-session");
+    assert_eq!(records[1]["arguments"]["sessionId"], "audit-session");
+    assert_eq!(
+        records[1]["arguments"]["content"]["len"],
+        SECRET_CONTENT.chars().count()
+    );
+    assert_eq!(records[0]["arguments"]["node_id"], json!(memory_id));
+    assert!(
+        !log.to_string().contains("password"),
+        "raw content must not be stored in the audit log"
+    );
+
+    // Tool filter and pagination
+    let forgets = call_tool(
+        &handlers,
+        5,
+        "query_audit_log",
+        json!({ "tool_name": "forget_concept" }),
+    )
+    .await;
+    assert_eq!(forgets["count"], 1);
+
+    let first_page = call_tool(&handlers, 6, "query_audit_log", json!({ "limit": 1 })).await;
+    assert_eq!(first_page["count"], 1);
+    assert_eq!(first_page["has_more"], json!(true));
+    assert_eq!(first_page["next_offset"], 1);
+    let second_page = call_tool(
+        &handlers,
+        7,
+        "query_audit_log",
+        json!({ "limit": 1, "offset": 1 }),
+    )
+    .await;
+    assert_eq!(second_page["records"][0]["tool_name"], "store_memory");
+    assert_eq!(second_page["has_more"], json!(false));
+}
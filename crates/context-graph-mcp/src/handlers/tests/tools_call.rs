@@ -2,9 +2,9 @@
 
 use serde_json::json;
 
-use crate::protocol::JsonRpcId;
+use crate::protocol::{error_codes, JsonRpcId};
 
-use super::{create_test_handlers, make_request};
+use super::{call_tool, create_test_handlers, extract_mcp_tool_data, make_request};
 
 // =========================================================================
 // get_memetic_status Tool Tests
@@ -85,6 +85,134 @@ async fn test_tools_call_store_memory_valid() {
     );
 }
 
+#[tokio::test]
+async fn test_tools_call_store_memory_deduplicates_exact_content() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+
+    let mut responses = Vec::new();
+    for (i, allow_duplicates) in [false, false, true].into_iter().enumerate() {
+        let params = json!({
+            "name": "store_memory",
+            "arguments": {
+                "content": "Duplicate detection test content",
+                "allowDuplicates": allow_duplicates
+            }
+        });
+        let request = make_request("tools/call", Some(JsonRpcId::Number(i as i64)), Some(params));
+        let result = handlers
+            .dispatch(request)
+            .await
+            .result
+            .expect("tools/call must return a result");
+        responses.push(extract_mcp_tool_data(&result));
+    }
+
+    let first_id = responses[0]["fingerprintId"].as_str().unwrap();
+    assert_eq!(responses[0]["deduplicated"], json!(false));
+    assert_eq!(responses[1]["deduplicated"], json!(true));
+    assert_eq!(responses[1]["fingerprintId"].as_str().unwrap(), first_id);
+    assert_eq!(responses[2]["deduplicated"], json!(false));
+    assert_ne!(responses[2]["fingerprintId"].as_str().unwrap(), first_id);
+}
+
+#[tokio::test]
+async fn test_tools_call_namespaces_never_cross_over() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    let content = "shared namespace content";
+
+    // Identical content in two namespaces must not be deduplicated across them
+    let a = call_tool(&handlers, 1, "store_memory", json!({"content": content, "namespace": "project-a"})).await;
+    let b = call_tool(&handlers, 2, "store_memory", json!({"content": content, "namespace": "project-b"})).await;
+    assert_eq!(a["deduplicated"], json!(false));
+    assert_eq!(b["deduplicated"], json!(false));
+    assert_eq!(b["namespace"], json!("project-b"));
+    let id_a = a["fingerprintId"].as_str().unwrap().to_string();
+    let id_b = b["fingerprintId"].as_str().unwrap().to_string();
+    assert_ne!(id_a, id_b);
+
+    for (i, (ns, own, other)) in [("project-a", &id_a, &id_b), ("project-b", &id_b, &id_a)]
+        .into_iter()
+        .enumerate()
+    {
+        let data = call_tool(
+            &handlers,
+            10 + i as i64,
+            "search_graph",
+            json!({"query": content, "topK": 10, "namespace": ns}),
+        )
+        .await;
+        let ids: Vec<&str> = data["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|r| r["fingerprintId"].as_str().unwrap())
+            .collect();
+        assert!(ids.contains(&own.as_str()), "{ns} must find its own memory");
+        assert!(!ids.contains(&other.as_str()), "{ns} must not see the other namespace");
+    }
+
+    // Default namespace sees neither
+    let data = call_tool(&handlers, 20, "search_graph", json!({"query": content, "topK": 10})).await;
+    assert!(data["results"].as_array().unwrap().is_empty());
+
+    let status = call_tool(&handlers, 21, "get_memetic_status", json!({})).await;
+    assert_eq!(status["namespaceCounts"]["project-a"], json!(1));
+    assert_eq!(status["namespaceCounts"]["project-b"], json!(1));
+}
+
+// =========================================================================
+// store_memories_batch Tool Tests
+// =========================================================================
+
+#[tokio::test]
+async fn test_tools_call_store_memories_batch_reports_partial_failure() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    let bad_index = 7;
+    let items: Vec<serde_json::Value> = (0..20)
+        .map(|i| {
+            if i == bad_index {
+                json!({"content": ""})
+            } else {
+                json!({"content": format!("Batch import memory number {}", i)})
+            }
+        })
+        .collect();
+
+    let data = call_tool(
+        &handlers,
+        1,
+        "store_memories_batch",
+        json!({"items": items}),
+    )
+    .await;
+
+    let results = data["results"].as_array().unwrap();
+    assert_eq!(results.len(), 20);
+    assert_eq!(data["stored"], json!(19));
+    assert_eq!(data["failed"], json!(1));
+    assert!(data["timing"]["embedMs"].is_u64());
+    assert!(data["timing"]["storeMs"].is_u64());
+
+    let ids: Vec<&str> = results
+        .iter()
+        .filter_map(|r| r["fingerprintId"].as_str())
+        .collect();
+    assert_eq!(ids.len(), 19);
+
+    let errors: Vec<&serde_json::Value> = results
+        .iter()
+        .filter(|r| r.get("error").is_some())
+        .collect();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["index"], json!(bad_index));
+    assert!(errors[0]["error"].as_str().unwrap().contains("empty"));
+
+    // Results are reported in input order
+    for (i, r) in results.iter().enumerate() {
+        assert_eq!(r["index"], json!(i));
+    }
+}
+
 // =========================================================================
 // search_graph Tool Tests
 // =========================================================================
@@ -185,3 +313,39 @@ async fn test_tool_error_sets_is_error_true() {
         .expect("content must be an array");
     assert!(!content.is_empty(), "Error content must not be empty");
 }
+
+#[tokio::test]
+async fn test_tools_call_rejects_misspelled_argument() {
+    let (handlers, _tempdir) = create_test_handlers().await;
+    let params = json!({
+        "name": "search_graph",
+        "arguments": {
+            "query_contnet": "rust ownership",
+            "topK": "5"
+        }
+    });
+    let request = make_request("tools/call", Some(JsonRpcId::Number(1)), Some(params));
+
+    let response = handlers.dispatch(request).await;
+
+    assert!(response.error.is_none(), "schema errors are tool errors");
+    let result = response.result.expect("tools/call must return a result");
+    assert_eq!(result["isError"], json!(true));
+    assert_eq!(result["errorCode"], json!(error_codes::INVALID_PARAMS));
+
+    let text = result["content"][0]["text"].as_str().unwrap();
+    assert!(text.contains("query_contnet"), "unexpected text: {}", text);
+    assert!(text.contains("did you mean 'query'"), "unexpected text: {}", text);
+
+    let errors = result["errors"].as_array().expect("structured errors");
+    let typo = errors
+        .iter()
+        .find(|e| e["path"] == json!("query_contnet"))
+        .expect("typo'd field must be listed");
+    assert!(typo["expected"].as_str().unwrap().contains("query"));
+    let wrong_type = errors
+        .iter()
+        .find(|e| e["path"] == json!("topK"))
+        .expect("mistyped field must be listed");
+    assert_eq!(wrong_type["expected"], json!("integer"));
+}
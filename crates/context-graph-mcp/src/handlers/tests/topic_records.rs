@@ -0,0 +1,207 @@
+//! Topic Record Tests
+//!
+//! Verifies the stored topic portfolio:
+//! - Two detection passes over a drifting corpus keep the IDs of continuing
+//!   topics and give a new cluster a new ID
+//! - get_topic_portfolio reads the stored records, sorted by size, recency
+//!   or stability, with offset/limit pagination
+//! - Deleting a member rewrites the stored record
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use serde_json::json;
+use uuid::Uuid;
+
+use context_graph_core::clustering::{reconcile_topic_records, Topic, TopicProfile, TopicRecord};
+use context_graph_core::traits::TeleologicalMemoryStore;
+
+use crate::protocol::JsonRpcId;
+
+use super::{call_tool, create_test_handlers_with_edges, make_request};
+
+const CONTENTS: [&str; 8] = [
+    "The payment service retries failed charges three times.",
+    "Charge retries in the payment service use exponential backoff.",
+    "Payment retries stop after the third declined charge.",
+    "The search index is rebuilt every night at two.",
+    "Nightly search index rebuilds take about forty minutes.",
+    "A declined charge triggers an email to the customer.",
+    "Release notes are drafted on Thursdays.",
+    "The release train leaves every other Friday.",
+];
+
+/// A detected topic as produced by one detection run.
+fn detected(members: &[Uuid]) -> TopicRecord {
+    let topic = Topic::new(
+        TopicProfile::new([0.5; 13]),
+        HashMap::new(),
+        members.to_vec(),
+    );
+    TopicRecord::from_topic(&topic, HashMap::new(), Utc::now())
+}
+
+/// Run one detection pass against the stored topics.
+async fn detection_pass(
+    store: &dyn TeleologicalMemoryStore,
+    topics: Vec<TopicRecord>,
+) -> Vec<Uuid> {
+    let existing = store.list_topic_records().await.unwrap();
+    let reconciliation = reconcile_topic_records(&existing, topics, Utc::now());
+    store
+        .apply_topic_reconciliation(&reconciliation)
+        .await
+        .unwrap();
+    reconciliation.upserts.iter().map(|r| r.id).collect()
+}
+
+fn topic_ids(portfolio: &serde_json::Value) -> Vec<String> {
+    portfolio["topics"]
+        .as_array()
+        .expect("topics array")
+        .iter()
+        .map(|t| t["id"].as_str().expect("topic id").to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_topic_portfolio_persists_ids_across_detection_passes() {
+    let (handlers, store, _edges, _tempdir) = create_test_handlers_with_edges().await;
+
+    let mut m = Vec::new();
+    for (i, content) in CONTENTS.iter().enumerate() {
+        let data = call_tool(
+            &handlers,
+            i as i64,
+            "store_memory",
+            json!({ "content": content }),
+        )
+        .await;
+        m.push(
+            data["fingerprintId"]
+                .as_str()
+                .expect("fingerprintId")
+                .parse::<Uuid>()
+                .unwrap(),
+        );
+    }
+
+    // Pass 1: payments {0,1,2}, search {3,4}
+    let first = detection_pass(store.as_ref(), vec![detected(&m[0..3]), detected(&m[3..5])]).await;
+    let (payments, search) = (first[0], first[1]);
+
+    // Pass 2: payments picks up memory 5, search is unchanged, releases are new
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    let payments_drifted = vec![m[0], m[1], m[2], m[5]];
+    let second = detection_pass(
+        store.as_ref(),
+        vec![
+            detected(&payments_drifted),
+            detected(&m[3..5]),
+            detected(&m[6..8]),
+        ],
+    )
+    .await;
+    assert_eq!(second[0], payments, "drifted topic keeps its ID");
+    assert_eq!(second[1], search, "unchanged topic keeps its ID");
+    let releases = second[2];
+    assert!(
+        releases != payments && releases != search,
+        "new cluster gets a new ID"
+    );
+
+    // By size: payments (4 members) first
+    let by_size = call_tool(&handlers, 20, "get_topic_portfolio", json!({})).await;
+    assert_eq!(by_size["total_topics"], json!(3));
+    assert_eq!(by_size["sort_by"], json!("size"));
+    assert_eq!(topic_ids(&by_size)[0], payments.to_string());
+    assert_eq!(by_size["topics"][0]["member_count"], json!(4));
+
+    // By recency: the topic first seen in pass 2 leads
+    let by_recency = call_tool(
+        &handlers,
+        21,
+        "get_topic_portfolio",
+        json!({ "sort_by": "recency", "limit": 1 }),
+    )
+    .await;
+    assert_eq!(topic_ids(&by_recency), vec![releases.to_string()]);
+    assert_eq!(by_recency["has_more"], json!(true));
+
+    // By stability: the unchanged topic scores 1.0
+    let by_stability = call_tool(
+        &handlers,
+        22,
+        "get_topic_portfolio",
+        json!({ "sort_by": "stability" }),
+    )
+    .await;
+    assert_eq!(topic_ids(&by_stability)[0], search.to_string());
+    assert_eq!(by_stability["topics"][0]["stability_score"], json!(1.0));
+
+    // Last page
+    let last_page = call_tool(
+        &handlers,
+        23,
+        "get_topic_portfolio",
+        json!({ "offset": 2, "limit": 2 }),
+    )
+    .await;
+    assert_eq!(topic_ids(&last_page).len(), 1);
+    assert_eq!(last_page["has_more"], json!(false));
+
+    // Deleting a member rewrites the stored record
+    call_tool(
+        &handlers,
+        24,
+        "forget_concept",
+        json!({ "node_id": m[5].to_string(), "soft_delete": true }),
+    )
+    .await;
+    let stored = store.list_topic_records().await.unwrap();
+    let record = stored
+        .iter()
+        .find(|r| r.id == payments)
+        .expect("payments topic");
+    assert_eq!(record.member_ids, m[0..3].to_vec());
+
+    let after_delete = call_tool(&handlers, 25, "get_topic_portfolio", json!({})).await;
+    assert_eq!(topic_ids(&after_delete)[0], payments.to_string());
+    assert_eq!(after_delete["topics"][0]["member_count"], json!(3));
+}
+
+#[tokio::test]
+async fn test_topic_portfolio_rejects_invalid_sort_and_limit() {
+    let (handlers, _store, _edges, _tempdir) = create_test_handlers_with_edges().await;
+    call_tool(
+        &handlers,
+        1,
+        "store_memory",
+        json!({ "content": CONTENTS[0] }),
+    )
+    .await;
+
+    for (i, arguments) in [
+        json!({ "sort_by": "alphabetical" }),
+        json!({ "limit": 0 }),
+        json!({ "limit": 501 }),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let params = json!({ "name": "get_topic_portfolio", "arguments": arguments });
+        let response = handlers
+            .dispatch(make_request(
+                "tools/call",
+                Some(JsonRpcId::Number(i as i64 + 2)),
+                Some(params),
+            ))
+            .await;
+        let rejected = match (response.result, response.error) {
+            (_, Some(_)) => true,
+            (Some(result), None) => result["isError"] == json!(true),
+            (None, None) => false,
+        };
+        assert!(rejected, "{} must be rejected", arguments);
+    }
+}
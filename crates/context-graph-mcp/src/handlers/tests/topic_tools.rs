@@ -183,6 +183,10 @@ async fn test_get_topic_stability_default_hours() {
         data.get("average_churn").is_some(),
         "Response must contain average_churn"
     );
+    assert!(
+        data.get("topic_series").is_some_and(|v| v.is_array()),
+        "Response must contain topic_series array"
+    );
 
     println!("[PASS] get_topic_stability returns valid response with default hours");
 }
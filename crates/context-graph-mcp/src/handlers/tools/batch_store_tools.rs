@@ -0,0 +1,482 @@
+//! Batch store tool implementation (store_memories_batch).
+//!
+//! Imports many memories in one tools/call instead of one store_memory round
+//! trip per item. Items are validated up front, embedded together through
+//! `embed_batch_all` (concurrent 13-embedder batch path), then stored one at a
+//! time so a bad item only fails itself.
+//!
+//! Differences from store_memory:
+//! - No per-item LLM causal hint or inline causal extraction; E5 causal
+//!   direction is inferred from the embedding. Run trigger_causal_discovery
+//!   after a large import if causal relationships are needed.
+//! - Exact duplicates inside the same batch are collapsed onto the first copy.
+//! - Edge inference runs once over every stored item after the loop, so
+//!   items link to each other as well as to older memories.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use serde::Deserialize;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use tracing::{debug, error, info, warn};
+
+use context_graph_core::traits::{EmbeddingMetadata, MultiArrayEmbeddingOutput};
+use context_graph_core::types::fingerprint::TeleologicalFingerprint;
+
+use crate::protocol::{JsonRpcId, JsonRpcResponse};
+
+use super::super::Handlers;
+use super::memory_tools::{
+    infer_causal_direction_from_fingerprint, parse_namespace, StoredMemoryProvenance,
+    MAX_RATIONALE_LEN, MIN_RATIONALE_LEN,
+};
+use super::validate::Validate;
+
+/// Maximum number of items accepted by one store_memories_batch call.
+pub(crate) const MAX_BATCH_ITEMS: usize = 100;
+
+/// Maximum combined content size (bytes) of one store_memories_batch call.
+pub(crate) const MAX_BATCH_CONTENT_BYTES: usize = 4 * 1024 * 1024;
+
+/// Request for store_memories_batch.
+///
+/// Items stay as raw JSON so a malformed item becomes an indexed error
+/// instead of failing deserialization of the whole batch.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreMemoriesBatchRequest {
+    /// Memories to store: each has `content` plus optional `rationale`,
+    /// `importance` and `namespace`.
+    pub items: Vec<serde_json::Value>,
+
+    /// Session ID applied to every item (default: current session).
+    #[serde(default)]
+    pub session_id: Option<String>,
+
+    /// Operator ID recorded in every item's provenance.
+    #[serde(default)]
+    pub operator_id: Option<String>,
+
+    /// Store items even if identical content already exists.
+    #[serde(default)]
+    pub allow_duplicates: bool,
+}
+
+impl Validate for StoreMemoriesBatchRequest {
+    fn validate(&self) -> Result<(), String> {
+        if self.items.is_empty() {
+            return Err("items must contain at least 1 memory".to_string());
+        }
+        if self.items.len() > MAX_BATCH_ITEMS {
+            return Err(format!(
+                "items must contain at most {} memories, got {}",
+                MAX_BATCH_ITEMS,
+                self.items.len()
+            ));
+        }
+        let content_bytes: usize = self
+            .items
+            .iter()
+            .filter_map(|item| item.get("content").and_then(|v| v.as_str()))
+            .map(str::len)
+            .sum();
+        if content_bytes > MAX_BATCH_CONTENT_BYTES {
+            return Err(format!(
+                "batch content is {} bytes, exceeding the {} byte limit; split it into smaller batches",
+                content_bytes, MAX_BATCH_CONTENT_BYTES
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A validated batch item waiting to be embedded and stored.
+#[derive(Debug)]
+struct BatchItem {
+    index: usize,
+    content: String,
+    content_hash: [u8; 32],
+    rationale: Option<String>,
+    importance: f32,
+    namespace: String,
+    session_sequence: u64,
+}
+
+/// Validate one raw item. Errors are reported against the item's index.
+fn parse_batch_item(index: usize, value: &serde_json::Value) -> Result<BatchItem, String> {
+    if !value.is_object() {
+        return Err("item must be an object".to_string());
+    }
+
+    let content = match value.get("content").and_then(|v| v.as_str()) {
+        Some(c) if !c.is_empty() => c.to_string(),
+        Some(_) => return Err("Content cannot be empty".to_string()),
+        None => return Err("Missing 'content' parameter".to_string()),
+    };
+
+    let rationale = match value.get("rationale") {
+        None => None,
+        Some(v) => match v.as_str() {
+            Some(r) if (MIN_RATIONALE_LEN..=MAX_RATIONALE_LEN).contains(&r.len()) => {
+                Some(r.to_string())
+            }
+            _ => {
+                return Err(format!(
+                    "rationale must be a string of {}-{} characters",
+                    MIN_RATIONALE_LEN, MAX_RATIONALE_LEN
+                ));
+            }
+        },
+    };
+
+    let importance = match value.get("importance") {
+        None => TeleologicalFingerprint::DEFAULT_IMPORTANCE,
+        Some(v) => match v.as_f64() {
+            Some(i) if (0.0..=1.0).contains(&i) => i as f32,
+            _ => return Err(format!("importance must be between 0.0 and 1.0, got {}", v)),
+        },
+    };
+
+    let namespace = parse_namespace(value)?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+
+    Ok(BatchItem {
+        index,
+        content,
+        content_hash: hasher.finalize().into(),
+        rationale,
+        importance,
+        namespace,
+        session_sequence: 0,
+    })
+}
+
+fn failed_result(index: usize, reason: &str) -> serde_json::Value {
+    json!({
+        "index": index,
+        "status": "failed",
+        "error": reason
+    })
+}
+
+impl Handlers {
+    /// store_memories_batch tool implementation.
+    ///
+    /// Stores up to [`MAX_BATCH_ITEMS`] memories in one call and returns one
+    /// result per item, in input order: a fingerprint ID on success or an
+    /// indexed error on failure. One bad item never aborts the rest.
+    ///
+    /// Response includes aggregate timing (embedMs, storeMs, totalMs).
+    pub(crate) async fn call_store_memories_batch(
+        &self,
+        id: Option<JsonRpcId>,
+        args: serde_json::Value,
+    ) -> JsonRpcResponse {
+        let total_start = Instant::now();
+
+        let request: StoreMemoriesBatchRequest =
+            match self.parse_request(id.clone(), args, "store_memories_batch") {
+                Ok(req) => req,
+                Err(resp) => return resp,
+            };
+
+        let item_count = request.items.len();
+        let mut results: Vec<Option<serde_json::Value>> = vec![None; item_count];
+
+        // Validate every item before spending any embedding time
+        let mut pending: Vec<BatchItem> = Vec::with_capacity(item_count);
+        for (index, value) in request.items.iter().enumerate() {
+            match parse_batch_item(index, value) {
+                Ok(item) => pending.push(item),
+                Err(reason) => {
+                    debug!(index, reason = %reason, "store_memories_batch: Item rejected");
+                    results[index] = Some(failed_result(index, &reason));
+                }
+            }
+        }
+
+        // Collapse exact duplicates within the batch onto their first copy
+        let mut first_copy: HashMap<([u8; 32], String), usize> = HashMap::new();
+        let mut batch_duplicates: Vec<(usize, usize)> = Vec::new();
+        if !request.allow_duplicates {
+            pending.retain(|item| {
+                match first_copy.entry((item.content_hash, item.namespace.clone())) {
+                    std::collections::hash_map::Entry::Occupied(first) => {
+                        batch_duplicates.push((item.index, *first.get()));
+                        false
+                    }
+                    std::collections::hash_map::Entry::Vacant(slot) => {
+                        slot.insert(item.index);
+                        true
+                    }
+                }
+            });
+
+            // Exact duplicates of already-stored memories return the existing fingerprint
+            let mut fresh = Vec::with_capacity(pending.len());
+            for item in pending {
+                match self
+                    .find_duplicate_in_namespace(&item.content_hash, &item.namespace)
+                    .await
+                {
+                    Ok(Some(existing_id)) => {
+                        results[item.index] = Some(json!({
+                            "index": item.index,
+                            "status": "deduplicated",
+                            "fingerprintId": existing_id.to_string(),
+                            "namespace": item.namespace,
+                            "deduplicated": true
+                        }));
+                    }
+                    Ok(None) => fresh.push(item),
+                    Err(e) => {
+                        error!(index = item.index, error = %e, "store_memories_batch: Duplicate check FAILED");
+                        results[item.index] = Some(failed_result(
+                            item.index,
+                            &format!("Duplicate check failed: {}", e),
+                        ));
+                    }
+                }
+            }
+            pending = fresh;
+        }
+
+        // Resolve the session once; sequence numbers follow input order (E4)
+        let session_id = request
+            .session_id
+            .clone()
+            .unwrap_or_else(|| self.get_or_init_session_id());
+        for item in &mut pending {
+            item.session_sequence = self.get_next_sequence();
+        }
+
+        let embed_start = Instant::now();
+        let outputs = self.embed_batch_items(&pending, &session_id).await;
+        let embed_ms = embed_start.elapsed().as_millis();
+
+        // Store one fingerprint at a time so a storage failure only fails its item
+        let store_start = Instant::now();
+        let mut stored_ids = Vec::with_capacity(pending.len());
+        for (item, output) in pending.into_iter().zip(outputs) {
+            let output = match output {
+                Ok(output) => output,
+                Err(reason) => {
+                    results[item.index] = Some(failed_result(
+                        item.index,
+                        &format!("Embedding failed: {}", reason),
+                    ));
+                    continue;
+                }
+            };
+
+            let cluster_array = output.fingerprint.to_cluster_array();
+            let causal_direction = infer_causal_direction_from_fingerprint(&output.fingerprint);
+            let e6_sparse = output.fingerprint.e6_sparse.clone();
+            let fingerprint = TeleologicalFingerprint::with_importance(
+                output.fingerprint,
+                item.content_hash,
+                item.importance,
+            )
+            .with_e6_sparse(e6_sparse)
+            .with_namespace(item.namespace.clone());
+            let fingerprint_id = fingerprint.id;
+
+            if let Err(e) = self.teleological_store.store(fingerprint).await {
+                error!(index = item.index, error = %e, "store_memories_batch: Storage FAILED");
+                results[item.index] =
+                    Some(failed_result(item.index, &format!("Storage failed: {}", e)));
+                continue;
+            }
+            self.status_aggregator.record_stored(&item.namespace);
+
+            self.index_stored_memory(
+                fingerprint_id,
+                &item.content,
+                cluster_array,
+                StoredMemoryProvenance {
+                    session_id: Some(session_id.clone()),
+                    session_sequence: item.session_sequence,
+                    causal_direction,
+                    operator_id: request.operator_id.clone(),
+                    rationale: item.rationale.as_deref(),
+                    importance: item.importance,
+                    embedding_hint_provenance: output.e5_hint_provenance,
+                    model_ids: output.model_ids,
+                    embedding_latency: output.total_latency,
+                    chunk: None,
+                    content_classification: None,
+                },
+            )
+            .await;
+            stored_ids.push(fingerprint_id);
+
+            results[item.index] = Some(json!({
+                "index": item.index,
+                "status": "stored",
+                "fingerprintId": fingerprint_id.to_string(),
+                "namespace": item.namespace,
+                "deduplicated": false
+            }));
+        }
+        let inferred = self.infer_stored_edges(&stored_ids).await;
+        let store_ms = store_start.elapsed().as_millis();
+
+        // In-batch duplicates share whatever their first copy ended up as
+        for (index, first) in batch_duplicates {
+            let resolved = match results[first].as_ref() {
+                Some(first_result) if first_result.get("fingerprintId").is_some() => json!({
+                    "index": index,
+                    "status": "deduplicated",
+                    "fingerprintId": first_result["fingerprintId"],
+                    "namespace": first_result["namespace"],
+                    "deduplicated": true
+                }),
+                Some(first_result) => failed_result(
+                    index,
+                    first_result["error"]
+                        .as_str()
+                        .unwrap_or("Duplicate of a failed item"),
+                ),
+                None => failed_result(index, "Duplicate of an unprocessed item"),
+            };
+            results[index] = Some(resolved);
+        }
+
+        let results: Vec<serde_json::Value> = results
+            .into_iter()
+            .enumerate()
+            .map(|(index, r)| r.unwrap_or_else(|| failed_result(index, "Item was not processed")))
+            .collect();
+        let count_status = |status: &str| results.iter().filter(|r| r["status"] == status).count();
+        let stored = count_status("stored");
+        let deduplicated = count_status("deduplicated");
+        let failed = count_status("failed");
+
+        info!(
+            items = item_count,
+            stored,
+            deduplicated,
+            failed,
+            embed_ms = embed_ms as u64,
+            store_ms = store_ms as u64,
+            "store_memories_batch: Completed"
+        );
+
+        let mut response = json!({
+            "results": results,
+            "stored": stored,
+            "deduplicated": deduplicated,
+            "failed": failed,
+            "timing": {
+                "embedMs": embed_ms,
+                "storeMs": store_ms,
+                "totalMs": total_start.elapsed().as_millis()
+            }
+        });
+        if let Some(report) = inferred {
+            response["edgesCreated"] = json!(report.edges_created());
+        }
+        self.tool_result(id, response)
+    }
+
+    /// Embed all pending items, one result per item in the same order.
+    ///
+    /// Uses the concurrent `embed_batch_all` path. That call fails as a whole
+    /// if any item fails, so on error every item is retried on its own to
+    /// isolate the bad one.
+    async fn embed_batch_items(
+        &self,
+        items: &[BatchItem],
+        session_id: &str,
+    ) -> Vec<Result<MultiArrayEmbeddingOutput, String>> {
+        if items.is_empty() {
+            return Vec::new();
+        }
+
+        let now = chrono::Utc::now();
+        let contents: Vec<String> = items.iter().map(|item| item.content.clone()).collect();
+        let metadata: Vec<EmbeddingMetadata> = items
+            .iter()
+            .map(|item| EmbeddingMetadata {
+                session_id: Some(session_id.to_string()),
+                session_sequence: Some(item.session_sequence),
+                timestamp: Some(now),
+                ..EmbeddingMetadata::default()
+            })
+            .collect();
+
+        match self
+            .multi_array_provider
+            .embed_batch_all(&contents, &metadata)
+            .await
+        {
+            Ok(outputs) if outputs.len() == items.len() => {
+                return outputs.into_iter().map(Ok).collect();
+            }
+            Ok(outputs) => warn!(
+                expected = items.len(),
+                actual = outputs.len(),
+                "store_memories_batch: Batch embedding returned wrong item count, retrying individually"
+            ),
+            Err(e) => warn!(
+                error = %e,
+                "store_memories_batch: Batch embedding FAILED, retrying items individually"
+            ),
+        }
+
+        let mut outputs = Vec::with_capacity(items.len());
+        for (content, item_metadata) in contents.iter().zip(metadata) {
+            outputs.push(
+                self.multi_array_provider
+                    .embed_all_with_metadata(content, item_metadata)
+                    .await
+                    .map_err(|e| e.to_string()),
+            );
+        }
+        outputs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_request_limits() {
+        let item = json!({"content": "x"});
+        let request = |items: Vec<serde_json::Value>| StoreMemoriesBatchRequest {
+            items,
+            session_id: None,
+            operator_id: None,
+            allow_duplicates: false,
+        };
+
+        assert!(request(vec![item.clone()]).validate().is_ok());
+        assert!(request(vec![]).validate().is_err());
+        assert!(request(vec![item; MAX_BATCH_ITEMS + 1]).validate().is_err());
+
+        let big = "a".repeat(MAX_BATCH_CONTENT_BYTES / 2 + 1);
+        let err = request(vec![json!({"content": big}); 2])
+            .validate()
+            .unwrap_err();
+        assert!(err.contains("byte limit"), "{err}");
+    }
+
+    #[test]
+    fn test_parse_batch_item_rejects_bad_fields() {
+        let ok = parse_batch_item(3, &json!({"content": "hello", "importance": 0.7})).unwrap();
+        assert_eq!(ok.index, 3);
+        assert_eq!(ok.namespace, TeleologicalFingerprint::DEFAULT_NAMESPACE);
+        assert!((ok.importance - 0.7).abs() < 1e-6);
+
+        assert!(parse_batch_item(0, &json!({"content": ""})).is_err());
+        assert!(parse_batch_item(0, &json!({"importance": 0.5})).is_err());
+        assert!(parse_batch_item(0, &json!({"content": "x", "importance": 2.0})).is_err());
+        assert!(parse_batch_item(0, &json!({"content": "x", "rationale": ""})).is_err());
+        assert!(parse_batch_item(0, &json!({"content": "x", "namespace": "bad ns"})).is_err());
+        assert!(parse_batch_item(0, &json!("just a string")).is_err());
+    }
+}
@@ -0,0 +1,213 @@
+//! Degradation policy for tools when GPU capabilities are missing.
+//!
+//! Every tool falls into one of three classes, checked against the
+//! `CapabilityMatrix` assembled at startup before the tool is dispatched:
+//!
+//! - **GPU required**: the tool has no CPU implementation and is refused with
+//!   a `CAPABILITY_UNAVAILABLE` error whose `data.missingCapabilities` names
+//!   what is missing. Only detect_topics (GPU HDBSCAN) is in this class.
+//! - **CPU fallback**: tools that embed (stores and searches) run embedding
+//!   inference on the CPU device and fuse scores on the CPU. They proceed
+//!   and the result carries `degraded: true` plus `degradedCapabilities`.
+//! - **Independent**: everything else (status, storage reads, graph
+//!   traversal, provenance) runs unchanged.
+//!
+//! Without a matrix (stdio tests, in-process callers) every tool runs as if
+//! all capabilities were present.
+
+use std::sync::Arc;
+
+use serde_json::json;
+use tracing::{debug, warn};
+
+use crate::adapters::{Capability, CapabilityMatrix};
+use crate::protocol::{error_codes, JsonRpcId, JsonRpcResponse};
+use crate::tools::tool_names;
+
+use super::super::Handlers;
+use super::embedding_status_tools::required_models;
+
+/// How a tool behaves when capabilities are missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GpuPolicy {
+    /// Refuse the call unless all of these are present.
+    Required(&'static [Capability]),
+    /// Run on the CPU path, flagged degraded, if any of these is missing.
+    CpuFallback(&'static [Capability]),
+    /// Does not touch the GPU.
+    Independent,
+}
+
+fn gpu_policy(tool: &str) -> GpuPolicy {
+    match tool {
+        tool_names::DETECT_TOPICS => GpuPolicy::Required(&[Capability::CudaDriver]),
+        _ if required_models(tool).is_some() => GpuPolicy::CpuFallback(&[Capability::CandleCuda]),
+        _ => GpuPolicy::Independent,
+    }
+}
+
+/// Outcome of checking a tool against the capability matrix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CapabilityGate {
+    /// Everything the tool uses is present.
+    Available,
+    /// The tool runs on its CPU path without these capabilities.
+    Degraded(Vec<Capability>),
+    /// The tool cannot run without these capabilities.
+    Unavailable(Vec<Capability>),
+}
+
+fn gate(matrix: &CapabilityMatrix, tool: &str) -> CapabilityGate {
+    match gpu_policy(tool) {
+        GpuPolicy::Required(required) => {
+            let missing = matrix.missing(required);
+            if missing.is_empty() {
+                CapabilityGate::Available
+            } else {
+                CapabilityGate::Unavailable(missing)
+            }
+        }
+        GpuPolicy::CpuFallback(preferred) => {
+            let missing = matrix.missing(preferred);
+            if missing.is_empty() {
+                CapabilityGate::Available
+            } else {
+                CapabilityGate::Degraded(missing)
+            }
+        }
+        GpuPolicy::Independent => CapabilityGate::Available,
+    }
+}
+
+fn capability_names(capabilities: &[Capability]) -> Vec<&'static str> {
+    capabilities.iter().map(|c| c.as_str()).collect()
+}
+
+/// `CAPABILITY_UNAVAILABLE` error for a GPU-only tool on a machine without
+/// the capabilities it needs.
+pub(crate) fn capability_unavailable_response(
+    id: Option<JsonRpcId>,
+    tool: &str,
+    missing: &[Capability],
+) -> JsonRpcResponse {
+    let names = capability_names(missing);
+    JsonRpcResponse::error_with_data(
+        id,
+        error_codes::CAPABILITY_UNAVAILABLE,
+        format!(
+            "{} requires unavailable capability: {}",
+            tool,
+            names.join(", ")
+        ),
+        json!({
+            "reason": "capability_unavailable",
+            "tool": tool,
+            "missingCapabilities": names
+        }),
+    )
+}
+
+/// Flag a tool result as produced on the CPU path.
+pub(crate) fn mark_degraded(response: &mut JsonRpcResponse, missing: &[Capability]) {
+    if let Some(serde_json::Value::Object(result)) = response.result.as_mut() {
+        result.insert("degraded".to_string(), json!(true));
+        result.insert(
+            "degradedCapabilities".to_string(),
+            json!(capability_names(missing)),
+        );
+    }
+}
+
+impl Handlers {
+    /// Share the capability matrix assembled at startup with the handlers.
+    ///
+    /// Must be called before wrapping Handlers in Arc.
+    pub(crate) fn set_capability_matrix(&mut self, matrix: Arc<CapabilityMatrix>) {
+        self.capability_matrix = Some(matrix);
+    }
+
+    /// Check `tool` against the capability matrix.
+    pub(crate) fn check_capabilities(&self, tool: &str) -> CapabilityGate {
+        let Some(matrix) = &self.capability_matrix else {
+            return CapabilityGate::Available;
+        };
+        let outcome = gate(matrix, tool);
+        match &outcome {
+            CapabilityGate::Available => {}
+            CapabilityGate::Degraded(missing) => {
+                debug!(tool, missing = ?capability_names(missing), "Running tool degraded");
+            }
+            CapabilityGate::Unavailable(missing) => {
+                warn!(tool, missing = ?capability_names(missing), "Tool requires unavailable capability");
+            }
+        }
+        outcome
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gpu_policy_classes() {
+        assert_eq!(
+            gpu_policy(tool_names::DETECT_TOPICS),
+            GpuPolicy::Required(&[Capability::CudaDriver])
+        );
+        assert_eq!(
+            gpu_policy(tool_names::STORE_MEMORY),
+            GpuPolicy::CpuFallback(&[Capability::CandleCuda])
+        );
+        assert_eq!(
+            gpu_policy(tool_names::SEARCH_GRAPH),
+            GpuPolicy::CpuFallback(&[Capability::CandleCuda])
+        );
+        assert_eq!(
+            gpu_policy(tool_names::GET_MEMETIC_STATUS),
+            GpuPolicy::Independent
+        );
+    }
+
+    #[test]
+    fn test_gate_without_gpu() {
+        let matrix = CapabilityMatrix::no_gpu();
+        assert_eq!(
+            gate(&matrix, tool_names::DETECT_TOPICS),
+            CapabilityGate::Unavailable(vec![Capability::CudaDriver])
+        );
+        assert_eq!(
+            gate(&matrix, tool_names::STORE_MEMORY),
+            CapabilityGate::Degraded(vec![Capability::CandleCuda])
+        );
+        assert_eq!(
+            gate(&matrix, tool_names::GET_TYPED_EDGES),
+            CapabilityGate::Available
+        );
+    }
+
+    #[test]
+    fn test_capability_unavailable_response() {
+        let response = capability_unavailable_response(
+            Some(JsonRpcId::Number(3)),
+            tool_names::DETECT_TOPICS,
+            &[Capability::CudaDriver],
+        );
+        let error = response.error.expect("error response");
+        assert_eq!(error.code, error_codes::CAPABILITY_UNAVAILABLE);
+        let data = error.data.expect("data");
+        assert_eq!(data["reason"], "capability_unavailable");
+        assert_eq!(data["tool"], "detect_topics");
+        assert_eq!(data["missingCapabilities"], json!(["cuda_driver"]));
+    }
+
+    #[test]
+    fn test_mark_degraded() {
+        let mut response =
+            JsonRpcResponse::success(Some(JsonRpcId::Number(1)), json!({ "isError": false }));
+        mark_degraded(&mut response, &[Capability::CandleCuda]);
+        let result = response.result.expect("result");
+        assert_eq!(result["degraded"], true);
+        assert_eq!(result["degradedCapabilities"], json!(["candle_cuda"]));
+    }
+}
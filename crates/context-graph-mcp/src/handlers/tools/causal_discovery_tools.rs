@@ -27,12 +27,43 @@ use context_graph_core::types::audit::{AuditOperation, AuditRecord};
 use context_graph_core::types::fingerprint::TeleologicalFingerprint;
 use context_graph_core::types::SourceMetadata;
 #[cfg(feature = "llm")]
+use context_graph_core::error::CoreResult;
+#[cfg(feature = "llm")]
+use context_graph_core::traits::TeleologicalMemoryStore;
+#[cfg(feature = "llm")]
 use context_graph_graph_agent::MemoryForGraphAnalysis;
 
 use crate::protocol::{JsonRpcId, JsonRpcResponse};
 
 use super::super::Handlers;
 
+/// Fetch the fingerprints of `ids` in order.
+///
+/// Uses one `retrieve_batch` (a single RocksDB multi-get). A batch fails as a
+/// whole when any one key cannot be read, so on failure each ID is retrieved
+/// on its own and only the unreadable ones come back as errors.
+#[cfg(feature = "llm")]
+async fn retrieve_fingerprints(
+    store: &dyn TeleologicalMemoryStore,
+    ids: &[uuid::Uuid],
+) -> Vec<CoreResult<Option<TeleologicalFingerprint>>> {
+    match store.retrieve_batch(ids).await {
+        Ok(fingerprints) => fingerprints.into_iter().map(Ok).collect(),
+        Err(e) => {
+            warn!(
+                error = %e,
+                count = ids.len(),
+                "causal_discovery: Fingerprint batch retrieval failed, retrieving one at a time"
+            );
+            let mut fingerprints = Vec::with_capacity(ids.len());
+            for id in ids {
+                fingerprints.push(store.retrieve(*id).await);
+            }
+            fingerprints
+        }
+    }
+}
+
 /// Non-LLM stubs: When `llm` feature is disabled, these tools return an error.
 #[cfg(not(feature = "llm"))]
 impl Handlers {
@@ -294,9 +325,11 @@ impl Handlers {
         let mut memories_for_analysis: Vec<MemoryForGraphAnalysis> = Vec::new();
         let mut fetch_errors = 0;
 
-        for uuid in &memory_ids {
-            // Get fingerprint
-            let fingerprint = match self.teleological_store.retrieve(*uuid).await {
+        let fingerprints =
+            retrieve_fingerprints(self.teleological_store.as_ref(), &memory_ids).await;
+
+        for (uuid, fingerprint) in memory_ids.iter().zip(fingerprints) {
+            let fingerprint = match fingerprint {
                 Ok(Some(fp)) => fp,
                 Ok(None) => {
                     debug!(uuid = %uuid, "causal_discovery: Fingerprint not found (deleted or expired)");
@@ -1074,3 +1107,37 @@ impl Handlers {
         )
     }
 }
+
+#[cfg(all(test, feature = "llm"))]
+mod tests {
+    use context_graph_core::stubs::InMemoryTeleologicalStore;
+    use context_graph_core::types::fingerprint::SemanticFingerprint;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_failed_batch_falls_back_to_single_retrieval() {
+        let store = InMemoryTeleologicalStore::new();
+        let mut ids = Vec::new();
+        for _ in 0..3 {
+            let fp = TeleologicalFingerprint::new(SemanticFingerprint::zeroed(), [0u8; 32]);
+            ids.push(store.store(fp).await.unwrap());
+        }
+        let missing = uuid::Uuid::new_v4();
+        ids.insert(1, missing);
+
+        store.set_fail_batch_retrieval(true);
+        assert!(store.retrieve_batch(&ids).await.is_err());
+
+        let fingerprints = retrieve_fingerprints(&store, &ids).await;
+        assert_eq!(fingerprints.len(), ids.len());
+        for (id, fingerprint) in ids.iter().zip(&fingerprints) {
+            let fingerprint = fingerprint.as_ref().expect("single retrieval must succeed");
+            if *id == missing {
+                assert!(fingerprint.is_none());
+            } else {
+                assert_eq!(fingerprint.as_ref().map(|fp| fp.id), Some(*id));
+            }
+        }
+    }
+}
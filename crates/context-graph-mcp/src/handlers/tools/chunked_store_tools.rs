@@ -0,0 +1,405 @@
+//! Chunked store_memory path.
+//!
+//! When `chunking.enabled` is set and the content exceeds `chunking.max_tokens`,
+//! store_memory splits it with [`SemanticChunker`] instead of embedding the
+//! whole document as one memory:
+//!
+//! - A parent "document" memory holds the full content and content hash (so
+//!   exact-duplicate detection still applies to the whole document). It is
+//!   embedded from the document outline (title and section headings), leaving
+//!   detailed matches to the chunks.
+//! - Each chunk is embedded and stored as its own memory. Source metadata
+//!   records chunk index, total chunks, line range and the parent document.
+//! - Typed edges (GraphConnected, Forward) link parent → chunk and
+//!   chunk i → chunk i+1.
+//!
+//! Everything is embedded in one `embed_batch_all` call before anything is
+//! stored, so an embedding failure leaves nothing behind. Like
+//! store_memories_batch, no LLM causal hint or inline causal extraction is run.
+
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+use context_graph_core::error::CoreError;
+use context_graph_core::graph_linking::{DirectedRelation, GraphLinkEdgeType, TypedEdge};
+use context_graph_core::memory::{Chunker, DocumentChunk, SemanticChunker};
+use context_graph_core::traits::EmbeddingMetadata;
+use context_graph_core::types::fingerprint::{TeleologicalFingerprint, NUM_EMBEDDERS};
+
+use crate::protocol::{JsonRpcId, JsonRpcResponse};
+
+use super::super::Handlers;
+use super::embedding_status_tools::embedding_backpressure_response;
+use super::memory_tools::{
+    infer_causal_direction_from_fingerprint, ChunkProvenance, StoredMemoryProvenance,
+};
+
+/// Maximum number of headings listed in a document outline.
+const MAX_OUTLINE_HEADINGS: usize = 32;
+
+/// A validated store_memory request that takes the chunked path.
+pub(super) struct ChunkedDocument<'a> {
+    pub content: &'a str,
+    pub content_hash: [u8; 32],
+    pub chunker: SemanticChunker,
+    pub namespace: String,
+    pub staged: bool,
+    pub importance: f32,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub e12_pruning: Option<f32>,
+    pub rationale: Option<&'a str>,
+    pub session_id: String,
+    /// Sequence number already reserved for the parent document.
+    pub session_sequence: u64,
+    pub operator_id: Option<String>,
+}
+
+/// Parse the optional `chunking` argument.
+///
+/// Returns `None` when absent or `enabled` is false.
+pub(super) fn parse_chunking(args: &serde_json::Value) -> Result<Option<SemanticChunker>, String> {
+    let Some(chunking) = args.get("chunking") else {
+        return Ok(None);
+    };
+    if !chunking.is_object() {
+        return Err("chunking must be an object".to_string());
+    }
+    if !chunking
+        .get("enabled")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+    {
+        return Ok(None);
+    }
+
+    let field = |name: &str, default: usize| -> Result<usize, String> {
+        match chunking.get(name) {
+            None => Ok(default),
+            Some(v) => v.as_u64().map(|n| n as usize).ok_or_else(|| {
+                format!(
+                    "chunking.{} must be a non-negative integer, got {}",
+                    name, v
+                )
+            }),
+        }
+    };
+    let max_tokens = field("max_tokens", SemanticChunker::DEFAULT_MAX_TOKENS)?;
+    let overlap = field("overlap", SemanticChunker::DEFAULT_OVERLAP_TOKENS)?;
+
+    SemanticChunker::new(max_tokens, overlap)
+        .map(Some)
+        .map_err(|e| format!("Invalid chunking: {}", e))
+}
+
+/// Text embedded for the parent document: first heading (or first line)
+/// followed by the section headings.
+fn document_outline(content: &str, chunks: &[DocumentChunk]) -> String {
+    let mut headings: Vec<&str> = Vec::new();
+    for heading in chunks.iter().filter_map(|c| c.heading.as_deref()) {
+        if headings.last() != Some(&heading) && headings.len() < MAX_OUTLINE_HEADINGS {
+            headings.push(heading);
+        }
+    }
+    match headings.split_first() {
+        Some((title, [])) => title.to_string(),
+        Some((title, sections)) => format!("{}\nSections: {}", title, sections.join("; ")),
+        None => content
+            .lines()
+            .map(str::trim)
+            .find(|l| !l.is_empty())
+            .unwrap_or_default()
+            .to_string(),
+    }
+}
+
+/// Parent → chunk and chunk → next-chunk edges.
+fn chunk_edges(document_id: Uuid, chunk_ids: &[Uuid]) -> Result<Vec<TypedEdge>, String> {
+    let parent_links = chunk_ids.iter().map(|&chunk| (document_id, chunk));
+    let sequence_links = chunk_ids.windows(2).map(|pair| (pair[0], pair[1]));
+    parent_links
+        .chain(sequence_links)
+        .map(|(source, target)| {
+            TypedEdge::new(
+                source,
+                target,
+                GraphLinkEdgeType::GraphConnected,
+                1.0,
+                DirectedRelation::Forward,
+                [0.0; NUM_EMBEDDERS],
+                0,
+                0,
+            )
+            .map_err(|e| e.to_string())
+        })
+        .collect()
+}
+
+impl Handlers {
+    /// Store `doc` as a parent document memory plus one memory per chunk.
+    ///
+    /// Requires the edge repository: chunks without their links would be
+    /// indistinguishable from unrelated memories.
+    pub(super) async fn store_chunked_document(
+        &self,
+        id: Option<JsonRpcId>,
+        doc: ChunkedDocument<'_>,
+    ) -> JsonRpcResponse {
+        let Some(edge_repo) = &self.edge_repository else {
+            error!("store_memory: EdgeRepository not available — cannot link document chunks");
+            return self.tool_error(
+                id,
+                "EdgeRepository not initialized — chunked store_memory requires graph linking. \
+                 Store without chunking or enable graph linking.",
+            );
+        };
+
+        let chunks = match doc.chunker.chunk(doc.content) {
+            Ok(chunks) => chunks,
+            Err(e) => return self.tool_error(id, &format!("Chunking failed: {}", e)),
+        };
+        let total_chunks = chunks.len() as u32;
+
+        // Index 0 is the parent document, 1.. are the chunks in order
+        let mut contents = Vec::with_capacity(chunks.len() + 1);
+        contents.push(document_outline(doc.content, &chunks));
+        contents.extend(chunks.iter().map(|c| c.content.clone()));
+        let mut sequences = Vec::with_capacity(contents.len());
+        sequences.push(doc.session_sequence);
+        sequences.extend(chunks.iter().map(|_| self.get_next_sequence()));
+
+        let now = chrono::Utc::now();
+        let metadata: Vec<EmbeddingMetadata> = sequences
+            .iter()
+            .map(|&sequence| EmbeddingMetadata {
+                session_id: Some(doc.session_id.clone()),
+                session_sequence: Some(sequence),
+                timestamp: Some(now),
+                e12_pruning: doc.e12_pruning,
+                ..EmbeddingMetadata::default()
+            })
+            .collect();
+
+        let outputs = match self
+            .multi_array_provider
+            .embed_batch_all(&contents, &metadata)
+            .await
+        {
+            Ok(outputs) if outputs.len() == contents.len() => outputs,
+            Ok(outputs) => {
+                error!(
+                    expected = contents.len(),
+                    actual = outputs.len(),
+                    "store_memory: Chunk embedding returned wrong item count"
+                );
+                return self.tool_error(
+                    id,
+                    &format!(
+                        "Embedding failed: expected {} outputs, got {}",
+                        contents.len(),
+                        outputs.len()
+                    ),
+                );
+            }
+            Err(CoreError::Backpressure {
+                queue_depth,
+                retry_after_ms,
+            }) => {
+                warn!(
+                    queue_depth,
+                    retry_after_ms,
+                    "store_memory: Embedding queue full, rejecting chunked document"
+                );
+                return embedding_backpressure_response(id, queue_depth, retry_after_ms);
+            }
+            Err(e) => {
+                error!(error = %e, "store_memory: Chunk embedding FAILED");
+                return self.tool_error(id, &format!("Embedding failed: {}", e));
+            }
+        };
+        let embedding_latency = outputs
+            .iter()
+            .map(|o| o.total_latency)
+            .max()
+            .unwrap_or_default();
+
+        let document_id = Uuid::new_v4();
+        let mut chunk_ids = Vec::with_capacity(chunks.len());
+        for (i, (output, sequence)) in outputs.into_iter().zip(sequences).enumerate() {
+            // Parent keeps the document hash; each chunk hashes its own text
+            let (content, content_hash, chunk) = if i == 0 {
+                (doc.content, doc.content_hash, None)
+            } else {
+                let c = &chunks[i - 1];
+                let hash: [u8; 32] = Sha256::digest(c.content.as_bytes()).into();
+                let provenance = ChunkProvenance {
+                    document_id,
+                    chunk_index: c.index as u32,
+                    total_chunks,
+                    start_line: c.start_line,
+                    end_line: c.end_line,
+                };
+                (c.content.as_str(), hash, Some(provenance))
+            };
+
+            let cluster_array = output.fingerprint.to_cluster_array();
+            let causal_direction = infer_causal_direction_from_fingerprint(&output.fingerprint);
+            let e6_sparse = output.fingerprint.e6_sparse.clone();
+            let mut fingerprint = TeleologicalFingerprint::with_importance(
+                output.fingerprint,
+                content_hash,
+                doc.importance,
+            )
+            .with_e6_sparse(e6_sparse)
+            .with_namespace(doc.namespace.clone());
+            if i == 0 {
+                fingerprint.id = document_id;
+            }
+            if let Some(deadline) = doc.expires_at {
+                fingerprint = fingerprint.with_expires_at(deadline);
+            }
+            let fingerprint_id = fingerprint.id;
+
+            if let Err(e) = self.teleological_store.store(fingerprint).await {
+                error!(
+                    document_id = %document_id,
+                    chunk = i,
+                    stored = chunk_ids.len(),
+                    error = %e,
+                    "store_memory: Storage FAILED for chunked document"
+                );
+                return self.tool_error(
+                    id,
+                    &format!(
+                        "Storage failed at item {} of {} (document {}): {}",
+                        i,
+                        contents.len(),
+                        document_id,
+                        e
+                    ),
+                );
+            }
+            self.status_aggregator.record_stored(&doc.namespace);
+
+            self.index_stored_memory(
+                fingerprint_id,
+                content,
+                cluster_array,
+                StoredMemoryProvenance {
+                    session_id: Some(doc.session_id.clone()),
+                    session_sequence: sequence,
+                    causal_direction,
+                    operator_id: doc.operator_id.clone(),
+                    rationale: doc.rationale,
+                    importance: doc.importance,
+                    embedding_hint_provenance: output.e5_hint_provenance,
+                    model_ids: output.model_ids,
+                    embedding_latency: output.total_latency,
+                    chunk,
+                    content_classification: None,
+                },
+            )
+            .await;
+
+            if i > 0 {
+                chunk_ids.push(fingerprint_id);
+            }
+        }
+
+        let edges = match chunk_edges(document_id, &chunk_ids) {
+            Ok(edges) => edges,
+            Err(e) => {
+                error!(document_id = %document_id, error = %e, "store_memory: Failed to build chunk edges");
+                return self.tool_error(id, &format!("Failed to link chunks: {}", e));
+            }
+        };
+        if let Err(e) = edge_repo.store_typed_edges_batch(&edges) {
+            error!(
+                document_id = %document_id,
+                edge_count = edges.len(),
+                error = %e,
+                "store_memory: Failed to persist chunk edges"
+            );
+            return self.tool_error(id, &format!("Failed to link chunks: {}", e));
+        }
+        debug!(
+            document_id = %document_id,
+            edges = edges.len(),
+            "store_memory: Chunk edges persisted"
+        );
+
+        info!(
+            document_id = %document_id,
+            chunks = chunk_ids.len(),
+            max_tokens = doc.chunker.max_tokens(),
+            "store_memory: Stored chunked document"
+        );
+
+        let mut response = json!({
+            "fingerprintId": document_id.to_string(),
+            "documentId": document_id.to_string(),
+            "chunkIds": chunk_ids.iter().map(Uuid::to_string).collect::<Vec<_>>(),
+            "chunkCount": chunk_ids.len(),
+            "edgesCreated": edges.len(),
+            "embedderCount": NUM_EMBEDDERS,
+            "embeddingLatencyMs": embedding_latency.as_millis(),
+            "namespace": doc.namespace,
+            "staged": doc.staged,
+            "deduplicated": false
+        });
+        if let Some(r) = doc.rationale {
+            response["rationale"] = json!(r);
+        }
+        if let Some(deadline) = doc.expires_at {
+            response["expiresAt"] = json!(deadline.to_rfc3339());
+        }
+        self.tool_result(id, response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_chunking() {
+        assert!(parse_chunking(&json!({})).unwrap().is_none());
+        assert!(parse_chunking(&json!({"chunking": {"enabled": false}}))
+            .unwrap()
+            .is_none());
+
+        let chunker = parse_chunking(
+            &json!({"chunking": {"enabled": true, "max_tokens": 100, "overlap": 10}}),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(chunker.max_tokens(), 100);
+        assert_eq!(chunker.overlap_tokens(), 10);
+
+        assert!(parse_chunking(&json!({"chunking": {"enabled": true, "max_tokens": 8}})).is_err());
+        assert!(parse_chunking(
+            &json!({"chunking": {"enabled": true, "max_tokens": 64, "overlap": 64}})
+        )
+        .is_err());
+        assert!(parse_chunking(&json!({"chunking": {"enabled": true, "max_tokens": -1}})).is_err());
+    }
+
+    #[test]
+    fn test_chunk_edges_link_parent_and_sequence() {
+        let document = Uuid::new_v4();
+        let chunks: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        let edges = chunk_edges(document, &chunks).unwrap();
+
+        assert_eq!(edges.len(), 5);
+        assert!(edges[..3].iter().all(|e| e.source() == document));
+        assert_eq!(
+            (edges[3].source(), edges[3].target()),
+            (chunks[0], chunks[1])
+        );
+        assert_eq!(
+            (edges[4].source(), edges[4].target()),
+            (chunks[1], chunks[2])
+        );
+    }
+}
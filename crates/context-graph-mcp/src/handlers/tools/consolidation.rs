@@ -1,7 +1,15 @@
 //! Consolidation tool handler.
 //!
 //! PRD v6 Section 10.1: trigger_consolidation is a Core tool.
+//!
+//! Candidate pairs are scored by E1 cosine and, unless `dry_run` is set,
+//! merged in place: the survivor absorbs the duplicate's access count and
+//! graph edges, and the duplicate is soft-deleted with a `MemoryConsolidated`
+//! audit record pointing at the survivor.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
 
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use serde_json::json;
 use tracing::{debug, error, info, warn};
@@ -10,10 +18,14 @@ use uuid::Uuid;
 use context_graph_core::causal::asymmetric::{
     infer_direction_from_fingerprint, CausalDirection,
 };
-use context_graph_core::types::audit::{AuditOperation, AuditRecord};
+use context_graph_core::graph_linking::{EmbedderEdge, TypedEdge};
+use context_graph_core::traits::{SearchStrategy, TeleologicalSearchOptions};
+use context_graph_core::types::audit::{
+    AuditOperation, AuditRecord, LineageOperation, LineageRecord,
+};
 
-use crate::handlers::Handlers;
 use crate::handlers::tools::helpers::cosine_similarity;
+use crate::handlers::{Handlers, ProgressReporter};
 use crate::protocol::{JsonRpcId, JsonRpcResponse};
 
 /// Configuration for automatic background consolidation.
@@ -83,16 +95,23 @@ struct MemoryContent {
     id: MemoryId,
     embedding: Vec<f32>,
     access_count: u32,
+    created_at: DateTime<Utc>,
     /// Gap 5: E5 causal direction for this memory (Cause, Effect, or Unknown)
     causal_direction: CausalDirection,
 }
 
 impl MemoryContent {
-    fn new(id: MemoryId, embedding: Vec<f32>, causal_direction: CausalDirection) -> Self {
+    fn new(
+        id: MemoryId,
+        embedding: Vec<f32>,
+        created_at: DateTime<Utc>,
+        causal_direction: CausalDirection,
+    ) -> Self {
         Self {
             id,
             embedding,
             access_count: 0,
+            created_at,
             causal_direction,
         }
     }
@@ -124,6 +143,37 @@ struct ConsolidationCandidate {
     similarity: f32,
 }
 
+impl ConsolidationCandidate {
+    /// The member of the pair that is folded into `target_id`.
+    fn merged_id(&self) -> MemoryId {
+        self.source_ids
+            .iter()
+            .copied()
+            .find(|id| *id != self.target_id)
+            .unwrap_or(self.target_id)
+    }
+}
+
+/// Survivor of a pair: the more-accessed memory, then the older one.
+fn pick_survivor(first: &MemoryContent, second: &MemoryContent) -> MemoryId {
+    if second.access_count > first.access_count
+        || (second.access_count == first.access_count && second.created_at < first.created_at)
+    {
+        second.id
+    } else {
+        first.id
+    }
+}
+
+/// Outcome of folding one memory into another.
+#[derive(Debug)]
+struct ConsolidatedPair {
+    kept_id: Uuid,
+    merged_id: Uuid,
+    similarity: f32,
+    edges_retargeted: usize,
+}
+
 /// Configuration for consolidation service.
 #[derive(Debug, Clone)]
 struct ConsolidationConfig {
@@ -142,12 +192,14 @@ impl ConsolidationService {
         Self { config }
     }
 
+    /// Score `pairs` and return those at or above the threshold, most
+    /// similar first, capped at `max_daily_merges`.
     fn find_consolidation_candidates(&self, pairs: &[MemoryPair]) -> Vec<ConsolidationCandidate> {
         if !self.config.enabled {
             return Vec::new();
         }
 
-        pairs
+        let mut candidates: Vec<ConsolidationCandidate> = pairs
             .iter()
             .filter_map(|pair| {
                 // Gap 5: Reject pairs with opposing E5 causal directions.
@@ -176,15 +228,18 @@ impl ConsolidationService {
                 if sim >= self.config.similarity_threshold {
                     Some(ConsolidationCandidate {
                         source_ids: vec![pair.first.id, pair.second.id],
-                        target_id: pair.first.id, // Keep the first as target
+                        target_id: pick_survivor(&pair.first, &pair.second),
                         similarity: sim,
                     })
                 } else {
                     None
                 }
             })
-            .take(self.config.max_daily_merges)
-            .collect()
+            .collect();
+
+        candidates.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+        candidates.truncate(self.config.max_daily_merges);
+        candidates
     }
 }
 
@@ -212,8 +267,18 @@ pub struct TriggerConsolidationParams {
     /// normalized from [-1,1] to [0,1] via (raw+1)/2.
     #[serde(default = "default_consolidation_similarity")]
     pub min_similarity: f32,
+
+    /// Only report candidates; do not merge anything (default: false)
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
+/// E1 HNSW neighbours fetched per memory by the "similarity" strategy.
+const SIMILARITY_NEIGHBORS: usize = 10;
+
+/// Width of a "temporal" strategy bucket: memories created on the same UTC day.
+const TEMPORAL_BUCKET_SECS: i64 = 24 * 60 * 60;
+
 fn default_max_memories() -> usize {
     100
 }
@@ -232,12 +297,17 @@ impl Handlers {
     /// trigger_consolidation tool implementation.
     ///
     /// PRD v6 Section 10.1: Trigger memory consolidation.
-    /// Uses ConsolidationService to merge similar memories.
+    /// Uses ConsolidationService to find similar pairs and merges each one
+    /// via `consolidate_pair`.
     ///
     /// Arguments:
     /// - max_memories (optional): Maximum to process (default: 100)
     /// - strategy (optional): "similarity", "temporal", "semantic" (default: "similarity")
     /// - min_similarity (optional): Minimum similarity for merge (default: 0.925)
+    /// - dry_run (optional): Report candidates without merging (default: false)
+    ///
+    /// Reports progress for scan, content load, pair building, scoring and
+    /// merging, and stops between stages if the client cancels.
     ///
     /// Returns:
     /// - consolidation_result: Pairs merged and outcome
@@ -246,6 +316,7 @@ impl Handlers {
         &self,
         id: Option<JsonRpcId>,
         arguments: serde_json::Value,
+        progress: ProgressReporter,
     ) -> JsonRpcResponse {
         debug!("Handling trigger_consolidation tool call");
 
@@ -301,6 +372,9 @@ impl Handlers {
             "trigger_consolidation: Parsed parameters"
         );
 
+        const STAGES: u64 = 5;
+        progress.report(0, Some(STAGES), "Scanning memories");
+
         // MED-13 FIX: Use unbiased fingerprint scan instead of semantic search
         // with hardcoded "context memory patterns" query (which biased retrieval).
         let unbiased_fingerprints = match self
@@ -327,6 +401,11 @@ impl Handlers {
             "trigger_consolidation: Retrieved fingerprints (unbiased scan)"
         );
 
+        if progress.checkpoint().await.is_err() {
+            return self.cancelled_result(id, "trigger_consolidation", "Loading content");
+        }
+        progress.report(1, Some(STAGES), "Loading content");
+
         // Batch-fetch content text for consolidation analysis (MED-04 fix)
         let fp_ids: Vec<Uuid> = unbiased_fingerprints.iter().map(|fp| fp.id).collect();
         // ERR-3 FIX: FAIL FAST on content fetch failure instead of substituting empty
@@ -348,7 +427,8 @@ impl Handlers {
 
         // Convert TeleologicalFingerprint to MemoryContent
         let mut memory_contents: Vec<MemoryContent> = Vec::with_capacity(unbiased_fingerprints.len());
-        let mut fingerprints: Vec<(Uuid, chrono::DateTime<chrono::Utc>)> = Vec::new();
+        // Position in memory_contents by id, for mapping E1 HNSW neighbours back
+        let mut positions: HashMap<Uuid, usize> = HashMap::new();
 
         for (idx, fp) in unbiased_fingerprints.iter().enumerate() {
             // MED-9 FIX: Skip memories with missing content instead of using empty string.
@@ -374,35 +454,69 @@ impl Handlers {
 
             // MCP-L3: text validated for non-empty above but not stored — only embedding used for similarity
             let _ = text;
-            let content = MemoryContent::new(MemoryId(fp.id), embedding, direction)
+            let content = MemoryContent::new(MemoryId(fp.id), embedding, fp.created_at, direction)
                 .with_access_count(fp.access_count as u32);
 
+            positions.insert(fp.id, memory_contents.len());
             memory_contents.push(content);
-            fingerprints.push((fp.id, fp.created_at));
         }
 
+        if progress.checkpoint().await.is_err() {
+            return self.cancelled_result(id, "trigger_consolidation", "Building candidate pairs");
+        }
+        progress.report(2, Some(STAGES), "Building candidate pairs");
+
         // Build pairs based on strategy.
         //
         // MCP-3 NOTE: Strategies differ in PAIR SELECTION, not scoring.
         // All pairs are scored by cosine similarity in find_consolidation_candidates().
-        //  - "similarity": Pre-filters by cosine >= threshold * 0.9 (most selective)
-        //  - "temporal": 24h time window only — temporally close but potentially dissimilar
+        //  - "similarity": E1 HNSW nearest neighbours of each scanned memory (most selective)
+        //  - "temporal": memories created on the same UTC day — potentially dissimilar
         //  - "semantic": No pre-filter — all O(n²) pairs sent for cosine scoring
         // The consolidation service makes the final merge/skip decision via cosine threshold.
         let pairs: Vec<MemoryPair> = match params.strategy.as_str() {
             "similarity" => {
                 let mut pairs = Vec::new();
-                let threshold = params.min_similarity * 0.9;
-
-                for i in 0..memory_contents.len() {
-                    for j in (i + 1)..memory_contents.len() {
-                        // MCP-05 FIX: Use cosine similarity instead of raw dot product.
-                        let sim = cosine_similarity(
-                            &memory_contents[i].embedding,
-                            &memory_contents[j].embedding,
-                        );
-
-                        if sim >= threshold {
+                let mut seen: HashSet<(Uuid, Uuid)> = HashSet::new();
+                let options = TeleologicalSearchOptions::quick(SIMILARITY_NEIGHBORS + 1)
+                    .with_strategy(SearchStrategy::E1Only);
+
+                for fp in &unbiased_fingerprints {
+                    let Some(&i) = positions.get(&fp.id) else {
+                        continue;
+                    };
+                    let neighbors = match self
+                        .teleological_store
+                        .search_semantic(&fp.semantic, options.clone())
+                        .await
+                    {
+                        Ok(results) => results,
+                        Err(e) => {
+                            error!(
+                                memory_id = %fp.id,
+                                error = %e,
+                                "trigger_consolidation: E1 neighbour search failed"
+                            );
+                            return self.tool_error(
+                                id,
+                                &format!("E1 neighbour search failed for {}: {}", fp.id, e),
+                            );
+                        }
+                    };
+
+                    // Only pair within the scanned batch; neighbours outside it
+                    // are picked up when their own batch is consolidated
+                    for neighbor in neighbors {
+                        let other = neighbor.fingerprint.id;
+                        let Some(&j) = positions.get(&other) else {
+                            continue;
+                        };
+                        let key = if fp.id < other {
+                            (fp.id, other)
+                        } else {
+                            (other, fp.id)
+                        };
+                        if i != j && seen.insert(key) {
                             pairs.push(MemoryPair::new(
                                 memory_contents[i].clone(),
                                 memory_contents[j].clone(),
@@ -413,19 +527,23 @@ impl Handlers {
                 pairs
             }
             "temporal" => {
-                let window_secs = 24 * 60 * 60;
-                let mut pairs = Vec::new();
+                let mut buckets: BTreeMap<i64, Vec<usize>> = BTreeMap::new();
+                for (i, memory) in memory_contents.iter().enumerate() {
+                    let bucket = memory
+                        .created_at
+                        .timestamp()
+                        .div_euclid(TEMPORAL_BUCKET_SECS);
+                    buckets.entry(bucket).or_default().push(i);
+                }
 
-                for i in 0..memory_contents.len() {
-                    for j in (i + 1)..memory_contents.len() {
-                        if i < fingerprints.len() && j < fingerprints.len() {
-                            let diff = (fingerprints[i].1 - fingerprints[j].1).num_seconds().abs();
-                            if diff < window_secs {
-                                pairs.push(MemoryPair::new(
-                                    memory_contents[i].clone(),
-                                    memory_contents[j].clone(),
-                                ));
-                            }
+                let mut pairs = Vec::new();
+                for members in buckets.values() {
+                    for (a, &i) in members.iter().enumerate() {
+                        for &j in &members[a + 1..] {
+                            pairs.push(MemoryPair::new(
+                                memory_contents[i].clone(),
+                                memory_contents[j].clone(),
+                            ));
                         }
                     }
                 }
@@ -450,6 +568,11 @@ impl Handlers {
             }
         };
 
+        if progress.checkpoint().await.is_err() {
+            return self.cancelled_result(id, "trigger_consolidation", "Scoring candidates");
+        }
+        progress.report(3, Some(STAGES), "Scoring candidates");
+
         // Create consolidation service
         let config = ConsolidationConfig {
             enabled: true,
@@ -489,19 +612,92 @@ impl Handlers {
             }
         }
 
+        if progress.checkpoint().await.is_err() {
+            return self.cancelled_result(id, "trigger_consolidation", "Merging candidates");
+        }
+        progress.report(4, Some(STAGES), "Merging candidates");
+
+        // Merge most-similar pairs first. A memory folded into another is
+        // consumed and cannot take part in a later pair; a survivor may absorb
+        // several duplicates.
+        let mut merged: Vec<ConsolidatedPair> = Vec::new();
+        let mut failures: Vec<serde_json::Value> = Vec::new();
+        let mut skipped = 0usize;
+        if !params.dry_run {
+            let mut consumed: HashSet<Uuid> = HashSet::new();
+            for candidate in &candidates {
+                let keep = candidate.target_id.0;
+                let merge = candidate.merged_id().0;
+                if keep == merge || consumed.contains(&keep) || consumed.contains(&merge) {
+                    skipped += 1;
+                    continue;
+                }
+                match self
+                    .consolidate_pair(keep, merge, candidate.similarity)
+                    .await
+                {
+                    Ok(outcome) => {
+                        consumed.insert(merge);
+                        merged.push(outcome);
+                    }
+                    Err(e) => {
+                        error!(
+                            kept_id = %keep,
+                            merged_id = %merge,
+                            error = %e,
+                            "trigger_consolidation: Failed to consolidate pair"
+                        );
+                        failures.push(json!({
+                            "kept_id": keep.to_string(),
+                            "merged_id": merge.to_string(),
+                            "error": e
+                        }));
+                    }
+                }
+            }
+        }
+        let edges_retargeted: usize = merged.iter().map(|m| m.edges_retargeted).sum();
+
         let statistics = json!({
             "pairs_evaluated": pairs.len(),
             "candidates_found": candidates.len(),
+            "merges_executed": merged.len(),
+            "edges_retargeted": edges_retargeted,
             "strategy": params.strategy,
             "similarity_threshold": params.min_similarity,
             "max_memories_limit": params.max_memories,
             "fingerprints_analyzed": memory_contents.len()
         });
 
+        let status = if candidates.is_empty() {
+            "no_candidates"
+        } else if params.dry_run {
+            "candidates_found"
+        } else if !failures.is_empty() {
+            "partial"
+        } else {
+            "merged"
+        };
+        let merged_pairs: Vec<serde_json::Value> = merged
+            .iter()
+            .map(|m| {
+                json!({
+                    "kept_id": m.kept_id.to_string(),
+                    "merged_id": m.merged_id.to_string(),
+                    "similarity": m.similarity,
+                    "edges_retargeted": m.edges_retargeted
+                })
+            })
+            .collect();
         let consolidation_result = json!({
-            "status": if candidates.is_empty() { "no_candidates" } else { "candidates_found" },
+            "status": status,
+            "dry_run": params.dry_run,
             "candidate_count": candidates.len(),
-            "note": "This tool identifies consolidation candidates but does not merge them. Use merge_concepts to execute merges."
+            "merged_count": merged.len(),
+            "skipped_count": skipped,
+            "failed_count": failures.len(),
+            "merged_pairs": merged_pairs,
+            "failures": failures
         });
 
         let candidates_sample: Vec<serde_json::Value> = candidates
@@ -518,10 +714,13 @@ impl Handlers {
 
         info!(
             candidate_count = candidates.len(),
+            merged_count = merged.len(),
             pairs_evaluated = pairs.len(),
             strategy = %params.strategy,
-            "trigger_consolidation: Analysis complete"
+            dry_run = params.dry_run,
+            "trigger_consolidation: Complete"
         );
+        progress.report(STAGES, Some(STAGES), "Complete");
 
         self.tool_result(
             id,
@@ -532,4 +731,215 @@ impl Handlers {
             }),
         )
     }
+
+    /// Fold `merge` into `keep`.
+    ///
+    /// 1. Add the duplicate's access count to the survivor and keep the higher
+    ///    importance (re-indexes the survivor in every space via `update`)
+    /// 2. Re-point typed and K-NN edges from `merge` to `keep`
+    /// 3. Record the tombstone pointer (`MemoryConsolidated { into: keep }`)
+    /// 4. Soft-delete `merge`, which hides it from every per-space search until
+    ///    compaction removes it from the indexes
+    async fn consolidate_pair(
+        &self,
+        keep: Uuid,
+        merge: Uuid,
+        similarity: f32,
+    ) -> Result<ConsolidatedPair, String> {
+        let fetched = self
+            .teleological_store
+            .retrieve_batch(&[keep, merge])
+            .await
+            .map_err(|e| format!("Failed to retrieve pair: {}", e))?;
+        let (mut survivor, duplicate) = match (fetched.first(), fetched.get(1)) {
+            (Some(Some(kept)), Some(Some(merged))) => (kept.clone(), merged.clone()),
+            _ => return Err(format!("Pair no longer present: {} / {}", keep, merge)),
+        };
+
+        // Step 1: Merge metadata into the survivor
+        survivor.access_count = survivor.access_count.saturating_add(duplicate.access_count);
+        survivor.importance = survivor.importance.max(duplicate.importance);
+        survivor.last_updated = Utc::now();
+        match self.teleological_store.update(survivor).await {
+            Ok(true) => {}
+            Ok(false) => return Err(format!("Survivor {} vanished during update", keep)),
+            Err(e) => return Err(format!("Failed to update survivor {}: {}", keep, e)),
+        }
+
+        // Step 2: Re-point graph edges
+        let edges_retargeted = self.retarget_edges(merge, keep)?;
+
+        // Step 3: Tombstone pointer. Written before the soft-delete so a
+        // consolidated memory never disappears without a record of its survivor
+        let tombstone = AuditRecord::new(
+            AuditOperation::MemoryConsolidated {
+                into: keep,
+                similarity,
+            },
+            merge,
+        )
+        .with_rationale(format!(
+            "trigger_consolidation: near-duplicate of {} (similarity {:.3})",
+            keep, similarity
+        ))
+        .with_parameters(json!({
+            "access_count": duplicate.access_count,
+            "edges_retargeted": edges_retargeted,
+        }));
+        self.teleological_store
+            .append_audit_record(&tombstone)
+            .await
+            .map_err(|e| format!("Failed to record tombstone for {}: {}", merge, e))?;
+
+        // Step 4: Soft-delete the duplicate
+        match self.teleological_store.delete(merge, true).await {
+            Ok(true) => {}
+            Ok(false) => return Err(format!("Merged memory {} vanished before delete", merge)),
+            Err(e) => return Err(format!("Failed to soft-delete {}: {}", merge, e)),
+        }
+
+        let lineage = AuditRecord::new(
+            AuditOperation::MemoryMerged {
+                source_ids: vec![merge],
+                strategy: "consolidation".to_string(),
+            },
+            keep,
+        )
+        .with_rationale(format!("Absorbed near-duplicate {}", merge));
+        if let Err(e) = self.teleological_store.append_audit_record(&lineage).await {
+            warn!(
+                kept_id = %keep,
+                error = %e,
+                "trigger_consolidation: Failed to append survivor audit record (merge completed)"
+            );
+        }
+
+        let lineage_record =
+            LineageRecord::new(keep, vec![merge], LineageOperation::Consolidation, similarity);
+        if let Err(e) = self.teleological_store.append_lineage_record(&lineage_record).await {
+            warn!(
+                kept_id = %keep,
+                error = %e,
+                "trigger_consolidation: Failed to write lineage record (merge completed)"
+            );
+        }
+
+        debug!(
+            kept_id = %keep,
+            merged_id = %merge,
+            similarity,
+            edges_retargeted,
+            "trigger_consolidation: Pair consolidated"
+        );
+        Ok(ConsolidatedPair {
+            kept_id: keep,
+            merged_id: merge,
+            similarity,
+            edges_retargeted,
+        })
+    }
+
+    /// Move every typed and K-NN edge touching `from` onto `to`.
+    ///
+    /// Edges between the pair collapse and are dropped; where `to` already has
+    /// an edge to the same node, the survivor's edge wins. Returns the number of
+    /// edges re-pointed. A no-op when graph linking is disabled.
+    pub(in crate::handlers) fn retarget_edges(
+        &self,
+        from: Uuid,
+        to: Uuid,
+    ) -> Result<usize, String> {
+        let Some(repo) = &self.edge_repository else {
+            return Ok(0);
+        };
+        let mut retargeted = 0usize;
+
+        // Typed edges, outgoing and incoming
+        let mut typed = repo
+            .get_typed_edges_from(from)
+            .map_err(|e| format!("Failed to read typed edges from {}: {}", from, e))?;
+        typed.extend(
+            repo.get_typed_edges_to(from)
+                .map_err(|e| format!("Failed to read typed edges to {}: {}", from, e))?,
+        );
+        for edge in typed {
+            repo.delete_typed_edge(edge.source(), edge.target())
+                .map_err(|e| format!("Failed to delete typed edge: {}", e))?;
+            let source = if edge.source() == from {
+                to
+            } else {
+                edge.source()
+            };
+            let target = if edge.target() == from {
+                to
+            } else {
+                edge.target()
+            };
+            let exists = repo
+                .get_typed_edge(source, target)
+                .map_err(|e| format!("Failed to read typed edge: {}", e))?
+                .is_some();
+            if source == target || exists {
+                continue;
+            }
+            let moved = TypedEdge::new(
+                source,
+                target,
+                edge.edge_type(),
+                edge.weight(),
+                edge.direction(),
+                *edge.embedder_scores(),
+                edge.agreement_count(),
+                edge.agreeing_embedders(),
+            )
+            .map_err(|e| format!("Failed to rebuild typed edge: {}", e))?;
+            repo.store_typed_edge(&moved)
+                .map_err(|e| format!("Failed to store typed edge: {}", e))?;
+            retargeted += 1;
+        }
+
+        // K-NN edges: drop the duplicate's own lists (the survivor's lists
+        // already cover its neighbourhood) and re-point inbound references
+        for embedder_id in 0..13u8 {
+            repo.delete_embedder_edges(embedder_id, from)
+                .map_err(|e| format!("Failed to delete E{} edges: {}", embedder_id + 1, e))?;
+
+            let inbound: Vec<(Uuid, Vec<EmbedderEdge>)> = repo
+                .iter_embedder_edges(embedder_id)
+                .map_err(|e| format!("Failed to scan E{} edges: {}", embedder_id + 1, e))?
+                .filter(|item| {
+                    item.as_ref()
+                        .map_or(true, |(_, edges)| edges.iter().any(|e| e.target() == from))
+                })
+                .collect::<Result<_, _>>()
+                .map_err(|e| format!("Failed to scan E{} edges: {}", embedder_id + 1, e))?;
+
+            for (source, edges) in inbound {
+                let links_survivor = source == to || edges.iter().any(|e| e.target() == to);
+                let mut rewritten = Vec::with_capacity(edges.len());
+                for edge in edges {
+                    if edge.target() != from {
+                        rewritten.push(edge);
+                    } else if !links_survivor {
+                        let moved = EmbedderEdge::with_direction(
+                            source,
+                            to,
+                            embedder_id,
+                            edge.similarity(),
+                            edge.direction(),
+                        )
+                        .map_err(|e| {
+                            format!("Failed to rebuild E{} edge: {}", embedder_id + 1, e)
+                        })?;
+                        rewritten.push(moved);
+                        retargeted += 1;
+                    }
+                }
+                repo.store_embedder_edges(embedder_id, source, &rewritten)
+                    .map_err(|e| format!("Failed to store E{} edges: {}", embedder_id + 1, e))?;
+            }
+        }
+
+        Ok(retargeted)
+    }
 }
@@ -10,6 +10,8 @@
 //! - BR-MCP-002: boost_importance clamps final value to [0.0, 1.0]
 
 use chrono::Utc;
+use context_graph_core::importance::ImportanceModel;
+use context_graph_core::traits::AccessStats;
 use tracing::{debug, error, info, warn};
 
 use crate::protocol::{JsonRpcId, JsonRpcResponse};
@@ -189,14 +191,27 @@ impl Handlers {
             }
         };
 
-        // Read actual importance field from fingerprint (not computed from access_count)
-        let old_importance = fingerprint.importance;
+        // Boost the importance search ranks by: decayed, plus the access
+        // bonus, not the stale stored value
+        let now = Utc::now();
+        let model = ImportanceModel::default();
+        let access = match self.teleological_store.access_stats(&[node_id]).await {
+            Ok(mut stats) => stats.pop().unwrap_or_default(),
+            Err(e) => {
+                warn!(error = %e, node_id = %node_id, "boost_importance: Access stats unavailable, boosting without access bonus");
+                AccessStats::default()
+            }
+        };
+        let old_importance = model.effective_with_access_at(&fingerprint, &access, now);
+        let access_bonus = model.access_bonus_at(&access, now);
 
         // Apply delta and clamp to [0.0, 1.0] per BR-MCP-002
         let (new_importance, clamped) = request.apply_delta(old_importance);
 
-        // Update the importance field directly
-        fingerprint.importance = new_importance;
+        // Store the boosted value without the access bonus, which is added
+        // back from live stats at ranking time, and restart the decay clock
+        fingerprint.importance = (new_importance - access_bonus).clamp(0.0, 1.0);
+        fingerprint.last_updated = now;
 
         debug!(
             node_id = %node_id,
@@ -204,12 +219,9 @@ impl Handlers {
             delta = request.delta,
             new_importance = new_importance,
             clamped = clamped,
-            "boost_importance: Updated importance field directly"
+            "boost_importance: Applied explicit boost"
         );
 
-        // Update last_updated timestamp
-        fingerprint.last_updated = Utc::now();
-
         // Persist the updated fingerprint
         match self.teleological_store.update(fingerprint).await {
             Ok(true) => {
@@ -7,14 +7,20 @@
 //! 1. Add the tool name constant to `tools/names.rs`
 //! 2. Add the handler method `call_X(id, args)` to the relevant `*_tools.rs`
 //! 3. Add one line to the `tool_dispatch!` invocation below
+//!
+//! Arguments are checked against the tool's published `inputSchema` before
+//! dispatch (see `tools::validation`), then the tool is checked against the
+//! GPU degradation policy (see `capability_policy`). Mutating tools that
+//! reach their handler are recorded in the tool audit log.
 
 use serde_json::json;
 use tracing::debug;
 
 use crate::protocol::{error_codes, JsonRpcId, JsonRpcResponse};
-use crate::tools::{get_tool_definitions, tool_names};
+use crate::tools::{get_tool_definitions, tool_names, validation};
 
-use super::super::Handlers;
+use super::super::{CancellationFlag, Handlers, RequestContext};
+use super::capability_policy::{capability_unavailable_response, mark_degraded, CapabilityGate};
 
 /// Dispatch tool calls to handler methods via generated match.
 ///
@@ -47,6 +53,7 @@ impl Handlers {
         &self,
         id: Option<JsonRpcId>,
         params: Option<serde_json::Value>,
+        ctx: RequestContext,
     ) -> JsonRpcResponse {
         let params = match params {
             Some(p) => p,
@@ -84,18 +91,72 @@ impl Handlers {
             }
         );
 
-        tool_dispatch!(self, id, tool_name,
+        // Reject arguments the published inputSchema does not allow (unknown
+        // fields, wrong types) instead of letting handlers silently ignore them
+        if let Some(schema) = validation::input_schema(tool_name) {
+            let errors = validation::validate_arguments(schema, &arguments);
+            if !errors.is_empty() {
+                debug!(
+                    tool = tool_name,
+                    ?errors,
+                    "Tool arguments failed schema validation"
+                );
+                return self.tool_schema_error(id, tool_name, &errors);
+            }
+        }
+
+        // Tools that embed fail fast with RETRY_LATER while the models they
+        // need are still loading
+        if let Some(not_ready) = self.check_model_readiness(&id, tool_name) {
+            return not_ready;
+        }
+
+        // GPU-only tools are refused without their capabilities; tools with
+        // a CPU path run and are flagged degraded
+        let degraded = match self.check_capabilities(tool_name) {
+            CapabilityGate::Available => None,
+            CapabilityGate::Degraded(missing) => Some(missing),
+            CapabilityGate::Unavailable(missing) => {
+                return capability_unavailable_response(id, tool_name, &missing);
+            }
+        };
+
+        // Per-session rate limit and category concurrency cap; the permit is
+        // held until the handler returns
+        let _permit = match self.dispatch_limiter.admit(tool_name, ctx.session()).await {
+            Ok(permit) => permit,
+            Err(busy) => return busy.into_response(id),
+        };
+
+        // Register for notifications/cancelled; unregistered when the call returns
+        let (cancel, _in_flight) = match &id {
+            Some(request_id) => {
+                let (flag, guard) = self.in_flight.register(request_id);
+                (flag, Some(guard))
+            }
+            None => (CancellationFlag::default(), None),
+        };
+        let progress = Self::progress_reporter(&params, &ctx, cancel);
+
+        // Mutating tools are audited (see core::tool_audit); the arguments
+        // are redacted here because dispatch consumes them
+        let audit = self.begin_tool_audit(tool_name, &arguments, &ctx);
+
+        let mut response = tool_dispatch!(self, id, tool_name,
             // Core tools (PRD Section 10.1)
             tool_names::STORE_MEMORY => call_store_memory(arguments),
-            tool_names::GET_MEMETIC_STATUS => call_get_memetic_status(),
+            tool_names::STORE_MEMORIES_BATCH => call_store_memories_batch(arguments),
+            tool_names::GET_MEMETIC_STATUS => call_get_memetic_status(arguments),
             tool_names::SEARCH_GRAPH => call_search_graph(arguments),
+            tool_names::SEARCH_GRAPH_BATCH => call_search_graph_batch(arguments),
             // Consolidation tools
-            tool_names::TRIGGER_CONSOLIDATION => call_trigger_consolidation(arguments),
+            tool_names::TRIGGER_CONSOLIDATION => call_trigger_consolidation(arguments, progress),
             // Topic tools (PRD Section 10.2)
             tool_names::GET_TOPIC_PORTFOLIO => call_get_topic_portfolio(arguments),
             tool_names::GET_TOPIC_STABILITY => call_get_topic_stability(arguments),
-            tool_names::DETECT_TOPICS => call_detect_topics(arguments),
+            tool_names::DETECT_TOPICS => call_detect_topics(arguments, progress),
             tool_names::GET_DIVERGENCE_ALERTS => call_get_divergence_alerts(arguments),
+            tool_names::ACKNOWLEDGE_DIVERGENCE_ALERT => call_acknowledge_divergence_alert(arguments),
             // Curation tools (PRD Section 10.3)
             tool_names::MERGE_CONCEPTS => call_merge_concepts(arguments),
             tool_names::FORGET_CONCEPT => call_forget_concept(arguments),
@@ -126,6 +187,8 @@ impl Handlers {
             tool_names::VALIDATE_GRAPH_LINK => call_validate_graph_link(arguments),
             // Keyword tools (E6)
             tool_names::SEARCH_BY_KEYWORDS => call_search_by_keywords(arguments),
+            // Query tools (structured DSL)
+            tool_names::QUERY_MEMORIES => call_query_memories(arguments),
             // Code tools (E7)
             tool_names::SEARCH_CODE => call_search_code(arguments),
             // Robustness tools (E9)
@@ -158,12 +221,31 @@ impl Handlers {
             tool_names::GET_UNIFIED_NEIGHBORS => call_get_unified_neighbors(arguments),
             // Maintenance tools
             tool_names::REPAIR_CAUSAL_RELATIONSHIPS => call_repair_causal_relationships(),
+            tool_names::CREATE_BACKUP => call_create_backup(arguments),
+            // Snapshot tools
+            tool_names::EXPORT_MEMORIES => call_export_memories(arguments),
+            tool_names::IMPORT_MEMORIES => call_import_memories(arguments),
+            // Staging tools
+            tool_names::PROMOTE_STAGED => call_promote_staged(arguments),
+            tool_names::END_STAGED_SESSION => call_end_staged_session(arguments),
             // Provenance tools (Phase P3)
             tool_names::GET_AUDIT_TRAIL => call_get_audit_trail(arguments),
             tool_names::GET_MERGE_HISTORY => call_get_merge_history(arguments),
             tool_names::GET_PROVENANCE_CHAIN => call_get_provenance_chain(arguments),
+            tool_names::GET_MEMORY_LINEAGE => call_get_memory_lineage(arguments),
+            tool_names::QUERY_AUDIT_LOG => call_query_audit_log(arguments),
             // Daemon tools (Multi-agent observability)
             tool_names::DAEMON_STATUS => call_daemon_status(),
-        )
+            tool_names::GET_EMBEDDING_STATUS => call_get_embedding_status(),
+        );
+
+        if let Some(audit) = audit {
+            self.finish_tool_audit(audit, &response).await;
+        }
+        self.status_aggregator.after_tool_call(tool_name);
+        if let Some(missing) = degraded {
+            mark_degraded(&mut response, &missing);
+        }
+        response
     }
 }
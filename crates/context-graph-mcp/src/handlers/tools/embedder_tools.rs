@@ -377,6 +377,8 @@ impl Handlers {
                     error!(error = %e, "get_embedder_clusters: Auto-recluster failed");
                     return self.tool_error_typed(id, ToolErrorKind::Execution, &format!("Auto-recluster failed: {}", e));
                 }
+                drop(cm);
+                self.notify_topics_updated();
             }
         }
 
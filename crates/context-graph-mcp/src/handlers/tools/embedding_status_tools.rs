@@ -0,0 +1,195 @@
+//! Embedding model readiness: the get_embedding_status tool and the
+//! RETRY_LATER check applied before embedding tools are dispatched.
+//!
+//! Both read the `ProviderHealth` shared with the model loader. Without one
+//! (stdio tests, in-process callers) every model is treated as ready.
+//!
+//! Also builds the SERVER_BUSY response for embedding backpressure, returned
+//! when the provider's per-embedder queues are full.
+
+use std::sync::Arc;
+
+use serde_json::json;
+use tracing::debug;
+
+use context_graph_embeddings::ModelId;
+
+use crate::adapters::provider_health::ModelLoadState;
+use crate::adapters::ProviderHealth;
+use crate::protocol::{error_codes, JsonRpcId, JsonRpcResponse};
+use crate::tools::tool_names;
+
+use super::super::{Handlers, ToolCategory};
+
+/// Retry hint when no load duration has been observed yet.
+const DEFAULT_RETRY_AFTER_MS: u64 = 1_000;
+
+/// Lower bound for the retry hint so clients don't spin on a nearly-done load.
+const MIN_RETRY_AFTER_MS: u64 = 100;
+
+/// `SERVER_BUSY` error for a request rejected by embedding backpressure.
+pub(crate) fn embedding_backpressure_response(
+    id: Option<JsonRpcId>,
+    queue_depth: usize,
+    retry_after_ms: u64,
+) -> JsonRpcResponse {
+    JsonRpcResponse::error_with_data(
+        id,
+        error_codes::SERVER_BUSY,
+        format!(
+            "Server busy: {} embedding requests queued; retry after {} ms",
+            queue_depth, retry_after_ms
+        ),
+        json!({
+            "reason": "embedding_backpressure",
+            "queueDepth": queue_depth,
+            "retryAfterMs": retry_after_ms
+        }),
+    )
+}
+
+/// Embedding models a tool needs, or `None` if it never embeds.
+///
+/// Searches and stores compute full 13-embedder fingerprints, so they need
+/// every production model.
+pub(super) fn required_models(tool: &str) -> Option<&'static [ModelId]> {
+    match tool {
+        tool_names::STORE_MEMORY | tool_names::STORE_MEMORIES_BATCH => Some(ModelId::production()),
+        _ if ToolCategory::of(tool) == Some(ToolCategory::Search) => Some(ModelId::production()),
+        _ => None,
+    }
+}
+
+impl Handlers {
+    /// Share the model loader's per-model state with the handlers.
+    ///
+    /// Must be called before wrapping Handlers in Arc.
+    pub(crate) fn set_provider_health(&mut self, health: Arc<ProviderHealth>) {
+        self.provider_health = Some(health);
+    }
+
+    /// RETRY_LATER response if `tool` needs embedding models that are not
+    /// ready, `None` if the call can proceed.
+    pub(crate) fn check_model_readiness(
+        &self,
+        id: &Option<JsonRpcId>,
+        tool: &str,
+    ) -> Option<JsonRpcResponse> {
+        let health = self.provider_health.as_ref()?;
+        let required = required_models(tool)?;
+        let blocking = health.blocking_models(required);
+        if blocking.is_empty() {
+            return None;
+        }
+
+        let blocking_names: Vec<&str> = blocking.iter().map(|m| m.as_str()).collect();
+        let failed_names: Vec<&str> = blocking
+            .iter()
+            .filter(|&&m| health.state(m).is_failed())
+            .map(|m| m.as_str())
+            .collect();
+        let retry_after_ms = health
+            .estimated_remaining()
+            .map(|eta| (eta.as_millis() as u64).max(MIN_RETRY_AFTER_MS))
+            .unwrap_or(DEFAULT_RETRY_AFTER_MS);
+        debug!(
+            tool,
+            blocking = blocking_names.len(),
+            retry_after_ms,
+            "Embedding models not ready"
+        );
+
+        Some(JsonRpcResponse::error_with_data(
+            id.clone(),
+            error_codes::RETRY_LATER,
+            format!(
+                "Embedding models not ready for {}: {}; retry after {} ms",
+                tool,
+                blocking_names.join(", "),
+                retry_after_ms
+            ),
+            json!({
+                "reason": "models_loading",
+                "blockingModels": blocking_names,
+                "failedModels": failed_names,
+                "retryAfterMs": retry_after_ms
+            }),
+        ))
+    }
+
+    /// Handle get_embedding_status tool call.
+    ///
+    /// Returns each production model's load state, aggregate readiness, the
+    /// estimated time until every model is ready, per-embedder queue depth
+    /// against capacity, the active determinism mode (`null` when off), and
+    /// the latest HNSW index build progress per space.
+    pub(crate) async fn call_get_embedding_status(&self, id: Option<JsonRpcId>) -> JsonRpcResponse {
+        let Some(health) = &self.provider_health else {
+            // No tracker: the provider was handed over fully loaded
+            let ready = self.multi_array_provider.is_ready();
+            return self.tool_result(
+                id,
+                json!({
+                    "ready": ready,
+                    "tracked": false,
+                    "models": [],
+                    "estimatedRemainingMs": null,
+                    "queues": self.multi_array_provider.queue_depths(),
+                    "determinism": self.multi_array_provider.determinism(),
+                    "indexBuild": []
+                }),
+            );
+        };
+
+        let models = health.snapshot();
+        let count =
+            |pred: fn(&ModelLoadState) -> bool| models.iter().filter(|m| pred(&m.state)).count();
+        let ready_count = count(ModelLoadState::is_ready);
+        let failed_count = count(ModelLoadState::is_failed);
+
+        self.tool_result(
+            id,
+            json!({
+                "ready": ready_count == models.len(),
+                "tracked": true,
+                "readyCount": ready_count,
+                "failedCount": failed_count,
+                "totalModels": models.len(),
+                "estimatedRemainingMs": health
+                    .estimated_remaining()
+                    .map(|eta| eta.as_millis() as u64),
+                "models": models,
+                "queues": self.multi_array_provider.queue_depths(),
+                "determinism": self.multi_array_provider.determinism(),
+                "indexBuild": health.index_build_snapshot()
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_models() {
+        assert_eq!(
+            required_models(tool_names::STORE_MEMORY).map(|m| m.len()),
+            Some(13)
+        );
+        assert!(required_models(tool_names::SEARCH_GRAPH).is_some());
+        assert!(required_models(tool_names::GET_MEMETIC_STATUS).is_none());
+        assert!(required_models(tool_names::GET_EMBEDDING_STATUS).is_none());
+    }
+
+    #[test]
+    fn test_embedding_backpressure_response() {
+        let response = embedding_backpressure_response(Some(JsonRpcId::Number(7)), 10, 50);
+        let error = response.error.expect("error response");
+        assert_eq!(error.code, error_codes::SERVER_BUSY);
+        let data = error.data.expect("data");
+        assert_eq!(data["reason"], "embedding_backpressure");
+        assert_eq!(data["queueDepth"], 10);
+        assert_eq!(data["retryAfterMs"], 50);
+    }
+}
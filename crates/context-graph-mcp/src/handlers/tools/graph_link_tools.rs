@@ -1256,31 +1256,12 @@ struct CandidateInfo {
 
 /// Convert string edge type to GraphLinkEdgeType.
 fn string_to_edge_type(s: &str) -> Option<GraphLinkEdgeType> {
-    match s {
-        "semantic_similar" => Some(GraphLinkEdgeType::SemanticSimilar),
-        "code_related" => Some(GraphLinkEdgeType::CodeRelated),
-        "entity_shared" => Some(GraphLinkEdgeType::EntityShared),
-        "causal_chain" => Some(GraphLinkEdgeType::CausalChain),
-        "graph_connected" => Some(GraphLinkEdgeType::GraphConnected),
-        "paraphrase_aligned" => Some(GraphLinkEdgeType::ParaphraseAligned),
-        "keyword_overlap" => Some(GraphLinkEdgeType::KeywordOverlap),
-        "multi_agreement" => Some(GraphLinkEdgeType::MultiAgreement),
-        _ => None,
-    }
+    GraphLinkEdgeType::from_name(s)
 }
 
 /// Convert GraphLinkEdgeType to string representation.
 fn edge_type_to_string(edge_type: GraphLinkEdgeType) -> String {
-    match edge_type {
-        GraphLinkEdgeType::SemanticSimilar => "semantic_similar".to_string(),
-        GraphLinkEdgeType::CodeRelated => "code_related".to_string(),
-        GraphLinkEdgeType::EntityShared => "entity_shared".to_string(),
-        GraphLinkEdgeType::CausalChain => "causal_chain".to_string(),
-        GraphLinkEdgeType::GraphConnected => "graph_connected".to_string(),
-        GraphLinkEdgeType::ParaphraseAligned => "paraphrase_aligned".to_string(),
-        GraphLinkEdgeType::KeywordOverlap => "keyword_overlap".to_string(),
-        GraphLinkEdgeType::MultiAgreement => "multi_agreement".to_string(),
-    }
+    edge_type.as_str().to_string()
 }
 
 /// Get contributing embedder names from bitmask.
@@ -1336,9 +1317,11 @@ mod tests {
     fn test_edge_type_conversions_and_helpers() {
         assert_eq!(string_to_edge_type("semantic_similar"), Some(GraphLinkEdgeType::SemanticSimilar));
         assert_eq!(string_to_edge_type("code_related"), Some(GraphLinkEdgeType::CodeRelated));
+        assert_eq!(string_to_edge_type("supersedes"), Some(GraphLinkEdgeType::Supersedes));
         assert_eq!(string_to_edge_type("invalid"), None);
         assert_eq!(edge_type_to_string(GraphLinkEdgeType::SemanticSimilar), "semantic_similar");
         assert_eq!(edge_type_to_string(GraphLinkEdgeType::CausalChain), "causal_chain");
+        assert_eq!(edge_type_to_string(GraphLinkEdgeType::TemporalFollows), "temporal_follows");
         // Contributing embedders from bitmask
         let mask: u16 = 0b0000_0101_0001; // E1, E5, E7
         let embedders = get_contributing_embedders(mask);
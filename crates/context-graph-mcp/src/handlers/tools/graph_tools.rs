@@ -689,11 +689,19 @@ impl Handlers {
         // Fetch memory content and metadata for analysis - FAIL FAST on any error
         let mut memories_for_analysis: Vec<MemoryForGraphAnalysis> = Vec::with_capacity(memory_uuids.len());
 
-        for uuid in &memory_uuids {
-            // Get fingerprint - FAIL FAST on error
-            let fingerprint = match self.teleological_store.retrieve(*uuid).await {
-                Ok(Some(fp)) => fp,
-                Ok(None) => {
+        // Fetch all fingerprints in one batch - FAIL FAST on error
+        let fingerprints = match self.teleological_store.retrieve_batch(&memory_uuids).await {
+            Ok(fps) => fps,
+            Err(e) => {
+                error!(error = %e, "discover_graph_relationships: Failed to fetch memories");
+                return self.tool_error(id, &format!("Failed to fetch memories: {}", e));
+            }
+        };
+
+        for (uuid, fingerprint) in memory_uuids.iter().zip(fingerprints) {
+            let fingerprint = match fingerprint {
+                Some(fp) => fp,
+                None => {
                     error!(uuid = %uuid, "discover_graph_relationships: Memory not found");
                     return self.tool_error(
                         id,
@@ -703,17 +711,6 @@ impl Handlers {
                         ),
                     );
                 }
-                Err(e) => {
-                    error!(uuid = %uuid, error = %e, "discover_graph_relationships: Failed to fetch memory");
-                    return self.tool_error(
-                        id,
-                        &format!(
-                            "Failed to fetch memory {}: {}",
-                            uuid,
-                            e
-                        ),
-                    );
-                }
             };
 
             // Get content - FAIL FAST on error
@@ -1,10 +1,19 @@
 //! MCP tool result and request-parsing helpers.
 
+use std::sync::Arc;
+
+use context_graph_core::error::{error_chain, ContextGraphError, CoreResult, ErrorKind};
+use context_graph_core::teleological::{Embedder, EmbedderGroup, EmbedderMask};
+use context_graph_core::traits::{EmbeddingMetadata, MultiArrayEmbeddingOutput};
+use context_graph_core::types::fingerprint::E7_DIM;
 use serde::de::DeserializeOwned;
 use serde_json::json;
+use tracing::{debug, Instrument};
 
 use crate::protocol::{error_codes, JsonRpcId, JsonRpcResponse};
+use crate::tools::validation::FieldError;
 
+use super::super::core::QueryEmbeddingBundle;
 use super::super::Handlers;
 use super::validate::{Validate, ValidateInto};
 
@@ -28,6 +37,16 @@ pub(crate) enum ToolErrorKind {
     NotFound,
     /// General execution failure (internal error, unexpected state)
     Execution,
+    /// A queue or rate limit is full; the client should retry later
+    Backpressure,
+    /// A model, GPU capability or feature the tool needs is not available
+    CapabilityUnavailable,
+    /// Stored data failed an integrity check
+    Corruption,
+    /// GPU/CUDA failure (OOM, kernel, device)
+    GpuFailure,
+    /// The operation ran out of time
+    Timeout,
 }
 
 impl ToolErrorKind {
@@ -38,6 +57,31 @@ impl ToolErrorKind {
             Self::Storage => (error_codes::STORAGE_ERROR, "STORAGE_ERROR"),
             Self::NotFound => (error_codes::NODE_NOT_FOUND, "NOT_FOUND"),
             Self::Execution => (error_codes::INTERNAL_ERROR, "EXECUTION_ERROR"),
+            Self::Backpressure => (error_codes::SERVER_BUSY, "BACKPRESSURE"),
+            Self::CapabilityUnavailable => (
+                error_codes::CAPABILITY_UNAVAILABLE,
+                "CAPABILITY_UNAVAILABLE",
+            ),
+            Self::Corruption => (error_codes::STORAGE_ERROR, "CORRUPTION"),
+            Self::GpuFailure => (error_codes::GPU_ERROR, "GPU_FAILURE"),
+            Self::Timeout => (error_codes::LAYER_TIMEOUT, "TIMEOUT"),
+        }
+    }
+}
+
+/// The single mapping from the cross-crate [`ErrorKind`] to tool error
+/// categories (and through `code_and_label`, to JSON-RPC codes).
+impl From<ErrorKind> for ToolErrorKind {
+    fn from(kind: ErrorKind) -> Self {
+        match kind {
+            ErrorKind::Backpressure => Self::Backpressure,
+            ErrorKind::CapabilityUnavailable => Self::CapabilityUnavailable,
+            ErrorKind::NotFound => Self::NotFound,
+            ErrorKind::Corruption => Self::Corruption,
+            ErrorKind::GpuFailure => Self::GpuFailure,
+            ErrorKind::InvalidInput => Self::Validation,
+            ErrorKind::Timeout => Self::Timeout,
+            ErrorKind::Internal => Self::Execution,
         }
     }
 }
@@ -100,6 +144,78 @@ impl Handlers {
         )
     }
 
+    /// MCP-compliant tool error from a classified error.
+    ///
+    /// The error's [`ErrorKind`] picks the code and label, and the response
+    /// carries `retryable` so clients know whether to try again. The full
+    /// `source()` chain is logged, so the underlying RocksDB/candle/CUDA
+    /// failure stays visible even when the message shown to the client is
+    /// short.
+    pub(crate) fn tool_error_from(
+        &self,
+        id: Option<JsonRpcId>,
+        tool_name: &str,
+        context: &str,
+        err: impl Into<ContextGraphError>,
+    ) -> JsonRpcResponse {
+        let err = err.into();
+        let kind = err.kind();
+        let retryable = err.is_retryable();
+        tracing::error!(
+            tool = tool_name,
+            kind = %kind,
+            retryable,
+            error_chain = %error_chain(&err),
+            "{}",
+            context
+        );
+        let (code, label) = ToolErrorKind::from(kind).code_and_label();
+        JsonRpcResponse::success(
+            id,
+            json!({
+                "content": [{
+                    "type": "text",
+                    "text": format!("[{} {}] {}: {}", label, code, context, err)
+                }],
+                "isError": true,
+                "errorCode": code,
+                "retryable": retryable
+            }),
+        )
+    }
+
+    /// Validation error for arguments that do not match the tool's input schema.
+    ///
+    /// Same shape as `tool_error_typed(.., ToolErrorKind::Validation, ..)` plus
+    /// an `errors` array with `path`, `expected` and `message` per field.
+    pub(crate) fn tool_schema_error(
+        &self,
+        id: Option<JsonRpcId>,
+        tool: &str,
+        errors: &[FieldError],
+    ) -> JsonRpcResponse {
+        let (code, label) = ToolErrorKind::Validation.code_and_label();
+        let details: Vec<String> = errors
+            .iter()
+            .map(|e| format!("{}: {}", e.path, e.message))
+            .collect();
+        JsonRpcResponse::success(
+            id,
+            json!({
+                "content": [{
+                    "type": "text",
+                    "text": format!(
+                        "[{} {}] Invalid arguments for {}: {}",
+                        label, code, tool, details.join("; ")
+                    )
+                }],
+                "isError": true,
+                "errorCode": code,
+                "errors": errors
+            }),
+        )
+    }
+
     /// MCP-compliant tool error helper (untyped convenience).
     ///
     /// For cases where the error category is obvious from context.
@@ -155,14 +271,140 @@ impl Handlers {
         query: &str,
         tool_name: &str,
     ) -> Result<context_graph_core::types::fingerprint::SemanticFingerprint, JsonRpcResponse> {
-        self.multi_array_provider
-            .embed_all(query)
+        self.query_embedding(id, query, EmbedderMask::all(), tool_name)
             .await
-            .map(|output| output.fingerprint)
-            .map_err(|e| {
-                tracing::error!("[{}] Embedding failed: {}", tool_name, e);
-                self.tool_error(id, &format!("Embedding failed: {}", e))
-            })
+            .map(|bundle| bundle.fingerprint.clone())
+    }
+
+    /// Embed a query for the spaces in `mask`, once per query text.
+    ///
+    /// Reuses a recent bundle of the same text whose mask covers `mask`, and
+    /// otherwise computes one with `embed_selective` and caches it. Runs in a
+    /// `query_embedding` span recording the spaces, whether the cache was hit,
+    /// and the provider latency of the bundle.
+    pub(crate) async fn query_embedding(
+        &self,
+        id: Option<JsonRpcId>,
+        query: &str,
+        mask: EmbedderMask,
+        tool_name: &str,
+    ) -> Result<Arc<QueryEmbeddingBundle>, JsonRpcResponse> {
+        let span = tracing::info_span!(
+            "query_embedding",
+            tool = tool_name,
+            spaces = mask.count(),
+            cache_hit = tracing::field::Empty,
+            provider_ms = tracing::field::Empty,
+        );
+        async {
+            let cached = self.query_embeddings.get(query, mask);
+            let cache_hit = cached.is_some();
+            let bundle = match cached {
+                Some(bundle) => bundle,
+                None => {
+                    let output = self
+                        .multi_array_provider
+                        .embed_selective(query, mask)
+                        .await
+                        .map_err(|e| self.tool_error_from(id, tool_name, "Embedding failed", e))?;
+                    let bundle = Arc::new(QueryEmbeddingBundle::from(output));
+                    self.query_embeddings.insert(query, Arc::clone(&bundle));
+                    bundle
+                }
+            };
+            let span = tracing::Span::current();
+            span.record("cache_hit", cache_hit);
+            span.record("provider_ms", bundle.provider_latency.as_millis() as u64);
+            Ok(bundle)
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Embed `content` for storage, reusing a recent query embedding of the
+    /// same text.
+    ///
+    /// When the text was just searched (the hook runs `search_graph` and then
+    /// `inject_context` on the same prompt), the content-only spaces come from
+    /// the cached bundle and only the spaces that read `metadata` are
+    /// computed: E2-E4, plus E5 with a useful causal hint and E12 with
+    /// pruning. Otherwise this is `embed_all_with_metadata`.
+    ///
+    /// With `skip_code` (pure prose) E7 is not run. Its space is stored as a
+    /// zero vector so the fingerprint keeps its fixed layout; HNSW indexing
+    /// passes over zero-norm vectors and every E7 similarity against it is 0.
+    pub(crate) async fn embed_for_storage(
+        &self,
+        content: &str,
+        metadata: EmbeddingMetadata,
+        skip_code: bool,
+    ) -> CoreResult<MultiArrayEmbeddingOutput> {
+        let mut metadata_mask = EmbedderGroup::Temporal.embedders();
+        if metadata.causal_hint.as_ref().is_some_and(|hint| hint.is_useful()) {
+            metadata_mask.set(Embedder::Causal);
+        }
+        if metadata.e12_pruning.is_some() {
+            metadata_mask.set(Embedder::LateInteraction);
+        }
+        let mut content_mask = EmbedderMask::all();
+        for embedder in metadata_mask.iter() {
+            content_mask.unset(embedder);
+        }
+        if skip_code {
+            content_mask.unset(Embedder::Code);
+        }
+
+        let Some(cached) = self.query_embeddings.get(content, content_mask) else {
+            if !skip_code {
+                return self
+                    .multi_array_provider
+                    .embed_all_with_metadata(content, metadata)
+                    .await;
+            }
+            let mut mask = EmbedderMask::all();
+            mask.unset(Embedder::Code);
+            let partial = self
+                .multi_array_provider
+                .embed_selective_with_metadata(content, mask, metadata)
+                .await?;
+            let mut fingerprint = partial.fingerprint;
+            fingerprint.e7_code = vec![0.0; E7_DIM];
+            return Ok(MultiArrayEmbeddingOutput {
+                fingerprint,
+                total_latency: partial.total_latency,
+                per_embedder_latency: partial.per_embedder_latency,
+                model_ids: partial.model_ids,
+                e5_hint_provenance: None,
+            });
+        };
+
+        let mut partial = self
+            .multi_array_provider
+            .embed_selective_with_metadata(content, metadata_mask, metadata)
+            .await?;
+        debug!(
+            spaces = metadata_mask.count(),
+            "embed_for_storage: reusing cached query embedding"
+        );
+
+        let mut fingerprint = cached.fingerprint.clone();
+        let mut per_embedder_latency = cached.per_embedder_latency;
+        for embedder in metadata_mask.iter() {
+            fingerprint.take_embedding(embedder, &mut partial.fingerprint);
+            per_embedder_latency[embedder.index()] =
+                partial.per_embedder_latency[embedder.index()];
+        }
+        if skip_code {
+            fingerprint.e7_code = vec![0.0; E7_DIM];
+            per_embedder_latency[Embedder::Code.index()] = std::time::Duration::ZERO;
+        }
+        Ok(MultiArrayEmbeddingOutput {
+            fingerprint,
+            total_latency: partial.total_latency,
+            per_embedder_latency,
+            model_ids: cached.model_ids.clone(),
+            e5_hint_provenance: None,
+        })
     }
 
     /// Parse JSON args into a typed DTO and run `validate() -> Result<Output, String>`.
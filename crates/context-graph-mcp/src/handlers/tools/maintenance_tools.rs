@@ -1,14 +1,68 @@
-//! Maintenance tool handlers for data repair and cleanup.
+//! Maintenance tool handlers for data repair, cleanup and backups.
 
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde::Deserialize;
 use serde_json::json;
 use tracing::{debug, error, info};
 use uuid::Uuid;
 
 use context_graph_core::types::audit::{AuditOperation, AuditRecord};
+use context_graph_storage::teleological::{BackupOptions, RocksDbTeleologicalStore};
 
 use crate::handlers::Handlers;
 use crate::protocol::{JsonRpcId, JsonRpcResponse};
 
+use super::helpers::ToolErrorKind;
+use super::validate::Validate;
+
+/// Longest accepted backup name.
+const MAX_BACKUP_NAME_LEN: usize = 128;
+
+/// Request for create_backup.
+#[derive(Debug, Deserialize)]
+pub struct CreateBackupRequest {
+    /// Subdirectory of the backup root to create (default: UTC timestamp).
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Earlier backup under the same root to link unchanged SST files to.
+    #[serde(default)]
+    pub incremental_from: Option<String>,
+}
+
+/// Backup names are single path components under the configured root.
+fn validate_backup_name(field: &str, name: &str) -> Result<(), String> {
+    let valid_chars = name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if name.is_empty() || name.len() > MAX_BACKUP_NAME_LEN || !valid_chars || name.starts_with('.')
+    {
+        return Err(format!(
+            "{} must be 1-{} characters of [A-Za-z0-9._-] not starting with '.', got '{}'",
+            field, MAX_BACKUP_NAME_LEN, name
+        ));
+    }
+    Ok(())
+}
+
+impl Validate for CreateBackupRequest {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(name) = &self.name {
+            validate_backup_name("name", name)?;
+        }
+        if let Some(base) = &self.incremental_from {
+            validate_backup_name("incremental_from", base)?;
+            if self.name.as_ref() == Some(base) {
+                return Err("incremental_from must name a different backup".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Handlers {
     /// Handle repair_causal_relationships tool call.
     ///
@@ -75,4 +129,87 @@ impl Handlers {
             }
         }
     }
+
+    /// Handle create_backup tool call.
+    ///
+    /// Writes a consistent backup (RocksDB checkpoint, HNSW snapshot and
+    /// checksummed manifest) into `<backup.root>/<name>` while the server
+    /// keeps serving. Refused unless `[mcp.backup] enabled = true`; paths
+    /// outside the configured root cannot be named.
+    pub(crate) async fn call_create_backup(
+        &self,
+        id: Option<JsonRpcId>,
+        args: serde_json::Value,
+    ) -> JsonRpcResponse {
+        if !self.backup.enabled {
+            return self.tool_error_typed(
+                id,
+                ToolErrorKind::CapabilityUnavailable,
+                "create_backup is disabled. Set [mcp.backup] enabled = true to allow it.",
+            );
+        }
+        let request: CreateBackupRequest =
+            match self.parse_request(id.clone(), args, "create_backup") {
+                Ok(req) => req,
+                Err(resp) => return resp,
+            };
+
+        let root = PathBuf::from(&self.backup.root);
+        let name = request
+            .name
+            .unwrap_or_else(|| Utc::now().format("%Y%m%dT%H%M%S%.3fZ").to_string());
+        let dir = root.join(&name);
+        let options = BackupOptions {
+            incremental_from: request.incremental_from.as_ref().map(|base| root.join(base)),
+        };
+        debug!(dir = %dir.display(), ?options, "create_backup: Starting backup");
+
+        let store = Arc::clone(&self.teleological_store);
+        let backup_dir = dir.clone();
+        let outcome = tokio::task::spawn_blocking(move || {
+            let Some(rocksdb_store) = store.as_any().downcast_ref::<RocksDbTeleologicalStore>()
+            else {
+                return Err("Store does not support backups. Only RocksDbTeleologicalStore \
+                            supports this operation."
+                    .to_string());
+            };
+            rocksdb_store
+                .create_backup(&backup_dir, &options)
+                .map_err(|e| e.to_string())
+        })
+        .await;
+
+        match outcome {
+            Ok(Ok(report)) => {
+                info!(
+                    path = %report.path.display(),
+                    files = report.files,
+                    bytes = report.bytes,
+                    linked = report.linked_files,
+                    "create_backup: Backup complete"
+                );
+                self.tool_result(
+                    id,
+                    json!({
+                        "name": name,
+                        "path": report.path.display().to_string(),
+                        "incrementalFrom": request.incremental_from,
+                        "files": report.files,
+                        "bytes": report.bytes,
+                        "linkedFiles": report.linked_files,
+                        "hnswFiles": report.hnsw_files,
+                        "elapsedMs": report.elapsed_ms,
+                    }),
+                )
+            }
+            Ok(Err(e)) => {
+                error!(error = %e, dir = %dir.display(), "create_backup: Backup failed");
+                self.tool_error_typed(id, ToolErrorKind::Storage, &format!("Backup failed: {}", e))
+            }
+            Err(e) => {
+                error!(error = %e, "create_backup: Backup task panicked");
+                self.tool_error_typed(id, ToolErrorKind::Execution, &format!("Backup failed: {}", e))
+            }
+        }
+    }
 }
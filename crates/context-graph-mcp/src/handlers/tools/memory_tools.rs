@@ -32,30 +32,49 @@ use context_graph_core::causal::asymmetric::{
     apply_causal_gate, causal_gate, compute_e5_asymmetric_fingerprint_similarity,
     detect_causal_query_intent, CausalDirection,
 };
+use context_graph_core::code::{ContentClassification, ContentClassifier, ContentType};
+use context_graph_core::error::{CoreError, CoreResult};
+use context_graph_core::graph_linking::{
+    extract_subgraph, GraphLinkEdgeType, SubgraphNode, SubgraphOptions, TraversalFilter,
+};
+use context_graph_core::importance::ImportanceModel;
+use context_graph_core::retrieval::{
+    resolve_search_profile, DomainSource, ResolvedSearchProfile, SearchDomain,
+};
 use context_graph_core::types::audit::{AuditOperation, AuditRecord};
 use context_graph_core::teleological::matrix_search::embedder_names;
-use context_graph_core::traits::{EmbeddingMetadata, SearchStrategy, TeleologicalSearchOptions};
+use context_graph_core::teleological::Embedder;
+use context_graph_core::traits::{
+    AccessStats, EmbeddingHintProvenance, EmbeddingMetadata, SearchStrategy, TeleologicalSearchOptions,
+};
 use context_graph_core::types::fingerprint::{SemanticFingerprint, TeleologicalFingerprint, NUM_EMBEDDERS};
 use context_graph_core::types::{SourceMetadata, SourceType};
+use context_graph_storage::EdgeInferenceReport;
 
 use crate::weights::{get_effective_weight_profile, apply_e11_disable, E11_ENTITY_ENABLED};
 
 use crate::protocol::JsonRpcId;
 use crate::protocol::JsonRpcResponse;
 
+use super::chunked_store_tools::{parse_chunking, ChunkedDocument};
+use super::embedding_status_tools::embedding_backpressure_response;
 use super::graph_link_dtos::{RRF_K, EMBEDDER_NAMES, embedder_name_to_index};
 use super::helpers::{ToolErrorKind, compute_position_label};
 use super::super::Handlers;
 
 // Validation constants for store_memory rationale (merged from inject_context)
 // When rationale is provided, validate: 1-1024 chars
-const MIN_RATIONALE_LEN: usize = 1;
-const MAX_RATIONALE_LEN: usize = 1024;
+pub(super) const MIN_RATIONALE_LEN: usize = 1;
+pub(super) const MAX_RATIONALE_LEN: usize = 1024;
 
 // Validation constants for search_graph (BUG-001)
 // Per PRD Section 10: topK must be 1-100
 const MIN_TOP_K: u64 = 1;
-const MAX_TOP_K: u64 = 100;
+pub(super) const MAX_TOP_K: u64 = 100;
+pub(super) const DEFAULT_TOP_K: u64 = 10;
+
+// Validation constant for the optional namespace argument (store_memory, search_graph)
+const MAX_NAMESPACE_LEN: usize = TeleologicalFingerprint::MAX_NAMESPACE_LEN;
 
 // E5 Causal Direction inference threshold
 // Per Phase 5: Infer causal direction from E5 embedding norms
@@ -72,7 +91,7 @@ const CAUSAL_DIRECTION_THRESHOLD: f32 = 0.1;
 /// - "cause" if cause vector has significantly higher variance (>10% difference)
 /// - "effect" if effect vector has significantly higher variance
 /// - "unknown" if variances are similar or both are near zero
-fn infer_causal_direction_from_fingerprint(fingerprint: &SemanticFingerprint) -> String {
+pub(super) fn infer_causal_direction_from_fingerprint(fingerprint: &SemanticFingerprint) -> String {
     let cause_variance = super::helpers::component_variance_f32(&fingerprint.e5_causal_as_cause);
     let effect_variance = super::helpers::component_variance_f32(&fingerprint.e5_causal_as_effect);
 
@@ -92,6 +111,79 @@ fn infer_causal_direction_from_fingerprint(fingerprint: &SemanticFingerprint) ->
     }
 }
 
+/// Parse the optional `namespace` argument, defaulting to the default namespace.
+///
+/// Namespaces must be 1-64 characters of ASCII alphanumerics, '-', '_' or '.'.
+pub(super) fn parse_namespace(args: &serde_json::Value) -> Result<String, String> {
+    match args.get("namespace") {
+        None => Ok(TeleologicalFingerprint::DEFAULT_NAMESPACE.to_string()),
+        Some(v) => {
+            let ns = v
+                .as_str()
+                .ok_or_else(|| "namespace must be a string".to_string())?;
+            let valid_chars = ns
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+            if ns.is_empty() || ns.len() > MAX_NAMESPACE_LEN || !valid_chars {
+                return Err(format!(
+                    "namespace must be 1-{} characters of [A-Za-z0-9._-], got '{}'",
+                    MAX_NAMESPACE_LEN, ns
+                ));
+            }
+            Ok(ns.to_string())
+        }
+    }
+}
+
+/// An existing memory whose E1 similarity to new content reaches the
+/// domain's `theta_dup`.
+#[derive(Debug, Clone)]
+pub(super) struct NearDuplicate {
+    pub fingerprint_id: uuid::Uuid,
+    /// E1 similarity on the search scale, as compared to `theta_dup`
+    pub similarity: f32,
+    pub theta_dup: f32,
+    pub domain: SearchDomain,
+    pub domain_source: DomainSource,
+}
+
+impl NearDuplicate {
+    /// The `duplicateOf` object of a store_memory response.
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "fingerprintId": self.fingerprint_id.to_string(),
+            "similarity": self.similarity,
+            "thetaDup": self.theta_dup,
+            "domain": self.domain.as_str(),
+            "domainSource": self.domain_source,
+        })
+    }
+}
+
+/// Provenance recorded alongside a newly stored memory.
+pub(super) struct StoredMemoryProvenance<'a> {
+    pub session_id: Option<String>,
+    pub session_sequence: u64,
+    pub causal_direction: String,
+    pub operator_id: Option<String>,
+    pub rationale: Option<&'a str>,
+    pub importance: f32,
+    pub embedding_hint_provenance: Option<EmbeddingHintProvenance>,
+    pub model_ids: [String; NUM_EMBEDDERS],
+    pub embedding_latency: std::time::Duration,
+    pub chunk: Option<ChunkProvenance>,
+    pub content_classification: Option<ContentClassification>,
+}
+
+/// Position of a chunk within its parent document (chunked store_memory).
+pub(super) struct ChunkProvenance {
+    pub document_id: uuid::Uuid,
+    pub chunk_index: u32,
+    pub total_chunks: u32,
+    pub start_line: u32,
+    pub end_line: u32,
+}
+
 impl Handlers {
     /// store_memory tool implementation.
     ///
@@ -102,6 +194,26 @@ impl Handlers {
     ///
     /// Note: inject_context was merged into this tool. When `rationale` is provided,
     /// the same validation (1-1024 chars) and response format is used.
+    ///
+    /// Exact duplicates (same SHA-256 content hash) return the existing fingerprint
+    /// with `deduplicated: true` unless `allowDuplicates` is set.
+    ///
+    /// Near duplicates are found after embedding: when the closest E1 neighbor
+    /// reaches the `theta_dup` of the content's domain (`domain`, or detected),
+    /// the memory is stored with a `duplicateOf` advisory, or refused when
+    /// `strict_dedup` is set. `dedupKind` tells the two checks apart.
+    ///
+    /// `ttlSeconds` makes the memory expire: it is hidden from retrieval and search
+    /// after the deadline and physically removed by the background TTL purge.
+    ///
+    /// `namespace` (default "default") isolates the memory; duplicate detection
+    /// only considers memories in the same namespace.
+    ///
+    /// `staged` stores into the session's staging namespace instead; see
+    /// promote_staged and end_staged_session.
+    ///
+    /// `chunking` splits content over its token budget into linked chunk
+    /// memories under a parent document; see chunked_store_tools.
     pub(crate) async fn call_store_memory(
         &self,
         id: Option<JsonRpcId>,
@@ -153,6 +265,138 @@ impl Handlers {
             None => TeleologicalFingerprint::DEFAULT_IMPORTANCE,
         };
 
+        // TTL: Optional lifetime in seconds; the memory becomes invisible once it elapses
+        let expires_at = match args.get("ttlSeconds") {
+            None => None,
+            Some(v) => match v.as_u64() {
+                Some(ttl) if ttl > 0 && ttl <= i64::MAX as u64 / 1000 => {
+                    Some(chrono::Utc::now() + chrono::Duration::seconds(ttl as i64))
+                }
+                _ => {
+                    return self.tool_error(
+                        id,
+                        &format!("ttlSeconds must be a positive integer, got {}", v),
+                    );
+                }
+            },
+        };
+
+        // E12 pruning: Optional fraction of ColBERT tokens to drop before storage
+        let e12_pruning = match args.get("e12Pruning") {
+            None => None,
+            Some(v) => match v.as_f64() {
+                Some(ratio) if ratio > 0.0 && ratio < 1.0 => Some(ratio as f32),
+                _ => {
+                    return self.tool_error(
+                        id,
+                        &format!(
+                            "e12Pruning must be between 0.0 and 1.0 (exclusive), got {}",
+                            v
+                        ),
+                    );
+                }
+            },
+        };
+
+        // Staged memories go to the session's staging namespace until promoted
+        let staged = args
+            .get("staged")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let namespace = if staged {
+            if args.get("namespace").is_some() {
+                return self.tool_error(id, "staged and namespace are mutually exclusive");
+            }
+            let staging_session = args
+                .get("sessionId")
+                .and_then(|v| v.as_str())
+                .map(String::from)
+                .unwrap_or_else(|| self.get_or_init_session_id());
+            TeleologicalFingerprint::staging_namespace(&staging_session)
+        } else {
+            match parse_namespace(&args) {
+                Ok(ns) => ns,
+                Err(msg) => return self.tool_error(id, &msg),
+            }
+        };
+
+        let allow_duplicates = args
+            .get("allowDuplicates")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        // Near-duplicate threshold: theta_dup of the given or detected domain
+        let explicit_domain = match args.get("domain").and_then(|v| v.as_str()) {
+            Some(s) => match s.parse::<SearchDomain>() {
+                Ok(domain) => Some(domain),
+                Err(msg) => return self.tool_error_typed(id, ToolErrorKind::Validation, &msg),
+            },
+            None => None,
+        };
+        let strict_dedup = args
+            .get("strict_dedup")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        // Content routing: an explicit content_type overrides the classifier
+        let explicit_content_type = match args.get("content_type") {
+            None => None,
+            Some(v) => match v.as_str().map(str::parse::<ContentType>) {
+                Some(Ok(content_type)) => Some(content_type),
+                Some(Err(msg)) => return self.tool_error(id, &msg),
+                None => {
+                    return self.tool_error(
+                        id,
+                        &format!("content_type must be a string, got {}", v),
+                    );
+                }
+            },
+        };
+
+        // Chunking applies only when the content is over the chunk budget
+        let chunker = match parse_chunking(&args) {
+            Ok(chunker) => chunker.filter(|c| c.count_tokens(&content) > c.max_tokens()),
+            Err(msg) => return self.tool_error(id, &msg),
+        };
+
+        // Compute content hash
+        let mut hasher = Sha256::new();
+        hasher.update(content.as_bytes());
+        let content_hash: [u8; 32] = hasher.finalize().into();
+
+        // DEDUP: Consult the content_hash index BEFORE embedding (and before
+        // consuming a session sequence number). Exact duplicates return the
+        // existing fingerprint instead of storing a second copy.
+        if !allow_duplicates {
+            match self
+                .find_duplicate_in_namespace(&content_hash, &namespace)
+                .await
+            {
+                Ok(existing) => {
+                    if let Some(existing_id) = existing {
+                        debug!(
+                            fingerprint_id = %existing_id,
+                            "store_memory: Exact duplicate content, returning existing fingerprint"
+                        );
+                        let mut response = json!({
+                            "fingerprintId": existing_id.to_string(),
+                            "namespace": namespace,
+                            "deduplicated": true,
+                            "dedupKind": "exact_hash"
+                        });
+                        if let Some(r) = rationale {
+                            response["rationale"] = json!(r);
+                        }
+                        return self.tool_result(id, response);
+                    }
+                }
+                Err(e) => {
+                    error!(error = %e, "store_memory: Duplicate check FAILED");
+                    return self.tool_error(id, &format!("Duplicate check failed: {}", e));
+                }
+            }
+        }
+
         // SESSION-ID-FIX: Priority: tool argument > env var > stored session_id > auto-generate
         // MUST resolve session ID BEFORE get_next_sequence() because auto-generation
         // via set_session_id() resets the sequence counter.
@@ -173,6 +417,28 @@ impl Handlers {
             .and_then(|v| v.as_str())
             .map(String::from);
 
+        if let Some(chunker) = chunker {
+            return self
+                .store_chunked_document(
+                    id,
+                    ChunkedDocument {
+                        content: &content,
+                        content_hash,
+                        chunker,
+                        namespace,
+                        staged,
+                        importance,
+                        expires_at,
+                        e12_pruning,
+                        rationale,
+                        session_id: session_id.unwrap_or_else(|| self.get_or_init_session_id()),
+                        session_sequence,
+                        operator_id,
+                    },
+                )
+                .await;
+        }
+
         // CAUSAL-HINT: Get causal hint if provider is available (non-blocking with timeout)
         // Per Phase 5: LLM analyzes content for causal nature, provides hints to E5 embedder
         // CAUSAL-HINT-FIX: Clone hint before moving into metadata so we can use direction for storage
@@ -221,6 +487,7 @@ impl Handlers {
             session_sequence: Some(session_sequence),
             timestamp: Some(chrono::Utc::now()),
             causal_hint,
+            e12_pruning,
         };
 
         debug!(
@@ -229,24 +496,85 @@ impl Handlers {
             "store_memory: Using session sequence for E4 embedding"
         );
 
+        // Pure prose skips E7: the code model only adds noise for it
+        let content_classification = match explicit_content_type {
+            Some(content_type) => ContentClassification::explicit(content_type),
+            None => ContentClassifier::default().classify(&content),
+        };
+        let skip_code = content_classification.is_pure_prose();
+        debug!(
+            content_type = %content_classification.content_type,
+            source = ?content_classification.source,
+            skip_code,
+            "store_memory: Classified content"
+        );
+
         // Generate all 13 embeddings using MultiArrayEmbeddingProvider
-        // E4-FIX: Use embed_all_with_metadata to pass sequence number to E4
-        let embedding_output = match self
-            .multi_array_provider
-            .embed_all_with_metadata(&content, metadata)
-            .await
-        {
+        // E4-FIX: Metadata reaches E4 (sequence number) even when the content-only
+        // spaces are reused from a query embedding of the same text
+        let embedding_output = match self.embed_for_storage(&content, metadata, skip_code).await {
             Ok(output) => output,
+            Err(CoreError::Backpressure {
+                queue_depth,
+                retry_after_ms,
+            }) => {
+                warn!(
+                    queue_depth,
+                    retry_after_ms, "store_memory: Embedding queue full, rejecting"
+                );
+                return embedding_backpressure_response(id, queue_depth, retry_after_ms);
+            }
             Err(e) => {
-                error!(error = %e, "store_memory: Multi-array embedding FAILED");
-                return self.tool_error(id, &format!("Embedding failed: {}", e));
+                return self.tool_error_from(id, "store_memory", "Embedding failed", e);
             }
         };
 
-        // Compute content hash
-        let mut hasher = Sha256::new();
-        hasher.update(content.as_bytes());
-        let content_hash: [u8; 32] = hasher.finalize().into();
+        // NEAR-DEDUP: Reworded copies pass the hash check; compare E1 against
+        // the domain's theta_dup before writing
+        let near_duplicate = if allow_duplicates {
+            None
+        } else {
+            let resolved = resolve_search_profile(explicit_domain, &content);
+            match self
+                .find_near_duplicate(&embedding_output.fingerprint, &namespace, &resolved)
+                .await
+            {
+                Ok(found) => found,
+                Err(e) => {
+                    return self.tool_error_from(
+                        id,
+                        "store_memory",
+                        "Near-duplicate check failed",
+                        e,
+                    );
+                }
+            }
+        };
+        if let Some(duplicate) = near_duplicate.as_ref().filter(|_| strict_dedup) {
+            debug!(
+                fingerprint_id = %duplicate.fingerprint_id,
+                similarity = duplicate.similarity,
+                theta_dup = duplicate.theta_dup,
+                "store_memory: Near duplicate refused by strict_dedup"
+            );
+            let mut response = self.tool_error_typed(
+                id,
+                ToolErrorKind::Validation,
+                &format!(
+                    "Near duplicate of {} (E1 similarity {:.3} >= theta_dup {:.2} for domain \
+                     '{}'). Set allowDuplicates=true or drop strict_dedup to store anyway.",
+                    duplicate.fingerprint_id,
+                    duplicate.similarity,
+                    duplicate.theta_dup,
+                    duplicate.domain.as_str()
+                ),
+            );
+            if let Some(result) = response.result.as_mut() {
+                result["dedupKind"] = json!("semantic");
+                result["duplicateOf"] = duplicate.to_json();
+            }
+            return response;
+        }
 
         // TASK-FIX-CLUSTERING: Compute cluster array BEFORE fingerprint is consumed
         // This must be done before TeleologicalFingerprint::new() moves the semantic fingerprint.
@@ -267,193 +595,37 @@ impl Handlers {
 
         // Create TeleologicalFingerprint from embeddings with user-specified importance
         // E6-FIX: Chain .with_e6_sparse() to propagate the E6 sparse vector
-        let fingerprint =
+        let mut fingerprint =
             TeleologicalFingerprint::with_importance(embedding_output.fingerprint, content_hash, importance)
                 .with_e6_sparse(e6_sparse);
+        if let Some(deadline) = expires_at {
+            fingerprint = fingerprint.with_expires_at(deadline);
+        }
+        fingerprint = fingerprint.with_namespace(namespace.clone());
         let fingerprint_id = fingerprint.id;
 
         match self.teleological_store.store(fingerprint).await {
             Ok(_) => {
-                // TASK-FIX-CLUSTERING: Insert into cluster_manager for topic detection
-                // This enables MultiSpaceClusterManager to track this memory for HDBSCAN/BIRCH clustering.
-                // Per PRD Section 5: Topics emerge from multi-space clustering with weighted_agreement >= 2.5.
-                {
-                    let mut cluster_mgr = self.cluster_manager.write();
-                    if let Err(e) = cluster_mgr.insert(fingerprint_id, &cluster_array) {
-                        // Non-fatal: fingerprint is stored, clustering can be retried via detect_topics
-                        warn!(
-                            fingerprint_id = %fingerprint_id,
-                            error = %e,
-                            "store_memory: Failed to insert into cluster_manager. \
-                             Topic detection may not include this memory until next recluster."
-                        );
-                    } else {
-                        debug!(
-                            fingerprint_id = %fingerprint_id,
-                            "store_memory: Inserted into cluster_manager for topic detection"
-                        );
-                    }
-                }
-
-                // TASK-GRAPHLINK-PHASE1: Enqueue fingerprint for background K-NN graph building
-                // The BackgroundGraphBuilder will process this in batch every 60s (configurable)
-                if let Some(builder) = self.graph_builder() {
-                    builder.enqueue(fingerprint_id).await;
-                    debug!(
-                        fingerprint_id = %fingerprint_id,
-                        "store_memory: Enqueued for K-NN graph building"
-                    );
-                }
-
-                // TASK-CONTENT-010: Store content text alongside fingerprint
-                // Content storage failure is non-fatal - fingerprint is primary data
-                if let Err(e) = self
-                    .teleological_store
-                    .store_content(fingerprint_id, &content)
-                    .await
-                {
-                    warn!(
-                        fingerprint_id = %fingerprint_id,
-                        error = %e,
-                        content_size = content.len(),
-                        "store_memory: Failed to store content text (fingerprint saved successfully). \
-                         Content retrieval will return None for this fingerprint."
-                    );
-                } else {
-                    debug!(
-                        fingerprint_id = %fingerprint_id,
-                        content_size = content.len(),
-                        "store_memory: Content text stored successfully"
-                    );
-                }
-
-                // ENTITY-LINK: Extract entities at store time for Jaccard overlap in search_by_entities.
-                // Without this, entity Jaccard is always 0.0 because entities aren't linked to stored memories.
-                let entity_names = {
-                    let entity_meta = super::entity_tools::extract_entity_mentions(&content);
-                    let ids: Vec<String> = entity_meta.canonical_ids().into_iter().map(String::from).collect();
-                    if !ids.is_empty() {
-                        debug!(
-                            fingerprint_id = %fingerprint_id,
-                            entity_count = ids.len(),
-                            entities = ?ids,
-                            "store_memory: Extracted entities for Jaccard scoring"
-                        );
-                        Some(ids)
-                    } else {
-                        None
-                    }
-                };
-
-                // E4-FIX Phase 1: Persist session metadata for E4 sequence retrieval
-                // This enables proper before/after queries by storing session_sequence
-                // PHASE-1.2: Add operator attribution fields for provenance tracking
-                let source_metadata = SourceMetadata {
-                    source_type: SourceType::Manual,
-                    session_id: session_id.clone(),
-                    session_sequence: Some(session_sequence),
-                    causal_direction: Some(causal_direction.clone()),
-                    created_by: operator_id.clone(),
-                    created_at: Some(chrono::Utc::now()),
-                    embedding_hint_provenance: embedding_output.e5_hint_provenance.clone(),
-                    entity_names,
-                    ..SourceMetadata::default()
-                };
-
-                if let Err(e) = self
-                    .teleological_store
-                    .store_source_metadata(fingerprint_id, &source_metadata)
-                    .await
-                {
-                    warn!(
-                        fingerprint_id = %fingerprint_id,
-                        error = %e,
-                        session_sequence = session_sequence,
-                        causal_direction = %causal_direction,
-                        "store_memory: Failed to store source metadata (fingerprint saved successfully). \
-                         E4 sequence retrieval may fall back to timestamp-based ordering."
-                    );
-                } else {
-                    debug!(
-                        fingerprint_id = %fingerprint_id,
-                        session_sequence = session_sequence,
-                        causal_direction = %causal_direction,
-                        "store_memory: Source metadata stored for E4 sequence retrieval"
-                    );
-                }
-
-                // PHASE-1.2: Append audit record for memory creation
-                {
-                    use context_graph_core::types::audit::{AuditOperation, AuditRecord};
-                    let mut audit_record = AuditRecord::new(AuditOperation::MemoryCreated, fingerprint_id);
-                    if let Some(ref op_id) = operator_id {
-                        audit_record = audit_record.with_operator(op_id.clone());
-                    }
-                    if let Some(ref sess_id) = session_id {
-                        audit_record = audit_record.with_session(sess_id.clone());
-                    }
-                    if let Some(r) = rationale {
-                        audit_record = audit_record.with_rationale(r);
-                    }
-                    audit_record = audit_record.with_parameters(json!({
-                        "importance": importance,
-                        "content_size": content.len(),
-                        "causal_direction": causal_direction,
-                        "embedding_hint_provenance": embedding_output.e5_hint_provenance,
-                    }));
-
-                    if let Err(e) = self.teleological_store.append_audit_record(&audit_record).await {
-                        // Non-fatal: audit is secondary to the main operation
-                        error!(
-                            fingerprint_id = %fingerprint_id,
-                            error = %e,
-                            "store_memory: Failed to append audit record (memory stored successfully)"
-                        );
-                    } else {
-                        debug!(
-                            fingerprint_id = %fingerprint_id,
-                            audit_id = %audit_record.id,
-                            "store_memory: Audit record appended successfully"
-                        );
-                    }
-                }
-
-                // Phase 5: Store embedding version record for provenance tracking
-                // M5 FIX: Use actual model_ids from embedding output, not static strings
-                {
-                    use context_graph_core::types::audit::EmbeddingVersionRecord;
-                    use std::collections::HashMap;
-
-                    let mut embedder_versions = HashMap::new();
-                    for (i, label) in EMBEDDER_NAMES.iter().enumerate() {
-                        embedder_versions.insert(
-                            label.to_string(),
-                            embedding_output.model_ids[i].clone(),
-                        );
-                    }
-
-                    let record = EmbeddingVersionRecord {
-                        fingerprint_id,
-                        computed_at: chrono::Utc::now(),
-                        embedder_versions,
-                        e7_model_version: Some(embedding_output.model_ids[6].clone()),
-                        computation_time_ms: Some(embedding_output.total_latency.as_millis() as u64),
-                    };
-
-                    if let Err(e) = self.teleological_store.store_embedding_version(&record).await {
-                        warn!(
-                            fingerprint_id = %fingerprint_id,
-                            error = %e,
-                            "store_memory: Failed to store embedding version record (non-fatal)"
-                        );
-                    } else {
-                        debug!(
-                            fingerprint_id = %fingerprint_id,
-                            computation_ms = embedding_output.total_latency.as_millis(),
-                            "store_memory: Embedding version record stored"
-                        );
-                    }
-                }
+                self.status_aggregator.record_stored(&namespace);
+                self.index_stored_memory(
+                    fingerprint_id,
+                    &content,
+                    cluster_array,
+                    StoredMemoryProvenance {
+                        session_id: session_id.clone(),
+                        session_sequence,
+                        causal_direction,
+                        operator_id,
+                        rationale,
+                        importance,
+                        embedding_hint_provenance: embedding_output.e5_hint_provenance,
+                        model_ids: embedding_output.model_ids,
+                        embedding_latency: embedding_output.total_latency,
+                        chunk: None,
+                        content_classification: Some(content_classification.clone()),
+                    },
+                )
+                .await;
 
                 // ===== INLINE CAUSAL RELATIONSHIP EXTRACTION =====
                 // INLINE-CAUSAL: Extract ALL causal relationships from content using the
@@ -466,23 +638,360 @@ impl Handlers {
                 #[cfg(feature = "llm")]
                 self.extract_inline_causal_relationships(&content, fingerprint_id).await;
 
+                let inferred = self.infer_stored_edges(&[fingerprint_id]).await;
+
                 // Build response, including rationale if provided
                 let mut response = json!({
                     "fingerprintId": fingerprint_id.to_string(),
                     "embedderCount": NUM_EMBEDDERS,
-                    "embeddingLatencyMs": embedding_output.total_latency.as_millis()
+                    "embeddingLatencyMs": embedding_output.total_latency.as_millis(),
+                    "namespace": namespace,
+                    "staged": staged,
+                    "deduplicated": false
+                });
+                if let Some(report) = inferred {
+                    response["edgesCreated"] = json!(report.edges_created());
+                }
+                if let Some(duplicate) = &near_duplicate {
+                    response["dedupKind"] = json!("semantic");
+                    response["duplicateOf"] = duplicate.to_json();
+                }
+                let skipped: Vec<&str> = content_classification
+                    .skipped_embedders()
+                    .into_iter()
+                    .map(Embedder::short_name)
+                    .collect();
+                response["contentClassification"] = json!({
+                    "contentType": content_classification.content_type,
+                    "source": content_classification.source,
+                    "distribution": content_classification.distribution,
+                    "language": content_classification.language,
+                    "skippedEmbedders": skipped,
                 });
 
                 // Include rationale in response when provided (merged from inject_context)
                 if let Some(r) = rationale {
                     response["rationale"] = json!(r);
                 }
+                if let Some(deadline) = expires_at {
+                    response["expiresAt"] = json!(deadline.to_rfc3339());
+                }
 
                 self.tool_result(id, response)
             }
+            Err(e) => self.tool_error_from(id, "store_memory", "Storage failed", e),
+        }
+    }
+
+    /// Find an already-stored memory with the same content hash in `namespace`.
+    ///
+    /// Exact duplicates in other namespaces are ignored so namespaces never
+    /// cross over.
+    pub(super) async fn find_duplicate_in_namespace(
+        &self,
+        content_hash: &[u8; 32],
+        namespace: &str,
+    ) -> CoreResult<Option<uuid::Uuid>> {
+        let existing = self
+            .teleological_store
+            .find_by_content_hash(content_hash)
+            .await?;
+        if existing.is_empty() {
+            return Ok(None);
+        }
+        let fingerprints = self.teleological_store.retrieve_batch(&existing).await?;
+        Ok(fingerprints
+            .into_iter()
+            .flatten()
+            .find(|fp| fp.namespace == namespace)
+            .map(|fp| fp.id))
+    }
+
+    /// Closest memory in `namespace` whose E1 similarity to `fingerprint`
+    /// reaches the `theta_dup` of the resolved domain.
+    pub(super) async fn find_near_duplicate(
+        &self,
+        fingerprint: &SemanticFingerprint,
+        namespace: &str,
+        resolved: &ResolvedSearchProfile,
+    ) -> CoreResult<Option<NearDuplicate>> {
+        let profile = resolved.profile;
+        let options = TeleologicalSearchOptions::quick(1)
+            .with_strategy(SearchStrategy::E1Only)
+            .with_min_similarity(profile.theta_dup)
+            .with_namespace(namespace);
+        let neighbors = self
+            .teleological_store
+            .search_semantic(fingerprint, options)
+            .await?;
+        Ok(neighbors
+            .into_iter()
+            .find(|n| n.similarity >= profile.theta_dup)
+            .map(|n| NearDuplicate {
+                fingerprint_id: n.fingerprint.id,
+                similarity: n.similarity,
+                theta_dup: profile.theta_dup,
+                domain: profile.domain,
+                domain_source: resolved.source,
+            }))
+    }
+
+    /// Link newly stored memories to their nearest neighbors.
+    ///
+    /// Returns None when edge inference is disabled or fails; a failure is
+    /// non-fatal because the memories themselves are already persisted.
+    pub(super) async fn infer_stored_edges(
+        &self,
+        fingerprint_ids: &[uuid::Uuid],
+    ) -> Option<EdgeInferenceReport> {
+        let service = self.edge_inference()?;
+        match service.infer_edges(fingerprint_ids).await {
+            Ok(report) => {
+                debug!(
+                    memories = report.memories,
+                    edges_created = report.edges_created(),
+                    "store_memory: Inferred edges to nearest neighbors"
+                );
+                Some(report)
+            }
             Err(e) => {
-                error!(error = %e, "store_memory: Storage FAILED");
-                self.tool_error(id, &format!("Storage failed: {}", e))
+                warn!(
+                    memories = fingerprint_ids.len(),
+                    error = %e,
+                    "store_memory: Edge inference failed (memories stored successfully). \
+                     They stay unlinked until the next K-NN graph batch."
+                );
+                None
+            }
+        }
+    }
+
+    /// Record everything that accompanies a newly stored fingerprint.
+    ///
+    /// Shared by store_memory and store_memories_batch: cluster manager insert,
+    /// K-NN graph enqueue, content text, source metadata, audit record and
+    /// embedding version record. Every step is non-fatal because the
+    /// fingerprint itself is already persisted.
+    pub(super) async fn index_stored_memory(
+        &self,
+        fingerprint_id: uuid::Uuid,
+        content: &str,
+        cluster_array: [Vec<f32>; NUM_EMBEDDERS],
+        provenance: StoredMemoryProvenance<'_>,
+    ) {
+        let StoredMemoryProvenance {
+            session_id,
+            session_sequence,
+            causal_direction,
+            operator_id,
+            rationale,
+            importance,
+            embedding_hint_provenance,
+            model_ids,
+            embedding_latency,
+            chunk,
+            content_classification,
+        } = provenance;
+
+        // TASK-FIX-CLUSTERING: Insert into cluster_manager for topic detection
+        // This enables MultiSpaceClusterManager to track this memory for HDBSCAN/BIRCH clustering.
+        // Per PRD Section 5: Topics emerge from multi-space clustering with weighted_agreement >= 2.5.
+        {
+            let mut cluster_mgr = self.cluster_manager.write();
+            if let Err(e) = cluster_mgr.insert(fingerprint_id, &cluster_array) {
+                // Non-fatal: fingerprint is stored, clustering can be retried via detect_topics
+                warn!(
+                    fingerprint_id = %fingerprint_id,
+                    error = %e,
+                    "store_memory: Failed to insert into cluster_manager. \
+                     Topic detection may not include this memory until next recluster."
+                );
+            } else {
+                debug!(
+                    fingerprint_id = %fingerprint_id,
+                    "store_memory: Inserted into cluster_manager for topic detection"
+                );
+            }
+        }
+
+        // TASK-GRAPHLINK-PHASE1: Enqueue fingerprint for background K-NN graph building
+        // The BackgroundGraphBuilder will process this in batch every 60s (configurable)
+        if let Some(builder) = self.graph_builder() {
+            builder.enqueue(fingerprint_id).await;
+            debug!(
+                fingerprint_id = %fingerprint_id,
+                "store_memory: Enqueued for K-NN graph building"
+            );
+        }
+
+        // TASK-CONTENT-010: Store content text alongside fingerprint
+        // Content storage failure is non-fatal - fingerprint is primary data
+        if let Err(e) = self
+            .teleological_store
+            .store_content(fingerprint_id, &content)
+            .await
+        {
+            warn!(
+                fingerprint_id = %fingerprint_id,
+                error = %e,
+                content_size = content.len(),
+                "store_memory: Failed to store content text (fingerprint saved successfully). \
+                 Content retrieval will return None for this fingerprint."
+            );
+        } else {
+            debug!(
+                fingerprint_id = %fingerprint_id,
+                content_size = content.len(),
+                "store_memory: Content text stored successfully"
+            );
+        }
+
+        // ENTITY-LINK: Extract entities at store time for Jaccard overlap in search_by_entities.
+        // Without this, entity Jaccard is always 0.0 because entities aren't linked to stored memories.
+        let entity_names = {
+            let entity_meta = super::entity_tools::extract_entity_mentions(&content);
+            let ids: Vec<String> = entity_meta
+                .canonical_ids()
+                .into_iter()
+                .map(String::from)
+                .collect();
+            if !ids.is_empty() {
+                debug!(
+                    fingerprint_id = %fingerprint_id,
+                    entity_count = ids.len(),
+                    entities = ?ids,
+                    "store_memory: Extracted entities for Jaccard scoring"
+                );
+                Some(ids)
+            } else {
+                None
+            }
+        };
+
+        // E4-FIX Phase 1: Persist session metadata for E4 sequence retrieval
+        // This enables proper before/after queries by storing session_sequence
+        // PHASE-1.2: Add operator attribution fields for provenance tracking
+        let source_metadata = SourceMetadata {
+            source_type: SourceType::Manual,
+            session_id: session_id.clone(),
+            session_sequence: Some(session_sequence),
+            causal_direction: Some(causal_direction.clone()),
+            created_by: operator_id.clone(),
+            created_at: Some(chrono::Utc::now()),
+            embedding_hint_provenance: embedding_hint_provenance.clone(),
+            entity_names,
+            chunk_index: chunk.as_ref().map(|c| c.chunk_index),
+            total_chunks: chunk.as_ref().map(|c| c.total_chunks),
+            start_line: chunk.as_ref().map(|c| c.start_line),
+            end_line: chunk.as_ref().map(|c| c.end_line),
+            derived_from: chunk.as_ref().map(|c| vec![c.document_id]),
+            derivation_method: chunk.as_ref().map(|_| "chunked".to_string()),
+            skipped_embedders: content_classification
+                .as_ref()
+                .map(ContentClassification::skipped_embedders)
+                .filter(|skipped| !skipped.is_empty()),
+            content_classification,
+            ..SourceMetadata::default()
+        };
+
+        if let Err(e) = self
+            .teleological_store
+            .store_source_metadata(fingerprint_id, &source_metadata)
+            .await
+        {
+            warn!(
+                fingerprint_id = %fingerprint_id,
+                error = %e,
+                session_sequence = session_sequence,
+                causal_direction = %causal_direction,
+                "store_memory: Failed to store source metadata (fingerprint saved successfully). \
+                 E4 sequence retrieval may fall back to timestamp-based ordering."
+            );
+        } else {
+            debug!(
+                fingerprint_id = %fingerprint_id,
+                session_sequence = session_sequence,
+                causal_direction = %causal_direction,
+                "store_memory: Source metadata stored for E4 sequence retrieval"
+            );
+        }
+
+        // PHASE-1.2: Append audit record for memory creation
+        {
+            use context_graph_core::types::audit::{AuditOperation, AuditRecord};
+            let mut audit_record = AuditRecord::new(AuditOperation::MemoryCreated, fingerprint_id);
+            if let Some(ref op_id) = operator_id {
+                audit_record = audit_record.with_operator(op_id.clone());
+            }
+            if let Some(ref sess_id) = session_id {
+                audit_record = audit_record.with_session(sess_id.clone());
+            }
+            if let Some(r) = rationale {
+                audit_record = audit_record.with_rationale(r);
+            }
+            audit_record = audit_record.with_parameters(json!({
+                "importance": importance,
+                "content_size": content.len(),
+                "causal_direction": causal_direction,
+                "embedding_hint_provenance": embedding_hint_provenance,
+            }));
+
+            if let Err(e) = self
+                .teleological_store
+                .append_audit_record(&audit_record)
+                .await
+            {
+                // Non-fatal: audit is secondary to the main operation
+                error!(
+                    fingerprint_id = %fingerprint_id,
+                    error = %e,
+                    "store_memory: Failed to append audit record (memory stored successfully)"
+                );
+            } else {
+                debug!(
+                    fingerprint_id = %fingerprint_id,
+                    audit_id = %audit_record.id,
+                    "store_memory: Audit record appended successfully"
+                );
+            }
+        }
+
+        // Phase 5: Store embedding version record for provenance tracking
+        // M5 FIX: Use actual model_ids from embedding output, not static strings
+        {
+            use context_graph_core::types::audit::EmbeddingVersionRecord;
+            use std::collections::HashMap;
+
+            let mut embedder_versions = HashMap::new();
+            for (i, label) in EMBEDDER_NAMES.iter().enumerate() {
+                embedder_versions.insert(label.to_string(), model_ids[i].clone());
+            }
+
+            let record = EmbeddingVersionRecord {
+                fingerprint_id,
+                computed_at: chrono::Utc::now(),
+                embedder_versions,
+                e7_model_version: Some(model_ids[6].clone()),
+                computation_time_ms: Some(embedding_latency.as_millis() as u64),
+                deterministic: self.multi_array_provider.determinism().is_some(),
+            };
+
+            if let Err(e) = self
+                .teleological_store
+                .store_embedding_version(&record)
+                .await
+            {
+                warn!(
+                    fingerprint_id = %fingerprint_id,
+                    error = %e,
+                    "store_memory: Failed to store embedding version record (non-fatal)"
+                );
+            } else {
+                debug!(
+                    fingerprint_id = %fingerprint_id,
+                    computation_ms = embedding_latency.as_millis(),
+                    "store_memory: Embedding version record stored"
+                );
             }
         }
     }
@@ -543,13 +1052,11 @@ impl Handlers {
                 );
             }
         }
-        let top_k = raw_top_k.unwrap_or(10) as usize;
+        let top_k = raw_top_k.unwrap_or(DEFAULT_TOP_K) as usize;
 
         // Parse minSimilarity parameter (default: 0.0 = no filtering)
-        let min_similarity = args
-            .get("minSimilarity")
-            .and_then(|v| v.as_f64())
-            .unwrap_or(0.0) as f32;
+        let min_similarity_arg = args.get("minSimilarity").and_then(|v| v.as_f64());
+        let min_similarity = min_similarity_arg.unwrap_or(0.0) as f32;
 
         if !(0.0..=1.0).contains(&min_similarity) {
             return self.tool_error(
@@ -561,6 +1068,12 @@ impl Handlers {
             );
         }
 
+        // Namespace isolation: search only the requested namespace (default: "default")
+        let namespace = match parse_namespace(&args) {
+            Ok(ns) => ns,
+            Err(msg) => return self.tool_error(id, &msg),
+        };
+
         // TASK-CONTENT-002: Parse includeContent parameter (default: false for backward compatibility)
         let include_content = args
             .get("includeContent")
@@ -662,12 +1175,30 @@ impl Handlers {
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
 
-        // TASK-MULTISPACE: Parse enable_rerank (default: false)
+        // Parse domain (default: detected from the query text). The domain's
+        // search profile supplies the weight profile, rerank setting and score
+        // cut-off unless weightProfile/customWeights, enableRerank or
+        // minSimilarity are given explicitly.
+        let explicit_domain = match args.get("domain").and_then(|v| v.as_str()) {
+            Some(s) => match s.parse::<SearchDomain>() {
+                Ok(domain) => Some(domain),
+                Err(msg) => return self.tool_error_typed(id, ToolErrorKind::Validation, &msg),
+            },
+            None => None,
+        };
+        let search_profile = resolve_search_profile(explicit_domain, query);
+        let domain_cutoff = if min_similarity_arg.is_none() {
+            search_profile.profile.min_score
+        } else {
+            0.0
+        };
+
+        // TASK-MULTISPACE: Parse enable_rerank (default: from the domain profile)
         // Per AP-73: ColBERT is for re-ranking only
         let enable_rerank = args
             .get("enableRerank")
             .and_then(|v| v.as_bool())
-            .unwrap_or(false);
+            .unwrap_or(search_profile.profile.rerank);
 
         // Parse useQuantizedPrefilter (default: false)
         let use_quantized_prefilter = args
@@ -723,6 +1254,87 @@ impl Handlers {
         // Parse lastDays shortcut (filter to last N days)
         let last_days = args.get("lastDays").and_then(|v| v.as_u64());
 
+        // Parse timeRange {start, end} (RFC 3339, hard filter on creation time)
+        let time_range = match args.get("timeRange") {
+            None | Some(serde_json::Value::Null) => None,
+            Some(range) => {
+                let bound = |key: &str| {
+                    range
+                        .get(key)
+                        .and_then(|v| v.as_str())
+                        .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+                        .map(|t| t.with_timezone(&chrono::Utc))
+                };
+                match (bound("start"), bound("end")) {
+                    (Some(start), Some(end)) if start < end => Some((start, end)),
+                    _ => {
+                        return self.tool_error_typed(
+                            id,
+                            ToolErrorKind::Validation,
+                            "timeRange needs RFC 3339 'start' and 'end' with start < end",
+                        );
+                    }
+                }
+            }
+        };
+
+        // Parse recencyWeight (0..1) and its half-life
+        let recency_weight = args
+            .get("recencyWeight")
+            .and_then(|v| v.as_f64())
+            .map(|v| v as f32)
+            .unwrap_or(0.0);
+        if !(0.0..=1.0).contains(&recency_weight) {
+            return self.tool_error_typed(
+                id,
+                ToolErrorKind::Validation,
+                &format!("recencyWeight must be between 0.0 and 1.0, got {}", recency_weight),
+            );
+        }
+        let recency_half_life = args
+            .get("recencyHalfLifeSecs")
+            .and_then(|v| v.as_u64())
+            .unwrap_or_else(|| TeleologicalSearchOptions::default().recency_half_life_secs);
+
+        // Parse edgeTypes: follow only these edge types in a graph-expansion
+        // phase around the final results
+        let expansion_edge_types = match args.get("edgeTypes") {
+            None | Some(serde_json::Value::Null) => None,
+            Some(value) => {
+                let parsed = value.as_array().and_then(|names| {
+                    names
+                        .iter()
+                        .map(|n| n.as_str().and_then(GraphLinkEdgeType::from_name))
+                        .collect::<Option<Vec<_>>>()
+                });
+                match parsed {
+                    Some(types) if !types.is_empty() => Some(types),
+                    _ => {
+                        return self.tool_error_typed(
+                            id,
+                            ToolErrorKind::Validation,
+                            &format!(
+                                "edgeTypes must be a non-empty array of: {}",
+                                GraphLinkEdgeType::NAMES.join(", ")
+                            ),
+                        );
+                    }
+                }
+            }
+        };
+        let expansion_hops = args
+            .get("expansionHops")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(1)
+            .clamp(1, 5) as usize;
+        if expansion_edge_types.is_some() && self.edge_repository.is_none() {
+            error!("search_graph: edgeTypes given but EdgeRepository not available - NO FALLBACKS");
+            return self.tool_error(
+                id,
+                "Graph linking not initialized. EdgeRepository is required - NO FALLBACKS.",
+            );
+        }
+
         // Parse sessionId (filter to specific session)
         let session_id = args
             .get("sessionId")
@@ -839,34 +1451,12 @@ impl Handlers {
             .and_then(|v| v.as_bool())
             .unwrap_or(true);
 
-        // Parse causalDirection (auto, cause, effect, none)
-        // - auto: Auto-detect from query text (default)
-        // - cause: Force query as seeking causes (for "why" queries)
-        // - effect: Force query as seeking effects (for "what happens" queries)
-        // - none: Disable causal processing
-        let causal_direction_param = args
-            .get("causalDirection")
-            .and_then(|v| v.as_str())
-            .unwrap_or("auto");
-
-        // Parse enableQueryExpansion (default: false)
-        // When enabled, causal queries are expanded with related terms
-        let enable_query_expansion = args
-            .get("enableQueryExpansion")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
-
         // =========================================================================
         // PHASE 1: CAUSAL DIRECTION DETECTION
         // =========================================================================
 
         // Detect causal direction from query text or use user-specified direction
-        let causal_direction = match causal_direction_param {
-            "cause" => CausalDirection::Cause,
-            "effect" => CausalDirection::Effect,
-            "none" => CausalDirection::Unknown,
-            _ => detect_causal_query_intent(query),
-        };
+        let causal_direction = search_causal_direction(query, &args);
 
         // Log causal detection for debugging/monitoring
         if causal_direction != CausalDirection::Unknown {
@@ -882,14 +1472,29 @@ impl Handlers {
         // =========================================================================
 
         // Expand causal queries with related terms for better recall
-        let search_query = if enable_query_expansion && causal_direction != CausalDirection::Unknown {
-            expand_causal_query(query, causal_direction)
-        } else {
-            query.to_string()
-        };
-
-        // Use user-specified weight profile directly (no auto-detection).
-        let effective_weight_profile = weight_profile.clone();
+        let search_query = search_query_text(query, causal_direction, &args);
+
+        // Content routing: code, mixed and structured-data queries, and prose
+        // queries without a domain weight profile, get fusion weights blended
+        // from the query's content distribution. weightProfile, customWeights
+        // and an explicit domain take precedence.
+        let query_classification = ContentClassifier::default().classify(query);
+        let routed_weights = (weight_profile.is_none()
+            && custom_weights.is_none()
+            && explicit_domain.is_none()
+            && (query_classification.content_type != ContentType::Prose
+                || search_profile.profile.weight_profile.is_none()))
+        .then(|| query_classification.fusion_weights());
+
+        // User-specified weight profile wins; otherwise the domain's profile
+        // applies (customWeights override both further down).
+        let effective_weight_profile = weight_profile.clone().or_else(|| {
+            if custom_weights.is_some() || routed_weights.is_some() {
+                None
+            } else {
+                search_profile.profile.weight_profile.map(String::from)
+            }
+        });
 
         // Build search options with multi-space parameters
         // For causal queries, over-fetch candidates to allow for reranking
@@ -904,7 +1509,8 @@ impl Handlers {
             .with_min_similarity(min_similarity)
             .with_strategy(strategy)
             .with_rerank(enable_rerank)
-            .with_causal_direction(causal_direction); // ARCH-15, AP-77: Thread direction to retrieval
+            .with_causal_direction(causal_direction) // ARCH-15, AP-77: Thread direction to retrieval
+            .with_namespace(namespace);
 
         // Map weight profile to synergy weights for cross-embedder correlation boost
         if let Some(sw) = match effective_weight_profile.as_deref() {
@@ -937,6 +1543,10 @@ impl Handlers {
             }
         }
 
+        if let Some(weights) = routed_weights {
+            options = options.with_custom_weights(weights);
+        }
+
         // GAP-1: Explicit custom weights override everything (including custom profiles)
         // HIGH-08 FIX: Validate weights BEFORE applying (AP-NAV-02)
         if let Some(weights) = custom_weights {
@@ -972,6 +1582,13 @@ impl Handlers {
         } else if let Some(days) = last_days {
             options = options.with_last_days(days);
         }
+        if let Some((start, end)) = time_range {
+            options = options.with_time_range(start, end);
+        }
+        if recency_weight > 0.0 {
+            options = options.with_recency_weight(recency_weight, recency_half_life);
+        }
+        let time_filter_exclusions = options.time_filter_exclusions.clone();
 
         // =========================================================================
         // SESSION SCOPE HANDLING (Phase 2 Enhancement)
@@ -1143,12 +1760,37 @@ impl Handlers {
                     false
                 };
 
+                // Shift scores by decayed importance plus the recent-access bonus,
+                // so boosted and frequently used memories rank higher only until
+                // the boost decays or the accesses stop
+                let importance_model = ImportanceModel::default();
+                let ranked_at = chrono::Utc::now();
+                let result_ids: Vec<uuid::Uuid> = results.iter().map(|r| r.fingerprint.id).collect();
+                let access_stats = match self.teleological_store.access_stats(&result_ids).await {
+                    Ok(stats) => stats,
+                    Err(e) => {
+                        warn!(error = %e, "search_graph: Access stats unavailable, ranking without access bonus");
+                        vec![AccessStats::default(); result_ids.len()]
+                    }
+                };
+                apply_importance_ranking(&mut results, &access_stats, &importance_model, ranked_at);
+
+                // Domain cut-off on the final (reranked) scores
+                let candidates_before_cutoff = results.len();
+                if domain_cutoff > 0.0 {
+                    results.retain(|r| r.similarity >= domain_cutoff);
+                }
+                let below_cutoff = candidates_before_cutoff - results.len();
+
                 // Truncate to requested top_k after reranking
                 results.truncate(top_k);
 
                 // M6 FIX: Update in-memory fingerprints first, then persist to RocksDB in background.
                 // Each update writes ~50KB fingerprint — doing 50+ synchronously inflates latency.
+                // Decay is folded into the stored importance first, since record_access
+                // moves last_updated (the decay clock) forward.
                 for result in &mut results {
+                    importance_model.materialize_at(&mut result.fingerprint, ranked_at);
                     result.fingerprint.record_access();
                 }
                 {
@@ -1232,7 +1874,7 @@ impl Handlers {
                 // Was: custom_profiles.read() acquired per-embedder per-result (50*13*3 = ~1,950 locks/search).
                 // Now: single lock acquisition, then direct array indexing.
                 // MCP-4 FIX: Error on invalid weight profile name instead of silent uniform fallback.
-                let resolved_weights: [f32; 13] = if let Some(cw) = custom_weights {
+                let resolved_weights: [f32; 13] = if let Some(cw) = custom_weights.or(routed_weights) {
                     cw
                 } else if let Some(ref profile_name) = effective_weight_profile {
                     // Audit-11 MCP-H3: get_weight_profile now returns Result, propagate errors.
@@ -1301,6 +1943,7 @@ impl Handlers {
                         let mut entry = json!({
                             "fingerprintId": r.fingerprint.id.to_string(),
                             "similarity": r.similarity,
+                            "importance": r.fingerprint.importance,
                             "e1Score": e1_score,
                             "embedderScores": embedder_scores,
                             "agreementCount": agreement_count
@@ -1504,10 +2147,33 @@ impl Handlers {
                 // When customWeights are provided, they override the profile (per constitution: customWeights > weightProfile)
                 if custom_weights.is_some() {
                     response["effectiveProfile"] = json!("custom");
+                } else if routed_weights.is_some() {
+                    response["effectiveProfile"] = json!("content_routed");
                 } else if let Some(ref profile) = effective_weight_profile {
                     response["effectiveProfile"] = json!(profile);
                 }
 
+                // Echo the query's content classification and whether it set the weights
+                response["contentRouting"] = json!({
+                    "contentType": query_classification.content_type,
+                    "distribution": query_classification.distribution,
+                    "language": query_classification.language,
+                    "applied": routed_weights.is_some(),
+                });
+
+                // Echo the domain profile and how it was chosen
+                response["searchProfile"] = json!({
+                    "domain": search_profile.profile.domain.as_str(),
+                    "detected": search_profile.source == DomainSource::Detected,
+                    "source": search_profile.source,
+                    "matchedCues": search_profile.matched_cues,
+                    "weightProfile": search_profile.profile.weight_profile,
+                    "rerank": enable_rerank,
+                    "minScore": domain_cutoff,
+                    "candidates": candidates_before_cutoff,
+                    "belowCutoff": below_cutoff,
+                });
+
                 // Echo back search parameters for transparency/debugging
                 let temporal_config = if temporal_weight > 0.0 {
                     Some(json!({
@@ -1520,14 +2186,56 @@ impl Handlers {
                 } else {
                     None
                 };
+                if let Some((start, end)) = time_range {
+                    response["timeFilter"] = json!({
+                        "start": start.to_rfc3339(),
+                        "end": end.to_rfc3339(),
+                        "excluded": time_filter_exclusions.count(),
+                    });
+                }
+                let recency_config = (recency_weight > 0.0).then(|| {
+                    json!({
+                        "recencyWeight": recency_weight,
+                        "recencyHalfLifeSecs": recency_half_life,
+                    })
+                });
                 response["searchParameters"] = json!({
                     "customWeightsValues": custom_weights.map(|w| w.to_vec()),
                     "excludedEmbedders": exclude_embedder_names,
                     "temporalConfig": temporal_config,
+                    "recencyConfig": recency_config,
                     "rrfConstant": RRF_K,
                     "resolvedWeightProfile": effective_weight_profile,
                 });
 
+                // Graph expansion over the requested edge types
+                if let (Some(edge_types), Some(edge_repo)) =
+                    (&expansion_edge_types, &self.edge_repository)
+                {
+                    let result_ids: Vec<uuid::Uuid> =
+                        results.iter().map(|r| r.fingerprint.id).collect();
+                    let opts = SubgraphOptions::default()
+                        .with_filter(TraversalFilter::edge_types(edge_types));
+                    let subgraph = match extract_subgraph(&result_ids, expansion_hops, &opts, |node| {
+                        edge_repo.get_typed_edges_from(node)
+                    }) {
+                        Ok(subgraph) => subgraph,
+                        Err(e) => {
+                            error!(error = %e, "search_graph: Graph expansion failed");
+                            return self.tool_error(id, &format!("Graph expansion failed: {}", e));
+                        }
+                    };
+                    let expanded: Vec<&SubgraphNode> =
+                        subgraph.nodes.iter().filter(|n| n.hop > 0).collect();
+                    response["graphExpansion"] = json!({
+                        "edgeTypes": edge_types,
+                        "hops": expansion_hops,
+                        "nodes": expanded,
+                        "edges": subgraph.edges,
+                        "truncated": subgraph.truncated,
+                    });
+                }
+
                 // =========================================================================
                 // SEARCH TRANSPARENCY: Show which embedders actually participated
                 // =========================================================================
@@ -1621,10 +2329,7 @@ impl Handlers {
 
                 self.tool_result(id, response)
             }
-            Err(e) => {
-                error!(error = %e, "search_graph: Search FAILED");
-                self.tool_error(id, &format!("Search failed: {}", e))
-            }
+            Err(e) => self.tool_error_from(id, "search_graph", "Search failed", e),
         }
     }
 }
@@ -1691,6 +2396,33 @@ fn apply_asymmetric_e5_reranking(
     });
 }
 
+/// Shift each result's similarity by its decayed importance plus access
+/// bonus and re-sort. `stats` holds the access stats of each result, in order.
+///
+/// Memories at the default importance are unchanged; boosted or recently
+/// accessed ones rise and long-untouched ones sink. Scores stay within [0.0, 1.0].
+fn apply_importance_ranking(
+    results: &mut [TeleologicalSearchResult],
+    stats: &[AccessStats],
+    model: &ImportanceModel,
+    now: chrono::DateTime<chrono::Utc>,
+) {
+    if results.is_empty() {
+        return;
+    }
+
+    for (result, stats) in results.iter_mut().zip(stats) {
+        let importance = model.effective_with_access_at(&result.fingerprint, stats, now);
+        result.similarity = model
+            .rank_score(result.similarity, importance)
+            .clamp(0.0, 1.0);
+    }
+
+    results.sort_by(|a, b| {
+        b.similarity.partial_cmp(&a.similarity).unwrap_or(std::cmp::Ordering::Equal)
+    });
+}
+
 /// Direction-aware reranking using keyword-detected query direction.
 ///
 /// Uses infer_result_causal_direction() (E5 vector norm comparison) to determine
@@ -1741,6 +2473,40 @@ fn infer_result_causal_direction(fingerprint: &SemanticFingerprint) -> CausalDir
     context_graph_core::causal::asymmetric::infer_direction_from_fingerprint(fingerprint)
 }
 
+/// Causal direction of a search_graph query.
+///
+/// `causalDirection` is auto (detect from the query text, default), cause
+/// (seeking causes, for "why" queries), effect (seeking effects, for "what
+/// happens" queries) or none (no causal processing).
+pub(super) fn search_causal_direction(query: &str, args: &serde_json::Value) -> CausalDirection {
+    match args.get("causalDirection").and_then(|v| v.as_str()) {
+        Some("cause") => CausalDirection::Cause,
+        Some("effect") => CausalDirection::Effect,
+        Some("none") => CausalDirection::Unknown,
+        _ => detect_causal_query_intent(query),
+    }
+}
+
+/// Text search_graph embeds for `query`.
+///
+/// With `enableQueryExpansion` (default: false) causal queries are expanded
+/// with related terms; otherwise this is the query itself.
+pub(super) fn search_query_text(
+    query: &str,
+    direction: CausalDirection,
+    args: &serde_json::Value,
+) -> String {
+    let enable_query_expansion = args
+        .get("enableQueryExpansion")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if enable_query_expansion && direction != CausalDirection::Unknown {
+        expand_causal_query(query, direction)
+    } else {
+        query.to_string()
+    }
+}
+
 /// Expand a causal query with related terms for better recall.
 ///
 /// # Arguments
@@ -2222,8 +2988,12 @@ mod tests {
         apply_causal_gate, expand_causal_query, get_e5_causal_weight,
         CausalDirection, COLBERT_WEIGHT,
         compute_blind_spots, build_embedder_scores_json, compute_navigation_hints,
+        apply_importance_ranking,
     };
     use context_graph_core::causal::asymmetric::{causal_gate, direction_mod};
+    use context_graph_core::importance::ImportanceModel;
+    use context_graph_core::traits::{AccessStats, TeleologicalSearchResult};
+    use context_graph_core::types::fingerprint::{SemanticFingerprint, TeleologicalFingerprint};
     use context_graph_storage::compute_maxsim_direct;
 
     #[test]
@@ -2293,4 +3063,29 @@ mod tests {
         let hints = compute_navigation_hints(&scores2);
         assert!(hints.iter().any(|h| h.contains("search_code")));
     }
+
+    #[test]
+    fn test_recent_access_outranks_equal_similarity() {
+        let now = chrono::Utc::now();
+        let result = |seed: u8| {
+            let fingerprint = TeleologicalFingerprint::new(SemanticFingerprint::zeroed(), [seed; 32]);
+            TeleologicalSearchResult::new(fingerprint, 0.70, [0.0; 13])
+        };
+        let (idle, used) = (result(1), result(2));
+        let (idle_id, used_id) = (idle.fingerprint.id, used.fingerprint.id);
+        let accessed = AccessStats { hits: 20, last_access: Some(now), hits_7d: 20 };
+
+        // Without access stats, ties keep search order
+        let mut results = vec![idle.clone(), used.clone()];
+        let untouched = vec![AccessStats::default(); 2];
+        apply_importance_ranking(&mut results, &untouched, &ImportanceModel::default(), now);
+        assert_eq!(results[0].fingerprint.id, idle_id);
+
+        // Recent hits move the used memory ahead of its equally similar peer
+        let mut results = vec![idle, used];
+        let stats = vec![AccessStats::default(), accessed];
+        apply_importance_ranking(&mut results, &stats, &ImportanceModel::default(), now);
+        assert_eq!(results[0].fingerprint.id, used_id);
+        assert!(results[0].similarity > results[1].similarity);
+    }
 }
@@ -2,6 +2,9 @@
 //!
 //! PRD v6 Section 10 MCP Tools:
 //! - store_memory, search_graph (memory_tools.rs) - inject_context merged into store_memory
+//!   (chunked documents: chunked_store_tools.rs)
+//! - store_memories_batch (batch_store_tools.rs)
+//! - search_graph_batch (search_batch_tools.rs) - Many search_graph queries, one embedding batch
 //! - get_memetic_status (status_tools.rs)
 //! - trigger_consolidation (consolidation.rs)
 //! - merge_concepts (../merge.rs)
@@ -11,6 +14,7 @@
 //! - get_conversation_context, get_session_timeline, traverse_memory_chain, compare_session_states (sequence_tools.rs)
 //! - search_causes, get_causal_chain (causal_tools.rs) - E5 Causal Priority 1
 //! - search_by_keywords (keyword_tools.rs) - E6 Keyword Search Enhancement
+//! - query_memories (query_tools.rs) - Structured lexical + semantic + metadata queries
 //! - search_code (code_tools.rs) - E7 Code Search Enhancement
 //! - search_connections, get_graph_path (graph_tools.rs) - E8 Upgrade Phase 4
 //! - search_robust (robustness_tools.rs) - E9 HDC Blind-Spot Detection
@@ -18,16 +22,25 @@
 //! - search_by_embedder, get_embedder_clusters, compare_embedder_views, list_embedder_indexes (embedder_tools.rs) - Constitution v6.3 Embedder-First Search
 //! - search_recent (temporal_tools.rs) - E2 V_freshness Temporal Search
 //! - get_memory_neighbors, get_typed_edges, traverse_graph (graph_link_tools.rs) - K-NN Graph Linking
+//! - export_memories, import_memories (snapshot_tools.rs) - Portable snapshots
+//! - promote_staged, end_staged_session (staging_tools.rs) - Session-scoped staging
+//! - daemon_status (daemon_tools.rs), get_embedding_status (embedding_status_tools.rs) - Observability
+//!
+//! GPU degradation policy (capability_policy.rs) is applied before dispatch.
 
+mod batch_store_tools;
+mod capability_policy;
 mod causal_discovery_tools;
 mod causal_relationship_tools;
 mod causal_tools;
+mod chunked_store_tools;
 mod code_tools;
 pub(crate) mod consolidation;
 mod curation_tools;
 pub(crate) mod daemon_tools;
 mod dispatch;
 mod embedder_tools;
+mod embedding_status_tools;
 mod entity_tools;
 mod file_watcher_tools;
 mod graph_link_tools;
@@ -40,11 +53,15 @@ pub(crate) mod validate;
 mod maintenance_tools;
 mod memory_tools;
 mod provenance_tools;
+mod query_tools;
 mod robustness_tools;
+mod search_batch_tools;
 mod sequence_tools;
+mod snapshot_tools;
+mod staging_tools;
 mod status_tools;
 mod temporal_tools;
-mod topic_tools;
+pub(crate) mod topic_tools;
 
 // DTOs for PRD v6 gap tools (TASK-GAP-005)
 pub mod causal_dtos;
@@ -56,6 +73,7 @@ pub mod graph_dtos;
 pub mod graph_link_dtos;
 pub mod keyword_dtos;
 pub mod provenance_dtos;
+pub mod query_dtos;
 pub mod robustness_dtos;
 pub mod temporal_dtos;
 pub mod topic_dtos;
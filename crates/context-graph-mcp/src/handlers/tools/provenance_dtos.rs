@@ -42,6 +42,36 @@ pub struct GetMergeHistoryParams {
     pub include_source_metadata: bool,
 }
 
+/// Parameters for get_memory_lineage tool.
+#[derive(Debug, Deserialize)]
+pub struct GetMemoryLineageParams {
+    pub memory_id: String,
+    #[serde(default = "default_lineage_depth", deserialize_with = "deserialize_usize_lenient")]
+    pub max_depth: usize,
+}
+
+/// Default lineage traversal depth.
+pub fn default_lineage_depth() -> usize {
+    5
+}
+
+/// Hard cap on lineage traversal depth.
+pub const MAX_LINEAGE_DEPTH: usize = 20;
+
+/// Parameters for query_audit_log tool.
+#[derive(Debug, Deserialize)]
+pub struct QueryAuditLogParams {
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub tool_name: Option<String>,
+    pub session_id: Option<String>,
+    pub memory_id: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_usize_lenient")]
+    pub offset: usize,
+    #[serde(default = "default_audit_limit", deserialize_with = "deserialize_usize_lenient")]
+    pub limit: usize,
+}
+
 /// Parameters for get_provenance_chain tool.
 #[derive(Debug, Deserialize)]
 pub struct GetProvenanceChainParams {
@@ -83,6 +113,30 @@ mod tests {
         assert!(!params.include_source_metadata);
     }
 
+    #[test]
+    fn test_memory_lineage_params_defaults() {
+        let params: GetMemoryLineageParams =
+            serde_json::from_str(r#"{"memory_id": "test-uuid"}"#).unwrap();
+        assert_eq!(params.max_depth, 5);
+
+        let params: GetMemoryLineageParams =
+            serde_json::from_str(r#"{"memory_id": "test-uuid", "max_depth": "3"}"#).unwrap();
+        assert_eq!(params.max_depth, 3);
+    }
+
+    #[test]
+    fn test_query_audit_log_params_defaults() {
+        let params: QueryAuditLogParams = serde_json::from_str("{}").unwrap();
+        assert_eq!(params.offset, 0);
+        assert_eq!(params.limit, 50);
+        assert!(params.tool_name.is_none());
+
+        let params: QueryAuditLogParams =
+            serde_json::from_str(r#"{"offset": "100", "limit": 10}"#).unwrap();
+        assert_eq!(params.offset, 100);
+        assert_eq!(params.limit, 10);
+    }
+
     #[test]
     fn test_provenance_chain_params_depth_full() {
         let params: GetProvenanceChainParams =
@@ -2,17 +2,24 @@
 //!
 //! MCP-L11: DTOs extracted to provenance_dtos.rs per *_dtos.rs convention.
 
+use std::collections::{HashSet, VecDeque};
+
 use chrono::DateTime;
 use serde_json::json;
 use tracing::{debug, error};
 use uuid::Uuid;
 
-use context_graph_core::types::audit::{AuditOperation, AuditRecord};
+use context_graph_core::types::audit::{
+    AuditOperation, AuditRecord, ToolAuditQuery, ToolAuditRecord,
+};
 
 use crate::handlers::Handlers;
 use crate::protocol::{JsonRpcId, JsonRpcResponse};
 
-use super::provenance_dtos::{GetAuditTrailParams, GetMergeHistoryParams, GetProvenanceChainParams};
+use super::provenance_dtos::{
+    GetAuditTrailParams, GetMemoryLineageParams, GetMergeHistoryParams, GetProvenanceChainParams,
+    QueryAuditLogParams, MAX_LINEAGE_DEPTH,
+};
 
 /// Serialize an audit record to JSON for API responses.
 fn audit_record_to_json(r: &AuditRecord) -> serde_json::Value {
@@ -29,6 +36,20 @@ fn audit_record_to_json(r: &AuditRecord) -> serde_json::Value {
     })
 }
 
+/// Serialize a tool audit record to JSON for API responses.
+fn tool_audit_record_to_json(r: &ToolAuditRecord) -> serde_json::Value {
+    json!({
+        "id": r.id.to_string(),
+        "timestamp": r.timestamp.to_rfc3339(),
+        "tool_name": r.tool_name,
+        "session_id": r.session_id,
+        "arguments": r.arguments.clone(),
+        "memory_ids": r.memory_ids.iter().map(Uuid::to_string).collect::<Vec<_>>(),
+        "outcome": serde_json::to_value(&r.outcome).unwrap_or_else(|_| json!(null)),
+        "duration_ms": r.duration_ms,
+    })
+}
+
 impl Handlers {
     pub(crate) async fn call_get_audit_trail(
         &self,
@@ -353,6 +374,7 @@ impl Handlers {
                         "embedder_versions": record.embedder_versions,
                         "e7_model_version": record.e7_model_version,
                         "computation_time_ms": record.computation_time_ms,
+                        "deterministic": record.deterministic,
                     }))
                 }
                 Ok(None) => {
@@ -438,4 +460,171 @@ impl Handlers {
             "merge_history": merge_history,
         }))
     }
+
+    pub(crate) async fn call_get_memory_lineage(
+        &self,
+        id: Option<JsonRpcId>,
+        arguments: serde_json::Value,
+    ) -> JsonRpcResponse {
+        debug!("Handling get_memory_lineage tool call");
+
+        let params: GetMemoryLineageParams = match serde_json::from_value(arguments) {
+            Ok(p) => p,
+            Err(e) => {
+                error!(error = %e, "get_memory_lineage: Failed to parse parameters");
+                return self.tool_error(id, &format!("Invalid parameters: {}", e));
+            }
+        };
+
+        let memory_uuid = match Uuid::parse_str(&params.memory_id) {
+            Ok(u) => u,
+            Err(e) => {
+                error!(error = %e, "get_memory_lineage: Invalid memory_id UUID");
+                return self.tool_error(id, &format!("Invalid memory_id UUID: {}", e));
+            }
+        };
+
+        let max_depth = params.max_depth.clamp(1, MAX_LINEAGE_DEPTH);
+
+        // Breadth-first walk from the child towards its ancestors. An ancestor
+        // reachable along several paths is reported once, at its shallowest depth.
+        let mut visited: HashSet<Uuid> = HashSet::from([memory_uuid]);
+        let mut queue: VecDeque<(Uuid, usize)> = VecDeque::from([(memory_uuid, 0)]);
+        let mut ancestors: Vec<(Uuid, usize)> = Vec::new();
+        let mut edges = Vec::new();
+        let mut truncated = false;
+
+        while let Some((node, depth)) = queue.pop_front() {
+            let records = match self.teleological_store.get_lineage_records(node).await {
+                Ok(r) => r,
+                Err(e) => {
+                    error!(error = %e, node = %node, "get_memory_lineage: Lineage query failed");
+                    return self.tool_error(id, &format!("Lineage query failed: {}", e));
+                }
+            };
+
+            if depth >= max_depth {
+                truncated |= !records.is_empty();
+                continue;
+            }
+
+            for record in records {
+                for parent in &record.parent_ids {
+                    edges.push(json!({
+                        "child_id": record.child_id.to_string(),
+                        "parent_id": parent.to_string(),
+                        "operation": record.operation.to_string(),
+                        "similarity": record.similarity,
+                        "timestamp": record.timestamp.to_rfc3339(),
+                    }));
+                    if visited.insert(*parent) {
+                        ancestors.push((*parent, depth + 1));
+                        queue.push_back((*parent, depth + 1));
+                    }
+                }
+            }
+        }
+
+        let mut nodes = Vec::with_capacity(ancestors.len());
+        for (ancestor, depth) in &ancestors {
+            let exists = match self.teleological_store.retrieve(*ancestor).await {
+                Ok(fp) => fp.is_some(),
+                Err(e) => {
+                    error!(error = %e, ancestor = %ancestor, "get_memory_lineage: Ancestor lookup failed");
+                    return self.tool_error(id, &format!("Ancestor lookup failed: {}", e));
+                }
+            };
+            nodes.push(json!({
+                "id": ancestor.to_string(),
+                "depth": depth,
+                "exists": exists,
+            }));
+        }
+
+        self.tool_result(id, json!({
+            "memory_id": params.memory_id,
+            "max_depth": max_depth,
+            "ancestors": nodes,
+            "ancestor_count": nodes.len(),
+            "edges": edges,
+            "truncated": truncated,
+        }))
+    }
+
+    pub(crate) async fn call_query_audit_log(
+        &self,
+        id: Option<JsonRpcId>,
+        arguments: serde_json::Value,
+    ) -> JsonRpcResponse {
+        debug!("Handling query_audit_log tool call");
+
+        let params: QueryAuditLogParams = match serde_json::from_value(arguments) {
+            Ok(p) => p,
+            Err(e) => {
+                error!(error = %e, "query_audit_log: Failed to parse parameters");
+                return self.tool_error(id, &format!("Invalid parameters: {}", e));
+            }
+        };
+
+        let mut query = ToolAuditQuery {
+            tool_name: params.tool_name.clone(),
+            session_id: params.session_id.clone(),
+            offset: params.offset,
+            limit: params.limit.clamp(1, 500),
+            ..Default::default()
+        };
+
+        for (field, value, slot) in [
+            ("start_time", &params.start_time, &mut query.from),
+            ("end_time", &params.end_time, &mut query.to),
+        ] {
+            if let Some(s) = value {
+                match DateTime::parse_from_rfc3339(s) {
+                    Ok(dt) => *slot = Some(dt.with_timezone(&chrono::Utc)),
+                    Err(e) => {
+                        error!(error = %e, "query_audit_log: Invalid {}", field);
+                        return self.tool_error(id, &format!("Invalid {}: {}", field, e));
+                    }
+                }
+            }
+        }
+        if let (Some(from), Some(to)) = (query.from, query.to) {
+            if from > to {
+                return self.tool_error(id, "start_time must not be after end_time");
+            }
+        }
+
+        if let Some(memory_id) = &params.memory_id {
+            match Uuid::parse_str(memory_id) {
+                Ok(u) => query.memory_id = Some(u),
+                Err(e) => {
+                    error!(error = %e, "query_audit_log: Invalid memory_id UUID");
+                    return self.tool_error(id, &format!("Invalid memory_id UUID: {}", e));
+                }
+            }
+        }
+
+        match self.teleological_store.query_tool_audit_log(&query).await {
+            Ok(page) => {
+                let records_json: Vec<serde_json::Value> =
+                    page.records.iter().map(tool_audit_record_to_json).collect();
+                let next_offset = page
+                    .has_more
+                    .then_some(query.offset + records_json.len());
+
+                self.tool_result(id, json!({
+                    "records": records_json,
+                    "count": records_json.len(),
+                    "offset": query.offset,
+                    "limit": query.limit,
+                    "has_more": page.has_more,
+                    "next_offset": next_offset,
+                }))
+            }
+            Err(e) => {
+                error!(error = %e, "query_audit_log: Store query failed");
+                self.tool_error(id, &format!("Tool audit query failed: {}", e))
+            }
+        }
+    }
 }
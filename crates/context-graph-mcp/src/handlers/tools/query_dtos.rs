@@ -0,0 +1,988 @@
+//! DTOs for the query_memories structured query tool.
+//!
+//! A query is a JSON boolean query (not a string language): `must`, `should`
+//! and `must_not` lists of clauses, each one of
+//!
+//! - `{"semantic": {"text", "spaces", "minScore"}}` - similarity in the given
+//!   embedder spaces (default E1), averaged across spaces
+//! - `{"lexical": {"terms": [...]}}` - shares at least one E13 SPLADE term
+//!   with the terms
+//! - `{"metadata": {"field", "op", "value"}}` - compares a memory attribute
+//! - `{"time_range": {"start", "end"}}` - created in `[start, end)`
+//! - `{"topic": {"id"}}` - member of a detected topic
+//!
+//! [`QueryMemoriesRequest`] validates into a [`QueryPlan`]: candidates are
+//! drawn from the lexical and topic clauses of `must` (intersected), else
+//! from its first semantic clause via E1 HNSW, else from every `should`
+//! clause (unioned). Survivors are filtered cheapest clause first and scored
+//! with the semantic and lexical clauses.
+//!
+//! # Constitution Compliance
+//!
+//! - ARCH-12: Semantic recall goes through E1
+//! - ARCH-25: E2-E4 are post-retrieval only and cannot be scored in a clause
+//! - AP-77: E5 needs a causal direction; use search_causes/search_effects
+//! - FAIL FAST: Every clause is validated before anything is executed
+
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use context_graph_core::code::ContentType;
+use context_graph_core::retrieval::SearchDomain;
+use context_graph_core::teleological::Embedder;
+
+use super::validate::ValidateInto;
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Default number of results.
+pub const DEFAULT_QUERY_TOP_K: usize = 10;
+
+/// Maximum number of results.
+pub const MAX_QUERY_TOP_K: usize = 100;
+
+/// Default cap on the candidates drawn by each recall clause.
+pub const DEFAULT_CANDIDATE_LIMIT: usize = 500;
+
+/// Maximum `candidateLimit`.
+pub const MAX_CANDIDATE_LIMIT: usize = 2000;
+
+/// Maximum clauses across `must`, `should` and `must_not`.
+pub const MAX_QUERY_CLAUSES: usize = 16;
+
+/// Maximum semantic and lexical clauses; each embeds its text.
+pub const MAX_EMBEDDED_CLAUSES: usize = 4;
+
+/// Maximum terms in one lexical clause.
+pub const MAX_LEXICAL_TERMS: usize = 32;
+
+/// Maximum values of an `in` metadata clause.
+pub const MAX_METADATA_VALUES: usize = 32;
+
+/// Spaces a semantic clause may score in.
+pub const SEMANTIC_CLAUSE_SPACES: [Embedder; 9] = [
+    Embedder::Semantic,
+    Embedder::Sparse,
+    Embedder::Code,
+    Embedder::Graph,
+    Embedder::Hdc,
+    Embedder::Contextual,
+    Embedder::Entity,
+    Embedder::LateInteraction,
+    Embedder::KeywordSplade,
+];
+
+// ============================================================================
+// REQUEST DTOs
+// ============================================================================
+
+/// Request parameters for query_memories.
+///
+/// # Example JSON
+/// ```json
+/// {
+///   "query": {
+///     "must": [{"semantic": {"text": "how are backups restored", "spaces": ["E1"]}}],
+///     "must_not": [{"metadata": {"field": "domain", "op": "eq", "value": "code"}}]
+///   },
+///   "topK": 10
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct QueryMemoriesRequest {
+    /// The boolean query (required).
+    pub query: BoolQuery,
+
+    /// Maximum number of results (1-100, default: 10).
+    #[serde(rename = "topK", default = "default_top_k")]
+    pub top_k: usize,
+
+    /// Cap on the candidates each recall clause draws (1-2000, default: 500).
+    #[serde(rename = "candidateLimit", default = "default_candidate_limit")]
+    pub candidate_limit: usize,
+
+    /// Whether to include full content text in results (default: false).
+    #[serde(rename = "includeContent", default)]
+    pub include_content: bool,
+}
+
+fn default_top_k() -> usize {
+    DEFAULT_QUERY_TOP_K
+}
+
+fn default_candidate_limit() -> usize {
+    DEFAULT_CANDIDATE_LIMIT
+}
+
+/// Boolean combination of clauses.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BoolQuery {
+    /// Every clause must match.
+    #[serde(default)]
+    pub must: Vec<QueryClause>,
+    /// Optional clauses; at least one must match when `must` is empty.
+    #[serde(default)]
+    pub should: Vec<QueryClause>,
+    /// No clause may match.
+    #[serde(default)]
+    pub must_not: Vec<QueryClause>,
+}
+
+/// One clause as sent by the client.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum QueryClause {
+    Semantic {
+        text: String,
+        /// Embedder spaces to score in (default: ["E1"]).
+        #[serde(default)]
+        spaces: Vec<String>,
+        /// Minimum averaged similarity for the clause to match (default: 0).
+        #[serde(rename = "minScore", default)]
+        min_score: f32,
+    },
+    Lexical {
+        terms: Vec<String>,
+    },
+    Metadata {
+        field: String,
+        op: String,
+        value: serde_json::Value,
+    },
+    TimeRange {
+        /// RFC 3339, inclusive.
+        start: String,
+        /// RFC 3339, exclusive.
+        end: String,
+    },
+    Topic {
+        id: String,
+    },
+}
+
+// ============================================================================
+// COMPILED QUERY
+// ============================================================================
+
+/// Where a clause sits in the boolean query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Occur {
+    Must,
+    Should,
+    MustNot,
+}
+
+impl Occur {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Occur::Must => "must",
+            Occur::Should => "should",
+            Occur::MustNot => "must_not",
+        }
+    }
+}
+
+/// Memory attribute a metadata clause compares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataField {
+    /// Search domain classified from the stored content.
+    Domain,
+    /// Content type classified at store time.
+    ContentType,
+    SourceType,
+    SessionId,
+    FilePath,
+    CreatedBy,
+    Namespace,
+    Importance,
+    AccessCount,
+}
+
+impl MetadataField {
+    pub const NAMES: [&'static str; 9] = [
+        "domain",
+        "contentType",
+        "sourceType",
+        "sessionId",
+        "filePath",
+        "createdBy",
+        "namespace",
+        "importance",
+        "accessCount",
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MetadataField::Domain => "domain",
+            MetadataField::ContentType => "contentType",
+            MetadataField::SourceType => "sourceType",
+            MetadataField::SessionId => "sessionId",
+            MetadataField::FilePath => "filePath",
+            MetadataField::CreatedBy => "createdBy",
+            MetadataField::Namespace => "namespace",
+            MetadataField::Importance => "importance",
+            MetadataField::AccessCount => "accessCount",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "domain" => MetadataField::Domain,
+            "contentType" => MetadataField::ContentType,
+            "sourceType" => MetadataField::SourceType,
+            "sessionId" => MetadataField::SessionId,
+            "filePath" => MetadataField::FilePath,
+            "createdBy" => MetadataField::CreatedBy,
+            "namespace" => MetadataField::Namespace,
+            "importance" => MetadataField::Importance,
+            "accessCount" => MetadataField::AccessCount,
+            _ => return None,
+        })
+    }
+
+    fn is_numeric(self) -> bool {
+        matches!(self, MetadataField::Importance | MetadataField::AccessCount)
+    }
+
+    /// Whether values are names compared case-insensitively.
+    fn is_enumerated(self) -> bool {
+        matches!(
+            self,
+            MetadataField::Domain | MetadataField::ContentType | MetadataField::SourceType
+        )
+    }
+}
+
+/// Comparison of a metadata clause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataOp {
+    Eq,
+    Ne,
+    In,
+    Contains,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+impl MetadataOp {
+    pub const NAMES: [&'static str; 8] = ["eq", "ne", "in", "contains", "gt", "gte", "lt", "lte"];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MetadataOp::Eq => "eq",
+            MetadataOp::Ne => "ne",
+            MetadataOp::In => "in",
+            MetadataOp::Contains => "contains",
+            MetadataOp::Gt => "gt",
+            MetadataOp::Gte => "gte",
+            MetadataOp::Lt => "lt",
+            MetadataOp::Lte => "lte",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "eq" => MetadataOp::Eq,
+            "ne" => MetadataOp::Ne,
+            "in" => MetadataOp::In,
+            "contains" => MetadataOp::Contains,
+            "gt" => MetadataOp::Gt,
+            "gte" => MetadataOp::Gte,
+            "lt" => MetadataOp::Lt,
+            "lte" => MetadataOp::Lte,
+            _ => return None,
+        })
+    }
+}
+
+/// Validated comparison value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataValue {
+    Text(String),
+    List(Vec<String>),
+    Number(f64),
+}
+
+/// Attributes of one memory that metadata clauses compare.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryAttributes {
+    pub domain: Option<SearchDomain>,
+    pub content_type: Option<ContentType>,
+    pub source_type: Option<String>,
+    pub session_id: Option<String>,
+    pub file_path: Option<String>,
+    pub created_by: Option<String>,
+    pub namespace: String,
+    pub importance: f32,
+    pub access_count: u64,
+}
+
+/// A validated clause.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClausePredicate {
+    Semantic {
+        text: String,
+        spaces: Vec<Embedder>,
+        min_score: f32,
+    },
+    Lexical {
+        terms: Vec<String>,
+    },
+    Metadata {
+        field: MetadataField,
+        op: MetadataOp,
+        value: MetadataValue,
+    },
+    TimeRange {
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    },
+    Topic {
+        id: Uuid,
+    },
+}
+
+impl ClausePredicate {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ClausePredicate::Semantic { .. } => "semantic",
+            ClausePredicate::Lexical { .. } => "lexical",
+            ClausePredicate::Metadata { .. } => "metadata",
+            ClausePredicate::TimeRange { .. } => "time_range",
+            ClausePredicate::Topic { .. } => "topic",
+        }
+    }
+
+    /// Whether the clause can draw candidates (recall) instead of only
+    /// filtering them.
+    pub fn is_recall(&self) -> bool {
+        matches!(
+            self,
+            ClausePredicate::Semantic { .. }
+                | ClausePredicate::Lexical { .. }
+                | ClausePredicate::Topic { .. }
+        )
+    }
+
+    /// Whether the clause produces a similarity score.
+    pub fn is_scored(&self) -> bool {
+        matches!(
+            self,
+            ClausePredicate::Semantic { .. } | ClausePredicate::Lexical { .. }
+        )
+    }
+
+    /// Evaluation order: cheap attribute checks first, embedding
+    /// comparisons last.
+    pub fn cost(&self) -> u8 {
+        match self {
+            ClausePredicate::TimeRange { .. } => 0,
+            ClausePredicate::Topic { .. } => 1,
+            ClausePredicate::Metadata { .. } => 2,
+            ClausePredicate::Lexical { .. } => 3,
+            ClausePredicate::Semantic { .. } => 4,
+        }
+    }
+
+    /// Text embedded for a semantic or lexical clause.
+    pub fn embedded_text(&self) -> Option<String> {
+        match self {
+            ClausePredicate::Semantic { text, .. } => Some(text.clone()),
+            ClausePredicate::Lexical { terms } => Some(terms.join(" ")),
+            _ => None,
+        }
+    }
+
+    /// Evaluate a metadata clause; `None` for other kinds.
+    ///
+    /// A missing attribute matches only `ne`.
+    pub fn matches_attributes(&self, attrs: &MemoryAttributes) -> Option<bool> {
+        let ClausePredicate::Metadata { field, op, value } = self else {
+            return None;
+        };
+        if field.is_numeric() {
+            let actual = match field {
+                MetadataField::Importance => attrs.importance as f64,
+                _ => attrs.access_count as f64,
+            };
+            let MetadataValue::Number(expected) = value else {
+                return Some(false);
+            };
+            return Some(match op {
+                MetadataOp::Eq => actual == *expected,
+                MetadataOp::Ne => actual != *expected,
+                MetadataOp::Gt => actual > *expected,
+                MetadataOp::Gte => actual >= *expected,
+                MetadataOp::Lt => actual < *expected,
+                MetadataOp::Lte => actual <= *expected,
+                MetadataOp::In | MetadataOp::Contains => false,
+            });
+        }
+
+        let actual: Option<String> = match field {
+            MetadataField::Domain => attrs.domain.map(|d| d.as_str().to_string()),
+            MetadataField::ContentType => attrs.content_type.map(|t| t.as_str().to_string()),
+            MetadataField::SourceType => attrs.source_type.clone(),
+            MetadataField::SessionId => attrs.session_id.clone(),
+            MetadataField::FilePath => attrs.file_path.clone(),
+            MetadataField::CreatedBy => attrs.created_by.clone(),
+            MetadataField::Namespace => Some(attrs.namespace.clone()),
+            MetadataField::Importance | MetadataField::AccessCount => unreachable!(),
+        };
+        let Some(actual) = actual else {
+            return Some(*op == MetadataOp::Ne);
+        };
+        let same = |expected: &str| {
+            if field.is_enumerated() {
+                actual.eq_ignore_ascii_case(expected)
+            } else {
+                actual == expected
+            }
+        };
+        Some(match (op, value) {
+            (MetadataOp::Eq, MetadataValue::Text(v)) => same(v),
+            (MetadataOp::Ne, MetadataValue::Text(v)) => !same(v),
+            (MetadataOp::In, MetadataValue::List(vs)) => vs.iter().any(|v| same(v)),
+            (MetadataOp::Contains, MetadataValue::Text(v)) => actual.contains(v.as_str()),
+            _ => false,
+        })
+    }
+}
+
+/// A validated clause and its position in the query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompiledClause {
+    pub occur: Occur,
+    /// Index within its `must`/`should`/`must_not` list.
+    pub index: usize,
+    pub predicate: ClausePredicate,
+}
+
+impl CompiledClause {
+    /// Position in the query, e.g. `must_not[0]`.
+    pub fn label(&self) -> String {
+        format!("{}[{}]", self.occur.as_str(), self.index)
+    }
+}
+
+/// How the candidate sets of the recall clauses are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combine {
+    Intersect,
+    Union,
+}
+
+impl Combine {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Combine::Intersect => "intersect",
+            Combine::Union => "union",
+        }
+    }
+}
+
+/// Execution plan compiled from a [`QueryMemoriesRequest`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryPlan {
+    /// All clauses, in evaluation order (cheapest first).
+    pub clauses: Vec<CompiledClause>,
+    /// Indices into `clauses` that draw candidates.
+    pub recall: Vec<usize>,
+    /// How the recall candidate sets are combined.
+    pub combine: Combine,
+    /// `time_range` of `must`, pushed down into semantic recall.
+    pub time_range: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    pub top_k: usize,
+    pub candidate_limit: usize,
+}
+
+impl QueryPlan {
+    /// Number of clauses contributing to the fused score: semantic and
+    /// lexical clauses in `must`, and every `should` clause.
+    pub fn scored_clause_count(&self) -> usize {
+        self.clauses
+            .iter()
+            .filter(|c| match c.occur {
+                Occur::Must => c.predicate.is_scored(),
+                Occur::Should => true,
+                Occur::MustNot => false,
+            })
+            .count()
+    }
+
+    pub fn has_must(&self) -> bool {
+        self.clauses.iter().any(|c| c.occur == Occur::Must)
+    }
+
+    /// Whether any metadata clause compares `field`.
+    pub fn uses_field(&self, field: MetadataField) -> bool {
+        self.clauses.iter().any(
+            |c| matches!(c.predicate, ClausePredicate::Metadata { field: f, .. } if f == field),
+        )
+    }
+}
+
+// ============================================================================
+// VALIDATION
+// ============================================================================
+
+fn compile_clause(clause: &QueryClause, label: &str) -> Result<ClausePredicate, String> {
+    match clause {
+        QueryClause::Semantic {
+            text,
+            spaces,
+            min_score,
+        } => {
+            if text.trim().is_empty() {
+                return Err(format!("{}: semantic text cannot be empty", label));
+            }
+            if !(0.0..=1.0).contains(min_score) {
+                return Err(format!(
+                    "{}: minScore must be between 0.0 and 1.0, got {}",
+                    label, min_score
+                ));
+            }
+            let mut embedders = Vec::with_capacity(spaces.len().max(1));
+            for space in spaces {
+                let embedder = Embedder::from_name(space)
+                    .ok()
+                    .filter(|e| SEMANTIC_CLAUSE_SPACES.contains(e))
+                    .ok_or_else(|| {
+                        format!(
+                            "{}: unsupported space '{}'. Valid: {} (E2-E4 are post-retrieval \
+                             only, E5 needs search_causes/search_effects)",
+                            label,
+                            space,
+                            SEMANTIC_CLAUSE_SPACES
+                                .iter()
+                                .map(|e| e.short_name())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )
+                    })?;
+                if !embedders.contains(&embedder) {
+                    embedders.push(embedder);
+                }
+            }
+            if embedders.is_empty() {
+                embedders.push(Embedder::Semantic);
+            }
+            Ok(ClausePredicate::Semantic {
+                text: text.clone(),
+                spaces: embedders,
+                min_score: *min_score,
+            })
+        }
+        QueryClause::Lexical { terms } => {
+            let terms: Vec<String> = terms
+                .iter()
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect();
+            if terms.is_empty() {
+                return Err(format!("{}: lexical terms cannot be empty", label));
+            }
+            if terms.len() > MAX_LEXICAL_TERMS {
+                return Err(format!(
+                    "{}: at most {} lexical terms, got {}",
+                    label,
+                    MAX_LEXICAL_TERMS,
+                    terms.len()
+                ));
+            }
+            Ok(ClausePredicate::Lexical { terms })
+        }
+        QueryClause::Metadata { field, op, value } => {
+            let field = MetadataField::from_name(field).ok_or_else(|| {
+                format!(
+                    "{}: unknown metadata field '{}'. Valid: {}",
+                    label,
+                    field,
+                    MetadataField::NAMES.join(", ")
+                )
+            })?;
+            let op = MetadataOp::from_name(op).ok_or_else(|| {
+                format!(
+                    "{}: unknown op '{}'. Valid: {}",
+                    label,
+                    op,
+                    MetadataOp::NAMES.join(", ")
+                )
+            })?;
+            let value = compile_metadata_value(field, op, value)
+                .map_err(|msg| format!("{}: {}", label, msg))?;
+            Ok(ClausePredicate::Metadata { field, op, value })
+        }
+        QueryClause::TimeRange { start, end } => {
+            let parse = |s: &str| {
+                DateTime::parse_from_rfc3339(s)
+                    .ok()
+                    .map(|t| t.with_timezone(&Utc))
+            };
+            match (parse(start), parse(end)) {
+                (Some(start), Some(end)) if start < end => {
+                    Ok(ClausePredicate::TimeRange { start, end })
+                }
+                _ => Err(format!(
+                    "{}: time_range needs RFC 3339 'start' and 'end' with start < end",
+                    label
+                )),
+            }
+        }
+        QueryClause::Topic { id } => Uuid::parse_str(id)
+            .map(|id| ClausePredicate::Topic { id })
+            .map_err(|_| format!("{}: invalid topic id '{}'", label, id)),
+    }
+}
+
+fn compile_metadata_value(
+    field: MetadataField,
+    op: MetadataOp,
+    value: &serde_json::Value,
+) -> Result<MetadataValue, String> {
+    let check_name = |s: &str| -> Result<(), String> {
+        match field {
+            MetadataField::Domain => s.parse::<SearchDomain>().map(|_| ()),
+            MetadataField::ContentType => s.parse::<ContentType>().map(|_| ()),
+            _ => Ok(()),
+        }
+    };
+
+    if field.is_numeric() {
+        if !matches!(
+            op,
+            MetadataOp::Eq
+                | MetadataOp::Ne
+                | MetadataOp::Gt
+                | MetadataOp::Gte
+                | MetadataOp::Lt
+                | MetadataOp::Lte
+        ) {
+            return Err(format!(
+                "op '{}' does not apply to numeric field '{}'",
+                op.as_str(),
+                field.as_str()
+            ));
+        }
+        return value
+            .as_f64()
+            .map(MetadataValue::Number)
+            .ok_or_else(|| format!("'{}' needs a numeric value", field.as_str()));
+    }
+
+    match op {
+        MetadataOp::Eq | MetadataOp::Ne | MetadataOp::Contains => {
+            let s = value
+                .as_str()
+                .ok_or_else(|| format!("'{}' needs a string value", field.as_str()))?;
+            if op != MetadataOp::Contains {
+                check_name(s)?;
+            }
+            Ok(MetadataValue::Text(s.to_string()))
+        }
+        MetadataOp::In => {
+            let values = value
+                .as_array()
+                .and_then(|vs| {
+                    vs.iter()
+                        .map(|v| v.as_str().map(String::from))
+                        .collect::<Option<Vec<_>>>()
+                })
+                .filter(|vs| !vs.is_empty())
+                .ok_or_else(|| "op 'in' needs a non-empty array of strings".to_string())?;
+            if values.len() > MAX_METADATA_VALUES {
+                return Err(format!(
+                    "op 'in' takes at most {} values, got {}",
+                    MAX_METADATA_VALUES,
+                    values.len()
+                ));
+            }
+            for v in &values {
+                check_name(v)?;
+            }
+            Ok(MetadataValue::List(values))
+        }
+        MetadataOp::Gt | MetadataOp::Gte | MetadataOp::Lt | MetadataOp::Lte => Err(format!(
+            "op '{}' applies only to importance and accessCount",
+            op.as_str()
+        )),
+    }
+}
+
+impl ValidateInto for QueryMemoriesRequest {
+    type Output = QueryPlan;
+
+    fn validate(&self) -> Result<QueryPlan, String> {
+        if self.top_k == 0 || self.top_k > MAX_QUERY_TOP_K {
+            return Err(format!(
+                "topK must be between 1 and {}, got {}",
+                MAX_QUERY_TOP_K, self.top_k
+            ));
+        }
+        if self.candidate_limit == 0 || self.candidate_limit > MAX_CANDIDATE_LIMIT {
+            return Err(format!(
+                "candidateLimit must be between 1 and {}, got {}",
+                MAX_CANDIDATE_LIMIT, self.candidate_limit
+            ));
+        }
+
+        let query = &self.query;
+        let total = query.must.len() + query.should.len() + query.must_not.len();
+        if total > MAX_QUERY_CLAUSES {
+            return Err(format!(
+                "A query takes at most {} clauses, got {}",
+                MAX_QUERY_CLAUSES, total
+            ));
+        }
+        if query.must.is_empty() && query.should.is_empty() {
+            return Err("A query needs at least one must or should clause".to_string());
+        }
+
+        let mut clauses = Vec::with_capacity(total);
+        for (occur, list) in [
+            (Occur::Must, &query.must),
+            (Occur::Should, &query.should),
+            (Occur::MustNot, &query.must_not),
+        ] {
+            for (index, clause) in list.iter().enumerate() {
+                let label = format!("{}[{}]", occur.as_str(), index);
+                clauses.push(CompiledClause {
+                    occur,
+                    index,
+                    predicate: compile_clause(clause, &label)?,
+                });
+            }
+        }
+
+        let embedded = clauses
+            .iter()
+            .filter(|c| c.predicate.embedded_text().is_some())
+            .count();
+        if embedded > MAX_EMBEDDED_CLAUSES {
+            return Err(format!(
+                "A query takes at most {} semantic and lexical clauses, got {}",
+                MAX_EMBEDDED_CLAUSES, embedded
+            ));
+        }
+
+        // Stable sort keeps must before should before must_not within a cost
+        clauses.sort_by_key(|c| c.predicate.cost());
+
+        let must_recall = |pred: fn(&ClausePredicate) -> bool| -> Vec<usize> {
+            clauses
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| c.occur == Occur::Must && pred(&c.predicate))
+                .map(|(i, _)| i)
+                .collect()
+        };
+        let exact = must_recall(|p| {
+            matches!(
+                p,
+                ClausePredicate::Lexical { .. } | ClausePredicate::Topic { .. }
+            )
+        });
+        let semantic = must_recall(|p| matches!(p, ClausePredicate::Semantic { .. }));
+        let (recall, combine) = if !exact.is_empty() {
+            (exact, Combine::Intersect)
+        } else if let Some(&first) = semantic.first() {
+            (vec![first], Combine::Intersect)
+        } else {
+            let should: Vec<usize> = clauses
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| c.occur == Occur::Should)
+                .map(|(i, _)| i)
+                .collect();
+            if let Some(c) = should
+                .iter()
+                .map(|&i| &clauses[i])
+                .find(|c| !c.predicate.is_recall())
+            {
+                return Err(format!(
+                    "{} ({}) cannot draw candidates: without a semantic, lexical or topic \
+                     clause in must, every should clause must be one",
+                    c.label(),
+                    c.predicate.kind()
+                ));
+            }
+            if should.is_empty() {
+                return Err(
+                    "A query needs a semantic, lexical or topic clause in must or should"
+                        .to_string(),
+                );
+            }
+            (should, Combine::Union)
+        };
+
+        let time_range = clauses.iter().find_map(|c| match c.predicate {
+            ClausePredicate::TimeRange { start, end } if c.occur == Occur::Must => {
+                Some((start, end))
+            }
+            _ => None,
+        });
+
+        Ok(QueryPlan {
+            clauses,
+            recall,
+            combine,
+            time_range,
+            top_k: self.top_k,
+            candidate_limit: self.candidate_limit,
+        })
+    }
+}
+
+impl fmt::Display for QueryPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let recall: Vec<String> = self
+            .recall
+            .iter()
+            .map(|&i| self.clauses[i].label())
+            .collect();
+        write!(f, "{}({})", self.combine.as_str(), recall.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn compile(query: serde_json::Value) -> Result<QueryPlan, String> {
+        let request: QueryMemoriesRequest =
+            serde_json::from_value(json!({ "query": query })).map_err(|e| e.to_string())?;
+        request.validate()
+    }
+
+    #[test]
+    fn test_recall_prefers_exact_must_clauses() {
+        let plan = compile(json!({
+            "must": [
+                {"semantic": {"text": "restore a backup"}},
+                {"lexical": {"terms": ["rocksdb", "checkpoint"]}},
+                {"time_range": {"start": "2026-01-01T00:00:00Z", "end": "2026-02-01T00:00:00Z"}}
+            ],
+            "must_not": [{"metadata": {"field": "domain", "op": "eq", "value": "Code"}}]
+        }))
+        .unwrap();
+
+        assert_eq!(plan.combine, Combine::Intersect);
+        assert_eq!(plan.to_string(), "intersect(must[1])");
+        assert!(plan.time_range.is_some());
+        // Cheapest first: time_range, metadata, lexical, semantic
+        let kinds: Vec<&str> = plan.clauses.iter().map(|c| c.predicate.kind()).collect();
+        assert_eq!(kinds, ["time_range", "metadata", "lexical", "semantic"]);
+        assert_eq!(plan.scored_clause_count(), 2);
+
+        let semantic_only = compile(json!({
+            "must": [{"semantic": {"text": "a"}}, {"semantic": {"text": "b", "spaces": ["E7"]}}]
+        }))
+        .unwrap();
+        assert_eq!(semantic_only.to_string(), "intersect(must[0])");
+    }
+
+    #[test]
+    fn test_should_only_query_unions_recall_clauses() {
+        let plan = compile(json!({
+            "should": [{"semantic": {"text": "a"}}, {"topic": {"id": Uuid::nil().to_string()}}]
+        }))
+        .unwrap();
+        assert_eq!(plan.combine, Combine::Union);
+        assert_eq!(plan.recall.len(), 2);
+        assert!(!plan.has_must());
+
+        let err = compile(json!({
+            "should": [
+                {"semantic": {"text": "a"}},
+                {"metadata": {"field": "sessionId", "op": "eq", "value": "s1"}}
+            ]
+        }))
+        .unwrap_err();
+        assert!(
+            err.contains("should[1] (metadata) cannot draw candidates"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_validation_limits() {
+        let too_many: Vec<_> = (0..=MAX_QUERY_CLAUSES)
+            .map(|_| json!({"metadata": {"field": "importance", "op": "gt", "value": 0.5}}))
+            .collect();
+        assert!(compile(json!({ "must": too_many }))
+            .unwrap_err()
+            .contains("at most"));
+
+        let embedded: Vec<_> = (0..=MAX_EMBEDDED_CLAUSES)
+            .map(|i| json!({"semantic": {"text": format!("q{}", i)}}))
+            .collect();
+        assert!(compile(json!({ "should": embedded })).is_err());
+
+        for bad in [
+            json!({"must_not": [{"semantic": {"text": "a"}}]}),
+            json!({"must": [{"semantic": {"text": "a", "spaces": ["E2"]}}]}),
+            json!({"must": [{"semantic": {"text": "a", "spaces": ["E5"]}}]}),
+            json!({"must": [{"lexical": {"terms": ["  "]}}]}),
+            json!({"must": [{"metadata": {"field": "domain", "op": "eq", "value": "cooking"}}]}),
+            json!({"must": [{"metadata": {"field": "sessionId", "op": "gt", "value": "a"}}]}),
+            json!({"must": [{"metadata": {"field": "colour", "op": "eq", "value": "a"}}]}),
+            json!({"must": [{"time_range": {"start": "2026-02-01T00:00:00Z", "end": "2026-01-01T00:00:00Z"}}]}),
+            json!({"must": [{"topic": {"id": "not-a-uuid"}}]}),
+            json!({"must": [{"vector": {"values": [1.0]}}]}),
+        ] {
+            assert!(compile(bad.clone()).is_err(), "accepted {}", bad);
+        }
+
+        let request: QueryMemoriesRequest = serde_json::from_value(json!({
+            "query": {"must": [{"semantic": {"text": "a"}}]},
+            "candidateLimit": MAX_CANDIDATE_LIMIT + 1
+        }))
+        .unwrap();
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn test_metadata_matching() {
+        let attrs = MemoryAttributes {
+            domain: Some(SearchDomain::Code),
+            content_type: Some(ContentType::Code),
+            session_id: Some("session-1".to_string()),
+            namespace: "default".to_string(),
+            importance: 0.7,
+            access_count: 3,
+            ..Default::default()
+        };
+        let eval = |field: &str, op: &str, value: serde_json::Value| {
+            let clause = QueryClause::Metadata {
+                field: field.to_string(),
+                op: op.to_string(),
+                value,
+            };
+            compile_clause(&clause, "must[0]")
+                .unwrap()
+                .matches_attributes(&attrs)
+                .unwrap()
+        };
+
+        assert!(eval("domain", "eq", json!("Code")));
+        assert!(!eval("domain", "ne", json!("code")));
+        assert!(eval("contentType", "in", json!(["prose", "code"])));
+        assert!(eval("sessionId", "contains", json!("sion")));
+        assert!(!eval("sessionId", "eq", json!("SESSION-1")));
+        assert!(eval("importance", "gte", json!(0.7)));
+        assert!(eval("accessCount", "lt", json!(4)));
+        // Missing attributes only match `ne`
+        assert!(!eval("filePath", "eq", json!("a.md")));
+        assert!(eval("filePath", "ne", json!("a.md")));
+    }
+}
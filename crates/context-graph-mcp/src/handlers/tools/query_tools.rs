@@ -0,0 +1,477 @@
+//! Structured query tool implementation (query_memories).
+//!
+//! Executes a [`QueryPlan`] compiled from the query DSL in one call, instead
+//! of the client stitching together SPLADE recall, semantic search and
+//! metadata filtering:
+//!
+//! 1. Recall: each recall clause draws up to `candidateLimit` ids (lexical
+//!    via the E13 inverted index, topic via its members, semantic via E1
+//!    HNSW); the sets are intersected or unioned
+//! 2. Load: fingerprints of the candidates in the namespace, plus content and
+//!    source metadata when a clause or `includeContent` needs them
+//! 3. Filter: `must` and `must_not` clauses, cheapest first
+//! 4. Score: semantic and lexical clauses are scored on the survivors and
+//!    fused by averaging; `should` clauses that match add 1.0 unless scored
+//!
+//! The response carries the executed plan with candidate counts per stage.
+
+use std::collections::{HashMap, HashSet};
+
+use serde_json::json;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use context_graph_core::retrieval::{classify_search_domain, compute_similarity_for_space};
+use context_graph_core::teleological::Embedder;
+use context_graph_core::traits::{SearchStrategy, TeleologicalSearchOptions};
+use context_graph_core::types::fingerprint::{SemanticFingerprint, TeleologicalFingerprint};
+
+use crate::protocol::JsonRpcId;
+use crate::protocol::JsonRpcResponse;
+
+use super::super::Handlers;
+use super::helpers::ToolErrorKind;
+use super::memory_tools::parse_namespace;
+use super::query_dtos::{
+    ClausePredicate, Combine, MemoryAttributes, MetadataField, Occur, QueryMemoriesRequest,
+    QueryPlan, MAX_EMBEDDED_CLAUSES, MAX_QUERY_CLAUSES,
+};
+
+const TOOL: &str = "query_memories";
+
+/// A loaded candidate and the clause outcomes recorded so far.
+struct Candidate {
+    fingerprint: TeleologicalFingerprint,
+    content: Option<String>,
+    attributes: MemoryAttributes,
+    /// (clause label, score) of every matched scored clause.
+    scores: Vec<(String, f32)>,
+    /// Labels of matched clauses.
+    matched: Vec<String>,
+}
+
+/// Outcome of one clause on one candidate: whether it matched, and its
+/// similarity for scored clauses.
+fn evaluate(
+    predicate: &ClausePredicate,
+    candidate: &Candidate,
+    query: Option<&SemanticFingerprint>,
+    topics: &HashMap<Uuid, HashSet<Uuid>>,
+) -> (bool, Option<f32>) {
+    let fp = &candidate.fingerprint;
+    match predicate {
+        ClausePredicate::TimeRange { start, end } => {
+            (fp.created_at >= *start && fp.created_at < *end, None)
+        }
+        ClausePredicate::Topic { id } => (
+            topics
+                .get(id)
+                .is_some_and(|members| members.contains(&fp.id)),
+            None,
+        ),
+        ClausePredicate::Metadata { .. } => (
+            predicate
+                .matches_attributes(&candidate.attributes)
+                .unwrap_or(false),
+            None,
+        ),
+        ClausePredicate::Lexical { .. } => {
+            let query = query.expect("lexical clause embedded");
+            let sim = compute_similarity_for_space(Embedder::KeywordSplade, query, &fp.semantic);
+            (sim > 0.0, Some(sim))
+        }
+        ClausePredicate::Semantic {
+            spaces, min_score, ..
+        } => {
+            let query = query.expect("semantic clause embedded");
+            let sim = spaces
+                .iter()
+                .map(|space| compute_similarity_for_space(*space, query, &fp.semantic))
+                .sum::<f32>()
+                / spaces.len() as f32;
+            (sim >= *min_score, Some(sim))
+        }
+    }
+}
+
+impl Handlers {
+    /// query_memories tool implementation.
+    ///
+    /// # Parameters
+    ///
+    /// - `query`: `{must, should, must_not}` lists of clauses (required)
+    /// - `topK`: Maximum results (1-100, default: 10)
+    /// - `candidateLimit`: Candidates per recall clause (1-2000, default: 500)
+    /// - `namespace`: Namespace to search (default: "default")
+    /// - `includeContent`: Include full content text (default: false)
+    pub(crate) async fn call_query_memories(
+        &self,
+        id: Option<JsonRpcId>,
+        args: serde_json::Value,
+    ) -> JsonRpcResponse {
+        let namespace = match parse_namespace(&args) {
+            Ok(ns) => ns,
+            Err(msg) => return self.tool_error_typed(id, ToolErrorKind::Validation, &msg),
+        };
+        let (request, plan): (QueryMemoriesRequest, QueryPlan) =
+            match self.parse_request_validated(id.clone(), args, TOOL) {
+                Ok(parsed) => parsed,
+                Err(resp) => return resp,
+            };
+
+        info!(
+            clauses = plan.clauses.len(),
+            recall = %plan,
+            top_k = plan.top_k,
+            candidate_limit = plan.candidate_limit,
+            "query_memories: Executing plan"
+        );
+
+        // Embed every semantic and lexical clause once
+        let mut embeddings: HashMap<usize, SemanticFingerprint> = HashMap::new();
+        for (i, clause) in plan.clauses.iter().enumerate() {
+            if let Some(text) = clause.predicate.embedded_text() {
+                match self.embed_query(id.clone(), &text, TOOL).await {
+                    Ok(fp) => {
+                        embeddings.insert(i, fp);
+                    }
+                    Err(resp) => return resp,
+                }
+            }
+        }
+
+        // Member sets of every referenced topic
+        let mut topics: HashMap<Uuid, HashSet<Uuid>> = HashMap::new();
+        let topic_ids: Vec<Uuid> = plan
+            .clauses
+            .iter()
+            .filter_map(|c| match c.predicate {
+                ClausePredicate::Topic { id } => Some(id),
+                _ => None,
+            })
+            .collect();
+        if !topic_ids.is_empty() {
+            let records = match self.teleological_store.list_topic_records().await {
+                Ok(records) => records,
+                Err(e) => return self.tool_error_from(id, TOOL, "Failed to load topic records", e),
+            };
+            for topic_id in topic_ids {
+                let Some(record) = records.iter().find(|r| r.id == topic_id) else {
+                    return self.tool_error_typed(
+                        id,
+                        ToolErrorKind::NotFound,
+                        &format!("Topic {} not found; run detect_topics first", topic_id),
+                    );
+                };
+                topics.insert(topic_id, record.member_ids.iter().copied().collect());
+            }
+        }
+
+        // =====================================================================
+        // STAGE 1: RECALL
+        // =====================================================================
+        let limit = plan.candidate_limit;
+        let mut sources = Vec::with_capacity(plan.recall.len());
+        let mut candidate_sets: Vec<Vec<Uuid>> = Vec::with_capacity(plan.recall.len());
+        for &ci in &plan.recall {
+            let clause = &plan.clauses[ci];
+            let (via, ids): (&str, Vec<Uuid>) = match &clause.predicate {
+                ClausePredicate::Lexical { .. } => {
+                    match self
+                        .teleological_store
+                        .search_sparse(&embeddings[&ci].e13_splade, limit)
+                        .await
+                    {
+                        Ok(hits) => (
+                            "E13 inverted index",
+                            hits.into_iter().map(|(id, _)| id).collect(),
+                        ),
+                        Err(e) => {
+                            return self.tool_error_from(id, TOOL, "Lexical recall failed", e)
+                        }
+                    }
+                }
+                ClausePredicate::Topic { id: topic_id } => {
+                    let mut members: Vec<Uuid> = topics[topic_id].iter().copied().collect();
+                    members.sort();
+                    ("topic members", members)
+                }
+                ClausePredicate::Semantic { .. } => {
+                    let mut options = TeleologicalSearchOptions::quick(limit)
+                        .with_strategy(SearchStrategy::E1Only)
+                        .with_namespace(namespace.clone());
+                    if let Some((start, end)) = plan.time_range {
+                        options = options.with_time_range(start, end);
+                    }
+                    match self
+                        .teleological_store
+                        .search_semantic(&embeddings[&ci], options)
+                        .await
+                    {
+                        Ok(results) => (
+                            "E1 HNSW",
+                            results.into_iter().map(|r| r.fingerprint.id).collect(),
+                        ),
+                        Err(e) => {
+                            return self.tool_error_from(id, TOOL, "Semantic recall failed", e)
+                        }
+                    }
+                }
+                ClausePredicate::Metadata { .. } | ClausePredicate::TimeRange { .. } => {
+                    unreachable!(
+                        "compiled plans only recall from semantic, lexical and topic clauses"
+                    )
+                }
+            };
+            let truncated = ids.len() >= limit;
+            sources.push(json!({
+                "clause": clause.label(),
+                "kind": clause.predicate.kind(),
+                "via": via,
+                "candidates": ids.len().min(limit),
+                "truncated": truncated,
+            }));
+            candidate_sets.push(ids.into_iter().take(limit).collect());
+        }
+
+        let candidate_ids: Vec<Uuid> = match plan.combine {
+            Combine::Intersect => {
+                let others: Vec<HashSet<Uuid>> = candidate_sets[1..]
+                    .iter()
+                    .map(|set| set.iter().copied().collect())
+                    .collect();
+                candidate_sets[0]
+                    .iter()
+                    .copied()
+                    .filter(|id| others.iter().all(|set| set.contains(id)))
+                    .collect()
+            }
+            Combine::Union => {
+                let mut seen = HashSet::new();
+                candidate_sets
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .filter(|id| seen.insert(*id))
+                    .collect()
+            }
+        };
+        debug!(
+            candidates = candidate_ids.len(),
+            combine = plan.combine.as_str(),
+            "query_memories: Recall complete"
+        );
+
+        // =====================================================================
+        // STAGE 2: LOAD
+        // =====================================================================
+        let fingerprints = match self.teleological_store.retrieve_batch(&candidate_ids).await {
+            Ok(fps) => fps,
+            Err(e) => return self.tool_error_from(id, TOOL, "Failed to load candidates", e),
+        };
+        let loaded: Vec<TeleologicalFingerprint> = fingerprints
+            .into_iter()
+            .flatten()
+            .filter(|fp| fp.namespace == namespace)
+            .collect();
+        let loaded_ids: Vec<Uuid> = loaded.iter().map(|fp| fp.id).collect();
+
+        let needs_content = request.include_content || plan.uses_field(MetadataField::Domain);
+        let contents = if needs_content && !loaded_ids.is_empty() {
+            match self.teleological_store.get_content_batch(&loaded_ids).await {
+                Ok(contents) => contents,
+                Err(e) => return self.tool_error_from(id, TOOL, "Failed to load content", e),
+            }
+        } else {
+            vec![None; loaded_ids.len()]
+        };
+        let needs_metadata = [
+            MetadataField::ContentType,
+            MetadataField::SourceType,
+            MetadataField::SessionId,
+            MetadataField::FilePath,
+            MetadataField::CreatedBy,
+        ]
+        .into_iter()
+        .any(|field| plan.uses_field(field));
+        let metadata = if needs_metadata && !loaded_ids.is_empty() {
+            match self
+                .teleological_store
+                .get_source_metadata_batch(&loaded_ids)
+                .await
+            {
+                Ok(metadata) => metadata,
+                Err(e) => {
+                    return self.tool_error_from(id, TOOL, "Failed to load source metadata", e)
+                }
+            }
+        } else {
+            vec![None; loaded_ids.len()]
+        };
+
+        let mut survivors: Vec<Candidate> = loaded
+            .into_iter()
+            .zip(contents)
+            .zip(metadata)
+            .map(|((fingerprint, content), meta)| {
+                let attributes = MemoryAttributes {
+                    domain: content.as_deref().map(|c| classify_search_domain(c).domain),
+                    content_type: meta
+                        .as_ref()
+                        .and_then(|m| m.content_classification.as_ref())
+                        .map(|c| c.content_type),
+                    source_type: meta.as_ref().map(|m| m.source_type.to_string()),
+                    session_id: meta.as_ref().and_then(|m| m.session_id.clone()),
+                    file_path: meta.as_ref().and_then(|m| m.file_path.clone()),
+                    created_by: meta.as_ref().and_then(|m| m.created_by.clone()),
+                    namespace: fingerprint.namespace.clone(),
+                    importance: fingerprint.importance,
+                    access_count: fingerprint.access_count,
+                };
+                Candidate {
+                    fingerprint,
+                    content,
+                    attributes,
+                    scores: Vec::new(),
+                    matched: Vec::new(),
+                }
+            })
+            .collect();
+        let loaded_count = survivors.len();
+
+        // =====================================================================
+        // STAGE 3: FILTER (must / must_not, cheapest first)
+        // =====================================================================
+        let mut filters = Vec::new();
+        for (ci, clause) in plan.clauses.iter().enumerate() {
+            if clause.occur == Occur::Should {
+                continue;
+            }
+            let label = clause.label();
+            let before = survivors.len();
+            survivors.retain_mut(|candidate| {
+                let (matched, score) =
+                    evaluate(&clause.predicate, candidate, embeddings.get(&ci), &topics);
+                if matched && clause.occur == Occur::Must {
+                    candidate.matched.push(label.clone());
+                    if let Some(score) = score {
+                        candidate.scores.push((label.clone(), score));
+                    }
+                }
+                matched == (clause.occur == Occur::Must)
+            });
+            filters.push(json!({
+                "clause": label,
+                "kind": clause.predicate.kind(),
+                "removed": before - survivors.len(),
+            }));
+        }
+
+        // =====================================================================
+        // STAGE 4: SHOULD + SCORE
+        // =====================================================================
+        for (ci, clause) in plan.clauses.iter().enumerate() {
+            if clause.occur != Occur::Should {
+                continue;
+            }
+            let label = clause.label();
+            for candidate in survivors.iter_mut() {
+                let (matched, score) =
+                    evaluate(&clause.predicate, candidate, embeddings.get(&ci), &topics);
+                if matched {
+                    candidate.matched.push(label.clone());
+                    candidate.scores.push((label.clone(), score.unwrap_or(1.0)));
+                }
+            }
+        }
+        if !plan.has_must() {
+            let before = survivors.len();
+            survivors.retain(|c| !c.matched.is_empty());
+            filters.push(json!({
+                "clause": "should",
+                "kind": "minimum_should_match",
+                "removed": before - survivors.len(),
+            }));
+        }
+
+        let scored_clauses = plan.scored_clause_count().max(1) as f32;
+        let mut ranked: Vec<(f32, Candidate)> = survivors
+            .into_iter()
+            .map(|c| {
+                (
+                    c.scores.iter().map(|(_, s)| s).sum::<f32>() / scored_clauses,
+                    c,
+                )
+            })
+            .collect();
+        ranked.sort_by(|a, b| {
+            b.0.partial_cmp(&a.0)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.1.fingerprint.id.cmp(&b.1.fingerprint.id))
+        });
+        let survivor_count = ranked.len();
+        ranked.truncate(plan.top_k);
+
+        let results: Vec<serde_json::Value> = ranked
+            .into_iter()
+            .map(|(score, candidate)| {
+                let mut result = json!({
+                    "fingerprintId": candidate.fingerprint.id.to_string(),
+                    "score": score,
+                    "matchedClauses": candidate.matched,
+                    "clauseScores": candidate.scores.iter().cloned().collect::<HashMap<_, _>>(),
+                    "createdAt": candidate.fingerprint.created_at.to_rfc3339(),
+                });
+                if request.include_content {
+                    result["content"] = json!(candidate.content);
+                }
+                result
+            })
+            .collect();
+
+        let scoring: Vec<String> = plan
+            .clauses
+            .iter()
+            .filter(|c| match c.occur {
+                Occur::Must => c.predicate.is_scored(),
+                Occur::Should => true,
+                Occur::MustNot => false,
+            })
+            .map(|c| c.label())
+            .collect();
+
+        info!(
+            candidates = candidate_ids.len(),
+            survivors = survivor_count,
+            returned = results.len(),
+            "query_memories: Completed"
+        );
+
+        let response = json!({
+            "results": results,
+            "count": results.len(),
+            "namespace": namespace,
+            "plan": {
+                "recall": {
+                    "combine": plan.combine.as_str(),
+                    "sources": sources,
+                    "candidates": candidate_ids.len(),
+                },
+                "loaded": loaded_count,
+                "filters": filters,
+                "scoring": {
+                    "clauses": scoring,
+                    "fusion": "mean",
+                },
+                "survivors": survivor_count,
+                "returned": results.len(),
+                "limits": {
+                    "candidateLimit": plan.candidate_limit,
+                    "maxClauses": MAX_QUERY_CLAUSES,
+                    "maxEmbeddedClauses": MAX_EMBEDDED_CLAUSES,
+                },
+            },
+        });
+        self.tool_result(id, response)
+    }
+}
@@ -0,0 +1,352 @@
+//! Batched search tool implementation (search_graph_batch).
+//!
+//! Runs many search_graph queries in one tools/call for agents that fan out
+//! several searches per step. Every query text that is not already in the
+//! query embedding cache is embedded in one `embed_batch_all` call and
+//! cached; each query then runs through search_graph unchanged, finds its
+//! embedding in the cache, and returns exactly what an individual call
+//! would. The searches then run interleaved on this task, up to one per
+//! search slot of the dispatch limiter: the batch's own slot plus any free
+//! ones it can take without waiting, so a batch never exceeds the search
+//! concurrency cap. [`MAX_BATCH_CANDIDATES`] bounds the total work.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Instant;
+
+use futures::stream::{self, StreamExt};
+use serde::Deserialize;
+use serde_json::json;
+use tracing::{debug, info, warn};
+
+use context_graph_core::teleological::EmbedderMask;
+use context_graph_core::traits::{EmbeddingMetadata, PartialMultiArrayOutput};
+
+use crate::protocol::{JsonRpcId, JsonRpcResponse};
+
+use super::super::core::{QueryEmbeddingBundle, ToolCategory};
+use super::super::Handlers;
+use super::memory_tools::{search_causal_direction, search_query_text, DEFAULT_TOP_K, MAX_TOP_K};
+use super::validate::Validate;
+
+/// Maximum number of queries accepted by one search_graph_batch call.
+pub(crate) const MAX_BATCH_QUERIES: usize = 20;
+
+/// Maximum sum of topK over the queries of one search_graph_batch call.
+///
+/// Each result is scored in all 13 spaces and may be hydrated, so this caps
+/// the work of one call at what five topK=100 searches would cost.
+pub(crate) const MAX_BATCH_CANDIDATES: u64 = 500;
+
+/// Request for search_graph_batch.
+///
+/// Queries stay as raw JSON so a bad query becomes an indexed error from
+/// search_graph instead of failing deserialization of the whole batch.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchGraphBatchRequest {
+    /// Query objects: `query` plus any search_graph option.
+    pub queries: Vec<serde_json::Value>,
+
+    /// search_graph options applied to every query unless it sets its own.
+    #[serde(default)]
+    pub options: serde_json::Map<String, serde_json::Value>,
+}
+
+impl SearchGraphBatchRequest {
+    /// search_graph arguments for each query: the shared options overlaid
+    /// with the query's own fields.
+    fn query_args(&self) -> Vec<serde_json::Value> {
+        self.queries
+            .iter()
+            .map(|query| {
+                let mut args = self.options.clone();
+                if let Some(fields) = query.as_object() {
+                    args.extend(fields.iter().map(|(k, v)| (k.clone(), v.clone())));
+                }
+                serde_json::Value::Object(args)
+            })
+            .collect()
+    }
+
+    /// Results the batch may return: topK summed over the queries, with
+    /// out-of-range values counted at the search_graph maximum.
+    fn candidate_budget(&self) -> u64 {
+        self.query_args()
+            .iter()
+            .map(|args| {
+                args.get("topK")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(DEFAULT_TOP_K)
+                    .min(MAX_TOP_K)
+            })
+            .sum()
+    }
+}
+
+impl Validate for SearchGraphBatchRequest {
+    fn validate(&self) -> Result<(), String> {
+        if self.queries.is_empty() {
+            return Err("queries must contain at least 1 query".to_string());
+        }
+        if self.queries.len() > MAX_BATCH_QUERIES {
+            return Err(format!(
+                "queries must contain at most {} queries, got {}",
+                MAX_BATCH_QUERIES,
+                self.queries.len()
+            ));
+        }
+        let candidates = self.candidate_budget();
+        if candidates > MAX_BATCH_CANDIDATES {
+            return Err(format!(
+                "topK sums to {} over the batch, exceeding the candidate budget of {}; \
+                 lower topK or split the batch",
+                candidates, MAX_BATCH_CANDIDATES
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Batch entry for one search_graph response: its result data, or an error
+/// object carrying the message and code search_graph reported.
+fn query_entry(index: usize, response: JsonRpcResponse) -> serde_json::Value {
+    let Some(result) = response.result else {
+        let message = response
+            .error
+            .map(|e| e.message)
+            .unwrap_or_else(|| "search_graph returned no result".to_string());
+        return json!({ "index": index, "error": message });
+    };
+    let text = result["content"][0]["text"].as_str().unwrap_or_default();
+    if result["isError"] == json!(true) {
+        let mut entry = json!({ "index": index, "error": text });
+        if let Some(code) = result.get("errorCode") {
+            entry["errorCode"] = code.clone();
+        }
+        return entry;
+    }
+    match serde_json::from_str::<serde_json::Value>(text) {
+        Ok(serde_json::Value::Object(mut data)) => {
+            data.insert("index".to_string(), json!(index));
+            serde_json::Value::Object(data)
+        }
+        _ => json!({ "index": index, "error": "search_graph returned malformed data" }),
+    }
+}
+
+impl Handlers {
+    /// search_graph_batch tool implementation.
+    ///
+    /// Runs up to [`MAX_BATCH_QUERIES`] search_graph queries, as many at a
+    /// time as free search slots allow, and returns one entry per query, in
+    /// input order: the search_graph response data, or an indexed error.
+    /// One failing query never aborts the rest.
+    ///
+    /// Response includes the embedding batch size and aggregate timing
+    /// (embedMs, searchMs, totalMs).
+    pub(crate) async fn call_search_graph_batch(
+        &self,
+        id: Option<JsonRpcId>,
+        args: serde_json::Value,
+    ) -> JsonRpcResponse {
+        let total_start = Instant::now();
+
+        let request: SearchGraphBatchRequest =
+            match self.parse_request(id.clone(), args, "search_graph_batch") {
+                Ok(req) => req,
+                Err(resp) => return resp,
+            };
+        let candidates = request.candidate_budget();
+        let query_args = request.query_args();
+
+        // The texts search_graph will embed, minus any already cached
+        let mut seen = HashSet::new();
+        let texts: Vec<String> = query_args
+            .iter()
+            .filter_map(|args| {
+                let query = args.get("query").and_then(|v| v.as_str())?;
+                if query.is_empty() {
+                    return None;
+                }
+                Some(search_query_text(
+                    query,
+                    search_causal_direction(query, args),
+                    args,
+                ))
+            })
+            .filter(|text| {
+                self.query_embeddings
+                    .get(text, EmbedderMask::all())
+                    .is_none()
+            })
+            .filter(|text| seen.insert(text.clone()))
+            .collect();
+
+        let embed_start = Instant::now();
+        let embedded = self.embed_batch_queries(&texts).await;
+        let embed_ms = embed_start.elapsed().as_millis();
+
+        // Queries are independent: search them concurrently, results in
+        // input order. Dispatch admitted this call on one search slot; each
+        // extra slot taken here allows one more query in flight.
+        let search_start = Instant::now();
+        let extra_slots = self
+            .dispatch_limiter
+            .try_admit_extra(ToolCategory::Search, query_args.len().saturating_sub(1));
+        let concurrency = 1 + extra_slots.len();
+        let responses: Vec<JsonRpcResponse> = stream::iter(query_args)
+            .map(|args| self.call_search_graph(id.clone(), args))
+            .buffered(concurrency)
+            .collect()
+            .await;
+        drop(extra_slots);
+        let results: Vec<_> = responses
+            .into_iter()
+            .enumerate()
+            .map(|(index, response)| {
+                let entry = query_entry(index, response);
+                if let Some(error) = entry.get("error") {
+                    debug!(index, error = %error, "search_graph_batch: Query failed");
+                }
+                entry
+            })
+            .collect();
+        let search_ms = search_start.elapsed().as_millis();
+        let failed = results.iter().filter(|r| r.get("error").is_some()).count();
+
+        info!(
+            queries = results.len(),
+            failed,
+            embedded,
+            concurrency,
+            embed_ms = embed_ms as u64,
+            search_ms = search_ms as u64,
+            "search_graph_batch: Completed"
+        );
+
+        self.tool_result(
+            id,
+            json!({
+                "results": results,
+                "count": results.len(),
+                "failed": failed,
+                "embeddingBatch": {
+                    "texts": texts.len(),
+                    "embedded": embedded
+                },
+                "candidateBudget": {
+                    "requested": candidates,
+                    "limit": MAX_BATCH_CANDIDATES
+                },
+                "timing": {
+                    "embedMs": embed_ms,
+                    "searchMs": search_ms,
+                    "totalMs": total_start.elapsed().as_millis()
+                }
+            }),
+        )
+    }
+
+    /// Embed `texts` in one `embed_batch_all` call and cache every bundle
+    /// for search_graph. Returns the number of texts cached.
+    ///
+    /// On failure nothing is cached and each query embeds on its own inside
+    /// search_graph, so a text the provider rejects only fails its query.
+    async fn embed_batch_queries(&self, texts: &[String]) -> usize {
+        if texts.is_empty() {
+            return 0;
+        }
+
+        let metadata = vec![EmbeddingMetadata::default(); texts.len()];
+        match self
+            .multi_array_provider
+            .embed_batch_all(texts, &metadata)
+            .await
+        {
+            Ok(outputs) if outputs.len() == texts.len() => {
+                for (text, output) in texts.iter().zip(outputs) {
+                    let partial = PartialMultiArrayOutput::from_full(output, EmbedderMask::all());
+                    self.query_embeddings
+                        .insert(text, Arc::new(QueryEmbeddingBundle::from(partial)));
+                }
+                texts.len()
+            }
+            Ok(outputs) => {
+                warn!(
+                    expected = texts.len(),
+                    actual = outputs.len(),
+                    "search_graph_batch: Batch embedding returned wrong item count, embedding queries individually"
+                );
+                0
+            }
+            Err(e) => {
+                warn!(
+                    error = %e,
+                    "search_graph_batch: Batch embedding FAILED, embedding queries individually"
+                );
+                0
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(
+        queries: Vec<serde_json::Value>,
+        options: serde_json::Value,
+    ) -> SearchGraphBatchRequest {
+        serde_json::from_value(json!({ "queries": queries, "options": options })).unwrap()
+    }
+
+    #[test]
+    fn test_batch_request_limits() {
+        let query = json!({"query": "x"});
+
+        assert!(request(vec![query.clone()], json!({})).validate().is_ok());
+        assert!(request(vec![], json!({})).validate().is_err());
+        assert!(
+            request(vec![query.clone(); MAX_BATCH_QUERIES + 1], json!({}))
+                .validate()
+                .is_err()
+        );
+
+        // 5 x 100 fits the budget exactly; one more result does not
+        let full = request(vec![query.clone(); 5], json!({"topK": 100}));
+        assert_eq!(full.candidate_budget(), MAX_BATCH_CANDIDATES);
+        assert!(full.validate().is_ok());
+        let over = request(
+            vec![
+                query.clone(),
+                query.clone(),
+                query.clone(),
+                query.clone(),
+                json!({"query": "y", "topK": 100}),
+                json!({"query": "z", "topK": 1}),
+            ],
+            json!({"topK": 100}),
+        );
+        assert!(over.validate().unwrap_err().contains("candidate budget"));
+    }
+
+    #[test]
+    fn test_query_args_override_options() {
+        let batch = request(
+            vec![json!({"query": "a"}), json!({"query": "b", "topK": 3})],
+            json!({"topK": 7, "strategy": "e1_only"}),
+        );
+        let args = batch.query_args();
+        assert_eq!(
+            args[0],
+            json!({"query": "a", "topK": 7, "strategy": "e1_only"})
+        );
+        assert_eq!(
+            args[1],
+            json!({"query": "b", "topK": 3, "strategy": "e1_only"})
+        );
+        assert_eq!(batch.candidate_budget(), 10);
+    }
+}
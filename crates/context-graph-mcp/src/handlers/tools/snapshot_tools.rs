@@ -0,0 +1,274 @@
+//! Snapshot tool implementations (export_memories, import_memories).
+//!
+//! Thin wrappers over `context_graph_core::snapshot`: the exporter and
+//! importer do the work; these handlers parse arguments, resolve topic
+//! members from the cluster manager, and hand imported memories to the
+//! K-NN graph builder.
+//!
+//! Imported memories are not assigned to topics until the next
+//! detect_topics run (use `force: true` after a large import).
+
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::json;
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+use context_graph_core::snapshot::{
+    ConflictPolicy, SnapshotExporter, SnapshotFilter, SnapshotImporter,
+};
+
+use crate::protocol::{JsonRpcId, JsonRpcResponse};
+
+use super::super::Handlers;
+use super::helpers::ToolErrorKind;
+use super::memory_tools::parse_namespace;
+use super::validate::Validate;
+
+/// Request for export_memories.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportMemoriesRequest {
+    /// Snapshot directory to create (must not already hold a snapshot).
+    pub path: String,
+
+    /// Only export memories created at or after this time (RFC 3339).
+    #[serde(default)]
+    pub created_after: Option<DateTime<Utc>>,
+
+    /// Only export memories created before this time (RFC 3339).
+    #[serde(default)]
+    pub created_before: Option<DateTime<Utc>>,
+
+    /// Only export members of this topic.
+    #[serde(default)]
+    pub topic_id: Option<Uuid>,
+}
+
+impl Validate for ExportMemoriesRequest {
+    fn validate(&self) -> Result<(), String> {
+        if self.path.trim().is_empty() {
+            return Err("path must not be empty".to_string());
+        }
+        if let (Some(after), Some(before)) = (self.created_after, self.created_before) {
+            if after >= before {
+                return Err(format!(
+                    "createdAfter ({}) must be before createdBefore ({})",
+                    after, before
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Request for import_memories.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportMemoriesRequest {
+    /// Snapshot directory to import.
+    pub path: String,
+
+    /// How to resolve memories whose ID already exists: skip, overwrite, re-id.
+    #[serde(default = "default_conflict_policy")]
+    pub conflict_policy: String,
+}
+
+fn default_conflict_policy() -> String {
+    ConflictPolicy::default().to_string()
+}
+
+impl Validate for ImportMemoriesRequest {
+    fn validate(&self) -> Result<(), String> {
+        if self.path.trim().is_empty() {
+            return Err("path must not be empty".to_string());
+        }
+        self.conflict_policy
+            .parse::<ConflictPolicy>()
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+impl Handlers {
+    /// Current provider's model IDs, recorded in and compared against manifests.
+    fn snapshot_model_ids(&self) -> Vec<String> {
+        self.multi_array_provider
+            .model_ids()
+            .iter()
+            .map(|m| m.to_string())
+            .collect()
+    }
+
+    /// export_memories tool implementation.
+    ///
+    /// Writes matching memories to a snapshot directory (manifest.json,
+    /// memories.cgeb, memories.cgei). Filters combine with AND; without
+    /// `namespace` every namespace is exported.
+    pub(crate) async fn call_export_memories(
+        &self,
+        id: Option<JsonRpcId>,
+        args: serde_json::Value,
+    ) -> JsonRpcResponse {
+        let request: ExportMemoriesRequest =
+            match self.parse_request(id.clone(), args.clone(), "export_memories") {
+                Ok(req) => req,
+                Err(resp) => return resp,
+            };
+        let namespace = match args.get("namespace") {
+            None => None,
+            Some(_) => match parse_namespace(&args) {
+                Ok(ns) => Some(ns),
+                Err(msg) => return self.tool_error_typed(id, ToolErrorKind::Validation, &msg),
+            },
+        };
+
+        let mut filter = SnapshotFilter::default()
+            .with_time_range(request.created_after, request.created_before);
+        if let Some(ns) = namespace {
+            filter = filter.with_namespace(ns);
+        }
+        if let Some(topic_id) = request.topic_id {
+            let members = {
+                let cluster_manager = self.cluster_manager.read();
+                cluster_manager
+                    .get_topics()
+                    .get(&topic_id)
+                    .map(|topic| topic.member_memories.clone())
+            };
+            let Some(members) = members else {
+                return self.tool_error_typed(
+                    id,
+                    ToolErrorKind::NotFound,
+                    &format!("Topic {} not found. Run detect_topics first.", topic_id),
+                );
+            };
+            filter = filter.with_topic(topic_id, members);
+        }
+
+        let path = PathBuf::from(&request.path);
+        debug!(path = %path.display(), ?filter, "export_memories: Starting export");
+        let exporter = SnapshotExporter::new(self.teleological_store.clone(), filter)
+            .with_model_ids(self.snapshot_model_ids());
+        match exporter.export(&path).await {
+            Ok(manifest) => {
+                info!(
+                    memory_count = manifest.memory_count,
+                    path = %path.display(),
+                    "export_memories: Export complete"
+                );
+                self.tool_result(
+                    id,
+                    json!({
+                        "path": path.display().to_string(),
+                        "memoryCount": manifest.memory_count,
+                        "namespaceCounts": manifest.namespace_counts,
+                        "formatVersion": manifest.format_version,
+                        "createdAt": manifest.created_at.to_rfc3339(),
+                    }),
+                )
+            }
+            Err(e) => {
+                error!(error = %e, path = %path.display(), "export_memories: Export failed");
+                self.tool_error_typed(id, ToolErrorKind::Storage, &format!("Export failed: {}", e))
+            }
+        }
+    }
+
+    /// import_memories tool implementation.
+    ///
+    /// Loads a snapshot through the store's normal write path, which rebuilds
+    /// HNSW, SPLADE inverted and content-hash indexes for each memory, then
+    /// enqueues imported memories for K-NN graph building.
+    pub(crate) async fn call_import_memories(
+        &self,
+        id: Option<JsonRpcId>,
+        args: serde_json::Value,
+    ) -> JsonRpcResponse {
+        let request: ImportMemoriesRequest =
+            match self.parse_request(id.clone(), args, "import_memories") {
+                Ok(req) => req,
+                Err(resp) => return resp,
+            };
+        // Already checked in validate(); parse again for the typed value
+        let policy: ConflictPolicy = match request.conflict_policy.parse() {
+            Ok(policy) => policy,
+            Err(e) => return self.tool_error_typed(id, ToolErrorKind::Validation, &e.to_string()),
+        };
+
+        let path = PathBuf::from(&request.path);
+        debug!(path = %path.display(), %policy, "import_memories: Starting import");
+        let importer = SnapshotImporter::new(self.teleological_store.clone(), policy)
+            .with_model_ids(self.snapshot_model_ids());
+        let report = match importer.import(&path).await {
+            Ok(report) => report,
+            Err(e) => {
+                error!(error = %e, path = %path.display(), "import_memories: Import failed");
+                return self.tool_error_typed(
+                    id,
+                    ToolErrorKind::Storage,
+                    &format!("Import failed: {}", e),
+                );
+            }
+        };
+
+        if let Some(builder) = self.graph_builder() {
+            for fingerprint_id in &report.stored_ids {
+                builder.enqueue(*fingerprint_id).await;
+            }
+        }
+        if report.model_mismatch {
+            warn!("import_memories: Snapshot embedded with different models");
+        }
+
+        self.tool_result(
+            id,
+            json!({
+                "path": path.display().to_string(),
+                "conflictPolicy": policy.to_string(),
+                "imported": report.imported,
+                "overwritten": report.overwritten,
+                "skipped": report.skipped,
+                "reassigned": report.reassigned,
+                "failed": report.failed,
+                "modelMismatch": report.model_mismatch,
+                "nextStep": if report.stored_ids.is_empty() {
+                    None
+                } else {
+                    Some("Run detect_topics with force=true to assign imported memories to topics")
+                },
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_request_rejects_unknown_policy() {
+        let request: ImportMemoriesRequest =
+            serde_json::from_value(json!({"path": "/tmp/snap", "conflictPolicy": "merge"}))
+                .unwrap();
+        assert!(request.validate().unwrap_err().contains("merge"));
+
+        let request: ImportMemoriesRequest =
+            serde_json::from_value(json!({"path": "/tmp/snap"})).unwrap();
+        assert_eq!(request.conflict_policy, "skip");
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_export_request_rejects_inverted_time_range() {
+        let request: ExportMemoriesRequest = serde_json::from_value(json!({
+            "path": "/tmp/snap",
+            "createdAfter": "2026-02-01T00:00:00Z",
+            "createdBefore": "2026-01-01T00:00:00Z"
+        }))
+        .unwrap();
+        assert!(request.validate().unwrap_err().contains("createdAfter"));
+    }
+}
@@ -0,0 +1,397 @@
+//! Session staging tool implementations (promote_staged, end_staged_session).
+//!
+//! `store_memory` with `staged: true` writes into the session's staging
+//! namespace (`staged.<sessionId>`), which searches skip unless they name it
+//! explicitly. These handlers move staged memories into the default namespace
+//! or soft-delete them when the session ends.
+//!
+//! Promotion goes through `update`, which re-indexes the fingerprint and
+//! rewrites its namespace marker, so HNSW, SPLADE and content-hash entries
+//! stay valid. Discarded memories are soft-deleted and purged by compaction.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde_json::json;
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+use context_graph_core::types::audit::{AuditOperation, AuditRecord};
+use context_graph_core::types::fingerprint::TeleologicalFingerprint;
+
+use crate::protocol::{JsonRpcId, JsonRpcResponse};
+
+use super::super::Handlers;
+use super::helpers::ToolErrorKind;
+use super::validate::Validate;
+
+/// What to do with a session's staged memories when it ends.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StagingPolicy {
+    /// Promote every staged memory.
+    PromoteAll,
+    /// Soft-delete every staged memory.
+    DiscardAll,
+    /// Promote memories with importance >= threshold, discard the rest.
+    PromoteAboveImportance(f32),
+}
+
+impl StagingPolicy {
+    /// Build a policy from its name and the optional threshold argument.
+    pub fn parse(name: &str, threshold: Option<f32>) -> Result<Self, String> {
+        match (name, threshold) {
+            ("promote_above_importance", Some(t)) if (0.0..=1.0).contains(&t) => {
+                Ok(Self::PromoteAboveImportance(t))
+            }
+            ("promote_above_importance", Some(t)) => {
+                Err(format!("threshold must be between 0.0 and 1.0, got {}", t))
+            }
+            ("promote_above_importance", None) => {
+                Err("policy promote_above_importance requires threshold".to_string())
+            }
+            (_, Some(_)) => Err(format!(
+                "threshold is only valid with policy promote_above_importance, got '{}'",
+                name
+            )),
+            (other, None) => other.parse(),
+        }
+    }
+
+    /// Whether a staged memory with this importance is kept.
+    fn promotes(self, importance: f32) -> bool {
+        match self {
+            Self::PromoteAll => true,
+            Self::DiscardAll => false,
+            Self::PromoteAboveImportance(threshold) => importance >= threshold,
+        }
+    }
+}
+
+impl FromStr for StagingPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "promote_all" => Ok(Self::PromoteAll),
+            "discard_all" => Ok(Self::DiscardAll),
+            "promote_above_importance" => {
+                Err("policy promote_above_importance requires threshold".to_string())
+            }
+            other => Err(format!(
+                "Unknown policy '{}'. Valid: promote_all, discard_all, promote_above_importance",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for StagingPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PromoteAll => write!(f, "promote_all"),
+            Self::DiscardAll => write!(f, "discard_all"),
+            Self::PromoteAboveImportance(t) => write!(f, "promote_above_importance({})", t),
+        }
+    }
+}
+
+/// Request for promote_staged.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromoteStagedRequest {
+    /// Session whose staging namespace is promoted from.
+    pub session_id: String,
+
+    /// Staged memories to promote.
+    #[serde(default)]
+    pub ids: Option<Vec<Uuid>>,
+
+    /// Promote every staged memory of the session.
+    #[serde(default)]
+    pub all: bool,
+}
+
+impl Validate for PromoteStagedRequest {
+    fn validate(&self) -> Result<(), String> {
+        if self.session_id.trim().is_empty() {
+            return Err("sessionId must not be empty".to_string());
+        }
+        match (&self.ids, self.all) {
+            (Some(_), true) => Err("ids and all are mutually exclusive".to_string()),
+            (Some(ids), false) if ids.is_empty() => Err("ids must not be empty".to_string()),
+            (None, false) => Err("Either ids or all: true is required".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Request for end_staged_session.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndStagedSessionRequest {
+    /// Session whose staged memories are resolved.
+    pub session_id: String,
+
+    /// promote_all, discard_all or promote_above_importance.
+    pub policy: String,
+
+    /// Importance threshold for promote_above_importance.
+    #[serde(default)]
+    pub threshold: Option<f32>,
+}
+
+impl Validate for EndStagedSessionRequest {
+    fn validate(&self) -> Result<(), String> {
+        if self.session_id.trim().is_empty() {
+            return Err("sessionId must not be empty".to_string());
+        }
+        StagingPolicy::parse(&self.policy, self.threshold).map(|_| ())
+    }
+}
+
+/// Outcome of resolving a set of staged memories.
+#[derive(Debug, Default)]
+struct StagingOutcome {
+    promoted: Vec<Uuid>,
+    discarded: Vec<Uuid>,
+    failures: Vec<serde_json::Value>,
+}
+
+impl Handlers {
+    /// promote_staged tool implementation.
+    ///
+    /// Moves the listed (or all) staged memories of a session into the
+    /// default namespace. IDs that are not staged in that session are
+    /// reported as failures rather than aborting the call.
+    pub(crate) async fn call_promote_staged(
+        &self,
+        id: Option<JsonRpcId>,
+        args: serde_json::Value,
+    ) -> JsonRpcResponse {
+        let request: PromoteStagedRequest =
+            match self.parse_request(id.clone(), args, "promote_staged") {
+                Ok(req) => req,
+                Err(resp) => return resp,
+            };
+        let staging = TeleologicalFingerprint::staging_namespace(&request.session_id);
+
+        let ids = match request.ids {
+            Some(ids) => ids,
+            None => match self.teleological_store.list_namespace_ids(&staging).await {
+                Ok(ids) => ids,
+                Err(e) => {
+                    error!(error = %e, namespace = %staging, "promote_staged: Listing staged memories failed");
+                    return self.tool_error_typed(
+                        id,
+                        ToolErrorKind::Storage,
+                        &format!("Failed to list staged memories: {}", e),
+                    );
+                }
+            },
+        };
+
+        let outcome = self
+            .resolve_staged(&staging, &ids, StagingPolicy::PromoteAll)
+            .await;
+        info!(
+            session_id = %request.session_id,
+            promoted = outcome.promoted.len(),
+            failed = outcome.failures.len(),
+            "promote_staged: Complete"
+        );
+        self.tool_result(
+            id,
+            json!({
+                "sessionId": request.session_id,
+                "stagingNamespace": staging,
+                "promotedCount": outcome.promoted.len(),
+                "promoted": outcome.promoted,
+                "failures": outcome.failures,
+            }),
+        )
+    }
+
+    /// end_staged_session tool implementation.
+    ///
+    /// Applies a SessionEnd policy to every memory still staged for the
+    /// session: promote all, discard all, or promote those whose importance
+    /// reaches the threshold and discard the rest.
+    pub(crate) async fn call_end_staged_session(
+        &self,
+        id: Option<JsonRpcId>,
+        args: serde_json::Value,
+    ) -> JsonRpcResponse {
+        let request: EndStagedSessionRequest =
+            match self.parse_request(id.clone(), args, "end_staged_session") {
+                Ok(req) => req,
+                Err(resp) => return resp,
+            };
+        // Already checked in validate(); parse again for the typed value
+        let policy = match StagingPolicy::parse(&request.policy, request.threshold) {
+            Ok(policy) => policy,
+            Err(msg) => return self.tool_error_typed(id, ToolErrorKind::Validation, &msg),
+        };
+        let staging = TeleologicalFingerprint::staging_namespace(&request.session_id);
+
+        let ids = match self.teleological_store.list_namespace_ids(&staging).await {
+            Ok(ids) => ids,
+            Err(e) => {
+                error!(error = %e, namespace = %staging, "end_staged_session: Listing staged memories failed");
+                return self.tool_error_typed(
+                    id,
+                    ToolErrorKind::Storage,
+                    &format!("Failed to list staged memories: {}", e),
+                );
+            }
+        };
+
+        let outcome = self.resolve_staged(&staging, &ids, policy).await;
+        info!(
+            session_id = %request.session_id,
+            %policy,
+            staged = ids.len(),
+            promoted = outcome.promoted.len(),
+            discarded = outcome.discarded.len(),
+            failed = outcome.failures.len(),
+            "end_staged_session: Complete"
+        );
+        self.tool_result(
+            id,
+            json!({
+                "sessionId": request.session_id,
+                "stagingNamespace": staging,
+                "policy": policy.to_string(),
+                "stagedCount": ids.len(),
+                "promotedCount": outcome.promoted.len(),
+                "discardedCount": outcome.discarded.len(),
+                "promoted": outcome.promoted,
+                "discarded": outcome.discarded,
+                "failures": outcome.failures,
+            }),
+        )
+    }
+
+    /// Promote or discard each staged memory in `ids` according to `policy`.
+    ///
+    /// Each memory is handled independently; failures are collected so one
+    /// bad ID does not strand the rest of the session's staging area.
+    async fn resolve_staged(
+        &self,
+        staging: &str,
+        ids: &[Uuid],
+        policy: StagingPolicy,
+    ) -> StagingOutcome {
+        let mut outcome = StagingOutcome::default();
+        for &memory_id in ids {
+            let fingerprint = match self.teleological_store.retrieve(memory_id).await {
+                Ok(Some(fp)) if fp.namespace == staging => fp,
+                Ok(_) => {
+                    warn!(%memory_id, staging, "Memory is not staged in this session");
+                    outcome.failures.push(json!({
+                        "id": memory_id,
+                        "error": format!("Memory {} is not staged in {}", memory_id, staging),
+                    }));
+                    continue;
+                }
+                Err(e) => {
+                    error!(%memory_id, error = %e, "Failed to retrieve staged memory");
+                    outcome
+                        .failures
+                        .push(json!({ "id": memory_id, "error": e.to_string() }));
+                    continue;
+                }
+            };
+
+            let result = if policy.promotes(fingerprint.importance) {
+                self.promote_fingerprint(fingerprint)
+                    .await
+                    .map(|()| outcome.promoted.push(memory_id))
+            } else {
+                self.discard_staged(memory_id)
+                    .await
+                    .map(|()| outcome.discarded.push(memory_id))
+            };
+            if let Err(msg) = result {
+                error!(%memory_id, error = %msg, "Resolving staged memory failed");
+                outcome
+                    .failures
+                    .push(json!({ "id": memory_id, "error": msg }));
+            }
+        }
+        outcome
+    }
+
+    /// Move a staged fingerprint into the default namespace.
+    async fn promote_fingerprint(
+        &self,
+        fingerprint: TeleologicalFingerprint,
+    ) -> Result<(), String> {
+        let memory_id = fingerprint.id;
+        let fingerprint = fingerprint.with_namespace(TeleologicalFingerprint::DEFAULT_NAMESPACE);
+        match self.teleological_store.update(fingerprint).await {
+            Ok(true) => {
+                debug!(%memory_id, "Promoted staged memory");
+                Ok(())
+            }
+            Ok(false) => Err(format!("Memory {} not found", memory_id)),
+            Err(e) => Err(format!("Promotion failed: {}", e)),
+        }
+    }
+
+    /// Soft-delete a staged memory and record why.
+    async fn discard_staged(&self, memory_id: Uuid) -> Result<(), String> {
+        match self.teleological_store.delete(memory_id, true).await {
+            Ok(true) => {}
+            Ok(false) => return Err(format!("Memory {} not found", memory_id)),
+            Err(e) => return Err(format!("Discard failed: {}", e)),
+        }
+
+        let record = AuditRecord::new(
+            AuditOperation::MemoryDeleted {
+                soft: true,
+                reason: Some("staged memory discarded at session end".to_string()),
+            },
+            memory_id,
+        );
+        if let Err(e) = self.teleological_store.append_audit_record(&record).await {
+            // Non-fatal: the memory is already discarded
+            warn!(%memory_id, error = %e, "Failed to append audit record for discarded memory");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_staging_policy_parse() {
+        assert_eq!(
+            StagingPolicy::parse("promote_all", None),
+            Ok(StagingPolicy::PromoteAll)
+        );
+        assert_eq!(
+            StagingPolicy::parse("discard_all", None),
+            Ok(StagingPolicy::DiscardAll)
+        );
+        assert_eq!(
+            StagingPolicy::parse("promote_above_importance", Some(0.7)),
+            Ok(StagingPolicy::PromoteAboveImportance(0.7))
+        );
+        assert!(StagingPolicy::parse("promote_above_importance", None).is_err());
+        assert!(StagingPolicy::parse("promote_above_importance", Some(1.5)).is_err());
+        assert!(StagingPolicy::parse("discard_all", Some(0.5)).is_err());
+        assert!(StagingPolicy::parse("keep_some", None).is_err());
+    }
+
+    #[test]
+    fn test_staging_policy_promotes() {
+        let policy = StagingPolicy::PromoteAboveImportance(0.6);
+        assert!(policy.promotes(0.6));
+        assert!(!policy.promotes(0.59));
+        assert!(StagingPolicy::PromoteAll.promotes(0.0));
+        assert!(!StagingPolicy::DiscardAll.promotes(1.0));
+    }
+}
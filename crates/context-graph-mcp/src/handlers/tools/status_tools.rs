@@ -1,28 +1,75 @@
 //! Status query tool implementations (get_memetic_status).
 
-use serde_json::json;
+use serde_json::{json, Value};
 use tracing::error;
 
+use context_graph_core::traits::{format_bytes, MemorySize};
 use context_graph_core::types::fingerprint::NUM_EMBEDDERS;
+use context_graph_cuda::{knn_device_bytes, knn_peak_device_bytes};
 
 use crate::protocol::{JsonRpcId, JsonRpcResponse};
 
+use super::super::core::StorageStatus;
 use super::super::Handlers;
 use super::helpers::ToolErrorKind;
 
+/// Upper bound of the `largest_memories` argument.
+const MAX_LARGEST_MEMORIES: u64 = 100;
+
 impl Handlers {
     /// get_memetic_status tool implementation.
     ///
     /// Returns system status including:
-    /// - Fingerprint count from TeleologicalMemoryStore
+    /// - Fingerprint count from TeleologicalMemoryStore (total and per namespace)
     /// - Number of embedders (13)
-    /// - Storage backend and size
+    /// - Storage backend and size, with a `storage` section breaking the
+    ///   size down per column family and auxiliary file
     /// - Layer status from LayerStatusProvider
-    pub(crate) async fn call_get_memetic_status(&self, id: Option<JsonRpcId>) -> JsonRpcResponse {
-        let fingerprint_count = match self.teleological_store.count().await {
-            Ok(count) => count,
+    /// - GPU k-NN index device memory (current and peak)
+    /// - Per-stage search latency (p50/p95/p99) since the store was opened
+    /// - Capability matrix (CUDA driver, Candle device, FAISS GPU, per-model
+    ///   load status); null when none was assembled
+    ///
+    /// Counts, layers and storage size come from the `StatusAggregator`
+    /// caches; `components` reports when each was computed and whether it is
+    /// stale. `force_refresh: true` recomputes all of them.
+    ///
+    /// `largest_memories: n` adds the n biggest memories by stored bytes.
+    /// That scans every fingerprint and is never cached.
+    pub(crate) async fn call_get_memetic_status(
+        &self,
+        id: Option<JsonRpcId>,
+        arguments: serde_json::Value,
+    ) -> JsonRpcResponse {
+        let force_refresh = arguments
+            .get("force_refresh")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let largest_n = match arguments.get("largest_memories") {
+            None | Some(Value::Null) => None,
+            Some(value) => match value.as_u64() {
+                Some(n) if (1..=MAX_LARGEST_MEMORIES).contains(&n) => Some(n as usize),
+                _ => {
+                    return self.tool_error_typed(
+                        id,
+                        ToolErrorKind::Validation,
+                        &format!(
+                            "largest_memories must be an integer between 1 and {}",
+                            MAX_LARGEST_MEMORIES
+                        ),
+                    );
+                }
+            },
+        };
+
+        let counts = match self
+            .status_aggregator
+            .counts(self.teleological_store.as_ref(), force_refresh)
+            .await
+        {
+            Ok(counts) => counts,
             Err(e) => {
-                error!(error = %e, "get_memetic_status: TeleologicalStore.count() FAILED");
+                error!(error = %e, "get_memetic_status: TeleologicalStore count FAILED");
                 return self.tool_error_typed(
                     id,
                     ToolErrorKind::Storage,
@@ -32,33 +79,49 @@ impl Handlers {
         };
 
         // Get REAL layer statuses from LayerStatusProvider — fail fast on errors
-        let perception_status = match self.layer_status_provider.perception_status().await {
-            Ok(s) => s.as_str().to_string(),
-            Err(e) => {
-                error!(error = %e, layer = "perception", "get_memetic_status: layer status FAILED");
-                return self.tool_error_typed(id, ToolErrorKind::Execution, &format!("Perception layer status failed: {}", e));
-            }
-        };
-        let memory_status = match self.layer_status_provider.memory_status().await {
-            Ok(s) => s.as_str().to_string(),
+        let layers = match self
+            .status_aggregator
+            .layers(self.layer_status_provider.as_ref(), force_refresh)
+            .await
+        {
+            Ok(layers) => layers,
             Err(e) => {
-                error!(error = %e, layer = "memory", "get_memetic_status: layer status FAILED");
-                return self.tool_error_typed(id, ToolErrorKind::Execution, &format!("Memory layer status failed: {}", e));
+                error!(error = %e.message, layer = e.layer, "get_memetic_status: layer status FAILED");
+                return self.tool_error_typed(
+                    id,
+                    ToolErrorKind::Execution,
+                    &format!("{} layer status failed: {}", capitalize(e.layer), e.message),
+                );
             }
         };
-        let action_status = match self.layer_status_provider.action_status().await {
-            Ok(s) => s.as_str().to_string(),
+
+        let storage = match self
+            .status_aggregator
+            .storage(self.teleological_store.as_ref(), force_refresh)
+        {
+            Ok(storage) => storage,
             Err(e) => {
-                error!(error = %e, layer = "action", "get_memetic_status: layer status FAILED");
-                return self.tool_error_typed(id, ToolErrorKind::Execution, &format!("Action layer status failed: {}", e));
+                error!(error = %e, "get_memetic_status: storage metrics FAILED");
+                return self.tool_error_typed(
+                    id,
+                    ToolErrorKind::Storage,
+                    &format!("Failed to collect storage metrics: {}", e),
+                );
             }
         };
-        let meta_status = match self.layer_status_provider.meta_status().await {
-            Ok(s) => s.as_str().to_string(),
-            Err(e) => {
-                error!(error = %e, layer = "meta", "get_memetic_status: layer status FAILED");
-                return self.tool_error_typed(id, ToolErrorKind::Execution, &format!("Meta layer status failed: {}", e));
-            }
+        let largest = match largest_n {
+            Some(n) => match self.teleological_store.largest_memories(n).await {
+                Ok(largest) => Some(largest),
+                Err(e) => {
+                    error!(error = %e, "get_memetic_status: largest_memories FAILED");
+                    return self.tool_error_typed(
+                        id,
+                        ToolErrorKind::Storage,
+                        &format!("Failed to rank memories by size: {}", e),
+                    );
+                }
+            },
+            None => None,
         };
 
         // E5 causal model health: report whether LoRA trained weights are loaded.
@@ -75,21 +138,112 @@ impl Handlers {
         self.tool_result(
             id,
             json!({
-                "fingerprintCount": fingerprint_count,
+                "fingerprintCount": counts.value.total,
+                "namespaceCounts": &counts.value.by_namespace,
                 "embedderCount": NUM_EMBEDDERS,
                 "storageBackend": self.teleological_store.backend_type().to_string(),
-                "storageSizeBytes": self.teleological_store.storage_size_bytes(),
+                "storageSizeBytes": storage.value.size_bytes,
+                "storage": storage_section(&storage.value, largest.as_deref()),
                 "layers": {
-                    "perception": perception_status,
-                    "memory": memory_status,
-                    "action": action_status,
-                    "meta": meta_status
+                    "perception": &layers.value.perception,
+                    "memory": &layers.value.memory,
+                    "action": &layers.value.action,
+                    "meta": &layers.value.meta
                 },
                 "e5CausalModel": {
                     "loraLoaded": e5_lora_loaded,
                     "causalGateFunctional": e5_lora_loaded
+                },
+                "gpuKnn": {
+                    "deviceBytes": knn_device_bytes(),
+                    "peakDeviceBytes": knn_peak_device_bytes()
+                },
+                "dispatchLimits": self.dispatch_limiter.status(),
+                "pipelineLatency": self.teleological_store.pipeline_metrics(),
+                "capabilities": self.capability_matrix.as_ref().map(|m| m.report()),
+                "components": {
+                    "counts": counts.meta(),
+                    "layers": layers.meta(),
+                    "storage": storage.meta()
                 }
             }),
         )
     }
 }
+
+/// The `storage` section: totals, non-empty column families (largest
+/// first), auxiliary files and, when requested, the largest memories.
+fn storage_section(status: &StorageStatus, largest: Option<&[MemorySize]>) -> Value {
+    let metrics = &status.metrics;
+    let (used, empty): (Vec<_>, Vec<_>) = metrics
+        .column_families
+        .iter()
+        .partition(|cf| cf.num_keys > 0 || cf.live_data_bytes > 0 || cf.sst_files > 0);
+    let column_families: Vec<Value> = used
+        .into_iter()
+        .map(|cf| {
+            json!({
+                "name": cf.name,
+                "liveDataBytes": cf.live_data_bytes,
+                "liveData": format_bytes(cf.live_data_bytes),
+                "numKeys": cf.num_keys,
+                "pendingCompactionBytes": cf.pending_compaction_bytes,
+                "sstFiles": cf.sst_files,
+                "sstBytes": cf.sst_bytes,
+                "sst": format_bytes(cf.sst_bytes),
+            })
+        })
+        .collect();
+    let auxiliary: Vec<Value> = metrics
+        .auxiliary
+        .iter()
+        .map(|a| {
+            json!({
+                "name": a.name,
+                "files": a.files,
+                "bytes": a.bytes,
+                "size": format_bytes(a.bytes),
+            })
+        })
+        .collect();
+
+    let mut section = json!({
+        "totals": {
+            "sizeBytes": status.size_bytes,
+            "size": format_bytes(status.size_bytes as u64),
+            "liveDataBytes": metrics.total_live_data_bytes(),
+            "sstBytes": metrics.total_sst_bytes(),
+            "sst": format_bytes(metrics.total_sst_bytes()),
+            "pendingCompactionBytes": metrics.total_pending_compaction_bytes(),
+            "auxiliaryBytes": metrics.auxiliary_bytes(),
+            "auxiliary": format_bytes(metrics.auxiliary_bytes()),
+        },
+        "columnFamilies": column_families,
+        "emptyColumnFamilies": empty.len(),
+        "auxiliaryFiles": auxiliary,
+    });
+    if let Some(largest) = largest {
+        section["largestMemories"] = largest
+            .iter()
+            .map(|m| {
+                json!({
+                    "id": m.id,
+                    "fingerprintBytes": m.fingerprint_bytes,
+                    "contentBytes": m.content_bytes,
+                    "totalBytes": m.total_bytes(),
+                    "total": format_bytes(m.total_bytes()),
+                })
+            })
+            .collect();
+    }
+    section
+}
+
+/// "perception" -> "Perception", for layer error messages.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -5,6 +5,7 @@
 //! - get_topic_stability: Get portfolio-level stability metrics
 //! - detect_topics: Force topic detection recalculation
 //! - get_divergence_alerts: Check for divergence from recent activity
+//! - acknowledge_divergence_alert: Acknowledge a cross-space divergence alert
 //!
 //! Constitution References:
 //! - ARCH-09: Topic threshold is weighted_agreement >= 2.5
@@ -16,6 +17,9 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use context_graph_core::clustering::TopicSeries;
+use context_graph_core::retrieval::{CrossSpaceAlert, CrossSpaceAlertPage};
+
 pub use context_graph_core::clustering::MAX_WEIGHTED_AGREEMENT;
 #[cfg(test)]
 pub use context_graph_core::clustering::TOPIC_THRESHOLD;
@@ -27,6 +31,15 @@ pub use context_graph_core::clustering::TOPIC_THRESHOLD;
 /// Default output format for topic portfolio.
 pub const DEFAULT_FORMAT: &str = "standard";
 
+/// Default sort order for topic portfolio pages.
+pub const DEFAULT_PORTFOLIO_SORT: &str = "size";
+
+/// Default page size for topic portfolio.
+pub const DEFAULT_PORTFOLIO_LIMIT: usize = 50;
+
+/// Maximum page size for topic portfolio.
+pub const MAX_PORTFOLIO_LIMIT: usize = 500;
+
 /// Default lookback hours for stability metrics (6 hours per constitution).
 pub const DEFAULT_STABILITY_HOURS: u32 = 6;
 
@@ -39,6 +52,12 @@ pub const DEFAULT_DIVERGENCE_LOOKBACK: u32 = 2;
 /// Maximum lookback hours for divergence alerts.
 pub const MAX_DIVERGENCE_LOOKBACK: u32 = 48;
 
+/// Default page size for cross-space divergence alerts.
+pub const DEFAULT_CROSS_SPACE_ALERT_LIMIT: usize = 20;
+
+/// Maximum page size for cross-space divergence alerts.
+pub const MAX_CROSS_SPACE_ALERT_LIMIT: usize = 100;
+
 /// Churn threshold for high churn warning.
 pub const CHURN_THRESHOLD: f32 = 0.5;
 
@@ -60,11 +79,14 @@ const SEVERITY_MEDIUM_ALERT_COUNT: usize = 2;
 ///
 /// # Example JSON
 /// ```json
-/// {"format": "standard"}
+/// {"format": "standard", "sort_by": "stability", "offset": 0, "limit": 50}
 /// ```
 ///
 /// # Defaults
 /// - `format`: "standard"
+/// - `sort_by`: "size"
+/// - `offset`: 0
+/// - `limit`: 50
 #[derive(Debug, Clone, Deserialize)]
 pub struct GetTopicPortfolioRequest {
     /// Output format: "brief", "standard", or "verbose"
@@ -73,12 +95,28 @@ pub struct GetTopicPortfolioRequest {
     /// - verbose: Full topic profiles with all 13 strengths
     #[serde(default = "default_format")]
     pub format: String,
+
+    /// Sort order: "size" (most members first), "recency" (newest topics
+    /// first) or "stability" (highest stability score first)
+    #[serde(default = "default_portfolio_sort")]
+    pub sort_by: String,
+
+    /// Offset into the sorted topics (default 0)
+    #[serde(default)]
+    pub offset: usize,
+
+    /// Maximum topics to return (default 50, max 500)
+    #[serde(default = "default_portfolio_limit")]
+    pub limit: usize,
 }
 
 impl Default for GetTopicPortfolioRequest {
     fn default() -> Self {
         Self {
             format: DEFAULT_FORMAT.to_string(),
+            sort_by: DEFAULT_PORTFOLIO_SORT.to_string(),
+            offset: 0,
+            limit: DEFAULT_PORTFOLIO_LIMIT,
         }
     }
 }
@@ -87,14 +125,26 @@ fn default_format() -> String {
     DEFAULT_FORMAT.to_string()
 }
 
+fn default_portfolio_sort() -> String {
+    DEFAULT_PORTFOLIO_SORT.to_string()
+}
+
+fn default_portfolio_limit() -> usize {
+    DEFAULT_PORTFOLIO_LIMIT
+}
+
 impl GetTopicPortfolioRequest {
     /// Valid format values for topic portfolio requests.
     pub const VALID_FORMATS: [&'static str; 3] = ["brief", "standard", "verbose"];
 
+    /// Valid sort_by values for topic portfolio requests.
+    pub const VALID_SORTS: [&'static str; 3] = ["size", "recency", "stability"];
+
     /// Validate the request parameters.
     ///
     /// # Errors
-    /// Returns an error message if format is invalid.
+    /// Returns an error message if format or sort_by is invalid, or limit is
+    /// out of range [1, 500].
     pub fn validate(&self) -> Result<(), String> {
         if !Self::VALID_FORMATS.contains(&self.format.as_str()) {
             return Err(format!(
@@ -103,6 +153,19 @@ impl GetTopicPortfolioRequest {
                 Self::VALID_FORMATS.join(", ")
             ));
         }
+        if !Self::VALID_SORTS.contains(&self.sort_by.as_str()) {
+            return Err(format!(
+                "Invalid sort_by '{}'. Valid values: {}",
+                self.sort_by,
+                Self::VALID_SORTS.join(", ")
+            ));
+        }
+        if self.limit == 0 || self.limit > MAX_PORTFOLIO_LIMIT {
+            return Err(format!(
+                "limit must be between 1 and {}, got {}",
+                MAX_PORTFOLIO_LIMIT, self.limit
+            ));
+        }
         Ok(())
     }
 }
@@ -192,22 +255,34 @@ impl Default for DetectTopicsRequest {
 ///
 /// # Example JSON
 /// ```json
-/// {"lookback_hours": 2}
+/// {"lookback_hours": 2, "offset": 0, "limit": 20}
 /// ```
 ///
 /// # Defaults
 /// - `lookback_hours`: 2
+/// - `offset`: 0
+/// - `limit`: 20
 #[derive(Debug, Clone, Deserialize)]
 pub struct GetDivergenceAlertsRequest {
     /// Hours to look back for recent activity comparison (default 2, max 48)
     #[serde(default = "default_lookback")]
     pub lookback_hours: u32,
+
+    /// Offset into unacknowledged cross-space alerts (default 0)
+    #[serde(default)]
+    pub offset: usize,
+
+    /// Maximum cross-space alerts to return (default 20, max 100)
+    #[serde(default = "default_cross_space_limit")]
+    pub limit: usize,
 }
 
 impl Default for GetDivergenceAlertsRequest {
     fn default() -> Self {
         Self {
             lookback_hours: DEFAULT_DIVERGENCE_LOOKBACK,
+            offset: 0,
+            limit: DEFAULT_CROSS_SPACE_ALERT_LIMIT,
         }
     }
 }
@@ -216,11 +291,16 @@ fn default_lookback() -> u32 {
     DEFAULT_DIVERGENCE_LOOKBACK
 }
 
+fn default_cross_space_limit() -> usize {
+    DEFAULT_CROSS_SPACE_ALERT_LIMIT
+}
+
 impl GetDivergenceAlertsRequest {
     /// Validate the request parameters.
     ///
     /// # Errors
-    /// Returns an error message if lookback_hours is out of range [1, 48].
+    /// Returns an error message if lookback_hours is out of range [1, 48]
+    /// or limit is out of range [1, 100].
     pub fn validate(&self) -> Result<(), String> {
         if self.lookback_hours == 0 {
             return Err("lookback_hours must be at least 1".to_string());
@@ -231,10 +311,48 @@ impl GetDivergenceAlertsRequest {
                 MAX_DIVERGENCE_LOOKBACK, self.lookback_hours
             ));
         }
+        if self.limit == 0 || self.limit > MAX_CROSS_SPACE_ALERT_LIMIT {
+            return Err(format!(
+                "limit must be between 1 and {}, got {}",
+                MAX_CROSS_SPACE_ALERT_LIMIT, self.limit
+            ));
+        }
         Ok(())
     }
 }
 
+/// Request parameters for acknowledge_divergence_alert tool.
+///
+/// # Example JSON
+/// ```json
+/// {"alert_id": "550e8400-e29b-41d4-a716-446655440000"}
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct AcknowledgeDivergenceAlertRequest {
+    /// UUID of the cross-space alert to acknowledge (required)
+    pub alert_id: String,
+}
+
+impl AcknowledgeDivergenceAlertRequest {
+    /// Validate the request parameters.
+    ///
+    /// # Errors
+    /// Returns an error message if alert_id is not a valid UUID.
+    pub fn validate(&self) -> Result<(), String> {
+        self.parse_alert_id().map(|_| ())
+    }
+
+    /// Parse alert_id as a UUID.
+    pub fn parse_alert_id(&self) -> Result<Uuid, String> {
+        Uuid::parse_str(&self.alert_id).map_err(|e| {
+            format!(
+                "alert_id must be a valid UUID, got '{}': {}",
+                self.alert_id, e
+            )
+        })
+    }
+}
+
 // ============================================================================
 // TRAIT IMPLS (parse_request helper)
 // ============================================================================
@@ -257,6 +375,12 @@ impl super::validate::Validate for GetDivergenceAlertsRequest {
     }
 }
 
+impl super::validate::Validate for AcknowledgeDivergenceAlertRequest {
+    fn validate(&self) -> Result<(), String> {
+        self.validate()
+    }
+}
+
 // ============================================================================
 // RESPONSE DTOs
 // ============================================================================
@@ -322,6 +446,10 @@ pub struct TopicSummary {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 
+    /// One-sentence description generated with the label from member content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
     /// Confidence score = weighted_agreement / 8.5 (range 0.0-1.0)
     pub confidence: f32,
 
@@ -411,6 +539,9 @@ pub struct TopicStabilityResponse {
 
     /// Average churn over the requested lookback period
     pub average_churn: f32,
+
+    /// Per-topic stability series over detection runs in the lookback period
+    pub topic_series: Vec<TopicSeries>,
 }
 
 impl TopicStabilityResponse {
@@ -495,6 +626,10 @@ pub struct DivergenceAlertsResponse {
 
     /// Overall severity: "none", "low", "medium", "high"
     pub severity: String,
+
+    /// Page of unacknowledged cross-space alerts (memories whose neighborhoods
+    /// disagree between two embedding spaces), newest first
+    pub cross_space_alerts: CrossSpaceAlertPage,
 }
 
 impl DivergenceAlertsResponse {
@@ -503,6 +638,7 @@ impl DivergenceAlertsResponse {
         Self {
             alerts: Vec::new(),
             severity: "none".to_string(),
+            cross_space_alerts: CrossSpaceAlertPage::default(),
         }
     }
 
@@ -540,6 +676,16 @@ impl DivergenceAlertsResponse {
     }
 }
 
+/// Response for acknowledge_divergence_alert tool.
+#[derive(Debug, Clone, Serialize)]
+pub struct AcknowledgeDivergenceAlertResponse {
+    /// The acknowledged cross-space alert
+    pub alert: CrossSpaceAlert,
+
+    /// Unacknowledged cross-space alerts remaining
+    pub remaining_unacknowledged: usize,
+}
+
 /// A single divergence alert from a semantic embedding space.
 #[derive(Debug, Clone, Serialize)]
 pub struct DivergenceAlert {
@@ -592,6 +738,9 @@ mod tests {
         let json = "{}";
         let req: GetTopicPortfolioRequest = serde_json::from_str(json).unwrap();
         assert_eq!(req.format, "standard");
+        assert_eq!(req.sort_by, "size");
+        assert_eq!(req.offset, 0);
+        assert_eq!(req.limit, DEFAULT_PORTFOLIO_LIMIT);
         println!("[PASS] GetTopicPortfolioRequest defaults to 'standard' format");
     }
 
@@ -607,13 +756,27 @@ mod tests {
     fn test_get_topic_portfolio_request_validation() {
         let req = GetTopicPortfolioRequest {
             format: "standard".to_string(),
+            ..Default::default()
         };
         assert!(req.validate().is_ok());
 
         let invalid = GetTopicPortfolioRequest {
             format: "invalid".to_string(),
+            ..Default::default()
         };
         assert!(invalid.validate().is_err());
+
+        let invalid_sort = GetTopicPortfolioRequest {
+            sort_by: "alphabetical".to_string(),
+            ..Default::default()
+        };
+        assert!(invalid_sort.validate().is_err());
+
+        let zero_limit = GetTopicPortfolioRequest {
+            limit: 0,
+            ..Default::default()
+        };
+        assert!(zero_limit.validate().is_err());
         println!("[PASS] GetTopicPortfolioRequest validation works");
     }
 
@@ -664,16 +827,29 @@ mod tests {
 
     #[test]
     fn test_get_divergence_alerts_request_validation() {
-        let req = GetDivergenceAlertsRequest { lookback_hours: 24 };
+        let req = GetDivergenceAlertsRequest {
+            lookback_hours: 24,
+            ..Default::default()
+        };
         assert!(req.validate().is_ok());
 
-        let zero = GetDivergenceAlertsRequest { lookback_hours: 0 };
+        let zero = GetDivergenceAlertsRequest {
+            lookback_hours: 0,
+            ..Default::default()
+        };
         assert!(zero.validate().is_err());
 
         let too_large = GetDivergenceAlertsRequest {
             lookback_hours: 100,
+            ..Default::default()
         };
         assert!(too_large.validate().is_err());
+
+        let zero_limit = GetDivergenceAlertsRequest {
+            limit: 0,
+            ..Default::default()
+        };
+        assert!(zero_limit.validate().is_err());
         println!("[PASS] GetDivergenceAlertsRequest validation works");
     }
 
@@ -684,6 +860,7 @@ mod tests {
         let summary = TopicSummary {
             id: Uuid::nil(),
             name: Some("Test Topic".to_string()),
+            description: None,
             confidence: 0.35,
             weighted_agreement: 3.0,
             member_count: 15,
@@ -706,6 +883,7 @@ mod tests {
         let summary = TopicSummary {
             id: Uuid::nil(),
             name: None,
+            description: None,
             confidence: 0.35,
             weighted_agreement: 3.0,
             member_count: 15,
@@ -725,6 +903,7 @@ mod tests {
         let valid = TopicSummary {
             id: Uuid::nil(),
             name: None,
+            description: None,
             confidence: 0.35,
             weighted_agreement: 3.0,
             member_count: 10,
@@ -738,6 +917,7 @@ mod tests {
         let invalid = TopicSummary {
             id: Uuid::nil(),
             name: None,
+            description: None,
             confidence: 0.2,
             weighted_agreement: 2.0,
             member_count: 5,
@@ -1016,6 +1196,7 @@ mod tests {
         for format in GetTopicPortfolioRequest::VALID_FORMATS {
             let req = GetTopicPortfolioRequest {
                 format: format.to_string(),
+                ..Default::default()
             };
             assert!(
                 req.validate().is_ok(),
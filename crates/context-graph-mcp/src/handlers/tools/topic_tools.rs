@@ -5,6 +5,7 @@
 //! - get_topic_stability: Get portfolio-level stability metrics
 //! - detect_topics: Force topic detection recalculation
 //! - get_divergence_alerts: Check for divergence from recent activity
+//! - acknowledge_divergence_alert: Acknowledge a cross-space divergence alert
 //!
 //! Constitution Compliance:
 //! - AP-60: Temporal embedders (E2-E4) weight = 0.0 in topic detection
@@ -13,23 +14,33 @@
 //!
 //! TASK-INTEG-TOPIC: Integrated with MultiSpaceClusterManager and TopicStabilityTracker.
 
+use std::sync::Arc;
+
 use chrono::Utc;
 use tracing::{debug, error, info, warn};
 
-use context_graph_core::clustering::Topic;
+use context_graph_core::clustering::{
+    label_corpus_ids, reconcile_topic_records, LabelDocument, LabelingConfig, TermVocabulary,
+    Topic, TopicLabeler, TopicRecord,
+};
 use context_graph_core::types::audit::{AuditOperation, AuditRecord};
 use context_graph_core::retrieval::config::low_thresholds;
+use context_graph_core::error::CoreResult;
 use context_graph_core::retrieval::divergence::DIVERGENCE_SPACES;
+use context_graph_core::retrieval::{
+    CrossSpaceAlertPage, DivergenceMonitor, DivergenceMonitorConfig,
+};
 use context_graph_core::teleological::Embedder;
 use context_graph_core::traits::{TeleologicalSearchOptions, TeleologicalSearchResult};
+use context_graph_core::types::fingerprint::TeleologicalFingerprint;
 
 use crate::protocol::{JsonRpcId, JsonRpcResponse};
 use super::helpers::ToolErrorKind;
 
-use super::super::Handlers;
+use super::super::{Handlers, ProgressReporter};
 use super::topic_dtos::{
-    DetectTopicsRequest, DetectTopicsResponse, DivergenceAlert, DivergenceAlertsResponse,
-    GetDivergenceAlertsRequest, GetTopicPortfolioRequest, GetTopicStabilityRequest, PhaseBreakdown,
+    AcknowledgeDivergenceAlertRequest, AcknowledgeDivergenceAlertResponse, DetectTopicsRequest,
+    DetectTopicsResponse, DivergenceAlert, DivergenceAlertsResponse, GetDivergenceAlertsRequest, GetTopicPortfolioRequest, GetTopicStabilityRequest, PhaseBreakdown,
     StabilityMetricsSummary, TopicPortfolioResponse, TopicStabilityResponse, TopicSummary,
 };
 
@@ -78,7 +89,7 @@ fn truncate_summary(content: &str, max_words: usize) -> String {
 /// Convert core Topic to TopicSummary DTO.
 ///
 /// TASK-INTEG-TOPIC: Helper for converting between core and DTO types.
-fn topic_to_summary(topic: &Topic) -> TopicSummary {
+pub(crate) fn topic_to_summary(topic: &Topic) -> TopicSummary {
     let weighted_agreement = topic.profile.weighted_agreement();
     let confidence = TopicSummary::compute_confidence(weighted_agreement);
     // Convert Embedder enum variants to human-readable names
@@ -92,6 +103,7 @@ fn topic_to_summary(topic: &Topic) -> TopicSummary {
     TopicSummary {
         id: topic.id,
         name: topic.name.clone(),
+        description: None,
         confidence,
         weighted_agreement,
         member_count: topic.member_count(),
@@ -102,6 +114,43 @@ fn topic_to_summary(topic: &Topic) -> TopicSummary {
     }
 }
 
+/// Convert a stored TopicRecord to a TopicSummary DTO.
+fn record_to_summary(record: &TopicRecord) -> TopicSummary {
+    TopicSummary {
+        id: record.id,
+        name: record.label.clone(),
+        description: record.description.clone(),
+        confidence: TopicSummary::compute_confidence(record.weighted_agreement),
+        weighted_agreement: record.weighted_agreement,
+        member_count: record.member_count(),
+        contributing_spaces: record
+            .contributing_spaces
+            .iter()
+            .map(|e| format!("{:?}", e))
+            .collect(),
+        phase: format!("{:?}", record.phase),
+        parent_topic_id: record.parent_id.map(|id| id.to_string()),
+        depth: record.depth,
+    }
+}
+
+/// Sort topic records for get_topic_portfolio, descending by `sort_by`
+/// ("size", "recency" or "stability"). Ties are broken by topic ID so pages
+/// are stable between calls.
+fn sort_topic_records(records: &mut [TopicRecord], sort_by: &str) {
+    records.sort_by(|a, b| {
+        let primary = match sort_by {
+            "recency" => b.created_at.cmp(&a.created_at),
+            "stability" => b
+                .stability_score
+                .total_cmp(&a.stability_score)
+                .then(b.runs_observed.cmp(&a.runs_observed)),
+            _ => b.member_count().cmp(&a.member_count()),
+        };
+        primary.then(a.id.cmp(&b.id))
+    });
+}
+
 
 /// Compute Shannon entropy from topic member counts.
 ///
@@ -177,11 +226,13 @@ fn compute_phase_breakdown(
 impl Handlers {
     /// Handle get_topic_portfolio tool call.
     ///
-    /// Returns discovered topics with profiles, stability metrics, and tier info.
+    /// Returns a page of the stored topics with profiles, stability metrics,
+    /// and tier info.
     ///
     /// # Arguments
     /// * `id` - JSON-RPC request ID
-    /// * `arguments` - Tool arguments (format: brief|standard|verbose)
+    /// * `arguments` - Tool arguments (format: brief|standard|verbose,
+    ///   sort_by: size|recency|stability, offset, limit)
     ///
     /// # Returns
     /// JsonRpcResponse with TopicPortfolioResponse
@@ -236,18 +287,28 @@ impl Handlers {
             };
         }
 
-        // TASK-INTEG-TOPIC: Get topics and stability from cluster_manager
-        // (cluster_manager owns the real stability_tracker that gets updated during recluster)
-        let cluster_manager = self.cluster_manager.read();
-        let topics_map = cluster_manager.get_topics();
-
-        // Convert core Topics to TopicSummary DTOs
-        let topics: Vec<TopicSummary> = topics_map.values().map(topic_to_summary).collect();
-
-        let total_topics = topics.len();
+        // Topics come from the stored records (IDs stable across detection
+        // runs); churn comes from cluster_manager's stability tracker.
+        let mut records = match self.teleological_store.list_topic_records().await {
+            Ok(records) => records,
+            Err(e) => {
+                error!(error = %e, "get_topic_portfolio: Failed to load topic records");
+                return self.tool_error(
+                    id,
+                    &format!("Storage error: Failed to load topics: {}", e),
+                );
+            }
+        };
+        let total_topics = records.len();
+        sort_topic_records(&mut records, &request.sort_by);
+        let page: Vec<TopicRecord> = records
+            .into_iter()
+            .skip(request.offset)
+            .take(request.limit)
+            .collect();
+        let has_more = total_topics > request.offset + page.len();
 
-        // Get stability metrics from cluster_manager's internal stability tracker
-        let churn_rate = cluster_manager.current_churn();
+        let churn_rate = self.cluster_manager.read().current_churn();
 
         // MCP-5 FIX: Build response based on format parameter.
         // - "brief": topic names, sizes, and top contributing space only
@@ -255,80 +316,79 @@ impl Handlers {
         // - "verbose": standard + per-topic embedder breakdown
         let format = request.format.as_str();
 
-        let topics_json: Vec<serde_json::Value> = match format {
-            "brief" => {
-                topics.iter().map(|t| {
-                    serde_json::json!({
+        let topics_json: Vec<serde_json::Value> = page
+            .iter()
+            .map(|record| {
+                let t = record_to_summary(record);
+                match format {
+                    "brief" => serde_json::json!({
                         "id": t.id.to_string(),
                         "name": t.name,
                         "member_count": t.member_count,
                         "top_space": t.contributing_spaces.first(),
                         "parentTopicId": t.parent_topic_id,
                         "depth": t.depth
-                    })
-                }).collect()
-            }
-            "verbose" => {
-                // Verbose: standard fields + full embedder strengths from topic profile
-                topics.iter().map(|t| {
-                    let topic_core = topics_map.values().find(|core_t| core_t.id == t.id);
-                    let mut entry = serde_json::json!({
-                        "id": t.id.to_string(),
-                        "name": t.name,
-                        "confidence": t.confidence,
-                        "weighted_agreement": t.weighted_agreement,
-                        "member_count": t.member_count,
-                        "contributing_spaces": t.contributing_spaces,
-                        "phase": t.phase,
-                        "parentTopicId": t.parent_topic_id,
-                        "depth": t.depth
-                    });
-                    if let Some(core_t) = topic_core {
-                        let strengths = core_t.profile.strengths;
-                        let embedder_breakdown: serde_json::Value = serde_json::json!({
-                            "E1_Semantic": strengths[0],
-                            "E2_TemporalRecent": strengths[1],
-                            "E3_TemporalPeriodic": strengths[2],
-                            "E4_TemporalPositional": strengths[3],
-                            "E5_Causal": strengths[4],
-                            "E6_Sparse": strengths[5],
-                            "E7_Code": strengths[6],
-                            "E8_Graph": strengths[7],
-                            "E9_HDC": strengths[8],
-                            "E10_Multimodal": strengths[9],
-                            "E11_Entity": strengths[10],
-                            "E12_LateInteraction": strengths[11],
-                            "E13_SPLADE": strengths[12]
-                        });
-                        entry["embedder_strengths"] = embedder_breakdown;
+                    }),
+                    "verbose" => {
+                        // Verbose: standard fields + full embedder strengths from topic profile
+                        let strengths = record.profile.strengths;
+                        serde_json::json!({
+                            "id": t.id.to_string(),
+                            "name": t.name,
+                            "confidence": t.confidence,
+                            "weighted_agreement": t.weighted_agreement,
+                            "member_count": t.member_count,
+                            "contributing_spaces": t.contributing_spaces,
+                            "phase": t.phase,
+                            "stability_score": record.stability_score,
+                            "runs_observed": record.runs_observed,
+                            "created_at": record.created_at.to_rfc3339(),
+                            "updated_at": record.updated_at.to_rfc3339(),
+                            "parentTopicId": t.parent_topic_id,
+                            "depth": t.depth,
+                            "embedder_strengths": {
+                                "E1_Semantic": strengths[0],
+                                "E2_TemporalRecent": strengths[1],
+                                "E3_TemporalPeriodic": strengths[2],
+                                "E4_TemporalPositional": strengths[3],
+                                "E5_Causal": strengths[4],
+                                "E6_Sparse": strengths[5],
+                                "E7_Code": strengths[6],
+                                "E8_Graph": strengths[7],
+                                "E9_HDC": strengths[8],
+                                "E10_Multimodal": strengths[9],
+                                "E11_Entity": strengths[10],
+                                "E12_LateInteraction": strengths[11],
+                                "E13_SPLADE": strengths[12]
+                            }
+                        })
                     }
-                    entry
-                }).collect()
-            }
-            _ => {
-                // "standard" (default): full TopicSummary serialization
-                topics.iter().map(|t| {
-                    serde_json::json!({
+                    // "standard" (default): full TopicSummary serialization
+                    _ => serde_json::json!({
                         "id": t.id.to_string(),
                         "name": t.name,
                         "confidence": t.confidence,
                         "weighted_agreement": t.weighted_agreement,
                         "member_count": t.member_count,
                         "contributing_spaces": t.contributing_spaces,
-                        "phase": t.phase
-                    })
-                }).collect()
-            }
-        };
+                        "phase": t.phase,
+                        "stability_score": record.stability_score,
+                        "created_at": record.created_at.to_rfc3339()
+                    }),
+                }
+            })
+            .collect();
 
         let stability = StabilityMetricsSummary::new(churn_rate, None);
 
         info!(
             tier = tier,
             total_topics = total_topics,
+            returned = topics_json.len(),
             churn_rate = churn_rate,
             is_stable = stability.is_stable,
             format = format,
+            sort_by = %request.sort_by,
             "get_topic_portfolio: Returning portfolio with {} topics",
             total_topics
         );
@@ -340,7 +400,11 @@ impl Handlers {
                 "is_stable": stability.is_stable
             },
             "total_topics": total_topics,
-            "tier": tier
+            "tier": tier,
+            "sort_by": request.sort_by,
+            "offset": request.offset,
+            "limit": request.limit,
+            "has_more": has_more
         });
 
         self.tool_result(id, response_json)
@@ -348,7 +412,8 @@ impl Handlers {
 
     /// Handle get_topic_stability tool call.
     ///
-    /// Returns stability metrics including churn, entropy, and phase breakdown.
+    /// Returns stability metrics including churn, entropy, phase breakdown,
+    /// and the per-topic stability series for runs within the lookback period.
     ///
     /// # Arguments
     /// * `id` - JSON-RPC request ID
@@ -382,6 +447,10 @@ impl Handlers {
         let cluster_manager = self.cluster_manager.read();
         let churn_rate = cluster_manager.current_churn();
         let average_churn = cluster_manager.average_churn(request.hours as i64);
+        let since = Utc::now() - chrono::Duration::hours(request.hours as i64);
+        let topic_series = cluster_manager
+            .stability_tracker()
+            .topic_series(Some(since));
 
         let high_churn_warning = TopicStabilityResponse::is_high_churn(churn_rate);
 
@@ -400,12 +469,14 @@ impl Handlers {
             phases,
             high_churn_warning,
             average_churn,
+            topic_series,
         };
 
         info!(
             churn_rate = response.churn_rate,
             average_churn = response.average_churn,
             high_churn_warning = response.high_churn_warning,
+            tracked_topics = response.topic_series.len(),
             "get_topic_stability: Returning stability response"
         );
 
@@ -422,6 +493,8 @@ impl Handlers {
     /// # Arguments
     /// * `id` - JSON-RPC request ID
     /// * `arguments` - Tool arguments (force: force detection)
+    /// * `progress` - Stage reporter; a cancel is honored before clustering
+    ///   starts (clustering replaces the cluster manager's contents)
     ///
     /// # Returns
     /// JsonRpcResponse with DetectTopicsResponse
@@ -436,6 +509,7 @@ impl Handlers {
         &self,
         id: Option<JsonRpcId>,
         arguments: serde_json::Value,
+        progress: ProgressReporter,
     ) -> JsonRpcResponse {
         debug!("Handling detect_topics");
 
@@ -462,6 +536,9 @@ impl Handlers {
 
         debug!(force = request.force, max_memories = max_memories, "detect_topics: Processing request");
 
+        const STAGES: u64 = 3;
+        progress.report(0, Some(STAGES), "Counting memories");
+
         // Check minimum memory count
         let memory_count = match self.teleological_store.count().await {
             Ok(c) => c,
@@ -499,6 +576,7 @@ impl Handlers {
         // FIX-BUG-001: Load all fingerprints from storage into cluster_manager BEFORE reclustering.
         // Previously, the cluster_manager only contained fingerprints added during the current session
         // via inject_context/store_memory, missing all existing fingerprints in storage.
+        progress.report(1, Some(STAGES), "Scanning fingerprints");
         info!("detect_topics: Loading all fingerprints from storage for clustering...");
 
         let fingerprints = match self.teleological_store.scan_fingerprints_for_clustering(Some(max_memories)).await {
@@ -517,10 +595,15 @@ impl Handlers {
             "detect_topics: Scanned fingerprints from storage"
         );
 
+        if progress.checkpoint().await.is_err() {
+            return self.cancelled_result(id, "detect_topics", "Clustering");
+        }
+        progress.report(2, Some(STAGES), "Clustering");
+
         // TASK-INTEG-TOPIC: Trigger reclustering via cluster_manager
         // Note: cluster_manager uses parking_lot::RwLock (guard is !Send),
         // so we must drop the guard before any .await calls.
-        let (recluster_result, new_topics, total_after, topic_audit_data) = {
+        let (recluster_result, detected_records) = {
             let mut cluster_manager = self.cluster_manager.write();
 
             // Clear existing data and load all fingerprints from storage
@@ -552,10 +635,6 @@ impl Handlers {
                 "detect_topics: Loaded fingerprints into cluster_manager"
             );
 
-            // Track topics before reclustering
-            let topics_before: std::collections::HashSet<uuid::Uuid> =
-                cluster_manager.get_topics().keys().cloned().collect();
-
             // Run HDBSCAN reclustering
             match cluster_manager.recluster() {
                 Ok(result) => {
@@ -565,33 +644,19 @@ impl Handlers {
                     let churn = cluster_manager.track_churn();
                     info!(churn = churn, "detect_topics: Computed churn after reclustering");
 
-                    let topics_after = cluster_manager.get_topics();
-                    let total_after = topics_after.len();
-
-                    let new_topics: Vec<TopicSummary> = topics_after
-                        .values()
-                        .filter(|t| !topics_before.contains(&t.id))
-                        .map(topic_to_summary)
-                        .collect();
-
-                    // Collect audit data for all detected topics (id + member_count)
-                    let topic_audit_data: Vec<(uuid::Uuid, usize)> = topics_after
-                        .values()
-                        .map(|t| (t.id, t.member_count()))
-                        .collect();
+                    let detected_records = cluster_manager.topic_records(Utc::now());
 
                     info!(
-                        new_topics = new_topics.len(),
-                        total_after = total_after,
+                        topics = detected_records.len(),
                         clusters_found = result.total_clusters,
                         "detect_topics: Reclustering completed successfully"
                     );
 
-                    (Ok(result), new_topics, total_after, topic_audit_data)
+                    (Ok(result), detected_records)
                 }
                 Err(e) => {
                     error!(error = %e, "detect_topics: Reclustering failed");
-                    (Err(e), vec![], 0, vec![])
+                    (Err(e), vec![])
                 }
             }
             // cluster_manager guard dropped here — safe to .await below
@@ -599,14 +664,54 @@ impl Handlers {
 
         match recluster_result {
             Ok(result) => {
+                // Reconcile with the stored topics so IDs survive membership drift
+                let mut reconciliation = match self.teleological_store.list_topic_records().await {
+                    Ok(existing) => reconcile_topic_records(&existing, detected_records, Utc::now()),
+                    Err(e) => {
+                        error!(error = %e, "detect_topics: Failed to load stored topics");
+                        return self.tool_error(
+                            id,
+                            &format!("Storage error: Failed to load topics: {}", e),
+                        );
+                    }
+                };
+                self.relabel_topics(&mut reconciliation.upserts).await;
+                if let Err(e) = self
+                    .teleological_store
+                    .apply_topic_reconciliation(&reconciliation)
+                    .await
+                {
+                    error!(error = %e, "detect_topics: Failed to persist topics");
+                    return self.tool_error(
+                        id,
+                        &format!("Storage error: Failed to persist topics: {}", e),
+                    );
+                }
+                info!(
+                    continued = reconciliation.continued,
+                    created = reconciliation.created,
+                    retired = reconciliation.retired.len(),
+                    "detect_topics: Topic records updated"
+                );
+
+                self.notify_topics_updated();
+
+                let total_after = reconciliation.upserts.len();
+                let new_topics: Vec<TopicSummary> = reconciliation
+                    .upserts
+                    .iter()
+                    .filter(|r| r.runs_observed == 1)
+                    .map(record_to_summary)
+                    .collect();
+
                 // Emit TopicDetected audit for each detected topic (non-fatal)
-                for (topic_id, members) in &topic_audit_data {
+                for record in &reconciliation.upserts {
                     let audit_record = AuditRecord::new(
                         AuditOperation::TopicDetected {
-                            topic_id: topic_id.to_string(),
-                            members: *members,
+                            topic_id: record.id.to_string(),
+                            members: record.member_count(),
                         },
-                        *topic_id,
+                        record.id,
                     )
                     .with_operator("detect_topics")
                     .with_parameters(serde_json::json!({
@@ -617,11 +722,12 @@ impl Handlers {
                     }));
 
                     if let Err(e) = self.teleological_store.append_audit_record(&audit_record).await {
-                        error!(error = %e, topic_id = %topic_id, "detect_topics: Failed to write TopicDetected audit (non-fatal)");
+                        error!(error = %e, topic_id = %record.id, "detect_topics: Failed to write TopicDetected audit (non-fatal)");
                     }
                 }
 
                 let merged_topics = vec![];
+                progress.report(STAGES, Some(STAGES), "Complete");
 
                 let response = DetectTopicsResponse {
                     new_topics,
@@ -701,6 +807,23 @@ impl Handlers {
             }
         };
 
+        // Cross-space divergence: memories whose k-NN neighborhoods disagree
+        // between two embedding spaces (e.g. E1 semantic vs E7 code)
+        let cross_space_alerts = match self
+            .scan_cross_space_divergence(&unbiased_fingerprints, request.offset, request.limit)
+            .await
+        {
+            Ok(page) => page,
+            Err(e) => {
+                error!(error = %e, "get_divergence_alerts: Cross-space divergence scan failed");
+                return self.tool_error_typed(
+                    id,
+                    ToolErrorKind::Storage,
+                    &format!("Cross-space divergence scan failed: {}", e),
+                );
+            }
+        };
+
         // Convert to TeleologicalSearchResult for compatibility with downstream code
         let all_results: Vec<_> = unbiased_fingerprints
             .into_iter()
@@ -728,7 +851,10 @@ impl Handlers {
                 min_required = MIN_MEMORIES_FOR_DIVERGENCE,
                 "get_divergence_alerts: Insufficient memories for divergence detection"
             );
-            let response = DivergenceAlertsResponse::no_alerts();
+            let response = DivergenceAlertsResponse {
+                cross_space_alerts,
+                ..DivergenceAlertsResponse::no_alerts()
+            };
             return match serde_json::to_value(response) {
                 Ok(v) => self.tool_result(id, v),
                 Err(e) => self.tool_error(id, &format!("Response serialization failed: {}", e)),
@@ -805,11 +931,16 @@ impl Handlers {
 
         // Step 8: Compute severity and build response
         let severity = DivergenceAlertsResponse::compute_severity(&alerts);
-        let response = DivergenceAlertsResponse { alerts, severity };
+        let response = DivergenceAlertsResponse {
+            alerts,
+            severity,
+            cross_space_alerts,
+        };
 
         info!(
             alert_count = response.alerts.len(),
             severity = %response.severity,
+            cross_space_unacknowledged = response.cross_space_alerts.total_unacknowledged,
             lookback_hours = lookback_hours,
             "get_divergence_alerts: Detected {} divergence alerts with severity '{}'",
             response.alerts.len(),
@@ -821,6 +952,163 @@ impl Handlers {
             Err(e) => self.tool_error(id, &format!("Response serialization failed: {}", e)),
         }
     }
+
+    /// Handle acknowledge_divergence_alert tool call.
+    ///
+    /// Marks a cross-space divergence alert as acknowledged so it no longer
+    /// appears in get_divergence_alerts pages. Acknowledging twice is a no-op.
+    ///
+    /// # Arguments
+    /// * `id` - JSON-RPC request ID
+    /// * `arguments` - Tool arguments (alert_id: UUID string)
+    ///
+    /// # Returns
+    /// JsonRpcResponse with AcknowledgeDivergenceAlertResponse
+    pub(crate) async fn call_acknowledge_divergence_alert(
+        &self,
+        id: Option<JsonRpcId>,
+        arguments: serde_json::Value,
+    ) -> JsonRpcResponse {
+        debug!("Handling acknowledge_divergence_alert");
+
+        let request: AcknowledgeDivergenceAlertRequest =
+            match self.parse_request(id.clone(), arguments, "acknowledge_divergence_alert") {
+                Ok(req) => req,
+                Err(resp) => return resp,
+            };
+        let alert_id = match request.parse_alert_id() {
+            Ok(alert_id) => alert_id,
+            Err(e) => return self.tool_error_typed(id, ToolErrorKind::Validation, &e),
+        };
+
+        let _guard = self.divergence_alerts_lock.lock().await;
+        let store = self.teleological_store.as_ref();
+
+        let mut monitor =
+            match DivergenceMonitor::load(store, DivergenceMonitorConfig::default()).await {
+                Ok(monitor) => monitor,
+                Err(e) => {
+                    error!(error = %e, "acknowledge_divergence_alert: Failed to load alerts");
+                    return self.tool_error_typed(id, ToolErrorKind::Storage, &e.to_string());
+                }
+            };
+
+        let Some(alert) = monitor.acknowledge(alert_id).cloned() else {
+            return self.tool_error_typed(
+                id,
+                ToolErrorKind::NotFound,
+                &format!("Divergence alert {} not found", alert_id),
+            );
+        };
+
+        if let Err(e) = monitor.persist(store).await {
+            error!(error = %e, "acknowledge_divergence_alert: Failed to persist alerts");
+            return self.tool_error_typed(id, ToolErrorKind::Storage, &e.to_string());
+        }
+
+        let response = AcknowledgeDivergenceAlertResponse {
+            alert,
+            remaining_unacknowledged: monitor.unacknowledged(0, 0).total_unacknowledged,
+        };
+
+        info!(
+            alert_id = %alert_id,
+            remaining = response.remaining_unacknowledged,
+            "acknowledge_divergence_alert: Alert acknowledged"
+        );
+
+        match serde_json::to_value(response) {
+            Ok(v) => self.tool_result(id, v),
+            Err(e) => self.tool_error(id, &format!("Response serialization failed: {}", e)),
+        }
+    }
+
+    /// Share the SPLADE vocabulary loaded at startup with the handlers.
+    ///
+    /// Must be called before wrapping Handlers in Arc.
+    pub(crate) fn set_term_vocabulary(&mut self, vocabulary: Arc<dyn TermVocabulary>) {
+        self.term_vocabulary = Some(vocabulary);
+    }
+
+    /// Regenerate labels for topics that are unlabeled or whose membership
+    /// churned past the labeler's threshold since they were labeled.
+    ///
+    /// Term idf is computed over the members of every detected topic.
+    /// Labeling is best-effort: on a storage error the records keep their
+    /// current labels.
+    async fn relabel_topics(&self, records: &mut [TopicRecord]) {
+        let mut labeler = TopicLabeler::new(LabelingConfig::default());
+        if let Some(vocabulary) = &self.term_vocabulary {
+            labeler = labeler.with_vocabulary(Arc::clone(vocabulary));
+        }
+        let threshold = labeler.config().relabel_churn_threshold;
+        if !records.iter().any(|r| r.needs_relabel(threshold)) {
+            return;
+        }
+
+        let ids = label_corpus_ids(records);
+        let fingerprints = match self.teleological_store.retrieve_batch(&ids).await {
+            Ok(fps) => fps,
+            Err(e) => {
+                warn!(error = %e, "detect_topics: Failed to load members for labeling (non-fatal)");
+                return;
+            }
+        };
+        let contents = match self.teleological_store.get_content_batch(&ids).await {
+            Ok(contents) => contents,
+            Err(e) => {
+                warn!(error = %e, "detect_topics: Failed to load member content for labeling (non-fatal)");
+                vec![None; ids.len()]
+            }
+        };
+
+        let corpus = labeler.corpus(
+            fingerprints
+                .iter()
+                .zip(contents)
+                .filter_map(|(fp, content)| {
+                    fp.as_ref()
+                        .map(|fp| LabelDocument::from_fingerprint(fp, content))
+                }),
+        );
+        let relabeled = labeler.relabel_records(records, &corpus, Utc::now()).await;
+        info!(
+            relabeled = relabeled,
+            corpus = corpus.len(),
+            "detect_topics: Topic labels regenerated"
+        );
+    }
+
+
+    /// Scan fingerprints for cross-space divergence and page the
+    /// unacknowledged alerts.
+    ///
+    /// The alert log is loaded from and persisted to the store under the
+    /// divergence alerts lock, so concurrent scans and acknowledgements
+    /// don't overwrite each other.
+    async fn scan_cross_space_divergence(
+        &self,
+        fingerprints: &[TeleologicalFingerprint],
+        offset: usize,
+        limit: usize,
+    ) -> CoreResult<CrossSpaceAlertPage> {
+        let _guard = self.divergence_alerts_lock.lock().await;
+        let store = self.teleological_store.as_ref();
+
+        let mut monitor =
+            DivergenceMonitor::load(store, DivergenceMonitorConfig::default()).await?;
+        let raised = monitor.scan(fingerprints.iter().map(|fp| (fp.id, &fp.semantic)));
+        if !raised.is_empty() {
+            monitor.persist(store).await?;
+        }
+
+        debug!(
+            raised = raised.len(),
+            "get_divergence_alerts: Cross-space divergence scan complete"
+        );
+
+        Ok(monitor.unacknowledged(offset, limit))
+    }
 }
 
 #[cfg(test)]
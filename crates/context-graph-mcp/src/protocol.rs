@@ -24,6 +24,26 @@ pub struct JsonRpcResponse {
     pub error: Option<JsonRpcError>,
 }
 
+/// JSON-RPC 2.0 notification (server to client, no response expected).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonRpcNotification {
+    pub jsonrpc: String,
+    pub method: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<serde_json::Value>,
+}
+
+impl JsonRpcNotification {
+    /// Create a notification.
+    pub fn new(method: impl Into<String>, params: serde_json::Value) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            method: method.into(),
+            params: Some(params),
+        }
+    }
+}
+
 /// JSON-RPC ID (can be string, number, or null per JSON-RPC 2.0 spec).
 ///
 /// The `Null` variant handles `"id": null` in requests, which is a valid
@@ -69,6 +89,25 @@ impl JsonRpcResponse {
             }),
         }
     }
+
+    /// Create an error response with structured `data` (e.g. a retry hint).
+    pub fn error_with_data(
+        id: Option<JsonRpcId>,
+        code: i32,
+        message: impl Into<String>,
+        data: serde_json::Value,
+    ) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id,
+            result: None,
+            error: Some(JsonRpcError {
+                code,
+                message: message.into(),
+                data: Some(data),
+            }),
+        }
+    }
 }
 
 /// JSON-RPC error codes.
@@ -86,16 +125,30 @@ pub mod error_codes {
     #[allow(dead_code)] // Protocol-defined; used in tests, reserved for future handlers
     pub const FEATURE_DISABLED: i32 = -32001;
     pub const NODE_NOT_FOUND: i32 = -32002;
+    /// resources/read on an unknown URI (the MCP spec assigns -32002)
+    pub const RESOURCE_NOT_FOUND: i32 = NODE_NOT_FOUND;
     pub const STORAGE_ERROR: i32 = -32004;
     #[allow(dead_code)] // Protocol-defined; used in tests, reserved for future handlers
     pub const EMBEDDING_ERROR: i32 = -32005;
     pub const TOOL_NOT_FOUND: i32 = -32006;
     pub const LAYER_TIMEOUT: i32 = -32007;
+    /// A GPU/CUDA operation failed (OOM, kernel, device lost)
+    pub const GPU_ERROR: i32 = -32009;
 
     /// Insufficient memories for topic detection (< min_cluster_size)
     #[allow(dead_code)] // D-L14: used in tests only
     pub const INSUFFICIENT_MEMORIES: i32 = -32021;
 
+    /// Session rate limit exceeded or tool category queue full; `data.retryAfterMs`
+    /// says when to retry
+    pub const SERVER_BUSY: i32 = -32050;
+    /// Embedding models the tool needs are not loaded yet; `data.blockingModels`
+    /// names them and `data.retryAfterMs` says when to retry
+    pub const RETRY_LATER: i32 = -32051;
+    /// The tool needs a GPU capability this machine lacks;
+    /// `data.missingCapabilities` names it
+    pub const CAPABILITY_UNAVAILABLE: i32 = -32052;
+
     // TCP Transport error codes (-32110 to -32119) - TASK-INTEG-018
     /// TCP bind failed - address/port unavailable or permission denied
     #[allow(dead_code)] // D-L15: protocol-defined, used in tests, pending full TCP transport
@@ -122,6 +175,17 @@ pub mod methods {
     // MCP tools protocol methods
     pub const TOOLS_LIST: &str = "tools/list";
     pub const TOOLS_CALL: &str = "tools/call";
+
+    // MCP resources protocol methods
+    pub const RESOURCES_LIST: &str = "resources/list";
+    pub const RESOURCES_READ: &str = "resources/read";
+    pub const RESOURCES_SUBSCRIBE: &str = "resources/subscribe";
+    pub const RESOURCES_UNSUBSCRIBE: &str = "resources/unsubscribe";
+
+    // MCP notifications
+    pub const NOTIFICATION_PROGRESS: &str = "notifications/progress";
+    pub const NOTIFICATION_CANCELLED: &str = "notifications/cancelled";
+    pub const NOTIFICATION_RESOURCE_UPDATED: &str = "notifications/resources/updated";
 }
 
 #[cfg(test)]
@@ -45,23 +45,26 @@ pub enum TransportMode {
     Tcp,
 }
 
+use context_graph_core::clustering::BertVocabulary;
 use context_graph_core::config::Config;
 use context_graph_core::traits::{MultiArrayEmbeddingProvider, TeleologicalMemoryStore};
 
+use context_graph_embeddings::batch::BatchProcessorConfig;
 use context_graph_embeddings::{
     get_warm_provider, initialize_global_warm_provider, is_warm_initialized, warm_status_message,
-    GpuConfig, ProductionMultiArrayProvider,
+    BackpressureProvider, DeterministicProvider, GpuConfig, ProductionMultiArrayProvider,
 };
 #[cfg(feature = "llm")]
 use context_graph_embeddings::{get_warm_causal_model, get_warm_graph_model};
 
 // REAL implementations - NO STUBS
-use crate::adapters::LazyMultiArrayProvider;
+use crate::adapters::{CapabilityMatrix, LazyMultiArrayProvider, ProviderHealth};
 #[cfg(feature = "llm")]
 use crate::adapters::LlmCausalHintProvider;
 #[cfg(feature = "llm")]
 use context_graph_embeddings::provider::CausalHintProvider;
-use context_graph_storage::teleological::RocksDbTeleologicalStore;
+use context_graph_storage::teleological::indexes::IndexBuildConfig;
+use context_graph_storage::teleological::{RocksDbTeleologicalStore, TeleologicalStoreConfig};
 // TASK-GRAPHLINK: EdgeRepository and BackgroundGraphBuilder for K-NN graph linking
 use context_graph_storage::{BackgroundGraphBuilder, EdgeRepository, GraphBuilderConfig};
 // GRAPH-AGENT: LLM-based relationship discovery (requires `llm` feature)
@@ -70,9 +73,12 @@ use context_graph_causal_agent::CausalDiscoveryLLM;
 #[cfg(feature = "llm")]
 use context_graph_graph_agent::{GraphDiscoveryConfig, GraphDiscoveryService};
 
-use crate::handlers::Handlers;
+use crate::handlers::{Handlers, RequestContext};
 use crate::protocol::{JsonRpcRequest, JsonRpcResponse};
 
+/// Rate-limit session for the single stdio client (TCP uses the connection tag).
+const STDIO_SESSION: &str = "stdio";
+
 // NOTE: LazyFailMultiArrayProvider was removed - now using ProductionMultiArrayProvider
 // from context-graph-embeddings crate (TASK-F007 COMPLETED)
 
@@ -161,17 +167,28 @@ impl McpServer {
         let db_path = Self::resolve_storage_path(&config);
         info!("Opening RocksDbTeleologicalStore at {:?}...", db_path);
 
-        let rocksdb_store = RocksDbTeleologicalStore::open(&db_path).map_err(|e| {
-            error!("FATAL: Failed to open RocksDB at {:?}: {}", db_path, e);
-            anyhow::anyhow!(
-                "Failed to open RocksDbTeleologicalStore at {:?}: {}. \
-                 Check path exists, permissions, and RocksDB isn't locked by another process.",
-                db_path,
-                e
-            )
-        })?;
+        // Per-model state for get_embedding_status and RETRY_LATER. Created
+        // before the store so the HNSW rebuild at open reports its progress
+        // there too.
+        let provider_health = Arc::new(ProviderHealth::new());
+        let store_config = TeleologicalStoreConfig {
+            index_build: IndexBuildConfig::default()
+                .with_progress(provider_health.index_build_observer()),
+            ..TeleologicalStoreConfig::default()
+        };
+
+        let rocksdb_store = RocksDbTeleologicalStore::open_with_config(&db_path, store_config)
+            .map_err(|e| {
+                error!("FATAL: Failed to open RocksDB at {:?}: {}", db_path, e);
+                anyhow::anyhow!(
+                    "Failed to open RocksDbTeleologicalStore at {:?}: {}. \
+                     Check path exists, permissions, and RocksDB isn't locked by another process.",
+                    db_path,
+                    e
+                )
+            })?;
         info!(
-            "Created RocksDbTeleologicalStore at {:?} (51 column families, persistent storage)",
+            "Created RocksDbTeleologicalStore at {:?} (57 column families, persistent storage)",
             db_path
         );
 
@@ -202,7 +219,7 @@ impl McpServer {
         let gc_task = tokio::spawn(async move {
             let gc_interval = std::time::Duration::from_secs(5 * 60);
             let gc_retention = 7 * 24 * 3600u64; // 7 days
-            info!("Soft-delete GC background task started (interval=5min, retention=7d)");
+            info!("Soft-delete/TTL GC background task started (interval=5min, retention=7d)");
             loop {
                 // SRV-M1: select! between sleep and shutdown signal.
                 // Whichever fires first wins — no more 5-minute zombie waits.
@@ -228,6 +245,16 @@ impl McpServer {
                         error!("GC cycle failed: {e}");
                     }
                 }
+                match gc_store.purge_expired(chrono::Utc::now()).await {
+                    Ok(purged) => {
+                        if purged > 0 {
+                            info!("GC cycle: purged {purged} TTL-expired entries");
+                        }
+                    }
+                    Err(e) => {
+                        error!("TTL purge failed: {e}");
+                    }
+                }
             }
         });
 
@@ -290,6 +317,17 @@ impl McpServer {
         let edge_repository = EdgeRepository::new(db_arc);
         info!("Created EdgeRepository for K-NN graph linking - NO FALLBACKS enabled");
 
+        // Rewrite typed edges stored before edge types had explicit ordinals
+        let migration = edge_repository
+            .migrate_typed_edges()
+            .map_err(|e| anyhow::anyhow!("Typed edge migration failed: {}", e))?;
+        if migration.edges_migrated > 0 {
+            info!(
+                "Migrated {} of {} typed edges to the current edge format",
+                migration.edges_migrated, migration.edges_scanned
+            );
+        }
+
         // TASK-GRAPHLINK-PHASE1: EdgeRepository clone for BackgroundGraphBuilder
         // The builder will use this to persist K-NN edges computed from embedder agreement
         let edge_repository_for_builder = edge_repository.clone();
@@ -322,6 +360,8 @@ impl McpServer {
             Arc::new(RwLock::new(None));
         let models_loading = Arc::new(AtomicBool::new(true));
         let models_failed: Arc<RwLock<Option<String>>> = Arc::new(RwLock::new(None));
+        // The loaders below bring all 13 models up in one call, so the
+        // per-model states in provider_health move together.
 
         // Check if global warm provider is already initialized
         let warm_provider_initialized = is_warm_initialized();
@@ -336,6 +376,7 @@ impl McpServer {
                     let mut slot = multi_array_provider.write().await;
                     *slot = Some(provider);
                     models_loading.store(false, Ordering::SeqCst);
+                    provider_health.set_all_ready();
                     info!("Using global warm provider - all 13 models ready (no loading delay)");
                 }
                 Err(e) => {
@@ -355,6 +396,7 @@ impl McpServer {
                     let mut failed = models_failed.write().await;
                     *failed = Some(format!("{}", e));
                     models_loading.store(false, Ordering::SeqCst);
+                    provider_health.set_all_failed(&e.to_string());
                 }
             }
             None
@@ -368,6 +410,7 @@ impl McpServer {
 
             let models_dir = Self::resolve_models_path(&config);
             info!("Loading models from {:?}...", models_dir);
+            provider_health.set_all_loading();
 
             // Initialize global warm provider SYNCHRONOUSLY
             match initialize_global_warm_provider().await {
@@ -378,6 +421,7 @@ impl McpServer {
                             let mut slot = multi_array_provider.write().await;
                             *slot = Some(provider);
                             models_loading.store(false, Ordering::SeqCst);
+                            provider_health.set_all_ready();
                             info!("SUCCESS: All 13 embedding models loaded into VRAM and ready");
                         }
                         Err(e) => {
@@ -425,7 +469,9 @@ impl McpServer {
             let provider_slot = Arc::clone(&multi_array_provider);
             let loading_flag = Arc::clone(&models_loading);
             let failed_slot = Arc::clone(&models_failed);
+            let health = Arc::clone(&provider_health);
             let models_dir_clone = models_dir.clone();
+            health.set_all_loading();
 
             // M5 FIX: Store JoinHandle — panics in this task are now observable.
             let model_load_handle = tokio::spawn(async move {
@@ -440,6 +486,7 @@ impl McpServer {
                                 let mut slot = provider_slot.write().await;
                                 *slot = Some(provider);
                                 loading_flag.store(false, Ordering::SeqCst);
+                                health.set_all_ready();
                                 info!(
                                     "Global warm provider initialized - 13 embedders ready (warm)"
                                 );
@@ -449,6 +496,7 @@ impl McpServer {
                                 let mut failed = failed_slot.write().await;
                                 *failed = Some(format!("{}", e));
                                 loading_flag.store(false, Ordering::SeqCst);
+                                health.set_all_failed(&e.to_string());
                             }
                         }
                     }
@@ -469,6 +517,7 @@ impl McpServer {
                                 let mut slot = provider_slot.write().await;
                                 *slot = Some(Arc::new(provider));
                                 loading_flag.store(false, Ordering::SeqCst);
+                                health.set_all_ready();
                                 info!("Background model loading COMPLETE - 13 embedders ready (cold loaded)");
                             }
                             Err(e) => {
@@ -480,6 +529,7 @@ impl McpServer {
                                 let mut failed = failed_slot.write().await;
                                 *failed = Some(format!("{}", e));
                                 loading_flag.store(false, Ordering::SeqCst);
+                                health.set_all_failed(&e.to_string());
                             }
                         }
                     }
@@ -488,13 +538,31 @@ impl McpServer {
             Some(model_load_handle)
         };
 
-        // Create lazy provider wrapper for immediate MCP startup
-        let lazy_provider: Arc<dyn MultiArrayEmbeddingProvider> =
-            Arc::new(LazyMultiArrayProvider::new(
+        // Create lazy provider wrapper for immediate MCP startup, bounded so a
+        // burst of store_memory calls gets Backpressure instead of piling up
+        let mut queue_config = BatchProcessorConfig::default();
+        queue_config.batch_config.max_queue_depth = config.embedding.max_queue_depth;
+        let lazy_provider: Arc<dyn MultiArrayEmbeddingProvider> = Arc::new(
+            LazyMultiArrayProvider::new(
                 Arc::clone(&multi_array_provider),
                 Arc::clone(&models_loading),
                 Arc::clone(&models_failed),
-            ));
+            )
+            .with_health(Arc::clone(&provider_health)),
+        );
+        // Determinism mode sits inside backpressure so rejected requests
+        // never wait on its serializing lock
+        let lazy_provider: Arc<dyn MultiArrayEmbeddingProvider> =
+            if config.embedding.determinism.enabled {
+                Arc::new(DeterministicProvider::new(
+                    lazy_provider,
+                    config.embedding.determinism,
+                ))
+            } else {
+                lazy_provider
+            };
+        let lazy_provider: Arc<dyn MultiArrayEmbeddingProvider> =
+            Arc::new(BackpressureProvider::new(lazy_provider, &queue_config));
 
         // ==========================================================================
         // 3. Create Handlers (PRD v6 Section 10 - 14 tools)
@@ -706,6 +774,28 @@ impl McpServer {
                 start_time: std::time::Instant::now(),
             },
         );
+        handlers.set_dispatch_limits(config.mcp.limits.clone());
+        handlers.set_edge_inference(config.mcp.edge_inference.clone());
+        handlers.set_tool_audit(config.mcp.audit.clone());
+        handlers.set_backup(config.mcp.backup.clone());
+        handlers.set_capability_matrix(Arc::new(
+            CapabilityMatrix::detect().with_health(Arc::clone(&provider_health)),
+        ));
+        handlers.set_provider_health(provider_health);
+
+        // Topic labels read E6/E13 terms through the sparse model's vocabulary
+        let vocab_path = Self::resolve_models_path(&config)
+            .join("sparse")
+            .join("vocab.txt");
+        if vocab_path.exists() {
+            match BertVocabulary::from_vocab_txt(&vocab_path) {
+                Ok(vocabulary) => handlers.set_term_vocabulary(Arc::new(vocabulary)),
+                Err(e) => warn!(
+                    "Failed to load SPLADE vocabulary from {:?}: {} - topic labels will use content words",
+                    vocab_path, e
+                ),
+            }
+        }
 
         Ok(Self {
             config,
@@ -754,9 +844,7 @@ impl McpServer {
         // when background tasks (like model loading) need to progress.
         let stdin = tokio::io::stdin();
         let stdout = tokio::io::stdout();
-        let mut reader = BufReader::new(stdin);
         let mut writer = tokio::io::BufWriter::new(stdout);
-        let mut line = String::new();
 
         info!("Server ready, waiting for requests (TeleologicalMemoryStore mode)...");
 
@@ -803,23 +891,31 @@ impl McpServer {
             }
         }
 
-        loop {
-            line.clear();
+        // AGT-04 FIX: The line reader uses bounded read_line to prevent OOM from unbounded input.
+        // read_line() allocates until newline; a malicious/broken client can OOM the process.
+        let mut lines =
+            transport::LineReader::spawn(BufReader::new(stdin), Arc::clone(&self.handlers));
+        let (notify_tx, mut notify_rx) = tokio::sync::mpsc::unbounded_channel();
 
-            // AGT-04 FIX: Use bounded read_line to prevent OOM from unbounded input.
-            // read_line() allocates until newline; a malicious/broken client can OOM the process.
-            let bytes_read = transport::read_line_bounded(&mut reader, &mut line, transport::MAX_LINE_BYTES)
+        loop {
+            let next = lines
+                .next_line(&mut notify_rx, &mut writer)
                 .await
                 .map_err(|e| {
+                    error!("FATAL: Failed to write notification to stdout: {}", e);
+                    anyhow::anyhow!("stdout write error: {}", e)
+                })?;
+            let line = match next {
+                Some(line) => line.map_err(|e| {
                     error!("FATAL: Failed to read from stdin: {}", e);
                     anyhow::anyhow!("stdin read error: {}", e)
-                })?;
-
-            // EOF - client closed connection
-            if bytes_read == 0 {
-                info!("stdin closed (EOF), shutting down...");
-                break;
-            }
+                })?,
+                None => {
+                    // EOF - client closed connection
+                    info!("stdin closed (EOF), shutting down...");
+                    break;
+                }
+            };
 
             let trimmed = line.trim();
             if trimmed.is_empty() {
@@ -908,7 +1004,10 @@ impl McpServer {
                     // Apply per-request timeout (same as single-request path)
                     let response = match tokio::time::timeout(
                         std::time::Duration::from_secs(request_timeout),
-                        self.handle_request(&req_str),
+                        self.handle_request(
+                            &req_str,
+                            RequestContext::default().with_session(STDIO_SESSION),
+                        ),
                     )
                     .await
                     {
@@ -989,12 +1088,20 @@ impl McpServer {
             // independently framed. A malformed line cannot cause byte-stream misalignment
             // because the next newline always starts a fresh message boundary. TCP streams
             // lack this guarantee, so a parse error may indicate irrecoverable misalignment.
-            let response = match tokio::time::timeout(
-                std::time::Duration::from_secs(request_timeout),
-                self.handle_request(trimmed),
+            let ctx = RequestContext::with_notifier(notify_tx.clone()).with_session(STDIO_SESSION);
+            let response = match transport::dispatch_streaming(
+                tokio::time::timeout(
+                    std::time::Duration::from_secs(request_timeout),
+                    self.handle_request(trimmed, ctx),
+                ),
+                &mut notify_rx,
+                &mut writer,
             )
             .await
-            {
+            .map_err(|e| {
+                error!("FATAL: Failed to write notification to stdout: {}", e);
+                anyhow::anyhow!("stdout write error: {}", e)
+            })? {
                 Ok(result) => result,
                 Err(_) => {
                     error!(
@@ -1142,7 +1249,9 @@ impl McpServer {
     }
 
     /// Handle a single JSON-RPC request.
-    async fn handle_request(&self, input: &str) -> JsonRpcResponse {
+    ///
+    /// Notifications the request emits (e.g. tool progress) go to `ctx`.
+    async fn handle_request(&self, input: &str, ctx: RequestContext) -> JsonRpcResponse {
         // Parse request
         let request: JsonRpcRequest = match serde_json::from_str(input) {
             Ok(r) => r,
@@ -1166,7 +1275,7 @@ impl McpServer {
         }
 
         // Dispatch to handler
-        self.handlers.dispatch(request).await
+        self.handlers.dispatch_with_context(request, ctx).await
     }
 
     /// Resolve the storage path from configuration or environment.
@@ -3,6 +3,7 @@
 //! Contains TCP transport code extracted from the main server module.
 //! TASK-INTEG-018: TCP transport with concurrent client handling.
 
+use std::future::Future;
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
@@ -14,12 +15,15 @@ use std::time::Duration;
 static CONNECTION_COUNTER: AtomicU64 = AtomicU64::new(0);
 
 use anyhow::Result;
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use serde::Serialize;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt, BufReader};
 use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 use tracing::{debug, error, info, warn};
 
-use crate::handlers::Handlers;
-use crate::protocol::{JsonRpcRequest, JsonRpcResponse};
+use crate::handlers::{Handlers, RequestContext};
+use crate::protocol::{methods, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse};
 
 use super::McpServer;
 
@@ -116,6 +120,137 @@ pub async fn read_line_bounded<R: tokio::io::AsyncBufRead + Unpin>(
     Ok(total)
 }
 
+// ============================================================================
+// Progress notifications and mid-request cancellation
+// ============================================================================
+
+/// Background reader that forwards NDJSON lines from a connection.
+///
+/// Requests are processed one at a time, so a `notifications/cancelled` sent
+/// while a long tool call runs would otherwise sit unread until the call
+/// finished. The reader task applies cancels as soon as they arrive and
+/// forwards every other line to the request loop.
+///
+/// The task is aborted when the reader is dropped.
+pub(crate) struct LineReader {
+    lines: mpsc::Receiver<std::io::Result<String>>,
+    task: JoinHandle<()>,
+}
+
+impl LineReader {
+    /// Lines buffered ahead of the request loop.
+    const BUFFERED_LINES: usize = 16;
+
+    pub(crate) fn spawn<R>(mut reader: R, handlers: Arc<Handlers>) -> Self
+    where
+        R: AsyncBufRead + Unpin + Send + 'static,
+    {
+        let (tx, lines) = mpsc::channel(Self::BUFFERED_LINES);
+        let task = tokio::spawn(async move {
+            loop {
+                let mut line = String::new();
+                match read_line_bounded(&mut reader, &mut line, MAX_LINE_BYTES).await {
+                    Ok(0) => break,
+                    Ok(_) => {
+                        if let Some(cancel) = parse_cancel_notification(&line) {
+                            handlers.dispatch(cancel).await;
+                            continue;
+                        }
+                        if tx.send(Ok(line)).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Err(e)).await;
+                        break;
+                    }
+                }
+            }
+        });
+        Self { lines, task }
+    }
+
+    /// Next line from the connection, or None at EOF.
+    ///
+    /// Notifications queued while waiting (e.g. resource updates) are written
+    /// to `writer` as they arrive; the outer error is a failed write.
+    pub(crate) async fn next_line<W>(
+        &mut self,
+        notifications: &mut mpsc::UnboundedReceiver<JsonRpcNotification>,
+        writer: &mut W,
+    ) -> std::io::Result<Option<std::io::Result<String>>>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        loop {
+            tokio::select! {
+                line = self.lines.recv() => return Ok(line),
+                Some(notification) = notifications.recv() => {
+                    write_line(writer, &notification).await?;
+                }
+            }
+        }
+    }
+}
+
+impl Drop for LineReader {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Parse `line` if it is a `notifications/cancelled` message.
+fn parse_cancel_notification(line: &str) -> Option<JsonRpcRequest> {
+    // Cheap pre-check; almost every line is an ordinary request
+    if !line.contains(methods::NOTIFICATION_CANCELLED) {
+        return None;
+    }
+    serde_json::from_str::<JsonRpcRequest>(line.trim())
+        .ok()
+        .filter(|r| {
+            r.jsonrpc == "2.0" && r.id.is_none() && r.method == methods::NOTIFICATION_CANCELLED
+        })
+}
+
+/// Await `dispatch`, writing the notifications it emits to `writer`.
+///
+/// Notifications still queued when the dispatch finishes are written before
+/// returning, so they always precede the response on the wire.
+pub(crate) async fn dispatch_streaming<F, W>(
+    dispatch: F,
+    notifications: &mut mpsc::UnboundedReceiver<JsonRpcNotification>,
+    writer: &mut W,
+) -> std::io::Result<F::Output>
+where
+    F: Future,
+    W: AsyncWrite + Unpin,
+{
+    tokio::pin!(dispatch);
+    let output = loop {
+        tokio::select! {
+            output = &mut dispatch => break output,
+            Some(notification) = notifications.recv() => {
+                write_line(writer, &notification).await?;
+            }
+        }
+    };
+    while let Ok(notification) = notifications.try_recv() {
+        write_line(writer, &notification).await?;
+    }
+    Ok(output)
+}
+
+async fn write_line<W, T>(writer: &mut W, message: &T) -> std::io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+    T: Serialize,
+{
+    let json = serde_json::to_string(message)?;
+    writer.write_all(json.as_bytes()).await?;
+    writer.write_all(b"\n").await?;
+    writer.flush().await
+}
+
 // ============================================================================
 // TASK-INTEG-018: TCP Transport Implementation
 // ============================================================================
@@ -270,21 +405,23 @@ impl McpServer {
         conn_tag: &str,
     ) -> Result<()> {
         let (reader, mut writer) = stream.into_split();
-        let mut reader = BufReader::new(reader);
-        let mut line = String::new();
+        // AGT-04 FIX: The line reader uses bounded read_line to prevent OOM from unbounded input.
+        // TCP is externally exploitable - a malicious client can send multi-GB data without newlines.
+        let mut lines = LineReader::spawn(BufReader::new(reader), Arc::clone(&handlers));
+        let (notify_tx, mut notify_rx) = mpsc::unbounded_channel();
 
         loop {
-            line.clear();
-
-            // AGT-04 FIX: Use bounded read_line to prevent OOM from unbounded input.
-            // TCP is externally exploitable - a malicious client can send multi-GB data without newlines.
-            let bytes_read = read_line_bounded(&mut reader, &mut line, MAX_LINE_BYTES).await?;
-
-            // EOF - client closed connection
-            if bytes_read == 0 {
-                debug!("[{}] Client {} closed connection (EOF)", conn_tag, peer_addr);
-                break;
-            }
+            let line = match lines.next_line(&mut notify_rx, &mut writer).await? {
+                Some(line) => line?,
+                None => {
+                    // EOF - client closed connection
+                    debug!(
+                        "[{}] Client {} closed connection (EOF)",
+                        conn_tag, peer_addr
+                    );
+                    break;
+                }
+            };
 
             let trimmed = line.trim();
             if trimmed.is_empty() {
@@ -512,11 +649,16 @@ impl McpServer {
             // so timeout errors can include the correct id per JSON-RPC 2.0 spec.
             let request_id = request.id.clone();
             let is_notification = request_id.is_none();
-            let response = match tokio::time::timeout(
-                Duration::from_secs(request_timeout),
-                handlers.dispatch(request),
+            let ctx = RequestContext::with_notifier(notify_tx.clone()).with_session(conn_tag);
+            let response = match dispatch_streaming(
+                tokio::time::timeout(
+                    Duration::from_secs(request_timeout),
+                    handlers.dispatch_with_context(request, ctx),
+                ),
+                &mut notify_rx,
+                &mut writer,
             )
-            .await
+            .await?
             {
                 Ok(result) => result,
                 Err(_) => {
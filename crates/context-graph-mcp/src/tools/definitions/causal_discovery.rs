@@ -15,8 +15,11 @@
 //! - AP-77: Direction modifiers (cause→effect=1.2, effect→cause=0.8)
 //! - E5 embeddings generated by real CausalModel inference
 
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::common::SessionScope;
 use crate::tools::types::ToolDefinition;
-use serde_json::json;
 
 /// Returns causal discovery tool definitions.
 /// Without `llm` feature: 0 tools (causal discovery requires LLM).
@@ -29,84 +32,109 @@ pub fn definitions() -> Vec<ToolDefinition> {
     #[cfg(feature = "llm")]
     vec![
         // trigger_causal_discovery - Manual trigger for causal analysis
-        ToolDefinition::new(
+        ToolDefinition::with_params::<TriggerCausalDiscoveryParams>(
             "trigger_causal_discovery",
             "Manually trigger the causal discovery agent to analyze memories for cause-effect relationships. \
              Uses Qwen2.5 via Candle with native CUDA for RTX 5090 Blackwell optimization. \
              FP16 tensor cores for inference, INT8 for larger models. \
              Confirmed relationships are embedded using CausalModel.embed_dual() for asymmetric E5 vectors. \
              VRAM usage: 3B=~6GB FP16, 7B=~14GB FP16. Use after bulk memory imports or for immediate causal analysis.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "mode": {
-                        "type": "string",
-                        "enum": ["extract", "pairs"],
-                        "description": "Discovery mode: 'extract' (find ALL cause-effect in each memory, default) or 'pairs' (find relationships BETWEEN memories via GraphDiscoveryService).",
-                        "default": "extract"
-                    },
-                    "maxPairs": {
-                        "type": "integer",
-                        "description": "Maximum candidate pairs to analyze per run (1-200, default: 50). More pairs = longer runtime.",
-                        "default": 50,
-                        "minimum": 1,
-                        "maximum": 200
-                    },
-                    "minConfidence": {
-                        "type": "number",
-                        "description": "Minimum LLM confidence to accept a causal relationship (0.5-1.0, default: 0.7). Higher = fewer but more confident relationships.",
-                        "default": 0.7,
-                        "minimum": 0.5,
-                        "maximum": 1.0
-                    },
-                    "sessionScope": {
-                        "type": "string",
-                        "enum": ["current", "all", "recent"],
-                        "description": "Scope of memories to analyze: 'current' (current session if session_id provided, else last 10 files), 'recent' (last 50 indexed files), 'all' (all indexed files). Default: 'all'.",
-                        "default": "all"
-                    },
-                    "session_id": {
-                        "type": "string",
-                        "description": "Optional session ID. When provided with sessionScope='current', filters memories by actual session_id from source_metadata instead of using a file-count proxy."
-                    },
-                    "dryRun": {
-                        "type": "boolean",
-                        "description": "If true, only find candidates and analyze with LLM, but don't create embeddings or graph edges. Useful for testing.",
-                        "default": false
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
         // get_causal_discovery_status - Check agent status
-        ToolDefinition::new(
+        ToolDefinition::with_params::<GetCausalDiscoveryStatusParams>(
             "get_causal_discovery_status",
             "Get the status and statistics of the causal discovery agent. \
              Shows whether the agent is running, last cycle results, VRAM usage, \
              and cumulative statistics (pairs analyzed, relationships found, etc.).",
-            json!({
-                "type": "object",
-                "properties": {
-                    "includeLastResult": {
-                        "type": "boolean",
-                        "description": "Include detailed results from the last discovery cycle (default: true).",
-                        "default": true
-                    },
-                    "includeGraphStats": {
-                        "type": "boolean",
-                        "description": "Include causal graph statistics (node count, edge count, etc.). Default: true.",
-                        "default": true
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
     ]
 }
 
+/// Parameters of the `trigger_causal_discovery` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TriggerCausalDiscoveryParams {
+    /// Discovery mode: 'extract' (find ALL cause-effect in each memory, default) or 'pairs' (find
+    /// relationships BETWEEN memories via GraphDiscoveryService).
+    #[serde(default = "default_mode")]
+    pub mode: DiscoveryMode,
+
+    /// Maximum candidate pairs to analyze per run (1-200, default: 50). More pairs = longer
+    /// runtime.
+    #[serde(rename = "maxPairs", default = "default_max_pairs")]
+    #[schemars(range(min = 1, max = 200))]
+    pub max_pairs: u64,
+
+    /// Minimum LLM confidence to accept a causal relationship (0.5-1.0, default: 0.7). Higher =
+    /// fewer but more confident relationships.
+    #[serde(rename = "minConfidence", default = "default_min_confidence")]
+    #[schemars(range(min = 0.5, max = 1.0))]
+    pub min_confidence: f64,
+
+    /// Scope of memories to analyze: 'current' (current session if session_id provided, else last
+    /// 10 files), 'recent' (last 50 indexed files), 'all' (all indexed files). Default: 'all'.
+    #[serde(rename = "sessionScope", default = "default_session_scope")]
+    pub session_scope: SessionScope,
+
+    /// Optional session ID. When provided with sessionScope='current', filters memories by actual
+    /// session_id from source_metadata instead of using a file-count proxy.
+    pub session_id: Option<String>,
+
+    /// If true, only find candidates and analyze with LLM, but don't create embeddings or graph
+    /// edges. Useful for testing.
+    #[serde(rename = "dryRun", default)]
+    pub dry_run: bool,
+}
+
+fn default_mode() -> DiscoveryMode {
+    DiscoveryMode::Extract
+}
+
+fn default_max_pairs() -> u64 {
+    50
+}
+
+fn default_min_confidence() -> f64 {
+    0.7
+}
+
+fn default_session_scope() -> SessionScope {
+    SessionScope::All
+}
+
+/// How causal discovery analyzes memories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscoveryMode {
+    Extract,
+    Pairs,
+}
+
+/// Parameters of the `get_causal_discovery_status` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GetCausalDiscoveryStatusParams {
+    /// Include detailed results from the last discovery cycle (default: true).
+    #[serde(default = "default_include_last_result")]
+    pub include_last_result: bool,
+
+    /// Include causal graph statistics (node count, edge count, etc.). Default: true.
+    #[serde(default = "default_include_graph_stats")]
+    pub include_graph_stats: bool,
+}
+
+fn default_include_last_result() -> bool {
+    true
+}
+
+fn default_include_graph_stats() -> bool {
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
 
     #[test]
     fn test_definitions_exist() {
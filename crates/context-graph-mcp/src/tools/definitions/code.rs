@@ -7,7 +7,8 @@
 //! Tools:
 //! - search_code: Find memories containing code patterns using E7 dense embeddings
 
-use serde_json::json;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 use crate::tools::types::ToolDefinition;
 
@@ -21,59 +22,77 @@ pub fn definitions() -> Vec<ToolDefinition> {
 
 /// Definition for search_code tool.
 fn search_code_definition() -> ToolDefinition {
-    ToolDefinition::new(
+    ToolDefinition::with_params::<SearchCodeParams>(
         "search_code",
         "Find memories containing code patterns using E7 dense embeddings (1536D). ENHANCES E1 semantic search with code-specific understanding. Use for \"code queries (implementations, functions)\" per constitution. Detects programming language from query. Returns nodes matching the query with relevance scores and detected language info.",
-        json!({
-            "type": "object",
-            "properties": {
-                "query": {
-                    "type": "string",
-                    "description": "The code query to search for. Can describe functionality, patterns, or specific code constructs."
-                },
-                "topK": {
-                    "type": "integer",
-                    "default": 10,
-                    "minimum": 1,
-                    "maximum": 50,
-                    "description": "Maximum number of results to return (1-50, default: 10)."
-                },
-                "minScore": {
-                    "type": "number",
-                    "default": 0.2,
-                    "minimum": 0,
-                    "maximum": 1,
-                    "description": "Minimum blended score threshold (0-1, default: 0.2). Results below this are filtered."
-                },
-                "blendWithSemantic": {
-                    "type": "number",
-                    "default": 0.4,
-                    "minimum": 0,
-                    "maximum": 1,
-                    "description": "E7 code weight in blend (0-1, default: 0.4). Higher = more code-specific emphasis. 0.0=pure E1 semantic, 1.0=pure E7 code."
-                },
-                "includeContent": {
-                    "type": "boolean",
-                    "default": false,
-                    "description": "Include full content text in results (default: false)."
-                },
-                "searchMode": {
-                    "type": "string",
-                    "enum": ["hybrid", "e7Only", "e1WithE7Rerank", "pipeline"],
-                    "default": "hybrid",
-                    "description": "Code search strategy: 'hybrid' (default, blend E1+E7 scores), 'e7Only' (pure E7 code search), 'e1WithE7Rerank' (E1 retrieval with E7 reranking), 'pipeline' (alias for hybrid, MED-17)."
-                },
-                "languageHint": {
-                    "type": "string",
-                    "description": "Optional programming language hint to boost language-specific results. Supports: rust, python, javascript, typescript, go, java, cpp, sql."
-                }
-            },
-            "required": ["query"],
-            "additionalProperties": false
-        }),
     )
 }
 
+/// Parameters of the `search_code` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SearchCodeParams {
+    /// The code query to search for. Can describe functionality, patterns, or specific code
+    /// constructs.
+    pub query: String,
+
+    /// Maximum number of results to return (1-50, default: 10).
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 50))]
+    pub top_k: u64,
+
+    /// Minimum blended score threshold (0-1, default: 0.2). Results below this are filtered.
+    #[serde(default = "default_min_score")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_score: f64,
+
+    /// E7 code weight in blend (0-1, default: 0.4). Higher = more code-specific emphasis. 0.0=pure
+    /// E1 semantic, 1.0=pure E7 code.
+    #[serde(default = "default_blend_with_semantic")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub blend_with_semantic: f64,
+
+    /// Include full content text in results (default: false).
+    #[serde(default)]
+    pub include_content: bool,
+
+    /// Code search strategy: 'hybrid' (default, blend E1+E7 scores), 'e7Only' (pure E7 code
+    /// search), 'e1WithE7Rerank' (E1 retrieval with E7 reranking), 'pipeline' (alias for hybrid,
+    /// MED-17).
+    #[serde(default = "default_search_mode")]
+    pub search_mode: CodeSearchMode,
+
+    /// Optional programming language hint to boost language-specific results. Supports: rust,
+    /// python, javascript, typescript, go, java, cpp, sql.
+    pub language_hint: Option<String>,
+}
+
+fn default_top_k() -> u64 {
+    10
+}
+
+fn default_min_score() -> f64 {
+    0.2
+}
+
+fn default_blend_with_semantic() -> f64 {
+    0.4
+}
+
+fn default_search_mode() -> CodeSearchMode {
+    CodeSearchMode::Hybrid
+}
+
+/// How E7 code search combines with E1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum CodeSearchMode {
+    Hybrid,
+    E7Only,
+    E1WithE7Rerank,
+    Pipeline,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,176 @@
+//! Parameter types shared by several tool definitions.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Direction to follow edges from the anchor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChainDirection {
+    Forward,
+    Backward,
+}
+
+/// Recency decay curve for E2 scoring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DecayFunction {
+    Linear,
+    Exponential,
+    Step,
+    None,
+    NoDecay,
+}
+
+/// Content domain of a memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Domain {
+    General,
+    Code,
+    Legal,
+    Academic,
+    Creative,
+}
+
+/// Typed edge between two memories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeType {
+    SemanticSimilar,
+    CodeRelated,
+    EntityShared,
+    CausalChain,
+    GraphConnected,
+    ParaphraseAligned,
+    KeywordOverlap,
+    MultiAgreement,
+    Refutes,
+    Supersedes,
+    DerivedFrom,
+    TemporalFollows,
+}
+
+/// One of the 13 embedders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Embedder {
+    E1,
+    E2,
+    E3,
+    E4,
+    E5,
+    E6,
+    E7,
+    E8,
+    E9,
+    E10,
+    E11,
+    E12,
+    E13,
+}
+
+/// Retrieval strategy of a search tool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchStrategy {
+    E1Only,
+    MultiSpace,
+    Pipeline,
+}
+
+/// Sessions a tool looks at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionScope {
+    Current,
+    All,
+    Recent,
+}
+
+/// Time scale of the recency decay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TemporalScale {
+    Micro,
+    Meso,
+    Macro,
+    Long,
+    Archival,
+}
+
+/// Built-in embedder weight profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WeightProfile {
+    SemanticSearch,
+    CausalReasoning,
+    CodeSearch,
+    FactChecking,
+    GraphReasoning,
+    TemporalNavigation,
+    SequenceNavigation,
+    ConversationHistory,
+    CategoryWeighted,
+    TypoTolerant,
+    PipelineStage1Recall,
+    PipelineStage2Scoring,
+    PipelineFull,
+    Balanced,
+}
+
+/// Per-embedder weights, each 0-1.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "UPPERCASE", deny_unknown_fields)]
+pub struct EmbedderWeights {
+    /// Semantic similarity (foundation)
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e1: Option<f64>,
+
+    /// Temporal recency — find memories stored near a given time
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e2: Option<f64>,
+
+    /// Temporal periodicity — find memories matching time-of-day or day-of-week patterns
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e3: Option<f64>,
+
+    /// Temporal sequence — find memories near the same position in a conversation
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e4: Option<f64>,
+
+    /// Causal relationships (cause→effect)
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e5: Option<f64>,
+
+    /// Sparse keyword matching
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e6: Option<f64>,
+
+    /// Code pattern similarity
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e7: Option<f64>,
+
+    /// Graph structure (imports, dependencies)
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e8: Option<f64>,
+
+    /// Noise-robust matching (typo tolerant)
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e9: Option<f64>,
+
+    /// Paraphrase/multimodal similarity
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e10: Option<f64>,
+
+    /// Named entity matching
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e11: Option<f64>,
+
+    /// ColBERT reranking (stage only, weight 0)
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e12: Option<f64>,
+
+    /// SPLADE recall (stage only, weight 0)
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e13: Option<f64>,
+}
@@ -1,69 +1,56 @@
 //! Core tool definitions per PRD v6 Section 10.
 //!
-//! Tools: store_memory, get_memetic_status, search_graph, trigger_consolidation
+//! Tools: store_memory, store_memories_batch, get_memetic_status, search_graph,
+//! trigger_consolidation
 //!
 //! Note: inject_context was merged into store_memory. When `rationale` is provided,
 //! the same validation (1-1024 chars) and response format is used.
 
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::common::{
+    DecayFunction, Domain, EdgeType, Embedder, EmbedderWeights, SearchStrategy, SessionScope,
+    TemporalScale, WeightProfile,
+};
 use crate::tools::types::ToolDefinition;
-use serde_json::json;
 
-/// Returns core tool definitions (4 tools - inject_context merged into store_memory).
+/// Returns core tool definitions (5 tools - inject_context merged into store_memory).
 pub fn definitions() -> Vec<ToolDefinition> {
     vec![
         // store_memory - store a memory node directly
         // Note: inject_context merged into this tool. When rationale is provided,
         // the same validation and response format is used.
-        ToolDefinition::new(
+        ToolDefinition::with_params::<StoreMemoryParams>(
             "store_memory",
             "Store a memory node directly in the knowledge graph without UTL processing.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "content": {
-                        "type": "string",
-                        "description": "The content to store"
-                    },
-                    "rationale": {
-                        "type": "string",
-                        "minLength": 1,
-                        "maxLength": 1024,
-                        "description": "Why this context is relevant and should be stored (OPTIONAL, 1-1024 chars). When provided, included in response."
-                    },
-                    "importance": {
-                        "type": "number",
-                        "minimum": 0,
-                        "maximum": 1,
-                        "default": 0.5,
-                        "description": "Importance score for the memory [0.0, 1.0]"
-                    },
-                    "sessionId": {
-                        "type": "string",
-                        "description": "Session ID for session-scoped storage. If omitted, uses CLAUDE_SESSION_ID env var."
-                    },
-                    "operatorId": {
-                        "type": "string",
-                        "description": "Operator/user ID for audit provenance tracking"
-                    }
-                },
-                "required": ["content"],
-                "additionalProperties": false
-            }),
+        ),
+        // store_memories_batch - store many memories in one call with per-item results
+        ToolDefinition::with_params::<StoreMemoriesBatchParams>(
+            "store_memories_batch",
+            "Store up to 100 memories in one call (4 MiB combined content). Items are embedded \
+             together and stored independently: each result carries the item index and either \
+             a fingerprintId or an error, so one bad item never aborts the rest. Exact duplicates \
+             return the existing fingerprintId with deduplicated=true unless allowDuplicates is set. \
+             Response includes aggregate timing (embedMs, storeMs). No LLM causal extraction is run.",
         ),
         // get_memetic_status - get system state and metrics
-        ToolDefinition::new(
+        ToolDefinition::with_params::<GetMemeticStatusParams>(
             "get_memetic_status",
             "Get current system status including fingerprint count, number of embedders (13), \
-             storage backend and size, and layer status from LayerStatusProvider.",
-            json!({
-                "type": "object",
-                "properties": {},
-                "required": [],
-                "additionalProperties": false
-            }),
+             storage backend and size, layer status from LayerStatusProvider, and dispatch \
+             limits (rate limit, per-category concurrency caps, in-flight and rejected calls), \
+             and the capability matrix (CUDA driver, Candle device, FAISS GPU, per-model load \
+             status). Without a GPU, detect_topics returns CAPABILITY_UNAVAILABLE and \
+             embedding tools run on CPU with degraded: true in the result. Counts, layer \
+             status and storage size are cached per component; `components` gives each one's \
+             computed_at and stale flag. `storage` breaks the on-disk size down per column \
+             family (live data, keys, pending compaction, SST files) and auxiliary file.",
         ),
         // search_graph - semantic search with E5 causal and E10 paraphrase asymmetric similarity (ARCH-15, AP-77)
-        ToolDefinition::new(
+        ToolDefinition::with_params::<SearchGraphParams>(
             "search_graph",
             "Search the knowledge graph using multi-space semantic similarity across 13 embedders. \
              Supports temporal search via E2 (recency), E3 (periodicity), E4 (sequence) — \
@@ -71,248 +58,490 @@ pub fn definitions() -> Vec<ToolDefinition> {
              For causal queries ('why', 'what happens'), automatically applies \
              asymmetric E5 similarity with direction modifiers (cause→effect 1.2x, \
              effect→cause 0.8x). Returns nodes matching the query with relevance scores.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "query": {
-                        "type": "string",
-                        "description": "The search query text"
-                    },
-                    "topK": {
-                        "type": "integer",
-                        "minimum": 1,
-                        "maximum": 100,
-                        "default": 10,
-                        "description": "Maximum number of results to return"
-                    },
-                    "minSimilarity": {
-                        "type": "number",
-                        "minimum": 0,
-                        "maximum": 1,
-                        "default": 0.0,
-                        "description": "Minimum similarity threshold [0.0, 1.0]"
-                    },
-                    "includeContent": {
-                        "type": "boolean",
-                        "default": false,
-                        "description": "Include content text in results"
-                    },
-                    "strategy": {
-                        "type": "string",
-                        "enum": ["e1_only", "multi_space", "pipeline"],
-                        "default": "multi_space",
-                        "description": "Search strategy: e1_only (fast, E1 only), multi_space (default - E1 + enhancers via Weighted RRF, uses weightProfile), pipeline (E13 recall → E1 dense → E12 rerank)"
-                    },
-                    "weightProfile": {
-                        "type": "string",
-                        "enum": [
-                            "semantic_search", "causal_reasoning", "code_search", "fact_checking",
-                            "graph_reasoning", "temporal_navigation", "sequence_navigation",
-                            "conversation_history", "category_weighted", "typo_tolerant",
-                            "pipeline_stage1_recall", "pipeline_stage2_scoring", "pipeline_full",
-                            "balanced"
-                        ],
-                        "description": "Weight profile for multi-space search. Temporal profiles: temporal_navigation (E2+E3+E4 balanced — time-based retrieval), sequence_navigation (E4-heavy — find nearby conversation items), conversation_history (E4+E1 — contextual recall within sessions). For fine-grained temporal control, use customWeights to set E2/E3/E4 independently."
-                    },
-                    "customWeights": {
-                        "type": "object",
-                        "description": "Custom per-embedder weights (overrides weightProfile). Each value 0-1, must sum to ~1.0. Omitted embedders default to 0. Temporal embedders E2/E3/E4 encode INDEPENDENT time dimensions and should be tuned separately: E2 (recency — how recently something was stored), E3 (periodicity — recurring time-of-day/day-of-week patterns), E4 (sequence — ordering within a conversation session). Set any combination to emphasize different temporal aspects.",
-                        "properties": {
-                            "E1":  { "type": "number", "minimum": 0, "maximum": 1, "description": "Semantic similarity (foundation)" },
-                            "E2":  { "type": "number", "minimum": 0, "maximum": 1, "description": "Temporal recency — find memories stored near a given time" },
-                            "E3":  { "type": "number", "minimum": 0, "maximum": 1, "description": "Temporal periodicity — find memories matching time-of-day or day-of-week patterns" },
-                            "E4":  { "type": "number", "minimum": 0, "maximum": 1, "description": "Temporal sequence — find memories near the same position in a conversation" },
-                            "E5":  { "type": "number", "minimum": 0, "maximum": 1, "description": "Causal relationships (cause→effect)" },
-                            "E6":  { "type": "number", "minimum": 0, "maximum": 1, "description": "Sparse keyword matching" },
-                            "E7":  { "type": "number", "minimum": 0, "maximum": 1, "description": "Code pattern similarity" },
-                            "E8":  { "type": "number", "minimum": 0, "maximum": 1, "description": "Graph structure (imports, dependencies)" },
-                            "E9":  { "type": "number", "minimum": 0, "maximum": 1, "description": "Noise-robust matching (typo tolerant)" },
-                            "E10": { "type": "number", "minimum": 0, "maximum": 1, "description": "Paraphrase/multimodal similarity" },
-                            "E11": { "type": "number", "minimum": 0, "maximum": 1, "description": "Named entity matching" },
-                            "E12": { "type": "number", "minimum": 0, "maximum": 1, "description": "ColBERT reranking (stage only, weight 0)" },
-                            "E13": { "type": "number", "minimum": 0, "maximum": 1, "description": "SPLADE recall (stage only, weight 0)" }
-                        },
-                        "additionalProperties": false
-                    },
-                    "excludeEmbedders": {
-                        "type": "array",
-                        "description": "Embedders to exclude from fusion (their weight becomes 0, remaining renormalized).",
-                        "items": {
-                            "type": "string",
-                            "enum": ["E1", "E2", "E3", "E4", "E5", "E6", "E7", "E8", "E9", "E10", "E11", "E12", "E13"]
-                        }
-                    },
-                    "useQuantizedPrefilter": {
-                        "type": "boolean",
-                        "description": "Use PQ-8 quantized vectors for fast approximate pre-filtering",
-                        "default": false
-                    },
-                    "includeEmbedderBreakdown": {
-                        "type": "boolean",
-                        "default": false,
-                        "description": "Include per-embedder scores and contribution breakdown in results. Shows which embedders contributed most to each result's ranking."
-                    },
-                    "enableRerank": {
-                        "type": "boolean",
-                        "default": false,
-                        "description": "Enable ColBERT E12 re-ranking (Stage 3)"
-                    },
-                    "enableAsymmetricE5": {
-                        "type": "boolean",
-                        "default": true,
-                        "description": "Enable asymmetric E5 causal reranking for detected causal queries"
-                    },
-                    "causalDirection": {
-                        "type": "string",
-                        "enum": ["auto", "cause", "effect", "none"],
-                        "default": "auto",
-                        "description": "Causal direction: auto (detect from query), cause (seeking causes), effect (seeking effects), none (disable)"
-                    },
-                    "enableQueryExpansion": {
-                        "type": "boolean",
-                        "default": false,
-                        "description": "Expand causal queries with related terms for better recall"
-                    },
-                    "temporalWeight": {
-                        "type": "number",
-                        "minimum": 0,
-                        "maximum": 1,
-                        "default": 0.0,
-                        "description": "Weight for temporal post-retrieval boost [0.0, 1.0]"
-                    },
-                    "conversationContext": {
-                        "type": "object",
-                        "description": "Convenience wrapper for sequence-based retrieval. Auto-anchors to current conversation turn.",
-                        "properties": {
-                            "anchorToCurrentTurn": {
-                                "type": "boolean",
-                                "default": true,
-                                "description": "Auto-anchor to current session sequence (overrides sequenceAnchor)"
-                            },
-                            "turnsBack": {
-                                "type": "integer",
-                                "minimum": 0,
-                                "maximum": 100,
-                                "default": 10,
-                                "description": "Number of turns to look back from anchor"
-                            },
-                            "turnsForward": {
-                                "type": "integer",
-                                "minimum": 0,
-                                "maximum": 100,
-                                "default": 0,
-                                "description": "Number of turns to look forward from anchor"
-                            }
-                        },
-                        "additionalProperties": false
-                    },
-                    "sessionScope": {
-                        "type": "string",
-                        "enum": ["current", "all", "recent"],
-                        "default": "all",
-                        "description": "Session scope: current (this session only), all (any session), recent (last 24h across sessions)"
-                    },
-                    "decayHalfLifeSecs": {
-                        "type": "integer",
-                        "description": "Half-life in seconds for exponential temporal decay (default: 86400 = 1 day). Only used with decayFunction='exponential'."
-                    },
-                    "lastHours": {
-                        "type": "integer",
-                        "minimum": 1,
-                        "description": "Filter results to the last N hours (integer). Shortcut for temporal window filtering."
-                    },
-                    "lastDays": {
-                        "type": "integer",
-                        "minimum": 1,
-                        "description": "Filter results to the last N days (integer). Shortcut for temporal window filtering."
-                    },
-                    "sessionId": {
-                        "type": "string",
-                        "description": "Filter results to a specific session ID."
-                    },
-                    "periodicBoost": {
-                        "type": "number",
-                        "minimum": 0,
-                        "maximum": 1,
-                        "description": "Weight for E3 periodic matching boost (0-1). Boosts results matching the target time pattern."
-                    },
-                    "targetHour": {
-                        "type": "integer",
-                        "minimum": 0,
-                        "maximum": 23,
-                        "description": "Target hour of day (0-23) for periodic matching. Used with periodicBoost."
-                    },
-                    "targetDayOfWeek": {
-                        "type": "integer",
-                        "minimum": 0,
-                        "maximum": 6,
-                        "description": "Target day of week (0=Sun, 6=Sat) for periodic matching. Used with periodicBoost."
-                    },
-                    "sequenceAnchor": {
-                        "type": "string",
-                        "format": "uuid",
-                        "description": "UUID of anchor memory for E4 sequence-based retrieval. Finds memories near this point in the conversation."
-                    },
-                    "sequenceDirection": {
-                        "type": "string",
-                        "enum": ["before", "after", "around", "both"],
-                        "description": "Direction for sequence-based retrieval relative to sequenceAnchor: 'before', 'after', 'around'/'both' (both directions)."
-                    },
-                    "includeProvenance": {
-                        "type": "boolean",
-                        "default": false,
-                        "description": "Include retrieval provenance metadata in results (default: false). Shows strategy, weight profile, query classification, and per-embedder contributions."
-                    },
-                    "decayFunction": {
-                        "type": "string",
-                        "enum": ["linear", "exponential", "step", "none", "no_decay"],
-                        "default": "exponential",
-                        "description": "Temporal decay function: linear, exponential, step, none, no_decay (default: exponential)"
-                    },
-                    "temporalScale": {
-                        "type": "string",
-                        "enum": ["micro", "meso", "macro", "long", "archival"],
-                        "default": "meso",
-                        "description": "Temporal scale for decay: micro, meso, macro, long, archival"
-                    }
-                },
-                "required": ["query"],
-                "additionalProperties": false
-            }),
         ),
         // trigger_consolidation - trigger memory consolidation (PRD Section 10.1)
-        ToolDefinition::new(
+        ToolDefinition::with_params::<TriggerConsolidationParams>(
             "trigger_consolidation",
-            "Analyze memories for consolidation candidates. Returns pairs that could be merged \
-             but does NOT automatically merge them. Use merge_concepts to execute merges. \
-             Uses similarity-based, temporal, or semantic strategies to identify candidates.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "strategy": {
-                        "type": "string",
-                        "enum": ["similarity", "temporal", "semantic"],
-                        "default": "similarity",
-                        "description": "Consolidation strategy to use"
-                    },
-                    "min_similarity": {
-                        "type": "number",
-                        "minimum": 0,
-                        "maximum": 1,
-                        "default": 0.925,
-                        "description": "Minimum similarity threshold for consolidation candidates (SRC-3 normalized [0,1] scale)"
-                    },
-                    "max_memories": {
-                        "type": "integer",
-                        "minimum": 1,
-                        "maximum": 10000,
-                        "default": 100,
-                        "description": "Maximum memories to process in one batch"
-                    }
-                },
-                "required": [],
-                "additionalProperties": false
-            }),
+            "Merge near-duplicate memories. Finds candidate pairs with similarity-based \
+             (E1 HNSW neighbours), temporal (same-day) or semantic (all pairs) strategies, \
+             then folds each duplicate into its survivor: access counts and graph edges move \
+             to the survivor and the duplicate is soft-deleted with an audit pointer to it. \
+             Returns the merged pair ids and counts. Set dry_run to only report candidates. \
+             Sends progress notifications when _meta.progressToken is set.",
         ),
     ]
 }
+
+/// Parameters of the `store_memory` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct StoreMemoryParams {
+    /// The content to store
+    pub content: String,
+
+    /// Why this context is relevant and should be stored (OPTIONAL, 1-1024 chars). When provided,
+    /// included in response.
+    #[schemars(length(min = 1, max = 1024))]
+    pub rationale: Option<String>,
+
+    /// Importance score for the memory [0.0, 1.0]
+    #[serde(default = "default_importance")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub importance: f64,
+
+    /// Session ID for session-scoped storage. If omitted, uses CLAUDE_SESSION_ID env var.
+    #[serde(rename = "sessionId")]
+    pub session_id: Option<String>,
+
+    /// Operator/user ID for audit provenance tracking
+    #[serde(rename = "operatorId")]
+    pub operator_id: Option<String>,
+
+    /// Store even if identical content already exists. When false, an exact duplicate returns the
+    /// existing fingerprintId with deduplicated=true and dedupKind=exact_hash, and near duplicates
+    /// are checked against the domain's theta_dup.
+    #[serde(rename = "allowDuplicates", default)]
+    pub allow_duplicates: bool,
+
+    /// Content domain whose duplicate threshold (theta_dup) applies to the near-duplicate check.
+    /// Detected from the content when omitted.
+    pub domain: Option<Domain>,
+
+    /// Refuse to store a near duplicate. By default it is stored and the response carries
+    /// duplicateOf (fingerprintId, similarity, thetaDup) with dedupKind=semantic.
+    #[serde(default)]
+    pub strict_dedup: bool,
+
+    /// Optional lifetime in seconds. After it elapses the memory is hidden from retrieval and
+    /// search, then purged by background GC.
+    #[serde(rename = "ttlSeconds")]
+    #[schemars(range(min = 1))]
+    pub ttl_seconds: Option<u64>,
+
+    /// Optional fraction of E12 (ColBERT) tokens to drop before storage, in (0.0, 1.0) exclusive.
+    /// The most-attended tokens are kept. Omit to keep all tokens.
+    #[serde(rename = "e12Pruning")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e12_pruning: Option<f64>,
+
+    /// Namespace (collection) to store the memory in. Searches in other namespaces never return it.
+    #[serde(default = "default_namespace")]
+    #[schemars(regex(pattern = r"^[A-Za-z0-9._-]{1,64}$"))]
+    pub namespace: String,
+
+    /// Store into the session's staging namespace (staged.<sessionId>) instead. Staged memories are
+    /// only searchable by naming that namespace until promote_staged or end_staged_session resolves
+    /// them. Cannot be combined with namespace.
+    #[serde(default)]
+    pub staged: bool,
+
+    /// Override the detected content type. Pure prose is stored without the E7 code embedding; the
+    /// classification is reported in contentClassification and kept in the memory's source
+    /// metadata.
+    pub content_type: Option<ContentType>,
+
+    /// Split long documents into chunks. When enabled and content exceeds max_tokens, it is split
+    /// on headings, blank lines and sentences; each chunk is stored as its own memory, linked in
+    /// order and to a parent document memory. The response then lists documentId and chunkIds.
+    pub chunking: Option<ChunkingOptions>,
+}
+
+fn default_importance() -> f64 {
+    0.5
+}
+
+fn default_namespace() -> String {
+    "default".to_string()
+}
+
+/// Shape of the stored content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentType {
+    Prose,
+    Code,
+    Mixed,
+    StructuredData,
+}
+
+/// Chunking of long content into several memories.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ChunkingOptions {
+    /// Enable chunking
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Token budget per chunk; content at or under it is stored unchunked
+    #[serde(default = "default_max_tokens")]
+    #[schemars(range(min = 32))]
+    pub max_tokens: u64,
+
+    /// Tokens of trailing sentences repeated at the start of the next chunk in the same section
+    /// (must be < max_tokens)
+    #[serde(default = "default_overlap")]
+    #[schemars(range(min = 0))]
+    pub overlap: u64,
+}
+
+fn default_max_tokens() -> u64 {
+    512
+}
+
+fn default_overlap() -> u64 {
+    64
+}
+
+/// Parameters of the `store_memories_batch` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct StoreMemoriesBatchParams {
+    /// Memories to store
+    #[schemars(length(min = 1, max = 100))]
+    pub items: Vec<BatchMemoryItem>,
+
+    /// Session ID applied to every item. If omitted, uses the current session.
+    pub session_id: Option<String>,
+
+    /// Operator/user ID for audit provenance tracking
+    pub operator_id: Option<String>,
+
+    /// Store items even if identical content already exists in the same namespace.
+    #[serde(default)]
+    pub allow_duplicates: bool,
+}
+
+/// One memory of a store_memories_batch call.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BatchMemoryItem {
+    /// The content to store
+    pub content: String,
+
+    /// Why this memory is relevant (OPTIONAL, 1-1024 chars). Recorded in the audit trail.
+    #[schemars(length(min = 1, max = 1024))]
+    pub rationale: Option<String>,
+
+    /// Importance score for the memory [0.0, 1.0]
+    #[serde(default = "default_importance")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub importance: f64,
+
+    /// Namespace (collection) to store the memory in
+    #[serde(default = "default_namespace")]
+    #[schemars(regex(pattern = r"^[A-Za-z0-9._-]{1,64}$"))]
+    pub namespace: String,
+}
+
+/// Parameters of the `get_memetic_status` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetMemeticStatusParams {
+    /// Recompute every cached component instead of serving it from cache
+    #[serde(default)]
+    pub force_refresh: bool,
+
+    /// Also list the N largest memories by stored bytes (scans every fingerprint; never cached)
+    #[schemars(range(min = 1, max = 100))]
+    pub largest_memories: Option<u64>,
+}
+
+/// Parameters of the `search_graph` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SearchGraphParams {
+    /// The search query text
+    pub query: String,
+
+    /// Maximum number of results to return
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 100))]
+    pub top_k: u64,
+
+    /// Minimum similarity threshold [0.0, 1.0]. When omitted, the domain profile's cut-off applies
+    /// to the reranked scores.
+    #[serde(default)]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_similarity: f64,
+
+    /// Content domain whose search profile (weight profile, rerank, score cut-off) to use. Detected
+    /// from the query text when omitted; the resolved profile is echoed as searchProfile.
+    pub domain: Option<Domain>,
+
+    /// Include content text in results
+    #[serde(default)]
+    pub include_content: bool,
+
+    /// Only return memories stored in this namespace. Use staged.<sessionId> to search a session's
+    /// staged memories.
+    #[serde(default = "default_namespace")]
+    #[schemars(regex(pattern = r"^[A-Za-z0-9._-]{1,64}$"))]
+    pub namespace: String,
+
+    /// Search strategy: e1_only (fast, E1 only), multi_space (default - E1 + enhancers via Weighted
+    /// RRF, uses weightProfile), pipeline (E13 recall → E1 dense → E12 rerank)
+    #[serde(default = "default_strategy")]
+    pub strategy: SearchStrategy,
+
+    /// Weight profile for multi-space search. Temporal profiles: temporal_navigation (E2+E3+E4
+    /// balanced — time-based retrieval), sequence_navigation (E4-heavy — find nearby conversation
+    /// items), conversation_history (E4+E1 — contextual recall within sessions). For fine-grained
+    /// temporal control, use customWeights to set E2/E3/E4 independently. When omitted (and no
+    /// domain is given), weights are routed from the query's content type: code queries lean on E7,
+    /// prose queries drop it; see contentRouting in the response.
+    pub weight_profile: Option<WeightProfile>,
+
+    /// Custom per-embedder weights (overrides weightProfile). Each value 0-1, must sum to ~1.0.
+    /// Omitted embedders default to 0. Temporal embedders E2/E3/E4 encode INDEPENDENT time
+    /// dimensions and should be tuned separately: E2 (recency — how recently something was stored),
+    /// E3 (periodicity — recurring time-of-day/day-of-week patterns), E4 (sequence — ordering
+    /// within a conversation session). Set any combination to emphasize different temporal aspects.
+    pub custom_weights: Option<EmbedderWeights>,
+
+    /// Embedders to exclude from fusion (their weight becomes 0, remaining renormalized).
+    pub exclude_embedders: Option<Vec<Embedder>>,
+
+    /// Use PQ-8 quantized vectors for fast approximate pre-filtering
+    #[serde(default)]
+    pub use_quantized_prefilter: bool,
+
+    /// Include per-embedder scores and contribution breakdown in results. Shows which embedders
+    /// contributed most to each result's ranking.
+    #[serde(default)]
+    pub include_embedder_breakdown: bool,
+
+    /// Enable ColBERT E12 re-ranking (Stage 3). Defaults to the domain profile's setting (on for
+    /// code and legal).
+    pub enable_rerank: Option<bool>,
+
+    /// Enable asymmetric E5 causal reranking for detected causal queries
+    #[serde(default = "default_enable_asymmetric_e5")]
+    pub enable_asymmetric_e5: bool,
+
+    /// Causal direction: auto (detect from query), cause (seeking causes), effect (seeking
+    /// effects), none (disable)
+    #[serde(default = "default_causal_direction")]
+    pub causal_direction: CausalDirection,
+
+    /// Expand causal queries with related terms for better recall
+    #[serde(default)]
+    pub enable_query_expansion: bool,
+
+    /// Weight for temporal post-retrieval boost [0.0, 1.0]
+    #[serde(default)]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub temporal_weight: f64,
+
+    /// Convenience wrapper for sequence-based retrieval. Auto-anchors to current conversation turn.
+    pub conversation_context: Option<ConversationContext>,
+
+    /// Session scope: current (this session only), all (any session), recent (last 24h across
+    /// sessions)
+    #[serde(default = "default_session_scope")]
+    pub session_scope: SessionScope,
+
+    /// Half-life in seconds for exponential temporal decay (default: 86400 = 1 day). Only used with
+    /// decayFunction='exponential'.
+    pub decay_half_life_secs: Option<u64>,
+
+    /// Filter results to the last N hours (integer). Shortcut for temporal window filtering.
+    #[schemars(range(min = 1))]
+    pub last_hours: Option<u64>,
+
+    /// Filter results to the last N days (integer). Shortcut for temporal window filtering.
+    #[schemars(range(min = 1))]
+    pub last_days: Option<u64>,
+
+    /// Only return memories created in [start, end) (RFC 3339). Applied during retrieval so topK
+    /// survives the filter; the response's timeFilter.excluded counts candidates it removed.
+    pub time_range: Option<TimeRange>,
+
+    /// Multiply scores by 1 - w + w * 0.5^(age / recencyHalfLifeSecs), favoring newer memories. 0
+    /// disables.
+    #[serde(default)]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub recency_weight: f64,
+
+    /// Half-life of the recency decay in seconds (default: 604800 = 7 days).
+    #[schemars(range(min = 1))]
+    pub recency_half_life_secs: Option<u64>,
+
+    /// Expand the results along typed edges of these types only; adds graphExpansion to the
+    /// response.
+    #[schemars(length(min = 1))]
+    pub edge_types: Option<Vec<EdgeType>>,
+
+    /// Hops followed by the edgeTypes graph expansion (default: 1).
+    #[serde(default = "default_expansion_hops")]
+    #[schemars(range(min = 1, max = 5))]
+    pub expansion_hops: u64,
+
+    /// Filter results to a specific session ID.
+    pub session_id: Option<String>,
+
+    /// Weight for E3 periodic matching boost (0-1). Boosts results matching the target time
+    /// pattern.
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub periodic_boost: Option<f64>,
+
+    /// Target hour of day (0-23) for periodic matching. Used with periodicBoost.
+    #[schemars(range(min = 0, max = 23))]
+    pub target_hour: Option<u64>,
+
+    /// Target day of week (0=Sun, 6=Sat) for periodic matching. Used with periodicBoost.
+    #[schemars(range(min = 0, max = 6))]
+    pub target_day_of_week: Option<u64>,
+
+    /// UUID of anchor memory for E4 sequence-based retrieval. Finds memories near this point in the
+    /// conversation.
+    pub sequence_anchor: Option<Uuid>,
+
+    /// Direction for sequence-based retrieval relative to sequenceAnchor: 'before', 'after',
+    /// 'around'/'both' (both directions).
+    pub sequence_direction: Option<SequenceDirection>,
+
+    /// Include retrieval provenance metadata in results (default: false). Shows strategy, weight
+    /// profile, query classification, and per-embedder contributions.
+    #[serde(default)]
+    pub include_provenance: bool,
+
+    /// Temporal decay function: linear, exponential, step, none, no_decay (default: exponential)
+    #[serde(default = "default_decay_function")]
+    pub decay_function: DecayFunction,
+
+    /// Temporal scale for decay: micro, meso, macro, long, archival
+    #[serde(default = "default_temporal_scale")]
+    pub temporal_scale: TemporalScale,
+}
+
+fn default_top_k() -> u64 {
+    10
+}
+
+fn default_strategy() -> SearchStrategy {
+    SearchStrategy::MultiSpace
+}
+
+fn default_enable_asymmetric_e5() -> bool {
+    true
+}
+
+fn default_causal_direction() -> CausalDirection {
+    CausalDirection::Auto
+}
+
+fn default_session_scope() -> SessionScope {
+    SessionScope::All
+}
+
+fn default_expansion_hops() -> u64 {
+    1
+}
+
+fn default_decay_function() -> DecayFunction {
+    DecayFunction::Exponential
+}
+
+fn default_temporal_scale() -> TemporalScale {
+    TemporalScale::Meso
+}
+
+/// Causal direction of a query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CausalDirection {
+    Auto,
+    Cause,
+    Effect,
+    None,
+}
+
+/// Turn window around the current conversation turn.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConversationContext {
+    /// Auto-anchor to current session sequence (overrides sequenceAnchor)
+    #[serde(default = "default_anchor_to_current_turn")]
+    pub anchor_to_current_turn: bool,
+
+    /// Number of turns to look back from anchor
+    #[serde(default = "default_turns_back")]
+    #[schemars(range(min = 0, max = 100))]
+    pub turns_back: u64,
+
+    /// Number of turns to look forward from anchor
+    #[serde(default)]
+    #[schemars(range(min = 0, max = 100))]
+    pub turns_forward: u64,
+}
+
+fn default_anchor_to_current_turn() -> bool {
+    true
+}
+
+fn default_turns_back() -> u64 {
+    10
+}
+
+/// Time window to restrict results to.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TimeRange {
+    pub start: DateTime<Utc>,
+
+    pub end: DateTime<Utc>,
+}
+
+/// Which side of the sequence anchor to search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SequenceDirection {
+    Before,
+    After,
+    Around,
+    Both,
+}
+
+/// Parameters of the `trigger_consolidation` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TriggerConsolidationParams {
+    /// Consolidation strategy to use
+    #[serde(default = "default_trigger_consolidation_strategy")]
+    pub strategy: ConsolidationStrategy,
+
+    /// Minimum similarity threshold for consolidation candidates (SRC-3 normalized [0,1] scale)
+    #[serde(default = "default_min_similarity")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_similarity: f64,
+
+    /// Maximum memories to process in one batch
+    #[serde(default = "default_max_memories")]
+    #[schemars(range(min = 1, max = 10000))]
+    pub max_memories: u64,
+
+    /// Report candidates without merging them
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+fn default_trigger_consolidation_strategy() -> ConsolidationStrategy {
+    ConsolidationStrategy::Similarity
+}
+
+fn default_min_similarity() -> f64 {
+    0.925
+}
+
+fn default_max_memories() -> u64 {
+    100
+}
+
+/// How consolidation groups memories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsolidationStrategy {
+    Similarity,
+    Temporal,
+    Semantic,
+}
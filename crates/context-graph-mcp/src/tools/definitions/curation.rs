@@ -10,75 +10,71 @@
 //! - BR-MCP-002: boost_importance clamps final value to [0.0, 1.0]
 //! - AP-10: No NaN/Infinity in values
 
+use schemars::JsonSchema;
+use serde::Deserialize;
+use uuid::Uuid;
+
 use crate::tools::types::ToolDefinition;
-use serde_json::json;
 
 /// Returns curation tool definitions (2 tools per PRD).
 pub fn definitions() -> Vec<ToolDefinition> {
     vec![
         // forget_concept
-        ToolDefinition::new(
+        ToolDefinition::with_params::<ForgetConceptParams>(
             "forget_concept",
             "Soft-delete a memory with 30-day recovery window (per SEC-06). \
              Set soft_delete=false for permanent deletion (use with caution). \
              Returns deleted_at timestamp for recovery tracking.",
-            json!({
-                "type": "object",
-                "required": ["node_id"],
-                "properties": {
-                    "node_id": {
-                        "type": "string",
-                        "format": "uuid",
-                        "description": "UUID of the memory to forget"
-                    },
-                    "soft_delete": {
-                        "type": "boolean",
-                        "default": true,
-                        "description": "Use soft delete with 30-day recovery (default true per BR-MCP-001)"
-                    },
-                    "operator_id": {
-                        "type": "string",
-                        "description": "Operator ID for provenance tracking (who performed the deletion)"
-                    },
-                    "reason": {
-                        "type": "string",
-                        "description": "Reason for deletion (for audit trail)"
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
         // boost_importance
-        ToolDefinition::new(
+        ToolDefinition::with_params::<BoostImportanceParams>(
             "boost_importance",
             "Adjust a memory's importance score by delta. Final value is clamped \
              to [0.0, 1.0] (per BR-MCP-002). Response includes old, delta, and new values.",
-            json!({
-                "type": "object",
-                "required": ["node_id", "delta"],
-                "properties": {
-                    "node_id": {
-                        "type": "string",
-                        "format": "uuid",
-                        "description": "UUID of the memory to boost"
-                    },
-                    "delta": {
-                        "type": "number",
-                        "minimum": -1.0,
-                        "maximum": 1.0,
-                        "description": "Importance change value (-1.0 to 1.0)"
-                    },
-                    "operator_id": {
-                        "type": "string",
-                        "description": "Operator ID for provenance tracking (who performed the boost)"
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
     ]
 }
 
+/// Parameters of the `forget_concept` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ForgetConceptParams {
+    /// UUID of the memory to forget
+    pub node_id: Uuid,
+
+    /// Use soft delete with 30-day recovery (default true per BR-MCP-001)
+    #[serde(default = "default_soft_delete")]
+    pub soft_delete: bool,
+
+    /// Operator ID for provenance tracking (who performed the deletion)
+    pub operator_id: Option<String>,
+
+    /// Reason for deletion (for audit trail)
+    pub reason: Option<String>,
+}
+
+fn default_soft_delete() -> bool {
+    true
+}
+
+/// Lowest `boost_importance` delta; schemars attributes take no negative literals.
+const MIN_BOOST_DELTA: f64 = -1.0;
+
+/// Parameters of the `boost_importance` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BoostImportanceParams {
+    /// UUID of the memory to boost
+    pub node_id: Uuid,
+
+    /// Importance change value (-1.0 to 1.0)
+    #[schemars(range(min = "MIN_BOOST_DELTA", max = 1.0))]
+    pub delta: f64,
+
+    /// Operator ID for provenance tracking (who performed the boost)
+    pub operator_id: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
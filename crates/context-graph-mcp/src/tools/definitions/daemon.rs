@@ -2,33 +2,57 @@
 //!
 //! Tools:
 //! - daemon_status: Returns daemon health, connection count, and background task state
+//! - get_embedding_status: Returns per-model embedding load state, ETA, queue depths,
+//!   determinism mode and HNSW index build progress
+
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::tools::types::ToolDefinition;
-use serde_json::json;
 
-/// Returns daemon tool definitions (1 tool).
+/// Returns daemon tool definitions (2 tools).
 pub fn definitions() -> Vec<ToolDefinition> {
-    vec![ToolDefinition::new(
-        "daemon_status",
-        "Returns the daemon's health metrics for multi-agent observability. \
-         Shows active connection count, model loading state, background task status \
-         (GC, HNSW persist, graph builder), uptime, and PID. \
-         Use this to diagnose connection issues or verify multi-agent setup is working.",
-        json!({
-            "type": "object",
-            "properties": {},
-            "additionalProperties": false
-        }),
-    )]
+    vec![
+        ToolDefinition::with_params::<DaemonStatusParams>(
+            "daemon_status",
+            "Returns the daemon's health metrics for multi-agent observability. \
+             Shows active connection count, model loading state, background task status \
+             (GC, HNSW persist, graph builder), uptime, and PID. \
+             Use this to diagnose connection issues or verify multi-agent setup is working.",
+        ),
+        ToolDefinition::with_params::<GetEmbeddingStatusParams>(
+            "get_embedding_status",
+            "Returns the load state of each embedding model (notLoaded, loading with percent, \
+             ready, failed with error), whether all models are ready, and the estimated time \
+             remaining based on observed load durations. Search and store tools return a \
+             RETRY_LATER error naming the blocking models until they are ready. Also reports \
+             each embedder's queue depth and capacity; store_memory returns SERVER_BUSY with \
+             retryAfterMs when a queue is full. indexBuild lists the latest HNSW index \
+             build progress per space (inserted/total, percent, ETA). determinism is the \
+             active embedding determinism mode (decimals, seed), or null when embeddings \
+             are not bit-reproducible.",
+        ),
+    ]
 }
 
+/// Parameters of the `daemon_status` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DaemonStatusParams {}
+
+/// Parameters of the `get_embedding_status` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetEmbeddingStatusParams {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
 
     #[test]
     fn test_daemon_definitions_count() {
-        assert_eq!(definitions().len(), 1);
+        assert_eq!(definitions().len(), 2);
     }
 
     #[test]
@@ -40,4 +64,13 @@ mod tests {
         let props = status.input_schema.get("properties").unwrap();
         assert!(props.as_object().unwrap().is_empty());
     }
+
+    #[test]
+    fn test_embedding_status_definition() {
+        let tools = definitions();
+        let status = &tools[1];
+        assert_eq!(status.name, "get_embedding_status");
+        assert!(status.description.contains("RETRY_LATER"));
+        assert_eq!(status.input_schema["additionalProperties"], json!(false));
+    }
 }
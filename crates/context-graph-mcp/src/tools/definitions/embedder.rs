@@ -25,320 +25,72 @@
 //! - ARCH-02: All comparisons within same embedder space (no cross-embedder)
 //! - Each embedder has its own FAISS/HNSW index on GPU
 
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::common::{Embedder, EmbedderWeights};
 use crate::tools::types::ToolDefinition;
-use serde_json::json;
 
 /// Returns embedder-first search tool definitions (7 tools).
 pub fn definitions() -> Vec<ToolDefinition> {
     vec![
         // search_by_embedder - Generic search using any embedder as primary
-        ToolDefinition::new(
+        ToolDefinition::with_params::<SearchByEmbedderParams>(
             "search_by_embedder",
             "Search using any embedder (E1-E13) as the primary perspective. Each of the 13 embedders \
              sees the knowledge graph differently. E1 finds semantic similarity, E11 finds entity \
              relationships, E7 finds code patterns, E5 finds causal chains. Use this to explore \
              what a specific embedder sees that others might miss. Per Constitution v6.3 \
              embedder-first search philosophy.",
-            json!({
-                "type": "object",
-                "required": ["embedder", "query"],
-                "properties": {
-                    "embedder": {
-                        "type": "string",
-                        "description": "Which embedder to use as primary. E1=semantic, E2=recency, \
-                                        E3=periodic, E4=sequence, E5=causal, E7=code, E8=graph, \
-                                        E9=robustness, E10=paraphrase, E11=entity. \
-                                        E6/E12/E13 use non-HNSW indexes and are not supported for direct search.",
-                        "enum": ["E1", "E2", "E3", "E4", "E5", "E7", "E8", "E9", "E10", "E11"]
-                    },
-                    "query": {
-                        "type": "string",
-                        "description": "Search query to find similar memories in the selected embedder's space."
-                    },
-                    "topK": {
-                        "type": "integer",
-                        "description": "Maximum number of results to return (1-100, default: 10).",
-                        "default": 10,
-                        "minimum": 1,
-                        "maximum": 100
-                    },
-                    "minSimilarity": {
-                        "type": "number",
-                        "description": "Minimum similarity threshold (0-1, default: 0). Results below this are filtered.",
-                        "default": 0,
-                        "minimum": 0,
-                        "maximum": 1
-                    },
-                    "includeContent": {
-                        "type": "boolean",
-                        "description": "Include full content text in results (default: false).",
-                        "default": false
-                    },
-                    "includeAllScores": {
-                        "type": "boolean",
-                        "description": "Include similarity scores from all 13 embedders in results (default: false). \
-                                        Useful for understanding how different embedders view the same memory.",
-                        "default": false
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
         // get_embedder_clusters - Explore clusters in a specific embedder's space
-        ToolDefinition::new(
+        ToolDefinition::with_params::<GetEmbedderClustersParams>(
             "get_embedder_clusters",
             "Explore clusters of memories in a specific embedder's space. Each embedder creates \
              different clusters based on what it sees - E7 (code) clusters by implementation patterns, \
              E11 (entity) clusters by entity relationships, E5 (causal) clusters by cause-effect chains. \
              Use to discover emergent groupings from different perspectives.",
-            json!({
-                "type": "object",
-                "required": ["embedder"],
-                "properties": {
-                    "embedder": {
-                        "type": "string",
-                        "description": "Which embedder's clusters to explore. E6/E12/E13 use non-HNSW indexes and are not supported for clustering.",
-                        "enum": ["E1", "E2", "E3", "E4", "E5", "E7", "E8", "E9", "E10", "E11"]
-                    },
-                    "minClusterSize": {
-                        "type": "integer",
-                        "description": "Minimum memories per cluster (default: 3, per HDBSCAN min_cluster_size).",
-                        "default": 3,
-                        "minimum": 2,
-                        "maximum": 50
-                    },
-                    "topClusters": {
-                        "type": "integer",
-                        "description": "Maximum number of clusters to return (default: 10).",
-                        "default": 10,
-                        "minimum": 1,
-                        "maximum": 50
-                    },
-                    "includeSamples": {
-                        "type": "boolean",
-                        "description": "Include sample memories from each cluster (default: true).",
-                        "default": true
-                    },
-                    "samplesPerCluster": {
-                        "type": "integer",
-                        "description": "Number of sample memories per cluster (default: 3).",
-                        "default": 3,
-                        "minimum": 1,
-                        "maximum": 10
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
         // compare_embedder_views - Compare how different embedders rank the same query
-        ToolDefinition::new(
+        ToolDefinition::with_params::<CompareEmbedderViewsParams>(
             "compare_embedder_views",
             "Compare how different embedders rank the same query. Shows rankings from each embedder \
              side-by-side, highlighting agreement (same top results) and unique finds (memories found \
              by only one embedder). Useful for understanding blind spots - e.g., what E11 (entity) \
              finds that E1 (semantic) misses.",
-            json!({
-                "type": "object",
-                "required": ["query", "embedders"],
-                "properties": {
-                    "query": {
-                        "type": "string",
-                        "description": "Search query to compare across embedders."
-                    },
-                    "embedders": {
-                        "type": "array",
-                        "description": "Which embedders to compare (2-5 embedders). E6/E12/E13 use non-HNSW indexes and are not supported.",
-                        "items": {
-                            "type": "string",
-                            "enum": ["E1", "E2", "E3", "E4", "E5", "E7", "E8", "E9", "E10", "E11"]
-                        },
-                        "minItems": 2,
-                        "maxItems": 5
-                    },
-                    "topK": {
-                        "type": "integer",
-                        "description": "Number of top results per embedder to compare (default: 5).",
-                        "default": 5,
-                        "minimum": 1,
-                        "maximum": 20
-                    },
-                    "includeContent": {
-                        "type": "boolean",
-                        "description": "Include content text in results (default: false).",
-                        "default": false
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
         // list_embedder_indexes - List all embedder indexes with stats
-        ToolDefinition::new(
+        ToolDefinition::with_params::<ListEmbedderIndexesParams>(
             "list_embedder_indexes",
             "List all 13 embedder indexes with their statistics. Shows dimension, index type, \
              vector count, size, and GPU residency for each embedder. Useful for understanding \
              the system's embedding infrastructure and checking index health.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "includeDetails": {
-                        "type": "boolean",
-                        "description": "Include detailed stats like memory usage and query latency (default: true).",
-                        "default": true
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
         // get_memory_fingerprint - Introspect per-embedder vectors for a specific memory
-        ToolDefinition::new(
+        ToolDefinition::with_params::<GetMemoryFingerprintParams>(
             "get_memory_fingerprint",
             "Retrieve the per-embedder fingerprint vectors for a specific memory. Returns dimension, \
              vector norm (L2), and presence status for each of the 13 embedders. Asymmetric embedders \
              (E5 causal, E8 graph, E10 paraphrase) show both directional variants. Sparse embedders \
              (E6, E13) show non-zero element count. Use to debug embedding quality, verify which \
              embedders produced vectors, and understand how a memory is represented across all 13 spaces.",
-            json!({
-                "type": "object",
-                "required": ["memoryId"],
-                "properties": {
-                    "memoryId": {
-                        "type": "string",
-                        "format": "uuid",
-                        "description": "UUID of the memory to inspect."
-                    },
-                    "embedders": {
-                        "type": "array",
-                        "description": "Filter to specific embedders (default: all 13). E.g., [\"E1\", \"E5\", \"E7\"].",
-                        "items": {
-                            "type": "string",
-                            "enum": ["E1", "E2", "E3", "E4", "E5", "E6", "E7", "E8", "E9", "E10", "E11", "E12", "E13"]
-                        }
-                    },
-                    "includeVectorNorms": {
-                        "type": "boolean",
-                        "default": true,
-                        "description": "Include L2 norm of each vector (default: true)."
-                    },
-                    "includeContent": {
-                        "type": "boolean",
-                        "default": false,
-                        "description": "Include the memory's content text (default: false)."
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
         // create_weight_profile - Create a session-scoped custom weight profile
-        ToolDefinition::new(
+        ToolDefinition::with_params::<CreateWeightProfileParams>(
             "create_weight_profile",
             "Create a named custom embedder weight profile for the current session. Assigns weights \
              to each of the 13 embedders (E1-E13). The profile can be referenced by name in \
              search_graph's weightProfile and get_unified_neighbors. Useful \
              for defining reusable search strategies. Rejects built-in profile names.",
-            json!({
-                "type": "object",
-                "required": ["name", "weights"],
-                "properties": {
-                    "name": {
-                        "type": "string",
-                        "description": "Name for the profile (1-64 chars). Must not conflict with built-in profiles.",
-                        "minLength": 1,
-                        "maxLength": 64
-                    },
-                    "weights": {
-                        "type": "object",
-                        "description": "Per-embedder weights. Keys are E1-E13, values are 0-1. Must sum to ~1.0. Temporal embedders are independent: E2 (recency — how recently stored), E3 (periodicity — time-of-day/day-of-week patterns), E4 (sequence — conversation ordering). Set any combination freely.",
-                        "properties": {
-                            "E1": { "type": "number", "minimum": 0, "maximum": 1 },
-                            "E2": { "type": "number", "minimum": 0, "maximum": 1 },
-                            "E3": { "type": "number", "minimum": 0, "maximum": 1 },
-                            "E4": { "type": "number", "minimum": 0, "maximum": 1 },
-                            "E5": { "type": "number", "minimum": 0, "maximum": 1 },
-                            "E6": { "type": "number", "minimum": 0, "maximum": 1 },
-                            "E7": { "type": "number", "minimum": 0, "maximum": 1 },
-                            "E8": { "type": "number", "minimum": 0, "maximum": 1 },
-                            "E9": { "type": "number", "minimum": 0, "maximum": 1 },
-                            "E10": { "type": "number", "minimum": 0, "maximum": 1 },
-                            "E11": { "type": "number", "minimum": 0, "maximum": 1 },
-                            "E12": { "type": "number", "minimum": 0, "maximum": 1 },
-                            "E13": { "type": "number", "minimum": 0, "maximum": 1 }
-                        },
-                        "additionalProperties": false
-                    },
-                    "description": {
-                        "type": "string",
-                        "description": "Optional description of the profile's purpose."
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
         // search_cross_embedder_anomalies - Find blind spots between embedders
-        ToolDefinition::new(
+        ToolDefinition::with_params::<SearchCrossEmbedderAnomaliesParams>(
             "search_cross_embedder_anomalies",
             "Find memories that score high in one embedder but low in another. Reveals blind \
              spots and perspective disagreements. Example: highEmbedder=E7 (code), \
              lowEmbedder=E1 (semantic) finds code patterns that semantic search misses. \
              Anomaly score = high_score - low_score.",
-            json!({
-                "type": "object",
-                "required": ["query", "highEmbedder", "lowEmbedder"],
-                "properties": {
-                    "query": {
-                        "type": "string",
-                        "description": "Search query."
-                    },
-                    "highEmbedder": {
-                        "type": "string",
-                        "description": "Embedder expected to score HIGH (E1-E13).",
-                        "enum": ["E1", "E2", "E3", "E4", "E5", "E6", "E7", "E8", "E9", "E10", "E11", "E12", "E13"]
-                    },
-                    "lowEmbedder": {
-                        "type": "string",
-                        "description": "Embedder expected to score LOW (E1-E13).",
-                        "enum": ["E1", "E2", "E3", "E4", "E5", "E6", "E7", "E8", "E9", "E10", "E11", "E12", "E13"]
-                    },
-                    "highThreshold": {
-                        "type": "number",
-                        "description": "Minimum score in highEmbedder (0-1, default: 0.5).",
-                        "default": 0.5,
-                        "minimum": 0,
-                        "maximum": 1
-                    },
-                    "lowThreshold": {
-                        "type": "number",
-                        "description": "Maximum score in lowEmbedder (0-1, default: 0.3).",
-                        "default": 0.3,
-                        "minimum": 0,
-                        "maximum": 1
-                    },
-                    "topK": {
-                        "type": "integer",
-                        "description": "Maximum results (1-100, default: 10).",
-                        "default": 10,
-                        "minimum": 1,
-                        "maximum": 100
-                    },
-                    "includeContent": {
-                        "type": "boolean",
-                        "description": "Include content text in results (default: false).",
-                        "default": false
-                    },
-                    "primaryEmbedder": {
-                        "type": "string",
-                        "description": "Primary embedder for generalized blind spot detection (default: E1).",
-                        "default": "E1",
-                        "enum": ["E1", "E2", "E3", "E4", "E5", "E6", "E7", "E8", "E9", "E10", "E11", "E12", "E13"]
-                    },
-                    "contrastEmbedder": {
-                        "type": "string",
-                        "description": "Contrast embedder for generalized blind spot detection (default: E9).",
-                        "default": "E9",
-                        "enum": ["E1", "E2", "E3", "E4", "E5", "E6", "E7", "E8", "E9", "E10", "E11", "E12", "E13"]
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
     ]
 }
@@ -346,40 +98,293 @@ pub fn definitions() -> Vec<ToolDefinition> {
 /// Returns E12/E13 standalone search tool definitions (2 tools).
 pub fn standalone_definitions() -> Vec<ToolDefinition> {
     vec![
-        ToolDefinition::new(
+        ToolDefinition::with_params::<SearchByTokensParams>(
             "search_by_tokens",
             "Search using E12 ColBERT token-level MaxSim scoring for precise phrase matching.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "query": { "type": "string", "description": "Query text" },
-                    "topK": { "type": "integer", "default": 10, "minimum": 1, "maximum": 100 },
-                    "minSimilarity": { "type": "number", "default": 0.3, "minimum": 0.0, "maximum": 1.0 }
-                },
-                "required": ["query"],
-                "additionalProperties": false
-            }),
         ),
-        ToolDefinition::new(
+        ToolDefinition::with_params::<SearchByExpansionParams>(
             "search_by_expansion",
             "Search using E13 SPLADE learned term expansion for enhanced keyword recall.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "query": { "type": "string", "description": "Query text" },
-                    "topK": { "type": "integer", "default": 10, "minimum": 1, "maximum": 100 },
-                    "minScore": { "type": "number", "default": 0.1, "minimum": 0.0, "maximum": 1.0 }
-                },
-                "required": ["query"],
-                "additionalProperties": false
-            }),
         ),
     ]
 }
 
+/// Parameters of the `search_by_embedder` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SearchByEmbedderParams {
+    /// Which embedder to use as primary. E1=semantic, E2=recency, E3=periodic, E4=sequence,
+    /// E5=causal, E7=code, E8=graph, E9=robustness, E10=paraphrase, E11=entity. E6/E12/E13 use
+    /// non-HNSW indexes and are not supported for direct search.
+    pub embedder: IndexedEmbedder,
+
+    /// Search query to find similar memories in the selected embedder's space.
+    pub query: String,
+
+    /// Maximum number of results to return (1-100, default: 10).
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 100))]
+    pub top_k: u64,
+
+    /// Minimum similarity threshold (0-1, default: 0). Results below this are filtered.
+    #[serde(default)]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_similarity: f64,
+
+    /// Include full content text in results (default: false).
+    #[serde(default)]
+    pub include_content: bool,
+
+    /// Include similarity scores from all 13 embedders in results (default: false). Useful for
+    /// understanding how different embedders view the same memory.
+    #[serde(default)]
+    pub include_all_scores: bool,
+}
+
+fn default_top_k() -> u64 {
+    10
+}
+
+/// Embedder with an HNSW index, searchable directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum IndexedEmbedder {
+    E1,
+    E2,
+    E3,
+    E4,
+    E5,
+    E7,
+    E8,
+    E9,
+    E10,
+    E11,
+}
+
+/// Parameters of the `get_embedder_clusters` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GetEmbedderClustersParams {
+    /// Which embedder's clusters to explore. E6/E12/E13 use non-HNSW indexes and are not supported
+    /// for clustering.
+    pub embedder: IndexedEmbedder,
+
+    /// Minimum memories per cluster (default: 3, per HDBSCAN min_cluster_size).
+    #[serde(default = "default_min_cluster_size")]
+    #[schemars(range(min = 2, max = 50))]
+    pub min_cluster_size: u64,
+
+    /// Maximum number of clusters to return (default: 10).
+    #[serde(default = "default_top_clusters")]
+    #[schemars(range(min = 1, max = 50))]
+    pub top_clusters: u64,
+
+    /// Include sample memories from each cluster (default: true).
+    #[serde(default = "default_include_samples")]
+    pub include_samples: bool,
+
+    /// Number of sample memories per cluster (default: 3).
+    #[serde(default = "default_samples_per_cluster")]
+    #[schemars(range(min = 1, max = 10))]
+    pub samples_per_cluster: u64,
+}
+
+fn default_min_cluster_size() -> u64 {
+    3
+}
+
+fn default_top_clusters() -> u64 {
+    10
+}
+
+fn default_include_samples() -> bool {
+    true
+}
+
+fn default_samples_per_cluster() -> u64 {
+    3
+}
+
+/// Parameters of the `compare_embedder_views` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CompareEmbedderViewsParams {
+    /// Search query to compare across embedders.
+    pub query: String,
+
+    /// Which embedders to compare (2-5 embedders). E6/E12/E13 use non-HNSW indexes and are not
+    /// supported.
+    #[schemars(length(min = 2, max = 5))]
+    pub embedders: Vec<IndexedEmbedder>,
+
+    /// Number of top results per embedder to compare (default: 5).
+    #[serde(default = "default_compare_embedder_views_top_k")]
+    #[schemars(range(min = 1, max = 20))]
+    pub top_k: u64,
+
+    /// Include content text in results (default: false).
+    #[serde(default)]
+    pub include_content: bool,
+}
+
+fn default_compare_embedder_views_top_k() -> u64 {
+    5
+}
+
+/// Parameters of the `list_embedder_indexes` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ListEmbedderIndexesParams {
+    /// Include detailed stats like memory usage and query latency (default: true).
+    #[serde(default = "default_include_details")]
+    pub include_details: bool,
+}
+
+fn default_include_details() -> bool {
+    true
+}
+
+/// Parameters of the `get_memory_fingerprint` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GetMemoryFingerprintParams {
+    /// UUID of the memory to inspect.
+    pub memory_id: Uuid,
+
+    /// Filter to specific embedders (default: all 13). E.g., ["E1", "E5", "E7"].
+    pub embedders: Option<Vec<Embedder>>,
+
+    /// Include L2 norm of each vector (default: true).
+    #[serde(default = "default_include_vector_norms")]
+    pub include_vector_norms: bool,
+
+    /// Include the memory's content text (default: false).
+    #[serde(default)]
+    pub include_content: bool,
+}
+
+fn default_include_vector_norms() -> bool {
+    true
+}
+
+/// Parameters of the `create_weight_profile` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateWeightProfileParams {
+    /// Name for the profile (1-64 chars). Must not conflict with built-in profiles.
+    #[schemars(length(min = 1, max = 64))]
+    pub name: String,
+
+    /// Per-embedder weights. Keys are E1-E13, values are 0-1. Must sum to ~1.0. Temporal embedders
+    /// are independent: E2 (recency — how recently stored), E3 (periodicity —
+    /// time-of-day/day-of-week patterns), E4 (sequence — conversation ordering). Set any
+    /// combination freely.
+    pub weights: EmbedderWeights,
+
+    /// Optional description of the profile's purpose.
+    pub description: Option<String>,
+}
+
+/// Parameters of the `search_cross_embedder_anomalies` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SearchCrossEmbedderAnomaliesParams {
+    /// Search query.
+    pub query: String,
+
+    /// Embedder expected to score HIGH (E1-E13).
+    pub high_embedder: Embedder,
+
+    /// Embedder expected to score LOW (E1-E13).
+    pub low_embedder: Embedder,
+
+    /// Minimum score in highEmbedder (0-1, default: 0.5).
+    #[serde(default = "default_high_threshold")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub high_threshold: f64,
+
+    /// Maximum score in lowEmbedder (0-1, default: 0.3).
+    #[serde(default = "default_low_threshold")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub low_threshold: f64,
+
+    /// Maximum results (1-100, default: 10).
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 100))]
+    pub top_k: u64,
+
+    /// Include content text in results (default: false).
+    #[serde(default)]
+    pub include_content: bool,
+
+    /// Primary embedder for generalized blind spot detection (default: E1).
+    #[serde(default = "default_primary_embedder")]
+    pub primary_embedder: Embedder,
+
+    /// Contrast embedder for generalized blind spot detection (default: E9).
+    #[serde(default = "default_contrast_embedder")]
+    pub contrast_embedder: Embedder,
+}
+
+fn default_high_threshold() -> f64 {
+    0.5
+}
+
+fn default_low_threshold() -> f64 {
+    0.3
+}
+
+fn default_primary_embedder() -> Embedder {
+    Embedder::E1
+}
+
+fn default_contrast_embedder() -> Embedder {
+    Embedder::E9
+}
+
+/// Parameters of the `search_by_tokens` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SearchByTokensParams {
+    /// Query text
+    pub query: String,
+
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 100))]
+    pub top_k: u64,
+
+    #[serde(default = "default_min_similarity")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_similarity: f64,
+}
+
+fn default_min_similarity() -> f64 {
+    0.3
+}
+
+/// Parameters of the `search_by_expansion` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SearchByExpansionParams {
+    /// Query text
+    pub query: String,
+
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 100))]
+    pub top_k: u64,
+
+    #[serde(default = "default_min_score")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_score: f64,
+}
+
+fn default_min_score() -> f64 {
+    0.1
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
 
     #[test]
     fn test_definitions_exist_with_required_fields() {
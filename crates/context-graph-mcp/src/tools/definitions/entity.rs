@@ -14,291 +14,317 @@
 //! - E11 is RELATIONAL_ENHANCER with topic_weight 0.5
 //! - Delta_S method: TransE ||h+r-t||
 
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::common::SearchStrategy;
 use crate::tools::types::ToolDefinition;
-use serde_json::json;
 
 /// Returns E11 entity tool definitions (6 tools).
 pub fn definitions() -> Vec<ToolDefinition> {
     vec![
         // extract_entities - Extract and canonicalize entities from text (Phase 1)
-        ToolDefinition::new(
+        ToolDefinition::with_params::<ExtractEntitiesParams>(
             "extract_entities",
             "Extract and canonicalize entities from text using pattern matching and knowledge base lookup. \
              Resolves variations to canonical forms (e.g., 'postgres' → 'postgresql', 'k8s' → 'kubernetes'). \
              Detects programming languages, frameworks, databases, cloud services, companies, and technical terms. \
              Per ARCH-20: E11 uses entity linking for disambiguation.",
-            json!({
-                "type": "object",
-                "required": ["text"],
-                "properties": {
-                    "text": {
-                        "type": "string",
-                        "description": "Text to extract entities from."
-                    },
-                    "includeUnknown": {
-                        "type": "boolean",
-                        "description": "Include entities not in knowledge base (detected via heuristics). Default: true.",
-                        "default": true
-                    },
-                    "groupByType": {
-                        "type": "boolean",
-                        "description": "Group results by entity type (ProgrammingLanguage, Framework, Database, etc). Default: false.",
-                        "default": false
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
         // search_by_entities - Find memories containing specific entities (Phase 2)
-        ToolDefinition::new(
+        ToolDefinition::with_params::<SearchByEntitiesParams>(
             "search_by_entities",
             "Find memories containing specific entities with entity-aware ranking. \
              Uses E11 entity embeddings combined with entity Jaccard similarity for hybrid scoring. \
              ENHANCES E1 semantic search with entity precision (ARCH-12). \
              Supports 'any' (match any entity) or 'all' (match all entities) modes.",
-            json!({
-                "type": "object",
-                "required": ["entities"],
-                "properties": {
-                    "entities": {
-                        "type": "array",
-                        "items": { "type": "string" },
-                        "description": "Entity names to search for (e.g., ['PostgreSQL', 'Rust']). Will be canonicalized."
-                    },
-                    "entityTypes": {
-                        "type": "array",
-                        "items": {
-                            "type": "string",
-                            "enum": ["ProgrammingLanguage", "Framework", "Database", "Cloud", "Company", "TechnicalTerm", "Unknown"]
-                        },
-                        "description": "Filter by entity types."
-                    },
-                    "matchMode": {
-                        "type": "string",
-                        "enum": ["any", "all"],
-                        "default": "any",
-                        "description": "Match any entity or all entities."
-                    },
-                    "topK": {
-                        "type": "integer",
-                        "description": "Maximum results to return (1-50, default: 10).",
-                        "default": 10,
-                        "minimum": 1,
-                        "maximum": 50
-                    },
-                    "minScore": {
-                        "type": "number",
-                        "description": "Minimum similarity threshold (0-1, default: 0.2).",
-                        "default": 0.2,
-                        "minimum": 0,
-                        "maximum": 1
-                    },
-                    "includeContent": {
-                        "type": "boolean",
-                        "description": "Include full memory content in results. Default: false.",
-                        "default": false
-                    },
-                    "boostExactMatch": {
-                        "type": "number",
-                        "description": "Boost multiplier for exact entity matches (1.0-3.0, default: 1.15).",
-                        "default": 1.15,
-                        "minimum": 1.0,
-                        "maximum": 3.0
-                    },
-                    "strategy": {
-                        "type": "string",
-                        "enum": ["e1_only", "multi_space", "pipeline"],
-                        "description": "Search strategy: 'e1_only' (default, E1+E11 union), 'multi_space' (multi-embedder fusion), 'pipeline' (E13 recall -> E1 -> E12 rerank)."
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
         // infer_relationship - Infer relationship between entities using TransE (Phase 3)
-        ToolDefinition::new(
+        ToolDefinition::with_params::<InferRelationshipParams>(
             "infer_relationship",
             "Infer the relationship between two entities using TransE knowledge graph operations. \
              Uses the formula r̂ = t - h to predict the relation vector, then matches against known relations. \
              Returns ranked relation candidates with TransE scores and confidence values. \
              Per constitution: Delta_S method for E11 is 'TransE ||h+r-t||'.",
-            json!({
-                "type": "object",
-                "required": ["headEntity", "tailEntity"],
-                "properties": {
-                    "headEntity": {
-                        "type": "string",
-                        "description": "Subject/head entity (e.g., 'Tokio')."
-                    },
-                    "tailEntity": {
-                        "type": "string",
-                        "description": "Object/tail entity (e.g., 'Rust')."
-                    },
-                    "headType": {
-                        "type": "string",
-                        "enum": ["ProgrammingLanguage", "Framework", "Database", "Cloud", "Company", "TechnicalTerm"],
-                        "description": "Optional type hint for head entity."
-                    },
-                    "tailType": {
-                        "type": "string",
-                        "enum": ["ProgrammingLanguage", "Framework", "Database", "Cloud", "Company", "TechnicalTerm"],
-                        "description": "Optional type hint for tail entity."
-                    },
-                    "topK": {
-                        "type": "integer",
-                        "description": "Number of relation candidates to return (1-20, default: 5).",
-                        "default": 5,
-                        "minimum": 1,
-                        "maximum": 20
-                    },
-                    "includeScore": {
-                        "type": "boolean",
-                        "description": "Include raw TransE scores in response. Default: true.",
-                        "default": true
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
         // find_related_entities - Find entities with given relationship (Phase 3)
-        ToolDefinition::new(
+        ToolDefinition::with_params::<FindRelatedEntitiesParams>(
             "find_related_entities",
             "Find entities that have a given relationship to a source entity using TransE. \
              Supports both directions: outgoing (h→t, what does X depend_on?) or incoming (t←h, what depends_on X?). \
              Uses TransE prediction: t̂ = h + r for outgoing, ĥ = t - r for incoming. \
              Can optionally filter to entities found in stored memories.",
-            json!({
-                "type": "object",
-                "required": ["entity", "relation"],
-                "properties": {
-                    "entity": {
-                        "type": "string",
-                        "description": "Source entity to find relationships for."
-                    },
-                    "relation": {
-                        "type": "string",
-                        "description": "Relationship to search for (e.g., 'depends_on', 'implements', 'created_by')."
-                    },
-                    "direction": {
-                        "type": "string",
-                        "enum": ["outgoing", "incoming"],
-                        "default": "outgoing",
-                        "description": "Direction: outgoing (h→t) or incoming (t←h)."
-                    },
-                    "entityType": {
-                        "type": "string",
-                        "enum": ["ProgrammingLanguage", "Framework", "Database", "Cloud", "Company", "TechnicalTerm"],
-                        "description": "Filter results to specific entity type."
-                    },
-                    "topK": {
-                        "type": "integer",
-                        "description": "Maximum results to return (1-50, default: 10).",
-                        "default": 10,
-                        "minimum": 1,
-                        "maximum": 50
-                    },
-                    "minScore": {
-                        "type": "number",
-                        "description": "Minimum TransE score threshold. More negative = less strict.",
-                        "minimum": -10,
-                        "maximum": 0
-                    },
-                    "searchMemories": {
-                        "type": "boolean",
-                        "description": "Filter to entities found in stored memories. Default: true.",
-                        "default": true
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
         // validate_knowledge - Score a knowledge triple (Phase 3)
-        ToolDefinition::new(
+        ToolDefinition::with_params::<ValidateKnowledgeParams>(
             "validate_knowledge",
             "Score whether a (subject, predicate, object) knowledge triple is valid using TransE. \
              Computes score = -||h + r - t||₂ where h=subject, r=predicate, t=object. \
              Score of 0 is perfect match. Returns validation result: 'valid', 'uncertain', or 'unlikely'. \
              Can also find supporting or contradicting memories in the knowledge graph.",
-            json!({
-                "type": "object",
-                "required": ["subject", "predicate", "object"],
-                "properties": {
-                    "subject": {
-                        "type": "string",
-                        "description": "Subject/head entity of the triple."
-                    },
-                    "predicate": {
-                        "type": "string",
-                        "description": "Predicate/relation of the triple (e.g., 'created_by', 'depends_on')."
-                    },
-                    "object": {
-                        "type": "string",
-                        "description": "Object/tail entity of the triple."
-                    },
-                    "subjectType": {
-                        "type": "string",
-                        "enum": ["ProgrammingLanguage", "Framework", "Database", "Cloud", "Company", "TechnicalTerm"],
-                        "description": "Optional type hint for subject entity."
-                    },
-                    "objectType": {
-                        "type": "string",
-                        "enum": ["ProgrammingLanguage", "Framework", "Database", "Cloud", "Company", "TechnicalTerm"],
-                        "description": "Optional type hint for object entity."
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
         // get_entity_graph - Visualize entity relationships (Phase 4)
-        ToolDefinition::new(
+        ToolDefinition::with_params::<GetEntityGraphParams>(
             "get_entity_graph",
             "Build and visualize entity relationships discovered in stored memories. \
              Returns a graph with entity nodes and relationship edges. \
              If centerEntity is provided, focuses on that entity's neighborhood. \
              Infers relationships using TransE and weights edges by score and evidence count.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "centerEntity": {
-                        "type": "string",
-                        "description": "Optional focal entity to center the graph on."
-                    },
-                    "maxNodes": {
-                        "type": "integer",
-                        "description": "Maximum number of nodes (1-500, default: 50).",
-                        "default": 50,
-                        "minimum": 1,
-                        "maximum": 500
-                    },
-                    "entityTypes": {
-                        "type": "array",
-                        "items": {
-                            "type": "string",
-                            "enum": ["ProgrammingLanguage", "Framework", "Database", "Cloud", "Company", "TechnicalTerm", "Unknown"]
-                        },
-                        "description": "Filter to specific entity types."
-                    },
-                    "minRelationScore": {
-                        "type": "number",
-                        "description": "Minimum edge score threshold (0-1, default: 0.3).",
-                        "default": 0.3,
-                        "minimum": 0,
-                        "maximum": 1
-                    },
-                    "includeMemoryCounts": {
-                        "type": "boolean",
-                        "description": "Include memory reference counts per node. Default: true.",
-                        "default": true
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
     ]
 }
 
+/// Parameters of the `extract_entities` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ExtractEntitiesParams {
+    /// Text to extract entities from.
+    pub text: String,
+
+    /// Include entities not in knowledge base (detected via heuristics). Default: true.
+    #[serde(default = "default_include_unknown")]
+    pub include_unknown: bool,
+
+    /// Group results by entity type (ProgrammingLanguage, Framework, Database, etc). Default:
+    /// false.
+    #[serde(default)]
+    pub group_by_type: bool,
+}
+
+fn default_include_unknown() -> bool {
+    true
+}
+
+/// Parameters of the `search_by_entities` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SearchByEntitiesParams {
+    /// Entity names to search for (e.g., ['PostgreSQL', 'Rust']). Will be canonicalized.
+    pub entities: Vec<String>,
+
+    /// Filter by entity types.
+    pub entity_types: Option<Vec<EntityTypeFilter>>,
+
+    /// Match any entity or all entities.
+    #[serde(default = "default_match_mode")]
+    pub match_mode: MatchMode,
+
+    /// Maximum results to return (1-50, default: 10).
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 50))]
+    pub top_k: u64,
+
+    /// Minimum similarity threshold (0-1, default: 0.2).
+    #[serde(default = "default_min_score")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_score: f64,
+
+    /// Include full memory content in results. Default: false.
+    #[serde(default)]
+    pub include_content: bool,
+
+    /// Boost multiplier for exact entity matches (1.0-3.0, default: 1.15).
+    #[serde(default = "default_boost_exact_match")]
+    #[schemars(range(min = 1.0, max = 3.0))]
+    pub boost_exact_match: f64,
+
+    /// Search strategy: 'e1_only' (default, E1+E11 union), 'multi_space' (multi-embedder fusion),
+    /// 'pipeline' (E13 recall -> E1 -> E12 rerank).
+    pub strategy: Option<SearchStrategy>,
+}
+
+fn default_match_mode() -> MatchMode {
+    MatchMode::Any
+}
+
+fn default_top_k() -> u64 {
+    10
+}
+
+fn default_min_score() -> f64 {
+    0.2
+}
+
+fn default_boost_exact_match() -> f64 {
+    1.15
+}
+
+/// Entity type to filter by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum EntityTypeFilter {
+    ProgrammingLanguage,
+    Framework,
+    Database,
+    Cloud,
+    Company,
+    TechnicalTerm,
+    Unknown,
+}
+
+/// How many of the entities a memory must contain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchMode {
+    Any,
+    All,
+}
+
+/// Parameters of the `infer_relationship` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct InferRelationshipParams {
+    /// Subject/head entity (e.g., 'Tokio').
+    pub head_entity: String,
+
+    /// Object/tail entity (e.g., 'Rust').
+    pub tail_entity: String,
+
+    /// Optional type hint for head entity.
+    pub head_type: Option<EntityType>,
+
+    /// Optional type hint for tail entity.
+    pub tail_type: Option<EntityType>,
+
+    /// Number of relation candidates to return (1-20, default: 5).
+    #[serde(default = "default_infer_relationship_top_k")]
+    #[schemars(range(min = 1, max = 20))]
+    pub top_k: u64,
+
+    /// Include raw TransE scores in response. Default: true.
+    #[serde(default = "default_include_score")]
+    pub include_score: bool,
+}
+
+fn default_infer_relationship_top_k() -> u64 {
+    5
+}
+
+fn default_include_score() -> bool {
+    true
+}
+
+/// Type of a known entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum EntityType {
+    ProgrammingLanguage,
+    Framework,
+    Database,
+    Cloud,
+    Company,
+    TechnicalTerm,
+}
+
+/// Lowest `find_related_entities` TransE score; schemars attributes take no negative literals.
+const MIN_TRANSE_SCORE: f64 = -10.0;
+
+/// Parameters of the `find_related_entities` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct FindRelatedEntitiesParams {
+    /// Source entity to find relationships for.
+    pub entity: String,
+
+    /// Relationship to search for (e.g., 'depends_on', 'implements', 'created_by').
+    pub relation: String,
+
+    /// Direction: outgoing (h→t) or incoming (t←h).
+    #[serde(default = "default_direction")]
+    pub direction: RelationDirection,
+
+    /// Filter results to specific entity type.
+    pub entity_type: Option<EntityType>,
+
+    /// Maximum results to return (1-50, default: 10).
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 50))]
+    pub top_k: u64,
+
+    /// Minimum TransE score threshold. More negative = less strict.
+    #[schemars(range(min = "MIN_TRANSE_SCORE", max = 0.0))]
+    pub min_score: Option<f64>,
+
+    /// Filter to entities found in stored memories. Default: true.
+    #[serde(default = "default_search_memories")]
+    pub search_memories: bool,
+}
+
+fn default_direction() -> RelationDirection {
+    RelationDirection::Outgoing
+}
+
+fn default_search_memories() -> bool {
+    true
+}
+
+/// Side of the relation the entity is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RelationDirection {
+    Outgoing,
+    Incoming,
+}
+
+/// Parameters of the `validate_knowledge` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ValidateKnowledgeParams {
+    /// Subject/head entity of the triple.
+    pub subject: String,
+
+    /// Predicate/relation of the triple (e.g., 'created_by', 'depends_on').
+    pub predicate: String,
+
+    /// Object/tail entity of the triple.
+    pub object: String,
+
+    /// Optional type hint for subject entity.
+    pub subject_type: Option<EntityType>,
+
+    /// Optional type hint for object entity.
+    pub object_type: Option<EntityType>,
+}
+
+/// Parameters of the `get_entity_graph` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GetEntityGraphParams {
+    /// Optional focal entity to center the graph on.
+    pub center_entity: Option<String>,
+
+    /// Maximum number of nodes (1-500, default: 50).
+    #[serde(default = "default_max_nodes")]
+    #[schemars(range(min = 1, max = 500))]
+    pub max_nodes: u64,
+
+    /// Filter to specific entity types.
+    pub entity_types: Option<Vec<EntityTypeFilter>>,
+
+    /// Minimum edge score threshold (0-1, default: 0.3).
+    #[serde(default = "default_min_relation_score")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_relation_score: f64,
+
+    /// Include memory reference counts per node. Default: true.
+    #[serde(default = "default_include_memory_counts")]
+    pub include_memory_counts: bool,
+}
+
+fn default_max_nodes() -> u64 {
+    50
+}
+
+fn default_min_relation_score() -> f64 {
+    0.3
+}
+
+fn default_include_memory_counts() -> bool {
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
 
     #[test]
     fn test_definitions_exist_with_required_fields() {
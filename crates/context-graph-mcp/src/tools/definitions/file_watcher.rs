@@ -10,93 +10,94 @@
 //! - SEC-06: Soft delete 30-day recovery for delete_file_content
 //! - FAIL FAST: All tools error on failures, no fallbacks
 
+use schemars::JsonSchema;
+use serde::Deserialize;
+
 use crate::tools::types::ToolDefinition;
-use serde_json::json;
 
 /// Returns file watcher tool definitions (4 tools).
 pub fn definitions() -> Vec<ToolDefinition> {
     vec![
         // list_watched_files
-        ToolDefinition::new(
+        ToolDefinition::with_params::<ListWatchedFilesParams>(
             "list_watched_files",
             "List all files that have embeddings in the knowledge graph from the file watcher. \
              Returns file paths with chunk counts and last update times.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "include_counts": {
-                        "type": "boolean",
-                        "default": true,
-                        "description": "Include chunk counts per file"
-                    },
-                    "path_filter": {
-                        "type": "string",
-                        "description": "Optional glob pattern to filter paths (e.g., '**/docs/*.md')"
-                    }
-                },
-                "required": [],
-                "additionalProperties": false
-            }),
         ),
         // get_file_watcher_stats
-        ToolDefinition::new(
+        ToolDefinition::with_params::<GetFileWatcherStatsParams>(
             "get_file_watcher_stats",
             "Get statistics about file watcher content in the knowledge graph. \
              Returns total files, total chunks, average chunks per file, and min/max values.",
-            json!({
-                "type": "object",
-                "properties": {},
-                "required": [],
-                "additionalProperties": false
-            }),
         ),
         // delete_file_content
-        ToolDefinition::new(
+        ToolDefinition::with_params::<DeleteFileContentParams>(
             "delete_file_content",
             "Delete all embeddings for a specific file path. Use for manual cleanup. \
              Supports soft delete with 30-day recovery (per SEC-06).",
-            json!({
-                "type": "object",
-                "properties": {
-                    "file_path": {
-                        "type": "string",
-                        "description": "Absolute path to the file"
-                    },
-                    "soft_delete": {
-                        "type": "boolean",
-                        "default": true,
-                        "description": "Use soft delete with 30-day recovery (default true per SEC-06)"
-                    }
-                },
-                "required": ["file_path"],
-                "additionalProperties": false
-            }),
         ),
         // reconcile_files
-        ToolDefinition::new(
+        ToolDefinition::with_params::<ReconcileFilesParams>(
             "reconcile_files",
             "Find orphaned files (embeddings exist but file doesn't on disk) and optionally delete them. \
              Use dry_run=true to preview changes without modifying data.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "dry_run": {
-                        "type": "boolean",
-                        "default": true,
-                        "description": "If true, only report orphans without deleting"
-                    },
-                    "base_path": {
-                        "type": "string",
-                        "description": "Optional base path to limit reconciliation scope"
-                    }
-                },
-                "required": [],
-                "additionalProperties": false
-            }),
         ),
     ]
 }
 
+/// Parameters of the `list_watched_files` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ListWatchedFilesParams {
+    /// Include chunk counts per file
+    #[serde(default = "default_include_counts")]
+    pub include_counts: bool,
+
+    /// Optional glob pattern to filter paths (e.g., '**/docs/*.md')
+    pub path_filter: Option<String>,
+}
+
+fn default_include_counts() -> bool {
+    true
+}
+
+/// Parameters of the `get_file_watcher_stats` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetFileWatcherStatsParams {}
+
+/// Parameters of the `delete_file_content` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DeleteFileContentParams {
+    /// Absolute path to the file
+    pub file_path: String,
+
+    /// Use soft delete with 30-day recovery (default true per SEC-06)
+    #[serde(default = "default_soft_delete")]
+    pub soft_delete: bool,
+}
+
+fn default_soft_delete() -> bool {
+    true
+}
+
+/// Parameters of the `reconcile_files` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReconcileFilesParams {
+    /// If true, only report orphans without deleting
+    #[serde(default = "default_dry_run")]
+    pub dry_run: bool,
+
+    /// Optional base path to limit reconciliation scope
+    pub base_path: Option<String>,
+}
+
+fn default_dry_run() -> bool {
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
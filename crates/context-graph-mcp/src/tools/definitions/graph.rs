@@ -8,8 +8,12 @@
 //! - ARCH-15: Uses asymmetric E8 with separate source/target encodings
 //! - AP-77: Direction modifiers: source→target=1.2, target→source=0.8
 
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::common::ChainDirection;
 use crate::tools::types::ToolDefinition;
-use serde_json::json;
 
 /// Returns graph tool definitions.
 /// Without `llm` feature: 2 tools (search_connections, get_graph_path).
@@ -17,208 +21,224 @@ use serde_json::json;
 pub fn definitions() -> Vec<ToolDefinition> {
     let mut tools = vec![
         // search_connections - Find connected memories
-        ToolDefinition::new(
+        ToolDefinition::with_params::<SearchConnectionsParams>(
             "search_connections",
             "Find memories connected to a given concept using asymmetric E8 similarity. \
              Searches for source connections (what points TO this), target connections \
              (what this points TO), or both. Uses 1.2x/0.8x direction modifiers per AP-77. \
              Use for \"what imports X?\", \"what does X use?\", \"what connects to X?\" queries.",
-            json!({
-                "type": "object",
-                "required": ["query"],
-                "properties": {
-                    "query": {
-                        "type": "string",
-                        "description": "The concept to find connections for. Can be a concept name or structural query."
-                    },
-                    "direction": {
-                        "type": "string",
-                        "enum": ["source", "target", "both"],
-                        "description": "Connection direction: source (what points TO this), target (what this points TO), both. Default: both.",
-                        "default": "both"
-                    },
-                    "topK": {
-                        "type": "integer",
-                        "description": "Maximum number of connections to return (1-50, default: 10).",
-                        "default": 10,
-                        "minimum": 1,
-                        "maximum": 50
-                    },
-                    "minScore": {
-                        "type": "number",
-                        "description": "Minimum connection score threshold (0-1, default: 0.1). Results below this are filtered.",
-                        "default": 0.1,
-                        "minimum": 0,
-                        "maximum": 1
-                    },
-                    "includeContent": {
-                        "type": "boolean",
-                        "description": "Include full content text in results (default: false).",
-                        "default": false
-                    },
-                    "filterGraphDirection": {
-                        "type": "string",
-                        "enum": ["source", "target", "unknown"],
-                        "description": "Filter results by persisted graph direction. Omit for no filtering."
-                    },
-                    "includeProvenance": {
-                        "type": "boolean",
-                        "description": "Include retrieval provenance metadata in results (default: false). Shows connection scoring method, direction modifiers, and E8 similarity details.",
-                        "default": false
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
         // get_graph_path - Multi-hop graph traversal
-        ToolDefinition::new(
+        ToolDefinition::with_params::<GetGraphPathParams>(
             "get_graph_path",
             "Build and visualize multi-hop graph paths from an anchor point. \
              Iteratively searches for connected memories using asymmetric E8 similarity. \
              Applies hop attenuation (0.9^hop) for path scoring. \
              Use for dependency chain visualization, connectivity exploration.",
-            json!({
-                "type": "object",
-                "required": ["anchorId"],
-                "properties": {
-                    "anchorId": {
-                        "type": "string",
-                        "format": "uuid",
-                        "description": "UUID of the starting memory (anchor point)."
-                    },
-                    "direction": {
-                        "type": "string",
-                        "enum": ["forward", "backward"],
-                        "description": "Direction to traverse: forward (source→target) or backward (target→source). Default: forward.",
-                        "default": "forward"
-                    },
-                    "maxHops": {
-                        "type": "integer",
-                        "description": "Maximum number of hops to traverse (1-10, default: 5).",
-                        "default": 5,
-                        "minimum": 1,
-                        "maximum": 10
-                    },
-                    "minSimilarity": {
-                        "type": "number",
-                        "description": "Minimum similarity threshold for each hop (0-1, default: 0.3).",
-                        "default": 0.3,
-                        "minimum": 0,
-                        "maximum": 1
-                    },
-                    "includeContent": {
-                        "type": "boolean",
-                        "description": "Include full content text in results (default: false).",
-                        "default": false
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
     ];
 
     #[cfg(feature = "llm")]
     {
         // discover_graph_relationships - LLM-based relationship discovery
-        tools.push(ToolDefinition::new(
+        tools.push(ToolDefinition::with_params::<DiscoverGraphRelationshipsParams>(
             "discover_graph_relationships",
             "Discover graph relationships between memories using LLM analysis with asymmetric E8 embeddings. \
              Uses the graph-agent with shared CausalDiscoveryLLM (Qwen2.5-3B) for relationship detection. \
              Supports 20 relationship types across 4 domains: Code (imports, calls, implements), \
              Legal (cites, overrules, interprets), Academic (cites, applies, extends), General. \
              Returns discovered relationships with confidence scores, categories, and directions.",
-            json!({
-                "type": "object",
-                "required": ["memory_ids"],
-                "properties": {
-                    "memory_ids": {
-                        "type": "array",
-                        "items": {
-                            "type": "string",
-                            "format": "uuid"
-                        },
-                        "description": "UUIDs of memories to analyze for relationships (2-50).",
-                        "minItems": 2,
-                        "maxItems": 50
-                    },
-                    "relationship_types": {
-                        "type": "array",
-                        "items": {
-                            "type": "string",
-                            "enum": [
-                                "contains", "scoped_by",
-                                "depends_on", "imports", "requires",
-                                "references", "cites", "interprets", "distinguishes",
-                                "implements", "complies_with", "fulfills",
-                                "extends", "modifies", "supersedes", "overrules",
-                                "calls", "applies", "used_by"
-                            ]
-                        },
-                        "description": "Filter to specific relationship types. Omit to discover all types."
-                    },
-                    "min_confidence": {
-                        "type": "number",
-                        "description": "Minimum confidence threshold for discovered relationships (0-1, default: 0.7).",
-                        "default": 0.7,
-                        "minimum": 0,
-                        "maximum": 1
-                    },
-                    "batch_size": {
-                        "type": "integer",
-                        "description": "Maximum number of candidate pairs to analyze (1-100, default: 50).",
-                        "default": 50,
-                        "minimum": 1,
-                        "maximum": 100
-                    }
-                },
-                "additionalProperties": false
-            }),
         ));
         // validate_graph_link - Single-pair LLM validation
-        tools.push(ToolDefinition::new(
+        tools.push(ToolDefinition::with_params::<ValidateGraphLinkParams>(
             "validate_graph_link",
             "Validate a proposed graph link between two memories using LLM analysis with asymmetric E8 embeddings. \
              Uses the graph-agent with shared CausalDiscoveryLLM (Qwen2.5-3B) for validation. \
              Supports 20 relationship types across Code, Legal, Academic, and General domains. \
              Returns validation result with confidence score, detected relationship type, category, and direction.",
-            json!({
-                "type": "object",
-                "required": ["source_id", "target_id"],
-                "properties": {
-                    "source_id": {
-                        "type": "string",
-                        "format": "uuid",
-                        "description": "UUID of the source memory (the one that 'points to')."
-                    },
-                    "target_id": {
-                        "type": "string",
-                        "format": "uuid",
-                        "description": "UUID of the target memory (the one that 'is pointed to')."
-                    },
-                    "expected_relationship_type": {
-                        "type": "string",
-                        "enum": [
-                            "contains", "scoped_by",
-                            "depends_on", "imports", "requires",
-                            "references", "cites", "interprets", "distinguishes",
-                            "implements", "complies_with", "fulfills",
-                            "extends", "modifies", "supersedes", "overrules",
-                            "calls", "applies", "used_by"
-                        ],
-                        "description": "Expected relationship type to validate. Omit to detect any relationship."
-                    }
-                },
-                "additionalProperties": false
-            }),
         ));
     }
 
     tools
 }
 
+/// Parameters of the `search_connections` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SearchConnectionsParams {
+    /// The concept to find connections for. Can be a concept name or structural query.
+    pub query: String,
+
+    /// Connection direction: source (what points TO this), target (what this points TO), both.
+    /// Default: both.
+    #[serde(default = "default_direction")]
+    pub direction: ConnectionDirection,
+
+    /// Maximum number of connections to return (1-50, default: 10).
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 50))]
+    pub top_k: u64,
+
+    /// Minimum connection score threshold (0-1, default: 0.1). Results below this are filtered.
+    #[serde(default = "default_min_score")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_score: f64,
+
+    /// Include full content text in results (default: false).
+    #[serde(default)]
+    pub include_content: bool,
+
+    /// Filter results by persisted graph direction. Omit for no filtering.
+    pub filter_graph_direction: Option<GraphDirectionFilter>,
+
+    /// Include retrieval provenance metadata in results (default: false). Shows connection scoring
+    /// method, direction modifiers, and E8 similarity details.
+    #[serde(default)]
+    pub include_provenance: bool,
+}
+
+fn default_direction() -> ConnectionDirection {
+    ConnectionDirection::Both
+}
+
+fn default_top_k() -> u64 {
+    10
+}
+
+fn default_min_score() -> f64 {
+    0.1
+}
+
+/// Direction of the connections to find.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionDirection {
+    Source,
+    Target,
+    Both,
+}
+
+/// Graph direction a result must have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphDirectionFilter {
+    Source,
+    Target,
+    Unknown,
+}
+
+/// Parameters of the `get_graph_path` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GetGraphPathParams {
+    /// UUID of the starting memory (anchor point).
+    pub anchor_id: Uuid,
+
+    /// Direction to traverse: forward (source→target) or backward (target→source). Default:
+    /// forward.
+    #[serde(default = "default_get_graph_path_direction")]
+    pub direction: ChainDirection,
+
+    /// Maximum number of hops to traverse (1-10, default: 5).
+    #[serde(default = "default_max_hops")]
+    #[schemars(range(min = 1, max = 10))]
+    pub max_hops: u64,
+
+    /// Minimum similarity threshold for each hop (0-1, default: 0.3).
+    #[serde(default = "default_min_similarity")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_similarity: f64,
+
+    /// Include full content text in results (default: false).
+    #[serde(default)]
+    pub include_content: bool,
+}
+
+fn default_get_graph_path_direction() -> ChainDirection {
+    ChainDirection::Forward
+}
+
+fn default_max_hops() -> u64 {
+    5
+}
+
+fn default_min_similarity() -> f64 {
+    0.3
+}
+
+/// Parameters of the `discover_graph_relationships` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DiscoverGraphRelationshipsParams {
+    /// UUIDs of memories to analyze for relationships (2-50).
+    #[schemars(length(min = 2, max = 50))]
+    pub memory_ids: Vec<Uuid>,
+
+    /// Filter to specific relationship types. Omit to discover all types.
+    pub relationship_types: Option<Vec<GraphRelationshipType>>,
+
+    /// Minimum confidence threshold for discovered relationships (0-1, default: 0.7).
+    #[serde(default = "default_min_confidence")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_confidence: f64,
+
+    /// Maximum number of candidate pairs to analyze (1-100, default: 50).
+    #[serde(default = "default_batch_size")]
+    #[schemars(range(min = 1, max = 100))]
+    pub batch_size: u64,
+}
+
+fn default_min_confidence() -> f64 {
+    0.7
+}
+
+fn default_batch_size() -> u64 {
+    50
+}
+
+/// Structural relationship the graph agent can discover.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphRelationshipType {
+    Contains,
+    ScopedBy,
+    DependsOn,
+    Imports,
+    Requires,
+    References,
+    Cites,
+    Interprets,
+    Distinguishes,
+    Implements,
+    CompliesWith,
+    Fulfills,
+    Extends,
+    Modifies,
+    Supersedes,
+    Overrules,
+    Calls,
+    Applies,
+    UsedBy,
+}
+
+/// Parameters of the `validate_graph_link` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ValidateGraphLinkParams {
+    /// UUID of the source memory (the one that 'points to').
+    pub source_id: Uuid,
+
+    /// UUID of the target memory (the one that 'is pointed to').
+    pub target_id: Uuid,
+
+    /// Expected relationship type to validate. Omit to detect any relationship.
+    pub expected_relationship_type: Option<GraphRelationshipType>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
 
     #[test]
     fn test_definitions_exist_with_required_fields() {
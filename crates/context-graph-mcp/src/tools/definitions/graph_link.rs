@@ -5,9 +5,12 @@
 //! - `get_typed_edges`: Get typed edges from a memory
 //! - `traverse_graph`: Multi-hop graph traversal
 
-use serde_json::json;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use super::super::types::ToolDefinition;
+use super::common::{EdgeType, Embedder, EmbedderWeights, WeightProfile};
 
 /// Get all graph linking tool definitions.
 pub fn definitions() -> Vec<ToolDefinition> {
@@ -20,254 +23,202 @@ pub fn definitions() -> Vec<ToolDefinition> {
 }
 
 fn get_memory_neighbors_definition() -> ToolDefinition {
-    ToolDefinition::new(
+    ToolDefinition::with_params::<GetMemoryNeighborsParams>(
         "get_memory_neighbors",
         "Get K nearest neighbors of a memory in a specific embedder space using pre-computed \
          K-NN edges. Returns neighbors sorted by similarity. NOTE: Recently stored memories \
          may return 0 neighbors until the background K-NN graph build runs (~60s). \
          Use get_unified_neighbors for immediate results on recent memories.",
-        json!({
-            "type": "object",
-            "required": ["memory_id"],
-            "properties": {
-                "memory_id": {
-                    "type": "string",
-                    "format": "uuid",
-                    "description": "UUID of the memory to find neighbors for"
-                },
-                "embedder_id": {
-                    "type": "integer",
-                    "minimum": 0,
-                    "maximum": 12,
-                    "default": 0,
-                    "description": "Embedder space to search (0=E1 semantic, 4=E5 causal, 6=E7 code, 7=E8 graph, 9=E10 paraphrase, 10=E11 entity)"
-                },
-                "top_k": {
-                    "type": "integer",
-                    "minimum": 1,
-                    "maximum": 50,
-                    "default": 10,
-                    "description": "Number of neighbors to return (default: 10)"
-                },
-                "min_similarity": {
-                    "type": "number",
-                    "minimum": 0.0,
-                    "maximum": 1.0,
-                    "default": 0.0,
-                    "description": "Minimum similarity threshold (default: 0.0)"
-                },
-                "include_content": {
-                    "type": "boolean",
-                    "default": false,
-                    "description": "Include memory content in results (default: false)"
-                }
-            },
-            "additionalProperties": false
-        }),
     )
 }
 
 fn get_typed_edges_definition() -> ToolDefinition {
-    ToolDefinition::new(
+    ToolDefinition::with_params::<GetTypedEdgesParams>(
         "get_typed_edges",
         "Get typed edges from a memory. Typed edges represent relationships derived from \
          embedder agreement patterns: semantic_similar, code_related, entity_shared, \
-         causal_chain, graph_connected, paraphrase_aligned, keyword_overlap, multi_agreement.",
-        json!({
-            "type": "object",
-            "required": ["memory_id"],
-            "properties": {
-                "memory_id": {
-                    "type": "string",
-                    "format": "uuid",
-                    "description": "UUID of the memory to get edges from"
-                },
-                "edge_type": {
-                    "type": "string",
-                    "enum": [
-                        "semantic_similar",
-                        "code_related",
-                        "entity_shared",
-                        "causal_chain",
-                        "graph_connected",
-                        "paraphrase_aligned",
-                        "keyword_overlap",
-                        "multi_agreement"
-                    ],
-                    "description": "Filter by edge type (optional, returns all types if not specified)"
-                },
-                "direction": {
-                    "type": "string",
-                    "enum": ["outgoing", "incoming", "both"],
-                    "default": "outgoing",
-                    "description": "Edge direction: outgoing (from memory), incoming (to memory), both"
-                },
-                "min_weight": {
-                    "type": "number",
-                    "minimum": 0.0,
-                    "maximum": 1.0,
-                    "default": 0.0,
-                    "description": "Minimum edge weight threshold (default: 0.0)"
-                },
-                "include_content": {
-                    "type": "boolean",
-                    "default": false,
-                    "description": "Include memory content in results (default: false)"
-                }
-            },
-            "additionalProperties": false
-        }),
+         causal_chain, graph_connected, paraphrase_aligned, keyword_overlap, multi_agreement; \
+         and explicit relations: refutes, supersedes, derived_from, temporal_follows.",
     )
 }
 
 fn traverse_graph_definition() -> ToolDefinition {
-    ToolDefinition::new(
+    ToolDefinition::with_params::<TraverseGraphParams>(
         "traverse_graph",
         "Multi-hop graph traversal starting from a memory. Explores the knowledge graph \
          following typed edges up to a maximum depth. Useful for discovering connected \
          memories, causal chains, or code dependencies.",
-        json!({
-            "type": "object",
-            "required": ["start_memory_id"],
-            "properties": {
-                "start_memory_id": {
-                    "type": "string",
-                    "format": "uuid",
-                    "description": "UUID of the starting memory"
-                },
-                "max_hops": {
-                    "type": "integer",
-                    "minimum": 1,
-                    "maximum": 5,
-                    "default": 2,
-                    "description": "Maximum traversal depth (default: 2, max: 5)"
-                },
-                "edge_type": {
-                    "type": "string",
-                    "enum": [
-                        "semantic_similar",
-                        "code_related",
-                        "entity_shared",
-                        "causal_chain",
-                        "graph_connected",
-                        "paraphrase_aligned",
-                        "keyword_overlap",
-                        "multi_agreement"
-                    ],
-                    "description": "Filter traversal by edge type (optional)"
-                },
-                "min_weight": {
-                    "type": "number",
-                    "minimum": 0.0,
-                    "maximum": 1.0,
-                    "default": 0.3,
-                    "description": "Minimum edge weight to follow (default: 0.3)"
-                },
-                "max_results": {
-                    "type": "integer",
-                    "minimum": 1,
-                    "maximum": 100,
-                    "default": 20,
-                    "description": "Maximum paths to return (default: 20)"
-                },
-                "include_content": {
-                    "type": "boolean",
-                    "default": false,
-                    "description": "Include memory content in results (default: false)"
-                }
-            },
-            "additionalProperties": false
-        }),
     )
 }
 
 fn get_unified_neighbors_definition() -> ToolDefinition {
-    ToolDefinition::new(
+    ToolDefinition::with_params::<GetUnifiedNeighborsParams>(
         "get_unified_neighbors",
         "Find neighbors using Weighted RRF fusion across all 13 embedders, providing a unified \
          view where neighbors are ranked by how consistently multiple embedders agree they are \
          related. Unlike get_memory_neighbors (single embedder), this shows what ALL embedders \
          agree on. Per ARCH-21: Uses Weighted RRF, not weighted sum. Per AP-60: Temporal \
          embedders (E2-E4) are excluded from semantic fusion.",
-        json!({
-            "type": "object",
-            "required": ["memory_id"],
-            "properties": {
-                "memory_id": {
-                    "type": "string",
-                    "format": "uuid",
-                    "description": "UUID of the memory to find unified neighbors for"
-                },
-                "weight_profile": {
-                    "type": "string",
-                    "enum": [
-                        "semantic_search", "causal_reasoning", "code_search", "fact_checking",
-                        "graph_reasoning", "temporal_navigation", "sequence_navigation",
-                        "conversation_history", "category_weighted", "typo_tolerant",
-                        "pipeline_stage1_recall", "pipeline_stage2_scoring", "pipeline_full",
-                        "balanced"
-                    ],
-                    "default": "semantic_search",
-                    "description": "Weight profile for RRF fusion. Temporal profiles: temporal_navigation (E2+E3+E4 balanced), sequence_navigation (E4-heavy), conversation_history (E4+E1). Use customWeights for fine-grained E2/E3/E4 control."
-                },
-                "top_k": {
-                    "type": "integer",
-                    "minimum": 1,
-                    "maximum": 50,
-                    "default": 10,
-                    "description": "Number of neighbors to return (default: 10)"
-                },
-                "min_score": {
-                    "type": "number",
-                    "minimum": 0.0,
-                    "maximum": 1.0,
-                    "default": 0.0,
-                    "description": "Minimum RRF score threshold (default: 0.0)"
-                },
-                "include_content": {
-                    "type": "boolean",
-                    "default": false,
-                    "description": "Include memory content in results (default: false)"
-                },
-                "include_embedder_breakdown": {
-                    "type": "boolean",
-                    "default": true,
-                    "description": "Include per-embedder scores and ranks in results (default: true)"
-                },
-                "custom_weights": {
-                    "type": "object",
-                    "description": "Custom per-embedder weights (overrides weight_profile). Each value 0-1, must sum to ~1.0.",
-                    "properties": {
-                        "E1":  { "type": "number", "minimum": 0, "maximum": 1 },
-                        "E2":  { "type": "number", "minimum": 0, "maximum": 1 },
-                        "E3":  { "type": "number", "minimum": 0, "maximum": 1 },
-                        "E4":  { "type": "number", "minimum": 0, "maximum": 1 },
-                        "E5":  { "type": "number", "minimum": 0, "maximum": 1 },
-                        "E6":  { "type": "number", "minimum": 0, "maximum": 1 },
-                        "E7":  { "type": "number", "minimum": 0, "maximum": 1 },
-                        "E8":  { "type": "number", "minimum": 0, "maximum": 1 },
-                        "E9":  { "type": "number", "minimum": 0, "maximum": 1 },
-                        "E10": { "type": "number", "minimum": 0, "maximum": 1 },
-                        "E11": { "type": "number", "minimum": 0, "maximum": 1 },
-                        "E12": { "type": "number", "minimum": 0, "maximum": 1 },
-                        "E13": { "type": "number", "minimum": 0, "maximum": 1 }
-                    },
-                    "additionalProperties": false
-                },
-                "exclude_embedders": {
-                    "type": "array",
-                    "description": "Embedders to exclude from fusion (their weight becomes 0, remaining renormalized).",
-                    "items": {
-                        "type": "string",
-                        "enum": ["E1", "E2", "E3", "E4", "E5", "E6", "E7", "E8", "E9", "E10", "E11", "E12", "E13"]
-                    }
-                }
-            },
-            "additionalProperties": false
-        }),
     )
 }
 
+/// Parameters of the `get_memory_neighbors` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetMemoryNeighborsParams {
+    /// UUID of the memory to find neighbors for
+    pub memory_id: Uuid,
+
+    /// Embedder space to search (0=E1 semantic, 4=E5 causal, 6=E7 code, 7=E8 graph, 9=E10
+    /// paraphrase, 10=E11 entity)
+    #[serde(default)]
+    #[schemars(range(min = 0, max = 12))]
+    pub embedder_id: u64,
+
+    /// Number of neighbors to return (default: 10)
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 50))]
+    pub top_k: u64,
+
+    /// Minimum similarity threshold (default: 0.0)
+    #[serde(default)]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_similarity: f64,
+
+    /// Include memory content in results (default: false)
+    #[serde(default)]
+    pub include_content: bool,
+}
+
+fn default_top_k() -> u64 {
+    10
+}
+
+/// Parameters of the `get_typed_edges` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetTypedEdgesParams {
+    /// UUID of the memory to get edges from
+    pub memory_id: Uuid,
+
+    /// Filter by edge type (optional, returns all types if not specified)
+    pub edge_type: Option<EdgeType>,
+
+    /// Edge direction: outgoing (from memory), incoming (to memory), both
+    #[serde(default = "default_direction")]
+    pub direction: EdgeDirection,
+
+    /// Minimum edge weight threshold (default: 0.0)
+    #[serde(default)]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_weight: f64,
+
+    /// Include memory content in results (default: false)
+    #[serde(default)]
+    pub include_content: bool,
+}
+
+fn default_direction() -> EdgeDirection {
+    EdgeDirection::Outgoing
+}
+
+/// Which edges of the memory to return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeDirection {
+    Outgoing,
+    Incoming,
+    Both,
+}
+
+/// Parameters of the `traverse_graph` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TraverseGraphParams {
+    /// UUID of the starting memory
+    pub start_memory_id: Uuid,
+
+    /// Maximum traversal depth (default: 2, max: 5)
+    #[serde(default = "default_max_hops")]
+    #[schemars(range(min = 1, max = 5))]
+    pub max_hops: u64,
+
+    /// Filter traversal by edge type (optional)
+    pub edge_type: Option<EdgeType>,
+
+    /// Minimum edge weight to follow (default: 0.3)
+    #[serde(default = "default_min_weight")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_weight: f64,
+
+    /// Maximum paths to return (default: 20)
+    #[serde(default = "default_max_results")]
+    #[schemars(range(min = 1, max = 100))]
+    pub max_results: u64,
+
+    /// Include memory content in results (default: false)
+    #[serde(default)]
+    pub include_content: bool,
+}
+
+fn default_max_hops() -> u64 {
+    2
+}
+
+fn default_min_weight() -> f64 {
+    0.3
+}
+
+fn default_max_results() -> u64 {
+    20
+}
+
+/// Parameters of the `get_unified_neighbors` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetUnifiedNeighborsParams {
+    /// UUID of the memory to find unified neighbors for
+    pub memory_id: Uuid,
+
+    /// Weight profile for RRF fusion. Temporal profiles: temporal_navigation (E2+E3+E4 balanced),
+    /// sequence_navigation (E4-heavy), conversation_history (E4+E1). Use customWeights for
+    /// fine-grained E2/E3/E4 control.
+    #[serde(default = "default_weight_profile")]
+    pub weight_profile: WeightProfile,
+
+    /// Number of neighbors to return (default: 10)
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 50))]
+    pub top_k: u64,
+
+    /// Minimum RRF score threshold (default: 0.0)
+    #[serde(default)]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_score: f64,
+
+    /// Include memory content in results (default: false)
+    #[serde(default)]
+    pub include_content: bool,
+
+    /// Include per-embedder scores and ranks in results (default: true)
+    #[serde(default = "default_include_embedder_breakdown")]
+    pub include_embedder_breakdown: bool,
+
+    /// Custom per-embedder weights (overrides weight_profile). Each value 0-1, must sum to ~1.0.
+    pub custom_weights: Option<EmbedderWeights>,
+
+    /// Embedders to exclude from fusion (their weight becomes 0, remaining renormalized).
+    pub exclude_embedders: Option<Vec<Embedder>>,
+}
+
+fn default_weight_profile() -> WeightProfile {
+    WeightProfile::SemanticSearch
+}
+
+fn default_include_embedder_breakdown() -> bool {
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
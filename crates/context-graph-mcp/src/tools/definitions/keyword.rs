@@ -7,8 +7,10 @@
 //! Tools:
 //! - search_by_keywords: Find memories matching specific keywords using E6 sparse embeddings
 
-use serde_json::json;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
+use super::common::SearchStrategy;
 use crate::tools::types::ToolDefinition;
 
 /// Get all keyword tool definitions.
@@ -21,60 +23,70 @@ pub fn definitions() -> Vec<ToolDefinition> {
 
 /// Definition for search_by_keywords tool.
 fn search_by_keywords_definition() -> ToolDefinition {
-    ToolDefinition::new(
+    ToolDefinition::with_params::<SearchByKeywordsParams>(
         "search_by_keywords",
         "Find memories matching specific keywords using E6 sparse embeddings. ENHANCES E1 semantic search with keyword-level precision for exact term matches. Use for \"keyword queries (exact terms, jargon)\" per constitution. Optionally expands terms with E13 SPLADE (fast→quick). Returns nodes matching the query with relevance scores.",
-        json!({
-            "type": "object",
-            "properties": {
-                "query": {
-                    "type": "string",
-                    "description": "The keyword query to search for. Can be a phrase or multiple keywords."
-                },
-                "topK": {
-                    "type": "integer",
-                    "default": 10,
-                    "minimum": 1,
-                    "maximum": 50,
-                    "description": "Maximum number of results to return (1-50, default: 10)."
-                },
-                "minScore": {
-                    "type": "number",
-                    "default": 0.1,
-                    "minimum": 0,
-                    "maximum": 1,
-                    "description": "Minimum blended score threshold (0-1, default: 0.1). Results below this are filtered."
-                },
-                "blendWithSemantic": {
-                    "type": "number",
-                    "default": 0.3,
-                    "minimum": 0,
-                    "maximum": 1,
-                    "description": "E6 keyword weight in blend (0-1, default: 0.3). Higher = more keyword emphasis. 0.0=pure E1 semantic, 1.0=pure E6 keyword."
-                },
-                "useSpladeExpansion": {
-                    "type": "boolean",
-                    "default": true,
-                    "description": "Use E13 SPLADE for term expansion (default: true). Expands query terms to related terms (fast→quick)."
-                },
-                "includeContent": {
-                    "type": "boolean",
-                    "default": false,
-                    "description": "Include full content text in results (default: false)."
-                },
-                "strategy": {
-                    "type": "string",
-                    "enum": ["e1_only", "multi_space", "pipeline"],
-                    "default": "multi_space",
-                    "description": "Search strategy: 'e1_only' (E1 only), 'multi_space' (default, multi-embedder fusion), 'pipeline' (E13 recall -> E1 -> E12 rerank)."
-                }
-            },
-            "required": ["query"],
-            "additionalProperties": false
-        }),
     )
 }
 
+/// Parameters of the `search_by_keywords` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SearchByKeywordsParams {
+    /// The keyword query to search for. Can be a phrase or multiple keywords.
+    pub query: String,
+
+    /// Maximum number of results to return (1-50, default: 10).
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 50))]
+    pub top_k: u64,
+
+    /// Minimum blended score threshold (0-1, default: 0.1). Results below this are filtered.
+    #[serde(default = "default_min_score")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_score: f64,
+
+    /// E6 keyword weight in blend (0-1, default: 0.3). Higher = more keyword emphasis. 0.0=pure E1
+    /// semantic, 1.0=pure E6 keyword.
+    #[serde(default = "default_blend_with_semantic")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub blend_with_semantic: f64,
+
+    /// Use E13 SPLADE for term expansion (default: true). Expands query terms to related terms
+    /// (fast→quick).
+    #[serde(default = "default_use_splade_expansion")]
+    pub use_splade_expansion: bool,
+
+    /// Include full content text in results (default: false).
+    #[serde(default)]
+    pub include_content: bool,
+
+    /// Search strategy: 'e1_only' (E1 only), 'multi_space' (default, multi-embedder fusion),
+    /// 'pipeline' (E13 recall -> E1 -> E12 rerank).
+    #[serde(default = "default_strategy")]
+    pub strategy: SearchStrategy,
+}
+
+fn default_top_k() -> u64 {
+    10
+}
+
+fn default_min_score() -> f64 {
+    0.1
+}
+
+fn default_blend_with_semantic() -> f64 {
+    0.3
+}
+
+fn default_use_splade_expansion() -> bool {
+    true
+}
+
+fn default_strategy() -> SearchStrategy {
+    SearchStrategy::MultiSpace
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
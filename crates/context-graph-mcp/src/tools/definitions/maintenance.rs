@@ -1,30 +1,56 @@
-//! Maintenance tool definitions for data repair and cleanup.
+//! Maintenance tool definitions for data repair, cleanup and backups.
 //!
 //! Tools:
 //! - repair_causal_relationships: Remove corrupted causal relationship entries
+//! - create_backup: Consistent backup of the data directory
+
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::tools::types::ToolDefinition;
-use serde_json::json;
 
-/// Returns maintenance tool definitions (1 tool).
+/// Returns maintenance tool definitions (2 tools).
 pub fn definitions() -> Vec<ToolDefinition> {
     vec![
         // repair_causal_relationships
-        ToolDefinition::new(
+        ToolDefinition::with_params::<RepairCausalRelationshipsParams>(
             "repair_causal_relationships",
             "Repair corrupted causal relationships by removing entries that fail deserialization. \
              Scans CF_CAUSAL_RELATIONSHIPS and deletes any truncated or corrupted entries. \
              This is useful after crashes or interrupted writes that may have left incomplete data. \
              Returns (deleted_count, total_scanned) statistics.",
-            json!({
-                "type": "object",
-                "properties": {},
-                "additionalProperties": false
-            }),
+        ),
+        // create_backup
+        ToolDefinition::with_params::<CreateBackupParams>(
+            "create_backup",
+            "Write a consistent backup of the data directory while the server keeps running: \
+             a RocksDB checkpoint of every column family, the HNSW index snapshot and a \
+             manifest with per-file SHA-256 checksums. Backups go to <name> under the \
+             configured backup root; with incremental_from, SST files unchanged since that \
+             earlier backup are hard-linked instead of copied. Restore with \
+             `context-graph-cli backup restore`. Disabled unless [mcp.backup] enabled = true.",
         ),
     ]
 }
 
+/// Parameters of the `repair_causal_relationships` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RepairCausalRelationshipsParams {}
+
+/// Parameters of the `create_backup` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateBackupParams {
+    /// Backup directory name under the backup root (default: UTC timestamp)
+    #[schemars(length(max = 128), regex(pattern = r"^[A-Za-z0-9_-][A-Za-z0-9._-]*$"))]
+    pub name: Option<String>,
+
+    /// Name of an earlier backup whose unchanged SST files are linked
+    #[schemars(length(max = 128), regex(pattern = r"^[A-Za-z0-9_-][A-Za-z0-9._-]*$"))]
+    pub incremental_from: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,12 +58,19 @@ mod tests {
     #[test]
     fn test_definitions_exist_with_required_fields() {
         let tools = definitions();
-        assert_eq!(tools.len(), 1);
+        assert_eq!(tools.len(), 2);
         let repair = tools.iter().find(|t| t.name == "repair_causal_relationships").unwrap();
         assert!(repair.description.contains("corrupted"));
         assert!(repair.description.contains("deserialization"));
         assert_eq!(repair.input_schema.get("type").unwrap().as_str().unwrap(), "object");
         let props = repair.input_schema.get("properties").unwrap();
         assert!(props.as_object().unwrap().is_empty());
+
+        let backup = tools.iter().find(|t| t.name == "create_backup").unwrap();
+        assert!(backup.description.contains("checkpoint"));
+        let props = backup.input_schema.get("properties").unwrap();
+        assert!(props.get("name").is_some());
+        assert!(props.get("incremental_from").is_some());
+        assert!(backup.input_schema.get("required").is_none());
     }
 }
@@ -8,60 +8,78 @@
 //! - intersection: Keep only common attributes
 //! - weighted_average: Weight by node importance/confidence
 
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::common::Domain;
 use crate::tools::types::ToolDefinition;
-use serde_json::json;
 
 /// Returns merge tool definitions.
 pub fn definitions() -> Vec<ToolDefinition> {
-    vec![ToolDefinition::new(
+    vec![ToolDefinition::with_params::<MergeConceptsParams>(
         "merge_concepts",
         "Merge two or more related concept nodes into a unified node. \
              Supports union (combine all), intersection (common only), or \
              weighted_average (by importance) strategies. Returns reversal_hash \
-             for 30-day undo capability. Requires rationale per PRD 0.3.",
-        json!({
-            "type": "object",
-            "required": ["source_ids", "target_name", "rationale"],
-            "properties": {
-                "source_ids": {
-                    "type": "array",
-                    "items": { "type": "string", "format": "uuid" },
-                    "minItems": 2,
-                    "maxItems": 10,
-                    "description": "UUIDs of concepts to merge (2-10 required)"
-                },
-                "target_name": {
-                    "type": "string",
-                    "minLength": 1,
-                    "maxLength": 256,
-                    "description": "Name for the merged concept (1-256 chars)"
-                },
-                "merge_strategy": {
-                    "type": "string",
-                    "enum": ["union", "intersection", "weighted_average"],
-                    "default": "union",
-                    "description": "Strategy: union=combine all, intersection=common only, weighted_average=by importance"
-                },
-                "rationale": {
-                    "type": "string",
-                    "minLength": 1,
-                    "maxLength": 1024,
-                    "description": "Rationale for merge (REQUIRED per PRD 0.3)"
-                },
-                "force_merge": {
-                    "type": "boolean",
-                    "default": false,
-                    "description": "Force merge even if priors conflict (use with caution)"
-                }
-            },
-            "additionalProperties": false
-        }),
+             for 30-day undo capability. Requires rationale per PRD 0.3. \
+             Refuses sources less similar than the domain's theta_dup unless \
+             force_merge; preview=true shows the result without writing.",
     )]
 }
 
+/// Parameters of the `merge_concepts` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MergeConceptsParams {
+    /// UUIDs of concepts to merge (2-10 required)
+    #[schemars(length(min = 2, max = 10))]
+    pub source_ids: Vec<Uuid>,
+
+    /// Name for the merged concept (1-256 chars)
+    #[schemars(length(min = 1, max = 256))]
+    pub target_name: String,
+
+    /// Strategy: union=combine all, intersection=common only, weighted_average=by importance
+    #[serde(default = "default_merge_strategy")]
+    pub merge_strategy: MergeStrategy,
+
+    /// Rationale for merge (REQUIRED per PRD 0.3)
+    #[schemars(length(min = 1, max = 1024))]
+    pub rationale: String,
+
+    /// Force merge even if priors conflict or the sources are less similar than the domain's
+    /// theta_dup (use with caution)
+    #[serde(default)]
+    pub force_merge: bool,
+
+    /// Return the would-be merged metadata, similarity breakdown and combined edge set without
+    /// writing anything
+    #[serde(default)]
+    pub preview: bool,
+
+    /// Content domain whose duplicate threshold (theta_dup) applies. Detected from the source
+    /// contents when omitted.
+    pub domain: Option<Domain>,
+}
+
+fn default_merge_strategy() -> MergeStrategy {
+    MergeStrategy::Union
+}
+
+/// How merged attributes are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeStrategy {
+    Union,
+    Intersection,
+    WeightedAverage,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
 
     #[test]
     fn test_definition_exists_with_required_fields() {
@@ -79,6 +97,8 @@ mod tests {
         assert_eq!(props["source_ids"]["minItems"], 2);
         assert_eq!(props["source_ids"]["maxItems"], 10);
         assert_eq!(props["merge_strategy"]["default"], "union");
+        assert_eq!(props["preview"]["default"], false);
+        assert_eq!(props["domain"]["enum"].as_array().unwrap().len(), 5);
     }
 
     #[test]
@@ -1,10 +1,12 @@
-//! Tool definitions per PRD v6 Section 10 (56 tools with LLM, 52 without).
+//! Tool definitions per PRD v6 Section 10 (70 tools with LLM, 66 without).
 //!
 //! Includes 17 original tools (inject_context merged into store_memory)
 //! plus 4 sequence tools for E4 integration
 //! plus 4 causal tools for E5 Priority 1 enhancement
 //! plus 2 causal discovery tools for E5 LLM-based relationship discovery (LLM only)
 //! plus 1 keyword tool for E6 keyword search enhancement
+//! plus 1 query tool for the structured query DSL (query_memories)
+//! plus 1 batched search tool (search_graph_batch)
 //! plus 1 code tool for E7 code search enhancement
 //! plus 2 graph tools (+2 with LLM) for E8 upgrade (Phase 4)
 //! plus 1 robustness tool for E9 typo-tolerant search
@@ -12,35 +14,43 @@
 //! plus 4 embedder-first search tools for Constitution v6.3
 //! plus 2 temporal tools for E2/E3 (search_recent, search_periodic)
 //! plus 4 graph linking tools (get_memory_neighbors, get_typed_edges, traverse_graph, get_unified_neighbors)
-//! plus 1 maintenance tool (repair_causal_relationships).
-
-pub(crate) mod causal;
-pub(crate) mod causal_discovery;
-pub(crate) mod code;
-pub(crate) mod core;
-pub(crate) mod curation;
-pub(crate) mod daemon;
-pub(crate) mod embedder;
-pub(crate) mod entity;
-pub(crate) mod file_watcher;
-pub(crate) mod graph;
-pub(crate) mod graph_link;
-pub(crate) mod keyword;
-pub(crate) mod maintenance;
-pub(crate) mod merge;
-pub(crate) mod provenance;
-pub(crate) mod robustness;
-pub(crate) mod sequence;
-pub(crate) mod temporal;
-pub(crate) mod topic;
+//! plus 2 maintenance tools (repair_causal_relationships, create_backup)
+//! plus 2 snapshot tools (export_memories, import_memories)
+//! plus 2 staging tools (promote_staged, end_staged_session)
+//! plus 2 daemon tools (daemon_status, get_embedding_status).
+
+pub mod causal;
+pub mod causal_discovery;
+pub mod code;
+pub mod common;
+pub mod core;
+pub mod curation;
+pub mod daemon;
+pub mod embedder;
+pub mod entity;
+pub mod file_watcher;
+pub mod graph;
+pub mod graph_link;
+pub mod keyword;
+pub mod maintenance;
+pub mod merge;
+pub mod provenance;
+pub mod query;
+pub mod robustness;
+pub mod search_batch;
+pub mod sequence;
+pub mod snapshot;
+pub mod staging;
+pub mod temporal;
+pub mod topic;
 
 use crate::tools::types::ToolDefinition;
 
 /// Get all tool definitions for the `tools/list` response.
 pub fn get_tool_definitions() -> Vec<ToolDefinition> {
-    let mut tools = Vec::with_capacity(58);
+    let mut tools = Vec::with_capacity(70);
 
-    // Core tools (4 - inject_context merged into store_memory)
+    // Core tools (5 - inject_context merged into store_memory)
     tools.extend(core::definitions());
 
     // Merge tool (1 - part of curation)
@@ -49,7 +59,7 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
     // Curation tools (2)
     tools.extend(curation::definitions());
 
-    // Topic tools (4)
+    // Topic tools (5)
     tools.extend(topic::definitions());
 
     // File watcher tools (4)
@@ -67,6 +77,12 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
     // Keyword tools (1) - E6 keyword search enhancement
     tools.extend(keyword::definitions());
 
+    // Query tools (1) - Structured lexical + semantic + metadata queries
+    tools.extend(query::definitions());
+
+    // Batched search tools (1) - search_graph queries embedded in one batch
+    tools.extend(search_batch::definitions());
+
     // Code tools (1) - E7 code search enhancement
     tools.extend(code::definitions());
 
@@ -94,10 +110,16 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
     // Maintenance tools (1) - Data repair and cleanup
     tools.extend(maintenance::definitions());
 
-    // Provenance tools (3) - Phase P3 provenance queries
+    // Snapshot tools (2) - Portable export/import
+    tools.extend(snapshot::definitions());
+
+    // Staging tools (2) - Session-scoped staging
+    tools.extend(staging::definitions());
+
+    // Provenance tools (5) - Phase P3 provenance queries
     tools.extend(provenance::definitions());
 
-    // Daemon tools (1) - Multi-agent observability
+    // Daemon tools (2) - Multi-agent observability and model readiness
     tools.extend(daemon::definitions());
 
     tools
@@ -111,9 +133,9 @@ mod tests {
     fn test_total_tool_count_and_no_duplicates() {
         let tools = get_tool_definitions();
         #[cfg(feature = "llm")]
-        assert_eq!(tools.len(), 58);
+        assert_eq!(tools.len(), 70);
         #[cfg(not(feature = "llm"))]
-        assert_eq!(tools.len(), 54);
+        assert_eq!(tools.len(), 66);
         // No duplicates
         let mut names: Vec<&str> = tools.iter().map(|t| t.name.as_str()).collect();
         let len_before = names.len();
@@ -129,23 +151,27 @@ mod tests {
 
     #[test]
     fn test_submodule_counts() {
-        assert_eq!(core::definitions().len(), 4);
+        assert_eq!(core::definitions().len(), 5);
         assert_eq!(merge::definitions().len(), 1);
         assert_eq!(curation::definitions().len(), 2);
-        assert_eq!(topic::definitions().len(), 4);
+        assert_eq!(topic::definitions().len(), 5);
         assert_eq!(file_watcher::definitions().len(), 4);
         assert_eq!(sequence::definitions().len(), 4);
         assert_eq!(causal::definitions().len(), 4);
         assert_eq!(keyword::definitions().len(), 1);
+        assert_eq!(query::definitions().len(), 1);
+        assert_eq!(search_batch::definitions().len(), 1);
         assert_eq!(code::definitions().len(), 1);
         assert_eq!(robustness::definitions().len(), 1);
         assert_eq!(entity::definitions().len(), 6);
         assert_eq!(embedder::definitions().len(), 7);
         assert_eq!(temporal::definitions().len(), 2);
         assert_eq!(graph_link::definitions().len(), 4);
-        assert_eq!(maintenance::definitions().len(), 1);
-        assert_eq!(provenance::definitions().len(), 3);
-        assert_eq!(daemon::definitions().len(), 1);
+        assert_eq!(maintenance::definitions().len(), 2);
+        assert_eq!(snapshot::definitions().len(), 2);
+        assert_eq!(staging::definitions().len(), 2);
+        assert_eq!(provenance::definitions().len(), 5);
+        assert_eq!(daemon::definitions().len(), 2);
         // Audit-12 TST-H2 FIX: graph and causal_discovery are LLM-gated, must be tested
         #[cfg(feature = "llm")]
         {
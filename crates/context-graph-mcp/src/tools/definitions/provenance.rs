@@ -4,102 +4,148 @@
 //! - get_audit_trail: Query audit log by target or time range
 //! - get_merge_history: Show merge lineage for a fingerprint
 //! - get_provenance_chain: Full provenance chain from embedding to source
+//! - get_memory_lineage: Merge/consolidation ancestry DAG for a memory
+//! - query_audit_log: Per-tool execution history of mutating tool calls
+
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::tools::types::ToolDefinition;
-use serde_json::json;
 
 pub fn definitions() -> Vec<ToolDefinition> {
     vec![
-        ToolDefinition::new(
+        ToolDefinition::with_params::<GetAuditTrailParams>(
             "get_audit_trail",
             "Query the audit log for a specific memory or time range. Returns chronological audit records showing all operations performed on the memory (create, merge, delete, boost, etc.).",
-            json!({
-                "type": "object",
-                "properties": {
-                    "target_id": {
-                        "type": "string",
-                        "description": "UUID of the memory to get audit trail for"
-                    },
-                    "start_time": {
-                        "type": "string",
-                        "description": "ISO 8601 timestamp for time range start (e.g., '2024-01-01T00:00:00Z')"
-                    },
-                    "end_time": {
-                        "type": "string",
-                        "description": "ISO 8601 timestamp for time range end"
-                    },
-                    "limit": {
-                        "type": "integer",
-                        "description": "Maximum records to return (default: 50, max: 500)",
-                        "default": 50
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
-        ToolDefinition::new(
+        ToolDefinition::with_params::<GetMergeHistoryParams>(
             "get_merge_history",
             "Show merge lineage and history for a fingerprint. Returns all merge operations that created or affected this memory, including source memory IDs, strategy used, and operator.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "memory_id": {
-                        "type": "string",
-                        "description": "UUID of the memory to get merge history for"
-                    },
-                    "include_source_metadata": {
-                        "type": "boolean",
-                        "description": "Include source metadata for merged sources (default: false)",
-                        "default": false
-                    }
-                },
-                "required": ["memory_id"],
-                "additionalProperties": false
-            }),
         ),
-        ToolDefinition::new(
+        ToolDefinition::with_params::<GetProvenanceChainParams>(
             "get_provenance_chain",
             "Full provenance chain from embedding to source for a memory. Shows source type, file path, chunk info, operator attribution, causal direction, and creation timestamps.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "memory_id": {
-                        "type": "string",
-                        "description": "UUID of the memory to trace provenance for"
-                    },
-                    "include_audit": {
-                        "type": "boolean",
-                        "description": "Include audit trail in provenance chain (default: false)",
-                        "default": false
-                    },
-                    "include_embedding_version": {
-                        "type": "boolean",
-                        "description": "Include embedding version info if available (default: false)",
-                        "default": false
-                    },
-                    "include_importance_history": {
-                        "type": "boolean",
-                        "description": "Include importance change history (default: false)",
-                        "default": false
-                    },
-                    "include_merge_history": {
-                        "type": "boolean",
-                        "description": "Include merge lineage history (default: false)",
-                        "default": false
-                    },
-                    "depth_full": {
-                        "type": "boolean",
-                        "description": "Include all provenance data (audit, embedding, importance, merge history) (default: false)",
-                        "default": false
-                    }
-                },
-                "required": ["memory_id"],
-                "additionalProperties": false
-            }),
+        ),
+        ToolDefinition::with_params::<GetMemoryLineageParams>(
+            "get_memory_lineage",
+            "Trace the ancestry of a memory through merge_concepts and consolidation. Returns the lineage DAG (nodes with depth and edges with operation, similarity, and timestamp) up to max_depth, and whether each ancestor still exists as a live memory.",
+        ),
+        ToolDefinition::with_params::<QueryAuditLogParams>(
+            "query_audit_log",
+            "Query the execution history of mutating tools (store_memory, store_memories_batch, forget_concept, merge_concepts, boost_importance, trigger_consolidation, import_memories, export_memories). Each record has the tool, session, redacted arguments (content replaced by SHA-256 hashes), affected memory IDs, outcome, and duration. Newest first; page with offset and next_offset.",
         ),
     ]
 }
 
+/// Parameters of the `get_audit_trail` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetAuditTrailParams {
+    /// UUID of the memory to get audit trail for
+    pub target_id: Option<String>,
+
+    /// ISO 8601 timestamp for time range start (e.g., '2024-01-01T00:00:00Z')
+    pub start_time: Option<String>,
+
+    /// ISO 8601 timestamp for time range end
+    pub end_time: Option<String>,
+
+    /// Maximum records to return (default: 50, max: 500)
+    #[serde(default = "default_limit")]
+    pub limit: u64,
+}
+
+fn default_limit() -> u64 {
+    50
+}
+
+/// Parameters of the `get_merge_history` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetMergeHistoryParams {
+    /// UUID of the memory to get merge history for
+    pub memory_id: String,
+
+    /// Include source metadata for merged sources (default: false)
+    #[serde(default)]
+    pub include_source_metadata: bool,
+}
+
+/// Parameters of the `get_provenance_chain` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetProvenanceChainParams {
+    /// UUID of the memory to trace provenance for
+    pub memory_id: String,
+
+    /// Include audit trail in provenance chain (default: false)
+    #[serde(default)]
+    pub include_audit: bool,
+
+    /// Include embedding version info if available (default: false)
+    #[serde(default)]
+    pub include_embedding_version: bool,
+
+    /// Include importance change history (default: false)
+    #[serde(default)]
+    pub include_importance_history: bool,
+
+    /// Include merge lineage history (default: false)
+    #[serde(default)]
+    pub include_merge_history: bool,
+
+    /// Include all provenance data (audit, embedding, importance, merge history) (default: false)
+    #[serde(default)]
+    pub depth_full: bool,
+}
+
+/// Parameters of the `get_memory_lineage` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetMemoryLineageParams {
+    /// UUID of the memory to trace lineage for
+    pub memory_id: String,
+
+    /// Maximum ancestry depth to traverse (default: 5, max: 20)
+    #[serde(default = "default_max_depth")]
+    #[schemars(range(min = 1, max = 20))]
+    pub max_depth: u64,
+}
+
+fn default_max_depth() -> u64 {
+    5
+}
+
+/// Parameters of the `query_audit_log` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct QueryAuditLogParams {
+    /// ISO 8601 timestamp; only records at or after this time
+    pub start_time: Option<String>,
+
+    /// ISO 8601 timestamp; only records at or before this time
+    pub end_time: Option<String>,
+
+    /// Only records for this tool (e.g., 'forget_concept')
+    pub tool_name: Option<String>,
+
+    /// Only records from this session
+    pub session_id: Option<String>,
+
+    /// Only records that affected this memory UUID
+    pub memory_id: Option<String>,
+
+    /// Matching records to skip (default: 0)
+    #[serde(default)]
+    #[schemars(range(min = 0))]
+    pub offset: u64,
+
+    /// Maximum records to return (default: 50, max: 500)
+    #[serde(default = "default_limit")]
+    #[schemars(range(min = 1, max = 500))]
+    pub limit: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,7 +153,7 @@ mod tests {
     #[test]
     fn test_provenance_definitions_count() {
         let tools = definitions();
-        assert_eq!(tools.len(), 3, "Should have 3 provenance tools");
+        assert_eq!(tools.len(), 5, "Should have 5 provenance tools");
     }
 
     #[test]
@@ -0,0 +1,213 @@
+//! Structured query tool definitions.
+//!
+//! Tools:
+//! - query_memories: Boolean query over semantic, lexical, metadata,
+//!   time_range and topic clauses, executed as one plan
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::tools::types::ToolDefinition;
+
+/// Get all query tool definitions.
+///
+/// Returns 1 tool:
+/// - query_memories
+pub fn definitions() -> Vec<ToolDefinition> {
+    vec![query_memories_definition()]
+}
+
+/// Definition for query_memories tool.
+fn query_memories_definition() -> ToolDefinition {
+    ToolDefinition::with_params::<QueryMemoriesParams>(
+        "query_memories",
+        "Run a structured boolean query in one call instead of stitching SPLADE recall, semantic search and metadata filtering together. must: every clause matches; should: optional, at least one must match when must is empty; must_not: no clause matches. Clauses: semantic {text, spaces, minScore}, lexical {terms}, metadata {field, op, value}, time_range {start, end}, topic {id}. Candidates come from lexical/topic clauses first (intersected), else E1 recall of the first semantic must clause, else the union of the should clauses; survivors are filtered cheapest clause first, scored by the semantic and lexical clauses and fused by averaging. At most 16 clauses, 4 of them semantic or lexical. The response includes the executed plan with candidate counts per stage.",
+    )
+}
+
+/// Parameters of the `query_memories` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct QueryMemoriesParams {
+    /// Boolean query of must/should/must_not clause lists.
+    pub query: BooleanQuery,
+
+    /// Maximum number of results to return (1-100, default: 10).
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 100))]
+    pub top_k: u64,
+
+    /// Candidates drawn by each recall clause (1-2000, default: 500). The plan reports truncated
+    /// recall.
+    #[serde(default = "default_candidate_limit")]
+    #[schemars(range(min = 1, max = 2000))]
+    pub candidate_limit: u64,
+
+    /// Namespace to search (default: "default").
+    pub namespace: Option<String>,
+
+    /// Include full content text in results (default: false).
+    #[serde(default)]
+    pub include_content: bool,
+}
+
+fn default_top_k() -> u64 {
+    10
+}
+
+fn default_candidate_limit() -> u64 {
+    500
+}
+
+/// Boolean query of must/should/must_not clause lists.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BooleanQuery {
+    pub must: Option<Vec<QueryClause>>,
+
+    pub should: Option<Vec<QueryClause>>,
+
+    pub must_not: Option<Vec<QueryClause>>,
+}
+
+/// One clause: an object with exactly one of semantic, lexical, metadata, time_range or topic.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct QueryClause {
+    pub semantic: Option<SemanticClause>,
+
+    pub lexical: Option<LexicalClause>,
+
+    pub metadata: Option<MetadataClause>,
+
+    pub time_range: Option<TimeRangeClause>,
+
+    pub topic: Option<TopicClause>,
+}
+
+/// Semantic similarity clause.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SemanticClause {
+    /// Text to compare against.
+    pub text: String,
+
+    /// Embedder spaces to score in, averaged (default: ["E1"]).
+    pub spaces: Option<Vec<QuerySpace>>,
+
+    /// Minimum similarity for the clause to match (default: 0).
+    #[serde(default)]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_score: f64,
+}
+
+/// Embedder space a semantic clause scores in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum QuerySpace {
+    E1,
+    E6,
+    E7,
+    E8,
+    E9,
+    E10,
+    E11,
+    E12,
+    E13,
+}
+
+/// Lexical (E13 SPLADE term) clause.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LexicalClause {
+    /// Terms; a memory matches when it shares an E13 SPLADE term with them.
+    #[schemars(length(max = 32))]
+    pub terms: Vec<String>,
+}
+
+/// Metadata comparison clause.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MetadataClause {
+    /// Attribute to compare. domain is classified from the stored content (general, code, legal,
+    /// academic, creative).
+    pub field: MetadataField,
+
+    /// Comparison. gt/gte/lt/lte apply to importance and accessCount; in takes an array.
+    pub op: MetadataOp,
+
+    /// String, number, or array of strings for 'in'.
+    pub value: Value,
+}
+
+/// Memory attribute a metadata clause compares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum MetadataField {
+    Domain,
+    ContentType,
+    SourceType,
+    SessionId,
+    FilePath,
+    CreatedBy,
+    Namespace,
+    Importance,
+    AccessCount,
+}
+
+/// Comparison of a metadata clause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MetadataOp {
+    Eq,
+    Ne,
+    In,
+    Contains,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+/// Creation time window clause.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TimeRangeClause {
+    /// RFC 3339, inclusive.
+    pub start: String,
+
+    /// RFC 3339, exclusive.
+    pub end: String,
+}
+
+/// Topic membership clause.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TopicClause {
+    /// Topic UUID from get_topic_portfolio.
+    pub id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_definition_schema() {
+        assert_eq!(definitions().len(), 1);
+        let def = query_memories_definition();
+        assert_eq!(def.name, "query_memories");
+        let query = &def.input_schema["properties"]["query"];
+        for occur in ["must", "should", "must_not"] {
+            let clause = &query["properties"][occur]["items"]["properties"];
+            for kind in ["semantic", "lexical", "metadata", "time_range", "topic"] {
+                assert!(
+                    clause.get(kind).is_some(),
+                    "{} clause missing {}",
+                    occur,
+                    kind
+                );
+            }
+        }
+    }
+}
@@ -18,8 +18,10 @@
 //! Tools:
 //! - search_robust: Find memories matching query despite typos/variations
 
-use serde_json::json;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
+use super::common::SearchStrategy;
 use crate::tools::types::ToolDefinition;
 
 /// Get all robustness tool definitions.
@@ -32,79 +34,86 @@ pub fn definitions() -> Vec<ToolDefinition> {
 
 /// Definition for search_robust tool.
 fn search_robust_definition() -> ToolDefinition {
-    ToolDefinition::new(
+    ToolDefinition::with_params::<SearchRobustParams>(
         "search_robust",
         "Find memories using E9 noise-robust structural matching. ENHANCES E1 semantic search \
          with typo tolerance via character trigram hypervectors. E9 preserves similarity \
          despite spelling errors, casing variations, and morphological changes. Use for \
          'noisy queries (typos, variations)' per constitution. Example: query 'authetication' \
          finds 'authentication' via character overlap E1 would miss.",
-        json!({
-            "type": "object",
-            "properties": {
-                "query": {
-                    "type": "string",
-                    "description": "Query text (typos are OK - E9 is noise-tolerant). \
-                                    Minimum 3 characters for trigram encoding.",
-                    "minLength": 3
-                },
-                "topK": {
-                    "type": "integer",
-                    "default": 10,
-                    "minimum": 1,
-                    "maximum": 50,
-                    "description": "Maximum number of results to return (1-50, default: 10)."
-                },
-                "minScore": {
-                    "type": "number",
-                    "default": 0.1,
-                    "minimum": 0,
-                    "maximum": 1,
-                    "description": "Minimum blended score threshold (0-1, default: 0.1). \
-                                    Results below this are filtered."
-                },
-                "e9DiscoveryThreshold": {
-                    "type": "number",
-                    "default": 0.08,
-                    "minimum": 0,
-                    "maximum": 1,
-                    "description": "Minimum E9 score for a result to be marked as 'E9 discovery' \
-                                    (0-1, default: 0.08). Calibrated for projected E9 vectors \
-                                    (1024D cosine, not native Hamming). Results with E9 score >= \
-                                    this AND E1 score < e1WeaknessThreshold are blind spots E9 found."
-                },
-                "e1WeaknessThreshold": {
-                    "type": "number",
-                    "default": 0.5,
-                    "minimum": 0,
-                    "maximum": 1,
-                    "description": "Maximum E1 score for a result to be considered 'missed' by E1 \
-                                    (0-1, default: 0.5). If E1 score >= this, E1 would have found it."
-                },
-                "includeContent": {
-                    "type": "boolean",
-                    "default": false,
-                    "description": "Include full content text in results (default: false)."
-                },
-                "includeE9Score": {
-                    "type": "boolean",
-                    "default": true,
-                    "description": "Include separate E9 and E1 scores in results (default: true). \
-                                    Useful for understanding where E9 helped find matches E1 missed."
-                },
-                "strategy": {
-                    "type": "string",
-                    "enum": ["e1_only", "multi_space", "pipeline"],
-                    "default": "multi_space",
-                    "description": "Search strategy: 'e1_only' (E1 only), 'multi_space' (default, multi-embedder fusion), 'pipeline' (E13 recall -> E1 -> E12 rerank)."
-                }
-            },
-            "required": ["query"],
-            "additionalProperties": false
-        }),
     )
 }
 
+/// Parameters of the `search_robust` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SearchRobustParams {
+    /// Query text (typos are OK - E9 is noise-tolerant). Minimum 3 characters for trigram encoding.
+    #[schemars(length(min = 3))]
+    pub query: String,
+
+    /// Maximum number of results to return (1-50, default: 10).
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 50))]
+    pub top_k: u64,
+
+    /// Minimum blended score threshold (0-1, default: 0.1). Results below this are filtered.
+    #[serde(default = "default_min_score")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_score: f64,
+
+    /// Minimum E9 score for a result to be marked as 'E9 discovery' (0-1, default: 0.08).
+    /// Calibrated for projected E9 vectors (1024D cosine, not native Hamming). Results with E9
+    /// score >= this AND E1 score < e1WeaknessThreshold are blind spots E9 found.
+    #[serde(default = "default_e9_discovery_threshold")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e9_discovery_threshold: f64,
+
+    /// Maximum E1 score for a result to be considered 'missed' by E1 (0-1, default: 0.5). If E1
+    /// score >= this, E1 would have found it.
+    #[serde(default = "default_e1_weakness_threshold")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub e1_weakness_threshold: f64,
+
+    /// Include full content text in results (default: false).
+    #[serde(default)]
+    pub include_content: bool,
+
+    /// Include separate E9 and E1 scores in results (default: true). Useful for understanding where
+    /// E9 helped find matches E1 missed.
+    #[serde(default = "default_include_e9_score")]
+    pub include_e9_score: bool,
+
+    /// Search strategy: 'e1_only' (E1 only), 'multi_space' (default, multi-embedder fusion),
+    /// 'pipeline' (E13 recall -> E1 -> E12 rerank).
+    #[serde(default = "default_strategy")]
+    pub strategy: SearchStrategy,
+}
+
+fn default_top_k() -> u64 {
+    10
+}
+
+fn default_min_score() -> f64 {
+    0.1
+}
+
+fn default_e9_discovery_threshold() -> f64 {
+    0.08
+}
+
+fn default_e1_weakness_threshold() -> f64 {
+    0.5
+}
+
+fn default_include_e9_score() -> bool {
+    true
+}
+
+fn default_strategy() -> SearchStrategy {
+    SearchStrategy::MultiSpace
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
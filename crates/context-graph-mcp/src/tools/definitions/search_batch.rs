@@ -0,0 +1,89 @@
+//! Batched search tool definitions.
+//!
+//! Tools:
+//! - search_graph_batch: Several search_graph queries in one call, embedded
+//!   in one provider batch
+
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use super::core::SearchGraphParams;
+use crate::tools::types::ToolDefinition;
+
+/// Get all batched search tool definitions.
+///
+/// Returns 1 tool:
+/// - search_graph_batch
+pub fn definitions() -> Vec<ToolDefinition> {
+    vec![search_graph_batch_definition()]
+}
+
+/// Definition for search_graph_batch tool.
+fn search_graph_batch_definition() -> ToolDefinition {
+    ToolDefinition::with_params::<SearchGraphBatchParams>(
+        "search_graph_batch",
+        "Run several search_graph queries in one call. All query texts are embedded in a single \
+         provider batch, then each query is searched exactly as search_graph would. Shared \
+         options apply to every query; a query object may override any of them. Returns one \
+         entry per query, in order: the search_graph response, or an error object when that \
+         query failed (other queries still run). At most 20 queries, and the topK values may \
+         sum to at most 500. Timing for the embedding batch and the searches is reported once.",
+    )
+}
+
+/// Parameters of the `search_graph_batch` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SearchGraphBatchParams {
+    /// Query objects with the search_graph arguments (1-20). Fields given here override
+    /// options.
+    #[schemars(length(min = 1, max = 20))]
+    pub queries: Vec<SearchGraphParams>,
+
+    /// search_graph options shared by every query (everything but query).
+    pub options: Option<SearchGraphOptions>,
+}
+
+/// search_graph arguments without `query`, merged into every query object of the batch.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct SearchGraphOptions(pub Map<String, Value>);
+
+impl JsonSchema for SearchGraphOptions {
+    fn schema_name() -> String {
+        "SearchGraphOptions".to_string()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let mut schema = SearchGraphParams::json_schema(generator).into_object();
+        schema.metadata = None;
+        let object = schema.object();
+        object.properties.remove("query");
+        object.required.remove("query");
+        schema.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_definition_schema() {
+        assert_eq!(definitions().len(), 1);
+        let def = search_graph_batch_definition();
+        assert_eq!(def.name, "search_graph_batch");
+        let properties = &def.input_schema["properties"];
+        let item = &properties["queries"]["items"];
+        assert_eq!(item["required"], json!(["query"]));
+        assert!(item["properties"].get("topK").is_some());
+        let options = &properties["options"]["properties"];
+        assert!(options.get("query").is_none());
+        assert!(options.get("topK").is_some());
+        assert!(options.get("strategy").is_some());
+    }
+}
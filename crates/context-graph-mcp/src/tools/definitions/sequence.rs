@@ -13,177 +13,223 @@
 //! - TG-RAG: Bi-level temporal graph
 //! - Episodic Memory for RAG: Space-time-anchored narratives
 
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{InstanceType, Schema, SchemaObject};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
 use crate::tools::types::ToolDefinition;
-use serde_json::json;
 
 /// Returns sequence tool definitions (4 tools per Phase 1 plan).
 pub fn definitions() -> Vec<ToolDefinition> {
     vec![
         // get_conversation_context - auto-anchored context retrieval
-        ToolDefinition::new(
+        ToolDefinition::with_params::<GetConversationContextParams>(
             "get_conversation_context",
             "Get memories around the current conversation turn with auto-anchoring. \
              Uses E4 (V_ordering) for sequence-based retrieval. \
              Perfect for \"What did we discuss before X?\" queries.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "direction": {
-                        "type": "string",
-                        "enum": ["before", "after", "both"],
-                        "default": "before",
-                        "description": "Direction to search: before (earlier turns), after (later turns), both"
-                    },
-                    "windowSize": {
-                        "type": "integer",
-                        "minimum": 1,
-                        "maximum": 50,
-                        "default": 10,
-                        "description": "Number of turns to include in the window"
-                    },
-                    "sessionOnly": {
-                        "type": "boolean",
-                        "default": true,
-                        "description": "Only include memories from current session"
-                    },
-                    "includeContent": {
-                        "type": "boolean",
-                        "default": true,
-                        "description": "Include full content text in results"
-                    },
-                    "query": {
-                        "type": "string",
-                        "description": "Optional semantic filter to apply alongside sequence ordering"
-                    },
-                    "minSimilarity": {
-                        "type": "number",
-                        "minimum": 0,
-                        "maximum": 1,
-                        "default": 0.0,
-                        "description": "Minimum similarity threshold when query is provided"
-                    }
-                },
-                "required": [],
-                "additionalProperties": false
-            }),
         ),
         // get_session_timeline - ordered timeline view
-        ToolDefinition::new(
+        ToolDefinition::with_params::<GetSessionTimelineParams>(
             "get_session_timeline",
             "Get an ordered timeline of all session memories with sequence numbers. \
              Returns memories sorted by session_sequence in ascending order. \
              Includes position labels like \"2 turns ago\", \"previous turn\".",
-            json!({
-                "type": "object",
-                "properties": {
-                    "sessionId": {
-                        "type": "string",
-                        "description": "Session ID to get timeline for (default: current session)"
-                    },
-                    "limit": {
-                        "type": "integer",
-                        "minimum": 1,
-                        "maximum": 200,
-                        "default": 50,
-                        "description": "Maximum number of memories to return"
-                    },
-                    "offset": {
-                        "type": "integer",
-                        "minimum": 0,
-                        "default": 0,
-                        "description": "Number of memories to skip for pagination"
-                    },
-                    "sourceTypes": {
-                        "type": "array",
-                        "items": {
-                            "type": "string",
-                            "enum": ["HookDescription", "ClaudeResponse", "Manual", "MDFileChunk"]
-                        },
-                        "description": "Filter by source types (default: all types)"
-                    },
-                    "includeContent": {
-                        "type": "boolean",
-                        "default": false,
-                        "description": "Include full content text in results"
-                    }
-                },
-                "required": [],
-                "additionalProperties": false
-            }),
         ),
         // traverse_memory_chain - multi-hop navigation
-        ToolDefinition::new(
+        ToolDefinition::with_params::<TraverseMemoryChainParams>(
             "traverse_memory_chain",
             "Navigate through a chain of memories starting from an anchor point. \
              Supports multi-hop traversal for understanding conversation flow. \
              Useful for tracing the evolution of a topic across turns.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "anchorId": {
-                        "type": "string",
-                        "format": "uuid",
-                        "description": "UUID of the starting memory (anchor point)"
-                    },
-                    "direction": {
-                        "type": "string",
-                        "enum": ["forward", "backward", "bidirectional"],
-                        "default": "backward",
-                        "description": "Direction to traverse: forward (later), backward (earlier), bidirectional"
-                    },
-                    "hops": {
-                        "type": "integer",
-                        "minimum": 1,
-                        "maximum": 20,
-                        "default": 5,
-                        "description": "Maximum number of hops to traverse"
-                    },
-                    "semanticFilter": {
-                        "type": "string",
-                        "description": "Optional topic filter to only traverse related memories"
-                    },
-                    "minSimilarity": {
-                        "type": "number",
-                        "minimum": 0,
-                        "maximum": 1,
-                        "default": 0.3,
-                        "description": "Minimum semantic similarity for filtered traversal"
-                    },
-                    "includeContent": {
-                        "type": "boolean",
-                        "default": true,
-                        "description": "Include full content text in results"
-                    }
-                },
-                "required": ["anchorId"],
-                "additionalProperties": false
-            }),
         ),
         // compare_session_states - before/after analysis
-        ToolDefinition::new(
+        ToolDefinition::with_params::<CompareSessionStatesParams>(
             "compare_session_states",
             "Compare memory state at different sequence points in a session. \
              Useful for understanding what changed between two points in the conversation. \
              Returns topics, memory counts, and key differences.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "beforeSequence": {
-                        "type": ["integer", "string"],
-                        "description": "Starting sequence number or \"start\" for session beginning"
-                    },
-                    "afterSequence": {
-                        "type": ["integer", "string"],
-                        "description": "Ending sequence number or \"current\" for current position"
-                    },
-                    "sessionId": {
-                        "type": "string",
-                        "description": "Session ID to compare within (default: current session)"
-                    }
-                },
-                "required": ["beforeSequence", "afterSequence"],
-                "additionalProperties": false
-            }),
         ),
     ]
 }
+
+/// Parameters of the `get_conversation_context` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GetConversationContextParams {
+    /// Direction to search: before (earlier turns), after (later turns), both
+    #[serde(default = "default_direction")]
+    pub direction: ContextDirection,
+
+    /// Number of turns to include in the window
+    #[serde(default = "default_window_size")]
+    #[schemars(range(min = 1, max = 50))]
+    pub window_size: u64,
+
+    /// Only include memories from current session
+    #[serde(default = "default_session_only")]
+    pub session_only: bool,
+
+    /// Include full content text in results
+    #[serde(default = "default_include_content")]
+    pub include_content: bool,
+
+    /// Optional semantic filter to apply alongside sequence ordering
+    pub query: Option<String>,
+
+    /// Minimum similarity threshold when query is provided
+    #[serde(default)]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_similarity: f64,
+}
+
+fn default_direction() -> ContextDirection {
+    ContextDirection::Before
+}
+
+fn default_window_size() -> u64 {
+    10
+}
+
+fn default_session_only() -> bool {
+    true
+}
+
+fn default_include_content() -> bool {
+    true
+}
+
+/// Which side of the current turn to return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContextDirection {
+    Before,
+    After,
+    Both,
+}
+
+/// Parameters of the `get_session_timeline` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GetSessionTimelineParams {
+    /// Session ID to get timeline for (default: current session)
+    pub session_id: Option<String>,
+
+    /// Maximum number of memories to return
+    #[serde(default = "default_limit")]
+    #[schemars(range(min = 1, max = 200))]
+    pub limit: u64,
+
+    /// Number of memories to skip for pagination
+    #[serde(default)]
+    #[schemars(range(min = 0))]
+    pub offset: u64,
+
+    /// Filter by source types (default: all types)
+    pub source_types: Option<Vec<SourceType>>,
+
+    /// Include full content text in results
+    #[serde(default)]
+    pub include_content: bool,
+}
+
+fn default_limit() -> u64 {
+    50
+}
+
+/// Source a memory was captured from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum SourceType {
+    HookDescription,
+    ClaudeResponse,
+    Manual,
+    MDFileChunk,
+}
+
+/// Parameters of the `traverse_memory_chain` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct TraverseMemoryChainParams {
+    /// UUID of the starting memory (anchor point)
+    pub anchor_id: Uuid,
+
+    /// Direction to traverse: forward (later), backward (earlier), bidirectional
+    #[serde(default = "default_traverse_memory_chain_direction")]
+    pub direction: TraversalDirection,
+
+    /// Maximum number of hops to traverse
+    #[serde(default = "default_hops")]
+    #[schemars(range(min = 1, max = 20))]
+    pub hops: u64,
+
+    /// Optional topic filter to only traverse related memories
+    pub semantic_filter: Option<String>,
+
+    /// Minimum semantic similarity for filtered traversal
+    #[serde(default = "default_min_similarity")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_similarity: f64,
+
+    /// Include full content text in results
+    #[serde(default = "default_include_content")]
+    pub include_content: bool,
+}
+
+fn default_traverse_memory_chain_direction() -> TraversalDirection {
+    TraversalDirection::Backward
+}
+
+fn default_hops() -> u64 {
+    5
+}
+
+fn default_min_similarity() -> f64 {
+    0.3
+}
+
+/// Direction to traverse the memory chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TraversalDirection {
+    Forward,
+    Backward,
+    Bidirectional,
+}
+
+/// Parameters of the `compare_session_states` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CompareSessionStatesParams {
+    /// Starting sequence number or "start" for session beginning
+    pub before_sequence: SequencePosition,
+
+    /// Ending sequence number or "current" for current position
+    pub after_sequence: SequencePosition,
+
+    /// Session ID to compare within (default: current session)
+    pub session_id: Option<String>,
+}
+
+/// Session position: a sequence number, or a keyword such as "start" or "current".
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum SequencePosition {
+    Number(i64),
+    Keyword(String),
+}
+
+impl JsonSchema for SequencePosition {
+    fn schema_name() -> String {
+        "SequencePosition".to_string()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(vec![InstanceType::Integer, InstanceType::String].into()),
+            ..Default::default()
+        }
+        .into()
+    }
+}
@@ -0,0 +1,102 @@
+//! Snapshot tool definitions for portable memory export and import.
+//!
+//! Tools:
+//! - export_memories: Write memories to a snapshot directory (.cgeb/.cgei + manifest)
+//! - import_memories: Load a snapshot into the store with a conflict policy
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::tools::types::ToolDefinition;
+
+/// Returns snapshot tool definitions (2 tools).
+pub fn definitions() -> Vec<ToolDefinition> {
+    vec![
+        // export_memories
+        ToolDefinition::with_params::<ExportMemoriesParams>(
+            "export_memories",
+            "Export memories to a snapshot directory: manifest.json (format version, counts per \
+             namespace, embedding models, filter), memories.cgeb (fingerprints with content, \
+             source metadata and embedding versions) and memories.cgei (record index). \
+             Filters combine with AND: namespace, creation time range, and topic membership. \
+             Audit history is not exported. Fails if the directory already holds a snapshot.",
+        ),
+        // import_memories
+        ToolDefinition::with_params::<ImportMemoriesParams>(
+            "import_memories",
+            "Import a snapshot written by export_memories. Each memory is stored through the \
+             normal write path, rebuilding HNSW, SPLADE inverted and content-hash indexes, and \
+             queued for K-NN graph building. Existing IDs are handled by conflictPolicy: skip \
+             (keep existing), overwrite (replace), or re-id (store under a new ID). Returns \
+             counts per outcome and whether the snapshot's embedding models differ from the \
+             local ones. Run detect_topics with force=true afterwards to assign topics.",
+        ),
+    ]
+}
+
+/// Parameters of the `export_memories` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ExportMemoriesParams {
+    /// Snapshot directory to create
+    pub path: String,
+
+    /// Only export this namespace (default: all namespaces)
+    pub namespace: Option<String>,
+
+    /// Only export memories created at or after this time (RFC 3339)
+    pub created_after: Option<DateTime<Utc>>,
+
+    /// Only export memories created before this time (RFC 3339)
+    pub created_before: Option<DateTime<Utc>>,
+
+    /// Only export members of this topic (from get_topic_portfolio)
+    pub topic_id: Option<Uuid>,
+}
+
+/// Parameters of the `import_memories` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ImportMemoriesParams {
+    /// Snapshot directory to import
+    pub path: String,
+
+    /// What to do when a memory ID already exists
+    #[serde(default = "default_conflict_policy")]
+    pub conflict_policy: ConflictPolicy,
+}
+
+fn default_conflict_policy() -> ConflictPolicy {
+    ConflictPolicy::Skip
+}
+
+/// What import does with an ID that already exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConflictPolicy {
+    Skip,
+    Overwrite,
+    ReId,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_snapshot_definitions() {
+        let tools = definitions();
+        assert_eq!(tools.len(), 2);
+        let export = tools.iter().find(|t| t.name == "export_memories").unwrap();
+        let required = export.input_schema["required"].as_array().unwrap();
+        assert_eq!(required, &vec![json!("path")]);
+        let import = tools.iter().find(|t| t.name == "import_memories").unwrap();
+        let policies = import.input_schema["properties"]["conflictPolicy"]["enum"]
+            .as_array()
+            .unwrap();
+        assert_eq!(policies.len(), 3);
+    }
+}
@@ -0,0 +1,94 @@
+//! Staging tool definitions for session-scoped memory staging.
+//!
+//! Tools:
+//! - promote_staged: Move staged memories into the default namespace
+//! - end_staged_session: Apply a SessionEnd policy to a session's staged memories
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::tools::types::ToolDefinition;
+
+/// Returns staging tool definitions (2 tools).
+pub fn definitions() -> Vec<ToolDefinition> {
+    vec![
+        // promote_staged
+        ToolDefinition::with_params::<PromoteStagedParams>(
+            "promote_staged",
+            "Promote staged memories of a session (stored with store_memory staged=true) into \
+             the default namespace, where regular searches find them. Pass either ids or \
+             all=true. IDs not staged in the session are reported as failures.",
+        ),
+        // end_staged_session
+        ToolDefinition::with_params::<EndStagedSessionParams>(
+            "end_staged_session",
+            "Resolve every memory still staged for a session, typically at SessionEnd. \
+             promote_all moves them all to the default namespace, discard_all soft-deletes \
+             them, promote_above_importance promotes those with importance >= threshold and \
+             discards the rest.",
+        ),
+    ]
+}
+
+/// Parameters of the `promote_staged` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PromoteStagedParams {
+    /// Session whose staging namespace to promote from
+    pub session_id: String,
+
+    /// Staged memory IDs to promote
+    #[schemars(length(min = 1))]
+    pub ids: Option<Vec<Uuid>>,
+
+    /// Promote every staged memory of the session
+    #[serde(default)]
+    pub all: bool,
+}
+
+/// Parameters of the `end_staged_session` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EndStagedSessionParams {
+    /// Session whose staged memories to resolve
+    pub session_id: String,
+
+    /// What to do with the staged memories
+    pub policy: StagedSessionPolicy,
+
+    /// Importance threshold (promote_above_importance only)
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub threshold: Option<f64>,
+}
+
+/// What happens to a session's staged memories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StagedSessionPolicy {
+    PromoteAll,
+    DiscardAll,
+    PromoteAboveImportance,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_staging_definitions() {
+        let tools = definitions();
+        assert_eq!(tools.len(), 2);
+        let end = tools
+            .iter()
+            .find(|t| t.name == "end_staged_session")
+            .unwrap();
+        let required = end.input_schema["required"].as_array().unwrap();
+        assert_eq!(required, &vec![json!("policy"), json!("sessionId")]);
+        let policies = end.input_schema["properties"]["policy"]["enum"]
+            .as_array()
+            .unwrap();
+        assert_eq!(policies.len(), 3);
+    }
+}
@@ -8,132 +8,137 @@
 //! - search_recent: Search with E2 temporal boost applied
 //! - search_periodic: Search with E3 periodic pattern boost applied
 
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use super::common::{DecayFunction, TemporalScale};
 use crate::tools::types::ToolDefinition;
-use serde_json::json;
 
 /// Returns temporal tool definitions.
 pub fn definitions() -> Vec<ToolDefinition> {
     vec![
         // search_recent - temporal search with E2 freshness boost
-        ToolDefinition::new(
+        ToolDefinition::with_params::<SearchRecentParams>(
             "search_recent",
             "Search for recent memories with E2 temporal boost applied. \
              Automatically applies freshness decay to prioritize recent results. \
              Use for queries like 'what did we discuss recently', 'latest updates', \
              'yesterday's conversation', or any time-sensitive retrieval. \
              Per ARCH-25: Temporal boost is applied POST-retrieval, not in similarity fusion.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "query": {
-                        "type": "string",
-                        "description": "The search query text"
-                    },
-                    "topK": {
-                        "type": "integer",
-                        "minimum": 1,
-                        "maximum": 100,
-                        "default": 10,
-                        "description": "Maximum number of results to return"
-                    },
-                    "temporalWeight": {
-                        "type": "number",
-                        "minimum": 0.1,
-                        "maximum": 1.0,
-                        "default": 0.3,
-                        "description": "Temporal boost weight [0.1, 1.0]. Higher = more recency preference. Default: 0.3"
-                    },
-                    "decayFunction": {
-                        "type": "string",
-                        "enum": ["linear", "exponential", "step", "none", "no_decay"],
-                        "default": "exponential",
-                        "description": "Decay function for freshness. 'exponential' (default) = natural forgetting curve, 'linear' = simple decay, 'step' = time bucket based, 'none'/'no_decay' = no decay applied"
-                    },
-                    "temporalScale": {
-                        "type": "string",
-                        "enum": ["micro", "meso", "macro", "long", "archival"],
-                        "default": "meso",
-                        "description": "Time scale for decay. 'micro' = 1 hour horizon, 'meso' = 1 day (default), 'macro' = 1 week, 'long' = 1 month, 'archival' = 1 year"
-                    },
-                    "includeContent": {
-                        "type": "boolean",
-                        "default": true,
-                        "description": "Include content text in results (default: true)"
-                    },
-                    "minSimilarity": {
-                        "type": "number",
-                        "minimum": 0,
-                        "maximum": 1,
-                        "default": 0.1,
-                        "description": "Minimum semantic similarity threshold before temporal boost"
-                    }
-                },
-                "required": ["query"],
-                "additionalProperties": false
-            }),
         ),
         // search_periodic - temporal search with E3 periodic pattern boost
-        ToolDefinition::new(
+        ToolDefinition::with_params::<SearchPeriodicParams>(
             "search_periodic",
             "Search for memories matching periodic time patterns (E3 V_periodicity). \
              Finds memories from similar times of day or days of week. \
              Use for queries like 'morning meetings', 'Friday deployments', \
              'what do I usually work on at 3pm?', or finding routine patterns. \
              Per ARCH-25: Periodic boost is applied POST-retrieval, not in similarity fusion.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "query": {
-                        "type": "string",
-                        "description": "The search query text"
-                    },
-                    "topK": {
-                        "type": "integer",
-                        "minimum": 1,
-                        "maximum": 100,
-                        "default": 10,
-                        "description": "Maximum number of results to return"
-                    },
-                    "targetHour": {
-                        "type": "integer",
-                        "minimum": 0,
-                        "maximum": 23,
-                        "description": "Target hour (0-23). If omitted and autoDetect=true, uses current hour."
-                    },
-                    "targetDayOfWeek": {
-                        "type": "integer",
-                        "minimum": 0,
-                        "maximum": 6,
-                        "description": "Target day (0=Sunday, 6=Saturday). If omitted and autoDetect=true, uses current day."
-                    },
-                    "autoDetect": {
-                        "type": "boolean",
-                        "default": false,
-                        "description": "Auto-detect target from current time. When true, targetHour/targetDayOfWeek are computed from now."
-                    },
-                    "periodicWeight": {
-                        "type": "number",
-                        "minimum": 0.1,
-                        "maximum": 1.0,
-                        "default": 0.3,
-                        "description": "Weight for periodic boost [0.1, 1.0]. Higher = more periodic preference. Default: 0.3"
-                    },
-                    "includeContent": {
-                        "type": "boolean",
-                        "default": true,
-                        "description": "Include content text in results (default: true)"
-                    },
-                    "minSimilarity": {
-                        "type": "number",
-                        "minimum": 0,
-                        "maximum": 1,
-                        "default": 0.1,
-                        "description": "Minimum semantic similarity threshold before periodic boost"
-                    }
-                },
-                "required": ["query"],
-                "additionalProperties": false
-            }),
         ),
     ]
 }
+
+/// Parameters of the `search_recent` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SearchRecentParams {
+    /// The search query text
+    pub query: String,
+
+    /// Maximum number of results to return
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 100))]
+    pub top_k: u64,
+
+    /// Temporal boost weight [0.1, 1.0]. Higher = more recency preference. Default: 0.3
+    #[serde(default = "default_temporal_weight")]
+    #[schemars(range(min = 0.1, max = 1.0))]
+    pub temporal_weight: f64,
+
+    /// Decay function for freshness. 'exponential' (default) = natural forgetting curve, 'linear' =
+    /// simple decay, 'step' = time bucket based, 'none'/'no_decay' = no decay applied
+    #[serde(default = "default_decay_function")]
+    pub decay_function: DecayFunction,
+
+    /// Time scale for decay. 'micro' = 1 hour horizon, 'meso' = 1 day (default), 'macro' = 1 week,
+    /// 'long' = 1 month, 'archival' = 1 year
+    #[serde(default = "default_temporal_scale")]
+    pub temporal_scale: TemporalScale,
+
+    /// Include content text in results (default: true)
+    #[serde(default = "default_include_content")]
+    pub include_content: bool,
+
+    /// Minimum semantic similarity threshold before temporal boost
+    #[serde(default = "default_min_similarity")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_similarity: f64,
+}
+
+fn default_top_k() -> u64 {
+    10
+}
+
+fn default_temporal_weight() -> f64 {
+    0.3
+}
+
+fn default_decay_function() -> DecayFunction {
+    DecayFunction::Exponential
+}
+
+fn default_temporal_scale() -> TemporalScale {
+    TemporalScale::Meso
+}
+
+fn default_include_content() -> bool {
+    true
+}
+
+fn default_min_similarity() -> f64 {
+    0.1
+}
+
+/// Parameters of the `search_periodic` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SearchPeriodicParams {
+    /// The search query text
+    pub query: String,
+
+    /// Maximum number of results to return
+    #[serde(default = "default_top_k")]
+    #[schemars(range(min = 1, max = 100))]
+    pub top_k: u64,
+
+    /// Target hour (0-23). If omitted and autoDetect=true, uses current hour.
+    #[schemars(range(min = 0, max = 23))]
+    pub target_hour: Option<u64>,
+
+    /// Target day (0=Sunday, 6=Saturday). If omitted and autoDetect=true, uses current day.
+    #[schemars(range(min = 0, max = 6))]
+    pub target_day_of_week: Option<u64>,
+
+    /// Auto-detect target from current time. When true, targetHour/targetDayOfWeek are computed
+    /// from now.
+    #[serde(default)]
+    pub auto_detect: bool,
+
+    /// Weight for periodic boost [0.1, 1.0]. Higher = more periodic preference. Default: 0.3
+    #[serde(default = "default_periodic_weight")]
+    #[schemars(range(min = 0.1, max = 1.0))]
+    pub periodic_weight: f64,
+
+    /// Include content text in results (default: true)
+    #[serde(default = "default_include_content")]
+    pub include_content: bool,
+
+    /// Minimum semantic similarity threshold before periodic boost
+    #[serde(default = "default_min_similarity")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_similarity: f64,
+}
+
+fn default_periodic_weight() -> f64 {
+    0.3
+}
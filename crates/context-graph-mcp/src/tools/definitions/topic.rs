@@ -5,102 +5,184 @@
 //! - get_topic_stability: Get portfolio-level stability metrics
 //! - detect_topics: Force topic detection recalculation
 //! - get_divergence_alerts: Check for divergence from recent activity
+//! - acknowledge_divergence_alert: Acknowledge a cross-space divergence alert
 //!
 //! Constitution Compliance:
 //! - ARCH-09: Topic threshold is weighted_agreement >= 2.5
 //! - AP-60: Temporal embedders (E2-E4) weight = 0.0 in topic detection
 
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
 use crate::tools::types::ToolDefinition;
-use serde_json::json;
 
 /// Returns topic tool definitions (4 tools per PRD).
 pub fn definitions() -> Vec<ToolDefinition> {
     vec![
         // get_topic_portfolio
-        ToolDefinition::new(
+        ToolDefinition::with_params::<GetTopicPortfolioParams>(
             "get_topic_portfolio",
-            "Get all discovered topics with profiles, stability metrics, and tier info. \
-             Topics emerge from weighted multi-space clustering (threshold >= 2.5). \
+            "Get discovered topics with profiles, stability metrics, and tier info. \
+             Topics emerge from weighted multi-space clustering (threshold >= 2.5) and are \
+             stored between detection runs; a topic keeps its ID while its membership drifts. \
              Temporal embedders (E2-E4) are excluded from topic detection.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "format": {
-                        "type": "string",
-                        "enum": ["brief", "standard", "verbose"],
-                        "default": "standard",
-                        "description": "Output format: brief (names only), standard (with spaces), verbose (full profiles)"
-                    }
-                },
-                "additionalProperties": false
-            }),
         ),
         // get_topic_stability
-        ToolDefinition::new(
+        ToolDefinition::with_params::<GetTopicStabilityParams>(
             "get_topic_stability",
-            "Get portfolio-level stability metrics including churn rate, entropy, and phase breakdown.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "hours": {
-                        "type": "integer",
-                        "minimum": 1,
-                        "maximum": 168,
-                        "default": 6,
-                        "description": "Lookback period in hours for computing averages"
-                    }
-                },
-                "additionalProperties": false
-            }),
+            "Get portfolio-level stability metrics including churn rate, entropy, phase breakdown, \
+             and per-topic churn/drift/stability series across recent detection runs.",
         ),
         // detect_topics
-        ToolDefinition::new(
+        ToolDefinition::with_params::<DetectTopicsParams>(
             "detect_topics",
             "Force topic detection recalculation using HDBSCAN clustering. \
              Requires minimum 3 memories (per clustering.parameters.min_cluster_size). \
-             Topics require weighted_agreement >= 2.5 to be recognized.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "force": {
-                        "type": "boolean",
-                        "default": false,
-                        "description": "Force detection even if recently computed"
-                    },
-                    "max_memories": {
-                        "type": "integer",
-                        "minimum": 1,
-                        "maximum": 50000,
-                        "default": 10000,
-                        "description": "Maximum fingerprints to load for clustering (default: 10000, max: 50000)"
-                    }
-                },
-                "additionalProperties": false
-            }),
+             Topics require weighted_agreement >= 2.5 to be recognized. \
+             Topics are labeled from their members' top keyword terms, and relabeled when \
+             their membership changes substantially. \
+             Sends progress notifications when _meta.progressToken is set.",
         ),
         // get_divergence_alerts
-        ToolDefinition::new(
+        ToolDefinition::with_params::<GetDivergenceAlertsParams>(
             "get_divergence_alerts",
             "Check for divergence from recent activity using SEMANTIC embedders only \
              (E1, E6, E7, E10, E12, E13 per AP-62). E5 (Causal) is excluded per AP-77 \
-             (returns 0.0 without CausalDirection). Temporal embedders (E2-E4) excluded per AP-63.",
-            json!({
-                "type": "object",
-                "properties": {
-                    "lookback_hours": {
-                        "type": "integer",
-                        "minimum": 1,
-                        "maximum": 48,
-                        "default": 2,
-                        "description": "Hours to look back for recent activity comparison"
-                    }
-                },
-                "additionalProperties": false
-            }),
+             (returns 0.0 without CausalDirection). Temporal embedders (E2-E4) excluded per AP-63. \
+             Also scans for cross-space divergence (memories whose nearest neighbors disagree \
+             between E1 and E7/E10/E13) and pages unacknowledged cross-space alerts.",
+        ),
+        // acknowledge_divergence_alert
+        ToolDefinition::with_params::<AcknowledgeDivergenceAlertParams>(
+            "acknowledge_divergence_alert",
+            "Acknowledge a cross-space divergence alert returned by get_divergence_alerts \
+             so it no longer appears in the unacknowledged list.",
         ),
     ]
 }
 
+/// Parameters of the `get_topic_portfolio` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetTopicPortfolioParams {
+    /// Output format: brief (names only), standard (with spaces), verbose (full profiles)
+    #[serde(default = "default_format")]
+    pub format: PortfolioFormat,
+
+    /// Order: size (most members), recency (newest topics), stability (highest stability score)
+    #[serde(default = "default_sort_by")]
+    pub sort_by: PortfolioSort,
+
+    /// Offset into the sorted topics
+    #[serde(default)]
+    #[schemars(range(min = 0))]
+    pub offset: u64,
+
+    /// Maximum topics to return
+    #[serde(default = "default_limit")]
+    #[schemars(range(min = 1, max = 500))]
+    pub limit: u64,
+}
+
+fn default_format() -> PortfolioFormat {
+    PortfolioFormat::Standard
+}
+
+fn default_sort_by() -> PortfolioSort {
+    PortfolioSort::Size
+}
+
+fn default_limit() -> u64 {
+    50
+}
+
+/// Detail level of the portfolio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PortfolioFormat {
+    Brief,
+    Standard,
+    Verbose,
+}
+
+/// Order of the returned topics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PortfolioSort {
+    Size,
+    Recency,
+    Stability,
+}
+
+/// Parameters of the `get_topic_stability` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetTopicStabilityParams {
+    /// Lookback period in hours for computing averages
+    #[serde(default = "default_hours")]
+    #[schemars(range(min = 1, max = 168))]
+    pub hours: u64,
+}
+
+fn default_hours() -> u64 {
+    6
+}
+
+/// Parameters of the `detect_topics` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DetectTopicsParams {
+    /// Force detection even if recently computed
+    #[serde(default)]
+    pub force: bool,
+
+    /// Maximum fingerprints to load for clustering (default: 10000, max: 50000)
+    #[serde(default = "default_max_memories")]
+    #[schemars(range(min = 1, max = 50000))]
+    pub max_memories: u64,
+}
+
+fn default_max_memories() -> u64 {
+    10000
+}
+
+/// Parameters of the `get_divergence_alerts` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDivergenceAlertsParams {
+    /// Hours to look back for recent activity comparison
+    #[serde(default = "default_lookback_hours")]
+    #[schemars(range(min = 1, max = 48))]
+    pub lookback_hours: u64,
+
+    /// Offset into unacknowledged cross-space alerts (newest first)
+    #[serde(default)]
+    #[schemars(range(min = 0))]
+    pub offset: u64,
+
+    /// Maximum cross-space alerts to return
+    #[serde(default = "default_get_divergence_alerts_limit")]
+    #[schemars(range(min = 1, max = 100))]
+    pub limit: u64,
+}
+
+fn default_lookback_hours() -> u64 {
+    2
+}
+
+fn default_get_divergence_alerts_limit() -> u64 {
+    20
+}
+
+/// Parameters of the `acknowledge_divergence_alert` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AcknowledgeDivergenceAlertParams {
+    /// ID of the cross-space alert to acknowledge
+    pub alert_id: Uuid,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,12 +190,13 @@ mod tests {
     #[test]
     fn test_definitions_exist_with_required_fields() {
         let tools = definitions();
-        assert_eq!(tools.len(), 4);
+        assert_eq!(tools.len(), 5);
         let names: Vec<&str> = tools.iter().map(|t| t.name.as_str()).collect();
         assert!(names.contains(&"get_topic_portfolio"));
         assert!(names.contains(&"get_topic_stability"));
         assert!(names.contains(&"detect_topics"));
         assert!(names.contains(&"get_divergence_alerts"));
+        assert!(names.contains(&"acknowledge_divergence_alert"));
         // Key schema checks
         let portfolio = tools.iter().find(|t| t.name == "get_topic_portfolio").unwrap();
         assert!(portfolio.description.contains("2.5"));
@@ -133,8 +216,8 @@ mod tests {
         // Stability hours range
         let stability = tools.iter().find(|t| t.name == "get_topic_stability").unwrap();
         let hours = &stability.input_schema["properties"]["hours"];
-        assert_eq!(hours["minimum"], 1);
-        assert_eq!(hours["maximum"], 168);
+        assert_eq!(hours["minimum"].as_f64(), Some(1.0));
+        assert_eq!(hours["maximum"].as_f64(), Some(168.0));
         // detect_topics force default
         let detect = tools.iter().find(|t| t.name == "detect_topics").unwrap();
         assert!(!detect.input_schema["properties"]["force"]["default"].as_bool().unwrap());
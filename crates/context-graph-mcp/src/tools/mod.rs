@@ -5,13 +5,16 @@
 //!
 //! # Module Structure
 //!
-//! - `types`: Core type definitions (`ToolDefinition`)
+//! - `types`: Core type definitions (`ToolDefinition`) and schema derivation from param structs
 //! - `names`: Tool name constants for dispatch matching
 //! - `registry`: Centralized tool registry with O(1) lookup
-//! - `definitions`: Tool definitions organized by category
-//!   - `core`: Core tools (store_memory, search_graph, get_memetic_status)
-//!   - `topic`: Topic tools (get_topic_portfolio, get_topic_stability, detect_topics, get_divergence_alerts)
+//! - `validation`: Checks `tools/call` arguments against the published input schemas
+//! - `definitions`: Tool definitions and their param structs, organized by category
+//!   - `common`: Param types shared by several categories (enums, `EmbedderWeights`)
+//!   - `core`: Core tools (store_memory, store_memories_batch, search_graph, get_memetic_status)
+//!   - `topic`: Topic tools (get_topic_portfolio, get_topic_stability, detect_topics, get_divergence_alerts, acknowledge_divergence_alert)
 //!   - `curation`: Curation tools (merge_concepts, forget_concept, boost_importance)
+//!   - `search_batch`: Batched search (search_graph_batch)
 //!
 //! Note: inject_context was merged into store_memory. When rationale is provided,
 //! the same validation (1-1024 chars) and response format is used.
@@ -20,6 +23,7 @@ pub mod aliases;
 pub mod definitions;
 pub mod names;
 pub mod types;
+pub mod validation;
 
 pub use self::definitions::get_tool_definitions;
 pub use self::names as tool_names;
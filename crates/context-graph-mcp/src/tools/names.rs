@@ -1,7 +1,7 @@
 //! Tool names as constants for dispatch matching.
 //!
 //! Per PRD v6 Section 10, these MCP tools should be exposed:
-//! - Core: inject_context, search_graph, store_memory, get_memetic_status
+//! - Core: inject_context, search_graph, store_memory, store_memories_batch, get_memetic_status
 //! - Topic: get_topic_portfolio, get_topic_stability, detect_topics, get_divergence_alerts
 //! - Consolidation: trigger_consolidation
 //! - Curation: merge_concepts, forget_concept, boost_importance
@@ -13,8 +13,11 @@
 // Note: inject_context was merged into store_memory. When rationale is provided,
 // the same validation (1-1024 chars) and response format is used.
 pub const STORE_MEMORY: &str = "store_memory";
+pub const STORE_MEMORIES_BATCH: &str = "store_memories_batch";
 pub const GET_MEMETIC_STATUS: &str = "get_memetic_status";
 pub const SEARCH_GRAPH: &str = "search_graph";
+/// Up to 20 search_graph queries embedded in one provider batch.
+pub const SEARCH_GRAPH_BATCH: &str = "search_graph_batch";
 
 // ========== CONSOLIDATION TOOLS (PRD Section 10.1) ==========
 pub const TRIGGER_CONSOLIDATION: &str = "trigger_consolidation";
@@ -24,6 +27,7 @@ pub const GET_TOPIC_PORTFOLIO: &str = "get_topic_portfolio";
 pub const GET_TOPIC_STABILITY: &str = "get_topic_stability";
 pub const DETECT_TOPICS: &str = "detect_topics";
 pub const GET_DIVERGENCE_ALERTS: &str = "get_divergence_alerts";
+pub const ACKNOWLEDGE_DIVERGENCE_ALERT: &str = "acknowledge_divergence_alert";
 // ANALYZE_FINGERPRINTS tool will be added in future phase as diagnostic tool
 
 // ========== CURATION TOOLS (PRD Section 10.3) ==========
@@ -62,6 +66,21 @@ pub const GET_CAUSAL_DISCOVERY_STATUS: &str = "get_causal_discovery_status";
 /// Repair corrupted causal relationships by removing entries that fail deserialization.
 /// Scans CF_CAUSAL_RELATIONSHIPS and deletes truncated/corrupted entries.
 pub const REPAIR_CAUSAL_RELATIONSHIPS: &str = "repair_causal_relationships";
+/// Write a checkpoint + HNSW snapshot backup under the configured backup root.
+/// Disabled unless `[mcp.backup] enabled = true`.
+pub const CREATE_BACKUP: &str = "create_backup";
+
+// ========== SNAPSHOT TOOLS ==========
+/// Export memories to a snapshot directory (.cgeb/.cgei + manifest).
+pub const EXPORT_MEMORIES: &str = "export_memories";
+/// Import a snapshot with a conflict policy (skip / overwrite / re-id).
+pub const IMPORT_MEMORIES: &str = "import_memories";
+
+// ========== STAGING TOOLS (Session-scoped staging) ==========
+/// Move staged memories of a session into the default namespace.
+pub const PROMOTE_STAGED: &str = "promote_staged";
+/// Apply a SessionEnd policy (promote / discard) to a session's staged memories.
+pub const END_STAGED_SESSION: &str = "end_staged_session";
 
 // ========== GRAPH TOOLS (E8 Upgrade - Phase 4) ==========
 pub const SEARCH_CONNECTIONS: &str = "search_connections";
@@ -77,6 +96,11 @@ pub const VALIDATE_GRAPH_LINK: &str = "validate_graph_link";
 // ========== KEYWORD TOOLS (E6 Keyword Search Enhancement) ==========
 pub const SEARCH_BY_KEYWORDS: &str = "search_by_keywords";
 
+// ========== QUERY TOOLS (Structured query DSL) ==========
+/// Boolean must/should/must_not query over semantic, lexical, metadata,
+/// time_range and topic clauses, executed as one plan.
+pub const QUERY_MEMORIES: &str = "query_memories";
+
 // ========== CODE TOOLS (E7 Code Search Enhancement) ==========
 pub const SEARCH_CODE: &str = "search_code";
 
@@ -129,6 +153,8 @@ pub const GET_UNIFIED_NEIGHBORS: &str = "get_unified_neighbors";
 // ========== DAEMON TOOLS (Multi-agent observability) ==========
 /// Returns daemon health metrics: active connections, model state, background tasks.
 pub const DAEMON_STATUS: &str = "daemon_status";
+/// Returns per-model embedding load state and the estimated time until ready.
+pub const GET_EMBEDDING_STATUS: &str = "get_embedding_status";
 
 // ========== PROVENANCE TOOLS (Phase P3 - Provenance Queries) ==========
 /// Query audit log for a specific memory or time range.
@@ -137,3 +163,7 @@ pub const GET_AUDIT_TRAIL: &str = "get_audit_trail";
 pub const GET_MERGE_HISTORY: &str = "get_merge_history";
 /// Full provenance chain from embedding to source for a memory.
 pub const GET_PROVENANCE_CHAIN: &str = "get_provenance_chain";
+/// Ancestry DAG of merges and consolidations that produced a memory.
+pub const GET_MEMORY_LINEAGE: &str = "get_memory_lineage";
+/// History of mutating tool calls, filterable by time, tool, session and memory.
+pub const QUERY_AUDIT_LOG: &str = "query_audit_log";
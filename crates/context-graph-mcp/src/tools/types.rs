@@ -1,5 +1,7 @@
 //! Tool type definitions for MCP protocol.
 
+use schemars::gen::SchemaSettings;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// MCP tool definition following the protocol specification.
@@ -31,4 +33,36 @@ impl ToolDefinition {
             input_schema,
         }
     }
+
+    /// Create a tool definition whose input schema is derived from its param struct.
+    pub fn with_params<P: JsonSchema>(
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self::new(name, description, input_schema_for::<P>())
+    }
+}
+
+/// Derive a tool input schema from a param struct.
+///
+/// Nested types are inlined so the schema has no `$ref`s, and the struct's own
+/// doc comment is dropped: the tool description already says what the tool does.
+pub fn input_schema_for<P: JsonSchema>() -> serde_json::Value {
+    let root = SchemaSettings::draft07()
+        .with(|settings| {
+            settings.inline_subschemas = true;
+            settings.option_nullable = false;
+            settings.option_add_null_type = false;
+        })
+        .into_generator()
+        .into_root_schema_for::<P>();
+    let mut schema = serde_json::to_value(root.schema).expect("tool input schema serializes");
+    if let Some(object) = schema.as_object_mut() {
+        object.remove("title");
+        object.remove("description");
+        object
+            .entry("properties")
+            .or_insert_with(|| serde_json::json!({}));
+    }
+    schema
 }
@@ -0,0 +1,351 @@
+//! Argument validation against the published tool input schemas.
+//!
+//! The `inputSchema` returned by `tools/list` is the single source of truth
+//! for what a tool accepts. It is derived with schemars from the tool's param
+//! struct in `tools::definitions`, whose `#[serde(deny_unknown_fields)]` makes
+//! every object schema closed. `tools/call` checks arguments against it before
+//! dispatch so a typo such as `query_contnet` fails loudly instead of being
+//! silently ignored by the handler.
+//!
+//! ## Checks
+//!
+//! - Unknown properties where the schema sets `additionalProperties: false`
+//!   (recursively into nested objects and array items), with a "did you mean"
+//!   suggestion for near-miss names
+//! - `type` mismatches (`string`, `integer`, `number`, `boolean`, `array`,
+//!   `object`, or a list of those)
+//!
+//! Required fields, ranges and enums stay with the handler DTOs, which already
+//! report them with field-specific messages. `null` for an optional property
+//! is treated as absent, matching how handlers read `Option` fields.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use super::get_tool_definitions;
+
+/// Input schemas keyed by canonical tool name, built once from the definitions.
+static INPUT_SCHEMAS: LazyLock<HashMap<String, Value>> = LazyLock::new(|| {
+    get_tool_definitions()
+        .into_iter()
+        .map(|tool| (tool.name, tool.input_schema))
+        .collect()
+});
+
+/// Published input schema for `tool`, if it exists.
+pub fn input_schema(tool: &str) -> Option<&'static Value> {
+    INPUT_SCHEMAS.get(tool)
+}
+
+/// One offending argument.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldError {
+    /// Location within `arguments`, e.g. `items[2].content`
+    pub path: String,
+    /// Expected type, or the accepted field names for an unknown field
+    pub expected: String,
+    /// Human-readable description of the problem
+    pub message: String,
+}
+
+/// Validate tool `arguments` against `schema`.
+///
+/// Returns every offending field; an empty list means the arguments conform.
+pub fn validate_arguments(schema: &Value, arguments: &Value) -> Vec<FieldError> {
+    let mut errors = Vec::new();
+    validate_value(schema, arguments, "", &mut errors);
+    errors
+}
+
+fn validate_value(schema: &Value, value: &Value, path: &str, errors: &mut Vec<FieldError>) {
+    if let Some(expected) = schema.get("type") {
+        if !type_matches(expected, value) {
+            let expected = describe_type(expected);
+            errors.push(FieldError {
+                path: display_path(path),
+                message: format!("expected {}, got {}", expected, json_type(value)),
+                expected,
+            });
+            return;
+        }
+    }
+
+    match value {
+        Value::Object(map) => validate_object(schema, map, path, errors),
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    validate_value(item_schema, item, &format!("{}[{}]", path, i), errors);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn validate_object(
+    schema: &Value,
+    map: &Map<String, Value>,
+    path: &str,
+    errors: &mut Vec<FieldError>,
+) {
+    let properties = schema.get("properties").and_then(Value::as_object);
+    let additional = schema.get("additionalProperties");
+
+    for (key, value) in map {
+        let field_path = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", path, key)
+        };
+        match properties.and_then(|props| props.get(key)) {
+            Some(_) if value.is_null() => {}
+            Some(property) => validate_value(property, value, &field_path, errors),
+            None => match additional {
+                Some(Value::Bool(false)) => {
+                    let known: Vec<&str> = properties
+                        .map(|props| props.keys().map(String::as_str).collect())
+                        .unwrap_or_default();
+                    let message = match closest_field(key, &known) {
+                        Some(suggestion) => {
+                            format!("unknown field '{}' (did you mean '{}'?)", key, suggestion)
+                        }
+                        None => format!("unknown field '{}'", key),
+                    };
+                    errors.push(FieldError {
+                        path: field_path,
+                        expected: if known.is_empty() {
+                            "no fields".to_string()
+                        } else {
+                            format!("one of: {}", known.join(", "))
+                        },
+                        message,
+                    });
+                }
+                Some(extra) if extra.is_object() => {
+                    validate_value(extra, value, &field_path, errors)
+                }
+                _ => {}
+            },
+        }
+    }
+}
+
+fn type_matches(expected: &Value, value: &Value) -> bool {
+    match expected {
+        Value::String(name) => single_type_matches(name, value),
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|name| single_type_matches(name, value)),
+        _ => true,
+    }
+}
+
+fn single_type_matches(name: &str, value: &Value) -> bool {
+    match name {
+        "string" => value.is_string(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "null" => value.is_null(),
+        // Unknown type keywords are not ours to enforce
+        _ => true,
+    }
+}
+
+fn describe_type(expected: &Value) -> String {
+    match expected {
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>()
+            .join(" or "),
+        other => other.as_str().unwrap_or("any").to_string(),
+    }
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn display_path(path: &str) -> String {
+    if path.is_empty() {
+        "arguments".to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+/// Closest known field to `unknown`: same name ignoring case and `_` (so
+/// `top_k` suggests `topK`), else the nearest within a small edit distance,
+/// else the longest known field `unknown` starts with (`query_contnet`).
+fn closest_field<'a>(unknown: &str, known: &[&'a str]) -> Option<&'a str> {
+    let target = normalize_field(unknown);
+    if let Some(same) = known.iter().find(|k| normalize_field(k) == target) {
+        return Some(*same);
+    }
+    let max_distance = (target.len() / 3).max(2);
+    let nearest = known
+        .iter()
+        .map(|k| (edit_distance(&target, &normalize_field(k)), *k))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, k)| k);
+    nearest.or_else(|| {
+        known
+            .iter()
+            .filter(|k| target.starts_with(&normalize_field(k)))
+            .max_by_key(|k| k.len())
+            .copied()
+    })
+}
+
+fn normalize_field(name: &str) -> String {
+    name.replace('_', "").to_lowercase()
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn search_schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "query": { "type": "string" },
+                "topK": { "type": "integer" },
+                "minSimilarity": { "type": "number" },
+                "filters": {
+                    "type": "object",
+                    "properties": { "namespace": { "type": "string" } },
+                    "additionalProperties": false
+                },
+                "items": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": { "content": { "type": "string" } },
+                        "additionalProperties": false
+                    }
+                }
+            },
+            "required": ["query"],
+            "additionalProperties": false
+        })
+    }
+
+    #[test]
+    fn test_conforming_arguments_pass() {
+        let args = json!({
+            "query": "rust",
+            "topK": 5,
+            "minSimilarity": 1,
+            "filters": { "namespace": "a" },
+            "items": [{ "content": "x" }]
+        });
+        assert!(validate_arguments(&search_schema(), &args).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_field_suggests_closest() {
+        let errors = validate_arguments(
+            &search_schema(),
+            &json!({ "query_contnet": "rust", "top_k": 5 }),
+        );
+        assert_eq!(errors.len(), 2);
+        let typo = errors.iter().find(|e| e.path == "query_contnet").unwrap();
+        assert!(typo.message.contains("did you mean 'query'"));
+        assert!(typo.expected.contains("topK"));
+        let snake = errors.iter().find(|e| e.path == "top_k").unwrap();
+        assert!(snake.message.contains("did you mean 'topK'"));
+    }
+
+    #[test]
+    fn test_nested_paths_and_type_mismatch() {
+        let args = json!({
+            "query": 42,
+            "topK": 2.5,
+            "filters": { "namespce": "a" },
+            "items": [{ "content": "ok" }, { "content": true }]
+        });
+        let errors = validate_arguments(&search_schema(), &args);
+        let by_path = |path: &str| errors.iter().find(|e| e.path == path).unwrap();
+        assert_eq!(errors.len(), 4);
+        assert!(by_path("filters.namespce").message.contains("namespace"));
+        assert_eq!(by_path("items[1].content").expected, "string");
+        assert_eq!(by_path("query").message, "expected string, got integer");
+        assert_eq!(by_path("topK").message, "expected integer, got number");
+    }
+
+    #[test]
+    fn test_null_optional_and_type_lists() {
+        let schema = json!({
+            "type": "object",
+            "properties": { "seq": { "type": ["integer", "string"] } },
+            "additionalProperties": false
+        });
+        assert!(validate_arguments(&schema, &json!({ "seq": null })).is_empty());
+        assert!(validate_arguments(&schema, &json!({ "seq": "current" })).is_empty());
+        let errors = validate_arguments(&schema, &json!({ "seq": [1] }));
+        assert_eq!(errors[0].expected, "integer or string");
+    }
+
+    #[test]
+    fn test_derived_schema_rejects_typo() {
+        use crate::tools::definitions::causal::SearchCausesParams;
+
+        let args = json!({ "query": "why did the build fail", "topk": 5 });
+        let schema = input_schema("search_causes").expect("schema registered");
+        let errors = validate_arguments(schema, &args);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, "topk");
+        assert!(errors[0].message.contains("did you mean 'topK'"));
+
+        let err = serde_json::from_value::<SearchCausesParams>(args).unwrap_err();
+        assert!(err.to_string().contains("unknown field `topk`"), "{}", err);
+    }
+
+    #[test]
+    fn test_every_tool_schema_is_an_object_schema() {
+        for tool in get_tool_definitions() {
+            let schema = input_schema(&tool.name).expect("schema registered");
+            assert_eq!(schema["type"], json!("object"), "{}", tool.name);
+            assert!(
+                validate_arguments(schema, &json!("not an object"))
+                    .iter()
+                    .any(|e| e.path == "arguments"),
+                "{} must reject non-object arguments",
+                tool.name
+            );
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Benchmarks for fingerprint store throughput under each `FsyncPolicy`.
+//!
+//! Every store is one atomic `WriteBatch`; the policy only changes how often
+//! the WAL is fsynced. `EveryNms(50)` amortizes the sync across all stores in
+//! a 50ms window and should be far closer to `OnShutdown` than to
+//! `EveryWrite`.
+//!
+//! # Usage
+//!
+//! ```bash
+//! cargo bench -p context-graph-storage --bench fsync_policy_bench
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use tempfile::TempDir;
+
+use context_graph_core::traits::TeleologicalMemoryStore;
+use context_graph_storage::teleological::{
+    FsyncPolicy, RocksDbTeleologicalStore, TeleologicalStoreConfig,
+};
+use context_graph_test_utils::CorpusBuilder;
+
+/// Fingerprints stored per iteration.
+const BATCH: usize = 50;
+
+fn bench_store_by_fsync_policy(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().expect("BENCH ERROR: Failed to create tokio runtime");
+    let corpus = CorpusBuilder::new(42)
+        .with_clusters(5, BATCH / 5, 0.8)
+        .build();
+
+    let mut group = c.benchmark_group("store/fsync_policy");
+    group.throughput(Throughput::Elements(BATCH as u64));
+    group.sample_size(10);
+
+    for (name, policy) in [
+        ("every_write", FsyncPolicy::EveryWrite),
+        ("every_50ms", FsyncPolicy::EveryNms(50)),
+        ("on_shutdown", FsyncPolicy::OnShutdown),
+    ] {
+        let temp_dir = TempDir::new().expect("BENCH ERROR: Failed to create temp directory");
+        let config = TeleologicalStoreConfig {
+            fsync_policy: policy,
+            ..TeleologicalStoreConfig::default()
+        };
+        let store = RocksDbTeleologicalStore::open_with_config(temp_dir.path(), config)
+            .expect("BENCH ERROR: Failed to open RocksDB store");
+
+        group.bench_with_input(BenchmarkId::from_parameter(name), &corpus, |b, corpus| {
+            b.iter(|| {
+                rt.block_on(async {
+                    for fp in corpus {
+                        // Fresh id per store so every iteration inserts
+                        let mut fp = fp.clone();
+                        fp.id = uuid::Uuid::new_v4();
+                        store
+                            .store(black_box(fp))
+                            .await
+                            .expect("BENCH ERROR: store failed");
+                    }
+                })
+            })
+        });
+
+        let metrics = store.write_metrics();
+        println!(
+            "{}: {} batches, {:.1} ops/batch, write amplification {:.3}, {} WAL syncs",
+            name,
+            metrics.batches_committed,
+            metrics.avg_batch_operations(),
+            metrics.write_amplification(),
+            metrics.wal_syncs
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_store_by_fsync_policy);
+criterion_main!(benches);
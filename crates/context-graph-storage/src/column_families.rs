@@ -26,7 +26,7 @@ use rocksdb::{BlockBasedOptions, Cache, ColumnFamilyDescriptor, Options, SliceTr
 
 /// Apply memory-optimized write buffer settings to CF options.
 ///
-/// RocksDB defaults to 64MB write buffer x 2 per CF, which for 51 CFs would
+/// RocksDB defaults to 64MB write buffer x 2 per CF, which for 57 CFs would
 /// consume ~6.4GB just for write buffers. This function applies sensible limits.
 // Audit-14 STOR-L2 FIX: pub(crate) so teleological/column_families.rs can reuse it
 // instead of duplicating the function.
@@ -387,12 +387,13 @@ pub fn get_all_column_family_descriptors(block_cache: &Cache) -> Vec<ColumnFamil
 }
 
 /// Total number of column families in a fully configured Context Graph database.
-/// Base (11: 8 original + 3 graph linking) + Teleological (20) + Quantized Embedder (13) + Code (5) + Causal (2) = 51
-/// Teleological 20 = 5 original + 1 content + 1 source_metadata + 1 file_index + 1 topic_portfolio
+/// Base (11: 8 original + 3 graph linking) + Teleological (26) + Quantized Embedder (13) + Code (5) + Causal (2) = 57
+/// Teleological 26 = 5 original + 1 content + 1 source_metadata + 1 file_index + 1 topic_portfolio
 ///   + 1 e12_late_interaction + 1 entity_provenance + 2 audit log + 2 merge/importance history
 ///   + 1 tool call index + 1 consolidation recommendations + 1 embedding registry + 1 custom weight profiles
-///   + 1 hnsw_graphs
-pub const TOTAL_COLUMN_FAMILIES: usize = 51;
+///   + 1 hnsw_graphs + 1 content_hash_index + 1 memory_lineage + 1 content_blobs + 1 topics
+///   + 1 tool_audit_log + 1 access_stats
+pub const TOTAL_COLUMN_FAMILIES: usize = 57;
 
 #[cfg(test)]
 mod tests {
@@ -669,8 +670,8 @@ mod tests {
         // PRD v6: Autonomous module removed - topics emerge from clustering, not goal hierarchies
         // Teleological: 15 active + 2 legacy = 17 (includes 2 audit log CFs)
         assert_eq!(
-            TOTAL_COLUMN_FAMILIES, 51,
-            "Total column families should be 51 (11 base + 20 teleological + 13 quantized + 5 code + 2 causal)"
+            TOTAL_COLUMN_FAMILIES, 57,
+            "Total column families should be 57 (11 base + 26 teleological + 13 quantized + 5 code + 2 causal)"
         );
     }
 
@@ -3,6 +3,7 @@
 //! Defines error types for all storage operations.
 //! Errors are designed for fail-fast debugging with descriptive messages.
 
+use context_graph_core::error::{ContextGraphError, ErrorKind};
 use context_graph_core::types::{EdgeType, NodeId, ValidationError};
 use thiserror::Error;
 
@@ -102,6 +103,25 @@ impl StorageError {
     }
 }
 
+impl StorageError {
+    /// Machine-readable classification of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::NotFound { .. } => ErrorKind::NotFound,
+            Self::ValidationFailed(_) => ErrorKind::InvalidInput,
+            Self::IndexCorrupted { .. } => ErrorKind::Corruption,
+            _ => ErrorKind::Internal,
+        }
+    }
+}
+
+impl From<StorageError> for ContextGraphError {
+    fn from(e: StorageError) -> Self {
+        let kind = e.kind();
+        ContextGraphError::from_source(kind, e)
+    }
+}
+
 /// Convenient Result type for storage operations.
 pub type StorageResult<T> = Result<T, StorageError>;
 
@@ -252,4 +272,19 @@ mod tests {
         let debug = format!("{:?}", error);
         assert!(debug.contains("WriteFailed"));
     }
+
+    #[test]
+    fn test_conversion_keeps_source() {
+        use std::error::Error;
+
+        let error = StorageError::IndexCorrupted {
+            index_name: "tags".to_string(),
+            details: "dangling key".to_string(),
+        };
+        let unified: ContextGraphError = error.into();
+        assert_eq!(unified.kind(), ErrorKind::Corruption);
+        assert!(unified.is_critical());
+        let source = unified.source().expect("original error is the source");
+        assert!(source.to_string().contains("dangling key"));
+    }
 }
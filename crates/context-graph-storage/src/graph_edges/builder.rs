@@ -38,6 +38,8 @@
 //! - **FAIL FAST**: Errors logged with context and returned immediately
 //! - **AP-60**: Temporal embedders (E2-E4) excluded from edge type detection
 //! - **AP-77**: E5 uses asymmetric similarity
+//! - **E8**: scored per pair with `compute_e8_edge_similarity` (1.2/0.8
+//!   direction modifiers), not plain cosine
 
 use std::collections::{HashMap, VecDeque};
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -49,13 +51,19 @@ use tokio::task::JoinHandle;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+use context_graph_core::graph::compute_e8_edge_similarity;
 use context_graph_core::graph_linking::service::{GraphLinkService, GraphLinkServiceConfig};
-use context_graph_core::graph_linking::{EdgeBuilderConfig, NnDescentConfig, TypedEdge};
+use context_graph_core::graph_linking::{
+    build_directed_knn, EdgeBuilderConfig, NnDescentConfig, TypedEdge,
+};
 use context_graph_core::traits::{MultiArrayEmbeddingProvider, TeleologicalMemoryStore};
 use context_graph_core::types::audit::{AuditOperation, AuditRecord};
 use context_graph_core::types::fingerprint::TeleologicalFingerprint;
 
-use super::{EdgeRepository, GraphEdgeStorageError, GraphEdgeStorageResult};
+use super::structural::E8_EMBEDDER_ID;
+use super::{
+    EdgeRepository, GraphEdgeStorageError, GraphEdgeStorageResult, StructuralRecomputeReport,
+};
 
 // ============================================================================
 // Configuration
@@ -494,6 +502,30 @@ impl BackgroundGraphBuilder {
         let edge_config =
             EdgeBuilderConfig::default().with_min_weighted_agreement(self.config.min_weighted_agreement);
 
+        // E8 compares both asymmetric pairings of whole fingerprints and
+        // applies the direction modifier, so it bypasses vector NN-Descent
+        let mut prebuilt = Vec::new();
+        if self.config.active_embedders.contains(&E8_EMBEDDER_ID) {
+            let by_id: HashMap<Uuid, &TeleologicalFingerprint> =
+                fingerprints.iter().map(|fp| (fp.id, fp)).collect();
+            let e8_graph = build_directed_knn(
+                E8_EMBEDDER_ID,
+                &node_ids,
+                |a, b| {
+                    let score = compute_e8_edge_similarity(
+                        &by_id.get(&a)?.semantic,
+                        &by_id.get(&b)?.semantic,
+                    );
+                    Some((score.similarity, score.direction))
+                },
+                nn_config.clone(),
+            )
+            .map_err(|e| GraphEdgeStorageError::GraphBuildError {
+                message: format!("E8 directed K-NN build failed: {}", e),
+            })?;
+            prebuilt.push(e8_graph);
+        }
+
         let service_config = GraphLinkServiceConfig::default()
             .with_active_embedders(self.config.active_embedders.clone())
             .with_nn_descent(nn_config)
@@ -503,7 +535,7 @@ impl BackgroundGraphBuilder {
 
         // Build K-NN graphs
         let build_result = service
-            .build(
+            .build_with_graphs(
                 &node_ids,
                 |id, emb_id| embedding_map.get(&(id, emb_id)).cloned(),
                 |a, b| {
@@ -518,6 +550,7 @@ impl BackgroundGraphBuilder {
                         (dot / (norm_a * norm_b)).clamp(-1.0, 1.0)
                     }
                 },
+                prebuilt,
             )
             .map_err(|e| GraphEdgeStorageError::GraphBuildError {
                 message: format!("NN-Descent build failed: {}", e),
@@ -654,6 +687,40 @@ impl BackgroundGraphBuilder {
         })
     }
 
+    /// Re-score stored E8 K-NN edges with the asymmetric E8 scorer.
+    ///
+    /// Maintenance pass for edges built before E8 edges carried a direction;
+    /// see `EdgeRepository::recompute_structural_weights`. Fingerprints that
+    /// fail to load leave their edges unchanged.
+    pub async fn recompute_structural_weights(
+        &self,
+    ) -> GraphEdgeStorageResult<StructuralRecomputeReport> {
+        let mut ids: Vec<Uuid> = Vec::new();
+        for entry in self.edge_repository.iter_embedder_edges(E8_EMBEDDER_ID)? {
+            let (source, edges) = entry?;
+            ids.push(source);
+            ids.extend(edges.iter().map(|e| e.target()));
+        }
+        ids.sort_unstable();
+        ids.dedup();
+
+        let mut fingerprints = HashMap::with_capacity(ids.len());
+        for id in ids {
+            match self.teleological_store.retrieve(id).await {
+                Ok(Some(fp)) => {
+                    fingerprints.insert(id, fp.semantic);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    warn!(%id, error = %e, "recompute_structural_weights: failed to retrieve fingerprint");
+                }
+            }
+        }
+
+        self.edge_repository
+            .recompute_structural_weights(|id| fingerprints.get(&id))
+    }
+
     /// Internal process_batch with configurable min_batch_size.
     async fn process_batch_internal(
         &self,
@@ -0,0 +1,372 @@
+//! Edge inference for newly stored memories.
+//!
+//! [`BackgroundGraphBuilder`](super::BackgroundGraphBuilder) links memories
+//! in periodic batches; until a batch runs, a new memory is an isolated node.
+//! [`EdgeInferenceService`] links it at store time instead:
+//!
+//! 1. Search the memory's top-k E1 neighbors in its namespace
+//! 2. Keep neighbors at or above `theta_edge` of the domain detected from the
+//!    memory's content (see `SearchProfile::theta_edge`)
+//! 3. Link both directions with `SemanticSimilar` edges weighted by E1
+//!    similarity
+//! 4. When the E5 cause/effect similarity of the pair is at least
+//!    `causal_ratio` times stronger one way (AP-77), that direction gets a
+//!    `CausalChain` edge instead
+//!
+//! Typed edges are keyed by (source, target), so a pair that already has an
+//! edge of any type is left alone, and no memory gets an outgoing edge once
+//! it has `max_fanout` of them.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use context_graph_core::causal::asymmetric::compute_e5_asymmetric_fingerprint_similarity;
+use context_graph_core::config::EdgeInferenceConfig;
+use context_graph_core::graph_linking::{
+    DirectedRelation, GraphLinkEdgeType, TypedEdge, DEFAULT_THRESHOLDS,
+};
+use context_graph_core::retrieval::resolve_search_profile;
+use context_graph_core::traits::{
+    SearchStrategy, TeleologicalMemoryStore, TeleologicalSearchOptions,
+};
+use context_graph_core::types::audit::{AuditOperation, AuditRecord};
+use context_graph_core::types::fingerprint::TeleologicalFingerprint;
+
+use super::{EdgeRepository, GraphEdgeStorageError, GraphEdgeStorageResult};
+
+/// E1 index in per-embedder score arrays.
+const E1_EMBEDDER_ID: usize = 0;
+
+/// E5 index in per-embedder score arrays.
+const E5_EMBEDDER_ID: usize = 4;
+
+/// Counts from one edge inference run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EdgeInferenceReport {
+    /// Memories edges were inferred for.
+    pub memories: usize,
+    /// `SemanticSimilar` edges created.
+    pub semantic_edges: usize,
+    /// `CausalChain` edges created.
+    pub causal_edges: usize,
+    /// Candidate edges skipped because the pair already had an edge.
+    pub skipped_existing: usize,
+    /// Candidate edges skipped because the source reached `max_fanout`.
+    pub skipped_fanout: usize,
+}
+
+impl EdgeInferenceReport {
+    /// Total edges created.
+    pub fn edges_created(&self) -> usize {
+        self.semantic_edges + self.causal_edges
+    }
+}
+
+/// Links newly stored memories to their nearest neighbors.
+///
+/// Cheap to construct: it holds handles to the edge repository and store.
+pub struct EdgeInferenceService {
+    edge_repository: EdgeRepository,
+    teleological_store: Arc<dyn TeleologicalMemoryStore>,
+    config: EdgeInferenceConfig,
+}
+
+/// Edges accepted so far in one run, so a batch sees its own writes.
+#[derive(Default)]
+struct PendingEdges {
+    edges: Vec<TypedEdge>,
+    pairs: HashSet<(Uuid, Uuid)>,
+    outgoing: HashMap<Uuid, usize>,
+}
+
+impl EdgeInferenceService {
+    /// Create a service over `edge_repository` and `teleological_store`.
+    pub fn new(
+        edge_repository: EdgeRepository,
+        teleological_store: Arc<dyn TeleologicalMemoryStore>,
+        config: EdgeInferenceConfig,
+    ) -> Self {
+        Self {
+            edge_repository,
+            teleological_store,
+            config,
+        }
+    }
+
+    /// Get the inference configuration.
+    pub fn config(&self) -> &EdgeInferenceConfig {
+        &self.config
+    }
+
+    /// Infer and store edges for `memory_ids`.
+    ///
+    /// Called with one ID after store_memory and with every stored ID after
+    /// store_memories_batch; a batch is loaded and written in one pass, and
+    /// its members link to each other as well as to older memories.
+    /// Memories that no longer exist are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if loading the memories, the neighbor search or the
+    /// edge write fails. Nothing is written in that case.
+    pub async fn infer_edges(
+        &self,
+        memory_ids: &[Uuid],
+    ) -> GraphEdgeStorageResult<EdgeInferenceReport> {
+        let mut report = EdgeInferenceReport::default();
+        if !self.config.enabled || memory_ids.is_empty() {
+            return Ok(report);
+        }
+
+        let fingerprints = self
+            .teleological_store
+            .retrieve_batch(memory_ids)
+            .await
+            .map_err(|e| GraphEdgeStorageError::GraphBuildError {
+                message: format!("Edge inference failed to load memories: {}", e),
+            })?;
+        let contents = self
+            .teleological_store
+            .get_content_batch(memory_ids)
+            .await
+            .map_err(|e| GraphEdgeStorageError::GraphBuildError {
+                message: format!("Edge inference failed to load content: {}", e),
+            })?;
+
+        let mut pending = PendingEdges::default();
+        for (fingerprint, content) in fingerprints.into_iter().zip(contents) {
+            let Some(fingerprint) = fingerprint else {
+                continue;
+            };
+            let content = content.unwrap_or_default();
+            self.infer_for(&fingerprint, &content, &mut pending, &mut report)
+                .await?;
+            report.memories += 1;
+        }
+
+        if !pending.edges.is_empty() {
+            self.edge_repository
+                .store_typed_edges_batch(&pending.edges)?;
+            self.record_audit(memory_ids, &report).await;
+        }
+
+        info!(
+            memories = report.memories,
+            semantic_edges = report.semantic_edges,
+            causal_edges = report.causal_edges,
+            skipped_existing = report.skipped_existing,
+            skipped_fanout = report.skipped_fanout,
+            "Edge inference complete"
+        );
+        Ok(report)
+    }
+
+    /// Collect the edges between `fingerprint` and its neighbors.
+    async fn infer_for(
+        &self,
+        fingerprint: &TeleologicalFingerprint,
+        content: &str,
+        pending: &mut PendingEdges,
+        report: &mut EdgeInferenceReport,
+    ) -> GraphEdgeStorageResult<()> {
+        let profile = resolve_search_profile(None, content).profile;
+        let options = TeleologicalSearchOptions::quick(self.config.top_k + 1)
+            .with_strategy(SearchStrategy::E1Only)
+            .with_min_similarity(profile.theta_edge)
+            .with_namespace(fingerprint.namespace.clone());
+        let neighbors = self
+            .teleological_store
+            .search_semantic(&fingerprint.semantic, options)
+            .await
+            .map_err(|e| GraphEdgeStorageError::GraphBuildError {
+                message: format!(
+                    "Edge inference neighbor search failed for {}: {}",
+                    fingerprint.id, e
+                ),
+            })?;
+
+        for neighbor in neighbors
+            .iter()
+            .filter(|n| n.fingerprint.id != fingerprint.id && n.similarity >= profile.theta_edge)
+            .take(self.config.top_k)
+        {
+            let causal = self.causal_direction(fingerprint, &neighbor.fingerprint);
+            for (source, target) in [
+                (fingerprint.id, neighbor.fingerprint.id),
+                (neighbor.fingerprint.id, fingerprint.id),
+            ] {
+                let edge = match causal {
+                    Some((cause, effect, score)) if (cause, effect) == (source, target) => {
+                        causal_edge(source, target, score)?
+                    }
+                    _ => semantic_edge(source, target, neighbor.similarity)?,
+                };
+                self.accept(edge, pending, report)?;
+            }
+        }
+
+        debug!(
+            fingerprint_id = %fingerprint.id,
+            domain = %profile.domain,
+            theta_edge = profile.theta_edge,
+            neighbors = neighbors.len(),
+            "Edge inference: neighbors searched"
+        );
+        Ok(())
+    }
+
+    /// The (cause, effect, score) of a strongly asymmetric E5 pair.
+    fn causal_direction(
+        &self,
+        a: &TeleologicalFingerprint,
+        b: &TeleologicalFingerprint,
+    ) -> Option<(Uuid, Uuid, f32)> {
+        if !self.config.causal_edges {
+            return None;
+        }
+        // a as cause vs b as effect, and the reverse pairing
+        let a_causes_b =
+            compute_e5_asymmetric_fingerprint_similarity(&a.semantic, &b.semantic, true);
+        let b_causes_a =
+            compute_e5_asymmetric_fingerprint_similarity(&a.semantic, &b.semantic, false);
+        let (cause, effect, strong, weak) = if a_causes_b >= b_causes_a {
+            (a.id, b.id, a_causes_b, b_causes_a)
+        } else {
+            (b.id, a.id, b_causes_a, a_causes_b)
+        };
+        let threshold = DEFAULT_THRESHOLDS.get(GraphLinkEdgeType::CausalChain);
+        (strong >= threshold && strong >= weak * self.config.causal_ratio).then_some((
+            cause,
+            effect,
+            strong.min(1.0),
+        ))
+    }
+
+    /// Add `edge` unless its pair is linked or its source is at `max_fanout`.
+    fn accept(
+        &self,
+        edge: TypedEdge,
+        pending: &mut PendingEdges,
+        report: &mut EdgeInferenceReport,
+    ) -> GraphEdgeStorageResult<()> {
+        let (source, target) = (edge.source(), edge.target());
+        if pending.pairs.contains(&(source, target))
+            || self
+                .edge_repository
+                .get_typed_edge(source, target)?
+                .is_some()
+        {
+            report.skipped_existing += 1;
+            return Ok(());
+        }
+
+        let outgoing = match pending.outgoing.get(&source) {
+            Some(count) => *count,
+            None => self.edge_repository.get_typed_edges_from(source)?.len(),
+        };
+        if outgoing >= self.config.max_fanout {
+            report.skipped_fanout += 1;
+            pending.outgoing.insert(source, outgoing);
+            return Ok(());
+        }
+
+        pending.outgoing.insert(source, outgoing + 1);
+        pending.pairs.insert((source, target));
+        match edge.edge_type() {
+            GraphLinkEdgeType::CausalChain => report.causal_edges += 1,
+            _ => report.semantic_edges += 1,
+        }
+        pending.edges.push(edge);
+        Ok(())
+    }
+
+    /// Record the run for provenance. Non-fatal: the edges are already stored.
+    async fn record_audit(&self, memory_ids: &[Uuid], report: &EdgeInferenceReport) {
+        let audit_record = AuditRecord::new(
+            AuditOperation::RelationshipDiscovered {
+                relationship_type: "inferred_edges".to_string(),
+                confidence: 1.0,
+            },
+            memory_ids.first().copied().unwrap_or(Uuid::nil()),
+        )
+        .with_operator("edge_inference")
+        .with_rationale(format!(
+            "Inferred {} semantic + {} causal edges for {} stored memories",
+            report.semantic_edges, report.causal_edges, report.memories
+        ))
+        .with_parameters(serde_json::json!({
+            "memories": report.memories,
+            "semantic_edges": report.semantic_edges,
+            "causal_edges": report.causal_edges,
+            "skipped_existing": report.skipped_existing,
+            "skipped_fanout": report.skipped_fanout,
+            "top_k": self.config.top_k,
+            "max_fanout": self.config.max_fanout,
+        }));
+
+        if let Err(e) = self
+            .teleological_store
+            .append_audit_record(&audit_record)
+            .await
+        {
+            warn!(error = %e, "EdgeInferenceService: Failed to write audit record (non-fatal)");
+        }
+    }
+}
+
+/// A symmetric E1 edge weighted by `similarity`.
+fn semantic_edge(source: Uuid, target: Uuid, similarity: f32) -> GraphEdgeStorageResult<TypedEdge> {
+    let mut scores = [0.0f32; 13];
+    scores[E1_EMBEDDER_ID] = similarity;
+    typed_edge(
+        source,
+        target,
+        GraphLinkEdgeType::SemanticSimilar,
+        similarity,
+        DirectedRelation::Symmetric,
+        scores,
+        E1_EMBEDDER_ID,
+    )
+}
+
+/// A cause → effect E5 edge weighted by the stronger direction's score.
+fn causal_edge(cause: Uuid, effect: Uuid, score: f32) -> GraphEdgeStorageResult<TypedEdge> {
+    let mut scores = [0.0f32; 13];
+    scores[E5_EMBEDDER_ID] = score;
+    typed_edge(
+        cause,
+        effect,
+        GraphLinkEdgeType::CausalChain,
+        score,
+        DirectedRelation::Forward,
+        scores,
+        E5_EMBEDDER_ID,
+    )
+}
+
+fn typed_edge(
+    source: Uuid,
+    target: Uuid,
+    edge_type: GraphLinkEdgeType,
+    weight: f32,
+    direction: DirectedRelation,
+    scores: [f32; 13],
+    embedder: usize,
+) -> GraphEdgeStorageResult<TypedEdge> {
+    TypedEdge::new(
+        source,
+        target,
+        edge_type,
+        weight.clamp(0.0, 1.0),
+        direction,
+        scores,
+        1,
+        1 << embedder,
+    )
+    .map_err(|e| GraphEdgeStorageError::GraphBuildError {
+        message: format!("Invalid inferred edge {} -> {}: {}", source, target, e),
+    })
+}
@@ -0,0 +1,195 @@
+//! Migration of stored typed edges to the explicit edge type ordinals.
+//!
+//! Version 1 typed edges carried the serde-derived variant index of
+//! `GraphLinkEdgeType`, which shifts whenever the enum is reordered or
+//! extended. This pass rewrites every version 1 value in the `typed_edges`
+//! CF in the current format, mapping the old ordinals through the explicit
+//! `legacy_edge_type` table. Keys and the `typed_edges_by_type` index are
+//! unchanged: the index already used the `u8` discriminants, which keep the
+//! same values for the original eight types.
+//!
+//! Values already in the current format are skipped, so a second run is a
+//! no-op.
+
+use rocksdb::{IteratorMode, WriteBatch};
+use tracing::info;
+
+use crate::column_families::cf_names;
+
+use super::repository::EdgeRepository;
+use super::serialization::{deserialize_typed_edge, serialize_typed_edge, GRAPH_EDGE_VERSION};
+use super::types::{GraphEdgeStorageError, GraphEdgeStorageResult};
+
+/// Outcome of `EdgeRepository::migrate_typed_edges`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EdgeMigrationReport {
+    /// Typed edges scanned.
+    pub edges_scanned: usize,
+    /// Version 1 edges rewritten in the current format.
+    pub edges_migrated: usize,
+}
+
+impl EdgeRepository {
+    /// Rewrite version 1 typed edges in the current serialization format.
+    ///
+    /// All rewrites go out in one batch, so a failure leaves the CF as it
+    /// was. A version 1 edge whose ordinal has no mapping fails the whole
+    /// migration rather than being dropped.
+    pub fn migrate_typed_edges(&self) -> GraphEdgeStorageResult<EdgeMigrationReport> {
+        let cf = self.db.cf_handle(cf_names::TYPED_EDGES).ok_or(
+            GraphEdgeStorageError::ColumnFamilyNotFound {
+                name: cf_names::TYPED_EDGES,
+            },
+        )?;
+
+        let mut report = EdgeMigrationReport::default();
+        let mut batch = WriteBatch::default();
+        for item in self.db.iterator_cf(&cf, IteratorMode::Start) {
+            let (key, value) = item.map_err(|e| {
+                GraphEdgeStorageError::rocksdb("migrate_typed_edges", cf_names::TYPED_EDGES, e)
+            })?;
+            report.edges_scanned += 1;
+            if value.first() != Some(&GRAPH_EDGE_VERSION) {
+                continue;
+            }
+
+            let edge = deserialize_typed_edge(&value)?;
+            batch.put_cf(&cf, key, serialize_typed_edge(&edge)?);
+            report.edges_migrated += 1;
+        }
+
+        if report.edges_migrated > 0 {
+            self.db.write(batch).map_err(|e| {
+                GraphEdgeStorageError::rocksdb("migrate_typed_edges", cf_names::TYPED_EDGES, e)
+            })?;
+        }
+
+        info!(
+            scanned = report.edges_scanned,
+            migrated = report.edges_migrated,
+            "migrate_typed_edges: complete"
+        );
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column_families::get_column_family_descriptors;
+    use crate::graph_edges::serialization::{LegacyTypedEdgeV1, TYPED_EDGE_VERSION};
+    use context_graph_core::graph_linking::{
+        DirectedRelation, GraphLinkEdgeType, TypedEdge, TypedEdgeStorageKey,
+    };
+    use rocksdb::{Cache, Options, DB};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+    use uuid::Uuid;
+
+    fn create_test_repo() -> (TempDir, EdgeRepository) {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new_lru_cache(64 * 1024 * 1024);
+        let descriptors = get_column_family_descriptors(&cache);
+
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.create_missing_column_families(true);
+
+        let db = DB::open_cf_descriptors(&opts, temp_dir.path(), descriptors).unwrap();
+        (temp_dir, EdgeRepository::new(Arc::new(db)))
+    }
+
+    /// Write a version 1 edge straight into the typed_edges CF.
+    fn put_v1(repo: &EdgeRepository, edge: &LegacyTypedEdgeV1) {
+        let mut value = vec![GRAPH_EDGE_VERSION];
+        value.extend(bincode::serialize(edge).unwrap());
+        let key = TypedEdgeStorageKey::new(edge.source, edge.target);
+        let cf = repo.db.cf_handle(cf_names::TYPED_EDGES).unwrap();
+        repo.db.put_cf(&cf, key.to_bytes(), value).unwrap();
+    }
+
+    fn raw_version(repo: &EdgeRepository, source: Uuid, target: Uuid) -> u8 {
+        let cf = repo.db.cf_handle(cf_names::TYPED_EDGES).unwrap();
+        let key = TypedEdgeStorageKey::new(source, target);
+        repo.db.get_cf(&cf, key.to_bytes()).unwrap().unwrap()[0]
+    }
+
+    #[test]
+    fn test_migrate_maps_version_1_ordinals() {
+        let (_tmp, repo) = create_test_repo();
+        let source = Uuid::new_v4();
+        let causal = LegacyTypedEdgeV1 {
+            source,
+            target: Uuid::new_v4(),
+            edge_type: 3,
+            weight: 0.75,
+            direction: DirectedRelation::Forward,
+            embedder_scores: [0.0; 13],
+            agreement_count: 1,
+            agreeing_embedders: 0b0000_0001_0000,
+        };
+        let multi = LegacyTypedEdgeV1 {
+            target: Uuid::new_v4(),
+            edge_type: 7,
+            weight: 0.8,
+            direction: DirectedRelation::Symmetric,
+            agreement_count: 3,
+            agreeing_embedders: 0b0000_0100_0101,
+            ..causal.clone()
+        };
+        put_v1(&repo, &causal);
+        put_v1(&repo, &multi);
+        let current =
+            TypedEdge::relation(source, Uuid::new_v4(), GraphLinkEdgeType::Supersedes).unwrap();
+        repo.store_typed_edge(&current).unwrap();
+
+        let report = repo.migrate_typed_edges().unwrap();
+        assert_eq!(
+            report,
+            EdgeMigrationReport {
+                edges_scanned: 3,
+                edges_migrated: 2,
+            }
+        );
+
+        let causal_edge = repo.get_typed_edge(source, causal.target).unwrap().unwrap();
+        assert_eq!(causal_edge.edge_type(), GraphLinkEdgeType::CausalChain);
+        assert_eq!(causal_edge.direction(), DirectedRelation::Forward);
+        let multi_edge = repo.get_typed_edge(source, multi.target).unwrap().unwrap();
+        assert_eq!(multi_edge.edge_type(), GraphLinkEdgeType::MultiAgreement);
+        assert_eq!(multi_edge.agreement_count(), 3);
+        assert_eq!(
+            raw_version(&repo, source, causal.target),
+            TYPED_EDGE_VERSION
+        );
+        assert_eq!(
+            repo.get_typed_edge(source, current.target()).unwrap(),
+            Some(current)
+        );
+
+        // Second run is a no-op
+        assert_eq!(repo.migrate_typed_edges().unwrap().edges_migrated, 0);
+    }
+
+    #[test]
+    fn test_migrate_fails_on_unknown_ordinal() {
+        let (_tmp, repo) = create_test_repo();
+        let edge = LegacyTypedEdgeV1 {
+            source: Uuid::new_v4(),
+            target: Uuid::new_v4(),
+            edge_type: 9,
+            weight: 0.5,
+            direction: DirectedRelation::Symmetric,
+            embedder_scores: [0.0; 13],
+            agreement_count: 0,
+            agreeing_embedders: 0,
+        };
+        put_v1(&repo, &edge);
+
+        assert!(repo.migrate_typed_edges().is_err());
+        assert_eq!(
+            raw_version(&repo, edge.source, edge.target),
+            GRAPH_EDGE_VERSION
+        );
+    }
+}
@@ -6,7 +6,8 @@
 //! - **K-NN edges per embedder**: Each of 13 embedders maintains its own K-NN
 //!   graph connecting memories to their nearest neighbors.
 //! - **Typed edges**: Multi-relation edges derived from embedder agreement
-//!   patterns, with 8 edge types based on which embedders agree.
+//!   patterns (8 types based on which embedders agree), plus asserted
+//!   relations (refutes, supersedes, derived_from, temporal_follows).
 //!
 //! # Column Families
 //!
@@ -22,6 +23,29 @@
 //!   - Key: `[edge_type: u8][source_uuid: 16 bytes]` = 17 bytes
 //!   - Value: `target_uuid` (16 bytes)
 //!
+//! # Bulk Transfer
+//!
+//! `EdgeRepository::import_edges` / `export_edges` move typed edges in and
+//! out as JSONL (see `EdgeRecord`), validating and deduplicating on import.
+//!
+//! # Typed Edge Migration
+//!
+//! Typed edges written before edge types had explicit ordinals are still
+//! read; `EdgeRepository::migrate_typed_edges` rewrites them in the current
+//! format (see `legacy_edge_type`).
+//!
+//! # Edge Inference
+//!
+//! `EdgeInferenceService` links each newly stored memory to its E1 nearest
+//! neighbors at store time, ahead of the background K-NN batches.
+//!
+//! # E8 Direction
+//!
+//! E8 K-NN edges are scored with `compute_e8_edge_similarity`, so A→B and
+//! B→A get different weights; each stored edge keeps the `DirectedRelation`
+//! whose modifier was applied. `EdgeRepository::recompute_structural_weights`
+//! upgrades lists written before directions were stored.
+//!
 //! # Architecture Reference
 //!
 //! - ARCH-18: E5/E8 use asymmetric similarity (direction matters)
@@ -47,17 +71,26 @@
 //! ```
 
 mod builder;
+mod inference;
+mod migration;
 mod repository;
 mod serialization;
+mod structural;
+mod transfer;
 mod types;
 
 // Re-export public types
 pub use builder::{
     BackgroundGraphBuilder, BatchBuildResult, BuilderStats, GraphBuilderConfig, RebuildResult,
 };
+pub use inference::{EdgeInferenceReport, EdgeInferenceService};
+pub use migration::EdgeMigrationReport;
 pub use repository::EdgeRepository;
 pub use serialization::{
-    deserialize_embedder_edges, deserialize_typed_edge, serialize_embedder_edges,
-    serialize_typed_edge, GRAPH_EDGE_VERSION,
+    deserialize_embedder_edges, deserialize_typed_edge, legacy_edge_type,
+    serialize_embedder_edges, serialize_typed_edge, EMBEDDER_EDGES_DIRECTED_VERSION,
+    GRAPH_EDGE_VERSION, TYPED_EDGE_VERSION,
 };
+pub use structural::StructuralRecomputeReport;
+pub use transfer::{EdgeExportFilter, EdgeFormat, EdgeRecord, ImportReport, RejectedEdge};
 pub use types::{GraphEdgeStats, GraphEdgeStorageError, GraphEdgeStorageResult};
@@ -31,7 +31,7 @@ use super::types::{GraphEdgeStats, GraphEdgeStorageError, GraphEdgeStorageResult
 /// All operations follow the FAIL FAST principle - errors are returned, never swallowed.
 #[derive(Clone)]
 pub struct EdgeRepository {
-    db: Arc<DB>,
+    pub(super) db: Arc<DB>,
 }
 
 impl EdgeRepository {
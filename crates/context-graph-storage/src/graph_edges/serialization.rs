@@ -9,12 +9,31 @@
 //! Uses bincode with a version prefix for future compatibility.
 //! All serialization is deterministic for consistent hashing.
 
-use context_graph_core::graph_linking::{EmbedderEdge, TypedEdge};
+use context_graph_core::graph_linking::{
+    DirectedRelation, EmbedderEdge, GraphLinkEdgeType, TypedEdge,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
 use super::types::{GraphEdgeStorageError, GraphEdgeStorageResult};
 
 /// Current serialization version for graph edges.
 pub const GRAPH_EDGE_VERSION: u8 = 1;
 
+/// Serialization version for K-NN edge lists that carry a direction byte.
+///
+/// Version 1 lists (`GRAPH_EDGE_VERSION`) are still read; their edges come
+/// back Symmetric.
+pub const EMBEDDER_EDGES_DIRECTED_VERSION: u8 = 2;
+
+/// Serialization version for typed edges whose edge type is the explicit
+/// `GraphLinkEdgeType::as_u8` ordinal.
+///
+/// Version 1 edges (`GRAPH_EDGE_VERSION`) stored the derive-generated
+/// variant index; they are still read through `legacy_edge_type` and
+/// rewritten by `EdgeRepository::migrate_typed_edges`.
+pub const TYPED_EDGE_VERSION: u8 = 2;
+
 /// Serialize a vector of EmbedderEdge (K-NN neighbors for one source).
 ///
 /// # Format
@@ -25,22 +44,26 @@ pub const GRAPH_EDGE_VERSION: u8 = 1;
 ///
 /// Each EmbedderEdge is serialized as:
 /// ```text
-/// [target_uuid: 16 bytes][similarity: f32]
+/// [target_uuid: 16 bytes][similarity: f32][direction: u8]
 /// ```
+///
+/// The direction records which E5/E8 direction modifier was applied to the
+/// similarity (see `DirectedRelation::similarity_modifier`).
 pub fn serialize_embedder_edges(edges: &[EmbedderEdge]) -> GraphEdgeStorageResult<Vec<u8>> {
-    let mut buffer = Vec::with_capacity(1 + 4 + edges.len() * 20);
+    let mut buffer = Vec::with_capacity(1 + 4 + edges.len() * 21);
 
     // Version prefix
-    buffer.push(GRAPH_EDGE_VERSION);
+    buffer.push(EMBEDDER_EDGES_DIRECTED_VERSION);
 
     // Edge count
     let count = edges.len() as u32;
     buffer.extend_from_slice(&count.to_le_bytes());
 
-    // Each edge: target UUID (16) + similarity (4)
+    // Each edge: target UUID (16) + similarity (4) + direction (1)
     for edge in edges {
         buffer.extend_from_slice(edge.target().as_bytes());
         buffer.extend_from_slice(&edge.similarity().to_le_bytes());
+        buffer.push(edge.direction().as_u8());
     }
 
     Ok(buffer)
@@ -60,6 +83,7 @@ pub fn serialize_embedder_edges(edges: &[EmbedderEdge]) -> GraphEdgeStorageResul
 /// - Version mismatch
 /// - Data truncated
 /// - Invalid UUID bytes
+/// - Invalid direction byte
 pub fn deserialize_embedder_edges(
     data: &[u8],
     source: uuid::Uuid,
@@ -74,12 +98,19 @@ pub fn deserialize_embedder_edges(
 
     // Check version
     let version = data[0];
-    if version != GRAPH_EDGE_VERSION {
-        return Err(GraphEdgeStorageError::deserialization(
-            "deserialize_embedder_edges",
-            format!("version mismatch: expected {}, got {}", GRAPH_EDGE_VERSION, version),
-        ));
-    }
+    let edge_len = match version {
+        GRAPH_EDGE_VERSION => 20,
+        EMBEDDER_EDGES_DIRECTED_VERSION => 21,
+        _ => {
+            return Err(GraphEdgeStorageError::deserialization(
+                "deserialize_embedder_edges",
+                format!(
+                    "version mismatch: expected {} or {}, got {}",
+                    GRAPH_EDGE_VERSION, EMBEDDER_EDGES_DIRECTED_VERSION, version
+                ),
+            ))
+        }
+    };
 
     // Read count
     if data.len() < 5 {
@@ -91,7 +122,7 @@ pub fn deserialize_embedder_edges(
     let count = u32::from_le_bytes([data[1], data[2], data[3], data[4]]) as usize;
 
     // Verify data length
-    let expected_len = 5 + count * 20;
+    let expected_len = 5 + count * edge_len;
     if data.len() < expected_len {
         return Err(GraphEdgeStorageError::deserialization(
             "deserialize_embedder_edges",
@@ -124,9 +155,28 @@ pub fn deserialize_embedder_edges(
         let similarity = f32::from_le_bytes(sim_bytes);
         offset += 4;
 
-        // Create edge (validation happens in EmbedderEdge::new_unchecked or we bypass it)
-        // Note: We use unsafe construction here since data was validated on write
-        edges.push(EmbedderEdge::from_storage(source, target, embedder_id, similarity));
+        // Parse direction (version 2 only)
+        let direction = if edge_len == 21 {
+            let byte = data[offset];
+            offset += 1;
+            DirectedRelation::from_u8(byte).ok_or_else(|| {
+                GraphEdgeStorageError::deserialization(
+                    "deserialize_embedder_edges",
+                    format!("invalid direction byte {}", byte),
+                )
+            })?
+        } else {
+            DirectedRelation::Symmetric
+        };
+
+        // Data was validated on write, so skip EmbedderEdge validation
+        edges.push(EmbedderEdge::from_storage_directed(
+            source,
+            target,
+            embedder_id,
+            similarity,
+            direction,
+        ));
     }
 
     Ok(edges)
@@ -136,9 +186,13 @@ pub fn deserialize_embedder_edges(
 ///
 /// # Format
 ///
-/// Uses bincode for TypedEdge which includes:
+/// ```text
+/// [version: u8 = TYPED_EDGE_VERSION][bincode TypedEdge]
+/// ```
+///
+/// The bincode body includes:
 /// - source/target UUIDs
-/// - edge type
+/// - edge type (its explicit ordinal, one byte)
 /// - weight
 /// - direction (for asymmetric edges)
 /// - embedder agreement scores
@@ -146,7 +200,7 @@ pub fn serialize_typed_edge(edge: &TypedEdge) -> GraphEdgeStorageResult<Vec<u8>>
     let mut buffer = Vec::with_capacity(128);
 
     // Version prefix
-    buffer.push(GRAPH_EDGE_VERSION);
+    buffer.push(TYPED_EDGE_VERSION);
 
     // Use bincode for the complex structure
     let edge_bytes = bincode::serialize(edge)
@@ -160,6 +214,9 @@ pub fn serialize_typed_edge(edge: &TypedEdge) -> GraphEdgeStorageResult<Vec<u8>>
 }
 
 /// Deserialize a TypedEdge.
+///
+/// Reads the current format and version 1 edges, whose type ordinals are
+/// mapped with `legacy_edge_type`.
 pub fn deserialize_typed_edge(data: &[u8]) -> GraphEdgeStorageResult<TypedEdge> {
     if data.is_empty() {
         return Err(GraphEdgeStorageError::deserialization(
@@ -169,29 +226,91 @@ pub fn deserialize_typed_edge(data: &[u8]) -> GraphEdgeStorageResult<TypedEdge>
     }
 
     // Check version
-    let version = data[0];
-    if version != GRAPH_EDGE_VERSION {
-        return Err(GraphEdgeStorageError::deserialization(
+    match data[0] {
+        TYPED_EDGE_VERSION => bincode::deserialize(&data[1..]).map_err(|e| {
+            GraphEdgeStorageError::deserialization(
+                "deserialize_typed_edge",
+                format!("bincode error: {}", e),
+            )
+        }),
+        GRAPH_EDGE_VERSION => deserialize_typed_edge_v1(&data[1..]),
+        version => Err(GraphEdgeStorageError::deserialization(
             "deserialize_typed_edge",
-            format!("version mismatch: expected {}, got {}", GRAPH_EDGE_VERSION, version),
-        ));
+            format!(
+                "version mismatch: expected {} or {}, got {}",
+                GRAPH_EDGE_VERSION, TYPED_EDGE_VERSION, version
+            ),
+        )),
     }
+}
 
-    // Deserialize with bincode
-    let edge: TypedEdge = bincode::deserialize(&data[1..])
-        .map_err(|e| GraphEdgeStorageError::deserialization(
-            "deserialize_typed_edge",
-            format!("bincode error: {}", e),
-        ))?;
+/// Map a version 1 edge type ordinal to its variant.
+///
+/// Version 1 stored the serde-derived variant index of the original eight
+/// types. The table is spelled out so reordering or extending
+/// `GraphLinkEdgeType` can never silently remap stored edges.
+pub fn legacy_edge_type(ordinal: u32) -> Option<GraphLinkEdgeType> {
+    match ordinal {
+        0 => Some(GraphLinkEdgeType::SemanticSimilar),
+        1 => Some(GraphLinkEdgeType::CodeRelated),
+        2 => Some(GraphLinkEdgeType::EntityShared),
+        3 => Some(GraphLinkEdgeType::CausalChain),
+        4 => Some(GraphLinkEdgeType::GraphConnected),
+        5 => Some(GraphLinkEdgeType::ParaphraseAligned),
+        6 => Some(GraphLinkEdgeType::KeywordOverlap),
+        7 => Some(GraphLinkEdgeType::MultiAgreement),
+        _ => None,
+    }
+}
+
+/// Version 1 on-disk layout of `TypedEdge`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct LegacyTypedEdgeV1 {
+    pub source: Uuid,
+    pub target: Uuid,
+    pub edge_type: u32,
+    pub weight: f32,
+    pub direction: DirectedRelation,
+    pub embedder_scores: [f32; 13],
+    pub agreement_count: u8,
+    pub agreeing_embedders: u16,
+}
 
-    Ok(edge)
+fn deserialize_typed_edge_v1(body: &[u8]) -> GraphEdgeStorageResult<TypedEdge> {
+    let legacy: LegacyTypedEdgeV1 = bincode::deserialize(body).map_err(|e| {
+        GraphEdgeStorageError::deserialization(
+            "deserialize_typed_edge",
+            format!("bincode error (version 1): {}", e),
+        )
+    })?;
+    let edge_type = legacy_edge_type(legacy.edge_type).ok_or_else(|| {
+        GraphEdgeStorageError::deserialization(
+            "deserialize_typed_edge",
+            format!("unknown version 1 edge type ordinal {}", legacy.edge_type),
+        )
+    })?;
+
+    TypedEdge::new(
+        legacy.source,
+        legacy.target,
+        edge_type,
+        legacy.weight,
+        legacy.direction,
+        legacy.embedder_scores,
+        legacy.agreement_count,
+        legacy.agreeing_embedders,
+    )
+    .map_err(|e| {
+        GraphEdgeStorageError::deserialization(
+            "deserialize_typed_edge",
+            format!("invalid version 1 edge: {}", e),
+        )
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use context_graph_core::graph_linking::{DirectedRelation, GraphLinkEdgeType};
-    use uuid::Uuid;
 
     fn create_test_embedder_edges(source: Uuid, embedder_id: u8, count: usize) -> Vec<EmbedderEdge> {
         (0..count)
@@ -224,6 +343,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_embedder_edges_roundtrip_direction() {
+        let source = Uuid::new_v4();
+        let edges = vec![
+            EmbedderEdge::from_storage_directed(
+                source,
+                Uuid::new_v4(),
+                7,
+                0.84,
+                DirectedRelation::Forward,
+            ),
+            EmbedderEdge::from_storage_directed(
+                source,
+                Uuid::new_v4(),
+                7,
+                0.56,
+                DirectedRelation::Backward,
+            ),
+        ];
+
+        let serialized = serialize_embedder_edges(&edges).unwrap();
+        assert_eq!(serialized[0], EMBEDDER_EDGES_DIRECTED_VERSION);
+        let deserialized = deserialize_embedder_edges(&serialized, source, 7).unwrap();
+
+        assert_eq!(deserialized, edges);
+    }
+
+    #[test]
+    fn test_embedder_edges_reads_version_1() {
+        let source = Uuid::new_v4();
+        let target = Uuid::new_v4();
+        let mut data = vec![GRAPH_EDGE_VERSION];
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(target.as_bytes());
+        data.extend_from_slice(&0.9f32.to_le_bytes());
+
+        let deserialized = deserialize_embedder_edges(&data, source, 0).unwrap();
+        assert_eq!(deserialized.len(), 1);
+        assert_eq!(deserialized[0].target(), target);
+        assert_eq!(deserialized[0].direction(), DirectedRelation::Symmetric);
+    }
+
     #[test]
     fn test_embedder_edges_empty() {
         let edges: Vec<EmbedderEdge> = vec![];
@@ -295,4 +456,49 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("empty data"));
     }
+
+    #[test]
+    fn test_typed_edge_reads_version_1_ordinals() {
+        let source = Uuid::new_v4();
+        let target = Uuid::new_v4();
+        let legacy = LegacyTypedEdgeV1 {
+            source,
+            target,
+            edge_type: 3, // CausalChain in version 1
+            weight: 0.75,
+            direction: DirectedRelation::Forward,
+            embedder_scores: [0.0; 13],
+            agreement_count: 1,
+            agreeing_embedders: 0b0000_0001_0000,
+        };
+        let mut data = vec![GRAPH_EDGE_VERSION];
+        data.extend(bincode::serialize(&legacy).unwrap());
+
+        let edge = deserialize_typed_edge(&data).unwrap();
+        assert_eq!(edge.edge_type(), GraphLinkEdgeType::CausalChain);
+        assert_eq!(edge.direction(), DirectedRelation::Forward);
+        assert_eq!(edge.source(), source);
+
+        let unknown = LegacyTypedEdgeV1 {
+            edge_type: 8,
+            ..legacy
+        };
+        let mut data = vec![GRAPH_EDGE_VERSION];
+        data.extend(bincode::serialize(&unknown).unwrap());
+        assert!(deserialize_typed_edge(&data).is_err());
+    }
+
+    #[test]
+    fn test_typed_edge_relation_roundtrip() {
+        let edge = TypedEdge::relation(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            GraphLinkEdgeType::Supersedes,
+        )
+        .unwrap();
+
+        let serialized = serialize_typed_edge(&edge).unwrap();
+        assert_eq!(serialized[0], TYPED_EDGE_VERSION);
+        assert_eq!(deserialize_typed_edge(&serialized).unwrap(), edge);
+    }
 }
@@ -0,0 +1,204 @@
+//! Maintenance pass that re-scores stored E8 (graph) K-NN edges.
+//!
+//! E8 edges built before the asymmetric scorer used plain cosine on the
+//! source encodings, so A→B and B→A carried the same weight. This pass
+//! re-scores every stored E8 neighbor with `compute_e8_edge_similarity` and
+//! records the applied direction modifier on the edge. It only writes lists
+//! whose scores changed, so a second run is a no-op.
+//!
+//! Typed edges keep their stored E8 score until the next rebuild derives
+//! them again from the upgraded K-NN lists.
+
+use context_graph_core::graph::compute_e8_edge_similarity;
+use context_graph_core::graph_linking::EmbedderEdge;
+use context_graph_core::types::fingerprint::SemanticFingerprint;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use super::repository::EdgeRepository;
+use super::types::GraphEdgeStorageResult;
+
+/// Embedder index of E8 (graph).
+pub(super) const E8_EMBEDDER_ID: u8 = 7;
+
+/// Scores closer than this count as unchanged.
+const SCORE_EPSILON: f32 = 1e-6;
+
+/// Outcome of `EdgeRepository::recompute_structural_weights`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StructuralRecomputeReport {
+    /// Source nodes with stored E8 neighbor lists.
+    pub sources_scanned: usize,
+    /// Edges re-scored.
+    pub edges_rescored: usize,
+    /// Edges whose similarity or direction changed.
+    pub edges_updated: usize,
+    /// Edges left as-is because a fingerprint was missing.
+    pub edges_skipped: usize,
+}
+
+impl EdgeRepository {
+    /// Re-score all stored E8 K-NN edges with the asymmetric E8 scorer.
+    ///
+    /// `fingerprint_of` looks up a memory's fingerprint; edges with either
+    /// end missing are left unchanged and counted as skipped. Neighbor lists
+    /// are rewritten sorted by the new similarity.
+    pub fn recompute_structural_weights<'a>(
+        &self,
+        fingerprint_of: impl Fn(Uuid) -> Option<&'a SemanticFingerprint>,
+    ) -> GraphEdgeStorageResult<StructuralRecomputeReport> {
+        let mut report = StructuralRecomputeReport::default();
+
+        // Collect first: lists are rewritten in place below
+        let lists = self
+            .iter_embedder_edges(E8_EMBEDDER_ID)?
+            .collect::<GraphEdgeStorageResult<Vec<_>>>()?;
+
+        for (source, mut edges) in lists {
+            report.sources_scanned += 1;
+            let Some(source_fp) = fingerprint_of(source) else {
+                report.edges_skipped += edges.len();
+                continue;
+            };
+
+            let mut changed = false;
+            for edge in &mut edges {
+                let Some(target_fp) = fingerprint_of(edge.target()) else {
+                    report.edges_skipped += 1;
+                    continue;
+                };
+                let score = compute_e8_edge_similarity(source_fp, target_fp);
+                report.edges_rescored += 1;
+                if (score.similarity - edge.similarity()).abs() > SCORE_EPSILON
+                    || score.direction != edge.direction()
+                {
+                    *edge = EmbedderEdge::from_storage_directed(
+                        source,
+                        edge.target(),
+                        E8_EMBEDDER_ID,
+                        score.similarity,
+                        score.direction,
+                    );
+                    report.edges_updated += 1;
+                    changed = true;
+                }
+            }
+
+            if changed {
+                edges.sort_by(|a, b| b.similarity().total_cmp(&a.similarity()));
+                self.store_embedder_edges(E8_EMBEDDER_ID, source, &edges)?;
+                debug!(%source, "recompute_structural_weights: rewrote E8 neighbors");
+            }
+        }
+
+        info!(
+            sources = report.sources_scanned,
+            rescored = report.edges_rescored,
+            updated = report.edges_updated,
+            skipped = report.edges_skipped,
+            "recompute_structural_weights: complete"
+        );
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column_families::get_column_family_descriptors;
+    use context_graph_core::graph_linking::DirectedRelation;
+    use context_graph_core::types::fingerprint::E8_DIM;
+    use rocksdb::{Cache, Options, DB};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    fn create_test_repo() -> (TempDir, EdgeRepository) {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new_lru_cache(64 * 1024 * 1024);
+        let descriptors = get_column_family_descriptors(&cache);
+
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.create_missing_column_families(true);
+
+        let db = DB::open_cf_descriptors(&opts, temp_dir.path(), descriptors).unwrap();
+        (temp_dir, EdgeRepository::new(Arc::new(db)))
+    }
+
+    fn axis(dims: &[usize]) -> Vec<f32> {
+        let mut v = vec![0.0f32; E8_DIM];
+        for &d in dims {
+            v[d] = 1.0;
+        }
+        v
+    }
+
+    /// Hub whose source encoding points at the leaf's target encoding.
+    fn hub_and_leaf() -> (SemanticFingerprint, SemanticFingerprint) {
+        let mut hub = SemanticFingerprint::zeroed();
+        hub.e8_graph_as_source = axis(&[0, 1]);
+        hub.e8_graph_as_target = axis(&[2]);
+        let mut leaf = SemanticFingerprint::zeroed();
+        leaf.e8_graph_as_source = axis(&[3]);
+        leaf.e8_graph_as_target = axis(&[0]);
+        (hub, leaf)
+    }
+
+    #[test]
+    fn test_recompute_upgrades_symmetric_edges_idempotently() {
+        let (_dir, repo) = create_test_repo();
+        let (hub, leaf) = hub_and_leaf();
+        let (hub_id, leaf_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let missing = Uuid::new_v4();
+        let fingerprints: HashMap<Uuid, SemanticFingerprint> =
+            [(hub_id, hub), (leaf_id, leaf)].into_iter().collect();
+
+        // Legacy edges: same cosine both ways, no direction
+        let legacy = |source, target| EmbedderEdge::from_storage(source, target, 7, 0.5);
+        repo.store_embedder_edges(
+            7,
+            hub_id,
+            &[legacy(hub_id, leaf_id), legacy(hub_id, missing)],
+        )
+        .unwrap();
+        repo.store_embedder_edges(7, leaf_id, &[legacy(leaf_id, hub_id)])
+            .unwrap();
+
+        let report = repo
+            .recompute_structural_weights(|id| fingerprints.get(&id))
+            .unwrap();
+        assert_eq!(report.sources_scanned, 2);
+        assert_eq!(report.edges_rescored, 2);
+        assert_eq!(report.edges_updated, 2);
+        assert_eq!(report.edges_skipped, 1);
+
+        let forward = repo.get_embedder_edges(7, hub_id).unwrap();
+        let forward = forward.iter().find(|e| e.target() == leaf_id).unwrap();
+        let reverse = repo.get_embedder_edges(7, leaf_id).unwrap()[0];
+        assert_eq!(forward.direction(), DirectedRelation::Forward);
+        assert_eq!(reverse.direction(), DirectedRelation::Backward);
+        let ratio = forward.similarity() / reverse.similarity();
+        assert!((ratio - 1.5).abs() < 1e-4, "ratio {}", ratio);
+
+        // Second pass finds nothing to change
+        let again = repo
+            .recompute_structural_weights(|id| fingerprints.get(&id))
+            .unwrap();
+        assert_eq!(again.edges_rescored, 2);
+        assert_eq!(again.edges_updated, 0);
+        assert_eq!(repo.get_embedder_edges(7, leaf_id).unwrap()[0], reverse);
+    }
+
+    #[test]
+    fn test_recompute_ignores_other_embedders() {
+        let (_dir, repo) = create_test_repo();
+        let (source, target) = (Uuid::new_v4(), Uuid::new_v4());
+        let edge = EmbedderEdge::from_storage(source, target, 0, 0.9);
+        repo.store_embedder_edges(0, source, &[edge]).unwrap();
+
+        let report = repo.recompute_structural_weights(|_| None).unwrap();
+        assert_eq!(report, StructuralRecomputeReport::default());
+        assert_eq!(repo.get_embedder_edges(0, source).unwrap(), vec![edge]);
+    }
+}
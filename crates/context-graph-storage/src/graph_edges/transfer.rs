@@ -0,0 +1,445 @@
+//! Bulk import and export of typed edges.
+//!
+//! Lets a graph be seeded from an existing relationship dataset (e.g. a
+//! dependency list) without inserting edges one at a time.
+//!
+//! # JSONL format
+//!
+//! One JSON object per line; blank lines are ignored:
+//!
+//! ```text
+//! {"source": "<uuid>", "target": "<uuid>", "edge_type": "code_related", "weight": 0.8}
+//! {"source": "<uuid>", "target": "<uuid>", "edge_type": "causal_chain", "weight": 0.6,
+//!  "direction": "backward", "embedder_scores": [0.0, ...13 values], "agreeing_embedders": 16}
+//! ```
+//!
+//! | Field | Required | Default |
+//! |-------|----------|---------|
+//! | `source`, `target` | yes | - |
+//! | `edge_type` | yes | - (snake_case `GraphLinkEdgeType`) |
+//! | `weight` | yes | - ([0.0, 1.0]) |
+//! | `direction` | no | `forward` for causal_chain/graph_connected, else `symmetric` |
+//! | `embedder_scores` | no | all 0.0 |
+//! | `agreeing_embedders` | no | 0 (bitset, bit 0 = E1) |
+//!
+//! Export writes the same format with every field present, so an export
+//! re-imports losslessly.
+//!
+//! # Validation
+//!
+//! Each record goes through `TypedEdge::new`, which checks the weight range,
+//! that asymmetric types carry a direction and that the agreement bitset is
+//! consistent. Self-loops are rejected. Edges are keyed by (source, target)
+//! in storage, so a later record for a pair already seen in the same import
+//! is rejected as a duplicate rather than silently overwriting it.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+
+use context_graph_core::graph_linking::{DirectedRelation, GraphLinkEdgeType, TypedEdge};
+use rocksdb::IteratorMode;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use super::repository::EdgeRepository;
+use super::serialization::deserialize_typed_edge;
+use super::types::{GraphEdgeStorageError, GraphEdgeStorageResult};
+use crate::column_families::cf_names;
+
+/// Edges written per RocksDB batch during import.
+const IMPORT_BATCH_SIZE: usize = 500;
+
+/// Supported edge file formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdgeFormat {
+    /// One JSON `EdgeRecord` per line.
+    #[default]
+    Jsonl,
+}
+
+impl FromStr for EdgeFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "jsonl" => Ok(Self::Jsonl),
+            other => Err(format!("unknown edge format '{}' (expected: jsonl)", other)),
+        }
+    }
+}
+
+impl fmt::Display for EdgeFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Jsonl => write!(f, "jsonl"),
+        }
+    }
+}
+
+/// One line of the JSONL edge format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EdgeRecord {
+    pub source: Uuid,
+    pub target: Uuid,
+    pub edge_type: GraphLinkEdgeType,
+    pub weight: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub direction: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedder_scores: Option<[f32; 13]>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agreeing_embedders: Option<u16>,
+}
+
+impl EdgeRecord {
+    /// Record carrying every field of `edge`.
+    pub fn from_edge(edge: &TypedEdge) -> Self {
+        Self {
+            source: edge.source(),
+            target: edge.target(),
+            edge_type: edge.edge_type(),
+            weight: edge.weight(),
+            direction: Some(direction_name(edge.direction()).to_string()),
+            embedder_scores: Some(*edge.embedder_scores()),
+            agreeing_embedders: Some(edge.agreeing_embedders()),
+        }
+    }
+
+    /// Validate the record and build the edge, filling in defaults.
+    pub fn into_edge(self) -> Result<TypedEdge, String> {
+        if self.source == self.target {
+            return Err("source and target are the same node".to_string());
+        }
+        let direction = match self.direction.as_deref() {
+            None if self.edge_type.is_asymmetric() => DirectedRelation::Forward,
+            None => DirectedRelation::Symmetric,
+            Some("symmetric") => DirectedRelation::Symmetric,
+            Some("forward") => DirectedRelation::Forward,
+            Some("backward") => DirectedRelation::Backward,
+            Some(other) => {
+                return Err(format!(
+                    "unknown direction '{}' (expected symmetric, forward or backward)",
+                    other
+                ))
+            }
+        };
+        let agreeing = self.agreeing_embedders.unwrap_or(0);
+        if agreeing >> 13 != 0 {
+            return Err(format!(
+                "agreeing_embedders {:#b} sets bits beyond E13",
+                agreeing
+            ));
+        }
+        TypedEdge::new(
+            self.source,
+            self.target,
+            self.edge_type,
+            self.weight,
+            direction,
+            self.embedder_scores.unwrap_or([0.0; 13]),
+            agreeing.count_ones() as u8,
+            agreeing,
+        )
+        .map_err(|e| e.to_string())
+    }
+}
+
+fn direction_name(direction: DirectedRelation) -> &'static str {
+    match direction {
+        DirectedRelation::Symmetric => "symmetric",
+        DirectedRelation::Forward => "forward",
+        DirectedRelation::Backward => "backward",
+    }
+}
+
+/// A line that was not imported.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RejectedEdge {
+    /// 1-based line number in the input.
+    pub line: usize,
+    pub reason: String,
+}
+
+/// Outcome of `EdgeRepository::import_edges`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportReport {
+    /// Non-blank lines read.
+    pub lines_read: usize,
+    /// Edges written to storage.
+    pub accepted: usize,
+    /// Lines skipped, with their reasons, in input order.
+    pub rejected: Vec<RejectedEdge>,
+}
+
+impl ImportReport {
+    /// Number of rejected lines.
+    pub fn rejected_count(&self) -> usize {
+        self.rejected.len()
+    }
+}
+
+/// Which typed edges `export_edges` writes. Empty filter exports all.
+#[derive(Debug, Clone, Default)]
+pub struct EdgeExportFilter {
+    pub edge_type: Option<GraphLinkEdgeType>,
+    pub source: Option<Uuid>,
+}
+
+impl EdgeExportFilter {
+    fn matches(&self, edge: &TypedEdge) -> bool {
+        self.edge_type.is_none_or(|t| edge.edge_type() == t)
+            && self.source.is_none_or(|s| edge.source() == s)
+    }
+}
+
+impl EdgeRepository {
+    /// Import typed edges from `reader`.
+    ///
+    /// Invalid and duplicate lines are reported, not fatal; only I/O and
+    /// storage failures abort the import. Edges accepted before such a
+    /// failure may already be written.
+    pub fn import_edges(
+        &self,
+        reader: impl BufRead,
+        format: EdgeFormat,
+    ) -> GraphEdgeStorageResult<ImportReport> {
+        let EdgeFormat::Jsonl = format;
+        let mut report = ImportReport::default();
+        let mut seen: HashMap<(Uuid, Uuid), usize> = HashMap::new();
+        let mut batch: Vec<TypedEdge> = Vec::with_capacity(IMPORT_BATCH_SIZE);
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line.map_err(|e| GraphEdgeStorageError::io("import_edges", e))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let line_no = index + 1;
+            report.lines_read += 1;
+
+            let parsed = serde_json::from_str::<EdgeRecord>(&line)
+                .map_err(|e| format!("invalid record: {}", e))
+                .and_then(EdgeRecord::into_edge);
+            let edge = match parsed {
+                Ok(edge) => edge,
+                Err(reason) => {
+                    debug!(line = line_no, %reason, "Rejected edge");
+                    report.rejected.push(RejectedEdge {
+                        line: line_no,
+                        reason,
+                    });
+                    continue;
+                }
+            };
+            if let Some(first) = seen.get(&(edge.source(), edge.target())) {
+                report.rejected.push(RejectedEdge {
+                    line: line_no,
+                    reason: format!("duplicate of line {}", first),
+                });
+                continue;
+            }
+            seen.insert((edge.source(), edge.target()), line_no);
+
+            batch.push(edge);
+            if batch.len() == IMPORT_BATCH_SIZE {
+                self.store_typed_edges_batch(&batch)?;
+                report.accepted += batch.len();
+                batch.clear();
+            }
+        }
+        self.store_typed_edges_batch(&batch)?;
+        report.accepted += batch.len();
+
+        info!(
+            lines = report.lines_read,
+            accepted = report.accepted,
+            rejected = report.rejected_count(),
+            "Edge import complete"
+        );
+        Ok(report)
+    }
+
+    /// Write the typed edges matching `filter` to `writer` as JSONL.
+    ///
+    /// Returns the number of edges written.
+    pub fn export_edges(
+        &self,
+        mut writer: impl Write,
+        filter: &EdgeExportFilter,
+    ) -> GraphEdgeStorageResult<u64> {
+        let cf = self.db.cf_handle(cf_names::TYPED_EDGES).ok_or(
+            GraphEdgeStorageError::ColumnFamilyNotFound {
+                name: cf_names::TYPED_EDGES,
+            },
+        )?;
+
+        let mut written = 0u64;
+        for item in self.db.iterator_cf(&cf, IteratorMode::Start) {
+            let (_key, value) = item.map_err(|e| {
+                GraphEdgeStorageError::rocksdb("export_edges", cf_names::TYPED_EDGES, e)
+            })?;
+            let edge = deserialize_typed_edge(&value)?;
+            if !filter.matches(&edge) {
+                continue;
+            }
+            let line = serde_json::to_string(&EdgeRecord::from_edge(&edge))
+                .map_err(|e| GraphEdgeStorageError::serialization("export_edges", e.to_string()))?;
+            writeln!(writer, "{}", line)
+                .map_err(|e| GraphEdgeStorageError::io("export_edges", e))?;
+            written += 1;
+        }
+        writer
+            .flush()
+            .map_err(|e| GraphEdgeStorageError::io("export_edges", e))?;
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column_families::get_column_family_descriptors;
+    use rocksdb::{Cache, Options, DB};
+    use std::io::Cursor;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    fn create_test_repo() -> (TempDir, EdgeRepository) {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new_lru_cache(64 * 1024 * 1024);
+        let descriptors = get_column_family_descriptors(&cache);
+
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.create_missing_column_families(true);
+
+        let db = DB::open_cf_descriptors(&opts, temp_dir.path(), descriptors).unwrap();
+        (temp_dir, EdgeRepository::new(Arc::new(db)))
+    }
+
+    fn record_line(source: Uuid, target: Uuid, edge_type: &str, weight: f32) -> String {
+        serde_json::json!({
+            "source": source,
+            "target": target,
+            "edge_type": edge_type,
+            "weight": weight
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_import_1000_lines_with_5_malformed() {
+        let (_temp, repo) = create_test_repo();
+        let nodes: Vec<Uuid> = (0..1000).map(|_| Uuid::new_v4()).collect();
+        let bad_lines = [7usize, 150, 333, 501, 999];
+
+        let mut input = String::new();
+        for line_no in 1..=1000usize {
+            let source = nodes[line_no - 1];
+            let target = nodes[line_no % 1000];
+            let line = match bad_lines.iter().position(|&b| b == line_no) {
+                Some(0) => "{not json".to_string(),
+                Some(1) => record_line(source, target, "semantic_similar", 1.5),
+                Some(2) => record_line(source, target, "no_such_type", 0.5),
+                Some(3) => record_line(source, source, "code_related", 0.5),
+                Some(_) => serde_json::json!({
+                    "source": source,
+                    "target": target,
+                    "edge_type": "causal_chain",
+                    "weight": 0.5,
+                    "direction": "symmetric"
+                })
+                .to_string(),
+                None => record_line(source, target, "code_related", 0.7),
+            };
+            input.push_str(&line);
+            input.push('\n');
+        }
+
+        let report = repo
+            .import_edges(Cursor::new(input), EdgeFormat::Jsonl)
+            .unwrap();
+        assert_eq!(report.lines_read, 1000);
+        assert_eq!(report.accepted, 995);
+        let rejected_lines: Vec<usize> = report.rejected.iter().map(|r| r.line).collect();
+        assert_eq!(rejected_lines, bad_lines);
+        assert!(report.rejected[3].reason.contains("same node"));
+        assert_eq!(repo.get_stats().unwrap().typed_edge_count, 995);
+    }
+
+    #[test]
+    fn test_duplicates_rejected_and_defaults_applied() {
+        let (_temp, repo) = create_test_repo();
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let input = format!(
+            "{}\n\n{}\n",
+            record_line(a, b, "causal_chain", 0.6),
+            record_line(a, b, "code_related", 0.9)
+        );
+
+        let report = repo
+            .import_edges(Cursor::new(input), EdgeFormat::Jsonl)
+            .unwrap();
+        assert_eq!(report.accepted, 1);
+        assert_eq!(
+            report.rejected,
+            vec![RejectedEdge {
+                line: 3,
+                reason: "duplicate of line 1".to_string()
+            }]
+        );
+        let edge = repo.get_typed_edge(a, b).unwrap().unwrap();
+        assert_eq!(edge.edge_type(), GraphLinkEdgeType::CausalChain);
+        assert_eq!(edge.direction(), DirectedRelation::Forward);
+        assert_eq!(edge.agreement_count(), 0);
+    }
+
+    #[test]
+    fn test_export_round_trips_and_filters() {
+        let (_temp, repo) = create_test_repo();
+        let source = Uuid::new_v4();
+        let input: String = (0..4)
+            .map(|i| {
+                let edge_type = if i % 2 == 0 {
+                    "code_related"
+                } else {
+                    "keyword_overlap"
+                };
+                let mut line = record_line(source, Uuid::new_v4(), edge_type, 0.5);
+                line.push('\n');
+                line
+            })
+            .collect();
+        repo.import_edges(Cursor::new(input), EdgeFormat::Jsonl)
+            .unwrap();
+
+        let mut out = Vec::new();
+        let filter = EdgeExportFilter {
+            edge_type: Some(GraphLinkEdgeType::CodeRelated),
+            source: None,
+        };
+        assert_eq!(repo.export_edges(&mut out, &filter).unwrap(), 2);
+
+        let mut all = Vec::new();
+        assert_eq!(
+            repo.export_edges(&mut all, &EdgeExportFilter::default())
+                .unwrap(),
+            4
+        );
+        let (_temp2, other) = create_test_repo();
+        let report = other
+            .import_edges(Cursor::new(all), EdgeFormat::Jsonl)
+            .unwrap();
+        assert_eq!(report.accepted, 4);
+        assert_eq!(other.get_typed_edges_from(source).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_edge_format_parse() {
+        assert_eq!("jsonl".parse::<EdgeFormat>(), Ok(EdgeFormat::Jsonl));
+        assert!("csv".parse::<EdgeFormat>().is_err());
+    }
+}
@@ -74,6 +74,14 @@ pub enum GraphEdgeStorageError {
     /// Graph building error.
     #[error("Graph build error: {message}")]
     GraphBuildError { message: String },
+
+    /// Reading an edge import or writing an edge export failed.
+    #[error("I/O error in {operation}: {source}")]
+    Io {
+        operation: &'static str,
+        #[source]
+        source: std::io::Error,
+    },
 }
 
 impl GraphEdgeStorageError {
@@ -106,6 +114,11 @@ impl GraphEdgeStorageError {
         }
     }
 
+    /// Create an I/O error with context.
+    pub fn io(operation: &'static str, source: std::io::Error) -> Self {
+        Self::Io { operation, source }
+    }
+
     /// Create an edge not found error.
     pub fn edge_not_found(source_node: Uuid, target_node: Option<Uuid>, embedder: Option<u8>) -> Self {
         Self::EdgeNotFound {
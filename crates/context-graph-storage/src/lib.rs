@@ -15,6 +15,7 @@
 //! - `graph_edges`: K-NN graph edges and typed edges
 //! - `indexes`: Secondary index operations (tags, temporal, sources)
 //! - `code`: Code entity and E7 embedding storage (separate from text)
+//! - `temporal_refresh`: Periodic rewrite of stale E2 recency vectors
 //!
 //! # Column Families
 //!
@@ -33,6 +34,7 @@ pub mod error;
 pub mod graph_edges;
 pub mod indexes;
 pub mod teleological;
+pub mod temporal_refresh;
 
 // Re-export column family types for storage consumers
 pub use column_families::{
@@ -89,10 +91,16 @@ pub use teleological::{
     QuantizedStorageError,
     QuantizedStorageResult,
     // RocksDB teleological store (TASK: test-remediation)
+    ContentBlobReader,
+    AccessStatsConfig,
+    FsyncPolicy,
+    RebuildStats,
     RocksDbTeleologicalStore,
+    StoreAccessMode,
     TeleologicalStoreConfig,
     TeleologicalStoreError,
     TeleologicalStoreResult,
+    WriteMetrics,
     CF_E13_SPLADE_INVERTED,
     // Column family names and functions
     CF_E1_MATRYOSHKA_128,
@@ -137,6 +145,21 @@ pub use teleological::{
     CF_IMPORTANCE_HISTORY,
     merge_history_cf_options,
     importance_history_cf_options,
+    // Merge/consolidation lineage
+    CF_MEMORY_LINEAGE,
+    memory_lineage_cf_options,
+    // Content-addressable content blobs
+    CF_CONTENT_BLOBS,
+    content_blobs_cf_options,
+    // Topic records maintained across detection runs
+    CF_TOPICS,
+    topics_cf_options,
+    // Per-tool execution audit log
+    CF_TOOL_AUDIT_LOG,
+    tool_audit_log_cf_options,
+    // Per-memory access statistics
+    CF_ACCESS_STATS,
+    access_stats_cf_options,
 };
 
 // Re-export code storage types (CODE-001)
@@ -155,6 +178,12 @@ pub use context_graph_core::types::{
 
 // Re-export graph edges storage types (TASK-GRAPHLINK)
 pub use graph_edges::{
-    BackgroundGraphBuilder, BatchBuildResult, BuilderStats, EdgeRepository, GraphBuilderConfig,
-    GraphEdgeStats, GraphEdgeStorageError, GraphEdgeStorageResult, RebuildResult,
+    BackgroundGraphBuilder, BatchBuildResult, BuilderStats, EdgeInferenceReport,
+    EdgeInferenceService, EdgeRepository, GraphBuilderConfig, GraphEdgeStats,
+    GraphEdgeStorageError, GraphEdgeStorageResult, RebuildResult,
+};
+
+// Re-export temporal refresh types
+pub use temporal_refresh::{
+    RecencyEncoding, TemporalRefreshConfig, TemporalRefreshCursor, TemporalRefreshJob,
 };
@@ -339,7 +339,96 @@ pub const CF_CUSTOM_WEIGHT_PROFILES: &str = "custom_weight_profiles";
 /// - Updated periodically + at shutdown
 pub const CF_HNSW_GRAPHS: &str = "hnsw_graphs";
 
-/// All teleological column family names (20 total).
+/// Column family for the content_hash secondary index.
+///
+/// Maps the SHA-256 content hash of a memory to every fingerprint stored with
+/// that hash, enabling O(1) duplicate detection at store time.
+///
+/// Key: content_hash (32 bytes) → Value: Vec<Uuid> via `serialize_memory_id_list`
+///
+/// # Storage Details
+/// - LZ4 compression
+/// - Bloom filter for fast "is this content already stored?" checks
+/// - Updated in the same WriteBatch as the fingerprint (store + delete)
+pub const CF_CONTENT_HASH_INDEX: &str = "content_hash_index";
+
+/// Column family for memory lineage records.
+///
+/// Every merge (`merge_concepts`) and consolidation (`trigger_consolidation`)
+/// writes one `LineageRecord` keyed by the child memory, so the ancestry DAG
+/// of any memory can be walked parent by parent.
+///
+/// Key: `{child_uuid_bytes}_{timestamp_nanos_be}` (16 + 8 = 24 bytes)
+/// Value: LineageRecord serialized via JSON (~200-1000 bytes)
+///
+/// # Storage Details
+/// - LZ4 compression
+/// - Bloom filter for fast child_id lookups
+/// - PERMANENT: never expires, never deleted
+pub const CF_MEMORY_LINEAGE: &str = "memory_lineage";
+
+/// Column family for content-addressable content blobs.
+///
+/// Raw memory content is stored once per distinct SHA-256 `content_hash`
+/// and shared by every fingerprint with that hash. Three key kinds share
+/// the CF, distinguished by a prefix byte:
+///
+/// - `b` + content_hash (33 bytes) → codec byte + payload (raw or zstd)
+/// - `r` + content_hash (33 bytes) → referencing fingerprint IDs (`serialize_memory_id_list`)
+/// - `i` + UUID (17 bytes) → content_hash (32 bytes)
+///
+/// # Storage Details
+/// - Payloads above 4 KiB are zstd-compressed when that makes them smaller
+/// - LZ4 at the RocksDB level for the remaining small raw payloads
+/// - Bloom filter for point lookups
+/// - The blob is deleted with its last reference (hard delete or delete_content)
+pub const CF_CONTENT_BLOBS: &str = "content_blobs";
+
+/// Column family for topic records maintained across detection runs.
+///
+/// One `TopicRecord` per topic, keyed by its stable ID. detect_topics
+/// reconciles each run against these records so IDs survive membership
+/// drift; deletes and merges rewrite the member lists in place.
+///
+/// Key: topic UUID (16 bytes)
+/// Value: TopicRecord serialized via JSON (~1-20KB, dominated by centroids)
+///
+/// # Storage Details
+/// - LZ4 compression (JSON compresses well)
+/// - Full scans on read: the portfolio holds tens to hundreds of topics
+pub const CF_TOPICS: &str = "topics";
+
+/// Column family for the per-tool execution audit log.
+///
+/// One `ToolAuditRecord` per mutating MCP tool call (store, forget, merge,
+/// boost, consolidation, import/export), with content arguments already
+/// redacted to hashes. Answers "who stored or forgot what, and when".
+///
+/// Key: `{timestamp_nanos_be}_{uuid_bytes}` (8 + 16 = 24 bytes)
+/// Value: ToolAuditRecord serialized via JSON (~200-1000 bytes)
+///
+/// # Storage Details
+/// - LZ4 compression (JSON compresses well)
+/// - Big-endian timestamp prefix ensures chronological iteration
+/// - Append-only; records older than the retention window are range-deleted
+pub const CF_TOOL_AUDIT_LOG: &str = "tool_audit_log";
+
+/// Column family for per-memory access statistics.
+///
+/// Total hits, last access time and per-day hit counts for the last 7 days,
+/// folded in from the in-memory access tracker by a background flush.
+/// Feeds recency/frequency signals to importance scoring.
+///
+/// Key: UUID (16 bytes)
+/// Value: fixed 72-byte big-endian record (hits, last access, 7 day buckets)
+///
+/// # Storage Details
+/// - No compression: records are small and fixed-size
+/// - Bloom filter for point lookups
+/// - Written in batches every few seconds, never on the read path
+pub const CF_ACCESS_STATS: &str = "access_stats";
+
+/// All teleological column family names (26 total).
 pub const TELEOLOGICAL_CFS: &[&str] = &[
     CF_FINGERPRINTS,
     CF_TOPIC_PROFILES,
@@ -361,10 +450,16 @@ pub const TELEOLOGICAL_CFS: &[&str] = &[
     CF_EMBEDDING_REGISTRY,
     CF_CUSTOM_WEIGHT_PROFILES,
     CF_HNSW_GRAPHS,
+    CF_CONTENT_HASH_INDEX,
+    CF_MEMORY_LINEAGE,
+    CF_CONTENT_BLOBS,
+    CF_TOPICS,
+    CF_TOOL_AUDIT_LOG,
+    CF_ACCESS_STATS,
 ];
 
 /// Total count of teleological CFs.
-pub const TELEOLOGICAL_CF_COUNT: usize = 20;
+pub const TELEOLOGICAL_CF_COUNT: usize = 26;
 
 // =============================================================================
 // QUANTIZED EMBEDDER COLUMN FAMILIES (13 CFs for per-embedder storage)
@@ -613,6 +708,108 @@ pub fn hnsw_graphs_cf_options(cache: &Cache) -> Options {
     opts
 }
 
+/// Options for the content_hash secondary index (hash -> Vec<Uuid> mapping).
+///
+/// # Configuration
+/// - LZ4 compression
+/// - Bloom filter for fast hash existence checks (most lookups miss)
+/// - Point lookups only
+///
+/// # Key Format
+/// SHA-256 content hash (32 bytes).
+///
+/// # Value Format
+/// Vec<Uuid> via `serialize_memory_id_list` (4-byte count + 16 bytes per UUID).
+///
+/// # FAIL FAST Policy
+/// No fallback options - let RocksDB error on open if misconfigured.
+pub fn content_hash_index_cf_options(cache: &Cache) -> Options {
+    let mut block_opts = BlockBasedOptions::default();
+    block_opts.set_block_cache(cache);
+    block_opts.set_bloom_filter(10.0, false);
+    block_opts.set_cache_index_and_filter_blocks(true);
+
+    let mut opts = Options::default();
+    opts.set_block_based_table_factory(&block_opts);
+    opts.set_compression_type(rocksdb::DBCompressionType::Lz4);
+    opts.optimize_for_point_lookup(16); // 16MB hint for point lookups
+    apply_write_buffer_limits(&mut opts, 2); // small index entries
+    opts.create_if_missing(true);
+    // FAIL FAST: No fallback options - let RocksDB error on open if misconfigured
+    opts
+}
+
+/// Options for memory lineage records (~200-1000 bytes per record).
+///
+/// # Configuration
+/// - LZ4 compression (JSON compresses well)
+/// - Bloom filter for fast child_id lookups
+/// - Append-only, prefix scans by child_id
+///
+/// # FAIL FAST Policy
+/// No fallback options - let RocksDB error on open if misconfigured.
+pub fn memory_lineage_cf_options(cache: &Cache) -> Options {
+    let mut block_opts = BlockBasedOptions::default();
+    block_opts.set_block_cache(cache);
+    block_opts.set_bloom_filter(10.0, false);
+    block_opts.set_cache_index_and_filter_blocks(true);
+
+    let mut opts = Options::default();
+    opts.set_block_based_table_factory(&block_opts);
+    opts.set_compression_type(rocksdb::DBCompressionType::Lz4);
+    opts.set_compaction_style(rocksdb::DBCompactionStyle::Level);
+    apply_write_buffer_limits(&mut opts, 2); // append, low volume
+    opts.create_if_missing(true);
+    // FAIL FAST: No fallback options - let RocksDB error on open if misconfigured
+    opts
+}
+
+/// Options for content blobs (variable size, up to 1MB, plus small index keys).
+///
+/// # Configuration
+/// - LZ4 compression (zstd payloads are already compressed; raw ones are small)
+/// - Bloom filter for hash and UUID point lookups
+///
+/// # FAIL FAST Policy
+/// No fallback options - let RocksDB error on open if misconfigured.
+pub fn content_blobs_cf_options(cache: &Cache) -> Options {
+    let mut block_opts = BlockBasedOptions::default();
+    block_opts.set_block_cache(cache);
+    block_opts.set_bloom_filter(10.0, false);
+    block_opts.set_cache_index_and_filter_blocks(true);
+
+    let mut opts = Options::default();
+    opts.set_block_based_table_factory(&block_opts);
+    opts.set_compression_type(rocksdb::DBCompressionType::Lz4);
+    opts.set_compaction_style(rocksdb::DBCompactionStyle::Level);
+    apply_write_buffer_limits(&mut opts, 8); // up to 1MB values
+    opts.create_if_missing(true);
+    // FAIL FAST: No fallback options - let RocksDB error on open if misconfigured
+    opts
+}
+
+/// Options for topic records (small JSON values, read by full scan).
+///
+/// # Configuration
+/// - LZ4 compression (JSON compresses well)
+/// - Small write buffer: rewritten once per detection run
+///
+/// # FAIL FAST Policy
+/// No fallback options - let RocksDB error on open if misconfigured.
+pub fn topics_cf_options(cache: &Cache) -> Options {
+    let mut block_opts = BlockBasedOptions::default();
+    block_opts.set_block_cache(cache);
+    block_opts.set_cache_index_and_filter_blocks(true);
+
+    let mut opts = Options::default();
+    opts.set_block_based_table_factory(&block_opts);
+    opts.set_compression_type(rocksdb::DBCompressionType::Lz4);
+    apply_write_buffer_limits(&mut opts, 2); // tens to hundreds of records
+    opts.create_if_missing(true);
+    // FAIL FAST: No fallback options - let RocksDB error on open if misconfigured
+    opts
+}
+
 /// Options for content text storage (variable size, up to 1MB).
 ///
 /// # Configuration
@@ -844,6 +1041,54 @@ pub fn audit_log_cf_options(cache: &Cache) -> Options {
     opts
 }
 
+/// Options for the tool execution audit log.
+///
+/// # Configuration
+/// - LZ4 compression (JSON arguments compress well)
+/// - No bloom filter: only range scans, never point lookups
+/// - Small write buffer: one record per mutating tool call
+///
+/// # FAIL FAST Policy
+/// No fallback options - let RocksDB error on open if misconfigured.
+pub fn tool_audit_log_cf_options(cache: &Cache) -> Options {
+    let mut block_opts = BlockBasedOptions::default();
+    block_opts.set_block_cache(cache);
+    block_opts.set_cache_index_and_filter_blocks(true);
+
+    let mut opts = Options::default();
+    opts.set_block_based_table_factory(&block_opts);
+    opts.set_compression_type(rocksdb::DBCompressionType::Lz4);
+    opts.set_compaction_style(rocksdb::DBCompactionStyle::Level);
+    apply_write_buffer_limits(&mut opts, 2); // append, low volume
+    opts.create_if_missing(true);
+    // FAIL FAST: No fallback options - let RocksDB error on open if misconfigured
+    opts
+}
+
+/// Options for per-memory access statistics.
+///
+/// # Configuration
+/// - No compression: 72-byte fixed records barely compress
+/// - Bloom filter for point lookups when stats are read
+/// - Small write buffer: batched flushes every few seconds
+///
+/// # FAIL FAST Policy
+/// No fallback options - let RocksDB error on open if misconfigured.
+pub fn access_stats_cf_options(cache: &Cache) -> Options {
+    let mut block_opts = BlockBasedOptions::default();
+    block_opts.set_block_cache(cache);
+    block_opts.set_bloom_filter(10.0, false);
+    block_opts.set_cache_index_and_filter_blocks(true);
+
+    let mut opts = Options::default();
+    opts.set_block_based_table_factory(&block_opts);
+    opts.set_compression_type(rocksdb::DBCompressionType::None);
+    apply_write_buffer_limits(&mut opts, 2); // small batched overwrites
+    opts.create_if_missing(true);
+    // FAIL FAST: No fallback options - let RocksDB error on open if misconfigured
+    opts
+}
+
 /// Options for audit log by-target secondary index.
 ///
 /// # Configuration
@@ -1052,13 +1297,13 @@ pub fn quantized_embedder_cf_options(cache: &Cache) -> Options {
     opts
 }
 
-/// Get all 20 teleological column family descriptors.
+/// Get all 26 teleological column family descriptors.
 ///
 /// # Arguments
 /// * `cache` - Shared block cache (recommended: 256MB via `Cache::new_lru_cache`)
 ///
 /// # Returns
-/// Vector of 20 `ColumnFamilyDescriptor`s for teleological storage.
+/// Vector of 26 `ColumnFamilyDescriptor`s for teleological storage.
 pub fn get_teleological_cf_descriptors(cache: &Cache) -> Vec<ColumnFamilyDescriptor> {
     vec![
         ColumnFamilyDescriptor::new(CF_FINGERPRINTS, fingerprint_cf_options(cache)),
@@ -1105,6 +1350,18 @@ pub fn get_teleological_cf_descriptors(cache: &Cache) -> Vec<ColumnFamilyDescrip
         ColumnFamilyDescriptor::new(CF_CUSTOM_WEIGHT_PROFILES, custom_weight_profiles_cf_options(cache)),
         // HNSW graph persistence for fast startup
         ColumnFamilyDescriptor::new(CF_HNSW_GRAPHS, hnsw_graphs_cf_options(cache)),
+        // content_hash -> Vec<Uuid> secondary index for duplicate detection
+        ColumnFamilyDescriptor::new(CF_CONTENT_HASH_INDEX, content_hash_index_cf_options(cache)),
+        // Merge/consolidation ancestry for get_memory_lineage
+        ColumnFamilyDescriptor::new(CF_MEMORY_LINEAGE, memory_lineage_cf_options(cache)),
+        // content_hash -> shared content blob with reference list
+        ColumnFamilyDescriptor::new(CF_CONTENT_BLOBS, content_blobs_cf_options(cache)),
+        // Topic records with IDs stable across detection runs
+        ColumnFamilyDescriptor::new(CF_TOPICS, topics_cf_options(cache)),
+        // Per-tool execution audit log for query_audit_log
+        ColumnFamilyDescriptor::new(CF_TOOL_AUDIT_LOG, tool_audit_log_cf_options(cache)),
+        // Per-memory access statistics for recency/frequency signals
+        ColumnFamilyDescriptor::new(CF_ACCESS_STATS, access_stats_cf_options(cache)),
     ]
 }
 
@@ -1137,14 +1394,14 @@ pub fn get_quantized_embedder_cf_descriptors(cache: &Cache) -> Vec<ColumnFamilyD
 
 /// Get ALL teleological + quantized embedder column family descriptors.
 ///
-/// Returns 33 descriptors total: 20 teleological + 13 quantized embedder.
+/// Returns 39 descriptors total: 26 teleological + 13 quantized embedder.
 /// Use this when opening a database that needs both fingerprint and per-embedder storage.
 ///
 /// # Arguments
 /// * `cache` - Shared block cache (recommended: 256MB via `Cache::new_lru_cache`)
 ///
 /// # Returns
-/// Vector of 33 `ColumnFamilyDescriptor`s.
+/// Vector of 39 `ColumnFamilyDescriptor`s.
 ///
 /// # Example
 /// ```ignore
@@ -1153,7 +1410,7 @@ pub fn get_quantized_embedder_cf_descriptors(cache: &Cache) -> Vec<ColumnFamilyD
 ///
 /// let cache = Cache::new_lru_cache(256 * 1024 * 1024); // 256MB
 /// let descriptors = get_all_teleological_cf_descriptors(&cache);
-/// assert_eq!(descriptors.len(), 33); // 20 teleological + 13 embedder
+/// assert_eq!(descriptors.len(), 39); // 26 teleological + 13 embedder
 /// ```
 pub fn get_all_teleological_cf_descriptors(cache: &Cache) -> Vec<ColumnFamilyDescriptor> {
     let mut descriptors = get_teleological_cf_descriptors(cache);
@@ -1481,13 +1738,13 @@ pub fn get_causal_cf_descriptors(cache: &Cache) -> Vec<ColumnFamilyDescriptor> {
 
 /// Get ALL column family descriptors (teleological + embedder + code + causal).
 ///
-/// Returns 40 descriptors total: 20 teleological + 13 quantized embedder + 5 code + 2 causal.
+/// Returns 46 descriptors total: 26 teleological + 13 quantized embedder + 5 code + 2 causal.
 ///
 /// # Arguments
 /// * `cache` - Shared block cache (recommended: 256MB via `Cache::new_lru_cache`)
 ///
 /// # Returns
-/// Vector of 40 `ColumnFamilyDescriptor`s.
+/// Vector of 46 `ColumnFamilyDescriptor`s.
 pub fn get_all_cf_descriptors(cache: &Cache) -> Vec<ColumnFamilyDescriptor> {
     let mut descriptors = get_all_teleological_cf_descriptors(cache);
     descriptors.extend(get_code_cf_descriptors(cache));
@@ -0,0 +1,420 @@
+//! Parallel HNSW index builds with progress reporting.
+//!
+//! Rebuilding the per-space indexes from CF_FINGERPRINTS inserts every
+//! fingerprint into every index. Done one fingerprint at a time this is
+//! single-threaded and takes minutes on large stores, with nothing to show
+//! for it. A build instead stages each space's vectors and builds up to
+//! `parallelism` spaces at once, each through the rayon-parallel
+//! `HnswEmbedderIndex::insert_batch`.
+//!
+//! # Memory
+//!
+//! Staging holds a copy of every vector while the indexes fill, so a
+//! concurrent build peaks at roughly twice the size of the finished
+//! indexes. [`plan_build`] estimates that peak from the vector count and
+//! dimensions and chooses [`IndexBuildPlan::Sequential`] (stream
+//! fingerprints straight into the indexes, nothing staged) when it exceeds
+//! the configured budget.
+//!
+//! # Progress
+//!
+//! Each space reports [`IndexBuildProgress`] to the configured callback
+//! after every chunk, and logs at every 10% step.
+
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use super::embedder_index::{EmbedderIndexOps, IndexError};
+use super::get_hnsw_config;
+use super::hnsw_config::EmbedderIndex;
+use super::hnsw_impl::HnswEmbedderIndex;
+
+/// Spaces built at once by default. Each build is itself multi-threaded.
+pub const DEFAULT_BUILD_PARALLELISM: usize = 4;
+
+/// Default peak-memory budget for a concurrent build (16 GiB).
+pub const DEFAULT_BUILD_MEMORY_BUDGET: usize = 16 * 1024 * 1024 * 1024;
+
+/// Default number of vectors inserted between progress reports.
+pub const DEFAULT_BUILD_CHUNK_SIZE: usize = 4096;
+
+/// Staging overhead per vector: the `Uuid` plus the `Vec` header.
+const STAGED_OVERHEAD_BYTES: usize = 16 + 24;
+
+/// Id mapping overhead per indexed vector (`id_to_key` + `key_to_id`).
+const MAPPING_BYTES: usize = 2 * (16 + 8);
+
+/// Bytes per neighbor slot in the usearch graph.
+const NEIGHBOR_SLOT_BYTES: usize = 4;
+
+/// Progress of one space's index build.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexBuildProgress {
+    /// Space being built.
+    pub embedder: EmbedderIndex,
+    /// Vectors processed so far. Never decreases within a build.
+    pub inserted: usize,
+    /// Vectors this space will process in total.
+    pub total: usize,
+    /// Time since this space's build started.
+    pub elapsed: Duration,
+    /// Estimated time until this space finishes, once anything is inserted.
+    pub eta: Option<Duration>,
+}
+
+impl IndexBuildProgress {
+    /// Percentage done, 0-100. An empty build is 100% done.
+    pub fn percent(&self) -> u8 {
+        if self.total == 0 {
+            return 100;
+        }
+        ((self.inserted * 100) / self.total).min(100) as u8
+    }
+
+    /// True once every vector is processed.
+    pub fn is_complete(&self) -> bool {
+        self.inserted >= self.total
+    }
+}
+
+/// Receives [`IndexBuildProgress`] updates. Called from build threads.
+pub type IndexBuildProgressFn = Arc<dyn Fn(&IndexBuildProgress) + Send + Sync>;
+
+/// How HNSW indexes are rebuilt from stored fingerprints.
+#[derive(Clone)]
+pub struct IndexBuildConfig {
+    /// Spaces built at once. 1 forces a sequential build.
+    pub parallelism: usize,
+    /// Peak memory a concurrent build may use, in bytes. Builds estimated
+    /// above it run sequentially.
+    pub memory_budget_bytes: usize,
+    /// Vectors inserted between progress reports.
+    pub chunk_size: usize,
+    /// Progress callback (default: none, log only).
+    pub progress: Option<IndexBuildProgressFn>,
+}
+
+impl Default for IndexBuildConfig {
+    fn default() -> Self {
+        Self {
+            parallelism: DEFAULT_BUILD_PARALLELISM,
+            memory_budget_bytes: DEFAULT_BUILD_MEMORY_BUDGET,
+            chunk_size: DEFAULT_BUILD_CHUNK_SIZE,
+            progress: None,
+        }
+    }
+}
+
+impl IndexBuildConfig {
+    /// Report progress to `callback`.
+    pub fn with_progress(mut self, callback: IndexBuildProgressFn) -> Self {
+        self.progress = Some(callback);
+        self
+    }
+}
+
+impl fmt::Debug for IndexBuildConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IndexBuildConfig")
+            .field("parallelism", &self.parallelism)
+            .field("memory_budget_bytes", &self.memory_budget_bytes)
+            .field("chunk_size", &self.chunk_size)
+            .field("progress", &self.progress.is_some())
+            .finish()
+    }
+}
+
+/// Strategy chosen for a rebuild.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexBuildPlan {
+    /// Stage vectors per space and build spaces concurrently.
+    Concurrent,
+    /// Stream fingerprints into all indexes one at a time.
+    Sequential,
+}
+
+/// Outcome of the last HNSW rebuild.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexBuildSummary {
+    /// Strategy used.
+    pub plan: IndexBuildPlan,
+    /// Fingerprints indexed.
+    pub fingerprints: usize,
+    /// Spaces built.
+    pub spaces: usize,
+    /// Estimated peak memory of a concurrent build, in bytes.
+    pub estimated_peak_bytes: usize,
+    /// Wall-clock duration.
+    pub elapsed: Duration,
+}
+
+/// Estimated peak memory of a concurrent build of `vector_count` vectors
+/// into each of `embedders`: the staged copies plus the finished indexes.
+pub fn estimate_concurrent_build_bytes(vector_count: usize, embedders: &[EmbedderIndex]) -> usize {
+    embedders
+        .iter()
+        .filter_map(|&embedder| get_hnsw_config(embedder))
+        .map(|config| {
+            let vector_bytes = config.dimension * std::mem::size_of::<f32>();
+            let staged = vector_bytes + STAGED_OVERHEAD_BYTES;
+            let indexed = vector_bytes + 2 * config.m * NEIGHBOR_SLOT_BYTES + MAPPING_BYTES;
+            vector_count.saturating_mul(staged + indexed)
+        })
+        .fold(0usize, usize::saturating_add)
+}
+
+/// Choose how to build `embedders` for `vector_count` fingerprints.
+///
+/// Returns the plan and the concurrent-build estimate it was based on.
+pub fn plan_build(
+    vector_count: usize,
+    embedders: &[EmbedderIndex],
+    config: &IndexBuildConfig,
+) -> (IndexBuildPlan, usize) {
+    let estimate = estimate_concurrent_build_bytes(vector_count, embedders);
+    if config.parallelism <= 1 {
+        return (IndexBuildPlan::Sequential, estimate);
+    }
+    if estimate > config.memory_budget_bytes {
+        warn!(
+            "Concurrent HNSW build of {} vectors x {} spaces needs ~{} MB, over the {} MB budget — building sequentially",
+            vector_count,
+            embedders.len(),
+            estimate / (1024 * 1024),
+            config.memory_budget_bytes / (1024 * 1024)
+        );
+        return (IndexBuildPlan::Sequential, estimate);
+    }
+    (IndexBuildPlan::Concurrent, estimate)
+}
+
+/// Tracks one space's progress, reporting every `chunk_size` vectors and
+/// logging at each 10% step.
+pub(crate) struct ProgressTracker<'a> {
+    embedder: EmbedderIndex,
+    total: usize,
+    inserted: usize,
+    unreported: usize,
+    report_every: usize,
+    next_log_pct: u8,
+    started: Instant,
+    callback: Option<&'a IndexBuildProgressFn>,
+}
+
+impl<'a> ProgressTracker<'a> {
+    pub(crate) fn new(embedder: EmbedderIndex, total: usize, config: &'a IndexBuildConfig) -> Self {
+        Self {
+            embedder,
+            total,
+            inserted: 0,
+            unreported: 0,
+            report_every: config.chunk_size.max(1),
+            next_log_pct: 10,
+            started: Instant::now(),
+            callback: config.progress.as_ref(),
+        }
+    }
+
+    /// Record `n` more vectors processed.
+    pub(crate) fn advance(&mut self, n: usize) {
+        self.inserted = (self.inserted + n).min(self.total);
+        self.unreported += n;
+        if self.unreported >= self.report_every || self.inserted == self.total {
+            self.report();
+        }
+    }
+
+    /// Report the final state. Spaces with nothing to insert report once.
+    pub(crate) fn finish(&mut self) {
+        if self.unreported > 0 || self.total == 0 {
+            self.report();
+        }
+    }
+
+    fn report(&mut self) {
+        self.unreported = 0;
+        let elapsed = self.started.elapsed();
+        let eta = (self.inserted > 0)
+            .then(|| elapsed.mul_f64((self.total - self.inserted) as f64 / self.inserted as f64));
+        let progress = IndexBuildProgress {
+            embedder: self.embedder,
+            inserted: self.inserted,
+            total: self.total,
+            elapsed,
+            eta,
+        };
+        if let Some(callback) = self.callback {
+            callback(&progress);
+        }
+
+        let pct = progress.percent();
+        if pct >= self.next_log_pct {
+            info!(
+                "HNSW build {:?}: {}% ({}/{}), eta {:?}",
+                self.embedder,
+                pct,
+                self.inserted,
+                self.total,
+                eta.unwrap_or_default()
+            );
+            self.next_log_pct = (pct / 10 + 1).saturating_mul(10);
+        }
+    }
+}
+
+/// Vectors staged for one space.
+pub(crate) type StagedSpace = (Arc<HnswEmbedderIndex>, Vec<(Uuid, Vec<f32>)>);
+
+/// Build staged spaces, up to `config.parallelism` at once.
+///
+/// Every chunk is attempted even after a failure; the failures are
+/// returned so the caller can fail the rebuild as a whole.
+pub(crate) fn build_staged(
+    spaces: Vec<StagedSpace>,
+    config: &IndexBuildConfig,
+) -> Vec<(EmbedderIndex, IndexError)> {
+    let next = AtomicUsize::new(0);
+    let failures = Mutex::new(Vec::new());
+    let workers = config.parallelism.clamp(1, spaces.len().max(1));
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                while let Some((index, items)) = spaces.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let embedder = index.embedder();
+                    let mut tracker = ProgressTracker::new(embedder, items.len(), config);
+                    for chunk in items.chunks(config.chunk_size.max(1)) {
+                        if let Err(e) = index.insert_batch(chunk) {
+                            error!(
+                                "FAIL FAST: HNSW build of {:?} failed a batch: {}",
+                                embedder, e
+                            );
+                            failures.lock().push((embedder, e));
+                        }
+                        tracker.advance(chunk.len());
+                    }
+                    tracker.finish();
+                }
+            });
+        }
+    });
+
+    failures.into_inner()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collecting_config(
+        chunk_size: usize,
+    ) -> (IndexBuildConfig, Arc<Mutex<Vec<IndexBuildProgress>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let config = IndexBuildConfig {
+            chunk_size,
+            ..IndexBuildConfig::default()
+        }
+        .with_progress(Arc::new(move |p: &IndexBuildProgress| {
+            sink.lock().push(p.clone())
+        }));
+        (config, events)
+    }
+
+    #[test]
+    fn test_estimate_scales_with_count_and_dimension() {
+        let small = estimate_concurrent_build_bytes(1000, &[EmbedderIndex::E1Matryoshka128]);
+        let large = estimate_concurrent_build_bytes(1000, &[EmbedderIndex::E7Code]);
+        assert!(small > 1000 * 128 * 4 * 2);
+        assert!(large > small);
+        assert_eq!(
+            estimate_concurrent_build_bytes(2000, &[EmbedderIndex::E7Code]),
+            2 * large
+        );
+        assert_eq!(
+            estimate_concurrent_build_bytes(1000, &[EmbedderIndex::E6Sparse]),
+            0
+        );
+    }
+
+    #[test]
+    fn test_plan_respects_budget_and_parallelism() {
+        let spaces = EmbedderIndex::all_hnsw();
+        let config = IndexBuildConfig::default();
+        assert_eq!(
+            plan_build(100, &spaces, &config).0,
+            IndexBuildPlan::Concurrent
+        );
+
+        let tight = IndexBuildConfig {
+            memory_budget_bytes: 1024,
+            ..IndexBuildConfig::default()
+        };
+        let (plan, estimate) = plan_build(100, &spaces, &tight);
+        assert_eq!(plan, IndexBuildPlan::Sequential);
+        assert!(estimate > tight.memory_budget_bytes);
+
+        let single = IndexBuildConfig {
+            parallelism: 1,
+            ..IndexBuildConfig::default()
+        };
+        assert_eq!(
+            plan_build(100, &spaces, &single).0,
+            IndexBuildPlan::Sequential
+        );
+    }
+
+    #[test]
+    fn test_tracker_reports_monotonic_progress_per_chunk() {
+        let (config, events) = collecting_config(10);
+        let mut tracker = ProgressTracker::new(EmbedderIndex::E1Semantic, 35, &config);
+        for _ in 0..35 {
+            tracker.advance(1);
+        }
+        tracker.finish();
+
+        let events = events.lock();
+        let inserted: Vec<usize> = events.iter().map(|p| p.inserted).collect();
+        assert_eq!(inserted, vec![10, 20, 30, 35]);
+        assert!(events.last().unwrap().is_complete());
+        assert_eq!(events.last().unwrap().eta, Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_build_staged_builds_every_space() {
+        let (config, events) = collecting_config(16);
+        let spaces: Vec<StagedSpace> = [EmbedderIndex::E1Matryoshka128, EmbedderIndex::E8Graph]
+            .into_iter()
+            .map(|embedder| {
+                let dim = embedder.dimension().unwrap();
+                let items = (0..50)
+                    .map(|i| (Uuid::new_v4(), vec![0.1 + i as f32 / 100.0; dim]))
+                    .collect();
+                (Arc::new(HnswEmbedderIndex::new(embedder)), items)
+            })
+            .collect();
+        let indexes: Vec<_> = spaces.iter().map(|(index, _)| Arc::clone(index)).collect();
+
+        let failures = build_staged(spaces, &config);
+        assert!(failures.is_empty());
+        for index in &indexes {
+            assert_eq!(index.len(), 50);
+        }
+
+        let events = events.lock();
+        for index in &indexes {
+            let space: Vec<&IndexBuildProgress> = events
+                .iter()
+                .filter(|p| p.embedder == index.embedder())
+                .collect();
+            assert!(space.windows(2).all(|w| w[0].inserted <= w[1].inserted));
+            assert!(space.last().unwrap().is_complete());
+        }
+    }
+}
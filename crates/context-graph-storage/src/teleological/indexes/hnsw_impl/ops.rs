@@ -2,6 +2,10 @@
 //!
 //! Implements O(log n) insert and search operations via usearch HNSW graph.
 
+use std::collections::HashSet;
+
+use rayon::prelude::*;
+use usearch::Index;
 use uuid::Uuid;
 
 use super::super::embedder_index::{validate_vector, EmbedderIndexOps, IndexError, IndexResult};
@@ -35,7 +39,10 @@ impl EmbedderIndexOps for HnswEmbedderIndex {
         let is_update = if let Some(&old_key) = id_to_key.get(&id) {
             key_to_id.remove(&old_key);
             // Note: usearch doesn't support deletion, so the old vector remains in index
-            // but won't be returned because key_to_id doesn't map it back
+            // but won't be returned because key_to_id doesn't map it back.
+            // Count it as a tombstone so compaction sees re-embedded vectors too.
+            self.removed_count
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
             true
         } else {
             false
@@ -124,17 +131,12 @@ impl EmbedderIndexOps for HnswEmbedderIndex {
             return Ok(Vec::new());
         }
 
-        // Compute effective k - can't return more than we have
-        // Request more from usearch in case some are removed
+        // Compute effective k - can't return more than we have.
+        // Over-fetch by the tombstone count so that even if every orphaned
+        // vector ranks ahead of live ones, k live hits can still be returned.
+        // When k exceeds the live count this requests the whole graph.
         let active_count = key_to_id.len();
-        let request_k = if k > active_count {
-            // If k > active vectors, we need all of them
-            // But usearch might have orphaned keys, so request size()
-            index.size().max(k)
-        } else {
-            // Request k + some buffer for potentially removed entries
-            k * 2
-        };
+        let request_k = (k + self.removed_count()).min(index.size()).max(k.min(active_count));
 
         // O(log n) HNSW graph traversal - NOT brute force!
         let results = index
@@ -158,14 +160,103 @@ impl EmbedderIndexOps for HnswEmbedderIndex {
         Ok(output)
     }
 
+    /// Insert many vectors, adding them to the usearch graph in parallel.
+    ///
+    /// Keys are assigned and capacity is reserved under the write locks,
+    /// then the rayon pool adds the vectors (usearch allows concurrent `add`
+    /// once capacity and thread contexts are reserved). Only ids whose add
+    /// succeeded are mapped; the first failure is returned.
+    ///
+    /// Batches that update an existing id, or repeat an id, fall back to one
+    /// `insert` per item so tombstones are counted as usual.
+    #[allow(clippy::readonly_write_lock)] // usearch uses interior mutability via C++ FFI
     fn insert_batch(&self, items: &[(Uuid, Vec<f32>)]) -> IndexResult<usize> {
-        // Batch insert - could be optimized with usearch batch API if available
+        for (_, vector) in items {
+            validate_vector(vector, self.config.dimension, self.embedder)?;
+        }
+
+        let mut id_to_key = self.id_to_key.write();
+        let mut key_to_id = self.key_to_id.write();
+        let index = self.index.write();
+        let mut next_key = self.next_key.write();
+
+        let mut seen = HashSet::with_capacity(items.len());
+        let has_updates = items
+            .iter()
+            .any(|(id, _)| id_to_key.contains_key(id) || !seen.insert(*id));
+        if has_updates {
+            drop((id_to_key, key_to_id, index, next_key));
+            let mut count = 0;
+            for (id, vec) in items {
+                self.insert(*id, vec)?;
+                count += 1;
+            }
+            return Ok(count);
+        }
+
+        // H1 FIX: Same capacity limit as insert(), checked for the whole batch
+        if key_to_id.len() + items.len() > MAX_HNSW_VECTORS_PER_INDEX {
+            return Err(IndexError::OperationFailed {
+                embedder: self.embedder,
+                message: format!(
+                    "HNSW index at maximum capacity ({} vectors); batch of {} rejected. \
+                     Remove vectors or increase MAX_HNSW_VECTORS_PER_INDEX.",
+                    MAX_HNSW_VECTORS_PER_INDEX,
+                    items.len()
+                ),
+            });
+        }
+
+        let required = index.size() + items.len();
+        let capacity = if required > index.capacity() {
+            required.max(index.capacity() * 2).max(1024)
+        } else {
+            index.capacity()
+        };
+        index
+            .reserve_capacity_and_threads(capacity, rayon::current_num_threads())
+            .map_err(|e| IndexError::OperationFailed {
+                embedder: self.embedder,
+                message: format!("usearch reserve failed: {}", e),
+            })?;
+
+        let base_key = *next_key;
+        *next_key += items.len() as u64;
+
+        let graph: &Index = &index;
+        let results: Vec<Result<(), String>> = items
+            .par_iter()
+            .enumerate()
+            .map(|(offset, (_, vector))| {
+                graph
+                    .add(base_key + offset as u64, vector)
+                    .map_err(|e| e.to_string())
+            })
+            .collect();
+
         let mut count = 0;
-        for (id, vec) in items {
-            self.insert(*id, vec)?;
-            count += 1;
+        let mut first_error = None;
+        for (offset, ((id, _), result)) in items.iter().zip(results).enumerate() {
+            match result {
+                Ok(()) => {
+                    let key = base_key + offset as u64;
+                    id_to_key.insert(*id, key);
+                    key_to_id.insert(key, *id);
+                    count += 1;
+                }
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match first_error {
+            Some(message) => Err(IndexError::OperationFailed {
+                embedder: self.embedder,
+                message: format!("usearch add failed: {}", message),
+            }),
+            None => Ok(count),
         }
-        Ok(count)
     }
 
     fn flush(&self) -> IndexResult<()> {
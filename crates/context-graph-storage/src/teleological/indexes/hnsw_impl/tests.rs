@@ -578,4 +578,46 @@ mod tests {
 
         println!("RESULT: PASS - All 10 edge cases verified");
     }
+
+    #[test]
+    fn test_insert_batch_parallel_and_update_fallback() {
+        println!("=== TEST: Parallel insert_batch maps every id; updates fall back ===");
+
+        let index = HnswEmbedderIndex::new(EmbedderIndex::E8Graph);
+        let dim = index.config().dimension;
+        // Larger than the initial capacity so the batch path must reserve
+        let items: Vec<(Uuid, Vec<f32>)> = (0..3000)
+            .map(|i| {
+                let vec: Vec<f32> = (0..dim).map(|j| ((i * 7 + j) % 97) as f32 + 1.0).collect();
+                (Uuid::new_v4(), vec)
+            })
+            .collect();
+
+        assert_eq!(index.insert_batch(&items).unwrap(), 3000);
+        assert_eq!(index.len(), 3000);
+        assert_eq!(index.removed_count(), 0);
+        for (id, vec) in items.iter().step_by(500) {
+            let results = index.search(vec, 1, None).unwrap();
+            assert_eq!(results[0].0, *id);
+        }
+
+        // Re-inserting existing ids goes through insert(): one tombstone each
+        let updates: Vec<(Uuid, Vec<f32>)> = items[..10]
+            .iter()
+            .map(|(id, _)| (*id, vec![2.0f32; dim]))
+            .collect();
+        assert_eq!(index.insert_batch(&updates).unwrap(), 10);
+        assert_eq!(index.len(), 3000);
+        assert_eq!(index.removed_count(), 10);
+
+        // A bad vector rejects the whole batch before anything is added
+        let bad = vec![
+            (Uuid::new_v4(), vec![1.0f32; dim]),
+            (Uuid::new_v4(), vec![1.0f32; dim - 1]),
+        ];
+        assert!(index.insert_batch(&bad).is_err());
+        assert_eq!(index.len(), 3000);
+
+        println!("RESULT: PASS");
+    }
 }
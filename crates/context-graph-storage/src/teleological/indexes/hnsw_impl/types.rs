@@ -9,6 +9,7 @@ use parking_lot::RwLock;
 use usearch::{Index, IndexOptions, MetricKind, ScalarKind};
 use uuid::Uuid;
 
+use super::super::embedder_index::{IndexError, IndexResult};
 use super::super::get_hnsw_config;
 use super::super::hnsw_config::{DistanceMetric, EmbedderIndex, HnswConfig};
 
@@ -17,6 +18,9 @@ use super::super::hnsw_config::{DistanceMetric, EmbedderIndex, HnswConfig};
 /// Prevents unbounded memory growth from runaway inserts.
 pub(crate) const MAX_HNSW_VECTORS_PER_INDEX: usize = 2_000_000;
 
+/// Initial usearch capacity - usearch requires a reservation before adding vectors.
+const INITIAL_CAPACITY: usize = 1024;
+
 /// Convert our DistanceMetric to usearch MetricKind.
 ///
 /// # Panics
@@ -40,6 +44,37 @@ pub(crate) fn metric_to_usearch(metric: DistanceMetric) -> MetricKind {
     }
 }
 
+/// Create an empty usearch index for `embedder` with `capacity` reserved.
+///
+/// # Panics
+///
+/// Panics if usearch Index creation or capacity reservation fails.
+fn new_usearch_index(embedder: EmbedderIndex, config: &HnswConfig, capacity: usize) -> Index {
+    let options = IndexOptions {
+        dimensions: config.dimension,
+        metric: metric_to_usearch(config.metric),
+        quantization: ScalarKind::F32,
+        connectivity: config.m,
+        expansion_add: config.ef_construction,
+        expansion_search: config.ef_search,
+        ..Default::default()
+    };
+
+    let index = Index::new(&options).unwrap_or_else(|e| {
+        panic!(
+            "FAIL FAST: Failed to create usearch index for {:?}: {}",
+            embedder, e
+        )
+    });
+    index.reserve(capacity).unwrap_or_else(|e| {
+        panic!(
+            "FAIL FAST: Failed to reserve capacity for {:?}: {}",
+            embedder, e
+        )
+    });
+    index
+}
+
 /// HNSW index for a single embedder using usearch for O(log n) graph traversal.
 ///
 /// Stores vectors with UUID associations and supports approximate nearest neighbor search.
@@ -156,33 +191,7 @@ impl HnswEmbedderIndex {
     ///
     /// Panics if usearch Index creation or capacity reservation fails.
     fn build(embedder: EmbedderIndex, config: HnswConfig) -> Self {
-        let usearch_metric = metric_to_usearch(config.metric);
-
-        let options = IndexOptions {
-            dimensions: config.dimension,
-            metric: usearch_metric,
-            quantization: ScalarKind::F32,
-            connectivity: config.m,
-            expansion_add: config.ef_construction,
-            expansion_search: config.ef_search,
-            ..Default::default()
-        };
-
-        let index = Index::new(&options).unwrap_or_else(|e| {
-            panic!(
-                "FAIL FAST: Failed to create usearch index for {:?}: {}",
-                embedder, e
-            )
-        });
-
-        // Reserve initial capacity - usearch requires this before adding vectors
-        const INITIAL_CAPACITY: usize = 1024;
-        index.reserve(INITIAL_CAPACITY).unwrap_or_else(|e| {
-            panic!(
-                "FAIL FAST: Failed to reserve capacity for {:?}: {}",
-                embedder, e
-            )
-        });
+        let index = new_usearch_index(embedder, &config, INITIAL_CAPACITY);
 
         Self {
             embedder,
@@ -206,7 +215,7 @@ impl HnswEmbedderIndex {
         self.index.read().size()
     }
 
-    /// H1 FIX: Check if compaction is needed (removed/total > 25%).
+    /// H1 FIX: Check if compaction is needed (removed/total > 20%).
     /// Compaction rebuilds the index from store, eliminating orphaned vectors.
     pub fn needs_compaction(&self) -> bool {
         let total = self.usearch_size();
@@ -214,8 +223,8 @@ impl HnswEmbedderIndex {
             return false;
         }
         let removed = self.removed_count();
-        // Compact when >25% of vectors are orphaned
-        removed * 4 > total
+        // Compact when >20% of vectors are orphaned
+        removed * 5 > total
     }
 
     /// H1 FIX: Reset removed count after compaction.
@@ -229,30 +238,7 @@ impl HnswEmbedderIndex {
     /// Used before `rebuild_indexes_from_store()` when a partial HNSW load has failed,
     /// to prevent duplicate/orphaned vectors from the partial load.
     pub fn clear(&self) {
-        let usearch_metric = metric_to_usearch(self.config.metric);
-        let options = IndexOptions {
-            dimensions: self.config.dimension,
-            metric: usearch_metric,
-            quantization: ScalarKind::F32,
-            connectivity: self.config.m,
-            expansion_add: self.config.ef_construction,
-            expansion_search: self.config.ef_search,
-            ..Default::default()
-        };
-
-        let new_index = Index::new(&options).unwrap_or_else(|e| {
-            panic!(
-                "FAIL FAST: Failed to create fresh usearch index for {:?}: {}",
-                self.embedder, e
-            )
-        });
-        const INITIAL_CAPACITY: usize = 1024;
-        new_index.reserve(INITIAL_CAPACITY).unwrap_or_else(|e| {
-            panic!(
-                "FAIL FAST: Failed to reserve capacity for {:?}: {}",
-                self.embedder, e
-            )
-        });
+        let new_index = new_usearch_index(self.embedder, &self.config, INITIAL_CAPACITY);
 
         *self.index.write() = new_index;
         let mut id_map = self.id_to_key.write();
@@ -266,11 +252,85 @@ impl HnswEmbedderIndex {
             .store(0, std::sync::atomic::Ordering::Relaxed);
     }
 
+    /// Rebuild the usearch graph from live vectors only, dropping all tombstones.
+    ///
+    /// Unlike `RocksDbTeleologicalStore::compact_hnsw_if_needed`, this does not
+    /// need the fingerprint store: live vectors are read back from the current
+    /// graph and re-added to a fresh one with compacted keys. Holds all write
+    /// locks for the duration, so concurrent inserts/searches wait.
+    ///
+    /// Returns the number of live vectors in the rebuilt index.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IndexError::OperationFailed` if a vector cannot be read back or
+    /// re-added. The existing index is left untouched in that case.
+    pub fn rebuild(&self) -> IndexResult<usize> {
+        let mut id_to_key = self.id_to_key.write();
+        let mut key_to_id = self.key_to_id.write();
+        let mut index = self.index.write();
+        let mut next_key = self.next_key.write();
+
+        let live = key_to_id.len();
+        let fresh = new_usearch_index(self.embedder, &self.config, live.max(INITIAL_CAPACITY));
+
+        // Re-add in old key order so insertion order (and graph shape) is stable
+        let mut entries: Vec<(u64, Uuid)> = key_to_id.iter().map(|(k, id)| (*k, *id)).collect();
+        entries.sort_unstable_by_key(|(k, _)| *k);
+
+        let mut new_id_to_key = HashMap::with_capacity(live);
+        let mut new_key_to_id = HashMap::with_capacity(live);
+        let mut vector = vec![0.0f32; self.config.dimension];
+        for (new_key, (old_key, id)) in entries.into_iter().enumerate() {
+            let new_key = new_key as u64;
+            index
+                .get(old_key, &mut vector)
+                .map_err(|e| IndexError::OperationFailed {
+                    embedder: self.embedder,
+                    message: format!("usearch get failed for {}: {}", id, e),
+                })?;
+            fresh
+                .add(new_key, &vector)
+                .map_err(|e| IndexError::OperationFailed {
+                    embedder: self.embedder,
+                    message: format!("usearch add failed during rebuild: {}", e),
+                })?;
+            new_id_to_key.insert(id, new_key);
+            new_key_to_id.insert(new_key, id);
+        }
+
+        *index = fresh;
+        *id_to_key = new_id_to_key;
+        *key_to_id = new_key_to_id;
+        *next_key = live as u64;
+        self.removed_count
+            .store(0, std::sync::atomic::Ordering::Relaxed);
+
+        Ok(live)
+    }
+
     /// Check if a vector ID exists in the index.
     pub fn contains(&self, id: Uuid) -> bool {
         self.id_to_key.read().contains_key(&id)
     }
 
+    /// Get the stored vector for `id`, if present.
+    pub fn get_vector(&self, id: Uuid) -> IndexResult<Option<Vec<f32>>> {
+        let id_to_key = self.id_to_key.read();
+        let Some(&key) = id_to_key.get(&id) else {
+            return Ok(None);
+        };
+        let mut vector = vec![0.0f32; self.config.dimension];
+        self.index
+            .read()
+            .get(key, &mut vector)
+            .map_err(|e| IndexError::OperationFailed {
+                embedder: self.embedder,
+                message: format!("usearch get failed for {}: {}", id, e),
+            })?;
+        Ok(Some(vector))
+    }
+
     /// Get all vector IDs in the index.
     pub fn ids(&self) -> Vec<Uuid> {
         self.id_to_key.read().keys().copied().collect()
@@ -348,6 +408,13 @@ impl HnswEmbedderIndex {
         }
         *next_key = meta.next_key;
 
+        // Persisted graphs carry their tombstones; restore the orphan count so
+        // needs_compaction() and search over-fetch stay accurate after reload.
+        self.removed_count.store(
+            idx.size().saturating_sub(count),
+            std::sync::atomic::Ordering::Relaxed,
+        );
+
         Ok(count)
     }
 }
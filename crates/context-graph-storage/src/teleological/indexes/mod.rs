@@ -20,6 +20,7 @@
 //! - [`embedder_index`]: Per-embedder ANN index trait (`EmbedderIndexOps`, `IndexError`)
 //! - [`hnsw_impl`]: HNSW index implementation (`HnswEmbedderIndex`)
 //! - [`registry`]: Index registry (`EmbedderIndexRegistry`)
+//! - [`build`]: Parallel index builds with progress reporting (`IndexBuildConfig`)
 //!
 //! # Example
 //!
@@ -75,6 +76,9 @@ pub mod embedder_index;
 pub mod hnsw_impl;
 pub mod registry;
 
+// Parallel rebuilds with progress reporting
+pub mod build;
+
 // Re-export from hnsw_config
 pub use hnsw_config::{
     // Functions
@@ -105,6 +109,12 @@ pub use embedder_index::{validate_vector, EmbedderIndexOps, IndexError, IndexRes
 pub use hnsw_impl::HnswEmbedderIndex;
 pub use registry::EmbedderIndexRegistry;
 
+// Re-export from build
+pub use build::{
+    estimate_concurrent_build_bytes, plan_build, IndexBuildConfig, IndexBuildPlan,
+    IndexBuildProgress, IndexBuildProgressFn, IndexBuildSummary,
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
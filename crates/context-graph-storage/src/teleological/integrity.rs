@@ -0,0 +1,442 @@
+//! Offline integrity check and repair for the teleological column families.
+//!
+//! Cross-validates `fingerprints` against its derived column families:
+//! - `e1_matryoshka_128`: every fingerprint needs a truncated E1 entry
+//! - `e13_splade_inverted` / `e6_sparse_inverted`: every term of a stored
+//!   fingerprint needs a posting, and every posting must reference a stored
+//!   fingerprint
+//!
+//! An unclean shutdown can leave these out of step (the write batch is
+//! atomic, but restores and manual CF surgery are not). Derived entries are
+//! regenerated from the stored fingerprint; orphans are deleted.
+//!
+//! Both functions work on a raw `DB`. Run them with the MCP server stopped:
+//! they do not take the store's `secondary_index_lock`.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use rocksdb::{ColumnFamily, IteratorMode, WriteBatch, DB};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use context_graph_core::types::fingerprint::TeleologicalFingerprint;
+
+use super::column_families::{
+    CF_E13_SPLADE_INVERTED, CF_E1_MATRYOSHKA_128, CF_E6_SPARSE_INVERTED, CF_FINGERPRINTS,
+};
+use super::rocksdb_store::{TeleologicalStoreError, TeleologicalStoreResult};
+use super::schema::{
+    e13_splade_inverted_key, e1_matryoshka_128_key, e6_sparse_inverted_key, fingerprint_key,
+    parse_e13_splade_key, parse_e1_matryoshka_key, parse_e6_sparse_key, parse_fingerprint_key,
+};
+use super::serialization::{
+    deserialize_memory_id_list, deserialize_teleological_fingerprint, serialize_e1_matryoshka_128,
+    serialize_memory_id_list,
+};
+
+/// Sparse inverted index checked by [`check_integrity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SparseIndex {
+    /// E13 SPLADE (`e13_splade_inverted`), from `semantic.e13_splade`.
+    E13Splade,
+    /// E6 keywords (`e6_sparse_inverted`), from `e6_sparse` when present.
+    E6Sparse,
+}
+
+impl SparseIndex {
+    /// Both indexes, in check order.
+    pub const ALL: [SparseIndex; 2] = [SparseIndex::E13Splade, SparseIndex::E6Sparse];
+
+    /// Column family holding this index.
+    pub fn cf_name(self) -> &'static str {
+        match self {
+            SparseIndex::E13Splade => CF_E13_SPLADE_INVERTED,
+            SparseIndex::E6Sparse => CF_E6_SPARSE_INVERTED,
+        }
+    }
+
+    fn key(self, term_id: u16) -> [u8; 2] {
+        match self {
+            SparseIndex::E13Splade => e13_splade_inverted_key(term_id),
+            SparseIndex::E6Sparse => e6_sparse_inverted_key(term_id),
+        }
+    }
+
+    fn parse_key(self, key: &[u8]) -> u16 {
+        match self {
+            SparseIndex::E13Splade => parse_e13_splade_key(key),
+            SparseIndex::E6Sparse => parse_e6_sparse_key(key),
+        }
+    }
+
+    fn terms(self, fp: &TeleologicalFingerprint) -> &[u16] {
+        match self {
+            SparseIndex::E13Splade => &fp.semantic.e13_splade.indices,
+            SparseIndex::E6Sparse => fp.e6_sparse.as_ref().map_or(&[], |s| &s.indices),
+        }
+    }
+}
+
+/// One (term, fingerprint) posting in a sparse inverted index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PostingRef {
+    /// Index the posting belongs to.
+    pub index: SparseIndex,
+    /// Term key of the posting list.
+    pub term_id: u16,
+    /// Fingerprint the posting references.
+    pub fingerprint_id: Uuid,
+}
+
+/// Findings of [`check_integrity`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// Entries in `fingerprints`.
+    pub fingerprints_scanned: usize,
+    /// Fingerprints that failed to deserialize (never repaired automatically).
+    pub corrupted_fingerprints: Vec<Uuid>,
+    /// Fingerprints without an `e1_matryoshka_128` entry.
+    pub missing_matryoshka: Vec<Uuid>,
+    /// Terms of stored fingerprints absent from their posting list.
+    pub missing_postings: Vec<PostingRef>,
+    /// `e1_matryoshka_128` entries whose fingerprint does not exist.
+    pub orphan_matryoshka: Vec<Uuid>,
+    /// Postings that reference a fingerprint that does not exist.
+    pub dangling_postings: Vec<PostingRef>,
+    /// Posting lists that failed to deserialize, as (index, term_id).
+    pub corrupted_posting_lists: Vec<(SparseIndex, u16)>,
+}
+
+impl IntegrityReport {
+    /// Total number of problems found.
+    pub fn issue_count(&self) -> usize {
+        self.corrupted_fingerprints.len()
+            + self.missing_matryoshka.len()
+            + self.missing_postings.len()
+            + self.orphan_matryoshka.len()
+            + self.dangling_postings.len()
+            + self.corrupted_posting_lists.len()
+    }
+
+    /// Whether the column families are fully consistent.
+    pub fn is_clean(&self) -> bool {
+        self.issue_count() == 0
+    }
+}
+
+/// What [`repair`] is allowed to change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepairPolicy {
+    /// Recompute missing Matryoshka entries and postings from the stored
+    /// fingerprints. Never deletes anything.
+    Regenerate,
+    /// Delete orphan Matryoshka entries, dangling postings and undecodable
+    /// posting lists. Never writes derived data.
+    DeleteOrphans,
+    /// Delete orphans first, then regenerate everything still missing.
+    All,
+}
+
+/// Outcome of [`repair`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RepairReport {
+    /// Matryoshka entries recomputed from E1.
+    pub matryoshka_regenerated: usize,
+    /// Postings re-added to their term lists.
+    pub postings_regenerated: usize,
+    /// Orphan Matryoshka entries removed.
+    pub matryoshka_deleted: usize,
+    /// Dangling postings removed from their term lists.
+    pub postings_deleted: usize,
+    /// Undecodable posting lists removed.
+    pub posting_lists_deleted: usize,
+    /// Missing entries that could not be regenerated (fingerprint vanished,
+    /// failed to decode, or has an E1 vector shorter than 128 dims).
+    pub unrepairable: usize,
+}
+
+fn cf<'a>(db: &'a DB, name: &'static str) -> TeleologicalStoreResult<&'a ColumnFamily> {
+    db.cf_handle(name)
+        .ok_or_else(|| TeleologicalStoreError::ColumnFamilyNotFound {
+            name: name.to_string(),
+        })
+}
+
+/// Cross-check `fingerprints` against the Matryoshka CF and both sparse
+/// inverted indexes, in both directions.
+pub fn check_integrity(db: &DB) -> TeleologicalStoreResult<IntegrityReport> {
+    let mut report = IntegrityReport::default();
+
+    // Pass 1: stored ids and the postings they imply
+    let mut ids: BTreeSet<Uuid> = BTreeSet::new();
+    let mut expected: BTreeMap<(SparseIndex, u16), BTreeSet<Uuid>> = BTreeMap::new();
+    for item in db.iterator_cf(cf(db, CF_FINGERPRINTS)?, IteratorMode::Start) {
+        let (key, value) = item
+            .map_err(|e| TeleologicalStoreError::rocksdb_op("iterate", CF_FINGERPRINTS, None, e))?;
+        let id = parse_fingerprint_key(&key);
+        report.fingerprints_scanned += 1;
+        ids.insert(id);
+
+        match deserialize_teleological_fingerprint(&value) {
+            Ok(fp) => {
+                for index in SparseIndex::ALL {
+                    for &term_id in index.terms(&fp) {
+                        expected.entry((index, term_id)).or_default().insert(id);
+                    }
+                }
+            }
+            Err(e) => {
+                warn!("check_integrity: fingerprint {} does not decode: {}", id, e);
+                report.corrupted_fingerprints.push(id);
+            }
+        }
+    }
+
+    // Pass 2: Matryoshka entries vs fingerprints
+    let mut with_matryoshka = BTreeSet::new();
+    for item in db.iterator_cf(cf(db, CF_E1_MATRYOSHKA_128)?, IteratorMode::Start) {
+        let (key, _) = item.map_err(|e| {
+            TeleologicalStoreError::rocksdb_op("iterate", CF_E1_MATRYOSHKA_128, None, e)
+        })?;
+        let id = parse_e1_matryoshka_key(&key);
+        if !ids.contains(&id) {
+            report.orphan_matryoshka.push(id);
+        }
+        with_matryoshka.insert(id);
+    }
+    report.missing_matryoshka = ids.difference(&with_matryoshka).copied().collect();
+
+    // Pass 3: posting lists vs fingerprints
+    for index in SparseIndex::ALL {
+        for item in db.iterator_cf(cf(db, index.cf_name())?, IteratorMode::Start) {
+            let (key, value) = item.map_err(|e| {
+                TeleologicalStoreError::rocksdb_op("iterate", index.cf_name(), None, e)
+            })?;
+            let term_id = index.parse_key(&key);
+            let postings = match deserialize_memory_id_list(&value) {
+                Ok(postings) => postings,
+                Err(e) => {
+                    warn!(
+                        "check_integrity: {} posting list for term {} does not decode: {}",
+                        index.cf_name(),
+                        term_id,
+                        e
+                    );
+                    report.corrupted_posting_lists.push((index, term_id));
+                    continue;
+                }
+            };
+            let mut wanted = expected.get_mut(&(index, term_id));
+            for fingerprint_id in postings {
+                if let Some(wanted) = wanted.as_deref_mut() {
+                    wanted.remove(&fingerprint_id);
+                }
+                if !ids.contains(&fingerprint_id) {
+                    report.dangling_postings.push(PostingRef {
+                        index,
+                        term_id,
+                        fingerprint_id,
+                    });
+                }
+            }
+        }
+    }
+    for ((index, term_id), remaining) in expected {
+        report
+            .missing_postings
+            .extend(remaining.into_iter().map(|fingerprint_id| PostingRef {
+                index,
+                term_id,
+                fingerprint_id,
+            }));
+    }
+
+    info!(
+        "check_integrity: {} fingerprints, {} issues ({} missing matryoshka, {} missing postings, \
+         {} orphan matryoshka, {} dangling postings, {} corrupted fingerprints, {} corrupted lists)",
+        report.fingerprints_scanned,
+        report.issue_count(),
+        report.missing_matryoshka.len(),
+        report.missing_postings.len(),
+        report.orphan_matryoshka.len(),
+        report.dangling_postings.len(),
+        report.corrupted_fingerprints.len(),
+        report.corrupted_posting_lists.len()
+    );
+    Ok(report)
+}
+
+/// Fix what [`check_integrity`] finds, within the limits of `policy`.
+///
+/// Corrupted fingerprints are only reported; restore them from a snapshot.
+pub fn repair(db: &DB, policy: RepairPolicy) -> TeleologicalStoreResult<RepairReport> {
+    let mut outcome = RepairReport::default();
+    let report = check_integrity(db)?;
+
+    if matches!(policy, RepairPolicy::DeleteOrphans | RepairPolicy::All) {
+        delete_orphans(db, &report, &mut outcome)?;
+    }
+    if matches!(policy, RepairPolicy::Regenerate | RepairPolicy::All) {
+        // Deleting undecodable lists turns their postings into missing ones
+        let report = if policy == RepairPolicy::All && outcome.posting_lists_deleted > 0 {
+            check_integrity(db)?
+        } else {
+            report
+        };
+        regenerate(db, &report, &mut outcome)?;
+    }
+
+    info!(
+        "repair({:?}): regenerated {} matryoshka / {} postings, deleted {} matryoshka / {} postings / {} lists, {} unrepairable",
+        policy,
+        outcome.matryoshka_regenerated,
+        outcome.postings_regenerated,
+        outcome.matryoshka_deleted,
+        outcome.postings_deleted,
+        outcome.posting_lists_deleted,
+        outcome.unrepairable
+    );
+    Ok(outcome)
+}
+
+fn delete_orphans(
+    db: &DB,
+    report: &IntegrityReport,
+    outcome: &mut RepairReport,
+) -> TeleologicalStoreResult<()> {
+    let mut batch = WriteBatch::default();
+
+    let cf_matryoshka = cf(db, CF_E1_MATRYOSHKA_128)?;
+    for id in &report.orphan_matryoshka {
+        batch.delete_cf(cf_matryoshka, e1_matryoshka_128_key(id));
+        outcome.matryoshka_deleted += 1;
+    }
+
+    for &(index, term_id) in &report.corrupted_posting_lists {
+        batch.delete_cf(cf(db, index.cf_name())?, index.key(term_id));
+        outcome.posting_lists_deleted += 1;
+    }
+
+    let mut dangling: BTreeMap<(SparseIndex, u16), BTreeSet<Uuid>> = BTreeMap::new();
+    for posting in &report.dangling_postings {
+        dangling
+            .entry((posting.index, posting.term_id))
+            .or_default()
+            .insert(posting.fingerprint_id);
+    }
+    for ((index, term_id), gone) in dangling {
+        let cf_index = cf(db, index.cf_name())?;
+        let mut postings = read_postings(db, cf_index, index, term_id)?;
+        let before = postings.len();
+        postings.retain(|id| !gone.contains(id));
+        outcome.postings_deleted += before - postings.len();
+        write_postings(&mut batch, cf_index, index, term_id, &postings);
+    }
+
+    db.write(batch).map_err(|e| {
+        TeleologicalStoreError::rocksdb_op("write_batch", CF_E1_MATRYOSHKA_128, None, e)
+    })
+}
+
+fn regenerate(
+    db: &DB,
+    report: &IntegrityReport,
+    outcome: &mut RepairReport,
+) -> TeleologicalStoreResult<()> {
+    let mut batch = WriteBatch::default();
+    let cf_fp = cf(db, CF_FINGERPRINTS)?;
+
+    let cf_matryoshka = cf(db, CF_E1_MATRYOSHKA_128)?;
+    for id in &report.missing_matryoshka {
+        let fp = match db.get_cf(cf_fp, fingerprint_key(id)) {
+            Ok(Some(data)) => deserialize_teleological_fingerprint(&data).ok(),
+            Ok(None) => None,
+            Err(e) => {
+                return Err(TeleologicalStoreError::rocksdb_op(
+                    "get",
+                    CF_FINGERPRINTS,
+                    Some(*id),
+                    e,
+                ))
+            }
+        };
+        let e1 = fp.as_ref().map(|fp| &fp.semantic.e1_semantic);
+        match e1.filter(|e1| e1.len() >= 128) {
+            Some(e1) => {
+                let mut truncated = [0.0f32; 128];
+                truncated.copy_from_slice(&e1[..128]);
+                batch.put_cf(
+                    cf_matryoshka,
+                    e1_matryoshka_128_key(id),
+                    serialize_e1_matryoshka_128(&truncated),
+                );
+                outcome.matryoshka_regenerated += 1;
+            }
+            None => {
+                warn!("repair: cannot regenerate matryoshka entry for {}", id);
+                outcome.unrepairable += 1;
+            }
+        }
+    }
+
+    let mut missing: BTreeMap<(SparseIndex, u16), BTreeSet<Uuid>> = BTreeMap::new();
+    for posting in &report.missing_postings {
+        missing
+            .entry((posting.index, posting.term_id))
+            .or_default()
+            .insert(posting.fingerprint_id);
+    }
+    for ((index, term_id), add) in missing {
+        let cf_index = cf(db, index.cf_name())?;
+        let mut postings: BTreeSet<Uuid> = read_postings(db, cf_index, index, term_id)?
+            .into_iter()
+            .collect();
+        for id in add {
+            if postings.insert(id) {
+                outcome.postings_regenerated += 1;
+            }
+        }
+        let postings: Vec<Uuid> = postings.into_iter().collect();
+        write_postings(&mut batch, cf_index, index, term_id, &postings);
+    }
+
+    db.write(batch).map_err(|e| {
+        TeleologicalStoreError::rocksdb_op("write_batch", CF_E1_MATRYOSHKA_128, None, e)
+    })
+}
+
+/// Posting list for a term; undecodable lists read as empty.
+fn read_postings(
+    db: &DB,
+    cf_index: &ColumnFamily,
+    index: SparseIndex,
+    term_id: u16,
+) -> TeleologicalStoreResult<Vec<Uuid>> {
+    let data = db
+        .get_cf(cf_index, index.key(term_id))
+        .map_err(|e| TeleologicalStoreError::rocksdb_op("get", index.cf_name(), None, e))?;
+    Ok(data
+        .and_then(|data| deserialize_memory_id_list(&data).ok())
+        .unwrap_or_default())
+}
+
+fn write_postings(
+    batch: &mut WriteBatch,
+    cf_index: &ColumnFamily,
+    index: SparseIndex,
+    term_id: u16,
+    postings: &[Uuid],
+) {
+    if postings.is_empty() {
+        batch.delete_cf(cf_index, index.key(term_id));
+    } else {
+        let mut sorted = postings.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        batch.put_cf(
+            cf_index,
+            index.key(term_id),
+            serialize_memory_id_list(&sorted),
+        );
+    }
+}
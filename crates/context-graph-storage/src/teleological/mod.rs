@@ -43,6 +43,7 @@
 
 pub mod column_families;
 pub mod indexes;
+pub mod integrity;
 pub mod quantized;
 pub mod rocksdb_store;
 pub mod schema;
@@ -76,6 +77,24 @@ pub use column_families::{
     // Quantized embedder column families (TASK-EMB-022)
     quantized_embedder_cf_options,
     custom_weight_profiles_cf_options,
+    // content_hash secondary index for duplicate detection
+    content_hash_index_cf_options,
+    CF_CONTENT_HASH_INDEX,
+    // Merge/consolidation lineage
+    memory_lineage_cf_options,
+    CF_MEMORY_LINEAGE,
+    // Content-addressable content blobs
+    content_blobs_cf_options,
+    CF_CONTENT_BLOBS,
+    // Topic records maintained across detection runs
+    topics_cf_options,
+    CF_TOPICS,
+    // Per-tool execution audit log
+    tool_audit_log_cf_options,
+    CF_TOOL_AUDIT_LOG,
+    // Per-memory access statistics
+    access_stats_cf_options,
+    CF_ACCESS_STATS,
     // TASK-CONTENT-001: Content column family
     CF_CONTENT,
     // TASK-STORAGE-P2-001: E12 Late Interaction column family constant
@@ -116,6 +135,11 @@ pub use column_families::{
     CF_IMPORTANCE_HISTORY,
 };
 
+// Re-export integrity check and repair
+pub use integrity::{
+    check_integrity, repair, IntegrityReport, PostingRef, RepairPolicy, RepairReport, SparseIndex,
+};
+
 // Re-export quantized storage types (TASK-EMB-022)
 pub use quantized::{QuantizedFingerprintStorage, QuantizedStorageError, QuantizedStorageResult};
 
@@ -185,8 +209,11 @@ pub use indexes::{
 
 // Re-export RocksDB teleological store (TASK: RocksDbTeleologicalStore)
 pub use rocksdb_store::{
-    RocksDbTeleologicalStore, TeleologicalStoreConfig, TeleologicalStoreError,
-    TeleologicalStoreResult,
+    read_lock_owner, AccessStatsConfig, restore_backup, verify_backup, BackupFile, BackupManifest, BackupOptions,
+    BackupReport, CommitLog, ContentBlobReader, FsyncPolicy, LockOwner, RebuildStats,
+    RestoreReport, RocksDbTeleologicalStore, StoreAccessMode, TeleologicalStoreConfig,
+    TeleologicalStoreError, TeleologicalStoreResult, WarmStartStats, WriteMetrics,
+    BACKUP_FORMAT_VERSION, BACKUP_MANIFEST_FILE, LOCK_OWNER_FILE,
 };
 
 // Re-export search types (TASK-LOGIC-005)
@@ -0,0 +1,423 @@
+//! Per-memory access tracking for recency and frequency signals.
+//!
+//! `retrieve` hits and search results bump an in-memory [`AccessRecord`] per
+//! ID. A background thread folds the pending records into `CF_ACCESS_STATS`
+//! every `flush_interval`, or sooner once `flush_after_updates` accesses are
+//! pending, so reads never wait on a RocksDB write. Pending records are also
+//! flushed when the store is dropped.
+//!
+//! Hard-deleted IDs are tombstoned: late accesses of them (a search that
+//! returned the memory just before the delete) are never recorded or
+//! flushed, so no stats row outlives its memory.
+//!
+//! Each record keeps 7 per-day buckets (ring indexed by UTC day) so
+//! `hits_7d` needs no per-access timestamps.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use chrono::{DateTime, TimeZone, Utc};
+use dashmap::DashMap;
+use parking_lot::RwLock;
+use rocksdb::{WriteBatch, DB};
+use tracing::{debug, error};
+use uuid::Uuid;
+
+use context_graph_core::traits::{AccessStats, ACCESS_WINDOW_DAYS};
+
+use crate::teleological::column_families::CF_ACCESS_STATS;
+
+use super::store::RocksDbTeleologicalStore;
+use super::types::{StoreAccessMode, TeleologicalStoreError, TeleologicalStoreResult};
+
+const MILLIS_PER_DAY: i64 = 24 * 3600 * 1000;
+const BUCKETS: usize = ACCESS_WINDOW_DAYS as usize;
+
+/// Serialized size: hits (8) + last access (8) + 7 x (day 4 + count 4).
+pub(crate) const ACCESS_RECORD_LEN: usize = 16 + BUCKETS * 8;
+
+/// When pending accesses are written to `CF_ACCESS_STATS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccessStatsConfig {
+    /// Flush pending accesses at least this often (default: 30s).
+    pub flush_interval: Duration,
+    /// Flush early once this many accesses are pending (default: 1000).
+    pub flush_after_updates: usize,
+}
+
+impl Default for AccessStatsConfig {
+    fn default() -> Self {
+        Self {
+            flush_interval: Duration::from_secs(30),
+            flush_after_updates: 1000,
+        }
+    }
+}
+
+/// Access counts of one memory, pending or persisted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct AccessRecord {
+    hits: u64,
+    /// Unix epoch milliseconds; 0 if never accessed.
+    last_access_ms: i64,
+    /// `(utc_day, hits)` in slot `utc_day % 7`.
+    days: [(u32, u32); BUCKETS],
+}
+
+impl AccessRecord {
+    fn day_of(millis: i64) -> u32 {
+        millis.div_euclid(MILLIS_PER_DAY).max(0) as u32
+    }
+
+    /// Add `hits` accesses on `day`. Hits older than the bucket's day are dropped.
+    fn add_to_day(&mut self, day: u32, hits: u32) {
+        let slot = &mut self.days[day as usize % BUCKETS];
+        if slot.0 < day {
+            *slot = (day, 0);
+        }
+        if slot.0 == day {
+            slot.1 = slot.1.saturating_add(hits);
+        }
+    }
+
+    pub(crate) fn hit(&mut self, at_ms: i64) {
+        self.hits += 1;
+        self.last_access_ms = self.last_access_ms.max(at_ms);
+        self.add_to_day(Self::day_of(at_ms), 1);
+    }
+
+    pub(crate) fn merge(&mut self, other: &AccessRecord) {
+        self.hits += other.hits;
+        self.last_access_ms = self.last_access_ms.max(other.last_access_ms);
+        for &(day, hits) in &other.days {
+            if hits > 0 {
+                self.add_to_day(day, hits);
+            }
+        }
+    }
+
+    pub(crate) fn stats_at(&self, now_ms: i64) -> AccessStats {
+        let today = Self::day_of(now_ms);
+        let hits_7d = self
+            .days
+            .iter()
+            .filter(|(day, _)| *day <= today && today - *day < BUCKETS as u32)
+            .map(|(_, hits)| u64::from(*hits))
+            .sum();
+        let last_access = (self.hits > 0)
+            .then(|| Utc.timestamp_millis_opt(self.last_access_ms).single())
+            .flatten();
+        AccessStats {
+            hits: self.hits,
+            last_access,
+            hits_7d,
+        }
+    }
+
+    pub(crate) fn to_bytes(&self) -> [u8; ACCESS_RECORD_LEN] {
+        let mut out = [0u8; ACCESS_RECORD_LEN];
+        out[..8].copy_from_slice(&self.hits.to_be_bytes());
+        out[8..16].copy_from_slice(&self.last_access_ms.to_be_bytes());
+        for (i, (day, hits)) in self.days.iter().enumerate() {
+            let at = 16 + i * 8;
+            out[at..at + 4].copy_from_slice(&day.to_be_bytes());
+            out[at + 4..at + 8].copy_from_slice(&hits.to_be_bytes());
+        }
+        out
+    }
+
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != ACCESS_RECORD_LEN {
+            return None;
+        }
+        let u32_at = |at: usize| u32::from_be_bytes(bytes[at..at + 4].try_into().unwrap());
+        let mut days = [(0u32, 0u32); BUCKETS];
+        for (i, slot) in days.iter_mut().enumerate() {
+            *slot = (u32_at(16 + i * 8), u32_at(20 + i * 8));
+        }
+        Some(Self {
+            hits: u64::from_be_bytes(bytes[..8].try_into().unwrap()),
+            last_access_ms: i64::from_be_bytes(bytes[8..16].try_into().unwrap()),
+            days,
+        })
+    }
+}
+
+enum Signal {
+    /// `flush_after_updates` accesses are pending.
+    Flush,
+    Shutdown,
+}
+
+/// State shared between the read path and the flush thread.
+struct Shared {
+    db: Arc<DB>,
+    pending: DashMap<Uuid, AccessRecord>,
+    pending_updates: AtomicUsize,
+    /// Held for writing while drained records are in flight, so readers
+    /// always find them in either the map or the CF, and while a hard
+    /// delete removes a row, so no flush writes it back.
+    flush_lock: RwLock<()>,
+    /// Hard-deleted IDs whose accesses must not be recorded or flushed.
+    deleted: DashMap<Uuid, ()>,
+}
+
+impl Shared {
+    fn persisted(&self, ids: &[Uuid]) -> TeleologicalStoreResult<Vec<AccessRecord>> {
+        let cf = self.db.cf_handle(CF_ACCESS_STATS).ok_or_else(|| {
+            TeleologicalStoreError::ColumnFamilyNotFound {
+                name: CF_ACCESS_STATS.to_string(),
+            }
+        })?;
+        let values = self
+            .db
+            .multi_get_cf(ids.iter().map(|id| (cf, id.as_bytes())));
+        ids.iter()
+            .zip(values)
+            .map(|(id, value)| {
+                let value = value.map_err(|e| {
+                    TeleologicalStoreError::rocksdb_op("multi_get", CF_ACCESS_STATS, Some(*id), e)
+                })?;
+                match value {
+                    Some(bytes) => AccessRecord::from_bytes(&bytes).ok_or_else(|| {
+                        TeleologicalStoreError::Deserialization {
+                            key: id.to_string(),
+                            message: format!(
+                                "access record is {} bytes, expected {}",
+                                bytes.len(),
+                                ACCESS_RECORD_LEN
+                            ),
+                        }
+                    }),
+                    None => Ok(AccessRecord::default()),
+                }
+            })
+            .collect()
+    }
+
+    /// Fold every pending record into `CF_ACCESS_STATS`. Returns the number
+    /// of memories written. On failure the drained records are re-queued.
+    fn flush(&self) -> TeleologicalStoreResult<usize> {
+        let _guard = self.flush_lock.write();
+        let ids: Vec<Uuid> = self.pending.iter().map(|entry| *entry.key()).collect();
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        self.pending_updates.store(0, Ordering::Relaxed);
+
+        let drained: Vec<(Uuid, AccessRecord)> = ids
+            .iter()
+            .filter_map(|id| self.pending.remove(id))
+            .filter(|(id, _)| !self.deleted.contains_key(id))
+            .collect();
+        if drained.is_empty() {
+            return Ok(0);
+        }
+        let result = self.write_merged(&drained);
+        if result.is_err() {
+            for (id, record) in &drained {
+                self.pending.entry(*id).or_default().merge(record);
+            }
+        }
+        result
+    }
+
+    fn write_merged(&self, drained: &[(Uuid, AccessRecord)]) -> TeleologicalStoreResult<usize> {
+        let ids: Vec<Uuid> = drained.iter().map(|(id, _)| *id).collect();
+        let mut merged = self.persisted(&ids)?;
+
+        let cf = self.db.cf_handle(CF_ACCESS_STATS).ok_or_else(|| {
+            TeleologicalStoreError::ColumnFamilyNotFound {
+                name: CF_ACCESS_STATS.to_string(),
+            }
+        })?;
+        let mut batch = WriteBatch::default();
+        for ((id, pending), record) in drained.iter().zip(merged.iter_mut()) {
+            record.merge(pending);
+            batch.put_cf(cf, id.as_bytes(), record.to_bytes());
+        }
+        self.db.write(batch).map_err(|e| {
+            TeleologicalStoreError::rocksdb_op("write_batch", CF_ACCESS_STATS, None, e)
+        })?;
+        debug!("Flushed access stats for {} memories", drained.len());
+        Ok(drained.len())
+    }
+}
+
+/// Counts accesses in memory and flushes them from a background thread.
+///
+/// Read-only stores report persisted stats but record nothing.
+pub(crate) struct AccessTracker {
+    shared: Arc<Shared>,
+    signals: Option<Sender<Signal>>,
+    worker: Option<JoinHandle<()>>,
+    flush_after_updates: usize,
+}
+
+impl AccessTracker {
+    pub(crate) fn new(
+        db: Arc<DB>,
+        config: AccessStatsConfig,
+        access_mode: StoreAccessMode,
+    ) -> TeleologicalStoreResult<Self> {
+        let shared = Arc::new(Shared {
+            db,
+            pending: DashMap::new(),
+            pending_updates: AtomicUsize::new(0),
+            flush_lock: RwLock::new(()),
+            deleted: DashMap::new(),
+        });
+        if !access_mode.is_writable() {
+            return Ok(Self {
+                shared,
+                signals: None,
+                worker: None,
+                flush_after_updates: config.flush_after_updates,
+            });
+        }
+
+        let (tx, rx) = mpsc::channel();
+        let worker_shared = Arc::clone(&shared);
+        let worker = std::thread::Builder::new()
+            .name("access-stats-flush".to_string())
+            .spawn(move || run_flush_loop(worker_shared, rx, config.flush_interval))
+            .map_err(|e| {
+                TeleologicalStoreError::Internal(format!(
+                    "failed to spawn access stats flush thread: {}",
+                    e
+                ))
+            })?;
+        Ok(Self {
+            shared,
+            signals: Some(tx),
+            worker: Some(worker),
+            flush_after_updates: config.flush_after_updates,
+        })
+    }
+
+    /// Count one access of each of `ids` now. Never blocks on I/O.
+    pub(crate) fn record<I: IntoIterator<Item = Uuid>>(&self, ids: I) {
+        let Some(signals) = &self.signals else {
+            return;
+        };
+        let now_ms = Utc::now().timestamp_millis();
+        let mut added = 0usize;
+        for id in ids {
+            if self.shared.deleted.contains_key(&id) {
+                continue;
+            }
+            self.shared.pending.entry(id).or_default().hit(now_ms);
+            added += 1;
+        }
+        if added == 0 {
+            return;
+        }
+        let before = self
+            .shared
+            .pending_updates
+            .fetch_add(added, Ordering::Relaxed);
+        if before < self.flush_after_updates && before + added >= self.flush_after_updates {
+            // Unbounded channel: never blocks. A closed channel means the
+            // store is shutting down and flushes on its own.
+            let _ = signals.send(Signal::Flush);
+        }
+    }
+
+    /// Hard-delete the access record of `id`: run `delete_row`, which
+    /// commits the removal of its `CF_ACCESS_STATS` row, then drop its
+    /// pending accesses and tombstone it.
+    ///
+    /// Flushes are held off throughout, so a flush that already drained the
+    /// memory's accesses cannot write the row back after the delete. The
+    /// tombstone drops accesses recorded after the delete until [`revive`].
+    ///
+    /// [`revive`]: Self::revive
+    pub(crate) fn forget<E>(
+        &self,
+        id: &Uuid,
+        delete_row: impl FnOnce() -> Result<(), E>,
+    ) -> Result<(), E> {
+        let _guard = self.shared.flush_lock.write();
+        delete_row()?;
+        self.shared.deleted.insert(*id, ());
+        self.shared.pending.remove(id);
+        Ok(())
+    }
+
+    /// Clear the tombstone of `id`, which is being stored again.
+    pub(crate) fn revive(&self, id: &Uuid) {
+        self.shared.deleted.remove(id);
+    }
+
+    /// Persisted plus pending stats for each of `ids`, as of `now`.
+    pub(crate) fn stats_at(
+        &self,
+        ids: &[Uuid],
+        now: DateTime<Utc>,
+    ) -> TeleologicalStoreResult<Vec<AccessStats>> {
+        let _guard = self.shared.flush_lock.read();
+        let now_ms = now.timestamp_millis();
+        let mut records = self.shared.persisted(ids)?;
+        Ok(ids
+            .iter()
+            .zip(records.iter_mut())
+            .map(|(id, record)| {
+                if let Some(pending) = self.shared.pending.get(id) {
+                    record.merge(&pending);
+                }
+                record.stats_at(now_ms)
+            })
+            .collect())
+    }
+
+    /// Write pending accesses now (no-op for read-only stores).
+    pub(crate) fn flush(&self) -> TeleologicalStoreResult<usize> {
+        if self.signals.is_none() {
+            return Ok(0);
+        }
+        self.shared.flush()
+    }
+}
+
+fn run_flush_loop(shared: Arc<Shared>, signals: Receiver<Signal>, interval: Duration) {
+    loop {
+        let shutdown = match signals.recv_timeout(interval) {
+            Ok(Signal::Flush) | Err(RecvTimeoutError::Timeout) => false,
+            Ok(Signal::Shutdown) | Err(RecvTimeoutError::Disconnected) => true,
+        };
+        if let Err(e) = shared.flush() {
+            error!("FAIL FAST: access stats flush failed: {}", e);
+        }
+        if shutdown {
+            return;
+        }
+    }
+}
+
+impl Drop for AccessTracker {
+    fn drop(&mut self) {
+        if let Some(signals) = self.signals.take() {
+            let _ = signals.send(Signal::Shutdown);
+        }
+        if let Some(worker) = self.worker.take() {
+            if worker.join().is_err() {
+                error!("Access stats flush thread panicked; pending accesses lost");
+            }
+        }
+    }
+}
+
+impl RocksDbTeleologicalStore {
+    /// Access stats for each of `ids`, including accesses not yet flushed.
+    pub fn access_stats_sync(&self, ids: &[Uuid]) -> TeleologicalStoreResult<Vec<AccessStats>> {
+        self.access_tracker.stats_at(ids, Utc::now())
+    }
+
+    /// Write pending access counts to `CF_ACCESS_STATS` now.
+    pub fn flush_access_stats_sync(&self) -> TeleologicalStoreResult<()> {
+        self.access_tracker.flush().map(|_| ())
+    }
+}
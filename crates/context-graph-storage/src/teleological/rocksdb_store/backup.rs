@@ -0,0 +1,444 @@
+//! Consistent backups of a store and restore into a fresh data directory.
+//!
+//! # Layout
+//!
+//! ```text
+//! <backup>/
+//!   db/                    RocksDB checkpoint (every column family)
+//!   hnsw/                  per-embedder HNSW snapshot files
+//!   backup_manifest.json   written last; a backup without it is incomplete
+//! ```
+//!
+//! Copying a live RocksDB directory yields backups that do not open. The
+//! checkpoint API instead captures a consistent view while writers keep
+//! running, and the HNSW snapshot is taken under the compaction lock right
+//! after it, so no index insert lands between the two. Topics, goals, edges
+//! and audit records live in column families and need no extra files.
+//!
+//! The manifest lists every file with its size and SHA-256. Restore checks
+//! all of them before copying anything.
+//!
+//! # Incremental Backups
+//!
+//! SST files are immutable, so an SST whose name, size and checksum match
+//! one in the base backup is hard-linked to the base's copy instead of
+//! stored twice. The base must stay on the same filesystem and must not be
+//! deleted while later backups link to it.
+
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::{debug, info, warn};
+
+use crate::teleological::integrity::{check_integrity, IntegrityReport};
+
+use super::helpers::hex_encode;
+use super::store::RocksDbTeleologicalStore;
+use super::types::{TeleologicalStoreError, TeleologicalStoreResult, WarmStartStats};
+
+/// Name of the manifest inside a backup directory.
+pub const BACKUP_MANIFEST_FILE: &str = "backup_manifest.json";
+
+/// Current backup format version.
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+const DB_DIR: &str = "db";
+const HNSW_DIR: &str = "hnsw";
+
+/// One file of a backup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupFile {
+    /// Path relative to the backup directory, `/`-separated.
+    pub path: String,
+    pub size: u64,
+    /// Hex SHA-256 of the contents.
+    pub sha256: String,
+    /// Hard-linked to the same file of the base backup.
+    #[serde(default)]
+    pub linked: bool,
+}
+
+/// Contents of [`BACKUP_MANIFEST_FILE`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub version: u32,
+    pub created_at: DateTime<Utc>,
+    /// Data directory the backup was taken from.
+    pub source: String,
+    /// Base backup of an incremental backup.
+    #[serde(default)]
+    pub base: Option<String>,
+    pub files: Vec<BackupFile>,
+}
+
+impl BackupManifest {
+    /// Read the manifest of the backup in `backup_dir`.
+    ///
+    /// # Errors
+    ///
+    /// - `RestoreFailed` if the manifest is missing, malformed or of an
+    ///   unknown version
+    pub fn read(backup_dir: &Path) -> TeleologicalStoreResult<Self> {
+        let path = backup_dir.join(BACKUP_MANIFEST_FILE);
+        let bytes = fs::read(&path).map_err(|e| {
+            restore_error(
+                backup_dir,
+                format!(
+                    "cannot read {}: {} (incomplete backup?)",
+                    BACKUP_MANIFEST_FILE, e
+                ),
+            )
+        })?;
+        let manifest: Self = serde_json::from_slice(&bytes).map_err(|e| {
+            restore_error(
+                backup_dir,
+                format!("malformed {}: {}", BACKUP_MANIFEST_FILE, e),
+            )
+        })?;
+        if manifest.version != BACKUP_FORMAT_VERSION {
+            return Err(restore_error(
+                backup_dir,
+                format!(
+                    "unsupported backup version {} (expected {})",
+                    manifest.version, BACKUP_FORMAT_VERSION
+                ),
+            ));
+        }
+        Ok(manifest)
+    }
+
+    /// Total size of all files in bytes.
+    pub fn total_bytes(&self) -> u64 {
+        self.files.iter().map(|f| f.size).sum()
+    }
+
+    fn has_hnsw_snapshot(&self) -> bool {
+        let prefix = format!("{}/", HNSW_DIR);
+        self.files.iter().any(|f| f.path.starts_with(&prefix))
+    }
+}
+
+/// Options of [`RocksDbTeleologicalStore::create_backup`].
+#[derive(Debug, Clone, Default)]
+pub struct BackupOptions {
+    /// Earlier backup whose unchanged SST files are hard-linked.
+    pub incremental_from: Option<PathBuf>,
+}
+
+/// Outcome of [`RocksDbTeleologicalStore::create_backup`].
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupReport {
+    pub path: PathBuf,
+    /// Files listed in the manifest.
+    pub files: usize,
+    /// Total size of those files.
+    pub bytes: u64,
+    /// SST files hard-linked to the base backup.
+    pub linked_files: usize,
+    /// HNSW snapshot files written.
+    pub hnsw_files: usize,
+    pub elapsed_ms: u64,
+}
+
+/// Outcome of [`restore_backup`].
+#[derive(Debug, Clone)]
+pub struct RestoreReport {
+    /// Database files copied into the data directory.
+    pub files: usize,
+    pub bytes: u64,
+    /// HNSW warm start from the backup's snapshot.
+    pub warm_start: WarmStartStats,
+    /// Integrity check of the restored store.
+    pub integrity: IntegrityReport,
+    pub elapsed_ms: u64,
+}
+
+impl RocksDbTeleologicalStore {
+    /// Write a consistent backup of this store into `dir`.
+    ///
+    /// `dir` must not exist or be empty. Writers may keep running: every
+    /// write committed before the checkpoint is in the backup.
+    ///
+    /// # Errors
+    ///
+    /// - `CheckpointFailed` if `dir` is not empty or RocksDB cannot create
+    ///   the checkpoint
+    /// - `RestoreFailed` if the incremental base does not verify
+    /// - `IndexOperation` if the HNSW snapshot cannot be written
+    pub fn create_backup(
+        &self,
+        dir: &Path,
+        options: &BackupOptions,
+    ) -> TeleologicalStoreResult<BackupReport> {
+        let start = Instant::now();
+        let base = match &options.incremental_from {
+            Some(base_dir) => Some((base_dir.clone(), verify_backup(base_dir)?)),
+            None => None,
+        };
+        prepare_empty_dir(dir).map_err(|message| TeleologicalStoreError::CheckpointFailed {
+            message: format!("backup directory '{}': {}", dir.display(), message),
+        })?;
+
+        let hnsw_files = {
+            // No index insert between the checkpoint and the HNSW snapshot
+            let _guard = self.compaction_lock.write();
+            let checkpoint = rocksdb::checkpoint::Checkpoint::new(&self.db).map_err(|e| {
+                TeleologicalStoreError::CheckpointFailed {
+                    message: e.to_string(),
+                }
+            })?;
+            checkpoint
+                .create_checkpoint(dir.join(DB_DIR))
+                .map_err(|e| TeleologicalStoreError::CheckpointFailed {
+                    message: e.to_string(),
+                })?;
+            self.save_hnsw_snapshot(&dir.join(HNSW_DIR))?
+        };
+
+        let mut files = Vec::new();
+        for sub in [DB_DIR, HNSW_DIR] {
+            collect_files(dir, Path::new(sub), &mut files).map_err(|e| {
+                TeleologicalStoreError::CheckpointFailed {
+                    message: format!("cannot list backup files: {}", e),
+                }
+            })?;
+        }
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let mut linked_files = 0;
+        if let Some((base_dir, base_manifest)) = &base {
+            for file in files.iter_mut().filter(|f| f.path.ends_with(".sst")) {
+                let unchanged = base_manifest
+                    .files
+                    .iter()
+                    .any(|b| b.path == file.path && b.size == file.size && b.sha256 == file.sha256);
+                if !unchanged {
+                    continue;
+                }
+                let target = dir.join(&file.path);
+                fs::remove_file(&target)
+                    .and_then(|()| fs::hard_link(base_dir.join(&file.path), &target))
+                    .map_err(|e| TeleologicalStoreError::CheckpointFailed {
+                        message: format!("cannot link {} to the base backup: {}", file.path, e),
+                    })?;
+                file.linked = true;
+                linked_files += 1;
+            }
+        }
+
+        let manifest = BackupManifest {
+            version: BACKUP_FORMAT_VERSION,
+            created_at: Utc::now(),
+            source: self.path.display().to_string(),
+            base: base.as_ref().map(|(d, _)| d.display().to_string()),
+            files,
+        };
+        write_manifest(dir, &manifest).map_err(|e| TeleologicalStoreError::CheckpointFailed {
+            message: format!("cannot write {}: {}", BACKUP_MANIFEST_FILE, e),
+        })?;
+
+        let report = BackupReport {
+            path: dir.to_path_buf(),
+            files: manifest.files.len(),
+            bytes: manifest.total_bytes(),
+            linked_files,
+            hnsw_files,
+            elapsed_ms: start.elapsed().as_millis() as u64,
+        };
+        info!(
+            "Backup written to {}: {} files, {} bytes ({} linked to base) in {}ms",
+            dir.display(),
+            report.files,
+            report.bytes,
+            report.linked_files,
+            report.elapsed_ms
+        );
+        Ok(report)
+    }
+}
+
+/// Check every file of the backup in `backup_dir` against its manifest.
+///
+/// # Errors
+///
+/// - `RestoreFailed` naming the first missing, resized or corrupted file
+pub fn verify_backup(backup_dir: &Path) -> TeleologicalStoreResult<BackupManifest> {
+    let manifest = BackupManifest::read(backup_dir)?;
+    for file in &manifest.files {
+        let path = backup_dir.join(&file.path);
+        let (size, sha256) = hash_file(&path)
+            .map_err(|e| restore_error(backup_dir, format!("{}: {}", file.path, e)))?;
+        if size != file.size || sha256 != file.sha256 {
+            return Err(restore_error(
+                backup_dir,
+                format!(
+                    "{} does not match the manifest (size {} vs {}, checksum {})",
+                    file.path,
+                    size,
+                    file.size,
+                    if sha256 == file.sha256 {
+                        "ok"
+                    } else {
+                        "mismatch"
+                    }
+                ),
+            ));
+        }
+    }
+    debug!(
+        "Verified {} backup files in {}",
+        manifest.files.len(),
+        backup_dir.display()
+    );
+    Ok(manifest)
+}
+
+/// Restore the backup in `backup_dir` into the new data directory `data_dir`.
+///
+/// Verifies the manifest checksums, copies the database, opens it (which
+/// rebuilds or loads the indexes as on any start), warm-starts HNSW from
+/// the backup's snapshot, persists the indexes and runs the integrity
+/// checker. `data_dir` must not exist or be empty.
+///
+/// # Errors
+///
+/// - `RestoreFailed` if the backup does not verify, `data_dir` is not empty,
+///   or a file cannot be copied
+/// - Any error of opening the restored store or warming its indexes
+pub fn restore_backup(
+    backup_dir: &Path,
+    data_dir: &Path,
+) -> TeleologicalStoreResult<RestoreReport> {
+    let start = Instant::now();
+    let manifest = verify_backup(backup_dir)?;
+    prepare_empty_dir(data_dir).map_err(|message| TeleologicalStoreError::RestoreFailed {
+        path: data_dir.display().to_string(),
+        message,
+    })?;
+
+    let prefix = format!("{}/", DB_DIR);
+    let mut files = 0;
+    let mut bytes = 0;
+    for file in manifest
+        .files
+        .iter()
+        .filter(|f| f.path.starts_with(&prefix))
+    {
+        let target = data_dir.join(&file.path[prefix.len()..]);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| restore_error(data_dir, format!("{}: {}", parent.display(), e)))?;
+        }
+        bytes += fs::copy(backup_dir.join(&file.path), &target)
+            .map_err(|e| restore_error(data_dir, format!("cannot copy {}: {}", file.path, e)))?;
+        files += 1;
+    }
+
+    let store = RocksDbTeleologicalStore::open(data_dir)?;
+    let warm_start = if manifest.has_hnsw_snapshot() {
+        store.warm_start_hnsw_indexes(&backup_dir.join(HNSW_DIR))?
+    } else {
+        warn!(
+            "Backup {} has no HNSW snapshot; indexes were rebuilt on open",
+            backup_dir.display()
+        );
+        WarmStartStats::default()
+    };
+    store.persist_hnsw_indexes()?;
+    let integrity = check_integrity(&store.db)?;
+    drop(store);
+
+    let report = RestoreReport {
+        files,
+        bytes,
+        warm_start,
+        integrity,
+        elapsed_ms: start.elapsed().as_millis() as u64,
+    };
+    info!(
+        "Restored {} into {}: {} files, {} bytes, {} fingerprints, {} integrity issues in {}ms",
+        backup_dir.display(),
+        data_dir.display(),
+        report.files,
+        report.bytes,
+        report.integrity.fingerprints_scanned,
+        report.integrity.issue_count(),
+        report.elapsed_ms
+    );
+    Ok(report)
+}
+
+fn restore_error(path: &Path, message: String) -> TeleologicalStoreError {
+    TeleologicalStoreError::RestoreFailed {
+        path: path.display().to_string(),
+        message,
+    }
+}
+
+/// Create `dir` if missing; refuse one that already holds files.
+fn prepare_empty_dir(dir: &Path) -> Result<(), String> {
+    match fs::read_dir(dir) {
+        Ok(mut entries) => {
+            if entries.next().is_some() {
+                return Err("directory is not empty".to_string());
+            }
+            Ok(())
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Append every file under `root/rel` to `files`, hashed.
+fn collect_files(root: &Path, rel: &Path, files: &mut Vec<BackupFile>) -> io::Result<()> {
+    let dir = root.join(rel);
+    if !dir.exists() {
+        return Ok(());
+    }
+    for entry in fs::read_dir(&dir)? {
+        let entry = entry?;
+        let rel_path = rel.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            collect_files(root, &rel_path, files)?;
+            continue;
+        }
+        let (size, sha256) = hash_file(&entry.path())?;
+        let path = rel_path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        files.push(BackupFile {
+            path,
+            size,
+            sha256,
+            linked: false,
+        });
+    }
+    Ok(())
+}
+
+/// Size and hex SHA-256 of the file at `path`.
+fn hash_file(path: &Path) -> io::Result<(u64, String)> {
+    let mut hasher = Sha256::new();
+    let size = io::copy(&mut File::open(path)?, &mut hasher)?;
+    Ok((size, hex_encode(&hasher.finalize())))
+}
+
+/// Write the manifest under a temporary name and rename it into place.
+fn write_manifest(dir: &Path, manifest: &BackupManifest) -> io::Result<()> {
+    let json = serde_json::to_vec_pretty(manifest)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let path = dir.join(BACKUP_MANIFEST_FILE);
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, json)?;
+    fs::File::open(&tmp)?.sync_all()?;
+    fs::rename(&tmp, &path)
+}
@@ -345,7 +345,7 @@ impl CausalE11Index {
         self.index.read().size()
     }
 
-    /// HIGH-2 FIX: Check if compaction is needed (removed/total > 25%).
+    /// HIGH-2 FIX: Check if compaction is needed (removed/total > 20%).
     /// Compaction rebuilds the index, eliminating orphaned vectors.
     pub fn needs_compaction(&self) -> bool {
         let total = self.usearch_size();
@@ -353,8 +353,8 @@ impl CausalE11Index {
             return false;
         }
         let removed = self.removed_count();
-        // Compact when >25% of vectors are orphaned
-        removed * 4 > total
+        // Compact when >20% of vectors are orphaned
+        removed * 5 > total
     }
 
     /// HIGH-2 FIX: Rebuild the index from the currently active vectors.
@@ -734,7 +734,7 @@ mod tests {
         assert_eq!(index.removed_count(), 0);
         assert!(!index.needs_compaction());
 
-        // Remove 3 entries (30% > 25% threshold)
+        // Remove 3 entries (30% > 20% threshold)
         for id in &ids[..3] {
             assert!(index.remove(*id));
         }
@@ -743,7 +743,7 @@ mod tests {
         assert_eq!(index.removed_count(), 3);
         // usearch_size() should still be 10 (orphaned vectors remain)
         assert_eq!(index.usearch_size(), 10);
-        // 3/10 = 30% > 25% => needs compaction
+        // 3/10 = 30% > 20% => needs compaction
         assert!(index.needs_compaction());
     }
 
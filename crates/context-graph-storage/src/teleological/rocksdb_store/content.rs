@@ -2,6 +2,11 @@
 //!
 //! Contains methods for storing and retrieving raw content text.
 //!
+//! New content is written to the content-addressable blob store
+//! (`content_blobs`), so fingerprints with identical content share one copy.
+//! CF_CONTENT still holds content written before blobs existed; reads fall
+//! back to it and re-storing content moves it into a blob.
+//!
 //! # Concurrency
 //!
 //! Batch operations use `spawn_blocking` to avoid blocking the Tokio async
@@ -9,6 +14,7 @@
 //! they're typically fast (<1ms).
 
 use std::sync::Arc;
+use rocksdb::WriteBatch;
 use sha2::{Digest, Sha256};
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
@@ -19,6 +25,7 @@ use crate::teleological::column_families::CF_CONTENT;
 use crate::teleological::schema::content_key;
 use crate::teleological::serialization::deserialize_teleological_fingerprint;
 
+use super::content_blobs::read_blob_content;
 use super::store::RocksDbTeleologicalStore;
 use super::types::TeleologicalStoreError;
 
@@ -82,20 +89,23 @@ impl RocksDbTeleologicalStore {
             );
         }
 
-        // 4. Store content
-        let cf = self.cf_content();
-        let key = content_key(&id);
+        // 4. Reference the shared blob and drop any legacy per-fingerprint copy
+        let _index_guard = self.secondary_index_lock.lock();
+        let mut batch = WriteBatch::default();
+        self.put_content_blob(&mut batch, &id, &computed_hash, content_bytes)?;
+        batch.delete_cf(self.cf_content(), content_key(&id));
 
-        self.db.put_cf(cf, key, content_bytes).map_err(|e| {
+        self.db.write(batch).map_err(|e| {
             error!(
                 "ROCKSDB ERROR: Failed to store content for fingerprint {}: {}",
                 id, e
             );
             TeleologicalStoreError::rocksdb_op("put_content", CF_CONTENT, Some(id), e)
         })?;
+        drop(_index_guard);
 
         info!(
-            "Stored content for fingerprint {} ({} bytes, LZ4 compressed)",
+            "Stored content for fingerprint {} ({} bytes, content-addressed)",
             id,
             content_bytes.len()
         );
@@ -105,9 +115,13 @@ impl RocksDbTeleologicalStore {
     /// Retrieve content for a fingerprint (internal async wrapper).
     ///
     /// DAT-8: Verifies SHA-256 hash on retrieval to detect data corruption.
-    /// If the fingerprint exists and its stored content_hash does not match
-    /// the recomputed hash of the retrieved bytes, returns an error.
+    /// Blobs are checked against their content-hash key; legacy CF_CONTENT
+    /// entries against the fingerprint's stored content_hash.
     pub(crate) async fn get_content_async(&self, id: Uuid) -> CoreResult<Option<String>> {
+        if let Some(bytes) = read_blob_content(&self.db, &id)? {
+            return content_from_utf8(id, bytes).map(Some);
+        }
+
         let key = content_key(&id);
         let cf = self.cf_content();
 
@@ -227,6 +241,10 @@ impl RocksDbTeleologicalStore {
                 .zip(fp_results.into_iter())
                 .enumerate()
             {
+                if let Some(bytes) = read_blob_content(&db, &ids[i])? {
+                    contents.push(Some(content_from_utf8(ids[i], bytes)?));
+                    continue;
+                }
                 match content_result {
                     Ok(Some(bytes)) => {
                         // DAT-8: SHA-256 verification (same as single-retrieval path)
@@ -321,10 +339,30 @@ impl RocksDbTeleologicalStore {
     }
 
     /// Delete content for a fingerprint (internal async wrapper).
+    ///
+    /// Drops the fingerprint's blob reference; the blob itself is deleted
+    /// only when no other fingerprint references it.
     pub(crate) async fn delete_content_async(&self, id: Uuid) -> CoreResult<bool> {
         let key = content_key(&id);
         let cf = self.cf_content();
 
+        {
+            let _index_guard = self.secondary_index_lock.lock();
+            let mut batch = WriteBatch::default();
+            if self.release_content_blob(&mut batch, &id)? {
+                batch.delete_cf(cf, key);
+                self.db.write(batch).map_err(|e| {
+                    error!(
+                        "ROCKSDB ERROR: Failed to delete content for fingerprint {}: {}",
+                        id, e
+                    );
+                    CoreError::StorageError(format!("Failed to delete content for {}: {}", id, e))
+                })?;
+                info!("Released content blob reference for fingerprint {}", id);
+                return Ok(true);
+            }
+        }
+
         let exists = match self.db.get_cf(cf, key) {
             Ok(Some(_)) => true,
             Ok(None) => {
@@ -357,3 +395,17 @@ impl RocksDbTeleologicalStore {
         Ok(exists)
     }
 }
+
+/// Decode stored content bytes, failing on invalid UTF-8 (data corruption).
+fn content_from_utf8(id: Uuid, bytes: Vec<u8>) -> CoreResult<String> {
+    String::from_utf8(bytes).map_err(|e| {
+        error!(
+            "CONTENT ERROR: Invalid UTF-8 in content blob for fingerprint {}. Error: {}",
+            id, e
+        );
+        CoreError::Internal(format!(
+            "Invalid UTF-8 in content for {}: {}. Data corruption detected.",
+            id, e
+        ))
+    })
+}
@@ -0,0 +1,358 @@
+//! Content-addressable blob operations (CF_CONTENT_BLOBS).
+//!
+//! Raw content is stored once per distinct SHA-256 `content_hash` and shared
+//! by every fingerprint with that hash:
+//!
+//! - `b` + hash → codec byte + payload (zstd above [`BLOB_COMPRESSION_THRESHOLD`])
+//! - `r` + hash → sorted list of referencing fingerprint IDs
+//! - `i` + UUID → hash the fingerprint's content is stored under
+//!
+//! The blob is removed in the same WriteBatch that drops its last reference.
+//! Reference updates read-modify-write the ID list, so callers must hold
+//! `secondary_index_lock` until the batch is committed (same contract as the
+//! content_hash index).
+//!
+//! Reads verify the SHA-256 of the decoded bytes against the key (DAT-8).
+
+use std::io::{self, Cursor, Read};
+
+use rocksdb::{WriteBatch, DB};
+use sha2::{Digest, Sha256};
+use tracing::{debug, error};
+use uuid::Uuid;
+
+use context_graph_core::error::{CoreError, CoreResult};
+
+use crate::teleological::column_families::CF_CONTENT_BLOBS;
+use crate::teleological::schema::{
+    content_blob_key, content_blob_pointer_key, content_blob_refs_key,
+};
+use crate::teleological::serialization::{deserialize_memory_id_list, serialize_memory_id_list};
+
+use super::store::RocksDbTeleologicalStore;
+use super::types::{TeleologicalStoreError, TeleologicalStoreResult};
+
+/// Payloads larger than this (bytes) are zstd-compressed when that shrinks them.
+pub const BLOB_COMPRESSION_THRESHOLD: usize = 4096;
+
+/// zstd level for blob payloads (fast, ~3x on prose).
+const ZSTD_LEVEL: i32 = 3;
+
+/// Codec byte: payload stored as-is.
+const CODEC_RAW: u8 = 0;
+/// Codec byte: payload is a zstd frame.
+const CODEC_ZSTD: u8 = 1;
+
+/// Encode content as a blob record: codec byte followed by the payload.
+fn encode_blob(content: &[u8]) -> Vec<u8> {
+    if content.len() > BLOB_COMPRESSION_THRESHOLD {
+        match zstd::bulk::compress(content, ZSTD_LEVEL) {
+            Ok(compressed) if compressed.len() < content.len() => {
+                let mut record = Vec::with_capacity(compressed.len() + 1);
+                record.push(CODEC_ZSTD);
+                record.extend_from_slice(&compressed);
+                return record;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                // Non-fatal: store uncompressed
+                debug!(error = %e, "zstd compression failed, storing blob uncompressed");
+            }
+        }
+    }
+    let mut record = Vec::with_capacity(content.len() + 1);
+    record.push(CODEC_RAW);
+    record.extend_from_slice(content);
+    record
+}
+
+/// Streaming reader over a blob record.
+///
+/// Decompresses incrementally and checks the SHA-256 of everything read
+/// against the content hash once the end is reached; a mismatch surfaces as
+/// an `InvalidData` error on the final read.
+pub struct ContentBlobReader {
+    inner: Box<dyn Read + Send>,
+    hasher: Sha256,
+    expected: [u8; 32],
+    verified: bool,
+}
+
+impl ContentBlobReader {
+    fn new(record: Vec<u8>, expected: [u8; 32]) -> io::Result<Self> {
+        let codec = *record
+            .first()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "empty blob record"))?;
+        let mut payload = Cursor::new(record);
+        payload.set_position(1);
+        let inner: Box<dyn Read + Send> = match codec {
+            CODEC_RAW => Box::new(payload),
+            CODEC_ZSTD => Box::new(zstd::stream::read::Decoder::new(payload)?),
+            other => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unknown blob codec {}", other),
+                ))
+            }
+        };
+        Ok(Self {
+            inner,
+            hasher: Sha256::new(),
+            expected,
+            verified: false,
+        })
+    }
+}
+
+impl Read for ContentBlobReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        if n > 0 {
+            self.hasher.update(&buf[..n]);
+        } else if !buf.is_empty() && !self.verified {
+            let computed: [u8; 32] = self.hasher.clone().finalize().into();
+            if computed != self.expected {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "content blob SHA-256 mismatch",
+                ));
+            }
+            self.verified = true;
+        }
+        Ok(n)
+    }
+}
+
+/// Content hash a fingerprint's content is stored under, if any.
+fn read_pointer(db: &DB, id: &Uuid) -> CoreResult<Option<[u8; 32]>> {
+    let cf = db
+        .cf_handle(CF_CONTENT_BLOBS)
+        .ok_or_else(|| CoreError::Internal("CF_CONTENT_BLOBS not found".to_string()))?;
+    let value = db
+        .get_pinned_cf(cf, content_blob_pointer_key(id))
+        .map_err(|e| {
+            CoreError::StorageError(format!("Failed to read content pointer for {}: {}", id, e))
+        })?;
+    match value {
+        Some(bytes) => {
+            let hash: [u8; 32] = bytes.as_ref().try_into().map_err(|_| {
+                CoreError::Internal(format!(
+                    "Content pointer for {} has {} bytes, expected 32. Data corruption detected.",
+                    id,
+                    bytes.len()
+                ))
+            })?;
+            Ok(Some(hash))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Open a streaming reader over the blob holding `id`'s content.
+pub(crate) fn open_blob_reader(db: &DB, id: &Uuid) -> CoreResult<Option<ContentBlobReader>> {
+    let Some(hash) = read_pointer(db, id)? else {
+        return Ok(None);
+    };
+    let cf = db
+        .cf_handle(CF_CONTENT_BLOBS)
+        .ok_or_else(|| CoreError::Internal("CF_CONTENT_BLOBS not found".to_string()))?;
+    let record = db
+        .get_cf(cf, content_blob_key(&hash))
+        .map_err(|e| {
+            CoreError::StorageError(format!("Failed to read content blob for {}: {}", id, e))
+        })?
+        .ok_or_else(|| {
+            error!(
+                "CONTENT ERROR: Fingerprint {} points at missing blob {:02x?}",
+                id,
+                &hash[..8]
+            );
+            CoreError::Internal(format!(
+                "Content blob for {} is missing. Data corruption detected.",
+                id
+            ))
+        })?;
+    ContentBlobReader::new(record, hash)
+        .map(Some)
+        .map_err(|e| CoreError::Internal(format!("Invalid content blob for {}: {}", id, e)))
+}
+
+/// Read and verify the full content of `id`'s blob.
+pub(crate) fn read_blob_content(db: &DB, id: &Uuid) -> CoreResult<Option<Vec<u8>>> {
+    let Some(mut reader) = open_blob_reader(db, id)? else {
+        return Ok(None);
+    };
+    let mut content = Vec::new();
+    reader.read_to_end(&mut content).map_err(|e| {
+        error!("DAT-8: Content blob for {} failed verification: {}", id, e);
+        CoreError::Internal(format!(
+            "Content integrity check failed for {}: {}. Stored content may be corrupted.",
+            id, e
+        ))
+    })?;
+    Ok(Some(content))
+}
+
+impl RocksDbTeleologicalStore {
+    /// Read the sorted reference list for a content hash.
+    fn read_blob_refs(&self, content_hash: &[u8; 32]) -> TeleologicalStoreResult<Vec<Uuid>> {
+        let cf = self.get_cf(CF_CONTENT_BLOBS)?;
+        let existing = self
+            .db
+            .get_cf(cf, content_blob_refs_key(content_hash))
+            .map_err(|e| TeleologicalStoreError::rocksdb_op("get", CF_CONTENT_BLOBS, None, e))?;
+        match existing {
+            Some(data) => {
+                let mut ids = deserialize_memory_id_list(&data)?;
+                ids.sort_unstable();
+                Ok(ids)
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Reference `content` from fingerprint `id`, writing the blob if new.
+    ///
+    /// Re-storing the same content for `id` is a no-op; storing different
+    /// content releases the previous blob reference first.
+    /// Caller must hold `secondary_index_lock` until `batch` is committed.
+    pub(crate) fn put_content_blob(
+        &self,
+        batch: &mut WriteBatch,
+        id: &Uuid,
+        content_hash: &[u8; 32],
+        content: &[u8],
+    ) -> TeleologicalStoreResult<()> {
+        let cf = self.get_cf(CF_CONTENT_BLOBS)?;
+        let previous = read_pointer(&self.db, id)?;
+        if let Some(old) = previous.filter(|old| old != content_hash) {
+            self.release_blob_ref(batch, id, &old)?;
+        }
+
+        let blob_key = content_blob_key(content_hash);
+        let blob_exists = self
+            .db
+            .get_pinned_cf(cf, blob_key)
+            .map_err(|e| TeleologicalStoreError::rocksdb_op("get", CF_CONTENT_BLOBS, Some(*id), e))?
+            .is_some();
+        if !blob_exists {
+            batch.put_cf(cf, blob_key, encode_blob(content));
+        }
+
+        let mut refs = self.read_blob_refs(content_hash)?;
+        if let Err(pos) = refs.binary_search(id) {
+            refs.insert(pos, *id);
+            batch.put_cf(
+                cf,
+                content_blob_refs_key(content_hash),
+                serialize_memory_id_list(&refs),
+            );
+        }
+        batch.put_cf(cf, content_blob_pointer_key(id), content_hash);
+        Ok(())
+    }
+
+    /// Drop `id`'s blob reference, deleting the blob with its last reference.
+    ///
+    /// Returns whether `id` referenced a blob.
+    /// Caller must hold `secondary_index_lock` until `batch` is committed.
+    pub(crate) fn release_content_blob(
+        &self,
+        batch: &mut WriteBatch,
+        id: &Uuid,
+    ) -> TeleologicalStoreResult<bool> {
+        match read_pointer(&self.db, id)? {
+            Some(hash) => {
+                self.release_blob_ref(batch, id, &hash)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn release_blob_ref(
+        &self,
+        batch: &mut WriteBatch,
+        id: &Uuid,
+        content_hash: &[u8; 32],
+    ) -> TeleologicalStoreResult<()> {
+        let cf = self.get_cf(CF_CONTENT_BLOBS)?;
+        let mut refs = self.read_blob_refs(content_hash)?;
+        if let Ok(pos) = refs.binary_search(id) {
+            refs.remove(pos);
+        }
+        if refs.is_empty() {
+            batch.delete_cf(cf, content_blob_refs_key(content_hash));
+            batch.delete_cf(cf, content_blob_key(content_hash));
+            debug!(
+                "Releasing last reference to content blob {:02x?}; blob deleted",
+                &content_hash[..8]
+            );
+        } else {
+            batch.put_cf(
+                cf,
+                content_blob_refs_key(content_hash),
+                serialize_memory_id_list(&refs),
+            );
+        }
+        batch.delete_cf(cf, content_blob_pointer_key(id));
+        Ok(())
+    }
+
+    /// Number of fingerprints referencing the blob for `content_hash`.
+    pub fn content_blob_ref_count(&self, content_hash: &[u8; 32]) -> CoreResult<usize> {
+        Ok(self.read_blob_refs(content_hash)?.len())
+    }
+
+    /// Whether a blob is stored for `content_hash`.
+    pub fn content_blob_exists(&self, content_hash: &[u8; 32]) -> CoreResult<bool> {
+        let cf = self.get_cf(CF_CONTENT_BLOBS)?;
+        let exists = self
+            .db
+            .get_pinned_cf(cf, content_blob_key(content_hash))
+            .map_err(|e| TeleologicalStoreError::rocksdb_op("get", CF_CONTENT_BLOBS, None, e))?
+            .is_some();
+        Ok(exists)
+    }
+
+    /// Stream `id`'s content without materializing it as one string.
+    ///
+    /// Only content stored as a blob is available here; content written
+    /// before blobs existed is returned by `get_content` alone.
+    pub fn open_content_reader(&self, id: Uuid) -> CoreResult<Option<ContentBlobReader>> {
+        open_blob_reader(&self.db, &id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_all(record: Vec<u8>, content: &[u8]) -> io::Result<Vec<u8>> {
+        let hash: [u8; 32] = Sha256::digest(content).into();
+        let mut out = Vec::new();
+        ContentBlobReader::new(record, hash)?.read_to_end(&mut out)?;
+        Ok(out)
+    }
+
+    #[test]
+    fn test_small_blobs_stay_raw_and_large_blobs_compress() {
+        let small = b"short memory".to_vec();
+        let record = encode_blob(&small);
+        assert_eq!(record[0], CODEC_RAW);
+        assert_eq!(read_all(record, &small).unwrap(), small);
+
+        let large = "the same sentence repeated. ".repeat(1000).into_bytes();
+        let record = encode_blob(&large);
+        assert_eq!(record[0], CODEC_ZSTD);
+        assert!(record.len() < large.len() / 10);
+        assert_eq!(read_all(record, &large).unwrap(), large);
+    }
+
+    #[test]
+    fn test_reader_rejects_hash_mismatch() {
+        let record = encode_blob(b"original");
+        let err = read_all(record, b"tampered").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
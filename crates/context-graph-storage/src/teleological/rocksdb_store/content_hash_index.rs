@@ -0,0 +1,96 @@
+//! Content hash secondary index operations.
+//!
+//! Maps the SHA-256 `content_hash` of a fingerprint to every fingerprint ID
+//! stored with that hash (CF_CONTENT_HASH_INDEX). Used for O(1) duplicate
+//! detection at store time.
+//!
+//! Index entries are written in the same WriteBatch as the fingerprint itself
+//! (under `secondary_index_lock`), so the index never drifts from the primary
+//! CF on crash. ID lists reuse the inverted index format
+//! (`serialize_memory_id_list`) and are kept sorted for binary search.
+
+use rocksdb::WriteBatch;
+use uuid::Uuid;
+
+use context_graph_core::error::CoreResult;
+
+use crate::teleological::column_families::CF_CONTENT_HASH_INDEX;
+use crate::teleological::serialization::{deserialize_memory_id_list, serialize_memory_id_list};
+
+use super::store::RocksDbTeleologicalStore;
+use super::types::{TeleologicalStoreError, TeleologicalStoreResult};
+
+impl RocksDbTeleologicalStore {
+    /// Read the raw ID list for a content hash (no soft-delete filtering).
+    fn read_content_hash_entry(&self, content_hash: &[u8; 32]) -> TeleologicalStoreResult<Vec<Uuid>> {
+        let cf = self.get_cf(CF_CONTENT_HASH_INDEX)?;
+        let existing = self
+            .db
+            .get_cf(cf, content_hash)
+            .map_err(|e| TeleologicalStoreError::rocksdb_op("get", CF_CONTENT_HASH_INDEX, None, e))?;
+        match existing {
+            Some(data) => {
+                let mut ids = deserialize_memory_id_list(&data)?;
+                ids.sort_unstable();
+                Ok(ids)
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Add a fingerprint ID to the content hash index.
+    ///
+    /// Caller must hold `secondary_index_lock` until `batch` is committed.
+    pub(crate) fn add_to_content_hash_index(
+        &self,
+        batch: &mut WriteBatch,
+        id: &Uuid,
+        content_hash: &[u8; 32],
+    ) -> TeleologicalStoreResult<()> {
+        let cf = self.get_cf(CF_CONTENT_HASH_INDEX)?;
+        let mut ids = self.read_content_hash_entry(content_hash)?;
+        if let Err(pos) = ids.binary_search(id) {
+            ids.insert(pos, *id);
+            batch.put_cf(cf, content_hash, serialize_memory_id_list(&ids));
+        }
+        Ok(())
+    }
+
+    /// Remove a fingerprint ID from the content hash index.
+    ///
+    /// Deletes the key entirely when the last ID is removed.
+    /// Caller must hold `secondary_index_lock` until `batch` is committed.
+    pub(crate) fn remove_from_content_hash_index(
+        &self,
+        batch: &mut WriteBatch,
+        id: &Uuid,
+        content_hash: &[u8; 32],
+    ) -> TeleologicalStoreResult<()> {
+        let cf = self.get_cf(CF_CONTENT_HASH_INDEX)?;
+        let mut ids = self.read_content_hash_entry(content_hash)?;
+        if let Ok(pos) = ids.binary_search(id) {
+            ids.remove(pos);
+            if ids.is_empty() {
+                batch.delete_cf(cf, content_hash);
+            } else {
+                batch.put_cf(cf, content_hash, serialize_memory_id_list(&ids));
+            }
+        }
+        Ok(())
+    }
+
+    /// Find all live fingerprints stored with the given content hash (internal async wrapper).
+    ///
+    /// Soft-deleted and TTL-expired fingerprints are filtered out (their index
+    /// entries are removed on hard delete / GC / purge).
+    pub(crate) async fn find_by_content_hash_async(
+        &self,
+        content_hash: &[u8; 32],
+    ) -> CoreResult<Vec<Uuid>> {
+        let ids = self.read_content_hash_entry(content_hash)?;
+        Ok(ids
+            .into_iter()
+            .filter(|id| !self.is_soft_deleted(id) && !self.is_expired(id))
+            .collect())
+    }
+}
@@ -9,26 +9,46 @@
 //! of spawn_blocking comes from batch/iteration operations in search.rs and
 //! persistence.rs.
 
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
 use rocksdb::WriteBatch;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 use context_graph_core::error::{CoreError, CoreResult};
-use context_graph_core::types::fingerprint::TeleologicalFingerprint;
+use context_graph_core::teleological::{Embedder, EmbedderMask};
+use context_graph_core::types::fingerprint::{
+    PartialEmbedding, PartialFingerprint, SemanticFingerprint, TeleologicalFingerprint,
+};
 
 use crate::teleological::column_families::{
-    CF_E12_LATE_INTERACTION, CF_E13_SPLADE_INVERTED, CF_E1_MATRYOSHKA_128, CF_FINGERPRINTS,
-    CF_SOURCE_METADATA, CF_TOPIC_PROFILES, QUANTIZED_EMBEDDER_CFS,
+    CF_ACCESS_STATS, CF_E12_LATE_INTERACTION, CF_E13_SPLADE_INVERTED, CF_E1_MATRYOSHKA_128,
+    CF_FINGERPRINTS, CF_SOURCE_METADATA, CF_TOPIC_PROFILES, QUANTIZED_EMBEDDER_CFS,
 };
 use crate::teleological::schema::{
     content_key, e12_late_interaction_key, e1_matryoshka_128_key, fingerprint_key,
     source_metadata_key, topic_profile_key,
 };
-use crate::teleological::serialization::deserialize_teleological_fingerprint;
+use crate::teleological::serialization::{
+    deserialize_e1_matryoshka_128, deserialize_teleological_fingerprint, deserialize_topic_profile,
+    serialize_teleological_fingerprint,
+};
 
 use super::store::RocksDbTeleologicalStore;
 use super::types::TeleologicalStoreError;
 
+/// Embedders whose only state outside the fingerprint record is their own
+/// HNSW index, so `update_embeddings` can replace them in place.
+pub(crate) const IN_PLACE_EMBEDDERS: [Embedder; 6] = [
+    Embedder::TemporalRecent,
+    Embedder::TemporalPeriodic,
+    Embedder::TemporalPositional,
+    Embedder::Code,
+    Embedder::Hdc,
+    Embedder::Entity,
+];
+
 /// Key prefix for soft-delete markers persisted in CF_SYSTEM.
 /// Format: "soft_deleted::{uuid}" -> i64 timestamp (8 bytes, big-endian Unix epoch millis)
 pub(crate) const SOFT_DELETE_PREFIX: &str = "soft_deleted::";
@@ -39,6 +59,28 @@ pub(crate) fn soft_delete_key(id: &Uuid) -> String {
     format!("{}{}", SOFT_DELETE_PREFIX, id)
 }
 
+/// Key prefix for TTL expiry markers persisted in CF_SYSTEM.
+/// Format: "expires_at::{uuid}" -> i64 deadline (8 bytes, big-endian Unix epoch millis)
+pub(crate) const EXPIRES_AT_PREFIX: &str = "expires_at::";
+
+/// Build the CF_SYSTEM key for a TTL expiry marker.
+#[inline]
+pub(crate) fn expires_at_key(id: &Uuid) -> String {
+    format!("{}{}", EXPIRES_AT_PREFIX, id)
+}
+
+/// Key prefix for namespace markers persisted in CF_SYSTEM.
+/// Format: "namespace::{uuid}" -> UTF-8 namespace name.
+/// Only non-default namespaces are recorded; a missing marker means "default",
+/// which keeps fingerprints stored before namespaces existed readable.
+pub(crate) const NAMESPACE_PREFIX: &str = "namespace::";
+
+/// Build the CF_SYSTEM key for a namespace marker.
+#[inline]
+pub(crate) fn namespace_key(id: &Uuid) -> String {
+    format!("{}{}", NAMESPACE_PREFIX, id)
+}
+
 impl RocksDbTeleologicalStore {
     /// Store a fingerprint (internal async wrapper).
     ///
@@ -72,8 +114,16 @@ impl RocksDbTeleologicalStore {
             });
         }
 
+        // Hidden from search until every index holds it
+        self.commit_log.begin(id);
+        // A previously hard-deleted ID counts accesses again
+        self.access_tracker.revive(&id);
+
         // Store in RocksDB (primary storage) — new insert, count for IDF
-        self.store_fingerprint_internal(&fingerprint, true)?;
+        if let Err(e) = self.store_fingerprint_internal(&fingerprint, true) {
+            self.commit_log.forget(&id);
+            return Err(e);
+        }
 
         // Add to per-embedder indexes for O(log n) search
         // DAT-4 fix: If indexing fails, rollback the RocksDB write to prevent
@@ -110,6 +160,9 @@ impl RocksDbTeleologicalStore {
             return Err(CoreError::IndexError(e.to_string()));
         }
 
+        let seq = self.commit_log.publish(id);
+        debug!("Fingerprint {} visible at commit sequence {}", id, seq);
+
         Ok(id)
     }
 
@@ -120,8 +173,8 @@ impl RocksDbTeleologicalStore {
     ) -> CoreResult<Option<TeleologicalFingerprint>> {
         debug!("Retrieving fingerprint {}", id);
 
-        // Check soft-deleted
-        if self.is_soft_deleted(&id) {
+        // Check soft-deleted and TTL expiry
+        if self.is_soft_deleted(&id) || self.is_expired(&id) {
             return Ok(None);
         }
 
@@ -136,6 +189,99 @@ impl RocksDbTeleologicalStore {
         }
     }
 
+    /// Retrieve a subset of a fingerprint (internal async wrapper).
+    ///
+    /// Reads only what the mask needs:
+    /// - `e1_matryoshka_128` (512 bytes) - always; also serves as the existence check
+    /// - `topic_profiles` (52 bytes) - always, `None` if not yet profiled
+    /// - `e12_late_interaction` - when E12 is the only full space requested
+    /// - `fingerprints` (~63KB) - only when any other full space is requested
+    pub(crate) async fn retrieve_partial_async(
+        &self,
+        id: Uuid,
+        mask: EmbedderMask,
+    ) -> CoreResult<Option<PartialFingerprint>> {
+        debug!("Retrieving partial fingerprint {} (mask={:#06x})", id, mask.as_u16());
+
+        if self.is_soft_deleted(&id) || self.is_expired(&id) {
+            return Ok(None);
+        }
+
+        // E12 has its own CF; every other space lives only in the full record.
+        let needs_full_record = mask.iter().any(|e| e != Embedder::LateInteraction);
+
+        let mut partial = if needs_full_record {
+            let raw = match self.get_fingerprint_raw(id)? {
+                Some(data) => data,
+                None => return Ok(None),
+            };
+            let fp = deserialize_teleological_fingerprint(&raw)?;
+            let mut partial = PartialFingerprint::from_full(&fp, mask);
+            partial.bytes_read = raw.len();
+            partial
+        } else {
+            // CF_E1_MATRYOSHKA_128 is written for every stored fingerprint
+            let cf_mat = self.get_cf(CF_E1_MATRYOSHKA_128)?;
+            let mat_raw = self
+                .db
+                .get_cf(cf_mat, e1_matryoshka_128_key(&id))
+                .map_err(|e| {
+                    TeleologicalStoreError::rocksdb_op("get", CF_E1_MATRYOSHKA_128, Some(id), e)
+                })?;
+            let mat_raw = match mat_raw {
+                Some(data) => data,
+                None => return Ok(None),
+            };
+            let mut partial = PartialFingerprint::new(id, mask);
+            partial.e1_matryoshka_128 = Some(deserialize_e1_matryoshka_128(&mat_raw).to_vec());
+            partial.bytes_read = mat_raw.len();
+
+            if mask.contains(Embedder::LateInteraction) {
+                let cf_e12 = self.get_cf(CF_E12_LATE_INTERACTION)?;
+                let tokens: Vec<Vec<f32>> = match self
+                    .db
+                    .get_cf(cf_e12, e12_late_interaction_key(&id))
+                    .map_err(|e| {
+                        TeleologicalStoreError::rocksdb_op(
+                            "get",
+                            CF_E12_LATE_INTERACTION,
+                            Some(id),
+                            e,
+                        )
+                    })? {
+                    Some(data) => {
+                        partial.bytes_read += data.len();
+                        bincode::deserialize(&data).map_err(|e| {
+                            CoreError::SerializationError(format!(
+                                "Failed to deserialize E12 tokens for {}: {}",
+                                id, e
+                            ))
+                        })?
+                    }
+                    // Empty token lists are not written (see store_fingerprint_internal)
+                    None => Vec::new(),
+                };
+                partial.set_embedding(
+                    Embedder::LateInteraction,
+                    PartialEmbedding::TokenLevel(tokens),
+                );
+            }
+            partial
+        };
+
+        let cf_tp = self.get_cf(CF_TOPIC_PROFILES)?;
+        if let Some(data) = self
+            .db
+            .get_cf(cf_tp, topic_profile_key(&id))
+            .map_err(|e| TeleologicalStoreError::rocksdb_op("get", CF_TOPIC_PROFILES, Some(id), e))?
+        {
+            partial.bytes_read += data.len();
+            partial.topic_profile = Some(deserialize_topic_profile(&data)?);
+        }
+
+        Ok(Some(partial))
+    }
+
     /// Update a fingerprint (internal async wrapper).
     ///
     /// STOR-7 NOTE: There is a brief transient inconsistency window between
@@ -174,6 +320,9 @@ impl RocksDbTeleologicalStore {
                 self.remove_from_e6_sparse_inverted_index(&mut batch, &id, old_e6_sparse)?;
             }
 
+            // Remove old content_hash entry (re-added by store_fingerprint_internal)
+            self.remove_from_content_hash_index(&mut batch, &id, &old_fp.content_hash)?;
+
             self.db.write(batch).map_err(|e| {
                 TeleologicalStoreError::rocksdb_op(
                     "write_batch",
@@ -240,6 +389,18 @@ impl RocksDbTeleologicalStore {
                     }
                 }
 
+                if let Err(ce) = self.remove_from_content_hash_index(
+                    &mut cleanup_batch,
+                    &id,
+                    &fingerprint.content_hash,
+                ) {
+                    warn!(
+                        id = %id,
+                        error = %ce,
+                        "Rollback: failed to remove new content_hash entry"
+                    );
+                }
+
                 if let Err(ce) = self.db.write(cleanup_batch) {
                     warn!(
                         id = %id,
@@ -272,6 +433,55 @@ impl RocksDbTeleologicalStore {
         Ok(true)
     }
 
+    /// Replace the spaces in `mask` (internal async implementation).
+    ///
+    /// When every selected embedder is in [`IN_PLACE_EMBEDDERS`], only the
+    /// fingerprint record and those embedders' HNSW entries are rewritten.
+    /// Anything else (E1 Matryoshka, E5/E10 asymmetric indexes, sparse
+    /// inverted indexes, E12 tokens) goes through the full `update_async`.
+    pub(crate) async fn update_embeddings_async(
+        &self,
+        id: Uuid,
+        mask: EmbedderMask,
+        mut embeddings: SemanticFingerprint,
+    ) -> CoreResult<bool> {
+        debug!("Updating embeddings of {} (mask={:#06x})", id, mask.as_u16());
+
+        if !mask.iter().all(|e| IN_PLACE_EMBEDDERS.contains(&e)) {
+            let raw = match self.get_fingerprint_raw(id)? {
+                Some(data) => data,
+                None => return Ok(false),
+            };
+            let mut fingerprint = deserialize_teleological_fingerprint(&raw)?;
+            fingerprint.apply_embeddings(mask, &mut embeddings);
+            return self.update_async(fingerprint).await;
+        }
+
+        // Read-modify-write under secondary_index_lock, like store_fingerprint_internal,
+        // so a concurrent full update cannot interleave with the record rewrite.
+        let fingerprint = {
+            let _index_guard = self.secondary_index_lock.lock();
+            let raw = match self.get_fingerprint_raw(id)? {
+                Some(data) => data,
+                None => return Ok(false),
+            };
+            let mut fingerprint = deserialize_teleological_fingerprint(&raw)?;
+            fingerprint.apply_embeddings(mask, &mut embeddings);
+
+            let cf = self.get_cf(CF_FINGERPRINTS)?;
+            let serialized = serialize_teleological_fingerprint(&fingerprint);
+            let mut batch = WriteBatch::default();
+            batch.put_cf(cf, fingerprint_key(&id), &serialized);
+            self.batch_writer
+                .commit(batch, serialized.len(), Some(id))?;
+            fingerprint
+        };
+
+        self.reindex_embedders(&fingerprint, mask)
+            .map_err(|e| CoreError::IndexError(e.to_string()))?;
+        Ok(true)
+    }
+
     /// Delete a fingerprint (internal async wrapper).
     pub(crate) async fn delete_async(&self, id: Uuid, soft: bool) -> CoreResult<bool> {
         debug!("Deleting fingerprint {} (soft={})", id, soft);
@@ -329,6 +539,9 @@ impl RocksDbTeleologicalStore {
 
             // Invalidate count cache (soft delete changes the effective count)
             *self.fingerprint_count.write() = None;
+
+            // Soft-deleted memories drop out of the stored topics
+            self.replace_topic_members(&[id], None)?;
         } else {
             // Hard delete: remove from all column families
             // M1 FIX: Check if this ID was already soft-deleted BEFORE the hard-delete
@@ -379,11 +592,16 @@ impl RocksDbTeleologicalStore {
                 if let Some(e6_sparse) = &fp.e6_sparse {
                     self.remove_from_e6_sparse_inverted_index(&mut batch, &id, e6_sparse)?;
                 }
+
+                // Remove from content_hash secondary index
+                self.remove_from_content_hash_index(&mut batch, &id, &fp.content_hash)?;
             }
 
             // Remove content (TASK-CONTENT-009: cascade content deletion)
+            // The shared blob is only deleted with its last reference.
             let cf_content = self.cf_content();
             batch.delete_cf(cf_content, content_key(&id));
+            self.release_content_blob(&mut batch, &id)?;
 
             // Remove E12 late interaction tokens (TASK-STORAGE-P2-001)
             let cf_e12 = self.get_cf(CF_E12_LATE_INTERACTION)?;
@@ -393,6 +611,10 @@ impl RocksDbTeleologicalStore {
             let cf_sm = self.get_cf(CF_SOURCE_METADATA)?;
             batch.delete_cf(cf_sm, source_metadata_key(&id));
 
+            // Remove access stats (pending counts dropped with the commit)
+            let cf_access = self.get_cf(CF_ACCESS_STATS)?;
+            batch.delete_cf(cf_access, id.as_bytes());
+
             // DAT-7: Remove quantized embedder data from all 13 emb_X CFs
             // Note: emb_X CFs are not populated in production (quantized write path not wired).
             // These deletes are no-ops but kept for forward compatibility when quantized storage is enabled.
@@ -403,6 +625,9 @@ impl RocksDbTeleologicalStore {
                 }
             }
 
+            // Drop the memory from stored topic member lists
+            self.replace_topic_members_in_batch(&mut batch, &HashSet::from([id]), None)?;
+
             // TODO: CF_FILE_INDEX cleanup requires reverse lookup (fingerprint_id -> file_path).
             // Currently orphaned entries are harmless (get_fingerprints_for_file returns stale UUIDs
             // that fail retrieval, effectively filtered). Full cleanup needs source_metadata read.
@@ -416,6 +641,13 @@ impl RocksDbTeleologicalStore {
             let sd_key = soft_delete_key(&id);
             batch.delete_cf(cf_system, sd_key.as_bytes());
 
+            // TTL: Remove expiry marker (in-memory entry dropped after commit)
+            batch.delete_cf(cf_system, expires_at_key(&id).as_bytes());
+            batch.delete_cf(cf_system, namespace_key(&id).as_bytes());
+
+            // Searches already running drop the ID from here on
+            self.commit_log.hide(id);
+
             // STOR-M2 FIX: Commit RocksDB batch BEFORE releasing the inverted-index lock.
             // Previously, drop(_index_guard) happened before db.write(batch), creating a
             // race window where a concurrent store could un-delete from the posting list
@@ -425,17 +657,22 @@ impl RocksDbTeleologicalStore {
             // If RocksDB fails, nothing is lost (HNSW still has the entry = safe).
             // If HNSW fails after RocksDB commit, entry is orphaned in HNSW
             // (harmless — search returns non-existent ID, filtered in post-processing).
-            self.db.write(batch).map_err(|e| {
-                TeleologicalStoreError::rocksdb_op("delete_batch", CF_FINGERPRINTS, Some(id), e)
-            })?;
+            //
+            // The commit runs under the access tracker's flush lock so an
+            // in-flight access flush cannot re-persist the deleted row.
+            self.access_tracker
+                .forget(&id, || self.batch_writer.commit(batch, 0, Some(id)))?;
 
             // Release inverted-index lock AFTER batch commit is durable.
             drop(_index_guard);
+            self.expiring.remove(&id);
+            self.namespaces.remove(&id);
 
             // Best-effort HNSW cleanup — log but don't fail if indexes can't be updated
             if let Err(e) = self.remove_from_indexes(id) {
                 warn!(id = %id, error = %e, "Hard-delete: HNSW index removal failed (orphan will be filtered at search time)");
             }
+            self.commit_log.forget(&id);
 
             // Invalidate count cache
             *self.fingerprint_count.write() = None;
@@ -604,6 +841,53 @@ impl RocksDbTeleologicalStore {
         Ok(deleted)
     }
 
+    // ==================== TTL Expiry Purge ====================
+
+    /// Physically delete every fingerprint whose `expires_at` is at or before `before`.
+    ///
+    /// Expired entries are already hidden from retrieve and search; this reclaims
+    /// their storage by hard-deleting them (all CFs, secondary indexes, HNSW).
+    ///
+    /// Returns the number of entries purged. Individual failures are logged and
+    /// retried on the next call.
+    pub async fn purge_expired(&self, before: DateTime<Utc>) -> CoreResult<usize> {
+        let cutoff = before.timestamp_millis();
+
+        let expired_ids: Vec<Uuid> = self
+            .expiring
+            .iter()
+            .filter(|entry| *entry.value() <= cutoff)
+            .map(|entry| *entry.key())
+            .collect();
+
+        if expired_ids.is_empty() {
+            debug!("TTL purge: no entries expired before {before}");
+            return Ok(0);
+        }
+
+        let mut purged = 0usize;
+        for id in &expired_ids {
+            match self.delete_async(*id, false).await {
+                Ok(true) => purged += 1,
+                Ok(false) => {
+                    // Marker outlived its fingerprint; drop the stale tracking entry
+                    warn!(id = %id, "TTL purge: expired entry not found in RocksDB");
+                    self.expiring.remove(id);
+                }
+                Err(e) => {
+                    warn!(
+                        id = %id,
+                        error = %e,
+                        "TTL purge: failed to hard-delete expired entry, will retry next cycle"
+                    );
+                }
+            }
+        }
+
+        info!("TTL purge: {purged}/{} expired entries hard-deleted", expired_ids.len());
+        Ok(purged)
+    }
+
     // ==================== Processing Cursor Storage ====================
 
     /// Store a processing cursor in CF_SYSTEM under a "cursor::" prefixed key.
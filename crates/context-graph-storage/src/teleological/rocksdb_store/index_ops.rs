@@ -5,6 +5,7 @@
 use tracing::{debug, warn};
 use uuid::Uuid;
 
+use context_graph_core::teleological::{Embedder, EmbedderMask};
 use context_graph_core::types::fingerprint::{SemanticFingerprint, TeleologicalFingerprint};
 use context_graph_core::weights::E11_ENTITY_ENABLED;
 
@@ -38,35 +39,10 @@ impl RocksDbTeleologicalStore {
         // Add to all HNSW-capable dense embedder indexes.
         // E2/E3/E4 temporal indexes are now populated for first-class fusion participation.
         // Weight profiles control whether they contribute to scoring (0.0 = excluded).
-        // Skip E11 when disabled — KEPLER produces near-identical vectors (0.96-0.98 cosine).
-        for embedder in EmbedderIndex::all_hnsw() {
-            if !E11_ENTITY_ENABLED && embedder == EmbedderIndex::E11Entity {
-                continue;
-            }
+        for embedder in Self::indexed_embedders() {
             if let Some(index) = self.index_registry.get(embedder) {
                 let vector = Self::get_embedder_vector(&fp.semantic, embedder);
-                // Skip zero-norm vectors: cosine similarity is undefined for zero-norm,
-                // so HNSW correctly rejects them. For E2/E3/E4 temporal embedders,
-                // this is expected legacy data (stored before temporal embedding fix).
-                // For other embedders, zero-norm indicates possible corruption — warn.
-                if vector.iter().all(|&v| v == 0.0) {
-                    let is_temporal = matches!(
-                        embedder,
-                        EmbedderIndex::E2TemporalRecent
-                            | EmbedderIndex::E3TemporalPeriodic
-                            | EmbedderIndex::E4TemporalPositional
-                    );
-                    if is_temporal {
-                        debug!(
-                            "Skipping zero-norm vector for {:?} on fingerprint {} (legacy data)",
-                            embedder, id
-                        );
-                    } else {
-                        warn!(
-                            "Skipping zero-norm vector for {:?} on fingerprint {} (possible corruption)",
-                            embedder, id
-                        );
-                    }
+                if !Self::is_indexable(embedder, id, vector) {
                     continue;
                 }
                 index.insert(id, vector)?;
@@ -81,6 +57,89 @@ impl RocksDbTeleologicalStore {
         Ok(())
     }
 
+    /// Replace `fp`'s vectors in the HNSW indexes of the embedders in `mask`.
+    ///
+    /// Indexes of unselected embedders keep their entries. Special indexes
+    /// (E1 Matryoshka, E5/E10 asymmetric) have no core embedder and are
+    /// never touched; callers replacing E1, E5 or E10 use a full update.
+    pub(crate) fn reindex_embedders(
+        &self,
+        fp: &TeleologicalFingerprint,
+        mask: EmbedderMask,
+    ) -> Result<(), IndexError> {
+        let _guard = self.compaction_lock.read();
+
+        for embedder in Self::indexed_embedders() {
+            let selected = embedder
+                .to_index()
+                .and_then(Embedder::from_index)
+                .is_some_and(|e| mask.contains(e));
+            if !selected {
+                continue;
+            }
+            if let Some(index) = self.index_registry.get(embedder) {
+                index.remove(fp.id)?;
+                let vector = Self::get_embedder_vector(&fp.semantic, embedder);
+                if Self::is_indexable(embedder, fp.id, vector) {
+                    index.insert(fp.id, vector)?;
+                }
+            }
+        }
+
+        debug!(
+            "Re-indexed fingerprint {} for mask {:#06x}",
+            fp.id,
+            mask.as_u16()
+        );
+        Ok(())
+    }
+
+    /// HNSW spaces fingerprints are indexed into.
+    ///
+    /// Skips E11 when disabled — KEPLER produces near-identical vectors (0.96-0.98 cosine).
+    pub(crate) fn indexed_embedders() -> Vec<EmbedderIndex> {
+        EmbedderIndex::all_hnsw()
+            .into_iter()
+            .filter(|&embedder| E11_ENTITY_ENABLED || embedder != EmbedderIndex::E11Entity)
+            .collect()
+    }
+
+    /// Whether `vector` can go into `embedder`'s HNSW index.
+    ///
+    /// Skip zero-norm vectors: cosine similarity is undefined for zero-norm,
+    /// so HNSW correctly rejects them. For E2/E3/E4 temporal embedders,
+    /// this is expected legacy data (stored before temporal embedding fix).
+    /// For E7 it is expected for prose memories stored without the code
+    /// embedder. For other embedders, zero-norm indicates possible corruption — warn.
+    pub(crate) fn is_indexable(embedder: EmbedderIndex, id: Uuid, vector: &[f32]) -> bool {
+        if !vector.iter().all(|&v| v == 0.0) {
+            return true;
+        }
+        let is_temporal = matches!(
+            embedder,
+            EmbedderIndex::E2TemporalRecent
+                | EmbedderIndex::E3TemporalPeriodic
+                | EmbedderIndex::E4TemporalPositional
+        );
+        if is_temporal {
+            debug!(
+                "Skipping zero-norm vector for {:?} on fingerprint {} (legacy data)",
+                embedder, id
+            );
+        } else if embedder == EmbedderIndex::E7Code {
+            debug!(
+                "Skipping zero-norm vector for {:?} on fingerprint {} (E7 skipped for prose)",
+                embedder, id
+            );
+        } else {
+            warn!(
+                "Skipping zero-norm vector for {:?} on fingerprint {} (possible corruption)",
+                embedder, id
+            );
+        }
+        false
+    }
+
     /// Extract vector for specific embedder from SemanticFingerprint.
     ///
     /// Returns the appropriate vector slice for the given embedder index.
@@ -5,30 +5,44 @@
 //! - E6 Sparse inverted index (exact keywords) - per e6upgrade.md
 //!
 //! Posting lists are stored sorted by UUID for O(log n) binary search.
-//! Legacy unsorted lists are sorted on first access (one-time migration cost).
+//! Legacy unsorted lists are sorted (and deduplicated) on first access
+//! (one-time migration cost).
+//!
+//! `rebuild_splade_index` reconstructs the E13 postings from CF_FINGERPRINTS
+//! for recovery when the index is suspected to have drifted.
+
+use std::collections::{BTreeMap, BTreeSet};
 
 use rocksdb::WriteBatch;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 use context_graph_core::types::fingerprint::SparseVector;
 
-use crate::teleological::column_families::{CF_E13_SPLADE_INVERTED, CF_E6_SPARSE_INVERTED};
-use crate::teleological::schema::{e13_splade_inverted_key, e6_sparse_inverted_key};
-use crate::teleological::serialization::{deserialize_memory_id_list, serialize_memory_id_list};
+use crate::teleological::column_families::{
+    CF_E13_SPLADE_INVERTED, CF_E6_SPARSE_INVERTED, CF_FINGERPRINTS,
+};
+use crate::teleological::schema::{
+    e13_splade_inverted_key, e6_sparse_inverted_key, parse_e13_splade_key, parse_fingerprint_key,
+};
+use crate::teleological::serialization::{
+    deserialize_memory_id_list, deserialize_teleological_fingerprint, serialize_memory_id_list,
+};
 
 use super::store::RocksDbTeleologicalStore;
-use super::types::{TeleologicalStoreError, TeleologicalStoreResult};
+use super::types::{RebuildStats, TeleologicalStoreError, TeleologicalStoreResult};
 
-/// Deserialize a posting list, ensuring it is sorted for binary search.
-/// Handles legacy unsorted lists by sorting them in-place. Returns whether
-/// the list needed sorting (for write-back to fix the stored order).
+/// Deserialize a posting list, ensuring it is sorted and duplicate-free for
+/// binary search. Handles legacy lists by sorting/deduplicating them in-place.
+/// Returns whether the list needed fixing (for write-back of the stored value).
 fn deserialize_sorted_posting_list(data: &[u8]) -> Result<(Vec<Uuid>, bool), context_graph_core::error::CoreError> {
     let mut ids = deserialize_memory_id_list(data)?;
-    let was_unsorted = !ids.windows(2).all(|w| w[0] <= w[1]);
-    if was_unsorted {
+    let is_strictly_sorted = ids.windows(2).all(|w| w[0] < w[1]);
+    if !is_strictly_sorted {
         ids.sort_unstable();
+        ids.dedup();
     }
-    Ok((ids, was_unsorted))
+    Ok((ids, !is_strictly_sorted))
 }
 
 // =============================================================================
@@ -55,13 +69,13 @@ fn update_inverted_index(
         let existing = result.map_err(|e| {
             TeleologicalStoreError::rocksdb_op("multi_get", cf_name, None, e)
         })?;
-        let (mut ids, was_unsorted) = match existing {
+        let (mut ids, needs_rewrite) = match existing {
             Some(data) => deserialize_sorted_posting_list(&data)?,
             None => (Vec::new(), false),
         };
         match ids.binary_search(id) {
             Ok(_) => {
-                if was_unsorted {
+                if needs_rewrite {
                     let serialized = serialize_memory_id_list(&ids);
                     batch.put_cf(cf, term_key.as_slice(), &serialized);
                 }
@@ -93,7 +107,7 @@ fn remove_from_inverted_index(
         if let Some(data) = result.map_err(|e| {
             TeleologicalStoreError::rocksdb_op("multi_get", cf_name, None, e)
         })? {
-            let (mut ids, _was_unsorted) = deserialize_sorted_posting_list(&data)?;
+            let (mut ids, _needs_rewrite) = deserialize_sorted_posting_list(&data)?;
             if let Ok(pos) = ids.binary_search(id) {
                 ids.remove(pos);
             } else {
@@ -139,6 +153,86 @@ impl RocksDbTeleologicalStore {
         remove_from_inverted_index(&self.db, cf, batch, id, &term_keys, CF_E13_SPLADE_INVERTED)
     }
 
+    /// Rebuild the E13 SPLADE inverted index from scratch.
+    ///
+    /// Scans every fingerprint in CF_FINGERPRINTS, reconstructs all posting
+    /// lists (sorted, deduplicated), and replaces the contents of
+    /// CF_E13_SPLADE_INVERTED in a single atomic WriteBatch. Term keys that no
+    /// fingerprint references anymore are deleted.
+    ///
+    /// Holds `secondary_index_lock` for the whole scan so concurrent stores
+    /// cannot interleave postings. This is a heavy O(n) maintenance operation
+    /// intended for recovery; run it from a blocking context.
+    ///
+    /// Corrupted fingerprints are skipped and counted in `corrupted_skipped`.
+    pub fn rebuild_splade_index(&self) -> TeleologicalStoreResult<RebuildStats> {
+        let start = std::time::Instant::now();
+        let _index_guard = self.secondary_index_lock.lock();
+
+        let cf_fp = self.get_cf(CF_FINGERPRINTS)?;
+        let cf_inverted = self.get_cf(CF_E13_SPLADE_INVERTED)?;
+        let mut stats = RebuildStats::default();
+
+        // term_id -> sorted, deduplicated posting list
+        let mut postings: BTreeMap<u16, BTreeSet<Uuid>> = BTreeMap::new();
+        for item in self.db.iterator_cf(cf_fp, rocksdb::IteratorMode::Start) {
+            let (key, value) = item.map_err(|e| {
+                TeleologicalStoreError::rocksdb_op("iterate", CF_FINGERPRINTS, None, e)
+            })?;
+            let id = parse_fingerprint_key(&key);
+            stats.fingerprints_scanned += 1;
+
+            let fp = match deserialize_teleological_fingerprint(&value) {
+                Ok(fp) => fp,
+                Err(e) => {
+                    warn!(
+                        "Skipping corrupted fingerprint {} during SPLADE index rebuild: {}",
+                        id, e
+                    );
+                    stats.corrupted_skipped += 1;
+                    continue;
+                }
+            };
+            for &term_id in &fp.semantic.e13_splade.indices {
+                postings.entry(term_id).or_default().insert(id);
+            }
+        }
+
+        let mut batch = WriteBatch::default();
+        for item in self.db.iterator_cf(cf_inverted, rocksdb::IteratorMode::Start) {
+            let (key, _) = item.map_err(|e| {
+                TeleologicalStoreError::rocksdb_op("iterate", CF_E13_SPLADE_INVERTED, None, e)
+            })?;
+            if !postings.contains_key(&parse_e13_splade_key(&key)) {
+                batch.delete_cf(cf_inverted, &key);
+                stats.stale_terms_removed += 1;
+            }
+        }
+        for (term_id, ids) in &postings {
+            let ids: Vec<Uuid> = ids.iter().copied().collect();
+            batch.put_cf(cf_inverted, e13_splade_inverted_key(*term_id), serialize_memory_id_list(&ids));
+            stats.terms_written += 1;
+            stats.postings_written += ids.len();
+        }
+
+        self.db.write(batch).map_err(|e| {
+            TeleologicalStoreError::rocksdb_op("write_batch", CF_E13_SPLADE_INVERTED, None, e)
+        })?;
+
+        stats.elapsed_ms = start.elapsed().as_millis() as u64;
+        info!(
+            "Rebuilt E13 SPLADE inverted index: {} fingerprints, {} terms, {} postings, \
+             {} stale terms removed, {} corrupted skipped in {}ms",
+            stats.fingerprints_scanned,
+            stats.terms_written,
+            stats.postings_written,
+            stats.stale_terms_removed,
+            stats.corrupted_skipped,
+            stats.elapsed_ms
+        );
+        Ok(stats)
+    }
+
     // =========================================================================
     // E6 SPARSE INVERTED INDEX OPERATIONS (per e6upgrade.md)
     // =========================================================================
@@ -211,7 +305,7 @@ impl RocksDbTeleologicalStore {
                 let ids = deserialize_memory_id_list(&data)?;
                 for id in ids {
                     // STOR-2 FIX: Skip soft-deleted fingerprints (ghost entries)
-                    if self.is_soft_deleted(&id) {
+                    if self.is_soft_deleted(&id) || self.is_expired(&id) {
                         continue;
                     }
                     *candidate_counts.entry(id).or_insert(0) += 1;
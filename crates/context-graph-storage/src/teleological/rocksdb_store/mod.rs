@@ -1,7 +1,7 @@
 //! RocksDB-backed TeleologicalMemoryStore implementation.
 //!
 //! This module provides a persistent storage implementation for TeleologicalFingerprints
-//! using RocksDB with 51 column families (11 base + 20 teleological + 13 quantized + 5 code + 2 causal).
+//! using RocksDB with 57 column families (11 base + 26 teleological + 13 quantized + 5 code + 2 causal).
 //!
 //! # Column Families Used
 //!
@@ -30,6 +30,7 @@
 //! # Module Structure
 //!
 //! - `types`: Error types, configuration, and result aliases
+//! - `access_stats`: Batched per-memory access counts for recency/frequency signals
 //! - `helpers`: Utility functions for similarity computation
 //! - `store`: Core RocksDbTeleologicalStore struct and constructors
 //! - `index_ops`: HNSW index add/remove operations
@@ -38,14 +39,24 @@
 //! - `search`: Search operation implementations
 //! - `persistence`: Batch, statistics, persistence operations
 //! - `content`: Content storage operations
+//! - `content_blobs`: content_hash -> shared, reference-counted content blob
+//! - `content_hash_index`: content_hash -> fingerprint ID secondary index
 //! - `source_metadata`: Source metadata storage operations
+//! - `storage_metrics`: Per-CF sizes, auxiliary files, largest memories
+//! - `tool_audit_log`: Per-tool execution audit log with retention pruning
+//! - `topic_records`: Topic records with IDs stable across detection runs
 //! - `trait_impl`: TeleologicalMemoryStore trait implementation (thin wrapper)
+//! - `visibility`: Commit sequence and per-search snapshot visibility
 //! - `tests`: Comprehensive test suite
 
+mod access_stats;
 mod audit_log;
+mod backup;
 mod causal_hnsw_index;
 mod causal_relationships;
 mod content;
+mod content_blobs;
+mod content_hash_index;
 mod crud;
 mod file_index;
 mod fusion;
@@ -53,22 +64,39 @@ mod helpers;
 mod index_ops;
 mod inverted_index;
 mod persistence;
+mod process_lock;
 mod provenance_storage;
 mod search;
 mod source_metadata;
+mod storage_metrics;
 mod store;
+mod tool_audit_log;
+mod topic_records;
 mod trait_impl;
 mod types;
+mod visibility;
+mod write_policy;
 
 #[cfg(test)]
 mod tests;
 
 // Re-export all public types for backwards compatibility
 // Audit-14 STOR-L1 FIX: weighted_rrf_fusion and compute_consensus are #[cfg(test)] only.
+pub use access_stats::AccessStatsConfig;
+pub use backup::{
+    restore_backup, verify_backup, BackupFile, BackupManifest, BackupOptions, BackupReport,
+    RestoreReport, BACKUP_FORMAT_VERSION, BACKUP_MANIFEST_FILE,
+};
 pub use fusion::{weighted_rrf_fusion_with_scores, RRF_K};
 pub use helpers::{compute_cosine_similarity, hex_encode, hnsw_distance_to_similarity};
+pub use content_blobs::{ContentBlobReader, BLOB_COMPRESSION_THRESHOLD};
 pub use store::RocksDbTeleologicalStore;
-pub use types::{TeleologicalStoreConfig, TeleologicalStoreError, TeleologicalStoreResult};
+pub use types::{
+    FsyncPolicy, RebuildStats, StoreAccessMode, TeleologicalStoreConfig, TeleologicalStoreError,
+    TeleologicalStoreResult, WarmStartStats, WriteMetrics,
+};
+pub use process_lock::{read_lock_owner, LockOwner, LOCK_OWNER_FILE};
+pub use visibility::CommitLog;
 
 // Re-export core file index types for convenience
 pub use context_graph_core::types::file_index::{FileIndexEntry, FileWatcherStats};
@@ -8,6 +8,7 @@
 //! Methods that perform O(n) RocksDB iteration use `spawn_blocking` to move
 //! I/O to Tokio's blocking thread pool, enabling parallel agent access.
 
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -24,10 +25,10 @@ use crate::teleological::column_families::{
     CF_FINGERPRINTS, CF_TOPIC_PORTFOLIO, QUANTIZED_EMBEDDER_CFS, TELEOLOGICAL_CFS,
     CODE_CFS, CAUSAL_CFS,
 };
-use crate::teleological::schema::parse_fingerprint_key;
+use crate::teleological::schema::{fingerprint_key, parse_fingerprint_key};
 use crate::teleological::serialization::deserialize_teleological_fingerprint;
 
-use super::store::RocksDbTeleologicalStore;
+use super::store::{is_expired_in, RocksDbTeleologicalStore};
 use super::types::TeleologicalStoreError;
 
 // ============================================================================
@@ -68,8 +69,12 @@ impl RocksDbTeleologicalStore {
                 continue;
             }
 
+            // Hidden from search until every index holds it
+            self.commit_log.begin(id);
+
             // Store in RocksDB (primary storage) — new insert, count for IDF
             if let Err(e) = self.store_fingerprint_internal(&fp, true) {
+                self.commit_log.forget(&id);
                 error!(
                     id = %id,
                     error = %e,
@@ -114,6 +119,8 @@ impl RocksDbTeleologicalStore {
                 continue;
             }
 
+            // Each fingerprint becomes visible on its own, not at batch end
+            self.commit_log.publish(id);
             succeeded.push(id);
         }
 
@@ -146,17 +153,26 @@ impl RocksDbTeleologicalStore {
 
     /// Retrieve batch of fingerprints (internal async wrapper).
     ///
-    /// Uses `spawn_blocking` to move batch I/O to Tokio's blocking thread pool.
+    /// Uses `spawn_blocking` to move batch I/O to Tokio's blocking thread pool,
+    /// and a single `multi_get_cf` instead of N point gets. Soft-deleted IDs are
+    /// not read at all, and TTL-expired IDs come back as `None`. Output order matches
+    /// `ids`, including duplicates.
     pub(crate) async fn retrieve_batch_async(
         &self,
         ids: &[Uuid],
     ) -> CoreResult<Vec<Option<TeleologicalFingerprint>>> {
         debug!("Retrieving batch of {} fingerprints", ids.len());
 
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
         // Clone Arc-wrapped fields for spawn_blocking closure
         // CRITICAL: Use Arc::clone for soft_deleted instead of cloning the HashMap
         let db = Arc::clone(&self.db);
         let soft_deleted = Arc::clone(&self.soft_deleted);
+        let expiring = Arc::clone(&self.expiring);
+        let now_millis = chrono::Utc::now().timestamp_millis();
         let ids_clone: Vec<Uuid> = ids.to_vec();
 
         let results = tokio::task::spawn_blocking(move || -> CoreResult<Vec<Option<TeleologicalFingerprint>>> {
@@ -168,26 +184,32 @@ impl RocksDbTeleologicalStore {
                 }
             })?;
 
-            let mut results = Vec::with_capacity(ids_clone.len());
-
-            for id in ids_clone {
-                // Skip soft-deleted entries (read lock inside spawn_blocking)
-                // FAIL FAST: Panic if lock is poisoned (thread panic elsewhere)
-                let is_deleted = soft_deleted.contains_key(&id);
-                if is_deleted {
-                    results.push(None);
-                    continue;
-                }
-
-                let key = fingerprint_key(&id);
-                match db.get_cf(cf, key) {
+            // Only read live IDs; soft-deleted and expired positions stay None
+            let live: Vec<(usize, Uuid)> = ids_clone
+                .iter()
+                .enumerate()
+                .filter(|(_, id)| {
+                    !soft_deleted.contains_key(*id)
+                        && !is_expired_in(&expiring, id, now_millis)
+                })
+                .map(|(i, id)| (i, *id))
+                .collect();
+
+            let keys: Vec<_> = live
+                .iter()
+                .map(|(_, id)| (cf, fingerprint_key(id)))
+                .collect();
+            let raw_results = db.multi_get_cf(keys);
+
+            let mut results: Vec<Option<TeleologicalFingerprint>> = vec![None; ids_clone.len()];
+            for ((pos, id), raw) in live.into_iter().zip(raw_results) {
+                match raw {
                     Ok(Some(data)) => {
-                        let fp = deserialize_teleological_fingerprint(&data)?;
-                        results.push(Some(fp));
+                        results[pos] = Some(deserialize_teleological_fingerprint(&data)?);
                     }
-                    Ok(None) => results.push(None),
+                    Ok(None) => {}
                     Err(e) => {
-                        return Err(TeleologicalStoreError::rocksdb_op("get", CF_FINGERPRINTS, Some(id), e).into());
+                        return Err(TeleologicalStoreError::rocksdb_op("multi_get", CF_FINGERPRINTS, Some(id), e).into());
                     }
                 }
             }
@@ -255,11 +277,51 @@ impl RocksDbTeleologicalStore {
         Ok(count)
     }
 
-    /// Get storage size in bytes across ALL 51 column families.
+    /// Count live fingerprints per namespace (internal async wrapper).
+    ///
+    /// Non-default namespaces come from the in-memory marker map; the default
+    /// namespace is the remainder of `count_async`, so legacy fingerprints
+    /// without a marker are counted there.
+    pub(crate) async fn count_by_namespace_async(&self) -> CoreResult<HashMap<String, usize>> {
+        let total = self.count_async().await?;
+
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for entry in self.namespaces.iter() {
+            if !self.soft_deleted.contains_key(entry.key()) {
+                *counts.entry(entry.value().clone()).or_insert(0) += 1;
+            }
+        }
+        let named: usize = counts.values().sum();
+        counts.insert(
+            TeleologicalFingerprint::DEFAULT_NAMESPACE.to_string(),
+            total.saturating_sub(named),
+        );
+        Ok(counts)
+    }
+
+    /// List live fingerprint IDs in a non-default namespace (internal).
+    pub(crate) fn list_namespace_ids_internal(&self, namespace: &str) -> Vec<Uuid> {
+        self.namespaces
+            .iter()
+            .filter(|entry| entry.value() == namespace)
+            .filter(|entry| !self.soft_deleted.contains_key(entry.key()))
+            .map(|entry| *entry.key())
+            .collect()
+    }
+
+    /// Check whether any live fingerprint sits in a session staging namespace.
+    pub(crate) fn has_staged_namespaces(&self) -> bool {
+        self.namespaces
+            .iter()
+            .filter(|entry| !self.soft_deleted.contains_key(entry.key()))
+            .any(|entry| TeleologicalFingerprint::is_staging_namespace(entry.value()))
+    }
+
+    /// Get storage size in bytes across ALL 57 column families.
     pub(crate) fn storage_size_bytes_internal(&self) -> usize {
         let mut total = 0usize;
 
-        // Iterate ALL CF groups: base(11) + teleological(20) + quantized(13) + code(5) + causal(2) = 51
+        // Iterate ALL CF groups: base(11) + teleological(26) + quantized(13) + code(5) + causal(2) = 57
         let all_cf_arrays: &[&[&str]] = &[
             cf_names::ALL,
             TELEOLOGICAL_CFS,
@@ -295,12 +357,15 @@ impl RocksDbTeleologicalStore {
 // ============================================================================
 
 impl RocksDbTeleologicalStore {
-    /// Flush ALL 51 column families (internal async wrapper).
+    /// Flush ALL 57 column families (internal async wrapper).
     ///
     /// Uses `spawn_blocking` to move flush I/O to Tokio's blocking thread pool.
-    /// Covers base(11) + teleological(20) + quantized(13) + code(5) + causal(2) = 51 CFs.
+    /// Covers base(11) + teleological(26) + quantized(13) + code(5) + causal(2) = 57 CFs.
     pub(crate) async fn flush_async(&self) -> CoreResult<()> {
-        debug!("Flushing all 51 column families");
+        debug!("Flushing all 57 column families");
+
+        // Pending access counts first, so the CF flush below includes them
+        self.access_tracker.flush()?;
 
         let db = Arc::clone(&self.db);
 
@@ -335,7 +400,7 @@ impl RocksDbTeleologicalStore {
         .await
         .map_err(|e| CoreError::Internal(format!("spawn_blocking failed: {}", e)))??;
 
-        info!("Flushed all 51 column families");
+        info!("Flushed all 57 column families");
         Ok(())
     }
 
@@ -510,7 +575,7 @@ impl RocksDbTeleologicalStore {
             }
         }
 
-        // Now compact ALL 51 RocksDB column families
+        // Now compact ALL 57 RocksDB column families
         let all_cf_arrays: &[&[&str]] = &[
             cf_names::ALL,
             TELEOLOGICAL_CFS,
@@ -833,4 +898,59 @@ impl RocksDbTeleologicalStore {
         info!(count = results.len(), "Unbiased fingerprint scan complete");
         Ok(results)
     }
+
+    /// List one page of fingerprints with IDs strictly after `after`.
+    ///
+    /// CF_FINGERPRINTS keys are the 16 UUID bytes, so iteration order is ID
+    /// order and a page starts with a seek instead of a full scan. Skips
+    /// soft-deleted and corrupted entries.
+    pub(crate) async fn list_fingerprints_after_async(
+        &self,
+        after: Option<Uuid>,
+        limit: usize,
+    ) -> CoreResult<Vec<TeleologicalFingerprint>> {
+        debug!(after = ?after, limit = limit, "Listing fingerprint page");
+
+        let db = Arc::clone(&self.db);
+        let soft_deleted = Arc::clone(&self.soft_deleted);
+
+        tokio::task::spawn_blocking(move || -> CoreResult<Vec<TeleologicalFingerprint>> {
+            let cf = db.cf_handle(CF_FINGERPRINTS).ok_or_else(|| {
+                TeleologicalStoreError::ColumnFamilyNotFound {
+                    name: CF_FINGERPRINTS.to_string(),
+                }
+            })?;
+            let start_key = after.map(|id| fingerprint_key(&id));
+            let mode = match start_key.as_ref() {
+                Some(key) => rocksdb::IteratorMode::From(key, rocksdb::Direction::Forward),
+                None => rocksdb::IteratorMode::Start,
+            };
+
+            let mut results = Vec::with_capacity(limit.min(1024));
+            for item in db.iterator_cf(cf, mode) {
+                if results.len() >= limit {
+                    break;
+                }
+                let (key, value) = item.map_err(|e| {
+                    TeleologicalStoreError::rocksdb_op("iterate", CF_FINGERPRINTS, None, e)
+                })?;
+
+                let id = parse_fingerprint_key(&key);
+                if Some(id) == after || soft_deleted.contains_key(&id) {
+                    continue;
+                }
+
+                match deserialize_teleological_fingerprint(&value) {
+                    Ok(fp) => results.push(fp),
+                    Err(e) => {
+                        warn!("Skipping corrupted fingerprint {} during paged scan: {}", id, e);
+                    }
+                }
+            }
+
+            Ok(results)
+        })
+        .await
+        .map_err(|e| CoreError::Internal(format!("spawn_blocking failed: {}", e)))?
+    }
 }
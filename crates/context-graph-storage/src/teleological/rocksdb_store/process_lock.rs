@@ -0,0 +1,145 @@
+//! Cross-process ownership of a store directory.
+//!
+//! RocksDB guards a database with a POSIX record lock on its `LOCK` file.
+//! That lock says *whether* the directory is held but nothing a user can
+//! act on, so an exclusive open also writes [`LOCK_OWNER_FILE`] next to it
+//! with the holder's pid and open time. A second writer reads it to build
+//! `TeleologicalStoreError::LockedByOtherProcess`.
+//!
+//! The owner file is advisory: the kernel lock decides, and an owner file
+//! whose pid is no longer alive is ignored.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+/// Name of the owner file inside the database directory.
+pub const LOCK_OWNER_FILE: &str = "LOCK.owner";
+
+/// Process that holds a store open for writing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockOwner {
+    /// Process id.
+    pub pid: u32,
+    /// When the process opened the store.
+    pub since: DateTime<Utc>,
+}
+
+/// Read the owner file of the database at `db_path`, if present and valid.
+pub fn read_lock_owner(db_path: &Path) -> Option<LockOwner> {
+    let owner_path = db_path.join(LOCK_OWNER_FILE);
+    let bytes = fs::read(&owner_path).ok()?;
+    match serde_json::from_slice(&bytes) {
+        Ok(owner) => Some(owner),
+        Err(e) => {
+            warn!(
+                "Ignoring malformed owner file '{}': {}",
+                owner_path.display(),
+                e
+            );
+            None
+        }
+    }
+}
+
+/// Owner file written by an exclusive open; removed again on drop.
+#[derive(Debug)]
+pub(crate) struct OwnerFileGuard {
+    path: PathBuf,
+    pid: u32,
+}
+
+impl OwnerFileGuard {
+    /// Record the current process as owner of the database at `db_path`.
+    pub(crate) fn create(db_path: &Path) -> io::Result<Self> {
+        let owner = LockOwner {
+            pid: std::process::id(),
+            since: Utc::now(),
+        };
+        let path = db_path.join(LOCK_OWNER_FILE);
+        let json = serde_json::to_vec(&owner).map_err(io::Error::other)?;
+        fs::write(&path, json)?;
+        debug!("Recorded pid {} in '{}'", owner.pid, path.display());
+        Ok(Self {
+            path,
+            pid: owner.pid,
+        })
+    }
+}
+
+impl Drop for OwnerFileGuard {
+    fn drop(&mut self) {
+        // Leave the file alone if another process has taken over since.
+        let ours = read_lock_owner(self.path.parent().unwrap_or(Path::new(".")))
+            .is_some_and(|owner| owner.pid == self.pid);
+        if ours {
+            if let Err(e) = fs::remove_file(&self.path) {
+                warn!(
+                    "Failed to remove owner file '{}': {}",
+                    self.path.display(),
+                    e
+                );
+            }
+        }
+    }
+}
+
+/// Pid of the process holding a write lock on `lock_path`, if any.
+///
+/// Uses `F_GETLK`, which sees the `fcntl` lock RocksDB takes (unlike
+/// `flock`). Locks held by the calling process are not reported; the kernel
+/// treats them as compatible with our own.
+#[cfg(unix)]
+pub(crate) fn lock_holder_pid(lock_path: &Path) -> io::Result<Option<u32>> {
+    use std::os::unix::io::AsRawFd;
+
+    let file = fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(lock_path)?;
+
+    // SAFETY: flock is a plain C struct; all-zero is a valid value.
+    let mut probe: libc::flock = unsafe { std::mem::zeroed() };
+    probe.l_type = libc::F_WRLCK as libc::c_short;
+    probe.l_whence = libc::SEEK_SET as libc::c_short;
+    probe.l_start = 0;
+    probe.l_len = 0;
+
+    // SAFETY: fd is open for the lifetime of `file`; `probe` is a valid flock.
+    let rc = unsafe { libc::fcntl(file.as_raw_fd(), libc::F_GETLK, &mut probe) };
+    if rc != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    if probe.l_type == libc::F_UNLCK as libc::c_short {
+        Ok(None)
+    } else {
+        Ok(Some(probe.l_pid as u32))
+    }
+}
+
+/// Whether a process with `pid` is alive.
+#[cfg(unix)]
+pub(crate) fn pid_is_alive(pid: u32) -> bool {
+    // SAFETY: signal 0 performs only the existence/permission check.
+    let rc = unsafe { libc::kill(pid as libc::pid_t, 0) };
+    rc == 0 || io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+/// Whether a process with `pid` is alive. Unknown off Unix; assume yes.
+#[cfg(not(unix))]
+pub(crate) fn pid_is_alive(_pid: u32) -> bool {
+    true
+}
+
+/// Whether `error` is RocksDB failing to take the `LOCK` file.
+pub(crate) fn is_lock_error(error: &rocksdb::Error) -> bool {
+    let msg = error.to_string();
+    msg.contains("LOCK")
+        && (msg.contains("lock hold by current process")
+            || msg.contains("Resource temporarily unavailable")
+            || msg.contains("No locks available"))
+}
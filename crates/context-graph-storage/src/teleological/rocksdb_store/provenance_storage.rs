@@ -2,6 +2,7 @@
 //!
 //! Provides storage methods for Phase 4-6 provenance column families:
 //! - CF_MERGE_HISTORY: Permanent merge lineage tracking
+//! - CF_MEMORY_LINEAGE: Parent links written by merges and consolidations
 //! - CF_IMPORTANCE_HISTORY: Permanent importance change audit trail
 //! - CF_EMBEDDING_REGISTRY: Embedding model version tracking per fingerprint
 //!
@@ -20,11 +21,12 @@ use tracing::{debug, error};
 use uuid::Uuid;
 
 use context_graph_core::types::audit::{
-    EmbeddingVersionRecord, ImportanceChangeRecord, MergeRecord,
+    EmbeddingVersionRecord, ImportanceChangeRecord, LineageRecord, MergeRecord,
 };
 
 use crate::teleological::column_families::{
-    CF_CUSTOM_WEIGHT_PROFILES, CF_EMBEDDING_REGISTRY, CF_IMPORTANCE_HISTORY, CF_MERGE_HISTORY,
+    CF_CUSTOM_WEIGHT_PROFILES, CF_EMBEDDING_REGISTRY, CF_IMPORTANCE_HISTORY, CF_MEMORY_LINEAGE,
+    CF_MERGE_HISTORY,
 };
 
 use super::store::RocksDbTeleologicalStore;
@@ -144,6 +146,99 @@ impl RocksDbTeleologicalStore {
     }
 }
 
+// ============================================================================
+// CF_MEMORY_LINEAGE Operations
+// ============================================================================
+
+impl RocksDbTeleologicalStore {
+    /// Append a lineage record for `record.child_id`.
+    ///
+    /// Writes to CF_MEMORY_LINEAGE. PERMANENT -- never expires, never deleted.
+    ///
+    /// # Key Format
+    /// `{child_uuid_bytes}_{timestamp_nanos_be}` (24 bytes)
+    pub fn append_lineage_record(&self, record: &LineageRecord) -> TeleologicalStoreResult<()> {
+        let key = record.storage_key();
+
+        let bytes = serde_json::to_vec(record).map_err(|e| {
+            error!(
+                "FAIL FAST: Failed to serialize LineageRecord for {}: {}",
+                record.child_id, e
+            );
+            TeleologicalStoreError::Serialization {
+                id: Some(record.child_id),
+                message: format!("LineageRecord serialization failed: {}", e),
+            }
+        })?;
+
+        let cf = self.get_cf(CF_MEMORY_LINEAGE)?;
+        self.db.put_cf(cf, key, &bytes).map_err(|e| {
+            error!(
+                "FAIL FAST: Failed to write LineageRecord for {} to CF '{}': {}",
+                record.child_id, CF_MEMORY_LINEAGE, e
+            );
+            TeleologicalStoreError::rocksdb_op("put", CF_MEMORY_LINEAGE, Some(record.child_id), e)
+        })?;
+
+        debug!(
+            "Appended lineage record: child_id={}, parents={}, operation={}",
+            record.child_id,
+            record.parent_ids.len(),
+            record.operation,
+        );
+
+        Ok(())
+    }
+
+    /// All lineage records whose child is `child_id`, oldest first.
+    ///
+    /// Uses prefix scan on CF_MEMORY_LINEAGE with the child_id UUID prefix.
+    pub fn get_lineage_records(
+        &self,
+        child_id: Uuid,
+    ) -> TeleologicalStoreResult<Vec<LineageRecord>> {
+        let cf = self.get_cf(CF_MEMORY_LINEAGE)?;
+        let prefix = LineageRecord::prefix_key(&child_id);
+        let iter = self.db.prefix_iterator_cf(cf, prefix);
+
+        let mut records = Vec::new();
+        for item in iter {
+            let (key, value) = item.map_err(|e| {
+                error!(
+                    "FAIL FAST: RocksDB iteration failed on CF '{}' for child_id {}: {}",
+                    CF_MEMORY_LINEAGE, child_id, e
+                );
+                TeleologicalStoreError::rocksdb_op("prefix_iterate", CF_MEMORY_LINEAGE, None, e)
+            })?;
+
+            // Verify key prefix matches (prefix_iterator may overshoot)
+            if key.len() < 16 || &key[..16] != child_id.as_bytes() {
+                break;
+            }
+
+            let record: LineageRecord = serde_json::from_slice(&value).map_err(|e| {
+                error!(
+                    "FAIL FAST: Failed to deserialize LineageRecord from CF '{}': {}",
+                    CF_MEMORY_LINEAGE, e
+                );
+                TeleologicalStoreError::Deserialization {
+                    key: format!("memory_lineage:{}", hex_encode(&key)),
+                    message: format!("LineageRecord deserialization failed: {}", e),
+                }
+            })?;
+            records.push(record);
+        }
+
+        debug!(
+            "Retrieved {} lineage records for child_id {}",
+            records.len(),
+            child_id
+        );
+
+        Ok(records)
+    }
+}
+
 // ============================================================================
 // CF_IMPORTANCE_HISTORY Operations
 // ============================================================================
@@ -43,6 +43,18 @@
 //!
 //! When `query_text` is provided in search options, the query type is auto-detected.
 //!
+//! # Namespaces
+//!
+//! Keys are not namespace-prefixed: every column family stays keyed by the
+//! bare UUID, and one set of HNSW and sparse indexes serves all namespaces.
+//! Prefixed keys would need an index set per namespace and a rewrite of every
+//! existing key. Instead each non-default namespace is recorded once as a
+//! `namespace::{uuid}` marker in CF_SYSTEM, loaded into `namespaces` on open,
+//! and a namespaced search scopes its [`SearchSnapshot`] to it. Candidate
+//! retrieval then uses the filtered k-NN path, so other namespaces never take
+//! top-k slots, however many memories they hold. Data written before
+//! namespaces existed has no markers and reads as "default" unchanged.
+//!
 //! References:
 //! - [Cascading Retrieval](https://www.pinecone.io/blog/cascading-retrieval/)
 //! - [Fusion Analysis](https://dl.acm.org/doi/10.1145/3596512)
@@ -51,19 +63,19 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
-// P5: DashMap for lock-free concurrent soft-delete checks
-use dashmap::DashMap;
-
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 use context_graph_core::error::{CoreError, CoreResult};
 use context_graph_core::fusion::{EmbedderRanking, FusionStrategy, fuse_rankings};
+use context_graph_core::retrieval::{PipelineStage, PipelineTimings, StageSpan};
 use context_graph_core::causal::asymmetric::CausalDirection;
-use context_graph_core::traits::{SearchStrategy, TeleologicalSearchOptions, TeleologicalSearchResult};
+use context_graph_core::traits::{
+    MetadataFilter, SearchStrategy, TeleologicalSearchOptions, TeleologicalSearchResult,
+};
 use context_graph_core::types::fingerprint::{SemanticFingerprint, SparseVector};
 
-use crate::teleological::search::temporal_boost;
+use crate::teleological::search::{temporal_boost, SingleEmbedderSearch};
 
 use crate::teleological::column_families::CF_E13_SPLADE_INVERTED;
 use crate::teleological::indexes::{EmbedderIndex, EmbedderIndexOps};
@@ -72,8 +84,9 @@ use crate::teleological::serialization::{
     deserialize_memory_id_list, deserialize_teleological_fingerprint,
 };
 
-use super::store::RocksDbTeleologicalStore;
+use super::store::{is_expired_in, RocksDbTeleologicalStore};
 use super::types::TeleologicalStoreError;
+use super::visibility::SearchSnapshot;
 
 use context_graph_core::code::CodeQueryType;
 use context_graph_core::types::fingerprint::TeleologicalFingerprint;
@@ -87,8 +100,8 @@ use context_graph_core::weights::{
 // =============================================================================
 
 use rocksdb::DB;
-use crate::teleological::column_families::CF_FINGERPRINTS;
-use crate::teleological::schema::fingerprint_key;
+use crate::teleological::column_families::{CF_FINGERPRINTS, CF_SOURCE_METADATA};
+use crate::teleological::schema::{fingerprint_key, source_metadata_key};
 use crate::teleological::indexes::EmbedderIndexRegistry;
 use super::helpers::{compute_cosine_similarity, hnsw_distance_to_similarity};
 
@@ -96,6 +109,11 @@ use super::helpers::{compute_cosine_similarity, hnsw_distance_to_similarity};
 /// E1, E2, E3, E4, E5, E7, E8, E9, E10, E11 = 10 embedders.
 const MULTI_SPACE_MAX_EMBEDDERS: usize = 10;
 
+/// Over-fetch factor for time-range searches. Rankings other than the
+/// filtered candidate search still carry out-of-range IDs, so results are
+/// retrieved at `top_k * 4` and filtered down.
+const TIME_RANGE_OVERFETCH: usize = 4;
+
 // =============================================================================
 // SPAWN_BLOCKING SYNC FUNCTIONS
 // These functions run in Tokio's blocking thread pool for parallel agent access
@@ -113,12 +131,113 @@ fn get_fingerprint_raw_sync(db: &DB, id: Uuid) -> Result<Option<Vec<u8>>, Teleol
         .map_err(|e| TeleologicalStoreError::rocksdb_op("get", CF_FINGERPRINTS, Some(id), e))
 }
 
-/// Check if an ID is soft-deleted (sync version for spawn_blocking).
+/// Check a candidate against metadata predicates (sync version for spawn_blocking).
 ///
-/// P5: Uses DashMap for lock-free concurrent reads. No global RwLock contention
-/// under concurrent search load.
-fn is_soft_deleted_sync(soft_deleted: &Arc<DashMap<Uuid, i64>>, id: &Uuid) -> bool {
-    soft_deleted.contains_key(id)
+/// Also rejects soft-deleted, expired and out-of-namespace fingerprints so that
+/// filtered k-NN counts only candidates that will actually be returned.
+fn passes_metadata_filter_sync(
+    db: &DB,
+    snapshot: &SearchSnapshot,
+    options: &TeleologicalSearchOptions,
+    filter: &MetadataFilter,
+    id: Uuid,
+) -> CoreResult<bool> {
+    if snapshot.excludes(&id, options.include_deleted) {
+        return Ok(false);
+    }
+
+    let Some(data) = get_fingerprint_raw_sync(db, id)? else {
+        return Ok(false);
+    };
+    let fp = deserialize_teleological_fingerprint(&data)?;
+    if fp.is_expired_at(chrono::Utc::now()) || !options.admits_namespace(&fp.namespace) {
+        return Ok(false);
+    }
+    if !options.time_range_admits(fp.created_at) {
+        options.time_filter_exclusions.record(id);
+        return Ok(false);
+    }
+    if !filter.matches_created_at(fp.created_at.timestamp_millis()) {
+        return Ok(false);
+    }
+
+    if filter.source_types.is_empty() {
+        return Ok(true);
+    }
+    let cf = db.cf_handle(CF_SOURCE_METADATA).ok_or_else(|| {
+        TeleologicalStoreError::ColumnFamilyNotFound {
+            name: CF_SOURCE_METADATA.to_string(),
+        }
+    })?;
+    let source_type = match db
+        .get_cf(cf, source_metadata_key(&id))
+        .map_err(|e| TeleologicalStoreError::rocksdb_op("get", CF_SOURCE_METADATA, Some(id), e))?
+    {
+        Some(bytes) => {
+            Some(RocksDbTeleologicalStore::deserialize_source_metadata(&bytes, id)?.source_type)
+        }
+        None => None,
+    };
+    Ok(filter.matches_source_type(source_type.as_ref()))
+}
+
+/// Retrieve HNSW candidates, honoring `options.metadata_filter`,
+/// `options.time_range` and the snapshot's namespace scope (sync version).
+///
+/// Without an active filter or scope this is a plain `index.search(k)`. With
+/// one, the search over-fetches progressively via
+/// `SingleEmbedderSearch::search_filtered` so that selective filters and small
+/// namespaces still yield up to `k` candidates.
+#[allow(clippy::too_many_arguments)]
+fn search_candidates_sync(
+    db: &Arc<DB>,
+    index_registry: &Arc<EmbedderIndexRegistry>,
+    snapshot: &SearchSnapshot,
+    options: &TeleologicalSearchOptions,
+    embedder: EmbedderIndex,
+    query_vec: &[f32],
+    k: usize,
+) -> CoreResult<Vec<(Uuid, f32)>> {
+    let filter = options.effective_metadata_filter().filter(|f| f.is_active());
+    let Some(filter) = filter.or_else(|| snapshot.is_scoped().then(MetadataFilter::default)) else {
+        let index = index_registry.get(embedder).ok_or_else(|| {
+            CoreError::IndexError(format!("HNSW index {:?} not found in registry", embedder))
+        })?;
+        return index.search(query_vec, k, None).map_err(|e| {
+            error!("{:?} search failed: {}", embedder, e);
+            CoreError::IndexError(e.to_string())
+        });
+    };
+
+    // The predicate cannot return errors, so keep the first one and fail after
+    let first_error = std::cell::RefCell::new(None);
+    let predicate = |id: Uuid| {
+        match passes_metadata_filter_sync(db, snapshot, options, &filter, id) {
+            Ok(passes) => passes,
+            Err(e) => {
+                first_error.borrow_mut().get_or_insert(e);
+                false
+            }
+        }
+    };
+
+    let results = SingleEmbedderSearch::new(Arc::clone(index_registry))
+        .search_filtered(embedder, query_vec, k, &predicate)
+        .map_err(|e| {
+            error!("Filtered {:?} search failed: {}", embedder, e);
+            CoreError::IndexError(e.to_string())
+        })?;
+    if let Some(e) = first_error.into_inner() {
+        return Err(e);
+    }
+
+    debug!(
+        "Filtered {:?} search: {} hits from {} candidates scanned",
+        embedder,
+        results.len(),
+        results.candidates_scanned
+    );
+    Ok(results.hits.into_iter().map(|h| (h.id, h.distance)).collect())
 }
 
 /// Get the query vector for a given embedder index (0-12).
@@ -153,7 +272,7 @@ fn get_query_vector_for_embedder(query: &SemanticFingerprint, embedder_idx: usiz
 fn search_single_embedder_sync(
     db: &Arc<DB>,
     index_registry: &Arc<EmbedderIndexRegistry>,
-    soft_deleted: &Arc<DashMap<Uuid, i64>>,
+    snapshot: &SearchSnapshot,
     query: &SemanticFingerprint,
     options: &TeleologicalSearchOptions,
     embedder_idx: usize,
@@ -177,17 +296,10 @@ fn search_single_embedder_sync(
         }
     })?;
 
-    let entry_index = index_registry.get(embedder).ok_or_else(|| {
-        CoreError::IndexError(format!("HNSW index {:?} not found in registry", embedder))
-    })?;
-
     let k = (options.top_k * 2).max(20);
-    let candidates = entry_index
-        .search(query_vec, k, None)
-        .map_err(|e| {
-            error!("Single-embedder search for {:?} failed: {}", embedder, e);
-            CoreError::IndexError(e.to_string())
-        })?;
+    let candidates = search_candidates_sync(
+        db, index_registry, snapshot, options, embedder, query_vec, k,
+    )?;
 
     debug!(
         "Single-embedder search: {:?} returned {} raw candidates",
@@ -200,7 +312,7 @@ fn search_single_embedder_sync(
     for (id, distance) in candidates {
         let similarity = hnsw_distance_to_similarity(distance);
 
-        if !options.include_deleted && is_soft_deleted_sync(soft_deleted, &id) {
+        if snapshot.excludes(&id, options.include_deleted) {
             continue;
         }
 
@@ -240,7 +352,7 @@ fn search_single_embedder_sync(
 fn search_filtered_multi_space_sync(
     db: &Arc<DB>,
     index_registry: &Arc<EmbedderIndexRegistry>,
-    soft_deleted: &Arc<DashMap<Uuid, i64>>,
+    snapshot: &SearchSnapshot,
     query: &SemanticFingerprint,
     options: &TeleologicalSearchOptions,
     embedder_indices: &[usize],
@@ -300,7 +412,7 @@ fn search_filtered_multi_space_sync(
                 Ok(candidates) => {
                     let ranked: Vec<(Uuid, f32)> = candidates
                         .into_iter()
-                        .filter(|(id, _)| options.include_deleted || !is_soft_deleted_sync(soft_deleted, id))
+                        .filter(|(id, _)| !snapshot.excludes(id, options.include_deleted))
                         .map(|(id, dist)| (id, hnsw_distance_to_similarity(dist)))
                         .collect();
 
@@ -442,33 +554,26 @@ fn compute_embedder_scores_sync(
 }
 
 /// E1-only search (sync version for spawn_blocking).
-/// P5: Uses Arc<DashMap> for lock-free concurrent soft-delete checks.
+/// Candidates outside `snapshot` (uncommitted or soft-deleted) are dropped.
 fn search_e1_only_sync(
     db: &Arc<DB>,
     index_registry: &Arc<EmbedderIndexRegistry>,
-    soft_deleted: &Arc<DashMap<Uuid, i64>>,
+    snapshot: &SearchSnapshot,
     query: &SemanticFingerprint,
     options: &TeleologicalSearchOptions,
 ) -> CoreResult<Vec<TeleologicalSearchResult>> {
-    let entry_embedder = EmbedderIndex::E1Semantic;
-    let entry_index = index_registry.get(entry_embedder).ok_or_else(|| {
-        CoreError::IndexError(format!("Index {:?} not found", entry_embedder))
-    })?;
-
     let k = (options.top_k * 2).max(20);
-    let candidates = entry_index
-        .search(&query.e1_semantic, k, None)
-        .map_err(|e| {
-            error!("E1 search failed: {}", e);
-            CoreError::IndexError(e.to_string())
-        })?;
+    let candidates = search_candidates_sync(
+        db, index_registry, snapshot, options, EmbedderIndex::E1Semantic,
+        &query.e1_semantic, k,
+    )?;
 
     let mut results = Vec::with_capacity(candidates.len());
 
     for (id, distance) in candidates {
         let similarity = hnsw_distance_to_similarity(distance);
 
-        if !options.include_deleted && is_soft_deleted_sync(soft_deleted, &id) {
+        if snapshot.excludes(&id, options.include_deleted) {
             continue;
         }
 
@@ -497,11 +602,11 @@ fn search_e1_only_sync(
 }
 
 /// Multi-space search (sync version for spawn_blocking).
-/// P5: Uses Arc<DashMap> for lock-free concurrent soft-delete checks.
+/// Every per-embedder ranking is filtered through `snapshot` before fusion.
 fn search_multi_space_sync(
     db: &Arc<DB>,
     index_registry: &Arc<EmbedderIndexRegistry>,
-    soft_deleted: &Arc<DashMap<Uuid, i64>>,
+    snapshot: &SearchSnapshot,
     query: &SemanticFingerprint,
     options: &TeleologicalSearchOptions,
 ) -> CoreResult<Vec<TeleologicalSearchResult>> {
@@ -513,22 +618,21 @@ fn search_multi_space_sync(
     // SEARCH-4: Track embedders that failed HNSW search for operational visibility
     let mut degraded_embedders: Vec<&str> = Vec::with_capacity(MULTI_SPACE_MAX_EMBEDDERS);
 
-    // E1 Semantic
-    let entry_embedder = EmbedderIndex::E1Semantic;
-    let entry_index = index_registry.get(entry_embedder).ok_or_else(|| {
-        CoreError::IndexError(format!("Index {:?} not found", entry_embedder))
-    })?;
-
-    let e1_candidates = entry_index
-        .search(&query.e1_semantic, k, None)
-        .map_err(|e| {
-            error!("E1 search failed: {}", e);
-            CoreError::IndexError(e.to_string())
-        })?;
+    // E1 Semantic, filtered during retrieval so the fusion always has up to
+    // k E1 candidates that pass metadata_filter and time_range
+    let e1_candidates = search_candidates_sync(
+        db,
+        index_registry,
+        snapshot,
+        options,
+        EmbedderIndex::E1Semantic,
+        &query.e1_semantic,
+        k,
+    )?;
 
     let e1_ranked: Vec<(Uuid, f32)> = e1_candidates
         .into_iter()
-        .filter(|(id, _)| options.include_deleted || !is_soft_deleted_sync(soft_deleted, id))
+        .filter(|(id, _)| !snapshot.excludes(id, options.include_deleted))
         .map(|(id, dist)| (id, hnsw_distance_to_similarity(dist)))
         .collect();
 
@@ -543,7 +647,7 @@ fn search_multi_space_sync(
                 Ok(e2_candidates) => {
                     let e2_ranked: Vec<(Uuid, f32)> = e2_candidates
                         .into_iter()
-                        .filter(|(id, _)| options.include_deleted || !is_soft_deleted_sync(soft_deleted, id))
+                        .filter(|(id, _)| !snapshot.excludes(id, options.include_deleted))
                         .map(|(id, dist)| (id, hnsw_distance_to_similarity(dist)))
                         .collect();
                     if !e2_ranked.is_empty() {
@@ -566,7 +670,7 @@ fn search_multi_space_sync(
                 Ok(e3_candidates) => {
                     let e3_ranked: Vec<(Uuid, f32)> = e3_candidates
                         .into_iter()
-                        .filter(|(id, _)| options.include_deleted || !is_soft_deleted_sync(soft_deleted, id))
+                        .filter(|(id, _)| !snapshot.excludes(id, options.include_deleted))
                         .map(|(id, dist)| (id, hnsw_distance_to_similarity(dist)))
                         .collect();
                     if !e3_ranked.is_empty() {
@@ -589,7 +693,7 @@ fn search_multi_space_sync(
                 Ok(e4_candidates) => {
                     let e4_ranked: Vec<(Uuid, f32)> = e4_candidates
                         .into_iter()
-                        .filter(|(id, _)| options.include_deleted || !is_soft_deleted_sync(soft_deleted, id))
+                        .filter(|(id, _)| !snapshot.excludes(id, options.include_deleted))
                         .map(|(id, dist)| (id, hnsw_distance_to_similarity(dist)))
                         .collect();
                     if !e4_ranked.is_empty() {
@@ -620,7 +724,7 @@ fn search_multi_space_sync(
             Ok(e5_candidates) => {
                 let e5_ranked: Vec<(Uuid, f32)> = e5_candidates
                     .into_iter()
-                    .filter(|(id, _)| options.include_deleted || !is_soft_deleted_sync(soft_deleted, id))
+                    .filter(|(id, _)| !snapshot.excludes(id, options.include_deleted))
                     .map(|(id, dist)| (id, hnsw_distance_to_similarity(dist)))
                     .collect();
 
@@ -651,7 +755,7 @@ fn search_multi_space_sync(
             Ok(e7_candidates) => {
                 let e7_ranked: Vec<(Uuid, f32)> = e7_candidates
                     .into_iter()
-                    .filter(|(id, _)| options.include_deleted || !is_soft_deleted_sync(soft_deleted, id))
+                    .filter(|(id, _)| !snapshot.excludes(id, options.include_deleted))
                     .map(|(id, dist)| (id, hnsw_distance_to_similarity(dist)))
                     .collect();
 
@@ -684,7 +788,7 @@ fn search_multi_space_sync(
             Ok(e10_candidates) => {
                 let e10_ranked: Vec<(Uuid, f32)> = e10_candidates
                     .into_iter()
-                    .filter(|(id, _)| options.include_deleted || !is_soft_deleted_sync(soft_deleted, id))
+                    .filter(|(id, _)| !snapshot.excludes(id, options.include_deleted))
                     .map(|(id, dist)| (id, hnsw_distance_to_similarity(dist)))
                     .collect();
 
@@ -718,7 +822,7 @@ fn search_multi_space_sync(
             Ok(e8_candidates) => {
                 let e8_ranked: Vec<(Uuid, f32)> = e8_candidates
                     .into_iter()
-                    .filter(|(id, _)| options.include_deleted || !is_soft_deleted_sync(soft_deleted, id))
+                    .filter(|(id, _)| !snapshot.excludes(id, options.include_deleted))
                     .map(|(id, dist)| (id, hnsw_distance_to_similarity(dist)))
                     .collect();
 
@@ -744,7 +848,7 @@ fn search_multi_space_sync(
                 Ok(e11_candidates) => {
                     let e11_ranked: Vec<(Uuid, f32)> = e11_candidates
                         .into_iter()
-                        .filter(|(id, _)| options.include_deleted || !is_soft_deleted_sync(soft_deleted, id))
+                        .filter(|(id, _)| !snapshot.excludes(id, options.include_deleted))
                         .map(|(id, dist)| (id, hnsw_distance_to_similarity(dist)))
                         .collect();
 
@@ -771,7 +875,7 @@ fn search_multi_space_sync(
             Ok(e9_candidates) => {
                 let e9_ranked: Vec<(Uuid, f32)> = e9_candidates
                     .into_iter()
-                    .filter(|(id, _)| options.include_deleted || !is_soft_deleted_sync(soft_deleted, id))
+                    .filter(|(id, _)| !snapshot.excludes(id, options.include_deleted))
                     .map(|(id, dist)| (id, hnsw_distance_to_similarity(dist)))
                     .collect();
 
@@ -860,14 +964,20 @@ fn search_multi_space_sync(
 /// Note: E12 MaxSim reranking (Stage 3) is NOT implemented. See AP-74.
 ///
 /// P1: Takes total_doc_count for O(1) IDF in sparse search stage.
-/// P5: Uses DashMap for lock-free soft-delete checks.
+/// Both stages filter through the same `snapshot`, so SPLADE recall and HNSW
+/// recall agree on which fingerprints exist.
+///
+/// Stage timings go into `timings`: `splade_recall` (E13), `hnsw_search` (the
+/// dense recall union), `fusion` (Stage 2) and `maxsim_rerank` when enabled.
+/// There is no Matryoshka prefilter, so `matryoshka_filter` is never recorded.
 fn search_pipeline_sync(
     db: &Arc<DB>,
     index_registry: &Arc<EmbedderIndexRegistry>,
-    soft_deleted: &Arc<DashMap<Uuid, i64>>,
+    snapshot: &SearchSnapshot,
     query: &SemanticFingerprint,
     options: &TeleologicalSearchOptions,
     total_doc_count: usize,
+    timings: &mut PipelineTimings,
 ) -> CoreResult<Vec<TeleologicalSearchResult>> {
     let recall_k = options.top_k * STAGE1_RECALL_MULTIPLIER;
     let stage2_k = options.top_k * STAGE2_CANDIDATE_MULTIPLIER;
@@ -885,7 +995,8 @@ fn search_pipeline_sync(
 
     // E13 SPLADE sparse recall
     if !query.e13_splade.is_empty() {
-        match search_sparse_sync(db, &query.e13_splade, recall_k, soft_deleted, total_doc_count) {
+        let stage = StageSpan::start(PipelineStage::SpladeRecall, total_doc_count);
+        match search_sparse_sync(db, &query.e13_splade, recall_k, snapshot, total_doc_count) {
             Ok(sparse_results) => {
                 let sparse_count = sparse_results.len();
                 candidate_ids.extend(sparse_results.into_iter().map(|(id, _)| id));
@@ -895,25 +1006,20 @@ fn search_pipeline_sync(
                 warn!("Stage 1: E13 SPLADE search failed: {}, continuing with E1 only", e);
             }
         }
+        stage.finish(candidate_ids.len(), timings);
     }
 
+    // Dense HNSW recall across E1..E11, merged into the SPLADE candidates
+    let stage = StageSpan::start(PipelineStage::HnswSearch, total_doc_count);
+
     // E1 Semantic HNSW
-    let entry_embedder = EmbedderIndex::E1Semantic;
-    if let Some(entry_index) = index_registry.get(entry_embedder) {
-        match entry_index.search(&query.e1_semantic, recall_k, None) {
-            Ok(e1_candidates) => {
-                let e1_count = e1_candidates.len();
-                candidate_ids.extend(e1_candidates.into_iter().map(|(id, _)| id));
-                debug!("Stage 1: E1 HNSW returned {} candidates", e1_count);
-            }
-            Err(e) => {
-                error!("Stage 1: E1 HNSW search failed: {}", e);
-                return Err(CoreError::IndexError(e.to_string()));
-            }
-        }
-    } else {
-        return Err(CoreError::IndexError("E1 Semantic index not found".into()));
-    }
+    let e1_candidates = search_candidates_sync(
+        db, index_registry, snapshot, options, EmbedderIndex::E1Semantic,
+        &query.e1_semantic, recall_k,
+    )?;
+    let e1_count = e1_candidates.len();
+    candidate_ids.extend(e1_candidates.into_iter().map(|(id, _)| id));
+    debug!("Stage 1: E1 HNSW returned {} candidates", e1_count);
 
     // E5 Causal — direction-aware HNSW retrieval for pipeline search (Gap 1 fix).
     // STOR-L5: Cross-pair retrieval — cause vector queries effect index and vice versa.
@@ -1048,6 +1154,7 @@ fn search_pipeline_sync(
         }
     }
 
+    stage.finish(candidate_ids.len(), timings);
     info!(
         "Stage 1 complete: {} unique candidates from E13+E1+E2*+E3*+E4*+E5+E7+E8+E9+E11 (* = weight-gated)",
         candidate_ids.len()
@@ -1059,12 +1166,13 @@ fn search_pipeline_sync(
     }
 
     // STAGE 2: MULTI-SPACE SCORING (weights resolved above for Stage 1 gating)
+    let stage = StageSpan::start(PipelineStage::Fusion, candidate_ids.len());
     let code_query_type = options.effective_code_query_type();
 
     let mut valid_candidates: Vec<(Uuid, TeleologicalFingerprint)> = Vec::with_capacity(candidate_ids.len());
 
     for id in candidate_ids {
-        if !options.include_deleted && is_soft_deleted_sync(soft_deleted, &id) {
+        if snapshot.excludes(&id, options.include_deleted) {
             continue;
         }
         if let Some(data) = get_fingerprint_raw_sync(db, id)? {
@@ -1160,6 +1268,7 @@ fn search_pipeline_sync(
 
     scored_candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
     scored_candidates.truncate(stage2_k);
+    stage.finish(scored_candidates.len(), timings);
 
     info!(
         "Stage 2 complete: {} candidates after {:?} fusion scoring",
@@ -1176,6 +1285,7 @@ fn search_pipeline_sync(
     // If enabled, compute MaxSim between query tokens and candidate e12_late_interaction tokens,
     // then interpolate with stage2 fusion score.
     if options.enable_rerank && !query.e12_late_interaction.is_empty() {
+        let stage = StageSpan::start(PipelineStage::MaxsimRerank, scored_candidates.len());
         let rerank_weight = options.rerank_weight;
         let mut reranked = Vec::with_capacity(scored_candidates.len());
 
@@ -1200,6 +1310,7 @@ fn search_pipeline_sync(
             reranked.len(),
             rerank_weight
         );
+        stage.finish(reranked.len(), timings);
         scored_candidates = reranked;
     }
 
@@ -1323,12 +1434,12 @@ fn resolve_weights_sync(options: &TeleologicalSearchOptions) -> CoreResult<[f32;
 /// Sparse search (sync version for spawn_blocking).
 ///
 /// P1: Takes `total_doc_count` as parameter instead of doing O(n) full-iterator scan.
-/// P5: Uses DashMap for lock-free soft-delete checks.
+/// Posting-list entries outside `snapshot` are skipped, soft-deleted ones included.
 fn search_sparse_sync(
     db: &DB,
     sparse_query: &SparseVector,
     top_k: usize,
-    soft_deleted: &Arc<DashMap<Uuid, i64>>,
+    snapshot: &SearchSnapshot,
     total_doc_count: usize,
 ) -> CoreResult<Vec<(Uuid, f32)>> {
     // P1: total_doc_count is O(1) atomic read (was O(n) iterator scan)
@@ -1378,7 +1489,7 @@ fn search_sparse_sync(
     for term in &term_data {
         let term_contribution = term.query_weight * term.idf;
         for doc_id in &term.doc_ids {
-            if is_soft_deleted_sync(soft_deleted, doc_id) {
+            if snapshot.excludes(doc_id, false) {
                 continue;
             }
             *doc_scores.entry(*doc_id).or_insert(0.0) += term_contribution;
@@ -1594,6 +1705,19 @@ impl RocksDbTeleologicalStore {
         query: &SemanticFingerprint,
         options: TeleologicalSearchOptions,
     ) -> CoreResult<Vec<TeleologicalSearchResult>> {
+        let (results, _) = self.search_semantic_timed_async(query, options).await?;
+        Ok(results)
+    }
+
+    /// [`search_semantic_async`](Self::search_semantic_async) plus the
+    /// per-stage timings of the retrieval, which are also folded into the
+    /// store's pipeline metrics. Post-retrieval filters and temporal boosts
+    /// are not part of any stage.
+    pub(crate) async fn search_semantic_timed_async(
+        &self,
+        query: &SemanticFingerprint,
+        options: TeleologicalSearchOptions,
+    ) -> CoreResult<(Vec<TeleologicalSearchResult>, PipelineTimings)> {
         debug!(
             "Searching semantic with strategy={:?}, top_k={}, min_similarity={}, embedder_indices={:?}, temporal_weight={}",
             options.strategy, options.top_k, options.min_similarity,
@@ -1602,22 +1726,38 @@ impl RocksDbTeleologicalStore {
         );
 
         // Clone Arc-wrapped fields for spawn_blocking closure
-        // P5: Arc::clone for DashMap is 8 bytes (vs 5-478KB HashMap clone)
         let db = Arc::clone(&self.db);
         let index_registry = Arc::clone(&self.index_registry);
-        let soft_deleted = Arc::clone(&self.soft_deleted);
+        // Fix the visible set before any stage runs; writes committed after
+        // this point stay out of every stage of this search.
+        let mut snapshot = SearchSnapshot::capture(&self.commit_log, &self.soft_deleted, options.as_of);
+        // Namespaces share the HNSW indexes: scope every stage's candidates
+        // so a small namespace still fills top_k
+        if options.namespace.is_some() || self.has_staged_namespaces() {
+            snapshot = snapshot.scoped_to(&self.namespaces, options.namespace.clone());
+        }
+        debug!("Search snapshot at commit sequence {}", snapshot.as_of());
         // P3: Wrap query in Arc to avoid cloning ~63KB SemanticFingerprint
         let query_arc = Arc::new(query.clone());
-        let options_clone = options.clone();
+        let mut options_clone = options.clone();
+        // Rankings not filtered during retrieval still carry out-of-range
+        // candidates, so over-fetch first and cut to top_k after the filter
+        if options.time_range.is_some() {
+            options_clone.top_k = options.top_k.saturating_mul(TIME_RANGE_OVERFETCH);
+        }
         // P1: Read total_doc_count atomically (O(1) vs O(n) iterator)
         let total_docs = self.total_doc_count.load(Ordering::Relaxed);
 
         // Move synchronous search work to blocking thread pool
-        let mut results = tokio::task::spawn_blocking(move || {
+        let (mut results, timings) = tokio::task::spawn_blocking(move || {
             let query_clone = &*query_arc;
+            let mut timings = PipelineTimings::new();
+            // Only the pipeline has distinct stages; every other route is
+            // timed as one dense search.
+            let dense = StageSpan::start(PipelineStage::HnswSearch, total_docs);
             // CRIT-06: When embedder_indices is set, route to specific HNSW index(es)
             // instead of always defaulting to E1 or the strategy-based dispatch.
-            if !options_clone.embedder_indices.is_empty() {
+            let results = if !options_clone.embedder_indices.is_empty() {
                 let indices = &options_clone.embedder_indices;
                 if indices.len() == 1 {
                     // Single embedder: search that specific HNSW index directly
@@ -1625,42 +1765,80 @@ impl RocksDbTeleologicalStore {
                         "Embedder-specific routing: single embedder index {}",
                         indices[0]
                     );
-                    return search_single_embedder_sync(
-                        &db, &index_registry, &soft_deleted, query_clone, &options_clone,
+                    search_single_embedder_sync(
+                        &db, &index_registry, &snapshot, query_clone, &options_clone,
                         indices[0],
-                    );
+                    )?
                 } else {
                     // Multiple embedders: filtered multi-space across only those embedders
                     debug!(
                         "Embedder-specific routing: filtered multi-space with {:?}",
                         indices
                     );
-                    return search_filtered_multi_space_sync(
-                        &db, &index_registry, &soft_deleted, query_clone, &options_clone,
+                    search_filtered_multi_space_sync(
+                        &db, &index_registry, &snapshot, query_clone, &options_clone,
                         indices,
-                    );
-                }
-            }
-
-            // Standard strategy-based dispatch when no specific embedders requested
-            match options_clone.strategy {
-                SearchStrategy::E1Only => {
-                    search_e1_only_sync(&db, &index_registry, &soft_deleted, query_clone, &options_clone)
-                }
-                SearchStrategy::MultiSpace => {
-                    search_multi_space_sync(&db, &index_registry, &soft_deleted, query_clone, &options_clone)
+                    )?
                 }
-                SearchStrategy::Pipeline => {
-                    warn!(
-                        "Pipeline strategy uses 2-stage retrieval (E13 recall + multi-space scoring). \
-                         E12 MaxSim reranking is not yet implemented."
-                    );
-                    search_pipeline_sync(&db, &index_registry, &soft_deleted, query_clone, &options_clone, total_docs)
+            } else {
+                // Standard strategy-based dispatch when no specific embedders requested
+                match options_clone.strategy {
+                    SearchStrategy::E1Only => {
+                        search_e1_only_sync(&db, &index_registry, &snapshot, query_clone, &options_clone)?
+                    }
+                    SearchStrategy::MultiSpace => {
+                        search_multi_space_sync(&db, &index_registry, &snapshot, query_clone, &options_clone)?
+                    }
+                    SearchStrategy::Pipeline => {
+                        warn!(
+                            "Pipeline strategy uses 2-stage retrieval (E13 recall + multi-space scoring). \
+                             E12 MaxSim reranking is not yet implemented."
+                        );
+                        let results = search_pipeline_sync(
+                            &db, &index_registry, &snapshot, query_clone, &options_clone,
+                            total_docs, &mut timings,
+                        )?;
+                        return Ok::<_, CoreError>((results, timings));
+                    }
                 }
-            }
+            };
+            dense.finish(results.len(), &mut timings);
+            Ok((results, timings))
         })
         .await
         .map_err(|e| CoreError::Internal(format!("spawn_blocking failed: {}", e)))??;
+        self.pipeline_metrics.record(&timings);
+
+        // TTL: expired fingerprints are invisible until purge_expired removes them
+        let now = chrono::Utc::now();
+        results.retain(|r| !r.fingerprint.is_expired_at(now));
+
+        // Namespace isolation is enforced by the snapshot scope during
+        // retrieval; this only guards the invariant
+        results.retain(|r| options.admits_namespace(&r.fingerprint.namespace));
+
+        // Hard time range over every ranking that fed the fusion
+        if options.time_range.is_some() {
+            results.retain(|r| {
+                let admitted = options.time_range_admits(r.fingerprint.created_at);
+                if !admitted {
+                    options.time_filter_exclusions.record(r.fingerprint.id);
+                }
+                admitted
+            });
+        }
+
+        // Recency decay on the fused scores, before the final cut to top_k
+        if options.recency_weight > 0.0 {
+            for result in results.iter_mut() {
+                result.similarity *= options.recency_factor(now - result.fingerprint.created_at);
+            }
+            results.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+        }
+
+        if options.time_range.is_some() {
+            results.truncate(options.top_k);
+        }
 
         // Apply time window filter if configured
         if let Some(ref window) = options.temporal_options.time_window {
@@ -1676,8 +1854,16 @@ impl RocksDbTeleologicalStore {
             self.apply_full_temporal_boosts(&mut results, query, &options).await?;
         }
 
-        debug!("Semantic search returned {} results", results.len());
-        Ok(results)
+        debug!(
+            "Semantic search returned {} results ({})",
+            results.len(),
+            timings
+                .iter()
+                .map(|(stage, t)| format!("{}={:?}", stage.name(), t.elapsed))
+                .collect::<Vec<_>>()
+                .join(" ")
+        );
+        Ok((results, timings))
     }
 
     /// Apply full temporal boost system POST-retrieval (ARCH-14).
@@ -1869,17 +2055,21 @@ impl RocksDbTeleologicalStore {
         let total_doc_count = self.total_doc_count.load(Ordering::Relaxed);
 
         // Clone Arc-wrapped fields for spawn_blocking closure
-        // P5: Arc::clone for DashMap is cheap (no HashMap clone)
         let db = Arc::clone(&self.db);
-        let soft_deleted = Arc::clone(&self.soft_deleted);
+        let snapshot = SearchSnapshot::capture(&self.commit_log, &self.soft_deleted, None);
         let sparse_query = sparse_query.clone();
 
         // Move synchronous RocksDB I/O to blocking thread pool
-        tokio::task::spawn_blocking(move || {
-            search_sparse_sync(&db, &sparse_query, top_k, &soft_deleted, total_doc_count)
+        let mut results = tokio::task::spawn_blocking(move || {
+            search_sparse_sync(&db, &sparse_query, top_k, &snapshot, total_doc_count)
         })
         .await
-        .map_err(|e| CoreError::Internal(format!("spawn_blocking failed: {}", e)))?
+        .map_err(|e| CoreError::Internal(format!("spawn_blocking failed: {}", e)))??;
+
+        // TTL: drop expired IDs (sparse results carry no fingerprint)
+        let now_millis = chrono::Utc::now().timestamp_millis();
+        results.retain(|(id, _)| !is_expired_in(&self.expiring, id, now_millis));
+        Ok(results)
     }
 }
 
@@ -25,7 +25,7 @@ impl RocksDbTeleologicalStore {
     /// Deserialize source metadata from JSON.
     ///
     /// JSON is the only supported format. Old bincode data will produce clear errors.
-    fn deserialize_source_metadata(bytes: &[u8], id: Uuid) -> CoreResult<SourceMetadata> {
+    pub(crate) fn deserialize_source_metadata(bytes: &[u8], id: Uuid) -> CoreResult<SourceMetadata> {
         serde_json::from_slice::<SourceMetadata>(bytes).map_err(|e| {
             error!(
                 "METADATA ERROR: Failed to deserialize source metadata for fingerprint {}: {}. \
@@ -0,0 +1,251 @@
+//! Storage footprint metrics (per column family and auxiliary files).
+//!
+//! [`RocksDbTeleologicalStore::collect_storage_metrics`] reads RocksDB
+//! properties for every column family plus one `live_files` listing for SST
+//! counts, then sizes the non-RocksDB entries of the data directory
+//! (checkpoints, HNSW snapshots). It touches no data blocks, so it is cheap
+//! enough to back every `get_memetic_status` call; the MCP status aggregator
+//! caches it anyway.
+//!
+//! [`RocksDbTeleologicalStore::largest_memories_sync`] is the expensive
+//! counterpart: it scans CF_FINGERPRINTS to rank memories by stored size.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use rocksdb::IteratorMode;
+use uuid::Uuid;
+
+use context_graph_core::traits::{
+    AuxiliaryFileMetrics, ColumnFamilyMetrics, MemorySize, StorageMetrics,
+};
+
+use crate::column_families::cf_names;
+use crate::teleological::column_families::{
+    CAUSAL_CFS, CF_CONTENT, CF_CONTENT_BLOBS, CF_FINGERPRINTS, CODE_CFS, QUANTIZED_EMBEDDER_CFS,
+    TELEOLOGICAL_CFS,
+};
+use crate::teleological::schema::{
+    content_blob_key, content_blob_pointer_key, content_key, parse_fingerprint_key,
+};
+
+use super::process_lock::LOCK_OWNER_FILE;
+use super::store::RocksDbTeleologicalStore;
+use super::types::{TeleologicalStoreError, TeleologicalStoreResult};
+
+/// Property names read for every column family.
+const PROP_LIVE_DATA_SIZE: &str = "rocksdb.estimate-live-data-size";
+const PROP_NUM_KEYS: &str = "rocksdb.estimate-num-keys";
+const PROP_PENDING_COMPACTION: &str = "rocksdb.estimate-pending-compaction-bytes";
+
+/// Whether `name` is a file RocksDB (or the store's lock owner record)
+/// keeps in the data directory.
+fn is_database_file(name: &str) -> bool {
+    matches!(name, "CURRENT" | "IDENTITY" | "LOCK" | "LOG" | LOCK_OWNER_FILE)
+        || name.starts_with("LOG.old")
+        || name.starts_with("MANIFEST-")
+        || name.starts_with("OPTIONS-")
+        || name.ends_with(".sst")
+        || name.ends_with(".log")
+        || name.ends_with(".blob")
+}
+
+/// Regular files and their total bytes under `path`, following no symlinks.
+fn disk_usage(path: &Path) -> io::Result<(u64, u64)> {
+    let meta = fs::symlink_metadata(path)?;
+    if meta.is_file() {
+        return Ok((1, meta.len()));
+    }
+    if !meta.is_dir() {
+        return Ok((0, 0));
+    }
+    let mut totals = (0, 0);
+    for entry in fs::read_dir(path)? {
+        let (files, bytes) = disk_usage(&entry?.path())?;
+        totals.0 += files;
+        totals.1 += bytes;
+    }
+    Ok(totals)
+}
+
+impl RocksDbTeleologicalStore {
+    /// Collect per-CF figures and auxiliary file sizes.
+    ///
+    /// # Errors
+    ///
+    /// FAIL FAST on a property read, `live_files` or filesystem failure.
+    pub fn collect_storage_metrics(&self) -> TeleologicalStoreResult<StorageMetrics> {
+        let mut sst: HashMap<String, (u64, u64)> = HashMap::new();
+        let live_files = self
+            .db
+            .live_files()
+            .map_err(|e| TeleologicalStoreError::rocksdb_op("live_files", "all", None, e))?;
+        for file in live_files {
+            let entry = sst.entry(file.column_family_name).or_default();
+            entry.0 += 1;
+            entry.1 += file.size as u64;
+        }
+
+        let all_cf_arrays: &[&[&'static str]] = &[
+            cf_names::ALL,
+            TELEOLOGICAL_CFS,
+            QUANTIZED_EMBEDDER_CFS,
+            CODE_CFS,
+            CAUSAL_CFS,
+        ];
+        let mut column_families = Vec::new();
+        for cf_names_arr in all_cf_arrays {
+            for &name in *cf_names_arr {
+                let cf = self.get_cf(name)?;
+                let property = |prop: &str| -> TeleologicalStoreResult<u64> {
+                    self.db
+                        .property_int_value_cf(cf, prop)
+                        .map(|v| v.unwrap_or(0))
+                        .map_err(|e| {
+                            TeleologicalStoreError::rocksdb_op("property_int_value", name, None, e)
+                        })
+                };
+                let (sst_files, sst_bytes) = sst.get(name).copied().unwrap_or_default();
+                column_families.push(ColumnFamilyMetrics {
+                    name: name.to_string(),
+                    live_data_bytes: property(PROP_LIVE_DATA_SIZE)?,
+                    num_keys: property(PROP_NUM_KEYS)?,
+                    pending_compaction_bytes: property(PROP_PENDING_COMPACTION)?,
+                    sst_files,
+                    sst_bytes,
+                });
+            }
+        }
+        column_families.sort_by(|a, b| {
+            b.live_data_bytes
+                .cmp(&a.live_data_bytes)
+                .then(b.sst_bytes.cmp(&a.sst_bytes))
+                .then(a.name.cmp(&b.name))
+        });
+
+        let io_error = |path: &Path, e: io::Error| {
+            TeleologicalStoreError::Internal(format!(
+                "Failed to size {} for storage metrics: {}",
+                path.display(),
+                e
+            ))
+        };
+        let mut auxiliary = Vec::new();
+        for entry in fs::read_dir(&self.path).map_err(|e| io_error(&self.path, e))? {
+            let entry = entry.map_err(|e| io_error(&self.path, e))?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if is_database_file(&name) {
+                continue;
+            }
+            let path = entry.path();
+            let (files, bytes) = disk_usage(&path).map_err(|e| io_error(&path, e))?;
+            auxiliary.push(AuxiliaryFileMetrics { name, files, bytes });
+        }
+        auxiliary.sort_by(|a, b| b.bytes.cmp(&a.bytes).then(a.name.cmp(&b.name)));
+
+        Ok(StorageMetrics {
+            column_families,
+            auxiliary,
+        })
+    }
+
+    /// The `n` live memories with the largest fingerprint plus content
+    /// bytes, largest first.
+    ///
+    /// Shared content blobs count fully toward every memory that references
+    /// them. Soft-deleted memories are skipped.
+    pub fn largest_memories_sync(&self, n: usize) -> TeleologicalStoreResult<Vec<MemorySize>> {
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+        let cf = self.get_cf(CF_FINGERPRINTS)?;
+        let mut heap: BinaryHeap<Reverse<(u64, Uuid, u64)>> = BinaryHeap::with_capacity(n + 1);
+        for item in self.db.iterator_cf(cf, IteratorMode::Start) {
+            let (key, value) = item.map_err(|e| {
+                TeleologicalStoreError::rocksdb_op("iterate", CF_FINGERPRINTS, None, e)
+            })?;
+            let id = parse_fingerprint_key(&key);
+            if self.soft_deleted.contains_key(&id) {
+                continue;
+            }
+            let fingerprint_bytes = value.len() as u64;
+            let content_bytes = self.stored_content_bytes(id)?;
+            heap.push(Reverse((fingerprint_bytes + content_bytes, id, fingerprint_bytes)));
+            if heap.len() > n {
+                heap.pop();
+            }
+        }
+
+        Ok(heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((total, id, fingerprint_bytes))| MemorySize {
+                id,
+                fingerprint_bytes,
+                content_bytes: total - fingerprint_bytes,
+            })
+            .collect())
+    }
+
+    /// Bytes `id`'s content occupies: its blob record if it points at one,
+    /// else its CF_CONTENT value.
+    fn stored_content_bytes(&self, id: Uuid) -> TeleologicalStoreResult<u64> {
+        let blobs = self.get_cf(CF_CONTENT_BLOBS)?;
+        let pointer = self
+            .db
+            .get_pinned_cf(blobs, content_blob_pointer_key(&id))
+            .map_err(|e| {
+                TeleologicalStoreError::rocksdb_op("get", CF_CONTENT_BLOBS, Some(id), e)
+            })?;
+        if let Some(pointer) = pointer {
+            let hash: [u8; 32] = pointer.as_ref().try_into().map_err(|_| {
+                TeleologicalStoreError::Internal(format!(
+                    "Content pointer for {} has {} bytes, expected 32",
+                    id,
+                    pointer.len()
+                ))
+            })?;
+            let blob = self
+                .db
+                .get_pinned_cf(blobs, content_blob_key(&hash))
+                .map_err(|e| {
+                    TeleologicalStoreError::rocksdb_op("get", CF_CONTENT_BLOBS, Some(id), e)
+                })?;
+            return Ok(blob.map_or(0, |b| b.len() as u64));
+        }
+
+        let content = self
+            .db
+            .get_pinned_cf(self.get_cf(CF_CONTENT)?, content_key(&id))
+            .map_err(|e| TeleologicalStoreError::rocksdb_op("get", CF_CONTENT, Some(id), e))?;
+        Ok(content.map_or(0, |c| c.len() as u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_database_files_are_not_auxiliary() {
+        for name in [
+            "CURRENT",
+            "LOCK",
+            "LOG",
+            "LOG.old.1700000000",
+            "MANIFEST-000005",
+            "OPTIONS-000007",
+            "000012.sst",
+            "000004.log",
+            "LOCK.owner",
+        ] {
+            assert!(is_database_file(name), "{}", name);
+        }
+        for name in ["checkpoints", "hnsw", "export.jsonl"] {
+            assert!(!is_database_file(name), "{}", name);
+        }
+    }
+}
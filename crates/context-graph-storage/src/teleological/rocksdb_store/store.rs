@@ -22,9 +22,11 @@ use parking_lot::RwLock;
 use bincode;
 use rocksdb::{Cache, ColumnFamily, Options, WriteBatch, DB};
 use serde_json;
+use sha2::{Digest, Sha256};
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+use context_graph_core::retrieval::PipelineMetrics;
 use context_graph_core::types::fingerprint::TeleologicalFingerprint;
 use context_graph_core::weights::E11_ENTITY_ENABLED;
 
@@ -33,9 +35,15 @@ use crate::teleological::column_families::{
     CF_CONTENT, CF_E12_LATE_INTERACTION, CF_E1_MATRYOSHKA_128, CF_FINGERPRINTS, CF_SOURCE_METADATA,
     QUANTIZED_EMBEDDER_CFS, TELEOLOGICAL_CFS, CODE_CFS, CAUSAL_CFS,
 };
-use crate::teleological::indexes::EmbedderIndexRegistry;
+use crate::teleological::indexes::build::{build_staged, ProgressTracker, StagedSpace};
+use crate::teleological::indexes::{
+    plan_build, EmbedderIndex, EmbedderIndexOps, EmbedderIndexRegistry, IndexBuildConfig,
+    IndexBuildPlan, IndexBuildSummary,
+};
 
+use super::access_stats::AccessTracker;
 use super::causal_hnsw_index::CausalE11Index;
+use super::write_policy::BatchWriter;
 use crate::teleological::schema::{
     e12_late_interaction_key, e1_matryoshka_128_key, fingerprint_key,
 };
@@ -43,7 +51,21 @@ use crate::teleological::serialization::{
     serialize_e1_matryoshka_128, serialize_teleological_fingerprint,
 };
 
-use super::types::{TeleologicalStoreConfig, TeleologicalStoreError, TeleologicalStoreResult};
+use super::crud::{expires_at_key, namespace_key};
+use super::process_lock::{is_lock_error, pid_is_alive, read_lock_owner, OwnerFileGuard};
+#[cfg(unix)]
+use super::process_lock::lock_holder_pid;
+use super::types::{
+    StoreAccessMode, TeleologicalStoreConfig, TeleologicalStoreError, TeleologicalStoreResult,
+    WarmStartStats,
+};
+use super::visibility::CommitLog;
+
+/// Check an expiry map for `id` as of `now_millis` (usable from spawn_blocking).
+#[inline]
+pub(crate) fn is_expired_in(expiring: &DashMap<Uuid, i64>, id: &Uuid, now_millis: i64) -> bool {
+    expiring.get(id).is_some_and(|deadline| *deadline <= now_millis)
+}
 
 // ============================================================================
 // Main Store Struct
@@ -52,7 +74,7 @@ use super::types::{TeleologicalStoreConfig, TeleologicalStoreError, Teleological
 /// RocksDB-backed storage for TeleologicalFingerprints.
 ///
 /// Implements the `TeleologicalMemoryStore` trait with persistent storage
-/// across 51 column families (11 base + 20 teleological + 13 quantized + 5 code + 2 causal).
+/// across 57 column families (11 base + 26 teleological + 13 quantized + 5 code + 2 causal).
 ///
 /// # Thread Safety
 ///
@@ -94,6 +116,19 @@ pub struct RocksDbTeleologicalStore {
     /// under 100+ concurrent searches x 50+ results = 5,000+ lookups).
     /// Wrapped in Arc for cheap cloning into spawn_blocking closures.
     pub(crate) soft_deleted: Arc<DashMap<Uuid, i64>>,
+    /// TTL: IDs with an `expires_at` deadline (Unix epoch milliseconds).
+    /// Persisted in CF_SYSTEM (`expires_at::{uuid}`) so the map is available
+    /// without a full fingerprint scan when HNSW indexes load from disk.
+    /// Used to hide expired memories from id-only paths (sparse search/recall)
+    /// and to find purge candidates in `purge_expired`.
+    pub(crate) expiring: Arc<DashMap<Uuid, i64>>,
+    /// Namespace of every fingerprint outside the default namespace.
+    /// Persisted in CF_SYSTEM (`namespace::{uuid}`); absent IDs are "default".
+    /// Used for per-namespace counts without a full fingerprint scan.
+    pub(crate) namespaces: Arc<DashMap<Uuid, String>>,
+    /// Commit sequence: fingerprints become visible to search only once
+    /// RocksDB and every HNSW index hold them (see `visibility.rs`).
+    pub(crate) commit_log: Arc<CommitLog>,
     /// Per-embedder index registry with 15 HNSW indexes for O(log n) ANN search.
     /// E6, E12, E13 use different index types (inverted/MaxSim).
     /// NO FALLBACKS - FAIL FAST on invalid operations.
@@ -124,6 +159,25 @@ pub struct RocksDbTeleologicalStore {
     /// Compaction is infrequent (~10min or manual) so write lock contention is negligible.
     /// Prevents duplicate/missing entries from concurrent store + rebuild race.
     pub(crate) compaction_lock: RwLock<()>,
+    /// Per-memory access counts, flushed to CF_ACCESS_STATS in the background.
+    /// Declared before `batch_writer` so its final flush on drop precedes
+    /// the shutdown WAL sync.
+    pub(crate) access_tracker: AccessTracker,
+    /// Commits fingerprint write batches under the configured `FsyncPolicy`
+    /// and tracks write metrics.
+    pub(crate) batch_writer: BatchWriter,
+    /// Per-stage latency histograms of semantic searches.
+    pub(crate) pipeline_metrics: PipelineMetrics,
+    /// How the database was opened; writes need `Exclusive`.
+    pub(crate) access_mode: StoreAccessMode,
+    /// How HNSW indexes are rebuilt from fingerprints.
+    pub(crate) index_build: IndexBuildConfig,
+    /// Outcome of the last rebuild from fingerprints.
+    pub(crate) last_index_build: RwLock<Option<IndexBuildSummary>>,
+    /// Owner file recording this process as writer (exclusive opens only).
+    /// Declared last so it is removed after the database handle closes.
+    #[allow(dead_code)]
+    pub(crate) owner_file: Option<OwnerFileGuard>,
 }
 
 // ============================================================================
@@ -133,7 +187,7 @@ pub struct RocksDbTeleologicalStore {
 impl RocksDbTeleologicalStore {
     /// Open a teleological store at the specified path with default configuration.
     ///
-    /// Creates the database and all 51 column families if they don't exist.
+    /// Creates the database and all 57 column families if they don't exist.
     /// **Automatically detects and removes stale lock files.**
     pub fn open<P: AsRef<Path>>(path: P) -> TeleologicalStoreResult<Self> {
         Self::open_with_config(path, TeleologicalStoreConfig::default())
@@ -147,14 +201,18 @@ impl RocksDbTeleologicalStore {
         let path_buf = path.as_ref().to_path_buf();
         let path_str = path_buf.to_string_lossy().to_string();
 
+        let access_mode = config.access_mode;
+
         info!(
-            "Opening RocksDbTeleologicalStore at '{}' with cache_size={}MB",
+            "Opening RocksDbTeleologicalStore at '{}' with cache_size={}MB, access_mode={:?}",
             path_str,
-            config.block_cache_size / (1024 * 1024)
+            config.block_cache_size / (1024 * 1024),
+            access_mode
         );
 
         // STALE LOCK DETECTION: Check for and remove stale lock files before opening.
-        if path_buf.exists() {
+        // Read-only and secondary opens take no lock, so they leave LOCK alone.
+        if access_mode.is_writable() && path_buf.exists() {
             match Self::detect_and_remove_stale_lock(&path_buf) {
                 Ok(true) => {
                     info!("Removed stale lock at '{}', proceeding with open", path_str);
@@ -193,7 +251,7 @@ impl RocksDbTeleologicalStore {
             db_opts.set_manual_wal_flush(true);
         }
 
-        // Get ALL column families (51 total: 11 base + 20 teleological + 13 quantized + 5 code + 2 causal)
+        // Get ALL column families (57 total: 11 base + 26 teleological + 13 quantized + 5 code + 2 causal)
         // This includes the graph edge CFs (embedder_edges, typed_edges, typed_edges_by_type)
         // required for K-NN graph-based retrieval. NO FALLBACKS - database must have all CFs.
         let cf_descriptors = get_all_column_family_descriptors(&cache);
@@ -204,11 +262,59 @@ impl RocksDbTeleologicalStore {
         );
 
         // Open database with all column families
-        let db = DB::open_cf_descriptors(&db_opts, &path_str, cf_descriptors).map_err(|e| {
+        let db = match access_mode {
+            StoreAccessMode::Exclusive => {
+                DB::open_cf_descriptors(&db_opts, &path_str, cf_descriptors)
+            }
+            StoreAccessMode::ReadOnly => {
+                DB::open_cf_descriptors_read_only(&db_opts, &path_str, cf_descriptors, false)
+            }
+            StoreAccessMode::SecondaryCatchUp => {
+                let secondary_path = config.secondary_path.clone().unwrap_or_else(|| {
+                    std::env::temp_dir()
+                        .join(format!("context-graph-secondary-{}", std::process::id()))
+                });
+                fs::create_dir_all(&secondary_path).map_err(|e| {
+                    TeleologicalStoreError::OpenFailed {
+                        path: path_str.clone(),
+                        message: format!(
+                            "Cannot create secondary directory '{}': {}",
+                            secondary_path.display(),
+                            e
+                        ),
+                    }
+                })?;
+                // Secondary instances must keep every file open (RocksDB requirement).
+                db_opts.set_max_open_files(-1);
+                DB::open_cf_descriptors_as_secondary(
+                    &db_opts,
+                    &path_str,
+                    &secondary_path,
+                    cf_descriptors,
+                )
+            }
+        }
+        .map_err(|e| {
             error!("Failed to open RocksDB at '{}': {}", path_str, e);
+            if is_lock_error(&e) {
+                return Self::locked_error(&path_buf, None);
+            }
             Self::transform_corruption_error(&path_str, e)
         })?;
 
+        // Record this process as the writer so a second opener can name it.
+        let owner_file = if access_mode.is_writable() {
+            match OwnerFileGuard::create(&path_buf) {
+                Ok(guard) => Some(guard),
+                Err(e) => {
+                    warn!("Failed to record lock owner at '{}': {}", path_str, e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
         // Create per-embedder index registry (15 HNSW indexes)
         let index_registry = Arc::new(EmbedderIndexRegistry::new());
 
@@ -271,6 +377,79 @@ impl RocksDbTeleologicalStore {
             Arc::new(map)
         };
 
+        // TTL: Load persisted expiry deadlines from CF_SYSTEM.
+        let expiring = {
+            use super::crud::EXPIRES_AT_PREFIX;
+
+            let map: DashMap<Uuid, i64> = DashMap::new();
+            if let Some(cf_system) = db_arc.cf_handle(crate::column_families::cf_names::SYSTEM) {
+                let iter = db_arc.prefix_iterator_cf(cf_system, EXPIRES_AT_PREFIX.as_bytes());
+                for item in iter {
+                    match item {
+                        Ok((key, value)) => {
+                            let key_str = String::from_utf8_lossy(&key);
+                            if let Some(uuid_str) = key_str.strip_prefix(EXPIRES_AT_PREFIX) {
+                                match (uuid::Uuid::parse_str(uuid_str), value.len()) {
+                                    (Ok(id), 8) => {
+                                        let ts = i64::from_be_bytes(value[..8].try_into().unwrap());
+                                        map.insert(id, ts);
+                                    }
+                                    _ => warn!("Skipping malformed expiry marker '{}'", key_str),
+                                }
+                            } else {
+                                // Prefix iterator went past our prefix -- stop
+                                break;
+                            }
+                        }
+                        Err(e) => {
+                            error!("Error reading expiry markers: {}", e);
+                            break;
+                        }
+                    }
+                }
+            }
+            if !map.is_empty() {
+                info!("Loaded {} persisted expiry markers from CF_SYSTEM", map.len());
+            }
+            Arc::new(map)
+        };
+
+        // Namespaces: Load persisted non-default namespace markers from CF_SYSTEM.
+        let namespaces = {
+            use super::crud::NAMESPACE_PREFIX;
+
+            let map: DashMap<Uuid, String> = DashMap::new();
+            if let Some(cf_system) = db_arc.cf_handle(crate::column_families::cf_names::SYSTEM) {
+                let iter = db_arc.prefix_iterator_cf(cf_system, NAMESPACE_PREFIX.as_bytes());
+                for item in iter {
+                    match item {
+                        Ok((key, value)) => {
+                            let key_str = String::from_utf8_lossy(&key);
+                            if let Some(uuid_str) = key_str.strip_prefix(NAMESPACE_PREFIX) {
+                                match (uuid::Uuid::parse_str(uuid_str), String::from_utf8(value.to_vec())) {
+                                    (Ok(id), Ok(ns)) => {
+                                        map.insert(id, ns);
+                                    }
+                                    _ => warn!("Skipping malformed namespace marker '{}'", key_str),
+                                }
+                            } else {
+                                // Prefix iterator went past our prefix -- stop
+                                break;
+                            }
+                        }
+                        Err(e) => {
+                            error!("Error reading namespace markers: {}", e);
+                            break;
+                        }
+                    }
+                }
+            }
+            if !map.is_empty() {
+                info!("Loaded {} persisted namespace markers from CF_SYSTEM", map.len());
+            }
+            Arc::new(map)
+        };
+
         // P1: Count total documents for O(1) IDF lookups in sparse search.
         // This replaces the O(n) full-iterator scan that was the #1 scaling bottleneck.
         // raw_fp_count is also used by verify_consistency() to avoid redundant O(n) scan.
@@ -292,6 +471,10 @@ impl RocksDbTeleologicalStore {
             (Arc::new(AtomicUsize::new(count)), raw_count)
         };
 
+        let batch_writer =
+            BatchWriter::new(Arc::clone(&db_arc), config.fsync_policy, access_mode);
+        let access_tracker =
+            AccessTracker::new(Arc::clone(&db_arc), config.access_stats, access_mode)?;
         let store = Self {
             db: db_arc,
             cache,
@@ -299,10 +482,20 @@ impl RocksDbTeleologicalStore {
             fingerprint_count: RwLock::new(None),
             total_doc_count,
             soft_deleted,
+            expiring,
+            namespaces,
+            commit_log: Arc::new(CommitLog::new()),
             index_registry,
             causal_e11_index,
             secondary_index_lock: parking_lot::Mutex::new(()),
             compaction_lock: RwLock::new(()),
+            access_tracker,
+            batch_writer,
+            pipeline_metrics: PipelineMetrics::new(),
+            access_mode,
+            index_build: config.index_build,
+            last_index_build: RwLock::new(None),
+            owner_file,
         };
 
         // Try fast path: load HNSW indexes from CF_HNSW_GRAPHS (persisted graphs).
@@ -316,7 +509,6 @@ impl RocksDbTeleologicalStore {
                 // path loads existing graphs but skips indexes with no CF_HNSW_GRAPHS
                 // entry. Detect empty indexes and trigger a full rebuild if needed.
                 if raw_fp_count > 0 {
-                    use crate::teleological::indexes::{EmbedderIndex, EmbedderIndexOps};
                     let has_empty = store.index_registry.iter().any(|(embedder, index)| {
                         if !E11_ENTITY_ENABLED && *embedder == EmbedderIndex::E11Entity {
                             return false;
@@ -369,8 +561,6 @@ impl RocksDbTeleologicalStore {
     /// Logs warnings for any inconsistencies detected.
     /// Does NOT block startup — operators investigate if warnings appear.
     fn verify_consistency(&self, raw_fp_count: usize) {
-        use crate::teleological::indexes::EmbedderIndexOps;
-
         let start = std::time::Instant::now();
 
         // raw_fp_count is passed from open_with_config() to avoid redundant O(n) scan.
@@ -397,7 +587,6 @@ impl RocksDbTeleologicalStore {
         // E2/E3/E4 HNSW indexes are now populated (temporal first-class fusion).
         // Skip E11 when E11_ENTITY_ENABLED=false (KEPLER non-discriminating).
         for (embedder, index) in self.index_registry.iter() {
-            use crate::teleological::indexes::EmbedderIndex;
             if !E11_ENTITY_ENABLED && *embedder == EmbedderIndex::E11Entity {
                 continue;
             }
@@ -451,41 +640,32 @@ impl RocksDbTeleologicalStore {
 
         #[cfg(unix)]
         {
-            use std::os::unix::io::AsRawFd;
-
-            let file = match fs::OpenOptions::new()
-                .read(true)
-                .write(true)
-                .open(&lock_path)
-            {
-                Ok(f) => f,
-                Err(e) => {
-                    warn!("Cannot open LOCK file for inspection: {}", e);
-                    return Self::try_remove_lock_file(&lock_path, &lock_path_str);
-                }
-            };
-
-            let fd = file.as_raw_fd();
-            let result = unsafe { libc::flock(fd, libc::LOCK_EX | libc::LOCK_NB) };
-
-            if result == 0 {
+            // The kernel hides our own process's locks from F_GETLK, and
+            // probing would close (and so release) them. Check the owner
+            // file for a same-process holder first.
+            let db_path = path.as_ref();
+            if read_lock_owner(db_path).is_some_and(|o| o.pid == std::process::id()) {
                 info!(
-                    "Acquired exclusive lock - LOCK file at '{}' is STALE",
+                    "LOCK file at '{}' is held by this process - NOT stale",
                     lock_path_str
                 );
-                unsafe { libc::flock(fd, libc::LOCK_UN) };
-                drop(file);
-                Self::try_remove_lock_file(&lock_path, &lock_path_str)
-            } else {
-                let errno = std::io::Error::last_os_error();
-                if errno.raw_os_error() == Some(libc::EWOULDBLOCK) {
+                return Err(Self::locked_error(db_path, Some(std::process::id())));
+            }
+
+            match lock_holder_pid(&lock_path) {
+                Ok(Some(pid)) => {
                     info!(
-                        "LOCK file at '{}' is held by another process - NOT stale",
-                        lock_path_str
+                        "LOCK file at '{}' is held by pid {} - NOT stale",
+                        lock_path_str, pid
                     );
-                    Ok(false)
-                } else {
-                    warn!("flock() failed with unexpected error: {}", errno);
+                    Err(Self::locked_error(db_path, Some(pid)))
+                }
+                Ok(None) => {
+                    info!("No process holds LOCK file at '{}' - STALE", lock_path_str);
+                    Self::try_remove_lock_file(&lock_path, &lock_path_str)
+                }
+                Err(e) => {
+                    warn!("Cannot inspect LOCK file '{}': {}", lock_path_str, e);
                     Self::try_remove_lock_file(&lock_path, &lock_path_str)
                 }
             }
@@ -513,6 +693,19 @@ impl RocksDbTeleologicalStore {
         }
     }
 
+    /// Build `LockedByOtherProcess` for the database at `db_path`, taking
+    /// the open time from the owner file when it names the same pid.
+    fn locked_error(db_path: &Path, pid: Option<u32>) -> TeleologicalStoreError {
+        let owner = read_lock_owner(db_path)
+            .filter(|o| pid.is_none() || pid == Some(o.pid))
+            .filter(|o| pid.is_some() || pid_is_alive(o.pid));
+        TeleologicalStoreError::LockedByOtherProcess {
+            path: db_path.to_string_lossy().to_string(),
+            pid: pid.or(owner.as_ref().map(|o| o.pid)),
+            since: owner.map(|o| o.since),
+        }
+    }
+
     /// Attempt to remove a stale lock file.
     fn try_remove_lock_file(
         lock_path: &Path,
@@ -663,32 +856,175 @@ impl RocksDbTeleologicalStore {
     /// This is called during `open()` to restore indexes on restart.
     /// The HNSW indexes are in-memory and need to be rebuilt from RocksDB data.
     ///
+    /// Builds spaces concurrently when the estimated peak memory fits the
+    /// configured `IndexBuildConfig` budget, sequentially otherwise (see
+    /// `indexes::build`). Progress goes to the configured callback.
+    ///
     /// # FAIL FAST: Errors during rebuild cause store open to fail.
     ///
     /// # Returns
     ///
     /// Ok(()) if rebuilding succeeds, Err if any fingerprint fails to add.
     fn rebuild_indexes_from_store(&self) -> TeleologicalStoreResult<()> {
-        use crate::teleological::column_families::CF_FINGERPRINTS;
-        use crate::teleological::schema::parse_fingerprint_key;
-        use crate::teleological::serialization::deserialize_teleological_fingerprint;
-        use context_graph_embeddings::models::custom::compute_decay_embedding;
-        use context_graph_embeddings::models::custom::DEFAULT_DECAY_RATES;
-
         // DATA-5 FIX: Acquire write lock — blocks all concurrent store/delete
         // until rebuild is complete. Prevents duplicate/missing entries.
         let _guard = self.compaction_lock.write();
 
         let start = std::time::Instant::now();
+        let embedders = Self::indexed_embedders();
 
-        let cf = self.get_cf(CF_FINGERPRINTS)?;
-        let iter = self.db.iterator_cf(cf, rocksdb::IteratorMode::Start);
+        let live_count = self.count_live_fingerprints()?;
+        if live_count == 0 {
+            debug!("No fingerprints to rebuild indexes from (empty store)");
+            return Ok(());
+        }
+
+        let (plan, estimated_peak_bytes) = plan_build(live_count, &embedders, &self.index_build);
+        info!(
+            "Rebuilding {} HNSW indexes from {} fingerprints ({:?}, estimated peak {} MB)",
+            embedders.len(),
+            live_count,
+            plan,
+            estimated_peak_bytes / (1024 * 1024)
+        );
+        let (success_count, error_count) = match plan {
+            IndexBuildPlan::Concurrent => self.rebuild_concurrent(&embedders)?,
+            IndexBuildPlan::Sequential => self.rebuild_sequential(&embedders, live_count)?,
+        };
+
+        let elapsed = start.elapsed();
+
+        if error_count > 0 {
+            error!(
+                "FAIL FAST: Index rebuild failed with {} errors out of {} fingerprints in {:?}",
+                error_count, live_count, elapsed
+            );
+            return Err(TeleologicalStoreError::Internal(format!(
+                "Index rebuild failed: {} fingerprints could not be added to indexes",
+                error_count
+            )));
+        }
+
+        info!(
+            "Rebuilt HNSW indexes: {} fingerprints added to {} indexes in {:?} ({:?}, {} E2 vectors recomputed)",
+            success_count,
+            embedders.len(),
+            elapsed,
+            plan,
+            success_count
+        );
+        *self.last_index_build.write() = Some(IndexBuildSummary {
+            plan,
+            fingerprints: success_count,
+            spaces: embedders.len(),
+            estimated_peak_bytes,
+            elapsed,
+        });
+
+        Ok(())
+    }
+
+    /// Stage every space's vectors, then build the spaces concurrently.
+    ///
+    /// Returns (fingerprints indexed, errors).
+    fn rebuild_concurrent(
+        &self,
+        embedders: &[EmbedderIndex],
+    ) -> TeleologicalStoreResult<(usize, usize)> {
+        let mut staged: Vec<StagedSpace> = embedders
+            .iter()
+            .filter_map(|&embedder| self.index_registry.get(embedder))
+            .map(|index| (Arc::clone(index), Vec::new()))
+            .collect();
+
+        let mut fingerprints = 0;
+        let corrupted = self.for_each_live_fingerprint(|fp| {
+            for (index, items) in staged.iter_mut() {
+                let embedder = index.embedder();
+                let vector = Self::get_embedder_vector(&fp.semantic, embedder);
+                if Self::is_indexable(embedder, fp.id, vector) {
+                    items.push((fp.id, vector.to_vec()));
+                }
+            }
+            fingerprints += 1;
+        })?;
+
+        let failures = build_staged(staged, &self.index_build);
+        Ok((fingerprints, corrupted + failures.len()))
+    }
+
+    /// Insert fingerprints into all indexes one at a time, staging nothing.
+    ///
+    /// Returns (fingerprints indexed, errors).
+    fn rebuild_sequential(
+        &self,
+        embedders: &[EmbedderIndex],
+        live_count: usize,
+    ) -> TeleologicalStoreResult<(usize, usize)> {
+        let mut trackers: Vec<ProgressTracker<'_>> = embedders
+            .iter()
+            .map(|&embedder| ProgressTracker::new(embedder, live_count, &self.index_build))
+            .collect();
 
         let mut success_count = 0;
-        let mut error_count = 0;
-        let mut e2_recomputed = 0;
+        let mut failed = 0;
+        let corrupted = self.for_each_live_fingerprint(|fp| {
+            // Add to HNSW indexes — use unlocked variant since we hold write lock
+            match self.add_to_indexes_unlocked(&fp) {
+                Ok(()) => success_count += 1,
+                Err(e) => {
+                    error!(
+                        "FAIL FAST: Failed to add fingerprint {} to indexes during rebuild: {}",
+                        fp.id, e
+                    );
+                    // Continue to count all errors, but we'll fail at the end
+                    failed += 1;
+                }
+            }
+            for tracker in trackers.iter_mut() {
+                tracker.advance(1);
+            }
+        })?;
 
-        for item in iter {
+        for tracker in trackers.iter_mut() {
+            tracker.finish();
+        }
+        Ok((success_count, corrupted + failed))
+    }
+
+    /// Number of fingerprints in CF_FINGERPRINTS that are not soft-deleted.
+    fn count_live_fingerprints(&self) -> TeleologicalStoreResult<usize> {
+        use crate::teleological::schema::parse_fingerprint_key;
+
+        let cf = self.get_cf(CF_FINGERPRINTS)?;
+        let mut count = 0;
+        for item in self.db.iterator_cf(cf, rocksdb::IteratorMode::Start) {
+            let (key, _) = item.map_err(|e| {
+                TeleologicalStoreError::rocksdb_op("iterate", CF_FINGERPRINTS, None, e)
+            })?;
+            if !self.is_soft_deleted(&parse_fingerprint_key(&key)) {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Visit every live fingerprint, with E2 recomputed, for an index rebuild.
+    ///
+    /// Corrupted records are skipped with a warning; returns how many.
+    fn for_each_live_fingerprint(
+        &self,
+        mut visit: impl FnMut(TeleologicalFingerprint),
+    ) -> TeleologicalStoreResult<usize> {
+        use crate::teleological::schema::parse_fingerprint_key;
+        use crate::teleological::serialization::deserialize_teleological_fingerprint;
+        use context_graph_embeddings::models::custom::compute_decay_embedding;
+        use context_graph_embeddings::models::custom::DEFAULT_DECAY_RATES;
+
+        let cf = self.get_cf(CF_FINGERPRINTS)?;
+        let mut corrupted = 0;
+
+        for item in self.db.iterator_cf(cf, rocksdb::IteratorMode::Start) {
             let (key, value) = item.map_err(|e| {
                 error!("FAIL FAST: RocksDB iteration failed during index rebuild: {}", e);
                 TeleologicalStoreError::rocksdb_op("iterate", CF_FINGERPRINTS, None, e)
@@ -710,7 +1046,7 @@ impl RocksDbTeleologicalStore {
                         "Skipping corrupted fingerprint {} during index rebuild: {}",
                         id, e
                     );
-                    error_count += 1;
+                    corrupted += 1;
                     continue;
                 }
             };
@@ -720,53 +1056,19 @@ impl RocksDbTeleologicalStore {
             // computed with delta≈0 decay). Recomputing ensures the E2 HNSW index contains
             // distinct vectors that reflect actual creation timestamps.
             // This is a fast CPU computation (~1μs per fingerprint).
-            let recomputed_e2 =
+            fp.semantic.e2_temporal_recent =
                 compute_decay_embedding(fp.created_at, None, &DEFAULT_DECAY_RATES);
-            fp.semantic.e2_temporal_recent = recomputed_e2;
-            e2_recomputed += 1;
 
-            // Add to HNSW indexes — use unlocked variant since we hold write lock
-            match self.add_to_indexes_unlocked(&fp) {
-                Ok(()) => {
-                    success_count += 1;
-                }
-                Err(e) => {
-                    error!(
-                        "FAIL FAST: Failed to add fingerprint {} to indexes during rebuild: {}",
-                        id, e
-                    );
-                    error_count += 1;
-                    // Continue to count all errors, but we'll fail at the end
-                }
-            }
+            visit(fp);
         }
 
-        let elapsed = start.elapsed();
-
-        if error_count > 0 {
-            error!(
-                "FAIL FAST: Index rebuild failed with {} errors out of {} fingerprints in {:?}",
-                error_count, success_count + error_count, elapsed
-            );
-            return Err(TeleologicalStoreError::Internal(format!(
-                "Index rebuild failed: {} fingerprints could not be added to indexes",
-                error_count
-            )));
-        }
-
-        if success_count > 0 {
-            info!(
-                "Rebuilt HNSW indexes: {} fingerprints added to {} indexes in {:?} ({} E2 vectors recomputed)",
-                success_count,
-                self.index_registry.len(),
-                elapsed,
-                e2_recomputed
-            );
-        } else {
-            debug!("No fingerprints to rebuild indexes from (empty store)");
-        }
+        Ok(corrupted)
+    }
 
-        Ok(())
+    /// Outcome of the last HNSW rebuild from fingerprints, if one has run
+    /// since open (restores from persisted graphs do not count).
+    pub fn last_index_build(&self) -> Option<IndexBuildSummary> {
+        self.last_index_build.read().clone()
     }
 
     /// Rebuild E11 HNSW index from existing causal relationships in RocksDB.
@@ -893,6 +1195,8 @@ impl RocksDbTeleologicalStore {
     pub fn persist_hnsw_indexes(&self) -> TeleologicalStoreResult<()> {
         use crate::teleological::column_families::CF_HNSW_GRAPHS;
 
+        self.ensure_writable("persist_hnsw_indexes")?;
+
         let start = std::time::Instant::now();
         let cf = self.get_cf(CF_HNSW_GRAPHS)?;
 
@@ -954,7 +1258,7 @@ impl RocksDbTeleologicalStore {
 
     /// H1/M9 FIX: Check if any HNSW index needs compaction and rebuild if so.
     ///
-    /// Compaction is triggered when > 25% of vectors in any index are orphaned
+    /// Compaction is triggered when > 20% of vectors in any index are orphaned
     /// (removed from UUID maps but still consuming memory in usearch graph).
     /// This rebuilds ALL indexes from CF_FINGERPRINTS, eliminating all orphans.
     pub fn compact_hnsw_if_needed(&self) -> TeleologicalStoreResult<()> {
@@ -1099,6 +1403,156 @@ impl RocksDbTeleologicalStore {
 
         Ok(restored > 0)
     }
+
+    /// Write per-embedder HNSW snapshot files into `dir`.
+    ///
+    /// See [`SingleEmbedderSearch::save`] for the file format. Pair with
+    /// [`Self::warm_start_hnsw_indexes`] on the next open.
+    ///
+    /// [`SingleEmbedderSearch::save`]: crate::teleological::search::SingleEmbedderSearch::save
+    pub fn save_hnsw_snapshot(&self, dir: &Path) -> TeleologicalStoreResult<usize> {
+        use crate::teleological::search::SingleEmbedderSearch;
+
+        let start = std::time::Instant::now();
+        let written = SingleEmbedderSearch::new(Arc::clone(&self.index_registry))
+            .save(dir)
+            .map_err(|e| TeleologicalStoreError::IndexOperation {
+                index_name: "hnsw_snapshot".to_string(),
+                message: e.to_string(),
+            })?;
+        info!(
+            "Saved {} HNSW snapshot files to {} in {:?}",
+            written,
+            dir.display(),
+            start.elapsed()
+        );
+        Ok(written)
+    }
+
+    /// Restore HNSW indexes from snapshot files, then catch up incrementally.
+    ///
+    /// Fingerprints created or updated after the snapshot, or missing from
+    /// its E1 index, are re-inserted, and snapshot ids whose fingerprint was
+    /// deleted or soft-deleted since are removed. The second case covers a
+    /// fingerprint written to RocksDB but not yet indexed when the snapshot
+    /// was taken. This still scans CF_FINGERPRINTS but skips the HNSW inserts
+    /// that dominate a full rebuild.
+    ///
+    /// If any registered embedder has no snapshot file, all indexes are cleared
+    /// and rebuilt from CF_FINGERPRINTS (`from_snapshot == false`).
+    ///
+    /// # Errors
+    ///
+    /// FAIL FAST on dimension mismatch, checksum mismatch, or a malformed
+    /// snapshot. Partially loaded indexes are cleared before returning.
+    pub fn warm_start_hnsw_indexes(&self, dir: &Path) -> TeleologicalStoreResult<WarmStartStats> {
+        use std::collections::HashSet;
+
+        use crate::teleological::schema::parse_fingerprint_key;
+        use crate::teleological::search::SingleEmbedderSearch;
+        use crate::teleological::serialization::deserialize_teleological_fingerprint;
+
+        let start = std::time::Instant::now();
+
+        let info = SingleEmbedderSearch::new(Arc::clone(&self.index_registry))
+            .load(dir)
+            .map_err(|e| {
+                error!("FAIL FAST: HNSW snapshot load from {} failed: {}", dir.display(), e);
+                self.index_registry.clear_all();
+                TeleologicalStoreError::RestoreFailed {
+                    path: dir.display().to_string(),
+                    message: e.to_string(),
+                }
+            })?;
+
+        let snapshot_at = match info.snapshot_at {
+            Some(snapshot_at) if info.is_complete() => snapshot_at,
+            _ => {
+                info!(
+                    "HNSW snapshot in {} incomplete (missing {:?}) — rebuilding from fingerprints",
+                    dir.display(),
+                    info.missing
+                );
+                self.index_registry.clear_all();
+                self.rebuild_indexes_from_store()?;
+                return Ok(WarmStartStats {
+                    from_snapshot: false,
+                    elapsed_ms: start.elapsed().as_millis() as u64,
+                    ..Default::default()
+                });
+            }
+        };
+
+        // Block concurrent store/delete while catching up, as in a full rebuild
+        let _guard = self.compaction_lock.write();
+
+        let snapshot_ids: HashSet<Uuid> = self
+            .index_registry
+            .get(EmbedderIndex::E1Semantic)
+            .map(|index| index.ids().into_iter().collect())
+            .unwrap_or_default();
+        let cf = self.get_cf(CF_FINGERPRINTS)?;
+        let mut live: HashSet<Uuid> = HashSet::new();
+        let mut replayed = 0;
+
+        for item in self.db.iterator_cf(cf, rocksdb::IteratorMode::Start) {
+            let (key, value) = item.map_err(|e| {
+                TeleologicalStoreError::rocksdb_op("iterate", CF_FINGERPRINTS, None, e)
+            })?;
+            let id = parse_fingerprint_key(&key);
+            if self.is_soft_deleted(&id) {
+                continue;
+            }
+            live.insert(id);
+
+            let fp = match deserialize_teleological_fingerprint(&value) {
+                Ok(fp) => fp,
+                Err(e) => {
+                    warn!("Skipping corrupted fingerprint {} during HNSW warm start: {}", id, e);
+                    continue;
+                }
+            };
+            let changed = fp.last_updated.max(fp.created_at) >= snapshot_at;
+            if changed || !snapshot_ids.contains(&id) {
+                self.add_to_indexes_unlocked(&fp).map_err(|e| {
+                    TeleologicalStoreError::IndexOperation {
+                        index_name: "hnsw_warm_start".to_string(),
+                        message: format!("Failed to replay fingerprint {}: {}", id, e),
+                    }
+                })?;
+                replayed += 1;
+            }
+        }
+
+        let mut pruned: HashSet<Uuid> = HashSet::new();
+        for (_embedder, index) in self.index_registry.iter() {
+            for id in index.ids() {
+                if !live.contains(&id) {
+                    index.remove(id).map_err(|e| TeleologicalStoreError::IndexOperation {
+                        index_name: "hnsw_warm_start".to_string(),
+                        message: format!("Failed to prune {}: {}", id, e),
+                    })?;
+                    pruned.insert(id);
+                }
+            }
+        }
+
+        let stats = WarmStartStats {
+            from_snapshot: true,
+            replayed,
+            pruned: pruned.len(),
+            elapsed_ms: start.elapsed().as_millis() as u64,
+        };
+        info!(
+            "HNSW warm start from {} (snapshot {}): {} replayed, {} pruned in {}ms",
+            dir.display(),
+            snapshot_at,
+            stats.replayed,
+            stats.pruned,
+            stats.elapsed_ms
+        );
+        Ok(stats)
+    }
 }
 
 // ============================================================================
@@ -1114,6 +1568,9 @@ impl RocksDbTeleologicalStore {
     /// 3. `e1_matryoshka_128` - Truncated E1 embedding for Stage 2
     /// 4. `e13_splade_inverted` - Updates inverted index for Stage 1
     /// 5. `e12_late_interaction` - ColBERT token embeddings for Stage 5
+    /// 6. `content_hash_index` - content_hash -> ID secondary index
+    /// 7. `system` - `expires_at::{uuid}` marker when the fingerprint has a TTL
+    /// 8. `system` - `namespace::{uuid}` marker for non-default namespaces
     ///
     /// HIGH-6 FIX: `count_as_new` controls whether total_doc_count is incremented.
     ///   - `true` for new inserts (store_async, store_batch_async)
@@ -1201,14 +1658,46 @@ impl RocksDbTeleologicalStore {
             );
         }
 
+        // 6. Update content_hash secondary index (idempotent for updates)
+        self.add_to_content_hash_index(&mut batch, &id, &fp.content_hash)?;
+
+        // 7. Persist (or clear) the TTL expiry marker
+        let cf_system = self.get_cf(cf_names::SYSTEM)?;
+        let expiry_key = expires_at_key(&id);
+        let expires_at_millis = fp.expires_at.map(|t| t.timestamp_millis());
+        match expires_at_millis {
+            Some(millis) => batch.put_cf(cf_system, expiry_key.as_bytes(), millis.to_be_bytes()),
+            None => batch.delete_cf(cf_system, expiry_key.as_bytes()),
+        }
+
+        // 8. Persist (or clear) the namespace marker; default namespace has none
+        let ns_key = namespace_key(&id);
+        if fp.is_default_namespace() {
+            batch.delete_cf(cf_system, ns_key.as_bytes());
+        } else {
+            batch.put_cf(cf_system, ns_key.as_bytes(), fp.namespace.as_bytes());
+        }
+
         // Execute atomic batch write (still under lock)
-        self.db.write(batch).map_err(|e| {
-            error!("Failed to write fingerprint batch for {}: {}", id, e);
-            TeleologicalStoreError::rocksdb_op("write_batch", CF_FINGERPRINTS, Some(id), e)
-        })?;
+        self.batch_writer
+            .commit(batch, serialized.len(), Some(id))?;
 
         // Lock released here via drop(_index_guard)
 
+        match expires_at_millis {
+            Some(millis) => {
+                self.expiring.insert(id, millis);
+            }
+            None => {
+                self.expiring.remove(&id);
+            }
+        }
+        if fp.is_default_namespace() {
+            self.namespaces.remove(&id);
+        } else {
+            self.namespaces.insert(id, fp.namespace.clone());
+        }
+
         // Invalidate count cache; only increment doc count for genuinely new documents
         *self.fingerprint_count.write() = None;
         if count_as_new {
@@ -1235,6 +1724,19 @@ impl RocksDbTeleologicalStore {
     pub(crate) fn is_soft_deleted(&self, id: &Uuid) -> bool {
         self.soft_deleted.contains_key(id)
     }
+
+    /// Highest commit sequence visible to search.
+    ///
+    /// Pass it to `TeleologicalSearchOptions::as_of` to repeat a search
+    /// against the same set of fingerprints. Resets to 0 on open.
+    pub fn commit_sequence(&self) -> u64 {
+        self.commit_log.current()
+    }
+
+    /// Check if an ID has passed its TTL deadline.
+    pub(crate) fn is_expired(&self, id: &Uuid) -> bool {
+        is_expired_in(&self.expiring, id, chrono::Utc::now().timestamp_millis())
+    }
 }
 
 // ============================================================================
@@ -1247,6 +1749,43 @@ impl RocksDbTeleologicalStore {
         &self.path
     }
 
+    /// How the store was opened.
+    pub fn access_mode(&self) -> StoreAccessMode {
+        self.access_mode
+    }
+
+    /// Fail with `ReadOnlyAccess` unless the store was opened `Exclusive`.
+    pub(crate) fn ensure_writable(&self, operation: &'static str) -> TeleologicalStoreResult<()> {
+        if self.access_mode.is_writable() {
+            Ok(())
+        } else {
+            Err(TeleologicalStoreError::ReadOnlyAccess {
+                operation,
+                mode: self.access_mode,
+            })
+        }
+    }
+
+    /// Replay the primary's new MANIFEST and WAL entries into this
+    /// secondary instance.
+    ///
+    /// Only the RocksDB view is refreshed; in-memory HNSW indexes keep the
+    /// state they were built with at open. Errors outside
+    /// `StoreAccessMode::SecondaryCatchUp`.
+    pub fn catch_up_with_primary(&self) -> TeleologicalStoreResult<()> {
+        if self.access_mode != StoreAccessMode::SecondaryCatchUp {
+            return Err(TeleologicalStoreError::Internal(format!(
+                "catch_up_with_primary requires SecondaryCatchUp mode, store is {:?}",
+                self.access_mode
+            )));
+        }
+        self.db.try_catch_up_with_primary().map_err(|e| {
+            TeleologicalStoreError::rocksdb_op("catch_up_with_primary", CF_FINGERPRINTS, None, e)
+        })?;
+        self.invalidate_count_cache();
+        Ok(())
+    }
+
     /// Get a reference to the underlying RocksDB instance FOR TESTING/DIAGNOSTICS ONLY.
     #[doc(hidden)]
     pub fn db(&self) -> &DB {
@@ -1271,6 +1810,35 @@ impl RocksDbTeleologicalStore {
         Arc::clone(&self.db)
     }
 
+    /// SHA-256 over every key and value of every column family, in key order.
+    ///
+    /// Equal digests mean byte-identical stored data. Reads the whole
+    /// database, so it is meant for tests and diagnostics such as checking
+    /// that a dry run wrote nothing.
+    pub fn state_digest(&self) -> TeleologicalStoreResult<String> {
+        let mut names = DB::list_cf(&Options::default(), &self.path)
+            .map_err(|e| TeleologicalStoreError::rocksdb_op("list_cf", CF_FINGERPRINTS, None, e))?;
+        names.sort();
+
+        let mut hasher = Sha256::new();
+        for name in &names {
+            let Some(cf) = self.db.cf_handle(name) else {
+                continue;
+            };
+            hasher.update(name.as_bytes());
+            for item in self.db.iterator_cf(cf, rocksdb::IteratorMode::Start) {
+                let (key, value) = item.map_err(|e| {
+                    TeleologicalStoreError::Internal(format!("Failed to iterate {}: {}", name, e))
+                })?;
+                hasher.update((key.len() as u64).to_le_bytes());
+                hasher.update(&key);
+                hasher.update((value.len() as u64).to_le_bytes());
+                hasher.update(&value);
+            }
+        }
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+
     /// Invalidate the fingerprint count cache.
     ///
     /// Useful for benchmarking to force re-counting on each call.
@@ -1280,7 +1848,7 @@ impl RocksDbTeleologicalStore {
         *self.fingerprint_count.write() = None;
     }
 
-    /// Health check: verify ALL 51 column families are accessible.
+    /// Health check: verify ALL 57 column families are accessible.
     pub fn health_check(&self) -> TeleologicalStoreResult<()> {
         let all_cf_arrays: &[&[&str]] = &[
             cf_names::ALL,
@@ -118,6 +118,26 @@ async fn test_store_and_retrieve() {
     assert_eq!(retrieved_fp.id, id);
 }
 
+#[tokio::test]
+async fn test_state_digest_tracks_writes() {
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    let empty = store.state_digest().unwrap();
+    assert_eq!(
+        store.state_digest().unwrap(),
+        empty,
+        "reads must not change the digest"
+    );
+
+    let fp = create_test_fingerprint();
+    store.retrieve(fp.id).await.unwrap();
+    assert_eq!(store.state_digest().unwrap(), empty);
+
+    store.store(fp).await.unwrap();
+    assert_ne!(store.state_digest().unwrap(), empty);
+}
+
 #[tokio::test]
 async fn test_physical_persistence() {
     let tmp = TempDir::new().unwrap();
@@ -226,6 +246,594 @@ async fn test_backend_type() {
     );
 }
 
+#[tokio::test]
+async fn test_retrieve_batch_interleaved_and_duplicates() {
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    let a = store.store(create_test_fingerprint_with_seed(11)).await.unwrap();
+    let b = store.store(create_test_fingerprint_with_seed(12)).await.unwrap();
+    let deleted = store.store(create_test_fingerprint_with_seed(13)).await.unwrap();
+    store.delete(deleted, true).await.unwrap();
+    let missing = Uuid::new_v4();
+
+    let ids = [missing, a, b, missing, a, deleted];
+    let results = store.retrieve_batch(&ids).await.unwrap();
+    let got: Vec<Option<Uuid>> = results.iter().map(|r| r.as_ref().map(|fp| fp.id)).collect();
+    assert_eq!(got, vec![None, Some(a), Some(b), None, Some(a), None]);
+
+    assert!(store.retrieve_batch(&[]).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_list_fingerprints_after_pages_in_id_order() {
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    let mut ids = Vec::new();
+    for seed in 20..27 {
+        ids.push(store.store(create_test_fingerprint_with_seed(seed)).await.unwrap());
+    }
+    let deleted = ids.remove(3);
+    store.delete(deleted, true).await.unwrap();
+    ids.sort();
+
+    let mut paged = Vec::new();
+    let mut after = None;
+    loop {
+        let page = store.list_fingerprints_after(after, 2).await.unwrap();
+        if page.is_empty() {
+            break;
+        }
+        assert!(page.len() <= 2);
+        after = page.last().map(|fp| fp.id);
+        paged.extend(page.into_iter().map(|fp| fp.id));
+    }
+    assert_eq!(paged, ids, "Every live fingerprint exactly once, ascending");
+}
+
+// ============================================================================
+// Partial Retrieval Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_retrieve_partial_reads_5x_fewer_bytes() {
+    use context_graph_core::teleological::EmbedderMask;
+
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    let fp = create_test_fingerprint();
+    let id = store.store(fp.clone()).await.unwrap();
+
+    let full_bytes = store.get_fingerprint_raw(id).unwrap().unwrap().len();
+    let partial = store
+        .retrieve_partial(id, EmbedderMask::new())
+        .await
+        .unwrap()
+        .expect("partial should exist");
+
+    assert!(
+        partial.bytes_read * 5 <= full_bytes,
+        "E1_128 + topic profile read {} bytes, full record is {} bytes",
+        partial.bytes_read,
+        full_bytes
+    );
+    assert_eq!(
+        partial.e1_matryoshka_128.as_deref(),
+        Some(&fp.semantic.e1_semantic[..128])
+    );
+    assert!(partial.loaded_mask().is_empty());
+}
+
+#[tokio::test]
+async fn test_retrieve_partial_slices_identical_to_full() {
+    use context_graph_core::teleological::{Embedder, EmbedderMask};
+    use context_graph_core::types::fingerprint::EmbeddingSlice;
+
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    let id = store.store(create_test_fingerprint_with_seed(7)).await.unwrap();
+    let full = store.retrieve(id).await.unwrap().unwrap();
+
+    // E12 alone is served from CF_E12_LATE_INTERACTION, the rest from CF_FINGERPRINTS
+    for mask in [
+        EmbedderMask::from_slice(&[Embedder::LateInteraction]),
+        EmbedderMask::from_slice(&[Embedder::Semantic, Embedder::Causal, Embedder::KeywordSplade]),
+    ] {
+        let partial = store.retrieve_partial(id, mask).await.unwrap().unwrap();
+        assert_eq!(partial.loaded_mask(), mask);
+        for embedder in Embedder::all() {
+            let idx = embedder.index();
+            match (partial.get_embedding(idx), full.semantic.get_embedding(idx)) {
+                (None, _) => assert!(!mask.contains(embedder)),
+                (Some(EmbeddingSlice::Dense(p)), Some(EmbeddingSlice::Dense(f))) => {
+                    assert_eq!(p, f)
+                }
+                (Some(EmbeddingSlice::Sparse(p)), Some(EmbeddingSlice::Sparse(f))) => {
+                    assert_eq!(p, f)
+                }
+                (Some(EmbeddingSlice::TokenLevel(p)), Some(EmbeddingSlice::TokenLevel(f))) => {
+                    assert_eq!(p, f)
+                }
+                other => panic!("shape mismatch for {}: {:?}", embedder, other),
+            }
+        }
+    }
+
+    assert!(store
+        .retrieve_partial(Uuid::new_v4(), EmbedderMask::all())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+// ============================================================================
+// Content Hash Index Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_content_hash_index_store_and_hard_delete() {
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    let fp = create_test_fingerprint_with_seed(21);
+    let hash = fp.content_hash;
+    assert!(store.find_by_content_hash(&hash).await.unwrap().is_empty());
+
+    let a = store.store(fp).await.unwrap();
+    assert_eq!(store.find_by_content_hash(&hash).await.unwrap(), vec![a]);
+
+    // Second fingerprint with the same hash (allowDuplicates path)
+    let mut dup = create_test_fingerprint_with_seed(22);
+    dup.content_hash = hash;
+    let b = store.store(dup).await.unwrap();
+    let mut found = store.find_by_content_hash(&hash).await.unwrap();
+    found.sort();
+    let mut expected = vec![a, b];
+    expected.sort();
+    assert_eq!(found, expected);
+
+    // Soft delete hides the entry, hard delete removes it from the index
+    store.delete(a, true).await.unwrap();
+    assert_eq!(store.find_by_content_hash(&hash).await.unwrap(), vec![b]);
+    store.delete(b, false).await.unwrap();
+    assert!(store.find_by_content_hash(&hash).await.unwrap().is_empty());
+
+    let cf = store.get_cf(crate::teleological::CF_CONTENT_HASH_INDEX).unwrap();
+    let raw = store.db.get_cf(cf, hash).unwrap().unwrap();
+    let ids = crate::teleological::deserialize_memory_id_list(&raw).unwrap();
+    assert_eq!(ids, vec![a], "only the soft-deleted entry may remain until GC");
+
+    store.delete(a, false).await.unwrap();
+    assert!(store.db.get_cf(cf, hash).unwrap().is_none(), "empty entry must be deleted");
+}
+
+#[tokio::test]
+async fn test_content_hash_index_follows_update() {
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    let mut fp = create_test_fingerprint_with_seed(23);
+    let old_hash = fp.content_hash;
+    let id = store.store(fp.clone()).await.unwrap();
+
+    fp.content_hash = [0xAB; 32];
+    assert!(store.update(fp).await.unwrap());
+
+    assert!(store.find_by_content_hash(&old_hash).await.unwrap().is_empty());
+    assert_eq!(store.find_by_content_hash(&[0xAB; 32]).await.unwrap(), vec![id]);
+}
+
+// ============================================================================
+// E13 SPLADE Inverted Index Maintenance Tests
+// ============================================================================
+
+const SHARED_TERM: u16 = 42;
+
+fn create_fingerprint_with_shared_term(seed: u64) -> TeleologicalFingerprint {
+    let mut fp = create_test_fingerprint_with_seed(seed);
+    fp.semantic.e13_splade = SparseVector {
+        indices: vec![SHARED_TERM, 1000 + seed as u16],
+        values: vec![1.0, 0.5],
+    };
+    fp
+}
+
+fn read_splade_posting_list(store: &RocksDbTeleologicalStore, term_id: u16) -> Vec<Uuid> {
+    use crate::teleological::{deserialize_memory_id_list, e13_splade_inverted_key, CF_E13_SPLADE_INVERTED};
+
+    let cf = store.get_cf(CF_E13_SPLADE_INVERTED).unwrap();
+    match store.db.get_cf(cf, e13_splade_inverted_key(term_id)).unwrap() {
+        Some(data) => deserialize_memory_id_list(&data).unwrap(),
+        None => Vec::new(),
+    }
+}
+
+#[tokio::test]
+async fn test_splade_posting_list_pruned_on_delete() {
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    let a = store.store(create_fingerprint_with_shared_term(1)).await.unwrap();
+    let b = store.store(create_fingerprint_with_shared_term(2)).await.unwrap();
+    let c = store.store(create_fingerprint_with_shared_term(3)).await.unwrap();
+    assert_eq!(read_splade_posting_list(&store, SHARED_TERM).len(), 3);
+
+    assert!(store.delete(b, false).await.unwrap());
+
+    let mut expected = vec![a, c];
+    expected.sort();
+    assert_eq!(read_splade_posting_list(&store, SHARED_TERM), expected);
+    assert!(read_splade_posting_list(&store, 1002).is_empty());
+
+    let query = SparseVector {
+        indices: vec![SHARED_TERM],
+        values: vec![1.0],
+    };
+    let hits: Vec<Uuid> = store
+        .search_sparse(&query, 10)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    assert!(!hits.contains(&b), "deleted id must not surface in SPLADE search");
+    assert!(hits.contains(&a) && hits.contains(&c));
+}
+
+#[tokio::test]
+async fn test_rebuild_splade_index_repairs_drift() {
+    use crate::teleological::{e13_splade_inverted_key, serialize_memory_id_list, CF_E13_SPLADE_INVERTED};
+
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    let a = store.store(create_fingerprint_with_shared_term(1)).await.unwrap();
+    let b = store.store(create_fingerprint_with_shared_term(2)).await.unwrap();
+
+    // Simulate drift: dangling id + duplicates on the shared term, orphan term
+    let ghost = Uuid::new_v4();
+    let cf = store.get_cf(CF_E13_SPLADE_INVERTED).unwrap();
+    store
+        .db
+        .put_cf(cf, e13_splade_inverted_key(SHARED_TERM), serialize_memory_id_list(&[a, ghost, a]))
+        .unwrap();
+    store
+        .db
+        .put_cf(cf, e13_splade_inverted_key(9999), serialize_memory_id_list(&[ghost]))
+        .unwrap();
+
+    let stats = store.rebuild_splade_index().unwrap();
+    assert_eq!(stats.fingerprints_scanned, 2);
+    assert_eq!(stats.corrupted_skipped, 0);
+    assert_eq!(stats.terms_written, 3); // shared + one unique term each
+    assert_eq!(stats.postings_written, 4);
+    assert_eq!(stats.stale_terms_removed, 1);
+
+    let mut expected = vec![a, b];
+    expected.sort();
+    assert_eq!(read_splade_posting_list(&store, SHARED_TERM), expected);
+    assert!(read_splade_posting_list(&store, 9999).is_empty());
+}
+
+// ============================================================================
+// TTL Expiry Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_expired_fingerprint_hidden_then_purged() {
+    use chrono::{Duration, Utc};
+    use context_graph_core::traits::TeleologicalSearchOptions;
+
+    use crate::column_families::cf_names;
+    use crate::teleological::{
+        e1_matryoshka_128_key, fingerprint_key, CF_CONTENT_HASH_INDEX, CF_E1_MATRYOSHKA_128,
+        CF_FINGERPRINTS,
+    };
+
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    let live = store.store(create_test_fingerprint_with_seed(31)).await.unwrap();
+    let expired_fp = create_fingerprint_with_shared_term(32)
+        .with_expires_at(Utc::now() - Duration::seconds(1));
+    let hash = expired_fp.content_hash;
+    let query = expired_fp.semantic.clone();
+    let expired = store.store(expired_fp).await.unwrap();
+
+    // Hidden immediately, before any purge
+    assert!(store.retrieve(expired).await.unwrap().is_none());
+    assert!(store.retrieve(live).await.unwrap().is_some());
+    let batch = store.retrieve_batch(&[expired, live]).await.unwrap();
+    assert!(batch[0].is_none() && batch[1].is_some());
+    assert!(store.find_by_content_hash(&hash).await.unwrap().is_empty());
+
+    let mut options = TeleologicalSearchOptions::default();
+    options.top_k = 10;
+    options.min_similarity = 0.0;
+    let results = store.search_semantic(&query, options).await.unwrap();
+    assert!(results.iter().all(|r| r.fingerprint.id != expired));
+    assert!(results.iter().any(|r| r.fingerprint.id == live));
+
+    let sparse_query = SparseVector {
+        indices: vec![SHARED_TERM],
+        values: vec![1.0],
+    };
+    let hits = store.search_sparse(&sparse_query, 10).await.unwrap();
+    assert!(hits.iter().all(|(id, _)| *id != expired));
+
+    // Nothing is due before the deadline
+    assert_eq!(store.purge_expired(Utc::now() - Duration::hours(1)).await.unwrap(), 0);
+    assert_eq!(store.purge_expired(Utc::now()).await.unwrap(), 1);
+
+    let fp_cf = store.get_cf(CF_FINGERPRINTS).unwrap();
+    assert!(store.db.get_cf(fp_cf, fingerprint_key(&expired)).unwrap().is_none());
+    let mat_cf = store.get_cf(CF_E1_MATRYOSHKA_128).unwrap();
+    assert!(store.db.get_cf(mat_cf, e1_matryoshka_128_key(&expired)).unwrap().is_none());
+    let hash_cf = store.get_cf(CF_CONTENT_HASH_INDEX).unwrap();
+    assert!(store.db.get_cf(hash_cf, hash).unwrap().is_none());
+    let sys_cf = store.get_cf(cf_names::SYSTEM).unwrap();
+    let marker = super::crud::expires_at_key(&expired);
+    assert!(store.db.get_cf(sys_cf, marker.as_bytes()).unwrap().is_none());
+    assert!(read_splade_posting_list(&store, SHARED_TERM).is_empty());
+
+    assert!(store.retrieve(live).await.unwrap().is_some());
+    assert_eq!(store.purge_expired(Utc::now()).await.unwrap(), 0);
+}
+
+// ============================================================================
+// Namespace Isolation Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_namespace_searches_never_cross_over() {
+    use context_graph_core::traits::{SearchStrategy, TeleologicalSearchOptions};
+
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().to_path_buf();
+
+    let mut ids_a = Vec::new();
+    let mut ids_b = Vec::new();
+    let query = create_test_fingerprint_with_seed(40).semantic;
+    {
+        let store = create_initialized_store(&path);
+        for seed in 40..44 {
+            let fp = create_test_fingerprint_with_seed(seed).with_namespace("project-a");
+            ids_a.push(store.store(fp).await.unwrap());
+            // Near-identical vectors in the other namespace rank just as high
+            let fp = create_test_fingerprint_with_seed(seed).with_namespace("project-b");
+            ids_b.push(store.store(fp).await.unwrap());
+        }
+        let legacy = store.store(create_test_fingerprint_with_seed(44)).await.unwrap();
+
+        for strategy in [SearchStrategy::E1Only, SearchStrategy::MultiSpace] {
+            for (ns, own, other) in [("project-a", &ids_a, &ids_b), ("project-b", &ids_b, &ids_a)] {
+                let options = TeleologicalSearchOptions::quick(3)
+                    .with_strategy(strategy)
+                    .with_namespace(ns);
+                let results = store.search_semantic(&query, options).await.unwrap();
+                assert_eq!(results.len(), 3, "{ns} must fill top_k from its own memories");
+                for r in &results {
+                    assert_eq!(r.fingerprint.namespace, ns);
+                    assert!(own.contains(&r.fingerprint.id));
+                    assert!(!other.contains(&r.fingerprint.id));
+                    assert_ne!(r.fingerprint.id, legacy);
+                }
+            }
+        }
+
+        let counts = store.count_by_namespace().await.unwrap();
+        assert_eq!(counts.get("project-a"), Some(&4));
+        assert_eq!(counts.get("project-b"), Some(&4));
+        assert_eq!(counts.get(TeleologicalFingerprint::DEFAULT_NAMESPACE), Some(&1));
+        store.flush().await.unwrap();
+    }
+
+    // Namespace markers survive reopen (HNSW fast path skips the fingerprint scan)
+    let store = create_initialized_store(&path);
+    let counts = store.count_by_namespace().await.unwrap();
+    assert_eq!(counts.get("project-a"), Some(&4));
+    assert_eq!(counts.get("project-b"), Some(&4));
+    let restored = store.retrieve(ids_b[0]).await.unwrap().unwrap();
+    assert_eq!(restored.namespace, "project-b");
+
+    assert!(store.delete(ids_b[0], false).await.unwrap());
+    let counts = store.count_by_namespace().await.unwrap();
+    assert_eq!(counts.get("project-b"), Some(&3));
+}
+
+#[tokio::test]
+async fn test_small_namespace_fills_top_k() {
+    use context_graph_core::traits::{SearchStrategy, TeleologicalSearchOptions};
+
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    // 40 default memories closest to the query, then 5 in a namespace holding
+    // under 1/4 of the store, least similar of all
+    let query = create_test_fingerprint_with_seed(60).semantic;
+    for seed in 60..100 {
+        store.store(create_test_fingerprint_with_seed(seed)).await.unwrap();
+    }
+    let mut small = Vec::new();
+    for seed in 100..105 {
+        let fp = create_test_fingerprint_with_seed(seed).with_namespace("small");
+        small.push(store.store(fp).await.unwrap());
+    }
+
+    for strategy in [SearchStrategy::E1Only, SearchStrategy::MultiSpace] {
+        let options = TeleologicalSearchOptions::quick(5)
+            .with_strategy(strategy)
+            .with_namespace("small");
+        let results = store.search_semantic(&query, options).await.unwrap();
+        assert_eq!(results.len(), 5, "{:?}: small namespace must fill top_k", strategy);
+        assert!(results.iter().all(|r| small.contains(&r.fingerprint.id)));
+    }
+}
+
+#[tokio::test]
+async fn test_metadata_filter_fills_top_k_from_selective_matches() {
+    use context_graph_core::traits::{MetadataFilter, SearchStrategy, TeleologicalSearchOptions, TimeWindow};
+    use context_graph_core::types::{SourceMetadata, SourceType};
+
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    let query = create_test_fingerprint_with_seed(60).semantic;
+    let mut md_ids = Vec::new();
+    for seed in 60..100 {
+        let id = store.store(create_test_fingerprint_with_seed(seed)).await.unwrap();
+        // Only the memories least similar to the query carry the wanted source type
+        if seed >= 97 {
+            store
+                .store_source_metadata(id, &SourceMetadata::md_file_chunk("notes.md", 0, 1))
+                .await
+                .unwrap();
+            md_ids.push(id);
+        }
+    }
+
+    let filter = MetadataFilter::default().with_source_types(vec![SourceType::MDFileChunk]);
+    let options = TeleologicalSearchOptions::quick(3)
+        .with_strategy(SearchStrategy::E1Only)
+        .with_metadata_filter(filter.clone());
+    let results = store.search_semantic(&query, options).await.unwrap();
+    assert_eq!(results.len(), 3, "filtered search must fill top_k");
+    assert!(results.iter().all(|r| md_ids.contains(&r.fingerprint.id)));
+
+    // A time window that excludes everything yields nothing
+    let filter = filter.with_created_within(TimeWindow {
+        start_ms: None,
+        end_ms: Some(0),
+    });
+    let options = TeleologicalSearchOptions::quick(3).with_metadata_filter(filter);
+    assert!(store.search_semantic(&query, options).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_time_range_returns_only_in_range_memories() {
+    use context_graph_core::traits::{SearchStrategy, TeleologicalSearchOptions};
+
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    // 40 memories spread over a year, one every 9 days (plus half a day)
+    let base = chrono::Utc::now();
+    let query = create_test_fingerprint_with_seed(60).semantic;
+    for seed in 60..100 {
+        let mut fp = create_test_fingerprint_with_seed(seed);
+        let age_hours = (seed - 60) as i64 * 9 * 24 + 12;
+        fp.created_at = base - chrono::Duration::hours(age_hours);
+        store.store(fp).await.unwrap();
+    }
+
+    // [base - 120d, base - 90d) holds the memories aged 90.5 to 117.5 days
+    let (start, end) = (base - chrono::Duration::days(120), base - chrono::Duration::days(90));
+    for strategy in [SearchStrategy::E1Only, SearchStrategy::MultiSpace] {
+        let options = TeleologicalSearchOptions::quick(10)
+            .with_strategy(strategy)
+            .with_time_range(start, end);
+        let exclusions = options.time_filter_exclusions.clone();
+        let results = store.search_semantic(&query, options).await.unwrap();
+
+        assert_eq!(results.len(), 4, "{:?}: every in-range memory", strategy);
+        for r in &results {
+            assert!(
+                r.fingerprint.created_at >= start && r.fingerprint.created_at < end,
+                "{:?} returned {} outside the range",
+                strategy,
+                r.fingerprint.created_at
+            );
+        }
+        // The memories nearest the query are the newest, all out of range
+        assert!(exclusions.count() > 0, "{:?}: exclusions counted", strategy);
+    }
+}
+
+#[tokio::test]
+async fn test_recency_weight_prefers_newer_of_equal_hits() {
+    use context_graph_core::traits::{SearchStrategy, TeleologicalSearchOptions};
+
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    // Same embeddings, different hashes: equal similarity to any query
+    let now = chrono::Utc::now();
+    let mut older = create_test_fingerprint_with_seed(70);
+    older.created_at = now - chrono::Duration::days(60);
+    let mut newer = create_test_fingerprint_with_seed(70);
+    newer.content_hash = [9u8; 32];
+    newer.created_at = now - chrono::Duration::days(1);
+    let (older_id, newer_id) = (older.id, newer.id);
+    store.store(older).await.unwrap();
+    store.store(newer).await.unwrap();
+
+    let query = create_test_fingerprint_with_seed(70).semantic;
+    let plain = TeleologicalSearchOptions::quick(2).with_strategy(SearchStrategy::E1Only);
+    let results = store.search_semantic(&query, plain.clone()).await.unwrap();
+    assert_eq!(results.len(), 2);
+    assert!((results[0].similarity - results[1].similarity).abs() < 1e-6);
+
+    let weighted = plain.with_recency_weight(0.5, 7 * 86400);
+    let results = store.search_semantic(&query, weighted).await.unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].fingerprint.id, newer_id);
+    assert_eq!(results[1].fingerprint.id, older_id);
+    assert!(results[0].similarity > results[1].similarity);
+}
+
+// ============================================================================
+// HNSW Snapshot Warm Start Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_warm_start_replays_new_and_prunes_deleted() {
+    use crate::teleological::indexes::{EmbedderIndex, EmbedderIndexOps};
+
+    let tmp = TempDir::new().unwrap();
+    let snapshot_dir = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    let kept = store.store(create_test_fingerprint_with_seed(1)).await.unwrap();
+    let deleted = store.store(create_test_fingerprint_with_seed(2)).await.unwrap();
+    store.save_hnsw_snapshot(snapshot_dir.path()).unwrap();
+
+    let added = store.store(create_test_fingerprint_with_seed(3)).await.unwrap();
+    store.delete(deleted, false).await.unwrap();
+
+    // Simulate a restart: indexes start empty
+    store.index_registry.clear_all();
+    let stats = store.warm_start_hnsw_indexes(snapshot_dir.path()).unwrap();
+
+    assert!(stats.from_snapshot);
+    assert!(stats.replayed >= 1, "fingerprint stored after snapshot must be replayed");
+    assert_eq!(stats.pruned, 1);
+    let e1 = store.index_registry.get(EmbedderIndex::E1Semantic).unwrap();
+    assert!(e1.contains(kept));
+    assert!(e1.contains(added));
+    assert!(!e1.contains(deleted));
+    assert_eq!(e1.len(), 2);
+}
+
+#[tokio::test]
+async fn test_warm_start_without_snapshot_rebuilds() {
+    use crate::teleological::indexes::{EmbedderIndex, EmbedderIndexOps};
+
+    let tmp = TempDir::new().unwrap();
+    let empty_dir = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+    let id = store.store(create_test_fingerprint()).await.unwrap();
+
+    let stats = store.warm_start_hnsw_indexes(empty_dir.path()).unwrap();
+
+    assert!(!stats.from_snapshot);
+    let e1 = store.index_registry.get(EmbedderIndex::E1Semantic).unwrap();
+    assert!(e1.contains(id));
+    assert_eq!(e1.len(), 1);
+}
+
 // ============================================================================
 // Corruption Detection Tests - REAL data, NO mocks (TASK-STORAGE-001)
 // ============================================================================
@@ -1162,6 +1770,105 @@ async fn test_provenance_merge_history_roundtrip() {
     println!("\n=== FSV: PASSED - Merge History Roundtrip ===\n");
 }
 
+#[tokio::test]
+async fn test_provenance_lineage_roundtrip() {
+    use context_graph_core::types::audit::{LineageOperation, LineageRecord};
+
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    let child = Uuid::new_v4();
+    let (a, b, d) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    let merge = LineageRecord::new(child, vec![a, b], LineageOperation::Merge, 0.93);
+    std::thread::sleep(std::time::Duration::from_millis(1));
+    let consolidation = LineageRecord::new(child, vec![d], LineageOperation::Consolidation, 0.97);
+    store.append_lineage_record(&consolidation).unwrap();
+    store.append_lineage_record(&merge).unwrap();
+    // Unrelated child must not leak into the prefix scan
+    store
+        .append_lineage_record(&LineageRecord::new(
+            Uuid::new_v4(),
+            vec![child],
+            LineageOperation::Merge,
+            0.9,
+        ))
+        .unwrap();
+
+    let records = store.get_lineage_records(child).unwrap();
+    assert_eq!(records, vec![merge, consolidation], "Oldest first");
+    assert!(store.get_lineage_records(a).unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_tool_audit_log_query_and_prune() {
+    use chrono::{Duration, Utc};
+    use context_graph_core::types::audit::{ToolAuditOutcome, ToolAuditQuery, ToolAuditRecord};
+
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+
+    let memory = Uuid::new_v4();
+    let record = |tool: &str, session: &str, ids: Vec<Uuid>, age_days: i64| {
+        let mut r = ToolAuditRecord::new(
+            tool,
+            Some(session.to_string()),
+            serde_json::json!({}),
+            ids,
+            ToolAuditOutcome::Success,
+            1,
+        );
+        r.timestamp = Utc::now() - Duration::days(age_days);
+        r
+    };
+    let old = record("store_memory", "s1", vec![memory], 40);
+    let stored = record("store_memory", "s1", vec![memory], 2);
+    let other = record("store_memory", "s2", vec![Uuid::new_v4()], 1);
+    let forgot = record("forget_concept", "s1", vec![memory], 0);
+    for r in [&old, &stored, &other, &forgot] {
+        store.append_tool_audit_record(r).unwrap();
+    }
+
+    // Newest first, filtered by memory, paged
+    let by_memory = ToolAuditQuery {
+        memory_id: Some(memory),
+        limit: 2,
+        ..Default::default()
+    };
+    let page = store.query_tool_audit_log(&by_memory).unwrap();
+    assert_eq!(page.records, vec![forgot.clone(), stored.clone()]);
+    assert!(page.has_more);
+    let next = store
+        .query_tool_audit_log(&ToolAuditQuery { offset: 2, ..by_memory.clone() })
+        .unwrap();
+    assert_eq!(next.records, vec![old.clone()]);
+    assert!(!next.has_more);
+
+    // Time range and session filters
+    let window = ToolAuditQuery {
+        from: Some(Utc::now() - Duration::days(3)),
+        to: Some(Utc::now() - Duration::hours(12)),
+        ..Default::default()
+    };
+    let page = store.query_tool_audit_log(&window).unwrap();
+    assert_eq!(page.records, vec![other.clone(), stored.clone()]);
+    let page = store
+        .query_tool_audit_log(&ToolAuditQuery {
+            session_id: Some("s2".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(page.records, vec![other]);
+
+    // Retention removes only records past the cutoff
+    let removed = store
+        .prune_tool_audit_log(Utc::now() - Duration::days(30))
+        .unwrap();
+    assert_eq!(removed, 1);
+    let page = store.query_tool_audit_log(&by_memory).unwrap();
+    assert_eq!(page.records, vec![forgot, stored]);
+    assert!(!page.has_more);
+}
+
 #[tokio::test]
 async fn test_provenance_importance_history_roundtrip() {
     use chrono::Utc;
@@ -1245,6 +1952,7 @@ async fn test_provenance_embedding_version_roundtrip() {
         embedder_versions: versions.clone(),
         e7_model_version: Some("qodo-embed-1-1.5b".to_string()),
         computation_time_ms: Some(42),
+        deterministic: false,
     };
 
     store.store_embedding_version(&rec).unwrap();
@@ -1276,6 +1984,7 @@ async fn test_provenance_embedding_version_roundtrip() {
         embedder_versions: updated_versions.clone(),
         e7_model_version: Some("qodo-embed-1-2.0b".to_string()),
         computation_time_ms: Some(55),
+        deterministic: true,
     };
     store.store_embedding_version(&rec2).unwrap();
 
@@ -1285,6 +1994,7 @@ async fn test_provenance_embedding_version_roundtrip() {
         Some(&"all-MiniLM-L6-v2.2".to_string())
     );
     assert_eq!(updated.computation_time_ms, Some(55));
+    assert!(updated.deterministic);
 
     // EDGE CASE: Non-existent fingerprint
     let none = store.get_embedding_version(Uuid::new_v4()).unwrap();
@@ -1304,16 +2014,18 @@ async fn test_provenance_all_cfs_physically_exist() {
     let tmp = TempDir::new().unwrap();
     let store = create_initialized_store(tmp.path());
 
-    // VERIFY: All 8 provenance column families are accessible
+    // VERIFY: All 10 provenance column families are accessible
     let provenance_cfs = [
         CF_AUDIT_LOG,
         CF_AUDIT_BY_TARGET,
         CF_MERGE_HISTORY,
+        CF_MEMORY_LINEAGE,
         CF_IMPORTANCE_HISTORY,
         CF_TOOL_CALL_INDEX,
         CF_ENTITY_PROVENANCE,
         CF_CONSOLIDATION_RECOMMENDATIONS,
         CF_EMBEDDING_REGISTRY,
+        CF_TOOL_AUDIT_LOG,
     ];
 
     for cf_name in &provenance_cfs {
@@ -1327,7 +2039,112 @@ async fn test_provenance_all_cfs_physically_exist() {
         println!("  ✓ CF '{}' exists and is accessible", cf_name);
     }
 
-    println!("\n=== FSV: PASSED - All 8 Provenance CFs Physically Exist ===\n");
+    println!("\n=== FSV: PASSED - All 10 Provenance CFs Physically Exist ===\n");
+}
+
+// ============================================================================
+// Topic Record Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_topic_records_survive_drift_and_follow_deletes() {
+    use std::collections::HashMap;
+
+    use chrono::Utc;
+    use context_graph_core::clustering::{
+        reconcile_topic_records, Topic, TopicProfile, TopicRecord,
+    };
+
+    fn detected(members: &[Uuid]) -> TopicRecord {
+        let topic = Topic::new(
+            TopicProfile::new([0.5; 13]),
+            HashMap::new(),
+            members.to_vec(),
+        );
+        TopicRecord::from_topic(&topic, HashMap::new(), Utc::now())
+    }
+
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+    let mut ids = Vec::new();
+    for seed in 40..48 {
+        ids.push(
+            store
+                .store(create_test_fingerprint_with_seed(seed))
+                .await
+                .unwrap(),
+        );
+    }
+
+    // Pass 1: topics A and B
+    let first = reconcile_topic_records(
+        &store.list_topic_records().unwrap(),
+        vec![detected(&ids[0..3]), detected(&ids[3..5])],
+        Utc::now(),
+    );
+    store.apply_topic_reconciliation(&first).unwrap();
+    let topic_a = first.upserts[0].id;
+    let topic_b = first.upserts[1].id;
+
+    // Pass 2: A gains a member, B is unchanged, C is new
+    let second = reconcile_topic_records(
+        &store.list_topic_records().unwrap(),
+        vec![
+            detected(&ids[0..4]),
+            detected(&ids[3..5]),
+            detected(&ids[5..8]),
+        ],
+        Utc::now(),
+    );
+    assert_ne!(
+        second.upserts[0].id,
+        detected(&ids[0..4]).id,
+        "sanity: synthesis id changed"
+    );
+    store.apply_topic_reconciliation(&second).unwrap();
+
+    let stored: HashMap<Uuid, TopicRecord> = store
+        .list_topic_records()
+        .unwrap()
+        .into_iter()
+        .map(|r| (r.id, r))
+        .collect();
+    assert_eq!(stored.len(), 3);
+    assert_eq!(stored[&topic_a].member_ids, ids[0..4].to_vec());
+    assert_eq!(stored[&topic_a].runs_observed, 2);
+    assert_eq!(stored[&topic_b].stability_score, 1.0);
+    let topic_c = second.upserts[2].id;
+    assert!(topic_c != topic_a && topic_c != topic_b);
+
+    // Hard and soft deletes rewrite the member lists
+    store.delete(ids[1], false).await.unwrap();
+    store.delete(ids[3], true).await.unwrap();
+    let stored: HashMap<Uuid, TopicRecord> = store
+        .list_topic_records()
+        .unwrap()
+        .into_iter()
+        .map(|r| (r.id, r))
+        .collect();
+    assert_eq!(stored[&topic_a].member_ids, vec![ids[0], ids[2]]);
+    assert_eq!(stored[&topic_b].member_ids, vec![ids[4]]);
+
+    // A merge replaces its sources; a topic emptied by deletes disappears
+    let merged = Uuid::new_v4();
+    assert_eq!(
+        store
+            .replace_topic_members(&ids[5..7], Some(merged))
+            .unwrap(),
+        1
+    );
+    assert_eq!(store.replace_topic_members(&[ids[4]], None).unwrap(), 1);
+    let stored: HashMap<Uuid, TopicRecord> = store
+        .list_topic_records()
+        .unwrap()
+        .into_iter()
+        .map(|r| (r.id, r))
+        .collect();
+    assert_eq!(stored[&topic_c].member_ids, vec![ids[7], merged]);
+    assert!(!stored.contains_key(&topic_b));
 }
 
 // ============================================================================
@@ -1807,3 +2624,422 @@ async fn test_temporal_near_zero_vectors_accepted() {
 
     println!("[VERIFIED] Near-zero temporal vectors accepted by HNSW");
 }
+
+// ============================================================================
+// Integrity Check / Repair Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_check_integrity_finds_and_repairs_gaps() {
+    use crate::teleological::{
+        check_integrity, e1_matryoshka_128_key, fingerprint_key, repair, PostingRef,
+        RepairPolicy, SparseIndex, CF_E1_MATRYOSHKA_128, CF_FINGERPRINTS,
+    };
+
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+    let a = store.store(create_fingerprint_with_shared_term(1)).await.unwrap();
+    let b = store.store(create_fingerprint_with_shared_term(2)).await.unwrap();
+    assert!(check_integrity(&store.db).unwrap().is_clean());
+
+    // Lose a's Matryoshka entry and b's fingerprint (its postings now dangle)
+    let cf_matryoshka = store.get_cf(CF_E1_MATRYOSHKA_128).unwrap();
+    let original = store.db.get_cf(cf_matryoshka, e1_matryoshka_128_key(&a)).unwrap();
+    store.db.delete_cf(cf_matryoshka, e1_matryoshka_128_key(&a)).unwrap();
+    let cf_fp = store.get_cf(CF_FINGERPRINTS).unwrap();
+    store.db.delete_cf(cf_fp, fingerprint_key(&b)).unwrap();
+
+    let report = check_integrity(&store.db).unwrap();
+    assert_eq!(report.fingerprints_scanned, 1);
+    assert_eq!(report.missing_matryoshka, vec![a]);
+    assert_eq!(report.orphan_matryoshka, vec![b]);
+    assert!(report.missing_postings.is_empty());
+    let dangle = |term_id| PostingRef {
+        index: SparseIndex::E13Splade,
+        term_id,
+        fingerprint_id: b,
+    };
+    assert_eq!(report.dangling_postings, vec![dangle(SHARED_TERM), dangle(1002)]);
+
+    let outcome = repair(&store.db, RepairPolicy::All).unwrap();
+    assert_eq!(outcome.matryoshka_regenerated, 1);
+    assert_eq!(outcome.matryoshka_deleted, 1);
+    assert_eq!(outcome.postings_deleted, 2);
+    assert_eq!(outcome.unrepairable, 0);
+
+    assert!(check_integrity(&store.db).unwrap().is_clean());
+    assert_eq!(read_splade_posting_list(&store, SHARED_TERM), vec![a]);
+    assert!(read_splade_posting_list(&store, 1002).is_empty());
+    let restored = store.db.get_cf(cf_matryoshka, e1_matryoshka_128_key(&a)).unwrap();
+    assert!(original.is_some());
+    assert_eq!(restored, original);
+}
+
+#[tokio::test]
+async fn test_repair_policies_stay_in_their_lane() {
+    use crate::teleological::{
+        check_integrity, e13_splade_inverted_key, repair, serialize_memory_id_list,
+        RepairPolicy, CF_E13_SPLADE_INVERTED,
+    };
+
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+    let a = store.store(create_fingerprint_with_shared_term(1)).await.unwrap();
+    let b = store.store(create_fingerprint_with_shared_term(2)).await.unwrap();
+
+    // Shared term lost a, gained a ghost
+    let ghost = Uuid::new_v4();
+    let cf = store.get_cf(CF_E13_SPLADE_INVERTED).unwrap();
+    store
+        .db
+        .put_cf(cf, e13_splade_inverted_key(SHARED_TERM), serialize_memory_id_list(&[b, ghost]))
+        .unwrap();
+    let report = check_integrity(&store.db).unwrap();
+    assert_eq!(report.missing_postings.len(), 1);
+    assert_eq!(report.dangling_postings.len(), 1);
+
+    let outcome = repair(&store.db, RepairPolicy::Regenerate).unwrap();
+    assert_eq!(outcome.postings_regenerated, 1);
+    assert_eq!(outcome.postings_deleted, 0);
+    let report = check_integrity(&store.db).unwrap();
+    assert!(report.missing_postings.is_empty());
+    assert_eq!(report.dangling_postings.len(), 1);
+
+    let outcome = repair(&store.db, RepairPolicy::DeleteOrphans).unwrap();
+    assert_eq!(outcome.postings_deleted, 1);
+    assert_eq!(outcome.postings_regenerated, 0);
+    assert!(check_integrity(&store.db).unwrap().is_clean());
+
+    let mut expected = vec![a, b];
+    expected.sort();
+    assert_eq!(read_splade_posting_list(&store, SHARED_TERM), expected);
+}
+
+// ============================================================================
+// Atomic Write / Fsync Policy Tests
+// ============================================================================
+
+/// Which of the store's per-fingerprint CF entries exist for `id`:
+/// (fingerprint, Matryoshka, SPLADE posting for `SHARED_TERM`, E12 tokens).
+fn written_entries(store: &RocksDbTeleologicalStore, id: Uuid) -> [bool; 4] {
+    use crate::teleological::{
+        e12_late_interaction_key, e1_matryoshka_128_key, fingerprint_key,
+        CF_E12_LATE_INTERACTION, CF_E1_MATRYOSHKA_128, CF_FINGERPRINTS,
+    };
+
+    let exists = |cf_name: &str, key: [u8; 16]| {
+        let cf = store.get_cf(cf_name).unwrap();
+        store.db.get_cf(cf, key).unwrap().is_some()
+    };
+    [
+        exists(CF_FINGERPRINTS, fingerprint_key(&id)),
+        exists(CF_E1_MATRYOSHKA_128, e1_matryoshka_128_key(&id)),
+        read_splade_posting_list(store, SHARED_TERM).contains(&id),
+        exists(CF_E12_LATE_INTERACTION, e12_late_interaction_key(&id)),
+    ]
+}
+
+#[tokio::test]
+async fn test_store_is_all_or_nothing_across_crash() {
+    use std::sync::atomic::Ordering;
+
+    let tmp = TempDir::new().unwrap();
+    let fp = create_fingerprint_with_shared_term(1);
+    let id = fp.id;
+
+    // Crash before the batch reaches RocksDB: nothing may survive a reopen
+    {
+        let store = create_initialized_store(tmp.path());
+        store
+            .batch_writer
+            .fail_next_commit
+            .store(true, Ordering::SeqCst);
+        assert!(store.store(fp.clone()).await.is_err());
+    }
+    {
+        let store = create_initialized_store(tmp.path());
+        assert_eq!(written_entries(&store, id), [false; 4]);
+        store.store(fp).await.unwrap();
+    }
+
+    // Committed batch: every entry survives a reopen
+    let store = create_initialized_store(tmp.path());
+    assert_eq!(written_entries(&store, id), [true; 4]);
+}
+
+#[tokio::test]
+async fn test_write_metrics_follow_fsync_policy() {
+    for (policy, expected_syncs) in [
+        (FsyncPolicy::EveryWrite, 2),
+        (FsyncPolicy::EveryNms(0), 2),
+        (FsyncPolicy::OnShutdown, 0),
+    ] {
+        let tmp = TempDir::new().unwrap();
+        let config = TeleologicalStoreConfig {
+            fsync_policy: policy,
+            ..Default::default()
+        };
+        let store = RocksDbTeleologicalStore::open_with_config(tmp.path(), config).unwrap();
+        store.store(create_test_fingerprint_with_seed(1)).await.unwrap();
+        store.store(create_test_fingerprint_with_seed(2)).await.unwrap();
+
+        let metrics = store.write_metrics();
+        assert_eq!(metrics.batches_committed, 2, "{:?}", policy);
+        assert_eq!(metrics.wal_syncs, expected_syncs, "{:?}", policy);
+        // Fingerprint, Matryoshka, E12 and at least one posting per batch
+        assert!(metrics.max_batch_operations >= 4, "{:?}", policy);
+        assert!(metrics.write_amplification() > 1.0, "{:?}", policy);
+    }
+}
+
+// ============================================================================
+// Backup / Restore Tests
+// ============================================================================
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_backup_under_writes_restores_committed_fingerprints() {
+    use std::sync::{Arc, Mutex};
+
+    use crate::teleological::indexes::{EmbedderIndex, EmbedderIndexOps};
+
+    let data = TempDir::new().unwrap();
+    let backups = TempDir::new().unwrap();
+    let restored_dir = backups.path().join("restored");
+    let store = Arc::new(create_initialized_store(data.path()));
+    let committed = Arc::new(Mutex::new(Vec::new()));
+
+    let writer = {
+        let store = Arc::clone(&store);
+        let committed = Arc::clone(&committed);
+        tokio::spawn(async move {
+            for seed in 0..40 {
+                let id = store.store(create_test_fingerprint_with_seed(seed)).await.unwrap();
+                committed.lock().unwrap().push(id);
+            }
+        })
+    };
+    while committed.lock().unwrap().len() < 10 {
+        tokio::task::yield_now().await;
+    }
+    let before_backup = committed.lock().unwrap().clone();
+    let report = store
+        .create_backup(&backups.path().join("full"), &BackupOptions::default())
+        .unwrap();
+    writer.await.unwrap();
+
+    assert!(report.files > 0);
+    assert!(report.hnsw_files > 0);
+    assert_eq!(report.linked_files, 0);
+
+    let restore = restore_backup(&backups.path().join("full"), &restored_dir).unwrap();
+    assert!(restore.warm_start.from_snapshot);
+    assert!(restore.integrity.is_clean(), "{:?}", restore.integrity);
+    assert!(restore.integrity.fingerprints_scanned >= before_backup.len());
+
+    let restored = create_initialized_store(&restored_dir);
+    let e1 = restored.index_registry.get(EmbedderIndex::E1Semantic).unwrap();
+    for id in &before_backup {
+        assert!(restored.retrieve(*id).await.unwrap().is_some(), "{} lost", id);
+        assert!(e1.contains(*id), "{} missing from the restored E1 index", id);
+    }
+}
+
+#[tokio::test]
+async fn test_incremental_backup_links_unchanged_ssts() {
+    let data = TempDir::new().unwrap();
+    let backups = TempDir::new().unwrap();
+    let (full, incremental) = (backups.path().join("full"), backups.path().join("incr"));
+    let store = create_initialized_store(data.path());
+    store.store(create_test_fingerprint_with_seed(1)).await.unwrap();
+    store.create_backup(&full, &BackupOptions::default()).unwrap();
+
+    let added = store.store(create_test_fingerprint_with_seed(2)).await.unwrap();
+    let options = BackupOptions {
+        incremental_from: Some(full.clone()),
+    };
+    let report = store.create_backup(&incremental, &options).unwrap();
+    assert!(report.linked_files > 0, "unchanged SSTs must be linked");
+
+    let manifest = verify_backup(&incremental).unwrap();
+    assert_eq!(manifest.base.as_deref(), Some(full.display().to_string().as_str()));
+    assert_eq!(
+        manifest.files.iter().filter(|f| f.linked).count(),
+        report.linked_files
+    );
+
+    let restored_dir = backups.path().join("restored");
+    restore_backup(&incremental, &restored_dir).unwrap();
+    let restored = create_initialized_store(&restored_dir);
+    assert!(restored.retrieve(added).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_restore_rejects_tampered_backup() {
+    use std::io::Write;
+
+    let data = TempDir::new().unwrap();
+    let backups = TempDir::new().unwrap();
+    let backup = backups.path().join("full");
+    let store = create_initialized_store(data.path());
+    store.store(create_test_fingerprint_with_seed(1)).await.unwrap();
+    store.create_backup(&backup, &BackupOptions::default()).unwrap();
+
+    // A second backup into the same directory is refused
+    assert!(matches!(
+        store.create_backup(&backup, &BackupOptions::default()),
+        Err(TeleologicalStoreError::CheckpointFailed { .. })
+    ));
+
+    let manifest = BackupManifest::read(&backup).unwrap();
+    let sst = manifest
+        .files
+        .iter()
+        .find(|f| f.path.ends_with(".sst"))
+        .expect("checkpoint holds SST files");
+    std::fs::OpenOptions::new()
+        .append(true)
+        .open(backup.join(&sst.path))
+        .unwrap()
+        .write_all(b"x")
+        .unwrap();
+
+    let restored_dir = backups.path().join("restored");
+    match restore_backup(&backup, &restored_dir) {
+        Err(TeleologicalStoreError::RestoreFailed { message, .. }) => {
+            assert!(message.contains(&sst.path), "{}", message)
+        }
+        other => panic!("expected RestoreFailed, got {:?}", other.map(|r| r.files)),
+    }
+    assert!(!restored_dir.exists(), "nothing may be restored from a bad backup");
+}
+
+#[tokio::test]
+async fn test_warm_start_replays_fingerprints_missing_from_snapshot() {
+    use crate::teleological::indexes::{EmbedderIndex, EmbedderIndexOps};
+
+    let tmp = TempDir::new().unwrap();
+    let snapshot_dir = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+    let id = store.store(create_test_fingerprint_with_seed(1)).await.unwrap();
+
+    // Written to RocksDB but not yet indexed when the snapshot was taken
+    store.index_registry.clear_all();
+    store.save_hnsw_snapshot(snapshot_dir.path()).unwrap();
+    let stats = store.warm_start_hnsw_indexes(snapshot_dir.path()).unwrap();
+
+    assert!(stats.from_snapshot);
+    assert_eq!(stats.replayed, 1);
+    let e1 = store.index_registry.get(EmbedderIndex::E1Semantic).unwrap();
+    assert!(e1.contains(id));
+}
+
+// ============================================================================
+// Access Statistics Tests
+// ============================================================================
+
+#[test]
+fn test_access_record_roundtrip_and_seven_day_window() {
+    use super::access_stats::{AccessRecord, ACCESS_RECORD_LEN};
+
+    const DAY_MS: i64 = 24 * 3600 * 1000;
+    let day0 = 20_000 * DAY_MS;
+    let mut record = AccessRecord::default();
+    record.hit(day0);
+    record.hit(day0 + 1);
+    record.hit(day0 + 3 * DAY_MS);
+
+    let bytes = record.to_bytes();
+    assert_eq!(bytes.len(), ACCESS_RECORD_LEN);
+    let decoded = AccessRecord::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded, record);
+    assert!(AccessRecord::from_bytes(&bytes[1..]).is_none());
+
+    let stats = decoded.stats_at(day0 + 3 * DAY_MS);
+    assert_eq!((stats.hits, stats.hits_7d), (3, 3));
+    assert_eq!(stats.last_access.unwrap().timestamp_millis(), day0 + 3 * DAY_MS);
+    // Day 0 leaves the window on day 7, day 3 on day 10
+    assert_eq!(decoded.stats_at(day0 + 7 * DAY_MS).hits_7d, 1);
+    assert_eq!(decoded.stats_at(day0 + 10 * DAY_MS).hits_7d, 0);
+
+    // A hit on day 7 reuses day 0's slot
+    let mut later = decoded;
+    later.hit(day0 + 7 * DAY_MS);
+    let mut merged = AccessRecord::default();
+    merged.merge(&decoded);
+    merged.merge(&later);
+    let stats = merged.stats_at(day0 + 7 * DAY_MS);
+    assert_eq!(stats.hits, 7);
+    assert_eq!(stats.hits_7d, 3);
+}
+
+#[tokio::test]
+async fn test_hard_delete_clears_access_stats() {
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+    let id = store.store(create_test_fingerprint_with_seed(1)).await.unwrap();
+
+    store.retrieve(id).await.unwrap();
+    store.flush_access_stats().await.unwrap();
+    store.retrieve(id).await.unwrap();
+    assert_eq!(store.access_stats(&[id]).await.unwrap()[0].hits, 2);
+
+    assert!(store.delete(id, false).await.unwrap());
+    store.flush_access_stats().await.unwrap();
+    assert!(store.access_stats(&[id]).await.unwrap()[0].is_untouched());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_hard_delete_racing_flushes_never_repersists_stats() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    let tmp = TempDir::new().unwrap();
+    let store = Arc::new(create_initialized_store(tmp.path()));
+    let mut ids = Vec::new();
+    for seed in 0..20 {
+        let id = store.store(create_test_fingerprint_with_seed(seed)).await.unwrap();
+        store.retrieve(id).await.unwrap();
+        ids.push(id);
+    }
+
+    // Flush continuously while the memories are hard-deleted
+    let done = Arc::new(AtomicBool::new(false));
+    let flusher = {
+        let (store, done) = (Arc::clone(&store), Arc::clone(&done));
+        std::thread::spawn(move || {
+            while !done.load(Ordering::Relaxed) {
+                store.flush_access_stats_sync().unwrap();
+            }
+        })
+    };
+    for id in &ids {
+        assert!(store.delete(*id, false).await.unwrap());
+    }
+    done.store(true, Ordering::Relaxed);
+    flusher.join().unwrap();
+
+    store.flush_access_stats().await.unwrap();
+    let stats = store.access_stats(&ids).await.unwrap();
+    assert!(stats.iter().all(|s| s.is_untouched()));
+}
+
+#[tokio::test]
+async fn test_access_after_hard_delete_is_never_persisted() {
+    let tmp = TempDir::new().unwrap();
+    let store = create_initialized_store(tmp.path());
+    let fingerprint = create_test_fingerprint_with_seed(7);
+    let id = store.store(fingerprint.clone()).await.unwrap();
+    store.retrieve(id).await.unwrap();
+    assert!(store.delete(id, false).await.unwrap());
+
+    // A search that returned the memory before the delete records it late
+    store.access_tracker.record([id]);
+    store.flush_access_stats().await.unwrap();
+    assert!(store.access_stats(&[id]).await.unwrap()[0].is_untouched());
+
+    // Storing the ID again counts its accesses again
+    store.store(fingerprint).await.unwrap();
+    store.retrieve(id).await.unwrap();
+    store.flush_access_stats().await.unwrap();
+    assert_eq!(store.access_stats(&[id]).await.unwrap()[0].hits, 1);
+}
@@ -0,0 +1,195 @@
+//! Tool execution audit log operations (CF_TOOL_AUDIT_LOG).
+//!
+//! One JSON `ToolAuditRecord` per mutating MCP tool call, keyed
+//! `{timestamp_nanos_be}_{uuid_bytes}` so iteration is chronological.
+//! Records are never updated; the retention policy removes whole time
+//! prefixes with a single range delete.
+//!
+//! Queries walk the log backwards from the end of the requested time range
+//! and apply the tool/session/memory filters per record. The log holds one
+//! record per mutating call, so a filtered scan stays cheap compared to
+//! maintaining secondary indexes for every filter.
+//!
+//! # FAIL FAST Policy
+//!
+//! All RocksDB operations return detailed errors with operation name, CF, and key context.
+
+use chrono::{DateTime, Utc};
+use tracing::{debug, error, info};
+
+use context_graph_core::types::audit::{ToolAuditPage, ToolAuditQuery, ToolAuditRecord};
+
+use crate::teleological::column_families::CF_TOOL_AUDIT_LOG;
+
+use super::helpers::hex_encode;
+use super::store::RocksDbTeleologicalStore;
+use super::types::{TeleologicalStoreError, TeleologicalStoreResult};
+
+/// First possible key at `timestamp` (UUID portion all zeros).
+fn time_floor_key(timestamp: &DateTime<Utc>) -> [u8; 24] {
+    let mut key = [0u8; 24];
+    let nanos = timestamp.timestamp_nanos_opt().unwrap_or(0);
+    key[..8].copy_from_slice(&nanos.to_be_bytes());
+    key
+}
+
+/// Last possible key at `timestamp` (UUID portion all 0xff).
+fn time_ceiling_key(timestamp: &DateTime<Utc>) -> [u8; 24] {
+    let mut key = [0xffu8; 24];
+    let nanos = timestamp.timestamp_nanos_opt().unwrap_or(i64::MAX);
+    key[..8].copy_from_slice(&nanos.to_be_bytes());
+    key
+}
+
+impl RocksDbTeleologicalStore {
+    /// Append a tool audit record.
+    ///
+    /// The record's `arguments` must already be redacted; they are stored as given.
+    pub fn append_tool_audit_record(
+        &self,
+        record: &ToolAuditRecord,
+    ) -> TeleologicalStoreResult<()> {
+        let key = record.storage_key();
+
+        let bytes = serde_json::to_vec(record).map_err(|e| {
+            error!(
+                "FAIL FAST: Failed to serialize ToolAuditRecord {}: {}",
+                record.id, e
+            );
+            TeleologicalStoreError::Serialization {
+                id: Some(record.id),
+                message: format!("ToolAuditRecord serialization failed: {}", e),
+            }
+        })?;
+
+        let cf = self.get_cf(CF_TOOL_AUDIT_LOG)?;
+        self.db.put_cf(cf, key, &bytes).map_err(|e| {
+            error!(
+                "FAIL FAST: Failed to write ToolAuditRecord {} to CF '{}': {}",
+                record.id, CF_TOOL_AUDIT_LOG, e
+            );
+            TeleologicalStoreError::rocksdb_op("put", CF_TOOL_AUDIT_LOG, Some(record.id), e)
+        })?;
+
+        debug!(
+            "Appended tool audit record {} ({} bytes): tool={}, memories={}",
+            record.id,
+            bytes.len(),
+            record.tool_name,
+            record.memory_ids.len(),
+        );
+
+        Ok(())
+    }
+
+    /// Query tool audit records, newest first.
+    ///
+    /// Iterates backwards from `query.to` (or the end of the log) and stops
+    /// at `query.from`. `has_more` is set when at least one further record
+    /// matches after the returned page.
+    pub fn query_tool_audit_log(
+        &self,
+        query: &ToolAuditQuery,
+    ) -> TeleologicalStoreResult<ToolAuditPage> {
+        let cf = self.get_cf(CF_TOOL_AUDIT_LOG)?;
+
+        let ceiling = query.to.as_ref().map(time_ceiling_key);
+        let mode = match &ceiling {
+            Some(key) => rocksdb::IteratorMode::From(key, rocksdb::Direction::Reverse),
+            None => rocksdb::IteratorMode::End,
+        };
+        let floor = query.from.as_ref().map(time_floor_key);
+        let limit = if query.limit == 0 { usize::MAX } else { query.limit };
+
+        let mut page = ToolAuditPage::default();
+        let mut skipped = 0usize;
+
+        for item in self.db.iterator_cf(cf, mode) {
+            let (key, value) = item.map_err(|e| {
+                error!(
+                    "FAIL FAST: RocksDB iteration failed on CF '{}': {}",
+                    CF_TOOL_AUDIT_LOG, e
+                );
+                TeleologicalStoreError::rocksdb_op("iterate", CF_TOOL_AUDIT_LOG, None, e)
+            })?;
+
+            if floor.as_ref().is_some_and(|f| key[..] < f[..]) {
+                break;
+            }
+
+            let record: ToolAuditRecord = serde_json::from_slice(&value).map_err(|e| {
+                error!(
+                    "FAIL FAST: Failed to deserialize ToolAuditRecord from CF '{}': {}",
+                    CF_TOOL_AUDIT_LOG, e
+                );
+                TeleologicalStoreError::Deserialization {
+                    key: format!("tool_audit_log:{}", hex_encode(&key)),
+                    message: format!("ToolAuditRecord deserialization failed: {}", e),
+                }
+            })?;
+
+            if !query.matches(&record) {
+                continue;
+            }
+            if skipped < query.offset {
+                skipped += 1;
+                continue;
+            }
+            if page.records.len() >= limit {
+                page.has_more = true;
+                break;
+            }
+            page.records.push(record);
+        }
+
+        debug!(
+            "Retrieved {} tool audit records (offset={}, limit={}, has_more={})",
+            page.records.len(),
+            query.offset,
+            query.limit,
+            page.has_more
+        );
+
+        Ok(page)
+    }
+
+    /// Delete every tool audit record timestamped before `before`.
+    ///
+    /// Counts the doomed records, then removes them with one range delete.
+    /// Returns the number of records removed.
+    pub fn prune_tool_audit_log(&self, before: DateTime<Utc>) -> TeleologicalStoreResult<usize> {
+        let cf = self.get_cf(CF_TOOL_AUDIT_LOG)?;
+        let end = time_floor_key(&before);
+
+        let mut removed = 0usize;
+        for item in self.db.iterator_cf(cf, rocksdb::IteratorMode::Start) {
+            let (key, _) = item.map_err(|e| {
+                error!(
+                    "FAIL FAST: RocksDB iteration failed on CF '{}' during prune: {}",
+                    CF_TOOL_AUDIT_LOG, e
+                );
+                TeleologicalStoreError::rocksdb_op("iterate", CF_TOOL_AUDIT_LOG, None, e)
+            })?;
+            if key[..] >= end[..] {
+                break;
+            }
+            removed += 1;
+        }
+
+        if removed == 0 {
+            return Ok(0);
+        }
+
+        self.db.delete_range_cf(cf, [0u8; 24], end).map_err(|e| {
+            error!(
+                "FAIL FAST: Failed to prune CF '{}' before {}: {}",
+                CF_TOOL_AUDIT_LOG, before, e
+            );
+            TeleologicalStoreError::rocksdb_op("delete_range", CF_TOOL_AUDIT_LOG, None, e)
+        })?;
+
+        info!("Pruned {} tool audit records older than {}", removed, before);
+
+        Ok(removed)
+    }
+}
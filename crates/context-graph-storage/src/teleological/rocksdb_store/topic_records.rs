@@ -0,0 +1,156 @@
+//! Topic record operations (CF_TOPICS).
+//!
+//! One JSON `TopicRecord` per topic, keyed by its 16-byte stable ID. Records
+//! are written by detection runs (after reconciliation) and rewritten in
+//! place when member memories are deleted or merged.
+//!
+//! Member rewrites read-modify-write every record, so writers hold
+//! `secondary_index_lock` until the batch is committed. Hard delete already
+//! holds it and adds the rewrite to its own batch.
+
+use std::collections::HashSet;
+
+use chrono::Utc;
+use rocksdb::WriteBatch;
+use tracing::{debug, error};
+use uuid::Uuid;
+
+use context_graph_core::clustering::{TopicReconciliation, TopicRecord};
+
+use crate::teleological::column_families::CF_TOPICS;
+
+use super::helpers::hex_encode;
+use super::store::RocksDbTeleologicalStore;
+use super::types::{TeleologicalStoreError, TeleologicalStoreResult};
+
+fn serialize_topic_record(record: &TopicRecord) -> TeleologicalStoreResult<Vec<u8>> {
+    serde_json::to_vec(record).map_err(|e| {
+        error!(
+            "FAIL FAST: Failed to serialize TopicRecord {}: {}",
+            record.id, e
+        );
+        TeleologicalStoreError::Serialization {
+            id: Some(record.id),
+            message: format!("TopicRecord serialization failed: {}", e),
+        }
+    })
+}
+
+impl RocksDbTeleologicalStore {
+    /// List every stored topic record, in key (topic ID) order.
+    pub fn list_topic_records(&self) -> TeleologicalStoreResult<Vec<TopicRecord>> {
+        let cf = self.get_cf(CF_TOPICS)?;
+
+        let mut records = Vec::new();
+        for item in self.db.iterator_cf(cf, rocksdb::IteratorMode::Start) {
+            let (key, value) = item.map_err(|e| {
+                error!(
+                    "FAIL FAST: RocksDB iteration failed on CF '{}': {}",
+                    CF_TOPICS, e
+                );
+                TeleologicalStoreError::rocksdb_op("iterate", CF_TOPICS, None, e)
+            })?;
+
+            let record: TopicRecord = serde_json::from_slice(&value).map_err(|e| {
+                error!(
+                    "FAIL FAST: Failed to deserialize TopicRecord from CF '{}': {}",
+                    CF_TOPICS, e
+                );
+                TeleologicalStoreError::Deserialization {
+                    key: format!("topics:{}", hex_encode(&key)),
+                    message: format!("TopicRecord deserialization failed: {}", e),
+                }
+            })?;
+            records.push(record);
+        }
+
+        debug!("Listed {} topic records", records.len());
+        Ok(records)
+    }
+
+    /// Write a detection run's reconciled topics and delete retired ones in
+    /// a single batch.
+    pub fn apply_topic_reconciliation(
+        &self,
+        reconciliation: &TopicReconciliation,
+    ) -> TeleologicalStoreResult<()> {
+        let cf = self.get_cf(CF_TOPICS)?;
+        let _guard = self.secondary_index_lock.lock();
+
+        let mut batch = WriteBatch::default();
+        let mut logical_bytes = 0usize;
+        for id in &reconciliation.retired {
+            batch.delete_cf(cf, id.as_bytes());
+        }
+        for record in &reconciliation.upserts {
+            let bytes = serialize_topic_record(record)?;
+            logical_bytes += bytes.len();
+            batch.put_cf(cf, record.id.as_bytes(), bytes);
+        }
+        self.batch_writer.commit(batch, logical_bytes, None)?;
+
+        debug!(
+            upserts = reconciliation.upserts.len(),
+            retired = reconciliation.retired.len(),
+            "Applied topic reconciliation"
+        );
+        Ok(())
+    }
+
+    /// Remove `removed` from every topic's member list, adding `replacement`
+    /// where any were present. Topics left empty are deleted.
+    ///
+    /// Returns the number of topic records changed.
+    pub fn replace_topic_members(
+        &self,
+        removed: &[Uuid],
+        replacement: Option<Uuid>,
+    ) -> TeleologicalStoreResult<usize> {
+        let removed: HashSet<Uuid> = removed.iter().copied().collect();
+        let _guard = self.secondary_index_lock.lock();
+
+        let mut batch = WriteBatch::default();
+        let changed = self.replace_topic_members_in_batch(&mut batch, &removed, replacement)?;
+        if changed > 0 {
+            self.batch_writer.commit(batch, 0, replacement)?;
+        }
+        Ok(changed)
+    }
+
+    /// Add the member rewrites for `removed` to `batch`.
+    ///
+    /// Caller must hold `secondary_index_lock` until `batch` is committed.
+    pub(crate) fn replace_topic_members_in_batch(
+        &self,
+        batch: &mut WriteBatch,
+        removed: &HashSet<Uuid>,
+        replacement: Option<Uuid>,
+    ) -> TeleologicalStoreResult<usize> {
+        let cf = self.get_cf(CF_TOPICS)?;
+        let now = Utc::now();
+
+        let mut changed = 0;
+        for mut record in self.list_topic_records()? {
+            if !record.replace_members(removed, replacement, now) {
+                continue;
+            }
+            changed += 1;
+            if record.member_count() == 0 {
+                debug!(topic_id = %record.id, "Topic record emptied, deleting");
+                batch.delete_cf(cf, record.id.as_bytes());
+            } else {
+                batch.put_cf(cf, record.id.as_bytes(), serialize_topic_record(&record)?);
+            }
+        }
+
+        if changed > 0 {
+            debug!(
+                removed = removed.len(),
+                replacement = ?replacement,
+                changed,
+                "Rewrote topic member lists"
+            );
+        }
+        Ok(changed)
+    }
+}
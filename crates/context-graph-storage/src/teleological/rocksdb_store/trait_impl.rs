@@ -7,18 +7,21 @@
 //! - `persistence.rs` - Batch, statistics, persistence, content
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use async_trait::async_trait;
 use uuid::Uuid;
 
 use context_graph_core::error::{CoreError, CoreResult};
+use context_graph_core::retrieval::{PipelineMetricsSnapshot, PipelineTimings};
 use context_graph_core::traits::{
-    TeleologicalMemoryStore, TeleologicalSearchOptions, TeleologicalSearchResult,
-    TeleologicalStorageBackend,
+    AccessStats, MemorySize, StorageMetrics, TeleologicalMemoryStore, TeleologicalSearchOptions,
+    TeleologicalSearchResult, TeleologicalStorageBackend,
 };
+use context_graph_core::teleological::EmbedderMask;
 use context_graph_core::types::fingerprint::{
-    SemanticFingerprint, SparseVector, TeleologicalFingerprint,
+    PartialFingerprint, SemanticFingerprint, SparseVector, TeleologicalFingerprint,
 };
 use context_graph_core::types::SourceMetadata;
 
@@ -33,18 +36,43 @@ impl TeleologicalMemoryStore for RocksDbTeleologicalStore {
     // ==================== CRUD Operations ====================
 
     async fn store(&self, fingerprint: TeleologicalFingerprint) -> CoreResult<Uuid> {
+        self.ensure_writable("store")?;
         self.store_async(fingerprint).await
     }
 
     async fn retrieve(&self, id: Uuid) -> CoreResult<Option<TeleologicalFingerprint>> {
-        self.retrieve_async(id).await
+        let fingerprint = self.retrieve_async(id).await?;
+        if fingerprint.is_some() {
+            self.access_tracker.record([id]);
+        }
+        Ok(fingerprint)
+    }
+
+    async fn retrieve_partial(
+        &self,
+        id: Uuid,
+        mask: EmbedderMask,
+    ) -> CoreResult<Option<PartialFingerprint>> {
+        self.retrieve_partial_async(id, mask).await
     }
 
     async fn update(&self, fingerprint: TeleologicalFingerprint) -> CoreResult<bool> {
+        self.ensure_writable("update")?;
         self.update_async(fingerprint).await
     }
 
+    async fn update_embeddings(
+        &self,
+        id: Uuid,
+        mask: EmbedderMask,
+        embeddings: SemanticFingerprint,
+    ) -> CoreResult<bool> {
+        self.ensure_writable("update_embeddings")?;
+        self.update_embeddings_async(id, mask, embeddings).await
+    }
+
     async fn delete(&self, id: Uuid, soft: bool) -> CoreResult<bool> {
+        self.ensure_writable("delete")?;
         self.delete_async(id, soft).await
     }
 
@@ -55,7 +83,23 @@ impl TeleologicalMemoryStore for RocksDbTeleologicalStore {
         query: &SemanticFingerprint,
         options: TeleologicalSearchOptions,
     ) -> CoreResult<Vec<TeleologicalSearchResult>> {
-        self.search_semantic_async(query, options).await
+        let results = self.search_semantic_async(query, options).await?;
+        self.access_tracker.record(results.iter().map(|r| r.fingerprint.id));
+        Ok(results)
+    }
+
+    async fn search_semantic_timed(
+        &self,
+        query: &SemanticFingerprint,
+        options: TeleologicalSearchOptions,
+    ) -> CoreResult<(Vec<TeleologicalSearchResult>, PipelineTimings)> {
+        let (results, timings) = self.search_semantic_timed_async(query, options).await?;
+        self.access_tracker.record(results.iter().map(|r| r.fingerprint.id));
+        Ok((results, timings))
+    }
+
+    fn pipeline_metrics(&self) -> PipelineMetricsSnapshot {
+        self.pipeline_metrics.snapshot()
     }
 
     async fn search_text(
@@ -90,6 +134,7 @@ impl TeleologicalMemoryStore for RocksDbTeleologicalStore {
         &self,
         fingerprints: Vec<TeleologicalFingerprint>,
     ) -> CoreResult<Vec<Uuid>> {
+        self.ensure_writable("store_batch")?;
         self.store_batch_async(fingerprints).await
     }
 
@@ -114,6 +159,24 @@ impl TeleologicalMemoryStore for RocksDbTeleologicalStore {
         self.backend_type_internal()
     }
 
+    fn storage_metrics(&self) -> CoreResult<StorageMetrics> {
+        Ok(self.collect_storage_metrics()?)
+    }
+
+    async fn largest_memories(&self, n: usize) -> CoreResult<Vec<MemorySize>> {
+        Ok(self.largest_memories_sync(n)?)
+    }
+
+    // ==================== Access Statistics ====================
+
+    async fn access_stats(&self, ids: &[Uuid]) -> CoreResult<Vec<AccessStats>> {
+        Ok(self.access_stats_sync(ids)?)
+    }
+
+    async fn flush_access_stats(&self) -> CoreResult<()> {
+        Ok(self.flush_access_stats_sync()?)
+    }
+
     // ==================== Persistence ====================
 
     async fn flush(&self) -> CoreResult<()> {
@@ -125,10 +188,12 @@ impl TeleologicalMemoryStore for RocksDbTeleologicalStore {
     }
 
     async fn restore(&self, checkpoint_path: &std::path::Path) -> CoreResult<()> {
+        self.ensure_writable("restore")?;
         self.restore_async(checkpoint_path).await
     }
 
     async fn compact(&self) -> CoreResult<()> {
+        self.ensure_writable("compact")?;
         self.compact_async().await
     }
 
@@ -172,6 +237,22 @@ impl TeleologicalMemoryStore for RocksDbTeleologicalStore {
         self.find_fingerprints_by_file_path(file_path).await
     }
 
+    // ==================== Content Hash Index ====================
+
+    async fn find_by_content_hash(&self, content_hash: &[u8; 32]) -> CoreResult<Vec<Uuid>> {
+        self.find_by_content_hash_async(content_hash).await
+    }
+
+    // ==================== Namespaces ====================
+
+    async fn count_by_namespace(&self) -> CoreResult<HashMap<String, usize>> {
+        self.count_by_namespace_async().await
+    }
+
+    async fn list_namespace_ids(&self, namespace: &str) -> CoreResult<Vec<Uuid>> {
+        Ok(self.list_namespace_ids_internal(namespace))
+    }
+
     // ==================== File Index Storage ====================
 
     async fn list_indexed_files(&self) -> CoreResult<Vec<context_graph_core::types::file_index::FileIndexEntry>> {
@@ -221,6 +302,29 @@ impl TeleologicalMemoryStore for RocksDbTeleologicalStore {
         self.load_latest_topic_portfolio_async().await
     }
 
+    // ==================== Topic Records ====================
+
+    async fn list_topic_records(&self) -> CoreResult<Vec<context_graph_core::clustering::TopicRecord>> {
+        self.list_topic_records().map_err(Into::into)
+    }
+
+    async fn apply_topic_reconciliation(
+        &self,
+        reconciliation: &context_graph_core::clustering::TopicReconciliation,
+    ) -> CoreResult<()> {
+        self.apply_topic_reconciliation(reconciliation)
+            .map_err(Into::into)
+    }
+
+    async fn replace_topic_members(
+        &self,
+        removed: &[Uuid],
+        replacement: Option<Uuid>,
+    ) -> CoreResult<usize> {
+        self.replace_topic_members(removed, replacement)
+            .map_err(Into::into)
+    }
+
     // ==================== Clustering Support ====================
 
     async fn scan_fingerprints_for_clustering(
@@ -237,6 +341,14 @@ impl TeleologicalMemoryStore for RocksDbTeleologicalStore {
         self.list_fingerprints_unbiased_async(limit).await
     }
 
+    async fn list_fingerprints_after(
+        &self,
+        after: Option<Uuid>,
+        limit: usize,
+    ) -> CoreResult<Vec<context_graph_core::types::fingerprint::TeleologicalFingerprint>> {
+        self.list_fingerprints_after_async(after, limit).await
+    }
+
     // ==================== Causal Relationship Storage ====================
 
     async fn store_causal_relationship(
@@ -380,6 +492,39 @@ impl TeleologicalMemoryStore for RocksDbTeleologicalStore {
         self.get_merge_history(merged_id, limit).map_err(Into::into)
     }
 
+    // ==================== Memory Lineage ====================
+
+    async fn append_lineage_record(&self, record: &context_graph_core::types::audit::LineageRecord) -> CoreResult<()> {
+        self.append_lineage_record(record).map_err(Into::into)
+    }
+
+    async fn get_lineage_records(
+        &self,
+        child_id: uuid::Uuid,
+    ) -> CoreResult<Vec<context_graph_core::types::audit::LineageRecord>> {
+        self.get_lineage_records(child_id).map_err(Into::into)
+    }
+
+    // ==================== Tool Execution Audit ====================
+
+    async fn append_tool_audit_record(
+        &self,
+        record: &context_graph_core::types::audit::ToolAuditRecord,
+    ) -> CoreResult<()> {
+        self.append_tool_audit_record(record).map_err(Into::into)
+    }
+
+    async fn query_tool_audit_log(
+        &self,
+        query: &context_graph_core::types::audit::ToolAuditQuery,
+    ) -> CoreResult<context_graph_core::types::audit::ToolAuditPage> {
+        self.query_tool_audit_log(query).map_err(Into::into)
+    }
+
+    async fn prune_tool_audit_log(&self, before: chrono::DateTime<chrono::Utc>) -> CoreResult<usize> {
+        self.prune_tool_audit_log(before).map_err(Into::into)
+    }
+
     // ==================== Importance History (Phase 4) ====================
 
     async fn append_importance_change(&self, record: &context_graph_core::types::audit::ImportanceChangeRecord) -> CoreResult<()> {
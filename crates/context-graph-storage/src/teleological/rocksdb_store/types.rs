@@ -11,11 +11,18 @@
 //! - Key (if applicable)
 //! - Underlying cause
 
-use context_graph_core::error::CoreError;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use context_graph_core::error::{ContextGraphError, CoreError, ErrorKind};
 use context_graph_core::teleological::ComparisonValidationError;
 use thiserror::Error;
 use uuid::Uuid;
 
+use crate::teleological::indexes::IndexBuildConfig;
+
+use super::access_stats::AccessStatsConfig;
+
 // ============================================================================
 // Error Types - FAIL FAST with detailed context
 // ============================================================================
@@ -75,6 +82,30 @@ pub enum TeleologicalStoreError {
     #[error("Stale lock detected at '{path}' but cleanup failed: {message}")]
     StaleLockCleanupFailed { path: String, message: String },
 
+    /// Another live process holds the database lock.
+    ///
+    /// Raised instead of RocksDB's raw lock error when opening in
+    /// [`StoreAccessMode::Exclusive`]. `pid` comes from the kernel lock or
+    /// the `LOCK.owner` file; `since` only from the latter.
+    #[error("Database at '{path}' is locked by another process ({}). \
+             Stop that process first, or open read-only (StoreAccessMode::ReadOnly / SecondaryCatchUp) \
+             for commands that do not write.", describe_lock_holder(*.pid, .since.as_ref()))]
+    LockedByOtherProcess {
+        /// Database path
+        path: String,
+        /// Pid of the lock holder, if known
+        pid: Option<u32>,
+        /// When the holder opened the database, if recorded
+        since: Option<DateTime<Utc>>,
+    },
+
+    /// Write attempted on a store opened without write access.
+    #[error("Cannot {operation}: store is open in {mode:?} mode")]
+    ReadOnlyAccess {
+        operation: &'static str,
+        mode: StoreAccessMode,
+    },
+
     /// Database corruption detected - FAIL FAST.
     ///
     /// This error is raised when MANIFEST references SST files that don't exist,
@@ -114,6 +145,14 @@ pub enum TeleologicalStoreError {
     ComparisonValidation(#[from] ComparisonValidationError),
 }
 
+fn describe_lock_holder(pid: Option<u32>, since: Option<&DateTime<Utc>>) -> String {
+    match (pid, since) {
+        (Some(pid), Some(since)) => format!("pid {} since {}", pid, since.to_rfc3339()),
+        (Some(pid), None) => format!("pid {}", pid),
+        (None, _) => "holder unknown".to_string(),
+    }
+}
+
 impl TeleologicalStoreError {
     /// Create a RocksDB operation error.
     pub fn rocksdb_op(
@@ -131,9 +170,32 @@ impl TeleologicalStoreError {
     }
 }
 
+impl TeleologicalStoreError {
+    /// Machine-readable classification of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Validation { .. } | Self::ComparisonValidation(_) => ErrorKind::InvalidInput,
+            Self::CorruptionDetected { .. } | Self::Deserialization { .. } => ErrorKind::Corruption,
+            Self::LockedByOtherProcess { .. } | Self::ReadOnlyAccess { .. } => {
+                ErrorKind::CapabilityUnavailable
+            }
+            _ => ErrorKind::Internal,
+        }
+    }
+}
+
+impl From<TeleologicalStoreError> for ContextGraphError {
+    fn from(e: TeleologicalStoreError) -> Self {
+        let kind = e.kind();
+        ContextGraphError::from_source(kind, e)
+    }
+}
+
+/// Keeps the store error as `source()` instead of flattening it to
+/// `CoreError::StorageError(String)`.
 impl From<TeleologicalStoreError> for CoreError {
     fn from(e: TeleologicalStoreError) -> Self {
-        CoreError::StorageError(e.to_string())
+        CoreError::from(ContextGraphError::from(e))
     }
 }
 
@@ -165,6 +227,19 @@ pub struct TeleologicalStoreConfig {
     /// Soft-deleted entries older than this are permanently hard-deleted by GC.
     /// Default: 7 days (604800 seconds). Set to 0 to GC immediately on next run.
     pub gc_retention_secs: u64,
+    /// When fingerprint writes fsync the WAL (default: `OnShutdown`).
+    pub fsync_policy: FsyncPolicy,
+    /// How the database is opened (default: `Exclusive`).
+    pub access_mode: StoreAccessMode,
+    /// Directory for the secondary instance's own info log and MANIFEST
+    /// in [`StoreAccessMode::SecondaryCatchUp`]. Default: a per-process
+    /// directory under the system temp dir.
+    pub secondary_path: Option<PathBuf>,
+    /// How HNSW indexes are rebuilt from fingerprints at open and after
+    /// compaction: parallelism, memory budget and progress callback.
+    pub index_build: IndexBuildConfig,
+    /// How often accesses counted in memory are written to `access_stats`.
+    pub access_stats: AccessStatsConfig,
 }
 
 impl Default for TeleologicalStoreConfig {
@@ -175,6 +250,134 @@ impl Default for TeleologicalStoreConfig {
             enable_wal: true,
             create_if_missing: true,
             gc_retention_secs: 7 * 24 * 3600, // 7 days
+            fsync_policy: FsyncPolicy::default(),
+            access_mode: StoreAccessMode::default(),
+            secondary_path: None,
+            index_build: IndexBuildConfig::default(),
+            access_stats: AccessStatsConfig::default(),
         }
     }
 }
+
+impl TeleologicalStoreConfig {
+    /// Default configuration with the given access mode.
+    pub fn with_access_mode(access_mode: StoreAccessMode) -> Self {
+        Self {
+            access_mode,
+            ..Self::default()
+        }
+    }
+}
+
+/// How a store opens its RocksDB directory.
+///
+/// Only one process may hold a database for writing. Commands that only
+/// read (fsck without repair, status, export) should open `ReadOnly` or
+/// `SecondaryCatchUp` so they can run next to the MCP server.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StoreAccessMode {
+    /// Read-write, holding the RocksDB lock. Fails with
+    /// `LockedByOtherProcess` if another process holds it.
+    #[default]
+    Exclusive,
+    /// Read-only view of the data committed when the store was opened.
+    /// Takes no lock.
+    ReadOnly,
+    /// RocksDB secondary instance that follows the primary's MANIFEST and
+    /// WAL via `catch_up_with_primary`. Takes no lock.
+    SecondaryCatchUp,
+}
+
+impl StoreAccessMode {
+    /// Whether writes are allowed in this mode.
+    pub fn is_writable(self) -> bool {
+        self == StoreAccessMode::Exclusive
+    }
+}
+
+/// WAL fsync policy for fingerprint writes.
+///
+/// Every fingerprint write is a single atomic `WriteBatch` regardless of
+/// policy; the policy only decides when the WAL reaches the disk. A crash
+/// (not a process exit) can lose writes made since the last sync, but never
+/// part of one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FsyncPolicy {
+    /// Sync the WAL on every batch. Durable, slowest.
+    EveryWrite,
+    /// Sync the WAL on the first batch at least this many milliseconds after
+    /// the previous sync, and on shutdown.
+    EveryNms(u64),
+    /// Leave syncing to the OS; sync once when the store is dropped.
+    #[default]
+    OnShutdown,
+}
+
+// ============================================================================
+// Maintenance Statistics
+// ============================================================================
+
+/// Statistics returned by inverted index rebuild operations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RebuildStats {
+    /// Fingerprints scanned from CF_FINGERPRINTS.
+    pub fingerprints_scanned: usize,
+    /// Fingerprints skipped because they failed to deserialize.
+    pub corrupted_skipped: usize,
+    /// Term posting lists written.
+    pub terms_written: usize,
+    /// Total (term, memory_id) postings written.
+    pub postings_written: usize,
+    /// Previously stored term keys that no fingerprint references anymore.
+    pub stale_terms_removed: usize,
+    /// Total time in milliseconds.
+    pub elapsed_ms: u64,
+}
+
+/// Counters for fingerprint batch writes since the store was opened.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WriteMetrics {
+    /// Batches committed.
+    pub batches_committed: u64,
+    /// Put/delete operations across all committed batches.
+    pub operations_written: u64,
+    /// Serialized batch bytes handed to RocksDB.
+    pub batch_bytes: u64,
+    /// Serialized fingerprint bytes (the payload callers asked to store).
+    pub logical_bytes: u64,
+    /// Largest batch committed, in operations.
+    pub max_batch_operations: u64,
+    /// Explicit WAL syncs (per-write syncs included).
+    pub wal_syncs: u64,
+}
+
+impl WriteMetrics {
+    /// Batch bytes per fingerprint byte (0.0 before the first write).
+    pub fn write_amplification(&self) -> f64 {
+        if self.logical_bytes == 0 {
+            return 0.0;
+        }
+        self.batch_bytes as f64 / self.logical_bytes as f64
+    }
+
+    /// Mean operations per committed batch.
+    pub fn avg_batch_operations(&self) -> f64 {
+        if self.batches_committed == 0 {
+            return 0.0;
+        }
+        self.operations_written as f64 / self.batches_committed as f64
+    }
+}
+
+/// Statistics returned by HNSW warm start from snapshot files.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WarmStartStats {
+    /// True if a complete snapshot was loaded; false means a full rebuild ran.
+    pub from_snapshot: bool,
+    /// Fingerprints created or updated after the snapshot and re-inserted.
+    pub replayed: usize,
+    /// Snapshot ids removed because their fingerprint is gone or soft-deleted.
+    pub pruned: usize,
+    /// Total time in milliseconds.
+    pub elapsed_ms: u64,
+}
@@ -0,0 +1,242 @@
+//! Commit sequence and snapshot visibility for search.
+//!
+//! A store writes a fingerprint in two steps: one RocksDB `WriteBatch` (the
+//! fingerprint, sparse postings, E12 tokens, ...) and then one insert per HNSW
+//! index. A search running between those steps could see the document in some
+//! stages but not others, or fuse it from a half-built set of spaces.
+//!
+//! [`CommitLog`] closes that window. `begin` hides an ID before the first
+//! write, `publish` makes it visible at a new sequence number once every index
+//! holds it. A search captures [`CommitLog::current`] at start and every stage
+//! drops IDs that became visible later (see [`SearchSnapshot`]).
+//!
+//! A snapshot can also be scoped to a namespace. All namespaces share the
+//! HNSW indexes, so the scope is checked on every candidate during retrieval
+//! rather than on the final results.
+//!
+//! Sequences are per process: they start at 0 on open, and fingerprints that
+//! were already on disk are visible at every sequence.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use parking_lot::Mutex;
+use uuid::Uuid;
+
+use context_graph_core::types::fingerprint::TeleologicalFingerprint;
+
+/// Marker for IDs that are being written or deleted.
+const PENDING: u64 = u64::MAX;
+
+/// Monotonic commit sequence with the sequence each ID became visible at.
+#[derive(Debug, Default)]
+pub struct CommitLog {
+    /// Highest published sequence.
+    published: AtomicU64,
+    /// Serializes publishes so sequences are assigned in visibility order.
+    publish_lock: Mutex<()>,
+    /// Sequence at which each ID written since open became visible.
+    /// IDs without an entry predate the open and are always visible.
+    visible_at: DashMap<Uuid, u64>,
+}
+
+impl CommitLog {
+    /// Create an empty log at sequence 0.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Highest sequence whose writes are fully visible.
+    #[inline]
+    pub fn current(&self) -> u64 {
+        self.published.load(Ordering::Acquire)
+    }
+
+    /// Hide `id` until it is published. Call before the first write.
+    pub(crate) fn begin(&self, id: Uuid) {
+        self.visible_at.insert(id, PENDING);
+    }
+
+    /// Make `id` visible at a new sequence and return that sequence.
+    ///
+    /// The entry is written before the sequence advances, so a search that
+    /// observes sequence `n` also observes every ID published at `<= n`.
+    pub(crate) fn publish(&self, id: Uuid) -> u64 {
+        let _guard = self.publish_lock.lock();
+        let seq = self.published.load(Ordering::Relaxed) + 1;
+        self.visible_at.insert(id, seq);
+        self.published.store(seq, Ordering::Release);
+        seq
+    }
+
+    /// Hide `id` for good: it is being deleted or its write was rolled back.
+    pub(crate) fn hide(&self, id: Uuid) {
+        self.visible_at.insert(id, PENDING);
+    }
+
+    /// Drop the entry of an ID whose data is gone from every index.
+    pub(crate) fn forget(&self, id: &Uuid) {
+        self.visible_at.remove(id);
+    }
+
+    /// Whether `id` is visible to a search that started at `as_of`.
+    #[inline]
+    pub fn is_visible(&self, id: &Uuid, as_of: u64) -> bool {
+        self.visible_at.get(id).is_none_or(|seq| *seq <= as_of)
+    }
+}
+
+/// What a single search may return, fixed when the search starts.
+#[derive(Debug, Clone)]
+pub(crate) struct SearchSnapshot {
+    commits: Arc<CommitLog>,
+    soft_deleted: Arc<DashMap<Uuid, i64>>,
+    as_of: u64,
+    scope: Option<NamespaceScope>,
+}
+
+/// Namespaces a scoped search may see.
+///
+/// Resolved from the store's map of non-default namespaces, so checking a
+/// candidate never reads its fingerprint.
+#[derive(Debug, Clone)]
+struct NamespaceScope {
+    namespaces: Arc<DashMap<Uuid, String>>,
+    /// Requested namespace; `None` admits everything but staging namespaces.
+    namespace: Option<String>,
+}
+
+impl NamespaceScope {
+    fn admits(&self, id: &Uuid) -> bool {
+        let entry = self.namespaces.get(id);
+        let namespace = entry
+            .as_ref()
+            .map_or(TeleologicalFingerprint::DEFAULT_NAMESPACE, |e| {
+                e.value().as_str()
+            });
+        match &self.namespace {
+            Some(ns) => ns == namespace,
+            None => !TeleologicalFingerprint::is_staging_namespace(namespace),
+        }
+    }
+}
+
+impl SearchSnapshot {
+    /// Snapshot at `as_of`, or at the current sequence when `None`.
+    pub(crate) fn capture(
+        commits: &Arc<CommitLog>,
+        soft_deleted: &Arc<DashMap<Uuid, i64>>,
+        as_of: Option<u64>,
+    ) -> Self {
+        Self {
+            commits: Arc::clone(commits),
+            soft_deleted: Arc::clone(soft_deleted),
+            as_of: as_of.unwrap_or_else(|| commits.current()),
+            scope: None,
+        }
+    }
+
+    /// Restrict the snapshot to `namespace`, or to every non-staging
+    /// namespace when `None` (same rule as `admits_namespace`).
+    ///
+    /// `namespaces` maps each ID outside the default namespace to its
+    /// namespace.
+    pub(crate) fn scoped_to(
+        mut self,
+        namespaces: &Arc<DashMap<Uuid, String>>,
+        namespace: Option<String>,
+    ) -> Self {
+        self.scope = Some(NamespaceScope {
+            namespaces: Arc::clone(namespaces),
+            namespace,
+        });
+        self
+    }
+
+    /// Whether a namespace scope applies, so plain k-NN may return
+    /// candidates this snapshot excludes.
+    #[inline]
+    pub(crate) fn is_scoped(&self) -> bool {
+        self.scope.is_some()
+    }
+
+    /// Sequence this snapshot reads at.
+    #[inline]
+    pub(crate) fn as_of(&self) -> u64 {
+        self.as_of
+    }
+
+    /// Whether `id` must be left out of results.
+    ///
+    /// Uncommitted IDs and IDs outside the namespace scope are always
+    /// excluded; soft-deleted ones only when `include_deleted` is false.
+    #[inline]
+    pub(crate) fn excludes(&self, id: &Uuid, include_deleted: bool) -> bool {
+        !self.commits.is_visible(id, self.as_of)
+            || (!include_deleted && self.soft_deleted.contains_key(id))
+            || self.scope.as_ref().is_some_and(|scope| !scope.admits(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publish_orders_visibility() {
+        let log = CommitLog::new();
+        let old = Uuid::new_v4();
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+
+        // IDs stored before open are visible at sequence 0
+        assert!(log.is_visible(&old, 0));
+
+        log.begin(a);
+        assert!(!log.is_visible(&a, u64::MAX - 1));
+        assert_eq!(log.publish(a), 1);
+        log.begin(b);
+        assert_eq!(log.publish(b), 2);
+        assert_eq!(log.current(), 2);
+
+        assert!(!log.is_visible(&a, 0));
+        assert!(log.is_visible(&a, 1));
+        assert!(!log.is_visible(&b, 1));
+        assert!(log.is_visible(&b, 2));
+
+        log.hide(a);
+        assert!(!log.is_visible(&a, log.current()));
+        log.forget(&a);
+        assert!(log.is_visible(&a, 0));
+    }
+
+    #[test]
+    fn test_namespace_scope_excludes_other_namespaces() {
+        let log = Arc::new(CommitLog::new());
+        let soft_deleted = Arc::new(DashMap::new());
+        let namespaces = Arc::new(DashMap::new());
+        let default = Uuid::new_v4();
+        let project = Uuid::new_v4();
+        let staged = Uuid::new_v4();
+        namespaces.insert(project, "project".to_string());
+        namespaces.insert(staged, "staged.session-1".to_string());
+
+        let unscoped = SearchSnapshot::capture(&log, &soft_deleted, None);
+        assert!(!unscoped.is_scoped());
+        assert!(!unscoped.excludes(&staged, false));
+
+        let project_only = unscoped
+            .clone()
+            .scoped_to(&namespaces, Some("project".to_string()));
+        assert!(project_only.is_scoped());
+        assert!(project_only.excludes(&default, false));
+        assert!(!project_only.excludes(&project, false));
+        assert!(project_only.excludes(&staged, false));
+
+        let unstaged = unscoped.scoped_to(&namespaces, None);
+        assert!(!unstaged.excludes(&default, false));
+        assert!(!unstaged.excludes(&project, false));
+        assert!(unstaged.excludes(&staged, false));
+    }
+}
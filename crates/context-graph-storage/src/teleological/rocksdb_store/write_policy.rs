@@ -0,0 +1,163 @@
+//! Atomic commit of fingerprint write batches under an [`FsyncPolicy`].
+//!
+//! A fingerprint store touches several column families (fingerprint,
+//! Matryoshka, inverted indexes, E12 tokens, content-hash index, system
+//! markers). They are collected into one `WriteBatch` and committed here, so
+//! a crash leaves either all of them or none. The policy decides when the
+//! WAL is fsynced; counters feed [`WriteMetrics`].
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use rocksdb::{WriteBatch, WriteOptions, DB};
+use tracing::{debug, error};
+use uuid::Uuid;
+
+use crate::teleological::column_families::CF_FINGERPRINTS;
+
+use super::store::RocksDbTeleologicalStore;
+use super::types::{
+    FsyncPolicy, StoreAccessMode, TeleologicalStoreError, TeleologicalStoreResult, WriteMetrics,
+};
+
+/// Commits batches and tracks write metrics. Syncs the WAL on drop unless
+/// every write was already synced or the store is not writable.
+pub(crate) struct BatchWriter {
+    db: Arc<DB>,
+    policy: FsyncPolicy,
+    access_mode: StoreAccessMode,
+    last_sync: Mutex<Instant>,
+    batches_committed: AtomicU64,
+    operations_written: AtomicU64,
+    batch_bytes: AtomicU64,
+    logical_bytes: AtomicU64,
+    max_batch_operations: AtomicU64,
+    wal_syncs: AtomicU64,
+    /// Fault injection: abandon the next batch as if the process died
+    /// before the commit reached RocksDB.
+    #[cfg(test)]
+    pub(crate) fail_next_commit: std::sync::atomic::AtomicBool,
+}
+
+impl BatchWriter {
+    pub(crate) fn new(db: Arc<DB>, policy: FsyncPolicy, access_mode: StoreAccessMode) -> Self {
+        Self {
+            db,
+            policy,
+            access_mode,
+            last_sync: Mutex::new(Instant::now()),
+            batches_committed: AtomicU64::new(0),
+            operations_written: AtomicU64::new(0),
+            batch_bytes: AtomicU64::new(0),
+            logical_bytes: AtomicU64::new(0),
+            max_batch_operations: AtomicU64::new(0),
+            wal_syncs: AtomicU64::new(0),
+            #[cfg(test)]
+            fail_next_commit: std::sync::atomic::AtomicBool::new(false),
+        }
+    }
+
+    /// Commit `batch` atomically. `logical_bytes` is the caller's payload
+    /// size, used for write amplification.
+    pub(crate) fn commit(
+        &self,
+        batch: WriteBatch,
+        logical_bytes: usize,
+        id: Option<Uuid>,
+    ) -> TeleologicalStoreResult<()> {
+        if !self.access_mode.is_writable() {
+            return Err(TeleologicalStoreError::ReadOnlyAccess {
+                operation: "write_batch",
+                mode: self.access_mode,
+            });
+        }
+
+        #[cfg(test)]
+        if self.fail_next_commit.swap(false, Ordering::SeqCst) {
+            drop(batch);
+            return Err(TeleologicalStoreError::Internal(
+                "injected fault: batch abandoned before commit".to_string(),
+            ));
+        }
+
+        let operations = batch.len() as u64;
+        let size = batch.size_in_bytes() as u64;
+
+        let mut opts = WriteOptions::default();
+        opts.set_sync(self.policy == FsyncPolicy::EveryWrite);
+        self.db.write_opt(batch, &opts).map_err(|e| {
+            error!("Failed to commit write batch for {:?}: {}", id, e);
+            TeleologicalStoreError::rocksdb_op("write_batch", CF_FINGERPRINTS, id, e)
+        })?;
+
+        self.batches_committed.fetch_add(1, Ordering::Relaxed);
+        self.operations_written
+            .fetch_add(operations, Ordering::Relaxed);
+        self.batch_bytes.fetch_add(size, Ordering::Relaxed);
+        self.logical_bytes
+            .fetch_add(logical_bytes as u64, Ordering::Relaxed);
+        self.max_batch_operations
+            .fetch_max(operations, Ordering::Relaxed);
+
+        match self.policy {
+            FsyncPolicy::EveryWrite => {
+                self.wal_syncs.fetch_add(1, Ordering::Relaxed);
+            }
+            FsyncPolicy::EveryNms(ms) => {
+                let mut last = self.last_sync.lock();
+                if last.elapsed() >= Duration::from_millis(ms) {
+                    self.sync_wal()?;
+                    *last = Instant::now();
+                }
+            }
+            FsyncPolicy::OnShutdown => {}
+        }
+        Ok(())
+    }
+
+    /// Flush and fsync the WAL now.
+    pub(crate) fn sync_wal(&self) -> TeleologicalStoreResult<()> {
+        self.db.flush_wal(true).map_err(|e| {
+            TeleologicalStoreError::rocksdb_op("flush_wal", CF_FINGERPRINTS, None, e)
+        })?;
+        self.wal_syncs.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    pub(crate) fn metrics(&self) -> WriteMetrics {
+        WriteMetrics {
+            batches_committed: self.batches_committed.load(Ordering::Relaxed),
+            operations_written: self.operations_written.load(Ordering::Relaxed),
+            batch_bytes: self.batch_bytes.load(Ordering::Relaxed),
+            logical_bytes: self.logical_bytes.load(Ordering::Relaxed),
+            max_batch_operations: self.max_batch_operations.load(Ordering::Relaxed),
+            wal_syncs: self.wal_syncs.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl Drop for BatchWriter {
+    fn drop(&mut self) {
+        if self.policy == FsyncPolicy::EveryWrite || !self.access_mode.is_writable() {
+            return;
+        }
+        match self.db.flush_wal(true) {
+            Ok(()) => debug!("Synced WAL on shutdown ({:?})", self.policy),
+            Err(e) => error!("Failed to sync WAL on shutdown: {}", e),
+        }
+    }
+}
+
+impl RocksDbTeleologicalStore {
+    /// Counters for fingerprint batch writes since the store was opened.
+    pub fn write_metrics(&self) -> WriteMetrics {
+        self.batch_writer.metrics()
+    }
+
+    /// Fsync the WAL now, regardless of the configured [`FsyncPolicy`].
+    pub fn sync_wal(&self) -> TeleologicalStoreResult<()> {
+        self.batch_writer.sync_wal()
+    }
+}
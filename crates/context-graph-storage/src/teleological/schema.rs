@@ -266,6 +266,55 @@ pub fn parse_content_key(key: &[u8]) -> Uuid {
     })
 }
 
+// =============================================================================
+// CONTENT BLOB KEYS (prefix byte + hash or UUID)
+// =============================================================================
+
+/// Key prefix for blob records (`b` + content_hash).
+pub const CONTENT_BLOB_PREFIX: u8 = b'b';
+
+/// Key prefix for blob reference lists (`r` + content_hash).
+pub const CONTENT_BLOB_REFS_PREFIX: u8 = b'r';
+
+/// Key prefix for fingerprint → content_hash pointers (`i` + UUID).
+pub const CONTENT_BLOB_POINTER_PREFIX: u8 = b'i';
+
+/// Key for a blob record in the content_blobs CF.
+///
+/// # Returns
+/// Exactly 33 bytes: prefix + SHA-256 content hash
+#[inline]
+pub fn content_blob_key(content_hash: &[u8; 32]) -> [u8; 33] {
+    let mut key = [0u8; 33];
+    key[0] = CONTENT_BLOB_PREFIX;
+    key[1..].copy_from_slice(content_hash);
+    key
+}
+
+/// Key for the list of fingerprints referencing a blob.
+///
+/// # Returns
+/// Exactly 33 bytes: prefix + SHA-256 content hash
+#[inline]
+pub fn content_blob_refs_key(content_hash: &[u8; 32]) -> [u8; 33] {
+    let mut key = [0u8; 33];
+    key[0] = CONTENT_BLOB_REFS_PREFIX;
+    key[1..].copy_from_slice(content_hash);
+    key
+}
+
+/// Key for the content_hash a fingerprint's content is stored under.
+///
+/// # Returns
+/// Exactly 17 bytes: prefix + UUID (big-endian)
+#[inline]
+pub fn content_blob_pointer_key(id: &Uuid) -> [u8; 17] {
+    let mut key = [0u8; 17];
+    key[0] = CONTENT_BLOB_POINTER_PREFIX;
+    key[1..].copy_from_slice(id.as_bytes());
+    key
+}
+
 // =============================================================================
 // SOURCE METADATA KEYS (UUID = 16 bytes)
 // =============================================================================
@@ -20,6 +20,7 @@
 //! └── Store - Storage layer error
 //! ```
 
+use context_graph_core::error::{ContextGraphError, ErrorKind};
 use uuid::Uuid;
 
 use super::super::indexes::{EmbedderIndex, IndexError};
@@ -104,6 +105,31 @@ pub enum SearchError {
 /// propagate them rather than attempting recovery.
 pub type SearchResult<T> = Result<T, SearchError>;
 
+impl SearchError {
+    /// Machine-readable classification of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::DimensionMismatch { .. }
+            | Self::EmptyQuery { .. }
+            | Self::InvalidVector { .. }
+            | Self::Index(IndexError::DimensionMismatch { .. })
+            | Self::Index(IndexError::InvalidVector { .. }) => ErrorKind::InvalidInput,
+            Self::UnsupportedEmbedder { .. } | Self::Index(IndexError::IndexNotFound { .. }) => {
+                ErrorKind::CapabilityUnavailable
+            }
+            Self::NotFound { .. } => ErrorKind::NotFound,
+            _ => ErrorKind::Internal,
+        }
+    }
+}
+
+impl From<SearchError> for ContextGraphError {
+    fn from(e: SearchError) -> Self {
+        let kind = e.kind();
+        ContextGraphError::from_source(kind, e)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -249,4 +275,20 @@ mod tests {
 
         println!("RESULT: PASS");
     }
+
+    #[test]
+    fn test_kind_classification() {
+        let err = SearchError::EmptyQuery {
+            embedder: EmbedderIndex::E1Semantic,
+        };
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+        let err = SearchError::NotFound { id: Uuid::nil() };
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+
+        let err = SearchError::Store("registry poisoned".to_string());
+        let unified = ContextGraphError::from(err);
+        assert_eq!(unified.kind(), ErrorKind::Internal);
+        assert!(unified.to_string().contains("registry poisoned"));
+    }
 }
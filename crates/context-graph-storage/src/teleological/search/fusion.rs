@@ -0,0 +1,322 @@
+//! Fusion of per-embedder search results.
+//!
+//! # Overview
+//!
+//! Merges several [`SingleEmbedderSearchResults`] (one per embedding space)
+//! into one ranked list, keeping each hit's per-space rank so callers can
+//! show which spaces contributed.
+//!
+//! # Methods
+//!
+//! - **ReciprocalRankFusion**: `score(d) = Σ wᵢ / (k + rankᵢ(d))`, 1-based rank.
+//!   Robust to score scale differences between spaces (ARCH-18).
+//! - **WeightedScoreSum**: similarities are min-max normalized per space,
+//!   then `score(d) = Σ wᵢ × normᵢ(d)`.
+//!
+//! # Temporal Exclusion (ARCH-04)
+//!
+//! Temporal embedders (E2-E4) measure time proximity, not topic. Exclude them
+//! with [`EmbedderWeightMap::without_temporal`]; a space with weight 0.0 is
+//! ignored entirely and does not appear in per-space ranks.
+
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use super::super::indexes::EmbedderIndex;
+use super::result::SingleEmbedderSearchResults;
+
+/// Standard RRF constant (see `context_graph_core::fusion::RRF_K`).
+pub const DEFAULT_RRF_K: f32 = 60.0;
+
+// ============================================================================
+// WEIGHTS AND METHOD
+// ============================================================================
+
+/// Per-embedder fusion weights.
+///
+/// Embedders not in the map weigh 1.0. Weight 0.0 excludes a space.
+#[derive(Debug, Clone, Default)]
+pub struct EmbedderWeightMap {
+    weights: HashMap<EmbedderIndex, f32>,
+}
+
+impl EmbedderWeightMap {
+    /// All spaces weigh 1.0.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the weight for one embedder.
+    pub fn with_weight(mut self, embedder: EmbedderIndex, weight: f32) -> Self {
+        self.weights.insert(embedder, weight);
+        self
+    }
+
+    /// Exclude temporal embedders E2-E4 (ARCH-04).
+    pub fn without_temporal(self) -> Self {
+        self.with_weight(EmbedderIndex::E2TemporalRecent, 0.0)
+            .with_weight(EmbedderIndex::E3TemporalPeriodic, 0.0)
+            .with_weight(EmbedderIndex::E4TemporalPositional, 0.0)
+    }
+
+    /// Weight for `embedder` (1.0 if unset).
+    #[inline]
+    pub fn weight(&self, embedder: EmbedderIndex) -> f32 {
+        self.weights.get(&embedder).copied().unwrap_or(1.0)
+    }
+
+    /// Check if `embedder` contributes to fusion.
+    #[inline]
+    pub fn is_included(&self, embedder: EmbedderIndex) -> bool {
+        self.weight(embedder) > 0.0
+    }
+}
+
+/// How per-space scores are combined.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FusionMethod {
+    /// Weighted Reciprocal Rank Fusion with constant `k`.
+    ReciprocalRankFusion { k: f32 },
+    /// Weighted sum of per-space min-max normalized similarities.
+    WeightedScoreSum,
+}
+
+impl Default for FusionMethod {
+    fn default() -> Self {
+        Self::ReciprocalRankFusion { k: DEFAULT_RRF_K }
+    }
+}
+
+// ============================================================================
+// RESULTS
+// ============================================================================
+
+/// A fused hit with its contributing spaces.
+#[derive(Debug, Clone)]
+pub struct FusedHit {
+    /// The memory ID (fingerprint UUID).
+    pub id: Uuid,
+
+    /// Fused score (interpretation depends on [`FusionMethod`]).
+    pub score: f32,
+
+    /// 1-based rank of this hit in each contributing space, in input order.
+    pub space_ranks: Vec<(EmbedderIndex, usize)>,
+}
+
+impl FusedHit {
+    /// Rank in `embedder`'s result list, if it appeared there.
+    pub fn rank_in(&self, embedder: EmbedderIndex) -> Option<usize> {
+        self.space_ranks
+            .iter()
+            .find(|(e, _)| *e == embedder)
+            .map(|(_, rank)| *rank)
+    }
+}
+
+/// Fused results across embedding spaces.
+#[derive(Debug, Clone)]
+pub struct FusedResults {
+    /// Hits sorted by fused score descending.
+    pub hits: Vec<FusedHit>,
+
+    /// Method used to fuse.
+    pub method: FusionMethod,
+
+    /// Spaces that contributed (weight > 0), in input order.
+    pub spaces: Vec<EmbedderIndex>,
+}
+
+impl FusedResults {
+    /// Check if no results were found.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.hits.is_empty()
+    }
+
+    /// Get the number of results.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.hits.len()
+    }
+
+    /// Get the top fused result.
+    #[inline]
+    pub fn top(&self) -> Option<&FusedHit> {
+        self.hits.first()
+    }
+
+    /// Get all IDs in fused order.
+    pub fn ids(&self) -> Vec<Uuid> {
+        self.hits.iter().map(|h| h.id).collect()
+    }
+}
+
+// ============================================================================
+// FUSION
+// ============================================================================
+
+/// Fuse per-embedder results into one ranking.
+///
+/// Each input is assumed sorted by similarity descending (as returned by
+/// search). Spaces with weight 0.0 are skipped.
+pub fn fuse_results(
+    results: &[SingleEmbedderSearchResults],
+    weights: &EmbedderWeightMap,
+    method: FusionMethod,
+) -> FusedResults {
+    let mut fused: HashMap<Uuid, FusedHit> = HashMap::new();
+    let mut spaces = Vec::with_capacity(results.len());
+
+    for space in results {
+        if !weights.is_included(space.embedder) {
+            continue;
+        }
+        spaces.push(space.embedder);
+        let weight = weights.weight(space.embedder);
+
+        // Per-space min-max bounds; a flat list normalizes to 1.0
+        let (min, max) = space.hits.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), h| {
+            (lo.min(h.similarity), hi.max(h.similarity))
+        });
+        let range = max - min;
+
+        for (i, hit) in space.hits.iter().enumerate() {
+            let rank = i + 1;
+            let contribution = match method {
+                FusionMethod::ReciprocalRankFusion { k } => weight / (k + rank as f32),
+                FusionMethod::WeightedScoreSum => {
+                    let norm = if range > f32::EPSILON {
+                        (hit.similarity - min) / range
+                    } else {
+                        1.0
+                    };
+                    weight * norm
+                }
+            };
+            let entry = fused.entry(hit.id).or_insert_with(|| FusedHit {
+                id: hit.id,
+                score: 0.0,
+                space_ranks: Vec::new(),
+            });
+            entry.score += contribution;
+            entry.space_ranks.push((space.embedder, rank));
+        }
+    }
+
+    let mut hits: Vec<FusedHit> = fused.into_values().collect();
+    hits.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    FusedResults {
+        hits,
+        method,
+        spaces,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::teleological::search::result::EmbedderSearchHit;
+
+    fn list(embedder: EmbedderIndex, hits: &[(Uuid, f32)]) -> SingleEmbedderSearchResults {
+        SingleEmbedderSearchResults {
+            hits: hits
+                .iter()
+                .map(|&(id, similarity)| EmbedderSearchHit {
+                    id,
+                    distance: 2.0 - 2.0 * similarity,
+                    similarity,
+                    embedder,
+                })
+                .collect(),
+            embedder,
+            k: 10,
+            threshold: None,
+            candidates_scanned: hits.len(),
+            latency_us: 0,
+        }
+    }
+
+    /// Three spaces with overlapping hits:
+    ///
+    /// | Space | Rank 1 | Rank 2 | Rank 3 |
+    /// |-------|--------|--------|--------|
+    /// | E1 | A 1.00 | B 0.99 | C 0.00 |
+    /// | E7 | C 1.00 | B 0.98 | A 0.00 |
+    /// | E8 | A 1.00 | C 0.00 | -      |
+    fn three_spaces() -> (Vec<SingleEmbedderSearchResults>, [Uuid; 3]) {
+        let (a, b, c) = (Uuid::from_u128(1), Uuid::from_u128(2), Uuid::from_u128(3));
+        let lists = vec![
+            list(EmbedderIndex::E1Semantic, &[(a, 1.0), (b, 0.99), (c, 0.0)]),
+            list(EmbedderIndex::E7Code, &[(c, 1.0), (b, 0.98), (a, 0.0)]),
+            list(EmbedderIndex::E8Graph, &[(a, 1.0), (c, 0.0)]),
+        ];
+        (lists, [a, b, c])
+    }
+
+    #[test]
+    fn test_rrf_ordering() {
+        println!("=== TEST: RRF fuses three spaces in hand-verified order ===");
+
+        let (lists, [a, b, c]) = three_spaces();
+        let fused = fuse_results(&lists, &EmbedderWeightMap::new(), FusionMethod::default());
+
+        // A = 1/61 + 1/63 + 1/61 = 0.04866
+        // C = 1/63 + 1/61 + 1/62 = 0.04840
+        // B = 1/62 + 1/62        = 0.03226
+        assert_eq!(fused.ids(), vec![a, c, b]);
+        let expected_a = 1.0 / 61.0 + 1.0 / 63.0 + 1.0 / 61.0;
+        assert!((fused.hits[0].score - expected_a).abs() < 1e-6);
+
+        let top = fused.top().unwrap();
+        assert_eq!(top.rank_in(EmbedderIndex::E1Semantic), Some(1));
+        assert_eq!(top.rank_in(EmbedderIndex::E7Code), Some(3));
+        assert_eq!(top.rank_in(EmbedderIndex::E8Graph), Some(1));
+        assert_eq!(fused.hits[2].rank_in(EmbedderIndex::E8Graph), None);
+
+        println!("RESULT: PASS");
+    }
+
+    #[test]
+    fn test_weighted_sum_ordering_differs_from_rrf() {
+        println!("=== TEST: Weighted score sum ranks B above C, unlike RRF ===");
+
+        let (lists, [a, b, c]) = three_spaces();
+        let fused = fuse_results(&lists, &EmbedderWeightMap::new(), FusionMethod::WeightedScoreSum);
+
+        // Normalized: A = 1 + 0 + 1 = 2.0, B = 0.99 + 0.98 = 1.97, C = 0 + 1 + 0 = 1.0
+        assert_eq!(fused.ids(), vec![a, b, c]);
+        let scores: Vec<f32> = fused.hits.iter().map(|h| h.score).collect();
+        for (actual, expected) in scores.iter().zip([2.0, 1.97, 1.0]) {
+            assert!((actual - expected).abs() < 1e-5, "{actual} != {expected}");
+        }
+
+        println!("RESULT: PASS");
+    }
+
+    #[test]
+    fn test_temporal_spaces_excluded() {
+        println!("=== TEST: E2-E4 excluded via weight map (ARCH-04) ===");
+
+        let (mut lists, [a, _, c]) = three_spaces();
+        lists.push(list(EmbedderIndex::E2TemporalRecent, &[(c, 1.0)]));
+
+        // With E2 counted, C gains 1/61 and overtakes A
+        let fused = fuse_results(&lists, &EmbedderWeightMap::new(), FusionMethod::default());
+        assert_eq!(fused.top().unwrap().id, c);
+
+        let weights = EmbedderWeightMap::new().without_temporal();
+        let fused = fuse_results(&lists, &weights, FusionMethod::default());
+        assert_eq!(fused.top().unwrap().id, a);
+        assert!(!fused.spaces.contains(&EmbedderIndex::E2TemporalRecent));
+        assert!(fused.hits.iter().all(|h| h.rank_in(EmbedderIndex::E2TemporalRecent).is_none()));
+
+        println!("RESULT: PASS");
+    }
+}
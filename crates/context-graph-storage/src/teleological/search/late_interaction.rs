@@ -0,0 +1,387 @@
+//! E12 ColBERT late-interaction scoring.
+//!
+//! # Overview
+//!
+//! Scores documents by token-level MaxSim against the query's E12 token
+//! embeddings. This is Stage 4 of the retrieval pipeline: a rerank over a
+//! small candidate set, NOT an index search.
+//!
+//! # Algorithm
+//!
+//! MaxSim(Q, D) = Σᵢ max_j cos(qᵢ, dⱼ)
+//!
+//! The score is asymmetric: every QUERY token looks for its best match among
+//! the document tokens, so swapping query and document changes the result.
+//! Unlike [`compute_maxsim_direct`](super::compute_maxsim_direct) the sum is
+//! neither averaged nor shifted to [0, 1].
+//!
+//! # GPU Path
+//!
+//! With the `candle` feature, [`rerank`] concatenates all candidate tokens
+//! into one matrix and scores them with a single `[|Q|, 128] × [128, ΣD]`
+//! matmul instead of per-candidate loops.
+//!
+//! # FAIL FAST Policy
+//!
+//! - Mismatched token dimensions are errors
+//! - Empty token matrices score 0.0 with a warning (no tokens, no evidence)
+
+#[cfg(not(feature = "candle"))]
+use rayon::prelude::*;
+use tracing::warn;
+use uuid::Uuid;
+
+use super::super::indexes::EmbedderIndex;
+use super::error::{SearchError, SearchResult};
+use super::maxsim::{cosine_similarity_128d, E12_TOKEN_DIM};
+
+// ============================================================================
+// TOKEN MATRIX
+// ============================================================================
+
+/// Row-major matrix of token embeddings (one row per token).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenMatrix {
+    dim: usize,
+    data: Vec<f32>,
+}
+
+impl TokenMatrix {
+    /// Build from per-token vectors.
+    ///
+    /// # Errors
+    ///
+    /// `SearchError::DimensionMismatch` if tokens differ in length.
+    pub fn new(tokens: &[Vec<f32>]) -> SearchResult<Self> {
+        let dim = tokens.first().map_or(E12_TOKEN_DIM, Vec::len);
+        let mut data = Vec::with_capacity(dim * tokens.len());
+        for token in tokens {
+            if token.len() != dim {
+                return Err(SearchError::DimensionMismatch {
+                    embedder: EmbedderIndex::E12LateInteraction,
+                    expected: dim,
+                    actual: token.len(),
+                });
+            }
+            data.extend_from_slice(token);
+        }
+        Ok(Self { dim, data })
+    }
+
+    /// Build from fixed-size 128D tokens.
+    pub fn from_tokens(tokens: &[[f32; E12_TOKEN_DIM]]) -> Self {
+        Self {
+            dim: E12_TOKEN_DIM,
+            data: tokens.iter().flatten().copied().collect(),
+        }
+    }
+
+    /// Token dimension.
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Number of tokens (rows).
+    #[inline]
+    pub fn num_tokens(&self) -> usize {
+        if self.dim == 0 {
+            0
+        } else {
+            self.data.len() / self.dim
+        }
+    }
+
+    /// Check if the matrix has no tokens.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.num_tokens() == 0
+    }
+
+    /// Iterate over token rows.
+    pub fn rows(&self) -> impl Iterator<Item = &[f32]> {
+        self.data.chunks_exact(self.dim.max(1))
+    }
+
+    /// Flat row-major data.
+    #[inline]
+    pub fn as_slice(&self) -> &[f32] {
+        &self.data
+    }
+}
+
+// ============================================================================
+// SCORING
+// ============================================================================
+
+/// MaxSim score for fixed-size 128D tokens.
+///
+/// Returns 0.0 (with a warning) if either side has no tokens.
+pub fn maxsim_score(
+    query_tokens: &[[f32; E12_TOKEN_DIM]],
+    doc_tokens: &[[f32; E12_TOKEN_DIM]],
+) -> f32 {
+    if query_tokens.is_empty() || doc_tokens.is_empty() {
+        warn!(
+            "E12 MaxSim on empty token matrix (query={}, doc={}) - scoring 0.0",
+            query_tokens.len(),
+            doc_tokens.len()
+        );
+        return 0.0;
+    }
+    query_tokens
+        .iter()
+        .map(|q| {
+            doc_tokens
+                .iter()
+                .map(|d| cosine_similarity_128d(q, d))
+                .fold(f32::NEG_INFINITY, f32::max)
+        })
+        .sum()
+}
+
+/// MaxSim score between two token matrices.
+///
+/// # Errors
+///
+/// `SearchError::DimensionMismatch` if the matrices differ in token dimension.
+pub fn maxsim_matrix(query: &TokenMatrix, doc: &TokenMatrix) -> SearchResult<f32> {
+    check_dims(query, doc)?;
+    if query.is_empty() || doc.is_empty() {
+        warn!(
+            "E12 MaxSim on empty token matrix (query={}, doc={}) - scoring 0.0",
+            query.num_tokens(),
+            doc.num_tokens()
+        );
+        return Ok(0.0);
+    }
+    Ok(query
+        .rows()
+        .map(|q| {
+            doc.rows()
+                .map(|d| cosine_similarity_128d(q, d))
+                .fold(f32::NEG_INFINITY, f32::max)
+        })
+        .sum())
+}
+
+/// Rerank candidates by MaxSim against the query tokens.
+///
+/// # Returns
+///
+/// Top `k` (id, score) pairs sorted by descending MaxSim.
+///
+/// # Errors
+///
+/// `SearchError::DimensionMismatch` if any candidate's token dimension
+/// differs from the query's.
+pub fn rerank(
+    query_tokens: &TokenMatrix,
+    candidates: &[(Uuid, &TokenMatrix)],
+    k: usize,
+) -> SearchResult<Vec<(Uuid, f32)>> {
+    for (_, doc) in candidates {
+        check_dims(query_tokens, doc)?;
+    }
+
+    #[cfg(feature = "candle")]
+    let scores = gpu::batch_scores(query_tokens, candidates)?;
+    #[cfg(not(feature = "candle"))]
+    let scores = candidates
+        .par_iter()
+        .map(|(_, doc)| maxsim_matrix(query_tokens, doc))
+        .collect::<SearchResult<Vec<f32>>>()?;
+
+    let mut ranked: Vec<(Uuid, f32)> = candidates
+        .iter()
+        .map(|(id, _)| *id)
+        .zip(scores)
+        .collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    ranked.truncate(k);
+    Ok(ranked)
+}
+
+fn check_dims(query: &TokenMatrix, doc: &TokenMatrix) -> SearchResult<()> {
+    if !doc.is_empty() && !query.is_empty() && doc.dim() != query.dim() {
+        return Err(SearchError::DimensionMismatch {
+            embedder: EmbedderIndex::E12LateInteraction,
+            expected: query.dim(),
+            actual: doc.dim(),
+        });
+    }
+    Ok(())
+}
+
+// ============================================================================
+// GPU BATCH PATH
+// ============================================================================
+
+#[cfg(feature = "candle")]
+mod gpu {
+    use candle_core::{Device, Tensor};
+    use tracing::warn;
+    use uuid::Uuid;
+
+    use super::TokenMatrix;
+    use crate::teleological::search::error::{SearchError, SearchResult};
+
+    fn candle_err(e: candle_core::Error) -> SearchError {
+        SearchError::Store(format!("candle MaxSim failed: {}", e))
+    }
+
+    /// L2-normalize each row so that a matmul yields cosine similarities.
+    fn normalized(data: &[f32], rows: usize, dim: usize, device: &Device) -> SearchResult<Tensor> {
+        let t = Tensor::from_slice(data, (rows, dim), device).map_err(candle_err)?;
+        let norms = t
+            .sqr()
+            .and_then(|s| s.sum_keepdim(1))
+            .and_then(|s| s.sqrt())
+            .and_then(|n| n.clamp(f32::EPSILON, f32::MAX))
+            .map_err(candle_err)?;
+        t.broadcast_div(&norms).map_err(candle_err)
+    }
+
+    /// Score all candidates with one `[|Q|, dim] × [dim, ΣD]` matmul.
+    pub(super) fn batch_scores(
+        query: &TokenMatrix,
+        candidates: &[(Uuid, &TokenMatrix)],
+    ) -> SearchResult<Vec<f32>> {
+        if query.is_empty() {
+            warn!("E12 MaxSim rerank with empty query tokens - scoring all candidates 0.0");
+            return Ok(vec![0.0; candidates.len()]);
+        }
+
+        let dim = query.dim();
+        let mut offsets = Vec::with_capacity(candidates.len());
+        let mut doc_data = Vec::new();
+        for (id, doc) in candidates {
+            if doc.is_empty() {
+                warn!("E12 MaxSim: candidate {} has no tokens - scoring 0.0", id);
+            }
+            offsets.push((doc_data.len() / dim, doc.num_tokens()));
+            doc_data.extend_from_slice(doc.as_slice());
+        }
+        let total = doc_data.len() / dim;
+        if total == 0 {
+            return Ok(vec![0.0; candidates.len()]);
+        }
+
+        let device = Device::cuda_if_available(0).map_err(candle_err)?;
+        let q = normalized(query.as_slice(), query.num_tokens(), dim, &device)?;
+        let d = normalized(&doc_data, total, dim, &device)?;
+        let sims: Vec<Vec<f32>> = d
+            .t()
+            .and_then(|dt| q.matmul(&dt))
+            .and_then(|s| s.to_vec2())
+            .map_err(candle_err)?;
+
+        Ok(offsets
+            .into_iter()
+            .map(|(start, len)| {
+                if len == 0 {
+                    return 0.0;
+                }
+                sims.iter()
+                    .map(|row| {
+                        row[start..start + len]
+                            .iter()
+                            .copied()
+                            .fold(f32::NEG_INFINITY, f32::max)
+                    })
+                    .sum()
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit(i: usize) -> [f32; E12_TOKEN_DIM] {
+        let mut v = [0.0; E12_TOKEN_DIM];
+        v[i] = 1.0;
+        v
+    }
+
+    fn normalized_sum(tokens: &[[f32; E12_TOKEN_DIM]]) -> [f32; E12_TOKEN_DIM] {
+        let mut v = [0.0; E12_TOKEN_DIM];
+        for t in tokens {
+            for (acc, x) in v.iter_mut().zip(t) {
+                *acc += x;
+            }
+        }
+        let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        v.map(|x| x / norm)
+    }
+
+    #[test]
+    fn test_maxsim_is_asymmetric() {
+        println!("=== TEST: MaxSim(Q, D) != MaxSim(D, Q) ===");
+
+        let a = unit(0);
+        let b = normalized_sum(&[unit(0), unit(1)]); // cos(a, b) = 0.707
+
+        let q_to_d = maxsim_score(&[a], &[a, b]);
+        let d_to_q = maxsim_score(&[a, b], &[a]);
+        println!("AFTER: Q->D = {q_to_d}, D->Q = {d_to_q}");
+
+        assert!((q_to_d - 1.0).abs() < 1e-5);
+        assert!((d_to_q - (1.0 + std::f32::consts::FRAC_1_SQRT_2)).abs() < 1e-5);
+
+        println!("RESULT: PASS");
+    }
+
+    #[test]
+    fn test_rerank_overturns_pooled_cosine() {
+        println!("=== TEST: MaxSim rerank changes order vs pooled (E1-style) cosine ===");
+
+        let query = [unit(0), unit(1)];
+        // X is one token halfway between both query tokens
+        let x = [normalized_sum(&query)];
+        // Y matches each query token exactly but adds an unrelated token
+        let y = [unit(0), unit(1), unit(2)];
+
+        let q_e1 = normalized_sum(&query);
+        let x_e1 = cosine_similarity_128d(&q_e1, &normalized_sum(&x));
+        let y_e1 = cosine_similarity_128d(&q_e1, &normalized_sum(&y));
+        assert!(x_e1 > y_e1, "E1 cosine ranks X first ({x_e1} vs {y_e1})");
+
+        let (id_x, id_y) = (Uuid::new_v4(), Uuid::new_v4());
+        let (mx, my) = (TokenMatrix::from_tokens(&x), TokenMatrix::from_tokens(&y));
+        let ranked = rerank(
+            &TokenMatrix::from_tokens(&query),
+            &[(id_x, &mx), (id_y, &my)],
+            10,
+        )
+        .unwrap();
+        println!("AFTER: {:?}", ranked);
+
+        assert_eq!(ranked[0].0, id_y, "MaxSim ranks Y first");
+        assert!((ranked[0].1 - 2.0).abs() < 1e-5);
+        assert!((ranked[1].1 - std::f32::consts::SQRT_2).abs() < 1e-5);
+
+        println!("RESULT: PASS");
+    }
+
+    #[test]
+    fn test_empty_and_mismatched_tokens() {
+        println!("=== TEST: Empty matrices score 0, mismatched dims fail fast ===");
+
+        let query = TokenMatrix::from_tokens(&[unit(0)]);
+        let empty = TokenMatrix::new(&[]).unwrap();
+        assert_eq!(maxsim_matrix(&query, &empty).unwrap(), 0.0);
+        assert_eq!(maxsim_score(&[], &[unit(0)]), 0.0);
+
+        let short = TokenMatrix::new(&[vec![1.0; 64]]).unwrap();
+        assert!(matches!(
+            maxsim_matrix(&query, &short),
+            Err(SearchError::DimensionMismatch { expected: 128, actual: 64, .. })
+        ));
+        assert!(rerank(&query, &[(Uuid::new_v4(), &short)], 1).is_err());
+        assert!(TokenMatrix::new(&[vec![0.0; 128], vec![0.0; 64]]).is_err());
+
+        println!("RESULT: PASS");
+    }
+}
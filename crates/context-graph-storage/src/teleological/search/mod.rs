@@ -9,6 +9,8 @@
 //!
 //! - **single**: Single embedder HNSW search (Stage 2/3 of pipeline)
 //! - **multi**: Multi-embedder parallel search with aggregation
+//! - **sparse**: E6/E13 inverted-index lexical search (Stage 1 recall)
+//! - **fusion**: RRF / weighted-sum merge of per-embedder result lists
 //!
 //! # Supported Embedders
 //!
@@ -25,11 +27,14 @@
 //! - E10Multimodal (768D) - Cross-modal embeddings
 //! - E11Entity (768D) - Named entity embeddings
 //!
+//! # Sparse Embedders (Inverted Index)
+//!
+//! - E6Sparse - `SparseSearch` over `e6_sparse_inverted`
+//! - E13Splade - `SparseSearch` over `e13_splade_inverted`
+//!
 //! # NOT Supported (Different Algorithms)
 //!
-//! - E6Sparse - Requires inverted index with BM25
-//! - E12LateInteraction - Requires ColBERT MaxSim token-level
-//! - E13Splade - Requires inverted index with learned expansion
+//! - E12LateInteraction - ColBERT MaxSim rerank, see `late_interaction`
 //!
 //! # Design Philosophy
 //!
@@ -78,12 +83,15 @@
 //! ```
 
 mod error;
+mod fusion;
+mod late_interaction;
 mod matrix;
 mod maxsim;
 mod multi;
 mod pipeline;
 mod result;
 mod single;
+mod sparse;
 pub mod temporal_boost;
 mod token_storage;
 
@@ -94,7 +102,18 @@ pub use error::{SearchError, SearchResult};
 pub use result::{EmbedderSearchHit, SingleEmbedderSearchResults};
 
 // Re-export single embedder search types
-pub use single::{SingleEmbedderSearch, SingleEmbedderSearchConfig};
+pub use single::{
+    snapshot_path, truncate_matryoshka, HnswSnapshotInfo, SingleEmbedderSearch,
+    SingleEmbedderSearchConfig, FILTERED_SEARCH_MAX_CANDIDATES, HNSW_SNAPSHOT_VERSION,
+};
+
+// Re-export per-embedder result fusion
+pub use fusion::{
+    fuse_results, EmbedderWeightMap, FusedHit, FusedResults, FusionMethod, DEFAULT_RRF_K,
+};
+
+// Re-export sparse inverted-index search types
+pub use sparse::{SparseSearch, SparseSearchConfig};
 
 // Re-export multi-embedder search types
 pub use multi::{
@@ -151,6 +170,9 @@ pub use pipeline::{
     TokenStorage,
 };
 
+// Re-export E12 late-interaction scoring (Stage 4 rerank)
+pub use late_interaction::{maxsim_matrix, maxsim_score, rerank as late_interaction_rerank, TokenMatrix};
+
 // Re-export MaxSim scorer types (TASK-STORAGE-P2-001)
 pub use maxsim::{
     // Standalone MaxSim computation
@@ -12,12 +12,11 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Instant;
 
-use rayon::prelude::*;
 use tracing::debug;
 use uuid::Uuid;
 
 use super::super::super::indexes::EmbedderIndex;
-use super::super::maxsim::compute_maxsim_direct;
+use super::super::late_interaction::{self, TokenMatrix};
 use super::super::single::SingleEmbedderSearch;
 use super::traits::{SpladeIndex, TokenStorage};
 use super::types::{
@@ -320,34 +319,34 @@ impl<'a> StageExecutor<'a> {
             });
         }
 
-        // Compute MaxSim scores in parallel using rayon
+        // Load token matrices; skip candidates without token embeddings
+        let query_matrix = TokenMatrix::new(query_tokens)?;
         let token_storage = &self.token_storage;
-        let scored: Vec<(PipelineCandidate, f32)> = candidates
-            .into_par_iter()
-            .filter_map(|mut c| {
-                if let Some(doc_tokens) = token_storage.get_tokens(c.id) {
-                    let maxsim_score = compute_maxsim_direct(query_tokens, &doc_tokens);
-                    c.add_stage_score(PipelineStage::MaxSimRerank, maxsim_score);
-                    Some((c, maxsim_score))
-                } else {
-                    None // Skip candidates without token embeddings
-                }
-            })
-            .collect();
-
-        // Sort by MaxSim score descending
-        let mut new_candidates: Vec<PipelineCandidate> = scored
-            .into_iter()
-            .filter(|(_, score)| *score >= config.min_score_threshold)
-            .map(|(c, _)| c)
-            .collect();
-
-        new_candidates.sort_by(|a, b| {
-            b.score
-                .partial_cmp(&a.score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-        new_candidates.truncate(self.config.k);
+        let mut by_id: HashMap<Uuid, (PipelineCandidate, TokenMatrix)> = HashMap::new();
+        for c in candidates {
+            if let Some(doc_tokens) = token_storage.get_tokens(c.id) {
+                let matrix = TokenMatrix::new(&doc_tokens)?;
+                by_id.insert(c.id, (c, matrix));
+            }
+        }
+        let refs: Vec<(Uuid, &TokenMatrix)> =
+            by_id.iter().map(|(id, (_, m))| (*id, m)).collect();
+        let ranked = late_interaction::rerank(&query_matrix, &refs, self.config.k)?;
+
+        // Map raw MaxSim sum to the [0, 1] mean scale shared with the fusion
+        // pipeline: mean over query tokens of (cos + 1) / 2
+        let num_query_tokens = query_matrix.num_tokens() as f32;
+        let mut new_candidates = Vec::with_capacity(ranked.len());
+        for (id, raw) in ranked {
+            let score = (raw / num_query_tokens + 1.0) / 2.0;
+            if score < config.min_score_threshold {
+                continue;
+            }
+            if let Some((mut c, _)) = by_id.remove(&id) {
+                c.add_stage_score(PipelineStage::MaxSimRerank, score);
+                new_candidates.push(c);
+            }
+        }
 
         let latency_us = stage_start.elapsed().as_micros() as u64;
         let latency_ms = latency_us / 1000;
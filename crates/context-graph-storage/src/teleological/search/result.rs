@@ -158,6 +158,7 @@ impl EmbedderSearchHit {
 ///     embedder: EmbedderIndex::E1Semantic,
 ///     k: 10,
 ///     threshold: Some(0.5),
+///     candidates_scanned: 2,
 ///     latency_us: 150,
 /// };
 ///
@@ -178,6 +179,11 @@ pub struct SingleEmbedderSearchResults {
     /// Threshold applied (if any).
     pub threshold: Option<f32>,
 
+    /// Raw HNSW candidates examined before filtering.
+    ///
+    /// Equals the hit count for plain searches; larger for filtered searches.
+    pub candidates_scanned: usize,
+
     /// Search latency in microseconds.
     pub latency_us: u64,
 }
@@ -333,6 +339,7 @@ mod tests {
             embedder: EmbedderIndex::E1Semantic,
             k: 10,
             threshold: None,
+            candidates_scanned: 0,
             latency_us: 100,
         };
 
@@ -363,6 +370,7 @@ mod tests {
             embedder: EmbedderIndex::E1Semantic,
             k: 10,
             threshold: None,
+            candidates_scanned: 0,
             latency_us: 250,
         };
 
@@ -394,6 +402,7 @@ mod tests {
             embedder: EmbedderIndex::E1Semantic,
             k: 10,
             threshold: None,
+            candidates_scanned: 0,
             latency_us: 100,
         };
 
@@ -418,6 +427,7 @@ mod tests {
             embedder: EmbedderIndex::E1Semantic,
             k: 10,
             threshold: None,
+            candidates_scanned: 0,
             latency_us: 100,
         };
 
@@ -447,6 +457,7 @@ mod tests {
             embedder: EmbedderIndex::E1Semantic,
             k: 10,
             threshold: None,
+            candidates_scanned: 0,
             latency_us: 100,
         };
 
@@ -470,6 +481,7 @@ mod tests {
             embedder: EmbedderIndex::E1Semantic,
             k: 10,
             threshold: None,
+            candidates_scanned: 0,
             latency_us: 100,
         };
 
@@ -49,6 +49,7 @@
 //! ```
 
 mod config;
+mod persist;
 mod search;
 
 #[cfg(test)]
@@ -56,4 +57,7 @@ mod tests;
 
 // Re-export for backwards compatibility
 pub use self::config::SingleEmbedderSearchConfig;
-pub use self::search::SingleEmbedderSearch;
+pub use self::persist::{snapshot_path, HnswSnapshotInfo, HNSW_SNAPSHOT_VERSION};
+pub use self::search::{
+    truncate_matryoshka, SingleEmbedderSearch, FILTERED_SEARCH_MAX_CANDIDATES,
+};
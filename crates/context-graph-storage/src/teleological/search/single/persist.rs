@@ -0,0 +1,268 @@
+//! Per-embedder HNSW snapshot files.
+//!
+//! # File Format (`{Embedder}.hnsw`, little-endian)
+//!
+//! | Field | Type |
+//! |-------|------|
+//! | magic | `b"CGHNSW\0\0"` |
+//! | version | u32 |
+//! | embedder name length + name | u16 + UTF-8 (`Debug` name) |
+//! | dimension | u32 |
+//! | live vector count | u64 |
+//! | snapshot time (ms since epoch) | i64 |
+//! | graph length, metadata length | u64, u64 |
+//! | usearch graph bytes, mapping JSON bytes | - |
+//! | SHA-256 of everything above | 32 bytes |
+//!
+//! Empty indexes are written with zero-length payloads so a complete snapshot
+//! always has one file per registered embedder.
+//!
+//! # FAIL FAST Policy
+//!
+//! Loading rejects bad magic, unknown versions, wrong embedder, dimension
+//! mismatch, count mismatch, and checksum mismatch. A corrupt snapshot never
+//! produces silently wrong neighbors.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, TimeZone, Utc};
+use sha2::{Digest, Sha256};
+
+use crate::teleological::indexes::{EmbedderIndex, EmbedderIndexOps, HnswEmbedderIndex};
+use crate::teleological::search::error::{SearchError, SearchResult};
+
+use super::search::SingleEmbedderSearch;
+
+const SNAPSHOT_MAGIC: &[u8; 8] = b"CGHNSW\0\0";
+
+/// Current snapshot format version.
+pub const HNSW_SNAPSHOT_VERSION: u32 = 1;
+
+const CHECKSUM_LEN: usize = 32;
+
+/// Outcome of [`SingleEmbedderSearch::load`].
+#[derive(Debug, Clone, Default)]
+pub struct HnswSnapshotInfo {
+    /// Embedders restored from snapshot files.
+    pub loaded: Vec<EmbedderIndex>,
+
+    /// Registered embedders with no snapshot file in the directory.
+    pub missing: Vec<EmbedderIndex>,
+
+    /// Oldest snapshot time among loaded files (None if nothing loaded).
+    pub snapshot_at: Option<DateTime<Utc>>,
+}
+
+impl HnswSnapshotInfo {
+    /// Check if every registered embedder was restored.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        !self.loaded.is_empty() && self.missing.is_empty()
+    }
+}
+
+/// Snapshot file path for `embedder` in `dir`.
+pub fn snapshot_path(dir: &Path, embedder: EmbedderIndex) -> PathBuf {
+    dir.join(format!("{:?}.hnsw", embedder))
+}
+
+impl SingleEmbedderSearch {
+    /// Write one snapshot file per registered embedder into `dir`.
+    ///
+    /// Files are written to a temporary name and renamed, so a crash mid-save
+    /// leaves the previous snapshot intact.
+    ///
+    /// # Returns
+    ///
+    /// Number of files written.
+    ///
+    /// # Errors
+    ///
+    /// - `SearchError::Store` on I/O or serialization failure
+    pub fn save(&self, dir: &Path) -> SearchResult<usize> {
+        fs::create_dir_all(dir).map_err(|e| io_error("create", dir, e))?;
+
+        let snapshot_at = Utc::now().timestamp_millis();
+        let mut written = 0;
+        for (embedder, index) in self.registry().iter() {
+            let bytes = encode_snapshot(*embedder, index, snapshot_at)?;
+            let path = snapshot_path(dir, *embedder);
+            let tmp = path.with_extension("hnsw.tmp");
+            fs::write(&tmp, &bytes).map_err(|e| io_error("write", &tmp, e))?;
+            fs::rename(&tmp, &path).map_err(|e| io_error("rename", &path, e))?;
+            written += 1;
+        }
+        Ok(written)
+    }
+
+    /// Restore registered indexes from snapshot files in `dir`.
+    ///
+    /// Embedders without a file are left untouched and reported in
+    /// [`HnswSnapshotInfo::missing`].
+    ///
+    /// # Errors
+    ///
+    /// - `SearchError::DimensionMismatch` if a file's dimension differs from
+    ///   the registered index
+    /// - `SearchError::Store` on I/O failure, bad header, or checksum mismatch
+    pub fn load(&self, dir: &Path) -> SearchResult<HnswSnapshotInfo> {
+        let mut info = HnswSnapshotInfo::default();
+        for (embedder, index) in self.registry().iter() {
+            let path = snapshot_path(dir, *embedder);
+            if !path.exists() {
+                info.missing.push(*embedder);
+                continue;
+            }
+            let bytes = fs::read(&path).map_err(|e| io_error("read", &path, e))?;
+            let snapshot_at = decode_snapshot(*embedder, index, &bytes, &path)?;
+            info.snapshot_at = Some(info.snapshot_at.map_or(snapshot_at, |t| t.min(snapshot_at)));
+            info.loaded.push(*embedder);
+        }
+        Ok(info)
+    }
+}
+
+fn encode_snapshot(
+    embedder: EmbedderIndex,
+    index: &HnswEmbedderIndex,
+    snapshot_at: i64,
+) -> SearchResult<Vec<u8>> {
+    let graph = index
+        .serialize_graph()
+        .map_err(SearchError::Store)?
+        .unwrap_or_default();
+    let meta = index.serialize_metadata().unwrap_or_default();
+    let name = format!("{:?}", embedder);
+
+    let mut buf = Vec::with_capacity(64 + name.len() + graph.len() + meta.len() + CHECKSUM_LEN);
+    buf.extend_from_slice(SNAPSHOT_MAGIC);
+    buf.extend_from_slice(&HNSW_SNAPSHOT_VERSION.to_le_bytes());
+    buf.extend_from_slice(&(name.len() as u16).to_le_bytes());
+    buf.extend_from_slice(name.as_bytes());
+    buf.extend_from_slice(&(index.config().dimension as u32).to_le_bytes());
+    buf.extend_from_slice(&(index.len() as u64).to_le_bytes());
+    buf.extend_from_slice(&snapshot_at.to_le_bytes());
+    buf.extend_from_slice(&(graph.len() as u64).to_le_bytes());
+    buf.extend_from_slice(&(meta.len() as u64).to_le_bytes());
+    buf.extend_from_slice(&graph);
+    buf.extend_from_slice(&meta);
+    let checksum = Sha256::digest(&buf);
+    buf.extend_from_slice(&checksum);
+    Ok(buf)
+}
+
+fn decode_snapshot(
+    embedder: EmbedderIndex,
+    index: &HnswEmbedderIndex,
+    bytes: &[u8],
+    path: &Path,
+) -> SearchResult<DateTime<Utc>> {
+    let corrupt = |message: &str| {
+        SearchError::Store(format!("Corrupt HNSW snapshot {}: {}", path.display(), message))
+    };
+
+    // Verify checksum before trusting any header field
+    if bytes.len() < SNAPSHOT_MAGIC.len() + CHECKSUM_LEN {
+        return Err(corrupt("file too short"));
+    }
+    let (body, checksum) = bytes.split_at(bytes.len() - CHECKSUM_LEN);
+    if Sha256::digest(body).as_slice() != checksum {
+        return Err(corrupt("checksum mismatch"));
+    }
+
+    let mut reader = Reader { bytes: body, pos: 0 };
+    if reader.take(SNAPSHOT_MAGIC.len()).ok_or_else(|| corrupt("truncated"))? != SNAPSHOT_MAGIC {
+        return Err(corrupt("bad magic"));
+    }
+    let version = reader.u32().ok_or_else(|| corrupt("truncated"))?;
+    if version != HNSW_SNAPSHOT_VERSION {
+        return Err(corrupt(&format!(
+            "unsupported version {} (expected {})",
+            version, HNSW_SNAPSHOT_VERSION
+        )));
+    }
+    let name_len = reader.u16().ok_or_else(|| corrupt("truncated"))? as usize;
+    let name = reader.take(name_len).ok_or_else(|| corrupt("truncated"))?;
+    if name != format!("{:?}", embedder).as_bytes() {
+        return Err(corrupt(&format!(
+            "embedder {} does not match {:?}",
+            String::from_utf8_lossy(name),
+            embedder
+        )));
+    }
+    let dimension = reader.u32().ok_or_else(|| corrupt("truncated"))? as usize;
+    if dimension != index.config().dimension {
+        return Err(SearchError::DimensionMismatch {
+            embedder,
+            expected: index.config().dimension,
+            actual: dimension,
+        });
+    }
+    let count = reader.u64().ok_or_else(|| corrupt("truncated"))? as usize;
+    let snapshot_ms = reader.i64().ok_or_else(|| corrupt("truncated"))?;
+    let graph_len = reader.u64().ok_or_else(|| corrupt("truncated"))? as usize;
+    let meta_len = reader.u64().ok_or_else(|| corrupt("truncated"))? as usize;
+    let graph = reader.take(graph_len).ok_or_else(|| corrupt("truncated graph"))?;
+    let meta = reader.take(meta_len).ok_or_else(|| corrupt("truncated metadata"))?;
+    if reader.pos != body.len() {
+        return Err(corrupt("trailing bytes"));
+    }
+
+    let snapshot_at = Utc
+        .timestamp_millis_opt(snapshot_ms)
+        .single()
+        .ok_or_else(|| corrupt(&format!("invalid timestamp {}", snapshot_ms)))?;
+
+    if count == 0 {
+        index.clear();
+        return Ok(snapshot_at);
+    }
+
+    let restored = index
+        .restore_from_persisted(graph, meta)
+        .map_err(|e| corrupt(&e))?;
+    if restored != count {
+        index.clear();
+        return Err(corrupt(&format!(
+            "header count {} but {} mappings restored",
+            count, restored
+        )));
+    }
+    Ok(snapshot_at)
+}
+
+fn io_error(op: &str, path: &Path, e: std::io::Error) -> SearchError {
+    SearchError::Store(format!("Failed to {} {}: {}", op, path.display(), e))
+}
+
+/// Bounds-checked little-endian reader over a snapshot body.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        let end = self.pos.checked_add(n)?;
+        let slice = self.bytes.get(self.pos..end)?;
+        self.pos = end;
+        Some(slice)
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_le_bytes(self.take(2)?.try_into().ok()?))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.take(8)?.try_into().ok()?))
+    }
+
+    fn i64(&mut self) -> Option<i64> {
+        Some(i64::from_le_bytes(self.take(8)?.try_into().ok()?))
+    }
+}
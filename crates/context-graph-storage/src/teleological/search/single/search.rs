@@ -5,12 +5,61 @@ use std::time::Instant;
 
 use uuid::Uuid;
 
-use crate::teleological::indexes::{EmbedderIndex, EmbedderIndexOps, EmbedderIndexRegistry};
+use crate::teleological::indexes::{
+    EmbedderIndex, EmbedderIndexOps, EmbedderIndexRegistry, HnswEmbedderIndex, E1_MATRYOSHKA_DIM,
+};
 use crate::teleological::search::error::{SearchError, SearchResult};
 use crate::teleological::search::result::{EmbedderSearchHit, SingleEmbedderSearchResults};
 
 use super::config::SingleEmbedderSearchConfig;
 
+/// Hard cap on candidates fetched by [`SingleEmbedderSearch::search_filtered`].
+///
+/// Bounds latency for filters that almost nothing passes.
+pub const FILTERED_SEARCH_MAX_CANDIDATES: usize = 10_000;
+
+/// Truncate an E1 vector to its 128D Matryoshka prefix and L2-renormalize.
+///
+/// # Errors
+///
+/// - `SearchError::DimensionMismatch` if `vector` is shorter than 128D
+/// - `SearchError::InvalidVector` if the prefix has zero or non-finite norm
+pub fn truncate_matryoshka(vector: &[f32]) -> SearchResult<Vec<f32>> {
+    let embedder = EmbedderIndex::E1Matryoshka128;
+    if vector.len() < E1_MATRYOSHKA_DIM {
+        return Err(SearchError::DimensionMismatch {
+            embedder,
+            expected: E1_MATRYOSHKA_DIM,
+            actual: vector.len(),
+        });
+    }
+
+    let prefix = &vector[..E1_MATRYOSHKA_DIM];
+    let norm = prefix.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if !norm.is_finite() || norm < f32::EPSILON {
+        return Err(SearchError::InvalidVector {
+            embedder,
+            message: format!("Matryoshka prefix has invalid norm {}", norm),
+        });
+    }
+    Ok(prefix.iter().map(|v| v / norm).collect())
+}
+
+fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    let (mut dot, mut na, mut nb) = (0.0f32, 0.0f32, 0.0f32);
+    for (x, y) in a.iter().zip(b) {
+        dot += x * y;
+        na += x * x;
+        nb += y * y;
+    }
+    let denom = na.sqrt() * nb.sqrt();
+    if denom < f32::EPSILON {
+        0.0
+    } else {
+        dot / denom
+    }
+}
+
 /// Single embedder HNSW search.
 ///
 /// Queries ONE of the 12 HNSW-capable indexes and returns ranked results
@@ -142,12 +191,14 @@ impl SingleEmbedderSearch {
                 embedder,
                 k,
                 threshold,
+                candidates_scanned: 0,
                 latency_us: start.elapsed().as_micros() as u64,
             });
         }
 
         // Execute HNSW search
         let raw_results = index.search(query, k, self.config.ef_search)?;
+        let candidates_scanned = raw_results.len();
 
         // Convert to hits with similarity scores
         let mut hits: Vec<EmbedderSearchHit> = raw_results
@@ -173,6 +224,160 @@ impl SingleEmbedderSearch {
             embedder,
             k,
             threshold,
+            candidates_scanned,
+            latency_us: start.elapsed().as_micros() as u64,
+        })
+    }
+
+    /// Filtered k-NN: top `k` hits among ids accepted by `filter`.
+    ///
+    /// Over-fetches with progressive expansion (2k, 4k, 8k, ...) until `k`
+    /// candidates pass, the index is exhausted, or
+    /// [`FILTERED_SEARCH_MAX_CANDIDATES`] is reached. Unlike post-filtering
+    /// a global top-k, selective filters still return a full result set.
+    ///
+    /// `candidates_scanned` in the result reports how many raw candidates the
+    /// final expansion round fetched, so callers can tune selectivity.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::search`].
+    pub fn search_filtered(
+        &self,
+        embedder: EmbedderIndex,
+        query: &[f32],
+        k: usize,
+        filter: &dyn Fn(Uuid) -> bool,
+    ) -> SearchResult<SingleEmbedderSearchResults> {
+        let start = Instant::now();
+
+        let index = self.index_for(embedder)?;
+        self.validate_query(embedder, query)?;
+
+        let mut hits = Vec::new();
+        let mut candidates_scanned = 0;
+
+        if k > 0 {
+            let available = index.len();
+            let mut fetch = k.saturating_mul(2).min(FILTERED_SEARCH_MAX_CANDIDATES);
+            loop {
+                let raw_results = index.search(query, fetch, self.config.ef_search)?;
+                candidates_scanned = raw_results.len();
+
+                // Results are ranked, so re-filtering each round keeps the
+                // true filtered order without merging across rounds
+                hits = raw_results
+                    .into_iter()
+                    .filter(|(id, _)| filter(*id))
+                    .take(k)
+                    .map(|(id, distance)| EmbedderSearchHit::from_hnsw(id, distance, embedder))
+                    .collect();
+
+                let exhausted = candidates_scanned < fetch || fetch >= available;
+                if hits.len() >= k || exhausted || fetch >= FILTERED_SEARCH_MAX_CANDIDATES {
+                    break;
+                }
+                fetch = fetch.saturating_mul(2).min(FILTERED_SEARCH_MAX_CANDIDATES);
+            }
+        }
+
+        hits.sort_by(|a, b| {
+            b.similarity
+                .partial_cmp(&a.similarity)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        Ok(SingleEmbedderSearchResults {
+            hits,
+            embedder,
+            k,
+            threshold: None,
+            candidates_scanned,
+            latency_us: start.elapsed().as_micros() as u64,
+        })
+    }
+
+    /// Two-stage E1 search: 128D Matryoshka candidates, 1024D rescoring.
+    ///
+    /// Truncates `query` to its renormalized 128D prefix, fetches
+    /// `k * refine_factor` candidates from the E1Matryoshka128 index, then
+    /// rescores them by exact cosine against their full E1Semantic vectors
+    /// (read from the E1Semantic index) and returns the top `k`.
+    ///
+    /// Hits are reported under `E1Semantic`. `candidates_scanned` is the
+    /// number of coarse candidates rescored.
+    ///
+    /// # Errors
+    ///
+    /// - `SearchError::DimensionMismatch` if `query` is not 1024D
+    /// - `SearchError::InvalidVector` if `query` has NaN/Inf or a zero prefix
+    pub fn matryoshka_search(
+        &self,
+        query: &[f32],
+        k: usize,
+        refine_factor: usize,
+    ) -> SearchResult<SingleEmbedderSearchResults> {
+        let full = self.index_for(EmbedderIndex::E1Semantic)?;
+        self.matryoshka_search_with(query, k, refine_factor, &|id| Ok(full.get_vector(id)?))
+    }
+
+    /// [`Self::matryoshka_search`] with a caller-supplied full-vector loader.
+    ///
+    /// Use when the 1024D vectors live outside the E1Semantic index (e.g. in
+    /// stored fingerprints). Candidates the loader returns `None` for are
+    /// dropped.
+    pub fn matryoshka_search_with(
+        &self,
+        query: &[f32],
+        k: usize,
+        refine_factor: usize,
+        load_full: &dyn Fn(Uuid) -> SearchResult<Option<Vec<f32>>>,
+    ) -> SearchResult<SingleEmbedderSearchResults> {
+        let start = Instant::now();
+        let embedder = EmbedderIndex::E1Semantic;
+
+        // FAIL FAST: Validate full query, then derive the truncated one
+        self.validate_query(embedder, query)?;
+        let truncated = truncate_matryoshka(query)?;
+        let coarse_index = self.index_for(EmbedderIndex::E1Matryoshka128)?;
+
+        let mut hits = Vec::new();
+        let mut candidates_scanned = 0;
+
+        if k > 0 {
+            let fetch = k.saturating_mul(refine_factor.max(1));
+            let coarse = coarse_index.search(&truncated, fetch, self.config.ef_search)?;
+            candidates_scanned = coarse.len();
+
+            for (id, _) in coarse {
+                let Some(vector) = load_full(id)? else {
+                    continue;
+                };
+                if vector.len() != query.len() {
+                    return Err(SearchError::DimensionMismatch {
+                        embedder,
+                        expected: query.len(),
+                        actual: vector.len(),
+                    });
+                }
+                let distance = 1.0 - cosine(query, &vector);
+                hits.push(EmbedderSearchHit::from_hnsw(id, distance, embedder));
+            }
+
+            hits.sort_by(|a, b| {
+                a.distance
+                    .partial_cmp(&b.distance)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            hits.truncate(k);
+        }
+
+        Ok(SingleEmbedderSearchResults {
+            hits,
+            embedder,
+            k,
+            threshold: None,
+            candidates_scanned,
             latency_us: start.elapsed().as_micros() as u64,
         })
     }
@@ -229,6 +434,62 @@ impl SingleEmbedderSearch {
             .collect())
     }
 
+    /// Remove `id` from an embedder index (tombstone).
+    ///
+    /// The vector stays in the HNSW graph until [`Self::rebuild`], but search
+    /// never returns it again, regardless of `k`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the id was present, `false` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// - `SearchError::UnsupportedEmbedder` if embedder is E6/E12/E13
+    /// - `SearchError::Store` if the index is missing from the registry
+    pub fn remove(&self, embedder: EmbedderIndex, id: Uuid) -> SearchResult<bool> {
+        Ok(self.index_for(embedder)?.remove(id)?)
+    }
+
+    /// Replace the vector stored for `id` (remove + insert).
+    ///
+    /// The previous vector is tombstoned and the new one inserted under the
+    /// same id in a single locked operation. Inserts if `id` was not present.
+    ///
+    /// # Errors
+    ///
+    /// - `SearchError::UnsupportedEmbedder` if embedder is E6/E12/E13
+    /// - `SearchError::Index` if the vector has the wrong dimension or NaN/Inf
+    pub fn update(&self, embedder: EmbedderIndex, id: Uuid, vector: &[f32]) -> SearchResult<()> {
+        Ok(self.index_for(embedder)?.insert(id, vector)?)
+    }
+
+    /// Whether tombstones make up enough of the index to justify a rebuild.
+    ///
+    /// Uses the index's compaction heuristic (> 20% of graph nodes orphaned).
+    pub fn compaction_needed(&self, embedder: EmbedderIndex) -> SearchResult<bool> {
+        Ok(self.index_for(embedder)?.needs_compaction())
+    }
+
+    /// Rebuild an embedder index from its live vectors, dropping all tombstones.
+    ///
+    /// # Returns
+    ///
+    /// Number of live vectors in the rebuilt index.
+    pub fn rebuild(&self, embedder: EmbedderIndex) -> SearchResult<usize> {
+        Ok(self.index_for(embedder)?.rebuild()?)
+    }
+
+    /// Look up an HNSW index in the registry. FAIL FAST on non-HNSW embedders.
+    fn index_for(&self, embedder: EmbedderIndex) -> SearchResult<&Arc<HnswEmbedderIndex>> {
+        if !embedder.uses_hnsw() {
+            return Err(SearchError::UnsupportedEmbedder { embedder });
+        }
+        self.registry.get(embedder).ok_or_else(|| {
+            SearchError::Store(format!("Index not found for {:?} in registry", embedder))
+        })
+    }
+
     /// Validate query vector. FAIL FAST on invalid input.
     fn validate_query(&self, embedder: EmbedderIndex, query: &[f32]) -> SearchResult<()> {
         // Check empty
@@ -1,5 +1,6 @@
 //! Tests for single embedder search.
 
 mod integration;
+mod persist;
 mod search;
 mod validation;
@@ -0,0 +1,140 @@
+//! Snapshot save/load tests for single embedder search.
+
+use std::sync::Arc;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use sha2::{Digest, Sha256};
+use tempfile::TempDir;
+use uuid::Uuid;
+
+use crate::teleological::indexes::{EmbedderIndex, EmbedderIndexOps, EmbedderIndexRegistry};
+use crate::teleological::search::error::SearchError;
+use crate::teleological::search::single::persist::snapshot_path;
+use crate::teleological::search::single::search::SingleEmbedderSearch;
+
+fn random_vector(rng: &mut StdRng, dim: usize) -> Vec<f32> {
+    (0..dim).map(|_| rng.gen::<f32>() - 0.5).collect()
+}
+
+/// Search over a fresh registry with 1k E8Graph vectors, saved to a temp dir.
+fn saved_search(rng: &mut StdRng) -> (TempDir, SingleEmbedderSearch) {
+    let search = SingleEmbedderSearch::new(Arc::new(EmbedderIndexRegistry::new()));
+    for _ in 0..1000 {
+        let vector = random_vector(rng, 1024);
+        search
+            .update(EmbedderIndex::E8Graph, Uuid::new_v4(), &vector)
+            .unwrap();
+    }
+    let tmp = TempDir::new().unwrap();
+    let written = search.save(tmp.path()).unwrap();
+    assert_eq!(written, search.registry().len());
+    (tmp, search)
+}
+
+/// Rewrite a snapshot file with `edit` applied to its body and a fresh checksum.
+fn rewrite_body(path: &std::path::Path, edit: impl FnOnce(&mut Vec<u8>)) {
+    let bytes = std::fs::read(path).unwrap();
+    let mut body = bytes[..bytes.len() - 32].to_vec();
+    edit(&mut body);
+    let checksum = Sha256::digest(&body);
+    body.extend_from_slice(&checksum);
+    std::fs::write(path, body).unwrap();
+}
+
+// ========== ROUND TRIP TESTS ==========
+
+#[test]
+fn test_save_load_preserves_top_10() {
+    println!("=== TEST: 1k-vector snapshot reload returns identical top-10 for 20 queries ===");
+
+    let mut rng = StdRng::seed_from_u64(782);
+    let (tmp, original) = saved_search(&mut rng);
+
+    let reloaded = SingleEmbedderSearch::new(Arc::new(EmbedderIndexRegistry::new()));
+    let info = reloaded.load(tmp.path()).unwrap();
+    println!("AFTER: loaded {} embedders, missing {:?}", info.loaded.len(), info.missing);
+
+    assert!(info.is_complete());
+    assert!(info.snapshot_at.is_some());
+    assert_eq!(
+        reloaded.registry().get(EmbedderIndex::E8Graph).unwrap().len(),
+        1000
+    );
+
+    for _ in 0..20 {
+        let query = random_vector(&mut rng, 1024);
+        let before = original.search(EmbedderIndex::E8Graph, &query, 10, None).unwrap();
+        let after = reloaded.search(EmbedderIndex::E8Graph, &query, 10, None).unwrap();
+        assert_eq!(before.ids(), after.ids());
+    }
+
+    println!("RESULT: PASS");
+}
+
+#[test]
+fn test_load_reports_missing_files() {
+    println!("=== TEST: Embedders without a snapshot file are reported missing ===");
+
+    let mut rng = StdRng::seed_from_u64(7820);
+    let (tmp, _) = saved_search(&mut rng);
+    std::fs::remove_file(snapshot_path(tmp.path(), EmbedderIndex::E7Code)).unwrap();
+
+    let reloaded = SingleEmbedderSearch::new(Arc::new(EmbedderIndexRegistry::new()));
+    let info = reloaded.load(tmp.path()).unwrap();
+
+    assert!(!info.is_complete());
+    assert_eq!(info.missing, vec![EmbedderIndex::E7Code]);
+
+    println!("RESULT: PASS");
+}
+
+// ========== FAIL FAST TESTS ==========
+
+#[test]
+fn test_load_rejects_checksum_mismatch() {
+    println!("=== TEST: Flipped byte fails checksum instead of loading ===");
+
+    let mut rng = StdRng::seed_from_u64(7821);
+    let (tmp, _) = saved_search(&mut rng);
+    let path = snapshot_path(tmp.path(), EmbedderIndex::E8Graph);
+    let mut bytes = std::fs::read(&path).unwrap();
+    let mid = bytes.len() / 2;
+    bytes[mid] ^= 0xFF;
+    std::fs::write(&path, bytes).unwrap();
+
+    let reloaded = SingleEmbedderSearch::new(Arc::new(EmbedderIndexRegistry::new()));
+    let err = reloaded.load(tmp.path()).unwrap_err();
+    println!("AFTER: error = {}", err);
+    assert!(err.to_string().contains("checksum"));
+
+    println!("RESULT: PASS");
+}
+
+#[test]
+fn test_load_rejects_dimension_mismatch() {
+    println!("=== TEST: Header dimension differing from the index fails fast ===");
+
+    let mut rng = StdRng::seed_from_u64(7822);
+    let (tmp, _) = saved_search(&mut rng);
+    let path = snapshot_path(tmp.path(), EmbedderIndex::E8Graph);
+
+    // magic (8) + version (4) + name length (2) + name
+    let dim_offset = 8 + 4 + 2 + "E8Graph".len();
+    rewrite_body(&path, |body| {
+        body[dim_offset..dim_offset + 4].copy_from_slice(&512u32.to_le_bytes());
+    });
+
+    let reloaded = SingleEmbedderSearch::new(Arc::new(EmbedderIndexRegistry::new()));
+    let err = reloaded.load(tmp.path()).unwrap_err();
+    assert!(matches!(
+        err,
+        SearchError::DimensionMismatch {
+            embedder: EmbedderIndex::E8Graph,
+            expected: 1024,
+            actual: 512,
+        }
+    ));
+
+    println!("RESULT: PASS");
+}
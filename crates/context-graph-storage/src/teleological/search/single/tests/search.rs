@@ -9,7 +9,7 @@ use uuid::Uuid;
 use crate::teleological::indexes::{EmbedderIndex, EmbedderIndexOps, EmbedderIndexRegistry};
 
 use crate::teleological::search::single::config::SingleEmbedderSearchConfig;
-use crate::teleological::search::single::search::SingleEmbedderSearch;
+use crate::teleological::search::single::search::{truncate_matryoshka, SingleEmbedderSearch};
 
 fn create_test_search() -> SingleEmbedderSearch {
     let registry = Arc::new(EmbedderIndexRegistry::new());
@@ -358,3 +358,272 @@ fn test_latency_recorded() {
     // TEST-11 FIX: Verify search completed and latency is within reasonable bounds.
     assert!(result.latency_us < 10_000_000, "Latency should be under 10s, got {} us", result.latency_us);
 }
+
+// ========== TOMBSTONE TESTS ==========
+
+#[test]
+fn test_removed_ids_never_returned() {
+    println!("=== TEST: Tombstoned ids are never returned, even with large k ===");
+
+    let registry = Arc::new(EmbedderIndexRegistry::new());
+    let search = SingleEmbedderSearch::new(Arc::clone(&registry));
+    let index = registry.get(EmbedderIndex::E8Graph).unwrap();
+
+    let ids: Vec<Uuid> = (0..100).map(|_| Uuid::new_v4()).collect();
+    for id in &ids {
+        let vector: Vec<f32> = (0..1024).map(|_| rand_float()).collect();
+        index.insert(*id, &vector).unwrap();
+    }
+    for id in &ids[..20] {
+        assert!(search.remove(EmbedderIndex::E8Graph, *id).unwrap());
+    }
+    // Exactly 20% tombstones is not yet over the threshold
+    assert!(!search.compaction_needed(EmbedderIndex::E8Graph).unwrap());
+    for id in &ids[20..30] {
+        assert!(search.remove(EmbedderIndex::E8Graph, *id).unwrap());
+    }
+    assert!(search.compaction_needed(EmbedderIndex::E8Graph).unwrap());
+
+    let query: Vec<f32> = (0..1024).map(|_| rand_float()).collect();
+    let result = search
+        .search(EmbedderIndex::E8Graph, &query, 100, None)
+        .unwrap();
+    println!("AFTER REMOVE: {} hits", result.len());
+    assert_eq!(result.len(), 70);
+    assert!(result.hits.iter().all(|h| !ids[..30].contains(&h.id)));
+
+    assert_eq!(search.rebuild(EmbedderIndex::E8Graph).unwrap(), 70);
+    assert!(!search.compaction_needed(EmbedderIndex::E8Graph).unwrap());
+
+    let result = search
+        .search(EmbedderIndex::E8Graph, &query, 100, None)
+        .unwrap();
+    println!("AFTER REBUILD: {} hits", result.len());
+    assert_eq!(result.len(), 70);
+    assert!(result.hits.iter().all(|h| !ids[..30].contains(&h.id)));
+
+    println!("RESULT: PASS");
+}
+
+#[test]
+fn test_update_changes_rank() {
+    println!("=== TEST: update replaces the vector and changes its rank ===");
+
+    let search = create_test_search();
+    let ids: Vec<Uuid> = (0..20).map(|_| Uuid::new_v4()).collect();
+    for id in &ids {
+        let vector: Vec<f32> = (0..1024).map(|_| rand_float()).collect();
+        search.update(EmbedderIndex::E8Graph, *id, &vector).unwrap();
+    }
+
+    let query: Vec<f32> = (0..1024).map(|_| rand_float()).collect();
+    let before = search
+        .search(EmbedderIndex::E8Graph, &query, 20, None)
+        .unwrap();
+    let target = before.hits.last().unwrap().id;
+    assert_ne!(before.top().unwrap().id, target);
+
+    search.update(EmbedderIndex::E8Graph, target, &query).unwrap();
+
+    let after = search
+        .search(EmbedderIndex::E8Graph, &query, 20, None)
+        .unwrap();
+    println!("AFTER UPDATE: top={}", after.top().unwrap().id);
+    assert_eq!(after.len(), 20, "update must not duplicate the id");
+    assert_eq!(after.top().unwrap().id, target);
+
+    println!("RESULT: PASS");
+}
+
+// ========== FILTERED SEARCH TESTS ==========
+
+#[test]
+fn test_search_filtered_selective_filter_returns_true_top_k() {
+    println!("=== TEST: search_filtered with 1% pass rate returns exact filtered top-k ===");
+
+    let registry = Arc::new(EmbedderIndexRegistry::new());
+    let search = SingleEmbedderSearch::new(Arc::clone(&registry));
+    let index = registry.get(EmbedderIndex::E8Graph).unwrap();
+
+    let mut vectors = Vec::new();
+    for _ in 0..2000 {
+        let id = Uuid::new_v4();
+        let vector: Vec<f32> = (0..1024).map(|_| rand_float() - 0.5).collect();
+        index.insert(id, &vector).unwrap();
+        vectors.push((id, vector));
+    }
+    // Every 100th vector passes: 20 of 2000
+    let allowed: std::collections::HashSet<Uuid> =
+        vectors.iter().step_by(100).map(|(id, _)| *id).collect();
+
+    let query: Vec<f32> = (0..1024).map(|_| rand_float() - 0.5).collect();
+    let cosine = |v: &[f32]| {
+        let dot: f32 = v.iter().zip(&query).map(|(a, b)| a * b).sum();
+        let norm = |x: &[f32]| x.iter().map(|a| a * a).sum::<f32>().sqrt();
+        dot / (norm(v) * norm(&query))
+    };
+    let mut expected: Vec<(Uuid, f32)> = vectors
+        .iter()
+        .filter(|(id, _)| allowed.contains(id))
+        .map(|(id, v)| (*id, cosine(v)))
+        .collect();
+    expected.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+    let expected: Vec<Uuid> = expected.into_iter().take(10).map(|(id, _)| id).collect();
+
+    let result = search
+        .search_filtered(EmbedderIndex::E8Graph, &query, 10, &|id| allowed.contains(&id))
+        .unwrap();
+    println!(
+        "AFTER: {} hits, {} candidates scanned",
+        result.len(),
+        result.candidates_scanned
+    );
+
+    assert_eq!(result.len(), 10);
+    assert_eq!(result.ids(), expected);
+    assert!(result.candidates_scanned > 10 * 2, "selective filter must expand");
+    assert!(result.candidates_scanned <= 2000);
+
+    println!("RESULT: PASS");
+}
+
+#[test]
+fn test_search_filtered_stops_when_index_exhausted() {
+    println!("=== TEST: search_filtered returns fewer than k when too few pass ===");
+
+    let search = create_test_search();
+    let ids: Vec<Uuid> = (0..50).map(|_| Uuid::new_v4()).collect();
+    for id in &ids {
+        let vector: Vec<f32> = (0..1024).map(|_| rand_float()).collect();
+        search.update(EmbedderIndex::E8Graph, *id, &vector).unwrap();
+    }
+
+    let query = vec![0.5f32; 1024];
+    let result = search
+        .search_filtered(EmbedderIndex::E8Graph, &query, 10, &|id| id == ids[7])
+        .unwrap();
+
+    assert_eq!(result.ids(), vec![ids[7]]);
+    assert_eq!(result.candidates_scanned, 50);
+
+    println!("RESULT: PASS");
+}
+
+// ========== MATRYOSHKA TWO-STAGE TESTS ==========
+
+#[test]
+fn test_truncate_matryoshka_renormalizes() {
+    println!("=== TEST: Matryoshka truncation keeps 128D prefix at unit norm ===");
+
+    let vector: Vec<f32> = (0..1024).map(|i| (i % 7) as f32 + 1.0).collect();
+    let truncated = truncate_matryoshka(&vector).unwrap();
+
+    assert_eq!(truncated.len(), 128);
+    let norm: f32 = truncated.iter().map(|v| v * v).sum::<f32>().sqrt();
+    assert!((norm - 1.0).abs() < 1e-5, "norm = {norm}");
+    // Direction of the prefix is preserved
+    let scale = truncated[0] / vector[0];
+    for (t, v) in truncated.iter().zip(&vector[..128]) {
+        assert!((t - v * scale).abs() < 1e-6);
+    }
+
+    let mut zero_prefix = vec![0.0f32; 1024];
+    zero_prefix[500] = 1.0;
+    assert!(truncate_matryoshka(&zero_prefix).is_err());
+    assert!(truncate_matryoshka(&[1.0; 64]).is_err());
+
+    println!("RESULT: PASS");
+}
+
+#[test]
+fn test_matryoshka_search_recall_at_10() {
+    println!("=== TEST: Two-stage Matryoshka search recall@10 >= 0.95 on 5k vectors ===");
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::{HashMap, HashSet};
+
+    let registry = Arc::new(EmbedderIndexRegistry::new());
+    let search = SingleEmbedderSearch::new(Arc::clone(&registry));
+    let coarse = registry.get(EmbedderIndex::E1Matryoshka128).unwrap();
+
+    // Matryoshka-trained embeddings front-load energy into early dimensions;
+    // emulate with an exponentially decaying per-dimension scale
+    let mut rng = StdRng::seed_from_u64(781);
+    let mut random_vector = || -> Vec<f32> {
+        (0..1024)
+            .map(|i| (rng.gen::<f32>() - 0.5) * (-(i as f32) / 32.0).exp())
+            .collect()
+    };
+
+    let mut full: HashMap<Uuid, Vec<f32>> = HashMap::new();
+    for i in 0..5000u128 {
+        let id = Uuid::from_u128(i + 1);
+        let vector = random_vector();
+        coarse.insert(id, &truncate_matryoshka(&vector).unwrap()).unwrap();
+        full.insert(id, vector);
+    }
+
+    let cosine = |a: &[f32], b: &[f32]| {
+        let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        let norm = |x: &[f32]| x.iter().map(|v| v * v).sum::<f32>().sqrt();
+        dot / (norm(a) * norm(b))
+    };
+    let load = |id: Uuid| Ok(full.get(&id).cloned());
+
+    let queries = 20;
+    let mut total_recall = 0.0;
+    for _ in 0..queries {
+        let query = random_vector();
+
+        let mut exact: Vec<(Uuid, f32)> =
+            full.iter().map(|(id, v)| (*id, cosine(&query, v))).collect();
+        exact.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        let expected: HashSet<Uuid> = exact.iter().take(10).map(|(id, _)| *id).collect();
+
+        let result = search.matryoshka_search_with(&query, 10, 4, &load).unwrap();
+        assert_eq!(result.len(), 10);
+        assert_eq!(result.embedder, EmbedderIndex::E1Semantic);
+        assert_eq!(result.candidates_scanned, 40);
+
+        let found = result.ids().iter().filter(|id| expected.contains(id)).count();
+        total_recall += found as f32 / 10.0;
+    }
+
+    let recall = total_recall / queries as f32;
+    println!("AFTER: mean recall@10 = {:.3}", recall);
+    assert!(recall >= 0.95, "recall@10 {recall} < 0.95");
+
+    println!("RESULT: PASS");
+}
+
+#[test]
+fn test_matryoshka_search_reads_full_vectors_from_e1_index() {
+    println!("=== TEST: matryoshka_search rescores with E1Semantic index vectors ===");
+
+    let search = create_test_search();
+    let ids: Vec<Uuid> = (0..50).map(|_| Uuid::new_v4()).collect();
+    let mut vectors = Vec::new();
+    for id in &ids {
+        let vector: Vec<f32> = (0..1024).map(|_| rand_float() - 0.5).collect();
+        search.update(EmbedderIndex::E1Semantic, *id, &vector).unwrap();
+        search
+            .update(
+                EmbedderIndex::E1Matryoshka128,
+                *id,
+                &truncate_matryoshka(&vector).unwrap(),
+            )
+            .unwrap();
+        vectors.push(vector);
+    }
+
+    let result = search.matryoshka_search(&vectors[3], 5, 4).unwrap();
+    assert_eq!(result.top().unwrap().id, ids[3]);
+    assert!(result.top().unwrap().similarity > 0.99);
+    assert!(result.len() <= 5);
+
+    // Query must be full 1024D
+    assert!(search.matryoshka_search(&vectors[3][..128], 5, 4).is_err());
+
+    println!("RESULT: PASS");
+}
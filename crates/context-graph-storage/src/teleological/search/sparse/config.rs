@@ -0,0 +1,50 @@
+//! Configuration types for sparse inverted-index search.
+
+/// Sparse search configuration.
+///
+/// # Fields
+///
+/// - `max_postings_per_term`: Postings scanned per query term
+/// - `max_document_frequency`: Terms in more documents are treated as stopwords
+/// - `length_normalization`: BM25 `b` parameter, None = raw dot product
+///
+/// # Example
+///
+/// ```
+/// use context_graph_storage::teleological::search::SparseSearchConfig;
+///
+/// let config = SparseSearchConfig {
+///     max_postings_per_term: 5_000,
+///     max_document_frequency: 50_000,
+///     length_normalization: Some(0.75),
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct SparseSearchConfig {
+    /// Maximum postings scanned per query term.
+    ///
+    /// Posting lists are stored sorted by UUID and truncated to this length,
+    /// bounding work for very common terms that survive the stopword cut.
+    pub max_postings_per_term: usize,
+
+    /// Terms present in more than this many documents are skipped.
+    ///
+    /// Document frequency is the full posting list length, before capping.
+    pub max_document_frequency: usize,
+
+    /// BM25-style length normalization strength `b` in [0.0, 1.0].
+    ///
+    /// Scores are divided by `1 - b + b * nnz / avg_nnz`, where `avg_nnz` is
+    /// the mean non-zero count over scored candidates. None = raw dot product.
+    pub length_normalization: Option<f32>,
+}
+
+impl Default for SparseSearchConfig {
+    fn default() -> Self {
+        Self {
+            max_postings_per_term: 10_000,
+            max_document_frequency: 100_000,
+            length_normalization: None,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Sparse lexical search over the E6/E13 inverted indexes.
+//!
+//! Stage 1 of the retrieval pipeline needs lexical recall that HNSW cannot
+//! provide. This module queries the `e6_sparse_inverted` /
+//! `e13_splade_inverted` column families directly.
+//!
+//! # Algorithm
+//!
+//! 1. Fetch posting lists for every active query term (one `multi_get_cf`)
+//! 2. Skip stopword-like terms whose document frequency exceeds a threshold
+//! 3. Cap postings scanned per term
+//! 4. Score each candidate by dot product of query and stored term weights,
+//!    optionally with BM25-style document length normalization
+//!
+//! # Supported Embedders
+//!
+//! - E6Sparse - `e6_sparse_inverted`
+//! - E13Splade - `e13_splade_inverted`
+
+mod config;
+mod search;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::config::SparseSearchConfig;
+pub use self::search::SparseSearch;
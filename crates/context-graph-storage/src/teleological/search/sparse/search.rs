@@ -0,0 +1,191 @@
+//! Sparse inverted-index search implementation.
+
+use std::collections::HashSet;
+
+use rocksdb::DB;
+use uuid::Uuid;
+
+use context_graph_core::types::fingerprint::SparseVector;
+
+use crate::teleological::column_families::{
+    CF_E13_SPLADE_INVERTED, CF_E6_SPARSE_INVERTED, CF_FINGERPRINTS,
+};
+use crate::teleological::indexes::EmbedderIndex;
+use crate::teleological::schema::{
+    e13_splade_inverted_key, e6_sparse_inverted_key, fingerprint_key,
+};
+use crate::teleological::search::error::{SearchError, SearchResult};
+use crate::teleological::search::result::EmbedderSearchHit;
+use crate::teleological::serialization::{
+    deserialize_memory_id_list, deserialize_teleological_fingerprint,
+};
+
+use super::config::SparseSearchConfig;
+
+/// Sparse lexical search over an inverted index column family.
+///
+/// Borrows the RocksDB handle, so it is cheap to construct per query.
+///
+/// # Example
+///
+/// ```no_run
+/// use context_graph_storage::teleological::search::SparseSearch;
+/// use context_graph_storage::teleological::indexes::EmbedderIndex;
+/// use context_graph_core::types::fingerprint::SparseVector;
+/// # fn example(db: &rocksdb::DB) {
+/// let query = SparseVector::new(vec![101, 2054], vec![0.8, 0.3]).unwrap();
+/// let hits = SparseSearch::new(db).search(EmbedderIndex::E13Splade, &query, 10);
+/// # }
+/// ```
+pub struct SparseSearch<'a> {
+    db: &'a DB,
+    config: SparseSearchConfig,
+}
+
+impl<'a> SparseSearch<'a> {
+    /// Create with default configuration.
+    pub fn new(db: &'a DB) -> Self {
+        Self {
+            db,
+            config: SparseSearchConfig::default(),
+        }
+    }
+
+    /// Create with custom configuration.
+    pub fn with_config(db: &'a DB, config: SparseSearchConfig) -> Self {
+        Self { db, config }
+    }
+
+    /// Top-k documents by sparse dot product with `query`.
+    ///
+    /// For sparse hits `similarity` is the (unbounded) score and `distance`
+    /// is its negation, so both orderings agree with HNSW hits.
+    ///
+    /// # Errors
+    ///
+    /// - `SearchError::UnsupportedEmbedder` if embedder is not E6/E13
+    /// - `SearchError::EmptyQuery` if query has no active terms
+    /// - `SearchError::InvalidVector` if query weights contain NaN/Inf
+    /// - `SearchError::Store` on RocksDB or deserialization failure
+    pub fn search(
+        &self,
+        embedder: EmbedderIndex,
+        query: &SparseVector,
+        k: usize,
+    ) -> SearchResult<Vec<EmbedderSearchHit>> {
+        let (cf_name, term_key): (&str, fn(u16) -> [u8; 2]) = match embedder {
+            EmbedderIndex::E6Sparse => (CF_E6_SPARSE_INVERTED, e6_sparse_inverted_key),
+            EmbedderIndex::E13Splade => (CF_E13_SPLADE_INVERTED, e13_splade_inverted_key),
+            _ => return Err(SearchError::UnsupportedEmbedder { embedder }),
+        };
+
+        // FAIL FAST: Validate query
+        if query.is_empty() {
+            return Err(SearchError::EmptyQuery { embedder });
+        }
+        if let Some(i) = query.values.iter().position(|v| !v.is_finite()) {
+            return Err(SearchError::InvalidVector {
+                embedder,
+                message: format!("Non-finite weight {} for term {}", query.values[i], query.indices[i]),
+            });
+        }
+
+        if k == 0 {
+            return Ok(Vec::new());
+        }
+
+        // Fetch all posting lists in one batch, dropping stopword-like terms
+        let cf = self.cf(cf_name)?;
+        let keys: Vec<[u8; 2]> = query.indices.iter().map(|&t| term_key(t)).collect();
+        let mut kept_terms = Vec::with_capacity(query.nnz());
+        let mut candidates: HashSet<Uuid> = HashSet::new();
+        for (i, result) in self
+            .db
+            .multi_get_cf(keys.iter().map(|key| (cf, key.as_slice())))
+            .into_iter()
+            .enumerate()
+        {
+            let data = result.map_err(|e| {
+                SearchError::Store(format!("Failed to read {} posting list: {}", cf_name, e))
+            })?;
+            let Some(data) = data else {
+                continue;
+            };
+            let postings = deserialize_memory_id_list(&data)
+                .map_err(|e| SearchError::Store(e.to_string()))?;
+            if postings.len() > self.config.max_document_frequency {
+                continue;
+            }
+            kept_terms.push(i);
+            candidates.extend(postings.into_iter().take(self.config.max_postings_per_term));
+        }
+
+        if candidates.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let kept_query = SparseVector {
+            indices: kept_terms.iter().map(|&i| query.indices[i]).collect(),
+            values: kept_terms.iter().map(|&i| query.values[i]).collect(),
+        };
+
+        // Score candidates against their stored weights
+        let candidates: Vec<Uuid> = candidates.into_iter().collect();
+        let cf_fp = self.cf(CF_FINGERPRINTS)?;
+        let fp_keys: Vec<[u8; 16]> = candidates.iter().map(fingerprint_key).collect();
+        let mut scored: Vec<(Uuid, f32, usize)> = Vec::with_capacity(candidates.len());
+        for (id, result) in candidates.iter().zip(
+            self.db
+                .multi_get_cf(fp_keys.iter().map(|key| (cf_fp, key.as_slice()))),
+        ) {
+            let data = result.map_err(|e| {
+                SearchError::Store(format!("Failed to read fingerprint {}: {}", id, e))
+            })?;
+            // Stale posting for a deleted fingerprint
+            let Some(data) = data else {
+                continue;
+            };
+            let fp = deserialize_teleological_fingerprint(&data)
+                .map_err(|e| SearchError::Store(e.to_string()))?;
+            let stored = match embedder {
+                EmbedderIndex::E6Sparse => &fp.semantic.e6_sparse,
+                _ => &fp.semantic.e13_splade,
+            };
+            scored.push((*id, kept_query.dot(stored), stored.nnz()));
+        }
+
+        if let Some(b) = self.config.length_normalization {
+            let avg_nnz = scored.iter().map(|(_, _, nnz)| *nnz as f32).sum::<f32>()
+                / scored.len().max(1) as f32;
+            if avg_nnz > 0.0 {
+                for (_, score, nnz) in &mut scored {
+                    *score /= 1.0 - b + b * (*nnz as f32 / avg_nnz);
+                }
+            }
+        }
+
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(k);
+
+        Ok(scored
+            .into_iter()
+            .map(|(id, score, _)| EmbedderSearchHit {
+                id,
+                distance: -score,
+                similarity: score,
+                embedder,
+            })
+            .collect())
+    }
+
+    /// Get the configuration.
+    pub fn config(&self) -> &SparseSearchConfig {
+        &self.config
+    }
+
+    fn cf(&self, name: &str) -> SearchResult<&rocksdb::ColumnFamily> {
+        self.db
+            .cf_handle(name)
+            .ok_or_else(|| SearchError::Store(format!("Column family {} not found", name)))
+    }
+}
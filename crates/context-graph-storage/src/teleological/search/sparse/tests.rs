@@ -0,0 +1,183 @@
+//! Tests for sparse inverted-index search.
+//!
+//! Uses a handcrafted 20-document corpus stored through the real RocksDB
+//! store, so posting lists are built by the production write path.
+//!
+//! Corpus (term -> weight), every document also has term 100 with weight 1.0:
+//!
+//! | Doc | Terms |
+//! |-----|-------|
+//! | d0 | 1: 3.0 |
+//! | d1 | 1: 1.0, 2: 2.0 |
+//! | d2 | 2: 3.0 |
+//! | d3 | 1: 0.5, 3: 4.0 |
+//! | d4..d19 | (10 + i): 1.0 |
+//!
+//! Query: 1: 1.0, 2: 0.5, 100: 5.0
+
+use tempfile::TempDir;
+use uuid::Uuid;
+
+use context_graph_core::traits::TeleologicalMemoryStore;
+use context_graph_core::types::fingerprint::{
+    SemanticFingerprint, SparseVector, TeleologicalFingerprint,
+};
+
+use crate::teleological::indexes::EmbedderIndex;
+use crate::teleological::rocksdb_store::RocksDbTeleologicalStore;
+
+use super::{SparseSearch, SparseSearchConfig};
+
+const STOPWORD_TERM: u16 = 100;
+
+fn doc_id(i: u128) -> Uuid {
+    Uuid::from_u128(i + 1)
+}
+
+fn sparse(terms: &[(u16, f32)]) -> SparseVector {
+    let mut terms = terms.to_vec();
+    terms.push((STOPWORD_TERM, 1.0));
+    terms.sort_by_key(|(t, _)| *t);
+    SparseVector::new(
+        terms.iter().map(|(t, _)| *t).collect(),
+        terms.iter().map(|(_, w)| *w).collect(),
+    )
+    .unwrap()
+}
+
+async fn create_corpus() -> (TempDir, RocksDbTeleologicalStore) {
+    let tmp = TempDir::new().unwrap();
+    let store = RocksDbTeleologicalStore::open(tmp.path()).unwrap();
+
+    let mut docs = vec![
+        sparse(&[(1, 3.0)]),
+        sparse(&[(1, 1.0), (2, 2.0)]),
+        sparse(&[(2, 3.0)]),
+        sparse(&[(1, 0.5), (3, 4.0)]),
+    ];
+    for i in 4..20u16 {
+        docs.push(sparse(&[(10 + i, 1.0)]));
+    }
+
+    for (i, doc) in docs.into_iter().enumerate() {
+        let semantic = SemanticFingerprint {
+            e6_sparse: doc.clone(),
+            e13_splade: doc,
+            ..SemanticFingerprint::stub()
+        };
+        let mut hash = [0u8; 32];
+        hash[0] = i as u8;
+        let fp = TeleologicalFingerprint::with_id(doc_id(i as u128), semantic, hash);
+        store.store(fp).await.unwrap();
+    }
+    (tmp, store)
+}
+
+fn query() -> SparseVector {
+    SparseVector::new(vec![1, 2, STOPWORD_TERM], vec![1.0, 0.5, 5.0]).unwrap()
+}
+
+fn ranked(hits: &[crate::teleological::search::EmbedderSearchHit]) -> Vec<Uuid> {
+    hits.iter().map(|h| h.id).collect()
+}
+
+// ========== RANKING TESTS ==========
+
+#[tokio::test]
+async fn test_dot_product_ranking_skips_stopwords() {
+    println!("=== TEST: Stopword term is skipped, remaining terms ranked by dot product ===");
+
+    let (_tmp, store) = create_corpus().await;
+    let config = SparseSearchConfig {
+        max_document_frequency: 10,
+        ..Default::default()
+    };
+    let search = SparseSearch::with_config(store.db(), config);
+
+    // d0 = 3.0, d1 = 1.0 + 1.0 = 2.0, d2 = 1.5, d3 = 0.5; fillers only share term 100
+    let hits = search.search(EmbedderIndex::E13Splade, &query(), 10).unwrap();
+    assert_eq!(ranked(&hits), vec![doc_id(0), doc_id(1), doc_id(2), doc_id(3)]);
+    let scores: Vec<f32> = hits.iter().map(|h| h.similarity).collect();
+    for (actual, expected) in scores.iter().zip([3.0, 2.0, 1.5, 0.5]) {
+        assert!((actual - expected).abs() < 1e-5, "{actual} != {expected}");
+    }
+
+    println!("RESULT: PASS");
+}
+
+#[tokio::test]
+async fn test_common_term_kept_below_threshold() {
+    println!("=== TEST: Common term contributes when under the df threshold ===");
+
+    let (_tmp, store) = create_corpus().await;
+    let search = SparseSearch::new(store.db());
+
+    // Term 100 adds 5.0 to every document: d0 = 8.0, d1 = 7.0, d2 = 6.5, d3 = 5.5, fillers 5.0
+    let hits = search.search(EmbedderIndex::E6Sparse, &query(), 5).unwrap();
+    assert_eq!(hits.len(), 5);
+    assert_eq!(ranked(&hits[..4]), vec![doc_id(0), doc_id(1), doc_id(2), doc_id(3)]);
+    assert!((hits[0].similarity - 8.0).abs() < 1e-5);
+    assert!((hits[4].similarity - 5.0).abs() < 1e-5);
+    assert!(hits[4].id.as_u128() > 4, "fifth hit must be a filler document");
+
+    println!("RESULT: PASS");
+}
+
+#[tokio::test]
+async fn test_length_normalization_reorders() {
+    println!("=== TEST: BM25-style length normalization favors shorter documents ===");
+
+    let (_tmp, store) = create_corpus().await;
+    let config = SparseSearchConfig {
+        max_document_frequency: 10,
+        length_normalization: Some(1.0),
+        ..Default::default()
+    };
+    let search = SparseSearch::with_config(store.db(), config);
+
+    // nnz: d0 = 2, d1 = 3, d2 = 2, d3 = 3, avg = 2.5
+    // d0 = 3.0 / 0.8 = 3.75, d2 = 1.5 / 0.8 = 1.875, d1 = 2.0 / 1.2 = 1.667, d3 = 0.5 / 1.2
+    let hits = search.search(EmbedderIndex::E13Splade, &query(), 10).unwrap();
+    assert_eq!(ranked(&hits), vec![doc_id(0), doc_id(2), doc_id(1), doc_id(3)]);
+    assert!((hits[0].similarity - 3.75).abs() < 1e-4);
+    assert!((hits[2].similarity - 2.0 / 1.2).abs() < 1e-4);
+
+    println!("RESULT: PASS");
+}
+
+#[tokio::test]
+async fn test_postings_cap_limits_candidates() {
+    println!("=== TEST: max_postings_per_term truncates posting lists ===");
+
+    let (_tmp, store) = create_corpus().await;
+    let config = SparseSearchConfig {
+        max_postings_per_term: 1,
+        max_document_frequency: 10,
+        ..Default::default()
+    };
+    let search = SparseSearch::with_config(store.db(), config);
+
+    // Postings are UUID-sorted: term 1 -> [d0, d1, d3], term 2 -> [d1, d2]
+    let hits = search.search(EmbedderIndex::E13Splade, &query(), 10).unwrap();
+    assert_eq!(ranked(&hits), vec![doc_id(0), doc_id(1)]);
+
+    println!("RESULT: PASS");
+}
+
+// ========== VALIDATION TESTS ==========
+
+#[tokio::test]
+async fn test_rejects_dense_embedder_and_empty_query() {
+    println!("=== TEST: FAIL FAST on non-sparse embedder and empty query ===");
+
+    let (_tmp, store) = create_corpus().await;
+    let search = SparseSearch::new(store.db());
+
+    assert!(search.search(EmbedderIndex::E1Semantic, &query(), 10).is_err());
+    assert!(search
+        .search(EmbedderIndex::E13Splade, &SparseVector::empty(), 10)
+        .is_err());
+    assert!(search.search(EmbedderIndex::E13Splade, &query(), 0).unwrap().is_empty());
+
+    println!("RESULT: PASS");
+}
@@ -21,17 +21,93 @@
 //! # Version Handling
 //!
 //! Each serialized type is prefixed with a version byte.
-//! Version mismatches return errors (no migration support).
+//! Version mismatches return errors, except legacy TeleologicalFingerprint records
+//! which are decoded via frozen layouts (see `TeleologicalFingerprintV1`/`V2`).
 
 use bincode::{deserialize, serialize};
+use chrono::{DateTime, Utc};
 use context_graph_core::error::CoreError;
-use context_graph_core::types::fingerprint::TeleologicalFingerprint;
+use context_graph_core::types::fingerprint::{
+    SemanticFingerprint, SparseVector, TeleologicalFingerprint,
+};
+use serde::Deserialize;
 use uuid::Uuid;
 
 /// Serialization version for TeleologicalFingerprint.
 ///
 /// Bump this when struct layout changes. Version mismatches will panic.
-pub const TELEOLOGICAL_VERSION: u8 = 1;
+///
+/// - v1: original layout
+/// - v2: appended `expires_at: Option<DateTime<Utc>>` (TTL support)
+/// - v3: appended `namespace: String` (namespace isolation)
+pub const TELEOLOGICAL_VERSION: u8 = 3;
+
+/// Frozen bincode layout of TeleologicalFingerprint at version 1.
+///
+/// bincode is positional, so v1 records cannot be decoded with the current
+/// struct. Field order MUST match the v1 struct exactly (`last_accessed_at`
+/// was `#[serde(skip)]` and is therefore absent). Do not modify.
+#[derive(Deserialize)]
+struct TeleologicalFingerprintV1 {
+    id: Uuid,
+    semantic: SemanticFingerprint,
+    content_hash: [u8; 32],
+    created_at: DateTime<Utc>,
+    last_updated: DateTime<Utc>,
+    access_count: u64,
+    importance: f32,
+    e6_sparse: Option<SparseVector>,
+}
+
+impl From<TeleologicalFingerprintV1> for TeleologicalFingerprintV2 {
+    fn from(v1: TeleologicalFingerprintV1) -> Self {
+        Self {
+            id: v1.id,
+            semantic: v1.semantic,
+            content_hash: v1.content_hash,
+            created_at: v1.created_at,
+            last_updated: v1.last_updated,
+            access_count: v1.access_count,
+            importance: v1.importance,
+            e6_sparse: v1.e6_sparse,
+            expires_at: None,
+        }
+    }
+}
+
+/// Frozen bincode layout of TeleologicalFingerprint at version 2 (v1 + `expires_at`).
+/// Do not modify.
+#[derive(Deserialize)]
+struct TeleologicalFingerprintV2 {
+    id: Uuid,
+    semantic: SemanticFingerprint,
+    content_hash: [u8; 32],
+    created_at: DateTime<Utc>,
+    last_updated: DateTime<Utc>,
+    access_count: u64,
+    importance: f32,
+    e6_sparse: Option<SparseVector>,
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl From<TeleologicalFingerprintV2> for TeleologicalFingerprint {
+    fn from(v2: TeleologicalFingerprintV2) -> Self {
+        Self {
+            id: v2.id,
+            semantic: v2.semantic,
+            content_hash: v2.content_hash,
+            created_at: v2.created_at,
+            last_updated: v2.last_updated,
+            access_count: v2.access_count,
+            importance: v2.importance,
+            last_accessed_at: Utc::now(),
+            e6_sparse: v2.e6_sparse,
+            expires_at: v2.expires_at,
+            namespace: TeleologicalFingerprint::DEFAULT_NAMESPACE.to_string(),
+            embedding_states: Default::default(),
+        }
+    }
+}
 
 /// Minimum expected size for a serialized TeleologicalFingerprint.
 ///
@@ -116,7 +192,8 @@ pub fn serialize_teleological_fingerprint(fp: &TeleologicalFingerprint) -> Vec<u
 ///
 /// # Errors
 /// - Empty data
-/// - Version mismatch (no migration support)
+/// - Unknown version (v1/v2 records are migrated: `expires_at = None`,
+///   `namespace = "default"`)
 /// - Bincode deserialization failure (indicates corruption)
 ///
 /// # Example
@@ -142,19 +219,7 @@ pub fn deserialize_teleological_fingerprint(data: &[u8]) -> Result<TeleologicalF
     }
 
     let version = data[0];
-    if version != TELEOLOGICAL_VERSION {
-        return Err(CoreError::SerializationError(format!(
-            "Version mismatch for TeleologicalFingerprint. Expected {}, got {}. \
-             Data length: {} bytes. \
-             This indicates stale data requiring migration. \
-             No automatic migration is supported - data must be regenerated.",
-            TELEOLOGICAL_VERSION,
-            version,
-            data.len()
-        )));
-    }
-
-    let mut fp: TeleologicalFingerprint = deserialize(&data[1..]).map_err(|e| {
+    let decode_err = |e: bincode::Error| {
         CoreError::SerializationError(format!(
             "Failed to deserialize TeleologicalFingerprint. \
              Error: {}. Data length: {} bytes, version: {}. \
@@ -163,7 +228,30 @@ pub fn deserialize_teleological_fingerprint(data: &[u8]) -> Result<TeleologicalF
             data.len(),
             version
         ))
-    })?;
+    };
+
+    let mut fp: TeleologicalFingerprint = match version {
+        TELEOLOGICAL_VERSION => deserialize(&data[1..]).map_err(decode_err)?,
+        // Legacy layouts: records without `namespace` land in the default namespace.
+        2 => deserialize::<TeleologicalFingerprintV2>(&data[1..])
+            .map_err(decode_err)?
+            .into(),
+        1 => TeleologicalFingerprintV2::from(
+            deserialize::<TeleologicalFingerprintV1>(&data[1..]).map_err(decode_err)?,
+        )
+        .into(),
+        _ => {
+            return Err(CoreError::SerializationError(format!(
+                "Version mismatch for TeleologicalFingerprint. Expected {}, got {}. \
+                 Data length: {} bytes. \
+                 This indicates stale data requiring migration. \
+                 No automatic migration is supported - data must be regenerated.",
+                TELEOLOGICAL_VERSION,
+                version,
+                data.len()
+            )));
+        }
+    };
 
     // DAT-3 fix: Migrate legacy single-vector E5/E8 to dual asymmetric format.
     // Without this, asymmetric causal search returns identical results for
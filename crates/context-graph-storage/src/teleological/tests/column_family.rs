@@ -8,14 +8,14 @@ use crate::teleological::*;
 
 #[test]
 fn test_teleological_cf_names_count() {
-    // 20 active teleological CFs (no legacy CFs)
+    // 26 active teleological CFs (no legacy CFs)
     assert_eq!(
         TELEOLOGICAL_CFS.len(),
         TELEOLOGICAL_CF_COUNT,
         "Must have exactly {} teleological column families",
         TELEOLOGICAL_CF_COUNT
     );
-    assert_eq!(TELEOLOGICAL_CF_COUNT, 20);
+    assert_eq!(TELEOLOGICAL_CF_COUNT, 26);
 }
 
 #[test]
@@ -144,12 +144,12 @@ fn test_get_all_teleological_cf_descriptors_returns_32() {
     let cache = Cache::new_lru_cache(256 * 1024 * 1024);
     let descriptors = get_all_teleological_cf_descriptors(&cache);
 
-    // 20 teleological + 13 quantized embedder = 33
+    // 26 teleological + 13 quantized embedder = 39
     // Quantized (13): emb_0 through emb_12
     assert_eq!(
         descriptors.len(),
-        33,
-        "Must return 20 teleological + 13 quantized = 33 CFs"
+        39,
+        "Must return 26 teleological + 13 quantized = 39 CFs"
     );
 }
 
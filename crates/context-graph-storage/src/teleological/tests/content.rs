@@ -384,3 +384,92 @@ async fn test_content_unicode_comprehensive() {
         test_cases.len()
     );
 }
+
+#[tokio::test]
+async fn test_content_blob_shared_until_last_reference_deleted() {
+    println!("=== TEST: identical content shares one reference-counted blob ===");
+
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+    let store = RocksDbTeleologicalStore::open(temp_dir.path()).expect("Failed to open store");
+
+    let content = "Identical content stored under two different memories";
+    let first = create_fingerprint_for_content(content);
+    let second = create_fingerprint_for_content(content);
+    let hash = first.content_hash;
+    let (a, b) = (first.id, second.id);
+    assert_ne!(a, b);
+
+    for fingerprint in [first, second] {
+        let id = fingerprint.id;
+        store
+            .store(fingerprint)
+            .await
+            .expect("Should store fingerprint");
+        store
+            .store_content(id, content)
+            .await
+            .expect("Should store content");
+    }
+
+    assert!(store.content_blob_exists(&hash).unwrap());
+    assert_eq!(store.content_blob_ref_count(&hash).unwrap(), 2);
+
+    // Re-storing the same content must not add a second reference
+    store
+        .store_content(a, content)
+        .await
+        .expect("Should re-store");
+    assert_eq!(store.content_blob_ref_count(&hash).unwrap(), 2);
+
+    // Deleting one memory leaves the blob for the other
+    assert!(store.delete(a, false).await.expect("Should delete"));
+    assert!(store.content_blob_exists(&hash).unwrap());
+    assert_eq!(store.content_blob_ref_count(&hash).unwrap(), 1);
+    assert!(store.get_content(a).await.unwrap().is_none());
+    assert_eq!(
+        store.get_content(b).await.unwrap().as_deref(),
+        Some(content)
+    );
+
+    // Deleting the last reference removes the blob
+    assert!(store.delete(b, false).await.expect("Should delete"));
+    assert!(!store.content_blob_exists(&hash).unwrap());
+    assert_eq!(store.content_blob_ref_count(&hash).unwrap(), 0);
+    assert!(store.get_content(b).await.unwrap().is_none());
+
+    println!("RESULT: PASS - Blob removed only after the last reference");
+}
+
+#[tokio::test]
+async fn test_content_blob_large_content_streams_back() {
+    use std::io::Read;
+
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+    let store = RocksDbTeleologicalStore::open(temp_dir.path()).expect("Failed to open store");
+
+    // Well above the compression threshold so the blob is zstd-encoded
+    let content = "The ledger database is snapshotted every six hours. ".repeat(400);
+    assert!(content.len() > crate::teleological::rocksdb_store::BLOB_COMPRESSION_THRESHOLD);
+    let fingerprint = create_fingerprint_for_content(&content);
+    let id = fingerprint.id;
+    store
+        .store(fingerprint)
+        .await
+        .expect("Should store fingerprint");
+    store
+        .store_content(id, &content)
+        .await
+        .expect("Should store content");
+
+    let mut streamed = String::new();
+    store
+        .open_content_reader(id)
+        .expect("Should open reader")
+        .expect("Blob should exist")
+        .read_to_string(&mut streamed)
+        .expect("Should stream content");
+    assert_eq!(streamed, content);
+    assert_eq!(store.get_content(id).await.unwrap(), Some(content));
+
+    assert!(store.open_content_reader(Uuid::new_v4()).unwrap().is_none());
+}
@@ -0,0 +1,115 @@
+//! HNSW rebuild tests on the stub corpus.
+//!
+//! Each test stores stub fingerprints, then reopens the store so every
+//! per-space index is rebuilt from CF_FINGERPRINTS (the persisted E2 graph
+//! is always skipped, which forces the rebuild). Progress events are
+//! collected through `IndexBuildConfig::progress`.
+//!
+//! CRITICAL: Uses #[tokio::test] to prevent zombie runtime threads.
+//! DO NOT use tokio::runtime::Runtime::new() in tests.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tempfile::TempDir;
+
+use super::helpers::create_real_fingerprint;
+use crate::teleological::indexes::{
+    EmbedderIndexOps, IndexBuildConfig, IndexBuildPlan, IndexBuildProgress,
+};
+use crate::teleological::{RocksDbTeleologicalStore, TeleologicalStoreConfig};
+use context_graph_core::traits::TeleologicalMemoryStore;
+
+const CORPUS_SIZE: usize = 64;
+
+async fn seed_stub_corpus(path: &Path) {
+    let store = RocksDbTeleologicalStore::open(path).expect("initial open");
+    for _ in 0..CORPUS_SIZE {
+        store.store(create_real_fingerprint()).await.expect("store");
+    }
+}
+
+/// Reopen `path` with `build`, returning the store and every progress event.
+fn reopen_with_build(
+    path: &Path,
+    build: IndexBuildConfig,
+) -> (RocksDbTeleologicalStore, Vec<IndexBuildProgress>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&events);
+    let config = TeleologicalStoreConfig {
+        index_build: build.with_progress(Arc::new(move |p: &IndexBuildProgress| {
+            sink.lock().push(p.clone())
+        })),
+        ..TeleologicalStoreConfig::default()
+    };
+    let store = RocksDbTeleologicalStore::open_with_config(path, config).expect("reopen");
+    let events = events.lock().clone();
+    (store, events)
+}
+
+/// Every indexed space holds the whole corpus and reported monotonic
+/// progress ending at completion.
+fn assert_all_indexes_built(store: &RocksDbTeleologicalStore, events: &[IndexBuildProgress]) {
+    let embedders = RocksDbTeleologicalStore::indexed_embedders();
+    for embedder in embedders {
+        let index = store.index_registry.get(embedder).expect("index exists");
+        assert_eq!(index.len(), CORPUS_SIZE, "{:?}", embedder);
+
+        let space: Vec<&IndexBuildProgress> =
+            events.iter().filter(|p| p.embedder == embedder).collect();
+        assert!(!space.is_empty(), "{:?} reported no progress", embedder);
+        assert!(
+            space.windows(2).all(|w| w[0].inserted <= w[1].inserted),
+            "{:?} progress went backwards",
+            embedder
+        );
+        let last = space.last().unwrap();
+        assert_eq!(last.total, CORPUS_SIZE, "{:?}", embedder);
+        assert!(last.is_complete(), "{:?} never completed", embedder);
+    }
+}
+
+#[tokio::test]
+async fn test_concurrent_rebuild_finishes_every_index() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    seed_stub_corpus(temp_dir.path()).await;
+
+    let build = IndexBuildConfig {
+        parallelism: 4,
+        chunk_size: 8,
+        ..IndexBuildConfig::default()
+    };
+    let (store, events) = reopen_with_build(temp_dir.path(), build);
+
+    let summary = store.last_index_build().expect("rebuild ran at open");
+    assert_eq!(summary.plan, IndexBuildPlan::Concurrent);
+    assert_eq!(summary.fingerprints, CORPUS_SIZE);
+    assert_eq!(
+        summary.spaces,
+        RocksDbTeleologicalStore::indexed_embedders().len()
+    );
+    // chunk_size 8 over 64 vectors: several reports per space
+    assert!(events.len() >= summary.spaces * (CORPUS_SIZE / 8));
+    assert_all_indexes_built(&store, &events);
+}
+
+#[tokio::test]
+async fn test_memory_budget_falls_back_to_sequential() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    seed_stub_corpus(temp_dir.path()).await;
+
+    let build = IndexBuildConfig {
+        parallelism: 4,
+        memory_budget_bytes: 1024,
+        chunk_size: 8,
+        ..IndexBuildConfig::default()
+    };
+    let (store, events) = reopen_with_build(temp_dir.path(), build);
+
+    let summary = store.last_index_build().expect("rebuild ran at open");
+    assert_eq!(summary.plan, IndexBuildPlan::Sequential);
+    assert!(summary.estimated_peak_bytes > 1024);
+    assert_eq!(summary.fingerprints, CORPUS_SIZE);
+    assert_all_indexes_built(&store, &events);
+}
@@ -13,12 +13,18 @@
 //! - `serialization`: Serialization round-trip tests
 //! - `panic`: Panic behavior tests (should_panic)
 //! - `stale_lock`: Stale lock detection tests
+//! - `process_lock`: Access mode and cross-process lock tests
+//! - `index_build`: HNSW rebuild parallelism, progress and memory budget tests
+//! - `snapshot_search`: Commit-sequence visibility of concurrent writes
 //! - `content`: Content storage tests
 
 mod column_family;
 mod content;
 mod helpers;
+mod index_build;
 mod key_format;
 mod panic;
+mod process_lock;
 mod serialization;
+mod snapshot_search;
 mod stale_lock;
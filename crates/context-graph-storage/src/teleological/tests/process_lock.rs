@@ -0,0 +1,160 @@
+//! Store access mode and cross-process lock tests.
+//!
+//! The cross-process test re-runs this test binary as a child that holds the
+//! store open exclusively (`lock_holder_process`), then opens the same
+//! directory from the parent.
+//!
+//! CRITICAL: Uses #[tokio::test] to prevent zombie runtime threads.
+//! DO NOT use tokio::runtime::Runtime::new() in tests.
+
+use super::helpers::create_real_fingerprint;
+use crate::teleological::{
+    read_lock_owner, RocksDbTeleologicalStore, StoreAccessMode, TeleologicalStoreConfig,
+    TeleologicalStoreError,
+};
+use context_graph_core::traits::TeleologicalMemoryStore;
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Command, Stdio};
+use tempfile::TempDir;
+
+/// Env var naming the database the child process should hold.
+const HOLDER_DB_ENV: &str = "CG_TEST_LOCK_HOLDER_DB";
+const HOLDING_MARKER: &str = "LOCK_HOLDER_READY";
+
+fn open_with_mode(
+    path: &std::path::Path,
+    mode: StoreAccessMode,
+) -> Result<RocksDbTeleologicalStore, TeleologicalStoreError> {
+    RocksDbTeleologicalStore::open_with_config(
+        path,
+        TeleologicalStoreConfig::with_access_mode(mode),
+    )
+}
+
+/// Child side of `test_exclusive_lock_across_processes`: hold the store
+/// until stdin closes. Does nothing unless spawned by that test.
+#[test]
+#[ignore = "spawned as a child process by test_exclusive_lock_across_processes"]
+fn lock_holder_process() {
+    let Ok(path) = std::env::var(HOLDER_DB_ENV) else {
+        return;
+    };
+    let _store = RocksDbTeleologicalStore::open(&path).expect("holder must open exclusively");
+    println!("{}", HOLDING_MARKER);
+    let mut sink = String::new();
+    let _ = std::io::stdin().read_to_string(&mut sink);
+}
+
+#[tokio::test]
+async fn test_exclusive_lock_across_processes() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("locked_db");
+
+    // Commit one fingerprint, then release the store
+    let stored_id = {
+        let store = RocksDbTeleologicalStore::open(&db_path).expect("initial open");
+        store.store(create_real_fingerprint()).await.expect("store")
+    };
+
+    let mut holder = Command::new(std::env::current_exe().expect("test binary path"))
+        .args([
+            "--exact",
+            "teleological::tests::process_lock::lock_holder_process",
+            "--ignored",
+            "--nocapture",
+            "--test-threads=1",
+        ])
+        .env(HOLDER_DB_ENV, &db_path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn lock holder");
+    let holder_pid = holder.id();
+
+    let stdout = holder.stdout.take().expect("holder stdout");
+    let mut lines = BufReader::new(stdout).lines();
+    let ready = lines
+        .by_ref()
+        .map_while(Result::ok)
+        .any(|line| line.contains(HOLDING_MARKER));
+    assert!(ready, "holder exited before taking the lock");
+    // Keep draining so the holder never writes to a closed pipe
+    let drain = std::thread::spawn(move || lines.for_each(drop));
+
+    // Write mode: typed error naming the holder
+    match open_with_mode(&db_path, StoreAccessMode::Exclusive) {
+        Err(TeleologicalStoreError::LockedByOtherProcess { pid, since, .. }) => {
+            assert_eq!(pid, Some(holder_pid));
+            assert!(since.is_some(), "owner file should record the open time");
+        }
+        Err(other) => panic!("expected LockedByOtherProcess, got {}", other),
+        Ok(_) => panic!("second exclusive open must fail while the holder runs"),
+    }
+    // The failed open must not have removed the holder's lock or owner file
+    assert!(db_path.join("LOCK").exists());
+    assert_eq!(read_lock_owner(&db_path).map(|o| o.pid), Some(holder_pid));
+
+    // Read-only mode: succeeds and sees committed data, but refuses writes
+    {
+        let reader = open_with_mode(&db_path, StoreAccessMode::ReadOnly).expect("read-only open");
+        assert_eq!(reader.access_mode(), StoreAccessMode::ReadOnly);
+        let fp = reader.retrieve(stored_id).await.expect("retrieve");
+        assert!(fp.is_some(), "read-only view must contain committed data");
+        assert!(reader.store(create_real_fingerprint()).await.is_err());
+    }
+
+    // Secondary mode: also opens alongside the holder
+    {
+        let secondary_dir = temp_dir.path().join("secondary");
+        let config = TeleologicalStoreConfig {
+            access_mode: StoreAccessMode::SecondaryCatchUp,
+            secondary_path: Some(secondary_dir),
+            ..TeleologicalStoreConfig::default()
+        };
+        let secondary =
+            RocksDbTeleologicalStore::open_with_config(&db_path, config).expect("secondary open");
+        secondary.catch_up_with_primary().expect("catch up");
+        assert!(secondary
+            .retrieve(stored_id)
+            .await
+            .expect("retrieve")
+            .is_some());
+    }
+
+    drop(holder.stdin.take());
+    let status = holder.wait().expect("holder exit");
+    drain.join().expect("drain stdout");
+    assert!(status.success());
+
+    // Holder gone: exclusive open works again and the owner file is ours
+    let store = open_with_mode(&db_path, StoreAccessMode::Exclusive).expect("reopen");
+    assert_eq!(
+        read_lock_owner(&db_path).map(|o| o.pid),
+        Some(std::process::id())
+    );
+    assert_eq!(store.count().await.expect("count"), 1);
+}
+
+#[tokio::test]
+async fn test_second_exclusive_open_in_same_process() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("same_process_db");
+
+    let first = RocksDbTeleologicalStore::open(&db_path).expect("first open");
+    match RocksDbTeleologicalStore::open(&db_path) {
+        Err(TeleologicalStoreError::LockedByOtherProcess { pid, .. }) => {
+            assert_eq!(pid, Some(std::process::id()));
+        }
+        Err(other) => panic!("expected LockedByOtherProcess, got {}", other),
+        Ok(_) => panic!("second exclusive open must fail"),
+    }
+
+    // The first handle keeps working
+    first.store(create_real_fingerprint()).await.expect("store");
+    drop(first);
+    assert!(
+        read_lock_owner(&db_path).is_none(),
+        "owner file removed on close"
+    );
+}
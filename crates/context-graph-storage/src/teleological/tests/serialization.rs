@@ -1,6 +1,7 @@
 //! Serialization round-trip tests.
 
 use super::helpers::create_real_fingerprint;
+use context_graph_core::types::fingerprint::TeleologicalFingerprint;
 use crate::teleological::*;
 use uuid::Uuid;
 
@@ -256,7 +257,78 @@ fn edge_case_topic_profile_extreme_values() {
 
 #[test]
 fn test_version_constant() {
-    assert_eq!(TELEOLOGICAL_VERSION, 1, "Version should be 1");
+    assert_eq!(TELEOLOGICAL_VERSION, 3, "Version should be 3");
+}
+
+#[test]
+fn test_expires_at_roundtrip() {
+    let deadline = chrono::Utc::now() + chrono::Duration::seconds(30);
+    let original = create_real_fingerprint().with_expires_at(deadline);
+    let restored = deserialize_teleological_fingerprint(&serialize_teleological_fingerprint(&original))
+        .expect("deserialize should succeed");
+    assert_eq!(restored.expires_at, Some(deadline));
+}
+
+#[test]
+fn test_deserialize_v1_record_without_expires_at() {
+    let fp = create_real_fingerprint();
+
+    // v1 layout: every persisted field except `expires_at`, in declaration order
+    let v1_body = bincode::serialize(&(
+        fp.id,
+        &fp.semantic,
+        fp.content_hash,
+        fp.created_at,
+        fp.last_updated,
+        fp.access_count,
+        fp.importance,
+        &fp.e6_sparse,
+    ))
+    .unwrap();
+    let mut v1_bytes = vec![1u8];
+    v1_bytes.extend(v1_body);
+
+    let restored = deserialize_teleological_fingerprint(&v1_bytes).expect("v1 must still decode");
+    assert_eq!(restored.id, fp.id);
+    assert_eq!(restored.content_hash, fp.content_hash);
+    assert_eq!(restored.semantic.e1_semantic, fp.semantic.e1_semantic);
+    assert!(restored.expires_at.is_none());
+    assert_eq!(restored.namespace, TeleologicalFingerprint::DEFAULT_NAMESPACE);
+}
+
+#[test]
+fn test_deserialize_v2_record_lands_in_default_namespace() {
+    let deadline = chrono::Utc::now() + chrono::Duration::seconds(30);
+    let fp = create_real_fingerprint().with_expires_at(deadline);
+
+    // v2 layout: v1 fields plus `expires_at`, no `namespace`
+    let v2_body = bincode::serialize(&(
+        fp.id,
+        &fp.semantic,
+        fp.content_hash,
+        fp.created_at,
+        fp.last_updated,
+        fp.access_count,
+        fp.importance,
+        &fp.e6_sparse,
+        fp.expires_at,
+    ))
+    .unwrap();
+    let mut v2_bytes = vec![2u8];
+    v2_bytes.extend(v2_body);
+
+    let restored = deserialize_teleological_fingerprint(&v2_bytes).expect("v2 must still decode");
+    assert_eq!(restored.id, fp.id);
+    assert_eq!(restored.expires_at, Some(deadline));
+    assert_eq!(restored.namespace, TeleologicalFingerprint::DEFAULT_NAMESPACE);
+}
+
+#[test]
+fn test_namespace_roundtrip() {
+    let original = create_real_fingerprint().with_namespace("project-a");
+    let restored = deserialize_teleological_fingerprint(&serialize_teleological_fingerprint(&original))
+        .expect("deserialize should succeed");
+    assert_eq!(restored.namespace, "project-a");
 }
 
 #[test]
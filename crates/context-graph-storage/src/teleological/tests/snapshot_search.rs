@@ -0,0 +1,119 @@
+//! Snapshot-consistent search tests.
+//!
+//! Searches run while fingerprints are being stored must only return IDs
+//! whose write has fully committed, and `as_of` must pin a search to an
+//! earlier commit sequence.
+//!
+//! CRITICAL: Uses #[tokio::test] to prevent zombie runtime threads.
+//! DO NOT use tokio::runtime::Runtime::new() in tests.
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use tempfile::TempDir;
+use uuid::Uuid;
+
+use super::helpers::{create_real_fingerprint, create_real_semantic};
+use crate::teleological::RocksDbTeleologicalStore;
+use context_graph_core::traits::{
+    SearchStrategy, TeleologicalMemoryStore, TeleologicalSearchOptions,
+};
+
+const STRATEGIES: [SearchStrategy; 3] = [
+    SearchStrategy::E1Only,
+    SearchStrategy::MultiSpace,
+    SearchStrategy::Pipeline,
+];
+
+async fn search_ids(
+    store: &RocksDbTeleologicalStore,
+    options: TeleologicalSearchOptions,
+) -> HashSet<Uuid> {
+    store
+        .search_semantic(&create_real_semantic(), options)
+        .await
+        .expect("search")
+        .into_iter()
+        .map(|r| r.fingerprint.id)
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_concurrent_searches_only_return_committed_ids() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let store = Arc::new(RocksDbTeleologicalStore::open(temp_dir.path()).expect("open"));
+    let writing = Arc::new(AtomicBool::new(true));
+
+    let writer = {
+        let store = Arc::clone(&store);
+        let writing = Arc::clone(&writing);
+        tokio::spawn(async move {
+            for _ in 0..8 {
+                let batch = (0..8).map(|_| create_real_fingerprint()).collect();
+                store.store_batch(batch).await.expect("store_batch");
+                store.store(create_real_fingerprint()).await.expect("store");
+                tokio::task::yield_now().await;
+            }
+            writing.store(false, Ordering::Release);
+        })
+    };
+
+    let readers: Vec<_> = STRATEGIES
+        .into_iter()
+        .map(|strategy| {
+            let store = Arc::clone(&store);
+            let writing = Arc::clone(&writing);
+            tokio::spawn(async move {
+                let mut searches = 0;
+                while writing.load(Ordering::Acquire) || searches == 0 {
+                    let options = TeleologicalSearchOptions::quick(20).with_strategy(strategy);
+                    for id in search_ids(&store, options).await {
+                        let fp = store.retrieve(id).await.expect("retrieve");
+                        assert!(fp.is_some(), "{:?} returned uncommitted {}", strategy, id);
+                    }
+                    searches += 1;
+                }
+                searches
+            })
+        })
+        .collect();
+
+    writer.await.expect("writer");
+    for reader in readers {
+        assert!(reader.await.expect("reader") > 0);
+    }
+    assert_eq!(store.commit_sequence(), 8 * 9);
+}
+
+#[tokio::test]
+async fn test_as_of_hides_later_commits() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let store = RocksDbTeleologicalStore::open(temp_dir.path()).expect("open");
+
+    let mut early = HashSet::new();
+    for _ in 0..3 {
+        early.insert(store.store(create_real_fingerprint()).await.expect("store"));
+    }
+    let seq = store.commit_sequence();
+    assert_eq!(seq, 3);
+    let late = store
+        .store_batch((0..3).map(|_| create_real_fingerprint()).collect())
+        .await
+        .expect("store_batch");
+    assert_eq!(store.commit_sequence(), 6);
+
+    for strategy in STRATEGIES {
+        let options = TeleologicalSearchOptions::quick(10).with_strategy(strategy);
+        let pinned = search_ids(&store, options.clone().as_of(seq)).await;
+        assert_eq!(pinned, early, "{:?}", strategy);
+
+        let current = search_ids(&store, options).await;
+        assert!(late.iter().all(|id| current.contains(id)), "{:?}", strategy);
+    }
+
+    // Hard-deleted IDs leave every later search, whatever sequence it reads at
+    assert!(store.delete(late[0], false).await.expect("delete"));
+    let options = TeleologicalSearchOptions::quick(10).as_of(store.commit_sequence());
+    assert!(!search_ids(&store, options).await.contains(&late[0]));
+}
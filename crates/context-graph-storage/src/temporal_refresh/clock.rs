@@ -0,0 +1,22 @@
+//! Time source for the temporal refresh job.
+
+use chrono::{DateTime, Utc};
+
+/// Source of the current time.
+///
+/// Decay-encoded E2 vectors depend on "now", so tests substitute a clock
+/// they can freeze and advance.
+pub trait Clock: Send + Sync {
+    /// The current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Wall-clock time via [`Utc::now`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
@@ -0,0 +1,140 @@
+//! Configuration and resume cursor for a temporal refresh run.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use context_graph_core::error::{CoreError, CoreResult};
+
+/// Default number of fingerprints checked per batch.
+pub const DEFAULT_TEMPORAL_REFRESH_BATCH_SIZE: usize = 256;
+
+/// Processing cursor key used when none is configured.
+pub const DEFAULT_TEMPORAL_REFRESH_CURSOR_KEY: &str = "temporal_refresh_cursor";
+
+/// Default cosine distance between the stored and expected E2 vector above
+/// which a fingerprint is refreshed.
+pub const DEFAULT_TEMPORAL_DRIFT_THRESHOLD: f32 = 0.01;
+
+/// How E2 encodes a fingerprint's creation time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecencyEncoding {
+    /// Sinusoidal encoding of the absolute timestamp (the production
+    /// encoding). It does not depend on the current time, so only vectors
+    /// written by an older encoding drift.
+    #[default]
+    Absolute,
+    /// Exponential decay relative to the current time. Every vector ages,
+    /// so the drift grows with the time since it was last computed.
+    Decay,
+}
+
+/// Configuration for a [`super::TemporalRefreshJob`].
+#[derive(Debug, Clone)]
+pub struct TemporalRefreshConfig {
+    /// Encoding the expected E2 vector is computed with.
+    pub encoding: RecencyEncoding,
+
+    /// Cosine distance (0.0-2.0) above which a stored vector is refreshed.
+    pub drift_threshold: f32,
+
+    /// Fingerprints checked and checkpointed per batch.
+    pub batch_size: usize,
+
+    /// Processing cursor key the run checkpoints under.
+    pub cursor_key: String,
+}
+
+impl Default for TemporalRefreshConfig {
+    fn default() -> Self {
+        Self {
+            encoding: RecencyEncoding::default(),
+            drift_threshold: DEFAULT_TEMPORAL_DRIFT_THRESHOLD,
+            batch_size: DEFAULT_TEMPORAL_REFRESH_BATCH_SIZE,
+            cursor_key: DEFAULT_TEMPORAL_REFRESH_CURSOR_KEY.to_string(),
+        }
+    }
+}
+
+impl TemporalRefreshConfig {
+    /// Set the encoding.
+    pub fn with_encoding(mut self, encoding: RecencyEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Set the drift threshold.
+    pub fn with_drift_threshold(mut self, drift_threshold: f32) -> Self {
+        self.drift_threshold = drift_threshold;
+        self
+    }
+
+    /// Set the batch size.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// - `CoreError::ValidationError` if the batch size is zero, the drift
+    ///   threshold is outside 0.0-2.0, or the cursor key is empty.
+    pub fn validate(&self) -> CoreResult<()> {
+        if self.batch_size == 0 {
+            return Err(CoreError::ValidationError {
+                field: "batch_size".to_string(),
+                message: "Batch size must be greater than 0".to_string(),
+            });
+        }
+        if !(0.0..=2.0).contains(&self.drift_threshold) {
+            return Err(CoreError::ValidationError {
+                field: "drift_threshold".to_string(),
+                message: format!(
+                    "Drift threshold must be a cosine distance in [0.0, 2.0], got {}",
+                    self.drift_threshold
+                ),
+            });
+        }
+        if self.cursor_key.is_empty() {
+            return Err(CoreError::ValidationError {
+                field: "cursor_key".to_string(),
+                message: "Cursor key cannot be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Persisted progress of a temporal refresh run.
+///
+/// Serialized as JSON via `store_processing_cursor` after every batch. The
+/// run resumes after `last_fingerprint_id`; a completed cursor starts a new
+/// run from the beginning.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemporalRefreshCursor {
+    /// ID of the last fingerprint checked (fingerprints are visited in ID order).
+    pub last_fingerprint_id: Option<Uuid>,
+    /// Fingerprints whose E2 vector was rewritten.
+    pub refreshed: u64,
+    /// Fingerprints within the drift threshold, or deleted mid-run.
+    pub skipped: u64,
+    /// True once the run has visited every fingerprint.
+    pub completed: bool,
+}
+
+impl TemporalRefreshCursor {
+    /// Cursor resuming after `fingerprint_id`.
+    pub fn after(fingerprint_id: Uuid) -> Self {
+        Self {
+            last_fingerprint_id: Some(fingerprint_id),
+            ..Self::default()
+        }
+    }
+
+    /// Total fingerprints visited so far.
+    pub fn visited(&self) -> u64 {
+        self.refreshed + self.skipped
+    }
+}
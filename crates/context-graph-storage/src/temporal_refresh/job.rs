@@ -0,0 +1,205 @@
+//! Temporal refresh job: streams stored fingerprints and rewrites stale E2 vectors.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use tracing::{debug, info, warn};
+
+use context_graph_core::error::{CoreError, CoreResult};
+use context_graph_core::teleological::{Embedder, EmbedderMask};
+use context_graph_core::traits::TeleologicalMemoryStore;
+use context_graph_core::types::fingerprint::SemanticFingerprint;
+use context_graph_embeddings::models::custom::{compute_decay_embedding, DEFAULT_DECAY_RATES};
+
+use super::clock::{Clock, SystemClock};
+use super::config::{RecencyEncoding, TemporalRefreshConfig, TemporalRefreshCursor};
+
+/// Rewrites E2 vectors that no longer match their fingerprint's timestamp.
+///
+/// Fingerprints are visited in ID order, one batch at a time. For each one,
+/// the expected E2 vector is computed from `created_at` (and, for
+/// [`RecencyEncoding::Decay`], the clock time at the start of the batch). If
+/// the cosine distance to the stored vector exceeds
+/// [`TemporalRefreshConfig::drift_threshold`], `update_embeddings()` replaces
+/// only the E2 slice and its HNSW entry; every other space is left as stored.
+///
+/// After each batch the cursor is checkpointed under
+/// [`TemporalRefreshConfig::cursor_key`]. Refreshed vectors are within the
+/// threshold afterwards, so replaying a batch after a crash does no extra work.
+pub struct TemporalRefreshJob {
+    store: Arc<dyn TeleologicalMemoryStore>,
+    clock: Arc<dyn Clock>,
+    config: TemporalRefreshConfig,
+}
+
+impl TemporalRefreshJob {
+    /// Create a job reading the system clock.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CoreError::ValidationError` if `config` is invalid.
+    pub fn new(
+        store: Arc<dyn TeleologicalMemoryStore>,
+        config: TemporalRefreshConfig,
+    ) -> CoreResult<Self> {
+        config.validate()?;
+        Ok(Self {
+            store,
+            clock: Arc::new(SystemClock),
+            config,
+        })
+    }
+
+    /// Read the current time from `clock` instead of the system clock.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// The job configuration.
+    pub fn config(&self) -> &TemporalRefreshConfig {
+        &self.config
+    }
+
+    /// Run to completion, starting from `resume_from` or the stored cursor.
+    ///
+    /// With `resume_from = None`, an unfinished stored cursor is resumed and a
+    /// finished (or missing) one starts a fresh run.
+    pub async fn run(
+        &self,
+        resume_from: Option<TemporalRefreshCursor>,
+    ) -> CoreResult<TemporalRefreshCursor> {
+        let mut cursor = match resume_from {
+            Some(cursor) => cursor,
+            None => self.load_cursor().await?,
+        };
+        info!(
+            encoding = ?self.config.encoding,
+            drift_threshold = self.config.drift_threshold,
+            batch_size = self.config.batch_size,
+            after = ?cursor.last_fingerprint_id,
+            "Starting temporal refresh run"
+        );
+
+        while self.run_batch(&mut cursor).await? {}
+
+        cursor.completed = true;
+        self.save_cursor(&cursor).await?;
+        info!(
+            refreshed = cursor.refreshed,
+            skipped = cursor.skipped,
+            "Temporal refresh run complete"
+        );
+        Ok(cursor)
+    }
+
+    /// Check the next batch after `cursor`, refresh drifted vectors, and
+    /// checkpoint it.
+    ///
+    /// Returns `false` when there are no fingerprints left.
+    pub async fn run_batch(&self, cursor: &mut TemporalRefreshCursor) -> CoreResult<bool> {
+        let page = self
+            .store
+            .list_fingerprints_after(cursor.last_fingerprint_id, self.config.batch_size)
+            .await?;
+        if page.is_empty() {
+            return Ok(false);
+        }
+
+        let now = self.clock.now();
+        let mask = EmbedderMask::from_slice(&[Embedder::TemporalRecent]);
+        for fingerprint in page {
+            let id = fingerprint.id;
+            let expected = self.expected_e2(fingerprint.created_at, now);
+            let drift = cosine_distance(&fingerprint.semantic.e2_temporal_recent, &expected);
+
+            if drift > self.config.drift_threshold {
+                let embeddings = SemanticFingerprint {
+                    e2_temporal_recent: expected,
+                    ..SemanticFingerprint::zeroed()
+                };
+                if self.store.update_embeddings(id, mask, embeddings).await? {
+                    debug!(fingerprint_id = %id, drift, "Refreshed E2 vector");
+                    cursor.refreshed += 1;
+                } else {
+                    // Deleted between listing and update
+                    warn!(fingerprint_id = %id, "Fingerprint disappeared during temporal refresh");
+                    cursor.skipped += 1;
+                }
+            } else {
+                cursor.skipped += 1;
+            }
+            cursor.last_fingerprint_id = Some(id);
+        }
+
+        self.save_cursor(cursor).await?;
+        debug!(
+            visited = cursor.visited(),
+            after = ?cursor.last_fingerprint_id,
+            "Temporal refresh batch checkpointed"
+        );
+        Ok(true)
+    }
+
+    /// The E2 vector a fingerprint created at `created_at` should hold at `now`.
+    pub fn expected_e2(&self, created_at: DateTime<Utc>, now: DateTime<Utc>) -> Vec<f32> {
+        let reference = match self.config.encoding {
+            RecencyEncoding::Absolute => None,
+            RecencyEncoding::Decay => Some(now),
+        };
+        compute_decay_embedding(created_at, reference, &DEFAULT_DECAY_RATES)
+    }
+
+    /// Load the stored cursor, or a fresh one if none is stored, the stored
+    /// run already completed, or it cannot be parsed.
+    pub async fn load_cursor(&self) -> CoreResult<TemporalRefreshCursor> {
+        let Some(bytes) = self
+            .store
+            .get_processing_cursor(&self.config.cursor_key)
+            .await?
+        else {
+            return Ok(TemporalRefreshCursor::default());
+        };
+        match serde_json::from_slice::<TemporalRefreshCursor>(&bytes) {
+            Ok(cursor) if cursor.completed => Ok(TemporalRefreshCursor::default()),
+            Ok(cursor) => Ok(cursor),
+            Err(e) => {
+                // Restarting is safe: fresh vectors are skipped
+                warn!(error = %e, "Failed to parse temporal refresh cursor, starting fresh");
+                Ok(TemporalRefreshCursor::default())
+            }
+        }
+    }
+
+    async fn save_cursor(&self, cursor: &TemporalRefreshCursor) -> CoreResult<()> {
+        let json = serde_json::to_vec(cursor).map_err(|e| {
+            CoreError::SerializationError(format!(
+                "Failed to serialize temporal refresh cursor: {}",
+                e
+            ))
+        })?;
+        self.store
+            .store_processing_cursor(&self.config.cursor_key, &json)
+            .await
+    }
+}
+
+/// Cosine distance (0.0-2.0) between two vectors.
+///
+/// Mismatched lengths and zero-norm vectors (legacy E2 data) count as the
+/// maximum distance, so they are always refreshed.
+pub(super) fn cosine_distance(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 2.0;
+    }
+    let (mut dot, mut norm_a, mut norm_b) = (0.0f32, 0.0f32, 0.0f32);
+    for (x, y) in a.iter().zip(b) {
+        dot += x * y;
+        norm_a += x * x;
+        norm_b += y * y;
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 2.0;
+    }
+    (1.0 - dot / (norm_a.sqrt() * norm_b.sqrt())).clamp(0.0, 2.0)
+}
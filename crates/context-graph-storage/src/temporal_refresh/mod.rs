@@ -0,0 +1,38 @@
+//! Temporal refresh of stored E2 recency vectors.
+//!
+//! E2 (TemporalRecent) is computed once, at store time. When E2 encodes age
+//! relative to "now" (decay encoding), stored vectors go stale as time
+//! passes; vectors written by an older encoding are stale from the start.
+//! [`TemporalRefreshJob`] recomputes the expected E2 from each fingerprint's
+//! stored `created_at`, and rewrites only the E2 slice (and its HNSW entry)
+//! of fingerprints whose stored vector has drifted past a threshold.
+//!
+//! No content is re-embedded: the expected vector is a pure function of the
+//! timestamp, so checking a fingerprint costs microseconds.
+//!
+//! E4 (TemporalPositional) encodes session position and sequence, which do
+//! not change with the current time, so it never drifts and is not refreshed.
+//! E3 (TemporalPeriodic) is likewise a function of the timestamp alone.
+//!
+//! Progress is checkpointed as a [`TemporalRefreshCursor`] after every batch,
+//! so an interrupted run resumes where it stopped.
+//!
+//! # Module Structure
+//!
+//! - `clock`: Time source, replaceable in tests
+//! - `config`: Run configuration and the persisted resume cursor
+//! - `job`: The batch loop and per-fingerprint drift check
+
+mod clock;
+mod config;
+mod job;
+#[cfg(test)]
+mod tests;
+
+pub use clock::{Clock, SystemClock};
+pub use config::{
+    RecencyEncoding, TemporalRefreshConfig, TemporalRefreshCursor,
+    DEFAULT_TEMPORAL_DRIFT_THRESHOLD, DEFAULT_TEMPORAL_REFRESH_BATCH_SIZE,
+    DEFAULT_TEMPORAL_REFRESH_CURSOR_KEY,
+};
+pub use job::TemporalRefreshJob;
@@ -0,0 +1,213 @@
+//! Tests for the temporal refresh job.
+
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use tempfile::TempDir;
+use uuid::Uuid;
+
+use context_graph_core::traits::TeleologicalMemoryStore;
+use context_graph_core::types::fingerprint::{SemanticFingerprint, TeleologicalFingerprint};
+use context_graph_embeddings::models::custom::{compute_decay_embedding, DEFAULT_DECAY_RATES};
+
+use crate::teleological::indexes::{EmbedderIndex, EmbedderIndexOps};
+use crate::teleological::RocksDbTeleologicalStore;
+
+use super::job::cosine_distance;
+use super::*;
+
+/// Clock frozen at a settable instant.
+struct FakeClock(AtomicI64);
+
+impl FakeClock {
+    fn at(time: DateTime<Utc>) -> Arc<Self> {
+        Arc::new(Self(AtomicI64::new(time.timestamp_millis())))
+    }
+
+    fn advance(&self, by: Duration) {
+        self.0.fetch_add(by.num_milliseconds(), Ordering::SeqCst);
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(self.0.load(Ordering::SeqCst))
+            .unwrap()
+    }
+}
+
+fn t0() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap()
+}
+
+/// Store one fingerprint per age, created `age` before `t0` with a
+/// decay-encoded E2 computed at `t0`.
+async fn seeded_store(ages: &[Duration]) -> (Arc<RocksDbTeleologicalStore>, Vec<Uuid>, TempDir) {
+    let tmp = TempDir::new().unwrap();
+    let store = Arc::new(RocksDbTeleologicalStore::open(tmp.path()).unwrap());
+    let mut ids = Vec::with_capacity(ages.len());
+    for (i, age) in ages.iter().enumerate() {
+        let created_at = t0() - *age;
+        let mut semantic = SemanticFingerprint::stub();
+        semantic.e2_temporal_recent =
+            compute_decay_embedding(created_at, Some(t0()), &DEFAULT_DECAY_RATES);
+        let mut fp = TeleologicalFingerprint::new(semantic, [i as u8; 32]);
+        fp.created_at = created_at;
+        fp.last_updated = created_at;
+        ids.push(store.store(fp).await.unwrap());
+    }
+    (store, ids, tmp)
+}
+
+fn decay_job(
+    store: &Arc<RocksDbTeleologicalStore>,
+    clock: &Arc<FakeClock>,
+    batch_size: usize,
+) -> TemporalRefreshJob {
+    let config = TemporalRefreshConfig::default()
+        .with_encoding(RecencyEncoding::Decay)
+        .with_batch_size(batch_size);
+    TemporalRefreshJob::new(store.clone(), config)
+        .unwrap()
+        .with_clock(clock.clone())
+}
+
+/// Serialized fingerprint with E2 blanked, to compare every other space bit for bit.
+fn without_e2(fp: &TeleologicalFingerprint) -> Vec<u8> {
+    let mut semantic = fp.semantic.clone();
+    semantic.e2_temporal_recent.clear();
+    bincode::serialize(&semantic).unwrap()
+}
+
+#[tokio::test]
+async fn test_advanced_clock_refreshes_only_e2() {
+    let ages = [Duration::minutes(1), Duration::hours(1), Duration::days(1)];
+    let (store, ids, _tmp) = seeded_store(&ages).await;
+    let clock = FakeClock::at(t0());
+
+    // Nothing has aged yet
+    let report = decay_job(&store, &clock, 64).run(None).await.unwrap();
+    assert_eq!(report.refreshed, 0);
+    assert_eq!(report.skipped, 3);
+
+    let mut before = Vec::new();
+    for id in &ids {
+        before.push(store.retrieve(*id).await.unwrap().unwrap());
+    }
+
+    clock.advance(Duration::hours(6));
+    let job = decay_job(&store, &clock, 64);
+    let report = job.run(None).await.unwrap();
+    assert!(report.completed);
+    assert_eq!(report.visited(), 3);
+    assert!(
+        report.refreshed > 0,
+        "six hours must age a one-minute-old vector"
+    );
+
+    let e2_index = store
+        .index_registry
+        .get(EmbedderIndex::E2TemporalRecent)
+        .unwrap();
+    let mut refreshed = 0;
+    for old in &before {
+        let expected = job.expected_e2(old.created_at, clock.now());
+        let new = store.retrieve(old.id).await.unwrap().unwrap();
+        if cosine_distance(&old.semantic.e2_temporal_recent, &expected)
+            > job.config().drift_threshold
+        {
+            refreshed += 1;
+            assert_eq!(new.semantic.e2_temporal_recent, expected);
+            let hits = e2_index.search(&expected, 1, None).unwrap();
+            assert_eq!(hits[0].0, old.id, "E2 HNSW entry must hold the new vector");
+        } else {
+            assert_eq!(
+                new.semantic.e2_temporal_recent,
+                old.semantic.e2_temporal_recent
+            );
+        }
+        assert_eq!(without_e2(&new), without_e2(old), "non-E2 spaces changed");
+        assert_eq!(new.created_at, old.created_at);
+        assert_eq!(new.last_updated, old.last_updated);
+    }
+    assert_eq!(report.refreshed, refreshed);
+}
+
+#[tokio::test]
+async fn test_interrupted_run_resumes_from_cursor() {
+    let ages: Vec<Duration> = (1..=5).map(Duration::minutes).collect();
+    let (store, _ids, _tmp) = seeded_store(&ages).await;
+    let clock = FakeClock::at(t0() + Duration::days(2));
+
+    // First run is "killed" after 2 batches (4 fingerprints)
+    {
+        let job = decay_job(&store, &clock, 2);
+        let mut cursor = job.load_cursor().await.unwrap();
+        for _ in 0..2 {
+            assert!(job.run_batch(&mut cursor).await.unwrap());
+        }
+        assert_eq!(cursor.visited(), 4);
+    }
+
+    let job = decay_job(&store, &clock, 2);
+    let resumed = job.load_cursor().await.unwrap();
+    assert_eq!(resumed.visited(), 4);
+    assert!(!resumed.completed);
+
+    let report = job.run(None).await.unwrap();
+    assert!(report.completed);
+    assert_eq!(report.refreshed, 5);
+    assert_eq!(report.skipped, 0);
+
+    // A completed cursor starts a new run, which finds nothing stale
+    let report = job.run(None).await.unwrap();
+    assert_eq!(report.refreshed, 0);
+    assert_eq!(report.skipped, 5);
+}
+
+#[tokio::test]
+async fn test_absolute_encoding_repairs_legacy_vectors() {
+    let tmp = TempDir::new().unwrap();
+    let store = Arc::new(RocksDbTeleologicalStore::open(tmp.path()).unwrap());
+    // Stub E2 (constant 0.1) is what pre-sinusoidal fingerprints look like
+    let id = store
+        .store(TeleologicalFingerprint::new(
+            SemanticFingerprint::stub(),
+            [7u8; 32],
+        ))
+        .await
+        .unwrap();
+
+    let job = TemporalRefreshJob::new(store.clone(), TemporalRefreshConfig::default()).unwrap();
+    let report = job.run(None).await.unwrap();
+    assert_eq!(report.refreshed, 1);
+
+    let fp = store.retrieve(id).await.unwrap().unwrap();
+    assert_eq!(
+        fp.semantic.e2_temporal_recent,
+        compute_decay_embedding(fp.created_at, None, &DEFAULT_DECAY_RATES)
+    );
+
+    // The absolute encoding does not age
+    let report = job.run(None).await.unwrap();
+    assert_eq!(report.refreshed, 0);
+    assert_eq!(report.skipped, 1);
+}
+
+#[test]
+fn test_config_validation() {
+    assert!(TemporalRefreshConfig::default().validate().is_ok());
+    assert!(TemporalRefreshConfig::default()
+        .with_batch_size(0)
+        .validate()
+        .is_err());
+    assert!(TemporalRefreshConfig::default()
+        .with_drift_threshold(2.5)
+        .validate()
+        .is_err());
+    assert!(TemporalRefreshConfig::default()
+        .with_drift_threshold(f32::NAN)
+        .validate()
+        .is_err());
+}
@@ -0,0 +1,93 @@
+//! Access statistics integration test.
+//!
+//! Counts accesses on a real RocksDB store and checks they survive restart:
+//! - 10 retrievals, a forced flush and a reopen leave 10 hits with a recent
+//!   timestamp; an untouched memory reports zero
+//! - Search hits are counted too, and pending counts are flushed when the
+//!   store is dropped without an explicit flush
+
+use std::path::Path;
+use std::time::Duration;
+
+use chrono::Utc;
+use context_graph_core::traits::{TeleologicalMemoryStore, TeleologicalSearchOptions};
+use context_graph_storage::teleological::{
+    AccessStatsConfig, RocksDbTeleologicalStore, TeleologicalStoreConfig,
+};
+use context_graph_test_utils::create_real_fingerprint;
+use tempfile::TempDir;
+
+/// Open a store whose tracker only flushes when asked or on drop.
+fn open_store(path: &Path) -> RocksDbTeleologicalStore {
+    let config = TeleologicalStoreConfig {
+        access_stats: AccessStatsConfig {
+            flush_interval: Duration::from_secs(3600),
+            flush_after_updates: usize::MAX,
+        },
+        ..TeleologicalStoreConfig::default()
+    };
+    RocksDbTeleologicalStore::open_with_config(path, config).expect("open store")
+}
+
+#[tokio::test]
+async fn test_access_stats_survive_restart() {
+    let temp_dir = TempDir::new().unwrap();
+    let (read, untouched) = (create_real_fingerprint(), create_real_fingerprint());
+    let (read_id, untouched_id) = (read.id, untouched.id);
+
+    {
+        let store = open_store(temp_dir.path());
+        store.store(read).await.unwrap();
+        store.store(untouched).await.unwrap();
+
+        for _ in 0..10 {
+            assert!(store.retrieve(read_id).await.unwrap().is_some());
+        }
+        // Pending counts are visible before the flush
+        let stats = store.access_stats(&[read_id]).await.unwrap();
+        assert_eq!(stats[0].hits, 10);
+
+        store.flush_access_stats().await.unwrap();
+    }
+
+    let store = open_store(temp_dir.path());
+    let stats = store.access_stats(&[read_id, untouched_id]).await.unwrap();
+
+    assert_eq!(stats[0].hits, 10);
+    assert_eq!(stats[0].hits_7d, 10);
+    let last_access = stats[0].last_access.expect("last access recorded");
+    let age = Utc::now() - last_access;
+    assert!(
+        age >= chrono::Duration::zero() && age < chrono::Duration::minutes(5),
+        "last access {} is not recent",
+        last_access
+    );
+
+    assert!(stats[1].is_untouched());
+    assert_eq!(stats[1].hits_7d, 0);
+    assert_eq!(stats[1].last_access, None);
+}
+
+#[tokio::test]
+async fn test_search_hits_flushed_on_drop() {
+    let temp_dir = TempDir::new().unwrap();
+    let fingerprint = create_real_fingerprint();
+    let (id, query) = (fingerprint.id, fingerprint.semantic.clone());
+
+    {
+        let store = open_store(temp_dir.path());
+        store.store(fingerprint).await.unwrap();
+        let results = store
+            .search_semantic(&query, TeleologicalSearchOptions::quick(5))
+            .await
+            .unwrap();
+        assert!(results.iter().any(|r| r.fingerprint.id == id));
+        store.retrieve(id).await.unwrap();
+        // Dropped without flush_access_stats
+    }
+
+    let store = open_store(temp_dir.path());
+    let stats = store.access_stats(&[id]).await.unwrap();
+    assert_eq!(stats[0].hits, 2);
+    assert!(stats[0].last_access.is_some());
+}
@@ -0,0 +1,117 @@
+//! E11 entity embedder integration test with a real relation fixture.
+//!
+//! Feeds the entity-relation triples from `context-graph-test-utils` through
+//! the warm `MultiArrayEmbeddingProvider` (all 13 models on GPU) and checks
+//! the E11 (KEPLER) output end to end:
+//! - Every E11 vector is non-zero, finite and unit-norm
+//! - The KEPLER slot sits at its declared offset/dimension in the 14-model layout
+//! - The E11 HNSW index accepts every vector and retrieves each triple by itself
+//!
+//! # No Mock Data Policy
+//! Embeddings come from the real provider. Requires CUDA and the model files.
+
+use context_graph_embeddings::types::dimensions::{
+    self, KEPLER, MODEL_COUNT, OFFSETS, PROJECTED_DIMENSIONS, TOTAL_DIMENSION,
+};
+use context_graph_embeddings::types::{ModelEmbedding, ModelInput};
+use context_graph_embeddings::{
+    get_warm_provider, initialize_global_warm_provider, ModelId, MultiArrayEmbedding,
+};
+use context_graph_storage::teleological::indexes::{
+    EmbedderIndex, EmbedderIndexOps, HnswEmbedderIndex,
+};
+use context_graph_test_utils::{
+    EntityRelationTriple, E11_EXPECTED_DIM, E11_EXPECTED_NORM, E11_NORM_TOLERANCE,
+    ENTITY_RELATION_TRIPLES,
+};
+use uuid::Uuid;
+
+fn l2_norm(v: &[f32]) -> f32 {
+    v.iter().map(|x| x * x).sum::<f32>().sqrt()
+}
+
+/// Embed every fixture triple and return (triple, E11 vector) pairs.
+async fn embed_fixture() -> Vec<(EntityRelationTriple, Vec<f32>)> {
+    initialize_global_warm_provider()
+        .await
+        .expect("warm provider must initialize (CUDA + models required)");
+    let provider = get_warm_provider().expect("warm provider");
+
+    let mut embedded = Vec::with_capacity(ENTITY_RELATION_TRIPLES.len());
+    for triple in ENTITY_RELATION_TRIPLES {
+        let input = ModelInput::entity_relation(triple.head, triple.relation, triple.tail)
+            .expect("fixture triples are valid");
+        let (content, _) = input.as_text().expect("entity relation input is text");
+        assert_eq!(content, triple.verbalized());
+
+        let output = provider.embed_all(content).await.expect("embed_all");
+        embedded.push((triple, output.fingerprint.e11_entity));
+    }
+    embedded
+}
+
+#[tokio::test]
+async fn test_e11_entity_relation_embeddings_end_to_end() {
+    let embedded = embed_fixture().await;
+
+    // E11 vectors: right size, non-zero, finite, unit-norm
+    for (triple, vector) in &embedded {
+        assert_eq!(vector.len(), E11_EXPECTED_DIM, "{:?}", triple);
+        assert!(vector.iter().all(|x| x.is_finite()), "{:?}", triple);
+        assert!(
+            vector.iter().any(|x| *x != 0.0),
+            "{:?} embedded to zeros",
+            triple
+        );
+        let norm = l2_norm(vector);
+        assert!(
+            (norm - E11_EXPECTED_NORM).abs() < E11_NORM_TOLERANCE,
+            "{:?}: norm {} not within {} of {}",
+            triple,
+            norm,
+            E11_NORM_TOLERANCE,
+            E11_EXPECTED_NORM
+        );
+    }
+
+    // KEPLER layout: last slot of the 14-model array, 768D, ends at TOTAL_DIMENSION
+    let kepler_idx = ModelId::Kepler as usize;
+    assert_eq!(kepler_idx, MODEL_COUNT - 1);
+    assert_eq!(KEPLER, E11_EXPECTED_DIM);
+    assert_eq!(PROJECTED_DIMENSIONS[kepler_idx], KEPLER);
+    assert_eq!(ModelId::Kepler.projected_dimension(), KEPLER);
+    assert_eq!(OFFSETS[kepler_idx], dimensions::offset_by_index(kepler_idx));
+    assert_eq!(OFFSETS[kepler_idx] + KEPLER, TOTAL_DIMENSION);
+
+    // The KEPLER slot of a multi-array embedding holds the E11 vector unchanged
+    let (_, first) = &embedded[0];
+    let mut multi = MultiArrayEmbedding::new();
+    let mut kepler = ModelEmbedding::new(ModelId::Kepler, first.clone(), 0);
+    kepler.set_projected(true);
+    multi.set(kepler);
+    multi.validate().expect("KEPLER embedding validates");
+    assert_eq!(multi.total_dimension(), KEPLER);
+    assert_eq!(multi.get_vector(ModelId::Kepler), Some(first.as_slice()));
+
+    // Per-space index accepts every vector and finds each triple first
+    let index = HnswEmbedderIndex::new(EmbedderIndex::E11Entity);
+    assert_eq!(index.config().dimension, E11_EXPECTED_DIM);
+    let ids: Vec<Uuid> = embedded.iter().map(|_| Uuid::new_v4()).collect();
+    for (id, (triple, vector)) in ids.iter().zip(&embedded) {
+        index
+            .insert(*id, vector)
+            .unwrap_or_else(|e| panic!("insert {:?}: {}", triple, e));
+    }
+    assert_eq!(index.len(), embedded.len());
+
+    for (id, (triple, vector)) in ids.iter().zip(&embedded) {
+        let hits = index.search(vector, 3, None).expect("search");
+        assert_eq!(hits.first().map(|h| h.0), Some(*id), "{:?}", triple);
+        assert!(
+            hits[0].1 < 1e-3,
+            "{:?}: self-distance {}",
+            triple,
+            hits[0].1
+        );
+    }
+}
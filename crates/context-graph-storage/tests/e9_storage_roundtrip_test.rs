@@ -92,6 +92,9 @@ fn create_test_fingerprint_with_unique_vectors() -> TeleologicalFingerprint {
         importance: 0.5,
         last_accessed_at: Utc::now(),
         e6_sparse: None,
+        expires_at: None,
+        namespace: TeleologicalFingerprint::DEFAULT_NAMESPACE.to_string(),
+        embedding_states: Default::default(),
     }
 }
 
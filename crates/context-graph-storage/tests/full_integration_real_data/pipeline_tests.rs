@@ -10,8 +10,9 @@ use context_graph_core::traits::TeleologicalMemoryStore;
 use context_graph_core::types::fingerprint::TeleologicalFingerprint;
 use context_graph_storage::teleological::{
     deserialize_e1_matryoshka_128, deserialize_teleological_fingerprint, e1_matryoshka_128_key,
-    fingerprint_key, RocksDbTeleologicalStore, TeleologicalStoreConfig, CF_E13_SPLADE_INVERTED,
-    CF_E1_MATRYOSHKA_128, CF_FINGERPRINTS, QUANTIZED_EMBEDDER_CFS, TELEOLOGICAL_CFS,
+    fingerprint_key, FsyncPolicy, RocksDbTeleologicalStore, TeleologicalStoreConfig,
+    CF_E13_SPLADE_INVERTED, CF_E1_MATRYOSHKA_128, CF_FINGERPRINTS, QUANTIZED_EMBEDDER_CFS,
+    TELEOLOGICAL_CFS,
 };
 use tempfile::TempDir;
 use uuid::Uuid;
@@ -36,6 +37,8 @@ async fn test_full_storage_pipeline_real_data() {
         enable_wal: true,
         create_if_missing: true,
         gc_retention_secs: 7 * 24 * 3600,
+        fsync_policy: FsyncPolicy::OnShutdown,
+        ..TeleologicalStoreConfig::default()
     };
     let store = RocksDbTeleologicalStore::open_with_config(temp_dir.path(), config)
         .expect("Failed to open store");
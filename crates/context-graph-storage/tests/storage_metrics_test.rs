@@ -0,0 +1,104 @@
+//! Storage metrics integration test.
+//!
+//! Populates a RocksDB store through `context-graph-test-utils` and checks
+//! `storage_metrics` / `largest_memories` end to end:
+//! - CF_FINGERPRINTS key counts match the stored memories, before and after
+//!   hard-deleting half of them and compacting
+//! - Live data and SST sizes are non-zero and shrink with the deletions
+//! - Checkpoints are reported as an auxiliary entry
+//! - `largest_memories` ranks live memories largest first
+
+use context_graph_core::traits::{ColumnFamilyMetrics, StorageMetrics};
+use context_graph_storage::teleological::CF_FINGERPRINTS;
+use context_graph_test_utils::{create_populated_store, PopulatedStoreSpec};
+
+const MEMORIES: usize = 20;
+
+fn fingerprints(metrics: &StorageMetrics) -> &ColumnFamilyMetrics {
+    metrics
+        .column_family(CF_FINGERPRINTS)
+        .expect("fingerprints CF must be reported")
+}
+
+#[tokio::test]
+async fn test_storage_metrics_track_stored_and_deleted_memories() {
+    let populated = create_populated_store(PopulatedStoreSpec {
+        memories: MEMORIES,
+        ..Default::default()
+    })
+    .await;
+    let store = &populated.store;
+    let ids = &populated.manifest.memory_ids;
+    store.flush().await.unwrap();
+
+    let before = store.storage_metrics().unwrap();
+    let fp_before = fingerprints(&before).clone();
+    assert_eq!(fp_before.num_keys, MEMORIES as u64);
+    assert!(fp_before.live_data_bytes > 0);
+    assert!(fp_before.sst_files > 0);
+    assert!(fp_before.sst_bytes > 0);
+    assert!(before.total_sst_bytes() >= fp_before.sst_bytes);
+    // Largest first
+    assert!(before
+        .column_families
+        .windows(2)
+        .all(|w| w[0].live_data_bytes >= w[1].live_data_bytes));
+
+    let largest = store.largest_memories(3).await.unwrap();
+    assert_eq!(largest.len(), 3);
+    assert!(largest
+        .windows(2)
+        .all(|w| w[0].total_bytes() >= w[1].total_bytes()));
+    for m in &largest {
+        assert!(ids.contains(&m.id));
+        assert!(m.fingerprint_bytes > 0);
+    }
+
+    for id in ids.iter().step_by(2) {
+        assert!(store.delete(*id, false).await.unwrap());
+    }
+    store.flush().await.unwrap();
+    store.compact().await.unwrap();
+
+    let after = store.storage_metrics().unwrap();
+    let fp_after = fingerprints(&after);
+    assert_eq!(fp_after.num_keys, (MEMORIES / 2) as u64);
+    assert!(fp_after.live_data_bytes > 0);
+    assert!(
+        fp_after.live_data_bytes < fp_before.live_data_bytes,
+        "{} !< {}",
+        fp_after.live_data_bytes,
+        fp_before.live_data_bytes
+    );
+    assert!(fp_after.sst_bytes < fp_before.sst_bytes);
+
+    let largest = store.largest_memories(MEMORIES).await.unwrap();
+    assert_eq!(largest.len(), MEMORIES / 2);
+    assert!(largest
+        .iter()
+        .all(|m| !ids.iter().step_by(2).any(|id| *id == m.id)));
+}
+
+#[tokio::test]
+async fn test_checkpoints_are_reported_as_auxiliary_files() {
+    let populated = create_populated_store(PopulatedStoreSpec {
+        memories: 5,
+        ..Default::default()
+    })
+    .await;
+    let store = &populated.store;
+
+    let before = store.storage_metrics().unwrap();
+    assert!(before.auxiliary.iter().all(|a| a.name != "checkpoints"));
+
+    store.checkpoint().await.unwrap();
+    let after = store.storage_metrics().unwrap();
+    let checkpoints = after
+        .auxiliary
+        .iter()
+        .find(|a| a.name == "checkpoints")
+        .expect("checkpoints directory must be reported");
+    assert!(checkpoints.files > 0);
+    assert!(checkpoints.bytes > 0);
+    assert!(after.auxiliary_bytes() >= checkpoints.bytes);
+}
@@ -0,0 +1,370 @@
+//! Seeded corpora of fingerprints with known cluster structure.
+//!
+//! [`CorpusBuilder`] generates clusters whose E1 vectors are perturbations of
+//! per-cluster centroids, so clustering and search tests can assert against
+//! a known [`CorpusGroundTruth`] instead of hand-rolling fixtures. Every
+//! random draw (vectors, ids, hashes, domains, timestamps) comes from the
+//! builder's seed: the same seed always yields the same corpus.
+//!
+//! ```ignore
+//! let builder = CorpusBuilder::new(42)
+//!     .with_clusters(4, 25, 0.8)
+//!     .with_domains(&[("code", 0.7), ("docs", 0.3)])
+//!     .with_temporal_spread(chrono::Duration::days(30));
+//! let (corpus, truth) = builder.build_with_ground_truth();
+//! assert!(truth.same_cluster(corpus[0].id, corpus[1].id));
+//! ```
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use context_graph_core::types::fingerprint::TeleologicalFingerprint;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use uuid::Uuid;
+
+use crate::fingerprints::{
+    generate_real_semantic_fingerprint_with_rng, generate_real_unit_vector_with_rng,
+};
+
+/// E1 semantic dimension.
+const E1_DIM: usize = 1024;
+
+/// Builder for a reproducible, clustered fingerprint corpus.
+#[derive(Debug, Clone)]
+pub struct CorpusBuilder {
+    seed: u64,
+    clusters: usize,
+    cluster_size: usize,
+    intra_similarity: f32,
+    domains: Vec<(String, f32)>,
+    temporal_spread: Duration,
+    base_time: DateTime<Utc>,
+}
+
+impl CorpusBuilder {
+    /// Create a builder: 3 clusters of 10, member-to-centroid cosine 0.8,
+    /// every fingerprint in the default namespace, all created at the same
+    /// instant (2024-01-01T00:00:00Z).
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            clusters: 3,
+            cluster_size: 10,
+            intra_similarity: 0.8,
+            domains: vec![(TeleologicalFingerprint::DEFAULT_NAMESPACE.to_string(), 1.0)],
+            temporal_spread: Duration::zero(),
+            base_time: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        }
+    }
+
+    /// Generate `n` clusters of `size` fingerprints each.
+    ///
+    /// Each member's E1 vector has cosine exactly `intra_similarity` to its
+    /// cluster centroid, so two members of one cluster have an expected
+    /// cosine of `intra_similarity²`. Centroids are independent random unit
+    /// vectors (expected cosine 0 between clusters).
+    ///
+    /// # Panics
+    /// If `n` or `size` is 0, or `intra_similarity` is outside (0, 1].
+    pub fn with_clusters(mut self, n: usize, size: usize, intra_similarity: f32) -> Self {
+        assert!(
+            n > 0 && size > 0,
+            "CorpusBuilder: empty cluster layout {n}x{size}"
+        );
+        assert!(
+            intra_similarity > 0.0 && intra_similarity <= 1.0,
+            "CorpusBuilder: intra_similarity {intra_similarity} outside (0, 1]"
+        );
+        self.clusters = n;
+        self.cluster_size = size;
+        self.intra_similarity = intra_similarity;
+        self
+    }
+
+    /// Assign each fingerprint a domain, stored as its namespace, drawn from
+    /// `distribution` (relative weights; they need not sum to 1).
+    ///
+    /// # Panics
+    /// If `distribution` is empty or any weight is not positive and finite.
+    pub fn with_domains(mut self, distribution: &[(&str, f32)]) -> Self {
+        assert!(!distribution.is_empty(), "CorpusBuilder: no domains given");
+        assert!(
+            distribution.iter().all(|(_, w)| w.is_finite() && *w > 0.0),
+            "CorpusBuilder: domain weights must be positive and finite"
+        );
+        self.domains = distribution
+            .iter()
+            .map(|(name, weight)| (name.to_string(), *weight))
+            .collect();
+        self
+    }
+
+    /// Spread `created_at` uniformly over `range` before the base time.
+    ///
+    /// # Panics
+    /// If `range` is negative.
+    pub fn with_temporal_spread(mut self, range: Duration) -> Self {
+        assert!(
+            range >= Duration::zero(),
+            "CorpusBuilder: negative temporal spread"
+        );
+        self.temporal_spread = range;
+        self
+    }
+
+    /// Newest timestamp in the corpus (default 2024-01-01T00:00:00Z).
+    pub fn with_base_time(mut self, base_time: DateTime<Utc>) -> Self {
+        self.base_time = base_time;
+        self
+    }
+
+    /// Generate the corpus, ordered cluster by cluster.
+    pub fn build(&self) -> Vec<TeleologicalFingerprint> {
+        self.build_with_ground_truth().0
+    }
+
+    /// Ground truth for the corpus [`build`](Self::build) returns.
+    pub fn ground_truth(&self) -> CorpusGroundTruth {
+        self.build_with_ground_truth().1
+    }
+
+    /// Generate the corpus and its ground truth in one pass.
+    pub fn build_with_ground_truth(&self) -> (Vec<TeleologicalFingerprint>, CorpusGroundTruth) {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let centroids: Vec<Vec<f32>> = (0..self.clusters)
+            .map(|_| generate_real_unit_vector_with_rng(&mut rng, E1_DIM))
+            .collect();
+
+        let total_weight: f32 = self.domains.iter().map(|(_, w)| w).sum();
+        let spread_ms = self.temporal_spread.num_milliseconds();
+
+        let mut corpus = Vec::with_capacity(self.clusters * self.cluster_size);
+        let mut members = vec![Vec::with_capacity(self.cluster_size); self.clusters];
+        let mut cluster_of = HashMap::new();
+        let mut domain_of = HashMap::new();
+
+        for (cluster, centroid) in centroids.iter().enumerate() {
+            for _ in 0..self.cluster_size {
+                let id = uuid::Builder::from_random_bytes(rng.gen()).into_uuid();
+                let mut semantic = generate_real_semantic_fingerprint_with_rng(&mut rng);
+                semantic.e1_semantic = perturb(&mut rng, centroid, self.intra_similarity);
+                let mut content_hash = [0u8; 32];
+                rng.fill(&mut content_hash);
+
+                let mut fp = TeleologicalFingerprint::with_id(id, semantic, content_hash);
+                let domain = pick_domain(&mut rng, &self.domains, total_weight);
+                let offset = if spread_ms > 0 {
+                    rng.gen_range(0..=spread_ms)
+                } else {
+                    0
+                };
+                let created_at = self.base_time - Duration::milliseconds(offset);
+                fp.created_at = created_at;
+                fp.last_updated = created_at;
+                fp.last_accessed_at = created_at;
+                fp.namespace = domain.to_string();
+
+                members[cluster].push(id);
+                cluster_of.insert(id, cluster);
+                domain_of.insert(id, domain.to_string());
+                corpus.push(fp);
+            }
+        }
+
+        let truth = CorpusGroundTruth {
+            members,
+            cluster_of,
+            domain_of,
+            intra_similarity: self.intra_similarity,
+        };
+        (corpus, truth)
+    }
+}
+
+/// Known structure of a generated corpus.
+#[derive(Debug, Clone)]
+pub struct CorpusGroundTruth {
+    members: Vec<Vec<Uuid>>,
+    cluster_of: HashMap<Uuid, usize>,
+    domain_of: HashMap<Uuid, String>,
+    intra_similarity: f32,
+}
+
+impl CorpusGroundTruth {
+    /// Number of clusters.
+    pub fn cluster_count(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Member ids of each cluster, in corpus order.
+    pub fn clusters(&self) -> &[Vec<Uuid>] {
+        &self.members
+    }
+
+    /// Cluster index of `id`, or `None` if it is not in the corpus.
+    pub fn cluster_of(&self, id: Uuid) -> Option<usize> {
+        self.cluster_of.get(&id).copied()
+    }
+
+    /// Domain (namespace) assigned to `id`.
+    pub fn domain_of(&self, id: Uuid) -> Option<&str> {
+        self.domain_of.get(&id).map(String::as_str)
+    }
+
+    /// Whether `a` and `b` were generated in the same cluster.
+    pub fn same_cluster(&self, a: Uuid, b: Uuid) -> bool {
+        matches!((self.cluster_of(a), self.cluster_of(b)), (Some(x), Some(y)) if x == y)
+    }
+
+    /// Configured cosine between a member's E1 vector and its centroid.
+    pub fn intra_similarity(&self) -> f32 {
+        self.intra_similarity
+    }
+
+    /// Expected E1 cosine between two members of one cluster.
+    pub fn expected_intra_cosine(&self) -> f32 {
+        self.intra_similarity * self.intra_similarity
+    }
+
+    /// Expected E1 cosine between members of different clusters.
+    pub fn expected_inter_cosine(&self) -> f32 {
+        0.0
+    }
+
+    /// Expected gap between intra- and inter-cluster E1 cosine.
+    pub fn margin(&self) -> f32 {
+        self.expected_intra_cosine() - self.expected_inter_cosine()
+    }
+
+    /// Expected E1 cosine between `a` and `b`, or `None` if either is unknown.
+    pub fn expected_similarity(&self, a: Uuid, b: Uuid) -> Option<f32> {
+        if a == b {
+            return self.cluster_of(a).map(|_| 1.0);
+        }
+        let (x, y) = (self.cluster_of(a)?, self.cluster_of(b)?);
+        Some(if x == y {
+            self.expected_intra_cosine()
+        } else {
+            self.expected_inter_cosine()
+        })
+    }
+}
+
+/// Unit vector with cosine exactly `similarity` to the unit vector `centroid`.
+fn perturb(rng: &mut StdRng, centroid: &[f32], similarity: f32) -> Vec<f32> {
+    // Random direction with the centroid component removed
+    let mut noise = generate_real_unit_vector_with_rng(rng, centroid.len());
+    let along: f32 = noise.iter().zip(centroid).map(|(n, c)| n * c).sum();
+    for (n, c) in noise.iter_mut().zip(centroid) {
+        *n -= along * c;
+    }
+    let norm = noise.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let orthogonal = (1.0 - similarity * similarity).max(0.0).sqrt() / norm.max(f32::EPSILON);
+    centroid
+        .iter()
+        .zip(&noise)
+        .map(|(c, n)| similarity * c + orthogonal * n)
+        .collect()
+}
+
+fn pick_domain<'a>(rng: &mut StdRng, domains: &'a [(String, f32)], total: f32) -> &'a str {
+    let mut draw = rng.gen_range(0.0..total);
+    for (name, weight) in domains {
+        if draw < *weight {
+            return name;
+        }
+        draw -= weight;
+    }
+    // Float rounding can leave a sliver past the last bucket
+    &domains[domains.len() - 1].0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cosine(a: &[f32], b: &[f32]) -> f32 {
+        let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        let na = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let nb = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+        dot / (na * nb)
+    }
+
+    #[test]
+    fn test_same_seed_builds_byte_identical_corpus() {
+        let builder = CorpusBuilder::new(7)
+            .with_clusters(2, 4, 0.9)
+            .with_domains(&[("code", 3.0), ("docs", 1.0)])
+            .with_temporal_spread(Duration::days(30));
+
+        let first = bincode::serialize(&builder.build()).unwrap();
+        let second = bincode::serialize(&builder.build()).unwrap();
+        assert_eq!(first, second);
+
+        let other = CorpusBuilder::new(8)
+            .with_clusters(2, 4, 0.9)
+            .with_domains(&[("code", 3.0), ("docs", 1.0)])
+            .with_temporal_spread(Duration::days(30));
+        assert_ne!(first, bincode::serialize(&other.build()).unwrap());
+    }
+
+    #[test]
+    fn test_intra_cluster_cosine_exceeds_inter_by_margin() {
+        let (corpus, truth) = CorpusBuilder::new(42)
+            .with_clusters(3, 8, 0.8)
+            .build_with_ground_truth();
+        assert_eq!(corpus.len(), 24);
+        assert_eq!(truth.cluster_count(), 3);
+
+        let (mut intra, mut inter) = (Vec::new(), Vec::new());
+        for (i, a) in corpus.iter().enumerate() {
+            for b in &corpus[i + 1..] {
+                let cos = cosine(&a.semantic.e1_semantic, &b.semantic.e1_semantic);
+                if truth.same_cluster(a.id, b.id) {
+                    intra.push(cos);
+                } else {
+                    inter.push(cos);
+                }
+            }
+        }
+
+        let mean = |v: &[f32]| v.iter().sum::<f32>() / v.len() as f32;
+        let gap = mean(&intra) - mean(&inter);
+        assert!(
+            (gap - truth.margin()).abs() < 0.05,
+            "gap {} vs margin {}",
+            gap,
+            truth.margin()
+        );
+        let min_intra = intra.iter().copied().fold(f32::INFINITY, f32::min);
+        let max_inter = inter.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        assert!(min_intra > max_inter, "{} <= {}", min_intra, max_inter);
+    }
+
+    #[test]
+    fn test_ground_truth_matches_build() {
+        let builder = CorpusBuilder::new(3)
+            .with_clusters(2, 5, 0.7)
+            .with_domains(&[("a", 1.0), ("b", 1.0)])
+            .with_temporal_spread(Duration::hours(12));
+        let corpus = builder.build();
+        let truth = builder.ground_truth();
+
+        for (i, fp) in corpus.iter().enumerate() {
+            assert_eq!(truth.cluster_of(fp.id), Some(i / 5));
+            assert_eq!(truth.domain_of(fp.id), Some(fp.namespace.as_str()));
+            let age = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() - fp.created_at;
+            assert!(age >= Duration::zero() && age <= Duration::hours(12));
+        }
+        let same = truth
+            .expected_similarity(corpus[0].id, corpus[1].id)
+            .unwrap();
+        assert!((same - 0.49).abs() < 1e-6);
+        assert_eq!(
+            truth.expected_similarity(corpus[0].id, corpus[5].id),
+            Some(0.0)
+        );
+        assert_eq!(truth.expected_similarity(corpus[0].id, Uuid::nil()), None);
+    }
+}
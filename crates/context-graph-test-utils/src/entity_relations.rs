@@ -0,0 +1,86 @@
+//! Entity-relation triples for E11 (KEPLER) embedding tests.
+//!
+//! The fixture is plain data so any crate can feed it through the real
+//! embedding provider. Triples cover several relation types and entity
+//! kinds (people, places, software, chemistry) so that two triples never
+//! verbalize to near-identical sentences.
+//!
+//! E11 output is L2-normalized, so every embedding is expected to have
+//! norm [`E11_EXPECTED_NORM`] within [`E11_NORM_TOLERANCE`].
+
+/// E11 entity embedding dimension (KEPLER, RoBERTa-base).
+pub const E11_EXPECTED_DIM: usize = 768;
+
+/// Expected L2 norm of an E11 embedding.
+pub const E11_EXPECTED_NORM: f32 = 1.0;
+
+/// Allowed deviation from [`E11_EXPECTED_NORM`].
+pub const E11_NORM_TOLERANCE: f32 = 1e-3;
+
+/// A (head, relation, tail) knowledge triple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntityRelationTriple {
+    /// Head entity name.
+    pub head: &'static str,
+    /// Relation predicate, snake_case as in Wikidata-style dumps.
+    pub relation: &'static str,
+    /// Tail entity name.
+    pub tail: &'static str,
+}
+
+impl EntityRelationTriple {
+    const fn new(head: &'static str, relation: &'static str, tail: &'static str) -> Self {
+        Self {
+            head,
+            relation,
+            tail,
+        }
+    }
+
+    /// The triple as KEPLER verbalizes it: relation underscores become spaces.
+    pub fn verbalized(&self) -> String {
+        format!(
+            "{} {} {}",
+            self.head,
+            self.relation.replace('_', " "),
+            self.tail
+        )
+    }
+}
+
+/// Fixture triples, all distinct after verbalization.
+pub const ENTITY_RELATION_TRIPLES: [EntityRelationTriple; 8] = [
+    EntityRelationTriple::new("Paris", "capital_of", "France"),
+    EntityRelationTriple::new("Marie Curie", "awarded", "Nobel Prize in Chemistry"),
+    EntityRelationTriple::new("Tokio", "depends_on", "Mio"),
+    EntityRelationTriple::new("Rust", "designed_by", "Graydon Hoare"),
+    EntityRelationTriple::new("Danube", "flows_through", "Vienna"),
+    EntityRelationTriple::new("Aspirin", "inhibits", "Cyclooxygenase"),
+    EntityRelationTriple::new("RocksDB", "forked_from", "LevelDB"),
+    EntityRelationTriple::new("Ada Lovelace", "collaborated_with", "Charles Babbage"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_fixture_triples_are_distinct_and_non_blank() {
+        let sentences: HashSet<String> = ENTITY_RELATION_TRIPLES
+            .iter()
+            .map(EntityRelationTriple::verbalized)
+            .collect();
+        assert_eq!(sentences.len(), ENTITY_RELATION_TRIPLES.len());
+
+        for t in &ENTITY_RELATION_TRIPLES {
+            assert!(!t.head.trim().is_empty());
+            assert!(!t.relation.trim().is_empty());
+            assert!(!t.tail.trim().is_empty());
+        }
+        assert_eq!(
+            ENTITY_RELATION_TRIPLES[0].verbalized(),
+            "Paris capital of France"
+        );
+    }
+}
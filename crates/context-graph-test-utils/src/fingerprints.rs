@@ -17,7 +17,11 @@ use uuid::Uuid;
 
 /// Generate a random unit vector of `dim` dimensions (L2 norm = 1.0).
 pub fn generate_real_unit_vector(dim: usize) -> Vec<f32> {
-    let mut rng = rand::thread_rng();
+    generate_real_unit_vector_with_rng(&mut rand::thread_rng(), dim)
+}
+
+/// [`generate_real_unit_vector`] drawing from `rng` (for seeded, reproducible data).
+pub fn generate_real_unit_vector_with_rng<R: Rng + ?Sized>(rng: &mut R, dim: usize) -> Vec<f32> {
     let mut vec: Vec<f32> = (0..dim).map(|_| rng.gen_range(-1.0..1.0)).collect();
     let norm: f32 = vec.iter().map(|x| x * x).sum::<f32>().sqrt();
     if norm > f32::EPSILON {
@@ -33,7 +37,14 @@ pub fn generate_real_unit_vector(dim: usize) -> Vec<f32> {
 /// Indices are drawn uniformly from 0..30522 (BERT vocab size).
 /// Values are positive (0.1..2.0), matching SPLADE/keyword score ranges.
 pub fn generate_real_sparse_vector(target_nnz: usize) -> SparseVector {
-    let mut rng = rand::thread_rng();
+    generate_real_sparse_vector_with_rng(&mut rand::thread_rng(), target_nnz)
+}
+
+/// [`generate_real_sparse_vector`] drawing from `rng` (for seeded, reproducible data).
+pub fn generate_real_sparse_vector_with_rng<R: Rng + ?Sized>(
+    rng: &mut R,
+    target_nnz: usize,
+) -> SparseVector {
     let mut indices_set: HashSet<u16> = HashSet::new();
     while indices_set.len() < target_nnz {
         indices_set.insert(rng.gen_range(0..30522));
@@ -46,13 +57,20 @@ pub fn generate_real_sparse_vector(target_nnz: usize) -> SparseVector {
 
 /// Generate a complete `SemanticFingerprint` with correct dimensions for all 13 embedders.
 pub fn generate_real_semantic_fingerprint() -> SemanticFingerprint {
-    let e5_cause_vec = generate_real_unit_vector(768);
-    let e5_effect_vec = generate_real_unit_vector(768);
+    generate_real_semantic_fingerprint_with_rng(&mut rand::thread_rng())
+}
+
+/// [`generate_real_semantic_fingerprint`] drawing from `rng` (for seeded, reproducible data).
+pub fn generate_real_semantic_fingerprint_with_rng<R: Rng + ?Sized>(
+    rng: &mut R,
+) -> SemanticFingerprint {
+    let e5_cause_vec = generate_real_unit_vector_with_rng(rng, 768);
+    let e5_effect_vec = generate_real_unit_vector_with_rng(rng, 768);
     SemanticFingerprint {
-        e1_semantic: generate_real_unit_vector(1024),
-        e2_temporal_recent: generate_real_unit_vector(512),
-        e3_temporal_periodic: generate_real_unit_vector(512),
-        e4_temporal_positional: generate_real_unit_vector(512),
+        e1_semantic: generate_real_unit_vector_with_rng(rng, 1024),
+        e2_temporal_recent: generate_real_unit_vector_with_rng(rng, 512),
+        e3_temporal_periodic: generate_real_unit_vector_with_rng(rng, 512),
+        e4_temporal_positional: generate_real_unit_vector_with_rng(rng, 512),
         e5_causal_as_cause: e5_cause_vec,
         e5_causal_as_effect: e5_effect_vec,
         // TST-L1: INTENTIONALLY empty. The legacy unified e5_causal field is deprecated;
@@ -60,21 +78,21 @@ pub fn generate_real_semantic_fingerprint() -> SemanticFingerprint {
         // Empty vectors ensure tests exercise the CORRECT dual-vector path, not
         // the legacy fallback which would produce zero scores.
         e5_causal: Vec::new(),
-        e6_sparse: generate_real_sparse_vector(100),
-        e7_code: generate_real_unit_vector(1536),
-        e8_graph_as_source: generate_real_unit_vector(1024),
-        e8_graph_as_target: generate_real_unit_vector(1024),
+        e6_sparse: generate_real_sparse_vector_with_rng(rng, 100),
+        e7_code: generate_real_unit_vector_with_rng(rng, 1536),
+        e8_graph_as_source: generate_real_unit_vector_with_rng(rng, 1024),
+        e8_graph_as_target: generate_real_unit_vector_with_rng(rng, 1024),
         // TST-L1: INTENTIONALLY empty. The legacy unified e8_graph field is deprecated;
         // production uses the dual vectors (e8_graph_as_source / e8_graph_as_target).
         // Empty vectors ensure tests exercise the CORRECT dual-vector path, not
         // the legacy fallback which would produce zero scores.
         e8_graph: Vec::new(),
-        e9_hdc: generate_real_unit_vector(1024),
-        e10_multimodal_paraphrase: generate_real_unit_vector(768),
-        e10_multimodal_as_context: generate_real_unit_vector(768),
-        e11_entity: generate_real_unit_vector(768),
-        e12_late_interaction: vec![generate_real_unit_vector(128); 16],
-        e13_splade: generate_real_sparse_vector(500),
+        e9_hdc: generate_real_unit_vector_with_rng(rng, 1024),
+        e10_multimodal_paraphrase: generate_real_unit_vector_with_rng(rng, 768),
+        e10_multimodal_as_context: generate_real_unit_vector_with_rng(rng, 768),
+        e11_entity: generate_real_unit_vector_with_rng(rng, 768),
+        e12_late_interaction: vec![generate_real_unit_vector_with_rng(rng, 128); 16],
+        e13_splade: generate_real_sparse_vector_with_rng(rng, 500),
     }
 }
 
@@ -11,13 +11,27 @@
 //! context-graph-test-utils = { path = "../context-graph-test-utils" }
 //! ```
 
+pub mod corpus;
+pub mod entity_relations;
 pub mod fingerprints;
+pub mod session_snapshots;
 pub mod stores;
 
 // Re-export commonly used items at crate root for convenience
+pub use corpus::{CorpusBuilder, CorpusGroundTruth};
+pub use entity_relations::{
+    EntityRelationTriple, E11_EXPECTED_DIM, E11_EXPECTED_NORM, E11_NORM_TOLERANCE,
+    ENTITY_RELATION_TRIPLES,
+};
 pub use fingerprints::{
     create_real_fingerprint, create_real_fingerprint_with_id, generate_real_content_hash,
-    generate_real_semantic_fingerprint, generate_real_sparse_vector,
-    generate_real_teleological_fingerprint, generate_real_unit_vector, hex_string,
+    generate_real_semantic_fingerprint, generate_real_semantic_fingerprint_with_rng,
+    generate_real_sparse_vector, generate_real_sparse_vector_with_rng,
+    generate_real_teleological_fingerprint, generate_real_unit_vector,
+    generate_real_unit_vector_with_rng, hex_string,
+};
+pub use session_snapshots::{SESSION_SNAPSHOT_V1, SESSION_SNAPSHOT_V2};
+pub use stores::{
+    create_initialized_store, create_populated_store, create_test_store, PopulatedStore,
+    PopulatedStoreManifest, PopulatedStoreSpec, StoreBackend, POPULATED_STORE_SESSION,
 };
-pub use stores::{create_initialized_store, create_test_store};
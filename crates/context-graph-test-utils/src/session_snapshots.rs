@@ -0,0 +1,16 @@
+//! Session identity snapshots written by earlier CLI releases.
+//!
+//! `session persist-identity` writes a versioned JSON snapshot. These
+//! fixtures are byte-for-byte what older releases wrote, so restore tests
+//! can prove every format in the support window still loads and migrates.
+//!
+//! | Fixture | Format |
+//! |---------|--------|
+//! | [`SESSION_SNAPSHOT_V1`] | No `version` key, single `coherence` scalar, no trajectory |
+//! | [`SESSION_SNAPSHOT_V2`] | `version: 2`, integration/reflection/differentiation and trajectory |
+
+/// Snapshot in the original, unversioned format.
+pub const SESSION_SNAPSHOT_V1: &str = include_str!("../fixtures/session_snapshot_v1.json");
+
+/// Snapshot in format version 2.
+pub const SESSION_SNAPSHOT_V2: &str = include_str!("../fixtures/session_snapshot_v2.json");
@@ -1,10 +1,25 @@
 //! Test store factories for RocksDB-backed teleological stores.
 //!
 //! Provides consistent store creation with correct initialization
-//! (EmbedderIndexRegistry is set up in the constructor).
+//! (EmbedderIndexRegistry is set up in the constructor), plus
+//! [`create_populated_store`] for tests that need a store already holding
+//! memories, graph edges and topics.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use context_graph_core::clustering::{PersistedTopicPortfolio, Topic, TopicProfile};
+use context_graph_core::graph_linking::EmbedderEdge;
+use context_graph_core::stubs::InMemoryTeleologicalStore;
+use context_graph_core::teleological::Embedder;
+use context_graph_core::traits::TeleologicalMemoryStore;
+use context_graph_core::types::fingerprint::{SparseVector, TeleologicalFingerprint};
+use context_graph_storage::graph_edges::EdgeRepository;
 use context_graph_storage::teleological::RocksDbTeleologicalStore;
 use tempfile::TempDir;
+use uuid::Uuid;
+
+use crate::corpus::{CorpusBuilder, CorpusGroundTruth};
 
 /// Create a `RocksDbTeleologicalStore` from a `TempDir` reference.
 ///
@@ -19,3 +34,320 @@ pub fn create_test_store(temp_dir: &TempDir) -> RocksDbTeleologicalStore {
 pub fn create_initialized_store(path: &std::path::Path) -> RocksDbTeleologicalStore {
     RocksDbTeleologicalStore::open(path).expect("Failed to open store")
 }
+
+/// Which store implementation [`create_populated_store`] fills.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StoreBackend {
+    /// `InMemoryTeleologicalStore`. Has no graph edge storage.
+    InMemory,
+    /// `RocksDbTeleologicalStore` in a fresh temp dir.
+    RocksDb,
+}
+
+/// What [`create_populated_store`] should create.
+#[derive(Debug, Clone)]
+pub struct PopulatedStoreSpec {
+    /// Seed for the underlying [`CorpusBuilder`].
+    pub seed: u64,
+    /// Number of memories stored.
+    pub memories: usize,
+    /// E1 K-NN edges stored per memory (RocksDB only).
+    pub edges_per_node: usize,
+    /// Number of topics (corpus clusters). 0 stores one cluster and no
+    /// topic portfolio.
+    pub topics: usize,
+    /// Keep E13 SPLADE vectors so the sparse inverted index is populated.
+    /// When false, memories are stored with empty E13 vectors.
+    pub with_splade_index: bool,
+    /// Store implementation to populate.
+    pub backend: StoreBackend,
+}
+
+impl Default for PopulatedStoreSpec {
+    fn default() -> Self {
+        Self {
+            seed: 42,
+            memories: 20,
+            edges_per_node: 0,
+            topics: 0,
+            with_splade_index: true,
+            backend: StoreBackend::RocksDb,
+        }
+    }
+}
+
+/// Session id the topic portfolio is persisted under.
+pub const POPULATED_STORE_SESSION: &str = "test-utils-populated";
+
+/// Ids of everything [`create_populated_store`] created.
+#[derive(Debug, Clone)]
+pub struct PopulatedStoreManifest {
+    /// Stored memory ids, in corpus order.
+    pub memory_ids: Vec<Uuid>,
+    /// Stored E1 (embedder 0) edges as `(source, target)`.
+    pub edges: Vec<(Uuid, Uuid)>,
+    /// Ids of the persisted topics, one per corpus cluster.
+    pub topic_ids: Vec<Uuid>,
+    /// Session the topic portfolio was persisted under, if any.
+    pub topic_session: Option<String>,
+    /// Cluster and domain assignments of the stored memories.
+    pub ground_truth: CorpusGroundTruth,
+}
+
+/// A store filled by [`create_populated_store`].
+///
+/// Keep it alive for the duration of the test: it owns the temp dir of a
+/// RocksDB-backed store.
+pub struct PopulatedStore {
+    /// The populated store.
+    pub store: Arc<dyn TeleologicalMemoryStore>,
+    /// Graph edge repository on the same database (RocksDB only).
+    pub edges: Option<EdgeRepository>,
+    /// Ids of everything created.
+    pub manifest: PopulatedStoreManifest,
+    _temp_dir: Option<TempDir>,
+}
+
+/// Create a store populated with a seeded corpus, E1 K-NN edges and a topic
+/// portfolio, all written through the public store APIs.
+///
+/// # Panics
+/// If `spec` asks for edges on the in-memory backend, or any store write fails.
+pub async fn create_populated_store(spec: PopulatedStoreSpec) -> PopulatedStore {
+    assert!(
+        spec.edges_per_node == 0 || spec.backend == StoreBackend::RocksDb,
+        "create_populated_store: graph edges need the RocksDb backend"
+    );
+
+    let clusters = spec.topics.max(1);
+    let cluster_size = spec.memories.div_ceil(clusters).max(1);
+    let (mut corpus, ground_truth) = CorpusBuilder::new(spec.seed)
+        .with_clusters(clusters, cluster_size, 0.8)
+        .build_with_ground_truth();
+    corpus.truncate(spec.memories);
+    if !spec.with_splade_index {
+        for fp in &mut corpus {
+            fp.semantic.e13_splade = SparseVector::empty();
+        }
+    }
+
+    let (store, edge_repo, temp_dir): (Arc<dyn TeleologicalMemoryStore>, _, _) = match spec.backend
+    {
+        StoreBackend::InMemory => (Arc::new(InMemoryTeleologicalStore::new()), None, None),
+        StoreBackend::RocksDb => {
+            let temp_dir = TempDir::new().expect("Failed to create temp dir");
+            let store = create_test_store(&temp_dir);
+            let repo = EdgeRepository::new(store.db_arc());
+            (Arc::new(store), Some(repo), Some(temp_dir))
+        }
+    };
+
+    let mut memory_ids = Vec::with_capacity(corpus.len());
+    for fp in &corpus {
+        let id = store
+            .store(fp.clone())
+            .await
+            .expect("Failed to store fingerprint");
+        memory_ids.push(id);
+    }
+
+    let mut edges = Vec::new();
+    if let Some(repo) = &edge_repo {
+        for (source, neighbors) in e1_knn(&corpus, spec.edges_per_node) {
+            let list: Vec<EmbedderEdge> = neighbors
+                .iter()
+                .map(|&(target, sim)| {
+                    EmbedderEdge::new(source, target, 0, sim).expect("Invalid E1 edge")
+                })
+                .collect();
+            repo.store_embedder_edges(0, source, &list)
+                .expect("Failed to store embedder edges");
+            edges.extend(neighbors.iter().map(|&(target, _)| (source, target)));
+        }
+    }
+
+    let (topic_ids, topic_session) = if spec.topics > 0 {
+        let topics: Vec<Topic> = ground_truth
+            .clusters()
+            .iter()
+            .enumerate()
+            .map(|(i, members)| {
+                let stored: Vec<Uuid> = members
+                    .iter()
+                    .copied()
+                    .filter(|id| memory_ids.contains(id))
+                    .collect();
+                let mut strengths = [0.0; 13];
+                strengths[Embedder::Semantic.index()] = 1.0;
+                strengths[Embedder::Causal.index()] = 1.0;
+                strengths[Embedder::Code.index()] = 1.0;
+                let cluster_ids = HashMap::from([(Embedder::Semantic, i as i32)]);
+                Topic::new(TopicProfile::new(strengths), cluster_ids, stored)
+            })
+            .collect();
+        let topic_ids = topics.iter().map(|t| t.id).collect();
+        let portfolio =
+            PersistedTopicPortfolio::new(topics, 0.0, 0.0, POPULATED_STORE_SESSION.to_string());
+        store
+            .persist_topic_portfolio(POPULATED_STORE_SESSION, &portfolio)
+            .await
+            .expect("Failed to persist topic portfolio");
+        (topic_ids, Some(POPULATED_STORE_SESSION.to_string()))
+    } else {
+        (Vec::new(), None)
+    };
+
+    PopulatedStore {
+        store,
+        edges: edge_repo,
+        manifest: PopulatedStoreManifest {
+            memory_ids,
+            edges,
+            topic_ids,
+            topic_session,
+            ground_truth,
+        },
+        _temp_dir: temp_dir,
+    }
+}
+
+/// Top-`k` E1 cosine neighbors of every fingerprint, best first.
+fn e1_knn(corpus: &[TeleologicalFingerprint], k: usize) -> Vec<(Uuid, Vec<(Uuid, f32)>)> {
+    if k == 0 {
+        return Vec::new();
+    }
+    corpus
+        .iter()
+        .map(|a| {
+            let mut neighbors: Vec<(Uuid, f32)> = corpus
+                .iter()
+                .filter(|b| b.id != a.id)
+                .map(|b| {
+                    let sim = cosine(&a.semantic.e1_semantic, &b.semantic.e1_semantic);
+                    (b.id, sim.clamp(-1.0, 1.0))
+                })
+                .collect();
+            neighbors.sort_by(|x, y| y.1.total_cmp(&x.1));
+            neighbors.truncate(k);
+            (a.id, neighbors)
+        })
+        .collect()
+}
+
+fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let na = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let nb = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if na <= f32::EPSILON || nb <= f32::EPSILON {
+        return 0.0;
+    }
+    dot / (na * nb)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn assert_manifest_matches(populated: &PopulatedStore, spec: &PopulatedStoreSpec) {
+        let manifest = &populated.manifest;
+        assert_eq!(manifest.memory_ids.len(), spec.memories);
+        assert_eq!(populated.store.count().await.unwrap(), spec.memories);
+        for id in &manifest.memory_ids {
+            assert!(populated.store.retrieve(*id).await.unwrap().is_some());
+        }
+
+        let portfolio = populated
+            .store
+            .load_topic_portfolio(POPULATED_STORE_SESSION)
+            .await
+            .unwrap();
+        if spec.topics > 0 {
+            let portfolio = portfolio.expect("topic portfolio persisted");
+            let ids: Vec<Uuid> = portfolio.topics.iter().map(|t| t.id).collect();
+            assert_eq!(ids, manifest.topic_ids);
+            let members: usize = portfolio
+                .topics
+                .iter()
+                .map(|t| t.member_memories.len())
+                .sum();
+            assert_eq!(members, spec.memories);
+        } else {
+            assert!(portfolio.is_none());
+            assert!(manifest.topic_ids.is_empty());
+        }
+
+        // Sparse recall finds a memory by its own SPLADE terms only when indexed
+        let first = populated
+            .store
+            .retrieve(manifest.memory_ids[0])
+            .await
+            .unwrap()
+            .unwrap();
+        let query = if spec.with_splade_index {
+            first.semantic.e13_splade.clone()
+        } else {
+            assert!(first.semantic.e13_splade.is_empty());
+            crate::generate_real_sparse_vector(50)
+        };
+        let hits = populated.store.search_sparse(&query, 5).await.unwrap();
+        assert_eq!(
+            hits.iter().any(|(id, _)| *id == first.id),
+            spec.with_splade_index
+        );
+    }
+
+    #[tokio::test]
+    async fn test_populated_rocksdb_store_matches_manifest() {
+        let spec = PopulatedStoreSpec {
+            seed: 11,
+            memories: 12,
+            edges_per_node: 3,
+            topics: 3,
+            with_splade_index: true,
+            backend: StoreBackend::RocksDb,
+        };
+        let populated = create_populated_store(spec.clone()).await;
+        assert_manifest_matches(&populated, &spec).await;
+
+        let repo = populated.edges.as_ref().unwrap();
+        assert_eq!(populated.manifest.edges.len(), 12 * 3);
+        let mut stored = 0;
+        for id in &populated.manifest.memory_ids {
+            let edges = repo.get_embedder_edges(0, *id).unwrap();
+            for edge in &edges {
+                assert!(populated.manifest.edges.contains(&(*id, edge.target())));
+            }
+            stored += edges.len();
+        }
+        assert_eq!(stored, populated.manifest.edges.len());
+    }
+
+    #[tokio::test]
+    async fn test_populated_in_memory_store_matches_manifest() {
+        let spec = PopulatedStoreSpec {
+            seed: 5,
+            memories: 7,
+            topics: 2,
+            with_splade_index: false,
+            backend: StoreBackend::InMemory,
+            ..PopulatedStoreSpec::default()
+        };
+        let populated = create_populated_store(spec.clone()).await;
+        assert!(populated.edges.is_none());
+        assert!(populated.manifest.edges.is_empty());
+        assert_manifest_matches(&populated, &spec).await;
+    }
+
+    #[tokio::test]
+    async fn test_same_seed_yields_same_manifest() {
+        let spec = PopulatedStoreSpec {
+            backend: StoreBackend::InMemory,
+            topics: 2,
+            ..PopulatedStoreSpec::default()
+        };
+        let a = create_populated_store(spec.clone()).await;
+        let b = create_populated_store(spec).await;
+        assert_eq!(a.manifest.memory_ids, b.manifest.memory_ids);
+        assert_eq!(a.manifest.topic_ids, b.manifest.topic_ids);
+    }
+}